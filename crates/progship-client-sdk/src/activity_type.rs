@@ -12,6 +12,9 @@ pub struct Activity {
     pub started_at: f64,
     pub duration: f32,
     pub target_room_id: Option<u32>,
+    pub animation_state: u8,
+    pub target_anchor_id: Option<u64>,
+    pub target_person_id: Option<u64>,
 }
 
 impl __sdk::InModule for Activity {
@@ -27,6 +30,9 @@ pub struct ActivityCols {
     pub started_at: __sdk::__query_builder::Col<Activity, f64>,
     pub duration: __sdk::__query_builder::Col<Activity, f32>,
     pub target_room_id: __sdk::__query_builder::Col<Activity, Option<u32>>,
+    pub animation_state: __sdk::__query_builder::Col<Activity, u8>,
+    pub target_anchor_id: __sdk::__query_builder::Col<Activity, Option<u64>>,
+    pub target_person_id: __sdk::__query_builder::Col<Activity, Option<u64>>,
 }
 
 impl __sdk::__query_builder::HasCols for Activity {
@@ -38,6 +44,9 @@ impl __sdk::__query_builder::HasCols for Activity {
             started_at: __sdk::__query_builder::Col::new(table_name, "started_at"),
             duration: __sdk::__query_builder::Col::new(table_name, "duration"),
             target_room_id: __sdk::__query_builder::Col::new(table_name, "target_room_id"),
+            animation_state: __sdk::__query_builder::Col::new(table_name, "animation_state"),
+            target_anchor_id: __sdk::__query_builder::Col::new(table_name, "target_anchor_id"),
+            target_person_id: __sdk::__query_builder::Col::new(table_name, "target_person_id"),
         }
     }
 }
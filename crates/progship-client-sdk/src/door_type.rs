@@ -17,8 +17,7 @@ pub struct Door {
     pub access_level: u8,
     pub door_x: f32,
     pub door_y: f32,
-    pub is_open: bool,
-    pub is_locked: bool,
+    pub state: u8,
 }
 
 impl __sdk::InModule for Door {
@@ -39,8 +38,7 @@ pub struct DoorCols {
     pub access_level: __sdk::__query_builder::Col<Door, u8>,
     pub door_x: __sdk::__query_builder::Col<Door, f32>,
     pub door_y: __sdk::__query_builder::Col<Door, f32>,
-    pub is_open: __sdk::__query_builder::Col<Door, bool>,
-    pub is_locked: __sdk::__query_builder::Col<Door, bool>,
+    pub state: __sdk::__query_builder::Col<Door, u8>,
 }
 
 impl __sdk::__query_builder::HasCols for Door {
@@ -60,8 +58,7 @@ impl __sdk::__query_builder::HasCols for Door {
             access_level: __sdk::__query_builder::Col::new(table_name, "access_level"),
             door_x: __sdk::__query_builder::Col::new(table_name, "door_x"),
             door_y: __sdk::__query_builder::Col::new(table_name, "door_y"),
-            is_open: __sdk::__query_builder::Col::new(table_name, "is_open"),
-            is_locked: __sdk::__query_builder::Col::new(table_name, "is_locked"),
+            state: __sdk::__query_builder::Col::new(table_name, "state"),
         }
     }
 }
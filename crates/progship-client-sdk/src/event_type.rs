@@ -16,6 +16,7 @@ pub struct Event {
     pub responders_needed: u8,
     pub responders_assigned: u8,
     pub severity: f32,
+    pub escalated_to: Option<u64>,
 }
 
 impl __sdk::InModule for Event {
@@ -35,6 +36,7 @@ pub struct EventCols {
     pub responders_needed: __sdk::__query_builder::Col<Event, u8>,
     pub responders_assigned: __sdk::__query_builder::Col<Event, u8>,
     pub severity: __sdk::__query_builder::Col<Event, f32>,
+    pub escalated_to: __sdk::__query_builder::Col<Event, Option<u64>>,
 }
 
 impl __sdk::__query_builder::HasCols for Event {
@@ -53,6 +55,7 @@ impl __sdk::__query_builder::HasCols for Event {
                 "responders_assigned",
             ),
             severity: __sdk::__query_builder::Col::new(table_name, "severity"),
+            escalated_to: __sdk::__query_builder::Col::new(table_name, "escalated_to"),
         }
     }
 }
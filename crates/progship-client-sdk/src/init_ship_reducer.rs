@@ -11,6 +11,8 @@ pub(super) struct InitShipArgs {
     pub deck_count: u32,
     pub crew_count: u32,
     pub passenger_count: u32,
+    pub corridor_topology: u8,
+    pub hull_taper: u8,
 }
 
 impl From<InitShipArgs> for super::Reducer {
@@ -20,6 +22,8 @@ impl From<InitShipArgs> for super::Reducer {
             deck_count: args.deck_count,
             crew_count: args.crew_count,
             passenger_count: args.passenger_count,
+            corridor_topology: args.corridor_topology,
+            hull_taper: args.hull_taper,
         }
     }
 }
@@ -46,6 +50,8 @@ pub trait init_ship {
         deck_count: u32,
         crew_count: u32,
         passenger_count: u32,
+        corridor_topology: u8,
+        hull_taper: u8,
     ) -> __sdk::Result<()>;
     /// Register a callback to run whenever we are notified of an invocation of the reducer `init_ship`.
     ///
@@ -56,7 +62,9 @@ pub trait init_ship {
     /// to cancel the callback.
     fn on_init_ship(
         &self,
-        callback: impl FnMut(&super::ReducerEventContext, &String, &u32, &u32, &u32) + Send + 'static,
+        callback: impl FnMut(&super::ReducerEventContext, &String, &u32, &u32, &u32, &u8, &u8)
+            + Send
+            + 'static,
     ) -> InitShipCallbackId;
     /// Cancel a callback previously registered by [`Self::on_init_ship`],
     /// causing it not to run in the future.
@@ -70,6 +78,8 @@ impl init_ship for super::RemoteReducers {
         deck_count: u32,
         crew_count: u32,
         passenger_count: u32,
+        corridor_topology: u8,
+        hull_taper: u8,
     ) -> __sdk::Result<()> {
         self.imp.call_reducer(
             "init_ship",
@@ -78,12 +88,14 @@ impl init_ship for super::RemoteReducers {
                 deck_count,
                 crew_count,
                 passenger_count,
+                corridor_topology,
+                hull_taper,
             },
         )
     }
     fn on_init_ship(
         &self,
-        mut callback: impl FnMut(&super::ReducerEventContext, &String, &u32, &u32, &u32)
+        mut callback: impl FnMut(&super::ReducerEventContext, &String, &u32, &u32, &u32, &u8, &u8)
             + Send
             + 'static,
     ) -> InitShipCallbackId {
@@ -100,6 +112,8 @@ impl init_ship for super::RemoteReducers {
                                     deck_count,
                                     crew_count,
                                     passenger_count,
+                                    corridor_topology,
+                                    hull_taper,
                                 },
                             ..
                         },
@@ -108,7 +122,15 @@ impl init_ship for super::RemoteReducers {
                 else {
                     unreachable!()
                 };
-                callback(ctx, name, deck_count, crew_count, passenger_count)
+                callback(
+                    ctx,
+                    name,
+                    deck_count,
+                    crew_count,
+                    passenger_count,
+                    corridor_topology,
+                    hull_taper,
+                )
             }),
         ))
     }
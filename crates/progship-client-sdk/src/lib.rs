@@ -6,42 +6,160 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+pub mod activity_anchor_table;
+pub mod activity_anchor_type;
+pub mod activity_explanation_table;
+pub mod activity_explanation_type;
 pub mod activity_table;
 pub mod activity_type;
+pub mod advisory_table;
+pub mod advisory_type;
+pub mod air_duct_table;
+pub mod air_duct_type;
+pub mod balance_config_table;
+pub mod balance_config_type;
+pub mod cancel_order_reducer;
+pub mod candidate_table;
+pub mod candidate_type;
+pub mod career_table;
+pub mod career_type;
+pub mod cargo_item_table;
+pub mod cargo_item_type;
+pub mod casualty_table;
+pub mod casualty_type;
 pub mod client_connected_reducer;
 pub mod client_disconnected_reducer;
+pub mod command_chain_table;
+pub mod command_chain_type;
+pub mod configure_autosnapshot_reducer;
+pub mod configure_stream_reducer;
 pub mod connected_player_table;
 pub mod connected_player_type;
 pub mod conversation_table;
 pub mod conversation_type;
+pub mod coolant_loop_table;
+pub mod coolant_loop_type;
 pub mod corridor_table;
 pub mod corridor_type;
 pub mod crew_table;
 pub mod crew_type;
-pub mod deck_atmosphere_table;
-pub mod deck_atmosphere_type;
+pub mod crop_table;
+pub mod crop_type;
+pub mod culture_mix_table;
+pub mod culture_mix_type;
+pub mod daily_history_table;
+pub mod daily_history_type;
+pub mod deck_generation_job_table;
+pub mod deck_generation_job_type;
+pub mod deck_nickname_table;
+pub mod deck_nickname_type;
+pub mod deck_table;
+pub mod deck_type;
+pub mod detention_table;
+pub mod detention_type;
 pub mod door_table;
 pub mod door_type;
+pub mod drinking_table;
+pub mod drinking_type;
+pub mod election_table;
+pub mod election_type;
+pub mod emotion_table;
+pub mod emotion_type;
+pub mod eva_mission_table;
+pub mod eva_mission_type;
+pub mod evacuation_route_table;
+pub mod evacuation_route_type;
 pub mod event_table;
 pub mod event_type;
+pub mod explain_activity_reducer;
+pub mod export_frame_reducer;
+pub mod export_state_reducer;
+pub mod faction_membership_table;
+pub mod faction_membership_type;
+pub mod faction_table;
+pub mod faction_type;
+pub mod family_member_table;
+pub mod family_member_type;
+pub mod family_table;
+pub mod family_type;
+pub mod feature_flags_table;
+pub mod feature_flags_type;
+pub mod frame_export_table;
+pub mod frame_export_type;
+pub mod funeral_table;
+pub mod funeral_type;
+pub mod furniture_table;
+pub mod furniture_type;
+pub mod garden_table;
+pub mod garden_type;
+pub mod generate_pending_deck_reducer;
+pub mod generate_voyage_report_reducer;
+pub mod generation_stats_table;
+pub mod generation_stats_type;
+pub mod give_item_reducer;
 pub mod graph_edge_table;
 pub mod graph_edge_type;
 pub mod graph_node_table;
 pub mod graph_node_type;
+pub mod grief_table;
+pub mod grief_type;
+pub mod hibernation_table;
+pub mod hibernation_type;
+pub mod history_config_table;
+pub mod history_config_type;
+pub mod hull_damage_table;
+pub mod hull_damage_type;
+pub mod hull_hatch_table;
+pub mod hull_hatch_type;
 pub mod in_conversation_table;
 pub mod in_conversation_type;
+pub mod incident_table;
+pub mod incident_type;
 pub mod infra_edge_table;
 pub mod infra_edge_type;
+pub mod init_daily_ship_reducer;
+pub mod init_reducer;
+pub mod init_scenario_reducer;
 pub mod init_ship_reducer;
-pub mod maintenance_task_table;
-pub mod maintenance_task_type;
+pub mod init_warm_ship_reducer;
+pub mod inventory_item_table;
+pub mod inventory_item_type;
+pub mod issue_order_reducer;
+pub mod launch_shuttle_reducer;
+pub mod lighting_table;
+pub mod lighting_type;
+pub mod meal_buffer_table;
+pub mod meal_buffer_type;
+pub mod mental_health_table;
+pub mod mental_health_type;
+pub mod milestone_table;
+pub mod milestone_tracker_table;
+pub mod milestone_tracker_type;
+pub mod milestone_type;
+pub mod morale_summary_table;
+pub mod morale_summary_type;
 pub mod movement_table;
 pub mod movement_type;
+pub mod mutiny_table;
+pub mod mutiny_type;
 pub mod needs_table;
 pub mod needs_type;
+pub mod negotiate_mutiny_reducer;
+pub mod newborn_name_table;
+pub mod newborn_name_type;
+pub mod order_assignment_table;
+pub mod order_assignment_type;
+pub mod part_stock_table;
+pub mod part_stock_type;
 pub mod passenger_table;
 pub mod passenger_type;
+pub mod patient_table;
+pub mod patient_type;
 pub mod person_table;
+pub mod person_timeline_table;
+pub mod person_timeline_type;
+pub mod person_trait_table;
+pub mod person_trait_type;
 pub mod person_type;
 pub mod personality_table;
 pub mod personality_type;
@@ -49,73 +167,295 @@ pub mod player_action_reducer;
 pub mod player_interact_reducer;
 pub mod player_join_reducer;
 pub mod player_move_reducer;
+pub mod player_objective_table;
+pub mod player_objective_type;
 pub mod player_use_elevator_reducer;
 pub mod player_use_ladder_reducer;
+pub mod player_view_screen_reducer;
+pub mod policy_table;
+pub mod policy_type;
+pub mod population_sample_table;
+pub mod population_sample_type;
 pub mod position_table;
 pub mod position_type;
+pub mod power_grid_table;
+pub mod power_grid_type;
+pub mod pregnancy_table;
+pub mod pregnancy_type;
+pub mod quarantine_table;
+pub mod quarantine_type;
+pub mod radiation_dose_table;
+pub mod radiation_dose_type;
+pub mod recall_shuttle_reducer;
+pub mod recover_from_snapshot_reducer;
 pub mod relationship_table;
 pub mod relationship_type;
+pub mod robot_table;
+pub mod robot_type;
+pub mod room_air_table;
+pub mod room_air_type;
+pub mod room_ambience_table;
+pub mod room_ambience_type;
+pub mod room_effect_table;
+pub mod room_effect_type;
+pub mod room_nickname_table;
+pub mod room_nickname_type;
+pub mod room_power_table;
+pub mod room_power_type;
 pub mod room_table;
 pub mod room_type;
+pub mod room_water_table;
+pub mod room_water_type;
+pub mod schooling_table;
+pub mod schooling_type;
+pub mod sensor_contact_table;
+pub mod sensor_contact_type;
+pub mod set_balance_config_reducer;
+pub mod set_culture_weight_reducer;
+pub mod set_deck_nickname_reducer;
+pub mod set_door_state_reducer;
+pub mod set_feature_flags_reducer;
+pub mod set_history_retention_reducer;
 pub mod set_paused_reducer;
+pub mod set_room_nickname_reducer;
 pub mod set_time_scale_reducer;
+pub mod set_zone_decks_reducer;
+pub mod shift_lighting_reducer;
 pub mod ship_config_table;
 pub mod ship_config_type;
+pub mod ship_owner_table;
+pub mod ship_owner_type;
 pub mod ship_resources_table;
 pub mod ship_resources_type;
 pub mod ship_system_table;
 pub mod ship_system_type;
+pub mod shuttle_table;
+pub mod shuttle_type;
+pub mod simulation_warning_table;
+pub mod simulation_warning_type;
+pub mod skill_upkeep_table;
+pub mod skill_upkeep_type;
 pub mod skills_table;
 pub mod skills_type;
+pub mod snapshot_config_table;
+pub mod snapshot_config_type;
+pub mod spotlight_table;
+pub mod spotlight_type;
+pub mod standing_order_table;
+pub mod standing_order_type;
+pub mod state_export_table;
+pub mod state_export_type;
+pub mod stock_trend_table;
+pub mod stock_trend_type;
+pub mod stream_action_table;
+pub mod stream_action_type;
+pub mod stream_config_table;
+pub mod stream_config_type;
+pub mod stream_name_newborn_reducer;
+pub mod stream_spotlight_reducer;
+pub mod stream_vote_reducer;
 pub mod subsystem_table;
 pub mod subsystem_type;
+pub mod suppress_mutiny_reducer;
+pub mod surgery_table;
+pub mod surgery_type;
 pub mod system_component_table;
 pub mod system_component_type;
+pub mod teacher_table;
+pub mod teacher_type;
 pub mod tick_reducer;
 pub mod toggle_door_reducer;
 pub mod vertical_shaft_table;
 pub mod vertical_shaft_type;
-
+pub mod voyage_report_table;
+pub mod voyage_report_type;
+pub mod voyage_state_table;
+pub mod voyage_state_type;
+pub mod wallet_table;
+pub mod wallet_type;
+pub mod work_order_table;
+pub mod work_order_type;
+pub mod zone_config_table;
+pub mod zone_config_type;
+
+pub use activity_anchor_table::*;
+pub use activity_anchor_type::ActivityAnchor;
+pub use activity_explanation_table::*;
+pub use activity_explanation_type::ActivityExplanation;
 pub use activity_table::*;
 pub use activity_type::Activity;
+pub use advisory_table::*;
+pub use advisory_type::Advisory;
+pub use air_duct_table::*;
+pub use air_duct_type::AirDuct;
+pub use balance_config_table::*;
+pub use balance_config_type::BalanceConfig;
+pub use cancel_order_reducer::{cancel_order, set_flags_for_cancel_order, CancelOrderCallbackId};
+pub use candidate_table::*;
+pub use candidate_type::Candidate;
+pub use career_table::*;
+pub use career_type::Career;
+pub use cargo_item_table::*;
+pub use cargo_item_type::CargoItem;
+pub use casualty_table::*;
+pub use casualty_type::Casualty;
 pub use client_connected_reducer::{
     client_connected, set_flags_for_client_connected, ClientConnectedCallbackId,
 };
 pub use client_disconnected_reducer::{
     client_disconnected, set_flags_for_client_disconnected, ClientDisconnectedCallbackId,
 };
+pub use command_chain_table::*;
+pub use command_chain_type::CommandChain;
+pub use configure_autosnapshot_reducer::{
+    configure_autosnapshot, set_flags_for_configure_autosnapshot, ConfigureAutosnapshotCallbackId,
+};
+pub use configure_stream_reducer::{
+    configure_stream, set_flags_for_configure_stream, ConfigureStreamCallbackId,
+};
 pub use connected_player_table::*;
 pub use connected_player_type::ConnectedPlayer;
 pub use conversation_table::*;
 pub use conversation_type::Conversation;
+pub use coolant_loop_table::*;
+pub use coolant_loop_type::CoolantLoop;
 pub use corridor_table::*;
 pub use corridor_type::Corridor;
 pub use crew_table::*;
 pub use crew_type::Crew;
-pub use deck_atmosphere_table::*;
-pub use deck_atmosphere_type::DeckAtmosphere;
+pub use crop_table::*;
+pub use crop_type::Crop;
+pub use culture_mix_table::*;
+pub use culture_mix_type::CultureMix;
+pub use daily_history_table::*;
+pub use daily_history_type::DailyHistory;
+pub use deck_generation_job_table::*;
+pub use deck_generation_job_type::DeckGenerationJob;
+pub use deck_nickname_table::*;
+pub use deck_nickname_type::DeckNickname;
+pub use deck_table::*;
+pub use deck_type::Deck;
+pub use detention_table::*;
+pub use detention_type::Detention;
 pub use door_table::*;
 pub use door_type::Door;
+pub use drinking_table::*;
+pub use drinking_type::Drinking;
+pub use election_table::*;
+pub use election_type::Election;
+pub use emotion_table::*;
+pub use emotion_type::Emotion;
+pub use eva_mission_table::*;
+pub use eva_mission_type::EvaMission;
+pub use evacuation_route_table::*;
+pub use evacuation_route_type::EvacuationRoute;
 pub use event_table::*;
 pub use event_type::Event;
+pub use explain_activity_reducer::{
+    explain_activity, set_flags_for_explain_activity, ExplainActivityCallbackId,
+};
+pub use export_frame_reducer::{export_frame, set_flags_for_export_frame, ExportFrameCallbackId};
+pub use export_state_reducer::{export_state, set_flags_for_export_state, ExportStateCallbackId};
+pub use faction_membership_table::*;
+pub use faction_membership_type::FactionMembership;
+pub use faction_table::*;
+pub use faction_type::Faction;
+pub use family_member_table::*;
+pub use family_member_type::FamilyMember;
+pub use family_table::*;
+pub use family_type::Family;
+pub use feature_flags_table::*;
+pub use feature_flags_type::FeatureFlags;
+pub use frame_export_table::*;
+pub use frame_export_type::FrameExport;
+pub use funeral_table::*;
+pub use funeral_type::Funeral;
+pub use furniture_table::*;
+pub use furniture_type::Furniture;
+pub use garden_table::*;
+pub use garden_type::Garden;
+pub use generate_pending_deck_reducer::{
+    generate_pending_deck, set_flags_for_generate_pending_deck, GeneratePendingDeckCallbackId,
+};
+pub use generate_voyage_report_reducer::{
+    generate_voyage_report, set_flags_for_generate_voyage_report, GenerateVoyageReportCallbackId,
+};
+pub use generation_stats_table::*;
+pub use generation_stats_type::GenerationStats;
+pub use give_item_reducer::{give_item, set_flags_for_give_item, GiveItemCallbackId};
 pub use graph_edge_table::*;
 pub use graph_edge_type::GraphEdge;
 pub use graph_node_table::*;
 pub use graph_node_type::GraphNode;
+pub use grief_table::*;
+pub use grief_type::Grief;
+pub use hibernation_table::*;
+pub use hibernation_type::Hibernation;
+pub use history_config_table::*;
+pub use history_config_type::HistoryConfig;
+pub use hull_damage_table::*;
+pub use hull_damage_type::HullDamage;
+pub use hull_hatch_table::*;
+pub use hull_hatch_type::HullHatch;
 pub use in_conversation_table::*;
 pub use in_conversation_type::InConversation;
+pub use incident_table::*;
+pub use incident_type::Incident;
 pub use infra_edge_table::*;
 pub use infra_edge_type::InfraEdge;
+pub use init_daily_ship_reducer::{
+    init_daily_ship, set_flags_for_init_daily_ship, InitDailyShipCallbackId,
+};
+pub use init_reducer::{init, set_flags_for_init, InitCallbackId};
+pub use init_scenario_reducer::{
+    init_scenario, set_flags_for_init_scenario, InitScenarioCallbackId,
+};
 pub use init_ship_reducer::{init_ship, set_flags_for_init_ship, InitShipCallbackId};
-pub use maintenance_task_table::*;
-pub use maintenance_task_type::MaintenanceTask;
+pub use init_warm_ship_reducer::{
+    init_warm_ship, set_flags_for_init_warm_ship, InitWarmShipCallbackId,
+};
+pub use inventory_item_table::*;
+pub use inventory_item_type::InventoryItem;
+pub use issue_order_reducer::{issue_order, set_flags_for_issue_order, IssueOrderCallbackId};
+pub use launch_shuttle_reducer::{
+    launch_shuttle, set_flags_for_launch_shuttle, LaunchShuttleCallbackId,
+};
+pub use lighting_table::*;
+pub use lighting_type::Lighting;
+pub use meal_buffer_table::*;
+pub use meal_buffer_type::MealBuffer;
+pub use mental_health_table::*;
+pub use mental_health_type::MentalHealth;
+pub use milestone_table::*;
+pub use milestone_tracker_table::*;
+pub use milestone_tracker_type::MilestoneTracker;
+pub use milestone_type::Milestone;
+pub use morale_summary_table::*;
+pub use morale_summary_type::MoraleSummary;
 pub use movement_table::*;
 pub use movement_type::Movement;
+pub use mutiny_table::*;
+pub use mutiny_type::Mutiny;
 pub use needs_table::*;
 pub use needs_type::Needs;
+pub use negotiate_mutiny_reducer::{
+    negotiate_mutiny, set_flags_for_negotiate_mutiny, NegotiateMutinyCallbackId,
+};
+pub use newborn_name_table::*;
+pub use newborn_name_type::NewbornName;
+pub use order_assignment_table::*;
+pub use order_assignment_type::OrderAssignment;
+pub use part_stock_table::*;
+pub use part_stock_type::PartStock;
 pub use passenger_table::*;
 pub use passenger_type::Passenger;
+pub use patient_table::*;
+pub use patient_type::Patient;
 pub use person_table::*;
+pub use person_timeline_table::*;
+pub use person_timeline_type::PersonTimeline;
+pub use person_trait_table::*;
+pub use person_trait_type::PersonTrait;
 pub use person_type::Person;
 pub use personality_table::*;
 pub use personality_type::Personality;
@@ -127,38 +467,152 @@ pub use player_interact_reducer::{
 };
 pub use player_join_reducer::{player_join, set_flags_for_player_join, PlayerJoinCallbackId};
 pub use player_move_reducer::{player_move, set_flags_for_player_move, PlayerMoveCallbackId};
+pub use player_objective_table::*;
+pub use player_objective_type::PlayerObjective;
 pub use player_use_elevator_reducer::{
     player_use_elevator, set_flags_for_player_use_elevator, PlayerUseElevatorCallbackId,
 };
 pub use player_use_ladder_reducer::{
     player_use_ladder, set_flags_for_player_use_ladder, PlayerUseLadderCallbackId,
 };
+pub use player_view_screen_reducer::{
+    player_view_screen, set_flags_for_player_view_screen, PlayerViewScreenCallbackId,
+};
+pub use policy_table::*;
+pub use policy_type::Policy;
+pub use population_sample_table::*;
+pub use population_sample_type::PopulationSample;
 pub use position_table::*;
 pub use position_type::Position;
+pub use power_grid_table::*;
+pub use power_grid_type::PowerGrid;
+pub use pregnancy_table::*;
+pub use pregnancy_type::Pregnancy;
+pub use quarantine_table::*;
+pub use quarantine_type::Quarantine;
+pub use radiation_dose_table::*;
+pub use radiation_dose_type::RadiationDose;
+pub use recall_shuttle_reducer::{
+    recall_shuttle, set_flags_for_recall_shuttle, RecallShuttleCallbackId,
+};
+pub use recover_from_snapshot_reducer::{
+    recover_from_snapshot, set_flags_for_recover_from_snapshot, RecoverFromSnapshotCallbackId,
+};
 pub use relationship_table::*;
 pub use relationship_type::Relationship;
+pub use robot_table::*;
+pub use robot_type::Robot;
+pub use room_air_table::*;
+pub use room_air_type::RoomAir;
+pub use room_ambience_table::*;
+pub use room_ambience_type::RoomAmbience;
+pub use room_effect_table::*;
+pub use room_effect_type::RoomEffect;
+pub use room_nickname_table::*;
+pub use room_nickname_type::RoomNickname;
+pub use room_power_table::*;
+pub use room_power_type::RoomPower;
 pub use room_table::*;
 pub use room_type::Room;
+pub use room_water_table::*;
+pub use room_water_type::RoomWater;
+pub use schooling_table::*;
+pub use schooling_type::Schooling;
+pub use sensor_contact_table::*;
+pub use sensor_contact_type::SensorContact;
+pub use set_balance_config_reducer::{
+    set_balance_config, set_flags_for_set_balance_config, SetBalanceConfigCallbackId,
+};
+pub use set_culture_weight_reducer::{
+    set_culture_weight, set_flags_for_set_culture_weight, SetCultureWeightCallbackId,
+};
+pub use set_deck_nickname_reducer::{
+    set_deck_nickname, set_flags_for_set_deck_nickname, SetDeckNicknameCallbackId,
+};
+pub use set_door_state_reducer::{
+    set_door_state, set_flags_for_set_door_state, SetDoorStateCallbackId,
+};
+pub use set_feature_flags_reducer::{
+    set_feature_flags, set_flags_for_set_feature_flags, SetFeatureFlagsCallbackId,
+};
+pub use set_history_retention_reducer::{
+    set_flags_for_set_history_retention, set_history_retention, SetHistoryRetentionCallbackId,
+};
 pub use set_paused_reducer::{set_flags_for_set_paused, set_paused, SetPausedCallbackId};
+pub use set_room_nickname_reducer::{
+    set_flags_for_set_room_nickname, set_room_nickname, SetRoomNicknameCallbackId,
+};
 pub use set_time_scale_reducer::{
     set_flags_for_set_time_scale, set_time_scale, SetTimeScaleCallbackId,
 };
+pub use set_zone_decks_reducer::{
+    set_flags_for_set_zone_decks, set_zone_decks, SetZoneDecksCallbackId,
+};
+pub use shift_lighting_reducer::{
+    set_flags_for_shift_lighting, shift_lighting, ShiftLightingCallbackId,
+};
 pub use ship_config_table::*;
 pub use ship_config_type::ShipConfig;
+pub use ship_owner_table::*;
+pub use ship_owner_type::ShipOwner;
 pub use ship_resources_table::*;
 pub use ship_resources_type::ShipResources;
 pub use ship_system_table::*;
 pub use ship_system_type::ShipSystem;
+pub use shuttle_table::*;
+pub use shuttle_type::Shuttle;
+pub use simulation_warning_table::*;
+pub use simulation_warning_type::SimulationWarning;
+pub use skill_upkeep_table::*;
+pub use skill_upkeep_type::SkillUpkeep;
 pub use skills_table::*;
 pub use skills_type::Skills;
+pub use snapshot_config_table::*;
+pub use snapshot_config_type::SnapshotConfig;
+pub use spotlight_table::*;
+pub use spotlight_type::Spotlight;
+pub use standing_order_table::*;
+pub use standing_order_type::StandingOrder;
+pub use state_export_table::*;
+pub use state_export_type::StateExport;
+pub use stock_trend_table::*;
+pub use stock_trend_type::StockTrend;
+pub use stream_action_table::*;
+pub use stream_action_type::StreamAction;
+pub use stream_config_table::*;
+pub use stream_config_type::StreamConfig;
+pub use stream_name_newborn_reducer::{
+    set_flags_for_stream_name_newborn, stream_name_newborn, StreamNameNewbornCallbackId,
+};
+pub use stream_spotlight_reducer::{
+    set_flags_for_stream_spotlight, stream_spotlight, StreamSpotlightCallbackId,
+};
+pub use stream_vote_reducer::{set_flags_for_stream_vote, stream_vote, StreamVoteCallbackId};
 pub use subsystem_table::*;
 pub use subsystem_type::Subsystem;
+pub use suppress_mutiny_reducer::{
+    set_flags_for_suppress_mutiny, suppress_mutiny, SuppressMutinyCallbackId,
+};
+pub use surgery_table::*;
+pub use surgery_type::Surgery;
 pub use system_component_table::*;
 pub use system_component_type::SystemComponent;
+pub use teacher_table::*;
+pub use teacher_type::Teacher;
 pub use tick_reducer::{set_flags_for_tick, tick, TickCallbackId};
 pub use toggle_door_reducer::{set_flags_for_toggle_door, toggle_door, ToggleDoorCallbackId};
 pub use vertical_shaft_table::*;
 pub use vertical_shaft_type::VerticalShaft;
+pub use voyage_report_table::*;
+pub use voyage_report_type::VoyageReport;
+pub use voyage_state_table::*;
+pub use voyage_state_type::VoyageState;
+pub use wallet_table::*;
+pub use wallet_type::Wallet;
+pub use work_order_table::*;
+pub use work_order_type::WorkOrder;
+pub use zone_config_table::*;
+pub use zone_config_type::ZoneConfig;
 
 #[derive(Clone, PartialEq, Debug)]
 
@@ -168,13 +622,69 @@ pub use vertical_shaft_type::VerticalShaft;
 /// to indicate which reducer caused the event.
 
 pub enum Reducer {
+    CancelOrder {
+        order_id: u64,
+    },
     ClientConnected,
     ClientDisconnected,
+    ConfigureAutosnapshot {
+        interval_hours: f64,
+        keep: u32,
+        sinks: u8,
+    },
+    ConfigureStream {
+        operators: Vec<__sdk::Identity>,
+        allowed_votes: u32,
+        cooldown_seconds: Vec<u32>,
+    },
+    ExplainActivity {
+        person_id: u64,
+    },
+    ExportFrame {
+        delta: bool,
+    },
+    ExportState,
+    GeneratePendingDeck {
+        job: DeckGenerationJob,
+    },
+    GenerateVoyageReport,
+    GiveItem {
+        item_id: u64,
+        target_person_id: u64,
+    },
+    Init,
+    InitDailyShip,
+    InitScenario {
+        scenario_id: String,
+    },
     InitShip {
         name: String,
         deck_count: u32,
         crew_count: u32,
         passenger_count: u32,
+        corridor_topology: u8,
+        hull_taper: u8,
+    },
+    InitWarmShip {
+        name: String,
+        deck_count: u32,
+        crew_count: u32,
+        passenger_count: u32,
+        corridor_topology: u8,
+        hull_taper: u8,
+        warm_start_days: f32,
+    },
+    IssueOrder {
+        kind: u8,
+        department: u8,
+        deck: i32,
+        hours: f64,
+    },
+    LaunchShuttle {
+        shuttle_id: u64,
+    },
+    NegotiateMutiny {
+        mutiny_id: u64,
     },
     PlayerAction {
         action: u8,
@@ -197,12 +707,77 @@ pub enum Reducer {
     PlayerUseLadder {
         direction: i32,
     },
+    PlayerViewScreen {
+        screen: u8,
+    },
+    RecallShuttle {
+        shuttle_id: u64,
+    },
+    RecoverFromSnapshot {
+        data: String,
+    },
+    SetBalanceConfig {
+        child_hunger: f32,
+        infant_fatigue: f32,
+        elder_fatigue: f32,
+        extraversion_social: f32,
+        neuroticism_comfort: f32,
+        unfit_fatigue: f32,
+    },
+    SetCultureWeight {
+        culture: u8,
+        weight: f32,
+    },
+    SetDeckNickname {
+        deck: i32,
+        name: String,
+    },
+    SetDoorState {
+        door_id: u64,
+        state: u8,
+    },
+    SetFeatureFlags {
+        disease: bool,
+        generational: bool,
+        crime: bool,
+        politics: bool,
+        hardcore_atmosphere: bool,
+    },
+    SetHistoryRetention {
+        retention_days: f64,
+    },
     SetPaused {
         paused: bool,
     },
+    SetRoomNickname {
+        room_id: u32,
+        name: String,
+    },
     SetTimeScale {
         scale: f32,
     },
+    SetZoneDecks {
+        zone: u8,
+        first_deck: u32,
+        end_deck: u32,
+    },
+    ShiftLighting {
+        deck: i32,
+        hours: f32,
+    },
+    StreamNameNewborn {
+        name: String,
+    },
+    StreamSpotlight {
+        person_id: u64,
+        caption: String,
+    },
+    StreamVote {
+        vote: u8,
+    },
+    SuppressMutiny {
+        mutiny_id: u64,
+    },
     Tick {
         delta_seconds: f32,
     },
@@ -218,17 +793,49 @@ impl __sdk::InModule for Reducer {
 impl __sdk::Reducer for Reducer {
     fn reducer_name(&self) -> &'static str {
         match self {
+            Reducer::CancelOrder { .. } => "cancel_order",
             Reducer::ClientConnected => "client_connected",
             Reducer::ClientDisconnected => "client_disconnected",
+            Reducer::ConfigureAutosnapshot { .. } => "configure_autosnapshot",
+            Reducer::ConfigureStream { .. } => "configure_stream",
+            Reducer::ExplainActivity { .. } => "explain_activity",
+            Reducer::ExportFrame { .. } => "export_frame",
+            Reducer::ExportState => "export_state",
+            Reducer::GeneratePendingDeck { .. } => "generate_pending_deck",
+            Reducer::GenerateVoyageReport => "generate_voyage_report",
+            Reducer::GiveItem { .. } => "give_item",
+            Reducer::Init => "init",
+            Reducer::InitDailyShip => "init_daily_ship",
+            Reducer::InitScenario { .. } => "init_scenario",
             Reducer::InitShip { .. } => "init_ship",
+            Reducer::InitWarmShip { .. } => "init_warm_ship",
+            Reducer::IssueOrder { .. } => "issue_order",
+            Reducer::LaunchShuttle { .. } => "launch_shuttle",
+            Reducer::NegotiateMutiny { .. } => "negotiate_mutiny",
             Reducer::PlayerAction { .. } => "player_action",
             Reducer::PlayerInteract { .. } => "player_interact",
             Reducer::PlayerJoin { .. } => "player_join",
             Reducer::PlayerMove { .. } => "player_move",
             Reducer::PlayerUseElevator { .. } => "player_use_elevator",
             Reducer::PlayerUseLadder { .. } => "player_use_ladder",
+            Reducer::PlayerViewScreen { .. } => "player_view_screen",
+            Reducer::RecallShuttle { .. } => "recall_shuttle",
+            Reducer::RecoverFromSnapshot { .. } => "recover_from_snapshot",
+            Reducer::SetBalanceConfig { .. } => "set_balance_config",
+            Reducer::SetCultureWeight { .. } => "set_culture_weight",
+            Reducer::SetDeckNickname { .. } => "set_deck_nickname",
+            Reducer::SetDoorState { .. } => "set_door_state",
+            Reducer::SetFeatureFlags { .. } => "set_feature_flags",
+            Reducer::SetHistoryRetention { .. } => "set_history_retention",
             Reducer::SetPaused { .. } => "set_paused",
+            Reducer::SetRoomNickname { .. } => "set_room_nickname",
             Reducer::SetTimeScale { .. } => "set_time_scale",
+            Reducer::SetZoneDecks { .. } => "set_zone_decks",
+            Reducer::ShiftLighting { .. } => "shift_lighting",
+            Reducer::StreamNameNewborn { .. } => "stream_name_newborn",
+            Reducer::StreamSpotlight { .. } => "stream_spotlight",
+            Reducer::StreamVote { .. } => "stream_vote",
+            Reducer::SuppressMutiny { .. } => "suppress_mutiny",
             Reducer::Tick { .. } => "tick",
             Reducer::ToggleDoor { .. } => "toggle_door",
             _ => unreachable!(),
@@ -239,6 +846,13 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
     type Error = __sdk::Error;
     fn try_from(value: __ws::ReducerCallInfo<__ws::BsatnFormat>) -> __sdk::Result<Self> {
         match &value.reducer_name[..] {
+            "cancel_order" => Ok(
+                __sdk::parse_reducer_args::<cancel_order_reducer::CancelOrderArgs>(
+                    "cancel_order",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "client_connected" => Ok(__sdk::parse_reducer_args::<
                 client_connected_reducer::ClientConnectedArgs,
             >("client_connected", &value.args)?
@@ -247,6 +861,58 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 client_disconnected_reducer::ClientDisconnectedArgs,
             >("client_disconnected", &value.args)?
             .into()),
+            "configure_autosnapshot" => Ok(__sdk::parse_reducer_args::<
+                configure_autosnapshot_reducer::ConfigureAutosnapshotArgs,
+            >("configure_autosnapshot", &value.args)?
+            .into()),
+            "configure_stream" => Ok(__sdk::parse_reducer_args::<
+                configure_stream_reducer::ConfigureStreamArgs,
+            >("configure_stream", &value.args)?
+            .into()),
+            "explain_activity" => Ok(__sdk::parse_reducer_args::<
+                explain_activity_reducer::ExplainActivityArgs,
+            >("explain_activity", &value.args)?
+            .into()),
+            "export_frame" => Ok(
+                __sdk::parse_reducer_args::<export_frame_reducer::ExportFrameArgs>(
+                    "export_frame",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "export_state" => Ok(
+                __sdk::parse_reducer_args::<export_state_reducer::ExportStateArgs>(
+                    "export_state",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "generate_pending_deck" => Ok(__sdk::parse_reducer_args::<
+                generate_pending_deck_reducer::GeneratePendingDeckArgs,
+            >("generate_pending_deck", &value.args)?
+            .into()),
+            "generate_voyage_report" => Ok(__sdk::parse_reducer_args::<
+                generate_voyage_report_reducer::GenerateVoyageReportArgs,
+            >("generate_voyage_report", &value.args)?
+            .into()),
+            "give_item" => Ok(
+                __sdk::parse_reducer_args::<give_item_reducer::GiveItemArgs>(
+                    "give_item",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "init" => Ok(
+                __sdk::parse_reducer_args::<init_reducer::InitArgs>("init", &value.args)?.into(),
+            ),
+            "init_daily_ship" => Ok(__sdk::parse_reducer_args::<
+                init_daily_ship_reducer::InitDailyShipArgs,
+            >("init_daily_ship", &value.args)?
+            .into()),
+            "init_scenario" => Ok(__sdk::parse_reducer_args::<
+                init_scenario_reducer::InitScenarioArgs,
+            >("init_scenario", &value.args)?
+            .into()),
             "init_ship" => Ok(
                 __sdk::parse_reducer_args::<init_ship_reducer::InitShipArgs>(
                     "init_ship",
@@ -254,6 +920,25 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 )?
                 .into(),
             ),
+            "init_warm_ship" => Ok(__sdk::parse_reducer_args::<
+                init_warm_ship_reducer::InitWarmShipArgs,
+            >("init_warm_ship", &value.args)?
+            .into()),
+            "issue_order" => Ok(
+                __sdk::parse_reducer_args::<issue_order_reducer::IssueOrderArgs>(
+                    "issue_order",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "launch_shuttle" => Ok(__sdk::parse_reducer_args::<
+                launch_shuttle_reducer::LaunchShuttleArgs,
+            >("launch_shuttle", &value.args)?
+            .into()),
+            "negotiate_mutiny" => Ok(__sdk::parse_reducer_args::<
+                negotiate_mutiny_reducer::NegotiateMutinyArgs,
+            >("negotiate_mutiny", &value.args)?
+            .into()),
             "player_action" => Ok(__sdk::parse_reducer_args::<
                 player_action_reducer::PlayerActionArgs,
             >("player_action", &value.args)?
@@ -284,6 +969,42 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 player_use_ladder_reducer::PlayerUseLadderArgs,
             >("player_use_ladder", &value.args)?
             .into()),
+            "player_view_screen" => Ok(__sdk::parse_reducer_args::<
+                player_view_screen_reducer::PlayerViewScreenArgs,
+            >("player_view_screen", &value.args)?
+            .into()),
+            "recall_shuttle" => Ok(__sdk::parse_reducer_args::<
+                recall_shuttle_reducer::RecallShuttleArgs,
+            >("recall_shuttle", &value.args)?
+            .into()),
+            "recover_from_snapshot" => Ok(__sdk::parse_reducer_args::<
+                recover_from_snapshot_reducer::RecoverFromSnapshotArgs,
+            >("recover_from_snapshot", &value.args)?
+            .into()),
+            "set_balance_config" => Ok(__sdk::parse_reducer_args::<
+                set_balance_config_reducer::SetBalanceConfigArgs,
+            >("set_balance_config", &value.args)?
+            .into()),
+            "set_culture_weight" => Ok(__sdk::parse_reducer_args::<
+                set_culture_weight_reducer::SetCultureWeightArgs,
+            >("set_culture_weight", &value.args)?
+            .into()),
+            "set_deck_nickname" => Ok(__sdk::parse_reducer_args::<
+                set_deck_nickname_reducer::SetDeckNicknameArgs,
+            >("set_deck_nickname", &value.args)?
+            .into()),
+            "set_door_state" => Ok(__sdk::parse_reducer_args::<
+                set_door_state_reducer::SetDoorStateArgs,
+            >("set_door_state", &value.args)?
+            .into()),
+            "set_feature_flags" => Ok(__sdk::parse_reducer_args::<
+                set_feature_flags_reducer::SetFeatureFlagsArgs,
+            >("set_feature_flags", &value.args)?
+            .into()),
+            "set_history_retention" => Ok(__sdk::parse_reducer_args::<
+                set_history_retention_reducer::SetHistoryRetentionArgs,
+            >("set_history_retention", &value.args)?
+            .into()),
             "set_paused" => Ok(
                 __sdk::parse_reducer_args::<set_paused_reducer::SetPausedArgs>(
                     "set_paused",
@@ -291,10 +1012,41 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 )?
                 .into(),
             ),
+            "set_room_nickname" => Ok(__sdk::parse_reducer_args::<
+                set_room_nickname_reducer::SetRoomNicknameArgs,
+            >("set_room_nickname", &value.args)?
+            .into()),
             "set_time_scale" => Ok(__sdk::parse_reducer_args::<
                 set_time_scale_reducer::SetTimeScaleArgs,
             >("set_time_scale", &value.args)?
             .into()),
+            "set_zone_decks" => Ok(__sdk::parse_reducer_args::<
+                set_zone_decks_reducer::SetZoneDecksArgs,
+            >("set_zone_decks", &value.args)?
+            .into()),
+            "shift_lighting" => Ok(__sdk::parse_reducer_args::<
+                shift_lighting_reducer::ShiftLightingArgs,
+            >("shift_lighting", &value.args)?
+            .into()),
+            "stream_name_newborn" => Ok(__sdk::parse_reducer_args::<
+                stream_name_newborn_reducer::StreamNameNewbornArgs,
+            >("stream_name_newborn", &value.args)?
+            .into()),
+            "stream_spotlight" => Ok(__sdk::parse_reducer_args::<
+                stream_spotlight_reducer::StreamSpotlightArgs,
+            >("stream_spotlight", &value.args)?
+            .into()),
+            "stream_vote" => Ok(
+                __sdk::parse_reducer_args::<stream_vote_reducer::StreamVoteArgs>(
+                    "stream_vote",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "suppress_mutiny" => Ok(__sdk::parse_reducer_args::<
+                suppress_mutiny_reducer::SuppressMutinyArgs,
+            >("suppress_mutiny", &value.args)?
+            .into()),
             "tick" => Ok(
                 __sdk::parse_reducer_args::<tick_reducer::TickArgs>("tick", &value.args)?.into(),
             ),
@@ -320,33 +1072,118 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
 #[doc(hidden)]
 pub struct DbUpdate {
     activity: __sdk::TableUpdate<Activity>,
+    activity_anchor: __sdk::TableUpdate<ActivityAnchor>,
+    activity_explanation: __sdk::TableUpdate<ActivityExplanation>,
+    advisory: __sdk::TableUpdate<Advisory>,
+    air_duct: __sdk::TableUpdate<AirDuct>,
+    balance_config: __sdk::TableUpdate<BalanceConfig>,
+    candidate: __sdk::TableUpdate<Candidate>,
+    career: __sdk::TableUpdate<Career>,
+    cargo_item: __sdk::TableUpdate<CargoItem>,
+    casualty: __sdk::TableUpdate<Casualty>,
+    command_chain: __sdk::TableUpdate<CommandChain>,
     connected_player: __sdk::TableUpdate<ConnectedPlayer>,
     conversation: __sdk::TableUpdate<Conversation>,
+    coolant_loop: __sdk::TableUpdate<CoolantLoop>,
     corridor: __sdk::TableUpdate<Corridor>,
     crew: __sdk::TableUpdate<Crew>,
-    deck_atmosphere: __sdk::TableUpdate<DeckAtmosphere>,
+    crop: __sdk::TableUpdate<Crop>,
+    culture_mix: __sdk::TableUpdate<CultureMix>,
+    daily_history: __sdk::TableUpdate<DailyHistory>,
+    deck: __sdk::TableUpdate<Deck>,
+    deck_generation_job: __sdk::TableUpdate<DeckGenerationJob>,
+    deck_nickname: __sdk::TableUpdate<DeckNickname>,
+    detention: __sdk::TableUpdate<Detention>,
     door: __sdk::TableUpdate<Door>,
+    drinking: __sdk::TableUpdate<Drinking>,
+    election: __sdk::TableUpdate<Election>,
+    emotion: __sdk::TableUpdate<Emotion>,
+    eva_mission: __sdk::TableUpdate<EvaMission>,
+    evacuation_route: __sdk::TableUpdate<EvacuationRoute>,
     event: __sdk::TableUpdate<Event>,
+    faction: __sdk::TableUpdate<Faction>,
+    faction_membership: __sdk::TableUpdate<FactionMembership>,
+    family: __sdk::TableUpdate<Family>,
+    family_member: __sdk::TableUpdate<FamilyMember>,
+    feature_flags: __sdk::TableUpdate<FeatureFlags>,
+    frame_export: __sdk::TableUpdate<FrameExport>,
+    funeral: __sdk::TableUpdate<Funeral>,
+    furniture: __sdk::TableUpdate<Furniture>,
+    garden: __sdk::TableUpdate<Garden>,
+    generation_stats: __sdk::TableUpdate<GenerationStats>,
     graph_edge: __sdk::TableUpdate<GraphEdge>,
     graph_node: __sdk::TableUpdate<GraphNode>,
+    grief: __sdk::TableUpdate<Grief>,
+    hibernation: __sdk::TableUpdate<Hibernation>,
+    history_config: __sdk::TableUpdate<HistoryConfig>,
+    hull_damage: __sdk::TableUpdate<HullDamage>,
+    hull_hatch: __sdk::TableUpdate<HullHatch>,
     in_conversation: __sdk::TableUpdate<InConversation>,
+    incident: __sdk::TableUpdate<Incident>,
     infra_edge: __sdk::TableUpdate<InfraEdge>,
-    maintenance_task: __sdk::TableUpdate<MaintenanceTask>,
+    inventory_item: __sdk::TableUpdate<InventoryItem>,
+    lighting: __sdk::TableUpdate<Lighting>,
+    meal_buffer: __sdk::TableUpdate<MealBuffer>,
+    mental_health: __sdk::TableUpdate<MentalHealth>,
+    milestone: __sdk::TableUpdate<Milestone>,
+    milestone_tracker: __sdk::TableUpdate<MilestoneTracker>,
+    morale_summary: __sdk::TableUpdate<MoraleSummary>,
     movement: __sdk::TableUpdate<Movement>,
+    mutiny: __sdk::TableUpdate<Mutiny>,
     needs: __sdk::TableUpdate<Needs>,
+    newborn_name: __sdk::TableUpdate<NewbornName>,
+    order_assignment: __sdk::TableUpdate<OrderAssignment>,
+    part_stock: __sdk::TableUpdate<PartStock>,
     passenger: __sdk::TableUpdate<Passenger>,
+    patient: __sdk::TableUpdate<Patient>,
     person: __sdk::TableUpdate<Person>,
+    person_timeline: __sdk::TableUpdate<PersonTimeline>,
+    person_trait: __sdk::TableUpdate<PersonTrait>,
     personality: __sdk::TableUpdate<Personality>,
+    player_objective: __sdk::TableUpdate<PlayerObjective>,
+    policy: __sdk::TableUpdate<Policy>,
+    population_sample: __sdk::TableUpdate<PopulationSample>,
     position: __sdk::TableUpdate<Position>,
+    power_grid: __sdk::TableUpdate<PowerGrid>,
+    pregnancy: __sdk::TableUpdate<Pregnancy>,
+    quarantine: __sdk::TableUpdate<Quarantine>,
+    radiation_dose: __sdk::TableUpdate<RadiationDose>,
     relationship: __sdk::TableUpdate<Relationship>,
+    robot: __sdk::TableUpdate<Robot>,
     room: __sdk::TableUpdate<Room>,
+    room_air: __sdk::TableUpdate<RoomAir>,
+    room_ambience: __sdk::TableUpdate<RoomAmbience>,
+    room_effect: __sdk::TableUpdate<RoomEffect>,
+    room_nickname: __sdk::TableUpdate<RoomNickname>,
+    room_power: __sdk::TableUpdate<RoomPower>,
+    room_water: __sdk::TableUpdate<RoomWater>,
+    schooling: __sdk::TableUpdate<Schooling>,
+    sensor_contact: __sdk::TableUpdate<SensorContact>,
     ship_config: __sdk::TableUpdate<ShipConfig>,
+    ship_owner: __sdk::TableUpdate<ShipOwner>,
     ship_resources: __sdk::TableUpdate<ShipResources>,
     ship_system: __sdk::TableUpdate<ShipSystem>,
+    shuttle: __sdk::TableUpdate<Shuttle>,
+    simulation_warning: __sdk::TableUpdate<SimulationWarning>,
+    skill_upkeep: __sdk::TableUpdate<SkillUpkeep>,
     skills: __sdk::TableUpdate<Skills>,
+    snapshot_config: __sdk::TableUpdate<SnapshotConfig>,
+    spotlight: __sdk::TableUpdate<Spotlight>,
+    standing_order: __sdk::TableUpdate<StandingOrder>,
+    state_export: __sdk::TableUpdate<StateExport>,
+    stock_trend: __sdk::TableUpdate<StockTrend>,
+    stream_action: __sdk::TableUpdate<StreamAction>,
+    stream_config: __sdk::TableUpdate<StreamConfig>,
     subsystem: __sdk::TableUpdate<Subsystem>,
+    surgery: __sdk::TableUpdate<Surgery>,
     system_component: __sdk::TableUpdate<SystemComponent>,
+    teacher: __sdk::TableUpdate<Teacher>,
     vertical_shaft: __sdk::TableUpdate<VerticalShaft>,
+    voyage_report: __sdk::TableUpdate<VoyageReport>,
+    voyage_state: __sdk::TableUpdate<VoyageState>,
+    wallet: __sdk::TableUpdate<Wallet>,
+    work_order: __sdk::TableUpdate<WorkOrder>,
+    zone_config: __sdk::TableUpdate<ZoneConfig>,
 }
 
 impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
@@ -358,87 +1195,342 @@ impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
                 "activity" => db_update
                     .activity
                     .append(activity_table::parse_table_update(table_update)?),
+                "activity_anchor" => db_update
+                    .activity_anchor
+                    .append(activity_anchor_table::parse_table_update(table_update)?),
+                "activity_explanation" => db_update.activity_explanation.append(
+                    activity_explanation_table::parse_table_update(table_update)?,
+                ),
+                "advisory" => db_update
+                    .advisory
+                    .append(advisory_table::parse_table_update(table_update)?),
+                "air_duct" => db_update
+                    .air_duct
+                    .append(air_duct_table::parse_table_update(table_update)?),
+                "balance_config" => db_update
+                    .balance_config
+                    .append(balance_config_table::parse_table_update(table_update)?),
+                "candidate" => db_update
+                    .candidate
+                    .append(candidate_table::parse_table_update(table_update)?),
+                "career" => db_update
+                    .career
+                    .append(career_table::parse_table_update(table_update)?),
+                "cargo_item" => db_update
+                    .cargo_item
+                    .append(cargo_item_table::parse_table_update(table_update)?),
+                "casualty" => db_update
+                    .casualty
+                    .append(casualty_table::parse_table_update(table_update)?),
+                "command_chain" => db_update
+                    .command_chain
+                    .append(command_chain_table::parse_table_update(table_update)?),
                 "connected_player" => db_update
                     .connected_player
                     .append(connected_player_table::parse_table_update(table_update)?),
                 "conversation" => db_update
                     .conversation
                     .append(conversation_table::parse_table_update(table_update)?),
+                "coolant_loop" => db_update
+                    .coolant_loop
+                    .append(coolant_loop_table::parse_table_update(table_update)?),
                 "corridor" => db_update
                     .corridor
                     .append(corridor_table::parse_table_update(table_update)?),
                 "crew" => db_update
                     .crew
                     .append(crew_table::parse_table_update(table_update)?),
-                "deck_atmosphere" => db_update
-                    .deck_atmosphere
-                    .append(deck_atmosphere_table::parse_table_update(table_update)?),
+                "crop" => db_update
+                    .crop
+                    .append(crop_table::parse_table_update(table_update)?),
+                "culture_mix" => db_update
+                    .culture_mix
+                    .append(culture_mix_table::parse_table_update(table_update)?),
+                "daily_history" => db_update
+                    .daily_history
+                    .append(daily_history_table::parse_table_update(table_update)?),
+                "deck" => db_update
+                    .deck
+                    .append(deck_table::parse_table_update(table_update)?),
+                "deck_generation_job" => db_update
+                    .deck_generation_job
+                    .append(deck_generation_job_table::parse_table_update(table_update)?),
+                "deck_nickname" => db_update
+                    .deck_nickname
+                    .append(deck_nickname_table::parse_table_update(table_update)?),
+                "detention" => db_update
+                    .detention
+                    .append(detention_table::parse_table_update(table_update)?),
                 "door" => db_update
                     .door
                     .append(door_table::parse_table_update(table_update)?),
+                "drinking" => db_update
+                    .drinking
+                    .append(drinking_table::parse_table_update(table_update)?),
+                "election" => db_update
+                    .election
+                    .append(election_table::parse_table_update(table_update)?),
+                "emotion" => db_update
+                    .emotion
+                    .append(emotion_table::parse_table_update(table_update)?),
+                "eva_mission" => db_update
+                    .eva_mission
+                    .append(eva_mission_table::parse_table_update(table_update)?),
+                "evacuation_route" => db_update
+                    .evacuation_route
+                    .append(evacuation_route_table::parse_table_update(table_update)?),
                 "event" => db_update
                     .event
                     .append(event_table::parse_table_update(table_update)?),
+                "faction" => db_update
+                    .faction
+                    .append(faction_table::parse_table_update(table_update)?),
+                "faction_membership" => db_update
+                    .faction_membership
+                    .append(faction_membership_table::parse_table_update(table_update)?),
+                "family" => db_update
+                    .family
+                    .append(family_table::parse_table_update(table_update)?),
+                "family_member" => db_update
+                    .family_member
+                    .append(family_member_table::parse_table_update(table_update)?),
+                "feature_flags" => db_update
+                    .feature_flags
+                    .append(feature_flags_table::parse_table_update(table_update)?),
+                "frame_export" => db_update
+                    .frame_export
+                    .append(frame_export_table::parse_table_update(table_update)?),
+                "funeral" => db_update
+                    .funeral
+                    .append(funeral_table::parse_table_update(table_update)?),
+                "furniture" => db_update
+                    .furniture
+                    .append(furniture_table::parse_table_update(table_update)?),
+                "garden" => db_update
+                    .garden
+                    .append(garden_table::parse_table_update(table_update)?),
+                "generation_stats" => db_update
+                    .generation_stats
+                    .append(generation_stats_table::parse_table_update(table_update)?),
                 "graph_edge" => db_update
                     .graph_edge
                     .append(graph_edge_table::parse_table_update(table_update)?),
                 "graph_node" => db_update
                     .graph_node
                     .append(graph_node_table::parse_table_update(table_update)?),
+                "grief" => db_update
+                    .grief
+                    .append(grief_table::parse_table_update(table_update)?),
+                "hibernation" => db_update
+                    .hibernation
+                    .append(hibernation_table::parse_table_update(table_update)?),
+                "history_config" => db_update
+                    .history_config
+                    .append(history_config_table::parse_table_update(table_update)?),
+                "hull_damage" => db_update
+                    .hull_damage
+                    .append(hull_damage_table::parse_table_update(table_update)?),
+                "hull_hatch" => db_update
+                    .hull_hatch
+                    .append(hull_hatch_table::parse_table_update(table_update)?),
                 "in_conversation" => db_update
                     .in_conversation
                     .append(in_conversation_table::parse_table_update(table_update)?),
+                "incident" => db_update
+                    .incident
+                    .append(incident_table::parse_table_update(table_update)?),
                 "infra_edge" => db_update
                     .infra_edge
                     .append(infra_edge_table::parse_table_update(table_update)?),
-                "maintenance_task" => db_update
-                    .maintenance_task
-                    .append(maintenance_task_table::parse_table_update(table_update)?),
+                "inventory_item" => db_update
+                    .inventory_item
+                    .append(inventory_item_table::parse_table_update(table_update)?),
+                "lighting" => db_update
+                    .lighting
+                    .append(lighting_table::parse_table_update(table_update)?),
+                "meal_buffer" => db_update
+                    .meal_buffer
+                    .append(meal_buffer_table::parse_table_update(table_update)?),
+                "mental_health" => db_update
+                    .mental_health
+                    .append(mental_health_table::parse_table_update(table_update)?),
+                "milestone" => db_update
+                    .milestone
+                    .append(milestone_table::parse_table_update(table_update)?),
+                "milestone_tracker" => db_update
+                    .milestone_tracker
+                    .append(milestone_tracker_table::parse_table_update(table_update)?),
+                "morale_summary" => db_update
+                    .morale_summary
+                    .append(morale_summary_table::parse_table_update(table_update)?),
                 "movement" => db_update
                     .movement
                     .append(movement_table::parse_table_update(table_update)?),
+                "mutiny" => db_update
+                    .mutiny
+                    .append(mutiny_table::parse_table_update(table_update)?),
                 "needs" => db_update
                     .needs
                     .append(needs_table::parse_table_update(table_update)?),
+                "newborn_name" => db_update
+                    .newborn_name
+                    .append(newborn_name_table::parse_table_update(table_update)?),
+                "order_assignment" => db_update
+                    .order_assignment
+                    .append(order_assignment_table::parse_table_update(table_update)?),
+                "part_stock" => db_update
+                    .part_stock
+                    .append(part_stock_table::parse_table_update(table_update)?),
                 "passenger" => db_update
                     .passenger
                     .append(passenger_table::parse_table_update(table_update)?),
+                "patient" => db_update
+                    .patient
+                    .append(patient_table::parse_table_update(table_update)?),
                 "person" => db_update
                     .person
                     .append(person_table::parse_table_update(table_update)?),
+                "person_timeline" => db_update
+                    .person_timeline
+                    .append(person_timeline_table::parse_table_update(table_update)?),
+                "person_trait" => db_update
+                    .person_trait
+                    .append(person_trait_table::parse_table_update(table_update)?),
                 "personality" => db_update
                     .personality
                     .append(personality_table::parse_table_update(table_update)?),
+                "player_objective" => db_update
+                    .player_objective
+                    .append(player_objective_table::parse_table_update(table_update)?),
+                "policy" => db_update
+                    .policy
+                    .append(policy_table::parse_table_update(table_update)?),
+                "population_sample" => db_update
+                    .population_sample
+                    .append(population_sample_table::parse_table_update(table_update)?),
                 "position" => db_update
                     .position
                     .append(position_table::parse_table_update(table_update)?),
+                "power_grid" => db_update
+                    .power_grid
+                    .append(power_grid_table::parse_table_update(table_update)?),
+                "pregnancy" => db_update
+                    .pregnancy
+                    .append(pregnancy_table::parse_table_update(table_update)?),
+                "quarantine" => db_update
+                    .quarantine
+                    .append(quarantine_table::parse_table_update(table_update)?),
+                "radiation_dose" => db_update
+                    .radiation_dose
+                    .append(radiation_dose_table::parse_table_update(table_update)?),
                 "relationship" => db_update
                     .relationship
                     .append(relationship_table::parse_table_update(table_update)?),
+                "robot" => db_update
+                    .robot
+                    .append(robot_table::parse_table_update(table_update)?),
                 "room" => db_update
                     .room
                     .append(room_table::parse_table_update(table_update)?),
+                "room_air" => db_update
+                    .room_air
+                    .append(room_air_table::parse_table_update(table_update)?),
+                "room_ambience" => db_update
+                    .room_ambience
+                    .append(room_ambience_table::parse_table_update(table_update)?),
+                "room_effect" => db_update
+                    .room_effect
+                    .append(room_effect_table::parse_table_update(table_update)?),
+                "room_nickname" => db_update
+                    .room_nickname
+                    .append(room_nickname_table::parse_table_update(table_update)?),
+                "room_power" => db_update
+                    .room_power
+                    .append(room_power_table::parse_table_update(table_update)?),
+                "room_water" => db_update
+                    .room_water
+                    .append(room_water_table::parse_table_update(table_update)?),
+                "schooling" => db_update
+                    .schooling
+                    .append(schooling_table::parse_table_update(table_update)?),
+                "sensor_contact" => db_update
+                    .sensor_contact
+                    .append(sensor_contact_table::parse_table_update(table_update)?),
                 "ship_config" => db_update
                     .ship_config
                     .append(ship_config_table::parse_table_update(table_update)?),
+                "ship_owner" => db_update
+                    .ship_owner
+                    .append(ship_owner_table::parse_table_update(table_update)?),
                 "ship_resources" => db_update
                     .ship_resources
                     .append(ship_resources_table::parse_table_update(table_update)?),
                 "ship_system" => db_update
                     .ship_system
                     .append(ship_system_table::parse_table_update(table_update)?),
+                "shuttle" => db_update
+                    .shuttle
+                    .append(shuttle_table::parse_table_update(table_update)?),
+                "simulation_warning" => db_update
+                    .simulation_warning
+                    .append(simulation_warning_table::parse_table_update(table_update)?),
+                "skill_upkeep" => db_update
+                    .skill_upkeep
+                    .append(skill_upkeep_table::parse_table_update(table_update)?),
                 "skills" => db_update
                     .skills
                     .append(skills_table::parse_table_update(table_update)?),
+                "snapshot_config" => db_update
+                    .snapshot_config
+                    .append(snapshot_config_table::parse_table_update(table_update)?),
+                "spotlight" => db_update
+                    .spotlight
+                    .append(spotlight_table::parse_table_update(table_update)?),
+                "standing_order" => db_update
+                    .standing_order
+                    .append(standing_order_table::parse_table_update(table_update)?),
+                "state_export" => db_update
+                    .state_export
+                    .append(state_export_table::parse_table_update(table_update)?),
+                "stock_trend" => db_update
+                    .stock_trend
+                    .append(stock_trend_table::parse_table_update(table_update)?),
+                "stream_action" => db_update
+                    .stream_action
+                    .append(stream_action_table::parse_table_update(table_update)?),
+                "stream_config" => db_update
+                    .stream_config
+                    .append(stream_config_table::parse_table_update(table_update)?),
                 "subsystem" => db_update
                     .subsystem
                     .append(subsystem_table::parse_table_update(table_update)?),
+                "surgery" => db_update
+                    .surgery
+                    .append(surgery_table::parse_table_update(table_update)?),
                 "system_component" => db_update
                     .system_component
                     .append(system_component_table::parse_table_update(table_update)?),
+                "teacher" => db_update
+                    .teacher
+                    .append(teacher_table::parse_table_update(table_update)?),
                 "vertical_shaft" => db_update
                     .vertical_shaft
                     .append(vertical_shaft_table::parse_table_update(table_update)?),
+                "voyage_report" => db_update
+                    .voyage_report
+                    .append(voyage_report_table::parse_table_update(table_update)?),
+                "voyage_state" => db_update
+                    .voyage_state
+                    .append(voyage_state_table::parse_table_update(table_update)?),
+                "wallet" => db_update
+                    .wallet
+                    .append(wallet_table::parse_table_update(table_update)?),
+                "work_order" => db_update
+                    .work_order
+                    .append(work_order_table::parse_table_update(table_update)?),
+                "zone_config" => db_update
+                    .zone_config
+                    .append(zone_config_table::parse_table_update(table_update)?),
 
                 unknown => {
                     return Err(__sdk::InternalError::unknown_name(
@@ -468,87 +1560,354 @@ impl __sdk::DbUpdate for DbUpdate {
         diff.activity = cache
             .apply_diff_to_table::<Activity>("activity", &self.activity)
             .with_updates_by_pk(|row| &row.person_id);
+        diff.activity_anchor = cache
+            .apply_diff_to_table::<ActivityAnchor>("activity_anchor", &self.activity_anchor)
+            .with_updates_by_pk(|row| &row.id);
+        diff.activity_explanation = cache
+            .apply_diff_to_table::<ActivityExplanation>(
+                "activity_explanation",
+                &self.activity_explanation,
+            )
+            .with_updates_by_pk(|row| &row.person_id);
+        diff.advisory = cache
+            .apply_diff_to_table::<Advisory>("advisory", &self.advisory)
+            .with_updates_by_pk(|row| &row.id);
+        diff.air_duct = cache
+            .apply_diff_to_table::<AirDuct>("air_duct", &self.air_duct)
+            .with_updates_by_pk(|row| &row.infra_edge_id);
+        diff.balance_config = cache
+            .apply_diff_to_table::<BalanceConfig>("balance_config", &self.balance_config)
+            .with_updates_by_pk(|row| &row.id);
+        diff.candidate = cache
+            .apply_diff_to_table::<Candidate>("candidate", &self.candidate)
+            .with_updates_by_pk(|row| &row.id);
+        diff.career = cache
+            .apply_diff_to_table::<Career>("career", &self.career)
+            .with_updates_by_pk(|row| &row.person_id);
+        diff.cargo_item = cache
+            .apply_diff_to_table::<CargoItem>("cargo_item", &self.cargo_item)
+            .with_updates_by_pk(|row| &row.id);
+        diff.casualty = cache
+            .apply_diff_to_table::<Casualty>("casualty", &self.casualty)
+            .with_updates_by_pk(|row| &row.person_id);
+        diff.command_chain = cache
+            .apply_diff_to_table::<CommandChain>("command_chain", &self.command_chain)
+            .with_updates_by_pk(|row| &row.person_id);
         diff.connected_player = cache
             .apply_diff_to_table::<ConnectedPlayer>("connected_player", &self.connected_player)
             .with_updates_by_pk(|row| &row.identity);
         diff.conversation = cache
             .apply_diff_to_table::<Conversation>("conversation", &self.conversation)
             .with_updates_by_pk(|row| &row.id);
+        diff.coolant_loop = cache
+            .apply_diff_to_table::<CoolantLoop>("coolant_loop", &self.coolant_loop)
+            .with_updates_by_pk(|row| &row.id);
         diff.corridor = cache
             .apply_diff_to_table::<Corridor>("corridor", &self.corridor)
             .with_updates_by_pk(|row| &row.id);
         diff.crew = cache
             .apply_diff_to_table::<Crew>("crew", &self.crew)
             .with_updates_by_pk(|row| &row.person_id);
-        diff.deck_atmosphere = cache
-            .apply_diff_to_table::<DeckAtmosphere>("deck_atmosphere", &self.deck_atmosphere)
+        diff.crop = cache
+            .apply_diff_to_table::<Crop>("crop", &self.crop)
+            .with_updates_by_pk(|row| &row.id);
+        diff.culture_mix = cache
+            .apply_diff_to_table::<CultureMix>("culture_mix", &self.culture_mix)
+            .with_updates_by_pk(|row| &row.culture);
+        diff.daily_history = cache
+            .apply_diff_to_table::<DailyHistory>("daily_history", &self.daily_history)
+            .with_updates_by_pk(|row| &row.day);
+        diff.deck = cache
+            .apply_diff_to_table::<Deck>("deck", &self.deck)
             .with_updates_by_pk(|row| &row.deck);
+        diff.deck_generation_job = cache
+            .apply_diff_to_table::<DeckGenerationJob>(
+                "deck_generation_job",
+                &self.deck_generation_job,
+            )
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.deck_nickname = cache
+            .apply_diff_to_table::<DeckNickname>("deck_nickname", &self.deck_nickname)
+            .with_updates_by_pk(|row| &row.deck);
+        diff.detention = cache
+            .apply_diff_to_table::<Detention>("detention", &self.detention)
+            .with_updates_by_pk(|row| &row.person_id);
         diff.door = cache
             .apply_diff_to_table::<Door>("door", &self.door)
             .with_updates_by_pk(|row| &row.id);
+        diff.drinking = cache
+            .apply_diff_to_table::<Drinking>("drinking", &self.drinking)
+            .with_updates_by_pk(|row| &row.person_id);
+        diff.election = cache
+            .apply_diff_to_table::<Election>("election", &self.election)
+            .with_updates_by_pk(|row| &row.id);
+        diff.emotion = cache
+            .apply_diff_to_table::<Emotion>("emotion", &self.emotion)
+            .with_updates_by_pk(|row| &row.person_id);
+        diff.eva_mission = cache
+            .apply_diff_to_table::<EvaMission>("eva_mission", &self.eva_mission)
+            .with_updates_by_pk(|row| &row.person_id);
+        diff.evacuation_route = cache
+            .apply_diff_to_table::<EvacuationRoute>("evacuation_route", &self.evacuation_route)
+            .with_updates_by_pk(|row| &row.room_id);
         diff.event = cache
             .apply_diff_to_table::<Event>("event", &self.event)
             .with_updates_by_pk(|row| &row.id);
+        diff.faction = cache
+            .apply_diff_to_table::<Faction>("faction", &self.faction)
+            .with_updates_by_pk(|row| &row.id);
+        diff.faction_membership = cache
+            .apply_diff_to_table::<FactionMembership>(
+                "faction_membership",
+                &self.faction_membership,
+            )
+            .with_updates_by_pk(|row| &row.person_id);
+        diff.family = cache
+            .apply_diff_to_table::<Family>("family", &self.family)
+            .with_updates_by_pk(|row| &row.id);
+        diff.family_member = cache
+            .apply_diff_to_table::<FamilyMember>("family_member", &self.family_member)
+            .with_updates_by_pk(|row| &row.person_id);
+        diff.feature_flags = cache
+            .apply_diff_to_table::<FeatureFlags>("feature_flags", &self.feature_flags)
+            .with_updates_by_pk(|row| &row.id);
+        diff.frame_export = cache
+            .apply_diff_to_table::<FrameExport>("frame_export", &self.frame_export)
+            .with_updates_by_pk(|row| &row.id);
+        diff.funeral = cache
+            .apply_diff_to_table::<Funeral>("funeral", &self.funeral)
+            .with_updates_by_pk(|row| &row.person_id);
+        diff.furniture = cache
+            .apply_diff_to_table::<Furniture>("furniture", &self.furniture)
+            .with_updates_by_pk(|row| &row.id);
+        diff.garden = cache
+            .apply_diff_to_table::<Garden>("garden", &self.garden)
+            .with_updates_by_pk(|row| &row.room_id);
+        diff.generation_stats = cache
+            .apply_diff_to_table::<GenerationStats>("generation_stats", &self.generation_stats)
+            .with_updates_by_pk(|row| &row.id);
         diff.graph_edge = cache
             .apply_diff_to_table::<GraphEdge>("graph_edge", &self.graph_edge)
             .with_updates_by_pk(|row| &row.id);
         diff.graph_node = cache
             .apply_diff_to_table::<GraphNode>("graph_node", &self.graph_node)
             .with_updates_by_pk(|row| &row.id);
+        diff.grief = cache
+            .apply_diff_to_table::<Grief>("grief", &self.grief)
+            .with_updates_by_pk(|row| &row.id);
+        diff.hibernation = cache
+            .apply_diff_to_table::<Hibernation>("hibernation", &self.hibernation)
+            .with_updates_by_pk(|row| &row.person_id);
+        diff.history_config = cache
+            .apply_diff_to_table::<HistoryConfig>("history_config", &self.history_config)
+            .with_updates_by_pk(|row| &row.id);
+        diff.hull_damage = cache
+            .apply_diff_to_table::<HullDamage>("hull_damage", &self.hull_damage)
+            .with_updates_by_pk(|row| &row.id);
+        diff.hull_hatch = cache
+            .apply_diff_to_table::<HullHatch>("hull_hatch", &self.hull_hatch)
+            .with_updates_by_pk(|row| &row.id);
         diff.in_conversation = cache
             .apply_diff_to_table::<InConversation>("in_conversation", &self.in_conversation)
             .with_updates_by_pk(|row| &row.person_id);
+        diff.incident = cache
+            .apply_diff_to_table::<Incident>("incident", &self.incident)
+            .with_updates_by_pk(|row| &row.id);
         diff.infra_edge = cache
             .apply_diff_to_table::<InfraEdge>("infra_edge", &self.infra_edge)
             .with_updates_by_pk(|row| &row.id);
-        diff.maintenance_task = cache
-            .apply_diff_to_table::<MaintenanceTask>("maintenance_task", &self.maintenance_task)
+        diff.inventory_item = cache
+            .apply_diff_to_table::<InventoryItem>("inventory_item", &self.inventory_item)
+            .with_updates_by_pk(|row| &row.id);
+        diff.lighting = cache
+            .apply_diff_to_table::<Lighting>("lighting", &self.lighting)
+            .with_updates_by_pk(|row| &row.deck);
+        diff.meal_buffer = cache
+            .apply_diff_to_table::<MealBuffer>("meal_buffer", &self.meal_buffer)
+            .with_updates_by_pk(|row| &row.galley_room_id);
+        diff.mental_health = cache
+            .apply_diff_to_table::<MentalHealth>("mental_health", &self.mental_health)
+            .with_updates_by_pk(|row| &row.person_id);
+        diff.milestone = cache
+            .apply_diff_to_table::<Milestone>("milestone", &self.milestone)
+            .with_updates_by_pk(|row| &row.id);
+        diff.milestone_tracker = cache
+            .apply_diff_to_table::<MilestoneTracker>("milestone_tracker", &self.milestone_tracker)
             .with_updates_by_pk(|row| &row.id);
+        diff.morale_summary = cache
+            .apply_diff_to_table::<MoraleSummary>("morale_summary", &self.morale_summary)
+            .with_updates_by_pk(|row| &row.scope);
         diff.movement = cache
             .apply_diff_to_table::<Movement>("movement", &self.movement)
             .with_updates_by_pk(|row| &row.person_id);
+        diff.mutiny = cache
+            .apply_diff_to_table::<Mutiny>("mutiny", &self.mutiny)
+            .with_updates_by_pk(|row| &row.id);
         diff.needs = cache
             .apply_diff_to_table::<Needs>("needs", &self.needs)
             .with_updates_by_pk(|row| &row.person_id);
+        diff.newborn_name = cache
+            .apply_diff_to_table::<NewbornName>("newborn_name", &self.newborn_name)
+            .with_updates_by_pk(|row| &row.id);
+        diff.order_assignment = cache
+            .apply_diff_to_table::<OrderAssignment>("order_assignment", &self.order_assignment)
+            .with_updates_by_pk(|row| &row.person_id);
+        diff.part_stock = cache
+            .apply_diff_to_table::<PartStock>("part_stock", &self.part_stock)
+            .with_updates_by_pk(|row| &row.kind);
         diff.passenger = cache
             .apply_diff_to_table::<Passenger>("passenger", &self.passenger)
             .with_updates_by_pk(|row| &row.person_id);
+        diff.patient = cache
+            .apply_diff_to_table::<Patient>("patient", &self.patient)
+            .with_updates_by_pk(|row| &row.person_id);
         diff.person = cache
             .apply_diff_to_table::<Person>("person", &self.person)
             .with_updates_by_pk(|row| &row.id);
+        diff.person_timeline = cache
+            .apply_diff_to_table::<PersonTimeline>("person_timeline", &self.person_timeline)
+            .with_updates_by_pk(|row| &row.person_id);
+        diff.person_trait = cache
+            .apply_diff_to_table::<PersonTrait>("person_trait", &self.person_trait)
+            .with_updates_by_pk(|row| &row.person_id);
         diff.personality = cache
             .apply_diff_to_table::<Personality>("personality", &self.personality)
             .with_updates_by_pk(|row| &row.person_id);
+        diff.player_objective = cache
+            .apply_diff_to_table::<PlayerObjective>("player_objective", &self.player_objective)
+            .with_updates_by_pk(|row| &row.person_id);
+        diff.policy = cache
+            .apply_diff_to_table::<Policy>("policy", &self.policy)
+            .with_updates_by_pk(|row| &row.id);
+        diff.population_sample = cache
+            .apply_diff_to_table::<PopulationSample>("population_sample", &self.population_sample)
+            .with_updates_by_pk(|row| &row.day);
         diff.position = cache
             .apply_diff_to_table::<Position>("position", &self.position)
             .with_updates_by_pk(|row| &row.person_id);
+        diff.power_grid = cache
+            .apply_diff_to_table::<PowerGrid>("power_grid", &self.power_grid)
+            .with_updates_by_pk(|row| &row.id);
+        diff.pregnancy = cache
+            .apply_diff_to_table::<Pregnancy>("pregnancy", &self.pregnancy)
+            .with_updates_by_pk(|row| &row.person_id);
+        diff.quarantine = cache
+            .apply_diff_to_table::<Quarantine>("quarantine", &self.quarantine)
+            .with_updates_by_pk(|row| &row.person_id);
+        diff.radiation_dose = cache
+            .apply_diff_to_table::<RadiationDose>("radiation_dose", &self.radiation_dose)
+            .with_updates_by_pk(|row| &row.person_id);
         diff.relationship = cache
             .apply_diff_to_table::<Relationship>("relationship", &self.relationship)
             .with_updates_by_pk(|row| &row.id);
+        diff.robot = cache
+            .apply_diff_to_table::<Robot>("robot", &self.robot)
+            .with_updates_by_pk(|row| &row.id);
         diff.room = cache
             .apply_diff_to_table::<Room>("room", &self.room)
             .with_updates_by_pk(|row| &row.id);
+        diff.room_air = cache
+            .apply_diff_to_table::<RoomAir>("room_air", &self.room_air)
+            .with_updates_by_pk(|row| &row.room_id);
+        diff.room_ambience = cache
+            .apply_diff_to_table::<RoomAmbience>("room_ambience", &self.room_ambience)
+            .with_updates_by_pk(|row| &row.room_id);
+        diff.room_effect = cache
+            .apply_diff_to_table::<RoomEffect>("room_effect", &self.room_effect)
+            .with_updates_by_pk(|row| &row.id);
+        diff.room_nickname = cache
+            .apply_diff_to_table::<RoomNickname>("room_nickname", &self.room_nickname)
+            .with_updates_by_pk(|row| &row.room_id);
+        diff.room_power = cache
+            .apply_diff_to_table::<RoomPower>("room_power", &self.room_power)
+            .with_updates_by_pk(|row| &row.room_id);
+        diff.room_water = cache
+            .apply_diff_to_table::<RoomWater>("room_water", &self.room_water)
+            .with_updates_by_pk(|row| &row.room_id);
+        diff.schooling = cache
+            .apply_diff_to_table::<Schooling>("schooling", &self.schooling)
+            .with_updates_by_pk(|row| &row.person_id);
+        diff.sensor_contact = cache
+            .apply_diff_to_table::<SensorContact>("sensor_contact", &self.sensor_contact)
+            .with_updates_by_pk(|row| &row.id);
         diff.ship_config = cache
             .apply_diff_to_table::<ShipConfig>("ship_config", &self.ship_config)
             .with_updates_by_pk(|row| &row.id);
+        diff.ship_owner = cache
+            .apply_diff_to_table::<ShipOwner>("ship_owner", &self.ship_owner)
+            .with_updates_by_pk(|row| &row.id);
         diff.ship_resources = cache
             .apply_diff_to_table::<ShipResources>("ship_resources", &self.ship_resources)
             .with_updates_by_pk(|row| &row.id);
         diff.ship_system = cache
             .apply_diff_to_table::<ShipSystem>("ship_system", &self.ship_system)
             .with_updates_by_pk(|row| &row.id);
+        diff.shuttle = cache
+            .apply_diff_to_table::<Shuttle>("shuttle", &self.shuttle)
+            .with_updates_by_pk(|row| &row.id);
+        diff.simulation_warning = cache
+            .apply_diff_to_table::<SimulationWarning>(
+                "simulation_warning",
+                &self.simulation_warning,
+            )
+            .with_updates_by_pk(|row| &row.id);
+        diff.skill_upkeep = cache
+            .apply_diff_to_table::<SkillUpkeep>("skill_upkeep", &self.skill_upkeep)
+            .with_updates_by_pk(|row| &row.person_id);
         diff.skills = cache
             .apply_diff_to_table::<Skills>("skills", &self.skills)
             .with_updates_by_pk(|row| &row.person_id);
+        diff.snapshot_config = cache
+            .apply_diff_to_table::<SnapshotConfig>("snapshot_config", &self.snapshot_config)
+            .with_updates_by_pk(|row| &row.id);
+        diff.spotlight = cache
+            .apply_diff_to_table::<Spotlight>("spotlight", &self.spotlight)
+            .with_updates_by_pk(|row| &row.id);
+        diff.standing_order = cache
+            .apply_diff_to_table::<StandingOrder>("standing_order", &self.standing_order)
+            .with_updates_by_pk(|row| &row.id);
+        diff.state_export = cache
+            .apply_diff_to_table::<StateExport>("state_export", &self.state_export)
+            .with_updates_by_pk(|row| &row.id);
+        diff.stock_trend = cache
+            .apply_diff_to_table::<StockTrend>("stock_trend", &self.stock_trend)
+            .with_updates_by_pk(|row| &row.stock);
+        diff.stream_action = cache
+            .apply_diff_to_table::<StreamAction>("stream_action", &self.stream_action)
+            .with_updates_by_pk(|row| &row.id);
+        diff.stream_config = cache
+            .apply_diff_to_table::<StreamConfig>("stream_config", &self.stream_config)
+            .with_updates_by_pk(|row| &row.id);
         diff.subsystem = cache
             .apply_diff_to_table::<Subsystem>("subsystem", &self.subsystem)
             .with_updates_by_pk(|row| &row.id);
+        diff.surgery = cache
+            .apply_diff_to_table::<Surgery>("surgery", &self.surgery)
+            .with_updates_by_pk(|row| &row.person_id);
         diff.system_component = cache
             .apply_diff_to_table::<SystemComponent>("system_component", &self.system_component)
             .with_updates_by_pk(|row| &row.id);
+        diff.teacher = cache
+            .apply_diff_to_table::<Teacher>("teacher", &self.teacher)
+            .with_updates_by_pk(|row| &row.person_id);
         diff.vertical_shaft = cache
             .apply_diff_to_table::<VerticalShaft>("vertical_shaft", &self.vertical_shaft)
             .with_updates_by_pk(|row| &row.id);
+        diff.voyage_report = cache
+            .apply_diff_to_table::<VoyageReport>("voyage_report", &self.voyage_report)
+            .with_updates_by_pk(|row| &row.id);
+        diff.voyage_state = cache
+            .apply_diff_to_table::<VoyageState>("voyage_state", &self.voyage_state)
+            .with_updates_by_pk(|row| &row.id);
+        diff.wallet = cache
+            .apply_diff_to_table::<Wallet>("wallet", &self.wallet)
+            .with_updates_by_pk(|row| &row.person_id);
+        diff.work_order = cache
+            .apply_diff_to_table::<WorkOrder>("work_order", &self.work_order)
+            .with_updates_by_pk(|row| &row.id);
+        diff.zone_config = cache
+            .apply_diff_to_table::<ZoneConfig>("zone_config", &self.zone_config)
+            .with_updates_by_pk(|row| &row.zone);
 
         diff
     }
@@ -559,33 +1918,118 @@ impl __sdk::DbUpdate for DbUpdate {
 #[doc(hidden)]
 pub struct AppliedDiff<'r> {
     activity: __sdk::TableAppliedDiff<'r, Activity>,
+    activity_anchor: __sdk::TableAppliedDiff<'r, ActivityAnchor>,
+    activity_explanation: __sdk::TableAppliedDiff<'r, ActivityExplanation>,
+    advisory: __sdk::TableAppliedDiff<'r, Advisory>,
+    air_duct: __sdk::TableAppliedDiff<'r, AirDuct>,
+    balance_config: __sdk::TableAppliedDiff<'r, BalanceConfig>,
+    candidate: __sdk::TableAppliedDiff<'r, Candidate>,
+    career: __sdk::TableAppliedDiff<'r, Career>,
+    cargo_item: __sdk::TableAppliedDiff<'r, CargoItem>,
+    casualty: __sdk::TableAppliedDiff<'r, Casualty>,
+    command_chain: __sdk::TableAppliedDiff<'r, CommandChain>,
     connected_player: __sdk::TableAppliedDiff<'r, ConnectedPlayer>,
     conversation: __sdk::TableAppliedDiff<'r, Conversation>,
+    coolant_loop: __sdk::TableAppliedDiff<'r, CoolantLoop>,
     corridor: __sdk::TableAppliedDiff<'r, Corridor>,
     crew: __sdk::TableAppliedDiff<'r, Crew>,
-    deck_atmosphere: __sdk::TableAppliedDiff<'r, DeckAtmosphere>,
+    crop: __sdk::TableAppliedDiff<'r, Crop>,
+    culture_mix: __sdk::TableAppliedDiff<'r, CultureMix>,
+    daily_history: __sdk::TableAppliedDiff<'r, DailyHistory>,
+    deck: __sdk::TableAppliedDiff<'r, Deck>,
+    deck_generation_job: __sdk::TableAppliedDiff<'r, DeckGenerationJob>,
+    deck_nickname: __sdk::TableAppliedDiff<'r, DeckNickname>,
+    detention: __sdk::TableAppliedDiff<'r, Detention>,
     door: __sdk::TableAppliedDiff<'r, Door>,
+    drinking: __sdk::TableAppliedDiff<'r, Drinking>,
+    election: __sdk::TableAppliedDiff<'r, Election>,
+    emotion: __sdk::TableAppliedDiff<'r, Emotion>,
+    eva_mission: __sdk::TableAppliedDiff<'r, EvaMission>,
+    evacuation_route: __sdk::TableAppliedDiff<'r, EvacuationRoute>,
     event: __sdk::TableAppliedDiff<'r, Event>,
+    faction: __sdk::TableAppliedDiff<'r, Faction>,
+    faction_membership: __sdk::TableAppliedDiff<'r, FactionMembership>,
+    family: __sdk::TableAppliedDiff<'r, Family>,
+    family_member: __sdk::TableAppliedDiff<'r, FamilyMember>,
+    feature_flags: __sdk::TableAppliedDiff<'r, FeatureFlags>,
+    frame_export: __sdk::TableAppliedDiff<'r, FrameExport>,
+    funeral: __sdk::TableAppliedDiff<'r, Funeral>,
+    furniture: __sdk::TableAppliedDiff<'r, Furniture>,
+    garden: __sdk::TableAppliedDiff<'r, Garden>,
+    generation_stats: __sdk::TableAppliedDiff<'r, GenerationStats>,
     graph_edge: __sdk::TableAppliedDiff<'r, GraphEdge>,
     graph_node: __sdk::TableAppliedDiff<'r, GraphNode>,
+    grief: __sdk::TableAppliedDiff<'r, Grief>,
+    hibernation: __sdk::TableAppliedDiff<'r, Hibernation>,
+    history_config: __sdk::TableAppliedDiff<'r, HistoryConfig>,
+    hull_damage: __sdk::TableAppliedDiff<'r, HullDamage>,
+    hull_hatch: __sdk::TableAppliedDiff<'r, HullHatch>,
     in_conversation: __sdk::TableAppliedDiff<'r, InConversation>,
+    incident: __sdk::TableAppliedDiff<'r, Incident>,
     infra_edge: __sdk::TableAppliedDiff<'r, InfraEdge>,
-    maintenance_task: __sdk::TableAppliedDiff<'r, MaintenanceTask>,
+    inventory_item: __sdk::TableAppliedDiff<'r, InventoryItem>,
+    lighting: __sdk::TableAppliedDiff<'r, Lighting>,
+    meal_buffer: __sdk::TableAppliedDiff<'r, MealBuffer>,
+    mental_health: __sdk::TableAppliedDiff<'r, MentalHealth>,
+    milestone: __sdk::TableAppliedDiff<'r, Milestone>,
+    milestone_tracker: __sdk::TableAppliedDiff<'r, MilestoneTracker>,
+    morale_summary: __sdk::TableAppliedDiff<'r, MoraleSummary>,
     movement: __sdk::TableAppliedDiff<'r, Movement>,
+    mutiny: __sdk::TableAppliedDiff<'r, Mutiny>,
     needs: __sdk::TableAppliedDiff<'r, Needs>,
+    newborn_name: __sdk::TableAppliedDiff<'r, NewbornName>,
+    order_assignment: __sdk::TableAppliedDiff<'r, OrderAssignment>,
+    part_stock: __sdk::TableAppliedDiff<'r, PartStock>,
     passenger: __sdk::TableAppliedDiff<'r, Passenger>,
+    patient: __sdk::TableAppliedDiff<'r, Patient>,
     person: __sdk::TableAppliedDiff<'r, Person>,
+    person_timeline: __sdk::TableAppliedDiff<'r, PersonTimeline>,
+    person_trait: __sdk::TableAppliedDiff<'r, PersonTrait>,
     personality: __sdk::TableAppliedDiff<'r, Personality>,
+    player_objective: __sdk::TableAppliedDiff<'r, PlayerObjective>,
+    policy: __sdk::TableAppliedDiff<'r, Policy>,
+    population_sample: __sdk::TableAppliedDiff<'r, PopulationSample>,
     position: __sdk::TableAppliedDiff<'r, Position>,
+    power_grid: __sdk::TableAppliedDiff<'r, PowerGrid>,
+    pregnancy: __sdk::TableAppliedDiff<'r, Pregnancy>,
+    quarantine: __sdk::TableAppliedDiff<'r, Quarantine>,
+    radiation_dose: __sdk::TableAppliedDiff<'r, RadiationDose>,
     relationship: __sdk::TableAppliedDiff<'r, Relationship>,
+    robot: __sdk::TableAppliedDiff<'r, Robot>,
     room: __sdk::TableAppliedDiff<'r, Room>,
+    room_air: __sdk::TableAppliedDiff<'r, RoomAir>,
+    room_ambience: __sdk::TableAppliedDiff<'r, RoomAmbience>,
+    room_effect: __sdk::TableAppliedDiff<'r, RoomEffect>,
+    room_nickname: __sdk::TableAppliedDiff<'r, RoomNickname>,
+    room_power: __sdk::TableAppliedDiff<'r, RoomPower>,
+    room_water: __sdk::TableAppliedDiff<'r, RoomWater>,
+    schooling: __sdk::TableAppliedDiff<'r, Schooling>,
+    sensor_contact: __sdk::TableAppliedDiff<'r, SensorContact>,
     ship_config: __sdk::TableAppliedDiff<'r, ShipConfig>,
+    ship_owner: __sdk::TableAppliedDiff<'r, ShipOwner>,
     ship_resources: __sdk::TableAppliedDiff<'r, ShipResources>,
     ship_system: __sdk::TableAppliedDiff<'r, ShipSystem>,
+    shuttle: __sdk::TableAppliedDiff<'r, Shuttle>,
+    simulation_warning: __sdk::TableAppliedDiff<'r, SimulationWarning>,
+    skill_upkeep: __sdk::TableAppliedDiff<'r, SkillUpkeep>,
     skills: __sdk::TableAppliedDiff<'r, Skills>,
+    snapshot_config: __sdk::TableAppliedDiff<'r, SnapshotConfig>,
+    spotlight: __sdk::TableAppliedDiff<'r, Spotlight>,
+    standing_order: __sdk::TableAppliedDiff<'r, StandingOrder>,
+    state_export: __sdk::TableAppliedDiff<'r, StateExport>,
+    stock_trend: __sdk::TableAppliedDiff<'r, StockTrend>,
+    stream_action: __sdk::TableAppliedDiff<'r, StreamAction>,
+    stream_config: __sdk::TableAppliedDiff<'r, StreamConfig>,
     subsystem: __sdk::TableAppliedDiff<'r, Subsystem>,
+    surgery: __sdk::TableAppliedDiff<'r, Surgery>,
     system_component: __sdk::TableAppliedDiff<'r, SystemComponent>,
+    teacher: __sdk::TableAppliedDiff<'r, Teacher>,
     vertical_shaft: __sdk::TableAppliedDiff<'r, VerticalShaft>,
+    voyage_report: __sdk::TableAppliedDiff<'r, VoyageReport>,
+    voyage_state: __sdk::TableAppliedDiff<'r, VoyageState>,
+    wallet: __sdk::TableAppliedDiff<'r, Wallet>,
+    work_order: __sdk::TableAppliedDiff<'r, WorkOrder>,
+    zone_config: __sdk::TableAppliedDiff<'r, ZoneConfig>,
     __unused: std::marker::PhantomData<&'r ()>,
 }
 
@@ -600,6 +2044,32 @@ impl<'r> __sdk::AppliedDiff<'r> for AppliedDiff<'r> {
         callbacks: &mut __sdk::DbCallbacks<RemoteModule>,
     ) {
         callbacks.invoke_table_row_callbacks::<Activity>("activity", &self.activity, event);
+        callbacks.invoke_table_row_callbacks::<ActivityAnchor>(
+            "activity_anchor",
+            &self.activity_anchor,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ActivityExplanation>(
+            "activity_explanation",
+            &self.activity_explanation,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Advisory>("advisory", &self.advisory, event);
+        callbacks.invoke_table_row_callbacks::<AirDuct>("air_duct", &self.air_duct, event);
+        callbacks.invoke_table_row_callbacks::<BalanceConfig>(
+            "balance_config",
+            &self.balance_config,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Candidate>("candidate", &self.candidate, event);
+        callbacks.invoke_table_row_callbacks::<Career>("career", &self.career, event);
+        callbacks.invoke_table_row_callbacks::<CargoItem>("cargo_item", &self.cargo_item, event);
+        callbacks.invoke_table_row_callbacks::<Casualty>("casualty", &self.casualty, event);
+        callbacks.invoke_table_row_callbacks::<CommandChain>(
+            "command_chain",
+            &self.command_chain,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<ConnectedPlayer>(
             "connected_player",
             &self.connected_player,
@@ -610,63 +2080,270 @@ impl<'r> __sdk::AppliedDiff<'r> for AppliedDiff<'r> {
             &self.conversation,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<CoolantLoop>(
+            "coolant_loop",
+            &self.coolant_loop,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Corridor>("corridor", &self.corridor, event);
         callbacks.invoke_table_row_callbacks::<Crew>("crew", &self.crew, event);
-        callbacks.invoke_table_row_callbacks::<DeckAtmosphere>(
-            "deck_atmosphere",
-            &self.deck_atmosphere,
+        callbacks.invoke_table_row_callbacks::<Crop>("crop", &self.crop, event);
+        callbacks.invoke_table_row_callbacks::<CultureMix>("culture_mix", &self.culture_mix, event);
+        callbacks.invoke_table_row_callbacks::<DailyHistory>(
+            "daily_history",
+            &self.daily_history,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<Deck>("deck", &self.deck, event);
+        callbacks.invoke_table_row_callbacks::<DeckGenerationJob>(
+            "deck_generation_job",
+            &self.deck_generation_job,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<DeckNickname>(
+            "deck_nickname",
+            &self.deck_nickname,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Detention>("detention", &self.detention, event);
         callbacks.invoke_table_row_callbacks::<Door>("door", &self.door, event);
+        callbacks.invoke_table_row_callbacks::<Drinking>("drinking", &self.drinking, event);
+        callbacks.invoke_table_row_callbacks::<Election>("election", &self.election, event);
+        callbacks.invoke_table_row_callbacks::<Emotion>("emotion", &self.emotion, event);
+        callbacks.invoke_table_row_callbacks::<EvaMission>("eva_mission", &self.eva_mission, event);
+        callbacks.invoke_table_row_callbacks::<EvacuationRoute>(
+            "evacuation_route",
+            &self.evacuation_route,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Event>("event", &self.event, event);
+        callbacks.invoke_table_row_callbacks::<Faction>("faction", &self.faction, event);
+        callbacks.invoke_table_row_callbacks::<FactionMembership>(
+            "faction_membership",
+            &self.faction_membership,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Family>("family", &self.family, event);
+        callbacks.invoke_table_row_callbacks::<FamilyMember>(
+            "family_member",
+            &self.family_member,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<FeatureFlags>(
+            "feature_flags",
+            &self.feature_flags,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<FrameExport>(
+            "frame_export",
+            &self.frame_export,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Funeral>("funeral", &self.funeral, event);
+        callbacks.invoke_table_row_callbacks::<Furniture>("furniture", &self.furniture, event);
+        callbacks.invoke_table_row_callbacks::<Garden>("garden", &self.garden, event);
+        callbacks.invoke_table_row_callbacks::<GenerationStats>(
+            "generation_stats",
+            &self.generation_stats,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<GraphEdge>("graph_edge", &self.graph_edge, event);
         callbacks.invoke_table_row_callbacks::<GraphNode>("graph_node", &self.graph_node, event);
+        callbacks.invoke_table_row_callbacks::<Grief>("grief", &self.grief, event);
+        callbacks.invoke_table_row_callbacks::<Hibernation>(
+            "hibernation",
+            &self.hibernation,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<HistoryConfig>(
+            "history_config",
+            &self.history_config,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<HullDamage>("hull_damage", &self.hull_damage, event);
+        callbacks.invoke_table_row_callbacks::<HullHatch>("hull_hatch", &self.hull_hatch, event);
         callbacks.invoke_table_row_callbacks::<InConversation>(
             "in_conversation",
             &self.in_conversation,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<Incident>("incident", &self.incident, event);
         callbacks.invoke_table_row_callbacks::<InfraEdge>("infra_edge", &self.infra_edge, event);
-        callbacks.invoke_table_row_callbacks::<MaintenanceTask>(
-            "maintenance_task",
-            &self.maintenance_task,
+        callbacks.invoke_table_row_callbacks::<InventoryItem>(
+            "inventory_item",
+            &self.inventory_item,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Lighting>("lighting", &self.lighting, event);
+        callbacks.invoke_table_row_callbacks::<MealBuffer>("meal_buffer", &self.meal_buffer, event);
+        callbacks.invoke_table_row_callbacks::<MentalHealth>(
+            "mental_health",
+            &self.mental_health,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Milestone>("milestone", &self.milestone, event);
+        callbacks.invoke_table_row_callbacks::<MilestoneTracker>(
+            "milestone_tracker",
+            &self.milestone_tracker,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<MoraleSummary>(
+            "morale_summary",
+            &self.morale_summary,
             event,
         );
         callbacks.invoke_table_row_callbacks::<Movement>("movement", &self.movement, event);
+        callbacks.invoke_table_row_callbacks::<Mutiny>("mutiny", &self.mutiny, event);
         callbacks.invoke_table_row_callbacks::<Needs>("needs", &self.needs, event);
+        callbacks.invoke_table_row_callbacks::<NewbornName>(
+            "newborn_name",
+            &self.newborn_name,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<OrderAssignment>(
+            "order_assignment",
+            &self.order_assignment,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<PartStock>("part_stock", &self.part_stock, event);
         callbacks.invoke_table_row_callbacks::<Passenger>("passenger", &self.passenger, event);
+        callbacks.invoke_table_row_callbacks::<Patient>("patient", &self.patient, event);
         callbacks.invoke_table_row_callbacks::<Person>("person", &self.person, event);
+        callbacks.invoke_table_row_callbacks::<PersonTimeline>(
+            "person_timeline",
+            &self.person_timeline,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<PersonTrait>(
+            "person_trait",
+            &self.person_trait,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Personality>(
             "personality",
             &self.personality,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<PlayerObjective>(
+            "player_objective",
+            &self.player_objective,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Policy>("policy", &self.policy, event);
+        callbacks.invoke_table_row_callbacks::<PopulationSample>(
+            "population_sample",
+            &self.population_sample,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Position>("position", &self.position, event);
+        callbacks.invoke_table_row_callbacks::<PowerGrid>("power_grid", &self.power_grid, event);
+        callbacks.invoke_table_row_callbacks::<Pregnancy>("pregnancy", &self.pregnancy, event);
+        callbacks.invoke_table_row_callbacks::<Quarantine>("quarantine", &self.quarantine, event);
+        callbacks.invoke_table_row_callbacks::<RadiationDose>(
+            "radiation_dose",
+            &self.radiation_dose,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Relationship>(
             "relationship",
             &self.relationship,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<Robot>("robot", &self.robot, event);
         callbacks.invoke_table_row_callbacks::<Room>("room", &self.room, event);
+        callbacks.invoke_table_row_callbacks::<RoomAir>("room_air", &self.room_air, event);
+        callbacks.invoke_table_row_callbacks::<RoomAmbience>(
+            "room_ambience",
+            &self.room_ambience,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<RoomEffect>("room_effect", &self.room_effect, event);
+        callbacks.invoke_table_row_callbacks::<RoomNickname>(
+            "room_nickname",
+            &self.room_nickname,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<RoomPower>("room_power", &self.room_power, event);
+        callbacks.invoke_table_row_callbacks::<RoomWater>("room_water", &self.room_water, event);
+        callbacks.invoke_table_row_callbacks::<Schooling>("schooling", &self.schooling, event);
+        callbacks.invoke_table_row_callbacks::<SensorContact>(
+            "sensor_contact",
+            &self.sensor_contact,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<ShipConfig>("ship_config", &self.ship_config, event);
+        callbacks.invoke_table_row_callbacks::<ShipOwner>("ship_owner", &self.ship_owner, event);
         callbacks.invoke_table_row_callbacks::<ShipResources>(
             "ship_resources",
             &self.ship_resources,
             event,
         );
         callbacks.invoke_table_row_callbacks::<ShipSystem>("ship_system", &self.ship_system, event);
+        callbacks.invoke_table_row_callbacks::<Shuttle>("shuttle", &self.shuttle, event);
+        callbacks.invoke_table_row_callbacks::<SimulationWarning>(
+            "simulation_warning",
+            &self.simulation_warning,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<SkillUpkeep>(
+            "skill_upkeep",
+            &self.skill_upkeep,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Skills>("skills", &self.skills, event);
+        callbacks.invoke_table_row_callbacks::<SnapshotConfig>(
+            "snapshot_config",
+            &self.snapshot_config,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Spotlight>("spotlight", &self.spotlight, event);
+        callbacks.invoke_table_row_callbacks::<StandingOrder>(
+            "standing_order",
+            &self.standing_order,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<StateExport>(
+            "state_export",
+            &self.state_export,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<StockTrend>("stock_trend", &self.stock_trend, event);
+        callbacks.invoke_table_row_callbacks::<StreamAction>(
+            "stream_action",
+            &self.stream_action,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<StreamConfig>(
+            "stream_config",
+            &self.stream_config,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Subsystem>("subsystem", &self.subsystem, event);
+        callbacks.invoke_table_row_callbacks::<Surgery>("surgery", &self.surgery, event);
         callbacks.invoke_table_row_callbacks::<SystemComponent>(
             "system_component",
             &self.system_component,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<Teacher>("teacher", &self.teacher, event);
         callbacks.invoke_table_row_callbacks::<VerticalShaft>(
             "vertical_shaft",
             &self.vertical_shaft,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<VoyageReport>(
+            "voyage_report",
+            &self.voyage_report,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<VoyageState>(
+            "voyage_state",
+            &self.voyage_state,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Wallet>("wallet", &self.wallet, event);
+        callbacks.invoke_table_row_callbacks::<WorkOrder>("work_order", &self.work_order, event);
+        callbacks.invoke_table_row_callbacks::<ZoneConfig>("zone_config", &self.zone_config, event);
     }
 }
 
@@ -1388,32 +3065,117 @@ impl __sdk::SpacetimeModule for RemoteModule {
 
     fn register_tables(client_cache: &mut __sdk::ClientCache<Self>) {
         activity_table::register_table(client_cache);
+        activity_anchor_table::register_table(client_cache);
+        activity_explanation_table::register_table(client_cache);
+        advisory_table::register_table(client_cache);
+        air_duct_table::register_table(client_cache);
+        balance_config_table::register_table(client_cache);
+        candidate_table::register_table(client_cache);
+        career_table::register_table(client_cache);
+        cargo_item_table::register_table(client_cache);
+        casualty_table::register_table(client_cache);
+        command_chain_table::register_table(client_cache);
         connected_player_table::register_table(client_cache);
         conversation_table::register_table(client_cache);
+        coolant_loop_table::register_table(client_cache);
         corridor_table::register_table(client_cache);
         crew_table::register_table(client_cache);
-        deck_atmosphere_table::register_table(client_cache);
+        crop_table::register_table(client_cache);
+        culture_mix_table::register_table(client_cache);
+        daily_history_table::register_table(client_cache);
+        deck_table::register_table(client_cache);
+        deck_generation_job_table::register_table(client_cache);
+        deck_nickname_table::register_table(client_cache);
+        detention_table::register_table(client_cache);
         door_table::register_table(client_cache);
+        drinking_table::register_table(client_cache);
+        election_table::register_table(client_cache);
+        emotion_table::register_table(client_cache);
+        eva_mission_table::register_table(client_cache);
+        evacuation_route_table::register_table(client_cache);
         event_table::register_table(client_cache);
+        faction_table::register_table(client_cache);
+        faction_membership_table::register_table(client_cache);
+        family_table::register_table(client_cache);
+        family_member_table::register_table(client_cache);
+        feature_flags_table::register_table(client_cache);
+        frame_export_table::register_table(client_cache);
+        funeral_table::register_table(client_cache);
+        furniture_table::register_table(client_cache);
+        garden_table::register_table(client_cache);
+        generation_stats_table::register_table(client_cache);
         graph_edge_table::register_table(client_cache);
         graph_node_table::register_table(client_cache);
+        grief_table::register_table(client_cache);
+        hibernation_table::register_table(client_cache);
+        history_config_table::register_table(client_cache);
+        hull_damage_table::register_table(client_cache);
+        hull_hatch_table::register_table(client_cache);
         in_conversation_table::register_table(client_cache);
+        incident_table::register_table(client_cache);
         infra_edge_table::register_table(client_cache);
-        maintenance_task_table::register_table(client_cache);
+        inventory_item_table::register_table(client_cache);
+        lighting_table::register_table(client_cache);
+        meal_buffer_table::register_table(client_cache);
+        mental_health_table::register_table(client_cache);
+        milestone_table::register_table(client_cache);
+        milestone_tracker_table::register_table(client_cache);
+        morale_summary_table::register_table(client_cache);
         movement_table::register_table(client_cache);
+        mutiny_table::register_table(client_cache);
         needs_table::register_table(client_cache);
+        newborn_name_table::register_table(client_cache);
+        order_assignment_table::register_table(client_cache);
+        part_stock_table::register_table(client_cache);
         passenger_table::register_table(client_cache);
+        patient_table::register_table(client_cache);
         person_table::register_table(client_cache);
+        person_timeline_table::register_table(client_cache);
+        person_trait_table::register_table(client_cache);
         personality_table::register_table(client_cache);
+        player_objective_table::register_table(client_cache);
+        policy_table::register_table(client_cache);
+        population_sample_table::register_table(client_cache);
         position_table::register_table(client_cache);
+        power_grid_table::register_table(client_cache);
+        pregnancy_table::register_table(client_cache);
+        quarantine_table::register_table(client_cache);
+        radiation_dose_table::register_table(client_cache);
         relationship_table::register_table(client_cache);
+        robot_table::register_table(client_cache);
         room_table::register_table(client_cache);
+        room_air_table::register_table(client_cache);
+        room_ambience_table::register_table(client_cache);
+        room_effect_table::register_table(client_cache);
+        room_nickname_table::register_table(client_cache);
+        room_power_table::register_table(client_cache);
+        room_water_table::register_table(client_cache);
+        schooling_table::register_table(client_cache);
+        sensor_contact_table::register_table(client_cache);
         ship_config_table::register_table(client_cache);
+        ship_owner_table::register_table(client_cache);
         ship_resources_table::register_table(client_cache);
         ship_system_table::register_table(client_cache);
+        shuttle_table::register_table(client_cache);
+        simulation_warning_table::register_table(client_cache);
+        skill_upkeep_table::register_table(client_cache);
         skills_table::register_table(client_cache);
+        snapshot_config_table::register_table(client_cache);
+        spotlight_table::register_table(client_cache);
+        standing_order_table::register_table(client_cache);
+        state_export_table::register_table(client_cache);
+        stock_trend_table::register_table(client_cache);
+        stream_action_table::register_table(client_cache);
+        stream_config_table::register_table(client_cache);
         subsystem_table::register_table(client_cache);
+        surgery_table::register_table(client_cache);
         system_component_table::register_table(client_cache);
+        teacher_table::register_table(client_cache);
         vertical_shaft_table::register_table(client_cache);
+        voyage_report_table::register_table(client_cache);
+        voyage_state_table::register_table(client_cache);
+        wallet_table::register_table(client_cache);
+        work_order_table::register_table(client_cache);
+        zone_config_table::register_table(client_cache);
     }
 }
@@ -14,6 +14,10 @@ pub struct Person {
     pub is_player: bool,
     pub is_alive: bool,
     pub owner_identity: Option<__sdk::Identity>,
+    pub age: u8,
+    pub birth_date: f64,
+    pub life_stage: u8,
+    pub clearance: u8,
 }
 
 impl __sdk::InModule for Person {
@@ -31,6 +35,10 @@ pub struct PersonCols {
     pub is_player: __sdk::__query_builder::Col<Person, bool>,
     pub is_alive: __sdk::__query_builder::Col<Person, bool>,
     pub owner_identity: __sdk::__query_builder::Col<Person, Option<__sdk::Identity>>,
+    pub age: __sdk::__query_builder::Col<Person, u8>,
+    pub birth_date: __sdk::__query_builder::Col<Person, f64>,
+    pub life_stage: __sdk::__query_builder::Col<Person, u8>,
+    pub clearance: __sdk::__query_builder::Col<Person, u8>,
 }
 
 impl __sdk::__query_builder::HasCols for Person {
@@ -44,6 +52,10 @@ impl __sdk::__query_builder::HasCols for Person {
             is_player: __sdk::__query_builder::Col::new(table_name, "is_player"),
             is_alive: __sdk::__query_builder::Col::new(table_name, "is_alive"),
             owner_identity: __sdk::__query_builder::Col::new(table_name, "owner_identity"),
+            age: __sdk::__query_builder::Col::new(table_name, "age"),
+            birth_date: __sdk::__query_builder::Col::new(table_name, "birth_date"),
+            life_stage: __sdk::__query_builder::Col::new(table_name, "life_stage"),
+            clearance: __sdk::__query_builder::Col::new(table_name, "clearance"),
         }
     }
 }
@@ -20,6 +20,8 @@ pub struct Room {
     pub ceiling_height: f32,
     pub deck_span: u8,
     pub cells: Vec<u8>,
+    pub hull_sides: u8,
+    pub has_window: bool,
 }
 
 impl __sdk::InModule for Room {
@@ -43,6 +45,8 @@ pub struct RoomCols {
     pub ceiling_height: __sdk::__query_builder::Col<Room, f32>,
     pub deck_span: __sdk::__query_builder::Col<Room, u8>,
     pub cells: __sdk::__query_builder::Col<Room, Vec<u8>>,
+    pub hull_sides: __sdk::__query_builder::Col<Room, u8>,
+    pub has_window: __sdk::__query_builder::Col<Room, bool>,
 }
 
 impl __sdk::__query_builder::HasCols for Room {
@@ -62,6 +66,8 @@ impl __sdk::__query_builder::HasCols for Room {
             ceiling_height: __sdk::__query_builder::Col::new(table_name, "ceiling_height"),
             deck_span: __sdk::__query_builder::Col::new(table_name, "deck_span"),
             cells: __sdk::__query_builder::Col::new(table_name, "cells"),
+            hull_sides: __sdk::__query_builder::Col::new(table_name, "hull_sides"),
+            has_window: __sdk::__query_builder::Col::new(table_name, "has_window"),
         }
     }
 }
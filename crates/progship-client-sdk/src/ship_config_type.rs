@@ -17,6 +17,15 @@ pub struct ShipConfig {
     pub paused: bool,
     pub death_count: u32,
     pub rationing_level: u8,
+    pub rationing_since: f64,
+    pub pending_decks: u32,
+    pub pending_people: u32,
+    pub warm_start_days: f32,
+    pub seed: u64,
+    pub scenario: String,
+    pub difficulty: u8,
+    pub corridor_topology: u8,
+    pub hull_taper: u8,
 }
 
 impl __sdk::InModule for ShipConfig {
@@ -37,6 +46,15 @@ pub struct ShipConfigCols {
     pub paused: __sdk::__query_builder::Col<ShipConfig, bool>,
     pub death_count: __sdk::__query_builder::Col<ShipConfig, u32>,
     pub rationing_level: __sdk::__query_builder::Col<ShipConfig, u8>,
+    pub rationing_since: __sdk::__query_builder::Col<ShipConfig, f64>,
+    pub pending_decks: __sdk::__query_builder::Col<ShipConfig, u32>,
+    pub pending_people: __sdk::__query_builder::Col<ShipConfig, u32>,
+    pub warm_start_days: __sdk::__query_builder::Col<ShipConfig, f32>,
+    pub seed: __sdk::__query_builder::Col<ShipConfig, u64>,
+    pub scenario: __sdk::__query_builder::Col<ShipConfig, String>,
+    pub difficulty: __sdk::__query_builder::Col<ShipConfig, u8>,
+    pub corridor_topology: __sdk::__query_builder::Col<ShipConfig, u8>,
+    pub hull_taper: __sdk::__query_builder::Col<ShipConfig, u8>,
 }
 
 impl __sdk::__query_builder::HasCols for ShipConfig {
@@ -53,6 +71,15 @@ impl __sdk::__query_builder::HasCols for ShipConfig {
             paused: __sdk::__query_builder::Col::new(table_name, "paused"),
             death_count: __sdk::__query_builder::Col::new(table_name, "death_count"),
             rationing_level: __sdk::__query_builder::Col::new(table_name, "rationing_level"),
+            rationing_since: __sdk::__query_builder::Col::new(table_name, "rationing_since"),
+            pending_decks: __sdk::__query_builder::Col::new(table_name, "pending_decks"),
+            pending_people: __sdk::__query_builder::Col::new(table_name, "pending_people"),
+            warm_start_days: __sdk::__query_builder::Col::new(table_name, "warm_start_days"),
+            seed: __sdk::__query_builder::Col::new(table_name, "seed"),
+            scenario: __sdk::__query_builder::Col::new(table_name, "scenario"),
+            difficulty: __sdk::__query_builder::Col::new(table_name, "difficulty"),
+            corridor_topology: __sdk::__query_builder::Col::new(table_name, "corridor_topology"),
+            hull_taper: __sdk::__query_builder::Col::new(table_name, "hull_taper"),
         }
     }
 }
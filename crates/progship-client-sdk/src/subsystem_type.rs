@@ -16,6 +16,7 @@ pub struct Subsystem {
     pub node_id: u64,
     pub power_draw: f32,
     pub crew_required: u8,
+    pub output: f32,
 }
 
 impl __sdk::InModule for Subsystem {
@@ -35,6 +36,7 @@ pub struct SubsystemCols {
     pub node_id: __sdk::__query_builder::Col<Subsystem, u64>,
     pub power_draw: __sdk::__query_builder::Col<Subsystem, f32>,
     pub crew_required: __sdk::__query_builder::Col<Subsystem, u8>,
+    pub output: __sdk::__query_builder::Col<Subsystem, f32>,
 }
 
 impl __sdk::__query_builder::HasCols for Subsystem {
@@ -50,6 +52,7 @@ impl __sdk::__query_builder::HasCols for Subsystem {
             node_id: __sdk::__query_builder::Col::new(table_name, "node_id"),
             power_draw: __sdk::__query_builder::Col::new(table_name, "power_draw"),
             crew_required: __sdk::__query_builder::Col::new(table_name, "crew_required"),
+            output: __sdk::__query_builder::Col::new(table_name, "output"),
         }
     }
 }
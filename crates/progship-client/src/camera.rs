@@ -7,6 +7,7 @@ use bevy::prelude::*;
 use bevy::prelude::{MessageReader, MessageWriter};
 use progship_client_sdk::*;
 
+use crate::lighting::BaseLight;
 use crate::state::{ConnectionState, PlayerCamera, PlayerState, ViewState};
 
 pub fn setup_camera(
@@ -85,11 +86,18 @@ pub fn setup_camera(
     } else {
         20.0
     };
-    commands.spawn(AmbientLight {
-        color: Color::srgb(0.7, 0.75, 0.85),
-        brightness: ambient_brightness,
-        affects_lightmapped_meshes: true,
-    });
+    let ambient_color = Color::srgb(0.7, 0.75, 0.85);
+    commands.spawn((
+        AmbientLight {
+            color: ambient_color,
+            brightness: ambient_brightness,
+            affects_lightmapped_meshes: true,
+        },
+        BaseLight {
+            color: ambient_color,
+            brightness: ambient_brightness,
+        },
+    ));
 }
 
 pub fn camera_follow_player(
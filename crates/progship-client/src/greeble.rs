@@ -10,10 +10,12 @@
 //! - Pipes: coolant/air/water lines running along ceiling center
 //! - Vent grilles: air recirculation intake/exhaust (flush with wall)
 //! - Control panels: door access panels placed beside door openings
+//!
+//! Rooms with a window get a glass band along each hull-facing wall.
 
 use bevy::prelude::*;
 use progship_client_sdk::Room;
-use progship_logic::constants::room_types;
+use progship_constants::room_types;
 
 use crate::rendering::add_mesh_pub;
 use crate::state::RoomEntity;
@@ -101,6 +103,7 @@ pub struct GreebleLibrary {
     pub mat_vent: Handle<StandardMaterial>,
     pub mat_trim: Handle<StandardMaterial>,
     pub mat_panel: Handle<StandardMaterial>,
+    pub mat_window: Handle<StandardMaterial>,
 }
 
 pub fn init_greeble_library(
@@ -145,6 +148,15 @@ pub fn init_greeble_library(
         ..default()
     });
 
+    let mat_window = materials.add(StandardMaterial {
+        base_color: Color::srgba(0.02, 0.03, 0.08, 0.85),
+        emissive: LinearRgba::rgb(0.01, 0.015, 0.04),
+        metallic: 0.1,
+        perceptual_roughness: 0.05,
+        alpha_mode: AlphaMode::Blend,
+        ..default()
+    });
+
     // Vent grille: thin flush-mounted air vent
     let vent_grille = add_mesh_pub(&mut meshes, Cuboid::new(0.30, 0.08, 0.005));
     // Control panel: small wall-mounted door access panel
@@ -162,6 +174,7 @@ pub fn init_greeble_library(
         mat_vent,
         mat_trim,
         mat_panel,
+        mat_window,
     });
 }
 
@@ -173,7 +186,10 @@ pub fn spawn_room_greebles(
     room: &Room,
     gaps: &WallGaps,
 ) {
-    if matches!(room.room_type, 110..=120) {
+    if matches!(
+        room.room_type,
+        room_types::ELEVATOR_SHAFT..=room_types::SERVICE_DECK
+    ) {
         return;
     }
 
@@ -426,7 +442,12 @@ fn spawn_room_wall_greebles(
         3.5
     };
 
-    let has_conduit = matches!(room.room_type, 60..=71 | 80..=86 | 90..=95);
+    let has_conduit = matches!(
+        room.room_type,
+        room_types::ENGINEERING..=room_types::COOLING_PLANT
+            | room_types::HYDROPONICS..=room_types::HVAC_CONTROL
+            | room_types::CARGO_BAY..=room_types::LABORATORY
+    );
 
     for wall in 0..4u8 {
         let (wall_len, horiz) = match wall {
@@ -470,6 +491,23 @@ fn spawn_room_wall_greebles(
             &segments,
         );
 
+        // Window band on hull walls, broken at doors like the trim
+        if room.has_window && room.hull_sides & (1 << wall) != 0 {
+            spawn_segmented_run(
+                commands,
+                meshes,
+                &lib.mat_window,
+                &re,
+                horiz,
+                wall_coord,
+                sign,
+                0.02,
+                1.6,
+                1.2,
+                &segments,
+            );
+        }
+
         // Conduit tray in industrial rooms (segmented)
         if has_conduit {
             spawn_segmented_run(
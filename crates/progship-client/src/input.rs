@@ -6,10 +6,14 @@ use bevy::input::mouse::MouseWheel;
 use bevy::prelude::MessageReader;
 use bevy::prelude::*;
 use progship_client_sdk::*;
-use progship_logic::constants::room_types;
+use progship_constants::{
+    activity_types, door_states, event_types, room_types, stream_actions, ui_screens,
+};
+use progship_logic::encyclopedia;
 use spacetimedb_sdk::{DbContext, Table};
 
 use crate::state::{ConnectionState, PlayerState, Toast, UiState, ViewState};
+use crate::ui::room_label;
 
 pub fn player_input(
     state: Res<ConnectionState>,
@@ -116,60 +120,66 @@ pub fn player_input(
                     ui.selected_person = Some(target_id);
                 } else {
                     // No person nearby — try toggling nearest door
-                    let my_room = my_pos.room_id;
-                    let mut closest_door: Option<(u64, f32)> = None;
-                    for door in conn.db.door().iter() {
-                        if door.room_a != my_room && door.room_b != my_room {
-                            continue;
-                        }
-                        let ddist = ((door.door_x - my_pos.x).powi(2)
-                            + (door.door_y - my_pos.y).powi(2))
-                        .sqrt();
-                        if ddist < 3.0 {
-                            if closest_door.is_none() || ddist < closest_door.unwrap().1 {
-                                closest_door = Some((door.id, ddist));
-                            }
-                        }
-                    }
-                    if let Some((door_id, _)) = closest_door {
-                        let _ = conn.reducers().toggle_door(door_id);
+                    if let Some(door) = nearest_door(conn, my_pos.room_id, my_pos.x, my_pos.y) {
+                        let _ = conn.reducers().toggle_door(door.id);
                     }
                 }
             }
         }
     }
 
+    // L to lock or unlock the nearest door (security crew and officers)
+    if keyboard.just_pressed(KeyCode::KeyL) {
+        if let Some(pid) = player.person_id {
+            if let Some(my_pos) = conn.db.position().person_id().find(&pid) {
+                if let Some(door) = nearest_door(conn, my_pos.room_id, my_pos.x, my_pos.y) {
+                    let state = match door.state {
+                        door_states::LOCKED => door_states::CLOSED,
+                        _ => door_states::LOCKED,
+                    };
+                    let _ = conn.reducers().set_door_state(door.id, state);
+                }
+            }
+        }
+    }
+
     // F to perform context action (eat/sleep/repair/exercise/hygiene)
     if keyboard.just_pressed(KeyCode::KeyF) {
         if let Some(pid) = player.person_id {
             if let Some(pos) = conn.db.position().person_id().find(&pid) {
                 if let Some(room) = conn.db.room().id().find(&pos.room_id) {
                     let action = match room.room_type {
-                        9 | 10 => 2, // Mess/Galley → eat
-                        5..=8 => {
+                        room_types::MESS_HALL
+                        | room_types::GALLEY
+                        | room_types::CAFE
+                        | room_types::BAKERY => activity_types::EATING,
+                        rt if room_types::is_quarters(rt) => {
                             // Quarters → sleep or hygiene
                             if let Some(needs) = conn.db.needs().person_id().find(&pid) {
                                 if needs.hygiene > needs.fatigue {
-                                    6
+                                    activity_types::HYGIENE
                                 } else {
-                                    3
+                                    activity_types::SLEEPING
                                 }
                             } else {
-                                3
+                                activity_types::SLEEPING
                             }
                         }
-                        2 | 3 | 4 | 21..=23 => 8, // Engineering rooms → repair
-                        12 | 13 => 12,            // Recreation/Gym → exercise
-                        _ => 255,                 // Invalid — server will reject
+                        room_types::SHARED_BATHROOM => activity_types::HYGIENE,
+                        room_types::ENGINEERING..=room_types::COOLING_PLANT => {
+                            activity_types::MAINTENANCE
+                        }
+                        rt if room_types::is_recreation(rt) => activity_types::EXERCISING,
+                        _ => 255, // Invalid — server will reject
                     };
                     if action != 255 {
                         let _ = conn.reducers().player_action(action);
                         let action_name = match action {
-                            2 => "Eating...",
-                            3 => "Sleeping...",
-                            6 => "Hygiene...",
-                            8 => "Repairing...",
-                            12 => "Exercising...",
+                            activity_types::EATING => "Eating...",
+                            activity_types::SLEEPING => "Sleeping...",
+                            activity_types::HYGIENE => "Hygiene...",
+                            activity_types::MAINTENANCE => "Repairing...",
+                            activity_types::EXERCISING => "Exercising...",
                             _ => "Acting...",
                         };
                         ui.toasts.push(Toast {
@@ -242,6 +252,38 @@ pub fn player_input(
     if keyboard.just_pressed(KeyCode::Tab) {
         ui.show_ship_overview = !ui.show_ship_overview;
         ui.selected_person = None;
+        if ui.show_ship_overview {
+            let _ = conn
+                .reducers()
+                .player_view_screen(ui_screens::SHIP_OVERVIEW);
+        }
+    }
+
+    // F1 to toggle the shipopedia; comma/period step through entries and
+    // PageUp/PageDown jump between sections
+    if keyboard.just_pressed(KeyCode::F1) {
+        ui.encyclopedia_page = match ui.encyclopedia_page {
+            Some(_) => None,
+            None => {
+                let _ = conn.reducers().player_view_screen(ui_screens::ENCYCLOPEDIA);
+                Some(0)
+            }
+        };
+    }
+    if let Some(page) = ui.encyclopedia_page {
+        let entries = &ui.encyclopedia;
+        let page = if keyboard.just_pressed(KeyCode::Period) {
+            (page + 1).min(entries.len().saturating_sub(1))
+        } else if keyboard.just_pressed(KeyCode::Comma) {
+            page.saturating_sub(1)
+        } else if keyboard.just_pressed(KeyCode::PageDown) {
+            encyclopedia::next_section(entries, page)
+        } else if keyboard.just_pressed(KeyCode::PageUp) {
+            encyclopedia::previous_section(entries, page)
+        } else {
+            page
+        };
+        ui.encyclopedia_page = Some(page);
     }
 
     // Q to select/deselect nearest NPC (without interacting)
@@ -264,6 +306,10 @@ pub fn player_input(
                     }
                 }
                 ui.selected_person = closest.map(|(id, _)| id);
+                if let Some(id) = ui.selected_person {
+                    let _ = conn.reducers().explain_activity(id);
+                    let _ = conn.reducers().player_view_screen(ui_screens::PERSON_INFO);
+                }
             }
         }
     }
@@ -328,7 +374,7 @@ pub fn player_input(
             let (msg, color) = event_toast_info(evt.event_type, evt.severity);
             if let Some(room) = conn.db.room().id().find(&evt.room_id) {
                 ui.toasts.push(Toast {
-                    message: format!("{} in {}", msg, room.name),
+                    message: format!("{} in {}", msg, room_label(conn, &room)),
                     color,
                     timer: 5.0,
                 });
@@ -337,6 +383,34 @@ pub fn player_input(
     }
     ui.last_event_count = active_events.len();
 
+    // Announce what stream chat did (the last few, after a reconnect); a
+    // spotlight selects its NPC
+    let mut actions: Vec<_> = conn
+        .db
+        .stream_action()
+        .iter()
+        .filter(|a| a.id > ui.last_stream_action)
+        .collect();
+    actions.sort_by_key(|a| a.id);
+    let skipped = actions.len().saturating_sub(3);
+    for action in actions.into_iter().skip(skipped) {
+        ui.last_stream_action = action.id;
+        if action.action == stream_actions::SPOTLIGHT {
+            if let Some(spotlight) = conn.db.spotlight().id().find(&0) {
+                ui.selected_person = Some(spotlight.person_id);
+            }
+        }
+        ui.toasts.push(Toast {
+            message: format!(
+                "Chat: {} - {}",
+                stream_actions::name(action.action),
+                action.detail
+            ),
+            color: Color::srgb(0.7, 0.5, 1.0),
+            timer: 5.0,
+        });
+    }
+
     // Tick toast timers
     let dt = time.delta_secs();
     ui.toasts.retain_mut(|t| {
@@ -345,30 +419,37 @@ pub fn player_input(
     });
 }
 
+/// The door of `room_id` within 3 m of (x, y) nearest to it.
+fn nearest_door(conn: &DbConnection, room_id: u32, x: f32, y: f32) -> Option<Door> {
+    conn.db
+        .door()
+        .iter()
+        .filter(|d| d.room_a == room_id || d.room_b == room_id)
+        .map(|d| {
+            let dist = ((d.door_x - x).powi(2) + (d.door_y - y).powi(2)).sqrt();
+            (d, dist)
+        })
+        .filter(|(_, dist)| *dist < 3.0)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(d, _)| d)
+}
+
 fn event_toast_info(event_type: u8, severity: f32) -> (String, Color) {
-    let name = event_type_name(event_type);
+    let name = event_types::name(event_type);
     let color = if severity > 0.7 {
         Color::srgb(1.0, 0.2, 0.2) // Red - critical
     } else if severity > 0.4 {
         Color::srgb(1.0, 0.7, 0.1) // Orange - warning
-    } else if event_type == 4 || event_type == 5 {
+    } else if matches!(
+        event_type,
+        event_types::DISCOVERY
+            | event_types::CELEBRATION
+            | event_types::PROMOTION
+            | event_types::VOYAGE_MILESTONE
+    ) {
         Color::srgb(0.3, 1.0, 0.3) // Green - positive
     } else {
         Color::srgb(1.0, 0.9, 0.3) // Yellow - info
     };
     (format!("!! {}", name), color)
 }
-
-fn event_type_name(event_type: u8) -> &'static str {
-    match event_type {
-        0 => "System Failure",
-        1 => "Medical Emergency",
-        2 => "Fire",
-        3 => "Hull Breach",
-        4 => "Discovery",
-        5 => "Celebration",
-        6 => "Altercation",
-        7 => "Resource Shortage",
-        _ => "Unknown Event",
-    }
-}
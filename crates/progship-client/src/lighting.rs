@@ -0,0 +1,147 @@
+//! Circadian lighting for the ProgShip client.
+//!
+//! Scales the ambient light and every room fixture on the current deck by
+//! the deck's lights as the server sets them (see the `lighting` table),
+//! falling back to the sim hour and the deck's light schedule, warming the
+//! color at night, and dims quarters as their occupants fall asleep. Lively rooms (see the
+//! `room_ambience` table) glow a little brighter, and rooms the power grid
+//! shed (see the `room_power` table) drop to emergency lighting. Smoke (see
+//! the `room_effect` table) swallows some of a room's light. The curves
+//! live in [`progship_logic::lighting`].
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use progship_client_sdk::*;
+use progship_constants::{activity_types, effect_kinds, room_types};
+use progship_logic::lighting::{deck_ambience, kelvin_to_rgb, quarters_ambience, Ambience};
+use progship_logic::room_effects::visibility;
+use spacetimedb_sdk::Table;
+
+use crate::state::{ConnectionState, RoomEntity, ViewState};
+
+/// Extra fixture brightness in a room at full liveliness.
+const LIVELY_BOOST: f32 = 0.15;
+
+/// Fixture brightness left on emergency lighting in a room the grid shed.
+const EMERGENCY_LIGHT: f32 = 0.1;
+
+/// Daytime color and brightness of a light, before circadian scaling.
+#[derive(Component)]
+pub struct BaseLight {
+    pub color: Color,
+    pub brightness: f32,
+}
+
+/// Color `base` tinted toward the color temperature `kelvin`.
+fn tinted(base: Color, kelvin: u32) -> Color {
+    let base = base.to_srgba();
+    let [r, g, b] = kelvin_to_rgb(kelvin);
+    Color::srgba(base.red * r, base.green * g, base.blue * b, base.alpha)
+}
+
+pub fn update_lighting(
+    state: Res<ConnectionState>,
+    view: Res<ViewState>,
+    time: Res<Time>,
+    mut timer: Local<f32>,
+    mut ambient_q: Query<(&mut AmbientLight, &BaseLight)>,
+    mut fixture_q: Query<(&mut PointLight, &BaseLight, &RoomEntity)>,
+) {
+    // Lighting drifts over sim hours; 2Hz is plenty
+    *timer += time.delta_secs();
+    if *timer < 0.5 {
+        return;
+    }
+    *timer = 0.0;
+
+    let conn = match &*state {
+        ConnectionState::Connected(c) => c,
+        _ => return,
+    };
+    let hour = conn
+        .db
+        .ship_config()
+        .id()
+        .find(&0)
+        .map(|c| (c.sim_time % 24.0) as f32)
+        .unwrap_or(12.0);
+    let Some(deck) = conn.db.deck().deck().find(&view.current_deck) else {
+        return;
+    };
+    let ambience = match conn.db.lighting().deck().find(&view.current_deck) {
+        Some(lighting) => Ambience {
+            level: lighting.level,
+            kelvin: lighting.kelvin,
+        },
+        None => deck_ambience(
+            deck.light_level,
+            deck.light_kelvin,
+            deck.light_schedule,
+            hour,
+        ),
+    };
+    // Fixtures are designed for full daylight, so scale them relative to it
+    let scale = |a: Ambience| a.level / deck.light_level.max(0.01);
+
+    for (mut light, base) in ambient_q.iter_mut() {
+        light.color = tinted(base.color, ambience.kelvin);
+        light.brightness = base.brightness * scale(ambience);
+    }
+
+    // (occupants, sleeping) per quarters room on this deck
+    let mut quarters: HashMap<u32, (u32, u32)> = conn
+        .db
+        .room()
+        .iter()
+        .filter(|r| r.deck == view.current_deck && room_types::is_quarters(r.room_type))
+        .map(|r| (r.id, (0, 0)))
+        .collect();
+    for pos in conn.db.position().iter() {
+        if let Some(count) = quarters.get_mut(&pos.room_id) {
+            count.0 += 1;
+            let asleep = conn
+                .db
+                .activity()
+                .person_id()
+                .find(&pos.person_id)
+                .is_some_and(|a| a.activity_type == activity_types::SLEEPING);
+            if asleep {
+                count.1 += 1;
+            }
+        }
+    }
+
+    let smoke: HashMap<u32, f32> = conn
+        .db
+        .room_effect()
+        .iter()
+        .filter(|e| e.kind == effect_kinds::SMOKE)
+        .map(|e| (e.room_id, e.intensity))
+        .collect();
+
+    for (mut light, base, room) in fixture_q.iter_mut() {
+        let lit = match quarters.get(&room.room_id) {
+            Some(&(occupants, sleeping)) => quarters_ambience(ambience, sleeping, occupants),
+            None => ambience,
+        };
+        let lively = conn
+            .db
+            .room_ambience()
+            .room_id()
+            .find(&room.room_id)
+            .map(|a| a.liveliness)
+            .unwrap_or(0.0);
+        let powered = conn
+            .db
+            .room_power()
+            .room_id()
+            .find(&room.room_id)
+            .is_none_or(|p| p.powered);
+        let emergency = if powered { 1.0 } else { EMERGENCY_LIGHT };
+        let haze = visibility(smoke.get(&room.room_id).copied().unwrap_or(0.0));
+        light.color = tinted(base.color, lit.kelvin);
+        light.intensity =
+            base.brightness * scale(lit) * (1.0 + LIVELY_BOOST * lively) * emergency * haze;
+    }
+}
@@ -21,6 +21,7 @@ use bevy::prelude::*;
 mod camera;
 mod greeble;
 mod input;
+mod lighting;
 mod minimap;
 mod networking;
 mod rendering;
@@ -29,9 +30,13 @@ mod ui;
 
 use camera::{camera_follow_player, handle_quit, setup_camera};
 use input::player_input;
+use lighting::update_lighting;
 use minimap::{minimap_toggle, render_minimap, MinimapState};
-use networking::{auto_join_game, connect_to_server, process_messages};
-use rendering::{animate_details, animate_dust_motes, sync_door_panels, sync_people, sync_rooms};
+use networking::{auto_join_game, connect_to_server, new_ship_menu_input, process_messages};
+use rendering::{
+    animate_details, animate_dust_motes, sync_door_panels, sync_people, sync_robots,
+    sync_room_effects, sync_rooms,
+};
 use state::{ConnectionConfig, ConnectionState, PlayerState, UiState, ViewState};
 use ui::{render_hud, render_info_panel, render_toasts, setup_ui};
 
@@ -79,6 +84,7 @@ fn main() {
                 connect_to_server,
                 process_messages,
                 auto_join_game,
+                new_ship_menu_input,
                 player_input,
                 minimap_toggle,
                 camera_follow_player,
@@ -86,8 +92,11 @@ fn main() {
                 sync_rooms,
                 sync_people,
                 sync_door_panels,
+                sync_room_effects,
+                sync_robots,
                 animate_details,
                 animate_dust_motes,
+                update_lighting,
             ),
         )
         .add_systems(
@@ -6,7 +6,7 @@
 
 use bevy::prelude::*;
 use progship_client_sdk::*;
-use progship_logic::constants::{room_type_icon, room_types};
+use progship_constants::{room_type_icon, room_types};
 use spacetimedb_sdk::Table;
 
 use crate::state::{ConnectionState, PlayerState, ViewState};
@@ -339,17 +339,31 @@ pub fn render_minimap(
 /// Simplified room colors for minimap (fewer distinct shades, bolder colors).
 fn minimap_room_color(room_type: u8) -> Color {
     match room_type {
-        0..=8 => Color::srgb(0.15, 0.15, 0.55),     // Command — blue
-        10..=18 => Color::srgb(0.25, 0.35, 0.40),   // Habitation — teal
-        20..=27 => Color::srgb(0.55, 0.45, 0.15),   // Food — yellow
-        30..=37 => Color::srgb(0.65, 0.70, 0.75),   // Medical — white
-        40..=56 => Color::srgb(0.20, 0.50, 0.25),   // Recreation — green
-        60..=71 => Color::srgb(0.55, 0.30, 0.10),   // Engineering — orange
-        80..=86 => Color::srgb(0.20, 0.45, 0.50),   // Life Support — cyan
-        90..=95 => Color::srgb(0.35, 0.28, 0.18),   // Cargo — brown
-        100..=102 => Color::srgb(0.15, 0.15, 0.18), // Corridors — dark gray
-        110..=111 => Color::srgb(0.30, 0.30, 0.38), // Shafts — lighter gray
-        120 => Color::srgb(0.10, 0.10, 0.12),       // Service deck
-        _ => Color::srgb(0.25, 0.25, 0.25),         // Unknown
+        // Command — blue
+        room_types::BRIDGE..=room_types::OBSERVATORY => Color::srgb(0.15, 0.15, 0.55),
+        // Habitation — teal
+        room_types::CABIN_SINGLE..=room_types::SHARED_LAUNDRY => Color::srgb(0.25, 0.35, 0.40),
+        // Food — yellow
+        room_types::MESS_HALL..=room_types::WATER_PURIFICATION => Color::srgb(0.55, 0.45, 0.15),
+        // Medical — white
+        room_types::HOSPITAL_WARD..=room_types::CRYO_BAY => Color::srgb(0.65, 0.70, 0.75),
+        // Recreation — green
+        room_types::GYM..=room_types::SHOPS => Color::srgb(0.20, 0.50, 0.25),
+        // Engineering — orange
+        room_types::ENGINEERING..=room_types::COOLING_PLANT => Color::srgb(0.55, 0.30, 0.10),
+        // Life Support — cyan
+        room_types::HYDROPONICS..=room_types::HVAC_CONTROL => Color::srgb(0.20, 0.45, 0.50),
+        // Cargo — brown
+        room_types::CARGO_BAY..=room_types::LABORATORY => Color::srgb(0.35, 0.28, 0.18),
+        // Corridors — dark gray
+        room_types::CORRIDOR..=room_types::CRAWLWAY => Color::srgb(0.15, 0.15, 0.18),
+        // Shafts — lighter gray
+        room_types::ELEVATOR_SHAFT..=room_types::SERVICE_ELEVATOR_SHAFT => {
+            Color::srgb(0.30, 0.30, 0.38)
+        }
+        // Service deck
+        room_types::SERVICE_DECK => Color::srgb(0.10, 0.10, 0.12),
+        // Unknown
+        _ => Color::srgb(0.25, 0.25, 0.25),
     }
 }
@@ -5,6 +5,7 @@
 
 use bevy::prelude::*;
 use progship_client_sdk::*;
+use progship_logic::scenarios;
 use spacetimedb_sdk::{DbContext, Table};
 
 use crate::state::{ConnectionConfig, ConnectionState, PlayerState, Toast, UiState};
@@ -59,22 +60,69 @@ pub fn connect_to_server(
                 "SELECT * FROM person",
                 "SELECT * FROM position",
                 "SELECT * FROM needs",
+                "SELECT * FROM radiation_dose",
+                "SELECT * FROM pregnancy",
+                "SELECT * FROM grief",
+                "SELECT * FROM detention",
+                "SELECT * FROM faction_membership",
+                "SELECT * FROM candidate",
+                "SELECT * FROM teacher",
+                "SELECT * FROM schooling",
+                "SELECT * FROM person_trait",
                 "SELECT * FROM activity",
+                "SELECT * FROM person_timeline",
+                "SELECT * FROM activity_explanation",
                 "SELECT * FROM crew",
+                "SELECT * FROM career",
                 "SELECT * FROM passenger",
-                "SELECT * FROM deck_atmosphere",
+                "SELECT * FROM room_air",
                 "SELECT * FROM ship_system",
                 "SELECT * FROM subsystem",
                 "SELECT * FROM system_component",
                 "SELECT * FROM infra_edge",
                 "SELECT * FROM ship_resources",
+                "SELECT * FROM voyage_state",
                 "SELECT * FROM conversation",
                 "SELECT * FROM in_conversation",
                 "SELECT * FROM relationship",
                 "SELECT * FROM event",
                 "SELECT * FROM movement",
-                "SELECT * FROM maintenance_task",
+                "SELECT * FROM work_order",
+                "SELECT * FROM eva_mission",
+                "SELECT * FROM robot",
+                "SELECT * FROM hull_damage",
+                "SELECT * FROM sensor_contact",
+                "SELECT * FROM part_stock",
+                "SELECT * FROM patient",
+                "SELECT * FROM surgery",
+                "SELECT * FROM quarantine",
+                "SELECT * FROM hibernation",
+                "SELECT * FROM mental_health",
+                "SELECT * FROM drinking",
+                "SELECT * FROM lighting",
+                "SELECT * FROM inventory_item",
+                "SELECT * FROM wallet",
                 "SELECT * FROM connected_player",
+                "SELECT * FROM milestone",
+                "SELECT * FROM deck",
+                "SELECT * FROM room_nickname",
+                "SELECT * FROM deck_nickname",
+                "SELECT * FROM emotion",
+                "SELECT * FROM room_ambience",
+                "SELECT * FROM room_power",
+                "SELECT * FROM power_grid",
+                "SELECT * FROM morale_summary",
+                "SELECT * FROM mutiny",
+                "SELECT * FROM room_effect",
+                "SELECT * FROM room_water",
+                "SELECT * FROM meal_buffer",
+                "SELECT * FROM garden",
+                "SELECT * FROM air_duct",
+                "SELECT * FROM coolant_loop",
+                "SELECT * FROM stream_action",
+                "SELECT * FROM spotlight",
+                "SELECT * FROM player_objective",
+                "SELECT * FROM advisory",
             ]);
             config.reset_backoff();
             if config.reconnect_attempts > 0 {
@@ -216,3 +264,52 @@ pub fn auto_join_game(
         }
     }
 }
+
+/// Start a ship from the scenario menu while the server has none:
+/// [1]-[6] pick a gallery scenario (the tutorial is [6]), [T] today's daily
+/// ship.
+pub fn new_ship_menu_input(
+    state: Res<ConnectionState>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut ui: ResMut<UiState>,
+) {
+    let conn = match &*state {
+        ConnectionState::Connected(c) => c,
+        _ => return,
+    };
+    if conn.db.ship_config().id().find(&0).is_some() {
+        return;
+    }
+
+    const KEYS: [KeyCode; scenarios::SCENARIOS.len()] = [
+        KeyCode::Digit1,
+        KeyCode::Digit2,
+        KeyCode::Digit3,
+        KeyCode::Digit4,
+        KeyCode::Digit5,
+        KeyCode::Digit6,
+    ];
+    let (label, result) = if let Some(i) = KEYS.iter().position(|k| keyboard.just_pressed(*k)) {
+        let scenario = &scenarios::SCENARIOS[i];
+        (
+            scenario.name,
+            conn.reducers().init_scenario(scenario.id.to_string()),
+        )
+    } else if keyboard.just_pressed(KeyCode::KeyT) {
+        ("the daily ship", conn.reducers().init_daily_ship())
+    } else {
+        return;
+    };
+    let message = match result {
+        Ok(()) => format!("Generating {}...", label),
+        Err(e) => {
+            error!("Failed to start a ship: {:?}", e);
+            "Failed to start a ship".into()
+        }
+    };
+    ui.toasts.push(Toast {
+        message,
+        color: bevy::color::Color::srgb(0.3, 0.8, 1.0),
+        timer: 5.0,
+    });
+}
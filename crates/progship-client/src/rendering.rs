@@ -4,15 +4,20 @@
 
 use bevy::prelude::*;
 use progship_client_sdk::*;
-use progship_logic::constants::{room_type_icon, room_types};
+use progship_constants::{
+    activity_types, door_states, effect_kinds, emotions, postures, robot_states, room_type_icon,
+    room_types,
+};
 use progship_logic::movement::decode_cell_rects;
 use spacetimedb_sdk::Table;
 
+use crate::lighting::BaseLight;
 use crate::state::{
     BlinkingLight, ConnectionState, DoorButton, DoorMarker, DoorPanel, DoorPlaque, DustMote,
-    IndicatorEntity, PersonEntity, PlayerState, PulsingEmissive, RoomEntity, RoomLabel, UiState,
-    ViewState,
+    EffectOverlay, IndicatorEntity, PersonEntity, PlayerState, PulsingEmissive, RobotEntity,
+    RoomEntity, RoomLabel, UiState, ViewState,
 };
+use crate::ui::room_label;
 
 /// Add a mesh to assets. When Solari is enabled, generates tangents for deferred GBuffer.
 fn add_mesh(meshes: &mut Assets<Mesh>, mesh: impl Into<Mesh>) -> Handle<Mesh> {
@@ -145,7 +150,7 @@ pub fn sync_rooms(
         if !room_types::is_corridor(room.room_type) {
             let font_size = (room.width.min(room.height) * 2.5).clamp(8.0, 28.0);
             commands.spawn((
-                Text2d::new(&room.name),
+                Text2d::new(room_label(conn, room)),
                 TextFont {
                     font_size,
                     ..default()
@@ -164,7 +169,7 @@ pub fn sync_rooms(
         }
 
         // Shaft interior geometry (ladders, elevator cars)
-        if matches!(room.room_type, 110..=112) {
+        if room_types::is_shaft(room.room_type) {
             spawn_shaft_interior(&mut commands, &mut meshes, &mut materials, room, wh);
         }
 
@@ -172,7 +177,13 @@ pub fn sync_rooms(
         spawn_room_lights(&mut commands, &mut meshes, &mut materials, room);
 
         // Dust motes in atmospheric rooms (engineering, cargo, corridors)
-        if matches!(room.room_type, 60..=71 | 80..=86 | 90..=95 | 100..=102) {
+        if matches!(
+            room.room_type,
+            room_types::ENGINEERING..=room_types::COOLING_PLANT
+                | room_types::HYDROPONICS..=room_types::HVAC_CONTROL
+                | room_types::CARGO_BAY..=room_types::LABORATORY
+                | room_types::CORRIDOR..=room_types::CROSS_CORRIDOR
+        ) {
             spawn_dust_motes(&mut commands, &mut meshes, &mut materials, room, wh);
         }
     }
@@ -246,7 +257,7 @@ pub fn sync_rooms(
         axis_pos: f32,
         width: f32,
         door_id: u64,
-        is_open: bool,
+        state: u8,
     }
     let mut doorway_cuts: Vec<DoorwayCut> = Vec::new();
 
@@ -329,7 +340,7 @@ pub fn sync_rooms(
                 },
                 width: door.width,
                 door_id: door.id,
-                is_open: door.is_open,
+                state: door.state,
             });
         }
     }
@@ -461,7 +472,7 @@ pub fn sync_rooms(
         let deck = room_walls[cut.room_idx].1;
         let rt_a = room_walls[cut.room_idx].2;
         let rt_b = room_walls[cut.other_idx].2;
-        let door_h = progship_logic::constants::deck_heights::door_opening_height(rt_a, rt_b);
+        let door_h = progship_constants::deck_heights::door_opening_height(rt_a, rt_b);
         let wh = rwalls.ceiling_height;
         // Place frame centered between the room's wall and the corridor's wall
         let (fx, fz, horiz) = match cut.wall_side {
@@ -492,7 +503,7 @@ pub fn sync_rooms(
         let open_slide = cut.width / 4.0 + post_w * 0.4; // how far panels slide when open
         for side in [-1.0_f32, 1.0] {
             let closed_offset = side * panel_hw; // center of each half
-            let current_offset = if cut.is_open {
+            let current_offset = if cut.state == door_states::OPEN {
                 closed_offset + side * open_slide
             } else {
                 closed_offset
@@ -537,10 +548,11 @@ pub fn sync_rooms(
         let other_rt = room_walls[cut.other_idx].2;
         if !room_types::is_corridor(room.room_type) && room_types::is_corridor(other_rt) {
             let icon = room_type_icon(room.room_type);
+            let name = room_label(conn, room);
             let label = if icon.is_empty() {
-                room.name.clone()
+                name
             } else {
-                format!("{} {}", icon, room.name)
+                format!("{} {}", icon, name)
             };
 
             // Offset plaque to the right of the door (looking from corridor),
@@ -590,10 +602,11 @@ pub fn sync_rooms(
         let btn_h = 1.2;
         let btn_offset = cut.width / 2.0 + post_w + 0.15;
         let wall_off = 0.01;
-        let btn_color = if cut.is_open {
-            Color::srgb(0.1, 0.8, 0.2)
-        } else {
-            Color::srgb(0.8, 0.1, 0.1)
+        let btn_color = match cut.state {
+            door_states::OPEN => Color::srgb(0.1, 0.8, 0.2),
+            door_states::LOCKED => Color::srgb(0.9, 0.6, 0.1),
+            door_states::WELDED => Color::srgb(0.4, 0.4, 0.45),
+            _ => Color::srgb(0.8, 0.1, 0.1),
         };
         let btn_mat = materials.add(StandardMaterial {
             base_color: btn_color,
@@ -681,7 +694,7 @@ pub fn sync_rooms(
     let win_spacing = 5.0;
 
     for room in &deck_rooms {
-        if room.room_type != 100 || !room.name.starts_with("Ring ") {
+        if room.room_type != room_types::CORRIDOR || !room.name.starts_with("Ring ") {
             continue;
         }
         let re = RoomEntity {
@@ -814,7 +827,7 @@ fn spawn_furniture(
 
     match rt {
         // Bridge / CIC — console desks: slab top + angled screen + leg supports
-        0 | 2 => {
+        room_types::BRIDGE | room_types::CIC => {
             let desk_mat = materials.add(StandardMaterial {
                 base_color: Color::srgb(0.15, 0.15, 0.25),
                 metallic: 0.6,
@@ -868,7 +881,10 @@ fn spawn_furniture(
             }
         }
         // Cabins / Quarters — bed frame + mattress + headboard
-        10 | 14 | 15 | 16 => {
+        room_types::CABIN_SINGLE
+        | room_types::QUARTERS_CREW
+        | room_types::QUARTERS_OFFICER
+        | room_types::QUARTERS_PASSENGER => {
             let frame_mat = materials.add(StandardMaterial {
                 base_color: Color::srgb(0.18, 0.18, 0.22),
                 metallic: 0.7,
@@ -913,7 +929,7 @@ fn spawn_furniture(
             }
         }
         // Cabin Double / Family / VIP — larger bed with frame + mattress + headboard
-        11..=13 => {
+        room_types::CABIN_DOUBLE..=room_types::VIP_SUITE => {
             let frame_mat = materials.add(StandardMaterial {
                 base_color: Color::srgb(0.22, 0.20, 0.28),
                 metallic: 0.6,
@@ -947,7 +963,7 @@ fn spawn_furniture(
             ));
         }
         // Mess Hall / Wardroom / Cafe — table (top + 4 legs) + benches
-        20 | 21 | 25 => {
+        room_types::MESS_HALL | room_types::WARDROOM | room_types::CAFE => {
             let table_mat = materials.add(StandardMaterial {
                 base_color: Color::srgb(0.40, 0.32, 0.22),
                 perceptual_roughness: 0.75,
@@ -1006,7 +1022,7 @@ fn spawn_furniture(
             }
         }
         // Hospital / Surgery / Medbay — bed frame + mattress + side rails
-        30 | 31 | 37 => {
+        room_types::HOSPITAL_WARD | room_types::SURGERY | room_types::MEDBAY => {
             let frame_mat = materials.add(StandardMaterial {
                 base_color: Color::srgb(0.70, 0.72, 0.75),
                 metallic: 0.3,
@@ -1049,7 +1065,7 @@ fn spawn_furniture(
             }
         }
         // Gym — equipment: frame + cylinder bar + weight plates
-        40 => {
+        room_types::GYM => {
             let frame_mat = materials.add(StandardMaterial {
                 base_color: Color::srgb(0.3, 0.3, 0.35),
                 metallic: 0.7,
@@ -1099,7 +1115,7 @@ fn spawn_furniture(
             }
         }
         // Engineering / Reactor — base + body + pipe cylinders
-        60..=63 => {
+        room_types::ENGINEERING..=room_types::ENGINE_ROOM => {
             let body_mat = materials.add(StandardMaterial {
                 base_color: Color::srgb(0.35, 0.25, 0.15),
                 metallic: 0.9,
@@ -1158,7 +1174,7 @@ fn spawn_furniture(
             }
         }
         // Hydroponics — planter troughs with soil + green tops
-        80 => {
+        room_types::HYDROPONICS => {
             let trough_mat = materials.add(StandardMaterial {
                 base_color: Color::srgb(0.3, 0.3, 0.32),
                 metallic: 0.4,
@@ -1203,7 +1219,7 @@ fn spawn_furniture(
             }
         }
         // Cargo Bay — stacked crates of varying sizes
-        90 | 91 => {
+        room_types::CARGO_BAY | room_types::STORAGE => {
             let crate_mat = materials.add(StandardMaterial {
                 base_color: Color::srgb(0.35, 0.30, 0.22),
                 metallic: 0.4,
@@ -1476,7 +1492,7 @@ pub fn sync_door_panels(
             .door()
             .iter()
             .find(|d| d.id == panel.door_id)
-            .is_none_or(|d| d.is_open);
+            .is_none_or(|d| d.state == door_states::OPEN);
 
         let closed_pos = panel.frame_center + panel.side * panel.half_width;
         let open_pos = closed_pos + panel.side * panel.open_offset;
@@ -1620,6 +1636,7 @@ pub fn sync_people(
         // Spawn indicators as children of surviving person entities
         let indicator_mesh = add_mesh(&mut meshes, Sphere::new(0.2));
         let convo_mesh = add_mesh(&mut meshes, Sphere::new(0.3));
+        let bubble_mesh = add_mesh(&mut meshes, Sphere::new(0.25));
         for (entity, pe, _) in existing.iter() {
             let pid = pe.person_id;
             if despawned.contains(&pid) {
@@ -1647,6 +1664,32 @@ pub fn sync_people(
                 }
             }
 
+            // Thought bubble for anyone not simply content
+            if let Some(emotion) = conn
+                .db
+                .emotion()
+                .person_id()
+                .find(&pid)
+                .filter(|e| e.emotion != emotions::CONTENT)
+            {
+                let color = emotion_color(emotion.emotion);
+                let child = commands
+                    .spawn((
+                        Mesh3d(bubble_mesh.clone()),
+                        MeshMaterial3d(materials.add(StandardMaterial {
+                            base_color: color,
+                            emissive: color.to_linear() * emotion.intensity,
+                            ..default()
+                        })),
+                        Transform::from_xyz(-0.5, person_height + 1.3, 0.0),
+                        IndicatorEntity,
+                    ))
+                    .id();
+                if let Ok(mut cmd) = commands.get_entity(entity) {
+                    cmd.add_child(child);
+                }
+            }
+
             if conn.db.in_conversation().person_id().find(&pid).is_some() {
                 let child = commands
                     .spawn((
@@ -1671,117 +1714,141 @@ pub fn sync_people(
     for (_, pe, mut transform) in existing.iter_mut() {
         if let Some(pos) = conn.db.position().person_id().find(&pe.person_id) {
             let is_player = Some(pe.person_id) == player.person_id;
-            let person_height = if is_player { 1.0 } else { 0.8 };
+            let standing_height = if is_player { 1.0 } else { 0.8 };
+            let posture = conn
+                .db
+                .activity()
+                .person_id()
+                .find(&pe.person_id)
+                .map(|a| a.animation_state)
+                .unwrap_or(postures::STANDING);
+            let (person_height, rotation) = posture_pose(posture, standing_height);
             let target = Vec3::new(pos.x, person_height, pos.y);
             let lerp_rate = if is_player { 12.0 } else { 6.0 };
             let t = (lerp_rate * dt).min(1.0);
             transform.translation = transform.translation.lerp(target, t);
+            transform.rotation = transform.rotation.slerp(rotation, t);
         }
     }
 }
 
+/// Capsule center height and tilt for a posture (see `postures`).
+fn posture_pose(posture: u8, standing_height: f32) -> (f32, Quat) {
+    match posture {
+        // Laid flat along X, resting on the bed
+        postures::LYING => (0.45, Quat::from_rotation_z(std::f32::consts::FRAC_PI_2)),
+        // Sunk into the seat
+        postures::SITTING => (standing_height - 0.35, Quat::IDENTITY),
+        // Leaning into the console or workbench
+        postures::WORKING => (standing_height - 0.05, Quat::from_rotation_x(0.2)),
+        _ => (standing_height, Quat::IDENTITY),
+    }
+}
+
 fn room_color(room_type: u8) -> Color {
     match room_type {
-        // Command (dark blue / gold tones) — 0..=8
-        0 => Color::srgb(0.12, 0.15, 0.45), // Bridge
-        1 => Color::srgb(0.18, 0.18, 0.40), // Conference
-        2 => Color::srgb(0.10, 0.12, 0.38), // CIC
-        3 => Color::srgb(0.15, 0.20, 0.42), // Comms Room
-        4 => Color::srgb(0.20, 0.18, 0.45), // Captain's Ready Room
-        5 => Color::srgb(0.35, 0.15, 0.15), // Security Office
-        6 => Color::srgb(0.40, 0.12, 0.12), // Brig
-        7 => Color::srgb(0.22, 0.20, 0.38), // Admin Office
-        8 => Color::srgb(0.15, 0.22, 0.50), // Observatory
-
-        // Habitation (blue / teal tones) — 10..=18
-        10 => Color::srgb(0.20, 0.30, 0.35), // Cabin Single
-        11 => Color::srgb(0.22, 0.32, 0.38), // Cabin Double
-        12 => Color::srgb(0.25, 0.35, 0.42), // Family Suite
-        13 => Color::srgb(0.30, 0.40, 0.48), // VIP Suite
-        14 => Color::srgb(0.18, 0.28, 0.32), // Crew Quarters
-        15 => Color::srgb(0.22, 0.30, 0.38), // Officer Quarters
-        16 => Color::srgb(0.20, 0.32, 0.36), // Passenger Quarters
-        17 => Color::srgb(0.35, 0.45, 0.50), // Shared Bathroom
-        18 => Color::srgb(0.32, 0.42, 0.48), // Shared Laundry
-
-        // Food service (warm yellow / orange) — 20..=27
-        20 => Color::srgb(0.50, 0.40, 0.15), // Mess Hall
-        21 => Color::srgb(0.45, 0.38, 0.18), // Wardroom
-        22 => Color::srgb(0.48, 0.35, 0.12), // Galley
-        23 => Color::srgb(0.30, 0.28, 0.22), // Food Storage Cold
-        24 => Color::srgb(0.35, 0.30, 0.18), // Food Storage Dry
-        25 => Color::srgb(0.52, 0.42, 0.20), // Cafe
-        26 => Color::srgb(0.55, 0.40, 0.18), // Bakery
-        27 => Color::srgb(0.25, 0.35, 0.50), // Water Purification
-
-        // Medical (white / cyan tones) — 30..=37
-        30 => Color::srgb(0.60, 0.65, 0.70), // Hospital Ward
-        31 => Color::srgb(0.55, 0.60, 0.68), // Surgery
-        32 => Color::srgb(0.58, 0.62, 0.65), // Dental Clinic
-        33 => Color::srgb(0.50, 0.55, 0.62), // Pharmacy
-        34 => Color::srgb(0.52, 0.58, 0.60), // Mental Health
-        35 => Color::srgb(0.55, 0.50, 0.50), // Quarantine
-        36 => Color::srgb(0.35, 0.30, 0.30), // Morgue
-        37 => Color::srgb(0.58, 0.63, 0.68), // Medbay
-
-        // Recreation (green tones) — 40..=56
-        40 => Color::srgb(0.25, 0.45, 0.25), // Gym
-        41 => Color::srgb(0.35, 0.30, 0.40), // Theatre
-        42 => Color::srgb(0.30, 0.35, 0.25), // Library
-        43 => Color::srgb(0.38, 0.32, 0.42), // Chapel
-        44 => Color::srgb(0.28, 0.40, 0.30), // Game Room
-        45 => Color::srgb(0.40, 0.30, 0.20), // Bar
-        46 => Color::srgb(0.35, 0.38, 0.30), // Art Studio
-        47 => Color::srgb(0.32, 0.35, 0.38), // Music Room
-        48 => Color::srgb(0.20, 0.35, 0.50), // Holodeck
-        49 => Color::srgb(0.15, 0.50, 0.20), // Arboretum
-        50 => Color::srgb(0.20, 0.30, 0.45), // Observation Lounge
-        51 => Color::srgb(0.25, 0.45, 0.55), // Pool
-        52 => Color::srgb(0.40, 0.45, 0.30), // Nursery
-        53 => Color::srgb(0.35, 0.42, 0.28), // School
-        54 => Color::srgb(0.30, 0.42, 0.32), // Recreation Center
-        55 => Color::srgb(0.32, 0.38, 0.35), // Lounge
-        56 => Color::srgb(0.42, 0.38, 0.25), // Shops
-
-        // Engineering & Propulsion (orange / amber) — 60..=71
-        60 => Color::srgb(0.50, 0.30, 0.10), // Main Engineering
-        61 => Color::srgb(0.55, 0.15, 0.10), // Reactor
-        62 => Color::srgb(0.50, 0.18, 0.12), // Backup Reactor
-        63 => Color::srgb(0.48, 0.28, 0.12), // Engine Room
-        64 => Color::srgb(0.45, 0.32, 0.15), // Power Distribution
-        65 => Color::srgb(0.42, 0.30, 0.18), // Machine Shop
-        66 => Color::srgb(0.38, 0.32, 0.22), // Electronics Lab
-        67 => Color::srgb(0.35, 0.28, 0.18), // Parts Storage
-        68 => Color::srgb(0.45, 0.20, 0.10), // Fuel Storage
-        69 => Color::srgb(0.40, 0.30, 0.20), // Robotics Bay
-        70 => Color::srgb(0.42, 0.28, 0.15), // Maintenance Bay
-        71 => Color::srgb(0.38, 0.35, 0.25), // Cooling Plant
-
-        // Life support (teal / cyan) — 80..=86
-        80 => Color::srgb(0.15, 0.45, 0.20), // Hydroponics
-        81 => Color::srgb(0.20, 0.38, 0.45), // Atmosphere Processing
-        82 => Color::srgb(0.18, 0.35, 0.50), // Water Recycling
-        83 => Color::srgb(0.22, 0.32, 0.35), // Waste Processing
-        84 => Color::srgb(0.20, 0.40, 0.42), // Environmental Monitoring
-        85 => Color::srgb(0.22, 0.42, 0.48), // Life Support Center
-        86 => Color::srgb(0.25, 0.38, 0.42), // HVAC Control
-
-        // Cargo & Storage (brown / gray) — 90..=95
-        90 => Color::srgb(0.30, 0.25, 0.18), // Cargo Bay
-        91 => Color::srgb(0.28, 0.25, 0.20), // Storage
-        92 => Color::srgb(0.38, 0.18, 0.15), // Armory
-        93 => Color::srgb(0.32, 0.30, 0.28), // Shuttle Bay
-        94 => Color::srgb(0.50, 0.12, 0.12), // Airlock
-        95 => Color::srgb(0.22, 0.30, 0.45), // Laboratory
-
-        // Infrastructure (dark gray) — 100..=120
-        100 => Color::srgb(0.18, 0.18, 0.22), // Corridor
-        101 => Color::srgb(0.15, 0.15, 0.18), // Service Corridor
-        102 => Color::srgb(0.20, 0.20, 0.24), // Cross Corridor
-        110 => Color::srgb(0.20, 0.35, 0.65), // Elevator Shaft (blue)
-        111 => Color::srgb(0.20, 0.55, 0.30), // Ladder Shaft (green)
-        112 => Color::srgb(0.65, 0.40, 0.15), // Service Elevator (orange)
-        120 => Color::srgb(0.12, 0.12, 0.15), // Service Deck
+        // Command (dark blue / gold tones)
+        room_types::BRIDGE => Color::srgb(0.12, 0.15, 0.45),
+        room_types::CONFERENCE => Color::srgb(0.18, 0.18, 0.40),
+        room_types::CIC => Color::srgb(0.10, 0.12, 0.38),
+        room_types::COMMS_ROOM => Color::srgb(0.15, 0.20, 0.42),
+        room_types::CAPTAINS_READY_ROOM => Color::srgb(0.20, 0.18, 0.45),
+        room_types::SECURITY_OFFICE => Color::srgb(0.35, 0.15, 0.15),
+        room_types::BRIG => Color::srgb(0.40, 0.12, 0.12),
+        room_types::ADMIN_OFFICE => Color::srgb(0.22, 0.20, 0.38),
+        room_types::OBSERVATORY => Color::srgb(0.15, 0.22, 0.50),
+
+        // Habitation (blue / teal tones)
+        room_types::CABIN_SINGLE => Color::srgb(0.20, 0.30, 0.35),
+        room_types::CABIN_DOUBLE => Color::srgb(0.22, 0.32, 0.38),
+        room_types::FAMILY_SUITE => Color::srgb(0.25, 0.35, 0.42),
+        room_types::VIP_SUITE => Color::srgb(0.30, 0.40, 0.48),
+        room_types::QUARTERS_CREW => Color::srgb(0.18, 0.28, 0.32),
+        room_types::QUARTERS_OFFICER => Color::srgb(0.22, 0.30, 0.38),
+        room_types::QUARTERS_PASSENGER => Color::srgb(0.20, 0.32, 0.36),
+        room_types::SHARED_BATHROOM => Color::srgb(0.35, 0.45, 0.50),
+        room_types::SHARED_LAUNDRY => Color::srgb(0.32, 0.42, 0.48),
+
+        // Food service (warm yellow / orange)
+        room_types::MESS_HALL => Color::srgb(0.50, 0.40, 0.15),
+        room_types::WARDROOM => Color::srgb(0.45, 0.38, 0.18),
+        room_types::GALLEY => Color::srgb(0.48, 0.35, 0.12),
+        room_types::FOOD_STORAGE_COLD => Color::srgb(0.30, 0.28, 0.22),
+        room_types::FOOD_STORAGE_DRY => Color::srgb(0.35, 0.30, 0.18),
+        room_types::CAFE => Color::srgb(0.52, 0.42, 0.20),
+        room_types::BAKERY => Color::srgb(0.55, 0.40, 0.18),
+        room_types::WATER_PURIFICATION => Color::srgb(0.25, 0.35, 0.50),
+
+        // Medical (white / cyan tones)
+        room_types::HOSPITAL_WARD => Color::srgb(0.60, 0.65, 0.70),
+        room_types::SURGERY => Color::srgb(0.55, 0.60, 0.68),
+        room_types::DENTAL_CLINIC => Color::srgb(0.58, 0.62, 0.65),
+        room_types::PHARMACY => Color::srgb(0.50, 0.55, 0.62),
+        room_types::MENTAL_HEALTH => Color::srgb(0.52, 0.58, 0.60),
+        room_types::QUARANTINE => Color::srgb(0.55, 0.50, 0.50),
+        room_types::MORGUE => Color::srgb(0.35, 0.30, 0.30),
+        room_types::MEDBAY => Color::srgb(0.58, 0.63, 0.68),
+        room_types::CRYO_BAY => Color::srgb(0.45, 0.58, 0.70),
+
+        // Recreation (green tones)
+        room_types::GYM => Color::srgb(0.25, 0.45, 0.25),
+        room_types::THEATRE => Color::srgb(0.35, 0.30, 0.40),
+        room_types::LIBRARY => Color::srgb(0.30, 0.35, 0.25),
+        room_types::CHAPEL => Color::srgb(0.38, 0.32, 0.42),
+        room_types::GAME_ROOM => Color::srgb(0.28, 0.40, 0.30),
+        room_types::BAR => Color::srgb(0.40, 0.30, 0.20),
+        room_types::ART_STUDIO => Color::srgb(0.35, 0.38, 0.30),
+        room_types::MUSIC_ROOM => Color::srgb(0.32, 0.35, 0.38),
+        room_types::HOLODECK => Color::srgb(0.20, 0.35, 0.50),
+        room_types::ARBORETUM => Color::srgb(0.15, 0.50, 0.20),
+        room_types::OBSERVATION_LOUNGE => Color::srgb(0.20, 0.30, 0.45),
+        room_types::POOL => Color::srgb(0.25, 0.45, 0.55),
+        room_types::NURSERY => Color::srgb(0.40, 0.45, 0.30),
+        room_types::SCHOOL => Color::srgb(0.35, 0.42, 0.28),
+        room_types::RECREATION => Color::srgb(0.30, 0.42, 0.32),
+        room_types::LOUNGE => Color::srgb(0.32, 0.38, 0.35),
+        room_types::SHOPS => Color::srgb(0.42, 0.38, 0.25),
+
+        // Engineering & Propulsion (orange / amber)
+        room_types::ENGINEERING => Color::srgb(0.50, 0.30, 0.10),
+        room_types::REACTOR => Color::srgb(0.55, 0.15, 0.10),
+        room_types::BACKUP_REACTOR => Color::srgb(0.50, 0.18, 0.12),
+        room_types::ENGINE_ROOM => Color::srgb(0.48, 0.28, 0.12),
+        room_types::POWER_DISTRIBUTION => Color::srgb(0.45, 0.32, 0.15),
+        room_types::MACHINE_SHOP => Color::srgb(0.42, 0.30, 0.18),
+        room_types::ELECTRONICS_LAB => Color::srgb(0.38, 0.32, 0.22),
+        room_types::PARTS_STORAGE => Color::srgb(0.35, 0.28, 0.18),
+        room_types::FUEL_STORAGE => Color::srgb(0.45, 0.20, 0.10),
+        room_types::ROBOTICS_BAY => Color::srgb(0.40, 0.30, 0.20),
+        room_types::MAINTENANCE_BAY => Color::srgb(0.42, 0.28, 0.15),
+        room_types::COOLING_PLANT => Color::srgb(0.38, 0.35, 0.25),
+
+        // Life support (teal / cyan)
+        room_types::HYDROPONICS => Color::srgb(0.15, 0.45, 0.20),
+        room_types::ATMOSPHERE_PROCESSING => Color::srgb(0.20, 0.38, 0.45),
+        room_types::WATER_RECYCLING => Color::srgb(0.18, 0.35, 0.50),
+        room_types::WASTE_PROCESSING => Color::srgb(0.22, 0.32, 0.35),
+        room_types::ENV_MONITORING => Color::srgb(0.20, 0.40, 0.42),
+        room_types::LIFE_SUPPORT => Color::srgb(0.22, 0.42, 0.48),
+        room_types::HVAC_CONTROL => Color::srgb(0.25, 0.38, 0.42),
+
+        // Cargo & Storage (brown / gray)
+        room_types::CARGO_BAY => Color::srgb(0.30, 0.25, 0.18),
+        room_types::STORAGE => Color::srgb(0.28, 0.25, 0.20),
+        room_types::ARMORY => Color::srgb(0.38, 0.18, 0.15),
+        room_types::SHUTTLE_BAY => Color::srgb(0.32, 0.30, 0.28),
+        room_types::AIRLOCK => Color::srgb(0.50, 0.12, 0.12),
+        room_types::LABORATORY => Color::srgb(0.22, 0.30, 0.45),
+
+        // Infrastructure (dark gray)
+        room_types::CORRIDOR => Color::srgb(0.18, 0.18, 0.22),
+        room_types::SERVICE_CORRIDOR => Color::srgb(0.15, 0.15, 0.18),
+        room_types::CROSS_CORRIDOR => Color::srgb(0.20, 0.20, 0.24),
+        room_types::CRAWLWAY => Color::srgb(0.12, 0.12, 0.14),
+        room_types::ELEVATOR_SHAFT => Color::srgb(0.20, 0.35, 0.65),
+        room_types::LADDER_SHAFT => Color::srgb(0.20, 0.55, 0.30),
+        room_types::SERVICE_ELEVATOR_SHAFT => Color::srgb(0.65, 0.40, 0.15),
+        room_types::SERVICE_DECK => Color::srgb(0.12, 0.12, 0.15),
 
         _ => Color::srgb(0.25, 0.25, 0.25), // Unknown - neutral gray
     }
@@ -1791,15 +1858,15 @@ fn room_color(room_type: u8) -> Color {
 fn floor_material(color: Color, room_type: u8) -> StandardMaterial {
     let (roughness, metallic) = match room_type {
         // Medical: smooth clinical tile
-        30..=37 => (0.3, 0.0),
+        room_types::HOSPITAL_WARD..=room_types::CRYO_BAY => (0.3, 0.0),
         // Engineering/propulsion: industrial grating
-        60..=71 => (0.65, 0.5),
+        room_types::ENGINEERING..=room_types::COOLING_PLANT => (0.65, 0.5),
         // Hydroponics: slightly damp concrete
-        80..=86 => (0.8, 0.0),
+        room_types::HYDROPONICS..=room_types::HVAC_CONTROL => (0.8, 0.0),
         // Cargo: rough industrial
-        90..=95 => (0.85, 0.15),
+        room_types::CARGO_BAY..=room_types::LABORATORY => (0.85, 0.15),
         // Corridors/infrastructure: worn non-slip
-        100..=120 => (0.75, 0.1),
+        room_types::CORRIDOR..=room_types::SERVICE_DECK => (0.75, 0.1),
         // Habitation/recreation: carpet/composite
         _ => (0.9, 0.0),
     };
@@ -1870,7 +1937,11 @@ fn spawn_floor_markings(
     }
 
     // Hazard striping for dangerous rooms (engineering, cargo, airlock)
-    let is_hazard = matches!(room.room_type, 60..=71 | 90..=94);
+    let is_hazard = matches!(
+        room.room_type,
+        room_types::ENGINEERING..=room_types::COOLING_PLANT
+            | room_types::CARGO_BAY..=room_types::AIRLOCK
+    );
     if is_hazard {
         let yellow = materials.add(StandardMaterial {
             base_color: Color::srgb(0.9, 0.75, 0.0),
@@ -1911,7 +1982,7 @@ fn spawn_shaft_interior(
 
     match room.room_type {
         // Ladder shaft (111) — vertical rails + rungs
-        111 => {
+        room_types::LADDER_SHAFT => {
             let rail_mat = materials.add(StandardMaterial {
                 base_color: Color::srgb(0.25, 0.50, 0.30),
                 metallic: 0.8,
@@ -1950,8 +2021,8 @@ fn spawn_shaft_interior(
             }
         }
         // Elevator shaft (110) / Service elevator (112) — car platform + guide rails
-        110 | 112 => {
-            let car_color = if room.room_type == 110 {
+        room_types::ELEVATOR_SHAFT | room_types::SERVICE_ELEVATOR_SHAFT => {
+            let car_color = if room.room_type == room_types::ELEVATOR_SHAFT {
                 Color::srgb(0.20, 0.30, 0.55)
             } else {
                 Color::srgb(0.55, 0.35, 0.15)
@@ -2041,15 +2112,24 @@ fn spawn_shaft_interior(
 /// Zone stripe color — bright tinted guide strips per zone type.
 fn zone_stripe_color(room_type: u8) -> Color {
     match room_type {
-        0..=8 => Color::srgb(0.3, 0.3, 0.8),    // Command: blue
-        10..=18 => Color::srgb(0.2, 0.5, 0.6),  // Habitation: teal
-        20..=27 => Color::srgb(0.7, 0.55, 0.1), // Food: warm yellow
-        30..=37 => Color::srgb(0.5, 0.8, 0.9),  // Medical: cyan
-        40..=56 => Color::srgb(0.2, 0.6, 0.3),  // Recreation: green
-        60..=71 => Color::srgb(0.8, 0.4, 0.1),  // Engineering: orange
-        80..=86 => Color::srgb(0.1, 0.6, 0.4),  // Life support: teal-green
-        90..=95 => Color::srgb(0.5, 0.4, 0.2),  // Cargo: brown
-        _ => Color::srgb(0.3, 0.3, 0.3),        // Infrastructure: gray
+        // Command: blue
+        room_types::BRIDGE..=room_types::OBSERVATORY => Color::srgb(0.3, 0.3, 0.8),
+        // Habitation: teal
+        room_types::CABIN_SINGLE..=room_types::SHARED_LAUNDRY => Color::srgb(0.2, 0.5, 0.6),
+        // Food: warm yellow
+        room_types::MESS_HALL..=room_types::WATER_PURIFICATION => Color::srgb(0.7, 0.55, 0.1),
+        // Medical: cyan
+        room_types::HOSPITAL_WARD..=room_types::CRYO_BAY => Color::srgb(0.5, 0.8, 0.9),
+        // Recreation: green
+        room_types::GYM..=room_types::SHOPS => Color::srgb(0.2, 0.6, 0.3),
+        // Engineering: orange
+        room_types::ENGINEERING..=room_types::COOLING_PLANT => Color::srgb(0.8, 0.4, 0.1),
+        // Life support: teal-green
+        room_types::HYDROPONICS..=room_types::HVAC_CONTROL => Color::srgb(0.1, 0.6, 0.4),
+        // Cargo: brown
+        room_types::CARGO_BAY..=room_types::LABORATORY => Color::srgb(0.5, 0.4, 0.2),
+        // Infrastructure: gray
+        _ => Color::srgb(0.3, 0.3, 0.3),
     }
 }
 
@@ -2182,6 +2262,10 @@ fn spawn_room_lights(
                     shadows_enabled: !is_corridor && i == 0,
                     ..default()
                 },
+                BaseLight {
+                    color,
+                    brightness: light_intensities[i],
+                },
                 Transform::from_xyz(lx, ly, lz),
                 re.clone(),
             ));
@@ -2192,25 +2276,33 @@ fn spawn_room_lights(
 fn room_light(room_type: u8) -> (Color, f32) {
     match room_type {
         // Command — cool white, bright
-        0..=8 => (Color::srgb(0.85, 0.88, 1.0), 3.0),
+        room_types::BRIDGE..=room_types::OBSERVATORY => (Color::srgb(0.85, 0.88, 1.0), 3.0),
         // Habitation — warm white, moderate
-        10..=16 => (Color::srgb(1.0, 0.92, 0.80), 1.5),
+        room_types::CABIN_SINGLE..=room_types::QUARTERS_PASSENGER => {
+            (Color::srgb(1.0, 0.92, 0.80), 1.5)
+        }
         // Bathrooms/laundry — neutral, bright
-        17..=18 => (Color::srgb(0.95, 0.95, 1.0), 2.0),
+        room_types::SHARED_BATHROOM..=room_types::SHARED_LAUNDRY => {
+            (Color::srgb(0.95, 0.95, 1.0), 2.0)
+        }
         // Food service — warm amber
-        20..=27 => (Color::srgb(1.0, 0.88, 0.65), 2.5),
+        room_types::MESS_HALL..=room_types::WATER_PURIFICATION => {
+            (Color::srgb(1.0, 0.88, 0.65), 2.5)
+        }
         // Medical — clinical white, very bright
-        30..=37 => (Color::srgb(0.95, 0.97, 1.0), 4.0),
+        room_types::HOSPITAL_WARD..=room_types::CRYO_BAY => (Color::srgb(0.95, 0.97, 1.0), 4.0),
         // Recreation — warm daylight
-        40..=56 => (Color::srgb(0.95, 0.92, 0.85), 2.0),
+        room_types::GYM..=room_types::SHOPS => (Color::srgb(0.95, 0.92, 0.85), 2.0),
         // Engineering — deep amber/industrial, darker pools
-        60..=71 => (Color::srgb(1.0, 0.65, 0.25), 1.5),
+        room_types::ENGINEERING..=room_types::COOLING_PLANT => (Color::srgb(1.0, 0.65, 0.25), 1.5),
         // Life support — cyan tint
-        80..=86 => (Color::srgb(0.80, 0.95, 1.0), 2.0),
+        room_types::HYDROPONICS..=room_types::HVAC_CONTROL => (Color::srgb(0.80, 0.95, 1.0), 2.0),
         // Cargo — dim utility, widely spaced
-        90..=95 => (Color::srgb(0.90, 0.85, 0.75), 0.8),
+        room_types::CARGO_BAY..=room_types::LABORATORY => (Color::srgb(0.90, 0.85, 0.75), 0.8),
         // Corridors — neutral cool white, dim
-        100..=115 => (Color::srgb(0.85, 0.88, 0.95), 1.0),
+        room_types::CORRIDOR..=room_types::SERVICE_ELEVATOR_SHAFT => {
+            (Color::srgb(0.85, 0.88, 0.95), 1.0)
+        }
         // Fallback
         _ => (Color::srgb(0.90, 0.90, 0.90), 1.5),
     }
@@ -2218,22 +2310,31 @@ fn room_light(room_type: u8) -> (Color, f32) {
 
 fn activity_indicator_color(activity_type: u8) -> Color {
     match activity_type {
-        0 => Color::srgb(0.4, 0.4, 0.4),  // Idle - gray
-        1 => Color::srgb(0.2, 0.5, 1.0),  // Working - blue
-        2 => Color::srgb(0.9, 0.7, 0.1),  // Eating - yellow
-        3 => Color::srgb(0.1, 0.1, 0.5),  // Sleeping - dark blue
-        4 => Color::srgb(0.9, 0.5, 0.9),  // Socializing - pink
-        5 => Color::srgb(0.3, 0.8, 0.3),  // Relaxing - green
-        6 => Color::srgb(0.5, 0.8, 1.0),  // Hygiene - light blue
-        7 => Color::srgb(1.0, 1.0, 1.0),  // Traveling - white
-        8 => Color::srgb(0.8, 0.5, 0.1),  // Maintenance - orange
-        9 => Color::srgb(0.1, 0.3, 0.8),  // On Duty - navy
-        11 => Color::srgb(1.0, 0.1, 0.1), // Emergency - red
-        12 => Color::srgb(0.1, 0.9, 0.3), // Exercising - bright green
+        activity_types::IDLE => Color::srgb(0.4, 0.4, 0.4), // gray
+        activity_types::WORKING => Color::srgb(0.2, 0.5, 1.0), // blue
+        activity_types::EATING => Color::srgb(0.9, 0.7, 0.1), // yellow
+        activity_types::SLEEPING => Color::srgb(0.1, 0.1, 0.5), // dark blue
+        activity_types::SOCIALIZING => Color::srgb(0.9, 0.5, 0.9), // pink
+        activity_types::RELAXING => Color::srgb(0.3, 0.8, 0.3), // green
+        activity_types::HYGIENE => Color::srgb(0.5, 0.8, 1.0), // light blue
+        activity_types::TRAVELING => Color::srgb(1.0, 1.0, 1.0), // white
+        activity_types::MAINTENANCE => Color::srgb(0.8, 0.5, 0.1), // orange
+        activity_types::ON_DUTY => Color::srgb(0.1, 0.3, 0.8), // navy
+        activity_types::EMERGENCY => Color::srgb(1.0, 0.1, 0.1), // red
+        activity_types::EXERCISING => Color::srgb(0.1, 0.9, 0.3), // bright green
         _ => Color::srgb(0.5, 0.5, 0.5),
     }
 }
 
+fn emotion_color(emotion: u8) -> Color {
+    match emotion {
+        emotions::STRESSED => Color::srgb(1.0, 0.35, 0.1), // red-orange
+        emotions::GRIEVING => Color::srgb(0.35, 0.4, 0.6), // slate blue
+        emotions::EXCITED => Color::srgb(1.0, 0.85, 0.2),  // gold
+        _ => Color::srgb(0.6, 0.8, 0.6),
+    }
+}
+
 /// When Solari is enabled, attach `RaytracingMesh3d` to all mesh entities so they
 /// participate in hardware raytracing (shadows, GI, reflections).
 #[cfg(feature = "solari")]
@@ -2344,6 +2445,226 @@ fn spawn_dust_motes(
     }
 }
 
+/// Tint the floors under smoke, coolant spills and frost on the current
+/// deck, with drifting puffs where there is smoke. Overlays fade with their
+/// effect and go once it clears.
+#[allow(clippy::too_many_arguments)]
+pub fn sync_room_effects(
+    state: Res<ConnectionState>,
+    view: Res<ViewState>,
+    time: Res<Time>,
+    mut timer: Local<f32>,
+    mut commands: Commands,
+    overlays: Query<(Entity, &EffectOverlay, &MeshMaterial3d<StandardMaterial>)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Effects change over sim minutes; 2Hz is plenty
+    *timer += time.delta_secs();
+    if *timer < 0.5 {
+        return;
+    }
+    *timer = 0.0;
+
+    let conn = match &*state {
+        ConnectionState::Connected(c) => c,
+        _ => return,
+    };
+    let effects: Vec<(RoomEffect, Room)> = conn
+        .db
+        .room_effect()
+        .iter()
+        .filter_map(|e| {
+            let room = conn.db.room().id().find(&e.room_id)?;
+            (room.deck == view.current_deck).then_some((e, room))
+        })
+        .collect();
+
+    let mut shown = std::collections::HashSet::new();
+    for (entity, overlay, material) in overlays.iter() {
+        match effects.iter().find(|(e, _)| e.id == overlay.effect_id) {
+            Some((effect, _)) => {
+                shown.insert(effect.id);
+                if let Some(mat) = materials.get_mut(&material.0) {
+                    mat.base_color = effect_color(effect.kind, effect.intensity * overlay.opacity);
+                }
+            }
+            None => {
+                if let Ok(mut cmd) = commands.get_entity(entity) {
+                    cmd.despawn();
+                }
+            }
+        }
+    }
+
+    for (effect, room) in effects.iter().filter(|(e, _)| !shown.contains(&e.id)) {
+        let re = RoomEntity {
+            room_id: room.id,
+            deck: room.deck,
+        };
+        let opacity = 0.5;
+        let tint_mat = materials.add(StandardMaterial {
+            base_color: effect_color(effect.kind, effect.intensity * opacity),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..default()
+        });
+        for (cuboid, pos) in cell_mask_floor_mesh(room, 0.02) {
+            commands.spawn((
+                Mesh3d(add_mesh(&mut meshes, cuboid)),
+                MeshMaterial3d(tint_mat.clone()),
+                Transform::from_translation(pos + Vec3::Y * 0.125),
+                EffectOverlay {
+                    effect_id: effect.id,
+                    opacity,
+                },
+                re.clone(),
+            ));
+        }
+        if effect.kind == effect_kinds::SMOKE {
+            spawn_smoke_puffs(&mut commands, &mut meshes, &mut materials, effect, room);
+        }
+    }
+}
+
+/// Draw the maintenance robots on the current deck in the rooms they are
+/// in, lined up along one wall and colored by what they are about.
+pub fn sync_robots(
+    state: Res<ConnectionState>,
+    view: Res<ViewState>,
+    time: Res<Time>,
+    mut timer: Local<f32>,
+    mut commands: Commands,
+    existing: Query<Entity, With<RobotEntity>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Robots move between rooms over sim minutes; 2Hz is plenty
+    *timer += time.delta_secs();
+    if *timer < 0.5 {
+        return;
+    }
+    *timer = 0.0;
+
+    let conn = match &*state {
+        ConnectionState::Connected(c) => c,
+        _ => return,
+    };
+    for entity in existing.iter() {
+        if let Ok(mut cmd) = commands.get_entity(entity) {
+            cmd.despawn();
+        }
+    }
+
+    let body = add_mesh(&mut meshes, Cuboid::new(0.6, 0.5, 0.6));
+    let mut robots: Vec<Robot> = conn.db.robot().iter().collect();
+    robots.sort_by_key(|r| r.id);
+    let mut per_room: std::collections::HashMap<u32, u32> = std::collections::HashMap::new();
+    for robot in robots {
+        let Some(room) = conn.db.room().id().find(&robot.room_id) else {
+            continue;
+        };
+        if room.deck != view.current_deck {
+            continue;
+        }
+        let slot = per_room.entry(room.id).or_default();
+        let across = (room.width - 1.0).max(0.0);
+        let offset = if across > 0.0 {
+            (*slot as f32 * 0.9) % across
+        } else {
+            0.0
+        };
+        *slot += 1;
+        let material = materials.add(StandardMaterial {
+            base_color: robot_color(robot.state),
+            metallic: 0.6,
+            perceptual_roughness: 0.4,
+            ..default()
+        });
+        commands.spawn((
+            Mesh3d(body.clone()),
+            MeshMaterial3d(material),
+            Transform::from_xyz(
+                room.x - across / 2.0 + offset,
+                0.35,
+                room.y + room.height / 2.0 - 0.6,
+            ),
+            RobotEntity { robot_id: robot.id },
+        ));
+    }
+}
+
+/// Body color of a robot by its state (see `robot_states`).
+fn robot_color(state: u8) -> Color {
+    match state {
+        robot_states::WORKING => Color::srgb(0.95, 0.65, 0.15),
+        robot_states::CHARGING => Color::srgb(0.3, 0.6, 0.95),
+        robot_states::BROKEN => Color::srgb(0.8, 0.2, 0.2),
+        _ => Color::srgb(0.7, 0.72, 0.75),
+    }
+}
+
+/// Spawn slow-rising smoke puffs for a smoke effect, animated like dust motes.
+fn spawn_smoke_puffs(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    effect: &RoomEffect,
+    room: &Room,
+) {
+    let opacity = 0.6;
+    let puff_mat = materials.add(StandardMaterial {
+        base_color: effect_color(effect.kind, effect.intensity * opacity),
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        ..default()
+    });
+    let puff = add_mesh(meshes, Sphere::new(0.4));
+    let count = (((room.width * room.height) / 6.0).floor() as i32).clamp(3, 16);
+    let mut seed = (effect.id as u32).wrapping_mul(48271).max(1);
+    let mut next = || {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        (seed & 0xFFFF) as f32 / 65536.0
+    };
+    let hw = room.width / 2.0 - 0.5;
+    let hh = room.height / 2.0 - 0.5;
+    for _ in 0..count {
+        let x = room.x + (next() - 0.5) * 2.0 * hw;
+        let z = room.y + (next() - 0.5) * 2.0 * hh;
+        let age = next();
+        commands.spawn((
+            Mesh3d(puff.clone()),
+            MeshMaterial3d(puff_mat.clone()),
+            Transform::from_xyz(x, 0.5 + age * 2.0, z),
+            DustMote {
+                drift: Vec3::new((next() - 0.5) * 0.1, 0.15, (next() - 0.5) * 0.1),
+                lifetime: 14.0,
+                age: age * 14.0,
+            },
+            EffectOverlay {
+                effect_id: effect.id,
+                opacity,
+            },
+            RoomEntity {
+                room_id: room.id,
+                deck: room.deck,
+            },
+        ));
+    }
+}
+
+/// Overlay color for a room effect, at `alpha`.
+fn effect_color(kind: u8, alpha: f32) -> Color {
+    let alpha = alpha.clamp(0.0, 1.0);
+    match kind {
+        effect_kinds::SMOKE => Color::srgba(0.35, 0.35, 0.38, alpha),
+        effect_kinds::COOLANT_SPILL => Color::srgba(0.2, 0.8, 0.7, alpha),
+        _ => Color::srgba(0.85, 0.93, 1.0, alpha),
+    }
+}
+
 /// Animate dust motes: drift upward and wrap when lifetime expires.
 pub fn animate_dust_motes(time: Res<Time>, mut query: Query<(&mut DustMote, &mut Transform)>) {
     let dt = time.delta_secs();
@@ -4,6 +4,7 @@
 
 use bevy::prelude::*;
 use progship_client_sdk::DbConnection;
+use progship_logic::encyclopedia::{self, Entry};
 
 // ============================================================================
 // RESOURCES
@@ -139,8 +140,13 @@ impl Default for PlayerState {
 pub struct UiState {
     pub selected_person: Option<u64>,
     pub show_ship_overview: bool,
+    /// Shipopedia entries, and the one on screen while it is open (F1)
+    pub encyclopedia: Vec<Entry>,
+    pub encyclopedia_page: Option<usize>,
     pub toasts: Vec<Toast>,
     pub last_event_count: usize,
+    /// Newest stream action already announced
+    pub last_stream_action: u64,
 }
 
 impl Default for UiState {
@@ -148,8 +154,11 @@ impl Default for UiState {
         Self {
             selected_person: None,
             show_ship_overview: false,
+            encyclopedia: encyclopedia::entries(),
+            encyclopedia_page: None,
             toasts: Vec::new(),
             last_event_count: 0,
+            last_stream_action: 0,
         }
     }
 }
@@ -236,6 +245,19 @@ pub struct PulsingEmissive {
     pub max_mul: f32, // maximum emissive multiplier
 }
 
+/// Floor tint or smoke puff showing a room effect; fades with it.
+#[derive(Component)]
+pub struct EffectOverlay {
+    pub effect_id: u64,
+    pub opacity: f32, // alpha at full intensity
+}
+
+/// A maintenance robot, drawn as a squat box in the room it is in.
+#[derive(Component)]
+pub struct RobotEntity {
+    pub robot_id: u64,
+}
+
 /// Floating dust mote particle — drifts slowly upward and fades.
 #[derive(Component)]
 pub struct DustMote {
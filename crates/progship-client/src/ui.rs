@@ -4,6 +4,24 @@
 
 use bevy::prelude::*;
 use progship_client_sdk::*;
+use progship_constants::{
+    activity_types, advisory_levels, certifications, contact_kinds, conversation_topics,
+    departments, difficulties, emotions, eva_phases, event_types, factions, item_kinds,
+    life_stages, lighting_modes, milestone_kinds, morale_moods, mutiny_demands, part_kinds,
+    patient_states, ranks, robot_states, room_types, shifts, surgery_outcomes, system_statuses,
+    triage_levels, voyage_phases, work_order_states,
+};
+use progship_logic::arboretum;
+use progship_logic::backstory;
+use progship_logic::bar::{is_impaired, DEPENDENT};
+use progship_logic::economy::u8_to_rationing;
+use progship_logic::hvac;
+use progship_logic::morale;
+use progship_logic::nicknames;
+use progship_logic::objectives;
+use progship_logic::scenarios;
+use progship_logic::thermal::OVERHEAT_C;
+use progship_logic::timeline::{self, TimelineKind};
 use spacetimedb_sdk::Table;
 
 use crate::state::{
@@ -46,7 +64,7 @@ pub fn setup_ui(mut commands: Commands) {
         NeedsBar,
     ));
 
-    // Info panel (right side — room info, selected NPC, or ship overview)
+    // Info panel (right side — room info, selected NPC, ship overview or shipopedia)
     commands.spawn((
         Text::new(""),
         TextFont {
@@ -142,9 +160,17 @@ pub fn render_hud(
         let person_count = conn.db.person().count();
         let active_events: Vec<_> = conn.db.event().iter().filter(|e| e.state != 2).collect();
 
-        let (ship_name, sim_time, time_scale, paused) = match config {
-            Some(c) => (c.name.clone(), c.sim_time, c.time_scale, c.paused),
-            None => ("No Ship".into(), 0.0, 1.0, false),
+        let (ship_name, sim_time, time_scale, paused, pending_decks, pending_people) = match &config
+        {
+            Some(c) => (
+                c.name.clone(),
+                c.sim_time,
+                c.time_scale,
+                c.paused,
+                c.pending_decks,
+                c.pending_people,
+            ),
+            None => ("No Ship".into(), 0.0, 1.0, false, 0, 0),
         };
 
         let hours = sim_time % 24.0;
@@ -152,7 +178,15 @@ pub fn render_hud(
         let h = hours as u32;
         let m = ((hours - h as f64) * 60.0) as u32;
 
-        let pause_str = if paused { " [PAUSED]" } else { "" };
+        let pause_str = if pending_decks > 0 {
+            format!(" [GENERATING: {} decks left]", pending_decks)
+        } else if pending_people > 0 {
+            format!(" [BOARDING: {} people left]", pending_people)
+        } else if paused {
+            " [PAUSED]".into()
+        } else {
+            String::new()
+        };
         let event_str = if !active_events.is_empty() {
             format!(" | {} EVENTS", active_events.len())
         } else {
@@ -173,9 +207,22 @@ pub fn render_hud(
                         .id()
                         .find(&0)
                         .map(|c| c.deck_count as i32);
+                    let mut hint = context_action_hint(r.room_type, Some(r.deck), total_decks);
+                    let dry = conn
+                        .db
+                        .room_water()
+                        .room_id()
+                        .find(&r.id)
+                        .is_some_and(|w| !w.supplied);
+                    if dry {
+                        hint.push_str(" (no water)");
+                    }
+                    if let Some(garden) = conn.db.garden().room_id().find(&r.id) {
+                        hint.push_str(&format!(" (plants {:.0}%)", garden.health * 100.0));
+                    }
                     (
-                        r.name.clone(),
-                        context_action_hint(r.room_type, Some(r.deck), total_decks),
+                        room_label(conn, &r),
+                        hint,
                         format!("({:.0},{:.0})", pos.x, pos.y),
                     )
                 })
@@ -186,15 +233,11 @@ pub fn render_hud(
         let activity_str = player
             .person_id
             .and_then(|pid| conn.db.activity().person_id().find(&pid))
-            .map(|a| format!(" ({})", activity_name(a.activity_type)))
+            .map(|a| format!(" ({})", activity_types::name(a.activity_type)))
             .unwrap_or_default();
 
         // Atmosphere info for current deck
-        let atmo_str = conn
-            .db
-            .deck_atmosphere()
-            .deck()
-            .find(&view.current_deck)
+        let atmo_str = deck_air(conn, view.current_deck)
             .map(|a| {
                 let o2_pct = a.oxygen * 100.0;
                 let temp = a.temperature;
@@ -211,11 +254,44 @@ pub fn render_hud(
             })
             .unwrap_or_default();
 
+        let deck_name = conn
+            .db
+            .deck()
+            .deck()
+            .find(&view.current_deck)
+            .map(|d| deck_label(conn, &d))
+            .unwrap_or_else(|| format!("Deck {}", view.current_deck + 1));
+        // The deck's lights, e.g. "(Evening)"
+        let light_str = conn
+            .db
+            .lighting()
+            .deck()
+            .find(&view.current_deck)
+            .map(|l| format!(" ({})", lighting_modes::name(l.mode)))
+            .unwrap_or_default();
+
+        // Scripted scenarios: the player's objective in progress
+        let objective_str = config
+            .as_ref()
+            .and_then(|c| scenarios::scenario_script(&c.scenario))
+            .zip(
+                player
+                    .person_id
+                    .and_then(|pid| conn.db.player_objective().person_id().find(&pid)),
+            )
+            .map(|(script, progress)| {
+                format!(
+                    "\n{}",
+                    objectives::progress_label(&script.objectives, progress.current as usize)
+                )
+            })
+            .unwrap_or_default();
+
         **text = format!(
             "{} | Day {} {:02}:{:02}{} | {}x{}\n\
-             Deck {} {} | {} | {} aboard | {}\n\
+             {}{} {} | {} | {} aboard | {}\n\
              {}{}\n\
-             [WASD] Move [E] Talk [F]{} [Q] Inspect [M] Map [Space] Pause [Esc] Quit",
+             [WASD] Move [E] Talk [F]{} [Q] Inspect [M] Map [Space] Pause [Esc] Quit{}",
             ship_name,
             day,
             h,
@@ -223,7 +299,8 @@ pub fn render_hud(
             pause_str,
             time_scale,
             event_str,
-            view.current_deck + 1,
+            deck_name,
+            light_str,
             pos_str,
             room_name,
             person_count,
@@ -231,6 +308,7 @@ pub fn render_hud(
             activity_str,
             if activity_str.is_empty() { "" } else { "" },
             context_hint,
+            objective_str,
         );
     }
 
@@ -280,9 +358,11 @@ pub fn render_hud(
                     needs.morale * 100.0,
                 );
             }
+        } else if conn.db.ship_config().id().find(&0).is_none() {
+            **text = new_ship_menu(today());
         } else {
             let join_msg = if player.join_attempts >= 3 {
-                "Failed to join — is the ship initialized?\nRun: spacetime call progship init_ship -- '\"Ship Name\"' 12 200 800 -s <server>"
+                "Failed to join — is the ship initialized?\nRun: spacetime call progship init_ship -- '\"Ship Name\"' 12 200 800 0 0 -s <server>"
             } else if player.joined {
                 &format!("Joining game... ({:.0}s)", player.join_timer)
             } else {
@@ -324,6 +404,24 @@ pub fn render_info_panel(
         return;
     };
 
+    if let Some(entry) = ui.encyclopedia_page.and_then(|i| ui.encyclopedia.get(i)) {
+        // Shipopedia (F1)
+        let section: Vec<_> = ui
+            .encyclopedia
+            .iter()
+            .filter(|e| e.category == entry.category)
+            .collect();
+        let index = section.iter().position(|e| *e == entry).unwrap_or(0);
+        **text = format!(
+            "=== SHIPOPEDIA ===\n{} ({}/{})\n\n{}\n[,/.] entry  [PgUp/PgDn] section  [F1] close",
+            entry.category.label(),
+            index + 1,
+            section.len(),
+            entry.to_text()
+        );
+        return;
+    }
+
     if ui.show_ship_overview {
         // Ship overview (Tab)
         let config = conn.db.ship_config().id().find(&0);
@@ -337,18 +435,133 @@ pub fn render_info_panel(
         }
         overview += &format!("Crew: {}  Passengers: {}\n\n", crew_count, passenger_count);
 
+        // The ship's computer's advisories, the most pressing first
+        let mut advisories: Vec<_> = conn.db.advisory().iter().collect();
+        if !advisories.is_empty() {
+            advisories.sort_by(|a, b| b.priority.total_cmp(&a.priority));
+            overview += &format!("--- Advisories ({}) ---\n", advisories.len());
+            for advisory in advisories.iter().take(5) {
+                overview += &format!(
+                    "[{}] {}\n",
+                    advisory_levels::name(advisory.level),
+                    advisory.message
+                );
+            }
+            overview += "\n";
+        }
+
+        // Morale
+        if let Some(ship) = conn.db.morale_summary().scope().find(&morale::SHIP_WIDE) {
+            overview += &format!(
+                "--- Morale: {:.0}% ({}) ---\n",
+                ship.morale * 100.0,
+                morale_moods::name(ship.mood)
+            );
+            for dept in conn
+                .db
+                .morale_summary()
+                .iter()
+                .filter(|s| s.scope != morale::SHIP_WIDE && s.mood != morale_moods::STEADY)
+            {
+                overview += &format!(
+                    "{}: {:.0}% ({})\n",
+                    departments::name(dept.scope),
+                    dept.morale * 100.0,
+                    morale_moods::name(dept.mood)
+                );
+            }
+            for mutiny in conn.db.mutiny().iter().filter(|m| m.ended_at.is_none()) {
+                overview += &format!(
+                    "MUTINY: {} demands {}{}\n",
+                    departments::name(mutiny.department),
+                    mutiny_demands::name(mutiny.demand),
+                    if mutiny.suppressing_since.is_some() {
+                        " (security sent in)"
+                    } else {
+                        ""
+                    }
+                );
+            }
+            overview += "\n";
+        }
+
         // Resources
         if let Some(res) = conn.db.ship_resources().id().find(&0) {
             overview += &format!("--- Resources ---\n");
             overview += &format!("Power: {:.0}/{:.0}\n", res.power, res.power_cap);
+            if let Some(grid) = conn.db.power_grid().id().find(&0) {
+                overview += &format!(
+                    "Grid:  {:.0}/{:.0} kW{}\n",
+                    grid.supplied_kw,
+                    grid.demand_kw,
+                    if grid.brownout { "  BROWNOUT" } else { "" }
+                );
+            }
             overview += &format!("Food:  {:.0}/{:.0}\n", res.food, res.food_cap);
+            let (meals, meal_cap, cooks) = conn
+                .db
+                .meal_buffer()
+                .iter()
+                .fold((0.0, 0.0, 0), |(m, c, k), b| {
+                    (m + b.meals, c + b.capacity, k + b.cooks)
+                });
+            if meal_cap > 0.0 {
+                overview += &format!("Meals: {:.0}/{:.0} ({} cooking)\n", meals, meal_cap, cooks);
+            }
+            if let Some(config) = conn.db.ship_config().id().find(&0) {
+                if config.rationing_level > 0 {
+                    overview += &format!(
+                        "Rationing: {} for {:.1} days\n",
+                        u8_to_rationing(config.rationing_level).name(),
+                        (config.sim_time - config.rationing_since).max(0.0) / 24.0
+                    );
+                }
+            }
             overview += &format!("Water: {:.0}/{:.0}\n", res.water, res.water_cap);
             overview += &format!("O2:    {:.0}/{:.0}\n", res.oxygen, res.oxygen_cap);
+            // Arboretum plantings and the breathing they take off life support
+            let gardens: Vec<_> = conn.db.garden().iter().collect();
+            if !gardens.is_empty() {
+                let health = gardens.iter().map(|g| g.health).sum::<f32>() / gardens.len() as f32;
+                let breathers: f32 = gardens
+                    .iter()
+                    .map(|g| arboretum::breathers_offset(g.health, g.conditions, g.area))
+                    .sum();
+                overview += &format!(
+                    "Arboretums: {:.0}% plant health, freshening air for {:.0}\n",
+                    health * 100.0,
+                    breathers
+                );
+            }
             overview += &format!("Fuel:  {:.0}/{:.0}\n", res.fuel, res.fuel_cap);
-            overview += &format!(
-                "Parts: {:.0}/{:.0}\n\n",
-                res.spare_parts, res.spare_parts_cap
-            );
+            if let Some(voyage) = conn.db.voyage_state().id().find(&0) {
+                let delta_v = if voyage.delta_v_c.is_finite() {
+                    format!("{:.3}c", voyage.delta_v_c)
+                } else {
+                    "unlimited".to_string()
+                };
+                overview += &format!(
+                    "Voyage: {}, {:.3}/{:.1} ly at {:.4}c, arriving day {:.0}, delta-v {}\n",
+                    voyage_phases::name(voyage.phase),
+                    voyage.covered_ly,
+                    voyage.total_ly,
+                    voyage.velocity_c,
+                    voyage.eta / 24.0 + 1.0,
+                    delta_v
+                );
+            }
+            overview += &format!("Parts: {:.0}/{:.0}\n", res.spare_parts, res.spare_parts_cap);
+            // Stock of each kind against what the ship set out with
+            let mut stocks: Vec<_> = conn.db.part_stock().iter().collect();
+            stocks.sort_by_key(|p| p.kind);
+            for stock in stocks.iter().filter(|p| p.launch_tons > 0.0) {
+                overview += &format!(
+                    "  {}: {:.0}%\n",
+                    part_kinds::name(stock.kind),
+                    stock.mass_tons / stock.launch_tons * 100.0
+                );
+            }
+            overview += "\n";
         }
 
         // Systems
@@ -361,7 +574,7 @@ pub fn render_info_panel(
                 if degraded.len() > 1 { "s" } else { "" }
             );
             for sys in degraded.iter().take(5) {
-                let status = system_status_str(sys.overall_status);
+                let status = system_statuses::label(sys.overall_status);
                 overview += &format!(
                     "{}: {:.0}% [{}]\n",
                     sys.name,
@@ -376,13 +589,13 @@ pub fn render_info_panel(
         if !active_events.is_empty() {
             overview += &format!("--- Events ({}) ---\n", active_events.len());
             for evt in active_events.iter().take(5) {
-                let etype = event_type_name(evt.event_type);
+                let etype = event_types::name(evt.event_type);
                 let room_name = conn
                     .db
                     .room()
                     .id()
                     .find(&evt.room_id)
-                    .map(|r| r.name.clone())
+                    .map(|r| room_label(conn, &r))
                     .unwrap_or("?".into());
                 overview += &format!(
                     "{} in {} [{:.0}%]\n",
@@ -394,11 +607,192 @@ pub fn render_info_panel(
             overview += "\n";
         }
 
-        // Deck atmospheres
+        // Work orders on the board, most urgent first
+        let mut orders: Vec<_> = conn
+            .db
+            .work_order()
+            .iter()
+            .filter(|o| {
+                matches!(
+                    o.state,
+                    work_order_states::OPEN | work_order_states::CLAIMED
+                )
+            })
+            .collect();
+        if !orders.is_empty() {
+            orders.sort_by(|a, b| {
+                b.priority
+                    .total_cmp(&a.priority)
+                    .then(a.created_at.total_cmp(&b.created_at))
+            });
+            overview += &format!("--- Work Orders ({}) ---\n", orders.len());
+            for order in orders.iter().take(5) {
+                let who = order
+                    .assigned_crew_id
+                    .and_then(|id| conn.db.person().id().find(&id))
+                    .map(|p| p.family_name)
+                    .or_else(|| {
+                        order
+                            .assigned_robot_id
+                            .and_then(|id| conn.db.robot().id().find(&id))
+                            .map(|r| r.name)
+                    })
+                    .unwrap_or_else(|| "unclaimed".to_string());
+                overview += &format!("{} [{}]\n", order.reason, who);
+            }
+            overview += "\n";
+        }
+        // Maintenance robots by what they are about
+        let robots: Vec<_> = conn.db.robot().iter().collect();
+        if !robots.is_empty() {
+            let count = |state: u8| robots.iter().filter(|r| r.state == state).count();
+            overview += &format!(
+                "Robots: {} working, {} charging, {} idle, {} broken down\n\n",
+                count(robot_states::WORKING),
+                count(robot_states::CHARGING),
+                count(robot_states::IDLE),
+                count(robot_states::BROKEN)
+            );
+        }
+        let damage = conn.db.hull_damage().count();
+        let outside = conn.db.eva_mission().count();
+        if damage > 0 || outside > 0 {
+            overview += &format!("Hull damage: {} spots, {} crew on EVA\n\n", damage, outside);
+        }
+        let now = conn
+            .db
+            .ship_config()
+            .id()
+            .find(&0)
+            .map_or(0.0, |c| c.sim_time);
+        let spotted: Vec<String> = conn
+            .db
+            .sensor_contact()
+            .iter()
+            .filter(|c| c.spotted_at.is_some())
+            .map(|c| {
+                format!(
+                    "Sensor contact: {}, {:.1}h out\n",
+                    contact_kinds::name(c.kind),
+                    (c.arrives_at - now).max(0.0)
+                )
+            })
+            .collect();
+        if !spotted.is_empty() {
+            overview += &spotted.concat();
+            overview += "\n";
+        }
+
+        // Patients in ward beds and waiting for one, those in quarantine,
+        // counseling and breaking down, and the sleepers in the cryo bays
+        let admitted = conn
+            .db
+            .patient()
+            .iter()
+            .filter(|p| p.state == patient_states::ADMITTED)
+            .count();
+        let waiting = conn.db.patient().count() as usize - admitted;
+        let quarantined = conn.db.quarantine().count();
+        let counseled = conn
+            .db
+            .mental_health()
+            .iter()
+            .filter(|m| m.session_room.is_some())
+            .count();
+        let breaking_down = conn
+            .db
+            .mental_health()
+            .iter()
+            .filter(|m| m.breakdown_until.is_some())
+            .count();
+        let reviving = conn
+            .db
+            .hibernation()
+            .iter()
+            .filter(|h| h.reviving_since.is_some())
+            .count();
+        let asleep = conn.db.hibernation().count() as usize - reviving;
+        if admitted + waiting > 0
+            || quarantined > 0
+            || counseled + breaking_down > 0
+            || asleep + reviving > 0
+        {
+            overview += &format!(
+                "--- Medical ---\n{} admitted, {} waiting for a bed\n",
+                admitted, waiting
+            );
+            let operating = conn
+                .db
+                .surgery()
+                .iter()
+                .filter(|s| s.finished_at.is_none())
+                .count();
+            if operating > 0 {
+                overview += &format!("{} booked for surgery\n", operating);
+            }
+            if quarantined > 0 {
+                overview += &format!("{} in quarantine\n", quarantined);
+            }
+            if counseled + breaking_down > 0 {
+                overview += &format!(
+                    "{} in counseling, {} breaking down\n",
+                    counseled, breaking_down
+                );
+            }
+            if asleep + reviving > 0 {
+                overview += &format!("{} in cryosleep, {} reviving\n", asleep, reviving);
+            }
+            overview += "\n";
+        }
+
+        // The drunk and those dependent on drink
+        let drunk = conn
+            .db
+            .drinking()
+            .iter()
+            .filter(|d| is_impaired(d.intoxication))
+            .count();
+        let dependent = conn
+            .db
+            .drinking()
+            .iter()
+            .filter(|d| d.dependence >= DEPENDENT)
+            .count();
+        if drunk + dependent > 0 {
+            overview += &format!(
+                "--- Bars ---\n{} drunk, {} dependent on drink\n\n",
+                drunk, dependent
+            );
+        }
+
+        // Milestones, in the order they were reached
+        let mut milestones: Vec<_> = conn.db.milestone().iter().collect();
+        if !milestones.is_empty() {
+            milestones.sort_by(|a, b| a.achieved_at.total_cmp(&b.achieved_at));
+            overview += "--- Milestones ---\n";
+            for m in &milestones {
+                overview += &format!(
+                    "Day {}: {}",
+                    (m.achieved_at / 24.0) as u32 + 1,
+                    milestone_kinds::name(m.kind)
+                );
+                if !m.detail.is_empty() {
+                    overview += &format!(" ({})", m.detail);
+                }
+                overview += "\n";
+            }
+            overview += "\n";
+        }
+
+        // Deck atmospheres, averaged over their rooms
         overview += "--- Atmosphere ---\n";
         for deck_idx in 0..6 {
-            if let Some(atmo) = conn.db.deck_atmosphere().deck().find(&deck_idx) {
-                let warn = if atmo.oxygen < 0.19 { " !" } else { "" };
+            if let Some(atmo) = deck_air(conn, deck_idx) {
+                let warn = if atmo.stale_rooms > 0 {
+                    format!(" ! {} rooms stale", atmo.stale_rooms)
+                } else {
+                    String::new()
+                };
                 overview += &format!(
                     "Dk{}: O2:{:.0}% {:.0}C {:.0}kPa{}\n",
                     deck_idx + 1,
@@ -409,6 +803,32 @@ pub fn render_info_panel(
                 );
             }
         }
+        let ducts: Vec<_> = conn.db.air_duct().iter().collect();
+        if !ducts.is_empty() {
+            let airflow = ducts.iter().map(|d| d.airflow).sum::<f32>() / ducts.len() as f32;
+            let fouled = ducts
+                .iter()
+                .filter(|d| hvac::needs_cleaning(d.fouling))
+                .count();
+            let failed = ducts.iter().filter(|d| d.airflow <= 0.0).count();
+            overview += &format!(
+                "HVAC: {:.0}% airflow, {} ducts fouled, {} carrying nothing\n",
+                airflow * 100.0,
+                fouled,
+                failed
+            );
+        }
+        if let Some(coolant) = conn.db.coolant_loop().id().find(&0) {
+            let warn = if coolant.temperature > OVERHEAT_C {
+                " OVERHEATING"
+            } else {
+                ""
+            };
+            overview += &format!(
+                "Coolant: {:.0}C, {:.0} kW in, {:.0} kW radiated{}\n",
+                coolant.temperature, coolant.absorbed_kw, coolant.radiated_kw, warn
+            );
+        }
 
         **text = overview;
         return;
@@ -423,14 +843,26 @@ pub fn render_info_panel(
 
         let mut info = format!("=== {} {} ===\n", person.given_name, person.family_name);
         info += if person.is_crew { "Crew" } else { "Passenger" };
+        info += &format!(
+            " - {}, age {}",
+            life_stages::name(person.life_stage),
+            person.age
+        );
 
         if let Some(crew) = conn.db.crew().person_id().find(&selected_id) {
             info += &format!(
                 "\n{} - {}\nShift: {}\n",
-                department_name(crew.department),
-                rank_name(crew.rank),
-                shift_name(crew.shift)
+                departments::name(crew.department),
+                ranks::name(crew.rank),
+                shifts::name(crew.shift)
             );
+            if let Some(career) = conn.db.career().person_id().find(&selected_id) {
+                info += &format!(
+                    "Certified: {} ({:.0} hours served)\n",
+                    certifications::name(career.certification),
+                    career.service_hours
+                );
+            }
         }
         if let Some(passenger) = conn.db.passenger().person_id().find(&selected_id) {
             info += &format!(
@@ -439,6 +871,14 @@ pub fn render_info_panel(
             );
         }
 
+        if let Some(emotion) = conn.db.emotion().person_id().find(&selected_id) {
+            info += &format!(
+                "\nMood: {} ({:.0}%)\n",
+                emotions::name(emotion.emotion),
+                emotion.intensity * 100.0
+            );
+        }
+
         if let Some(needs) = conn.db.needs().person_id().find(&selected_id) {
             info += &format!("\n--- Needs ---\n");
             info += &format!(
@@ -457,14 +897,229 @@ pub fn render_info_panel(
                 needs.hygiene * 100.0
             );
         }
+        if let Some(dose) = conn.db.radiation_dose().person_id().find(&selected_id) {
+            info += &format!(
+                "Dose: {:.0} mSv ({:.0} recent)\n",
+                dose.lifetime_msv, dose.acute_msv
+            );
+        }
+
+        if let Some(pregnancy) = conn.db.pregnancy().person_id().find(&selected_id) {
+            let now = conn
+                .db
+                .ship_config()
+                .id()
+                .find(&0)
+                .map_or(0.0, |c| c.sim_time);
+            info += &format!(
+                "Expecting: due in {:.0} days\n",
+                ((pregnancy.due_at - now) / 24.0).max(0.0)
+            );
+        }
+
+        if let Some(member) = conn.db.faction_membership().person_id().find(&selected_id) {
+            info += &format!(
+                "Faction: {} (loyalty {:.0}%)\n",
+                factions::name(member.faction),
+                member.loyalty * 100.0
+            );
+        }
+
+        let latest_election = conn.db.candidate().iter().map(|c| c.election_id).max();
+        if let Some(seat) = conn.db.candidate().iter().find(|c| {
+            c.person_id == selected_id && c.elected && Some(c.election_id) == latest_election
+        }) {
+            info += &format!("Councillor: elected with {} votes\n", seat.votes);
+        }
+
+        if let Some(order) = conn.db.work_order().iter().find(|o| {
+            o.state == work_order_states::CLAIMED && o.assigned_crew_id == Some(selected_id)
+        }) {
+            info += &format!(
+                "Working on: {} ({:.0}%)\n",
+                order.reason,
+                order.progress * 100.0
+            );
+        }
+        if let Some(eva) = conn.db.eva_mission().person_id().find(&selected_id) {
+            info += &format!(
+                "EVA: {}, suit O2 {:.1} h\n",
+                eva_phases::name(eva.phase),
+                eva.oxygen_hours.max(0.0)
+            );
+        }
+
+        if let Some(patient) = conn.db.patient().person_id().find(&selected_id) {
+            let triage = triage_levels::name(patient.triage);
+            match patient.room_id.and_then(|id| conn.db.room().id().find(&id)) {
+                Some(ward) if patient.state == patient_states::ADMITTED => {
+                    info += &format!("Patient: {}, admitted to {}", triage, ward.name);
+                    if let Some(medic) = patient
+                        .medic_id
+                        .and_then(|id| conn.db.person().id().find(&id))
+                    {
+                        info += &format!(" (under {})", medic.family_name);
+                    }
+                    info += "\n";
+                }
+                _ => info += &format!("Patient: {}, waiting for a bed\n", triage),
+            }
+        }
+
+        if let Some(surgery) = conn.db.surgery().person_id().find(&selected_id) {
+            let surgeon = conn
+                .db
+                .person()
+                .id()
+                .find(&surgery.surgeon_id)
+                .map_or("a surgeon".to_string(), |p| p.family_name);
+            match (surgery.outcome, surgery.started_at) {
+                (Some(outcome), _) => {
+                    info += &format!(
+                        "Surgery: {} (by {})\n",
+                        surgery_outcomes::name(outcome),
+                        surgeon
+                    )
+                }
+                (None, Some(_)) => info += &format!("Surgery: under way (by {})\n", surgeon),
+                (None, None) => info += &format!("Surgery: booked with {}\n", surgeon),
+            }
+        }
+        if let Some(surgery) = conn
+            .db
+            .surgery()
+            .iter()
+            .find(|s| s.surgeon_id == selected_id && s.finished_at.is_none())
+        {
+            let patient = conn
+                .db
+                .person()
+                .id()
+                .find(&surgery.person_id)
+                .map_or("a patient".to_string(), |p| p.family_name);
+            info += &format!("Operating on: {}\n", patient);
+        }
+
+        if let Some(quarantine) = conn.db.quarantine().person_id().find(&selected_id) {
+            let now = conn
+                .db
+                .ship_config()
+                .id()
+                .find(&0)
+                .map_or(0.0, |c| c.sim_time);
+            let room = conn.db.room().id().find(&quarantine.room_id);
+            info += &format!(
+                "Quarantined: {} for {:.0} hours\n",
+                room.map_or("quarantine".to_string(), |r| r.name),
+                (now - quarantine.flagged_at).max(0.0)
+            );
+        }
+
+        if let Some(mind) = conn.db.mental_health().person_id().find(&selected_id) {
+            info += &format!("Stress: {:.0}%", mind.stress * 100.0);
+            if mind.breakdown_until.is_some() {
+                info += " (breaking down)";
+            } else if let Some(room) = mind.session_room {
+                let room = conn.db.room().id().find(&room);
+                info += &format!(
+                    " (counseling in {})",
+                    room.map_or("Mental Health".to_string(), |r| r.name)
+                );
+            }
+            if mind.breakdowns > 0 {
+                info += &format!(", {} breakdowns", mind.breakdowns);
+            }
+            info += "\n";
+        }
+
+        if let Some(drinking) = conn.db.drinking().person_id().find(&selected_id) {
+            info += &format!("Drinks: {} had", drinking.drinks);
+            if is_impaired(drinking.intoxication) {
+                info += ", drunk";
+            }
+            if drinking.dependence >= DEPENDENT {
+                info += ", dependent";
+            }
+            info += "\n";
+        }
+
+        let mut items: Vec<_> = conn
+            .db
+            .inventory_item()
+            .iter()
+            .filter(|i| i.owner_id == selected_id)
+            .collect();
+        if !items.is_empty() {
+            items.sort_by_key(|i| i.id);
+            let carried: Vec<String> = items
+                .iter()
+                .map(|i| match i.stolen_from {
+                    Some(_) => format!("{} (stolen)", item_kinds::name(i.kind)),
+                    None => item_kinds::name(i.kind).to_string(),
+                })
+                .collect();
+            info += &format!("Carrying: {}\n", carried.join(", "));
+        }
+        if let Some(wallet) = conn.db.wallet().person_id().find(&selected_id) {
+            info += &format!(
+                "Credits: {} (earned {}, spent {})\n",
+                wallet.credits, wallet.earned, wallet.spent
+            );
+        }
+
+        if let Some(teacher) = conn.db.teacher().person_id().find(&selected_id) {
+            let room = conn.db.room().id().find(&teacher.room_id);
+            info += &format!(
+                "Teacher: {}\n",
+                room.map_or("classroom".to_string(), |r| r.name)
+            );
+        }
+        if let Some(schooling) = conn.db.schooling().person_id().find(&selected_id) {
+            info += &format!(
+                "School: {:.0} hours in class ({:.0} taught)\n",
+                schooling.hours, schooling.taught_hours
+            );
+        }
+
+        if let Some(detention) = conn.db.detention().person_id().find(&selected_id) {
+            let now = conn
+                .db
+                .ship_config()
+                .id()
+                .find(&0)
+                .map_or(0.0, |c| c.sim_time);
+            info += &format!(
+                "Detained: released in {:.0} hours\n",
+                (detention.until - now).max(0.0)
+            );
+        }
+
+        let mourning: Vec<String> = conn
+            .db
+            .grief()
+            .iter()
+            .filter(|g| g.person_id == selected_id)
+            .filter_map(|g| conn.db.person().id().find(&g.deceased_id))
+            .map(|p| format!("{} {}", p.given_name, p.family_name))
+            .collect();
+        if !mourning.is_empty() {
+            info += &format!("Mourning: {}\n", mourning.join(", "));
+        }
+
+        if let Some(traits) = conn.db.person_trait().person_id().find(&selected_id) {
+            info += &format!("Traits: {}\n", backstory::describe(traits.traits));
+        }
 
         if let Some(activity) = conn.db.activity().person_id().find(&selected_id) {
-            info += &format!("\nActivity: {}\n", activity_name(activity.activity_type));
+            info += &format!(
+                "\nActivity: {}\n",
+                activity_types::name(activity.activity_type)
+            );
         }
 
         if let Some(pos) = conn.db.position().person_id().find(&selected_id) {
             if let Some(room) = conn.db.room().id().find(&pos.room_id) {
-                info += &format!("Location: {}\n", room.name);
+                info += &format!("Location: {}\n", room_label(conn, &room));
             }
         }
 
@@ -486,11 +1141,53 @@ pub fn render_info_panel(
                 info += &format!(
                     "\nTalking to: {}\nTopic: {}\n",
                     other_name,
-                    topic_name(conv.topic)
+                    conversation_topics::name(conv.topic)
                 );
             }
         }
 
+        // Why the current activity won, best candidates first
+        if let Some(explanation) = conn
+            .db
+            .activity_explanation()
+            .person_id()
+            .find(&selected_id)
+        {
+            info += "\n--- Why ---\n";
+            for line in explanation.candidates.iter().take(4) {
+                info += &format!("{}\n", line);
+            }
+        }
+
+        // Recent history, newest first
+        if let Some(history) = conn.db.person_timeline().person_id().find(&selected_id) {
+            let entries = timeline::entries(&history.entries);
+            if !entries.is_empty() {
+                info += "\n--- Today ---\n";
+            }
+            for entry in entries.iter().rev().take(10) {
+                let what = match entry.kind {
+                    TimelineKind::Activity => activity_types::name(entry.value as u8).to_string(),
+                    TimelineKind::Location => conn
+                        .db
+                        .room()
+                        .id()
+                        .find(&entry.value)
+                        .map(|r| format!("-> {}", room_label(conn, &r)))
+                        .unwrap_or("-> ?".into()),
+                    TimelineKind::Conversation => conn
+                        .db
+                        .person()
+                        .id()
+                        .find(&(entry.value as u64))
+                        .map(|p| format!("Talked to {} {}", p.given_name, p.family_name))
+                        .unwrap_or("Talked to ?".into()),
+                };
+                let minute = entry.minute % (24 * 60);
+                info += &format!("{:02}:{:02} {}\n", minute / 60, minute % 60, what);
+            }
+        }
+
         **text = info;
         return;
     }
@@ -510,9 +1207,10 @@ pub fn render_info_panel(
     };
 
     let mut info = format!(
-        "=== {} ===\n{}\n\n",
-        room.name,
-        room_type_name(room.room_type)
+        "=== {} ===\n{}{}\n\n",
+        room_label(conn, &room),
+        room_types::name(room.room_type),
+        if room.has_window { " - window" } else { "" }
     );
 
     // People in room
@@ -537,7 +1235,7 @@ pub fn render_info_panel(
                 .activity()
                 .person_id()
                 .find(&p.person_id)
-                .map(|a| format!(" - {}", activity_name(a.activity_type)))
+                .map(|a| format!(" - {}", activity_types::name(a.activity_type)))
                 .unwrap_or_default();
             info += &format!(
                 "  {} {}{}{}\n",
@@ -559,7 +1257,7 @@ pub fn render_info_panel(
     if !subsystems_here.is_empty() {
         info += "\n--- Subsystems ---\n";
         for sub in &subsystems_here {
-            let status = system_status_str(sub.status);
+            let status = system_statuses::label(sub.status);
             info += &format!("{}: {:.0}% [{}]\n", sub.name, sub.health * 100.0, status);
         }
     }
@@ -576,7 +1274,7 @@ pub fn render_info_panel(
         for evt in &events_here {
             info += &format!(
                 "!! {} [{:.0}% severity]\n",
-                event_type_name(evt.event_type),
+                event_types::name(evt.event_type),
                 evt.severity * 100.0
             );
         }
@@ -608,164 +1306,101 @@ pub fn render_toasts(
     }
 }
 
-// Helper functions
-fn activity_name(activity_type: u8) -> &'static str {
-    match activity_type {
-        0 => "Idle",
-        1 => "Working",
-        2 => "Eating",
-        3 => "Sleeping",
-        4 => "Socializing",
-        5 => "Relaxing",
-        6 => "Hygiene",
-        7 => "Traveling",
-        8 => "Maintenance",
-        9 => "On Duty",
-        10 => "Off Duty",
-        11 => "Emergency",
-        12 => "Exercising",
-        _ => "Unknown",
-    }
-}
-
-fn room_type_name(room_type: u8) -> &'static str {
-    match room_type {
-        0 => "Bridge",
-        1 => "Conference Room",
-        2 => "Engineering",
-        3 => "Reactor",
-        4 => "Maintenance Bay",
-        5 => "Quarters",
-        6 => "Crew Quarters",
-        7 => "Officer Quarters",
-        8 => "Passenger Quarters",
-        9 => "Mess Hall",
-        10 => "Galley",
-        11 => "Medical Bay",
-        12 => "Recreation",
-        13 => "Gym",
-        14 => "Cargo Bay",
-        15 => "Storage",
-        16 => "Airlock",
-        17 => "Corridor",
-        18 => "Elevator",
-        19 => "Laboratory",
-        20 => "Observatory",
-        21 => "Life Support",
-        22 => "Hydroponics",
-        23 => "Water Recycling",
-        24 => "Service Corridor",
-        25 => "Elevator Shaft",
-        26 => "Ladder Shaft",
-        27 => "Surgery",
-        28 => "Pharmacy",
-        29 => "Recovery Ward",
-        30 => "Chapel",
-        31 => "Laundry",
-        32 => "Shops",
-        33 => "Lounge",
-        34 => "CIC",
-        35 => "Cooling Plant",
-        36 => "Power Distribution",
-        37 => "HVAC Control",
-        38 => "Parts Storage",
-        39 => "Waste Processing",
-        40 => "Comms Room",
-        _ => "Unknown",
-    }
-}
-
-fn department_name(dept: u8) -> &'static str {
-    match dept {
-        0 => "Command",
-        1 => "Engineering",
-        2 => "Medical",
-        3 => "Science",
-        4 => "Security",
-        5 => "Operations",
-        6 => "Civilian",
-        _ => "Unknown",
-    }
-}
-
-fn rank_name(rank: u8) -> &'static str {
-    match rank {
-        0 => "Crewman",
-        1 => "Specialist",
-        2 => "Petty Officer",
-        3 => "Chief",
-        4 => "Ensign",
-        5 => "Lieutenant",
-        6 => "Commander",
-        7 => "Captain",
-        _ => "Unknown",
-    }
-}
-
-fn shift_name(shift: u8) -> &'static str {
-    match shift {
-        0 => "Alpha (06:00-14:00)",
-        1 => "Beta (14:00-22:00)",
-        2 => "Gamma (22:00-06:00)",
-        _ => "Unknown",
-    }
-}
-
-fn topic_name(topic: u8) -> &'static str {
-    match topic {
-        0 => "Greeting",
-        1 => "Work",
-        2 => "Gossip",
-        3 => "Personal",
-        4 => "Complaint",
-        5 => "Request",
-        6 => "Flirtation",
-        7 => "Argument",
-        8 => "Farewell",
-        _ => "Unknown",
-    }
-}
-
-fn system_status_str(status: u8) -> &'static str {
-    match status {
-        0 => "OK",
-        1 => "DEGRADED",
-        2 => "CRITICAL",
-        3 => "OFFLINE",
-        4 => "DESTROYED",
-        _ => "?",
+/// Scenario picker shown while the server has no ship yet.
+pub fn new_ship_menu(today: i64) -> String {
+    let mut menu = String::from("No ship yet — choose a scenario:\n");
+    for (i, s) in scenarios::SCENARIOS.iter().enumerate() {
+        menu.push_str(&format!(
+            "[{}] {} — {} ({} decks, {} aboard, {})\n",
+            i + 1,
+            s.name,
+            s.summary,
+            s.deck_count,
+            s.crew_count + s.passenger_count,
+            difficulties::name(s.difficulty)
+        ));
     }
+    let daily = scenarios::daily_scenario(today);
+    menu.push_str(&format!(
+        "[T] Daily ship {}: {} ({} decks, {} aboard)",
+        scenarios::date_label(today),
+        daily.name,
+        daily.deck_count,
+        daily.crew_count + daily.passenger_count
+    ));
+    menu
 }
 
-pub fn event_type_name(event_type: u8) -> &'static str {
-    match event_type {
-        0 => "System Failure",
-        1 => "Medical Emergency",
-        2 => "Fire",
-        3 => "Hull Breach",
-        4 => "Discovery",
-        5 => "Celebration",
-        6 => "Altercation",
-        7 => "Resource Shortage",
-        _ => "Unknown Event",
-    }
+/// Today's UTC day number (see `scenarios::day_number`).
+fn today() -> i64 {
+    let micros = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_micros() as i64);
+    scenarios::day_number(micros)
 }
 
 pub fn context_action_hint(room_type: u8, deck: Option<i32>, total_decks: Option<i32>) -> String {
     match room_type {
-        20 | 21 | 22 | 25 => " Eat".to_string(),
-        10..=18 => " Sleep/Wash".to_string(),
-        60..=71 => " Repair".to_string(),
-        40 | 51 => " Exercise".to_string(),
-        110 => {
+        room_types::MESS_HALL | room_types::GALLEY | room_types::CAFE | room_types::BAKERY => {
+            " Eat".to_string()
+        }
+        rt if room_types::is_quarters(rt) => " Sleep/Wash".to_string(),
+        room_types::SHARED_BATHROOM => " Wash".to_string(),
+        room_types::ENGINEERING..=room_types::COOLING_PLANT => " Repair".to_string(),
+        rt if room_types::is_recreation(rt) => " Exercise".to_string(),
+        room_types::ELEVATOR_SHAFT | room_types::SERVICE_ELEVATOR_SHAFT => {
             let d = deck.map(|d| d + 1).unwrap_or(0);
             let t = total_decks.unwrap_or(12);
             format!(" Elevator (Deck {d}/{t}) [1-9,0,-,=]")
         }
-        111 => {
+        room_types::LADDER_SHAFT => {
             let d = deck.map(|d| d + 1).unwrap_or(0);
             format!(" Ladder (Deck {d}) [Up/Down]")
         }
         _ => String::new(),
     }
 }
+
+/// Room name to show: its crew nickname if it has one.
+pub fn room_label(conn: &DbConnection, room: &Room) -> String {
+    let nickname = conn.db.room_nickname().room_id().find(&room.id);
+    nicknames::display_name(&room.name, nickname.as_ref().map(|n| n.name.as_str())).to_string()
+}
+
+/// A deck's air, averaged over its rooms.
+pub struct DeckAir {
+    pub oxygen: f32,
+    pub temperature: f32,
+    pub pressure: f32,
+    /// Rooms with too little oxygen or too much CO2.
+    pub stale_rooms: usize,
+}
+
+/// The air on `deck`, if it has any rooms with air.
+pub fn deck_air(conn: &DbConnection, deck: i32) -> Option<DeckAir> {
+    let rooms: Vec<_> = conn
+        .db
+        .room_air()
+        .iter()
+        .filter(|a| a.deck == deck)
+        .collect();
+    if rooms.is_empty() {
+        return None;
+    }
+    let n = rooms.len() as f32;
+    Some(DeckAir {
+        oxygen: rooms.iter().map(|a| a.oxygen).sum::<f32>() / n,
+        temperature: rooms.iter().map(|a| a.temperature).sum::<f32>() / n,
+        pressure: rooms.iter().map(|a| a.pressure).sum::<f32>() / n,
+        stale_rooms: rooms
+            .iter()
+            .filter(|a| a.oxygen < 0.19 || a.co_2 > 0.02)
+            .count(),
+    })
+}
+
+/// Deck name to show: its crew nickname if it has one.
+pub fn deck_label(conn: &DbConnection, deck: &Deck) -> String {
+    let nickname = conn.db.deck_nickname().deck().find(&deck.deck);
+    nicknames::display_name(&deck.name, nickname.as_ref().map(|n| n.name.as_str())).to_string()
+}
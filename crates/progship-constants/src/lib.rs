@@ -0,0 +1,1938 @@
+//! Game constants — room types, activity types, shifts, groups, etc.
+//!
+//! These are simple `u8` constants with no database or engine dependency,
+//! plus display names for the UI. The simulation logic, the SpacetimeDB
+//! server, the native simtest and the Bevy client all share them.
+
+#![cfg_attr(not(test), no_std)]
+
+pub mod groups {
+    pub const COMMAND: u8 = 0;
+    pub const SECURITY: u8 = 1;
+    pub const HABITATION: u8 = 2;
+    pub const FOOD_SERVICE: u8 = 3;
+    pub const MEDICAL: u8 = 4;
+    pub const RECREATION: u8 = 5;
+    pub const ENGINEERING: u8 = 6;
+    pub const WORKSHOP: u8 = 7;
+    pub const PROPULSION: u8 = 8;
+    pub const LIFE_SUPPORT: u8 = 9;
+    pub const CARGO: u8 = 10;
+    pub const INFRASTRUCTURE: u8 = 11;
+}
+
+pub mod room_types {
+    // Command & Administration
+    pub const BRIDGE: u8 = 0;
+    pub const CONFERENCE: u8 = 1;
+    pub const CIC: u8 = 2;
+    pub const COMMS_ROOM: u8 = 3;
+    pub const CAPTAINS_READY_ROOM: u8 = 4;
+    pub const SECURITY_OFFICE: u8 = 5;
+    pub const BRIG: u8 = 6;
+    pub const ADMIN_OFFICE: u8 = 7;
+    pub const OBSERVATORY: u8 = 8;
+    // Habitation
+    pub const CABIN_SINGLE: u8 = 10;
+    pub const CABIN_DOUBLE: u8 = 11;
+    pub const FAMILY_SUITE: u8 = 12;
+    pub const VIP_SUITE: u8 = 13;
+    pub const QUARTERS_CREW: u8 = 14;
+    pub const QUARTERS_OFFICER: u8 = 15;
+    pub const QUARTERS_PASSENGER: u8 = 16;
+    pub const SHARED_BATHROOM: u8 = 17;
+    pub const SHARED_LAUNDRY: u8 = 18;
+    // Food & Dining
+    pub const MESS_HALL: u8 = 20;
+    pub const WARDROOM: u8 = 21;
+    pub const GALLEY: u8 = 22;
+    pub const FOOD_STORAGE_COLD: u8 = 23;
+    pub const FOOD_STORAGE_DRY: u8 = 24;
+    pub const CAFE: u8 = 25;
+    pub const BAKERY: u8 = 26;
+    pub const WATER_PURIFICATION: u8 = 27;
+    // Medical
+    pub const HOSPITAL_WARD: u8 = 30;
+    pub const SURGERY: u8 = 31;
+    pub const DENTAL_CLINIC: u8 = 32;
+    pub const PHARMACY: u8 = 33;
+    pub const MENTAL_HEALTH: u8 = 34;
+    pub const QUARANTINE: u8 = 35;
+    pub const MORGUE: u8 = 36;
+    pub const MEDBAY: u8 = 37;
+    pub const CRYO_BAY: u8 = 38;
+    // Recreation & Morale
+    pub const GYM: u8 = 40;
+    pub const THEATRE: u8 = 41;
+    pub const LIBRARY: u8 = 42;
+    pub const CHAPEL: u8 = 43;
+    pub const GAME_ROOM: u8 = 44;
+    pub const BAR: u8 = 45;
+    pub const ART_STUDIO: u8 = 46;
+    pub const MUSIC_ROOM: u8 = 47;
+    pub const HOLODECK: u8 = 48;
+    pub const ARBORETUM: u8 = 49;
+    pub const OBSERVATION_LOUNGE: u8 = 50;
+    pub const POOL: u8 = 51;
+    pub const NURSERY: u8 = 52;
+    pub const SCHOOL: u8 = 53;
+    pub const RECREATION: u8 = 54;
+    pub const LOUNGE: u8 = 55;
+    pub const SHOPS: u8 = 56;
+    // Engineering & Propulsion
+    pub const ENGINEERING: u8 = 60;
+    pub const MAIN_ENGINEERING: u8 = 60; // alias for clarity in deck_heights
+    pub const REACTOR: u8 = 61;
+    pub const BACKUP_REACTOR: u8 = 62;
+    pub const ENGINE_ROOM: u8 = 63;
+    pub const POWER_DISTRIBUTION: u8 = 64;
+    pub const MACHINE_SHOP: u8 = 65;
+    pub const ELECTRONICS_LAB: u8 = 66;
+    pub const PARTS_STORAGE: u8 = 67;
+    pub const FUEL_STORAGE: u8 = 68;
+    pub const ROBOTICS_BAY: u8 = 69;
+    pub const MAINTENANCE_BAY: u8 = 70;
+    pub const COOLING_PLANT: u8 = 71;
+    pub const WORKSHOP: u8 = 72;
+    pub const UTILITY: u8 = 73;
+    pub const EMERGENCY_SUPPLY: u8 = 74;
+    // Life Support
+    pub const HYDROPONICS: u8 = 80;
+    pub const ATMOSPHERE_PROCESSING: u8 = 81;
+    pub const WATER_RECYCLING: u8 = 82;
+    pub const WASTE_PROCESSING: u8 = 83;
+    pub const ENV_MONITORING: u8 = 84;
+    pub const LIFE_SUPPORT: u8 = 85;
+    pub const HVAC_CONTROL: u8 = 86;
+    // Cargo & Logistics
+    pub const CARGO_BAY: u8 = 90;
+    pub const STORAGE: u8 = 91;
+    pub const ARMORY: u8 = 92;
+    pub const SHUTTLE_BAY: u8 = 93;
+    pub const AIRLOCK: u8 = 94;
+    pub const LABORATORY: u8 = 95;
+    // Infrastructure (not placeable rooms)
+    pub const CORRIDOR: u8 = 100;
+    pub const SERVICE_CORRIDOR: u8 = 101;
+    pub const CROSS_CORRIDOR: u8 = 102;
+    pub const CRAWLWAY: u8 = 103;
+    pub const ELEVATOR_SHAFT: u8 = 110;
+    pub const LADDER_SHAFT: u8 = 111;
+    pub const SERVICE_ELEVATOR_SHAFT: u8 = 112;
+    pub const SERVICE_DECK: u8 = 120;
+
+    /// Returns true if this room type is any kind of sleeping quarters
+    pub fn is_quarters(rt: u8) -> bool {
+        matches!(
+            rt,
+            CABIN_SINGLE
+                | CABIN_DOUBLE
+                | FAMILY_SUITE
+                | VIP_SUITE
+                | QUARTERS_CREW
+                | QUARTERS_OFFICER
+                | QUARTERS_PASSENGER
+        )
+    }
+    /// Returns true if this room type is a dining/food area
+    pub fn is_dining(rt: u8) -> bool {
+        matches!(rt, MESS_HALL | WARDROOM | CAFE | GALLEY)
+    }
+    /// Returns true if this room type is recreation/social
+    pub fn is_recreation(rt: u8) -> bool {
+        matches!(
+            rt,
+            GYM | THEATRE
+                | LIBRARY
+                | CHAPEL
+                | GAME_ROOM
+                | BAR
+                | ART_STUDIO
+                | MUSIC_ROOM
+                | HOLODECK
+                | ARBORETUM
+                | OBSERVATION_LOUNGE
+                | POOL
+                | NURSERY
+                | SCHOOL
+                | RECREATION
+                | LOUNGE
+                | SHOPS
+        )
+    }
+    /// Returns true if this room type is a corridor/infrastructure
+    pub fn is_corridor(rt: u8) -> bool {
+        rt >= 100
+    }
+    /// Returns true if this room type is a walkable corridor (not a shaft or service deck)
+    pub fn is_plain_corridor(rt: u8) -> bool {
+        matches!(rt, CORRIDOR | SERVICE_CORRIDOR | CROSS_CORRIDOR)
+    }
+    /// Returns true if this room type is a vertical shaft (elevator, ladder, service elevator)
+    pub fn is_shaft(rt: u8) -> bool {
+        matches!(rt, ELEVATOR_SHAFT | LADDER_SHAFT | SERVICE_ELEVATOR_SHAFT)
+    }
+    /// Returns true if this room type is a medical facility
+    pub fn is_medical(rt: u8) -> bool {
+        matches!(
+            rt,
+            HOSPITAL_WARD | SURGERY | DENTAL_CLINIC | PHARMACY | QUARANTINE
+        )
+    }
+    /// Display name of a room type
+    pub fn name(rt: u8) -> &'static str {
+        match rt {
+            BRIDGE => "Bridge",
+            CONFERENCE => "Conference Room",
+            CIC => "CIC",
+            COMMS_ROOM => "Comms Room",
+            CAPTAINS_READY_ROOM => "Captain's Ready Room",
+            SECURITY_OFFICE => "Security Office",
+            BRIG => "Brig",
+            ADMIN_OFFICE => "Admin Office",
+            OBSERVATORY => "Observatory",
+            CABIN_SINGLE => "Single Cabin",
+            CABIN_DOUBLE => "Double Cabin",
+            FAMILY_SUITE => "Family Suite",
+            VIP_SUITE => "VIP Suite",
+            QUARTERS_CREW => "Crew Quarters",
+            QUARTERS_OFFICER => "Officer Quarters",
+            QUARTERS_PASSENGER => "Passenger Quarters",
+            SHARED_BATHROOM => "Shared Bathroom",
+            SHARED_LAUNDRY => "Laundry",
+            MESS_HALL => "Mess Hall",
+            WARDROOM => "Wardroom",
+            GALLEY => "Galley",
+            FOOD_STORAGE_COLD => "Cold Storage",
+            FOOD_STORAGE_DRY => "Dry Storage",
+            CAFE => "Cafe",
+            BAKERY => "Bakery",
+            WATER_PURIFICATION => "Water Purification",
+            HOSPITAL_WARD => "Hospital Ward",
+            SURGERY => "Surgery",
+            DENTAL_CLINIC => "Dental Clinic",
+            PHARMACY => "Pharmacy",
+            MENTAL_HEALTH => "Mental Health",
+            QUARANTINE => "Quarantine",
+            MORGUE => "Morgue",
+            MEDBAY => "Medical Bay",
+            CRYO_BAY => "Cryo Bay",
+            GYM => "Gym",
+            THEATRE => "Theatre",
+            LIBRARY => "Library",
+            CHAPEL => "Chapel",
+            GAME_ROOM => "Game Room",
+            BAR => "Bar",
+            ART_STUDIO => "Art Studio",
+            MUSIC_ROOM => "Music Room",
+            HOLODECK => "Holodeck",
+            ARBORETUM => "Arboretum",
+            OBSERVATION_LOUNGE => "Observation Lounge",
+            POOL => "Pool",
+            NURSERY => "Nursery",
+            SCHOOL => "School",
+            RECREATION => "Recreation Center",
+            LOUNGE => "Lounge",
+            SHOPS => "Shops",
+            ENGINEERING => "Main Engineering",
+            REACTOR => "Reactor",
+            BACKUP_REACTOR => "Backup Reactor",
+            ENGINE_ROOM => "Engine Room",
+            POWER_DISTRIBUTION => "Power Distribution",
+            MACHINE_SHOP => "Machine Shop",
+            ELECTRONICS_LAB => "Electronics Lab",
+            PARTS_STORAGE => "Parts Storage",
+            FUEL_STORAGE => "Fuel Storage",
+            ROBOTICS_BAY => "Robotics Bay",
+            MAINTENANCE_BAY => "Maintenance Bay",
+            COOLING_PLANT => "Cooling Plant",
+            HYDROPONICS => "Hydroponics",
+            ATMOSPHERE_PROCESSING => "Atmosphere Processing",
+            WATER_RECYCLING => "Water Recycling",
+            WASTE_PROCESSING => "Waste Processing",
+            ENV_MONITORING => "Environmental Monitoring",
+            LIFE_SUPPORT => "Life Support",
+            HVAC_CONTROL => "HVAC Control",
+            CARGO_BAY => "Cargo Bay",
+            STORAGE => "Storage",
+            ARMORY => "Armory",
+            SHUTTLE_BAY => "Shuttle Bay",
+            AIRLOCK => "Airlock",
+            LABORATORY => "Laboratory",
+            CORRIDOR => "Corridor",
+            SERVICE_CORRIDOR => "Service Corridor",
+            CROSS_CORRIDOR => "Cross Corridor",
+            CRAWLWAY => "Crawlway",
+            ELEVATOR_SHAFT => "Elevator",
+            LADDER_SHAFT => "Ladder",
+            SERVICE_ELEVATOR_SHAFT => "Service Elevator",
+            SERVICE_DECK => "Service Deck",
+            _ => "Unknown",
+        }
+    }
+}
+
+/// Returns a short label for a room type, suitable for minimap and door signage.
+/// Uses ASCII-safe abbreviations guaranteed to render in any font.
+pub fn room_type_icon(rt: u8) -> &'static str {
+    match rt {
+        // Command & Administration
+        room_types::BRIDGE => "BR",
+        room_types::CONFERENCE => "CF",
+        room_types::CIC => "CIC",
+        room_types::COMMS_ROOM => "COM",
+        room_types::CAPTAINS_READY_ROOM => "CPT",
+        room_types::SECURITY_OFFICE => "SEC",
+        room_types::BRIG => "BRG",
+        room_types::ADMIN_OFFICE => "ADM",
+        room_types::OBSERVATORY => "OBS",
+        // Habitation
+        room_types::CABIN_SINGLE => "C1",
+        room_types::CABIN_DOUBLE => "C2",
+        room_types::FAMILY_SUITE => "FS",
+        room_types::VIP_SUITE => "VIP",
+        room_types::QUARTERS_CREW => "CQ",
+        room_types::QUARTERS_OFFICER => "OQ",
+        room_types::QUARTERS_PASSENGER => "PQ",
+        room_types::SHARED_BATHROOM => "WC",
+        room_types::SHARED_LAUNDRY => "LA",
+        // Food & Dining
+        room_types::MESS_HALL => "MH",
+        room_types::WARDROOM => "WR",
+        room_types::GALLEY => "GL",
+        room_types::FOOD_STORAGE_COLD => "CS",
+        room_types::FOOD_STORAGE_DRY => "DS",
+        room_types::CAFE => "CA",
+        room_types::BAKERY => "BK",
+        room_types::WATER_PURIFICATION => "WP",
+        // Medical
+        room_types::HOSPITAL_WARD => "H+",
+        room_types::SURGERY => "SU",
+        room_types::DENTAL_CLINIC => "DT",
+        room_types::PHARMACY => "RX",
+        room_types::MENTAL_HEALTH => "MH",
+        room_types::QUARANTINE => "QT",
+        room_types::MORGUE => "MG",
+        room_types::MEDBAY => "M+",
+        room_types::CRYO_BAY => "CY",
+        // Recreation & Morale
+        room_types::GYM => "GY",
+        room_types::THEATRE => "TH",
+        room_types::LIBRARY => "LB",
+        room_types::CHAPEL => "CH",
+        room_types::GAME_ROOM => "GM",
+        room_types::BAR => "BA",
+        room_types::ART_STUDIO => "AR",
+        room_types::MUSIC_ROOM => "MU",
+        room_types::HOLODECK => "HD",
+        room_types::ARBORETUM => "AB",
+        room_types::OBSERVATION_LOUNGE => "OL",
+        room_types::POOL => "PL",
+        room_types::NURSERY => "NU",
+        room_types::SCHOOL => "SC",
+        room_types::RECREATION => "RC",
+        room_types::LOUNGE => "LG",
+        room_types::SHOPS => "SH",
+        // Engineering & Propulsion
+        room_types::ENGINEERING => "EN",
+        room_types::REACTOR => "RX!",
+        room_types::BACKUP_REACTOR => "BR!",
+        room_types::ENGINE_ROOM => "ENG",
+        room_types::POWER_DISTRIBUTION => "PD",
+        room_types::MACHINE_SHOP => "MS",
+        room_types::ELECTRONICS_LAB => "EL",
+        room_types::PARTS_STORAGE => "PS",
+        room_types::FUEL_STORAGE => "FU",
+        room_types::ROBOTICS_BAY => "RB",
+        room_types::MAINTENANCE_BAY => "MB",
+        room_types::COOLING_PLANT => "CP",
+        // Life Support
+        room_types::HYDROPONICS => "HY",
+        room_types::ATMOSPHERE_PROCESSING => "AT",
+        room_types::WATER_RECYCLING => "WR",
+        room_types::WASTE_PROCESSING => "WS",
+        room_types::ENV_MONITORING => "EM",
+        room_types::LIFE_SUPPORT => "LS",
+        room_types::HVAC_CONTROL => "HV",
+        // Cargo & Logistics
+        room_types::CARGO_BAY => "CG",
+        room_types::STORAGE => "ST",
+        room_types::ARMORY => "AM",
+        room_types::SHUTTLE_BAY => "SB",
+        room_types::AIRLOCK => "AL",
+        room_types::LABORATORY => "LAB",
+        // Infrastructure
+        room_types::ELEVATOR_SHAFT => "EV",
+        room_types::LADDER_SHAFT => "LD",
+        room_types::SERVICE_ELEVATOR_SHAFT => "SE",
+        _ => "",
+    }
+}
+
+pub mod deck_heights {
+    use super::room_types;
+
+    /// Minimum deck height in meters (floor-to-ceiling for standard rooms).
+    pub const MIN_DECK_HEIGHT: f32 = 3.5;
+
+    /// Standard personnel door opening height in meters.
+    pub const STANDARD_DOOR_HEIGHT: f32 = 2.4;
+
+    /// Equipment / large-access door opening height in meters.
+    pub const EQUIPMENT_DOOR_HEIGHT: f32 = 3.0;
+
+    /// Returns the ceiling height for a given room type, in meters.
+    ///
+    /// Multi-deck rooms (reactor, engine room, cargo bay, shuttle bay) get
+    /// double height. All other rooms use the base deck height.
+    pub fn room_ceiling_height(room_type: u8) -> f32 {
+        let span = room_deck_span(room_type) as f32;
+        MIN_DECK_HEIGHT * span
+    }
+
+    /// Returns how many decks a room of this type spans (1, 2, or 3).
+    pub fn room_deck_span(room_type: u8) -> u8 {
+        match room_type {
+            room_types::SHUTTLE_BAY => 3,
+            room_types::REACTOR
+            | room_types::ENGINE_ROOM
+            | room_types::CARGO_BAY
+            | room_types::MAIN_ENGINEERING
+            | room_types::BACKUP_REACTOR
+            | room_types::FUEL_STORAGE
+            | room_types::HYDROPONICS
+            | room_types::ARBORETUM
+            | room_types::THEATRE
+            | room_types::POOL => 2,
+            _ => 1,
+        }
+    }
+
+    /// Returns the door opening height for a doorway between two room types.
+    ///
+    /// Equipment-height doors are used when either side is an engineering,
+    /// cargo, maintenance, or shuttle room. All others get standard height.
+    pub fn door_opening_height(rt_a: u8, rt_b: u8) -> f32 {
+        if is_equipment_door_room(rt_a) || is_equipment_door_room(rt_b) {
+            EQUIPMENT_DOOR_HEIGHT
+        } else {
+            STANDARD_DOOR_HEIGHT
+        }
+    }
+
+    /// Returns true if doors to/from this room type should use equipment height.
+    fn is_equipment_door_room(rt: u8) -> bool {
+        matches!(
+            rt,
+            room_types::ENGINEERING
+                | room_types::REACTOR
+                | room_types::BACKUP_REACTOR
+                | room_types::ENGINE_ROOM
+                | room_types::MACHINE_SHOP
+                | room_types::FUEL_STORAGE
+                | room_types::ROBOTICS_BAY
+                | room_types::MAINTENANCE_BAY
+                | room_types::COOLING_PLANT
+                | room_types::CARGO_BAY
+                | room_types::SHUTTLE_BAY
+                | room_types::AIRLOCK
+        )
+    }
+}
+
+/// Room placement constraints for generation.
+///
+/// Encoded as u8 for compactness and serialization.
+pub mod placement {
+    use super::room_types;
+
+    pub const NONE: u8 = 0;
+    pub const HULL_FACING: u8 = 1; // Must touch ship exterior (perimeter ring)
+    pub const INTERIOR: u8 = 2; // Must NOT touch hull (protected/shielded)
+    pub const AFT: u8 = 3; // Prefer aft (high-Y) section
+    pub const FORWARD: u8 = 4; // Prefer forward (low-Y) section
+
+    /// Returns the placement constraint for a room type.
+    pub fn room_placement(room_type: u8) -> u8 {
+        match room_type {
+            // Hull-facing: viewports, launch doors, exhaust, antennas, radiators, venting
+            room_types::OBSERVATORY
+            | room_types::OBSERVATION_LOUNGE
+            | room_types::COMMS_ROOM
+            | room_types::VIP_SUITE
+            | room_types::SHUTTLE_BAY
+            | room_types::AIRLOCK
+            | room_types::CARGO_BAY
+            | room_types::FUEL_STORAGE
+            | room_types::COOLING_PLANT => HULL_FACING,
+
+            // Interior/protected: shielding, security, contamination containment
+            room_types::REACTOR
+            | room_types::CIC
+            | room_types::BRIG
+            | room_types::ARMORY
+            | room_types::WATER_PURIFICATION
+            | room_types::QUARANTINE
+            | room_types::WATER_RECYCLING
+            | room_types::WASTE_PROCESSING
+            | room_types::HOLODECK => INTERIOR,
+
+            // Aft section: propulsion, engineering
+            room_types::ENGINE_ROOM | room_types::ENGINEERING => AFT,
+
+            // Forward: command
+            room_types::BRIDGE => FORWARD,
+
+            _ => NONE,
+        }
+    }
+
+    /// Parses a placement string from the manifest JSON.
+    pub fn from_str(s: &str) -> u8 {
+        match s {
+            "hull_facing" => HULL_FACING,
+            "interior" => INTERIOR,
+            "aft" => AFT,
+            "forward" => FORWARD,
+            _ => NONE,
+        }
+    }
+}
+
+pub mod shifts {
+    pub const ALPHA: u8 = 0; // 0600-1400
+    pub const BETA: u8 = 1; // 1400-2200
+    pub const GAMMA: u8 = 2; // 2200-0600
+
+    /// Display name of a shift, with its watch hours
+    pub fn name(shift: u8) -> &'static str {
+        match shift {
+            ALPHA => "Alpha (06:00-14:00)",
+            BETA => "Beta (14:00-22:00)",
+            GAMMA => "Gamma (22:00-06:00)",
+            _ => "Unknown",
+        }
+    }
+}
+
+pub mod activity_types {
+    pub const IDLE: u8 = 0;
+    pub const WORKING: u8 = 1;
+    pub const EATING: u8 = 2;
+    pub const SLEEPING: u8 = 3;
+    pub const SOCIALIZING: u8 = 4;
+    pub const RELAXING: u8 = 5;
+    pub const HYGIENE: u8 = 6;
+    pub const TRAVELING: u8 = 7;
+    pub const MAINTENANCE: u8 = 8;
+    pub const ON_DUTY: u8 = 9;
+    pub const OFF_DUTY: u8 = 10;
+    pub const EMERGENCY: u8 = 11;
+    pub const EXERCISING: u8 = 12;
+    pub const TRAINING: u8 = 13;
+    pub const SURGERY: u8 = 14;
+
+    /// Display name of an activity type
+    pub fn name(activity: u8) -> &'static str {
+        match activity {
+            IDLE => "Idle",
+            WORKING => "Working",
+            EATING => "Eating",
+            SLEEPING => "Sleeping",
+            SOCIALIZING => "Socializing",
+            RELAXING => "Relaxing",
+            HYGIENE => "Hygiene",
+            TRAVELING => "Traveling",
+            MAINTENANCE => "Maintenance",
+            ON_DUTY => "On Duty",
+            OFF_DUTY => "Off Duty",
+            EMERGENCY => "Emergency",
+            EXERCISING => "Exercising",
+            TRAINING => "Training",
+            SURGERY => "Surgery",
+            _ => "Unknown",
+        }
+    }
+}
+
+pub mod departments {
+    pub const COMMAND: u8 = 0;
+    pub const ENGINEERING: u8 = 1;
+    pub const MEDICAL: u8 = 2;
+    pub const SCIENCE: u8 = 3;
+    pub const SECURITY: u8 = 4;
+    pub const OPERATIONS: u8 = 5;
+    pub const CIVILIAN: u8 = 6;
+
+    /// Display name of a department
+    pub fn name(dept: u8) -> &'static str {
+        match dept {
+            COMMAND => "Command",
+            ENGINEERING => "Engineering",
+            MEDICAL => "Medical",
+            SCIENCE => "Science",
+            SECURITY => "Security",
+            OPERATIONS => "Operations",
+            CIVILIAN => "Civilian",
+            _ => "Unknown",
+        }
+    }
+}
+
+pub mod ranks {
+    pub const CREWMAN: u8 = 0;
+    pub const SPECIALIST: u8 = 1;
+    pub const PETTY: u8 = 2;
+    pub const CHIEF: u8 = 3;
+    pub const ENSIGN: u8 = 4;
+    pub const LIEUTENANT: u8 = 5;
+    pub const COMMANDER: u8 = 6;
+    pub const CAPTAIN: u8 = 7;
+
+    /// Display name of a rank
+    pub fn name(rank: u8) -> &'static str {
+        match rank {
+            CREWMAN => "Crewman",
+            SPECIALIST => "Specialist",
+            PETTY => "Petty Officer",
+            CHIEF => "Chief",
+            ENSIGN => "Ensign",
+            LIEUTENANT => "Lieutenant",
+            COMMANDER => "Commander",
+            CAPTAIN => "Captain",
+            _ => "Unknown",
+        }
+    }
+}
+
+pub mod command_posts {
+    pub const CAPTAIN: u8 = 0;
+    pub const EXECUTIVE_OFFICER: u8 = 1;
+    pub const DEPARTMENT_HEAD: u8 = 2;
+    pub const WATCH_OFFICER: u8 = 3;
+
+    /// Display name of a command post
+    pub fn name(post: u8) -> &'static str {
+        match post {
+            CAPTAIN => "Captain",
+            EXECUTIVE_OFFICER => "Executive Officer",
+            DEPARTMENT_HEAD => "Department Head",
+            WATCH_OFFICER => "Watch Officer",
+            _ => "Unknown",
+        }
+    }
+}
+
+/// Qualification in a crew member's department skill (see
+/// `progship_logic::careers`).
+pub mod certifications {
+    pub const NONE: u8 = 0;
+    pub const BASIC: u8 = 1;
+    pub const ADVANCED: u8 = 2;
+    pub const MASTER: u8 = 3;
+
+    /// Display name of a certification
+    pub fn name(certification: u8) -> &'static str {
+        match certification {
+            NONE => "Uncertified",
+            BASIC => "Basic",
+            ADVANCED => "Advanced",
+            MASTER => "Master",
+            _ => "Unknown",
+        }
+    }
+}
+
+pub mod system_types {
+    pub const POWER: u8 = 0;
+    pub const LIFE_SUPPORT: u8 = 1;
+    pub const PROPULSION: u8 = 2;
+    pub const NAVIGATION: u8 = 3;
+    pub const COMMUNICATIONS: u8 = 4;
+    pub const WEAPONS: u8 = 5;
+    pub const SHIELDS: u8 = 6;
+    pub const MEDICAL: u8 = 7;
+    pub const FOOD_PRODUCTION: u8 = 8;
+    pub const WATER_RECYCLING: u8 = 9;
+    pub const GRAVITY: u8 = 10;
+}
+
+pub mod system_statuses {
+    pub const NOMINAL: u8 = 0;
+    pub const DEGRADED: u8 = 1;
+    pub const CRITICAL: u8 = 2;
+    pub const OFFLINE: u8 = 3;
+    pub const DESTROYED: u8 = 4;
+
+    /// Short upper-case status label for system readouts
+    pub fn label(status: u8) -> &'static str {
+        match status {
+            NOMINAL => "OK",
+            DEGRADED => "DEGRADED",
+            CRITICAL => "CRITICAL",
+            OFFLINE => "OFFLINE",
+            DESTROYED => "DESTROYED",
+            _ => "?",
+        }
+    }
+}
+
+pub mod power_priorities {
+    /// Life support, navigation — last to lose power.
+    pub const CRITICAL: u8 = 0;
+    /// Medical, comms.
+    pub const HIGH: u8 = 1;
+    /// Gravity, water recycling, food production.
+    pub const NORMAL: u8 = 2;
+    /// Recreation, non-essential.
+    pub const LOW: u8 = 3;
+}
+
+pub mod relationship_types {
+    pub const STRANGER: u8 = 0;
+    pub const ACQUAINTANCE: u8 = 1;
+    pub const COLLEAGUE: u8 = 2;
+    pub const FRIEND: u8 = 3;
+    pub const CLOSE_FRIEND: u8 = 4;
+    pub const ROMANTIC: u8 = 5;
+    pub const FAMILY: u8 = 6;
+    pub const RIVAL: u8 = 7;
+    pub const ENEMY: u8 = 8;
+
+    /// Returns true for ties of blood or partnership, which social
+    /// interaction strengthens but never reclassifies
+    pub fn is_kin(rt: u8) -> bool {
+        matches!(rt, ROMANTIC | FAMILY)
+    }
+}
+
+pub mod family_roles {
+    pub const HEAD: u8 = 0;
+    pub const PARTNER: u8 = 1;
+    pub const CHILD: u8 = 2;
+    pub const GRANDPARENT: u8 = 3;
+
+    /// Display name of a family role
+    pub fn name(role: u8) -> &'static str {
+        match role {
+            HEAD => "Head of Household",
+            PARTNER => "Partner",
+            CHILD => "Child",
+            GRANDPARENT => "Grandparent",
+            _ => "Unknown",
+        }
+    }
+}
+
+pub mod life_stages {
+    pub const INFANT: u8 = 0;
+    pub const CHILD: u8 = 1;
+    pub const ADULT: u8 = 2;
+    pub const ELDER: u8 = 3;
+
+    /// Age at which infants start school
+    pub const SCHOOL_AGE: u8 = 5;
+    /// Age of majority
+    pub const ADULT_AGE: u8 = 18;
+    /// Age from which people count as elders
+    pub const ELDER_AGE: u8 = 65;
+
+    /// Life stage of someone `age` years old
+    pub fn from_age(age: u8) -> u8 {
+        match age {
+            a if a < SCHOOL_AGE => INFANT,
+            a if a < ADULT_AGE => CHILD,
+            a if a < ELDER_AGE => ADULT,
+            _ => ELDER,
+        }
+    }
+
+    /// Display name of a life stage
+    pub fn name(stage: u8) -> &'static str {
+        match stage {
+            INFANT => "Infant",
+            CHILD => "Child",
+            ADULT => "Adult",
+            ELDER => "Elder",
+            _ => "Unknown",
+        }
+    }
+}
+
+pub mod emotions {
+    pub const CONTENT: u8 = 0;
+    pub const STRESSED: u8 = 1;
+    pub const GRIEVING: u8 = 2;
+    pub const EXCITED: u8 = 3;
+
+    /// Display name of an emotion
+    pub fn name(emotion: u8) -> &'static str {
+        match emotion {
+            CONTENT => "Content",
+            STRESSED => "Stressed",
+            GRIEVING => "Grieving",
+            EXCITED => "Excited",
+            _ => "Unknown",
+        }
+    }
+}
+
+/// Backstory tags and quirks; a person's set is stored as a bitmask with one
+/// bit per trait.
+pub mod person_traits {
+    // Backstories: where someone came from
+    pub const EX_MILITARY: u8 = 0;
+    pub const COLONY_BORN: u8 = 1;
+    pub const ACADEMIC: u8 = 2;
+    // Quirks
+    pub const CLAUSTROPHOBIC: u8 = 3;
+    pub const INSOMNIAC: u8 = 4;
+    pub const GREEN_THUMB: u8 = 5;
+    pub const GOURMAND: u8 = 6;
+    pub const LONER: u8 = 7;
+
+    pub const BACKSTORIES: [u8; 3] = [EX_MILITARY, COLONY_BORN, ACADEMIC];
+    pub const QUIRKS: [u8; 5] = [CLAUSTROPHOBIC, INSOMNIAC, GREEN_THUMB, GOURMAND, LONER];
+
+    /// Display name of a trait
+    pub fn name(trait_id: u8) -> &'static str {
+        match trait_id {
+            EX_MILITARY => "Ex-military",
+            COLONY_BORN => "Colony-born",
+            ACADEMIC => "Academic",
+            CLAUSTROPHOBIC => "Claustrophobic",
+            INSOMNIAC => "Insomniac",
+            GREEN_THUMB => "Green thumb",
+            GOURMAND => "Gourmand",
+            LONER => "Loner",
+            _ => "Unknown",
+        }
+    }
+
+    /// Whether the `mask` bitmask includes `trait_id`
+    pub fn has(mask: u16, trait_id: u8) -> bool {
+        trait_id < 16 && mask & (1 << trait_id) != 0
+    }
+
+    /// The trait IDs set in `mask`, lowest first
+    pub fn ids(mask: u16) -> impl Iterator<Item = u8> {
+        (0..16).filter(move |&t| has(mask, t))
+    }
+}
+
+pub mod conversation_topics {
+    pub const GREETING: u8 = 0;
+    pub const WORK: u8 = 1;
+    pub const GOSSIP: u8 = 2;
+    pub const PERSONAL: u8 = 3;
+    pub const COMPLAINT: u8 = 4;
+    pub const REQUEST: u8 = 5;
+    pub const FLIRTATION: u8 = 6;
+    pub const ARGUMENT: u8 = 7;
+    pub const FAREWELL: u8 = 8;
+
+    /// Display name of a conversation topic
+    pub fn name(topic: u8) -> &'static str {
+        match topic {
+            GREETING => "Greeting",
+            WORK => "Work",
+            GOSSIP => "Gossip",
+            PERSONAL => "Personal",
+            COMPLAINT => "Complaint",
+            REQUEST => "Request",
+            FLIRTATION => "Flirtation",
+            ARGUMENT => "Argument",
+            FAREWELL => "Farewell",
+            _ => "Unknown",
+        }
+    }
+}
+
+pub mod event_types {
+    pub const SYSTEM_FAILURE: u8 = 0;
+    pub const MEDICAL_EMERGENCY: u8 = 1;
+    pub const FIRE: u8 = 2;
+    pub const HULL_BREACH: u8 = 3;
+    pub const DISCOVERY: u8 = 4;
+    pub const CELEBRATION: u8 = 5;
+    pub const ALTERCATION: u8 = 6;
+    pub const RESOURCE_SHORTAGE: u8 = 7;
+    pub const DEATH: u8 = 8;
+    pub const DIAGNOSTIC: u8 = 9;
+    pub const SOLAR_FLARE: u8 = 10;
+    pub const FUNERAL: u8 = 11;
+    pub const RALLY: u8 = 12;
+    pub const STRIKE: u8 = 13;
+    pub const MUTINY: u8 = 14;
+    pub const PROMOTION: u8 = 15;
+    pub const VOYAGE_MILESTONE: u8 = 16;
+    pub const REVIVAL: u8 = 17;
+
+    /// Display name of an event type
+    pub fn name(event: u8) -> &'static str {
+        match event {
+            SYSTEM_FAILURE => "System Failure",
+            MEDICAL_EMERGENCY => "Medical Emergency",
+            FIRE => "Fire",
+            HULL_BREACH => "Hull Breach",
+            DISCOVERY => "Discovery",
+            CELEBRATION => "Celebration",
+            ALTERCATION => "Altercation",
+            RESOURCE_SHORTAGE => "Resource Shortage",
+            DEATH => "Death",
+            DIAGNOSTIC => "Diagnostic",
+            SOLAR_FLARE => "Solar Flare",
+            FUNERAL => "Funeral",
+            RALLY => "Rally",
+            STRIKE => "Strike",
+            MUTINY => "Mutiny",
+            PROMOTION => "Promotion",
+            VOYAGE_MILESTONE => "Voyage Milestone",
+            REVIVAL => "Cryo Revival",
+            _ => "Unknown Event",
+        }
+    }
+}
+
+pub mod effect_kinds {
+    /// Smoke from a fire: hard to see through, slow to walk through.
+    pub const SMOKE: u8 = 0;
+    /// Coolant on the floor from a burst line: slippery.
+    pub const COOLANT_SPILL: u8 = 1;
+    /// Frost from failed climate control: slippery and cold.
+    pub const FROST: u8 = 2;
+
+    /// Display name of a room effect
+    pub fn name(kind: u8) -> &'static str {
+        match kind {
+            SMOKE => "Smoke",
+            COOLANT_SPILL => "Coolant Spill",
+            FROST => "Frost",
+            _ => "Unknown Effect",
+        }
+    }
+}
+
+pub mod milestone_kinds {
+    pub const FIRST_BIRTH: u8 = 0;
+    pub const FATALITY_FREE_100_DAYS: u8 = 1;
+    pub const REACTOR_SCRAM_SURVIVED: u8 = 2;
+    pub const ARRIVAL: u8 = 3;
+    pub const HELIOPAUSE: u8 = 4;
+    pub const ENGINE_CUTOFF: u8 = 5;
+    pub const MIDCOURSE_CORRECTION: u8 = 6;
+    pub const TURNOVER: u8 = 7;
+    pub const DESTINATION_FLYBY: u8 = 8;
+    pub const ARRIVAL_PREP: u8 = 9;
+
+    /// Display name of a milestone
+    pub fn name(kind: u8) -> &'static str {
+        match kind {
+            FIRST_BIRTH => "First Birth Aboard",
+            FATALITY_FREE_100_DAYS => "100 Days Without a Fatality",
+            REACTOR_SCRAM_SURVIVED => "Survived a Reactor Scram",
+            ARRIVAL => "Arrival",
+            HELIOPAUSE => "Crossed the Heliopause",
+            ENGINE_CUTOFF => "Main Engine Cutoff",
+            MIDCOURSE_CORRECTION => "Mid-Course Correction",
+            TURNOVER => "Turnover",
+            DESTINATION_FLYBY => "Flyby of the Outer Planets",
+            ARRIVAL_PREP => "Arrival Preparations",
+            _ => "Unknown Milestone",
+        }
+    }
+}
+
+pub mod death_causes {
+    pub const UNKNOWN: u8 = 0;
+    pub const STARVATION: u8 = 1;
+    pub const EXHAUSTION: u8 = 2;
+    pub const ASPHYXIATION: u8 = 3;
+    pub const DECOMPRESSION: u8 = 4;
+    pub const FIRE: u8 = 5;
+    pub const ILLNESS: u8 = 6;
+    pub const OLD_AGE: u8 = 7;
+    pub const SURGERY: u8 = 8;
+
+    /// Display name of a cause of death
+    pub fn name(cause: u8) -> &'static str {
+        match cause {
+            STARVATION => "Starvation",
+            EXHAUSTION => "Exhaustion",
+            ASPHYXIATION => "Asphyxiation",
+            DECOMPRESSION => "Decompression",
+            FIRE => "Fire",
+            ILLNESS => "Illness",
+            OLD_AGE => "Old Age",
+            SURGERY => "Died in Surgery",
+            _ => "Unknown",
+        }
+    }
+}
+
+pub mod voyage_outcomes {
+    pub const IN_PROGRESS: u8 = 0;
+    pub const ARRIVED: u8 = 1;
+    pub const LOST: u8 = 2;
+
+    /// Display name of a voyage outcome
+    pub fn name(outcome: u8) -> &'static str {
+        match outcome {
+            IN_PROGRESS => "In Progress",
+            ARRIVED => "Arrived",
+            LOST => "Ship Lost",
+            _ => "Unknown",
+        }
+    }
+}
+
+pub mod difficulties {
+    pub const TUTORIAL: u8 = 0;
+    pub const EASY: u8 = 1;
+    pub const NORMAL: u8 = 2;
+    pub const HARD: u8 = 3;
+
+    /// Display name of a difficulty
+    pub fn name(difficulty: u8) -> &'static str {
+        match difficulty {
+            TUTORIAL => "Tutorial",
+            EASY => "Easy",
+            NORMAL => "Normal",
+            HARD => "Hard",
+            _ => "Unknown",
+        }
+    }
+}
+
+/// Client screens a scenario objective can ask the player to open.
+pub mod ui_screens {
+    pub const SHIP_OVERVIEW: u8 = 0;
+    pub const PERSON_INFO: u8 = 1;
+    pub const ENCYCLOPEDIA: u8 = 2;
+
+    /// Display name of a screen
+    pub fn name(screen: u8) -> &'static str {
+        match screen {
+            SHIP_OVERVIEW => "Ship Overview",
+            PERSON_INFO => "Person Info",
+            ENCYCLOPEDIA => "Shipopedia",
+            _ => "Unknown",
+        }
+    }
+}
+
+pub mod loss_causes {
+    pub const NONE: u8 = 0;
+    pub const ALL_DEAD: u8 = 1;
+    pub const LIFE_SUPPORT_COLLAPSE: u8 = 2;
+    pub const BELOW_VIABILITY: u8 = 3;
+    pub const FUEL_EXHAUSTED: u8 = 4;
+
+    /// Display name of the reason a ship was lost
+    pub fn name(cause: u8) -> &'static str {
+        match cause {
+            NONE => "None",
+            ALL_DEAD => "No Survivors",
+            LIFE_SUPPORT_COLLAPSE => "Life Support Collapse",
+            BELOW_VIABILITY => "Population Below Viability",
+            FUEL_EXHAUSTED => "Fuel Exhausted",
+            _ => "Unknown",
+        }
+    }
+}
+
+/// Bulk orders officers can give a group of NPCs.
+pub mod order_kinds {
+    /// A department's crew to the worst active incident.
+    pub const DAMAGE_CONTROL: u8 = 0;
+    /// Security crew to patrol one deck.
+    pub const SECURITY_SWEEP: u8 = 1;
+    /// Crew outside command, engineering, medical and security to quarters.
+    pub const TO_QUARTERS: u8 = 2;
+
+    /// Display name of an order kind
+    pub fn name(kind: u8) -> &'static str {
+        match kind {
+            DAMAGE_CONTROL => "Damage Control",
+            SECURITY_SWEEP => "Security Sweep",
+            TO_QUARTERS => "Non-essential Crew to Quarters",
+            _ => "Unknown",
+        }
+    }
+}
+
+pub mod work_kinds {
+    /// A ship system, component or conduit worn below par.
+    pub const REPAIR: u8 = 0;
+    /// A leaking pipe, duct or cable run.
+    pub const PIPE_REPAIR: u8 = 1;
+    /// A fire, breach, failure or casualty to be dealt with on the spot.
+    pub const EMERGENCY: u8 = 2;
+    /// A batch of spare parts to make from raw materials in a workshop.
+    pub const FABRICATION: u8 = 3;
+    /// Radiators, antennas or hull plating to repair from outside the hull.
+    pub const EVA_REPAIR: u8 = 4;
+    /// Checking on the sleepers in a cryo bay.
+    pub const CRYO_CHECK: u8 = 5;
+    /// Broken-down maintenance robots to get running again.
+    pub const ROBOT_REPAIR: u8 = 6;
+    /// Tending the plantings in an arboretum.
+    pub const GARDENING: u8 = 7;
+    /// Clearing a fouled HVAC duct.
+    pub const DUCT_CLEANING: u8 = 8;
+
+    /// Display name of a work order kind
+    pub fn name(kind: u8) -> &'static str {
+        match kind {
+            REPAIR => "Repair",
+            PIPE_REPAIR => "Pipe Repair",
+            EMERGENCY => "Emergency",
+            FABRICATION => "Fabrication",
+            EVA_REPAIR => "EVA Repair",
+            CRYO_CHECK => "Cryo Check",
+            ROBOT_REPAIR => "Robot Repair",
+            GARDENING => "Gardening",
+            DUCT_CLEANING => "Duct Cleaning",
+            _ => "Unknown",
+        }
+    }
+}
+
+pub mod part_kinds {
+    /// Pumps, valves, motors, fans and other moving parts.
+    pub const MECHANICAL: u8 = 0;
+    /// Generators, capacitors, transformers, breakers and coils.
+    pub const ELECTRICAL: u8 = 1;
+    /// Sensors, processors, antennas, displays and scanner heads.
+    pub const ELECTRONIC: u8 = 2;
+    /// Seals, filters, gaskets and hull patches.
+    pub const SEALS: u8 = 3;
+
+    /// Display name of a kind of spare part
+    pub fn name(kind: u8) -> &'static str {
+        match kind {
+            MECHANICAL => "Mechanical Parts",
+            ELECTRICAL => "Electrical Parts",
+            ELECTRONIC => "Electronics",
+            SEALS => "Seals & Filters",
+            _ => "Unknown",
+        }
+    }
+}
+
+pub mod work_order_states {
+    /// On the board, waiting for someone to take it.
+    pub const OPEN: u8 = 0;
+    /// Taken by a crew member who is on their way or at work.
+    pub const CLAIMED: u8 = 1;
+    /// Finished.
+    pub const DONE: u8 = 2;
+    /// Withdrawn before it was finished: the need went away.
+    pub const CANCELLED: u8 = 3;
+
+    /// Display name of a work order state
+    pub fn name(state: u8) -> &'static str {
+        match state {
+            OPEN => "Open",
+            CLAIMED => "Claimed",
+            DONE => "Done",
+            CANCELLED => "Cancelled",
+            _ => "Unknown",
+        }
+    }
+}
+
+pub mod triage_levels {
+    /// Hurt enough to want a bed, but in no danger.
+    pub const MINOR: u8 = 0;
+    /// Past natural recovery; needs treatment.
+    pub const SERIOUS: u8 = 1;
+    /// At risk of dying without treatment.
+    pub const CRITICAL: u8 = 2;
+
+    /// Display name of a triage level
+    pub fn name(level: u8) -> &'static str {
+        match level {
+            MINOR => "Minor",
+            SERIOUS => "Serious",
+            CRITICAL => "Critical",
+            _ => "Unknown",
+        }
+    }
+}
+
+pub mod patient_states {
+    /// Waiting for a bed on a ward.
+    pub const WAITING: u8 = 0;
+    /// In a ward bed, being treated.
+    pub const ADMITTED: u8 = 1;
+
+    /// Display name of a patient state
+    pub fn name(state: u8) -> &'static str {
+        match state {
+            WAITING => "Waiting for a Bed",
+            ADMITTED => "Admitted",
+            _ => "Unknown",
+        }
+    }
+}
+
+pub mod surgery_outcomes {
+    /// The operation saved them.
+    pub const SUCCESS: u8 = 0;
+    /// They came through it, but no better off.
+    pub const COMPLICATION: u8 = 1;
+    /// They died on the table.
+    pub const DEATH: u8 = 2;
+
+    /// Display name of a surgery outcome
+    pub fn name(outcome: u8) -> &'static str {
+        match outcome {
+            SUCCESS => "Success",
+            COMPLICATION => "Complication",
+            DEATH => "Died on the Table",
+            _ => "Unknown",
+        }
+    }
+}
+
+/// Modes a deck's lights cycle through over the ship day (see
+/// `progship_logic::lighting`).
+pub mod lighting_modes {
+    /// Full daylight, dawn included.
+    pub const DAY: u8 = 0;
+    /// Dusk and the warm, dimmer hours after it.
+    pub const EVENING: u8 = 1;
+    /// Night lighting.
+    pub const NIGHT: u8 = 2;
+
+    /// Display name of a lighting mode
+    pub fn name(mode: u8) -> &'static str {
+        match mode {
+            DAY => "Day",
+            EVENING => "Evening",
+            NIGHT => "Night",
+            _ => "Unknown",
+        }
+    }
+}
+
+/// Stages of a crew member's trip outside the hull (see
+/// `progship_logic::eva`).
+pub mod eva_phases {
+    /// Suited up in the airlock while it cycles open.
+    pub const CYCLING_OUT: u8 = 0;
+    /// Crossing the hull to the site.
+    pub const OUTBOUND: u8 = 1;
+    /// At work on the site.
+    pub const WORKING: u8 = 2;
+    /// Heading back and cycling in.
+    pub const RETURNING: u8 = 3;
+
+    /// Display name of an EVA phase
+    pub fn name(phase: u8) -> &'static str {
+        match phase {
+            CYCLING_OUT => "Cycling Out",
+            OUTBOUND => "Crossing the Hull",
+            WORKING => "Working",
+            RETURNING => "Returning",
+            _ => "Unknown",
+        }
+    }
+}
+
+/// What a maintenance robot is about (see `progship_logic::robots`).
+pub mod robot_states {
+    /// Waiting in its bay for work.
+    pub const IDLE: u8 = 0;
+    /// On its way to or at work on a work order.
+    pub const WORKING: u8 = 1;
+    /// On its way to or plugged in at a charging point.
+    pub const CHARGING: u8 = 2;
+    /// Broken down or run flat, waiting for crew to repair it.
+    pub const BROKEN: u8 = 3;
+
+    /// Display name of a robot state
+    pub fn name(state: u8) -> &'static str {
+        match state {
+            IDLE => "Idle",
+            WORKING => "Working",
+            CHARGING => "Charging",
+            BROKEN => "Broken Down",
+            _ => "Unknown",
+        }
+    }
+}
+
+/// Stages of the voyage's flight plan (see `progship_logic::navigation`).
+pub mod voyage_phases {
+    /// Burning up to coasting velocity.
+    pub const ACCELERATING: u8 = 0;
+    /// Coasting between the burns.
+    pub const CRUISING: u8 = 1;
+    /// Burning to brake into the destination system.
+    pub const DECELERATING: u8 = 2;
+    /// In orbit at the destination.
+    pub const ARRIVED: u8 = 3;
+
+    /// Display name of a voyage phase
+    pub fn name(phase: u8) -> &'static str {
+        match phase {
+            ACCELERATING => "Accelerating",
+            CRUISING => "Cruising",
+            DECELERATING => "Decelerating",
+            ARRIVED => "Arrived",
+            _ => "Unknown",
+        }
+    }
+}
+
+/// Hazards the sensors track on their way to the ship (see
+/// `progship_logic::sensors`).
+pub mod contact_kinds {
+    /// Particles from a flare, following its flash.
+    pub const SOLAR_FLARE: u8 = 0;
+    /// A grain of dust or rock on a collision course.
+    pub const MICROMETEORITE: u8 = 1;
+
+    /// Display name of a sensor contact
+    pub fn name(kind: u8) -> &'static str {
+        match kind {
+            SOLAR_FLARE => "Solar Flare",
+            MICROMETEORITE => "Micrometeorite",
+            _ => "Unknown",
+        }
+    }
+}
+
+/// Stores the ship's computer tracks the trend of (see
+/// `progship_logic::advisor`).
+pub mod stocks {
+    pub const FOOD: u8 = 0;
+    pub const WATER: u8 = 1;
+    pub const OXYGEN: u8 = 2;
+    /// Power held in reserve, not generated.
+    pub const POWER: u8 = 3;
+    pub const FUEL: u8 = 4;
+
+    /// Every store, in order.
+    pub const ALL: [u8; 5] = [FOOD, WATER, OXYGEN, POWER, FUEL];
+
+    /// Display name of a store
+    pub fn name(stock: u8) -> &'static str {
+        match stock {
+            FOOD => "Food",
+            WATER => "Water",
+            OXYGEN => "Oxygen",
+            POWER => "Power reserves",
+            FUEL => "Fuel",
+            _ => "Unknown",
+        }
+    }
+}
+
+/// What a ship's computer advisory is about.
+pub mod advisory_kinds {
+    /// A store running down.
+    pub const STOCK: u8 = 0;
+    /// A ship system in poor health.
+    pub const SYSTEM: u8 = 1;
+    /// The grid shedding loads.
+    pub const POWER: u8 = 2;
+    /// A room packed past its capacity.
+    pub const CROWDING: u8 = 3;
+
+    /// Display name of an advisory kind
+    pub fn name(kind: u8) -> &'static str {
+        match kind {
+            STOCK => "Stores",
+            SYSTEM => "Ship Systems",
+            POWER => "Power Grid",
+            CROWDING => "Crowding",
+            _ => "Unknown",
+        }
+    }
+}
+
+/// How serious a ship's computer advisory is.
+pub mod advisory_levels {
+    /// Worth keeping an eye on.
+    pub const CAUTION: u8 = 0;
+    /// Needs seeing to soon.
+    pub const WARNING: u8 = 1;
+    /// Needs seeing to now.
+    pub const CRITICAL: u8 = 2;
+
+    /// Display name of an advisory level
+    pub fn name(level: u8) -> &'static str {
+        match level {
+            CAUTION => "Caution",
+            WARNING => "Warning",
+            CRITICAL => "Critical",
+            _ => "Unknown",
+        }
+    }
+}
+
+/// Things people carry (see `progship_logic::inventory`).
+pub mod item_kinds {
+    /// Tools engineers need to repair at full speed.
+    pub const TOOLKIT: u8 = 0;
+    /// Something of sentimental value from home.
+    pub const KEEPSAKE: u8 = 1;
+    /// A packed meal eaten when the galley is empty.
+    pub const RATION: u8 = 2;
+    /// Goods security confiscates.
+    pub const CONTRABAND: u8 = 3;
+
+    /// Display name of an item kind
+    pub fn name(kind: u8) -> &'static str {
+        match kind {
+            TOOLKIT => "Toolkit",
+            KEEPSAKE => "Keepsake",
+            RATION => "Ration",
+            CONTRABAND => "Contraband",
+            _ => "Unknown",
+        }
+    }
+}
+
+pub mod stream_votes {
+    /// Security sweeps a deck without warning.
+    pub const SURPRISE_INSPECTION: u8 = 0;
+    /// A party breaks out somewhere aboard.
+    pub const CELEBRATION: u8 = 1;
+    /// The science teams turn something up.
+    pub const DISCOVERY: u8 = 2;
+    /// Non-essential crew drill going to quarters.
+    pub const QUARTERS_DRILL: u8 = 3;
+    /// A fight breaks out.
+    pub const ALTERCATION: u8 = 4;
+
+    /// Display name of a stream vote
+    pub fn name(vote: u8) -> &'static str {
+        match vote {
+            SURPRISE_INSPECTION => "Surprise Inspection",
+            CELEBRATION => "Celebration",
+            DISCOVERY => "Discovery",
+            QUARTERS_DRILL => "Quarters Drill",
+            ALTERCATION => "Altercation",
+            _ => "Unknown",
+        }
+    }
+}
+
+pub mod stream_actions {
+    /// Chat voted for a ship event (see stream_votes).
+    pub const VOTE: u8 = 0;
+    /// A subscriber's name was given to a newborn.
+    pub const NAME_NEWBORN: u8 = 1;
+    /// An NPC was put in the spotlight.
+    pub const SPOTLIGHT: u8 = 2;
+    /// Number of stream action kinds.
+    pub const COUNT: usize = 3;
+
+    /// Display name of a stream action
+    pub fn name(action: u8) -> &'static str {
+        match action {
+            VOTE => "Vote",
+            NAME_NEWBORN => "Name Newborn",
+            SPOTLIGHT => "Spotlight",
+            _ => "Unknown",
+        }
+    }
+}
+
+/// How a department or the whole ship is holding up (see
+/// `progship_logic::morale`).
+pub mod morale_moods {
+    /// Neither low nor high.
+    pub const STEADY: u8 = 0;
+    /// Morale is low.
+    pub const LOW: u8 = 1;
+    /// Morale is critically low.
+    pub const CRITICAL: u8 = 2;
+    /// In high spirits.
+    pub const HIGH: u8 = 3;
+
+    /// Display name of a mood
+    pub fn name(mood: u8) -> &'static str {
+        match mood {
+            STEADY => "Steady",
+            LOW => "Low",
+            CRITICAL => "Critical",
+            HIGH => "High Spirits",
+            _ => "Unknown",
+        }
+    }
+}
+
+/// Political blocs aboard.
+pub mod factions {
+    /// Enlisted crew standing together.
+    pub const CREW_UNION: u8 = 0;
+    /// The passengers' voice in how the ship is run.
+    pub const COLONIST_COUNCIL: u8 = 1;
+    /// The ship's religious fellowship.
+    pub const RELIGIOUS: u8 = 2;
+    /// Officers and investors answering to the sponsoring company.
+    pub const CORPORATE: u8 = 3;
+    /// Number of factions.
+    pub const COUNT: usize = 4;
+
+    /// Display name of a faction
+    pub fn name(faction: u8) -> &'static str {
+        match faction {
+            CREW_UNION => "Crew Union",
+            COLONIST_COUNCIL => "Colonist Council",
+            RELIGIOUS => "Fellowship",
+            CORPORATE => "Corporate",
+            _ => "Unknown",
+        }
+    }
+}
+
+/// Offenses in the incident log.
+pub mod incident_kinds {
+    /// Something taken from someone.
+    pub const THEFT: u8 = 0;
+    /// Equipment deliberately broken.
+    pub const VANDALISM: u8 = 1;
+    /// Someone attacked.
+    pub const ASSAULT: u8 = 2;
+    /// A ringleader of a mutiny security put down.
+    pub const MUTINY: u8 = 3;
+    /// Drunk and disorderly.
+    pub const DRUNKENNESS: u8 = 4;
+
+    /// Display name of an incident kind
+    pub fn name(kind: u8) -> &'static str {
+        match kind {
+            THEFT => "Theft",
+            VANDALISM => "Vandalism",
+            ASSAULT => "Assault",
+            MUTINY => "Mutiny",
+            DRUNKENNESS => "Drunk and Disorderly",
+            _ => "Unknown",
+        }
+    }
+}
+
+/// What mutineers demand (see `progship_logic::mutiny`).
+pub mod mutiny_demands {
+    /// Shorter watches.
+    pub const SHORTER_WATCHES: u8 = 0;
+    /// An end to rationing.
+    pub const END_RATIONING: u8 = 1;
+    /// The curfew lifted.
+    pub const LIFT_CURFEW: u8 = 2;
+
+    /// Display name of a demand
+    pub fn name(demand: u8) -> &'static str {
+        match demand {
+            SHORTER_WATCHES => "Shorter Watches",
+            END_RATIONING => "End Rationing",
+            LIFT_CURFEW => "Lift the Curfew",
+            _ => "Unknown",
+        }
+    }
+}
+
+pub mod cargo_categories {
+    pub const COLONY_SUPPLIES: u8 = 0;
+    pub const MACHINERY: u8 = 1;
+    pub const SEED_VAULT: u8 = 2;
+    pub const PERSONAL_EFFECTS: u8 = 3;
+    pub const SPARE_PARTS: u8 = 4;
+    pub const MEDICAL_SUPPLIES: u8 = 5;
+    pub const RAW_MATERIALS: u8 = 6;
+
+    /// Display name of a cargo category
+    pub fn name(category: u8) -> &'static str {
+        match category {
+            COLONY_SUPPLIES => "Colony Supplies",
+            MACHINERY => "Machinery",
+            SEED_VAULT => "Seed Vault",
+            PERSONAL_EFFECTS => "Personal Effects",
+            SPARE_PARTS => "Spare Parts",
+            MEDICAL_SUPPLIES => "Medical Supplies",
+            RAW_MATERIALS => "Raw Materials",
+            _ => "Unknown",
+        }
+    }
+}
+
+pub mod edge_types {
+    pub const CREW_PATH: u8 = 0;
+    pub const POWER: u8 = 1;
+    pub const WATER: u8 = 2;
+    pub const COOLANT: u8 = 3;
+    pub const HVAC: u8 = 4;
+    pub const DATA: u8 = 5;
+    pub const STRUCTURAL: u8 = 6;
+}
+
+pub mod wall_sides {
+    pub const NORTH: u8 = 0;
+    pub const SOUTH: u8 = 1;
+    pub const EAST: u8 = 2;
+    pub const WEST: u8 = 3;
+}
+
+pub mod door_states {
+    /// Open; anyone it admits walks through.
+    pub const OPEN: u8 = 0;
+    /// Shut but free to open.
+    pub const CLOSED: u8 = 1;
+    /// Shut and locked by security or sealed in an emergency.
+    pub const LOCKED: u8 = 2;
+    /// Welded or jammed shut; must be cut free before it opens again.
+    pub const WELDED: u8 = 3;
+
+    /// Display name of a door state
+    pub fn name(state: u8) -> &'static str {
+        match state {
+            OPEN => "Open",
+            CLOSED => "Closed",
+            LOCKED => "Locked",
+            WELDED => "Welded Shut",
+            _ => "Unknown",
+        }
+    }
+}
+
+pub mod hatch_types {
+    pub const AIRLOCK: u8 = 0;
+    pub const EVA: u8 = 1;
+    pub const BAY_DOOR: u8 = 2;
+
+    /// Display name of a hull hatch type
+    pub fn name(hatch_type: u8) -> &'static str {
+        match hatch_type {
+            AIRLOCK => "Airlock Outer Door",
+            EVA => "EVA Hatch",
+            BAY_DOOR => "Bay Door",
+            _ => "Unknown",
+        }
+    }
+}
+
+pub mod corridor_types {
+    pub const MAIN: u8 = 0;
+    pub const SERVICE: u8 = 1;
+    pub const BRANCH: u8 = 2;
+    pub const CRAWLWAY: u8 = 3;
+}
+
+pub mod shaft_types {
+    pub const ELEVATOR: u8 = 0;
+    pub const SERVICE_ELEVATOR: u8 = 1;
+    pub const LADDER: u8 = 2;
+}
+
+/// Utilities a corridor carries, as a bitmask.
+pub mod carries_flags {
+    pub const CREW_PATH: u8 = 1;
+    pub const POWER: u8 = 2;
+    pub const WATER: u8 = 4;
+    pub const HVAC: u8 = 8;
+    pub const DATA: u8 = 16;
+    pub const COOLANT: u8 = 32;
+}
+
+pub mod furniture_types {
+    pub const BED: u8 = 0;
+    pub const BUNK: u8 = 1;
+    pub const MEDICAL_BED: u8 = 2;
+    pub const TABLE: u8 = 3;
+    pub const CONSOLE: u8 = 4;
+    pub const WORKBENCH: u8 = 5;
+    pub const HYDROPONIC_RACK: u8 = 6;
+    pub const STORAGE_RACK: u8 = 7;
+    pub const COUNTER: u8 = 8;
+    pub const CRYO_POD: u8 = 9;
+
+    /// Returns true if this furniture type is something a person sleeps in
+    pub fn is_bed(ft: u8) -> bool {
+        matches!(ft, BED | BUNK | MEDICAL_BED)
+    }
+}
+
+pub mod anchor_types {
+    pub const SERVING_LINE: u8 = 0;
+    pub const DINING_SEAT: u8 = 1;
+    pub const TREADMILL: u8 = 2;
+    pub const CONSOLE: u8 = 3;
+    pub const OPERATING_TABLE: u8 = 4;
+    pub const WORKBENCH: u8 = 5;
+    pub const PLANTER: u8 = 6;
+    pub const COUNTER: u8 = 7;
+    pub const BED: u8 = 8;
+    pub const SEAT: u8 = 9;
+}
+
+pub mod postures {
+    pub const STANDING: u8 = 0;
+    pub const SITTING: u8 = 1;
+    pub const LYING: u8 = 2;
+    pub const WORKING: u8 = 3;
+
+    /// Display name of a posture
+    pub fn name(posture: u8) -> &'static str {
+        match posture {
+            STANDING => "Standing",
+            SITTING => "Sitting",
+            LYING => "Lying",
+            WORKING => "Working",
+            _ => "Unknown",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::deck_heights::*;
+    use super::room_types;
+
+    #[test]
+    fn standard_rooms_single_deck() {
+        assert_eq!(room_deck_span(room_types::BRIDGE), 1);
+        assert_eq!(room_deck_span(room_types::CABIN_SINGLE), 1);
+        assert_eq!(room_deck_span(room_types::CORRIDOR), 1);
+        assert_eq!(room_deck_span(room_types::GYM), 1);
+    }
+
+    #[test]
+    fn multi_deck_rooms() {
+        assert_eq!(room_deck_span(room_types::REACTOR), 2);
+        assert_eq!(room_deck_span(room_types::ENGINE_ROOM), 2);
+        assert_eq!(room_deck_span(room_types::CARGO_BAY), 2);
+        assert_eq!(room_deck_span(room_types::SHUTTLE_BAY), 3);
+        assert_eq!(room_deck_span(room_types::ENGINEERING), 2);
+        assert_eq!(room_deck_span(room_types::BACKUP_REACTOR), 2);
+        assert_eq!(room_deck_span(room_types::FUEL_STORAGE), 2);
+        assert_eq!(room_deck_span(room_types::HYDROPONICS), 2);
+        assert_eq!(room_deck_span(room_types::ARBORETUM), 2);
+        assert_eq!(room_deck_span(room_types::THEATRE), 2);
+        assert_eq!(room_deck_span(room_types::POOL), 2);
+    }
+
+    #[test]
+    fn ceiling_heights() {
+        assert!((room_ceiling_height(room_types::BRIDGE) - 3.5).abs() < 0.001);
+        assert!((room_ceiling_height(room_types::REACTOR) - 7.0).abs() < 0.001);
+        assert!((room_ceiling_height(room_types::CORRIDOR) - 3.5).abs() < 0.001);
+        assert!((room_ceiling_height(room_types::SHUTTLE_BAY) - 10.5).abs() < 0.001);
+    }
+
+    #[test]
+    fn door_heights_standard() {
+        let h = door_opening_height(room_types::CORRIDOR, room_types::CABIN_SINGLE);
+        assert!((h - STANDARD_DOOR_HEIGHT).abs() < 0.001);
+    }
+
+    #[test]
+    fn door_heights_equipment() {
+        let h = door_opening_height(room_types::CORRIDOR, room_types::CARGO_BAY);
+        assert!((h - EQUIPMENT_DOOR_HEIGHT).abs() < 0.001);
+        let h2 = door_opening_height(room_types::ENGINE_ROOM, room_types::CORRIDOR);
+        assert!((h2 - EQUIPMENT_DOOR_HEIGHT).abs() < 0.001);
+    }
+
+    #[test]
+    fn door_heights_both_standard() {
+        let h = door_opening_height(room_types::MESS_HALL, room_types::GALLEY);
+        assert!((h - STANDARD_DOOR_HEIGHT).abs() < 0.001);
+    }
+
+    #[test]
+    fn placement_constraints() {
+        use super::placement;
+        assert_eq!(
+            placement::room_placement(room_types::SHUTTLE_BAY),
+            placement::HULL_FACING
+        );
+        assert_eq!(
+            placement::room_placement(room_types::REACTOR),
+            placement::INTERIOR
+        );
+        assert_eq!(
+            placement::room_placement(room_types::ENGINE_ROOM),
+            placement::AFT
+        );
+        assert_eq!(
+            placement::room_placement(room_types::BRIDGE),
+            placement::FORWARD
+        );
+        assert_eq!(
+            placement::room_placement(room_types::CABIN_SINGLE),
+            placement::NONE
+        );
+    }
+
+    #[test]
+    fn placement_from_str() {
+        use super::placement;
+        assert_eq!(placement::from_str("hull_facing"), placement::HULL_FACING);
+        assert_eq!(placement::from_str("interior"), placement::INTERIOR);
+        assert_eq!(placement::from_str("aft"), placement::AFT);
+        assert_eq!(placement::from_str("forward"), placement::FORWARD);
+        assert_eq!(placement::from_str("none"), placement::NONE);
+        assert_eq!(placement::from_str(""), placement::NONE);
+    }
+
+    #[test]
+    fn room_type_names_cover_every_type() {
+        for rt in 0..=u8::MAX {
+            let icon = super::room_type_icon(rt);
+            if !icon.is_empty() {
+                assert_ne!(room_types::name(rt), "Unknown", "room type {}", rt);
+            }
+        }
+        assert_eq!(room_types::name(room_types::MESS_HALL), "Mess Hall");
+        assert_eq!(room_types::name(room_types::ELEVATOR_SHAFT), "Elevator");
+        assert_eq!(room_types::name(room_types::CRAWLWAY), "Crawlway");
+        assert_eq!(room_types::name(200), "Unknown");
+    }
+
+    #[test]
+    fn display_names() {
+        use super::*;
+        assert_eq!(activity_types::name(activity_types::ON_DUTY), "On Duty");
+        assert_eq!(departments::name(departments::CIVILIAN), "Civilian");
+        assert_eq!(ranks::name(ranks::PETTY), "Petty Officer");
+        assert_eq!(certifications::name(certifications::ADVANCED), "Advanced");
+        assert_eq!(shifts::name(shifts::GAMMA), "Gamma (22:00-06:00)");
+        assert_eq!(event_types::name(event_types::DEATH), "Death");
+        assert_eq!(event_types::name(event_types::DIAGNOSTIC), "Diagnostic");
+        assert_eq!(event_types::name(event_types::SOLAR_FLARE), "Solar Flare");
+        assert_eq!(event_types::name(event_types::FUNERAL), "Funeral");
+        assert_eq!(event_types::name(event_types::STRIKE), "Strike");
+        assert_eq!(event_types::name(event_types::PROMOTION), "Promotion");
+        assert_eq!(
+            event_types::name(event_types::VOYAGE_MILESTONE),
+            "Voyage Milestone"
+        );
+        assert_eq!(milestone_kinds::name(milestone_kinds::TURNOVER), "Turnover");
+        assert_eq!(event_types::name(event_types::REVIVAL), "Cryo Revival");
+        assert_eq!(morale_moods::name(morale_moods::HIGH), "High Spirits");
+        assert_eq!(
+            stream_votes::name(stream_votes::SURPRISE_INSPECTION),
+            "Surprise Inspection"
+        );
+        assert_eq!(stream_actions::name(stream_actions::SPOTLIGHT), "Spotlight");
+        assert_eq!(
+            death_causes::name(death_causes::DECOMPRESSION),
+            "Decompression"
+        );
+        assert_eq!(death_causes::name(death_causes::OLD_AGE), "Old Age");
+        assert_eq!(voyage_outcomes::name(voyage_outcomes::LOST), "Ship Lost");
+        assert_eq!(difficulties::name(difficulties::TUTORIAL), "Tutorial");
+        assert_eq!(ui_screens::name(ui_screens::SHIP_OVERVIEW), "Ship Overview");
+        assert_eq!(ui_screens::name(ui_screens::ENCYCLOPEDIA), "Shipopedia");
+        assert_eq!(
+            loss_causes::name(loss_causes::FUEL_EXHAUSTED),
+            "Fuel Exhausted"
+        );
+        assert_eq!(
+            order_kinds::name(order_kinds::SECURITY_SWEEP),
+            "Security Sweep"
+        );
+        assert_eq!(work_kinds::name(work_kinds::PIPE_REPAIR), "Pipe Repair");
+        assert_eq!(triage_levels::name(triage_levels::CRITICAL), "Critical");
+        assert_eq!(
+            patient_states::name(patient_states::WAITING),
+            "Waiting for a Bed"
+        );
+        assert_eq!(work_kinds::name(work_kinds::FABRICATION), "Fabrication");
+        assert_eq!(
+            surgery_outcomes::name(surgery_outcomes::COMPLICATION),
+            "Complication"
+        );
+        assert_eq!(activity_types::name(activity_types::SURGERY), "Surgery");
+        assert_eq!(lighting_modes::name(lighting_modes::EVENING), "Evening");
+        assert_eq!(item_kinds::name(item_kinds::RATION), "Ration");
+        assert_eq!(work_kinds::name(work_kinds::EVA_REPAIR), "EVA Repair");
+        assert_eq!(work_kinds::name(work_kinds::CRYO_CHECK), "Cryo Check");
+        assert_eq!(work_kinds::name(work_kinds::ROBOT_REPAIR), "Robot Repair");
+        assert_eq!(work_kinds::name(work_kinds::GARDENING), "Gardening");
+        assert_eq!(work_kinds::name(work_kinds::DUCT_CLEANING), "Duct Cleaning");
+        assert_eq!(robot_states::name(robot_states::BROKEN), "Broken Down");
+        assert_eq!(eva_phases::name(eva_phases::OUTBOUND), "Crossing the Hull");
+        assert_eq!(
+            voyage_phases::name(voyage_phases::DECELERATING),
+            "Decelerating"
+        );
+        assert_eq!(
+            contact_kinds::name(contact_kinds::MICROMETEORITE),
+            "Micrometeorite"
+        );
+        assert_eq!(stocks::name(stocks::POWER), "Power reserves");
+        assert_eq!(advisory_kinds::name(advisory_kinds::CROWDING), "Crowding");
+        assert_eq!(advisory_levels::name(advisory_levels::CRITICAL), "Critical");
+        assert_eq!(death_causes::name(death_causes::SURGERY), "Died in Surgery");
+        assert_eq!(part_kinds::name(part_kinds::SEALS), "Seals & Filters");
+        assert_eq!(
+            cargo_categories::name(cargo_categories::RAW_MATERIALS),
+            "Raw Materials"
+        );
+        assert_eq!(
+            work_order_states::name(work_order_states::CLAIMED),
+            "Claimed"
+        );
+        assert_eq!(door_states::name(door_states::WELDED), "Welded Shut");
+        assert_eq!(incident_kinds::name(incident_kinds::ASSAULT), "Assault");
+        assert_eq!(
+            incident_kinds::name(incident_kinds::DRUNKENNESS),
+            "Drunk and Disorderly"
+        );
+        assert_eq!(
+            mutiny_demands::name(mutiny_demands::LIFT_CURFEW),
+            "Lift the Curfew"
+        );
+        assert_eq!(
+            factions::name(factions::COLONIST_COUNCIL),
+            "Colonist Council"
+        );
+        assert_eq!(
+            effect_kinds::name(effect_kinds::COOLANT_SPILL),
+            "Coolant Spill"
+        );
+        assert_eq!(
+            cargo_categories::name(cargo_categories::SEED_VAULT),
+            "Seed Vault"
+        );
+        assert_eq!(system_statuses::label(system_statuses::NOMINAL), "OK");
+        assert_eq!(
+            conversation_topics::name(conversation_topics::FAREWELL),
+            "Farewell"
+        );
+        assert_eq!(family_roles::name(family_roles::GRANDPARENT), "Grandparent");
+        assert_eq!(postures::name(postures::LYING), "Lying");
+        assert_eq!(life_stages::name(life_stages::ELDER), "Elder");
+        assert_eq!(emotions::name(emotions::GRIEVING), "Grieving");
+        assert_eq!(hatch_types::name(hatch_types::EVA), "EVA Hatch");
+        assert_eq!(
+            command_posts::name(command_posts::EXECUTIVE_OFFICER),
+            "Executive Officer"
+        );
+        assert_eq!(ranks::name(99), "Unknown");
+    }
+
+    #[test]
+    fn life_stage_from_age() {
+        use super::life_stages::*;
+        assert_eq!(from_age(0), INFANT);
+        assert_eq!(from_age(SCHOOL_AGE), CHILD);
+        assert_eq!(from_age(17), CHILD);
+        assert_eq!(from_age(ADULT_AGE), ADULT);
+        assert_eq!(from_age(ELDER_AGE), ELDER);
+    }
+
+    #[test]
+    fn kin_relationships() {
+        use super::relationship_types::*;
+        assert!(is_kin(FAMILY) && is_kin(ROMANTIC));
+        assert!(!is_kin(CLOSE_FRIEND) && !is_kin(STRANGER));
+    }
+}
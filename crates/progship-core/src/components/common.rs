@@ -182,6 +182,10 @@ pub struct Movement {
     pub entry_door_positions: Vec<Vec3>,
     /// Exit door positions for each room in path
     pub exit_door_positions: Vec<Vec3>,
+    /// Seconds to pause on entering each room in path (elevator waits, shaft travel)
+    pub transit_waits: Vec<f32>,
+    /// Seconds left before moving again (waiting for or riding a shaft)
+    pub wait_remaining: f32,
 }
 
 impl Movement {
@@ -195,6 +199,8 @@ impl Movement {
             next_door_position: None,
             entry_door_positions: Vec::new(),
             exit_door_positions: Vec::new(),
+            transit_waits: Vec::new(),
+            wait_remaining: 0.0,
         }
     }
 
@@ -364,6 +364,21 @@ impl Faction {
         }
     }
 
+    /// Display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Faction::Command => "Command",
+            Faction::Engineering => "Engineering",
+            Faction::Medical => "Medical",
+            Faction::Science => "Science",
+            Faction::Security => "Security",
+            Faction::Operations => "Operations",
+            Faction::PassengersFirst => "First Class",
+            Faction::PassengersStandard => "Standard Class",
+            Faction::PassengersSteerage => "Steerage",
+        }
+    }
+
     /// Base affinity between factions (-1.0 to 1.0)
     /// Positive = friendly, Negative = rivalry
     pub fn affinity_with(&self, other: &Faction) -> f32 {
@@ -19,6 +19,12 @@ pub struct Room {
     pub capacity: u32,
     /// Legacy deck_id for backwards compatibility
     pub deck_id: u32,
+    /// Door access level required to enter (see `progship_logic::security::access_levels`)
+    #[serde(default)]
+    pub access_level: u8,
+    /// Department that owns a department-restricted room
+    #[serde(default)]
+    pub department: Option<u8>,
 }
 
 impl Room {
@@ -32,6 +38,8 @@ impl Room {
             deck_level: 0,
             capacity: ((width * depth) / 4.0).max(1.0) as u32, // ~4 sq meters per person
             deck_id: 0,
+            access_level: 0,
+            department: None,
         }
     }
 
@@ -58,6 +66,12 @@ impl Room {
         self
     }
 
+    pub fn with_access(mut self, access_level: u8, department: Option<u8>) -> Self {
+        self.access_level = access_level;
+        self.department = department;
+        self
+    }
+
     /// Room width (x dimension)
     pub fn width(&self) -> f32 {
         self.bounds.max.x - self.bounds.min.x
@@ -173,6 +187,7 @@ pub enum RoomType {
     Airlock,
     Corridor,
     Elevator,
+    Ladder,
 
     // Science
     Laboratory,
@@ -209,7 +224,9 @@ impl RoomType {
             RoomType::Recreation | RoomType::Gym => {
                 vec![ActivityType::Relaxing, ActivityType::Socializing]
             }
-            RoomType::Corridor | RoomType::Elevator => vec![ActivityType::Traveling],
+            RoomType::Corridor | RoomType::Elevator | RoomType::Ladder => {
+                vec![ActivityType::Traveling]
+            }
             _ => vec![ActivityType::Working],
         }
     }
@@ -1,11 +1,33 @@
 //! Simulation engine - main entry point for running the simulation
+//!
+//! `update` takes the host's frame delta but advances the simulation in fixed
+//! steps of `1 / tick_rate` sim-seconds, carrying the remainder to the next
+//! frame, so a run plays out the same at 30 or 144 frames per second.
+//! Renderers blend between the last two steps with `interpolation_alpha` and
+//! `interpolated_position`.
 
 use crate::components::*;
 use crate::generation::{
-    generate_crew, generate_passengers, generate_ship, ShipConfig, ShipLayout,
+    generate_crew, generate_passengers, generate_ship, FeatureFlags, ShipConfig, ShipLayout,
 };
 use crate::systems::*;
-use hecs::World;
+use hecs::{Entity, World};
+use progship_logic::constants::{event_types, voyage_outcomes};
+use progship_logic::final_report::{
+    tally, FactionSummary, FinalReport, Incident, PopulationPoint, ResourceMargin, NOTABLE_SEVERITY,
+};
+use progship_logic::history::sim_day;
+use std::collections::HashMap;
+
+/// Default fixed steps per simulated second
+pub const DEFAULT_TICK_RATE: f32 = 20.0;
+
+/// Most fixed steps one `update` runs; an overloaded host drops the time
+/// beyond them instead of the step growing
+pub const MAX_STEPS_PER_UPDATE: u32 = 2000;
+
+/// Sim-seconds per step while fast-forwarding
+pub const FAST_FORWARD_STEP_SECONDS: f32 = 60.0;
 
 /// Main simulation engine
 pub struct SimulationEngine {
@@ -34,9 +56,19 @@ pub struct SimulationEngine {
     last_wandering_update: f64,
     last_duty_update: f64,
     last_events_update: f64,
+    last_watchdog_update: f64,
+
+    // Fixed timestep
+    tick_rate: f32,
+    /// Scaled seconds not yet simulated
+    accumulator: f64,
+    /// Positions at the start of the latest step, for interpolation
+    previous_positions: HashMap<Entity, (u32, Vec3)>,
 
     // Configuration
     time_scale: f32,
+    /// Optional systems to run
+    features: FeatureFlags,
 }
 
 impl SimulationEngine {
@@ -58,13 +90,19 @@ impl SimulationEngine {
             last_wandering_update: 0.0,
             last_duty_update: 0.0,
             last_events_update: 0.0,
+            last_watchdog_update: 0.0,
+            tick_rate: DEFAULT_TICK_RATE,
+            accumulator: 0.0,
+            previous_positions: HashMap::new(),
             time_scale: 1.0,
+            features: FeatureFlags::default(),
         }
     }
 
     /// Generate a complete ship with crew and passengers
     pub fn generate(&mut self, config: ShipConfig) {
         let mut rng = rand::thread_rng();
+        self.features = config.features;
 
         // Generate ship structure
         let layout = generate_ship(&mut self.world, &config, &mut rng);
@@ -83,9 +121,56 @@ impl SimulationEngine {
         self.ship_layout = Some(layout);
     }
 
-    /// Update the simulation by delta_seconds
-    pub fn update(&mut self, delta_seconds: f32) {
-        let scaled_delta = delta_seconds * self.time_scale;
+    /// Advance the simulation by `delta_seconds` of real time, scaled by the
+    /// time scale, in fixed steps; returns the number of steps run
+    pub fn update(&mut self, delta_seconds: f32) -> u32 {
+        let scaled = delta_seconds as f64 * self.time_scale as f64;
+        if scaled.is_finite() && scaled > 0.0 {
+            self.accumulator += scaled;
+        }
+        let step = self.fixed_step();
+        let mut steps = 0;
+        while self.accumulator >= step && steps < MAX_STEPS_PER_UPDATE {
+            self.accumulator -= step;
+            // Only the last step's starting positions are blended from
+            if self.accumulator < step || steps + 1 == MAX_STEPS_PER_UPDATE {
+                self.snapshot_positions();
+            }
+            self.step(step as f32);
+            steps += 1;
+        }
+        // Drop what the cap left over, or the debt outgrows every update
+        if steps == MAX_STEPS_PER_UPDATE {
+            self.accumulator = self.accumulator.min(step);
+        }
+        steps
+    }
+
+    /// Warm start: advance `days` (up to 30) in coarse one-minute steps, so a
+    /// new ship already has relationships, wear and history; returns the
+    /// number of steps run
+    pub fn fast_forward(&mut self, days: f32) -> u32 {
+        let step_hours = FAST_FORWARD_STEP_SECONDS as f64 / 3600.0;
+        let steps = progship_logic::warm_start::fast_forward_steps(days, step_hours);
+        for _ in 0..steps {
+            self.step(FAST_FORWARD_STEP_SECONDS);
+        }
+        // Nothing to blend from after a jump
+        self.previous_positions.clear();
+        steps
+    }
+
+    /// Record everyone's position as the start of the next step
+    fn snapshot_positions(&mut self) {
+        self.previous_positions.clear();
+        for (entity, pos) in self.world.query::<&Position>().iter() {
+            self.previous_positions
+                .insert(entity, (pos.room_id, pos.local));
+        }
+    }
+
+    /// Run one fixed step of `scaled_delta` sim-seconds
+    fn step(&mut self, scaled_delta: f32) {
         let delta_hours = scaled_delta as f64 / 3600.0;
         self.sim_time += delta_hours;
 
@@ -174,11 +259,79 @@ impl SimulationEngine {
         if self.sim_time - self.last_events_update >= events_interval {
             let mut rng = rand::thread_rng();
 
-            generate_random_events(&self.world, &mut self.events, self.sim_time, &mut rng);
+            generate_random_events(
+                &self.world,
+                &mut self.events,
+                &self.features,
+                self.sim_time,
+                &mut rng,
+            );
             dispatch_emergency_responders(&mut self.world, &mut self.events, self.sim_time);
 
             self.last_events_update = self.sim_time;
         }
+
+        // T4: Invariant watchdog (hourly)
+        let watchdog_interval = progship_logic::watchdog::WATCHDOG_INTERVAL_HOURS;
+        if self.sim_time - self.last_watchdog_update >= watchdog_interval {
+            if let Some(layout) = &self.ship_layout {
+                watchdog_system(
+                    &self.world,
+                    &self.resources,
+                    &self.conversations,
+                    &mut self.events,
+                    layout.rooms.len(),
+                    self.sim_time,
+                );
+            }
+            self.last_watchdog_update = self.sim_time;
+        }
+    }
+
+    /// Length of one fixed step in sim-seconds
+    pub fn fixed_step(&self) -> f64 {
+        1.0 / self.tick_rate as f64
+    }
+
+    /// Set the fixed steps per simulated second (clamped to 1..=1000)
+    pub fn set_tick_rate(&mut self, hz: f32) {
+        self.tick_rate = if hz.is_finite() {
+            hz.clamp(1.0, 1000.0)
+        } else {
+            DEFAULT_TICK_RATE
+        };
+    }
+
+    /// Get the fixed steps per simulated second
+    pub fn tick_rate(&self) -> f32 {
+        self.tick_rate
+    }
+
+    /// How far the host is between the last step and the next, 0..1
+    pub fn interpolation_alpha(&self) -> f32 {
+        (self.accumulator / self.fixed_step()).clamp(0.0, 1.0) as f32
+    }
+
+    /// Local position of `entity` blended between the last two steps by
+    /// `interpolation_alpha`; snaps to the current position after a room change
+    pub fn interpolated_position(&self, entity: Entity) -> Option<Vec3> {
+        let pos = self.world.get::<&Position>(entity).ok()?;
+        match self.previous_positions.get(&entity) {
+            Some(&(room_id, previous)) if room_id == pos.room_id => {
+                Some(previous + (pos.local - previous) * self.interpolation_alpha())
+            }
+            _ => Some(pos.local),
+        }
+    }
+
+    /// Set which optional systems run from now on
+    pub fn set_features(&mut self, features: FeatureFlags) {
+        self.features = features;
+    }
+
+    /// Get which optional systems run
+    pub fn features(&self) -> FeatureFlags {
+        self.features
     }
 
     /// Set time scale (1.0 = real-time, 2.0 = 2x speed, etc.)
@@ -236,10 +389,131 @@ impl SimulationEngine {
         find_urgent_needs(&self.world, threshold)
     }
 
+    /// Report on the voyage so far (see `progship_logic::final_report`). The
+    /// core engine models no deaths, health or milestones, so everyone
+    /// counts as alive and healthy, and morale is how well needs are met.
+    pub fn final_report(&self) -> FinalReport {
+        let mut factions: Vec<(Faction, u32, f32)> = Vec::new();
+        let mut morale_sum = 0.0;
+        let mut alive = 0;
+        for (_, (_, crew, passenger, needs)) in self
+            .world
+            .query::<(&Person, Option<&Crew>, Option<&Passenger>, Option<&Needs>)>()
+            .iter()
+        {
+            let morale = needs.map_or(1.0, |n| {
+                1.0 - (n.hunger + n.fatigue + n.social + n.comfort + n.hygiene) / 5.0
+            });
+            alive += 1;
+            morale_sum += morale;
+            let faction = match (crew, passenger) {
+                (Some(c), _) => Faction::from_department(c.department),
+                (None, Some(p)) => Faction::from_cabin_class(p.cabin_class),
+                (None, None) => continue,
+            };
+            match factions.iter_mut().find(|(f, _, _)| *f == faction) {
+                Some(entry) => {
+                    entry.1 += 1;
+                    entry.2 += morale;
+                }
+                None => factions.push((faction, 1, morale)),
+            }
+        }
+
+        let room_name = |room_id: u32| {
+            self.ship_layout
+                .as_ref()
+                .and_then(|layout| layout.rooms.get(room_id as usize))
+                .and_then(|&e| self.world.get::<&Room>(e).ok().map(|r| r.name.clone()))
+                .unwrap_or_else(|| format!("Room {}", room_id))
+        };
+        let events = &self.events.events;
+        let resources = [
+            ("Power", ResourceType::Power),
+            ("Water", ResourceType::Water),
+            ("Oxygen", ResourceType::Oxygen),
+            ("Food", ResourceType::Food),
+            ("Fuel", ResourceType::Fuel),
+            ("Coolant", ResourceType::Coolant),
+            ("Spare Parts", ResourceType::SpareParts),
+        ];
+
+        FinalReport {
+            ship_name: self
+                .ship_layout
+                .as_ref()
+                .map(|l| l.name.clone())
+                .unwrap_or_default(),
+            outcome: voyage_outcomes::IN_PROGRESS,
+            sim_time: self.sim_time,
+            departed: alive,
+            alive,
+            population: vec![PopulationPoint {
+                day: sim_day(self.sim_time),
+                alive,
+            }],
+            incident_counts: tally(
+                events
+                    .iter()
+                    .map(|e| e.event_type.type_id())
+                    .filter(|&t| t != event_types::DIAGNOSTIC),
+                event_types::name,
+            ),
+            incidents: events
+                .iter()
+                .map(|e| (e, e.event_type.severity() as f32 / 5.0))
+                .filter(|&(e, severity)| {
+                    severity >= NOTABLE_SEVERITY && e.event_type != EventType::Diagnostic
+                })
+                .map(|(e, severity)| Incident {
+                    event_type: e.event_type.type_id(),
+                    started_at: e.started_at,
+                    severity,
+                    place: room_name(e.room_id),
+                })
+                .collect(),
+            factions: factions
+                .into_iter()
+                .map(|(faction, members, morale)| FactionSummary {
+                    name: faction.name().to_string(),
+                    departed: members,
+                    alive: members,
+                    mean_morale: morale / members as f32,
+                })
+                .collect(),
+            resources: resources
+                .into_iter()
+                .map(|(name, resource)| ResourceMargin {
+                    resource: name.to_string(),
+                    amount: self.resources.storage.get(resource),
+                    capacity: self.resources.capacity.get(resource),
+                })
+                .collect(),
+            mean_health: 1.0,
+            mean_morale: if alive > 0 {
+                morale_sum / alive as f32
+            } else {
+                0.0
+            },
+            ..FinalReport::default()
+        }
+        .finish()
+    }
+
     /// Save simulation state to a writer
     pub fn save<W: std::io::Write>(&self, writer: W) -> Result<(), crate::persistence::SaveError> {
+        self.save_as(writer, "Quicksave")
+    }
+
+    /// Save simulation state to a writer, recording `slot_name` in the header
+    pub fn save_as<W: std::io::Write>(
+        &self,
+        writer: W,
+        slot_name: &str,
+    ) -> Result<(), crate::persistence::SaveError> {
         crate::persistence::save_simulation(
             writer,
+            self.save_header(slot_name),
             &self.world,
             self.sim_time,
             self.time_scale,
@@ -252,6 +526,26 @@ impl SimulationEngine {
         )
     }
 
+    /// Summary of the current state for a save header
+    pub fn save_header(&self, slot_name: &str) -> crate::persistence::SaveHeader {
+        let saved_at = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        crate::persistence::SaveHeader {
+            slot_name: slot_name.to_string(),
+            saved_at,
+            ship_name: self
+                .ship_layout
+                .as_ref()
+                .map(|l| l.name.clone())
+                .unwrap_or_default(),
+            sim_time: self.sim_time,
+            crew_count: self.crew_count() as u32,
+            passenger_count: self.passenger_count() as u32,
+        }
+    }
+
     /// Load simulation state from a reader
     pub fn load<R: std::io::Read>(
         &mut self,
@@ -273,14 +567,78 @@ impl SimulationEngine {
             self.rebuild_ship_layout(layout_info);
         }
 
-        // Reset update timers
+        self.reset_update_timers();
+        Ok(())
+    }
+
+    /// Replace the simulation with a server state snapshot
+    ///
+    /// Ship systems, relationships, conversations and events start fresh;
+    /// see [`import_snapshot`](crate::persistence::import_snapshot).
+    pub fn load_snapshot(&mut self, snapshot: &progship_logic::snapshot::StateSnapshot) {
+        let imported = crate::persistence::import_snapshot(snapshot);
+
+        self.world = imported.world;
+        self.sim_time = imported.sim_time;
+        self.ship_layout = Some(imported.ship_layout);
+        self.resources = ShipResources::new();
+        self.maintenance_queue = MaintenanceQueue::new();
+        self.relationships = RelationshipGraph::new();
+        self.conversations = ConversationManager::new();
+        self.events = EventManager::new();
+
+        self.reset_update_timers();
+    }
+
+    /// Export the simulation as a server state snapshot, or `None` before a
+    /// ship is generated or loaded
+    ///
+    /// See [`export_snapshot`](crate::persistence::export_snapshot).
+    pub fn export_snapshot(&self) -> Option<progship_logic::snapshot::StateSnapshot> {
+        let layout = self.ship_layout.as_ref()?;
+        Some(crate::persistence::export_snapshot(
+            &self.world,
+            self.sim_time,
+            self.time_scale,
+            layout,
+            &self.resources,
+        ))
+    }
+
+    /// A key frame of every room and everyone aboard for external renderers,
+    /// or `None` before a ship is generated or loaded
+    pub fn frame(&self) -> Option<progship_logic::frame::Frame> {
+        self.export_snapshot()
+            .map(|s| progship_logic::frame::frame_from_snapshot(&s))
+    }
+
+    /// Load either a binary save or a JSON state snapshot, detected from the
+    /// contents
+    ///
+    /// Returns whether the data was a snapshot.
+    pub fn load_bytes(&mut self, bytes: &[u8]) -> Result<bool, crate::persistence::SaveError> {
+        use progship_logic::snapshot::StateSnapshot;
+        if !StateSnapshot::sniff(bytes) {
+            self.load(bytes)?;
+            return Ok(false);
+        }
+        let json = std::str::from_utf8(bytes)
+            .map_err(|e| crate::persistence::SaveError::Snapshot(e.to_string()))?;
+        let snapshot =
+            StateSnapshot::from_json(json).map_err(crate::persistence::SaveError::Snapshot)?;
+        self.load_snapshot(&snapshot);
+        Ok(true)
+    }
+
+    fn reset_update_timers(&mut self) {
         self.last_needs_update = self.sim_time;
         self.last_systems_update = self.sim_time;
         self.last_maintenance_update = self.sim_time;
         self.last_social_update = self.sim_time;
         self.last_wandering_update = self.sim_time;
-
-        Ok(())
+        self.last_watchdog_update = self.sim_time;
+        self.accumulator = 0.0;
+        self.previous_positions.clear();
     }
 
     /// Rebuild ship layout entity references from loaded world
@@ -314,9 +672,9 @@ impl SimulationEngine {
         });
 
         // Collect elevator rooms (rooms that appear on multiple decks)
-        // For simplicity, find rooms with room_type Elevator
+        // For simplicity, find rooms with a shaft room type
         for (entity, room) in self.world.query::<&Room>().iter() {
-            if room.room_type == RoomType::Elevator {
+            if matches!(room.room_type, RoomType::Elevator | RoomType::Ladder) {
                 elevators.push(entity);
             }
         }
@@ -328,6 +686,7 @@ impl SimulationEngine {
             elevators,
             ship_length: layout_info.ship_length,
             ship_width: layout_info.ship_width,
+            corridor_topology: layout_info.corridor_topology,
         });
     }
 }
@@ -390,6 +749,24 @@ mod tests {
         assert!((engine.sim_time() - 1.0).abs() < 0.01);
     }
 
+    #[test]
+    fn test_final_report() {
+        let mut engine = SimulationEngine::new();
+        engine.generate(ShipConfig {
+            num_decks: 2,
+            rooms_per_deck: 3,
+            crew_size: 10,
+            passenger_capacity: 20,
+            ..Default::default()
+        });
+        let report = engine.final_report();
+        assert_eq!((report.departed, report.alive), (30, 30));
+        assert_eq!(report.factions.iter().map(|f| f.departed).sum::<u32>(), 30);
+        assert_eq!(report.resources.len(), 7);
+        assert!(report.colony_readiness > 0.0);
+        assert!(report.to_markdown().contains("## Factions"));
+    }
+
     #[test]
     fn test_time_scale() {
         let mut engine = SimulationEngine::new();
@@ -400,4 +777,94 @@ mod tests {
         let expected_hours = 2.0 / 3600.0;
         assert!((engine.sim_time() - expected_hours).abs() < 0.0001);
     }
+
+    #[test]
+    fn test_fixed_step_ignores_frame_rate() {
+        let config = || ShipConfig {
+            num_decks: 1,
+            rooms_per_deck: 2,
+            crew_size: 5,
+            passenger_capacity: 5,
+            ..Default::default()
+        };
+        let mut slow = SimulationEngine::new();
+        slow.generate(config());
+        let mut fast = SimulationEngine::new();
+        fast.generate(config());
+
+        let slow_steps: u32 = (0..30).map(|_| slow.update(1.0 / 30.0)).sum();
+        let fast_steps: u32 = (0..144).map(|_| fast.update(1.0 / 144.0)).sum();
+        assert_eq!(slow_steps, fast_steps);
+        assert!((slow.sim_time() - fast.sim_time()).abs() < 1e-9);
+
+        // A frame shorter than a step only builds up the accumulator
+        let before = fast.sim_time();
+        assert_eq!(fast.update(0.01), 0);
+        assert_eq!(fast.sim_time(), before);
+        assert!(fast.interpolation_alpha() > 0.0);
+    }
+
+    #[test]
+    fn test_step_cap_drops_leftover_time() {
+        let mut engine = SimulationEngine::new();
+        let step = engine.fixed_step() as f32;
+        assert_eq!(
+            engine.update(step * (MAX_STEPS_PER_UPDATE * 10) as f32),
+            MAX_STEPS_PER_UPDATE
+        );
+        assert!(engine.accumulator <= engine.fixed_step());
+        // The next frame runs its own steps, not the dropped backlog
+        assert!(engine.update(0.0) <= 1);
+    }
+
+    #[test]
+    fn test_interpolated_position_blends_steps() {
+        let mut engine = SimulationEngine::new();
+        let entity = engine.world.spawn((Person, Position::new(0.0, 0.0, 0)));
+        engine.update(engine.fixed_step() as f32);
+        engine.world.get::<&mut Position>(entity).unwrap().local = Vec3::new(4.0, 0.0, 0.0);
+        engine.previous_positions.insert(entity, (0, Vec3::ZERO));
+
+        engine.accumulator = engine.fixed_step() / 2.0;
+        let halfway = engine.interpolated_position(entity).unwrap();
+        assert!((halfway.x - 2.0).abs() < 1e-5);
+
+        // Changing rooms snaps instead of sliding through walls
+        engine.world.get::<&mut Position>(entity).unwrap().room_id = 1;
+        assert_eq!(engine.interpolated_position(entity).unwrap().x, 4.0);
+    }
+
+    #[test]
+    fn test_update_snapshots_before_last_step() {
+        let mut engine = SimulationEngine::new();
+        // One unit a step
+        let speed = engine.tick_rate();
+        let entity = engine.world.spawn((
+            Person,
+            Position::new(0.0, 0.0, 0),
+            Movement::new(Vec3::new(100.0, 0.0, 0.0), speed),
+        ));
+        assert_eq!(engine.update(engine.fixed_step() as f32 * 3.5), 3);
+        let (room_id, previous) = engine.previous_positions[&entity];
+        assert_eq!(room_id, 0);
+        assert!((previous.x - 2.0).abs() < 1e-4);
+        let halfway = engine.interpolated_position(entity).unwrap();
+        assert!((halfway.x - 2.5).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_fast_forward_advances_days() {
+        let mut engine = SimulationEngine::new();
+        engine.generate(ShipConfig {
+            num_decks: 1,
+            rooms_per_deck: 2,
+            crew_size: 5,
+            passenger_capacity: 5,
+            ..Default::default()
+        });
+        assert_eq!(engine.fast_forward(0.5), 720);
+        assert!((engine.sim_time() - 12.0).abs() < 1e-6);
+        assert_eq!(engine.interpolation_alpha(), 0.0);
+        assert_eq!(engine.fast_forward(0.0), 0);
+    }
 }
@@ -1,9 +1,17 @@
-//! Ship generation - creates a realistic connected ship layout
+//! Ship generation - creates a realistic connected ship layout from the
+//! shared graph-first pipeline in `progship_logic::genlib`
 
 use crate::components::{
     Deck, ResourceFlow, ResourceType, Room, RoomConnections, RoomType, ShipSystem, SystemType,
 };
 use hecs::World;
+use progship_logic::constants::room_types;
+pub use progship_logic::features::FeatureFlags;
+use progship_logic::genlib::facilities::get_facility_manifest;
+use progship_logic::genlib::graph::build_facility_graph;
+pub use progship_logic::genlib::layout::CorridorTopology;
+use progship_logic::genlib::layout::{generate_layout, LayoutConfig, HULL_BAND_WIDTH};
+use progship_logic::security::{access_levels, default_access_for_room, department_for_room};
 use rand::Rng;
 
 /// Configuration for ship generation
@@ -18,6 +26,10 @@ pub struct ShipConfig {
     pub ship_length: f32,
     /// Ship width in meters (port to starboard)  
     pub ship_width: f32,
+    /// Central spine, or twin port/starboard spines for wide hulls
+    pub corridor_topology: CorridorTopology,
+    /// Optional simulation systems the engine runs
+    pub features: FeatureFlags,
 }
 
 impl Default for ShipConfig {
@@ -30,242 +42,76 @@ impl Default for ShipConfig {
             crew_size: 1000,
             ship_length: 200.0,
             ship_width: 40.0,
+            corridor_topology: CorridorTopology::CentralSpine,
+            features: FeatureFlags::default(),
         }
     }
 }
 
-/// Check if a point is inside the ship's elliptical hull
-fn point_in_hull(x: f32, y: f32, half_length: f32, half_width: f32) -> bool {
-    (x * x) / (half_length * half_length) + (y * y) / (half_width * half_width) <= 1.0
-}
+/// Generate a ship layout in the ECS world.
+///
+/// Builds the shared facility graph for the configured population and lays it
+/// out with [`generate_layout`], the generator the server lays its ships out
+/// with, so the engine gets the same ring, spines, cross corridors, rooms and
+/// shafts. The plan's hull bands sit inside `ship_length`, so every room
+/// stays within the configured hull.
+pub fn generate_ship(world: &mut World, config: &ShipConfig, _rng: &mut impl Rng) -> ShipLayout {
+    let mut layout = ShipLayout::new(
+        &config.name,
+        config.ship_length,
+        config.ship_width,
+        config.corridor_topology,
+    );
+
+    let graph = build_facility_graph(
+        &get_facility_manifest(),
+        config.crew_size,
+        config.passenger_capacity,
+    );
+    let layout_config = LayoutConfig {
+        deck_count: config.num_decks,
+        ship_beam: config.ship_width.round().max(1.0) as usize,
+        ship_length: (config.ship_length.round() as usize)
+            .saturating_sub(2 * HULL_BAND_WIDTH)
+            .max(1),
+        population: config.crew_size + config.passenger_capacity,
+        max_rooms_per_deck: Some(config.rooms_per_deck),
+        topology: config.corridor_topology,
+        ..Default::default()
+    };
+    let plan = generate_layout(&graph.nodes, &layout_config);
 
-/// Get the hull width at a given x position
-fn hull_width_at_x(x: f32, half_length: f32, half_width: f32) -> f32 {
-    if x.abs() >= half_length {
-        return 0.0;
-    }
-    // Ellipse equation: y = b * sqrt(1 - x²/a²)
-    let ratio = 1.0 - (x * x) / (half_length * half_length);
-    if ratio <= 0.0 {
-        0.0
-    } else {
-        2.0 * half_width * ratio.sqrt()
-    }
-}
-
-/// Get the hull x-extent at a given y position (returns max absolute x value)
-fn hull_x_at_y(y: f32, half_length: f32, half_width: f32) -> f32 {
-    if y.abs() >= half_width {
-        return 0.0;
-    }
-    // Ellipse equation: x = a * sqrt(1 - y²/b²)
-    let ratio = 1.0 - (y * y) / (half_width * half_width);
-    if ratio <= 0.0 {
-        0.0
-    } else {
-        half_length * ratio.sqrt()
-    }
-}
-
-/// Generate a ship layout in the ECS world
-pub fn generate_ship(world: &mut World, config: &ShipConfig, rng: &mut impl Rng) -> ShipLayout {
-    let mut layout = ShipLayout::new(&config.name, config.ship_length, config.ship_width);
-
-    let half_length = config.ship_length / 2.0;
-    let half_width = config.ship_width / 2.0;
-    let corridor_width = 3.0;
-
-    // Generate each deck
     for deck_idx in 0..config.num_decks {
         let deck_level = deck_idx as i32;
         let deck_name = deck_name_for_level(deck_level, config.num_decks);
-
-        let deck_entity = world.spawn((Deck::new(&deck_name, deck_level),));
-        layout.decks.push(deck_entity);
-
-        // Corridor starts where hull is wide enough
-        let corridor_start_x = find_hull_x_for_width(half_length, half_width, corridor_width + 4.0);
-        let corridor_length = corridor_start_x * 2.0 - 4.0; // Leave margin at ends
-
-        // Create central corridor
-        let corridor_entity = create_corridor(
-            world,
-            &format!("{} Main Corridor", deck_name),
-            deck_level,
-            corridor_length,
-            corridor_width,
-            &mut layout,
-        );
-        let corridor_room_id = layout.rooms.len() as u32 - 1;
-
-        // Get room types for this deck
-        let room_types =
-            deck_room_distribution(deck_level, config.rooms_per_deck, config.num_decks);
-
-        // Filter out corridors
-        let actual_rooms: Vec<_> = room_types
-            .iter()
-            .filter(|rt| **rt != RoomType::Corridor)
-            .collect();
-
-        // Split rooms between port (negative y) and starboard (positive y)
-        let port_rooms: Vec<_> = actual_rooms
-            .iter()
-            .enumerate()
-            .filter(|(i, _)| i % 2 == 0)
-            .map(|(_, rt)| *rt)
-            .collect();
-        let starboard_rooms: Vec<_> = actual_rooms
-            .iter()
-            .enumerate()
-            .filter(|(i, _)| i % 2 == 1)
-            .map(|(_, rt)| *rt)
-            .collect();
-
-        // Place port-side rooms (negative y)
-        // Rooms are placed along the corridor (x-axis) with depths extending to hull (y-axis)
-        let num_port = port_rooms.len();
-        for (slot_idx, room_type) in port_rooms.iter().enumerate() {
-            // First, calculate the room depth (toward hull)
-            // Use center position to get approximate available depth
-            let approx_x = 0.0; // We'll refine this
-            let available_width = hull_width_at_x(approx_x, half_length, half_width);
-            let max_depth = (available_width - corridor_width) / 2.0 - 0.5;
-
-            if max_depth < 4.0 {
-                continue;
-            }
-
-            let room_depth = max_depth.min(15.0).max(4.0); // Cap depth at 15m for more rooms
-            let room_y = -(corridor_width / 2.0 + room_depth / 2.0);
-            let outer_y = room_y - room_depth / 2.0; // Outer edge (most negative y)
-
-            // Find hull x-extent at the outer y position
-            let hull_x_extent = hull_x_at_y(outer_y, half_length, half_width);
-
-            // Divide the available x-range among rooms on this side
-            let usable_x = hull_x_extent * 2.0 - 2.0; // Leave 1m margin on each end
-            let slot_width = usable_x / num_port as f32;
-            let room_width = (slot_width - 0.5).max(4.0);
-            let room_x = -usable_x / 2.0 + slot_width * (slot_idx as f32 + 0.5);
-
-            // Verify corners fit
-            if !point_in_hull(room_x - room_width / 2.0, outer_y, half_length, half_width)
-                || !point_in_hull(room_x + room_width / 2.0, outer_y, half_length, half_width)
-            {
-                continue;
-            }
-
-            let room_name = generate_room_name(**room_type, slot_idx * 2);
-            let room = Room::new(&room_name, **room_type, room_width, room_depth)
-                .with_deck_level(deck_level)
-                .with_position(room_x, room_y);
-
-            let room_id = layout.rooms.len() as u32;
-            let room_entity = world.spawn((room, RoomConnections::new()));
-
-            if let Ok(mut conn) = world.get::<&mut RoomConnections>(room_entity) {
-                conn.connect(corridor_room_id);
-            }
-            if let Ok(mut corridor_conn) = world.get::<&mut RoomConnections>(corridor_entity) {
-                corridor_conn.connect(room_id);
-            }
-
-            layout.rooms.push(room_entity);
-        }
-
-        // Place starboard-side rooms (positive y)
-        let num_starboard = starboard_rooms.len();
-        for (slot_idx, room_type) in starboard_rooms.iter().enumerate() {
-            // First, calculate the room depth (toward hull)
-            let approx_x = 0.0;
-            let available_width = hull_width_at_x(approx_x, half_length, half_width);
-            let max_depth = (available_width - corridor_width) / 2.0 - 0.5;
-
-            if max_depth < 4.0 {
-                continue;
-            }
-
-            let room_depth = max_depth.min(15.0).max(4.0); // Cap depth at 15m
-            let room_y = corridor_width / 2.0 + room_depth / 2.0;
-            let outer_y = room_y + room_depth / 2.0; // Outer edge (most positive y)
-
-            // Find hull x-extent at the outer y position
-            let hull_x_extent = hull_x_at_y(outer_y, half_length, half_width);
-
-            // Divide the available x-range among rooms on this side
-            let usable_x = hull_x_extent * 2.0 - 2.0; // Leave 1m margin on each end
-            let slot_width = usable_x / num_starboard as f32;
-            let room_width = (slot_width - 0.5).max(4.0);
-            let room_x = -usable_x / 2.0 + slot_width * (slot_idx as f32 + 0.5);
-
-            // Verify corners fit
-            if !point_in_hull(room_x - room_width / 2.0, outer_y, half_length, half_width)
-                || !point_in_hull(room_x + room_width / 2.0, outer_y, half_length, half_width)
-            {
-                continue;
-            }
-
-            let room_name = generate_room_name(**room_type, slot_idx * 2 + 1);
-            let room = Room::new(&room_name, **room_type, room_width, room_depth)
-                .with_deck_level(deck_level)
-                .with_position(room_x, room_y);
-
-            let room_id = layout.rooms.len() as u32;
-            let room_entity = world.spawn((room, RoomConnections::new()));
-
-            if let Ok(mut conn) = world.get::<&mut RoomConnections>(room_entity) {
-                conn.connect(corridor_room_id);
-            }
-            if let Ok(mut corridor_conn) = world.get::<&mut RoomConnections>(corridor_entity) {
-                corridor_conn.connect(room_id);
-            }
-
-            layout.rooms.push(room_entity);
-        }
-
-        // Add elevator at center of each deck
-        let elevator_room = Room::new("Elevator", RoomType::Elevator, 4.0, 4.0)
-            .with_deck_level(deck_level)
-            .with_position(0.0, 0.0);
-
-        let elevator_entity = world.spawn((elevator_room, RoomConnections::new()));
-
-        // Connect elevator to corridor
-        if let Ok(mut conn) = world.get::<&mut RoomConnections>(elevator_entity) {
-            conn.connect(corridor_room_id);
-        }
-        if let Ok(mut corridor_conn) = world.get::<&mut RoomConnections>(corridor_entity) {
-            corridor_conn.connect(layout.rooms.len() as u32);
-        }
-
-        layout.rooms.push(elevator_entity);
-        layout.elevators.push(elevator_entity);
+        layout
+            .decks
+            .push(world.spawn((Deck::new(&deck_name, deck_level),)));
     }
 
-    // Connect elevators across decks
-    for i in 1..layout.elevators.len() {
-        let current_elevator = layout.elevators[i];
-        let prev_elevator = layout.elevators[i - 1];
-
-        // Find their room IDs
-        let current_id = layout
-            .rooms
-            .iter()
-            .position(|&e| e == current_elevator)
-            .unwrap_or(0) as u32;
-        let prev_id = layout
-            .rooms
-            .iter()
-            .position(|&e| e == prev_elevator)
-            .unwrap_or(0) as u32;
-
-        if let Ok(mut conn) = world.get::<&mut RoomConnections>(current_elevator) {
-            conn.connect(prev_id);
-        }
-        if let Ok(mut conn) = world.get::<&mut RoomConnections>(prev_elevator) {
-            conn.connect(current_id);
+    // Plan coordinates: x across the beam, y along the length, origin at a corner.
+    // Engine coordinates: x along the length, y across the beam, origin at the center.
+    let half_length = (layout_config.ship_length + 2 * HULL_BAND_WIDTH) as f32 / 2.0;
+    let half_beam = layout_config.ship_beam as f32 / 2.0;
+    let mut connections = vec![RoomConnections::new(); plan.rooms.len()];
+    for door in &plan.doors {
+        connections[door.room_a as usize].connect(door.room_b);
+        connections[door.room_b as usize].connect(door.room_a);
+    }
+    for (r, conn) in plan.rooms.iter().zip(connections) {
+        let mut room = Room::new(&r.name, core_room_type(r.room_type), r.height, r.width)
+            .with_deck_level(r.deck)
+            .with_position(r.y - half_length, r.x - half_beam)
+            .with_access(room_access(r.room_type), department_for_room(r.room_type));
+        if r.capacity > 0 {
+            room = room.with_capacity(r.capacity);
         }
+        layout.rooms.push(world.spawn((room, conn)));
+    }
+    for shaft in &plan.shafts {
+        layout
+            .elevators
+            .extend(shaft.deck_rooms.iter().map(|&id| layout.rooms[id as usize]));
     }
 
     // Generate ship systems
@@ -274,34 +120,57 @@ pub fn generate_ship(world: &mut World, config: &ShipConfig, rng: &mut impl Rng)
     layout
 }
 
-/// Find the x position where hull width equals target_width
-fn find_hull_x_for_width(half_length: f32, half_width: f32, target_width: f32) -> f32 {
-    // From ellipse: width = 2 * b * sqrt(1 - x²/a²)
-    // Solve for x: x = a * sqrt(1 - (width/2b)²)
-    let ratio = target_width / (2.0 * half_width);
-    if ratio >= 1.0 {
-        return 0.0;
+/// Map a shared `room_types` ID onto the engine's coarser [`RoomType`].
+pub(crate) fn core_room_type(room_type: u8) -> RoomType {
+    match room_type {
+        room_types::BRIDGE
+        | room_types::CIC
+        | room_types::COMMS_ROOM
+        | room_types::CAPTAINS_READY_ROOM => RoomType::Bridge,
+        room_types::CONFERENCE
+        | room_types::SECURITY_OFFICE
+        | room_types::BRIG
+        | room_types::ADMIN_OFFICE => RoomType::ConferenceRoom,
+        room_types::OBSERVATORY | room_types::OBSERVATION_LOUNGE => RoomType::Observatory,
+        room_types::QUARTERS_CREW => RoomType::QuartersCrew,
+        room_types::QUARTERS_OFFICER => RoomType::QuartersOfficer,
+        room_types::CABIN_SINGLE..=room_types::VIP_SUITE | room_types::QUARTERS_PASSENGER => {
+            RoomType::QuartersPassenger
+        }
+        room_types::SHARED_BATHROOM | room_types::SHARED_LAUNDRY => RoomType::Quarters,
+        room_types::MESS_HALL | room_types::WARDROOM | room_types::CAFE => RoomType::Mess,
+        room_types::GALLEY | room_types::BAKERY => RoomType::Galley,
+        room_types::WATER_PURIFICATION
+        | room_types::WATER_RECYCLING
+        | room_types::WASTE_PROCESSING => RoomType::WaterRecycling,
+        room_types::HOSPITAL_WARD..=room_types::MEDBAY => RoomType::Medical,
+        room_types::GYM | room_types::POOL => RoomType::Gym,
+        room_types::THEATRE..=room_types::SHOPS => RoomType::Recreation,
+        room_types::REACTOR | room_types::BACKUP_REACTOR => RoomType::ReactorRoom,
+        room_types::MACHINE_SHOP | room_types::ROBOTICS_BAY | room_types::MAINTENANCE_BAY => {
+            RoomType::MaintenanceBay
+        }
+        room_types::ELECTRONICS_LAB | room_types::LABORATORY => RoomType::Laboratory,
+        room_types::ENGINEERING..=room_types::COOLING_PLANT => RoomType::Engineering,
+        room_types::HYDROPONICS => RoomType::Hydroponics,
+        room_types::ATMOSPHERE_PROCESSING..=room_types::HVAC_CONTROL => RoomType::LifeSupport,
+        room_types::CARGO_BAY | room_types::SHUTTLE_BAY => RoomType::Cargo,
+        room_types::AIRLOCK => RoomType::Airlock,
+        room_types::LADDER_SHAFT => RoomType::Ladder,
+        room_types::ELEVATOR_SHAFT | room_types::SERVICE_ELEVATOR_SHAFT => RoomType::Elevator,
+        rt if room_types::is_plain_corridor(rt) => RoomType::Corridor,
+        _ => RoomType::Storage,
     }
-    half_length * (1.0 - ratio * ratio).sqrt()
 }
 
-/// Create a central corridor
-fn create_corridor(
-    world: &mut World,
-    name: &str,
-    deck_level: i32,
-    length: f32,
-    width: f32,
-    layout: &mut ShipLayout,
-) -> hecs::Entity {
-    let corridor = Room::new(name, RoomType::Corridor, length, width)
-        .with_deck_level(deck_level)
-        .with_position(0.0, 0.0);
-
-    let entity = world.spawn((corridor, RoomConnections::new()));
-
-    layout.rooms.push(entity);
-    entity
+/// Access level for a planned room. The layout's elevator is the main
+/// passenger shaft, so it stays public like the server's main shafts.
+pub(crate) fn room_access(room_type: u8) -> u8 {
+    if room_type == room_types::ELEVATOR_SHAFT {
+        access_levels::PUBLIC
+    } else {
+        default_access_for_room(room_type)
+    }
 }
 
 /// Result of ship generation
@@ -310,17 +179,19 @@ pub struct ShipLayout {
     pub name: String,
     pub ship_length: f32,
     pub ship_width: f32,
+    pub corridor_topology: CorridorTopology,
     pub decks: Vec<hecs::Entity>,
     pub rooms: Vec<hecs::Entity>,
     pub elevators: Vec<hecs::Entity>,
 }
 
 impl ShipLayout {
-    fn new(name: &str, length: f32, width: f32) -> Self {
+    fn new(name: &str, length: f32, width: f32, corridor_topology: CorridorTopology) -> Self {
         Self {
             name: name.to_string(),
             ship_length: length,
             ship_width: width,
+            corridor_topology,
             decks: Vec::new(),
             rooms: Vec::new(),
             elevators: Vec::new(),
@@ -329,7 +200,7 @@ impl ShipLayout {
 }
 
 /// Get deck name for a level
-fn deck_name_for_level(level: i32, total_decks: u32) -> String {
+pub(crate) fn deck_name_for_level(level: i32, total_decks: u32) -> String {
     match level {
         0 => "Main Deck".to_string(),
         1 => "Upper Deck".to_string(),
@@ -339,121 +210,6 @@ fn deck_name_for_level(level: i32, total_decks: u32) -> String {
     }
 }
 
-/// Determine room distribution for a deck based on its level
-fn deck_room_distribution(deck_level: i32, num_rooms: u32, total_decks: u32) -> Vec<RoomType> {
-    let mut rooms = Vec::with_capacity(num_rooms as usize);
-
-    match deck_level {
-        0 => {
-            // Main deck: command, common areas
-            rooms.push(RoomType::Bridge);
-            rooms.push(RoomType::ConferenceRoom);
-            rooms.push(RoomType::Mess);
-            rooms.push(RoomType::Galley);
-            rooms.push(RoomType::Medical);
-            rooms.push(RoomType::Recreation);
-            for _ in rooms.len()..num_rooms as usize {
-                rooms.push(RoomType::QuartersOfficer);
-            }
-        }
-        1 => {
-            // Deck 2: crew quarters, recreation
-            rooms.push(RoomType::Gym);
-            rooms.push(RoomType::Recreation);
-            for _ in rooms.len()..num_rooms as usize {
-                rooms.push(RoomType::QuartersCrew);
-            }
-        }
-        n if n == (total_decks - 1) as i32 => {
-            // Top deck: observation, science
-            rooms.push(RoomType::Observatory);
-            rooms.push(RoomType::Laboratory);
-            rooms.push(RoomType::Laboratory);
-            for _ in rooms.len()..num_rooms as usize {
-                rooms.push(RoomType::QuartersPassenger);
-            }
-        }
-        n if n > 1 => {
-            // Middle decks: passenger quarters
-            rooms.push(RoomType::Recreation);
-            for _ in rooms.len()..num_rooms as usize {
-                rooms.push(RoomType::QuartersPassenger);
-            }
-        }
-        _ => {
-            // Engineering deck
-            rooms.push(RoomType::Engineering);
-            rooms.push(RoomType::ReactorRoom);
-            rooms.push(RoomType::LifeSupport);
-            for _ in rooms.len()..num_rooms as usize {
-                rooms.push(RoomType::Cargo);
-            }
-        }
-    }
-
-    rooms.truncate(num_rooms as usize);
-    rooms
-}
-
-/// Generate a descriptive room name
-fn generate_room_name(room_type: RoomType, index: usize) -> String {
-    match room_type {
-        RoomType::Bridge => "Bridge".to_string(),
-        RoomType::Engineering => "Main Engineering".to_string(),
-        RoomType::ReactorRoom => "Reactor Room".to_string(),
-        RoomType::Medical => "Sickbay".to_string(),
-        RoomType::Mess => "Mess Hall".to_string(),
-        RoomType::Corridor => format!("Corridor {}", index + 1),
-        RoomType::QuartersCrew => format!("Crew Quarters {}", (index / 2) + 1),
-        RoomType::QuartersOfficer => format!("Officer Quarters {}", (index / 2) + 1),
-        RoomType::QuartersPassenger => format!("Cabin {}", (index / 2) + 1),
-        RoomType::Observatory => "Observation Deck".to_string(),
-        RoomType::Laboratory => format!("Lab {}", (index / 2) + 1),
-        RoomType::Recreation => "Rec Room".to_string(),
-        RoomType::Gym => "Gymnasium".to_string(),
-        RoomType::Galley => "Galley".to_string(),
-        RoomType::ConferenceRoom => "Conference Room".to_string(),
-        _ => format!("{:?} {}", room_type, (index / 2) + 1),
-    }
-}
-
-/// Get typical dimensions for a room type (width along corridor, depth perpendicular)
-fn room_dimensions(room_type: RoomType, rng: &mut impl Rng) -> (f32, f32) {
-    match room_type {
-        // Large command/common rooms
-        RoomType::Bridge => (20.0, 15.0),
-        RoomType::Engineering => (25.0, 18.0),
-        RoomType::ReactorRoom => (15.0, 15.0),
-        RoomType::Mess => (25.0, 15.0),
-        RoomType::Recreation => (20.0, 12.0),
-        RoomType::Gym => (20.0, 15.0),
-        RoomType::Observatory => (25.0, 18.0),
-
-        // Medium rooms
-        RoomType::Medical => (18.0, 12.0),
-        RoomType::Laboratory => (15.0, 10.0),
-        RoomType::ConferenceRoom => (12.0, 10.0),
-        RoomType::Galley => (15.0, 10.0),
-        RoomType::Cargo => (20.0, 15.0),
-        RoomType::LifeSupport => (15.0, 12.0),
-        RoomType::Hydroponics => (20.0, 15.0),
-        RoomType::WaterRecycling => (12.0, 10.0),
-        RoomType::MaintenanceBay => (15.0, 12.0),
-        RoomType::Storage => (12.0, 10.0),
-
-        // Quarters - sized for occupants
-        RoomType::QuartersCrew => (8.0 + rng.gen_range(0.0..2.0), 6.0), // 48 sq m, fits 12 people
-        RoomType::QuartersOfficer => (10.0 + rng.gen_range(0.0..2.0), 8.0), // 80 sq m, fits 4 people
-        RoomType::QuartersPassenger => (9.0 + rng.gen_range(0.0..2.0), 7.0), // 63 sq m, fits 8 people
-        RoomType::Quarters => (8.0, 6.0),
-
-        // Utility
-        RoomType::Corridor => (3.0, rng.gen_range(10.0..20.0)),
-        RoomType::Elevator => (4.0, 4.0),
-        RoomType::Airlock => (5.0, 5.0),
-    }
-}
-
 /// Generate ship systems (power, life support, etc.) with resource flows
 fn generate_ship_systems(world: &mut World, layout: &mut ShipLayout, config: &ShipConfig) {
     let population = config.crew_size + config.passenger_capacity;
@@ -528,6 +284,11 @@ fn generate_ship_systems(world: &mut World, layout: &mut ShipLayout, config: &Sh
 mod tests {
     use super::*;
 
+    /// Check if a point is inside the ship's elliptical hull
+    fn point_in_hull(x: f32, y: f32, half_length: f32, half_width: f32) -> bool {
+        x.abs() <= half_length && y.abs() <= half_width
+    }
+
     #[test]
     fn test_generate_ship() {
         let mut world = World::new();
@@ -549,7 +310,12 @@ mod tests {
             "Expected at least 6 rooms, got {}",
             layout.rooms.len()
         );
-        assert_eq!(layout.elevators.len(), 3);
+        let elevator_decks: std::collections::BTreeSet<i32> = layout
+            .elevators
+            .iter()
+            .map(|&e| world.get::<&Room>(e).unwrap().deck_level)
+            .collect();
+        assert_eq!(elevator_decks.len(), 3);
     }
 
     #[test]
@@ -603,17 +369,117 @@ mod tests {
     }
 
     #[test]
-    fn test_hull_width_calculation() {
-        let half_length = 100.0;
-        let half_width = 25.0;
+    fn test_room_type_mapping() {
+        assert_eq!(core_room_type(room_types::BRIDGE), RoomType::Bridge);
+        assert_eq!(
+            core_room_type(room_types::CABIN_DOUBLE),
+            RoomType::QuartersPassenger
+        );
+        assert_eq!(
+            core_room_type(room_types::QUARTERS_CREW),
+            RoomType::QuartersCrew
+        );
+        assert_eq!(core_room_type(room_types::SURGERY), RoomType::Medical);
+        assert_eq!(core_room_type(room_types::REACTOR), RoomType::ReactorRoom);
+        assert_eq!(
+            core_room_type(room_types::COOLING_PLANT),
+            RoomType::Engineering
+        );
+        assert_eq!(
+            core_room_type(room_types::CROSS_CORRIDOR),
+            RoomType::Corridor
+        );
+        assert_eq!(
+            core_room_type(room_types::ELEVATOR_SHAFT),
+            RoomType::Elevator
+        );
+        assert_eq!(core_room_type(room_types::SERVICE_DECK), RoomType::Storage);
+    }
 
-        // At center, width should be max
-        let center_width = hull_width_at_x(0.0, half_length, half_width);
-        assert!((center_width - 50.0).abs() < 0.01);
+    #[test]
+    fn test_connections_are_symmetric() {
+        let mut world = World::new();
+        let config = ShipConfig::default();
+        let mut rng = rand::thread_rng();
 
-        // At ends, width should be 0
-        let end_width = hull_width_at_x(100.0, half_length, half_width);
-        assert!(end_width < 0.01);
+        let layout = generate_ship(&mut world, &config, &mut rng);
+
+        for (id, &entity) in layout.rooms.iter().enumerate() {
+            let conn = world.get::<&RoomConnections>(entity).unwrap();
+            assert!(
+                !conn.connected_to.is_empty(),
+                "room {} has no connections",
+                id
+            );
+            for &other in &conn.connected_to {
+                let back = world
+                    .get::<&RoomConnections>(layout.rooms[other as usize])
+                    .unwrap();
+                assert!(back.connected_to.contains(&(id as u32)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_twin_spine_ship() {
+        let mut world = World::new();
+        let config = ShipConfig {
+            num_decks: 4,
+            ship_length: 300.0,
+            ship_width: 60.0,
+            corridor_topology: CorridorTopology::TwinSpine,
+            ..Default::default()
+        };
+        let mut rng = rand::thread_rng();
+
+        let layout = generate_ship(&mut world, &config, &mut rng);
+
+        for spine in ["Port Spine", "Starboard Spine"] {
+            let decks: std::collections::BTreeSet<i32> = layout
+                .rooms
+                .iter()
+                .filter_map(|&e| {
+                    let room = world.get::<&Room>(e).unwrap();
+                    (room.room_type == RoomType::Corridor && room.name.starts_with(spine))
+                        .then_some(room.deck_level)
+                })
+                .collect();
+            assert_eq!(decks.len(), config.num_decks as usize, "{}", spine);
+        }
+        assert!(!layout.elevators.is_empty());
+    }
+
+    #[test]
+    fn test_room_access_levels() {
+        let mut world = World::new();
+        let config = ShipConfig::default();
+        let mut rng = rand::thread_rng();
+
+        let layout = generate_ship(&mut world, &config, &mut rng);
+
+        let mut restricted = 0;
+        for &e in &layout.rooms {
+            let room = world.get::<&Room>(e).unwrap();
+            match room.room_type {
+                RoomType::Corridor | RoomType::QuartersPassenger => {
+                    assert_eq!(room.access_level, access_levels::PUBLIC, "{}", room.name)
+                }
+                // Service elevators and ladders are crew-only
+                RoomType::Elevator if room.name.starts_with("Elevator") => {
+                    assert_eq!(room.access_level, access_levels::PUBLIC, "{}", room.name)
+                }
+                RoomType::Bridge => assert!(room.access_level > access_levels::PUBLIC),
+                RoomType::ReactorRoom => {
+                    assert_eq!(room.access_level, access_levels::DEPARTMENT);
+                    assert!(room.department.is_some());
+                }
+                _ => {}
+            }
+            if room.access_level > access_levels::PUBLIC {
+                restricted += 1;
+            }
+        }
+        assert!(restricted > 0);
     }
 
     #[test]
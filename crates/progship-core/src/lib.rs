@@ -31,6 +31,7 @@ pub mod components;
 pub mod engine;
 pub mod generation;
 pub mod persistence;
+pub mod save_slots;
 pub mod systems;
 
 /// Commonly used types for convenient importing
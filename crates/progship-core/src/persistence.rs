@@ -2,23 +2,55 @@
 //!
 //! Uses bincode for efficient binary serialization of the entire simulation.
 //! Components are serialized individually then reconstructed on load.
+//! Every save starts with a [`SaveHeader`] (slot name, timestamp, ship
+//! summary) that save browsers can read without decoding the whole file.
+//! JSON state snapshots exported by the server can be imported as well.
 
 use hecs::World;
+use progship_logic::constants::{activity_types, departments, ranks, room_types, shifts};
+use progship_logic::nicknames::display_name;
+use progship_logic::security::department_for_room;
+use progship_logic::snapshot::{
+    SnapshotCrew, SnapshotPerson, SnapshotResources, SnapshotRoom, StateSnapshot, SNAPSHOT_VERSION,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::io::{Read, Write};
 
 use crate::components::*;
-use crate::generation::ShipLayout;
+use crate::generation::{
+    core_room_type, deck_name_for_level, room_access, CorridorTopology, ShipLayout,
+};
 use crate::systems::{ConversationManager, MaintenanceQueue, RelationshipGraph, ShipResources};
 
-/// Version number for save file format (increment when format changes)
-const SAVE_VERSION: u32 = 1;
+/// Version number for save file format (increment when format changes).
+/// Bincode ignores serde defaults, so saves of any other version are
+/// rejected by [`check_version`] rather than decoded with missing fields.
+const SAVE_VERSION: u32 = 3;
+
+/// Summary written at the front of every save, readable on its own with
+/// [`read_save_header`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SaveHeader {
+    /// Slot the save was written to
+    pub slot_name: String,
+    /// Wall-clock time of the save, in seconds since the Unix epoch
+    pub saved_at: u64,
+    /// Name of the ship
+    pub ship_name: String,
+    /// Simulation time in hours
+    pub sim_time: f64,
+    pub crew_count: u32,
+    pub passenger_count: u32,
+}
 
 /// Serializable snapshot of the simulation state
 #[derive(Serialize, Deserialize)]
 pub struct SaveData {
     /// Save format version
     pub version: u32,
+    /// Save summary
+    pub header: SaveHeader,
     /// Simulation time in hours
     pub sim_time: f64,
     /// Time scale
@@ -45,6 +77,7 @@ pub struct SerializableShipLayout {
     pub name: String,
     pub ship_length: f32,
     pub ship_width: f32,
+    pub corridor_topology: CorridorTopology,
     pub room_count: usize,
     pub deck_count: usize,
     pub elevator_count: usize,
@@ -56,6 +89,7 @@ impl From<&ShipLayout> for SerializableShipLayout {
             name: layout.name.clone(),
             ship_length: layout.ship_length,
             ship_width: layout.ship_width,
+            corridor_topology: layout.corridor_topology,
             room_count: layout.rooms.len(),
             deck_count: layout.decks.len(),
             elevator_count: layout.elevators.len(),
@@ -232,6 +266,7 @@ fn spawn_entity(world: &mut World, se: SerializableEntity) {
 /// Save the complete simulation to a writer
 pub fn save_simulation<W: Write>(
     writer: W,
+    header: SaveHeader,
     world: &World,
     sim_time: f64,
     time_scale: f32,
@@ -246,6 +281,7 @@ pub fn save_simulation<W: Write>(
 
     let save_data = SaveData {
         version: SAVE_VERSION,
+        header,
         sim_time,
         time_scale,
         ship_layout: ship_layout.map(SerializableShipLayout::from),
@@ -262,20 +298,18 @@ pub fn save_simulation<W: Write>(
 }
 
 /// Load a simulation from a reader
-pub fn load_simulation<R: Read>(reader: R) -> Result<LoadedSimulation, SaveError> {
-    let save_data: SaveData = bincode::deserialize_from(reader)?;
-
-    if save_data.version != SAVE_VERSION {
-        return Err(SaveError::VersionMismatch {
-            expected: SAVE_VERSION,
-            found: save_data.version,
-        });
-    }
+pub fn load_simulation<R: Read>(mut reader: R) -> Result<LoadedSimulation, SaveError> {
+    // Check the version first so older formats fail cleanly
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+    check_version(bincode::deserialize(&bytes)?)?;
+    let save_data: SaveData = bincode::deserialize(&bytes)?;
 
     let mut world = World::new();
     deserialize_entities(&mut world, save_data.entities);
 
     Ok(LoadedSimulation {
+        header: save_data.header,
         world,
         sim_time: save_data.sim_time,
         time_scale: save_data.time_scale,
@@ -288,8 +322,25 @@ pub fn load_simulation<R: Read>(reader: R) -> Result<LoadedSimulation, SaveError
     })
 }
 
+/// Read just the header of a save, without decoding the rest
+pub fn read_save_header<R: Read>(mut reader: R) -> Result<SaveHeader, SaveError> {
+    check_version(bincode::deserialize_from(&mut reader)?)?;
+    Ok(bincode::deserialize_from(reader)?)
+}
+
+fn check_version(version: u32) -> Result<(), SaveError> {
+    if version != SAVE_VERSION {
+        return Err(SaveError::VersionMismatch {
+            expected: SAVE_VERSION,
+            found: version,
+        });
+    }
+    Ok(())
+}
+
 /// Result of loading a simulation
 pub struct LoadedSimulation {
+    pub header: SaveHeader,
     pub world: World,
     pub sim_time: f64,
     pub time_scale: f32,
@@ -301,12 +352,383 @@ pub struct LoadedSimulation {
     pub events: crate::systems::EventManager,
 }
 
+/// Result of importing a server state snapshot
+pub struct ImportedSnapshot {
+    pub world: World,
+    pub sim_time: f64,
+    pub ship_layout: ShipLayout,
+}
+
+/// Convert a server [`StateSnapshot`] into engine state.
+///
+/// Engine room IDs are positions in the room list, so rooms are re-indexed in
+/// server ID order. They are recentered on the middle of the ship with the
+/// server's X (across the beam) and Y (along the length) swapped into engine
+/// axes, matching generated ships. People keep their room and their offset
+/// inside it; ship systems, relationships and conversations start fresh.
+pub fn import_snapshot(snapshot: &StateSnapshot) -> ImportedSnapshot {
+    let mut world = World::new();
+    let mut rooms: Vec<_> = snapshot.rooms.iter().collect();
+    rooms.sort_by_key(|r| r.id);
+    let index: HashMap<u32, u32> = rooms
+        .iter()
+        .enumerate()
+        .map(|(i, r)| (r.id, i as u32))
+        .collect();
+
+    let bounds = rooms
+        .iter()
+        .fold(None, |acc: Option<(f32, f32, f32, f32)>, r| {
+            let (x0, y0) = (r.x - r.width / 2.0, r.y - r.height / 2.0);
+            let (x1, y1) = (r.x + r.width / 2.0, r.y + r.height / 2.0);
+            Some(match acc {
+                Some((a, b, c, d)) => (a.min(x0), b.min(y0), c.max(x1), d.max(y1)),
+                None => (x0, y0, x1, y1),
+            })
+        });
+    let (min_x, min_y, max_x, max_y) = bounds.unwrap_or_default();
+    let (mid_x, mid_y) = ((min_x + max_x) / 2.0, (min_y + max_y) / 2.0);
+
+    let deck_count = snapshot
+        .deck_count
+        .max(rooms.iter().map(|r| r.deck + 1).max().unwrap_or(0).max(0) as u32);
+    let mut layout = ShipLayout {
+        name: snapshot.ship_name.clone(),
+        rooms: Vec::with_capacity(rooms.len()),
+        decks: (0..deck_count as i32)
+            .map(|level| {
+                let generated = deck_name_for_level(level, deck_count);
+                let name = display_name(&generated, snapshot.deck_nickname(level));
+                world.spawn((Deck::new(name, level),))
+            })
+            .collect(),
+        elevators: Vec::new(),
+        corridor_topology: CorridorTopology::from_u8(snapshot.corridor_topology)
+            .unwrap_or_default(),
+        ship_length: max_y - min_y,
+        ship_width: max_x - min_x,
+    };
+
+    let mut connections = vec![RoomConnections::new(); rooms.len()];
+    for (a, b) in &snapshot.doors {
+        if let (Some(&a), Some(&b)) = (index.get(a), index.get(b)) {
+            connections[a as usize].connect(b);
+            connections[b as usize].connect(a);
+        }
+    }
+    for (r, conn) in rooms.iter().zip(connections) {
+        let name = display_name(&r.name, snapshot.room_nickname(r.id));
+        let mut room = Room::new(name, core_room_type(r.room_type), r.height, r.width)
+            .with_deck_level(r.deck)
+            .with_position(r.y - mid_y, r.x - mid_x)
+            .with_access(room_access(r.room_type), department_for_room(r.room_type));
+        if r.capacity > 0 {
+            room = room.with_capacity(r.capacity);
+        }
+        let is_shaft = matches!(room.room_type, RoomType::Elevator | RoomType::Ladder);
+        let entity = world.spawn((room, conn));
+        if is_shaft {
+            layout.elevators.push(entity);
+        }
+        layout.rooms.push(entity);
+    }
+
+    for p in &snapshot.people {
+        let Some(&room_id) = index.get(&p.room_id) else {
+            continue;
+        };
+        let r = rooms[room_id as usize];
+        // Offset from the room's low corner, in engine axes
+        let local_x = (p.y - r.y + r.height / 2.0).clamp(0.0, r.height);
+        let local_y = (p.x - r.x + r.width / 2.0).clamp(0.0, r.width);
+        let [hunger, fatigue, social, comfort, hygiene] = p.needs;
+        let entity = world.spawn((
+            Person,
+            Name::new(&p.given_name, &p.family_name),
+            Position::new(local_x, local_y, room_id),
+            Needs {
+                hunger,
+                fatigue,
+                social,
+                comfort,
+                hygiene,
+            },
+            Personality::default(),
+            Skills::default(),
+            Activity::new(activity_from_id(p.activity_type), snapshot.sim_time, 0.0),
+        ));
+        let _ = match p.crew {
+            Some(c) => {
+                let station = index.get(&c.duty_station_id).copied().unwrap_or(room_id);
+                let crew = Crew::new(
+                    department_from_id(c.department),
+                    rank_from_id(c.rank),
+                    shift_from_id(c.shift),
+                )
+                .with_station(station);
+                world.insert_one(entity, crew)
+            }
+            None => world.insert_one(entity, Passenger::new(CabinClass::Standard)),
+        };
+    }
+
+    ImportedSnapshot {
+        world,
+        sim_time: snapshot.sim_time,
+        ship_layout: layout,
+    }
+}
+
+/// Convert engine state into a server [`StateSnapshot`], the way back from
+/// [`import_snapshot`].
+///
+/// Room IDs are positions in the room list and axes are swapped back into
+/// the server's. The engine keeps no health or morale, so everyone exports
+/// healthy and content; nicknames are already in the room and deck names.
+pub fn export_snapshot(
+    world: &World,
+    sim_time: f64,
+    time_scale: f32,
+    layout: &ShipLayout,
+    resources: &ShipResources,
+) -> StateSnapshot {
+    let mut rooms = Vec::with_capacity(layout.rooms.len());
+    let mut doors = Vec::new();
+    for (id, &entity) in layout.rooms.iter().enumerate() {
+        let Ok(room) = world.get::<&Room>(entity) else {
+            continue;
+        };
+        rooms.push(SnapshotRoom {
+            id: id as u32,
+            name: room.name.clone(),
+            room_type: room_type_id(room.room_type),
+            deck: room.deck_level,
+            x: room.world_y,
+            y: room.world_x,
+            width: room.depth(),
+            height: room.width(),
+            capacity: room.capacity,
+        });
+        if let Ok(conn) = world.get::<&RoomConnections>(entity) {
+            doors.extend(
+                conn.connected_to
+                    .iter()
+                    .filter(|&&other| other > id as u32)
+                    .map(|&other| (id as u32, other)),
+            );
+        }
+    }
+
+    let mut people = Vec::new();
+    let mut query = world.query::<(&Person, &Position, &Name)>();
+    for (entity, (_, pos, name)) in query.iter() {
+        let world_pos = layout
+            .rooms
+            .get(pos.room_id as usize)
+            .and_then(|&r| world.get::<&Room>(r).ok())
+            .map_or(pos.local, |room| room.local_to_world(pos.local));
+        let needs = world.get::<&Needs>(entity).map_or([0.0; 5], |n| {
+            [n.hunger, n.fatigue, n.social, n.comfort, n.hygiene]
+        });
+        let activity_type = world
+            .get::<&Activity>(entity)
+            .map_or(activity_types::IDLE, |a| activity_id(a.activity_type));
+        let crew = world.get::<&Crew>(entity).ok().map(|c| SnapshotCrew {
+            department: department_id(c.department),
+            rank: rank_id(c.rank),
+            shift: shift_id(c.shift),
+            duty_station_id: c.duty_station_id,
+        });
+        people.push(SnapshotPerson {
+            id: entity.to_bits().get(),
+            given_name: name.given.clone(),
+            family_name: name.family.clone(),
+            crew,
+            room_id: pos.room_id,
+            x: world_pos.y,
+            y: world_pos.x,
+            needs,
+            health: 1.0,
+            morale: 1.0,
+            activity_type,
+        });
+    }
+    people.sort_by_key(|p| p.id);
+    let crew_count = people.iter().filter(|p| p.crew.is_some()).count() as u32;
+
+    let stores = &resources.storage;
+    StateSnapshot {
+        version: SNAPSHOT_VERSION,
+        ship_name: layout.name.clone(),
+        sim_time,
+        deck_count: layout.decks.len() as u32,
+        crew_count,
+        passenger_count: people.len() as u32 - crew_count,
+        seed: progship_logic::scenarios::default_seed(),
+        corridor_topology: layout.corridor_topology as u8,
+        hull_taper: progship_logic::genlib::hull::TaperCurve::default() as u8,
+        scenario: String::new(),
+        difficulty: progship_logic::scenarios::default_difficulty(),
+        time_scale,
+        death_count: 0,
+        resources: SnapshotResources {
+            power: stores.power,
+            water: stores.water,
+            oxygen: stores.oxygen,
+            food: stores.food,
+            fuel: stores.fuel,
+            spare_parts: stores.spare_parts,
+        },
+        rooms,
+        doors,
+        people,
+        room_nicknames: Vec::new(),
+        deck_nicknames: Vec::new(),
+    }
+}
+
+/// Server room type standing for `room_type`: one that imports back as it.
+fn room_type_id(room_type: RoomType) -> u8 {
+    match room_type {
+        RoomType::Bridge => room_types::BRIDGE,
+        RoomType::ConferenceRoom => room_types::CONFERENCE,
+        RoomType::Engineering => room_types::ENGINEERING,
+        RoomType::ReactorRoom => room_types::REACTOR,
+        RoomType::MaintenanceBay => room_types::MAINTENANCE_BAY,
+        RoomType::Quarters => room_types::SHARED_BATHROOM,
+        RoomType::QuartersCrew => room_types::QUARTERS_CREW,
+        RoomType::QuartersOfficer => room_types::QUARTERS_OFFICER,
+        RoomType::QuartersPassenger => room_types::QUARTERS_PASSENGER,
+        RoomType::Mess => room_types::MESS_HALL,
+        RoomType::Galley => room_types::GALLEY,
+        RoomType::Medical => room_types::MEDBAY,
+        RoomType::Recreation => room_types::THEATRE,
+        RoomType::Gym => room_types::GYM,
+        RoomType::Cargo => room_types::CARGO_BAY,
+        RoomType::Storage => room_types::STORAGE,
+        RoomType::Airlock => room_types::AIRLOCK,
+        RoomType::Corridor => room_types::CORRIDOR,
+        RoomType::Elevator => room_types::ELEVATOR_SHAFT,
+        RoomType::Ladder => room_types::LADDER_SHAFT,
+        RoomType::Laboratory => room_types::LABORATORY,
+        RoomType::Observatory => room_types::OBSERVATORY,
+        RoomType::LifeSupport => room_types::ATMOSPHERE_PROCESSING,
+        RoomType::Hydroponics => room_types::HYDROPONICS,
+        RoomType::WaterRecycling => room_types::WATER_RECYCLING,
+    }
+}
+
+fn activity_id(activity: ActivityType) -> u8 {
+    match activity {
+        ActivityType::Idle => activity_types::IDLE,
+        ActivityType::Working => activity_types::WORKING,
+        ActivityType::Eating => activity_types::EATING,
+        ActivityType::Sleeping => activity_types::SLEEPING,
+        ActivityType::Socializing => activity_types::SOCIALIZING,
+        ActivityType::Relaxing => activity_types::RELAXING,
+        ActivityType::Hygiene => activity_types::HYGIENE,
+        ActivityType::Traveling => activity_types::TRAVELING,
+        ActivityType::Maintenance => activity_types::MAINTENANCE,
+        ActivityType::OnDuty => activity_types::ON_DUTY,
+        ActivityType::OffDuty => activity_types::OFF_DUTY,
+        ActivityType::Emergency => activity_types::EMERGENCY,
+    }
+}
+
+fn department_id(department: Department) -> u8 {
+    match department {
+        Department::Command => departments::COMMAND,
+        Department::Engineering => departments::ENGINEERING,
+        Department::Medical => departments::MEDICAL,
+        Department::Science => departments::SCIENCE,
+        Department::Security => departments::SECURITY,
+        Department::Operations => departments::OPERATIONS,
+        Department::Civilian => departments::CIVILIAN,
+    }
+}
+
+fn rank_id(rank: Rank) -> u8 {
+    match rank {
+        Rank::Crewman => ranks::CREWMAN,
+        Rank::Specialist => ranks::SPECIALIST,
+        Rank::Petty => ranks::PETTY,
+        Rank::Chief => ranks::CHIEF,
+        Rank::Ensign => ranks::ENSIGN,
+        Rank::Lieutenant => ranks::LIEUTENANT,
+        Rank::Commander => ranks::COMMANDER,
+        Rank::Captain => ranks::CAPTAIN,
+    }
+}
+
+fn shift_id(shift: Shift) -> u8 {
+    match shift {
+        Shift::Alpha => shifts::ALPHA,
+        Shift::Beta => shifts::BETA,
+        Shift::Gamma => shifts::GAMMA,
+    }
+}
+
+fn activity_from_id(id: u8) -> ActivityType {
+    match id {
+        activity_types::WORKING => ActivityType::Working,
+        activity_types::EATING => ActivityType::Eating,
+        activity_types::SLEEPING => ActivityType::Sleeping,
+        activity_types::SOCIALIZING => ActivityType::Socializing,
+        activity_types::RELAXING | activity_types::EXERCISING => ActivityType::Relaxing,
+        activity_types::HYGIENE => ActivityType::Hygiene,
+        activity_types::TRAVELING => ActivityType::Traveling,
+        activity_types::MAINTENANCE => ActivityType::Maintenance,
+        activity_types::ON_DUTY => ActivityType::OnDuty,
+        activity_types::OFF_DUTY => ActivityType::OffDuty,
+        activity_types::EMERGENCY => ActivityType::Emergency,
+        _ => ActivityType::Idle,
+    }
+}
+
+fn department_from_id(id: u8) -> Department {
+    match id {
+        departments::COMMAND => Department::Command,
+        departments::ENGINEERING => Department::Engineering,
+        departments::MEDICAL => Department::Medical,
+        departments::SCIENCE => Department::Science,
+        departments::SECURITY => Department::Security,
+        departments::OPERATIONS => Department::Operations,
+        _ => Department::Civilian,
+    }
+}
+
+fn rank_from_id(id: u8) -> Rank {
+    match id {
+        ranks::SPECIALIST => Rank::Specialist,
+        ranks::PETTY => Rank::Petty,
+        ranks::CHIEF => Rank::Chief,
+        ranks::ENSIGN => Rank::Ensign,
+        ranks::LIEUTENANT => Rank::Lieutenant,
+        ranks::COMMANDER => Rank::Commander,
+        ranks::CAPTAIN => Rank::Captain,
+        _ => Rank::Crewman,
+    }
+}
+
+fn shift_from_id(id: u8) -> Shift {
+    match id {
+        shifts::BETA => Shift::Beta,
+        shifts::GAMMA => Shift::Gamma,
+        _ => Shift::Alpha,
+    }
+}
+
 /// Errors that can occur during save/load
 #[derive(Debug)]
 pub enum SaveError {
     Io(std::io::Error),
     Bincode(Box<bincode::ErrorKind>),
-    VersionMismatch { expected: u32, found: u32 },
+    VersionMismatch {
+        expected: u32,
+        found: u32,
+    },
+    /// A JSON state snapshot could not be decoded
+    Snapshot(String),
 }
 
 impl From<std::io::Error> for SaveError {
@@ -333,6 +755,7 @@ impl std::fmt::Display for SaveError {
                     expected, found
                 )
             }
+            SaveError::Snapshot(e) => write!(f, "Snapshot error: {}", e),
         }
     }
 }
@@ -357,6 +780,7 @@ mod tests {
             crew_size: 20,
             ship_length: 100.0,
             ship_width: 20.0,
+            ..Default::default()
         });
 
         // Run a few updates
@@ -373,6 +797,13 @@ mod tests {
 
         println!("Save size: {} bytes", save_buffer.len());
 
+        let header = read_save_header(&save_buffer[..]).expect("Header read failed");
+        assert_eq!(header.ship_name, "Test Ship");
+        assert_eq!(
+            (header.crew_count + header.passenger_count) as usize,
+            original_people
+        );
+
         // Load into new engine
         let mut loaded_engine = SimulationEngine::new();
         loaded_engine.load(&save_buffer[..]).expect("Load failed");
@@ -384,4 +815,176 @@ mod tests {
             original_people
         );
     }
+
+    #[test]
+    fn test_old_version_rejected() {
+        let mut old = bincode::serialize(&1u32).unwrap();
+        old.extend_from_slice(&[0; 16]);
+        assert!(matches!(
+            read_save_header(&old[..]),
+            Err(SaveError::VersionMismatch { found: 1, .. })
+        ));
+        assert!(matches!(
+            load_simulation(&old[..]),
+            Err(SaveError::VersionMismatch { found: 1, .. })
+        ));
+    }
+
+    fn sample_snapshot() -> StateSnapshot {
+        use progship_logic::constants::room_types;
+        use progship_logic::snapshot::{
+            SnapshotCrew, SnapshotPerson, SnapshotRoom, SNAPSHOT_VERSION,
+        };
+        let room = |id, name: &str, room_type, x| SnapshotRoom {
+            id,
+            name: name.into(),
+            room_type,
+            deck: 0,
+            x,
+            y: 10.0,
+            width: 10.0,
+            height: 20.0,
+            capacity: 4,
+        };
+        let person = |id, room_id, x, crew| SnapshotPerson {
+            id,
+            given_name: "Ada".into(),
+            family_name: "Marsh".into(),
+            crew,
+            room_id,
+            x,
+            y: 12.0,
+            needs: [0.5; 5],
+            health: 1.0,
+            morale: 0.8,
+            activity_type: activity_types::EXERCISING,
+        };
+        StateSnapshot {
+            version: SNAPSHOT_VERSION,
+            ship_name: "Exported".into(),
+            sim_time: 30.0,
+            deck_count: 1,
+            crew_count: 1,
+            passenger_count: 1,
+            seed: 42,
+            corridor_topology: 0,
+            hull_taper: 0,
+            scenario: String::new(),
+            difficulty: progship_logic::scenarios::default_difficulty(),
+            time_scale: 1.0,
+            death_count: 0,
+            resources: Default::default(),
+            // Out of ID order on purpose
+            rooms: vec![
+                room(9, "Gym", room_types::GYM, 15.0),
+                room(4, "Bridge", room_types::BRIDGE, 5.0),
+            ],
+            doors: vec![(4, 9)],
+            room_nicknames: vec![(9, "The Sweatbox".into())],
+            deck_nicknames: Vec::new(),
+            people: vec![
+                person(
+                    1,
+                    4,
+                    3.0,
+                    Some(SnapshotCrew {
+                        department: departments::COMMAND,
+                        rank: ranks::CAPTAIN,
+                        shift: shifts::GAMMA,
+                        duty_station_id: 4,
+                    }),
+                ),
+                person(2, 9, 18.0, None),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_import_snapshot() {
+        let imported = import_snapshot(&sample_snapshot());
+        let world = &imported.world;
+        let layout = &imported.ship_layout;
+        assert_eq!(layout.rooms.len(), 2);
+        assert_eq!(layout.decks.len(), 1);
+        assert_eq!((layout.ship_length, layout.ship_width), (20.0, 20.0));
+
+        // Rooms are indexed in server ID order and recentered
+        let bridge = world.get::<&Room>(layout.rooms[0]).unwrap();
+        assert_eq!(bridge.name, "Bridge");
+        // Nicknames replace the generated name for display
+        let gym = world.get::<&Room>(layout.rooms[1]).unwrap();
+        assert_eq!(gym.name, "The Sweatbox");
+        assert_eq!((bridge.world_x, bridge.world_y), (0.0, -5.0));
+        let conn = world.get::<&RoomConnections>(layout.rooms[0]).unwrap();
+        assert!(conn.is_connected(1));
+
+        let mut crew = world.query::<(&Crew, &Position, &Activity)>();
+        let (_, (c, pos, activity)) = crew.iter().next().unwrap();
+        assert_eq!(
+            (c.rank, c.shift, c.duty_station_id),
+            (Rank::Captain, Shift::Gamma, 0)
+        );
+        assert_eq!((pos.room_id, pos.local.x, pos.local.y), (0, 12.0, 3.0));
+        assert_eq!(activity.activity_type, ActivityType::Relaxing);
+        assert_eq!(world.query::<&Passenger>().iter().count(), 1);
+    }
+
+    #[test]
+    fn test_export_snapshot_round_trips_import() {
+        let original = sample_snapshot();
+        let mut engine = SimulationEngine::new();
+        engine.load_snapshot(&original);
+        let exported = engine.export_snapshot().expect("layout loaded");
+
+        assert_eq!(exported.rooms.len(), 2);
+        let (bridge, gym) = (&exported.rooms[0], &exported.rooms[1]);
+        assert_eq!((bridge.id, bridge.room_type), (0, room_types::BRIDGE));
+        assert_eq!(gym.name, "The Sweatbox");
+        assert_eq!((gym.width, gym.height, gym.capacity), (10.0, 20.0, 4));
+        // Recentered, but the rooms keep their spacing
+        assert_eq!(gym.x - bridge.x, 10.0);
+        assert_eq!(exported.doors, vec![(0, 1)]);
+
+        assert_eq!((exported.crew_count, exported.passenger_count), (1, 1));
+        let captain = exported.people.iter().find(|p| p.crew.is_some()).unwrap();
+        assert_eq!(
+            captain.crew,
+            Some(SnapshotCrew {
+                department: departments::COMMAND,
+                rank: ranks::CAPTAIN,
+                shift: shifts::GAMMA,
+                duty_station_id: 0,
+            })
+        );
+        assert_eq!(captain.room_id, 0);
+        assert!((captain.x - bridge.x - (3.0 - 5.0)).abs() < 1e-4);
+        assert_eq!(captain.activity_type, activity_types::RELAXING);
+
+        let frame = engine.frame().expect("layout loaded");
+        assert_eq!((frame.rooms.len(), frame.people.len()), (2, 2));
+        assert!((frame.sim_time - 30.0).abs() < 1e-9);
+        assert!(SimulationEngine::new().frame().is_none());
+    }
+
+    #[test]
+    fn test_load_bytes_detects_format() {
+        let mut engine = SimulationEngine::new();
+        let json = sample_snapshot().to_json();
+        assert!(engine
+            .load_bytes(json.as_bytes())
+            .expect("Snapshot load failed"));
+        assert_eq!(engine.crew_count() + engine.passenger_count(), 2);
+        assert!((engine.sim_time - 30.0).abs() < 0.001);
+
+        let mut save_buffer = Vec::new();
+        engine.save(&mut save_buffer).expect("Save failed");
+        let mut reloaded = SimulationEngine::new();
+        assert!(!reloaded.load_bytes(&save_buffer).expect("Save load failed"));
+        assert_eq!(reloaded.crew_count(), 1);
+
+        assert!(matches!(
+            reloaded.load_bytes(b"{ not json"),
+            Err(SaveError::Snapshot(_))
+        ));
+    }
 }
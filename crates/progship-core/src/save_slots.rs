@@ -0,0 +1,257 @@
+//! Named save slots and the autosave schedule
+//!
+//! Each slot is one save file in a slot directory, named after the slot.
+//! [`SaveSlots::list`] reads only the [`SaveHeader`] of each file, so a
+//! browser can show names, timestamps and ship summaries cheaply.
+//! [`AutosaveSchedule`] rotates through a few autosave slots as simulation
+//! time passes.
+
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::{Path, PathBuf};
+
+use crate::engine::SimulationEngine;
+use crate::persistence::{read_save_header, SaveError, SaveHeader};
+
+/// File extension of slot saves
+pub const SLOT_EXTENSION: &str = "sav";
+
+/// Simulation hours between autosaves
+pub const AUTOSAVE_INTERVAL_HOURS: f64 = 6.0;
+
+/// Number of autosave slots rotated through
+pub const AUTOSAVE_SLOTS: u32 = 3;
+
+/// A save slot found on disk
+#[derive(Debug, Clone)]
+pub struct SlotInfo {
+    pub path: PathBuf,
+    pub header: SaveHeader,
+}
+
+/// A directory of named save slots
+#[derive(Debug, Clone)]
+pub struct SaveSlots {
+    dir: PathBuf,
+}
+
+impl SaveSlots {
+    /// Slots stored in `dir` (created on first save)
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Directory the slots live in
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// File a slot is stored in
+    pub fn slot_path(&self, slot_name: &str) -> PathBuf {
+        self.dir
+            .join(format!("{}.{}", slot_file_stem(slot_name), SLOT_EXTENSION))
+    }
+
+    /// All readable slots, most recently saved first
+    ///
+    /// Files that are not saves, or are from another save version, are
+    /// skipped.
+    pub fn list(&self) -> Vec<SlotInfo> {
+        let Ok(entries) = std::fs::read_dir(&self.dir) else {
+            return Vec::new();
+        };
+        let mut slots: Vec<SlotInfo> = entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == SLOT_EXTENSION))
+            .filter_map(|path| {
+                let file = File::open(&path).ok()?;
+                let header = read_save_header(BufReader::new(file)).ok()?;
+                Some(SlotInfo { path, header })
+            })
+            .collect();
+        slots.sort_by(|a, b| {
+            b.header
+                .saved_at
+                .cmp(&a.header.saved_at)
+                .then_with(|| a.header.slot_name.cmp(&b.header.slot_name))
+        });
+        slots
+    }
+
+    /// Save `engine` into a slot, replacing any save already there
+    pub fn save(&self, engine: &SimulationEngine, slot_name: &str) -> Result<PathBuf, SaveError> {
+        std::fs::create_dir_all(&self.dir)?;
+        let path = self.slot_path(slot_name);
+        let file = File::create(&path)?;
+        engine.save_as(BufWriter::new(file), slot_name)?;
+        Ok(path)
+    }
+
+    /// Load a slot into `engine`
+    pub fn load(&self, engine: &mut SimulationEngine, slot_name: &str) -> Result<(), SaveError> {
+        let file = File::open(self.slot_path(slot_name))?;
+        engine.load(BufReader::new(file))
+    }
+
+    /// Delete a slot
+    pub fn delete(&self, slot_name: &str) -> Result<(), SaveError> {
+        std::fs::remove_file(self.slot_path(slot_name))?;
+        Ok(())
+    }
+}
+
+/// File name stem for a slot: letters and digits kept, anything else
+/// becomes `_`
+pub fn slot_file_stem(slot_name: &str) -> String {
+    let stem: String = slot_name
+        .trim()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if stem.is_empty() {
+        "unnamed".to_string()
+    } else {
+        stem
+    }
+}
+
+/// Name of an autosave slot (`index` wraps at [`AUTOSAVE_SLOTS`])
+pub fn autosave_slot_name(index: u32) -> String {
+    format!("Autosave {}", index % AUTOSAVE_SLOTS + 1)
+}
+
+/// `saved_at` as `YYYY-MM-DD HH:MM` (UTC)
+pub fn format_saved_at(saved_at: u64) -> String {
+    let days = (saved_at / 86_400) as i64;
+    let minutes = (saved_at % 86_400) / 60;
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}",
+        year,
+        month,
+        day,
+        minutes / 60,
+        minutes % 60
+    )
+}
+
+/// When to autosave, and which autosave slot is next
+#[derive(Debug, Clone)]
+pub struct AutosaveSchedule {
+    /// Simulation time of the last autosave (or of the start)
+    pub last_save: f64,
+    /// Index of the next autosave slot
+    pub next_slot: u32,
+}
+
+impl AutosaveSchedule {
+    /// Schedule starting at `sim_time`
+    pub fn new(sim_time: f64) -> Self {
+        Self {
+            last_save: sim_time,
+            next_slot: 0,
+        }
+    }
+
+    /// Slot to autosave into at `sim_time`, if one is due. Advances the
+    /// schedule when it returns a slot.
+    pub fn due(&mut self, sim_time: f64) -> Option<String> {
+        if sim_time < self.last_save {
+            // Loaded an earlier save: restart from there
+            self.last_save = sim_time;
+        }
+        if sim_time - self.last_save < AUTOSAVE_INTERVAL_HOURS {
+            return None;
+        }
+        self.last_save = sim_time;
+        let slot = autosave_slot_name(self.next_slot);
+        self.next_slot = (self.next_slot + 1) % AUTOSAVE_SLOTS;
+        Some(slot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::ShipConfig;
+
+    fn small_engine() -> SimulationEngine {
+        let mut engine = SimulationEngine::new();
+        engine.generate(ShipConfig {
+            name: "Slot Ship".to_string(),
+            num_decks: 2,
+            rooms_per_deck: 5,
+            passenger_capacity: 20,
+            crew_size: 10,
+            ship_length: 100.0,
+            ship_width: 20.0,
+            ..Default::default()
+        });
+        engine
+    }
+
+    #[test]
+    fn test_slot_file_stem() {
+        assert_eq!(slot_file_stem("Before the storm!"), "Before_the_storm_");
+        assert_eq!(slot_file_stem("  "), "unnamed");
+    }
+
+    #[test]
+    fn test_slots_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("progship-slots-{}", std::process::id()));
+        let slots = SaveSlots::new(&dir);
+        assert!(slots.list().is_empty());
+
+        let engine = small_engine();
+        slots.save(&engine, "First").expect("Save failed");
+        slots.save(&engine, "Second").expect("Save failed");
+        std::fs::write(dir.join("junk.sav"), b"not a save").unwrap();
+
+        let listed = slots.list();
+        assert_eq!(listed.len(), 2);
+        let first = listed
+            .iter()
+            .find(|s| s.header.slot_name == "First")
+            .unwrap();
+        assert_eq!(first.header.ship_name, "Slot Ship");
+        assert_eq!(first.header.crew_count, engine.crew_count() as u32);
+
+        let mut loaded = SimulationEngine::new();
+        slots.load(&mut loaded, "First").expect("Load failed");
+        assert_eq!(loaded.crew_count(), engine.crew_count());
+
+        slots.delete("First").expect("Delete failed");
+        assert_eq!(slots.list().len(), 1);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_format_saved_at() {
+        assert_eq!(format_saved_at(0), "1970-01-01 00:00");
+        assert_eq!(format_saved_at(951_827_696), "2000-02-29 12:34");
+    }
+
+    #[test]
+    fn test_autosave_rotates() {
+        let mut schedule = AutosaveSchedule::new(0.0);
+        assert_eq!(schedule.due(1.0), None);
+        assert_eq!(schedule.due(6.0), Some("Autosave 1".to_string()));
+        assert_eq!(schedule.due(7.0), None);
+        assert_eq!(schedule.due(12.5), Some("Autosave 2".to_string()));
+        assert_eq!(schedule.due(19.0), Some("Autosave 3".to_string()));
+        assert_eq!(schedule.due(25.0), Some("Autosave 1".to_string()));
+        // Going back in time restarts the interval
+        assert_eq!(schedule.due(3.0), None);
+        assert_eq!(schedule.due(8.0), None);
+        assert!(schedule.due(9.0).is_some());
+    }
+}
@@ -80,6 +80,8 @@ fn start_movement_to_room(world: &mut World, entity: hecs::Entity, target_room_i
                 next_door_position: None,
                 entry_door_positions: Vec::new(),
                 exit_door_positions: Vec::new(),
+                transit_waits: Vec::new(),
+                wait_remaining: 0.0,
             };
 
             let _ = world.insert_one(entity, movement);
@@ -7,6 +7,7 @@ use crate::components::{
     Activity, ActivityType, Crew, Person, Position, Room, RoomType, ShipSystem, SystemStatus,
 };
 use hecs::World;
+use progship_logic::features::FeatureFlags;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
@@ -29,6 +30,8 @@ pub enum EventType {
     Altercation,
     /// Resource shortage alert
     ResourceShortage,
+    /// Invariant violation detected by the watchdog
+    Diagnostic,
 }
 
 impl EventType {
@@ -41,11 +44,28 @@ impl EventType {
             EventType::MedicalEmergency => 3,
             EventType::ResourceShortage => 2,
             EventType::Altercation => 2,
+            EventType::Diagnostic => 1,
             EventType::Discovery => 1,
             EventType::Celebration => 1,
         }
     }
 
+    /// Shared event type ID (see `progship_logic::constants::event_types`)
+    pub fn type_id(&self) -> u8 {
+        use progship_logic::constants::event_types;
+        match self {
+            EventType::SystemFailure => event_types::SYSTEM_FAILURE,
+            EventType::MedicalEmergency => event_types::MEDICAL_EMERGENCY,
+            EventType::Fire => event_types::FIRE,
+            EventType::HullBreach => event_types::HULL_BREACH,
+            EventType::Discovery => event_types::DISCOVERY,
+            EventType::Celebration => event_types::CELEBRATION,
+            EventType::Altercation => event_types::ALTERCATION,
+            EventType::ResourceShortage => event_types::RESOURCE_SHORTAGE,
+            EventType::Diagnostic => event_types::DIAGNOSTIC,
+        }
+    }
+
     /// Does this event require emergency response?
     pub fn is_emergency(&self) -> bool {
         self.severity() >= 3
@@ -144,6 +164,7 @@ impl EventManager {
             EventType::MedicalEmergency => 1.0,
             EventType::Celebration => 4.0,
             EventType::Discovery => 0.5,
+            EventType::Diagnostic => 1.0,
             _ => 0.5,
         };
 
@@ -242,6 +263,7 @@ impl EventManager {
 pub fn generate_random_events(
     world: &World,
     event_manager: &mut EventManager,
+    features: &FeatureFlags,
     sim_time: f64,
     rng: &mut impl Rng,
 ) {
@@ -261,12 +283,16 @@ pub fn generate_random_events(
         return;
     }
 
-    // Random chance for each event type
+    // Random chance for each event type the feature flags allow
     // Probabilities are per check (roughly every 6 sim minutes)
+    let allowed = |event_type: EventType| features.allows_event(event_type.type_id());
 
     // System failure: check systems with low health
     for (entity, system) in world.query::<&ShipSystem>().iter() {
-        if system.status == SystemStatus::Critical && rng.gen_bool(0.02) {
+        if system.status == SystemStatus::Critical
+            && allowed(EventType::SystemFailure)
+            && rng.gen_bool(0.02)
+        {
             let room_id = entity.id() as u32;
             event_manager.spawn_event(
                 EventType::SystemFailure,
@@ -283,7 +309,7 @@ pub fn generate_random_events(
 
     // Medical emergency: random chance based on population
     let person_count = world.query::<&Person>().iter().count();
-    if person_count > 0 && rng.gen_bool(0.001) {
+    if person_count > 0 && allowed(EventType::MedicalEmergency) && rng.gen_bool(0.001) {
         // Pick a random room with people
         if let Some((_, (_, pos))) = world.query::<(&Person, &Position)>().iter().next() {
             event_manager.spawn_event(
@@ -296,7 +322,7 @@ pub fn generate_random_events(
     }
 
     // Celebration: occasional morale boost
-    if rng.gen_bool(0.0005) {
+    if allowed(EventType::Celebration) && rng.gen_bool(0.0005) {
         // Find a recreation or mess room
         for (entity, room) in world.query::<&Room>().iter() {
             if room.room_type == RoomType::Recreation || room.room_type == RoomType::Mess {
@@ -312,7 +338,7 @@ pub fn generate_random_events(
     }
 
     // Discovery: science labs occasionally make discoveries
-    if rng.gen_bool(0.0002) {
+    if allowed(EventType::Discovery) && rng.gen_bool(0.0002) {
         for (entity, room) in world.query::<&Room>().iter() {
             if room.room_type == RoomType::Laboratory || room.room_type == RoomType::Observatory {
                 event_manager.spawn_event(
@@ -381,6 +407,7 @@ mod tests {
     fn test_event_severity() {
         assert_eq!(EventType::HullBreach.severity(), 5);
         assert_eq!(EventType::Celebration.severity(), 1);
+        assert!(!EventType::Diagnostic.is_emergency());
         assert!(EventType::Fire.is_emergency());
         assert!(!EventType::Discovery.is_emergency());
     }
@@ -436,4 +463,33 @@ mod tests {
 
         assert!(resolved.contains(&id2));
     }
+
+    #[test]
+    fn test_disease_flag_gates_medical_emergencies() {
+        use rand::SeedableRng;
+        let mut world = World::new();
+        world.spawn((Person, Position::new(0.0, 0.0, 0)));
+        let emergencies = |features: FeatureFlags| {
+            let mut manager = EventManager::new();
+            let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+            let mut count = 0;
+            for check in 1..=20_000 {
+                generate_random_events(&world, &mut manager, &features, check as f64, &mut rng);
+                count += manager
+                    .events
+                    .iter()
+                    .filter(|e| e.event_type == EventType::MedicalEmergency)
+                    .count();
+                // Keep the emergency cap out of the way
+                manager.events.clear();
+            }
+            count
+        };
+        assert!(emergencies(FeatureFlags::default()) > 0);
+        let healthy = FeatureFlags {
+            disease: false,
+            ..FeatureFlags::default()
+        };
+        assert_eq!(emergencies(healthy), 0);
+    }
 }
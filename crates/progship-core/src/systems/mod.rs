@@ -10,6 +10,7 @@ mod needs;
 mod ship_systems;
 mod social;
 mod wandering;
+mod watchdog;
 
 pub use activity::*;
 pub use dialogue::*;
@@ -21,3 +22,4 @@ pub use needs::*;
 pub use ship_systems::*;
 pub use social::*;
 pub use wandering::*;
+pub use watchdog::*;
@@ -1,7 +1,10 @@
 //! Movement system - updates positions for entities with Movement component
 
-use crate::components::{Movement, Position, Room, Vec3};
+use crate::components::{Crew, Movement, Position, Room, RoomType, Vec3};
 use hecs::World;
+use progship_logic::constants::{departments, room_types};
+use progship_logic::pathfinding::{transit_waits, TransitStep};
+use progship_logic::security::{check_access, AccessRequest};
 
 /// Move entities toward their destinations (handles inter-room paths)
 pub fn movement_system(world: &mut World, delta_seconds: f32) {
@@ -41,6 +44,13 @@ fn process_movement(
     movement: &Movement,
     delta_seconds: f32,
 ) -> (Position, Option<Movement>) {
+    // Waiting for an elevator car or riding/climbing a shaft
+    if movement.wait_remaining > 0.0 {
+        let mut waiting = movement.clone();
+        waiting.wait_remaining = (movement.wait_remaining - delta_seconds).max(0.0);
+        return (*pos, Some(waiting));
+    }
+
     let current = pos.local;
     let target = movement.destination;
 
@@ -94,6 +104,12 @@ fn process_movement(
                     .copied(),
                 entry_door_positions: movement.entry_door_positions.clone(),
                 exit_door_positions: movement.exit_door_positions.clone(),
+                transit_waits: movement.transit_waits.clone(),
+                wait_remaining: movement
+                    .transit_waits
+                    .get(movement.path_index + 1)
+                    .copied()
+                    .unwrap_or(0.0),
             };
 
             (new_pos, Some(new_movement))
@@ -116,8 +132,79 @@ fn process_movement(
     }
 }
 
-/// Calculate path between rooms (simple A* on room graph)
+/// Who is walking a path, for door access checks on each room entered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Clearance {
+    pub is_crew: bool,
+    /// Rank value (see `progship_logic::constants::ranks`)
+    pub rank: u8,
+    /// Department value (see `progship_logic::constants::departments`)
+    pub department: u8,
+}
+
+impl Clearance {
+    /// A passenger: public rooms only.
+    pub fn passenger() -> Self {
+        Self {
+            is_crew: false,
+            rank: 0,
+            department: departments::CIVILIAN,
+        }
+    }
+
+    /// A crew member with the given rank and department.
+    pub fn crew(crew: &Crew) -> Self {
+        Self {
+            is_crew: true,
+            rank: crew.rank as u8,
+            department: crew.department as u8,
+        }
+    }
+
+    /// Clearance of an entity: crew if it has a [`Crew`] component, otherwise passenger.
+    pub fn of(world: &World, entity: hecs::Entity) -> Self {
+        world
+            .get::<&Crew>(entity)
+            .map(|crew| Self::crew(&crew))
+            .unwrap_or_else(|_| Self::passenger())
+    }
+
+    /// Whether this person may pass the door into `room`.
+    pub fn can_enter(&self, room: &Room) -> bool {
+        check_access(&AccessRequest {
+            door_access_level: room.access_level,
+            is_crew: self.is_crew,
+            rank: self.rank,
+            department: self.department,
+            door_department: room.department,
+            is_lockdown: false,
+        })
+        .allowed
+    }
+}
+
+/// Calculate path between rooms (simple A* on room graph), ignoring access levels
 pub fn find_path(world: &World, from_room_id: u32, to_room_id: u32) -> Option<Vec<u32>> {
+    find_path_inner(world, from_room_id, to_room_id, None)
+}
+
+/// Calculate a path that only enters rooms `clearance` may access.
+/// Leaving the starting room is always allowed.
+pub fn find_accessible_path(
+    world: &World,
+    from_room_id: u32,
+    to_room_id: u32,
+    clearance: &Clearance,
+) -> Option<Vec<u32>> {
+    find_path_inner(world, from_room_id, to_room_id, Some(clearance))
+}
+
+fn find_path_inner(
+    world: &World,
+    from_room_id: u32,
+    to_room_id: u32,
+    clearance: Option<&Clearance>,
+) -> Option<Vec<u32>> {
     use crate::components::RoomConnections;
     use std::cmp::Reverse;
     use std::collections::{BinaryHeap, HashMap};
@@ -128,10 +215,14 @@ pub fn find_path(world: &World, from_room_id: u32, to_room_id: u32) -> Option<Ve
 
     // Build room graph from world
     let mut connections: HashMap<u32, Vec<u32>> = HashMap::new();
+    let mut enterable: HashMap<u32, bool> = HashMap::new();
     let mut room_id_counter = 0u32;
 
-    for (_, conn) in world.query::<&RoomConnections>().iter() {
+    for (_, (conn, room)) in world.query::<(&RoomConnections, Option<&Room>)>().iter() {
         connections.insert(room_id_counter, conn.connected_to.clone());
+        if let (Some(clearance), Some(room)) = (clearance, room) {
+            enterable.insert(room_id_counter, clearance.can_enter(room));
+        }
         room_id_counter += 1;
     }
 
@@ -158,6 +249,9 @@ pub fn find_path(world: &World, from_room_id: u32, to_room_id: u32) -> Option<Ve
 
         if let Some(neighbors) = connections.get(&current) {
             for &next in neighbors {
+                if !enterable.get(&next).copied().unwrap_or(true) {
+                    continue;
+                }
                 let new_cost = cost_so_far
                     .get(&current)
                     .unwrap_or(&u32::MAX)
@@ -174,7 +268,8 @@ pub fn find_path(world: &World, from_room_id: u32, to_room_id: u32) -> Option<Ve
     None // No path found
 }
 
-/// Start movement for an entity to a destination room
+/// Start movement for an entity to a destination room, routing only through
+/// rooms its [`Clearance`] allows
 pub fn start_movement_to_room(
     world: &mut World,
     entity: hecs::Entity,
@@ -188,19 +283,26 @@ pub fn start_movement_to_room(
         Err(_) => return false,
     };
 
-    if let Some(path) = find_path(world, current_room_id, target_room_id) {
+    let clearance = Clearance::of(world, entity);
+    if let Some(path) = find_accessible_path(world, current_room_id, target_room_id, &clearance) {
         // Calculate door positions for each room in path
         let mut entry_door_positions = Vec::new();
         let mut exit_door_positions = Vec::new();
+        let mut steps = Vec::new();
 
         for &room_id in &path {
             if (room_id as usize) < room_entities.len() {
                 if let Ok(room) = world.get::<&Room>(room_entities[room_id as usize]) {
                     entry_door_positions.push(room.door_position());
                     exit_door_positions.push(room.door_position());
+                    steps.push(transit_step(&room));
                 } else {
                     entry_door_positions.push(Vec3::new(0.0, 0.0, 0.0));
                     exit_door_positions.push(Vec3::new(5.0, 5.0, 0.0));
+                    steps.push(TransitStep {
+                        shaft_type: None,
+                        deck: 0,
+                    });
                 }
             }
         }
@@ -223,6 +325,8 @@ pub fn start_movement_to_room(
             next_door_position: next_door,
             entry_door_positions,
             exit_door_positions,
+            transit_waits: transit_waits(&steps),
+            wait_remaining: 0.0,
         };
 
         let _ = world.insert_one(entity, movement);
@@ -232,6 +336,19 @@ pub fn start_movement_to_room(
     }
 }
 
+/// Describe a room for shaft wait pricing.
+fn transit_step(room: &Room) -> TransitStep {
+    let shaft_type = match room.room_type {
+        RoomType::Elevator => Some(room_types::ELEVATOR_SHAFT),
+        RoomType::Ladder => Some(room_types::LADDER_SHAFT),
+        _ => None,
+    };
+    TransitStep {
+        shaft_type,
+        deck: room.deck_level,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -290,6 +407,8 @@ mod tests {
             next_door_position: Some(Vec3::new(0.0, 0.0, 0.0)),
             entry_door_positions: vec![Vec3::new(0.0, 0.0, 0.0); 3],
             exit_door_positions: vec![Vec3::new(5.0, 0.0, 0.0); 3],
+            transit_waits: Vec::new(),
+            wait_remaining: 0.0,
         };
 
         let entity = world.spawn((Position::new(0.0, 0.0, 0), movement));
@@ -301,4 +420,193 @@ mod tests {
         let pos = world.get::<&Position>(entity).unwrap();
         assert_eq!(pos.room_id, 1);
     }
+
+    /// Corridor D0 ↔ elevator D0 ↔ elevator D1 ↔ corridor D1.
+    fn two_deck_world() -> (World, Vec<hecs::Entity>) {
+        use crate::components::RoomConnections;
+
+        let mut world = World::new();
+        let specs = [
+            (RoomType::Corridor, 0, vec![1]),
+            (RoomType::Elevator, 0, vec![0, 2]),
+            (RoomType::Elevator, 1, vec![1, 3]),
+            (RoomType::Corridor, 1, vec![2]),
+        ];
+        let rooms = specs
+            .into_iter()
+            .map(|(room_type, deck, links)| {
+                let room = Room::new("Room", room_type, 4.0, 4.0).with_deck_level(deck);
+                let mut conn = RoomConnections::new();
+                for id in links {
+                    conn.connect(id);
+                }
+                world.spawn((room, conn))
+            })
+            .collect();
+        (world, rooms)
+    }
+
+    #[test]
+    fn test_cross_deck_path_prices_elevator() {
+        let (mut world, rooms) = two_deck_world();
+        let person = world.spawn((Position::new(1.0, 1.0, 0),));
+
+        assert!(start_movement_to_room(
+            &mut world,
+            person,
+            3,
+            Vec3::new(2.0, 2.0, 0.0),
+            100.0,
+            &rooms,
+        ));
+
+        let movement = world.get::<&Movement>(person).unwrap();
+        assert_eq!(movement.path, vec![0, 1, 2, 3]);
+        assert_eq!(
+            movement.transit_waits,
+            transit_waits(&[
+                TransitStep {
+                    shaft_type: None,
+                    deck: 0
+                },
+                TransitStep {
+                    shaft_type: Some(room_types::ELEVATOR_SHAFT),
+                    deck: 0
+                },
+                TransitStep {
+                    shaft_type: Some(room_types::ELEVATOR_SHAFT),
+                    deck: 1
+                },
+                TransitStep {
+                    shaft_type: None,
+                    deck: 1
+                },
+            ])
+        );
+        assert!(movement.transit_waits[1] > 0.0);
+    }
+
+    #[test]
+    fn test_elevator_wait_delays_arrival() {
+        let (mut world, rooms) = two_deck_world();
+        let person = world.spawn((Position::new(1.0, 1.0, 0),));
+        start_movement_to_room(
+            &mut world,
+            person,
+            3,
+            Vec3::new(2.0, 2.0, 0.0),
+            100.0,
+            &rooms,
+        );
+
+        // Walk into the elevator, then stand waiting for the car
+        movement_system(&mut world, 0.1);
+        assert_eq!(world.get::<&Position>(person).unwrap().room_id, 1);
+        let wait = world.get::<&Movement>(person).unwrap().wait_remaining;
+        assert!(wait > 0.0);
+        movement_system(&mut world, wait / 2.0);
+        assert_eq!(world.get::<&Position>(person).unwrap().room_id, 1);
+
+        // Long enough for the call wait and one deck of travel
+        for _ in 0..100 {
+            movement_system(&mut world, 1.0);
+        }
+        assert!(world.get::<&Movement>(person).is_err());
+        assert_eq!(world.get::<&Position>(person).unwrap().room_id, 3);
+    }
+
+    /// Corridor ↔ reactor (engineering only) ↔ corridor, plus a public
+    /// detour through a mess hall from the first corridor to the last.
+    fn restricted_world(with_detour: bool) -> (World, Vec<hecs::Entity>) {
+        use crate::components::RoomConnections;
+        use progship_logic::security::access_levels;
+
+        let mut world = World::new();
+        let mut specs = vec![
+            (RoomType::Corridor, access_levels::PUBLIC, vec![1]),
+            (RoomType::ReactorRoom, access_levels::DEPARTMENT, vec![0, 2]),
+            (RoomType::Corridor, access_levels::PUBLIC, vec![1]),
+        ];
+        if with_detour {
+            specs[0].2.push(3);
+            specs[2].2.push(3);
+            specs.push((RoomType::Mess, access_levels::PUBLIC, vec![0, 2]));
+        }
+        let rooms = specs
+            .into_iter()
+            .map(|(room_type, access, links)| {
+                let department =
+                    (access == access_levels::DEPARTMENT).then_some(departments::ENGINEERING);
+                let room = Room::new("Room", room_type, 4.0, 4.0).with_access(access, department);
+                let mut conn = RoomConnections::new();
+                for id in links {
+                    conn.connect(id);
+                }
+                world.spawn((room, conn))
+            })
+            .collect();
+        (world, rooms)
+    }
+
+    #[test]
+    fn test_passenger_blocked_by_restricted_room() {
+        use crate::components::{Department, Rank, Shift};
+
+        let (mut world, rooms) = restricted_world(false);
+        let passenger = world.spawn((Position::new(1.0, 1.0, 0),));
+        let engineer = world.spawn((
+            Position::new(1.0, 1.0, 0),
+            Crew::new(Department::Engineering, Rank::Crewman, Shift::Alpha),
+        ));
+        let medic = world.spawn((
+            Position::new(1.0, 1.0, 0),
+            Crew::new(Department::Medical, Rank::Crewman, Shift::Alpha),
+        ));
+
+        let target = Vec3::new(2.0, 2.0, 0.0);
+        assert!(!start_movement_to_room(
+            &mut world, passenger, 2, target, 1.2, &rooms
+        ));
+        assert!(!start_movement_to_room(
+            &mut world, medic, 2, target, 1.2, &rooms
+        ));
+        assert!(start_movement_to_room(
+            &mut world, engineer, 2, target, 1.2, &rooms
+        ));
+        assert_eq!(
+            world.get::<&Movement>(engineer).unwrap().path,
+            vec![0, 1, 2]
+        );
+
+        // Unrestricted pathfinding still sees the shortcut
+        assert_eq!(find_path(&world, 0, 2), Some(vec![0, 1, 2]));
+    }
+
+    #[test]
+    fn test_passenger_routes_around_restricted_room() {
+        let (mut world, rooms) = restricted_world(true);
+        let passenger = world.spawn((Position::new(1.0, 1.0, 0),));
+
+        assert!(start_movement_to_room(
+            &mut world,
+            passenger,
+            2,
+            Vec3::new(2.0, 2.0, 0.0),
+            1.2,
+            &rooms,
+        ));
+        assert_eq!(
+            world.get::<&Movement>(passenger).unwrap().path,
+            vec![0, 3, 2]
+        );
+    }
+
+    #[test]
+    fn test_clearance_leaves_restricted_start_room() {
+        let (world, _) = restricted_world(false);
+        assert_eq!(
+            find_accessible_path(&world, 1, 0, &Clearance::passenger()),
+            Some(vec![1, 0])
+        );
+    }
 }
@@ -5,8 +5,11 @@ use crate::components::{Activity, Movement, Person, Position, Room, RoomConnecti
 use hecs::World;
 use rand::Rng;
 
+/// Fraction of exploration trips that target any room rather than a neighbour.
+const SHIP_TRIP_CHANCE: f32 = 0.2;
+
 /// Give idle people (no Activity, no Movement) random wander targets within room bounds
-/// Occasionally sends people to adjacent rooms for exploration
+/// Occasionally sends people to adjacent rooms, or to other decks, for exploration
 pub fn wandering_system(world: &mut World, room_entities: &[hecs::Entity]) {
     let mut rng = rand::thread_rng();
     let mut local_wander = Vec::new();
@@ -84,8 +87,13 @@ pub fn wandering_system(world: &mut World, room_entities: &[hecs::Entity]) {
                 continue;
             }
 
-            // Pick a random connected room
-            let target_room_id = connected[rng.gen_range(0..connected.len())];
+            // Usually pick a neighbouring room; sometimes head anywhere on the
+            // ship, which sends people through the elevators to other decks
+            let target_room_id = if rng.gen::<f32>() < SHIP_TRIP_CHANCE {
+                rng.gen_range(0..room_entities.len()) as u32
+            } else {
+                connected[rng.gen_range(0..connected.len())]
+            };
 
             // Get target room dimensions
             let (target_width, target_depth): (f32, f32) = room_sizes
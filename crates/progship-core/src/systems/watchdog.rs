@@ -0,0 +1,149 @@
+//! Watchdog system - hourly invariant checks that surface corrupt state
+
+use crate::components::{ConversationState, InConversation, Needs, Person, Position};
+use crate::systems::{ConversationManager, EventManager, EventType, ShipResources};
+use hecs::World;
+use progship_logic::watchdog::{guard_violation, needs_violations, resource_violations, Violation};
+
+/// Collect every broken invariant in the world
+pub fn check_invariants(
+    world: &World,
+    resources: &ShipResources,
+    conversations: &ConversationManager,
+    room_count: usize,
+) -> Vec<Violation> {
+    let mut violations = Vec::new();
+
+    for (entity, (_, pos)) in world.query::<(&Person, &Position)>().iter() {
+        if pos.room_id as usize >= room_count {
+            violations.push(Violation::MissingRoom {
+                person_id: entity.id() as u64,
+                room_id: pos.room_id,
+            });
+        }
+    }
+
+    for (entity, (_, n)) in world.query::<(&Person, &Needs)>().iter() {
+        violations.extend(needs_violations(
+            entity.id() as u64,
+            &[
+                ("hunger", n.hunger),
+                ("fatigue", n.fatigue),
+                ("social", n.social),
+                ("comfort", n.comfort),
+                ("hygiene", n.hygiene),
+            ],
+        ));
+    }
+
+    let s = &resources.storage;
+    violations.extend(resource_violations(&[
+        ("power", s.power),
+        ("water", s.water),
+        ("oxygen", s.oxygen),
+        ("food", s.food),
+        ("fuel", s.fuel),
+        ("coolant", s.coolant),
+        ("spare_parts", s.spare_parts),
+    ]));
+
+    let person_count = world.query::<&Person>().iter().count();
+    let mut orphaned: Vec<u32> = conversations
+        .conversations
+        .iter()
+        .filter(|(_, c)| c.participants.iter().any(|&p| p as usize >= person_count))
+        .map(|(id, _)| *id)
+        .collect();
+    for (_, ic) in world.query::<&InConversation>().iter() {
+        let running = conversations
+            .conversations
+            .iter()
+            .any(|(id, c)| *id == ic.conversation_id && c.state != ConversationState::Ended);
+        if !running {
+            orphaned.push(ic.conversation_id);
+        }
+    }
+    orphaned.sort_unstable();
+    orphaned.dedup();
+    violations.extend(
+        orphaned
+            .into_iter()
+            .map(|id| Violation::OrphanedConversation {
+                conversation_id: id as u64,
+            }),
+    );
+    violations.extend(guard_violation());
+
+    violations
+}
+
+/// Run the invariant checks and raise a diagnostic event listing any
+/// violations. Returns the violations found.
+pub fn watchdog_system(
+    world: &World,
+    resources: &ShipResources,
+    conversations: &ConversationManager,
+    events: &mut EventManager,
+    room_count: usize,
+    sim_time: f64,
+) -> Vec<Violation> {
+    let violations = check_invariants(world, resources, conversations, room_count);
+    let already_active = events
+        .active_events()
+        .any(|e| e.event_type == EventType::Diagnostic);
+    if !violations.is_empty() && !already_active {
+        let description = violations
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join("; ");
+        events.spawn_event(EventType::Diagnostic, 0, sim_time, description);
+    }
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_watchdog_reports_broken_state() {
+        let mut world = World::new();
+        let mut resources = ShipResources::new();
+        let conversations = ConversationManager::new();
+        let mut events = EventManager::new();
+        let needs = Needs {
+            hunger: 0.5,
+            fatigue: 0.5,
+            social: 0.5,
+            comfort: 0.5,
+            hygiene: 0.5,
+        };
+        world.spawn((Person, Position::new(0.0, 0.0, 0), needs));
+        assert!(
+            watchdog_system(&world, &resources, &conversations, &mut events, 1, 1.0).is_empty()
+        );
+        assert_eq!(events.active_events().count(), 0);
+
+        world.spawn((
+            Person,
+            Position::new(0.0, 0.0, 5),
+            Needs {
+                hunger: 1.5,
+                ..needs
+            },
+            InConversation { conversation_id: 3 },
+        ));
+        resources.storage.water = -1.0;
+        let found = watchdog_system(&world, &resources, &conversations, &mut events, 1, 2.0);
+        assert_eq!(found.len(), 4);
+        assert!(found.contains(&Violation::OrphanedConversation { conversation_id: 3 }));
+        let diagnostic = events.active_events().next().unwrap();
+        assert_eq!(diagnostic.event_type, EventType::Diagnostic);
+        assert!(diagnostic.description.contains("water is -1"));
+
+        // One diagnostic at a time
+        watchdog_system(&world, &resources, &conversations, &mut events, 1, 3.0);
+        assert_eq!(events.active_events().count(), 1);
+    }
+}
@@ -119,8 +119,38 @@ typedef struct ProgShipRoom {
    * Room type (see RoomType enum values)
    */
   uint8_t room_type;
+  /**
+   * Access level required to enter (0=public, 1=crew, 2=department, 3=officer, 4=captain)
+   */
+  uint8_t access_level;
 } ProgShipRoom;
 
+/**
+ * Optional simulation systems (all off runs the base simulation)
+ */
+typedef struct ProgShipFeatureFlags {
+  /**
+   * Illness: medical emergencies break out
+   */
+  bool disease;
+  /**
+   * Generational mode: people age, have children and die of old age
+   */
+  bool generational;
+  /**
+   * Crime: altercations break out
+   */
+  bool crime;
+  /**
+   * Politics: factions and shipboard governance
+   */
+  bool politics;
+  /**
+   * Hardcore atmosphere: bad air, heat, cold and low pressure do double harm
+   */
+  bool hardcore_atmosphere;
+} ProgShipFeatureFlags;
+
 #ifdef __cplusplus
 extern "C" {
 #endif // __cplusplus
@@ -169,6 +199,33 @@ void progship_set_time_scale(ProgShipHandle handle, float scale);
  */
 float progship_get_time_scale(ProgShipHandle handle);
 
+/**
+ * Set the fixed simulation steps per simulated second (clamped to 1-1000)
+ */
+void progship_set_tick_rate(ProgShipHandle handle, float hz);
+
+/**
+ * Get the fixed simulation steps per simulated second
+ */
+float progship_get_tick_rate(ProgShipHandle handle);
+
+/**
+ * Get how far the host is between the last step and the next (0.0-1.0)
+ *
+ * Blend positions from the last two steps by this to render smoothly.
+ */
+float progship_get_interpolation_alpha(ProgShipHandle handle);
+
+/**
+ * Choose which optional systems run from now on
+ */
+void progship_set_feature_flags(ProgShipHandle handle, struct ProgShipFeatureFlags flags);
+
+/**
+ * Get which optional systems run
+ */
+bool progship_get_feature_flags(ProgShipHandle handle, struct ProgShipFeatureFlags *flags);
+
 /**
  * Get simulation statistics
  */
@@ -186,6 +243,17 @@ uint32_t progship_person_count(ProgShipHandle handle);
  */
 bool progship_get_person(ProgShipHandle handle, uint32_t index, struct ProgShipPerson *person);
 
+/**
+ * Get a person's world position blended between the last two steps
+ *
+ * Same indexing as `progship_get_person`. Returns true if successful,
+ * false if index out of bounds
+ */
+bool progship_get_interpolated_position(ProgShipHandle handle,
+                                        uint32_t index,
+                                        float *world_x,
+                                        float *world_y);
+
 /**
  * Get the number of rooms
  */
@@ -216,6 +284,16 @@ double progship_get_sim_time(ProgShipHandle handle);
  */
 uint32_t progship_get_hour_of_day(ProgShipHandle handle);
 
+/**
+ * Encode a key frame of every room and everyone aboard (see the
+ * progship-frame wire format) into `buffer`
+ *
+ * Returns the frame's size in bytes, or 0 with no ship. Nothing is written
+ * if `buffer` is null or smaller than that, so call once with a null
+ * buffer to size it.
+ */
+uintptr_t progship_export_frame(ProgShipHandle handle, uint8_t *buffer, uintptr_t capacity);
+
 #ifdef __cplusplus
 } // extern "C"
 #endif // __cplusplus
@@ -30,7 +30,7 @@
 //! ```
 
 use progship_core::engine::SimulationEngine;
-use progship_core::generation::ShipConfig;
+use progship_core::generation::{CorridorTopology, FeatureFlags, ShipConfig};
 use progship_core::components::{Position, Person, Crew, Room, Needs, Vec3};
 
 /// Opaque handle to the simulation engine
@@ -76,6 +76,8 @@ pub struct ProgShipRoom {
     pub deck_level: i32,
     /// Room type (see RoomType enum values)
     pub room_type: u8,
+    /// Access level required to enter (0=public, 1=crew, 2=department, 3=officer, 4=captain)
+    pub access_level: u8,
 }
 
 /// Simulation statistics
@@ -97,6 +99,21 @@ pub struct ProgShipStats {
     pub time_scale: f32,
 }
 
+/// Optional simulation systems (all off runs the base simulation)
+#[repr(C)]
+pub struct ProgShipFeatureFlags {
+    /// Illness: medical emergencies break out
+    pub disease: bool,
+    /// Generational mode: people age, have children and die of old age
+    pub generational: bool,
+    /// Crime: altercations break out
+    pub crime: bool,
+    /// Politics: factions and shipboard governance
+    pub politics: bool,
+    /// Hardcore atmosphere: bad air, heat, cold and low pressure do double harm
+    pub hardcore_atmosphere: bool,
+}
+
 // ============================================================================
 // Lifecycle Functions
 // ============================================================================
@@ -147,6 +164,8 @@ pub extern "C" fn progship_generate(
         crew_size,
         ship_length: 200.0,
         ship_width: 40.0,
+        corridor_topology: CorridorTopology::CentralSpine,
+        features: FeatureFlags::default(),
     };
     sim.generate(config);
 }
@@ -190,6 +209,78 @@ pub extern "C" fn progship_get_time_scale(handle: ProgShipHandle) -> f32 {
     sim.time_scale()
 }
 
+/// Set the fixed simulation steps per simulated second (clamped to 1-1000)
+#[no_mangle]
+pub extern "C" fn progship_set_tick_rate(handle: ProgShipHandle, hz: f32) {
+    if handle.is_null() {
+        return;
+    }
+    
+    let sim = unsafe { &mut *handle };
+    sim.set_tick_rate(hz);
+}
+
+/// Get the fixed simulation steps per simulated second
+#[no_mangle]
+pub extern "C" fn progship_get_tick_rate(handle: ProgShipHandle) -> f32 {
+    if handle.is_null() {
+        return 0.0;
+    }
+    
+    let sim = unsafe { &*handle };
+    sim.tick_rate()
+}
+
+/// Get how far the host is between the last step and the next (0.0-1.0)
+/// 
+/// Blend positions from the last two steps by this to render smoothly.
+#[no_mangle]
+pub extern "C" fn progship_get_interpolation_alpha(handle: ProgShipHandle) -> f32 {
+    if handle.is_null() {
+        return 0.0;
+    }
+    
+    let sim = unsafe { &*handle };
+    sim.interpolation_alpha()
+}
+
+/// Choose which optional systems run from now on
+#[no_mangle]
+pub extern "C" fn progship_set_feature_flags(handle: ProgShipHandle, flags: ProgShipFeatureFlags) {
+    if handle.is_null() {
+        return;
+    }
+    
+    let sim = unsafe { &mut *handle };
+    sim.set_features(FeatureFlags {
+        disease: flags.disease,
+        generational: flags.generational,
+        crime: flags.crime,
+        politics: flags.politics,
+        hardcore_atmosphere: flags.hardcore_atmosphere,
+    });
+}
+
+/// Get which optional systems run
+#[no_mangle]
+pub extern "C" fn progship_get_feature_flags(handle: ProgShipHandle, flags: *mut ProgShipFeatureFlags) -> bool {
+    if handle.is_null() || flags.is_null() {
+        return false;
+    }
+    
+    let sim = unsafe { &*handle };
+    let out = unsafe { &mut *flags };
+    let features = sim.features();
+    
+    out.disease = features.disease;
+    out.generational = features.generational;
+    out.crime = features.crime;
+    out.politics = features.politics;
+    out.hardcore_atmosphere = features.hardcore_atmosphere;
+    
+    true
+}
+
 // ============================================================================
 // Query Functions
 // ============================================================================
@@ -277,6 +368,48 @@ pub extern "C" fn progship_get_person(
     false
 }
 
+/// Get a person's world position blended between the last two steps
+/// 
+/// Same indexing as `progship_get_person`. Returns true if successful,
+/// false if index out of bounds
+#[no_mangle]
+pub extern "C" fn progship_get_interpolated_position(
+    handle: ProgShipHandle,
+    index: u32,
+    world_x: *mut f32,
+    world_y: *mut f32,
+) -> bool {
+    if handle.is_null() {
+        return false;
+    }
+    
+    let sim = unsafe { &*handle };
+    
+    let Some((entity, pos)) = sim
+        .world
+        .query::<(&Person, &Position)>()
+        .iter()
+        .nth(index as usize)
+        .map(|(entity, (_, pos))| (entity, *pos))
+    else {
+        return false;
+    };
+    let blended = Position {
+        local: sim.interpolated_position(entity).unwrap_or(pos.local),
+        ..pos
+    };
+    let (x, y, _) = get_world_position(sim, &blended);
+    
+    if !world_x.is_null() {
+        unsafe { *world_x = x; }
+    }
+    if !world_y.is_null() {
+        unsafe { *world_y = y; }
+    }
+    
+    true
+}
+
 /// Get the number of rooms
 #[no_mangle]
 pub extern "C" fn progship_room_count(handle: ProgShipHandle) -> u32 {
@@ -323,6 +456,7 @@ pub extern "C" fn progship_get_room(
     out.depth = room_data.depth();
     out.deck_level = room_data.deck_level;
     out.room_type = room_data.room_type as u8;
+    out.access_level = room_data.access_level;
     
     true
 }
@@ -387,6 +521,37 @@ pub extern "C" fn progship_get_hour_of_day(handle: ProgShipHandle) -> u32 {
     sim.hour_of_day() as u32
 }
 
+/// Encode a key frame of every room and everyone aboard (see the
+/// progship-frame wire format) into `buffer`
+/// 
+/// Returns the frame's size in bytes, or 0 with no ship. Nothing is written
+/// if `buffer` is null or smaller than that, so call once with a null
+/// buffer to size it.
+#[no_mangle]
+pub extern "C" fn progship_export_frame(
+    handle: ProgShipHandle,
+    buffer: *mut u8,
+    capacity: usize,
+) -> usize {
+    if handle.is_null() {
+        return 0;
+    }
+    
+    let sim = unsafe { &*handle };
+    let Some(frame) = sim.frame() else {
+        return 0;
+    };
+    let bytes = frame.encode();
+    
+    if !buffer.is_null() && capacity >= bytes.len() {
+        unsafe {
+            std::ptr::copy_nonoverlapping(bytes.as_ptr(), buffer, bytes.len());
+        }
+    }
+    
+    bytes.len()
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -0,0 +1,637 @@
+//! Compact binary world frames for renderers outside SpacetimeDB.
+//!
+//! A [`Frame`] is the ship at one moment as a renderer sees it: rooms,
+//! people and events. The server's `export_frame` reducer writes frames to
+//! its `frame_export` table, either whole (a key frame) or as a
+//! [`FrameDelta`] carrying only the people and events that changed since
+//! the frame before, so a web viewer or a stream overlay can follow the
+//! ship for a few bytes a tick. The crate has no dependencies: a renderer
+//! needs nothing else to read the bytes.
+//!
+//! # Wire Format
+//!
+//! Little-endian throughout. Counts and IDs are LEB128 varints; decks are
+//! zigzag varints. Rooms only travel in key frames.
+//!
+//! ```text
+//! header  "PSFR", u8 version, u8 kind (0 = key frame, 1 = delta)
+//! key     f64 sim_time, [room], [person], [event]
+//! delta   f64 base_time, f64 sim_time, [person] changed, [id] people gone,
+//!         [event] changed, [id] events gone
+//! room    id, u8 type, deck, f32 x, f32 y, f32 width, f32 height
+//! person  id, room id, i16 x, i16 y (decimeters), u8 activity,
+//!         u8 health, u8 morale, u8 flags
+//! event   id, u8 type, room id, u8 state, u8 severity
+//! ```
+//!
+//! ```
+//! use progship_frame::{decode, quantize_position, Frame, FramePerson, Packet};
+//!
+//! let mut frame = Frame { sim_time: 1.0, ..Default::default() };
+//! frame.people.push(FramePerson {
+//!     id: 7,
+//!     room_id: 3,
+//!     x: quantize_position(12.0),
+//!     y: quantize_position(4.5),
+//!     activity_type: 0,
+//!     health: 255,
+//!     morale: 128,
+//!     flags: 0,
+//! });
+//! let Packet::Key(read) = decode(&frame.encode()).unwrap() else { panic!() };
+//! assert_eq!(read, frame);
+//!
+//! let mut next = frame.clone();
+//! next.sim_time = 1.1;
+//! next.people[0].room_id = 4;
+//! frame.apply(&frame.diff(&next)).unwrap();
+//! assert_eq!(frame, next);
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+/// Frame format version (increment when the wire format changes).
+pub const FRAME_VERSION: u8 = 1;
+
+/// Wire units per meter of a person's position.
+pub const POSITION_SCALE: f32 = 10.0;
+
+const MAGIC: &[u8; 4] = b"PSFR";
+const KIND_KEY: u8 = 0;
+const KIND_DELTA: u8 = 1;
+
+/// Bits of [`FramePerson::flags`].
+pub mod person_flags {
+    /// The person is a crew member.
+    pub const CREW: u8 = 1;
+    /// The person is player-controlled.
+    pub const PLAYER: u8 = 2;
+}
+
+/// The ship at one moment.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Frame {
+    /// Simulation time in hours.
+    pub sim_time: f64,
+    /// Every room.
+    pub rooms: Vec<FrameRoom>,
+    /// Every living person, in ID order.
+    pub people: Vec<FramePerson>,
+    /// Every event, in ID order.
+    pub events: Vec<FrameEvent>,
+}
+
+/// One room, placed by its center in world meters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrameRoom {
+    /// Server room ID.
+    pub id: u32,
+    /// Room type (see `room_types`).
+    pub room_type: u8,
+    /// Deck number (0 = top deck).
+    pub deck: i32,
+    /// Center X in meters.
+    pub x: f32,
+    /// Center Y in meters.
+    pub y: f32,
+    /// Extent along X in meters.
+    pub width: f32,
+    /// Extent along Y in meters.
+    pub height: f32,
+}
+
+/// One person, at wire precision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FramePerson {
+    /// Server person ID.
+    pub id: u64,
+    /// Room the person is in.
+    pub room_id: u32,
+    /// World X in decimeters (see [`position_meters`]).
+    pub x: i16,
+    /// World Y in decimeters.
+    pub y: i16,
+    /// Current activity (see `activity_types`).
+    pub activity_type: u8,
+    /// Health, 0 dead – 255 healthy (see [`unit_value`]).
+    pub health: u8,
+    /// Morale, 0 despairing – 255 happy.
+    pub morale: u8,
+    /// [`person_flags`] bits.
+    pub flags: u8,
+}
+
+/// One ship event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameEvent {
+    /// Server event ID.
+    pub id: u64,
+    /// Event type (see `event_types`).
+    pub event_type: u8,
+    /// Room the event is in.
+    pub room_id: u32,
+    /// Event state (see `event_states`).
+    pub state: u8,
+    /// Severity, 0 minor – 255 critical.
+    pub severity: u8,
+}
+
+/// What changed between two frames.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FrameDelta {
+    /// Simulation time of the frame this delta applies to.
+    pub base_time: f64,
+    /// Simulation time of the frame it produces.
+    pub sim_time: f64,
+    /// People who appeared or changed.
+    pub people: Vec<FramePerson>,
+    /// IDs of people no longer in the frame.
+    pub people_gone: Vec<u64>,
+    /// Events that started or changed.
+    pub events: Vec<FrameEvent>,
+    /// IDs of events no longer in the frame.
+    pub events_gone: Vec<u64>,
+}
+
+/// A decoded frame export.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Packet {
+    /// A whole frame.
+    Key(Frame),
+    /// Changes since the previous frame.
+    Delta(FrameDelta),
+}
+
+/// A position in meters at wire precision, saturating far off the ship.
+pub fn quantize_position(meters: f32) -> i16 {
+    (meters * POSITION_SCALE)
+        .round()
+        .clamp(i16::MIN as f32, i16::MAX as f32) as i16
+}
+
+/// A wire position in meters.
+pub fn position_meters(position: i16) -> f32 {
+    position as f32 / POSITION_SCALE
+}
+
+/// A 0.0–1.0 value at wire precision.
+pub fn quantize_unit(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// A wire 0–255 value as 0.0–1.0.
+pub fn unit_value(value: u8) -> f32 {
+    value as f32 / 255.0
+}
+
+impl Frame {
+    /// Encode as a key frame.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut out = header(KIND_KEY);
+        out.extend_from_slice(&self.sim_time.to_le_bytes());
+        write_varint(&mut out, self.rooms.len() as u64);
+        for room in &self.rooms {
+            write_varint(&mut out, room.id as u64);
+            out.push(room.room_type);
+            write_varint(&mut out, zigzag(room.deck));
+            for v in [room.x, room.y, room.width, room.height] {
+                out.extend_from_slice(&v.to_le_bytes());
+            }
+        }
+        write_people(&mut out, &self.people);
+        write_events(&mut out, &self.events);
+        out
+    }
+
+    /// The delta that turns this frame into `next`. Rooms are not
+    /// compared; send a key frame when they change.
+    pub fn diff(&self, next: &Frame) -> FrameDelta {
+        FrameDelta {
+            base_time: self.sim_time,
+            sim_time: next.sim_time,
+            people: changed(&self.people, &next.people, |p| p.id),
+            people_gone: gone(&self.people, &next.people, |p| p.id),
+            events: changed(&self.events, &next.events, |e| e.id),
+            events_gone: gone(&self.events, &next.events, |e| e.id),
+        }
+    }
+
+    /// Apply `delta`, which must have been taken from a frame at this
+    /// frame's time.
+    pub fn apply(&mut self, delta: &FrameDelta) -> Result<(), String> {
+        if delta.base_time != self.sim_time {
+            return Err(format!(
+                "delta from t={} applied to frame at t={}",
+                delta.base_time, self.sim_time
+            ));
+        }
+        upsert(&mut self.people, &delta.people, &delta.people_gone, |p| {
+            p.id
+        });
+        upsert(&mut self.events, &delta.events, &delta.events_gone, |e| {
+            e.id
+        });
+        self.sim_time = delta.sim_time;
+        Ok(())
+    }
+}
+
+impl FrameDelta {
+    /// Encode as a delta.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut out = header(KIND_DELTA);
+        out.extend_from_slice(&self.base_time.to_le_bytes());
+        out.extend_from_slice(&self.sim_time.to_le_bytes());
+        write_people(&mut out, &self.people);
+        write_ids(&mut out, &self.people_gone);
+        write_events(&mut out, &self.events);
+        write_ids(&mut out, &self.events_gone);
+        out
+    }
+}
+
+/// Decode one frame export, rejecting other format versions.
+pub fn decode(bytes: &[u8]) -> Result<Packet, String> {
+    let mut r = Reader { bytes, pos: 0 };
+    if r.take(4)? != MAGIC {
+        return Err("not a ProgShip frame".to_string());
+    }
+    let version = r.u8()?;
+    if version != FRAME_VERSION {
+        return Err(format!(
+            "frame version mismatch: expected {}, found {}",
+            FRAME_VERSION, version
+        ));
+    }
+    let packet = match r.u8()? {
+        KIND_KEY => {
+            let sim_time = r.f64()?;
+            let rooms = r.list(|r| {
+                Ok(FrameRoom {
+                    id: r.varint_u32()?,
+                    room_type: r.u8()?,
+                    deck: unzigzag(r.varint()?)?,
+                    x: r.f32()?,
+                    y: r.f32()?,
+                    width: r.f32()?,
+                    height: r.f32()?,
+                })
+            })?;
+            Packet::Key(Frame {
+                sim_time,
+                rooms,
+                people: r.list(Reader::person)?,
+                events: r.list(Reader::event)?,
+            })
+        }
+        KIND_DELTA => Packet::Delta(FrameDelta {
+            base_time: r.f64()?,
+            sim_time: r.f64()?,
+            people: r.list(Reader::person)?,
+            people_gone: r.list(Reader::varint)?,
+            events: r.list(Reader::event)?,
+            events_gone: r.list(Reader::varint)?,
+        }),
+        kind => return Err(format!("unknown frame kind {}", kind)),
+    };
+    if r.pos != bytes.len() {
+        return Err(format!("{} bytes after the frame", bytes.len() - r.pos));
+    }
+    Ok(packet)
+}
+
+/// Rebuild the latest frame from a key frame followed by the deltas taken
+/// after it, oldest first.
+pub fn replay<'a>(exports: impl IntoIterator<Item = &'a [u8]>) -> Result<Frame, String> {
+    let mut exports = exports.into_iter();
+    let mut frame = match exports.next().map(decode).transpose()? {
+        Some(Packet::Key(frame)) => frame,
+        Some(Packet::Delta(_)) => return Err("replay must start at a key frame".to_string()),
+        None => return Err("nothing to replay".to_string()),
+    };
+    for bytes in exports {
+        match decode(bytes)? {
+            Packet::Key(key) => frame = key,
+            Packet::Delta(delta) => frame.apply(&delta)?,
+        }
+    }
+    Ok(frame)
+}
+
+fn header(kind: u8) -> Vec<u8> {
+    let mut out = MAGIC.to_vec();
+    out.push(FRAME_VERSION);
+    out.push(kind);
+    out
+}
+
+fn write_varint(out: &mut Vec<u8>, mut v: u64) {
+    while v >= 0x80 {
+        out.push(v as u8 | 0x80);
+        v >>= 7;
+    }
+    out.push(v as u8);
+}
+
+fn zigzag(v: i32) -> u64 {
+    ((v << 1) ^ (v >> 31)) as u32 as u64
+}
+
+fn unzigzag(v: u64) -> Result<i32, String> {
+    let v = u32::try_from(v).map_err(|_| "deck out of range".to_string())?;
+    Ok((v >> 1) as i32 ^ -((v & 1) as i32))
+}
+
+fn write_ids(out: &mut Vec<u8>, ids: &[u64]) {
+    write_varint(out, ids.len() as u64);
+    for &id in ids {
+        write_varint(out, id);
+    }
+}
+
+fn write_people(out: &mut Vec<u8>, people: &[FramePerson]) {
+    write_varint(out, people.len() as u64);
+    for p in people {
+        write_varint(out, p.id);
+        write_varint(out, p.room_id as u64);
+        out.extend_from_slice(&p.x.to_le_bytes());
+        out.extend_from_slice(&p.y.to_le_bytes());
+        out.extend_from_slice(&[p.activity_type, p.health, p.morale, p.flags]);
+    }
+}
+
+fn write_events(out: &mut Vec<u8>, events: &[FrameEvent]) {
+    write_varint(out, events.len() as u64);
+    for e in events {
+        write_varint(out, e.id);
+        out.push(e.event_type);
+        write_varint(out, e.room_id as u64);
+        out.extend_from_slice(&[e.state, e.severity]);
+    }
+}
+
+/// Items of `next` that are new or differ from `prev`.
+fn changed<T: Copy + PartialEq>(prev: &[T], next: &[T], id: impl Fn(&T) -> u64) -> Vec<T> {
+    let before: HashMap<u64, &T> = prev.iter().map(|t| (id(t), t)).collect();
+    next.iter()
+        .filter(|t| before.get(&id(t)) != Some(t))
+        .copied()
+        .collect()
+}
+
+/// IDs in `prev` missing from `next`.
+fn gone<T>(prev: &[T], next: &[T], id: impl Fn(&T) -> u64) -> Vec<u64> {
+    let after: HashSet<u64> = next.iter().map(&id).collect();
+    prev.iter().map(id).filter(|i| !after.contains(i)).collect()
+}
+
+fn upsert<T: Copy>(items: &mut Vec<T>, changed: &[T], gone: &[u64], id: impl Fn(&T) -> u64) {
+    let replaced: HashSet<u64> = changed
+        .iter()
+        .map(&id)
+        .chain(gone.iter().copied())
+        .collect();
+    items.retain(|t| !replaced.contains(&id(t)));
+    items.extend_from_slice(changed);
+    items.sort_by_key(|t| id(t));
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], String> {
+        let end = self
+            .pos
+            .checked_add(n)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or_else(|| "frame is truncated".to_string())?;
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        let mut a = [0; N];
+        a.copy_from_slice(self.take(N)?);
+        Ok(a)
+    }
+
+    fn u8(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn i16(&mut self) -> Result<i16, String> {
+        self.array().map(i16::from_le_bytes)
+    }
+
+    fn f32(&mut self) -> Result<f32, String> {
+        self.array().map(f32::from_le_bytes)
+    }
+
+    fn f64(&mut self) -> Result<f64, String> {
+        self.array().map(f64::from_le_bytes)
+    }
+
+    fn varint(&mut self) -> Result<u64, String> {
+        let mut v = 0u64;
+        for shift in (0..64).step_by(7) {
+            let b = self.u8()?;
+            v |= ((b & 0x7f) as u64) << shift;
+            if b & 0x80 == 0 {
+                return Ok(v);
+            }
+        }
+        Err("malformed varint".to_string())
+    }
+
+    fn varint_u32(&mut self) -> Result<u32, String> {
+        u32::try_from(self.varint()?).map_err(|_| "ID out of range".to_string())
+    }
+
+    /// A count-prefixed list; the count is checked against the bytes left
+    /// so a corrupt count can't reserve gigabytes.
+    fn list<T>(
+        &mut self,
+        mut item: impl FnMut(&mut Self) -> Result<T, String>,
+    ) -> Result<Vec<T>, String> {
+        let n = self.varint()?;
+        if n > (self.bytes.len() - self.pos) as u64 {
+            return Err("frame is truncated".to_string());
+        }
+        (0..n).map(|_| item(self)).collect()
+    }
+
+    fn person(&mut self) -> Result<FramePerson, String> {
+        Ok(FramePerson {
+            id: self.varint()?,
+            room_id: self.varint_u32()?,
+            x: self.i16()?,
+            y: self.i16()?,
+            activity_type: self.u8()?,
+            health: self.u8()?,
+            morale: self.u8()?,
+            flags: self.u8()?,
+        })
+    }
+
+    fn event(&mut self) -> Result<FrameEvent, String> {
+        Ok(FrameEvent {
+            id: self.varint()?,
+            event_type: self.u8()?,
+            room_id: self.varint_u32()?,
+            state: self.u8()?,
+            severity: self.u8()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn person(id: u64, room_id: u32, x: f32) -> FramePerson {
+        FramePerson {
+            id,
+            room_id,
+            x: quantize_position(x),
+            y: quantize_position(-3.5),
+            activity_type: 2,
+            health: quantize_unit(0.9),
+            morale: quantize_unit(0.5),
+            flags: person_flags::CREW,
+        }
+    }
+
+    fn sample() -> Frame {
+        Frame {
+            sim_time: 12.5,
+            rooms: vec![
+                FrameRoom {
+                    id: 0,
+                    room_type: 0,
+                    deck: 0,
+                    x: 10.0,
+                    y: 5.0,
+                    width: 8.0,
+                    height: 6.0,
+                },
+                FrameRoom {
+                    id: 300,
+                    room_type: 41,
+                    deck: -2,
+                    x: 140.5,
+                    y: 390.0,
+                    width: 3.0,
+                    height: 4.0,
+                },
+            ],
+            people: vec![
+                person(1, 0, 11.0),
+                person(2, 300, 141.25),
+                person(900, 0, 9.0),
+            ],
+            events: vec![FrameEvent {
+                id: 4,
+                event_type: 1,
+                room_id: 300,
+                state: 0,
+                severity: quantize_unit(0.7),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_key_frame_roundtrip() {
+        let frame = sample();
+        assert_eq!(decode(&frame.encode()), Ok(Packet::Key(frame.clone())));
+        assert_eq!(
+            decode(&Frame::default().encode()),
+            Ok(Packet::Key(Frame::default()))
+        );
+        // A person costs a dozen bytes or so
+        let mut crowd = frame.clone();
+        crowd.people = (0..1000).map(|i| person(i, 300, i as f32 * 0.1)).collect();
+        let per_person = (crowd.encode().len() - frame.encode().len()) / 997;
+        assert!(per_person <= 14, "{} bytes a person", per_person);
+    }
+
+    #[test]
+    fn test_quantization() {
+        assert_eq!(position_meters(quantize_position(141.25)), 141.3);
+        assert_eq!(quantize_position(1e9), i16::MAX);
+        assert_eq!(quantize_position(-1e9), i16::MIN);
+        assert_eq!(quantize_unit(1.5), 255);
+        assert_eq!(quantize_unit(f32::NAN), 0);
+        assert_eq!(unit_value(quantize_unit(0.5)), 128.0 / 255.0);
+    }
+
+    #[test]
+    fn test_delta_roundtrip_and_apply() {
+        let before = sample();
+        let mut after = before.clone();
+        after.sim_time = 12.6;
+        after.people[1].room_id = 0;
+        after.people.remove(0);
+        after.people.push(person(901, 0, 2.0));
+        after.events.clear();
+
+        let delta = before.diff(&after);
+        assert_eq!(
+            delta.people.iter().map(|p| p.id).collect::<Vec<_>>(),
+            [2, 901]
+        );
+        assert_eq!(delta.people_gone, [1]);
+        assert_eq!(delta.events_gone, [4]);
+        assert_eq!(decode(&delta.encode()), Ok(Packet::Delta(delta.clone())));
+
+        let mut frame = before.clone();
+        frame.apply(&delta).unwrap();
+        assert_eq!(frame, after);
+        // A delta only applies to the frame it was taken from
+        let err = frame.apply(&delta).unwrap_err();
+        assert!(err.contains("t=12.5"));
+        // Nothing changed, nothing sent
+        let idle = after.diff(&after);
+        assert!(idle.people.is_empty() && idle.people_gone.is_empty());
+    }
+
+    #[test]
+    fn test_replay() {
+        let a = sample();
+        let mut b = a.clone();
+        b.sim_time = 13.0;
+        b.people[0].x += 5;
+        let mut c = b.clone();
+        c.sim_time = 13.5;
+        c.people.pop();
+        let exports = [a.encode(), a.diff(&b).encode(), b.diff(&c).encode()];
+        assert_eq!(replay(exports.iter().map(|e| e.as_slice())), Ok(c));
+        assert!(replay(exports[1..].iter().map(|e| e.as_slice())).is_err());
+        assert!(replay(std::iter::empty()).is_err());
+    }
+
+    #[test]
+    fn test_malformed_frames_rejected() {
+        let bytes = sample().encode();
+        assert!(decode(b"{\"version\":2}").unwrap_err().contains("not a"));
+        let mut newer = bytes.clone();
+        newer[4] = FRAME_VERSION + 1;
+        assert!(decode(&newer).unwrap_err().contains("version"));
+        let mut odd = bytes.clone();
+        odd[5] = 9;
+        assert!(decode(&odd).unwrap_err().contains("kind"));
+        for len in [3, 6, bytes.len() - 1] {
+            assert!(decode(&bytes[..len]).is_err(), "truncated to {}", len);
+        }
+        let mut long = bytes;
+        long.push(0);
+        assert!(decode(&long).unwrap_err().contains("after"));
+        let mut huge = header(KIND_DELTA);
+        huge.extend_from_slice(&[0; 16]);
+        huge.extend_from_slice(&[0xff, 0xff, 0xff, 0x0f]);
+        assert!(decode(&huge).is_err());
+    }
+}
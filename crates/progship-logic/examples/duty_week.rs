@@ -0,0 +1,144 @@
+//! A week of watches for a thirty-strong crew, ten to a shift.
+//!
+//! Each hour every crew member tires while awake and recovers asleep, goes
+//! to bed when [`duty::should_sleep`] says so and sleeps out the window,
+//! and stands watch while [`duty::should_be_on_duty`] and
+//! [`duty::is_fit_for_duty`] agree.
+//!
+//! ```text
+//! cargo run -p progship-logic --example duty_week
+//! ```
+
+use progship_logic::constants::shifts;
+use progship_logic::duty;
+
+/// Crew members per shift.
+pub const CREW_PER_SHIFT: usize = 10;
+
+/// Hours simulated.
+pub const HOURS: usize = 7 * 24;
+
+/// Fatigue gained per waking hour.
+const FATIGUE_AWAKE: f32 = 0.05;
+
+/// Fatigue shed per hour asleep.
+const FATIGUE_ASLEEP: f32 = 0.15;
+
+/// What the week looked like.
+#[derive(Debug, Clone)]
+pub struct DutyWeek {
+    /// Crew on watch in each hour.
+    pub on_watch: Vec<usize>,
+    /// Hours each crew member stood watch.
+    pub hours_worked: Vec<u32>,
+    /// Hours each crew member slept.
+    pub hours_slept: Vec<u32>,
+    /// Watch hours missed by crew who were unfit or asleep.
+    pub missed: u32,
+    /// Highest fatigue anyone reached.
+    pub peak_fatigue: f32,
+}
+
+impl DutyWeek {
+    /// Check the week held together: every hour covered by a full shift,
+    /// everyone worked their 56 hours and got their sleep.
+    pub fn check(&self) -> Result<(), String> {
+        if let Some(hour) = self.on_watch.iter().position(|&n| n != CREW_PER_SHIFT) {
+            return Err(format!(
+                "hour {hour} had {} on watch, want {CREW_PER_SHIFT}",
+                self.on_watch[hour]
+            ));
+        }
+        if self.missed > 0 {
+            return Err(format!("{} watch hours missed", self.missed));
+        }
+        if let Some(&short) = self.hours_worked.iter().find(|&&h| h != 56) {
+            return Err(format!("someone worked {short} hours, want 56"));
+        }
+        if let Some(&short) = self.hours_slept.iter().find(|&&h| h < 6 * 7) {
+            return Err(format!("someone slept only {short} hours in a week"));
+        }
+        if self.peak_fatigue >= 0.9 {
+            return Err(format!("fatigue reached {:.2}", self.peak_fatigue));
+        }
+        Ok(())
+    }
+}
+
+/// Run the week.
+pub fn run() -> DutyWeek {
+    let crew: Vec<u8> = [shifts::ALPHA, shifts::BETA, shifts::GAMMA]
+        .iter()
+        .flat_map(|&s| std::iter::repeat_n(s, CREW_PER_SHIFT))
+        .collect();
+    // The week opens at midnight with the off watches in bed
+    let mut asleep: Vec<bool> = crew
+        .iter()
+        .map(|&s| duty::is_crew_sleep_time(s, 0.0))
+        .collect();
+    let mut fatigue: Vec<f32> = asleep.iter().map(|&a| if a { 0.6 } else { 0.2 }).collect();
+    let mut week = DutyWeek {
+        on_watch: vec![0; HOURS],
+        hours_worked: vec![0; crew.len()],
+        hours_slept: vec![0; crew.len()],
+        missed: 0,
+        peak_fatigue: 0.0,
+    };
+
+    for hour in 0..HOURS {
+        let clock = (hour % 24) as f32;
+        for (i, &shift) in crew.iter().enumerate() {
+            let on_shift = duty::should_be_on_duty(shift, clock);
+            asleep[i] = if asleep[i] {
+                duty::is_crew_sleep_time(shift, clock) && !on_shift
+            } else {
+                !on_shift && duty::should_sleep(shift, clock, fatigue[i])
+            };
+            if asleep[i] {
+                fatigue[i] = (fatigue[i] - FATIGUE_ASLEEP).max(0.0);
+                week.hours_slept[i] += 1;
+                if on_shift {
+                    week.missed += 1;
+                }
+                continue;
+            }
+            fatigue[i] = (fatigue[i] + FATIGUE_AWAKE).min(1.0);
+            week.peak_fatigue = week.peak_fatigue.max(fatigue[i]);
+            if on_shift {
+                if duty::is_fit_for_duty(0.3, fatigue[i], 1.0) {
+                    week.on_watch[hour] += 1;
+                    week.hours_worked[i] += 1;
+                } else {
+                    week.missed += 1;
+                }
+            }
+        }
+    }
+    week
+}
+
+fn main() {
+    let week = run();
+    for (name, shift) in ["Alpha", "Beta", "Gamma"].iter().zip(0..) {
+        let members = shift * CREW_PER_SHIFT..(shift + 1) * CREW_PER_SHIFT;
+        let slept: u32 = week.hours_slept[members.clone()].iter().sum();
+        let worked: u32 = week.hours_worked[members].iter().sum();
+        println!(
+            "{name:>5} watch: {:>3} h worked, {:>4.1} h slept a night on average",
+            worked / CREW_PER_SHIFT as u32,
+            slept as f32 / CREW_PER_SHIFT as f32 / 7.0
+        );
+    }
+    println!("Peak fatigue {:.2}", week.peak_fatigue);
+    week.check().expect("duty week");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_a_week_of_watches_holds() {
+        assert_eq!(run().check(), Ok(()));
+    }
+}
@@ -0,0 +1,164 @@
+//! Two months of food stores for a thousand people through a crop blight.
+//!
+//! On day five a blight spoils most of the stores and cuts the harvest to
+//! under a third for a fortnight; then the replanted beds come in strong.
+//! Each hour the ship eats at the portion [`economy::compute_rationing`]
+//! sets and the crew's morale pays [`economy::rationing_morale_penalty`]
+//! for it.
+//!
+//! ```text
+//! cargo run -p progship-logic --example economy_shock
+//! ```
+
+use progship_logic::economy::{self, RationingLevel, ResourceValues};
+use progship_logic::food::{MEALS_PER_DAY, MEAL_RAW_FOOD};
+
+/// People aboard.
+pub const POPULATION: f32 = 1000.0;
+
+/// Days simulated.
+pub const DAYS: usize = 60;
+
+/// Raw food stores the ship can hold, in kg.
+const FOOD_CAP: f32 = 60_000.0;
+
+/// Day the blight strikes.
+const BLIGHT_DAY: usize = 5;
+
+/// Days the harvest stays blighted.
+const BLIGHT_DAYS: usize = 14;
+
+/// Share of the stores the blight spoils.
+const SPOILED: f32 = 0.7;
+
+/// Raw food eaten per hour at full rations.
+fn full_rations() -> f32 {
+    POPULATION * MEALS_PER_DAY * MEAL_RAW_FOOD / 24.0
+}
+
+/// What the two months looked like.
+#[derive(Debug, Clone)]
+pub struct EconomyShock {
+    /// Rationing level in each hour.
+    pub rationing: Vec<RationingLevel>,
+    /// Lowest the stores fell, as a share of capacity.
+    pub min_food: f32,
+    /// Hours food was reported short.
+    pub shortage_hours: u32,
+    /// Morale lost to rationing over the run.
+    pub morale_lost: f32,
+}
+
+impl EconomyShock {
+    /// Worst rationing the ship went to.
+    pub fn peak(&self) -> RationingLevel {
+        self.rationing
+            .iter()
+            .copied()
+            .max_by_key(|&l| economy::rationing_to_u8(l))
+            .unwrap_or(RationingLevel::Normal)
+    }
+
+    /// Check the ship rode the blight out: it rationed hard but never hit
+    /// emergency or empty stores, and was back on full rations by the end.
+    pub fn check(&self) -> Result<(), String> {
+        let peak = self.peak();
+        if peak != RationingLevel::Heavy {
+            return Err(format!("peak rationing {peak:?}, want Heavy"));
+        }
+        if self.min_food <= 0.0 {
+            return Err("food stores ran out".to_string());
+        }
+        if self.shortage_hours == 0 {
+            return Err("the blight never caused a shortage".to_string());
+        }
+        match self.rationing.last() {
+            Some(RationingLevel::Normal) => Ok(()),
+            last => Err(format!("still rationing at the end: {last:?}")),
+        }
+    }
+}
+
+/// Run the two months.
+pub fn run() -> EconomyShock {
+    let mut vals = ResourceValues {
+        food: FOOD_CAP,
+        food_cap: FOOD_CAP,
+        water: 1.0,
+        water_cap: 1.0,
+        oxygen: 1.0,
+        oxygen_cap: 1.0,
+        power: 1.0,
+        power_cap: 1.0,
+        fuel: 1.0,
+        fuel_cap: 1.0,
+        spare_parts: 1.0,
+        spare_parts_cap: 1.0,
+    };
+    let mut shock = EconomyShock {
+        rationing: Vec::with_capacity(DAYS * 24),
+        min_food: 1.0,
+        shortage_hours: 0,
+        morale_lost: 0.0,
+    };
+
+    for hour in 0..DAYS * 24 {
+        let day = hour / 24;
+        if hour == BLIGHT_DAY * 24 {
+            vals.food *= 1.0 - SPOILED;
+        }
+        let harvest = if day < BLIGHT_DAY {
+            1.0
+        } else if day < BLIGHT_DAY + BLIGHT_DAYS {
+            0.3
+        } else {
+            1.2
+        };
+        let levels = economy::compute_levels(&vals);
+        let level = economy::compute_rationing(&levels);
+        let eaten = full_rations() * economy::rationing_consumption_factor(level);
+        vals.food = (vals.food + full_rations() * harvest - eaten).clamp(0.0, FOOD_CAP);
+
+        if economy::detect_shortages(&levels)
+            .iter()
+            .any(|&(name, _)| name == "food")
+        {
+            shock.shortage_hours += 1;
+        }
+        shock.morale_lost += economy::rationing_morale_penalty(level);
+        shock.min_food = shock.min_food.min(levels.food);
+        shock.rationing.push(level);
+    }
+    shock
+}
+
+fn main() {
+    let shock = run();
+    for day in (0..DAYS).step_by(5) {
+        println!("Day {day:>2}: {:?}", shock.rationing[day * 24]);
+    }
+    let recovered = shock
+        .rationing
+        .iter()
+        .rposition(|&l| l != RationingLevel::Normal)
+        .map_or(0, |h| h / 24 + 1);
+    println!(
+        "Peak {:?}, stores fell to {:.0}%, {} h short, back to normal on day {}, {:.2} morale lost",
+        shock.peak(),
+        shock.min_food * 100.0,
+        shock.shortage_hours,
+        recovered,
+        shock.morale_lost
+    );
+    shock.check().expect("economy shock");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rationing_sees_the_blight_through() {
+        assert_eq!(run().check(), Ok(()));
+    }
+}
@@ -0,0 +1,185 @@
+//! A rumor of a reactor scare spreading through a hundred crew in ten
+//! days.
+//!
+//! Everyone has four neighbors along a ring of cabins and one friend
+//! across the ship. Now and then someone chats with one of them, and if
+//! they know the rumor they may pass it on, while
+//! [`conversation::should_share_gossip`] says it is fresh, close enough to
+//! its source and news to the listener.
+//!
+//! ```text
+//! cargo run -p progship-logic --example gossip_spread
+//! ```
+
+use progship_logic::conversation::{
+    self, ConversationMemory, ConversationRecord, GossipConfig, Tone, Topic,
+};
+
+/// Crew the rumor can reach.
+pub const AGENTS: usize = 100;
+
+/// Hours simulated.
+pub const HOURS: usize = 10 * 24;
+
+/// Chance someone strikes up a conversation in a given hour.
+const CHAT_CHANCE: f64 = 0.2;
+
+/// What the ten days looked like.
+#[derive(Debug, Clone)]
+pub struct GossipSpread {
+    /// Crew who knew the rumor at the end of each hour.
+    pub reach: Vec<usize>,
+    /// Hour each crew member heard it, if they did.
+    pub heard_at: Vec<Option<usize>>,
+    /// Hops the rumor had traveled when each crew member heard it.
+    pub hops: Vec<Option<u32>>,
+    /// The rules it spread by.
+    pub config: GossipConfig,
+}
+
+impl GossipSpread {
+    /// Check the rumor spread the way the rules allow: widely, never past
+    /// its hop limit and never after it went stale.
+    pub fn check(&self) -> Result<(), String> {
+        let reached = self.reach.last().copied().unwrap_or(0);
+        if reached < 20 {
+            return Err(format!("rumor reached only {reached} crew"));
+        }
+        if self.reach.windows(2).any(|w| w[1] < w[0]) {
+            return Err("someone forgot the rumor".to_string());
+        }
+        if let Some(hops) = self
+            .hops
+            .iter()
+            .flatten()
+            .find(|&&h| h > self.config.max_hops)
+        {
+            return Err(format!("rumor traveled {hops} hops"));
+        }
+        let stale = self.config.max_age_hours as usize + 1;
+        if let Some(hour) = self.heard_at.iter().flatten().find(|&&h| h > stale) {
+            return Err(format!("someone heard the stale rumor at hour {hour}"));
+        }
+        Ok(())
+    }
+}
+
+/// Everyone's ring neighbors and the friends they have across the ship.
+fn contacts() -> Vec<Vec<usize>> {
+    let mut contacts: Vec<Vec<usize>> = (0..AGENTS)
+        .map(|i| {
+            [1, 2, AGENTS - 1, AGENTS - 2]
+                .iter()
+                .map(|d| (i + d) % AGENTS)
+                .collect()
+        })
+        .collect();
+    for i in 0..AGENTS {
+        let friend = i * 37 % AGENTS;
+        if friend != i && !contacts[i].contains(&friend) {
+            contacts[i].push(friend);
+            contacts[friend].push(i);
+        }
+    }
+    contacts
+}
+
+/// Run the ten days.
+pub fn run() -> GossipSpread {
+    let config = GossipConfig::default();
+    let contacts = contacts();
+    let mut memories: Vec<ConversationMemory> = (0..AGENTS)
+        .map(|_| ConversationMemory::new(config.memory_capacity))
+        .collect();
+    let rumor = conversation::create_event_gossip(1, "Reactor scare on deck 3", 0.0);
+    memories[0].add(
+        0,
+        ConversationRecord {
+            topic: Topic::ShipEvent,
+            tone: Tone::Tense,
+            hour: 0.0,
+            initiated_by_self: true,
+            gossip: Some(rumor),
+        },
+    );
+    let mut spread = GossipSpread {
+        reach: Vec::with_capacity(HOURS),
+        heard_at: vec![None; AGENTS],
+        hops: vec![None; AGENTS],
+        config,
+    };
+    spread.heard_at[0] = Some(0);
+    spread.hops[0] = Some(0);
+
+    let mut seed: u64 = 0x5eed;
+    let mut roll = || {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 40) as f64 / (1u64 << 24) as f64
+    };
+    for hour in 1..=HOURS {
+        let now = hour as f64;
+        for speaker in 0..AGENTS {
+            if roll() >= CHAT_CHANCE {
+                continue;
+            }
+            let picks = &contacts[speaker];
+            let listener = picks[(roll() * picks.len() as f64) as usize % picks.len()];
+            let shares = roll() < spread.config.share_probability;
+            let Some(item) = memories[speaker].known_gossip().first().map(|&g| g.clone()) else {
+                continue;
+            };
+            let fresh = conversation::should_share_gossip(
+                &item,
+                &memories[listener],
+                now,
+                spread.config.max_age_hours,
+                spread.config.max_hops,
+            );
+            if !shares || !fresh {
+                continue;
+            }
+            let passed = conversation::propagate_gossip(&item);
+            spread.heard_at[listener] = Some(hour);
+            spread.hops[listener] = Some(passed.hops);
+            memories[listener].add(
+                speaker as u32,
+                ConversationRecord {
+                    topic: Topic::Gossip,
+                    tone: Tone::Excited,
+                    hour: now,
+                    initiated_by_self: false,
+                    gossip: Some(passed),
+                },
+            );
+        }
+        spread
+            .reach
+            .push(spread.heard_at.iter().filter(|h| h.is_some()).count());
+    }
+    spread
+}
+
+fn main() {
+    let spread = run();
+    for day in 1..=HOURS / 24 {
+        println!("Day {day:>2}: {:>3} know", spread.reach[day * 24 - 1]);
+    }
+    let mut by_hops = vec![0; spread.config.max_hops as usize + 1];
+    for &h in spread.hops.iter().flatten() {
+        by_hops[h as usize] += 1;
+    }
+    println!("Heard at each hop: {by_hops:?}");
+    spread.check().expect("gossip spread");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_the_rumor_spreads_within_the_rules() {
+        assert_eq!(run().check(), Ok(()));
+    }
+}
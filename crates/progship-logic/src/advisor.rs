@@ -0,0 +1,229 @@
+//! Ship's computer — advisories on what is going wrong aboard, most
+//! pressing first.
+//!
+//! The computer follows each store's level and how fast it is changing,
+//! smoothed over a day ([`smoothed_rate`]), and works out how long what is
+//! left lasts at that rate ([`days_left`]): a store that runs out before
+//! arrival is a caution, within a month a warning and within a week
+//! critical ([`stock_level`]). Fuel is burned only in the burns, so it is
+//! flagged instead once what is left cannot brake the ship
+//! ([`braking_share`]). Ship systems are flagged below
+//! [`SYSTEM_WARNING`] health or with subsystems offline ([`system_level`]),
+//! the grid while it sheds loads, and rooms packed past [`CROWDED`] times
+//! their capacity ([`crowding_level`]). Advisories are listed by level, the
+//! most urgent first within one ([`priority`]).
+
+use crate::constants::{advisory_levels, stocks};
+
+/// Hours over which a store's rate of change is smoothed.
+pub const TREND_HOURS: f64 = 24.0;
+
+/// Days left of a store below which running out is critical.
+pub const CRITICAL_DAYS: f64 = 7.0;
+
+/// Days left of a store below which running out is a warning.
+pub const WARNING_DAYS: f64 = 30.0;
+
+/// Health below which a ship system is a warning.
+pub const SYSTEM_WARNING: f32 = 0.5;
+
+/// Health below which a ship system is critical.
+pub const SYSTEM_CRITICAL: f32 = 0.25;
+
+/// Occupants per place at which a room counts as crowded.
+pub const CROWDED: f32 = 1.5;
+
+/// Most crowded rooms advised on at once, the worst first.
+pub const MAX_CROWDED_ROOMS: usize = 3;
+
+/// A store's rate of change an hour after `hours` more at `observed`,
+/// smoothed from `rate`.
+pub fn smoothed_rate(rate: f64, observed: f64, hours: f64) -> f64 {
+    if !observed.is_finite() {
+        return rate;
+    }
+    rate + (observed - rate) * (hours / TREND_HOURS).clamp(0.0, 1.0)
+}
+
+/// Days `level` lasts changing by `rate_per_hour`; `None` while it is not
+/// running down.
+pub fn days_left(level: f64, rate_per_hour: f64) -> Option<f64> {
+    if rate_per_hour >= 0.0 || !rate_per_hour.is_finite() {
+        return None;
+    }
+    Some(level.max(0.0) / -rate_per_hour / 24.0)
+}
+
+/// Level (see `advisory_levels`) of a store with `days` left and
+/// `days_to_arrival` to go, if it is worth advising on.
+pub fn stock_level(days: f64, days_to_arrival: f64) -> Option<u8> {
+    if days < CRITICAL_DAYS {
+        Some(advisory_levels::CRITICAL)
+    } else if days < WARNING_DAYS {
+        Some(advisory_levels::WARNING)
+    } else if days < days_to_arrival {
+        Some(advisory_levels::CAUTION)
+    } else {
+        None
+    }
+}
+
+/// What the computer says of a store (see `stocks`) with `days` left.
+pub fn stock_message(stock: u8, days: f64) -> String {
+    if days < 1.0 {
+        format!(
+            "{} will run out in {:.0} hours at current consumption",
+            stocks::name(stock),
+            days * 24.0
+        )
+    } else {
+        format!(
+            "{} will run out in {:.0} days at current consumption",
+            stocks::name(stock),
+            days
+        )
+    }
+}
+
+/// Share of the delta-v braking from `velocity_c` needs that the fuel left
+/// buys, if short of it.
+pub fn braking_share(delta_v_c: f64, velocity_c: f64) -> Option<f32> {
+    if velocity_c <= 0.0 || delta_v_c >= velocity_c {
+        return None;
+    }
+    Some((delta_v_c.max(0.0) / velocity_c) as f32)
+}
+
+/// What the computer says of fuel with `share` of what braking needs.
+pub fn fuel_message(share: f32) -> String {
+    format!("Fuel left covers {:.0}% of the braking burn", share * 100.0)
+}
+
+/// Level of a ship system in `health` with `offline` subsystems, if it is
+/// worth advising on.
+pub fn system_level(health: f32, offline: u32) -> Option<u8> {
+    if health < SYSTEM_CRITICAL {
+        Some(advisory_levels::CRITICAL)
+    } else if health < SYSTEM_WARNING || offline > 0 {
+        Some(advisory_levels::WARNING)
+    } else {
+        None
+    }
+}
+
+/// What the computer says of the ship system `name`.
+pub fn system_message(name: &str, health: f32, offline: u32) -> String {
+    let mut message = format!("{} at {:.0}%", name, health * 100.0);
+    if offline > 0 {
+        message += &format!(
+            ", {} subsystem{} offline",
+            offline,
+            if offline == 1 { "" } else { "s" }
+        );
+    }
+    message
+}
+
+/// What the computer says of a grid shedding loads.
+pub fn power_message(demand_kw: f32, generation_kw: f32) -> String {
+    format!(
+        "Shedding loads: {:.0} kW wanted, {:.0} kW generated",
+        demand_kw, generation_kw
+    )
+}
+
+/// Level of a room with `occupants` in `capacity` places, if crowded.
+pub fn crowding_level(occupants: u32, capacity: u32) -> Option<u8> {
+    if capacity == 0 {
+        return None;
+    }
+    let ratio = occupants as f32 / capacity as f32;
+    if ratio >= 2.0 * CROWDED {
+        Some(advisory_levels::WARNING)
+    } else if ratio >= CROWDED {
+        Some(advisory_levels::CAUTION)
+    } else {
+        None
+    }
+}
+
+/// What the computer says of the crowded room `name`.
+pub fn crowding_message(name: &str, occupants: u32, capacity: u32) -> String {
+    format!(
+        "{} crowded: {} people for {} places",
+        name, occupants, capacity
+    )
+}
+
+/// Place on the list of an advisory at `level` with `urgency` (0–1)
+/// within it.
+pub fn priority(level: u8, urgency: f32) -> f32 {
+    level as f32 + urgency.clamp(0.0, 0.99)
+}
+
+/// Urgency of running out in `days`: the sooner, the more.
+pub fn days_urgency(days: f64) -> f32 {
+    (CRITICAL_DAYS / (CRITICAL_DAYS + days.max(0.0))) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stock_trends() {
+        assert_eq!(smoothed_rate(0.0, -2.0, TREND_HOURS), -2.0);
+        assert_eq!(smoothed_rate(0.0, -2.0, TREND_HOURS * 0.5), -1.0);
+        assert_eq!(smoothed_rate(-1.0, f64::NAN, 1.0), -1.0);
+        assert_eq!(days_left(100.0, 0.0), None);
+        assert_eq!(days_left(100.0, 1.0), None);
+        assert_eq!(days_left(240.0, -1.0), Some(10.0));
+        assert_eq!(stock_level(3.0, 100.0), Some(advisory_levels::CRITICAL));
+        assert_eq!(stock_level(20.0, 100.0), Some(advisory_levels::WARNING));
+        assert_eq!(stock_level(60.0, 100.0), Some(advisory_levels::CAUTION));
+        assert_eq!(stock_level(200.0, 100.0), None);
+        assert_eq!(
+            stock_message(stocks::FOOD, 42.2),
+            "Food will run out in 42 days at current consumption"
+        );
+        assert!(stock_message(stocks::OXYGEN, 0.5).contains("12 hours"));
+        assert_eq!(braking_share(0.02, 0.01), None);
+        assert_eq!(braking_share(0.0, 0.0), None);
+        assert_eq!(braking_share(0.005, 0.01), Some(0.5));
+        assert_eq!(
+            fuel_message(0.5),
+            "Fuel left covers 50% of the braking burn"
+        );
+    }
+
+    #[test]
+    fn test_systems_power_and_crowding() {
+        assert_eq!(system_level(0.9, 0), None);
+        assert_eq!(system_level(0.9, 1), Some(advisory_levels::WARNING));
+        assert_eq!(system_level(0.4, 0), Some(advisory_levels::WARNING));
+        assert_eq!(system_level(0.1, 0), Some(advisory_levels::CRITICAL));
+        assert_eq!(
+            system_message("Life Support", 0.42, 2),
+            "Life Support at 42%, 2 subsystems offline"
+        );
+        assert!(power_message(120.0, 80.0).contains("120 kW"));
+        assert_eq!(crowding_level(10, 10), None);
+        assert_eq!(crowding_level(15, 10), Some(advisory_levels::CAUTION));
+        assert_eq!(crowding_level(30, 10), Some(advisory_levels::WARNING));
+        assert_eq!(crowding_level(5, 0), None);
+        assert_eq!(
+            crowding_message("Mess Hall", 40, 20),
+            "Mess Hall crowded: 40 people for 20 places"
+        );
+    }
+
+    #[test]
+    fn test_priority() {
+        assert!(priority(advisory_levels::CRITICAL, 0.0) > priority(advisory_levels::WARNING, 1.0));
+        assert!(days_urgency(1.0) > days_urgency(10.0));
+        assert!(
+            priority(advisory_levels::WARNING, days_urgency(8.0))
+                > priority(advisory_levels::WARNING, days_urgency(20.0))
+        );
+    }
+}
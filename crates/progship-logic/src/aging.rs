@@ -0,0 +1,122 @@
+//! Aging — birthdays, slower healing with age and death of old age.
+//!
+//! Everyone ages with sim time from their birth date (see
+//! [`crate::demographics::age_at`]): a birthday moves them on a year, and
+//! into the next life stage when the year takes them there, and is
+//! celebrated where they are. Healing slows past [`SLOW_HEALING_AGE`]
+//! ([`recovery_factor`]), and the chance of dying of old age grows
+//! exponentially with age ([`yearly_mortality`]), rolled once a day
+//! ([`dies_of_old_age`]), so a voyage of several decades sees its elders
+//! pass and its children grow up.
+
+use crate::demographics::age_at;
+use crate::numeric::roll;
+
+/// Age from which wounds heal slower.
+pub const SLOW_HEALING_AGE: u8 = 40;
+
+/// Share of the natural healing rate left at 90 and beyond.
+const OLDEST_RECOVERY: f32 = 0.4;
+
+/// Gompertz mortality: yearly chance of death at age 0 ...
+const MORTALITY_BASE: f32 = 0.0001;
+
+/// ... growing by this factor of e per year of age (doubling every ~8).
+const MORTALITY_GROWTH: f32 = 0.085;
+
+/// Highest yearly chance of death.
+const MAX_MORTALITY: f32 = 0.9;
+
+/// Whether someone born at `birth_date` and currently `age` has had a
+/// birthday by `sim_time`: their age has caught up a year or more.
+pub fn had_birthday(birth_date: f64, age: u8, sim_time: f64) -> bool {
+    age_at(birth_date, sim_time) > age
+}
+
+/// Share of their healing rate someone `age` years old keeps: all of it up
+/// to [`SLOW_HEALING_AGE`], falling to 40% at 90.
+pub fn recovery_factor(age: u8) -> f32 {
+    let past = age.saturating_sub(SLOW_HEALING_AGE) as f32 / (90 - SLOW_HEALING_AGE) as f32;
+    1.0 - past.min(1.0) * (1.0 - OLDEST_RECOVERY)
+}
+
+/// Health after a recovery step from `before` to `after`, with the gain
+/// (never a loss) scaled by [`recovery_factor`] for `age`.
+pub fn age_recovery(before: f32, after: f32, age: u8) -> f32 {
+    if after <= before {
+        return after;
+    }
+    before + (after - before) * recovery_factor(age)
+}
+
+/// Chance (0–[`MAX_MORTALITY`]) of someone `age` years old dying of old age
+/// within a year: about 0.1% at 30, 2% at 65 and a third by 100.
+pub fn yearly_mortality(age: u8) -> f32 {
+    (MORTALITY_BASE * (MORTALITY_GROWTH * age as f32).exp()).min(MAX_MORTALITY)
+}
+
+/// Whether `person_id`, `age` years old, dies of old age on sim day `day`.
+pub fn dies_of_old_age(person_id: u64, day: u64, age: u8) -> bool {
+    let daily = 1.0 - (1.0 - yearly_mortality(age)).powf(1.0 / 365.0);
+    roll(person_id, day as f64 * 24.0, 0xa6e) < daily
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::demographics::{birth_date, HOURS_PER_YEAR};
+
+    #[test]
+    fn test_had_birthday() {
+        let born = birth_date(30, 0.5);
+        assert!(!had_birthday(born, 30, 0.0));
+        assert!(!had_birthday(born, 30, HOURS_PER_YEAR * 0.49));
+        assert!(had_birthday(born, 30, HOURS_PER_YEAR * 0.51));
+        assert!(!had_birthday(born, 31, HOURS_PER_YEAR * 0.51));
+    }
+
+    #[test]
+    fn test_recovery_slows_with_age() {
+        assert_eq!(recovery_factor(20), 1.0);
+        assert_eq!(recovery_factor(SLOW_HEALING_AGE), 1.0);
+        assert!((recovery_factor(90) - OLDEST_RECOVERY).abs() < 1e-6);
+        assert_eq!(recovery_factor(120), recovery_factor(90));
+        assert!(recovery_factor(70) < recovery_factor(50));
+
+        assert!((age_recovery(0.5, 0.6, 90) - 0.54).abs() < 1e-6);
+        assert_eq!(age_recovery(0.5, 0.6, 20), 0.6);
+        // Losses pass through untouched
+        assert_eq!(age_recovery(0.5, 0.4, 90), 0.4);
+    }
+
+    #[test]
+    fn test_mortality_grows_with_age() {
+        assert!(yearly_mortality(30) < 0.002);
+        assert!((0.01..0.04).contains(&yearly_mortality(65)));
+        assert!((0.3..0.6).contains(&yearly_mortality(100)));
+        assert_eq!(yearly_mortality(255), MAX_MORTALITY);
+        assert!(
+            (1..120).all(|age| yearly_mortality(age) > yearly_mortality(age - 1)
+                || yearly_mortality(age) == MAX_MORTALITY)
+        );
+    }
+
+    #[test]
+    fn test_dies_of_old_age() {
+        // A year of daily rolls for 1000 people matches the yearly chance
+        let deaths = |age| {
+            (0..1000u64)
+                .filter(|&id| (0..365).any(|day| dies_of_old_age(id, day, age)))
+                .count()
+        };
+        assert!(deaths(20) < 5);
+        let elders = deaths(85);
+        let expected = yearly_mortality(85) * 1000.0;
+        assert!(
+            (elders as f32 - expected).abs() < expected * 0.25,
+            "{} of 1000 85-year-olds died, expected {}",
+            elders,
+            expected
+        );
+    }
+}
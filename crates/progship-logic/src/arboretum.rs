@@ -0,0 +1,166 @@
+//! Arboretum — the ship's parks as a small ecology.
+//!
+//! Each arboretum's plantings grow on water, light and CO2
+//! ([`growing_conditions`]): with the room dry or dark they wilt, in
+//! daylight with the air they need they thrive, and left untended past
+//! [`TENDING_INTERVAL_HOURS`] they slowly go to seed ([`plant_health`]).
+//! Gardeners from the science department keep them, filed as work orders
+//! on the board ([`tending_hours`]). Healthy plantings freshen their
+//! room's stale air back to normal ([`freshened`]), taking up CO2 and
+//! giving off O2 for as many people as [`breathers_offset`] makes out, and
+//! ease the discomfort and lift the spirits of those who visit
+//! ([`visit_effects`]).
+
+use crate::atmosphere::atmo_constants::{NORMAL_CO2, NORMAL_O2};
+
+/// Hours after a gardener's visit before plantings count as neglected.
+pub const TENDING_INTERVAL_HOURS: f64 = 48.0;
+
+/// Priority of tending an arboretum on the board.
+pub const TENDING_PRIORITY: f32 = 0.25;
+
+/// Floor area (m²) of plantings that freshens the air of one person.
+pub const M2_PER_BREATHER: f32 = 20.0;
+
+/// Growing conditions below which plantings wilt.
+pub const WILTING: f32 = 0.2;
+
+/// Growing conditions at or above which plantings thrive.
+pub const THRIVING: f32 = 0.5;
+
+/// Plant health a gardener's visit puts back.
+pub const TENDING_BOOST: f32 = 0.1;
+
+/// Plant health lost per hour wilting.
+const WILT_PER_HOUR: f32 = 0.02;
+
+/// Plant health lost per hour neglected.
+const NEGLECT_PER_HOUR: f32 = 0.004;
+
+/// Plant health regained per hour thriving in ideal conditions.
+const RECOVERY_PER_HOUR: f32 = 0.01;
+
+/// Comfort eased per hour in a thriving arboretum.
+const COMFORT_PER_HOUR: f32 = 0.03;
+
+/// Morale lifted per hour in a thriving arboretum.
+const MORALE_PER_HOUR: f32 = 0.008;
+
+/// How well plantings grow (0–1) with water or not, `light` (0–1) and
+/// `co2` (fraction of the air): CO2 thinner than normal air starves them.
+pub fn growing_conditions(watered: bool, light: f32, co2: f32) -> f32 {
+    if !watered {
+        return 0.0;
+    }
+    let co2_factor = (co2.max(0.0) / NORMAL_CO2).min(1.0);
+    (light.clamp(0.0, 1.0) * co2_factor).clamp(0.0, 1.0)
+}
+
+/// Plant health after `hours` in `conditions`, `neglected` or not.
+pub fn plant_health(health: f32, conditions: f32, neglected: bool, hours: f32) -> f32 {
+    let hours = hours.max(0.0);
+    let change = if conditions < WILTING {
+        -WILT_PER_HOUR
+    } else if neglected {
+        -NEGLECT_PER_HOUR
+    } else if conditions >= THRIVING {
+        RECOVERY_PER_HOUR * conditions
+    } else {
+        0.0
+    };
+    (health + change * hours).clamp(0.0, 1.0)
+}
+
+/// Whether plantings last tended at `tended_at` are neglected by
+/// `sim_time`.
+pub fn neglected(tended_at: f64, sim_time: f64) -> bool {
+    sim_time - tended_at > TENDING_INTERVAL_HOURS
+}
+
+/// Hours a gardener takes to tend `area` m² of plantings.
+pub fn tending_hours(area: f32) -> f32 {
+    (area.max(0.0) / 400.0).clamp(1.0, 4.0)
+}
+
+/// Plant health after a gardener's visit.
+pub fn tended(health: f32) -> f32 {
+    (health + TENDING_BOOST).min(1.0)
+}
+
+/// People whose breathing `area` m² of plantings in `health` offsets,
+/// growing in `conditions`.
+pub fn breathers_offset(health: f32, conditions: f32, area: f32) -> f32 {
+    area.max(0.0) / M2_PER_BREATHER * health.clamp(0.0, 1.0) * conditions.clamp(0.0, 1.0)
+}
+
+/// A room's (O2, CO2) after plantings give off `o2_given` and take up
+/// `co2_taken`, freshening stale air back to normal and no further.
+pub fn freshened(o2: f32, co2: f32, o2_given: f32, co2_taken: f32) -> (f32, f32) {
+    let o2 = if o2 < NORMAL_O2 {
+        (o2 + o2_given.max(0.0)).min(NORMAL_O2)
+    } else {
+        o2
+    };
+    let co2 = if co2 > NORMAL_CO2 {
+        (co2 - co2_taken.max(0.0)).max(NORMAL_CO2)
+    } else {
+        co2
+    };
+    (o2, co2)
+}
+
+/// (comfort, morale) of a visitor after `hours` among plantings in
+/// `health`.
+pub fn visit_effects(comfort: f32, morale: f32, health: f32, hours: f32) -> (f32, f32) {
+    let health = health.clamp(0.0, 1.0);
+    let hours = hours.max(0.0);
+    (
+        (comfort - COMFORT_PER_HOUR * health * hours).max(0.0),
+        (morale + MORALE_PER_HOUR * health * hours).min(1.0),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_growing_conditions() {
+        assert_eq!(growing_conditions(false, 1.0, NORMAL_CO2), 0.0);
+        assert_eq!(growing_conditions(true, 1.0, NORMAL_CO2), 1.0);
+        assert_eq!(growing_conditions(true, 0.0, NORMAL_CO2), 0.0);
+        assert_eq!(growing_conditions(true, 1.0, NORMAL_CO2 * 0.5), 0.5);
+        assert_eq!(growing_conditions(true, 1.0, 0.05), 1.0);
+        assert_eq!(growing_conditions(true, 0.25, NORMAL_CO2), 0.25);
+    }
+
+    #[test]
+    fn test_plant_health() {
+        assert!(plant_health(0.5, 0.0, false, 1.0) < 0.5);
+        assert!(plant_health(0.5, 1.0, false, 1.0) > 0.5);
+        assert!(plant_health(0.5, 1.0, true, 1.0) < 0.5);
+        assert!(plant_health(0.5, 1.0, true, 1.0) > plant_health(0.5, 0.0, true, 1.0));
+        // Lit for the night, plantings hold
+        assert_eq!(plant_health(0.5, 0.25, false, 1.0), 0.5);
+        assert_eq!(plant_health(0.01, 0.0, false, 10.0), 0.0);
+        assert!(!neglected(0.0, TENDING_INTERVAL_HOURS));
+        assert!(neglected(0.0, TENDING_INTERVAL_HOURS + 1.0));
+        assert_eq!(tended(0.95), 1.0);
+        assert_eq!(tending_hours(800.0), 2.0);
+        assert_eq!(tending_hours(10.0), 1.0);
+    }
+
+    #[test]
+    fn test_air_and_visitors() {
+        assert_eq!(breathers_offset(1.0, 1.0, 800.0), 40.0);
+        assert_eq!(breathers_offset(0.5, 1.0, 800.0), 20.0);
+        assert_eq!(breathers_offset(1.0, 0.0, 800.0), 0.0);
+        assert_eq!(freshened(0.125, 0.0625, 0.0625, 0.03125), (0.1875, 0.03125));
+        assert_eq!(freshened(0.20, 0.01, 1.0, 1.0), (NORMAL_O2, NORMAL_CO2));
+        assert_eq!(freshened(0.22, 0.0001, 1.0, 1.0), (0.22, 0.0001));
+        let (comfort, morale) = visit_effects(0.5, 0.5, 1.0, 1.0);
+        assert!(comfort < 0.5 && morale > 0.5);
+        assert_eq!(visit_effects(0.5, 0.5, 0.0, 1.0), (0.5, 0.5));
+        assert_eq!(visit_effects(0.0, 1.0, 1.0, 1.0), (0.0, 1.0));
+    }
+}
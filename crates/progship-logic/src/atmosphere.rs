@@ -1,13 +1,17 @@
 //! Per-room atmosphere simulation — O2, CO2, temperature, pressure.
 //!
 //! Each room tracks its own atmospheric conditions. Gas flows between
-//! connected rooms based on pressure differentials. Life support systems
-//! actively regulate atmosphere through vent connections.
+//! connected rooms based on pressure differentials, freely through open
+//! doors and more slowly along ducts ([`exchange_gas_through`]). Life
+//! support systems actively regulate atmosphere through vent connections,
+//! as hard as their efficiency allows ([`step_room_atmosphere`]).
 //!
-//! This replaces the simpler per-deck atmosphere model with room-level
-//! granularity, enabling scenarios like localized fires, sealed rooms
-//! during emergencies, and HVAC failure isolation.
+//! Breathing is reckoned against a standard cabin's air ([`breathers`]), so
+//! a crowd in a small room fouls and warms its air far faster than in a
+//! mess hall, and fires, breaches and sealed rooms play out where they
+//! happen.
 
+use crate::numeric::{clamp_finite, finite_or, time_step, unit};
 use serde::{Deserialize, Serialize};
 
 /// Atmospheric state for a single room.
@@ -44,6 +48,19 @@ impl Default for RoomAtmosphere {
 }
 
 /// Atmospheric danger levels for crew safety.
+impl RoomAtmosphere {
+    /// The same atmosphere with every reading finite and in bounds, any NaN
+    /// or infinity replaced by the normal value.
+    pub fn sanitized(mut self) -> Self {
+        use atmo_constants::*;
+        self.o2 = unit(self.o2, NORMAL_O2);
+        self.co2 = unit(self.co2, NORMAL_CO2);
+        self.temperature = clamp_finite(self.temperature, TEMP_FLOOR, TEMP_CEILING, LS_TARGET_TEMP);
+        self.pressure = clamp_finite(self.pressure, 0.0, MAX_PRESSURE, 1.0);
+        self
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AtmosphereHazard {
     /// All parameters within safe range.
@@ -90,6 +107,10 @@ pub mod atmo_constants {
     pub const O2_CONSUMPTION_PER_PERSON: f32 = 0.0008;
     /// CO2 produced per person per hour.
     pub const CO2_PRODUCTION_PER_PERSON: f32 = 0.0008;
+    /// Breathing of someone exercising, in people at rest.
+    pub const EXERCISE_BREATHING: f32 = 2.0;
+    /// Degrees per hour a person warms a standard cabin's air.
+    pub const BODY_HEAT_PER_PERSON: f32 = 0.25;
 
     /// O2 consumed by fire per hour (per unit of fire).
     pub const FIRE_O2_CONSUMPTION: f32 = 0.02;
@@ -109,11 +130,29 @@ pub mod atmo_constants {
     pub const LS_TEMP_REGULATION: f32 = 2.0;
     /// Target temperature for life support.
     pub const LS_TARGET_TEMP: f32 = 22.0;
+
+    /// Physical bounds a room's temperature is kept within (°C).
+    pub const TEMP_FLOOR: f32 = -270.0;
+    pub const TEMP_CEILING: f32 = 1500.0;
+    /// Highest pressure a room can hold (atm).
+    pub const MAX_PRESSURE: f32 = 3.0;
+
+    /// Life support repressurization rate (atm per hour toward 1.0).
+    pub const LS_REPRESSURIZATION: f32 = 0.05;
+    /// Floor area (m²) of the standard cabin the per-person rates are for.
+    pub const STANDARD_ROOM_M2: f32 = 12.0;
+    /// Relative humidity a person breathes out per hour in a standard cabin.
+    pub const HUMIDITY_PER_PERSON: f32 = 0.005;
+    /// Share per hour of the way life support brings humidity to setpoint.
+    pub const LS_HUMIDITY_REGULATION: f32 = 0.5;
+    /// Kilopascals in an atmosphere.
+    pub const KPA_PER_ATM: f32 = 101.325;
 }
 
 /// Assess the hazard level of a room's atmosphere.
 pub fn assess_hazard(atmo: &RoomAtmosphere) -> AtmosphereHazard {
     use atmo_constants::*;
+    let atmo = &atmo.sanitized();
 
     // Check each parameter and return the worst hazard level
     let mut worst = AtmosphereHazard::Safe;
@@ -173,13 +212,47 @@ fn worst_hazard(a: AtmosphereHazard, b: AtmosphereHazard) -> AtmosphereHazard {
 /// This handles breathing, fire effects, and life support regulation.
 /// Gas exchange between rooms is handled separately by `exchange_gas`.
 pub fn update_room_atmosphere(atmo: &mut RoomAtmosphere, occupants: u32, dt: f32) {
-    use atmo_constants::*;
+    step_room_atmosphere(
+        atmo,
+        occupants as f32,
+        1.0,
+        1.0,
+        atmo_constants::LS_TARGET_TEMP,
+        dt,
+    );
+}
 
-    // Breathing: people consume O2 and produce CO2
-    let breathing_o2 = O2_CONSUMPTION_PER_PERSON * occupants as f32 * dt;
-    let breathing_co2 = CO2_PRODUCTION_PER_PERSON * occupants as f32 * dt;
+/// People breathing in a standard cabin's worth of air, for `people` in a
+/// room of `floor_area` m².
+pub fn breathers(people: f32, floor_area: f32) -> f32 {
+    people.max(0.0) * atmo_constants::STANDARD_ROOM_M2 / floor_area.max(1.0)
+}
+
+/// Update a single room's atmosphere for one time step, with `breathers`
+/// (see [`breathers`]) breathing and warming it, life support at
+/// `life_support` efficiency (0–1) and climate control at `cooling`
+/// efficiency (0–1) warming or cooling it toward `target_temp`.
+pub fn step_room_atmosphere(
+    atmo: &mut RoomAtmosphere,
+    breathers: f32,
+    life_support: f32,
+    cooling: f32,
+    target_temp: f32,
+    dt: f32,
+) {
+    use atmo_constants::*;
+    *atmo = atmo.sanitized();
+    let dt = time_step(dt);
+    let breathers = finite_or(breathers, 0.0).max(0.0);
+    let efficiency = unit(life_support, 0.0);
+    let cooling = unit(cooling, 0.0);
+
+    // Breathing: people consume O2, produce CO2 and give off heat
+    let breathing_o2 = O2_CONSUMPTION_PER_PERSON * breathers * dt;
+    let breathing_co2 = CO2_PRODUCTION_PER_PERSON * breathers * dt;
     atmo.o2 = (atmo.o2 - breathing_o2).max(0.0);
     atmo.co2 = (atmo.co2 + breathing_co2).min(1.0);
+    atmo.temperature += BODY_HEAT_PER_PERSON * breathers * dt;
 
     // Fire effects
     if atmo.fire && atmo.o2 > 0.05 {
@@ -196,26 +269,49 @@ pub fn update_room_atmosphere(atmo: &mut RoomAtmosphere, occupants: u32, dt: f32
     if atmo.has_life_support && !atmo.sealed {
         // Produce O2 (up to normal)
         if atmo.o2 < NORMAL_O2 {
-            atmo.o2 = (atmo.o2 + LS_O2_PRODUCTION * dt).min(NORMAL_O2);
+            atmo.o2 = (atmo.o2 + LS_O2_PRODUCTION * efficiency * dt).min(NORMAL_O2);
         }
         // Scrub CO2 (down to normal)
         if atmo.co2 > NORMAL_CO2 {
-            atmo.co2 = (atmo.co2 - LS_CO2_SCRUBBING * dt).max(NORMAL_CO2);
+            atmo.co2 = (atmo.co2 - LS_CO2_SCRUBBING * efficiency * dt).max(NORMAL_CO2);
         }
         // Temperature regulation toward target
-        let temp_diff = LS_TARGET_TEMP - atmo.temperature;
+        let target_temp = clamp_finite(target_temp, TEMP_FLOOR, TEMP_CEILING, LS_TARGET_TEMP);
+        let temp_diff = target_temp - atmo.temperature;
         if temp_diff.abs() > 0.1 {
-            let adjust = temp_diff.signum() * LS_TEMP_REGULATION * dt;
+            let adjust = temp_diff.signum() * LS_TEMP_REGULATION * cooling * dt;
             if temp_diff.abs() > adjust.abs() {
                 atmo.temperature += adjust;
             } else {
-                atmo.temperature = LS_TARGET_TEMP;
+                atmo.temperature = target_temp;
             }
         }
+        // Repressurize (up to normal)
+        if atmo.pressure < 1.0 {
+            atmo.pressure = (atmo.pressure + LS_REPRESSURIZATION * efficiency * dt).min(1.0);
+        }
     }
 
     // Pressure clamp (simplified — no vacuum simulation yet)
-    atmo.pressure = atmo.pressure.clamp(0.0, 3.0);
+    *atmo = atmo.sanitized();
+}
+
+/// Relative humidity after `dt` hours with `breathers` (see [`breathers`])
+/// breathing and life support at `life_support` efficiency (0–1) bringing
+/// it toward `setpoint`.
+pub fn humidity_after(
+    humidity: f32,
+    breathers: f32,
+    life_support: f32,
+    setpoint: f32,
+    dt: f32,
+) -> f32 {
+    use atmo_constants::*;
+    let dt = time_step(dt);
+    let humidity =
+        unit(humidity, setpoint) + HUMIDITY_PER_PERSON * finite_or(breathers, 0.0).max(0.0) * dt;
+    let pull = (LS_HUMIDITY_REGULATION * unit(life_support, 0.0) * dt).min(1.0);
+    unit(humidity + (unit(setpoint, 0.45) - humidity) * pull, 0.45)
 }
 
 /// Exchange gas between two connected rooms based on pressure differential.
@@ -223,11 +319,26 @@ pub fn update_room_atmosphere(atmo: &mut RoomAtmosphere, occupants: u32, dt: f32
 /// Only operates if neither room is sealed. Equalizes O2, CO2, temperature,
 /// and pressure toward the average.
 pub fn exchange_gas(a: &mut RoomAtmosphere, b: &mut RoomAtmosphere, dt: f32) {
+    exchange_gas_through(a, b, 1.0, dt);
+}
+
+/// Exchange gas between two rooms joined by an opening `conductance` times
+/// as free as an open door, e.g. a duct.
+pub fn exchange_gas_through(
+    a: &mut RoomAtmosphere,
+    b: &mut RoomAtmosphere,
+    conductance: f32,
+    dt: f32,
+) {
     if a.sealed || b.sealed {
         return;
     }
 
-    let rate = atmo_constants::GAS_EXCHANGE_RATE * dt;
+    *a = a.sanitized();
+    *b = b.sanitized();
+    // Past a rate of 0.5 the rooms would overshoot each other
+    let conductance = finite_or(conductance, 0.0).max(0.0);
+    let rate = (atmo_constants::GAS_EXCHANGE_RATE * conductance * time_step(dt)).min(0.5);
 
     // O2 exchange
     let o2_diff = b.o2 - a.o2;
@@ -252,11 +363,14 @@ pub fn exchange_gas(a: &mut RoomAtmosphere, b: &mut RoomAtmosphere, dt: f32) {
     let press_flow = press_diff * rate;
     a.pressure = (a.pressure + press_flow).max(0.0);
     b.pressure = (b.pressure - press_flow).max(0.0);
+    *a = a.sanitized();
+    *b = b.sanitized();
 }
 
 /// Health damage rate from bad atmosphere (damage per hour).
 pub fn atmosphere_health_damage(atmo: &RoomAtmosphere) -> f32 {
     use atmo_constants::*;
+    let atmo = &atmo.sanitized();
 
     let mut damage = 0.0;
 
@@ -278,7 +392,7 @@ pub fn atmosphere_health_damage(atmo: &RoomAtmosphere) -> f32 {
         damage += (TEMP_MIN_DANGER - atmo.temperature) * 0.02;
     }
 
-    damage
+    finite_or(damage, 0.0)
 }
 
 #[cfg(test)]
@@ -327,10 +441,92 @@ mod tests {
         assert_eq!(assess_hazard(&atmo), AtmosphereHazard::Warning);
     }
 
+    #[test]
+    fn test_breathers_scale_with_room() {
+        use atmo_constants::STANDARD_ROOM_M2;
+        assert_eq!(breathers(1.0, STANDARD_ROOM_M2), 1.0);
+        assert_eq!(breathers(10.0, STANDARD_ROOM_M2 * 10.0), 1.0);
+        assert_eq!(breathers(4.0, 0.0), 4.0 * STANDARD_ROOM_M2);
+        assert_eq!(breathers(-1.0, 12.0), 0.0);
+    }
+
+    #[test]
+    fn test_weak_life_support_falls_behind() {
+        let mut full = RoomAtmosphere {
+            o2: 0.15,
+            ..Default::default()
+        };
+        let mut weak = full;
+        let mut dead = full;
+        step_room_atmosphere(&mut full, 0.0, 1.0, 1.0, 22.0, 1.0);
+        step_room_atmosphere(&mut weak, 0.0, 0.5, 1.0, 22.0, 1.0);
+        step_room_atmosphere(&mut dead, 0.0, 0.0, 1.0, 22.0, 1.0);
+        assert!(full.o2 > weak.o2 && weak.o2 > dead.o2);
+        assert_eq!(dead.o2, 0.15);
+    }
+
+    #[test]
+    fn test_life_support_targets_and_repressurizes() {
+        let mut atmo = RoomAtmosphere {
+            pressure: 0.5,
+            ..Default::default()
+        };
+        step_room_atmosphere(&mut atmo, 0.0, 1.0, 1.0, 26.0, 1.0);
+        assert!(atmo.temperature > 22.0, "LS should warm toward the target");
+        assert!(atmo.pressure > 0.5, "LS should repressurize");
+        let mut sealed = RoomAtmosphere {
+            pressure: 0.5,
+            sealed: true,
+            ..Default::default()
+        };
+        step_room_atmosphere(&mut sealed, 0.0, 1.0, 1.0, 22.0, 1.0);
+        assert_eq!(sealed.pressure, 0.5);
+    }
+
+    #[test]
+    fn test_uncooled_rooms_heat_up() {
+        let mut cooled = RoomAtmosphere::default();
+        let mut uncooled = cooled;
+        step_room_atmosphere(&mut cooled, 4.0, 1.0, 1.0, 22.0, 1.0);
+        step_room_atmosphere(&mut uncooled, 4.0, 1.0, 0.0, 22.0, 1.0);
+        assert_eq!(cooled.temperature, 22.0);
+        assert_eq!(uncooled.temperature, 23.0);
+        // Scrubbed all the same
+        assert_eq!(cooled.co2, uncooled.co2);
+    }
+
+    #[test]
+    fn test_humidity() {
+        assert!(humidity_after(0.45, 10.0, 0.0, 0.45, 1.0) > 0.45);
+        assert_eq!(humidity_after(0.8, 0.0, 1.0, 0.4, 2.0), 0.4);
+        assert!(humidity_after(0.8, 0.0, 0.5, 0.4, 1.0) < 0.8);
+        assert_eq!(humidity_after(f32::NAN, 0.0, 0.0, 0.5, 1.0), 0.5);
+    }
+
+    #[test]
+    fn test_ducts_exchange_slower_than_doors() {
+        let stale = RoomAtmosphere {
+            o2: 0.15,
+            ..Default::default()
+        };
+        let fresh = RoomAtmosphere::default();
+        let (mut door_a, mut door_b) = (stale, fresh);
+        exchange_gas(&mut door_a, &mut door_b, 1.0);
+        let (mut duct_a, mut duct_b) = (stale, fresh);
+        exchange_gas_through(&mut duct_a, &mut duct_b, 0.25, 1.0);
+        assert!(door_a.o2 > duct_a.o2 && duct_a.o2 > stale.o2);
+        let (mut shut_a, mut shut_b) = (stale, fresh);
+        exchange_gas_through(&mut shut_a, &mut shut_b, 0.0, 1.0);
+        assert_eq!(shut_a.o2, stale.o2);
+    }
+
     #[test]
     fn test_breathing_consumes_o2() {
-        let mut atmo = RoomAtmosphere::default();
-        atmo.has_life_support = false; // Disable LS to see raw effect
+        // Disable LS to see raw effect
+        let mut atmo = RoomAtmosphere {
+            has_life_support: false,
+            ..Default::default()
+        };
         let o2_before = atmo.o2;
         update_room_atmosphere(&mut atmo, 10, 1.0); // 10 people, 1 hour
         assert!(atmo.o2 < o2_before, "breathing should reduce O2");
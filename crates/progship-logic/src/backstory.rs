@@ -0,0 +1,93 @@
+//! Backstories and quirks — small personal traits rolled at generation.
+//!
+//! Everyone gets one backstory tag and one or two quirks (see
+//! `person_traits`), stored as a bitmask. The utility AI adds each trait's
+//! nudge to the matching activity: ex-military crew lean into duty, insomniacs
+//! put off sleep, loners skip socializing, and so on. The nudges are small
+//! next to the need curves, so traits color behavior without overriding it.
+
+use crate::constants::{activity_types, person_traits};
+
+/// Score nudge per (trait, activity), added on top of the need-driven score.
+pub const TRAIT_MODIFIERS: [(u8, u8, f32); 10] = [
+    (person_traits::EX_MILITARY, activity_types::ON_DUTY, 1.5),
+    (person_traits::EX_MILITARY, activity_types::EXERCISING, 1.0),
+    (person_traits::COLONY_BORN, activity_types::SOCIALIZING, 1.0),
+    (person_traits::ACADEMIC, activity_types::EXERCISING, -1.0),
+    (person_traits::ACADEMIC, activity_types::RELAXING, 0.5),
+    (
+        person_traits::CLAUSTROPHOBIC,
+        activity_types::SOCIALIZING,
+        -1.0,
+    ),
+    (person_traits::INSOMNIAC, activity_types::SLEEPING, -2.0),
+    (person_traits::GREEN_THUMB, activity_types::RELAXING, 1.0),
+    (person_traits::GOURMAND, activity_types::EATING, 1.0),
+    (person_traits::LONER, activity_types::SOCIALIZING, -1.5),
+];
+
+/// Roll a trait bitmask: one backstory and one or two distinct quirks.
+/// `roll` yields uniform values in 0..1.
+pub fn pick_traits(mut roll: impl FnMut() -> f32) -> u16 {
+    let pick = |pool: &[u8], u: f32| pool[((u * pool.len() as f32) as usize).min(pool.len() - 1)];
+    let mut mask = 1 << pick(&person_traits::BACKSTORIES, roll());
+    let mut quirks = person_traits::QUIRKS.to_vec();
+    let count = if roll() < 0.5 { 1 } else { 2 };
+    for _ in 0..count {
+        let quirk = pick(&quirks, roll());
+        quirks.retain(|&q| q != quirk);
+        mask |= 1 << quirk;
+    }
+    mask
+}
+
+/// Total nudge the traits in `mask` give `activity_type`.
+pub fn trait_bonus(mask: u16, activity_type: u8) -> f32 {
+    TRAIT_MODIFIERS
+        .iter()
+        .filter(|&&(t, a, _)| a == activity_type && person_traits::has(mask, t))
+        .map(|&(_, _, bonus)| bonus)
+        .sum()
+}
+
+/// Trait names in `mask`, joined for display, e.g. `Ex-military, Insomniac`.
+pub fn describe(mask: u16) -> String {
+    person_traits::ids(mask)
+        .map(person_traits::name)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pick_traits_gives_a_backstory_and_quirks() {
+        let mut state = 7u32;
+        for _ in 0..200 {
+            let mask = pick_traits(|| {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                (state >> 8) as f32 / (1u32 << 24) as f32
+            });
+            let count = person_traits::ids(mask).count();
+            assert!((2..=3).contains(&count), "{mask:b}");
+            let backstories = person_traits::BACKSTORIES
+                .iter()
+                .filter(|&&t| person_traits::has(mask, t))
+                .count();
+            assert_eq!(backstories, 1);
+        }
+        // Rolls at the top of the range stay in bounds
+        assert_eq!(person_traits::ids(pick_traits(|| 1.0)).count(), 3);
+    }
+
+    #[test]
+    fn test_trait_bonus_sums_matching_traits() {
+        let mask = (1 << person_traits::LONER) | (1 << person_traits::CLAUSTROPHOBIC);
+        assert_eq!(trait_bonus(mask, activity_types::SOCIALIZING), -2.5);
+        assert_eq!(trait_bonus(mask, activity_types::EATING), 0.0);
+        assert_eq!(trait_bonus(0, activity_types::SLEEPING), 0.0);
+        assert_eq!(describe(mask), "Claustrophobic, Loner");
+    }
+}
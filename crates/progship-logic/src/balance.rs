@@ -0,0 +1,150 @@
+//! Balance tuning — how fast needs build up for different people.
+//!
+//! Activities set the base per-hour rates; [`BalanceConfig`] scales the
+//! rising ones by who the person is. Children get hungry sooner, infants and
+//! elders tire faster, extraverts get lonely and neurotic people
+//! uncomfortable faster, and unfit people tire faster. Recovery (negative
+//! rates while eating, sleeping or washing) isn't scaled.
+
+use serde::{Deserialize, Serialize};
+
+use crate::constants::life_stages;
+
+/// Per-hour need changes as (hunger, fatigue, social, comfort, hygiene);
+/// positive builds the need up, negative satisfies it.
+pub type NeedRates = (f32, f32, f32, f32, f32);
+
+/// Need build-up per hour when idle (no activity effects).
+pub const IDLE_RATES: NeedRates = (0.04, 0.03, 0.02, 0.02, 0.02);
+
+/// The traits of a person that change how fast their needs build up.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DecayTraits {
+    /// Life stage (see `life_stages`).
+    pub life_stage: u8,
+    pub extraversion: f32,
+    pub neuroticism: f32,
+    /// Physical fitness 0..=1 (the server uses health).
+    pub fitness: f32,
+}
+
+impl Default for DecayTraits {
+    fn default() -> Self {
+        Self {
+            life_stage: life_stages::ADULT,
+            extraversion: 0.5,
+            neuroticism: 0.5,
+            fitness: 1.0,
+        }
+    }
+}
+
+/// Multipliers on need build-up by age, personality and fitness.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BalanceConfig {
+    /// Hunger multiplier for infants and children.
+    pub child_hunger: f32,
+    /// Fatigue multiplier for infants.
+    pub infant_fatigue: f32,
+    /// Fatigue multiplier for elders.
+    pub elder_fatigue: f32,
+    /// Social multiplier spread from extraversion 0 to 1, centred on 1.0.
+    pub extraversion_social: f32,
+    /// Comfort multiplier spread from neuroticism 0 to 1, centred on 1.0.
+    pub neuroticism_comfort: f32,
+    /// Extra fatigue at zero fitness; fully fit people tire at the base rate.
+    pub unfit_fatigue: f32,
+}
+
+impl Default for BalanceConfig {
+    fn default() -> Self {
+        Self {
+            child_hunger: 1.3,
+            infant_fatigue: 1.4,
+            elder_fatigue: 1.3,
+            extraversion_social: 0.6,
+            neuroticism_comfort: 0.6,
+            unfit_fatigue: 0.5,
+        }
+    }
+}
+
+impl BalanceConfig {
+    /// Build-up multipliers for a person, as (hunger, fatigue, social,
+    /// comfort, hygiene). Never negative.
+    pub fn multipliers(&self, traits: &DecayTraits) -> NeedRates {
+        let hunger = match traits.life_stage {
+            life_stages::INFANT | life_stages::CHILD => self.child_hunger,
+            _ => 1.0,
+        };
+        let age_fatigue = match traits.life_stage {
+            life_stages::INFANT => self.infant_fatigue,
+            life_stages::ELDER => self.elder_fatigue,
+            _ => 1.0,
+        };
+        let fitness = traits.fitness.clamp(0.0, 1.0);
+        let fatigue = age_fatigue * (1.0 + (1.0 - fitness) * self.unfit_fatigue);
+        let social = 1.0 + (traits.extraversion.clamp(0.0, 1.0) - 0.5) * self.extraversion_social;
+        let comfort = 1.0 + (traits.neuroticism.clamp(0.0, 1.0) - 0.5) * self.neuroticism_comfort;
+        (
+            hunger.max(0.0),
+            fatigue.max(0.0),
+            social.max(0.0),
+            comfort.max(0.0),
+            1.0,
+        )
+    }
+
+    /// `base` activity rates scaled for a person; only build-up is scaled.
+    pub fn personal_rates(&self, base: NeedRates, traits: &DecayTraits) -> NeedRates {
+        let m = self.multipliers(traits);
+        let scale = |rate: f32, mult: f32| if rate > 0.0 { rate * mult } else { rate };
+        (
+            scale(base.0, m.0),
+            scale(base.1, m.1),
+            scale(base.2, m.2),
+            scale(base.3, m.3),
+            scale(base.4, m.4),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_age_changes_hunger_and_fatigue() {
+        let balance = BalanceConfig::default();
+        let rates = |life_stage| {
+            balance.personal_rates(
+                IDLE_RATES,
+                &DecayTraits {
+                    life_stage,
+                    ..Default::default()
+                },
+            )
+        };
+        let adult = rates(life_stages::ADULT);
+        assert_eq!(adult, IDLE_RATES);
+        assert!(rates(life_stages::CHILD).0 > adult.0);
+        assert!(rates(life_stages::ELDER).1 > adult.1);
+        assert!(rates(life_stages::INFANT).1 > adult.1);
+    }
+
+    #[test]
+    fn test_traits_and_fitness_scale_build_up_only() {
+        let balance = BalanceConfig::default();
+        let traits = DecayTraits {
+            extraversion: 1.0,
+            neuroticism: 0.0,
+            fitness: 0.0,
+            ..Default::default()
+        };
+        let m = balance.multipliers(&traits);
+        assert!(m.1 > 1.0 && m.2 > 1.0 && m.3 < 1.0);
+        // Sleeping recovers fatigue at the same pace for everyone
+        let sleeping = (0.02, -0.15, 0.01, -0.02, 0.01);
+        assert_eq!(balance.personal_rates(sleeping, &traits).1, -0.15);
+    }
+}
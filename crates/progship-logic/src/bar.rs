@@ -0,0 +1,136 @@
+//! Bar — drinks, intoxication, dependence and the night life.
+//!
+//! The bars open for the evening ([`is_open`]) and draw those looking for
+//! somewhere to unwind ([`nightlife_appeal`]). Off duty in an open bar,
+//! socializing or relaxing, people take a drink an hour, those dependent
+//! on it more ([`drinks_wanted`]), each of which eases their social and
+//! comfort needs, costs the ship [`DRINK_FOOD`] and [`DRINK_WATER`] and
+//! goes to their head ([`DRINK_INTOXICATION`]). From [`IMPAIRED`] they are
+//! unfit for duty until they sober up ([`SOBER_PER_HOUR`]), and from
+//! [`DISORDERLY`] the disagreeable may cause a scene ([`disorderly_chance`])
+//! security has to deal with. The neurotic grow dependent on drink
+//! ([`dependence_gain`]); from [`DEPENDENT`] they crave it and head for the
+//! bar whenever it is open, and dependence only fades without a drink.
+
+use crate::constants::room_types;
+
+/// Hour the bars open.
+pub const OPEN_HOUR: f32 = 18.0;
+
+/// Hour the bars close, after midnight.
+pub const CLOSE_HOUR: f32 = 2.0;
+
+/// Food a drink takes.
+pub const DRINK_FOOD: f32 = 0.1;
+
+/// Water a drink takes.
+pub const DRINK_WATER: f32 = 0.3;
+
+/// Social need a drink eases.
+pub const DRINK_SOCIAL: f32 = 0.08;
+
+/// Comfort need a drink eases.
+pub const DRINK_COMFORT: f32 = 0.05;
+
+/// Intoxication a drink adds.
+pub const DRINK_INTOXICATION: f32 = 0.2;
+
+/// Intoxication worn off an hour.
+pub const SOBER_PER_HOUR: f32 = 0.1;
+
+/// Intoxication from which someone is unfit for duty.
+pub const IMPAIRED: f32 = 0.3;
+
+/// Intoxication from which someone may cause a scene.
+pub const DISORDERLY: f32 = 0.7;
+
+/// Dependence from which someone craves a drink.
+pub const DEPENDENT: f32 = 0.5;
+
+/// Dependence faded an hour without a drink.
+pub const DEPENDENCE_FADE_PER_HOUR: f32 = 0.002;
+
+/// Salt of the [`roll`](crate::numeric::roll) for a drink too many.
+pub const DRINK_SALT: u64 = 0xd41c;
+
+/// Whether drinks are served in a room of `room_type`.
+pub fn is_bar(room_type: u8) -> bool {
+    room_type == room_types::BAR
+}
+
+/// Whether the bars are open at ship `hour`.
+pub fn is_open(hour: f32) -> bool {
+    !(CLOSE_HOUR..OPEN_HOUR).contains(&hour)
+}
+
+/// Extra appeal of a recreation room of `room_type` at ship `hour`: bars
+/// draw the crowds while open.
+pub fn nightlife_appeal(room_type: u8, hour: f32) -> f32 {
+    if is_bar(room_type) && is_open(hour) {
+        0.3
+    } else {
+        0.0
+    }
+}
+
+/// Drinks an hour someone with `dependence` takes.
+pub fn drinks_wanted(dependence: f32) -> u32 {
+    if dependence >= DEPENDENT {
+        2
+    } else {
+        1
+    }
+}
+
+/// Dependence a drink builds in someone of `neuroticism`: none for the
+/// steady, most for the most neurotic.
+pub fn dependence_gain(neuroticism: f32) -> f32 {
+    0.05 * ((neuroticism - 0.6) / 0.4).clamp(0.0, 1.0)
+}
+
+/// Whether someone at `intoxication` is unfit for duty.
+pub fn is_impaired(intoxication: f32) -> bool {
+    intoxication >= IMPAIRED
+}
+
+/// Chance this hour that someone at `intoxication` and `agreeableness`
+/// causes a scene: none below [`DISORDERLY`], more the drunker and less
+/// agreeable they are.
+pub fn disorderly_chance(intoxication: f32, agreeableness: f32) -> f32 {
+    if intoxication < DISORDERLY {
+        return 0.0;
+    }
+    0.2 * (1.0 - agreeableness.clamp(0.0, 1.0)) * intoxication.clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_opening_hours() {
+        assert!(is_open(20.0) && is_open(1.0));
+        assert!(!is_open(2.0) && !is_open(12.0));
+        assert!(is_bar(room_types::BAR) && !is_bar(room_types::CAFE));
+        assert!(nightlife_appeal(room_types::BAR, 22.0) > 0.0);
+        assert_eq!(nightlife_appeal(room_types::BAR, 10.0), 0.0);
+        assert_eq!(nightlife_appeal(room_types::THEATRE, 22.0), 0.0);
+    }
+
+    #[test]
+    fn test_dependence() {
+        assert_eq!(dependence_gain(0.5), 0.0);
+        assert!(dependence_gain(1.0) > dependence_gain(0.8));
+        assert_eq!(drinks_wanted(0.0), 1);
+        assert_eq!(drinks_wanted(DEPENDENT), 2);
+    }
+
+    #[test]
+    fn test_intoxication() {
+        assert!(!is_impaired(DRINK_INTOXICATION));
+        assert!(is_impaired(2.0 * DRINK_INTOXICATION));
+        assert_eq!(disorderly_chance(0.5, 0.0), 0.0);
+        assert!(disorderly_chance(0.9, 0.1) > disorderly_chance(0.9, 0.8));
+        assert!(disorderly_chance(1.0, 0.0) <= 1.0);
+    }
+}
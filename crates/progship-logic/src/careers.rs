@@ -0,0 +1,194 @@
+//! Careers — crew experience, certification in their department's skill
+//! and promotion into ranks that open up.
+//!
+//! Crew gain experience in their [`career_skill`] on duty and, three times
+//! as fast, in training in a classroom ([`practice`]). Reaching a level of
+//! it earns a [`certification`], kept for good. When the crew holds fewer
+//! of a rank than `population::rank_distribution` allots a crew its size,
+//! the vacancy goes to the most deserving of the rank below
+//! ([`best_candidate`]) who holds the [`required_certification`] and has
+//! served [`MIN_DAYS_IN_RANK`]. Commanders and the captain hold posts in the
+//! chain of command and are not promoted into.
+
+use crate::constants::{certifications, ranks};
+use crate::skills::{
+    apply_experience, department_specialty, SkillCategory, SkillProfile, SkillProgressionConfig,
+};
+
+/// Days crew serve in a rank before they can be promoted out of it.
+pub const MIN_DAYS_IN_RANK: f64 = 90.0;
+
+/// Ranks vacancies are filled in, highest first so that a promotion opens
+/// the rank below in time to be filled too.
+pub const PROMOTABLE: [u8; 5] = [
+    ranks::LIEUTENANT,
+    ranks::ENSIGN,
+    ranks::CHIEF,
+    ranks::PETTY,
+    ranks::SPECIALIST,
+];
+
+/// Morale a promotion gives the one promoted.
+pub const PROMOTION_MORALE: f32 = 0.15;
+
+/// Skill gained an hour on duty, before training and diminishing returns.
+const DUTY_GAIN_PER_HOUR: f32 = 0.0001;
+
+/// Service hours worth the most merit.
+const SEASONED_HOURS: f32 = 10_000.0;
+
+/// The skill a career in `department` is built on: its specialty, or social
+/// skill where it has none.
+pub fn career_skill(department: u8) -> SkillCategory {
+    department_specialty(department).unwrap_or(SkillCategory::Social)
+}
+
+/// Level of `skill` at `level` after `hours` of duty, or of training in a
+/// classroom `in_training`, following `skills::apply_experience` at a
+/// pace fit for a voyage of years.
+pub fn practice(level: f32, skill: SkillCategory, hours: f32, in_training: bool) -> f32 {
+    let mut levels = [0.0; 6];
+    if let Some(i) = SkillCategory::ALL.iter().position(|&c| c == skill) {
+        levels[i] = level;
+    }
+    let [eng, med, pilot, sci, soc, combat] = levels;
+    let mut profile = SkillProfile::new(eng, med, pilot, sci, soc, combat);
+    let config = SkillProgressionConfig {
+        base_gain_per_hour: DUTY_GAIN_PER_HOUR,
+        ..SkillProgressionConfig::default()
+    };
+    apply_experience(&mut profile, skill, hours.max(0.0), in_training, &config)
+}
+
+/// Certification (see `certifications`) a career skill at `level` earns.
+pub fn certification(level: f32) -> u8 {
+    match level {
+        l if l >= 0.8 => certifications::MASTER,
+        l if l >= 0.6 => certifications::ADVANCED,
+        l if l >= 0.4 => certifications::BASIC,
+        _ => certifications::NONE,
+    }
+}
+
+/// Certification it takes to hold `rank`.
+pub fn required_certification(rank: u8) -> u8 {
+    match rank {
+        ranks::CREWMAN => certifications::NONE,
+        ranks::SPECIALIST => certifications::BASIC,
+        ranks::PETTY..=ranks::ENSIGN => certifications::ADVANCED,
+        _ => certifications::MASTER,
+    }
+}
+
+/// A crew member who might be promoted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Candidate {
+    /// Person ID.
+    pub person_id: u64,
+    /// Rank held now.
+    pub rank: u8,
+    /// Certification held (see `certifications`).
+    pub certification: u8,
+    /// Days served in the rank.
+    pub days_in_rank: f64,
+    /// Level of their career skill.
+    pub skill: f32,
+    /// Hours served on duty and in training.
+    pub service_hours: f32,
+}
+
+/// How deserving of promotion a candidate is: their career skill, plus up
+/// to 0.5 for long service.
+pub fn merit(candidate: &Candidate) -> f32 {
+    candidate.skill + (candidate.service_hours / SEASONED_HOURS).clamp(0.0, 1.0) * 0.5
+}
+
+/// Of `candidates`, the one to promote into a vacancy at `rank`: the most
+/// deserving of the rank below who is certified for it and has served long
+/// enough, the first of them on a tie.
+pub fn best_candidate(candidates: &[Candidate], rank: u8) -> Option<u64> {
+    let below = rank.checked_sub(1)?;
+    candidates
+        .iter()
+        .filter(|c| c.rank == below)
+        .filter(|c| c.certification >= required_certification(rank))
+        .filter(|c| c.days_in_rank >= MIN_DAYS_IN_RANK)
+        .max_by(|a, b| {
+            merit(a)
+                .total_cmp(&merit(b))
+                .then(b.person_id.cmp(&a.person_id))
+        })
+        .map(|c| c.person_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::departments;
+
+    #[test]
+    fn test_career_skill() {
+        assert_eq!(
+            career_skill(departments::ENGINEERING),
+            SkillCategory::Engineering
+        );
+        assert_eq!(career_skill(departments::OPERATIONS), SkillCategory::Social);
+    }
+
+    #[test]
+    fn test_training_is_faster_than_duty() {
+        let duty = practice(0.5, SkillCategory::Medical, 100.0, false);
+        let training = practice(0.5, SkillCategory::Medical, 100.0, true);
+        assert!(duty > 0.5 && training > duty);
+        assert_eq!(practice(0.5, SkillCategory::Medical, 0.0, false), 0.5);
+        // Mastery comes slowly
+        assert!(practice(0.95, SkillCategory::Medical, 100.0, false) - 0.95 < duty - 0.5);
+    }
+
+    #[test]
+    fn test_certification() {
+        assert_eq!(certification(0.2), certifications::NONE);
+        assert_eq!(certification(0.45), certifications::BASIC);
+        assert_eq!(certification(0.7), certifications::ADVANCED);
+        assert_eq!(certification(0.85), certifications::MASTER);
+        assert_eq!(
+            required_certification(ranks::SPECIALIST),
+            certifications::BASIC
+        );
+        assert_eq!(
+            required_certification(ranks::CHIEF),
+            certifications::ADVANCED
+        );
+        assert_eq!(
+            required_certification(ranks::LIEUTENANT),
+            certifications::MASTER
+        );
+    }
+
+    #[test]
+    fn test_best_candidate() {
+        let candidate = |person_id, rank, certification, days_in_rank, skill| Candidate {
+            person_id,
+            rank,
+            certification,
+            days_in_rank,
+            skill,
+            service_hours: 1000.0,
+        };
+        let advanced = certifications::ADVANCED;
+        let pool = [
+            candidate(1, ranks::PETTY, advanced, 200.0, 0.65),
+            candidate(2, ranks::PETTY, advanced, 200.0, 0.75),
+            // Most skilled, but too new to the rank
+            candidate(3, ranks::PETTY, certifications::MASTER, 10.0, 0.9),
+            // Not certified for it
+            candidate(4, ranks::PETTY, certifications::BASIC, 400.0, 0.95),
+            // Already a chief
+            candidate(5, ranks::CHIEF, advanced, 400.0, 0.99),
+        ];
+        assert_eq!(best_candidate(&pool, ranks::CHIEF), Some(2));
+        assert_eq!(best_candidate(&pool, ranks::ENSIGN), Some(5));
+        assert_eq!(best_candidate(&pool, ranks::LIEUTENANT), None);
+        assert_eq!(best_candidate(&pool, ranks::CREWMAN), None);
+    }
+}
@@ -0,0 +1,263 @@
+//! Cargo manifest — the physical lots stowed in the ship's holds.
+//!
+//! [`cargo_manifest`] turns the voyage's spare parts and medical stockpiles
+//! (from the [`SupplyManifest`]) and the colony's own freight — supplies,
+//! machinery, the seed vault, everyone's belongings and the workshops' raw
+//! materials — into crate lots of at most [`LOT_MAX_TONS`]. [`stow`]
+//! assigns each lot to a hold, and [`draw`] takes stock out of a set of
+//! lots as repairs, fabrication and daily use consume it.
+
+use crate::constants::{cargo_categories as cc, room_types as rt};
+use crate::supplies::SupplyManifest;
+
+/// Heaviest single lot (t).
+pub const LOT_MAX_TONS: f32 = 25.0;
+/// Share of a hold's volume usable for stowage (the rest is aisles and
+/// clearance).
+pub const STOWAGE_FILL: f32 = 0.6;
+/// Spare parts used by one completed repair (t).
+pub const REPAIR_PARTS_TONS: f32 = 0.05;
+/// Medical supplies used per person per hour (t), as in the supply manifest.
+pub const MEDICAL_TONS_PER_PERSON_HOUR: f32 = 0.0001 / 24.0;
+/// Hours between routine draws on the stores.
+pub const CARGO_DRAW_INTERVAL_HOURS: f64 = 1.0;
+
+/// Colony supplies per person (t): habitat kits, tools, fabricator feedstock.
+const COLONY_TONS_PER_PERSON: f32 = 0.5;
+/// Colony machinery per person (t).
+const MACHINERY_TONS_PER_PERSON: f32 = 0.2;
+/// Personal effects per person (t).
+const PERSONAL_TONS_PER_PERSON: f32 = 0.1;
+/// Raw materials for fabricating spare parts per person (t).
+const RAW_MATERIALS_TONS_PER_PERSON: f32 = 0.02;
+/// Seed vault core collection (t), plus a share per person.
+const SEED_VAULT_BASE_TONS: f32 = 10.0;
+const SEED_VAULT_TONS_PER_PERSON: f32 = 0.01;
+
+/// One crate lot of cargo.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CargoLot {
+    /// Category (see `cargo_categories`).
+    pub category: u8,
+    pub mass_tons: f32,
+    pub volume_m3: f32,
+}
+
+/// Packing density of a cargo category (t/m³).
+pub fn density(category: u8) -> f32 {
+    match category {
+        cc::RAW_MATERIALS => 2.0,
+        cc::MACHINERY => 1.5,
+        cc::SPARE_PARTS => 1.2,
+        cc::SEED_VAULT => 0.6,
+        cc::MEDICAL_SUPPLIES => 0.4,
+        cc::PERSONAL_EFFECTS => 0.3,
+        _ => 0.5,
+    }
+}
+
+/// Room types a category is stowed in, most preferred first.
+pub fn holds(category: u8) -> &'static [u8] {
+    match category {
+        cc::SPARE_PARTS => &[rt::PARTS_STORAGE, rt::CARGO_BAY, rt::STORAGE],
+        cc::MEDICAL_SUPPLIES => &[rt::PHARMACY, rt::CARGO_BAY, rt::STORAGE],
+        _ => &[rt::CARGO_BAY, rt::STORAGE],
+    }
+}
+
+fn split(category: u8, tons: f32, lots: &mut Vec<CargoLot>) {
+    if tons <= 0.0 {
+        return;
+    }
+    let count = (tons / LOT_MAX_TONS).ceil().max(1.0);
+    let mass_tons = tons / count;
+    for _ in 0..count as u32 {
+        lots.push(CargoLot {
+            category,
+            mass_tons,
+            volume_m3: mass_tons / density(category),
+        });
+    }
+}
+
+/// Cargo lots for the voyage's supply manifest and a ship of `population`.
+pub fn cargo_manifest(supplies: &SupplyManifest, population: u32) -> Vec<CargoLot> {
+    let people = population as f32;
+    let mut lots = Vec::new();
+    split(
+        cc::COLONY_SUPPLIES,
+        people * COLONY_TONS_PER_PERSON,
+        &mut lots,
+    );
+    split(cc::MACHINERY, people * MACHINERY_TONS_PER_PERSON, &mut lots);
+    split(
+        cc::SEED_VAULT,
+        SEED_VAULT_BASE_TONS + people * SEED_VAULT_TONS_PER_PERSON,
+        &mut lots,
+    );
+    split(
+        cc::PERSONAL_EFFECTS,
+        people * PERSONAL_TONS_PER_PERSON,
+        &mut lots,
+    );
+    split(
+        cc::SPARE_PARTS,
+        supplies.spare_parts.stockpile_tons as f32,
+        &mut lots,
+    );
+    split(
+        cc::RAW_MATERIALS,
+        people * RAW_MATERIALS_TONS_PER_PERSON,
+        &mut lots,
+    );
+    split(
+        cc::MEDICAL_SUPPLIES,
+        supplies.medical.stockpile_tons as f32,
+        &mut lots,
+    );
+    lots
+}
+
+/// Hold for each lot, as an index into `rooms` (room type, volume in m³).
+///
+/// Each lot goes to the hold with the most free space among the most
+/// preferred room type that still has room for it; if every suitable hold is
+/// full it goes to the emptiest one anyway. `None` means no room of a
+/// suitable type exists.
+pub fn stow(lots: &[CargoLot], rooms: &[(u8, f32)]) -> Vec<Option<usize>> {
+    let mut free: Vec<f32> = rooms.iter().map(|&(_, v)| v * STOWAGE_FILL).collect();
+    lots.iter()
+        .map(|lot| {
+            let emptiest = |room_type: u8, free: &[f32]| {
+                (0..rooms.len())
+                    .filter(|&i| rooms[i].0 == room_type)
+                    .max_by(|&a, &b| free[a].total_cmp(&free[b]))
+            };
+            let preferred = holds(lot.category);
+            let chosen = preferred
+                .iter()
+                .filter_map(|&t| emptiest(t, &free))
+                .find(|&i| free[i] >= lot.volume_m3)
+                .or_else(|| {
+                    preferred
+                        .iter()
+                        .filter_map(|&t| emptiest(t, &free))
+                        .max_by(|&a, &b| free[a].total_cmp(&free[b]))
+                });
+            if let Some(i) = chosen {
+                free[i] -= lot.volume_m3;
+            }
+            chosen
+        })
+        .collect()
+}
+
+/// Take up to `wanted` tons out of `stock` (tons per lot), emptying lots in
+/// order. Returns the tons actually taken.
+pub fn draw(stock: &mut [f32], wanted: f32) -> f32 {
+    let mut remaining = wanted.max(0.0);
+    for tons in stock.iter_mut() {
+        if remaining <= 0.0 {
+            break;
+        }
+        let taken = tons.min(remaining);
+        *tons -= taken;
+        remaining -= taken;
+    }
+    wanted.max(0.0) - remaining
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{select_systems, SystemOverrides};
+    use crate::mission::MissionConfig;
+    use crate::population::compute_population;
+    use crate::supplies::compute_supply_manifest;
+
+    fn manifest(population: u32) -> Vec<CargoLot> {
+        let mission = MissionConfig::default();
+        let systems = select_systems(&mission, &SystemOverrides::default());
+        let supplies =
+            compute_supply_manifest(&mission, &systems, &compute_population(&mission, &systems));
+        cargo_manifest(&supplies, population)
+    }
+
+    fn total(lots: &[CargoLot], category: u8) -> f32 {
+        lots.iter()
+            .filter(|l| l.category == category)
+            .map(|l| l.mass_tons)
+            .sum()
+    }
+
+    #[test]
+    fn test_manifest_covers_categories() {
+        let lots = manifest(1000);
+        for category in [
+            cc::COLONY_SUPPLIES,
+            cc::MACHINERY,
+            cc::SEED_VAULT,
+            cc::PERSONAL_EFFECTS,
+            cc::SPARE_PARTS,
+            cc::MEDICAL_SUPPLIES,
+            cc::RAW_MATERIALS,
+        ] {
+            assert!(total(&lots, category) > 0.0, "missing {category}");
+        }
+        assert!(lots.iter().all(|l| l.mass_tons <= LOT_MAX_TONS + 0.001));
+        assert!((total(&lots, cc::COLONY_SUPPLIES) - 500.0).abs() < 0.1);
+        // Volume follows density
+        let machinery = lots.iter().find(|l| l.category == cc::MACHINERY).unwrap();
+        assert!((machinery.volume_m3 - machinery.mass_tons / 1.5).abs() < 0.001);
+        assert!(manifest(5000).len() > lots.len());
+    }
+
+    #[test]
+    fn test_stow_prefers_matching_holds() {
+        let lots = vec![
+            CargoLot {
+                category: cc::SPARE_PARTS,
+                mass_tons: 12.0,
+                volume_m3: 10.0,
+            },
+            CargoLot {
+                category: cc::COLONY_SUPPLIES,
+                mass_tons: 10.0,
+                volume_m3: 20.0,
+            },
+            CargoLot {
+                category: cc::COLONY_SUPPLIES,
+                mass_tons: 10.0,
+                volume_m3: 20.0,
+            },
+        ];
+        let rooms = [
+            (rt::CARGO_BAY, 50.0),
+            (rt::PARTS_STORAGE, 20.0),
+            (rt::CARGO_BAY, 40.0),
+        ];
+        let placed = stow(&lots, &rooms);
+        assert_eq!(placed[0], Some(1));
+        // Spread across bays by free space
+        assert_eq!(placed[1], Some(0));
+        assert_eq!(placed[2], Some(2));
+        // Overflow still lands in a suitable hold
+        let big = vec![CargoLot {
+            category: cc::SEED_VAULT,
+            mass_tons: 25.0,
+            volume_m3: 500.0,
+        }];
+        assert_eq!(stow(&big, &rooms), vec![Some(0)]);
+        assert_eq!(stow(&big, &[(rt::PARTS_STORAGE, 100.0)]), vec![None]);
+    }
+
+    #[test]
+    fn test_draw_empties_lots_in_order() {
+        let mut stock = [0.5, 2.0, 1.0];
+        assert_eq!(draw(&mut stock, 1.5), 1.5);
+        assert_eq!(stock, [0.0, 1.0, 1.0]);
+        assert_eq!(draw(&mut stock, 5.0), 2.0);
+        assert_eq!(stock, [0.0, 0.0, 0.0]);
+        assert_eq!(draw(&mut stock, 1.0), 0.0);
+    }
+}
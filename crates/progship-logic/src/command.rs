@@ -0,0 +1,215 @@
+//! Chain of command — captain, executive officer, department heads and
+//! watch officers, and who a problem escalates to.
+//!
+//! [`plan_command_chain`] lays out the posts crew generation fills first, in
+//! the same order as the top of
+//! [`crew_ranks`](crate::population::crew_ranks). [`escalation_order`] walks
+//! the chain for a department at a given hour: the department head if on
+//! watch, then the officer of the watch, then up through the XO to the
+//! captain.
+
+use serde::{Deserialize, Serialize};
+
+use crate::constants::{command_posts, departments, event_types, ranks, shifts};
+use crate::duty::should_be_on_duty;
+
+/// Departments with their own head, in generation order.
+pub const HEADED_DEPARTMENTS: [u8; 5] = [
+    departments::ENGINEERING,
+    departments::MEDICAL,
+    departments::SCIENCE,
+    departments::SECURITY,
+    departments::OPERATIONS,
+];
+
+/// Watches that each get a watch officer.
+pub const WATCHES: [u8; 3] = [shifts::ALPHA, shifts::BETA, shifts::GAMMA];
+
+/// One post in the chain of command.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommandPost {
+    /// Post (see `command_posts`).
+    pub post: u8,
+    /// Department the post belongs to.
+    pub department: u8,
+    /// Shift the holder works.
+    pub shift: u8,
+    /// Rank the post requires.
+    pub rank: u8,
+    /// Index of the superior post, `None` for the captain.
+    pub superior: Option<usize>,
+}
+
+/// Posts for a crew of `crew_count`, highest first. Small crews fill only
+/// the top of the chain.
+pub fn plan_command_chain(crew_count: u32) -> Vec<CommandPost> {
+    let post = |post, department, shift, rank, superior| CommandPost {
+        post,
+        department,
+        shift,
+        rank,
+        superior,
+    };
+    let mut posts = vec![
+        post(
+            command_posts::CAPTAIN,
+            departments::COMMAND,
+            shifts::ALPHA,
+            ranks::CAPTAIN,
+            None,
+        ),
+        post(
+            command_posts::EXECUTIVE_OFFICER,
+            departments::COMMAND,
+            shifts::ALPHA,
+            ranks::COMMANDER,
+            Some(0),
+        ),
+    ];
+    for department in HEADED_DEPARTMENTS {
+        posts.push(post(
+            command_posts::DEPARTMENT_HEAD,
+            department,
+            shifts::ALPHA,
+            ranks::COMMANDER,
+            Some(1),
+        ));
+    }
+    for shift in WATCHES {
+        posts.push(post(
+            command_posts::WATCH_OFFICER,
+            departments::COMMAND,
+            shift,
+            ranks::LIEUTENANT,
+            Some(1),
+        ));
+    }
+    posts.truncate(crew_count as usize);
+    posts
+}
+
+/// Department responsible for handling an event type.
+pub fn event_department(event_type: u8) -> u8 {
+    match event_type {
+        event_types::MEDICAL_EMERGENCY | event_types::DEATH | event_types::REVIVAL => {
+            departments::MEDICAL
+        }
+        event_types::ALTERCATION => departments::SECURITY,
+        event_types::DISCOVERY => departments::SCIENCE,
+        event_types::RESOURCE_SHORTAGE | event_types::DIAGNOSTIC => departments::OPERATIONS,
+        event_types::CELEBRATION | event_types::PROMOTION | event_types::VOYAGE_MILESTONE => {
+            departments::COMMAND
+        }
+        _ => departments::ENGINEERING,
+    }
+}
+
+/// Indices into `posts` of who to escalate a `department` problem to at
+/// ship `hour`, most directly responsible first.
+pub fn escalation_order(posts: &[CommandPost], department: u8, hour: f32) -> Vec<usize> {
+    let find = |pred: &dyn Fn(&CommandPost) -> bool| posts.iter().position(pred);
+    let head = find(&|p| p.post == command_posts::DEPARTMENT_HEAD && p.department == department);
+    let watch =
+        find(&|p| p.post == command_posts::WATCH_OFFICER && should_be_on_duty(p.shift, hour));
+    let head_on_watch = head.filter(|&i| should_be_on_duty(posts[i].shift, hour));
+
+    let mut order = Vec::new();
+    let candidates = [
+        head_on_watch,
+        watch,
+        head,
+        find(&|p| p.post == command_posts::EXECUTIVE_OFFICER),
+        find(&|p| p.post == command_posts::CAPTAIN),
+    ];
+    for i in candidates.into_iter().flatten() {
+        if !order.contains(&i) {
+            order.push(i);
+        }
+    }
+    order
+}
+
+/// Why a client playing `person_id` (`None` if they never joined a
+/// character) may not give orders, or `None` if they hold a command post.
+pub fn command_refusal(person_id: Option<u64>, holds_post: bool) -> Option<&'static str> {
+    match person_id {
+        None => Some("has no character to command with"),
+        Some(_) if !holds_post => Some("holds no command post"),
+        Some(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::population::crew_ranks;
+
+    #[test]
+    fn test_chain_shape() {
+        let posts = plan_command_chain(100);
+        assert_eq!(posts.len(), 2 + HEADED_DEPARTMENTS.len() + WATCHES.len());
+        assert_eq!(posts[0].post, command_posts::CAPTAIN);
+        assert_eq!(posts[0].superior, None);
+        // Everyone's superior ranks at least as high, ending at the captain
+        for p in &posts[1..] {
+            let boss = &posts[p.superior.unwrap()];
+            assert!(boss.rank >= p.rank);
+        }
+        assert_eq!(plan_command_chain(3).len(), 3);
+        assert!(plan_command_chain(0).is_empty());
+    }
+
+    #[test]
+    fn test_post_ranks_match_crew_ranks() {
+        for crew in [2, 7, 10, 500] {
+            let ranks_list = crew_ranks(crew);
+            for (i, post) in plan_command_chain(crew).iter().enumerate() {
+                assert_eq!(post.rank, ranks_list[i], "crew {} post {}", crew, i);
+            }
+        }
+    }
+
+    #[test]
+    fn test_only_command_posts_may_command() {
+        assert!(command_refusal(None, false).is_some());
+        assert!(command_refusal(Some(7), false).is_some());
+        assert_eq!(command_refusal(Some(7), true), None);
+    }
+
+    #[test]
+    fn test_escalation_follows_watch() {
+        let posts = plan_command_chain(100);
+        let head = posts
+            .iter()
+            .position(|p| p.department == departments::MEDICAL)
+            .unwrap();
+        // Day: the medical head is on watch and hears first
+        let day = escalation_order(&posts, departments::MEDICAL, 10.0);
+        assert_eq!(day[0], head);
+        assert_eq!(*day.last().unwrap(), 0);
+        // Night: the gamma watch officer comes first, the head is woken next
+        let night = escalation_order(&posts, departments::MEDICAL, 2.0);
+        assert_eq!(posts[night[0]].post, command_posts::WATCH_OFFICER);
+        assert_eq!(posts[night[0]].shift, shifts::GAMMA);
+        assert_eq!(night[1], head);
+        // Command problems skip straight to the watch, XO and captain
+        let command = escalation_order(&posts, departments::COMMAND, 10.0);
+        assert_eq!(command.len(), 3);
+    }
+
+    #[test]
+    fn test_event_departments() {
+        assert_eq!(
+            event_department(event_types::FIRE),
+            departments::ENGINEERING
+        );
+        assert_eq!(
+            event_department(event_types::MEDICAL_EMERGENCY),
+            departments::MEDICAL
+        );
+        assert_eq!(
+            event_department(event_types::ALTERCATION),
+            departments::SECURITY
+        );
+    }
+}
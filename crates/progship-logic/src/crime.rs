@@ -0,0 +1,138 @@
+//! Crime — theft, vandalism and assault aboard, and the brig.
+//!
+//! Someone whose morale has sunk below [`CRIME_MORALE`] may offend: once
+//! an hour they roll [`offense`], more likely the more neurotic and the
+//! less agreeable they are ([`offense_chance`]). The most volatile lash out
+//! at someone; others steal from them or break something. Security crew on
+//! duty respond, and a suspect they catch up with is held in the first of
+//! [`HOLDING_ROOMS`] the ship has for [`detention_hours`]. Incidents nobody
+//! has solved after [`COLD_CASE_HOURS`] go unsolved.
+
+use crate::constants::{incident_kinds, room_types};
+use crate::numeric::roll;
+
+/// Morale below which people may offend.
+pub const CRIME_MORALE: f32 = 0.35;
+
+/// Hours after which an unsolved incident is closed.
+pub const COLD_CASE_HOURS: f64 = 24.0;
+
+/// Rooms suspects are held in, best first.
+pub const HOLDING_ROOMS: [u8; 2] = [room_types::BRIG, room_types::SECURITY_OFFICE];
+
+/// Health an assault costs its victim.
+pub const ASSAULT_INJURY: f32 = 0.15;
+
+/// Morale an offense costs its victim.
+pub const VICTIM_MORALE: f32 = 0.1;
+
+/// Health vandalism takes off a piece of equipment.
+pub const VANDALISM_DAMAGE: f32 = 0.1;
+
+/// Closed incidents kept in the log.
+pub const INCIDENT_KEEP: usize = 200;
+
+/// Hourly chance of offending for the most volatile person in despair.
+const MAX_HOURLY_CHANCE: f32 = 0.004;
+
+/// Chance (0–1) that someone with `morale`, `neuroticism` and
+/// `agreeableness` offends this hour: none at or above [`CRIME_MORALE`],
+/// rising as morale falls and temper shortens.
+pub fn offense_chance(morale: f32, neuroticism: f32, agreeableness: f32) -> f32 {
+    if morale >= CRIME_MORALE {
+        return 0.0;
+    }
+    let despair = 1.0 - morale.max(0.0) / CRIME_MORALE;
+    let temper = (neuroticism.clamp(0.0, 1.0) + 1.0 - agreeableness.clamp(0.0, 1.0)) / 2.0;
+    MAX_HOURLY_CHANCE * despair * temper
+}
+
+/// The offense (see `incident_kinds`) `person_id` commits in sim hour
+/// `hour`, if any: assault for the share given by how neurotic and
+/// disagreeable they are, otherwise vandalism or theft.
+pub fn offense(
+    person_id: u64,
+    hour: u64,
+    morale: f32,
+    neuroticism: f32,
+    agreeableness: f32,
+) -> Option<u8> {
+    if roll(person_id, hour as f64, 0xc41e) >= offense_chance(morale, neuroticism, agreeableness) {
+        return None;
+    }
+    let kind = roll(person_id, hour as f64, 0xc41f);
+    let volatility = neuroticism.clamp(0.0, 1.0) * (1.0 - agreeableness.clamp(0.0, 1.0));
+    Some(if kind < volatility {
+        incident_kinds::ASSAULT
+    } else if kind < (1.0 + volatility) / 2.0 {
+        incident_kinds::VANDALISM
+    } else {
+        incident_kinds::THEFT
+    })
+}
+
+/// Hours a suspect is held for an offense of `kind`.
+pub fn detention_hours(kind: u8) -> f64 {
+    match kind {
+        incident_kinds::ASSAULT | incident_kinds::MUTINY => 72.0,
+        incident_kinds::VANDALISM => 36.0,
+        // Long enough to sleep it off
+        incident_kinds::DRUNKENNESS => 12.0,
+        _ => 24.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offense_chance() {
+        assert_eq!(offense_chance(CRIME_MORALE, 1.0, 0.0), 0.0);
+        assert_eq!(offense_chance(0.0, 1.0, 0.0), MAX_HOURLY_CHANCE);
+        assert!(offense_chance(0.1, 0.5, 0.5) > offense_chance(0.3, 0.5, 0.5));
+        assert!(offense_chance(0.1, 0.9, 0.2) > offense_chance(0.1, 0.2, 0.9));
+        assert_eq!(offense_chance(0.0, 0.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_volatile_people_assault() {
+        let kinds = |neuroticism, agreeableness| {
+            let mut counts = [0usize; 3];
+            for id in 0..2000u64 {
+                for hour in 0..500 {
+                    if let Some(kind) = offense(id, hour, 0.0, neuroticism, agreeableness) {
+                        counts[kind as usize] += 1;
+                    }
+                }
+            }
+            counts
+        };
+        let volatile = kinds(1.0, 0.0);
+        assert!(volatile[incident_kinds::ASSAULT as usize] > 0);
+        assert_eq!(volatile[incident_kinds::THEFT as usize], 0);
+
+        let calm = kinds(0.5, 0.5);
+        let total: usize = calm.iter().sum();
+        assert!(total > 0 && total < volatile.iter().sum());
+        assert!(calm[incident_kinds::ASSAULT as usize] < calm[incident_kinds::THEFT as usize]);
+        assert!(offense(1, 1, CRIME_MORALE, 1.0, 0.0).is_none());
+    }
+
+    #[test]
+    fn test_detention_fits_the_offense() {
+        assert!(
+            detention_hours(incident_kinds::ASSAULT) > detention_hours(incident_kinds::VANDALISM)
+        );
+        assert!(
+            detention_hours(incident_kinds::VANDALISM) > detention_hours(incident_kinds::THEFT)
+        );
+        assert_eq!(
+            detention_hours(incident_kinds::MUTINY),
+            detention_hours(incident_kinds::ASSAULT)
+        );
+        assert!(
+            detention_hours(incident_kinds::DRUNKENNESS) < detention_hours(incident_kinds::THEFT)
+        );
+    }
+}
@@ -0,0 +1,172 @@
+//! Cryosleep — passengers who make the voyage asleep in the cryo bays.
+//!
+//! Up to [`CRYO_SHARE`] of the passengers, those travelling without family,
+//! are put to sleep in the pods of the cryo bays at launch
+//! ([`sleeper_count`], enough bays for them from [`bays_needed`]). Sleepers
+//! have no needs and no social life, but their pods draw [`POD_KW`] each
+//! and their vitals need checking by the medical crew every
+//! [`CHECK_INTERVAL_HOURS`] ([`check_hours`]): past due, vitals slowly
+//! fall, and without power they fall fast ([`vitals_after`]). Sleepers are revived on
+//! schedule in the days before arrival ([`wake_time`]), or in an emergency
+//! once their vitals drop to [`EMERGENCY_VITALS`] ([`revival_due`]).
+//! Revival takes [`REVIVAL_HOURS`] and leaves them hungry, groggy and a
+//! little hurt, an emergency or unattended revival more so
+//! ([`revival_harm`]).
+
+use crate::constants::room_types;
+
+/// Share of the passengers, at most, who travel asleep.
+pub const CRYO_SHARE: f32 = 0.1;
+
+/// kW a cryo pod draws to keep its sleeper cold.
+pub const POD_KW: f32 = 0.5;
+
+/// Hours between the medical checks a sleeper needs.
+pub const CHECK_INTERVAL_HOURS: f64 = 7.0 * 24.0;
+
+/// Vitals lost an hour while a sleeper's check is overdue.
+pub const OVERDUE_DECLINE: f32 = 0.01;
+
+/// Vitals lost an hour in a pod without power.
+pub const UNPOWERED_DECLINE: f32 = 0.1;
+
+/// Vitals at which a sleeper is revived in an emergency.
+pub const EMERGENCY_VITALS: f32 = 0.3;
+
+/// Hours a revival takes.
+pub const REVIVAL_HOURS: f32 = 4.0;
+
+/// Hours before arrival the scheduled revivals begin, spread a day apart
+/// over a week.
+pub const WAKE_LEAD_HOURS: f64 = 14.0 * 24.0;
+
+/// Hours checking on each sleeper takes.
+pub const CHECK_HOURS_PER_SLEEPER: f32 = 0.05;
+
+/// Priority of a cryo check on the work order board: that of repairing a
+/// subsystem at half health.
+pub const CHECK_PRIORITY: f32 = 0.5;
+
+/// Hunger a sleeper wakes with.
+pub const WAKING_HUNGER: f32 = 0.7;
+
+/// Fatigue a sleeper wakes with.
+pub const WAKING_FATIGUE: f32 = 0.6;
+
+/// Cryo bays of `pods_per_bay` needed for the sleepers among
+/// `passengers`.
+pub fn bays_needed(passengers: u32, pods_per_bay: u32) -> u32 {
+    if pods_per_bay == 0 {
+        return 0;
+    }
+    wanted_sleepers(passengers).div_ceil(pods_per_bay)
+}
+
+/// Passengers put to sleep at launch: the wanted share of them, as far
+/// as `candidates` travelling alone and `pods` go.
+pub fn sleeper_count(passengers: u32, candidates: u32, pods: u32) -> u32 {
+    wanted_sleepers(passengers).min(candidates).min(pods)
+}
+
+fn wanted_sleepers(passengers: u32) -> u32 {
+    (passengers as f32 * CRYO_SHARE).ceil() as u32
+}
+
+/// kW the pods of a room of `room_type` and `capacity` draw.
+pub fn pod_draw(room_type: u8, capacity: u32) -> f32 {
+    if room_type == room_types::CRYO_BAY {
+        capacity as f32 * POD_KW
+    } else {
+        0.0
+    }
+}
+
+/// Sim time the `index`-th sleeper of a voyage of `voyage_hours` is
+/// revived.
+pub fn wake_time(voyage_hours: f64, index: u32) -> f64 {
+    (voyage_hours - WAKE_LEAD_HOURS + (index % 7) as f64 * 24.0).max(0.0)
+}
+
+/// Whether a sleeper last checked at `checked_at` is due a check at
+/// `sim_time`.
+pub fn check_due(checked_at: f64, sim_time: f64) -> bool {
+    sim_time - checked_at >= CHECK_INTERVAL_HOURS
+}
+
+/// Hours checking on `sleepers` in a bay takes, half an hour at least.
+pub fn check_hours(sleepers: u32) -> f32 {
+    (sleepers as f32 * CHECK_HOURS_PER_SLEEPER).max(0.5)
+}
+
+/// A sleeper's vitals after `hours` in a pod with or without power, with
+/// or without their check overdue.
+pub fn vitals_after(vitals: f32, powered: bool, overdue: bool, hours: f32) -> f32 {
+    let decline = if !powered {
+        UNPOWERED_DECLINE
+    } else if overdue {
+        OVERDUE_DECLINE
+    } else {
+        0.0
+    };
+    (vitals - decline * hours.max(0.0)).clamp(0.0, 1.0)
+}
+
+/// Whether a sleeper due to wake at `wake_at` with `vitals` is to be
+/// revived at `sim_time`: `Some(true)` in an emergency, `Some(false)` on
+/// schedule.
+pub fn revival_due(sim_time: f64, wake_at: f64, vitals: f32) -> Option<bool> {
+    if vitals <= EMERGENCY_VITALS {
+        Some(true)
+    } else if sim_time >= wake_at {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Health a revival costs, in an emergency or not and with medical crew
+/// on hand or not.
+pub fn revival_harm(emergency: bool, attended: bool) -> f32 {
+    let harm = if emergency { 0.2 } else { 0.05 };
+    if attended {
+        harm
+    } else {
+        harm * 2.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sleepers_and_bays() {
+        assert_eq!(bays_needed(1000, 30), 4);
+        assert_eq!(bays_needed(0, 30), 0);
+        assert_eq!(bays_needed(1000, 0), 0);
+        assert_eq!(sleeper_count(1000, 500, 120), 100);
+        assert_eq!(sleeper_count(1000, 40, 120), 40);
+        assert_eq!(sleeper_count(1000, 500, 60), 60);
+        assert_eq!(pod_draw(room_types::CRYO_BAY, 30), 15.0);
+        assert_eq!(pod_draw(room_types::MEDBAY, 30), 0.0);
+        assert_eq!(wake_time(10_000.0, 0), 10_000.0 - WAKE_LEAD_HOURS);
+        assert_eq!(wake_time(10_000.0, 8), wake_time(10_000.0, 1));
+        assert_eq!(wake_time(10.0, 0), 0.0);
+    }
+
+    #[test]
+    fn test_vitals_and_revival() {
+        assert!(!check_due(0.0, CHECK_INTERVAL_HOURS - 1.0));
+        assert!(check_due(0.0, CHECK_INTERVAL_HOURS));
+        assert_eq!(check_hours(1), 0.5);
+        assert_eq!(check_hours(30), 1.5);
+        assert_eq!(vitals_after(1.0, true, false, 10.0), 1.0);
+        assert!(vitals_after(1.0, true, true, 10.0) > vitals_after(1.0, false, false, 2.0));
+        assert_eq!(vitals_after(0.1, false, true, 10.0), 0.0);
+        assert_eq!(revival_due(0.0, 100.0, 1.0), None);
+        assert_eq!(revival_due(100.0, 100.0, 1.0), Some(false));
+        assert_eq!(revival_due(0.0, 100.0, EMERGENCY_VITALS), Some(true));
+        assert!(revival_harm(true, true) > revival_harm(false, true));
+        assert!(revival_harm(false, false) > revival_harm(false, true));
+    }
+}
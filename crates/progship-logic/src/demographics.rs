@@ -0,0 +1,176 @@
+//! Passenger ages — a configurable demographic curve and the life stages it
+//! produces.
+//!
+//! A [`DemographicCurve`] is a set of weighted age bands. Each household role
+//! draws its age from the part of the curve that fits it (children are under
+//! 18, grandparents at least 55), so the curve shapes the ship's age pyramid
+//! while households stay plausible. Life stages then follow from age (see
+//! `life_stages`) and steer activity choice: children go to school, elders
+//! skip the gym.
+
+use serde::{Deserialize, Serialize};
+
+use crate::constants::{family_roles as fr, life_stages};
+
+/// Simulation hours in a year.
+pub const HOURS_PER_YEAR: f64 = 24.0 * 365.0;
+
+/// Ages `min_age..=max_age`, weighted by `weight` on the curve.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AgeBand {
+    pub min_age: u8,
+    pub max_age: u8,
+    pub weight: f32,
+}
+
+/// Age distribution of passengers at departure.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DemographicCurve {
+    pub bands: Vec<AgeBand>,
+}
+
+impl Default for DemographicCurve {
+    /// A young colony: plenty of children and working-age adults, few elders.
+    fn default() -> Self {
+        let band = |min_age, max_age, weight| AgeBand {
+            min_age,
+            max_age,
+            weight,
+        };
+        Self {
+            bands: vec![
+                band(0, 4, 6.0),
+                band(5, 17, 16.0),
+                band(18, 34, 28.0),
+                band(35, 49, 22.0),
+                band(50, 64, 16.0),
+                band(65, 90, 12.0),
+            ],
+        }
+    }
+}
+
+/// Ages a household member in `role` can have (see `family_roles`).
+pub fn role_age_range(role: u8) -> (u8, u8) {
+    match role {
+        fr::CHILD => (0, life_stages::ADULT_AGE - 1),
+        fr::GRANDPARENT => (55, 95),
+        _ => (20, 85),
+    }
+}
+
+impl DemographicCurve {
+    /// Age for a member in `role`, picked from the role's slice of the curve
+    /// by `u` (0.0–1.0, e.g. from a seeded RNG). Falls back to the middle of
+    /// the role's range when no band overlaps it.
+    pub fn sample_age(&self, role: u8, u: f32) -> u8 {
+        let (lo, hi) = role_age_range(role);
+        // Each band's weight is cut down to the share of it the role can use
+        let slices: Vec<(u8, u8, f32)> = self
+            .bands
+            .iter()
+            .filter_map(|b| {
+                let (min, max) = (b.min_age.max(lo), b.max_age.min(hi));
+                let span = b.max_age.saturating_sub(b.min_age) as f32 + 1.0;
+                let share = (max as f32 - min as f32 + 1.0) / span;
+                (min <= max && b.weight > 0.0).then_some((min, max, b.weight * share))
+            })
+            .collect();
+        let total: f32 = slices.iter().map(|s| s.2).sum();
+        if total <= 0.0 {
+            return lo + (hi - lo) / 2;
+        }
+        let mut pick = u.clamp(0.0, 0.999_999) * total;
+        for &(min, max, weight) in &slices {
+            if pick < weight {
+                let span = (max - min) as f32 + 1.0;
+                return (min + (pick / weight * span) as u8).min(max);
+            }
+            pick -= weight;
+        }
+        slices.last().map(|s| s.1).unwrap_or(lo)
+    }
+}
+
+/// Sim time of birth for someone `age` years old at departure (sim time 0),
+/// `u` (0.0–1.0) of the way from their last birthday to their next.
+pub fn birth_date(age: u8, u: f32) -> f64 {
+    -(age as f64 + u.clamp(0.0, 0.999) as f64) * HOURS_PER_YEAR
+}
+
+/// Age in whole years at `sim_time` of someone born at `birth_date`.
+pub fn age_at(birth_date: f64, sim_time: f64) -> u8 {
+    ((sim_time - birth_date) / HOURS_PER_YEAR).clamp(0.0, 255.0) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ages_fit_roles() {
+        let curve = DemographicCurve::default();
+        for i in 0..100 {
+            let u = i as f32 / 100.0;
+            assert!(curve.sample_age(fr::CHILD, u) < life_stages::ADULT_AGE);
+            assert!(curve.sample_age(fr::GRANDPARENT, u) >= 55);
+            let head = curve.sample_age(fr::HEAD, u);
+            assert!((20..=85).contains(&head));
+        }
+    }
+
+    #[test]
+    fn test_curve_shapes_ages() {
+        let young = DemographicCurve {
+            bands: vec![AgeBand {
+                min_age: 20,
+                max_age: 29,
+                weight: 1.0,
+            }],
+        };
+        let old = DemographicCurve {
+            bands: vec![AgeBand {
+                min_age: 70,
+                max_age: 80,
+                weight: 1.0,
+            }],
+        };
+        for i in 0..10 {
+            let u = i as f32 / 10.0;
+            assert!((20..=29).contains(&young.sample_age(fr::HEAD, u)));
+            assert_eq!(
+                life_stages::from_age(old.sample_age(fr::PARTNER, u)),
+                life_stages::ELDER
+            );
+        }
+        // No band fits a child: middle of the child range
+        assert_eq!(young.sample_age(fr::CHILD, 0.5), 8);
+    }
+
+    #[test]
+    fn test_default_curve_has_every_stage() {
+        let curve = DemographicCurve::default();
+        let stages: Vec<u8> = (0..20)
+            .flat_map(|i| {
+                let u = i as f32 / 20.0;
+                [fr::CHILD, fr::HEAD].map(|r| life_stages::from_age(curve.sample_age(r, u)))
+            })
+            .collect();
+        for stage in [
+            life_stages::INFANT,
+            life_stages::CHILD,
+            life_stages::ADULT,
+            life_stages::ELDER,
+        ] {
+            assert!(stages.contains(&stage), "missing {}", stage);
+        }
+    }
+
+    #[test]
+    fn test_birth_date_roundtrip() {
+        let born = birth_date(34, 0.5);
+        assert!(born < 0.0);
+        assert_eq!(age_at(born, 0.0), 34);
+        assert_eq!(age_at(born, HOURS_PER_YEAR), 35);
+    }
+}
@@ -0,0 +1,144 @@
+//! Door states — who may open, close, lock or weld a door, and what
+//! events do to them.
+//!
+//! Only an [`door_states::OPEN`] door can be walked through, so pathfinding
+//! and movement route around every other state. Security locks doors,
+//! engineering cuts welded ones free, and officers may do either; a hull
+//! breach seals its room and a fire warps its doors shut, both until the
+//! event is over.
+
+use crate::constants::{departments, door_states, event_types};
+use crate::security::access_levels;
+
+/// Whether people can walk through a door in `state`.
+pub fn is_passable(state: u8) -> bool {
+    state == door_states::OPEN
+}
+
+/// Whether someone with door `clearance` in `department` (`None` for
+/// passengers) may move a door from `from` to `to`. Anyone may open or
+/// close a free door; locking and unlocking takes security, welding and
+/// cutting free takes engineering. Officers may do anything.
+pub fn may_set_state(from: u8, to: u8, clearance: u8, department: Option<u8>) -> bool {
+    if from == to || to > door_states::WELDED {
+        return false;
+    }
+    if clearance >= access_levels::OFFICER {
+        return true;
+    }
+    let needs = |state: u8| match state {
+        door_states::LOCKED => Some(departments::SECURITY),
+        door_states::WELDED => Some(departments::ENGINEERING),
+        _ => None,
+    };
+    [needs(from), needs(to)]
+        .into_iter()
+        .flatten()
+        .all(|d| department == Some(d))
+}
+
+/// Clearance and department a caller changes doors with: a joined
+/// player's own, the captain's for the module itself, or `None` for a
+/// client that never joined a character, who may not touch doors.
+pub fn caller_clearance(
+    player: Option<(u8, Option<u8>)>,
+    is_module: bool,
+) -> Option<(u8, Option<u8>)> {
+    match player {
+        Some(player) => Some(player),
+        None if is_module => Some((access_levels::CAPTAIN, None)),
+        None => None,
+    }
+}
+
+/// State a door toggles to: open and closed swap, a locked or welded door
+/// stays put.
+pub fn toggled(state: u8) -> u8 {
+    match state {
+        door_states::OPEN => door_states::CLOSED,
+        door_states::CLOSED => door_states::OPEN,
+        other => other,
+    }
+}
+
+/// State the doors of an event's room are forced into when the event
+/// escalates, if any: a breach is sealed and a fire welds doors shut.
+pub fn escalation_door_state(event_type: u8) -> Option<u8> {
+    match event_type {
+        event_types::HULL_BREACH => Some(door_states::LOCKED),
+        event_types::FIRE => Some(door_states::WELDED),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_open_doors_pass() {
+        assert!(is_passable(door_states::OPEN));
+        for state in [
+            door_states::CLOSED,
+            door_states::LOCKED,
+            door_states::WELDED,
+        ] {
+            assert!(!is_passable(state));
+        }
+    }
+
+    #[test]
+    fn test_who_may_change_a_door() {
+        let (open, closed, locked, welded) = (
+            door_states::OPEN,
+            door_states::CLOSED,
+            door_states::LOCKED,
+            door_states::WELDED,
+        );
+        let public = access_levels::PUBLIC;
+        let crew = access_levels::CREW_ONLY;
+        let security = Some(departments::SECURITY);
+        let engineering = Some(departments::ENGINEERING);
+        assert!(may_set_state(open, closed, public, None));
+        assert!(!may_set_state(closed, locked, crew, engineering));
+        assert!(may_set_state(closed, locked, crew, security));
+        assert!(may_set_state(locked, open, crew, security));
+        assert!(!may_set_state(welded, open, crew, security));
+        assert!(may_set_state(welded, closed, crew, engineering));
+        // Unwelding into a lock needs both departments
+        assert!(!may_set_state(welded, locked, crew, engineering));
+        assert!(may_set_state(welded, locked, access_levels::OFFICER, None));
+        assert!(!may_set_state(open, open, access_levels::CAPTAIN, None));
+        assert!(!may_set_state(open, 9, access_levels::CAPTAIN, None));
+    }
+
+    #[test]
+    fn test_unjoined_caller_may_not_lock() {
+        let security = (access_levels::CREW_ONLY, Some(departments::SECURITY));
+        assert_eq!(caller_clearance(Some(security), false), Some(security));
+        assert_eq!(caller_clearance(None, false), None);
+        let (clearance, department) = caller_clearance(None, true).unwrap();
+        assert!(may_set_state(
+            door_states::CLOSED,
+            door_states::LOCKED,
+            clearance,
+            department
+        ));
+    }
+
+    #[test]
+    fn test_toggle_and_events() {
+        assert_eq!(toggled(door_states::OPEN), door_states::CLOSED);
+        assert_eq!(toggled(door_states::CLOSED), door_states::OPEN);
+        assert_eq!(toggled(door_states::WELDED), door_states::WELDED);
+        assert_eq!(
+            escalation_door_state(event_types::HULL_BREACH),
+            Some(door_states::LOCKED)
+        );
+        assert_eq!(
+            escalation_door_state(event_types::FIRE),
+            Some(door_states::WELDED)
+        );
+        assert_eq!(escalation_door_state(event_types::MEDICAL_EMERGENCY), None);
+    }
+}
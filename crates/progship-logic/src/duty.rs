@@ -12,6 +12,18 @@ pub fn should_be_on_duty(shift: u8, hour: f32) -> bool {
     }
 }
 
+/// Check if a crew member works at `hour` when watches last `work_hours`
+/// from their shift's usual start; 8 hours is the regular watch.
+pub fn should_be_on_duty_for(shift: u8, hour: f32, work_hours: f32) -> bool {
+    let start = match shift {
+        shifts::ALPHA => 6.0,
+        shifts::BETA => 14.0,
+        shifts::GAMMA => 22.0,
+        _ => return false,
+    };
+    (hour - start).rem_euclid(24.0) < work_hours
+}
+
 /// Check if a crew member is fit for duty based on their needs.
 ///
 /// Exhausted, starving, or critically injured crew should skip duty.
@@ -93,6 +105,23 @@ mod tests {
         assert!(!should_be_on_duty(shifts::GAMMA, 21.9));
     }
 
+    #[test]
+    fn test_should_be_on_duty_for_work_hours() {
+        for shift in [shifts::ALPHA, shifts::BETA, shifts::GAMMA] {
+            for tenth in 0..240 {
+                let hour = tenth as f32 / 10.0;
+                assert_eq!(
+                    should_be_on_duty_for(shift, hour, 8.0),
+                    should_be_on_duty(shift, hour),
+                    "shift {shift} at {hour}"
+                );
+            }
+        }
+        assert!(should_be_on_duty_for(shifts::ALPHA, 15.0, 10.0));
+        assert!(should_be_on_duty_for(shifts::GAMMA, 7.5, 10.0));
+        assert!(!should_be_on_duty_for(shifts::BETA, 20.5, 6.0));
+    }
+
     #[test]
     fn test_is_fit_for_duty() {
         assert!(is_fit_for_duty(0.5, 0.5, 0.8));
@@ -1,4 +1,39 @@
 //! Pure economy logic — resource scarcity, rationing, production rates.
+//!
+//! Rationing smaller portions satisfy less ([`rationed_meal_rate`]), the
+//! mess halls drop lunch and then breakfast as it tightens
+//! ([`is_mess_open`]), and it wears on morale the longer it goes on
+//! ([`rationing_morale_penalty_over`]).
+//!
+//! Aboard, people pay in credits: crew earn an hourly wage on duty by rank
+//! ([`hourly_wage`]), passengers board with savings ([`starting_credits`]).
+//! Shops sell rations, keepsakes and toolkits ([`shop_purchase`]) and the
+//! bars drinks, at prices that climb as the stores behind them run low
+//! ([`price`]).
+
+use crate::constants::{departments, item_kinds};
+use crate::numeric::{finite_or, unit};
+
+/// Price of a drink at the bar with the stores full.
+pub const DRINK_PRICE: u32 = 4;
+
+/// Price of a ration at the shops with the stores full.
+pub const RATION_PRICE: u32 = 6;
+
+/// Price of a keepsake at the shops.
+pub const KEEPSAKE_PRICE: u32 = 25;
+
+/// Price of a toolkit at the shops with the stores full.
+pub const TOOLKIT_PRICE: u32 = 40;
+
+/// Food (kg) a ration sold takes from the stores.
+pub const RATION_FOOD: f32 = 0.7;
+
+/// Spare parts (kg) a toolkit sold takes from the stores.
+pub const TOOLKIT_PARTS: f32 = 5.0;
+
+/// Comfort need a purchase eases.
+pub const SHOPPING_COMFORT: f32 = 0.05;
 
 /// Resource levels as fractions of capacity (0.0 = empty, 1.0 = full).
 #[derive(Debug, Clone, Default)]
@@ -41,10 +76,11 @@ pub fn compute_levels(vals: &ResourceValues) -> ResourceLevels {
 }
 
 fn safe_ratio(current: f32, cap: f32) -> f32 {
+    let cap = finite_or(cap, 0.0);
     if cap <= 0.0 {
         0.0
     } else {
-        (current / cap).clamp(0.0, 1.0)
+        unit(current / cap, 0.0)
     }
 }
 
@@ -58,6 +94,17 @@ pub enum RationingLevel {
     Emergency = 3,
 }
 
+impl RationingLevel {
+    pub fn name(self) -> &'static str {
+        match self {
+            RationingLevel::Normal => "normal",
+            RationingLevel::Light => "light",
+            RationingLevel::Heavy => "heavy",
+            RationingLevel::Emergency => "emergency",
+        }
+    }
+}
+
 /// Determine rationing level from resource levels.
 /// Uses the worst of food, water, oxygen (the life-critical consumables).
 pub fn compute_rationing(levels: &ResourceLevels) -> RationingLevel {
@@ -104,6 +151,45 @@ pub fn rationing_hunger_factor(level: RationingLevel) -> f32 {
     }
 }
 
+/// Hours of rationing after which its morale penalty has doubled.
+pub const RATIONING_WEARINESS_HOURS: f32 = 168.0;
+
+/// Most the morale penalty grows with how long rationing has lasted.
+const MAX_RATIONING_WEARINESS: f32 = 3.0;
+
+/// Morale penalty per hour from rationing at `level` that has lasted
+/// `hours`: it doubles after [`RATIONING_WEARINESS_HOURS`] and at most
+/// triples.
+pub fn rationing_morale_penalty_over(level: RationingLevel, hours: f32) -> f32 {
+    let wear = 1.0 + finite_or(hours, 0.0).max(0.0) / RATIONING_WEARINESS_HOURS;
+    rationing_morale_penalty(level) * wear.min(MAX_RATIONING_WEARINESS)
+}
+
+/// Hunger rate while eating a galley meal under rationing at `level`:
+/// smaller portions ease hunger more slowly.
+pub fn rationed_meal_rate(hunger_rate: f32, level: RationingLevel) -> f32 {
+    if hunger_rate < 0.0 {
+        hunger_rate / rationing_hunger_factor(level)
+    } else {
+        hunger_rate
+    }
+}
+
+/// Whether the mess halls serve a meal at `hour` under rationing at
+/// `level`: breakfast at 07:00, lunch at 12:00 and dinner at 18:00 for an
+/// hour each, lunch dropped under heavy rationing and all but dinner in an
+/// emergency.
+pub fn is_mess_open(level: RationingLevel, hour: f32) -> bool {
+    let breakfast = (7.0..8.0).contains(&hour);
+    let lunch = (12.0..13.0).contains(&hour);
+    let dinner = (18.0..19.0).contains(&hour);
+    match level {
+        RationingLevel::Normal | RationingLevel::Light => breakfast || lunch || dinner,
+        RationingLevel::Heavy => breakfast || dinner,
+        RationingLevel::Emergency => dinner,
+    }
+}
+
 /// Detect which resources are in shortage (below 20% capacity).
 /// Returns a list of (resource_name, level) for resources in shortage.
 pub fn detect_shortages(levels: &ResourceLevels) -> Vec<(&'static str, f32)> {
@@ -147,7 +233,7 @@ pub fn resource_health_damage(levels: &ResourceLevels) -> f32 {
 /// Each growth chamber produces base food_rate per hour when at full health.
 pub fn food_production_rate(growth_chamber_count: u32, avg_efficiency: f32) -> f32 {
     let base_rate = 5.0; // kg per hour per growth chamber
-    growth_chamber_count as f32 * base_rate * avg_efficiency
+    growth_chamber_count as f32 * base_rate * unit(avg_efficiency, 0.0)
 }
 
 /// Water recycling rate based on recycler count and efficiency.
@@ -158,15 +244,92 @@ pub fn water_recycling_rate(
     population: f32,
     consumption_rate_per_person: f32,
 ) -> f32 {
+    let population = finite_or(population, 0.0).max(0.0);
+    let consumption_rate_per_person = finite_or(consumption_rate_per_person, 0.0).max(0.0);
+    let avg_efficiency = unit(avg_efficiency, 0.0);
     // Each recycler can handle ~500 people at full efficiency
     let capacity_ratio = (recycler_count as f32 * 500.0 / population.max(1.0)).min(1.0);
     let recovery_rate = 0.9; // 90% water recovery at full efficiency
-    population * consumption_rate_per_person * recovery_rate * avg_efficiency * capacity_ratio
+    finite_or(
+        population * consumption_rate_per_person * recovery_rate * avg_efficiency * capacity_ratio,
+        0.0,
+    )
 }
 
 /// Power balance: total generation minus total draw.
 pub fn power_balance(total_generation: f32, total_draw: f32) -> f32 {
-    total_generation - total_draw
+    finite_or(
+        finite_or(total_generation, 0.0) - finite_or(total_draw, 0.0),
+        0.0,
+    )
+}
+
+/// Credits a crew member of `rank` (see `ranks`) earns an hour on duty.
+pub fn hourly_wage(rank: u8) -> u32 {
+    5 + 2 * rank.min(7) as u32
+}
+
+/// Credits someone boards with: passengers bring their savings.
+pub fn starting_credits(is_crew: bool) -> u32 {
+    if is_crew {
+        50
+    } else {
+        300
+    }
+}
+
+/// Price multiplier for goods from stores at `level` (0.0–1.0): list price
+/// down to half full, rising to four times it as they run out.
+pub fn scarcity_multiplier(level: f32) -> f32 {
+    let level = unit(level, 1.0);
+    if level >= 0.5 {
+        1.0
+    } else {
+        0.5 / level.max(0.125)
+    }
+}
+
+/// Price of goods listed at `list_price` from stores at `level`.
+pub fn price(list_price: u32, level: f32) -> u32 {
+    (list_price as f32 * scarcity_multiplier(level)).round() as u32
+}
+
+/// Price at the shops of an item of `kind` (see `item_kinds`) with the
+/// ship's stores at `levels`; `None` for what the shops do not sell.
+pub fn item_price(kind: u8, levels: &ResourceLevels) -> Option<u32> {
+    match kind {
+        item_kinds::RATION => Some(price(RATION_PRICE, levels.food)),
+        item_kinds::KEEPSAKE => Some(KEEPSAKE_PRICE),
+        item_kinds::TOOLKIT => Some(price(TOOLKIT_PRICE, levels.spare_parts)),
+        _ => None,
+    }
+}
+
+/// What someone of `department` (`None` for passengers) carrying `carried`
+/// (see `item_kinds`) with `credits` buys at the shops, if anything: an
+/// engineer's missing toolkit first, then a ration for later, then a
+/// keepsake for those who have lost theirs and can spare twice its price.
+pub fn shop_purchase(
+    department: Option<u8>,
+    carried: &[u8],
+    credits: u32,
+    levels: &ResourceLevels,
+) -> Option<(u8, u32)> {
+    let missing = |kind: u8| !carried.contains(&kind);
+    let mut wants = Vec::with_capacity(3);
+    if department == Some(departments::ENGINEERING) && missing(item_kinds::TOOLKIT) {
+        wants.push((item_kinds::TOOLKIT, 1));
+    }
+    if missing(item_kinds::RATION) {
+        wants.push((item_kinds::RATION, 1));
+    }
+    if missing(item_kinds::KEEPSAKE) {
+        wants.push((item_kinds::KEEPSAKE, 2));
+    }
+    wants.into_iter().find_map(|(kind, margin)| {
+        let price = item_price(kind, levels)?;
+        (credits >= price * margin).then_some((kind, price))
+    })
 }
 
 /// Convert rationing level to u8 for storage.
@@ -311,6 +474,33 @@ mod tests {
         assert!(rationing_hunger_factor(RationingLevel::Emergency) > 1.0);
     }
 
+    #[test]
+    fn test_rationing_downstream() {
+        let heavy = RationingLevel::Heavy;
+        assert_eq!(
+            rationing_morale_penalty_over(RationingLevel::Normal, 500.0),
+            0.0
+        );
+        let fresh = rationing_morale_penalty_over(heavy, 0.0);
+        assert_eq!(fresh, rationing_morale_penalty(heavy));
+        let week = rationing_morale_penalty_over(heavy, RATIONING_WEARINESS_HOURS);
+        assert!((week - 2.0 * fresh).abs() < 1e-6);
+        let long = rationing_morale_penalty_over(heavy, 1e6);
+        assert!((long - 3.0 * fresh).abs() < 1e-6);
+        assert_eq!(rationing_morale_penalty_over(heavy, f32::NAN), fresh);
+
+        assert_eq!(rationed_meal_rate(-1.0, RationingLevel::Normal), -1.0);
+        assert!((rationed_meal_rate(-1.0, heavy) + 1.0 / 1.5).abs() < 1e-6);
+        assert_eq!(rationed_meal_rate(0.2, heavy), 0.2);
+
+        assert!(is_mess_open(RationingLevel::Light, 12.5));
+        assert!(!is_mess_open(heavy, 12.5));
+        assert!(is_mess_open(heavy, 7.5));
+        assert!(!is_mess_open(RationingLevel::Emergency, 7.5));
+        assert!(is_mess_open(RationingLevel::Emergency, 18.5));
+        assert!(!is_mess_open(RationingLevel::Normal, 15.0));
+    }
+
     #[test]
     fn test_detect_shortages_none() {
         let levels = full_levels();
@@ -365,6 +555,58 @@ mod tests {
         assert_eq!(food_production_rate(0, 1.0), 0.0);
     }
 
+    #[test]
+    fn test_wages_and_savings() {
+        assert!(hourly_wage(7) > hourly_wage(0));
+        assert_eq!(hourly_wage(u8::MAX), hourly_wage(7));
+        assert!(starting_credits(false) > starting_credits(true));
+    }
+
+    #[test]
+    fn test_scarcity_prices() {
+        assert_eq!(price(RATION_PRICE, 1.0), RATION_PRICE);
+        assert_eq!(price(RATION_PRICE, 0.5), RATION_PRICE);
+        assert_eq!(price(RATION_PRICE, 0.25), 2 * RATION_PRICE);
+        assert_eq!(price(RATION_PRICE, 0.0), 4 * RATION_PRICE);
+        assert_eq!(price(RATION_PRICE, f32::NAN), RATION_PRICE);
+        let mut levels = full_levels();
+        levels.food = 0.1;
+        assert!(item_price(item_kinds::RATION, &levels) > Some(RATION_PRICE));
+        assert_eq!(item_price(item_kinds::CONTRABAND, &levels), None);
+    }
+
+    #[test]
+    fn test_shop_purchase() {
+        let levels = full_levels();
+        let engineer = Some(departments::ENGINEERING);
+        assert_eq!(
+            shop_purchase(engineer, &[item_kinds::RATION], 100, &levels),
+            Some((item_kinds::TOOLKIT, TOOLKIT_PRICE))
+        );
+        // Too poor for a toolkit, a ration will do
+        assert_eq!(
+            shop_purchase(engineer, &[], 10, &levels),
+            Some((item_kinds::RATION, RATION_PRICE))
+        );
+        assert_eq!(
+            shop_purchase(None, &[item_kinds::RATION], 30, &levels),
+            None
+        );
+        assert_eq!(
+            shop_purchase(None, &[item_kinds::RATION], 50, &levels),
+            Some((item_kinds::KEEPSAKE, KEEPSAKE_PRICE))
+        );
+        assert_eq!(
+            shop_purchase(
+                None,
+                &[item_kinds::RATION, item_kinds::KEEPSAKE],
+                500,
+                &levels
+            ),
+            None
+        );
+    }
+
     #[test]
     fn test_rationing_roundtrip() {
         for level in [
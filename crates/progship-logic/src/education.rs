@@ -0,0 +1,182 @@
+//! Education — children at school, the teachers who staff it and the
+//! professions school leavers go into.
+//!
+//! Infants spend school hours ([`is_school_time`]) in the nursery and
+//! children in the school, one teacher to every [`PUPILS_PER_TEACHER`]
+//! ([`teachers_needed`]). Teachers are drawn from adults with the social
+//! skill for it ([`teacher_fitness`]): passengers who teach by profession
+//! first, then other passengers, crew only when there are not enough.
+//! Every school hour a child learns, most in the subject they are best at
+//! ([`lesson_gain`]), up to what a school leaver can know
+//! ([`SCHOOL_CEILING`]), faster with a good teacher in the room and slowly
+//! without one. Coming of age, they take up the profession of their best
+//! subject ([`profession_for`]).
+
+use crate::constants::{life_stages, room_types};
+use crate::skills::SkillCategory;
+
+/// Pupils one teacher can take.
+pub const PUPILS_PER_TEACHER: u32 = 12;
+
+/// Social skill an adult needs to teach.
+pub const TEACHER_MIN_SOCIAL: f32 = 0.45;
+
+/// Most a child can learn of any subject at school.
+pub const SCHOOL_CEILING: f32 = 0.6;
+
+/// Skill a school leaver's best subject needs to make a profession of it.
+pub const PROFESSION_LEVEL: f32 = 0.35;
+
+/// Skill a child gains an hour in their best subject with a teacher of
+/// average social skill.
+const LESSON_GAIN_PER_HOUR: f32 = 0.00005;
+
+/// Share of the lesson gain the other subjects get.
+const OTHER_SUBJECTS: f32 = 0.3;
+
+/// Share of the lesson gain left without a teacher in the room.
+const UNTAUGHT: f32 = 0.25;
+
+/// Whether `hour` is in school hours: the morning, and the early afternoon
+/// after lunch.
+pub fn is_school_time(hour: f32) -> bool {
+    (8.0..12.0).contains(&hour) || (13.0..15.0).contains(&hour)
+}
+
+/// Room type where those of `life_stage` spend school hours: the nursery
+/// for infants, the school for children.
+pub fn classroom_for(life_stage: u8) -> Option<u8> {
+    match life_stage {
+        life_stages::INFANT => Some(room_types::NURSERY),
+        life_stages::CHILD => Some(room_types::SCHOOL),
+        _ => None,
+    }
+}
+
+/// Teachers it takes to staff a class of `pupils`.
+pub fn teachers_needed(pupils: u32) -> u32 {
+    pupils.div_ceil(PUPILS_PER_TEACHER)
+}
+
+/// How suited an adult of `social` skill is to teach (higher first), if at
+/// all: teachers by profession ahead of other passengers, and crew, who
+/// have duties of their own, last.
+pub fn teacher_fitness(social: f32, teacher_by_profession: bool, is_crew: bool) -> Option<f32> {
+    if social < TEACHER_MIN_SOCIAL {
+        return None;
+    }
+    let profession = if teacher_by_profession { 1.0 } else { 0.0 };
+    let crew = if is_crew { -1.0 } else { 0.0 };
+    Some(social + profession + crew)
+}
+
+/// Skill levels (in [`SkillCategory::ALL`] order) of a child after `hours`
+/// at school, taught by a teacher of `teacher_social` skill if one is in
+/// the room. The best subject grows fastest, none past [`SCHOOL_CEILING`].
+pub fn lesson_gain(levels: &[f32; 6], teacher_social: Option<f32>, hours: f32) -> [f32; 6] {
+    let rate = match teacher_social {
+        Some(social) => 0.5 + social.clamp(0.0, 1.0),
+        None => UNTAUGHT,
+    } * LESSON_GAIN_PER_HOUR
+        * hours.max(0.0);
+    let best = best_subject(levels);
+    let mut next = *levels;
+    for (i, level) in next.iter_mut().enumerate() {
+        if *level >= SCHOOL_CEILING {
+            continue;
+        }
+        let share = if i == best { 1.0 } else { OTHER_SUBJECTS };
+        *level = (*level + rate * share).min(SCHOOL_CEILING);
+    }
+    next
+}
+
+/// Index of the highest of `levels`, the first on a tie.
+fn best_subject(levels: &[f32; 6]) -> usize {
+    levels
+        .iter()
+        .enumerate()
+        .fold(0, |best, (i, &l)| if l > levels[best] { i } else { best })
+}
+
+/// Profession a school leaver with skill `levels` (in
+/// [`SkillCategory::ALL`] order) goes into: that of their best subject if
+/// they are good enough at it, else colonist.
+pub fn profession_for(levels: &[f32; 6]) -> &'static str {
+    let best = best_subject(levels);
+    if levels[best] < PROFESSION_LEVEL {
+        return "Colonist";
+    }
+    match SkillCategory::ALL[best] {
+        SkillCategory::Engineering => "Engineer",
+        SkillCategory::Medical => "Doctor",
+        SkillCategory::Science => "Scientist",
+        SkillCategory::Social => "Teacher",
+        SkillCategory::Piloting | SkillCategory::Combat => "Colonist",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_school_hours() {
+        assert!(is_school_time(9.0));
+        assert!(!is_school_time(12.5));
+        assert!(is_school_time(14.0));
+        assert!(!is_school_time(20.0));
+    }
+
+    #[test]
+    fn test_classrooms() {
+        assert_eq!(
+            classroom_for(life_stages::INFANT),
+            Some(room_types::NURSERY)
+        );
+        assert_eq!(classroom_for(life_stages::CHILD), Some(room_types::SCHOOL));
+        assert_eq!(classroom_for(life_stages::ADULT), None);
+    }
+
+    #[test]
+    fn test_teachers_needed() {
+        assert_eq!(teachers_needed(0), 0);
+        assert_eq!(teachers_needed(1), 1);
+        assert_eq!(teachers_needed(PUPILS_PER_TEACHER), 1);
+        assert_eq!(teachers_needed(PUPILS_PER_TEACHER + 1), 2);
+    }
+
+    #[test]
+    fn test_teacher_fitness() {
+        assert_eq!(teacher_fitness(0.3, true, false), None);
+        let teacher = teacher_fitness(0.5, true, false).unwrap();
+        let passenger = teacher_fitness(0.8, false, false).unwrap();
+        let crew = teacher_fitness(0.9, false, true).unwrap();
+        assert!(teacher > passenger && passenger > crew);
+    }
+
+    #[test]
+    fn test_lessons_favor_best_subject() {
+        let levels = [0.1, 0.1, 0.0, 0.2, 0.1, 0.0];
+        let taught = lesson_gain(&levels, Some(0.8), 1000.0);
+        let untaught = lesson_gain(&levels, None, 1000.0);
+        let science = SkillCategory::ALL
+            .iter()
+            .position(|&c| c == SkillCategory::Science)
+            .unwrap();
+        assert!(taught[science] - levels[science] > taught[0] - levels[0]);
+        assert!(taught[science] > untaught[science] && untaught[science] > levels[science]);
+        // Nothing is learned past the school ceiling
+        let capped = lesson_gain(&[0.59, 0.7, 0.0, 0.0, 0.0, 0.0], Some(1.0), 1e6);
+        assert_eq!(capped[0], SCHOOL_CEILING);
+        assert_eq!(capped[1], 0.7);
+    }
+
+    #[test]
+    fn test_professions() {
+        assert_eq!(profession_for(&[0.5, 0.1, 0.0, 0.2, 0.1, 0.0]), "Engineer");
+        assert_eq!(profession_for(&[0.1, 0.1, 0.0, 0.2, 0.45, 0.0]), "Teacher");
+        assert_eq!(profession_for(&[0.1, 0.1, 0.0, 0.2, 0.3, 0.0]), "Colonist");
+        assert_eq!(profession_for(&[0.1, 0.1, 0.5, 0.2, 0.3, 0.0]), "Colonist");
+    }
+}
@@ -0,0 +1,168 @@
+//! Emotions — a person's visible mood, derived from needs and events.
+//!
+//! Emotions are cheap to compute and recomputed every
+//! [`EMOTION_INTERVAL_HOURS`]. Grief overrides everything while it lasts,
+//! unmet needs or low morale make people stressed, and celebrations or
+//! discoveries nearby (or simply feeling great) make them excited. Clients
+//! render the result as thought bubbles; conversations lean toward topics
+//! that fit the speaker's mood.
+
+use crate::constants::{conversation_topics, emotions, relationship_types as rel};
+
+/// Sim hours between emotion updates.
+pub const EMOTION_INTERVAL_HOURS: f64 = 0.25;
+/// Stress level (0.0–1.0) above which someone counts as stressed.
+const STRESS_THRESHOLD: f32 = 0.6;
+
+/// Inputs to [`compute_emotion`] — pure data, no DB access.
+#[derive(Debug, Clone, Default)]
+pub struct EmotionInput {
+    pub hunger: f32,
+    pub fatigue: f32,
+    pub comfort: f32,
+    pub hygiene: f32,
+    pub health: f32,
+    pub morale: f32,
+    /// Still mourning a death.
+    pub grieving: bool,
+    /// A celebration or discovery is under way nearby.
+    pub good_news: bool,
+}
+
+/// How stressed someone is (0.0–1.0): their worst unmet need, worsened by
+/// poor health and low morale.
+pub fn stress_level(input: &EmotionInput) -> f32 {
+    let worst_need = input
+        .hunger
+        .max(input.fatigue)
+        .max(input.comfort)
+        .max(input.hygiene)
+        .max(1.0 - input.health);
+    (worst_need * 0.7 + (1.0 - input.morale) * 0.3).clamp(0.0, 1.0)
+}
+
+/// Emotion (see `emotions`) and its intensity (0.0–1.0).
+pub fn compute_emotion(input: &EmotionInput) -> (u8, f32) {
+    if input.grieving {
+        return (emotions::GRIEVING, 0.6 + (1.0 - input.morale) * 0.4);
+    }
+    let stress = stress_level(input);
+    if stress > STRESS_THRESHOLD {
+        return (emotions::STRESSED, stress);
+    }
+    if input.good_news || input.morale > 0.9 {
+        return (emotions::EXCITED, input.morale.max(0.5));
+    }
+    (emotions::CONTENT, input.morale)
+}
+
+/// Hours someone mourns a death, by their relationship to the deceased
+/// (`None` for strangers) and whether they saw it happen.
+pub fn grief_hours(relationship_type: Option<u8>, witnessed: bool) -> f64 {
+    let bond = match relationship_type {
+        Some(rel::FAMILY | rel::ROMANTIC) => 72.0,
+        Some(rel::CLOSE_FRIEND) => 24.0,
+        Some(rel::FRIEND) => 8.0,
+        _ => 0.0,
+    };
+    let shock = if witnessed { 2.0 } else { 0.0 };
+    f64::max(bond, shock)
+}
+
+/// Conversation topic someone in `emotion` steers toward, if any. `seed`
+/// (0.0–10.0) adds variety.
+pub fn emotion_topic(emotion: u8, seed: f32) -> Option<u8> {
+    match emotion {
+        emotions::GRIEVING => Some(conversation_topics::PERSONAL),
+        emotions::STRESSED if seed < 6.0 => Some(conversation_topics::COMPLAINT),
+        emotions::EXCITED if seed < 6.0 => Some(conversation_topics::GOSSIP),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn calm() -> EmotionInput {
+        EmotionInput {
+            hunger: 0.2,
+            fatigue: 0.2,
+            comfort: 0.2,
+            hygiene: 0.2,
+            health: 1.0,
+            morale: 0.7,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_calm_is_content() {
+        assert_eq!(compute_emotion(&calm()).0, emotions::CONTENT);
+    }
+
+    #[test]
+    fn test_unmet_needs_stress() {
+        let starving = EmotionInput {
+            hunger: 0.95,
+            ..calm()
+        };
+        let (emotion, intensity) = compute_emotion(&starving);
+        assert_eq!(emotion, emotions::STRESSED);
+        assert!(intensity > STRESS_THRESHOLD);
+        let despairing = EmotionInput {
+            hunger: 0.5,
+            morale: 0.0,
+            ..calm()
+        };
+        assert!(stress_level(&despairing) > stress_level(&calm()));
+    }
+
+    #[test]
+    fn test_grief_overrides() {
+        let input = EmotionInput {
+            hunger: 0.95,
+            good_news: true,
+            grieving: true,
+            ..calm()
+        };
+        assert_eq!(compute_emotion(&input).0, emotions::GRIEVING);
+    }
+
+    #[test]
+    fn test_good_news_excites() {
+        let input = EmotionInput {
+            good_news: true,
+            ..calm()
+        };
+        assert_eq!(compute_emotion(&input).0, emotions::EXCITED);
+        // ...unless people are too stressed to care
+        let hungry = EmotionInput {
+            hunger: 0.95,
+            ..input
+        };
+        assert_eq!(compute_emotion(&hungry).0, emotions::STRESSED);
+    }
+
+    #[test]
+    fn test_grief_hours() {
+        assert_eq!(grief_hours(Some(rel::FAMILY), false), 72.0);
+        assert!(grief_hours(Some(rel::CLOSE_FRIEND), true) > grief_hours(None, true));
+        assert_eq!(grief_hours(None, true), 2.0);
+        assert_eq!(grief_hours(Some(rel::RIVAL), false), 0.0);
+    }
+
+    #[test]
+    fn test_emotion_topics() {
+        assert_eq!(
+            emotion_topic(emotions::GRIEVING, 9.0),
+            Some(conversation_topics::PERSONAL)
+        );
+        assert_eq!(
+            emotion_topic(emotions::STRESSED, 1.0),
+            Some(conversation_topics::COMPLAINT)
+        );
+        assert_eq!(emotion_topic(emotions::EXCITED, 9.0), None);
+        assert_eq!(emotion_topic(emotions::CONTENT, 1.0), None);
+    }
+}
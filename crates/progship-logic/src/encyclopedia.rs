@@ -0,0 +1,313 @@
+//! Encyclopedia — the in-game "shipopedia" of rooms, systems and mechanics.
+//!
+//! Text lives in `data/encyclopedia.json`; the numbers come from the specs
+//! the simulation itself uses ([`SystemSpec`], [`PropulsionType::spec`] and
+//! the facility manifest), so an entry can never disagree with the game.
+
+use serde::Deserialize;
+
+use crate::constants::{deck_heights, room_types};
+use crate::genlib::facilities::get_facility_manifest;
+use crate::mission::PropulsionType;
+use crate::systems::{
+    DefenseVariant, FoodVariant, GravityVariant, LifeSupportVariant, MedicalVariant, PowerVariant,
+    SystemSpec, WaterVariant,
+};
+
+/// Encyclopedia section, in browsing order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Category {
+    Room,
+    System,
+    Propulsion,
+    Resource,
+    Mechanic,
+}
+
+impl Category {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Room => "Rooms",
+            Self::System => "Ship Systems",
+            Self::Propulsion => "Propulsion",
+            Self::Resource => "Resources",
+            Self::Mechanic => "Mechanics",
+        }
+    }
+}
+
+/// One encyclopedia article.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    pub category: Category,
+    pub title: String,
+    pub summary: String,
+    /// (label, value) pairs taken from spec data.
+    pub facts: Vec<(String, String)>,
+}
+
+impl Entry {
+    /// Plain-text rendering for a UI panel.
+    pub fn to_text(&self) -> String {
+        let mut text = format!("{}\n{}\n", self.title, self.summary);
+        for (label, value) in &self.facts {
+            text.push_str(&format!("{}: {}\n", label, value));
+        }
+        text
+    }
+}
+
+#[derive(Deserialize)]
+struct Article {
+    title: String,
+    summary: String,
+}
+
+#[derive(Deserialize)]
+struct RoomArticle {
+    room_type: u8,
+    summary: String,
+}
+
+#[derive(Deserialize)]
+struct EncyclopediaData {
+    rooms: Vec<RoomArticle>,
+    /// Summaries by [`PropulsionType`] id.
+    propulsion: Vec<String>,
+    resources: Vec<Article>,
+    mechanics: Vec<Article>,
+}
+
+const ZONE_NAMES: [&str; 7] = [
+    "Command",
+    "Habitation",
+    "Services",
+    "Recreation",
+    "Life Support",
+    "Cargo",
+    "Engineering",
+];
+
+/// Every entry, grouped by [`Category`] and in manifest order within one.
+pub fn entries() -> Vec<Entry> {
+    const ENCYCLOPEDIA_JSON: &str = include_str!("../../../data/encyclopedia.json");
+    let data: EncyclopediaData =
+        serde_json::from_str(ENCYCLOPEDIA_JSON).expect("encyclopedia.json is invalid");
+
+    let mut entries: Vec<Entry> = get_facility_manifest()
+        .into_iter()
+        .map(|facility| Entry {
+            category: Category::Room,
+            title: room_types::name(facility.room_type).to_string(),
+            summary: data
+                .rooms
+                .iter()
+                .find(|r| r.room_type == facility.room_type)
+                .map(|r| r.summary.clone())
+                .unwrap_or_default(),
+            facts: vec![
+                fact("Floor area", format!("{:.0} m²", facility.target_area)),
+                fact("Capacity", facility.capacity),
+                fact(
+                    "Zone",
+                    ZONE_NAMES
+                        .get(facility.deck_zone as usize)
+                        .copied()
+                        .unwrap_or("Any"),
+                ),
+                fact(
+                    "Ceiling",
+                    format!(
+                        "{:.1} m",
+                        deck_heights::room_ceiling_height(facility.room_type)
+                    ),
+                ),
+            ],
+        })
+        .collect();
+
+    let systems: [(&str, Vec<SystemSpec>); 7] = [
+        (
+            "Power",
+            PowerVariant::all().iter().map(|v| v.spec()).collect(),
+        ),
+        (
+            "Life Support",
+            LifeSupportVariant::all().iter().map(|v| v.spec()).collect(),
+        ),
+        (
+            "Food",
+            FoodVariant::all().iter().map(|v| v.spec()).collect(),
+        ),
+        (
+            "Water",
+            WaterVariant::all().iter().map(|v| v.spec()).collect(),
+        ),
+        (
+            "Defense",
+            DefenseVariant::all().iter().map(|v| v.spec()).collect(),
+        ),
+        (
+            "Medical",
+            MedicalVariant::all().iter().map(|v| v.spec()).collect(),
+        ),
+        (
+            "Gravity",
+            GravityVariant::all().iter().map(|v| v.spec()).collect(),
+        ),
+    ];
+    for (kind, specs) in systems {
+        entries.extend(specs.into_iter().map(|spec| system_entry(kind, spec)));
+    }
+
+    entries.extend((0..).map_while(PropulsionType::from_u8).map(|drive| {
+        let spec = drive.spec();
+        Entry {
+            category: Category::Propulsion,
+            title: spec.name.to_string(),
+            summary: data
+                .propulsion
+                .get(drive as usize)
+                .cloned()
+                .unwrap_or_default(),
+            facts: vec![
+                fact(
+                    "Cruise velocity",
+                    format!("{:.2} c", spec.cruise_velocity_c),
+                ),
+                fact("Power draw", format!("{:.0} MW", spec.power_draw_mw)),
+                fact("Fuel use", format!("{:.1} kg/h", spec.fuel_rate)),
+                fact("Crew", spec.crew_required),
+                fact("MTBF", format!("{:.0} h", spec.mtbf_hours)),
+            ],
+        }
+    }));
+
+    for (category, articles) in [
+        (Category::Resource, data.resources),
+        (Category::Mechanic, data.mechanics),
+    ] {
+        entries.extend(articles.into_iter().map(|a| Entry {
+            category,
+            title: a.title,
+            summary: a.summary,
+            facts: Vec::new(),
+        }));
+    }
+    entries
+}
+
+/// Entries whose title or summary contains `query`, ignoring case.
+pub fn search<'a>(entries: &'a [Entry], query: &str) -> Vec<&'a Entry> {
+    let query = query.to_lowercase();
+    entries
+        .iter()
+        .filter(|e| {
+            e.title.to_lowercase().contains(&query) || e.summary.to_lowercase().contains(&query)
+        })
+        .collect()
+}
+
+/// Index of the first entry of the section after `page`'s, or `page` if it
+/// is in the last section.
+pub fn next_section(entries: &[Entry], page: usize) -> usize {
+    let Some(current) = entries.get(page) else {
+        return page;
+    };
+    entries
+        .iter()
+        .position(|e| e.category > current.category)
+        .unwrap_or(page)
+}
+
+/// Index of the first entry of the section before `page`'s, or 0.
+pub fn previous_section(entries: &[Entry], page: usize) -> usize {
+    let section_start = |i: usize| {
+        entries
+            .iter()
+            .position(|e| Some(e.category) == entries.get(i).map(|c| c.category))
+            .unwrap_or(0)
+    };
+    match section_start(page) {
+        0 => 0,
+        start => section_start(start - 1),
+    }
+}
+
+fn system_entry(kind: &str, spec: SystemSpec) -> Entry {
+    Entry {
+        category: Category::System,
+        title: spec.name.to_string(),
+        summary: spec.description.to_string(),
+        facts: vec![
+            fact("Type", kind),
+            fact("Output", format!("{:.0}", spec.output)),
+            fact("Crew", spec.crew_needed),
+            fact("Power draw", format!("{:.0} kW", spec.power_draw)),
+            fact("MTBF", format!("{:.0} h", spec.mtbf_hours)),
+            fact("Mass", format!("{:.0} t", spec.mass_tons)),
+            fact("Installed in", room_types::name(spec.room_type)),
+            fact("Tech level", spec.min_tech_level),
+        ],
+    }
+}
+
+fn fact(label: &str, value: impl ToString) -> (String, String) {
+    (label.to_string(), value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entries_cover_manifest_and_specs() {
+        let entries = entries();
+        for facility in get_facility_manifest() {
+            let title = room_types::name(facility.room_type);
+            let room = entries
+                .iter()
+                .find(|e| e.category == Category::Room && e.title == title)
+                .unwrap();
+            assert!(!room.summary.is_empty(), "{title} has no summary");
+        }
+        let fission = entries
+            .iter()
+            .find(|e| e.title == PowerVariant::FissionReactor.spec().name)
+            .unwrap();
+        assert!(fission.to_text().contains("Crew: 6\n"));
+        assert!(fission.to_text().contains("Installed in: Reactor\n"));
+        assert!(entries.windows(2).all(|w| w[0].category <= w[1].category));
+        assert!(entries
+            .iter()
+            .filter(|e| e.category == Category::Propulsion)
+            .all(|e| !e.summary.is_empty()));
+        assert!(entries.iter().any(|e| e.category == Category::Mechanic));
+    }
+
+    #[test]
+    fn test_search_ignores_case() {
+        let entries = entries();
+        let hits = search(&entries, "hydroponics");
+        assert!(hits.iter().any(|e| e.title == "Hydroponics"));
+        assert!(search(&entries, "no such thing").is_empty());
+    }
+
+    #[test]
+    fn test_section_navigation() {
+        let entries = entries();
+        let first_system = entries
+            .iter()
+            .position(|e| e.category == Category::System)
+            .unwrap();
+        assert_eq!(next_section(&entries, 3), first_system);
+        assert_eq!(previous_section(&entries, first_system + 2), 0);
+        assert_eq!(previous_section(&entries, 3), 0);
+        let last = entries.len() - 1;
+        assert_eq!(next_section(&entries, last), last);
+        assert_eq!(
+            entries[previous_section(&entries, last)].category,
+            Category::Resource
+        );
+    }
+}
@@ -0,0 +1,135 @@
+//! EVA — crew repairing what can only be reached from outside the hull.
+//!
+//! Radiator panels, antennas and damaged hull plating are repaired on EVA.
+//! A fit crew member trained for it ([`can_go_outside`]) suits up at the
+//! airlock or EVA hatch nearest the site ([`nearest_hatch`]), cycles out
+//! ([`CYCLE_HOURS`]), crosses the hull ([`traverse_hours`]) and works
+//! until the job is done or the suit is down to the oxygen needed to get
+//! back ([`must_turn_back`]). Every hour outside risks an accident
+//! ([`accident_chance`]), less for the skilled. Until it is patched,
+//! damaged plating leaks the ship's air ([`hull_leak`]).
+
+use crate::constants::deck_heights::MIN_DECK_HEIGHT;
+use crate::numeric::{finite_or, unit};
+
+/// Hours of oxygen in a full suit.
+pub const SUIT_O2_HOURS: f32 = 6.0;
+
+/// Hours for an airlock to cycle, each way.
+pub const CYCLE_HOURS: f32 = 0.25;
+
+/// Meters a suited crew member crosses the hull in an hour, clipping and
+/// unclipping their tether as they go.
+pub const HULL_SPEED: f32 = 120.0;
+
+/// Meters radiators and antennas stand out from the hull.
+pub const MOUNT_REACH: f32 = 40.0;
+
+/// Suit oxygen, in hours, kept in hand on the way back.
+pub const O2_RESERVE_HOURS: f32 = 0.5;
+
+/// Skill in the work needed to go outside.
+pub const MIN_EVA_SKILL: f32 = 0.3;
+
+/// Health needed to go outside.
+pub const MIN_EVA_HEALTH: f32 = 0.7;
+
+/// Health an accident outside costs.
+pub const ACCIDENT_HEALTH: f32 = 0.3;
+
+/// Health lost per hour once the suit's oxygen has run out.
+pub const NO_O2_HEALTH: f32 = 0.5;
+
+/// Chance per hour of an accident for someone of middling skill.
+const ACCIDENT_CHANCE: f32 = 0.02;
+
+/// Share of the oxygen stores lost per hour through damage of severity 1.
+const LEAK_PER_HOUR: f32 = 0.0005;
+
+/// Salt of the [`roll`](crate::numeric::roll) for a spacewalk accident.
+pub const ACCIDENT_SALT: u64 = 0xacc1;
+
+/// Whether someone with `skill` in the work and at `health` may go out.
+pub fn can_go_outside(skill: f32, health: f32) -> bool {
+    skill >= MIN_EVA_SKILL && health >= MIN_EVA_HEALTH
+}
+
+/// Meters across the hull between `(deck, x, y)` points.
+pub fn hull_distance(from: (i32, f32, f32), to: (i32, f32, f32)) -> f32 {
+    let dx = to.1 - from.1;
+    let dy = to.2 - from.2;
+    let dz = (to.0 - from.0) as f32 * MIN_DECK_HEIGHT;
+    finite_or(dx.abs() + dy.abs() + dz.abs(), 0.0)
+}
+
+/// Hours to cross `distance` meters of hull.
+pub fn traverse_hours(distance: f32) -> f32 {
+    finite_or(distance, 0.0).max(0.0) / HULL_SPEED
+}
+
+/// Index into `hatches`, as `(deck, x, y)`, of the one closest to `site`.
+pub fn nearest_hatch(hatches: &[(i32, f32, f32)], site: (i32, f32, f32)) -> Option<usize> {
+    hatches
+        .iter()
+        .enumerate()
+        .min_by(|a, b| {
+            hull_distance(*a.1, site)
+                .total_cmp(&hull_distance(*b.1, site))
+                .then(a.0.cmp(&b.0))
+        })
+        .map(|(i, _)| i)
+}
+
+/// Whether someone with `o2_hours` left in their suit who needs
+/// `return_hours` to get back inside should head back now.
+pub fn must_turn_back(o2_hours: f32, return_hours: f32) -> bool {
+    o2_hours <= return_hours + O2_RESERVE_HOURS
+}
+
+/// Chance of an accident in `hours` outside for someone with `skill`.
+pub fn accident_chance(skill: f32, hours: f32) -> f32 {
+    ACCIDENT_CHANCE * (1.5 - unit(skill, 0.0)) * finite_or(hours, 0.0).max(0.0)
+}
+
+/// Oxygen lost in `hours` through hull damage of `severity` from stores
+/// holding up to `oxygen_cap`.
+pub fn hull_leak(oxygen_cap: f32, severity: f32, hours: f32) -> f32 {
+    finite_or(oxygen_cap, 0.0).max(0.0)
+        * LEAK_PER_HOUR
+        * unit(severity, 0.0)
+        * finite_or(hours, 0.0).max(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_going_outside() {
+        assert!(can_go_outside(0.5, 0.9));
+        assert!(!can_go_outside(0.1, 0.9));
+        assert!(!can_go_outside(0.5, 0.5));
+        let hatches = [(0, 0.0, 0.0), (2, 10.0, 0.0), (1, 50.0, 0.0)];
+        assert_eq!(nearest_hatch(&hatches, (2, 12.0, 0.0)), Some(1));
+        assert_eq!(nearest_hatch(&[], (0, 0.0, 0.0)), None);
+        let d = hull_distance((0, 0.0, 0.0), (2, 3.0, 4.0));
+        assert!((d - (7.0 + 2.0 * MIN_DECK_HEIGHT)).abs() < 1e-4);
+        assert!((traverse_hours(HULL_SPEED) - 1.0).abs() < 1e-6);
+        assert_eq!(traverse_hours(f32::NAN), 0.0);
+    }
+
+    #[test]
+    fn test_suit_oxygen_and_accidents() {
+        assert!(!must_turn_back(SUIT_O2_HOURS, 1.0));
+        assert!(must_turn_back(1.4, 1.0));
+        assert!(accident_chance(1.0, 1.0) < accident_chance(0.0, 1.0));
+        assert_eq!(accident_chance(0.5, -1.0), 0.0);
+    }
+
+    #[test]
+    fn test_hull_leak() {
+        assert!((hull_leak(1000.0, 1.0, 1.0) - 0.5).abs() < 1e-6);
+        assert_eq!(hull_leak(1000.0, 0.0, 1.0), 0.0);
+        assert_eq!(hull_leak(f32::NAN, 1.0, 1.0), 0.0);
+    }
+}
@@ -0,0 +1,98 @@
+//! Evacuation — which emergencies clear which rooms, and where people go.
+//!
+//! Fires clear the room they burn in; hull breaches clear the whole deck.
+//! Evacuees head for the nearest muster station (large public gathering
+//! rooms) outside the danger, along routes precomputed for every room with
+//! [`NavGraph::flow_field`](crate::pathfinding::NavGraph::flow_field), so a
+//! mass evacuation never searches a path per person.
+
+use crate::constants::{event_types, room_types};
+
+/// How much of the ship an emergency makes dangerous.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HazardScope {
+    /// Only the room the event is in.
+    Room,
+    /// Every room on the event's deck.
+    Deck,
+}
+
+/// The area an event forces people out of, if it's an evacuation hazard.
+pub fn hazard_scope(event_type: u8) -> Option<HazardScope> {
+    match event_type {
+        event_types::FIRE => Some(HazardScope::Room),
+        event_types::HULL_BREACH => Some(HazardScope::Deck),
+        _ => None,
+    }
+}
+
+/// True for rooms people muster in during an evacuation: public spaces big
+/// enough to gather a crowd.
+pub fn is_muster_station(room_type: u8) -> bool {
+    matches!(
+        room_type,
+        room_types::MESS_HALL
+            | room_types::THEATRE
+            | room_types::CHAPEL
+            | room_types::OBSERVATION_LOUNGE
+            | room_types::RECREATION
+            | room_types::LOUNGE
+            | room_types::SHUTTLE_BAY
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pathfinding::{DoorEdge, NavGraph};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_hazard_scopes() {
+        assert_eq!(hazard_scope(event_types::FIRE), Some(HazardScope::Room));
+        assert_eq!(
+            hazard_scope(event_types::HULL_BREACH),
+            Some(HazardScope::Deck)
+        );
+        assert_eq!(hazard_scope(event_types::CELEBRATION), None);
+        assert!(is_muster_station(room_types::MESS_HALL));
+        assert!(!is_muster_station(room_types::CABIN_SINGLE));
+    }
+
+    fn door(room_a: u32, room_b: u32, door_x: f32) -> DoorEdge {
+        DoorEdge {
+            room_a,
+            room_b,
+            door_x,
+            door_y: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_flow_field_routes_every_room_to_nearest_station() {
+        // Stations 0 and 6 at either end of a corridor 1-2-3-4-5
+        let graph = NavGraph::from_doors(&[
+            door(0, 1, 0.0),
+            door(1, 2, 10.0),
+            door(2, 3, 20.0),
+            door(3, 4, 30.0),
+            door(4, 5, 40.0),
+            door(5, 6, 50.0),
+        ]);
+        let field = graph.flow_field(&[0, 6], &HashSet::new());
+        assert_eq!(field.step(2).unwrap().goal, 0);
+        assert_eq!(field.step(5).unwrap().goal, 6);
+        let path = field.path_from(2);
+        assert_eq!(
+            path.iter().map(|w| w.room_id).collect::<Vec<_>>(),
+            vec![1, 0]
+        );
+        assert!(field.path_from(0).is_empty());
+        assert!(field.step(99).is_none());
+
+        // A fire in 2 sends 3 the long way round, but 2 can still get out
+        let field = graph.flow_field(&[0, 6], &HashSet::from([2]));
+        assert_eq!(field.step(3).unwrap().goal, 6);
+        assert_eq!(field.step(2).unwrap().goal, 0);
+    }
+}
@@ -0,0 +1,127 @@
+//! Fabrication — spare parts by kind, and the workshops that make more.
+//!
+//! Spare parts are stocked by kind (see `part_kinds`), shared out at launch
+//! in proportion to the components of each kind aboard ([`launch_shares`]),
+//! and a repair draws the kind its component takes. A kind run down below
+//! [`LOW_STOCK_SHARE`] of its launch stock ([`stock_low`]) is made good by
+//! fabrication jobs in a workshop that makes it ([`fabricates`]): the
+//! Machine Shop turns out mechanical parts and seals, the Robotics Bay
+//! electrical parts and electronics. A batch of [`BATCH_TONS`] takes
+//! [`RAW_PER_PART_TON`] times its mass in raw materials from the holds.
+
+use crate::constants::{part_kinds, room_types};
+
+/// Number of kinds of spare part.
+pub const PART_KIND_COUNT: usize = 4;
+
+/// Share of its launch stock a kind can fall to before more is made.
+pub const LOW_STOCK_SHARE: f32 = 0.5;
+
+/// Spare parts one fabrication job makes (t).
+pub const BATCH_TONS: f32 = 0.25;
+
+/// Hours a fabrication job takes.
+pub const BATCH_HOURS: f32 = 6.0;
+
+/// Raw materials used per ton of parts made (t), offcuts and rejects
+/// included.
+pub const RAW_PER_PART_TON: f32 = 1.25;
+
+/// Whether a workshop of `room_type` can make parts of `kind`.
+pub fn fabricates(room_type: u8, kind: u8) -> bool {
+    match room_type {
+        room_types::MACHINE_SHOP => matches!(kind, part_kinds::MECHANICAL | part_kinds::SEALS),
+        room_types::ROBOTICS_BAY => {
+            matches!(kind, part_kinds::ELECTRICAL | part_kinds::ELECTRONIC)
+        }
+        _ => false,
+    }
+}
+
+/// Share of the spare parts stockpile each kind gets at launch, by how many
+/// components of each kind (`counts`, indexed by `part_kinds` ID) are
+/// aboard; an even split when there are none.
+pub fn launch_shares(counts: &[u32; PART_KIND_COUNT]) -> [f32; PART_KIND_COUNT] {
+    let total: u32 = counts.iter().sum();
+    if total == 0 {
+        return [1.0 / PART_KIND_COUNT as f32; PART_KIND_COUNT];
+    }
+    counts.map(|c| c as f32 / total as f32)
+}
+
+/// Whether `stock_tons` of a kind stocked with `launch_tons` has run low
+/// enough to fabricate more. A kind the ship set out without is never
+/// missed.
+pub fn stock_low(stock_tons: f32, launch_tons: f32) -> bool {
+    launch_tons > 0.0 && stock_tons < launch_tons * LOW_STOCK_SHARE
+}
+
+/// Priority of a fabrication job for a kind at `stock_tons` of
+/// `launch_tons`: higher the emptier its shelves, and below every repair,
+/// whose priority is at least 0.3.
+pub fn fabrication_priority(stock_tons: f32, launch_tons: f32) -> f32 {
+    if launch_tons <= 0.0 {
+        return 0.0;
+    }
+    0.3 * (1.0 - stock_tons / launch_tons).clamp(0.0, 1.0)
+}
+
+/// Raw materials a batch of `part_tons` takes (t).
+pub fn raw_needed(part_tons: f32) -> f32 {
+    part_tons.max(0.0) * RAW_PER_PART_TON
+}
+
+/// Parts made from `raw_tons` of raw materials (t).
+pub fn parts_made(raw_tons: f32) -> f32 {
+    raw_tons.max(0.0) / RAW_PER_PART_TON
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_workshops() {
+        assert!(fabricates(room_types::MACHINE_SHOP, part_kinds::MECHANICAL));
+        assert!(fabricates(room_types::MACHINE_SHOP, part_kinds::SEALS));
+        assert!(!fabricates(
+            room_types::MACHINE_SHOP,
+            part_kinds::ELECTRONIC
+        ));
+        assert!(fabricates(room_types::ROBOTICS_BAY, part_kinds::ELECTRONIC));
+        assert!(!fabricates(room_types::CARGO_BAY, part_kinds::MECHANICAL));
+        // Every kind has a workshop
+        for kind in 0..PART_KIND_COUNT as u8 {
+            assert!(
+                fabricates(room_types::MACHINE_SHOP, kind)
+                    || fabricates(room_types::ROBOTICS_BAY, kind)
+            );
+        }
+    }
+
+    #[test]
+    fn test_launch_shares() {
+        let shares = launch_shares(&[6, 2, 2, 0]);
+        assert!((shares[0] - 0.6).abs() < 0.001);
+        assert_eq!(shares[3], 0.0);
+        assert!((shares.iter().sum::<f32>() - 1.0).abs() < 0.001);
+        assert_eq!(launch_shares(&[0; 4]), [0.25; 4]);
+    }
+
+    #[test]
+    fn test_stock_low() {
+        assert!(!stock_low(6.0, 10.0));
+        assert!(stock_low(4.0, 10.0));
+        assert!(!stock_low(0.0, 0.0));
+        assert!(fabrication_priority(0.0, 10.0) > fabrication_priority(4.0, 10.0));
+        assert!(fabrication_priority(0.0, 10.0) <= 0.3);
+        assert_eq!(fabrication_priority(1.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_raw_materials() {
+        assert!((raw_needed(BATCH_TONS) - 0.3125).abs() < 0.0001);
+        assert!((parts_made(raw_needed(0.4)) - 0.4).abs() < 0.0001);
+        assert_eq!(raw_needed(-1.0), 0.0);
+    }
+}
@@ -0,0 +1,209 @@
+//! Factions — the ship's political blocs and the tension between them.
+//!
+//! Every adult belongs to one faction (see `factions`): crew to the crew
+//! union and officers and first-class passengers to the corporate bloc,
+//! other passengers to the colonist council, with some of everyone in the
+//! religious fellowship ([`founding_faction`]). How loyal members are
+//! shifts with what happens aboard ([`event_swing`]) and with every
+//! conversation ([`sway`]); someone whose loyalty gives out
+//! ([`DEFECTION_LOYALTY`]) goes over to the faction that won them away.
+//!
+//! Blocs that are loyal and unhappy in a divided ship make for tension
+//! ([`tension`]), and tension makes trouble break out more often
+//! ([`event_frequency`]).
+
+use crate::constants::{conversation_topics as topics, event_types, factions, ranks};
+use crate::numeric::roll;
+
+/// Loyalty a new member starts with.
+pub const STARTING_LOYALTY: f32 = 0.5;
+
+/// Loyalty below which a member swayed by another faction defects to it.
+pub const DEFECTION_LOYALTY: f32 = 0.1;
+
+/// Share of passengers and crew in the religious fellowship.
+const FAITHFUL_SHARE: f32 = 0.15;
+
+/// Salt of the [`roll`] for joining the religious fellowship.
+const FAITH_SALT: u64 = 0xfa17;
+
+/// How much more often events break out at full tension.
+const TENSION_EVENTS: f32 = 1.0;
+
+/// Loyalty the weaker side of a friendly talk across factions loses.
+const PERSUASION: f32 = 0.04;
+
+/// One faction's standing: its members, their average loyalty and their
+/// average morale.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Standing {
+    pub members: u32,
+    pub loyalty: f32,
+    pub morale: f32,
+}
+
+/// The faction someone joins when they first take a side: crew of
+/// officer `rank` and first-class passengers the corporate bloc, other
+/// crew the union, other passengers the council; the religious take
+/// `seed`'s share of everyone.
+pub fn founding_faction(is_crew: bool, rank: u8, first_class: bool, seed: u64) -> u8 {
+    if roll(seed, 0.0, FAITH_SALT) < FAITHFUL_SHARE {
+        factions::RELIGIOUS
+    } else if (is_crew && rank >= ranks::ENSIGN) || (!is_crew && first_class) {
+        factions::CORPORATE
+    } else if is_crew {
+        factions::CREW_UNION
+    } else {
+        factions::COLONIST_COUNCIL
+    }
+}
+
+/// Change in loyalty members of `faction` feel when an `event_type` event
+/// breaks out: shortages turn people against the corporate bloc, breakdowns
+/// and fires rally the union, deaths send people to the faithful, strikes,
+/// mutinies and rallies stir the union and council against the corporate
+/// bloc, and
+/// a celebration or discovery draws the ship together.
+pub fn event_swing(event_type: u8, faction: u8) -> f32 {
+    match (event_type, faction) {
+        (event_types::RESOURCE_SHORTAGE, factions::CORPORATE) => -0.05,
+        (event_types::RESOURCE_SHORTAGE, factions::COLONIST_COUNCIL) => 0.03,
+        (event_types::RESOURCE_SHORTAGE, factions::CREW_UNION) => 0.02,
+        (
+            event_types::SYSTEM_FAILURE | event_types::FIRE | event_types::HULL_BREACH,
+            factions::CREW_UNION,
+        ) => 0.03,
+        (
+            event_types::SYSTEM_FAILURE | event_types::FIRE | event_types::HULL_BREACH,
+            factions::CORPORATE,
+        ) => -0.02,
+        (event_types::DEATH | event_types::FUNERAL, factions::RELIGIOUS) => 0.03,
+        (event_types::STRIKE, factions::CREW_UNION) => 0.05,
+        (event_types::STRIKE, factions::CORPORATE) => -0.03,
+        (event_types::MUTINY, factions::CREW_UNION) => 0.08,
+        (event_types::MUTINY, factions::CORPORATE) => -0.05,
+        (event_types::RALLY, factions::COLONIST_COUNCIL | factions::CREW_UNION) => 0.03,
+        (event_types::RALLY, factions::CORPORATE) => -0.02,
+        (event_types::ALTERCATION, _) => 0.01,
+        (event_types::CELEBRATION | event_types::DISCOVERY, _) => -0.02,
+        _ => 0.0,
+    }
+}
+
+/// Change in someone's loyalty after a conversation on `topic` with
+/// someone of `relationship` strength to them, who is in the same faction
+/// or not, as loyal as `loyalty` against their `other_loyalty`. Members
+/// talking among themselves grow closer to their faction, complaints most;
+/// across factions a friendly talk wins the less loyal side over a little,
+/// and an argument has both dig in.
+pub fn sway(
+    topic: u8,
+    same_faction: bool,
+    relationship: f32,
+    loyalty: f32,
+    other_loyalty: f32,
+) -> f32 {
+    let warmth = relationship.clamp(0.0, 1.0);
+    match (topic, same_faction) {
+        (topics::ARGUMENT, true) => -0.02,
+        (topics::ARGUMENT, false) => 0.02,
+        (topics::COMPLAINT, true) => 0.03,
+        (_, true) => 0.01,
+        (topics::GREETING | topics::FAREWELL, false) => 0.0,
+        (_, false) if other_loyalty > loyalty => -PERSUASION * warmth,
+        _ => 0.0,
+    }
+}
+
+/// How tense the ship is (0.0–1.0) given every faction's standing: the
+/// member-weighted grievance of loyal, unhappy blocs, damped when one
+/// faction dominates.
+pub fn tension(standings: &[Standing]) -> f32 {
+    let total: u32 = standings.iter().map(|s| s.members).sum();
+    if total == 0 {
+        return 0.0;
+    }
+    let share = |s: &Standing| s.members as f32 / total as f32;
+    let grievance: f32 = standings
+        .iter()
+        .map(|s| share(s) * s.loyalty.clamp(0.0, 1.0) * (1.0 - s.morale.clamp(0.0, 1.0)))
+        .sum();
+    let largest = standings.iter().map(share).fold(0.0, f32::max);
+    (4.0 * grievance * (1.0 - largest)).clamp(0.0, 1.0)
+}
+
+/// Multiplier on how often random events break out at `tension`.
+pub fn event_frequency(tension: f32) -> f32 {
+    1.0 + TENSION_EVENTS * tension.clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_founding_faction() {
+        let join = |is_crew, rank, first_class| {
+            let mut counts = [0u32; factions::COUNT];
+            for seed in 0..1000 {
+                counts[founding_faction(is_crew, rank, first_class, seed) as usize] += 1;
+            }
+            counts
+        };
+        let crew = join(true, ranks::CREWMAN, false);
+        assert!(crew[factions::CREW_UNION as usize] > 700);
+        assert_eq!(crew[factions::COLONIST_COUNCIL as usize], 0);
+        assert!((100..200).contains(&crew[factions::RELIGIOUS as usize]));
+        assert!(join(true, ranks::CAPTAIN, false)[factions::CORPORATE as usize] > 700);
+        assert!(join(false, 0, true)[factions::CORPORATE as usize] > 700);
+        assert!(join(false, 0, false)[factions::COLONIST_COUNCIL as usize] > 700);
+    }
+
+    #[test]
+    fn test_events_swing_loyalty() {
+        assert!(event_swing(event_types::RESOURCE_SHORTAGE, factions::CORPORATE) < 0.0);
+        assert!(event_swing(event_types::FIRE, factions::CREW_UNION) > 0.0);
+        assert!(event_swing(event_types::DEATH, factions::RELIGIOUS) > 0.0);
+        assert!(event_swing(event_types::STRIKE, factions::CREW_UNION) > 0.0);
+        assert!(event_swing(event_types::CELEBRATION, factions::COLONIST_COUNCIL) < 0.0);
+        assert_eq!(
+            event_swing(event_types::DIAGNOSTIC, factions::CORPORATE),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_conversations_sway_loyalty() {
+        assert!(
+            sway(topics::COMPLAINT, true, 0.5, 0.5, 0.5) > sway(topics::WORK, true, 0.5, 0.5, 0.5)
+        );
+        assert!(sway(topics::PERSONAL, false, 0.8, 0.3, 0.7) < 0.0);
+        assert_eq!(sway(topics::PERSONAL, false, 0.8, 0.7, 0.3), 0.0);
+        assert!(
+            sway(topics::PERSONAL, false, 0.8, 0.3, 0.7)
+                < sway(topics::PERSONAL, false, 0.2, 0.3, 0.7)
+        );
+        assert!(sway(topics::ARGUMENT, false, -0.5, 0.3, 0.7) > 0.0);
+        assert_eq!(sway(topics::GREETING, false, 0.8, 0.3, 0.7), 0.0);
+    }
+
+    #[test]
+    fn test_tension() {
+        let bloc = |members, loyalty, morale| Standing {
+            members,
+            loyalty,
+            morale,
+        };
+        assert_eq!(tension(&[]), 0.0);
+        let divided = [bloc(100, 0.8, 0.2), bloc(100, 0.8, 0.2)];
+        let content = [bloc(100, 0.8, 0.9), bloc(100, 0.8, 0.9)];
+        let dominated = [bloc(190, 0.8, 0.2), bloc(10, 0.8, 0.2)];
+        assert!(tension(&divided) > 0.5);
+        assert!(tension(&content) < tension(&divided));
+        assert!(tension(&dominated) < tension(&divided) / 2.0);
+        assert_eq!(tension(&[bloc(200, 1.0, 0.0)]), 0.0);
+
+        assert_eq!(event_frequency(0.0), 1.0);
+        assert_eq!(event_frequency(1.0), 1.0 + TENSION_EVENTS);
+    }
+}
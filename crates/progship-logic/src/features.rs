@@ -0,0 +1,97 @@
+//! Feature flags — which optional simulation systems a ship runs.
+//!
+//! Hosts scope the simulation's complexity and cost with a
+//! [`FeatureFlags`]: illness outbreaks, aging and the generations that
+//! follow, crime, shipboard politics, and a hardcore atmosphere that hurts
+//! twice as fast. The server keeps them in its `feature_flags` table, the
+//! core engine in its `ShipConfig`, and each system checks its flag before
+//! doing its work.
+
+use serde::{Deserialize, Serialize};
+
+use crate::constants::event_types;
+
+/// How much harder bad air hits in hardcore atmosphere mode.
+pub const HARDCORE_ATMOSPHERE_FACTOR: f32 = 2.0;
+
+/// Optional simulation systems to run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeatureFlags {
+    /// Illness: medical emergencies break out.
+    pub disease: bool,
+    /// Generational mode: people age, have children and die of old age.
+    pub generational: bool,
+    /// Crime: altercations break out.
+    pub crime: bool,
+    /// Politics: factions and shipboard governance.
+    pub politics: bool,
+    /// Hardcore atmosphere: bad air, heat, cold and low pressure do
+    /// [`HARDCORE_ATMOSPHERE_FACTOR`] times the harm.
+    pub hardcore_atmosphere: bool,
+}
+
+impl Default for FeatureFlags {
+    /// Everything but hardcore atmosphere.
+    fn default() -> Self {
+        Self {
+            disease: true,
+            generational: true,
+            crime: true,
+            politics: true,
+            hardcore_atmosphere: false,
+        }
+    }
+}
+
+impl FeatureFlags {
+    /// Whether events of `event_type` may break out: medical emergencies
+    /// need disease, altercations crime, rallies, strikes and mutinies
+    /// politics.
+    pub fn allows_event(&self, event_type: u8) -> bool {
+        match event_type {
+            event_types::MEDICAL_EMERGENCY => self.disease,
+            event_types::ALTERCATION => self.crime,
+            event_types::RALLY | event_types::STRIKE | event_types::MUTINY => self.politics,
+            _ => true,
+        }
+    }
+
+    /// Multiplier on the harm the atmosphere does.
+    pub fn atmosphere_factor(&self) -> f32 {
+        if self.hardcore_atmosphere {
+            HARDCORE_ATMOSPHERE_FACTOR
+        } else {
+            1.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_runs_everything_but_hardcore() {
+        let flags = FeatureFlags::default();
+        assert!(flags.disease && flags.generational && flags.crime && flags.politics);
+        assert!(!flags.hardcore_atmosphere);
+        assert_eq!(flags.atmosphere_factor(), 1.0);
+    }
+
+    #[test]
+    fn test_flags_gate_events() {
+        let flags = FeatureFlags {
+            disease: false,
+            crime: false,
+            politics: false,
+            hardcore_atmosphere: true,
+            ..FeatureFlags::default()
+        };
+        assert!(!flags.allows_event(event_types::MEDICAL_EMERGENCY));
+        assert!(!flags.allows_event(event_types::STRIKE));
+        assert!(!flags.allows_event(event_types::ALTERCATION));
+        assert!(flags.allows_event(event_types::FIRE));
+        assert!(FeatureFlags::default().allows_event(event_types::ALTERCATION));
+        assert_eq!(flags.atmosphere_factor(), HARDCORE_ATMOSPHERE_FACTOR);
+    }
+}
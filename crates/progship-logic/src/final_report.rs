@@ -0,0 +1,507 @@
+//! Final voyage report — the ship's record at arrival or loss.
+//!
+//! A [`FinalReport`] gathers what happened on the voyage: the population
+//! curve, casualties and their causes, notable incidents, how each faction
+//! aboard fared, how much is left in the stores, the milestones reached and
+//! a [`colony_readiness`] score for the colony being founded. The server and
+//! the core engine each fill one in from their own state; it exports as
+//! JSON ([`FinalReport::to_json`]) or Markdown ([`FinalReport::to_markdown`]).
+//!
+//! [`loss_cause`] decides when the voyage has failed for good, so the run
+//! ends with a report on the loss instead of carrying on without survivors.
+
+use serde::{Deserialize, Serialize};
+
+use crate::constants::{
+    event_types, loss_causes, milestone_kinds, system_statuses, voyage_outcomes,
+};
+use crate::history::sim_day;
+use crate::milestones::MilestoneEntry;
+use crate::numeric::unit;
+
+/// Events at or above this severity are listed individually.
+pub const NOTABLE_SEVERITY: f32 = 0.7;
+
+/// Fraction of the people who set out below which the survivors cannot
+/// found a colony.
+pub const MIN_VIABLE_FRACTION: f32 = 0.2;
+
+/// Population curve points shown in the Markdown report.
+const CURVE_ROWS: usize = 10;
+
+/// People alive at the start of a simulation day.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PopulationPoint {
+    /// Simulation day (0-based).
+    pub day: u32,
+    pub alive: u32,
+}
+
+/// How many times something happened.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tally {
+    pub name: String,
+    pub count: u32,
+}
+
+/// One notable incident.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Incident {
+    /// Event type (see `event_types`).
+    pub event_type: u8,
+    /// Simulation time it started, in hours.
+    pub started_at: f64,
+    /// 0.0 minor – 1.0 critical.
+    pub severity: f32,
+    /// Where it happened.
+    pub place: String,
+}
+
+/// How one faction aboard (a crew department, a passenger cabin class) fared.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FactionSummary {
+    pub name: String,
+    /// Members who set out.
+    pub departed: u32,
+    /// Members still alive.
+    pub alive: u32,
+    /// Mean morale of the living members (1.0 happy – 0.0 despairing).
+    pub mean_morale: f32,
+}
+
+/// What is left of one store.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResourceMargin {
+    pub resource: String,
+    pub amount: f32,
+    pub capacity: f32,
+}
+
+impl ResourceMargin {
+    /// Amount as a fraction of capacity (0 for a store with no capacity).
+    pub fn fraction(&self) -> f32 {
+        if self.capacity > 0.0 {
+            unit(self.amount / self.capacity, 0.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+/// End-of-voyage (or so-far) report.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct FinalReport {
+    pub ship_name: String,
+    /// How the voyage ended (see `voyage_outcomes`).
+    pub outcome: u8,
+    /// Why the ship was lost (see `loss_causes`).
+    #[serde(default)]
+    pub loss_cause: u8,
+    /// Simulation time of the report, in hours.
+    pub sim_time: f64,
+    /// People who set out.
+    pub departed: u32,
+    /// People alive now.
+    pub alive: u32,
+    /// Daily population samples, oldest first.
+    pub population: Vec<PopulationPoint>,
+    pub casualties: u32,
+    /// Deaths by cause, most common first.
+    pub causes: Vec<Tally>,
+    /// Events by type over the whole voyage, most common first.
+    pub incident_counts: Vec<Tally>,
+    /// Incidents at or above [`NOTABLE_SEVERITY`], oldest first.
+    pub incidents: Vec<Incident>,
+    pub factions: Vec<FactionSummary>,
+    pub resources: Vec<ResourceMargin>,
+    /// Milestones, in the order they were reached.
+    pub milestones: Vec<MilestoneEntry>,
+    /// Mean health of the living (1.0 healthy).
+    pub mean_health: f32,
+    /// Mean morale of the living.
+    pub mean_morale: f32,
+    /// See [`colony_readiness`].
+    pub colony_readiness: f32,
+}
+
+/// How ready the survivors are to found a colony, 0–100: weighted by
+/// survival, health, morale and what is left in the stores.
+pub fn colony_readiness(
+    survival: f32,
+    mean_health: f32,
+    mean_morale: f32,
+    resource_margin: f32,
+) -> f32 {
+    let score = 0.4 * unit(survival, 0.0)
+        + 0.2 * unit(mean_health, 0.0)
+        + 0.2 * unit(mean_morale, 0.0)
+        + 0.2 * unit(resource_margin, 0.0);
+    (score * 100.0).round()
+}
+
+/// Verdict for a [`colony_readiness`] score.
+pub fn readiness_label(score: f32) -> &'static str {
+    match score {
+        s if s >= 80.0 => "Ready",
+        s if s >= 60.0 => "Viable",
+        s if s >= 40.0 => "Struggling",
+        _ => "Not viable",
+    }
+}
+
+/// Whether a ship with `alive` people aboard is lost.
+pub fn ship_lost(alive: u32, departed: u32) -> bool {
+    departed > 0 && alive == 0
+}
+
+/// The state of the ship that decides whether the voyage has failed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShipCondition {
+    /// People who set out.
+    pub departed: u32,
+    /// People alive now.
+    pub alive: u32,
+    /// Best status among the life support systems (see `system_statuses`),
+    /// `None` if the ship has none.
+    pub life_support_status: Option<u8>,
+    /// Oxygen in reserve.
+    pub oxygen: f32,
+    /// Fuel left, and how much the tanks hold.
+    pub fuel: f32,
+    pub fuel_cap: f32,
+    /// Whether the ship has reached its destination.
+    pub arrived: bool,
+}
+
+/// Why the voyage has failed for good (see `loss_causes`), or
+/// [`loss_causes::NONE`]: everyone is dead, life support is off line with
+/// the oxygen reserve gone, too few are left to found a colony
+/// ([`MIN_VIABLE_FRACTION`]), or the fuel ran out before arrival.
+pub fn loss_cause(ship: &ShipCondition) -> u8 {
+    let viable = (ship.departed as f32 * MIN_VIABLE_FRACTION).ceil() as u32;
+    if ship_lost(ship.alive, ship.departed) {
+        loss_causes::ALL_DEAD
+    } else if ship
+        .life_support_status
+        .is_some_and(|s| s >= system_statuses::OFFLINE)
+        && ship.oxygen <= 0.0
+    {
+        loss_causes::LIFE_SUPPORT_COLLAPSE
+    } else if ship.alive < viable {
+        loss_causes::BELOW_VIABILITY
+    } else if !ship.arrived && ship.fuel_cap > 0.0 && ship.fuel <= 0.0 {
+        loss_causes::FUEL_EXHAUSTED
+    } else {
+        loss_causes::NONE
+    }
+}
+
+/// Count `ids` by display name, most common first.
+pub fn tally(ids: impl IntoIterator<Item = u8>, name: fn(u8) -> &'static str) -> Vec<Tally> {
+    let mut tallies: Vec<Tally> = Vec::new();
+    for id in ids {
+        match tallies.iter_mut().find(|t| t.name == name(id)) {
+            Some(t) => t.count += 1,
+            None => tallies.push(Tally {
+                name: name(id).to_string(),
+                count: 1,
+            }),
+        }
+    }
+    tallies.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.name.cmp(&b.name)));
+    tallies
+}
+
+impl FinalReport {
+    /// Order the lists and compute the readiness score.
+    pub fn finish(mut self) -> Self {
+        self.population.sort_by_key(|p| p.day);
+        self.incidents
+            .sort_by(|a, b| a.started_at.total_cmp(&b.started_at));
+        self.milestones
+            .sort_by(|a, b| a.achieved_at.total_cmp(&b.achieved_at));
+        let survival = if self.departed > 0 {
+            self.alive as f32 / self.departed as f32
+        } else {
+            0.0
+        };
+        let margin = if self.resources.is_empty() {
+            0.0
+        } else {
+            self.resources
+                .iter()
+                .map(ResourceMargin::fraction)
+                .sum::<f32>()
+                / self.resources.len() as f32
+        };
+        self.colony_readiness =
+            colony_readiness(survival, self.mean_health, self.mean_morale, margin);
+        self
+    }
+
+    /// Encode as JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("report serializes")
+    }
+
+    /// Render as a Markdown document.
+    pub fn to_markdown(&self) -> String {
+        let mut md = format!("# Voyage Report: {}\n\n", self.ship_name);
+        let outcome = if self.loss_cause == loss_causes::NONE {
+            voyage_outcomes::name(self.outcome).to_string()
+        } else {
+            format!(
+                "{} ({})",
+                voyage_outcomes::name(self.outcome),
+                loss_causes::name(self.loss_cause)
+            )
+        };
+        md += &format!(
+            "**Outcome:** {}, day {}\n\n**Colony readiness:** {:.0}/100 ({})\n\n",
+            outcome,
+            sim_day(self.sim_time) + 1,
+            self.colony_readiness,
+            readiness_label(self.colony_readiness)
+        );
+
+        md += "## Population\n\n";
+        md += &format!(
+            "Departed with {}; {} alive, mean health {:.0}%, mean morale {:.0}%.\n\n",
+            self.departed,
+            self.alive,
+            self.mean_health * 100.0,
+            self.mean_morale * 100.0
+        );
+        if !self.population.is_empty() {
+            md += "| Day | Alive |\n|----:|------:|\n";
+            let step = self.population.len().div_ceil(CURVE_ROWS).max(1);
+            let last = self.population.len() - 1;
+            for (i, p) in self.population.iter().enumerate() {
+                if i % step == 0 || i == last {
+                    md += &format!("| {} | {} |\n", p.day + 1, p.alive);
+                }
+            }
+            md += "\n";
+        }
+
+        md += &format!("## Casualties\n\n{} deaths\n", self.casualties);
+        for t in &self.causes {
+            md += &format!("- {}: {}\n", t.name, t.count);
+        }
+
+        md += "\n## Incidents\n\n";
+        if self.incident_counts.is_empty() {
+            md += "None\n";
+        }
+        for t in &self.incident_counts {
+            md += &format!("- {}: {}\n", t.name, t.count);
+        }
+        if !self.incidents.is_empty() {
+            md += "\nNotable:\n";
+        }
+        for i in &self.incidents {
+            md += &format!(
+                "- Day {}: {} in {} (severity {:.1})\n",
+                sim_day(i.started_at) + 1,
+                event_types::name(i.event_type),
+                i.place,
+                i.severity
+            );
+        }
+
+        if !self.factions.is_empty() {
+            md +=
+                "\n## Factions\n\n| Faction | Departed | Alive | Morale |\n|---|---:|---:|---:|\n";
+        }
+        for f in &self.factions {
+            md += &format!(
+                "| {} | {} | {} | {:.0}% |\n",
+                f.name,
+                f.departed,
+                f.alive,
+                f.mean_morale * 100.0
+            );
+        }
+
+        if !self.resources.is_empty() {
+            md += "\n## Resources\n\n| Resource | Stock | Capacity | Margin |\n|---|---:|---:|---:|\n";
+        }
+        for r in &self.resources {
+            md += &format!(
+                "| {} | {:.0} | {:.0} | {:.0}% |\n",
+                r.resource,
+                r.amount,
+                r.capacity,
+                r.fraction() * 100.0
+            );
+        }
+
+        md += "\n## Milestones\n\n";
+        if self.milestones.is_empty() {
+            md += "None\n";
+        }
+        for m in &self.milestones {
+            md += &format!(
+                "- Day {}: {}",
+                sim_day(m.achieved_at) + 1,
+                milestone_kinds::name(m.kind)
+            );
+            if !m.detail.is_empty() {
+                md += &format!(" ({})", m.detail);
+            }
+            md += "\n";
+        }
+        md
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::death_causes;
+
+    fn sample() -> FinalReport {
+        FinalReport {
+            ship_name: "ISV Test".into(),
+            outcome: voyage_outcomes::ARRIVED,
+            loss_cause: loss_causes::NONE,
+            sim_time: 240.0,
+            departed: 10,
+            alive: 8,
+            population: (0..10)
+                .rev()
+                .map(|day| PopulationPoint {
+                    day,
+                    alive: 10 - day / 5,
+                })
+                .collect(),
+            casualties: 2,
+            causes: tally([death_causes::FIRE, death_causes::FIRE], death_causes::name),
+            incident_counts: tally([event_types::FIRE], event_types::name),
+            incidents: vec![Incident {
+                event_type: event_types::FIRE,
+                started_at: 50.0,
+                severity: 0.9,
+                place: "Galley".into(),
+            }],
+            factions: vec![FactionSummary {
+                name: "Engineering".into(),
+                departed: 4,
+                alive: 3,
+                mean_morale: 0.5,
+            }],
+            resources: vec![ResourceMargin {
+                resource: "Food".into(),
+                amount: 50.0,
+                capacity: 100.0,
+            }],
+            milestones: vec![MilestoneEntry {
+                kind: milestone_kinds::ARRIVAL,
+                achieved_at: 240.0,
+                detail: String::new(),
+            }],
+            mean_health: 1.0,
+            mean_morale: 0.5,
+            colony_readiness: 0.0,
+        }
+        .finish()
+    }
+
+    #[test]
+    fn test_colony_readiness() {
+        assert_eq!(colony_readiness(1.0, 1.0, 1.0, 1.0), 100.0);
+        assert_eq!(colony_readiness(0.0, 0.0, 0.0, 0.0), 0.0);
+        assert_eq!(colony_readiness(2.0, -1.0, 0.5, 0.5), 60.0);
+        assert_eq!(readiness_label(85.0), "Ready");
+        assert_eq!(readiness_label(10.0), "Not viable");
+        assert!(ship_lost(0, 100));
+        assert!(!ship_lost(0, 0));
+    }
+
+    #[test]
+    fn test_loss_cause() {
+        let healthy = ShipCondition {
+            departed: 100,
+            alive: 90,
+            life_support_status: Some(system_statuses::NOMINAL),
+            oxygen: 500.0,
+            fuel: 1000.0,
+            fuel_cap: 2000.0,
+            arrived: false,
+        };
+        assert_eq!(loss_cause(&healthy), loss_causes::NONE);
+        let lost = |change: fn(&mut ShipCondition)| {
+            let mut ship = healthy;
+            change(&mut ship);
+            loss_cause(&ship)
+        };
+        assert_eq!(lost(|s| s.alive = 0), loss_causes::ALL_DEAD);
+        assert_eq!(lost(|s| s.alive = 19), loss_causes::BELOW_VIABILITY);
+        assert_eq!(lost(|s| s.alive = 20), loss_causes::NONE);
+        assert_eq!(
+            lost(|s| {
+                s.life_support_status = Some(system_statuses::OFFLINE);
+                s.oxygen = 0.0;
+            }),
+            loss_causes::LIFE_SUPPORT_COLLAPSE
+        );
+        // Off line with oxygen in reserve, or out of air with life support running
+        assert_eq!(
+            lost(|s| s.life_support_status = Some(system_statuses::DESTROYED)),
+            loss_causes::NONE
+        );
+        assert_eq!(lost(|s| s.oxygen = 0.0), loss_causes::NONE);
+        assert_eq!(lost(|s| s.fuel = 0.0), loss_causes::FUEL_EXHAUSTED);
+        assert_eq!(
+            lost(|s| {
+                s.fuel = 0.0;
+                s.arrived = true;
+            }),
+            loss_causes::NONE
+        );
+    }
+
+    #[test]
+    fn test_tally_orders_by_count() {
+        let t = tally([2, 5, 5, 2, 5, 8], event_types::name);
+        let names: Vec<_> = t.iter().map(|t| (t.name.as_str(), t.count)).collect();
+        assert_eq!(names, [("Celebration", 3), ("Fire", 2), ("Death", 1)]);
+    }
+
+    #[test]
+    fn test_finish_scores_and_sorts() {
+        let report = sample();
+        assert_eq!(report.population[0].day, 0);
+        // 0.4 * 0.8 + 0.2 * 1.0 + 0.2 * 0.5 + 0.2 * 0.5
+        assert_eq!(report.colony_readiness, 72.0);
+    }
+
+    #[test]
+    fn test_exports() {
+        let report = sample();
+        let back: FinalReport = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(back, report);
+        let md = report.to_markdown();
+        assert!(md.starts_with("# Voyage Report: ISV Test\n"), "{md}");
+        assert!(md.contains("**Outcome:** Arrived, day 11"), "{md}");
+        assert!(md.contains("72/100 (Viable)"), "{md}");
+        assert!(md.contains("- Fire: 2\n"), "{md}");
+        assert!(
+            md.contains("- Day 3: Fire in Galley (severity 0.9)"),
+            "{md}"
+        );
+        assert!(md.contains("| Engineering | 4 | 3 | 50% |"), "{md}");
+        assert!(md.contains("| Food | 50 | 100 | 50% |"), "{md}");
+        assert!(md.contains("| 10 | 9 |"), "{md}");
+        assert!(md.contains("- Day 11: Arrival"), "{md}");
+
+        let lost = FinalReport {
+            outcome: voyage_outcomes::LOST,
+            loss_cause: loss_causes::LIFE_SUPPORT_COLLAPSE,
+            ..report
+        };
+        assert!(lost
+            .to_markdown()
+            .contains("**Outcome:** Ship Lost (Life Support Collapse), day 11"));
+    }
+}
@@ -0,0 +1,165 @@
+//! Food chain — crops grown in hydroponics, cooked into meals in the
+//! galleys and served in the mess halls.
+//!
+//! Hydroponics rooms hold crop beds ([`crop_beds`]) that ripen over a
+//! growth cycle ([`crop_growth`]) while lit, watered and tended by the
+//! growth chambers, wilting when they are not ([`crop_health`]). A ripe
+//! bed is harvested into the raw food stores and replanted. Cooks on duty
+//! in a galley turn raw food into meals ([`cook`]) held in the galley's
+//! meal buffer, and everyone eats from the buffer of the galley nearest
+//! their deck ([`serving_galley`]). Eating with the buffer empty fills
+//! no one ([`unfed_rates`]).
+
+use crate::balance::{NeedRates, IDLE_RATES};
+
+/// Hours from planting to harvest in ideal conditions.
+pub const CROP_CYCLE_HOURS: f32 = 40.0 * 24.0;
+
+/// Floor area of one crop bed in m².
+pub const CROP_BED_M2: f32 = 12.0;
+
+/// Raw food (kg) in a full-portion meal.
+pub const MEAL_RAW_FOOD: f32 = 2.0 / 3.0;
+
+/// Meals a person eats a day.
+pub const MEALS_PER_DAY: f32 = 3.0;
+
+/// Hours it takes to eat a meal.
+pub const MEAL_HOURS: f32 = 0.5;
+
+/// Meals one cook turns out per hour.
+pub const MEALS_PER_COOK_HOUR: f32 = 50.0;
+
+/// Meals a galley holds ready per person it feeds.
+pub const MEAL_BUFFER_PER_PERSON: f32 = 0.5;
+
+/// Crop health lost per hour without light or water.
+const WILT_PER_HOUR: f32 = 0.02;
+
+/// Crop health regained per hour in good conditions.
+const RECOVERY_PER_HOUR: f32 = 0.01;
+
+/// Crop beds that fit in a hydroponics room of `area` m²; at least one.
+pub fn crop_beds(area: f32) -> u32 {
+    ((area / CROP_BED_M2) as u32).max(1)
+}
+
+/// Growth (0–1, ripe at 1) of a bed after `hours` at `conditions`: the
+/// growth chambers' efficiency, zero without light or water.
+pub fn crop_growth(growth: f32, conditions: f32, hours: f32) -> f32 {
+    (growth + conditions.clamp(0.0, 1.0) * hours / CROP_CYCLE_HOURS).min(1.0)
+}
+
+/// Crop health after `hours`, wilting unless `thriving` (lit and watered).
+pub fn crop_health(health: f32, thriving: bool, hours: f32) -> f32 {
+    let drift = if thriving {
+        RECOVERY_PER_HOUR
+    } else {
+        -WILT_PER_HOUR
+    };
+    (health + drift * hours).clamp(0.0, 1.0)
+}
+
+/// Raw food harvested from a ripe bed planted for `expected_yield` kg.
+pub fn harvest_yield(expected_yield: f32, health: f32) -> f32 {
+    expected_yield * health.clamp(0.0, 1.0)
+}
+
+/// Meals a galley cooks in `hours` and the raw food they use: as many as
+/// its `cooks` can make, the `raw_food` stores allow at `portion` (1.0 =
+/// full rations) and fit in the buffer's free `room`.
+pub fn cook(cooks: u32, raw_food: f32, portion: f32, room: f32, hours: f32) -> (f32, f32) {
+    let per_meal = MEAL_RAW_FOOD * portion.max(0.0);
+    let labor = cooks as f32 * MEALS_PER_COOK_HOUR * hours;
+    let stocked = if per_meal > 0.0 {
+        raw_food.max(0.0) / per_meal
+    } else {
+        f32::MAX
+    };
+    let meals = labor.min(stocked).min(room.max(0.0));
+    (meals, meals * per_meal)
+}
+
+/// Cooks a galley feeding `diners` needs on duty to keep up.
+pub fn cooks_wanted(diners: u32) -> u32 {
+    let meals_per_hour = diners as f32 * MEALS_PER_DAY / 24.0;
+    ((meals_per_hour / MEALS_PER_COOK_HOUR).ceil() as u32).max(1)
+}
+
+/// The galley serving someone on `deck`: the one on the nearest deck,
+/// lowest ID first, from `(galley ID, deck)` pairs.
+pub fn serving_galley(deck: i32, galleys: &[(u32, i32)]) -> Option<u32> {
+    galleys
+        .iter()
+        .min_by_key(|&&(id, d)| ((d - deck).abs(), id))
+        .map(|&(id, _)| id)
+}
+
+/// Need rates of someone eating with no meal to eat: hunger is not
+/// relieved.
+pub fn unfed_rates(rates: NeedRates) -> NeedRates {
+    (
+        rates.0.max(IDLE_RATES.0),
+        rates.1,
+        rates.2,
+        rates.3,
+        rates.4,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crops_ripen_and_wilt() {
+        assert_eq!(crop_beds(5.0), 1);
+        assert_eq!(crop_beds(60.0), 5);
+        let half = crop_growth(0.0, 1.0, CROP_CYCLE_HOURS / 2.0);
+        assert!((half - 0.5).abs() < 1e-6);
+        assert_eq!(crop_growth(0.9, 1.0, CROP_CYCLE_HOURS), 1.0);
+        assert_eq!(crop_growth(0.3, 0.0, 100.0), 0.3);
+        assert!((crop_health(1.0, false, 10.0) - 0.8).abs() < 1e-6);
+        assert!((crop_health(0.5, true, 10.0) - 0.6).abs() < 1e-6);
+        assert_eq!(harvest_yield(100.0, 0.5), 50.0);
+    }
+
+    #[test]
+    fn test_cooking_limits() {
+        // Labor-bound
+        let (meals, raw) = cook(2, 1000.0, 1.0, 500.0, 1.0);
+        assert_eq!(meals, 100.0);
+        assert!((raw - 100.0 * MEAL_RAW_FOOD).abs() < 1e-3);
+        // Stores-bound, and rationed portions stretch them
+        let (meals, _) = cook(10, 10.0, 1.0, 500.0, 1.0);
+        assert!((meals - 15.0).abs() < 1e-3);
+        let (rationed, _) = cook(10, 10.0, 0.5, 500.0, 1.0);
+        assert!((rationed - 30.0).abs() < 1e-3);
+        // Buffer-bound, and nothing without cooks
+        assert_eq!(cook(10, 1000.0, 1.0, 20.0, 1.0).0, 20.0);
+        assert_eq!(cook(0, 1000.0, 1.0, 500.0, 1.0), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_cooks_wanted() {
+        assert_eq!(cooks_wanted(0), 1);
+        assert_eq!(cooks_wanted(400), 1);
+        // 1000 diners eat 125 meals an hour
+        assert_eq!(cooks_wanted(1000), 3);
+    }
+
+    #[test]
+    fn test_serving_galley_and_going_hungry() {
+        let galleys = [(7, 2), (3, 6), (5, 4)];
+        assert_eq!(serving_galley(1, &galleys), Some(7));
+        assert_eq!(serving_galley(6, &galleys), Some(3));
+        // Equally near: lowest ID
+        assert_eq!(serving_galley(5, &galleys), Some(3));
+        assert_eq!(serving_galley(0, &[]), None);
+
+        let eating = (-0.3, 0.01, -0.05, -0.02, 0.02);
+        let unfed = unfed_rates(eating);
+        assert_eq!(unfed.0, IDLE_RATES.0);
+        assert_eq!(unfed.2, eating.2);
+    }
+}
@@ -0,0 +1,123 @@
+//! Compact binary world frames for renderers outside SpacetimeDB.
+//!
+//! Re-exports the [`progship_frame`] wire format and builds frames from a
+//! [`StateSnapshot`], so anything that can take a snapshot can also feed a
+//! renderer. The server's `export_frame` reducer adds the ship's events and
+//! keeps the newest [`FRAME_EXPORT_KEEP`] frames, key frames and deltas
+//! alike, in its `frame_export` table.
+
+pub use progship_frame::*;
+
+use crate::snapshot::StateSnapshot;
+
+/// Frame exports kept on the server.
+pub const FRAME_EXPORT_KEEP: u32 = 64;
+
+/// A key frame of everyone and every room in `snapshot`. Snapshots carry no
+/// events or players, so the frame has none either.
+pub fn frame_from_snapshot(snapshot: &StateSnapshot) -> Frame {
+    let rooms = snapshot
+        .rooms
+        .iter()
+        .map(|r| FrameRoom {
+            id: r.id,
+            room_type: r.room_type,
+            deck: r.deck,
+            x: r.x,
+            y: r.y,
+            width: r.width,
+            height: r.height,
+        })
+        .collect();
+    let mut people: Vec<FramePerson> = snapshot
+        .people
+        .iter()
+        .map(|p| FramePerson {
+            id: p.id,
+            room_id: p.room_id,
+            x: quantize_position(p.x),
+            y: quantize_position(p.y),
+            activity_type: p.activity_type,
+            health: quantize_unit(p.health),
+            morale: quantize_unit(p.morale),
+            flags: if p.crew.is_some() {
+                person_flags::CREW
+            } else {
+                0
+            },
+        })
+        .collect();
+    people.sort_by_key(|p| p.id);
+    Frame {
+        sim_time: snapshot.sim_time,
+        rooms,
+        people,
+        events: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snapshot::{SnapshotPerson, SnapshotResources, SnapshotRoom, SNAPSHOT_VERSION};
+
+    #[test]
+    fn test_frame_from_snapshot() {
+        let person = |id: u64, x: f32| SnapshotPerson {
+            id,
+            given_name: "Ada".into(),
+            family_name: "Marsh".into(),
+            crew: None,
+            room_id: 3,
+            x,
+            y: 4.0,
+            needs: [0.0; 5],
+            health: 0.5,
+            morale: 1.0,
+            activity_type: 4,
+        };
+        let snapshot = StateSnapshot {
+            version: SNAPSHOT_VERSION,
+            ship_name: "Test".into(),
+            sim_time: 30.0,
+            deck_count: 2,
+            crew_count: 0,
+            passenger_count: 2,
+            seed: 1,
+            corridor_topology: 0,
+            hull_taper: 0,
+            scenario: String::new(),
+            difficulty: crate::constants::difficulties::NORMAL,
+            time_scale: 1.0,
+            death_count: 0,
+            resources: SnapshotResources::default(),
+            rooms: vec![SnapshotRoom {
+                id: 3,
+                name: "Mess".into(),
+                room_type: 20,
+                deck: 1,
+                x: 10.0,
+                y: 5.0,
+                width: 8.0,
+                height: 6.0,
+                capacity: 40,
+            }],
+            doors: Vec::new(),
+            people: vec![person(9, 11.04), person(2, 7.5)],
+            room_nicknames: Vec::new(),
+            deck_nicknames: Vec::new(),
+        };
+
+        let frame = frame_from_snapshot(&snapshot);
+        assert_eq!(frame.sim_time, 30.0);
+        assert_eq!(frame.rooms[0].deck, 1);
+        assert_eq!(
+            frame.people.iter().map(|p| p.id).collect::<Vec<_>>(),
+            [2, 9]
+        );
+        assert_eq!(position_meters(frame.people[1].x), 11.0);
+        assert_eq!(frame.people[0].health, 128);
+        assert_eq!(frame.people[0].flags & person_flags::CREW, 0);
+        assert_eq!(decode(&frame.encode()), Ok(Packet::Key(frame)));
+    }
+}
@@ -0,0 +1,106 @@
+//! Funerals — laying the dead to rest and the grief that follows.
+//!
+//! When someone dies their body is taken to the morgue and a funeral is
+//! held in the chapel [`FUNERAL_DELAY_HOURS`] later. Family, partners and
+//! anyone else whose bond to the deceased was strong enough ([`mourns`])
+//! grieve: their morale is held under a ceiling ([`grief_ceiling`]) that
+//! lifts over the following weeks, halving every
+//! [`GRIEF_HALF_LIFE_HOURS`], and sooner for those who said goodbye at the
+//! funeral.
+
+use crate::constants::relationship_types as rel;
+
+/// Hours from a death to the funeral.
+pub const FUNERAL_DELAY_HOURS: f64 = 48.0;
+
+/// How long a funeral lasts (hours).
+pub const FUNERAL_HOURS: f32 = 2.0;
+
+/// Weakest relationship strength that makes someone other than kin a
+/// mourner.
+pub const MOURNER_STRENGTH: f32 = 0.5;
+
+/// Hours for grief to ease by half.
+pub const GRIEF_HALF_LIFE_HOURS: f64 = 168.0;
+
+/// Hours after which grief has run its course: six weeks.
+pub const GRIEF_HOURS: f64 = 1008.0;
+
+/// Morale the deepest grief, for the closest kin, takes away.
+const GRIEF_DEPTH: f32 = 0.5;
+
+/// Share of grief left to those who attended the funeral.
+const FAREWELL_RELIEF: f32 = 0.6;
+
+/// Most morale all of someone's griefs together take away.
+const MAX_GRIEF: f32 = 0.6;
+
+/// Whether a `relationship_type` tie of `strength` to the deceased makes
+/// someone a mourner: kin on good terms, or anyone close enough.
+pub fn mourns(relationship_type: u8, strength: f32) -> bool {
+    (rel::is_kin(relationship_type) && strength > 0.0) || strength >= MOURNER_STRENGTH
+}
+
+/// Morale a mourner's grief takes away at first: deeper the stronger the
+/// bond, and deepest for kin.
+pub fn grief_depth(relationship_type: u8, strength: f32) -> f32 {
+    let kin = if rel::is_kin(relationship_type) {
+        1.0
+    } else {
+        0.7
+    };
+    GRIEF_DEPTH * strength.clamp(0.0, 1.0) * kin
+}
+
+/// Highest morale (0.4–1.0) someone can keep under their griefs, each
+/// given as (depth, hours since the death, attended the funeral).
+pub fn grief_ceiling(griefs: &[(f32, f64, bool)]) -> f32 {
+    let total: f32 = griefs
+        .iter()
+        .filter(|(_, hours, _)| *hours < GRIEF_HOURS)
+        .map(|&(depth, hours, attended)| {
+            let fading = 0.5f64.powf(hours.max(0.0) / GRIEF_HALF_LIFE_HOURS) as f32;
+            let relief = if attended { FAREWELL_RELIEF } else { 1.0 };
+            depth * fading * relief
+        })
+        .sum();
+    1.0 - total.min(MAX_GRIEF)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mourners() {
+        assert!(mourns(rel::FAMILY, 0.2));
+        assert!(mourns(rel::ROMANTIC, 0.8));
+        assert!(!mourns(rel::FAMILY, -0.3));
+        assert!(mourns(rel::CLOSE_FRIEND, MOURNER_STRENGTH));
+        assert!(!mourns(rel::COLLEAGUE, 0.3));
+        assert!(!mourns(rel::RIVAL, -0.8));
+    }
+
+    #[test]
+    fn test_grief_depth() {
+        assert_eq!(grief_depth(rel::FAMILY, 1.0), GRIEF_DEPTH);
+        assert!(grief_depth(rel::FRIEND, 1.0) < grief_depth(rel::FAMILY, 1.0));
+        assert!(grief_depth(rel::FAMILY, 0.4) < grief_depth(rel::FAMILY, 0.9));
+        assert_eq!(grief_depth(rel::FAMILY, -1.0), 0.0);
+    }
+
+    #[test]
+    fn test_grief_lifts_over_weeks() {
+        assert_eq!(grief_ceiling(&[]), 1.0);
+        let at = |hours| grief_ceiling(&[(0.5, hours, false)]);
+        assert!((at(0.0) - 0.5).abs() < 1e-6);
+        assert!((at(GRIEF_HALF_LIFE_HOURS) - 0.75).abs() < 1e-6);
+        assert!(at(GRIEF_HALF_LIFE_HOURS * 3.0) > 0.9);
+        assert_eq!(at(GRIEF_HOURS), 1.0);
+
+        // Saying goodbye helps; many losses don't sink morale below the floor
+        assert!(grief_ceiling(&[(0.5, 0.0, true)]) > at(0.0));
+        let losses = [(0.5, 0.0, false); 4];
+        assert!((grief_ceiling(&losses) - (1.0 - MAX_GRIEF)).abs() < 1e-6);
+    }
+}
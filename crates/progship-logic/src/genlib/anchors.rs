@@ -0,0 +1,248 @@
+//! Activity anchor points inside rooms.
+//!
+//! An anchor is the spot a person stands or sits at while doing something:
+//! a seat at a mess table, the serving line, a treadmill, the chair in front
+//! of a console. Most anchors hang off the room's furniture plan; a few
+//! (serving line, treadmills) are fixtures of the room type itself. Each
+//! anchor also decides the posture its user holds, which clients render.
+
+use super::furniture::FurniturePlacement;
+use crate::constants::{
+    activity_types as at, anchor_types as an, furniture_types as ft, postures, room_types as rt,
+};
+use serde::{Deserialize, Serialize};
+
+/// Distance from a piece's edge to the person using it, in meters.
+const STAND_OFF: f32 = 0.25;
+/// Distance from the wall to a wall-mounted fixture anchor, in meters.
+const FIXTURE_INSET: f32 = 0.5;
+/// Spacing between serving line positions and between treadmills, in meters.
+const FIXTURE_SPACING: f32 = 1.5;
+
+/// One interaction point, positioned relative to the room center.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnchorPoint {
+    /// Anchor type (see `anchor_types`).
+    pub anchor_type: u8,
+    /// Activity performed here (see `activity_types`).
+    pub activity_type: u8,
+    /// X offset from the room center, in meters.
+    pub x: f32,
+    /// Y offset from the room center, in meters.
+    pub y: f32,
+}
+
+fn anchor(anchor_type: u8, activity_type: u8, x: f32, y: f32) -> AnchorPoint {
+    AnchorPoint {
+        anchor_type,
+        activity_type,
+        x,
+        y,
+    }
+}
+
+/// Anchors for a single piece of furniture.
+fn piece_anchors(room_type: u8, p: &FurniturePlacement, out: &mut Vec<AnchorPoint>) {
+    let front = p.y + p.height / 2.0 + STAND_OFF;
+    let side = p.x + p.width / 2.0 + STAND_OFF;
+    match p.furniture_type {
+        ft::BED | ft::BUNK => out.push(anchor(an::BED, at::SLEEPING, p.x, p.y)),
+        ft::MEDICAL_BED if room_type == rt::SURGERY => {
+            out.push(anchor(an::OPERATING_TABLE, at::ON_DUTY, side, p.y))
+        }
+        ft::MEDICAL_BED => out.push(anchor(an::BED, at::SLEEPING, p.x, p.y)),
+        ft::TABLE => {
+            // Two seats along each of the long (north and south) sides
+            let (kind, activity) = if rt::is_dining(room_type) {
+                (an::DINING_SEAT, at::EATING)
+            } else {
+                (an::SEAT, at::SOCIALIZING)
+            };
+            for sy in [p.y - p.height / 2.0 - STAND_OFF, front] {
+                for sx in [p.x - p.width / 4.0, p.x + p.width / 4.0] {
+                    out.push(anchor(kind, activity, sx, sy));
+                }
+            }
+        }
+        ft::CONSOLE => out.push(anchor(an::CONSOLE, at::ON_DUTY, p.x, front)),
+        ft::WORKBENCH => out.push(anchor(an::WORKBENCH, at::ON_DUTY, p.x, front)),
+        ft::COUNTER => out.push(anchor(an::COUNTER, at::ON_DUTY, p.x, front)),
+        ft::HYDROPONIC_RACK => out.push(anchor(an::PLANTER, at::ON_DUTY, side, p.y)),
+        _ => {}
+    }
+}
+
+/// Evenly spaced positions along a wall segment of `length`, centered on 0.
+fn fixture_row(length: f32) -> impl Iterator<Item = f32> {
+    let count = ((length / FIXTURE_SPACING).floor() as usize).max(1);
+    let start = -(count as f32 - 1.0) * FIXTURE_SPACING / 2.0;
+    (0..count).map(move |i| start + i as f32 * FIXTURE_SPACING)
+}
+
+/// Anchors for a `width` × `height` room of the given type holding
+/// `furniture` (as planned by [`furnish_room`](super::furniture::furnish_room)).
+///
+/// Mess halls and cafes get a serving line along the west wall, in the clear
+/// margin the furniture plan leaves free; gyms get a row of treadmills along
+/// the north wall. Every anchor lies inside the room footprint.
+pub fn room_anchors(
+    room_type: u8,
+    width: f32,
+    height: f32,
+    furniture: &[FurniturePlacement],
+) -> Vec<AnchorPoint> {
+    let mut out = Vec::new();
+    if width < 2.0 * FIXTURE_INSET || height < 2.0 * FIXTURE_INSET {
+        return out;
+    }
+    match room_type {
+        rt::MESS_HALL | rt::CAFE => {
+            let x = -width / 2.0 + FIXTURE_INSET;
+            for y in fixture_row(height / 2.0) {
+                out.push(anchor(an::SERVING_LINE, at::EATING, x, y));
+            }
+        }
+        rt::GYM => {
+            let y = -height / 2.0 + 2.0 * FIXTURE_INSET;
+            for x in fixture_row(width - 2.0) {
+                out.push(anchor(an::TREADMILL, at::EXERCISING, x, y));
+            }
+        }
+        _ => {}
+    }
+    for piece in furniture {
+        piece_anchors(room_type, piece, &mut out);
+    }
+    out
+}
+
+/// Posture held while using an anchor of the given type.
+pub fn anchor_posture(anchor_type: u8) -> u8 {
+    match anchor_type {
+        an::BED => postures::LYING,
+        an::DINING_SEAT | an::SEAT | an::CONSOLE => postures::SITTING,
+        an::SERVING_LINE | an::OPERATING_TABLE | an::WORKBENCH | an::PLANTER | an::COUNTER => {
+            postures::WORKING
+        }
+        _ => postures::STANDING,
+    }
+}
+
+/// Posture for an activity, at an anchor if one is in use or otherwise
+/// wherever the person stopped.
+pub fn activity_posture(activity_type: u8, anchor_type: Option<u8>) -> u8 {
+    if let Some(anchor_type) = anchor_type {
+        return anchor_posture(anchor_type);
+    }
+    match activity_type {
+        at::SLEEPING => postures::LYING,
+        at::EATING | at::RELAXING => postures::SITTING,
+        at::WORKING | at::MAINTENANCE | at::ON_DUTY => postures::WORKING,
+        _ => postures::STANDING,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::furniture::furnish_room;
+    use super::*;
+
+    fn anchors(room_type: u8, w: f32, h: f32, cap: u32) -> Vec<AnchorPoint> {
+        room_anchors(room_type, w, h, &furnish_room(room_type, w, h, cap))
+    }
+
+    fn inside(a: &AnchorPoint, w: f32, h: f32) -> bool {
+        a.x.abs() <= w / 2.0 && a.y.abs() <= h / 2.0
+    }
+
+    #[test]
+    fn test_mess_hall_serving_line_and_seats() {
+        let list = anchors(rt::MESS_HALL, 30.0, 20.0, 16);
+        let serving = list
+            .iter()
+            .filter(|a| a.anchor_type == an::SERVING_LINE)
+            .count();
+        let seats = list
+            .iter()
+            .filter(|a| a.anchor_type == an::DINING_SEAT)
+            .count();
+        assert!(serving >= 2);
+        assert_eq!(seats, 16);
+        assert!(list.iter().all(|a| a.activity_type == at::EATING));
+        assert!(list.iter().all(|a| inside(a, 30.0, 20.0)));
+    }
+
+    #[test]
+    fn test_anchors_do_not_overlap() {
+        for (room_type, w, h, cap) in [
+            (rt::MESS_HALL, 30.0, 20.0, 64),
+            (rt::CAFE, 12.0, 10.0, 12),
+            (rt::BRIDGE, 12.0, 10.0, 20),
+            (rt::HYDROPONICS, 20.0, 15.0, 10),
+        ] {
+            let list = anchors(room_type, w, h, cap);
+            for (i, a) in list.iter().enumerate() {
+                assert!(inside(a, w, h), "room type {} anchor {:?}", room_type, a);
+                for b in &list[i + 1..] {
+                    let d = ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt();
+                    assert!(d > 0.4, "{:?} too close to {:?}", a, b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_stations_and_beds() {
+        let bridge = anchors(rt::BRIDGE, 12.0, 10.0, 4);
+        assert_eq!(bridge.len(), 4);
+        assert!(bridge
+            .iter()
+            .all(|a| a.anchor_type == an::CONSOLE && a.activity_type == at::ON_DUTY));
+
+        let cabin = anchors(rt::CABIN_DOUBLE, 5.0, 5.0, 2);
+        assert_eq!(cabin.len(), 2);
+        assert!(cabin.iter().all(|a| a.activity_type == at::SLEEPING));
+
+        let surgery = anchors(rt::SURGERY, 8.0, 6.0, 1);
+        assert_eq!(surgery[0].anchor_type, an::OPERATING_TABLE);
+    }
+
+    #[test]
+    fn test_gym_treadmills() {
+        let gym = anchors(rt::GYM, 12.0, 8.0, 20);
+        assert_eq!(gym.len(), 6);
+        assert!(gym
+            .iter()
+            .all(|a| a.anchor_type == an::TREADMILL && a.activity_type == at::EXERCISING));
+        assert!(anchors(rt::CORRIDOR, 40.0, 4.0, 0).is_empty());
+    }
+
+    #[test]
+    fn test_postures() {
+        assert_eq!(activity_posture(at::SLEEPING, None), postures::LYING);
+        assert_eq!(
+            activity_posture(at::SLEEPING, Some(an::BED)),
+            postures::LYING
+        );
+        assert_eq!(
+            activity_posture(at::EATING, Some(an::SERVING_LINE)),
+            postures::WORKING
+        );
+        assert_eq!(
+            activity_posture(at::ON_DUTY, Some(an::CONSOLE)),
+            postures::SITTING
+        );
+        assert_eq!(
+            activity_posture(at::EXERCISING, Some(an::TREADMILL)),
+            postures::STANDING
+        );
+        assert_eq!(activity_posture(at::TRAVELING, None), postures::STANDING);
+        // Every anchor a room offers maps to a posture consistent with its activity
+        for a in anchors(rt::MESS_HALL, 30.0, 20.0, 16) {
+            assert_ne!(
+                activity_posture(a.activity_type, Some(a.anchor_type)),
+                postures::LYING
+            );
+        }
+    }
+}
@@ -0,0 +1,109 @@
+//! Maintenance crawlways — narrow conduit runs behind the rooms.
+//!
+//! Rooms between the ring corridor and the spine open onto one or the other,
+//! so their back walls meet midway across the strip. A one-metre crawlway
+//! runs down that seam carrying power, HVAC and coolant, giving engineers a
+//! physical route to the conduits that doesn't cut through anyone's cabin.
+//! Cross-corridors, spurs and large rooms cut each crawlway into runs (see
+//! [`crawlway_runs`]), hatched into the corridors at their ends.
+
+use crate::constants::room_types;
+
+/// Width of a crawlway (m).
+pub const CRAWLWAY_WIDTH: usize = 1;
+/// Shortest crawlway run worth keeping (m).
+pub const MIN_CRAWLWAY_RUN: usize = 2;
+/// Walking speed inside a crawlway relative to a corridor.
+pub const CRAWLWAY_SPEED_FACTOR: f32 = 0.4;
+
+/// Column of the crawlway behind the rooms of a strip spanning `x0..x1`, if
+/// the strip leaves `min_room_dim` of room depth on both sides of it.
+pub fn crawlway_column(x0: usize, x1: usize, min_room_dim: usize) -> Option<usize> {
+    (x1.saturating_sub(x0) >= 2 * min_room_dim + CRAWLWAY_WIDTH)
+        .then(|| x0 + (x1 - x0 - CRAWLWAY_WIDTH) / 2)
+}
+
+/// Maximal runs `[start, end)` within `y0..y1` where `free(y)` holds, at
+/// least [`MIN_CRAWLWAY_RUN`] long.
+pub fn crawlway_runs(y0: usize, y1: usize, free: impl Fn(usize) -> bool) -> Vec<(usize, usize)> {
+    let mut runs = Vec::new();
+    let mut start = None;
+    for y in y0..=y1 {
+        match (start, y < y1 && free(y)) {
+            (None, true) => start = Some(y),
+            (Some(s), false) => {
+                if y - s >= MIN_CRAWLWAY_RUN {
+                    runs.push((s, y));
+                }
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    runs
+}
+
+/// Split `x0..x1` into the ranges between crawlway `columns`.
+pub fn split_around(x0: usize, x1: usize, columns: &[usize]) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut start = x0;
+    let mut cols: Vec<usize> = columns
+        .iter()
+        .copied()
+        .filter(|&c| c >= x0 && c < x1)
+        .collect();
+    cols.sort_unstable();
+    for c in cols {
+        if c > start {
+            ranges.push((start, c));
+        }
+        start = c + CRAWLWAY_WIDTH;
+    }
+    if x1 > start {
+        ranges.push((start, x1));
+    }
+    ranges
+}
+
+/// Walking speed multiplier inside a room of `room_type`.
+pub fn walk_speed_factor(room_type: u8) -> f32 {
+    if room_type == room_types::CRAWLWAY {
+        CRAWLWAY_SPEED_FACTOR
+    } else {
+        1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crawlway_column_needs_rooms_both_sides() {
+        assert_eq!(crawlway_column(4, 24, 4), Some(13));
+        assert_eq!(crawlway_column(4, 13, 4), Some(8));
+        assert_eq!(crawlway_column(4, 12, 4), None);
+    }
+
+    #[test]
+    fn test_crawlway_runs_split_at_obstacles() {
+        let blocked = [10, 11, 12, 20];
+        let runs = crawlway_runs(5, 25, |y| !blocked.contains(&y));
+        assert_eq!(runs, vec![(5, 10), (13, 20), (21, 25)]);
+        // A single free cell is too short for a run
+        assert!(crawlway_runs(0, 5, |y| y == 2).is_empty());
+    }
+
+    #[test]
+    fn test_split_around_columns() {
+        assert_eq!(split_around(4, 24, &[13]), vec![(4, 13), (14, 24)]);
+        assert_eq!(split_around(4, 24, &[30]), vec![(4, 24)]);
+        assert_eq!(split_around(4, 24, &[]), vec![(4, 24)]);
+    }
+
+    #[test]
+    fn test_crawlways_are_slower() {
+        assert!(walk_speed_factor(room_types::CRAWLWAY) < 1.0);
+        assert_eq!(walk_speed_factor(room_types::CORRIDOR), 1.0);
+    }
+}
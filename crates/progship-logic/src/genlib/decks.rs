@@ -0,0 +1,204 @@
+//! Deck naming and zone theming.
+//!
+//! Turns the rooms placed on each deck into a theme: a display name such as
+//! "Deck 4 — Habitation Ring B", the dominant facility zone, a gravity level
+//! and ambient lighting hints with a day/night schedule, plus the atmosphere
+//! setpoints for that zone.
+
+use super::facilities::deck_range_for_zone;
+use crate::lighting::zone_light_schedule;
+use serde::{Deserialize, Serialize};
+
+/// Number of facility zones (0=command … 6=engineering).
+pub const ZONE_COUNT: usize = 7;
+
+/// Theme title per zone, used in deck names.
+const ZONE_TITLES: [&str; ZONE_COUNT] = [
+    "Command Deck",
+    "Habitation Ring",
+    "Services Concourse",
+    "Recreation Promenade",
+    "Life Support Level",
+    "Cargo Hold",
+    "Engineering Section",
+];
+
+/// Theme of one deck, derived from its zone composition.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeckTheme {
+    /// Display name, e.g. "Deck 4 — Habitation Ring B".
+    pub name: String,
+    /// Zone with the most rooms on this deck.
+    pub primary_zone: u8,
+    /// Rooms per zone on this deck, indexed by zone.
+    pub zone_rooms: [u32; ZONE_COUNT],
+    /// Artificial gravity in g.
+    pub gravity: f32,
+    /// Ambient light level (0.0–1.0).
+    pub light_level: f32,
+    /// Ambient light color temperature in kelvin.
+    pub light_kelvin: u32,
+    /// Shift whose day the deck's lights follow (see [`zone_light_schedule`]).
+    pub light_schedule: u8,
+}
+
+/// Nominal atmosphere setpoints for a deck zone.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ZoneAtmosphere {
+    /// Temperature in degrees Celsius.
+    pub temperature: f32,
+    /// Relative humidity (0.0–1.0).
+    pub humidity: f32,
+}
+
+/// Atmosphere setpoints for a zone: hydroponics-heavy life support decks run
+/// warm and humid, engineering warm and dry, cargo holds cool.
+pub fn zone_atmosphere(zone: u8) -> ZoneAtmosphere {
+    let (temperature, humidity) = match zone {
+        4 => (24.0, 0.6),
+        5 => (16.0, 0.35),
+        6 => (26.0, 0.35),
+        _ => (22.0, 0.45),
+    };
+    ZoneAtmosphere {
+        temperature,
+        humidity,
+    }
+}
+
+/// Gravity and lighting hints `(gravity, light_level, light_kelvin)` for a zone.
+fn zone_ambience(zone: u8) -> (f32, f32, u32) {
+    match zone {
+        0 => (1.0, 0.8, 5000),
+        1 => (1.0, 0.7, 3200),
+        2 => (1.0, 0.9, 4500),
+        3 => (1.0, 1.0, 4000),
+        4 => (1.0, 1.0, 6500),
+        5 => (0.6, 0.5, 5000),
+        _ => (0.8, 0.6, 4000),
+    }
+}
+
+/// Zone with the most rooms; ties go to the lower zone. A deck with no rooms
+/// takes the zone [`deck_range_for_zone`] assigns it.
+fn primary_zone(zone_rooms: &[u32; ZONE_COUNT], deck: u32, deck_count: u32) -> u8 {
+    let (best, &count) = zone_rooms
+        .iter()
+        .enumerate()
+        .rev()
+        .max_by_key(|&(_, c)| c)
+        .unwrap();
+    if count > 0 {
+        return best as u8;
+    }
+    (0..ZONE_COUNT as u8)
+        .find(|&z| {
+            let (lo, hi) = deck_range_for_zone(z, deck_count);
+            (lo..hi).contains(&deck)
+        })
+        .unwrap_or(1)
+}
+
+/// Theme every deck from its per-zone room counts (index = deck number).
+///
+/// Decks are numbered from 1 in names. When several decks share a primary
+/// zone they get letter suffixes in deck order ("Habitation Ring A", "B", …).
+pub fn deck_themes(zone_rooms: &[[u32; ZONE_COUNT]]) -> Vec<DeckTheme> {
+    let deck_count = zone_rooms.len() as u32;
+    let zones: Vec<u8> = zone_rooms
+        .iter()
+        .enumerate()
+        .map(|(d, rooms)| primary_zone(rooms, d as u32, deck_count))
+        .collect();
+    let mut totals = [0u32; ZONE_COUNT];
+    for &z in &zones {
+        totals[z as usize] += 1;
+    }
+
+    let mut seen = [0u32; ZONE_COUNT];
+    zones
+        .iter()
+        .zip(zone_rooms)
+        .enumerate()
+        .map(|(d, (&zone, rooms))| {
+            let z = zone as usize;
+            let mut title = ZONE_TITLES[z].to_string();
+            if totals[z] > 1 {
+                let letter = (b'A' + (seen[z] % 26) as u8) as char;
+                title = format!("{} {}", title, letter);
+            }
+            seen[z] += 1;
+            let (gravity, light_level, light_kelvin) = zone_ambience(zone);
+            DeckTheme {
+                name: format!("Deck {} — {}", d + 1, title),
+                primary_zone: zone,
+                zone_rooms: *rooms,
+                gravity,
+                light_level,
+                light_kelvin,
+                light_schedule: zone_light_schedule(zone),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rooms(zone: usize, count: u32) -> [u32; ZONE_COUNT] {
+        let mut r = [0; ZONE_COUNT];
+        r[zone] = count;
+        r
+    }
+
+    #[test]
+    fn test_deck_names_letter_shared_zones() {
+        let themes = deck_themes(&[rooms(0, 5), rooms(1, 40), rooms(1, 38), rooms(6, 9)]);
+        let names: Vec<&str> = themes.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "Deck 1 — Command Deck",
+                "Deck 2 — Habitation Ring A",
+                "Deck 3 — Habitation Ring B",
+                "Deck 4 — Engineering Section",
+            ]
+        );
+        assert_eq!(themes[3].primary_zone, 6);
+    }
+
+    #[test]
+    fn test_primary_zone_is_majority() {
+        let mut mixed = rooms(1, 10);
+        mixed[2] = 12;
+        let themes = deck_themes(&[mixed]);
+        assert_eq!(themes[0].primary_zone, 2);
+        assert_eq!(themes[0].zone_rooms, mixed);
+
+        // Ties go to the lower zone
+        let mut tie = rooms(3, 4);
+        tie[1] = 4;
+        assert_eq!(deck_themes(&[tie])[0].primary_zone, 1);
+    }
+
+    #[test]
+    fn test_empty_deck_uses_zone_range() {
+        let themes = deck_themes(&[[0; ZONE_COUNT]; 20]);
+        assert_eq!(themes[0].primary_zone, 0);
+        assert_eq!(themes[19].primary_zone, 6);
+    }
+
+    #[test]
+    fn test_zone_ambience_and_atmosphere() {
+        let themes = deck_themes(&[rooms(1, 1), rooms(5, 1)]);
+        assert!(themes[0].light_kelvin < themes[1].light_kelvin);
+        assert!(themes[1].gravity < themes[0].gravity);
+        let engineering = deck_themes(&[rooms(6, 1)]);
+        assert_ne!(engineering[0].light_schedule, themes[0].light_schedule);
+
+        assert_eq!(zone_atmosphere(1).temperature, 22.0);
+        assert!(zone_atmosphere(4).humidity > zone_atmosphere(1).humidity);
+        assert!(zone_atmosphere(6).temperature > zone_atmosphere(5).temperature);
+    }
+}
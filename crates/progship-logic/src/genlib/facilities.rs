@@ -7,13 +7,13 @@
 //! `include_str!()`. To add or modify room types, edit the JSON file — no code
 //! changes required.
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Facility manifest entry — describes one kind of room to instantiate.
 ///
 /// Deserialized from `data/facility_manifest.json`.
-#[derive(Debug, Clone, Deserialize)]
-pub(super) struct FacilitySpec {
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FacilitySpec {
     pub name: String,
     pub room_type: u8,
     pub target_area: f32,
@@ -27,7 +27,7 @@ pub(super) struct FacilitySpec {
 
 /// Deck-zone → deck range mapping.
 /// Proportionally distributes zones across the available deck count.
-pub(super) fn deck_range_for_zone(zone: u8, deck_count: u32) -> (u32, u32) {
+pub fn deck_range_for_zone(zone: u8, deck_count: u32) -> (u32, u32) {
     let dc = deck_count;
     // Zone weights: how many "slices" each zone ideally occupies (out of 20)
     // 0=command(2), 1=hab(8), 2=services(2), 3=rec(2), 4=lifesup(3), 5=cargo(1), 6=eng(2)
@@ -59,10 +59,125 @@ pub(super) fn deck_range_for_zone(zone: u8, deck_count: u32) -> (u32, u32) {
     }
 }
 
+/// Decks `first_deck..end_deck` given to a zone, either pinned by the ship
+/// designer or recorded from the layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ZoneDecks {
+    pub zone: u8,
+    pub first_deck: u32,
+    pub end_deck: u32,
+}
+
+/// Demand-driven zone of each deck, outside-in: command on top, engineering
+/// at the bottom with cargo above it, life support below command, and
+/// recreation then services in the middle of the habitation decks.
+/// `zone_decks_needed[z]` is how many decks zone `z`'s rooms fill.
+pub fn assign_deck_zones(zone_decks_needed: &[u32; 7], deck_count: u32) -> Vec<u8> {
+    let mut deck_zone_map: Vec<u8> = vec![1; deck_count as usize];
+    let dc = deck_count as usize;
+
+    // Top: COMMAND
+    let cmd_decks = zone_decks_needed[0].min(deck_count) as usize;
+    for zone in deck_zone_map.iter_mut().take(cmd_decks) {
+        *zone = 0;
+    }
+    // Bottom: ENGINEERING
+    let eng_decks = zone_decks_needed[6].min(deck_count) as usize;
+    for d in 0..eng_decks {
+        let idx = dc - 1 - d;
+        if deck_zone_map[idx] == 1 {
+            deck_zone_map[idx] = 6;
+        }
+    }
+    // Above engineering: CARGO
+    let cargo_start = dc.saturating_sub(eng_decks);
+    let cargo_decks = zone_decks_needed[5].min(deck_count) as usize;
+    for d in 0..cargo_decks {
+        let idx = cargo_start.saturating_sub(1 + d);
+        if idx < dc && deck_zone_map[idx] == 1 {
+            deck_zone_map[idx] = 5;
+        }
+    }
+    // Below command: LIFE_SUPPORT
+    let life_start = cmd_decks;
+    let life_decks = zone_decks_needed[4].min(deck_count) as usize;
+    for d in 0..life_decks {
+        let idx = life_start + d;
+        if idx < dc && deck_zone_map[idx] == 1 {
+            deck_zone_map[idx] = 4;
+        }
+    }
+    // REC and SVC in middle of remaining HAB slots
+    let hab_slots: Vec<usize> = (0..dc).filter(|&d| deck_zone_map[d] == 1).collect();
+    if !hab_slots.is_empty() {
+        let rec_decks = zone_decks_needed[3].min(hab_slots.len() as u32) as usize;
+        for d in 0..rec_decks {
+            let idx_pos = (hab_slots.len() / 2 + d).min(hab_slots.len() - 1);
+            let idx = hab_slots[idx_pos];
+            deck_zone_map[idx] = 3;
+        }
+        let svc_decks = zone_decks_needed[2].min(hab_slots.len() as u32) as usize;
+        let hab_slots2: Vec<usize> = (0..dc).filter(|&d| deck_zone_map[d] == 1).collect();
+        for d in 0..svc_decks {
+            if let Some(&idx) = hab_slots2.get(hab_slots2.len() / 2 + d) {
+                deck_zone_map[idx] = 2;
+            }
+        }
+    }
+    deck_zone_map
+}
+
+/// [`assign_deck_zones`] with `overrides` pinning zones to deck ranges. The
+/// other zones are assigned over the remaining decks as if those were the
+/// whole ship; decks no zone claims hold habitation.
+pub fn deck_zones_with_overrides(
+    zone_decks_needed: &[u32; 7],
+    deck_count: u32,
+    overrides: &[ZoneDecks],
+) -> Vec<u8> {
+    let pinned = |d: u32| {
+        overrides
+            .iter()
+            .any(|o| (o.first_deck..o.end_deck).contains(&d))
+    };
+    let mut needed = *zone_decks_needed;
+    for o in overrides {
+        needed[o.zone.min(6) as usize] = 0;
+    }
+    let free: Vec<u32> = (0..deck_count).filter(|&d| !pinned(d)).collect();
+    let free_zones = assign_deck_zones(&needed, free.len() as u32);
+
+    let mut deck_zone_map = vec![1; deck_count as usize];
+    for (&d, &zone) in free.iter().zip(&free_zones) {
+        deck_zone_map[d as usize] = zone;
+    }
+    for o in overrides {
+        for d in o.first_deck..o.end_deck.min(deck_count) {
+            deck_zone_map[d as usize] = o.zone.min(6);
+        }
+    }
+    deck_zone_map
+}
+
+/// The deck range each zone spans in `deck_zone_map`, for zones with decks.
+pub fn zone_deck_ranges(deck_zone_map: &[u8]) -> Vec<ZoneDecks> {
+    (0..7u8)
+        .filter_map(|zone| {
+            let first = deck_zone_map.iter().position(|&z| z == zone)?;
+            let last = deck_zone_map.iter().rposition(|&z| z == zone)?;
+            Some(ZoneDecks {
+                zone,
+                first_deck: first as u32,
+                end_deck: last as u32 + 1,
+            })
+        })
+        .collect()
+}
+
 /// Returns the complete facility manifest for ship generation.
 ///
 /// Loaded from `data/facility_manifest.json` embedded at compile time.
-pub(super) fn get_facility_manifest() -> Vec<FacilitySpec> {
+pub fn get_facility_manifest() -> Vec<FacilitySpec> {
     const MANIFEST_JSON: &str = include_str!("../../../../data/facility_manifest.json");
     serde_json::from_str(MANIFEST_JSON).expect("facility_manifest.json is invalid")
 }
@@ -70,7 +185,43 @@ pub(super) fn get_facility_manifest() -> Vec<FacilitySpec> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tables::{groups, room_types};
+    use crate::constants::{groups, room_types};
+
+    #[test]
+    fn test_assign_deck_zones_outside_in() {
+        let zones = assign_deck_zones(&[1, 3, 1, 1, 1, 1, 1], 10);
+        assert_eq!(zones[0], 0);
+        assert_eq!(zones[1], 4);
+        assert_eq!(zones[8], 5);
+        assert_eq!(zones[9], 6);
+        assert_eq!(zones.iter().filter(|&&z| z == 3).count(), 1);
+        assert_eq!(zones.iter().filter(|&&z| z == 2).count(), 1);
+    }
+
+    #[test]
+    fn test_overrides_pin_zones_and_others_fill_the_rest() {
+        // Engineering up top on a 10-deck ship
+        let eng_top = ZoneDecks {
+            zone: 6,
+            first_deck: 0,
+            end_deck: 2,
+        };
+        let zones = deck_zones_with_overrides(&[1, 3, 1, 1, 1, 1, 1], 10, &[eng_top]);
+        assert_eq!(&zones[..2], &[6, 6]);
+        assert!(!zones[2..].contains(&6));
+        // Command takes the top of the decks left over
+        assert_eq!(zones[2], 0);
+        assert_eq!(
+            zone_deck_ranges(&zones).into_iter().find(|r| r.zone == 6),
+            Some(eng_top)
+        );
+        // No overrides: same as the demand-driven assignment
+        let needed = [1, 3, 1, 1, 1, 1, 1];
+        assert_eq!(
+            deck_zones_with_overrides(&needed, 10, &[]),
+            assign_deck_zones(&needed, 10)
+        );
+    }
 
     #[test]
     fn test_facility_manifest_not_empty() {
@@ -209,7 +360,8 @@ mod tests {
         let deck_count = 20;
         let (start, end) = deck_range_for_zone(6, deck_count); // Engineering zone
 
-        assert!(start >= 19, "Engineering zone should be in lower decks");
+        // Engineering weight is 2 of 20 slices → last 2 decks
+        assert!(start >= 18, "Engineering zone should be in lower decks");
         assert_eq!(end, deck_count, "Engineering zone should extend to bottom");
     }
 
@@ -218,7 +370,7 @@ mod tests {
         let manifest = get_facility_manifest();
 
         // Verify all deck_zone values are used
-        let mut zones_used = vec![false; 7];
+        let mut zones_used = [false; 7];
         for spec in &manifest {
             if spec.deck_zone < 7 {
                 zones_used[spec.deck_zone as usize] = true;
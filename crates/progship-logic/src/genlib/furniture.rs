@@ -0,0 +1,171 @@
+//! Furniture and prop placement inside rooms.
+//!
+//! Chooses what a room holds from its type and capacity (beds for cabins,
+//! tables for dining halls, consoles for command stations, racks for
+//! hydroponics) and packs the pieces in rows inside the room footprint,
+//! keeping a clear margin along the walls and an aisle between rows.
+
+use crate::constants::{furniture_types as ft, room_types as rt};
+use serde::{Deserialize, Serialize};
+
+/// Clear floor kept along every wall, in meters.
+const WALL_MARGIN: f32 = 1.0;
+/// Gap between neighboring pieces in a row, in meters.
+const PIECE_GAP: f32 = 0.5;
+/// Aisle between rows, in meters.
+const AISLE: f32 = 1.0;
+
+/// One piece of furniture, positioned relative to the room center.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FurniturePlacement {
+    /// Furniture type (see `furniture_types`).
+    pub furniture_type: u8,
+    /// Center X offset from the room center, in meters.
+    pub x: f32,
+    /// Center Y offset from the room center, in meters.
+    pub y: f32,
+    /// Footprint along X, in meters.
+    pub width: f32,
+    /// Footprint along Y, in meters.
+    pub height: f32,
+}
+
+/// Furniture type, footprint `(width, height)` and people served per piece.
+fn furniture_kind(room_type: u8) -> Option<(u8, (f32, f32), u32)> {
+    let kind = match room_type {
+        rt::QUARTERS_CREW | rt::QUARTERS_PASSENGER => (ft::BUNK, (1.0, 2.0), 2),
+        r if rt::is_quarters(r) => (ft::BED, (1.0, 2.0), 1),
+        rt::HOSPITAL_WARD | rt::MEDBAY | rt::QUARANTINE | rt::SURGERY => {
+            (ft::MEDICAL_BED, (1.0, 2.2), 1)
+        }
+        rt::CRYO_BAY => (ft::CRYO_POD, (1.0, 2.2), 1),
+        rt::MESS_HALL | rt::WARDROOM | rt::CAFE | rt::BAR | rt::CONFERENCE | rt::LIBRARY => {
+            (ft::TABLE, (1.5, 1.5), 4)
+        }
+        rt::BRIDGE | rt::CIC | rt::COMMS_ROOM | rt::ENGINEERING | rt::ENV_MONITORING => {
+            (ft::CONSOLE, (1.5, 0.8), 1)
+        }
+        rt::MACHINE_SHOP | rt::ELECTRONICS_LAB | rt::ROBOTICS_BAY | rt::LABORATORY => {
+            (ft::WORKBENCH, (2.0, 1.0), 2)
+        }
+        rt::HYDROPONICS => (ft::HYDROPONIC_RACK, (1.2, 4.0), 2),
+        rt::STORAGE
+        | rt::PARTS_STORAGE
+        | rt::FOOD_STORAGE_DRY
+        | rt::FOOD_STORAGE_COLD
+        | rt::CARGO_BAY => (ft::STORAGE_RACK, (1.0, 3.0), 1),
+        rt::GALLEY | rt::BAKERY => (ft::COUNTER, (2.5, 0.8), 2),
+        _ => return None,
+    };
+    Some(kind)
+}
+
+/// Number of pieces wanted for a room. Racks fill the floor; everything else
+/// scales with capacity.
+fn wanted_pieces(furniture_type: u8, capacity: u32, per_piece: u32) -> usize {
+    match furniture_type {
+        ft::HYDROPONIC_RACK | ft::STORAGE_RACK => usize::MAX,
+        _ => capacity.max(1).div_ceil(per_piece) as usize,
+    }
+}
+
+/// Furnish a `width` × `height` room of the given type and capacity.
+///
+/// Pieces are packed row by row from the room's low-X, low-Y corner and stop
+/// when the room is full, so small rooms may hold fewer pieces than their
+/// capacity asks for. Corridors, shafts and unlisted room types stay empty.
+pub fn furnish_room(
+    room_type: u8,
+    width: f32,
+    height: f32,
+    capacity: u32,
+) -> Vec<FurniturePlacement> {
+    let Some((furniture_type, (pw, ph), per_piece)) = furniture_kind(room_type) else {
+        return Vec::new();
+    };
+    let usable_w = width - 2.0 * WALL_MARGIN;
+    let usable_h = height - 2.0 * WALL_MARGIN;
+    if usable_w < pw || usable_h < ph {
+        return Vec::new();
+    }
+
+    let per_row = ((usable_w + PIECE_GAP) / (pw + PIECE_GAP)).floor() as usize;
+    let rows = ((usable_h + AISLE) / (ph + AISLE)).floor() as usize;
+    let count = wanted_pieces(furniture_type, capacity, per_piece).min(per_row * rows);
+
+    let x0 = -width / 2.0 + WALL_MARGIN + pw / 2.0;
+    let y0 = -height / 2.0 + WALL_MARGIN + ph / 2.0;
+    (0..count)
+        .map(|i| FurniturePlacement {
+            furniture_type,
+            x: x0 + (i % per_row) as f32 * (pw + PIECE_GAP),
+            y: y0 + (i / per_row) as f32 * (ph + AISLE),
+            width: pw,
+            height: ph,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inside(p: &FurniturePlacement, width: f32, height: f32) -> bool {
+        p.x - p.width / 2.0 >= -width / 2.0 + WALL_MARGIN - 1e-4
+            && p.x + p.width / 2.0 <= width / 2.0 - WALL_MARGIN + 1e-4
+            && p.y - p.height / 2.0 >= -height / 2.0 + WALL_MARGIN - 1e-4
+            && p.y + p.height / 2.0 <= height / 2.0 - WALL_MARGIN + 1e-4
+    }
+
+    fn overlaps(a: &FurniturePlacement, b: &FurniturePlacement) -> bool {
+        (a.x - b.x).abs() < (a.width + b.width) / 2.0 - 1e-4
+            && (a.y - b.y).abs() < (a.height + b.height) / 2.0 - 1e-4
+    }
+
+    #[test]
+    fn test_cabins_get_a_bed_per_occupant() {
+        let beds = furnish_room(rt::CABIN_DOUBLE, 5.0, 5.0, 2);
+        assert_eq!(beds.len(), 2);
+        assert!(beds.iter().all(|b| b.furniture_type == ft::BED));
+
+        let bunks = furnish_room(rt::QUARTERS_CREW, 8.0, 6.0, 4);
+        assert_eq!(bunks.len(), 2);
+        assert!(bunks.iter().all(|b| b.furniture_type == ft::BUNK));
+    }
+
+    #[test]
+    fn test_mess_hall_tables_scale_with_capacity() {
+        let small = furnish_room(rt::MESS_HALL, 30.0, 20.0, 16);
+        let large = furnish_room(rt::MESS_HALL, 30.0, 20.0, 64);
+        assert_eq!(small.len(), 4);
+        assert_eq!(large.len(), 16);
+        assert!(small.iter().all(|t| t.furniture_type == ft::TABLE));
+    }
+
+    #[test]
+    fn test_pieces_fit_without_overlap() {
+        for (room_type, w, h, cap) in [
+            (rt::HYDROPONICS, 20.0, 15.0, 10),
+            (rt::BRIDGE, 12.0, 10.0, 20),
+            (rt::QUARTERS_PASSENGER, 7.0, 5.0, 40),
+            (rt::CARGO_BAY, 25.0, 20.0, 0),
+        ] {
+            let pieces = furnish_room(room_type, w, h, cap);
+            assert!(!pieces.is_empty(), "room type {}", room_type);
+            for (i, a) in pieces.iter().enumerate() {
+                assert!(inside(a, w, h), "room type {} piece {:?}", room_type, a);
+                for b in &pieces[i + 1..] {
+                    assert!(!overlaps(a, b), "{:?} overlaps {:?}", a, b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_unfurnished_rooms() {
+        assert!(furnish_room(rt::CORRIDOR, 40.0, 4.0, 0).is_empty());
+        assert!(furnish_room(rt::ELEVATOR_SHAFT, 4.0, 4.0, 0).is_empty());
+        // Too small for even one bed
+        assert!(furnish_room(rt::CABIN_SINGLE, 2.5, 3.0, 1).is_empty());
+    }
+}
@@ -0,0 +1,316 @@
+//! Facility graph construction for spatial and infrastructure connectivity.
+//!
+//! Expands the facility manifest into one node per room instance (scaled to
+//! the population) and links them with crew paths plus power, water, HVAC
+//! and data networks. Node indices double as IDs for edges.
+
+use super::facilities::FacilitySpec;
+use crate::constants::{edge_types, room_types};
+use serde::{Deserialize, Serialize};
+
+/// One room instance in the facility graph.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FacilityNode {
+    pub name: String,
+    pub room_type: u8,
+    pub target_area: f32,
+    pub capacity: u32,
+    /// Deck zone (0=command … 6=engineering), see [`super::facilities::deck_range_for_zone`].
+    pub deck_zone: u8,
+    pub group: u8,
+}
+
+/// A link between two nodes, by index into [`FacilityGraph::nodes`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FacilityEdge {
+    pub from: usize,
+    pub to: usize,
+    pub edge_type: u8,
+    pub weight: f32,
+    pub bidirectional: bool,
+}
+
+/// Facility nodes and the edges that connect them.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FacilityGraph {
+    pub nodes: Vec<FacilityNode>,
+    pub edges: Vec<FacilityEdge>,
+}
+
+impl FacilityGraph {
+    fn link(&mut self, from: usize, to: usize, edge_type: u8, weight: f32, bidirectional: bool) {
+        self.edges.push(FacilityEdge {
+            from,
+            to,
+            edge_type,
+            weight,
+            bidirectional,
+        });
+    }
+
+    fn find_by_type(&self, room_type: u8) -> Option<usize> {
+        self.nodes.iter().position(|n| n.room_type == room_type)
+    }
+
+    fn zone_nodes(&self, zone: u8) -> Vec<usize> {
+        (0..self.nodes.len())
+            .filter(|&i| self.nodes[i].deck_zone == zone)
+            .collect()
+    }
+}
+
+/// Scale room counts based on actual population.
+/// The manifest counts are baselined for ~5000 people.
+pub fn scale_room_count(
+    spec: &FacilitySpec,
+    total_pop: u32,
+    crew_count: u32,
+    passenger_count: u32,
+) -> u32 {
+    let base_pop = 5000.0f32;
+    let pop_ratio = total_pop as f32 / base_pop;
+
+    match spec.room_type {
+        // Cabins scale to give every person a bed
+        // Single cabin = 1 person, double = 2, family suite = 4, crew quarters = 4
+        room_types::CABIN_SINGLE => {
+            // ~40% of passengers in singles
+            ((passenger_count as f32 * 0.4) / 1.0).ceil() as u32
+        }
+        room_types::CABIN_DOUBLE => {
+            // ~30% of passengers in doubles
+            ((passenger_count as f32 * 0.3) / 2.0).ceil() as u32
+        }
+        room_types::FAMILY_SUITE => {
+            // ~15% of passengers in family suites
+            ((passenger_count as f32 * 0.15) / 4.0).ceil() as u32
+        }
+        room_types::VIP_SUITE => {
+            // ~5% of passengers in VIP
+            ((passenger_count as f32 * 0.05) / 2.0).ceil().max(1.0) as u32
+        }
+        room_types::QUARTERS_PASSENGER => {
+            // ~10% of passengers in shared quarters
+            ((passenger_count as f32 * 0.1) / 8.0).ceil().max(1.0) as u32
+        }
+        room_types::QUARTERS_CREW => {
+            // Every crew member needs a berth (4 per quarters)
+            (crew_count as f32 / 4.0).ceil() as u32
+        }
+        room_types::QUARTERS_OFFICER => {
+            // ~10% of crew are officers (2 per quarters)
+            ((crew_count as f32 * 0.1) / 2.0).ceil().max(1.0) as u32
+        }
+        // Food/dining scales with population
+        room_types::MESS_HALL | room_types::GALLEY => {
+            (spec.count as f32 * pop_ratio).ceil().max(1.0) as u32
+        }
+        // Shared facilities scale with population
+        room_types::SHARED_BATHROOM | room_types::SHARED_LAUNDRY => {
+            (spec.count as f32 * pop_ratio).ceil().max(2.0) as u32
+        }
+        // Medical scales with population
+        room_types::HOSPITAL_WARD | room_types::MEDBAY => {
+            (spec.count as f32 * pop_ratio).ceil().max(1.0) as u32
+        }
+        // Cryo bays hold the passengers who travel asleep
+        room_types::CRYO_BAY => crate::cryo::bays_needed(passenger_count, spec.capacity),
+        // Recreation scales with population
+        room_types::CAFE | room_types::BAR | room_types::GAME_ROOM => {
+            (spec.count as f32 * pop_ratio).ceil().max(1.0) as u32
+        }
+        // Infrastructure rooms: use manifest count (don't scale)
+        _ => spec.count,
+    }
+}
+
+/// Build the facility graph: one node per room instance, crew paths within
+/// and between zones, and infrastructure edges from the utility rooms.
+pub fn build_facility_graph(
+    manifest: &[FacilitySpec],
+    crew_count: u32,
+    passenger_count: u32,
+) -> FacilityGraph {
+    let total_pop = crew_count + passenger_count;
+    let mut graph = FacilityGraph::default();
+
+    for spec in manifest {
+        let count = scale_room_count(spec, total_pop, crew_count, passenger_count);
+        for i in 0..count {
+            let name = if count == 1 {
+                spec.name.to_string()
+            } else {
+                format!("{} {}", spec.name, i + 1)
+            };
+            graph.nodes.push(FacilityNode {
+                name,
+                room_type: spec.room_type,
+                target_area: spec.target_area,
+                capacity: spec.capacity,
+                deck_zone: spec.deck_zone,
+                group: spec.group,
+            });
+        }
+    }
+
+    // Intra-zone crew_path edges (connect rooms in same zone, sample to keep edge count manageable)
+    for zone in 0..7u8 {
+        let zone_ids = graph.zone_nodes(zone);
+        // Fully connect small groups; for large groups connect each to a few neighbors
+        let threshold = 30;
+        if zone_ids.len() <= threshold {
+            for i in 0..zone_ids.len() {
+                for j in (i + 1)..zone_ids.len() {
+                    graph.link(zone_ids[i], zone_ids[j], edge_types::CREW_PATH, 1.0, true);
+                }
+            }
+        } else {
+            // Ring + short-range links
+            for i in 0..zone_ids.len() {
+                let next = (i + 1) % zone_ids.len();
+                graph.link(
+                    zone_ids[i],
+                    zone_ids[next],
+                    edge_types::CREW_PATH,
+                    1.0,
+                    true,
+                );
+                // Skip-3 link for connectivity
+                let skip = (i + 3) % zone_ids.len();
+                if skip != next && skip != i {
+                    graph.link(
+                        zone_ids[i],
+                        zone_ids[skip],
+                        edge_types::CREW_PATH,
+                        1.0,
+                        true,
+                    );
+                }
+            }
+        }
+    }
+
+    // Cross-zone crew paths: connect adjacent zones
+    for z in 0..6u8 {
+        let z_ids = graph.zone_nodes(z);
+        let z1_ids = graph.zone_nodes(z + 1);
+        if let (Some(&a), Some(&b)) = (z_ids.first(), z1_ids.first()) {
+            graph.link(a, b, edge_types::CREW_PATH, 2.0, true);
+        }
+        if let (Some(&a), Some(&b)) = (z_ids.last(), z1_ids.last()) {
+            graph.link(a, b, edge_types::CREW_PATH, 2.0, true);
+        }
+    }
+
+    let reactor_node = graph.find_by_type(room_types::REACTOR);
+    let eng_node = graph.find_by_type(room_types::ENGINEERING);
+    let water_node = graph.find_by_type(room_types::WATER_RECYCLING);
+    let hvac_node = graph.find_by_type(room_types::HVAC_CONTROL);
+    let comms_node = graph.find_by_type(room_types::COMMS_ROOM);
+    let bridge_node = graph.find_by_type(room_types::BRIDGE);
+    let cic_node = graph.find_by_type(room_types::CIC);
+    let node_count = graph.nodes.len();
+
+    // POWER: Reactor -> Engineering -> every other room
+    if let (Some(reactor), Some(eng)) = (reactor_node, eng_node) {
+        graph.link(reactor, eng, edge_types::POWER, 100.0, false);
+        for nid in 0..node_count {
+            if nid != reactor && nid != eng {
+                graph.link(eng, nid, edge_types::POWER, 10.0, false);
+            }
+        }
+    }
+
+    // WATER: Water Recycling -> habitable rooms
+    if let Some(water) = water_node {
+        for nid in 0..node_count {
+            let func = graph.nodes[nid].room_type;
+            if nid != water
+                && (room_types::is_quarters(func)
+                    || room_types::is_dining(func)
+                    || func == room_types::HYDROPONICS
+                    || func == room_types::HOSPITAL_WARD)
+            {
+                graph.link(water, nid, edge_types::WATER, 5.0, false);
+            }
+        }
+    }
+
+    // HVAC: HVAC Control -> all rooms (sample: only first 200 to keep manageable)
+    if let Some(hvac) = hvac_node {
+        let targets: Vec<usize> = (0..node_count).filter(|&n| n != hvac).take(200).collect();
+        for nid in targets {
+            graph.link(hvac, nid, edge_types::HVAC, 1.0, false);
+        }
+    }
+
+    // DATA: Comms -> Bridge, CIC, Engineering
+    if let Some(comms) = comms_node {
+        for t in [bridge_node, cic_node, eng_node].into_iter().flatten() {
+            graph.link(comms, t, edge_types::DATA, 1.0, false);
+        }
+    }
+
+    graph
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genlib::facilities::get_facility_manifest;
+
+    #[test]
+    fn test_graph_scales_cabins_with_passengers() {
+        let manifest = get_facility_manifest();
+        let small = build_facility_graph(&manifest, 50, 100);
+        let large = build_facility_graph(&manifest, 50, 1000);
+        let cabins = |g: &FacilityGraph| {
+            g.nodes
+                .iter()
+                .filter(|n| n.room_type == room_types::CABIN_SINGLE)
+                .count()
+        };
+        assert_eq!(cabins(&small), 40);
+        assert_eq!(cabins(&large), 400);
+    }
+
+    #[test]
+    fn test_graph_edges_reference_valid_nodes() {
+        let graph = build_facility_graph(&get_facility_manifest(), 100, 400);
+        assert!(!graph.edges.is_empty());
+        for e in &graph.edges {
+            assert!(e.from < graph.nodes.len());
+            assert!(e.to < graph.nodes.len());
+            assert_ne!(e.from, e.to);
+        }
+    }
+
+    #[test]
+    fn test_graph_has_infrastructure_networks() {
+        let graph = build_facility_graph(&get_facility_manifest(), 100, 400);
+        for edge_type in [
+            edge_types::CREW_PATH,
+            edge_types::POWER,
+            edge_types::WATER,
+            edge_types::HVAC,
+            edge_types::DATA,
+        ] {
+            assert!(
+                graph.edges.iter().any(|e| e.edge_type == edge_type),
+                "missing edge type {}",
+                edge_type
+            );
+        }
+    }
+
+    #[test]
+    fn test_hvac_edges_capped() {
+        let graph = build_facility_graph(&get_facility_manifest(), 1000, 4000);
+        let hvac = graph
+            .edges
+            .iter()
+            .filter(|e| e.edge_type == edge_types::HVAC)
+            .count();
+        assert_eq!(hvac, 200);
+    }
+}
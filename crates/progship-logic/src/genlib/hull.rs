@@ -0,0 +1,457 @@
+//! Parametric hull profile for deck tapering.
+//!
+//! Computes hull width and length per deck, tapering toward the bow (top
+//! decks) and stern (bottom decks) along configurable curves, plus the
+//! rounded fore/aft end caps of each deck outline. [`hull_spans`] finds where
+//! a placed room meets the outer hull, [`hatch_type`] which rooms get a
+//! hatch through it and [`has_window`] which get a window.
+
+use crate::constants::{hatch_types, room_types, wall_sides};
+use serde::{Deserialize, Serialize};
+
+/// Curve used to blend from a tapered tip back to full hull size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum TaperCurve {
+    /// Quarter-ellipse: grows quickly near the tip, flattens near the equator.
+    #[default]
+    Elliptical = 0,
+    /// Straight-line ramp from tip to full size.
+    Linear = 1,
+    /// Hard step: tip size on every taper deck, full size after.
+    Step = 2,
+}
+
+impl TaperCurve {
+    pub fn from_u8(val: u8) -> Option<Self> {
+        match val {
+            0 => Some(Self::Elliptical),
+            1 => Some(Self::Linear),
+            2 => Some(Self::Step),
+            _ => None,
+        }
+    }
+
+    /// Blend factor for `t` in 0..=1 (0 = tip, 1 = full size).
+    pub fn blend(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            TaperCurve::Step => {
+                if t >= 1.0 {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            TaperCurve::Linear => t,
+            TaperCurve::Elliptical => (1.0 - (1.0 - t) * (1.0 - t)).sqrt(),
+        }
+    }
+}
+
+/// Parametric hull profile: bow/stern taper across decks and deck end caps.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HullProfile {
+    /// Beam at the bow tip deck, as a percentage of full beam.
+    pub bow_beam_pct: u32,
+    /// Length at the bow tip deck, as a percentage of full length.
+    pub bow_length_pct: u32,
+    /// Beam at the stern tip deck, as a percentage of full beam.
+    pub stern_beam_pct: u32,
+    /// Length at the stern tip deck, as a percentage of full length.
+    pub stern_length_pct: u32,
+    /// Fraction of decks (from the top) that taper toward the bow.
+    pub bow_taper_fraction: f32,
+    /// Fraction of decks (from the bottom) that taper toward the stern.
+    pub stern_taper_fraction: f32,
+    /// Taper curve for the bow decks.
+    pub bow_curve: TaperCurve,
+    /// Taper curve for the stern decks.
+    pub stern_curve: TaperCurve,
+    /// Curve that rounds the fore/aft ends of each deck outline.
+    pub end_cap_curve: TaperCurve,
+    /// Width of the outermost end-cap row, as a percentage of deck width.
+    pub end_cap_tip_pct: u32,
+}
+
+impl Default for HullProfile {
+    fn default() -> Self {
+        Self {
+            bow_beam_pct: 60,
+            bow_length_pct: 50,
+            stern_beam_pct: 75,
+            stern_length_pct: 75,
+            bow_taper_fraction: 0.25,
+            stern_taper_fraction: 0.2,
+            bow_curve: TaperCurve::default(),
+            stern_curve: TaperCurve::default(),
+            end_cap_curve: TaperCurve::Elliptical,
+            end_cap_tip_pct: 40,
+        }
+    }
+}
+
+impl HullProfile {
+    /// The default profile with the bow and stern tapering along `curve`.
+    pub fn with_taper(curve: TaperCurve) -> Self {
+        Self {
+            bow_curve: curve,
+            stern_curve: curve,
+            ..Default::default()
+        }
+    }
+
+    /// Number of bow and stern taper decks. The midship deck is never tapered,
+    /// so it is always the widest and longest deck.
+    fn taper_decks(&self, deck_count: u32) -> (u32, u32) {
+        let mid = deck_count / 2;
+        let bow = ((deck_count as f32 * self.bow_taper_fraction).ceil() as u32).min(mid);
+        let stern = ((deck_count as f32 * self.stern_taper_fraction).ceil() as u32)
+            .min(deck_count.saturating_sub(1).saturating_sub(mid));
+        (bow, stern)
+    }
+
+    /// Blend `tip_pct` toward 100% for this deck. Returns a whole percentage.
+    fn taper_pct(&self, deck: u32, deck_count: u32, bow_pct: u32, stern_pct: u32) -> usize {
+        let (bow_decks, stern_decks) = self.taper_decks(deck_count);
+        let from_stern = deck_count.saturating_sub(1).saturating_sub(deck);
+        let (tip_pct, curve, t) = if deck < bow_decks {
+            (bow_pct, self.bow_curve, deck as f32 / bow_decks as f32)
+        } else if from_stern < stern_decks {
+            (
+                stern_pct,
+                self.stern_curve,
+                from_stern as f32 / stern_decks as f32,
+            )
+        } else {
+            return 100;
+        };
+        let tip = tip_pct.min(100) as f32;
+        (tip + (100.0 - tip) * curve.blend(t)).round() as usize
+    }
+
+    /// Hull width (beam) for a given deck.
+    pub fn width(&self, deck: u32, deck_count: u32, ship_beam: usize) -> usize {
+        let pct = self.taper_pct(deck, deck_count, self.bow_beam_pct, self.stern_beam_pct);
+        (ship_beam * pct / 100).max(10)
+    }
+
+    /// Hull length for a given deck.
+    pub fn length(&self, deck: u32, deck_count: u32, ship_length: usize) -> usize {
+        let pct = self.taper_pct(deck, deck_count, self.bow_length_pct, self.stern_length_pct);
+        (ship_length * pct / 100).max(30)
+    }
+
+    /// Fraction of the deck width available `depth` meters into an end cap of
+    /// `cap_depth` meters (depth 0 touches the full-width body of the deck).
+    pub fn end_cap_width_fraction(&self, depth: f32, cap_depth: f32) -> f32 {
+        if cap_depth <= 0.0 {
+            return 1.0;
+        }
+        let s = (depth / cap_depth).clamp(0.0, 1.0);
+        let tip = self.end_cap_tip_pct.min(100) as f32 / 100.0;
+        tip + (1.0 - tip) * self.end_cap_curve.blend(1.0 - s)
+    }
+
+    /// Cells to trim from each side of a deck at `depth` rows into an end cap
+    /// of `cap_depth` rows, sampled at the row center.
+    pub fn end_cap_inset(&self, depth: usize, cap_depth: usize, deck_width: usize) -> usize {
+        if cap_depth == 0 || depth >= cap_depth {
+            return deck_width / 2;
+        }
+        let frac = self.end_cap_width_fraction(depth as f32 + 0.5, cap_depth as f32);
+        let row_width = (deck_width as f32 * frac).round() as usize;
+        deck_width.saturating_sub(row_width) / 2
+    }
+}
+
+/// Run of cells along one wall of a room whose outside neighbors are hull.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HullSpan {
+    /// Wall side (see `wall_sides`).
+    pub wall: u8,
+    /// Grid coordinate of the first cell along the wall (x for north/south,
+    /// y for east/west).
+    pub start: i32,
+    pub len: u32,
+}
+
+impl HullSpan {
+    /// Grid point at the middle of the span, on the wall line.
+    pub fn center(&self, x: i32, y: i32, w: i32, h: i32) -> (f32, f32) {
+        let mid = self.start as f32 + self.len as f32 / 2.0;
+        match self.wall {
+            wall_sides::NORTH => (mid, y as f32),
+            wall_sides::SOUTH => (mid, (y + h) as f32),
+            wall_sides::EAST => ((x + w) as f32, mid),
+            _ => (x as f32, mid),
+        }
+    }
+}
+
+/// Longest hull-facing run on each wall of the rect (x, y, w, h).
+///
+/// A cell counts when `in_room` holds for it and `is_hull` holds for its
+/// neighbor across the wall. North is low y. Walls that never touch the hull
+/// are left out, so an empty result means a landlocked room.
+pub fn hull_spans(
+    x: i32,
+    y: i32,
+    w: i32,
+    h: i32,
+    in_room: impl Fn(i32, i32) -> bool,
+    is_hull: impl Fn(i32, i32) -> bool,
+) -> Vec<HullSpan> {
+    let walls = [
+        (wall_sides::NORTH, x, w, (0, -1)),
+        (wall_sides::SOUTH, x, w, (0, 1)),
+        (wall_sides::EAST, y, h, (1, 0)),
+        (wall_sides::WEST, y, h, (-1, 0)),
+    ];
+    let mut spans = Vec::new();
+    for (wall, from, count, (dx, dy)) in walls {
+        let cell = |i: i32| match wall {
+            wall_sides::NORTH => (i, y),
+            wall_sides::SOUTH => (i, y + h - 1),
+            wall_sides::EAST => (x + w - 1, i),
+            _ => (x, i),
+        };
+        let mut best: Option<HullSpan> = None;
+        let mut run_start = None;
+        for i in from..=from + count {
+            let facing = i < from + count && {
+                let (cx, cy) = cell(i);
+                in_room(cx, cy) && is_hull(cx + dx, cy + dy)
+            };
+            match (facing, run_start) {
+                (true, None) => run_start = Some(i),
+                (false, Some(start)) => {
+                    let len = (i - start) as u32;
+                    if best.is_none_or(|b| len > b.len) {
+                        best = Some(HullSpan { wall, start, len });
+                    }
+                    run_start = None;
+                }
+                _ => {}
+            }
+        }
+        spans.extend(best);
+    }
+    spans
+}
+
+/// Bit set of the walls in `spans` (bit `1 << wall`).
+pub fn hull_sides(spans: &[HullSpan]) -> u8 {
+    spans.iter().fold(0, |sides, s| sides | 1 << s.wall)
+}
+
+/// Hatch cut through the hull for a room type, if any: airlocks get their
+/// outer door, shuttle bays their launch doors, and rooms with external
+/// antennas or radiators an EVA hatch for maintenance.
+pub fn hatch_type(room_type: u8) -> Option<u8> {
+    match room_type {
+        room_types::AIRLOCK => Some(hatch_types::AIRLOCK),
+        room_types::SHUTTLE_BAY => Some(hatch_types::BAY_DOOR),
+        room_types::COMMS_ROOM | room_types::COOLING_PLANT => Some(hatch_types::EVA),
+        _ => None,
+    }
+}
+
+/// Whether a room of `room_type` touching the hull on `hull_sides` (see
+/// [`hull_sides`]) gets a window. Shafts, shielded plant and bulk storage
+/// stay windowless even on the hull.
+pub fn has_window(room_type: u8, hull_sides: u8) -> bool {
+    hull_sides != 0
+        && !room_types::is_shaft(room_type)
+        && !matches!(
+            room_type,
+            room_types::REACTOR
+                | room_types::BACKUP_REACTOR
+                | room_types::FUEL_STORAGE
+                | room_types::CARGO_BAY
+                | room_types::STORAGE
+                | room_types::PARTS_STORAGE
+                | room_types::FOOD_STORAGE_COLD
+                | room_types::FOOD_STORAGE_DRY
+        )
+}
+
+/// Order in which hull-facing rooms claim hull band space (lower first):
+/// airlocks, then the rooms built around their view of the stars, then the rest.
+pub fn hull_band_priority(room_type: u8) -> u8 {
+    match room_type {
+        room_types::AIRLOCK => 0,
+        room_types::OBSERVATION_LOUNGE | room_types::OBSERVATORY => 1,
+        _ => 2,
+    }
+}
+
+/// Opening width (m) of a hatch placed on a hull span of `span_len` cells.
+pub fn hatch_width(hatch_type: u8, span_len: u32) -> f32 {
+    let width = match hatch_type {
+        hatch_types::BAY_DOOR => span_len as f32 * 0.8,
+        hatch_types::AIRLOCK => 2.0,
+        _ => 1.0,
+    };
+    width.min(span_len as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_windows_and_hull_band_priority() {
+        let north = 1 << wall_sides::NORTH;
+        assert!(has_window(room_types::OBSERVATION_LOUNGE, north));
+        assert!(has_window(room_types::CABIN_SINGLE, north));
+        assert!(!has_window(room_types::CABIN_SINGLE, 0));
+        assert!(!has_window(room_types::REACTOR, north));
+        assert!(!has_window(room_types::ELEVATOR_SHAFT, north));
+        assert!(
+            hull_band_priority(room_types::AIRLOCK) < hull_band_priority(room_types::OBSERVATORY)
+        );
+        assert!(
+            hull_band_priority(room_types::OBSERVATION_LOUNGE)
+                < hull_band_priority(room_types::CARGO_BAY)
+        );
+    }
+
+    #[test]
+    fn test_blend_endpoints() {
+        for curve in [TaperCurve::Step, TaperCurve::Linear, TaperCurve::Elliptical] {
+            assert_eq!(curve.blend(0.0), 0.0, "{:?}", curve);
+            assert_eq!(curve.blend(1.0), 1.0, "{:?}", curve);
+        }
+        assert!(TaperCurve::Elliptical.blend(0.5) > TaperCurve::Linear.blend(0.5));
+        assert_eq!(TaperCurve::Step.blend(0.99), 0.0);
+    }
+
+    #[test]
+    fn test_taper_curve_from_u8() {
+        for curve in [TaperCurve::Elliptical, TaperCurve::Linear, TaperCurve::Step] {
+            assert_eq!(TaperCurve::from_u8(curve as u8), Some(curve));
+        }
+        assert_eq!(TaperCurve::from_u8(3), None);
+    }
+
+    #[test]
+    fn test_step_curve_matches_flat_taper() {
+        let profile = HullProfile::with_taper(TaperCurve::Step);
+        let deck_count = 20;
+        for deck in 0..5 {
+            assert_eq!(profile.width(deck, deck_count, 100), 60);
+        }
+        assert_eq!(profile.width(5, deck_count, 100), 100);
+        for deck in 16..20 {
+            assert_eq!(profile.width(deck, deck_count, 100), 75);
+        }
+    }
+
+    #[test]
+    fn test_tiny_deck_counts() {
+        let profile = HullProfile::default();
+        for deck_count in 1..4 {
+            let mid = deck_count / 2;
+            let mid_w = profile.width(mid, deck_count, 65);
+            assert_eq!(mid_w, 65);
+            for deck in 0..deck_count {
+                assert!(profile.width(deck, deck_count, 65) <= mid_w);
+            }
+        }
+    }
+
+    #[test]
+    fn test_end_cap_width_fraction() {
+        let profile = HullProfile::default();
+        assert_eq!(profile.end_cap_width_fraction(0.0, 15.0), 1.0);
+        assert!((profile.end_cap_width_fraction(15.0, 15.0) - 0.4).abs() < 1e-6);
+        assert_eq!(profile.end_cap_width_fraction(5.0, 0.0), 1.0);
+
+        let ellipse = HullProfile {
+            end_cap_tip_pct: 0,
+            ..Default::default()
+        };
+        // Full ellipse: width fraction follows sqrt(1 - s²)
+        let f = ellipse.end_cap_width_fraction(6.0, 10.0);
+        assert!((f - 0.8).abs() < 1e-4, "fraction {}", f);
+    }
+
+    #[test]
+    fn test_end_cap_inset_rounds_deck_ends() {
+        let profile = HullProfile::default();
+        let cap = 15;
+        let width = 60;
+
+        // Row touching the deck body is (nearly) full width
+        assert!(profile.end_cap_inset(0, cap, width) <= 1);
+
+        // Insets grow toward the tip
+        let insets: Vec<usize> = (0..cap)
+            .map(|d| profile.end_cap_inset(d, cap, width))
+            .collect();
+        assert!(insets.windows(2).all(|w| w[0] <= w[1]), "{:?}", insets);
+
+        // Tip keeps at least end_cap_tip_pct of the width
+        let tip_row = width - 2 * insets[cap - 1];
+        assert!(tip_row >= width * 40 / 100, "tip row {}", tip_row);
+        assert!(tip_row < width);
+    }
+
+    #[test]
+    fn test_hull_spans_find_exterior_walls() {
+        // 10x10 grid; hull is the row y < 0 and the column x >= 8 for y < 3
+        let is_hull = |x: i32, y: i32| y < 0 || (x >= 8 && y < 3);
+        let in_rect = |x0: i32, y0: i32, w: i32, h: i32| {
+            move |x: i32, y: i32| x >= x0 && x < x0 + w && y >= y0 && y < y0 + h
+        };
+        let spans = hull_spans(2, 0, 6, 4, in_rect(2, 0, 6, 4), is_hull);
+        assert_eq!(
+            spans,
+            vec![
+                HullSpan {
+                    wall: wall_sides::NORTH,
+                    start: 2,
+                    len: 6
+                },
+                HullSpan {
+                    wall: wall_sides::EAST,
+                    start: 0,
+                    len: 3
+                },
+            ]
+        );
+        assert_eq!(
+            hull_sides(&spans),
+            1 << wall_sides::NORTH | 1 << wall_sides::EAST
+        );
+        assert_eq!(spans[0].center(2, 0, 6, 4), (5.0, 0.0));
+        assert_eq!(spans[1].center(2, 0, 6, 4), (8.0, 1.5));
+        // Landlocked room
+        assert!(hull_spans(2, 4, 4, 4, in_rect(2, 4, 4, 4), is_hull).is_empty());
+        // Cells outside the room's mask don't count
+        let spans = hull_spans(
+            2,
+            0,
+            6,
+            4,
+            |x, y| in_rect(2, 0, 6, 4)(x, y) && x != 4,
+            is_hull,
+        );
+        assert_eq!(spans[0].start, 5);
+        assert_eq!(spans[0].len, 3);
+    }
+
+    #[test]
+    fn test_hatch_types() {
+        assert_eq!(hatch_type(room_types::AIRLOCK), Some(hatch_types::AIRLOCK));
+        assert_eq!(
+            hatch_type(room_types::SHUTTLE_BAY),
+            Some(hatch_types::BAY_DOOR)
+        );
+        assert_eq!(hatch_type(room_types::GALLEY), None);
+        assert_eq!(hatch_width(hatch_types::AIRLOCK, 6), 2.0);
+        assert_eq!(hatch_width(hatch_types::AIRLOCK, 1), 1.0);
+        assert_eq!(hatch_width(hatch_types::BAY_DOOR, 10), 8.0);
+    }
+}
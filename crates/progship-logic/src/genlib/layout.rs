@@ -0,0 +1,3459 @@
+//! Ring-and-spur deck layout shared by the server and the core engine.
+//!
+//! Pipeline: hull sizing → perimeter ring corridor → spine + cross-corridors →
+//! spurs into wide segments → shafts at intersections → maintenance crawlways →
+//! segment identification → BSP room packing → wavefront gap fill → filler
+//! backfill → hull band rooms → room-to-room doors.
+//!
+//! The ring corridor wraps the entire deck perimeter as a public walkway.
+//! One central spine (or twin port/starboard spines, see [`CorridorTopology`])
+//! runs the length of each deck and cross-corridors join it to the ring.
+//! Rooms fill the rectangular segments between corridors, so every room
+//! touches at least one corridor by construction. Callers turn the
+//! [`ShipLayoutPlan`] into their own rows or entities.
+//!
+//! Coordinates are meters on the midship deck's grid: `x` runs across the
+//! beam (`0..ship_beam`), `y` along the length (`0..ship_length + 2 *
+//! HULL_BAND_WIDTH`, bow at low Y). Room and door positions are centers,
+//! matching the server's `Room` rows.
+
+use super::crawlways::{crawlway_column, crawlway_runs, split_around, CRAWLWAY_WIDTH};
+use super::facilities::{deck_zones_with_overrides, ZoneDecks};
+use super::graph::FacilityNode;
+use super::hull::{
+    has_window, hatch_type, hatch_width, hull_band_priority, hull_sides, hull_spans, HullProfile,
+};
+use super::room_doors::should_have_room_door;
+use crate::constants::{
+    carries_flags, corridor_types, deck_heights, placement, room_types, shaft_types, wall_sides,
+};
+use crate::movement::cell_mask_contains;
+use crate::numeric::finite_or;
+use crate::security::{access_levels, door_access_level};
+use serde::{Deserialize, Serialize};
+use std::ops::{Range, RangeBounds};
+
+// Grid cell type markers
+const CELL_EMPTY: u8 = 0;
+const CELL_MAIN_CORRIDOR: u8 = 1;
+const CELL_CRAWLWAY: u8 = 2;
+const CELL_SHAFT: u8 = 3;
+const CELL_HULL: u8 = 4;
+const CELL_ROOM_BASE: u8 = 10;
+
+/// Base width of each spine corridor in meters.
+pub const SPINE_WIDTH: usize = 4;
+/// Base width of cross-corridors in meters.
+pub const CROSS_CORRIDOR_WIDTH: usize = 4;
+/// Width of the perimeter ring corridor in meters, the same as the spine.
+pub const RING_WIDTH: usize = 4;
+/// Widest the spines grow, for the busiest deck.
+pub const MAX_SPINE_WIDTH: usize = 6;
+/// Widest a deck's cross-corridors grow.
+pub const MAX_CROSS_CORRIDOR_WIDTH: usize = 6;
+/// Width of spur corridors, narrower than the spine.
+pub const SPUR_WIDTH: usize = 3;
+/// Smallest room dimension the packer will emit.
+pub const MIN_ROOM_DIM: usize = 4;
+/// Room columns wider than this get a spur corridor through each segment.
+const SPUR_THRESHOLD: usize = 12;
+/// Depth of the hull-facing room band fore and aft of the ring corridor.
+pub const HULL_BAND_WIDTH: usize = 15;
+
+/// Filler room pool: used to backfill empty deck space after zone rooms are placed.
+const FILLER_POOL: &[(u8, &str, f32, u32)] = &[
+    (room_types::STORAGE, "Storage", 60.0, 0),
+    (room_types::MAINTENANCE_BAY, "Maintenance Bay", 40.0, 4),
+    (room_types::PARTS_STORAGE, "Parts Storage", 30.0, 0),
+    (room_types::WORKSHOP, "Workshop", 35.0, 6),
+    (room_types::UTILITY, "Utility Room", 20.0, 2),
+    (room_types::EMERGENCY_SUPPLY, "Emergency Supply", 25.0, 0),
+];
+
+/// Inputs for [`generate_layout`] and [`generate_deck_layouts`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LayoutConfig {
+    /// Decks in the ship, or 0 to pick the count from the room area.
+    pub deck_count: u32,
+    /// Full beam (width) in meters at the midship deck, or 0 to size the
+    /// hull from the room area.
+    pub ship_beam: usize,
+    /// Full length in meters at the midship deck, or 0 to size the hull from
+    /// the room area.
+    pub ship_length: usize,
+    /// People aboard; elevators and ladders are added for every few hundred.
+    pub population: u32,
+    /// Per-deck taper and end-cap rounding.
+    pub hull: HullProfile,
+    /// Optional cap on facility rooms per deck (corridors, shafts and
+    /// fillers excluded).
+    pub max_rooms_per_deck: Option<u32>,
+    /// Longitudinal corridor arrangement.
+    pub topology: CorridorTopology,
+    /// Zones pinned to deck ranges by the ship designer.
+    pub zone_overrides: Vec<ZoneDecks>,
+    /// ID of the first room laid out, so decks laid out over several calls
+    /// keep numbering after the rooms already placed.
+    pub first_room_id: u32,
+}
+
+/// Longitudinal corridor arrangement for every deck.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum CorridorTopology {
+    /// One spine down the centerline; rooms reach out to the ring on both sides.
+    #[default]
+    CentralSpine = 0,
+    /// Port and starboard spines at quarter beam, joined by the cross
+    /// corridors. Halves the room depth on wide decks and splits traffic
+    /// between two hallways. Every deck shares the spines, which move
+    /// inboard to fit the narrowest deck; ships too narrow for them get a
+    /// central spine.
+    TwinSpine = 1,
+}
+
+impl CorridorTopology {
+    pub fn from_u8(val: u8) -> Option<Self> {
+        match val {
+            0 => Some(Self::CentralSpine),
+            1 => Some(Self::TwinSpine),
+            _ => None,
+        }
+    }
+
+    /// Left edge (x) of each spine `spine_width` wide on a deck `beam`
+    /// meters across, port first.
+    pub fn spine_xs(self, beam: usize, spine_width: usize) -> Vec<usize> {
+        if self == CorridorTopology::TwinSpine {
+            let port = (beam / 4).saturating_sub(spine_width / 2);
+            if port >= RING_WIDTH + 2 * MIN_ROOM_DIM {
+                return vec![port, beam - port - spine_width];
+            }
+        }
+        vec![(beam / 2).saturating_sub(spine_width / 2)]
+    }
+
+    fn spine_names(self) -> &'static [&'static str] {
+        match self {
+            CorridorTopology::CentralSpine => &["Spine"],
+            CorridorTopology::TwinSpine => &["Port Spine", "Starboard Spine"],
+        }
+    }
+
+    fn column_names(self) -> &'static [&'static str] {
+        match self {
+            CorridorTopology::CentralSpine => &["Port", "Starb"],
+            CorridorTopology::TwinSpine => &["Port", "Center", "Starb"],
+        }
+    }
+}
+
+/// People a meter of corridor width serves before it gets crowded.
+pub const PEOPLE_PER_CORRIDOR_METER: f32 = 50.0;
+
+/// Predicted traffic through the corridors serving rooms of these
+/// capacities: everyone who uses them walks the corridor, and even an
+/// unstaffed store room gets visitors.
+pub fn corridor_traffic(capacities: impl IntoIterator<Item = u32>) -> f32 {
+    capacities.into_iter().map(|c| c.max(1) as f32).sum()
+}
+
+/// Width in meters of a corridor carrying `traffic`, between `base` and
+/// `max`.
+pub fn corridor_width_for_traffic(traffic: f32, base: usize, max: usize) -> usize {
+    let needed = (finite_or(traffic, 0.0).max(0.0) / PEOPLE_PER_CORRIDOR_METER).ceil() as usize;
+    needed.clamp(base, max.max(base))
+}
+
+/// A placed room (facility, filler, corridor, crawlway or shaft) on one deck.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayoutRoom {
+    /// Room ID, counting up from [`LayoutConfig::first_room_id`].
+    pub id: u32,
+    /// Facility graph node this room realises, if any.
+    pub node: Option<usize>,
+    pub name: String,
+    pub room_type: u8,
+    pub deck: i32,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub capacity: u32,
+    pub ceiling_height: f32,
+    pub deck_span: u8,
+    /// Footprint cells (see `movement::cell_mask_contains`); empty for a
+    /// plain rectangle.
+    pub cells: Vec<u8>,
+    /// Walls on the outer hull, one bit per `wall_sides` value.
+    pub hull_sides: u8,
+    pub has_window: bool,
+}
+
+/// A door between two rooms.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayoutDoor {
+    pub room_a: u32,
+    pub room_b: u32,
+    pub wall_a: u8,
+    pub wall_b: u8,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    /// The stricter of the level the layout asked for and what the rooms on
+    /// either side require (see `security::door_access_level`).
+    pub access_level: u8,
+}
+
+/// A corridor run and the utilities it carries.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayoutCorridor {
+    pub deck: i32,
+    pub corridor_type: u8,
+    /// Top-left corner.
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub length: f32,
+    /// 0 runs across the beam, 1 along the length.
+    pub orientation: u8,
+    /// `carries_flags` bitmask.
+    pub carries: u8,
+}
+
+/// A hatch through the outer hull.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayoutHatch {
+    pub room_id: u32,
+    pub hatch_type: u8,
+    pub wall: u8,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+}
+
+/// A vertical shaft and the room it occupies on each deck it serves.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayoutShaft {
+    pub name: String,
+    pub shaft_type: u8,
+    /// Public passenger elevator, as opposed to a crew-only service shaft.
+    pub is_main: bool,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    /// Shaft room IDs, ordered from the top deck down. Filled in by
+    /// [`join_shafts`].
+    pub deck_rooms: Vec<u32>,
+    /// Deck of each room in `deck_rooms`.
+    pub decks_served: Vec<i32>,
+}
+
+/// Result of [`generate_layout`] and [`generate_deck_layouts`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ShipLayoutPlan {
+    pub deck_count: u32,
+    pub ship_beam: usize,
+    pub ship_length: usize,
+    /// Zone of each deck, by deck index.
+    pub deck_zones: Vec<u8>,
+    pub rooms: Vec<LayoutRoom>,
+    pub doors: Vec<LayoutDoor>,
+    pub corridors: Vec<LayoutCorridor>,
+    pub hatches: Vec<LayoutHatch>,
+    pub shafts: Vec<LayoutShaft>,
+    /// Graph nodes that did not fit on their assigned deck.
+    pub unplaced: Vec<usize>,
+}
+
+/// Room request for one facility graph node.
+#[derive(Debug, Clone)]
+struct RoomRequest {
+    node: usize,
+    name: String,
+    room_type: u8,
+    target_area: f32,
+    capacity: u32,
+    placement: u8,
+}
+
+/// Grid rectangle `(x, y, w, h)` in cells.
+type Cells = (usize, usize, usize, usize);
+
+/// Grid bounds `(x0, y0, x1, y1)`, end exclusive.
+type Bounds = (usize, usize, usize, usize);
+
+/// Wall two grid rectangles share: where a door through it goes, the wall on
+/// each side and how long the shared stretch is.
+#[derive(Debug, Clone, Copy)]
+struct SharedEdge {
+    x: f32,
+    y: f32,
+    wall_a: u8,
+    wall_b: u8,
+    overlap: f32,
+}
+
+/// A door at `(x, y)` through fixed walls, for corridor junctions.
+fn junction(x: f32, y: f32, wall_a: u8, wall_b: u8) -> SharedEdge {
+    SharedEdge {
+        x,
+        y,
+        wall_a,
+        wall_b,
+        overlap: 0.0,
+    }
+}
+
+/// A room placed by the packer, tracked so later passes can grow it.
+#[derive(Debug, Clone, Copy)]
+struct PlacedRoom {
+    id: u32,
+    x: usize,
+    y: usize,
+    w: usize,
+    h: usize,
+    room_type: u8,
+    placement: u8,
+}
+
+/// Shaft definition for placement at corridor intersections.
+struct ShaftPlacement {
+    x: usize,
+    y: usize,
+    w: usize,
+    h: usize,
+    shaft_type: u8,
+    name: &'static str,
+    is_main: bool,
+    /// Preferred door wall (NORTH/SOUTH/EAST/WEST) — consistent across all decks.
+    preferred_wall: u8,
+}
+
+/// Ship-wide geometry every deck is laid out against.
+struct ShipGrid<'a> {
+    config: &'a LayoutConfig,
+    deck_count: u32,
+    ship_beam: usize,
+    ship_length: usize,
+    mid_hw_hull: usize,
+    mid_hl_hull: usize,
+    /// Grid size: the midship hull, plus the hull band fore and aft.
+    hw: usize,
+    hl: usize,
+    spine_width: usize,
+    /// Corridor arrangement every deck shares, and its spines' left edges.
+    topology: CorridorTopology,
+    spine_xs: Vec<usize>,
+    mid_cross_ys: Vec<usize>,
+    shafts: Vec<ShaftPlacement>,
+    /// Decks each shaft in `shafts` serves.
+    shaft_decks: Vec<Range<usize>>,
+}
+
+/// The ship's spines: `topology`'s spines on the midship grid `beam` cells
+/// across, moved inboard to leave a room column outboard of them on the
+/// deck with the widest `taper_margin`. Every deck shares them so the shafts
+/// beside the port spine stack; a ship too narrow for twin spines everywhere
+/// gets one central spine.
+fn ship_spines(
+    topology: CorridorTopology,
+    beam: usize,
+    spine_width: usize,
+    taper_margin: usize,
+) -> (CorridorTopology, Vec<usize>) {
+    let xs = topology.spine_xs(beam, spine_width);
+    if xs.len() == 2 {
+        let port = xs[0].max(taper_margin + RING_WIDTH + MIN_ROOM_DIM);
+        let starboard = beam.saturating_sub(port + spine_width);
+        if starboard >= port + spine_width + 2 * MIN_ROOM_DIM {
+            return (CorridorTopology::TwinSpine, vec![port, starboard]);
+        }
+    }
+    (
+        CorridorTopology::CentralSpine,
+        CorridorTopology::CentralSpine.spine_xs(beam, spine_width),
+    )
+}
+
+/// The corridors of one deck, which rooms get their doors to.
+struct DeckCorridors {
+    spine_width: usize,
+    /// (room id, x, y0, y1) of each spine segment between cross-corridors.
+    spine_segments: Vec<(u32, usize, usize, usize)>,
+    /// (room id, y) of each cross-corridor.
+    cross_rooms: Vec<(u32, usize)>,
+    cross_width: usize,
+    spur_rooms: Vec<(u32, Cells)>,
+    inner_x0: usize,
+    inner_x1: usize,
+    inner_y1: usize,
+    ring_x0: usize,
+    ring_x1: usize,
+    ring_y0: usize,
+    ring_y1: usize,
+    /// Ring room IDs: north, south, west, east.
+    ring_ids: [u32; 4],
+}
+
+impl DeckCorridors {
+    /// Every corridor a room may open onto, in the order a door prefers
+    /// them: spine, cross-corridors, spurs, then the ring.
+    fn door_targets(&self) -> Vec<(u32, Cells)> {
+        let [ring_n_id, ring_s_id, ring_w_id, ring_e_id] = self.ring_ids;
+        let ring_w = self.ring_x1 - self.ring_x0;
+        let ring_h = self.ring_y1 - self.ring_y0;
+        let mut targets: Vec<(u32, Cells)> = self
+            .spine_segments
+            .iter()
+            .map(|&(id, x, y0, y1)| (id, (x, y0, self.spine_width, y1 - y0)))
+            .collect();
+        targets.extend(self.cross_rooms.iter().map(|&(id, cy)| {
+            (
+                id,
+                (
+                    self.inner_x0,
+                    cy,
+                    self.inner_x1 - self.inner_x0,
+                    self.cross_width,
+                ),
+            )
+        }));
+        targets.extend(self.spur_rooms.iter().copied());
+        targets.extend([
+            (ring_w_id, (self.ring_x0, self.ring_y0, RING_WIDTH, ring_h)),
+            (
+                ring_e_id,
+                (self.ring_x1 - RING_WIDTH, self.ring_y0, RING_WIDTH, ring_h),
+            ),
+            (ring_n_id, (self.ring_x0, self.ring_y0, ring_w, RING_WIDTH)),
+            (ring_s_id, (self.ring_x0, self.inner_y1, ring_w, RING_WIDTH)),
+        ]);
+        targets
+    }
+
+    /// Whether a rectangle touches any corridor. Pure geometry check.
+    fn touches_any(&self, rect: Cells) -> bool {
+        self.door_targets()
+            .iter()
+            .any(|&(_, corridor)| shared_edge(rect, corridor).is_some())
+    }
+}
+
+/// The plan under construction and the ID of its first room.
+struct PlanBuilder {
+    plan: ShipLayoutPlan,
+    first_room_id: u32,
+}
+
+impl PlanBuilder {
+    /// Add a room covering grid cells `rect`, returning its ID.
+    fn room(
+        &mut self,
+        node: Option<usize>,
+        name: String,
+        room_type: u8,
+        deck: i32,
+        capacity: u32,
+        (x, y, w, h): Cells,
+    ) -> u32 {
+        let id = self.first_room_id + self.plan.rooms.len() as u32;
+        self.plan.rooms.push(LayoutRoom {
+            id,
+            node,
+            name,
+            room_type,
+            deck,
+            x: x as f32 + w as f32 / 2.0,
+            y: y as f32 + h as f32 / 2.0,
+            width: w as f32,
+            height: h as f32,
+            capacity,
+            ceiling_height: deck_heights::room_ceiling_height(room_type),
+            deck_span: deck_heights::room_deck_span(room_type),
+            cells: Vec::new(),
+            hull_sides: 0,
+            has_window: false,
+        });
+        id
+    }
+
+    fn room_mut(&mut self, id: u32) -> Option<&mut LayoutRoom> {
+        let index = id.checked_sub(self.first_room_id)? as usize;
+        self.plan.rooms.get_mut(index)
+    }
+
+    fn room_type(&self, id: u32) -> u8 {
+        id.checked_sub(self.first_room_id)
+            .and_then(|i| self.plan.rooms.get(i as usize))
+            .map_or(room_types::CORRIDOR, |r| r.room_type)
+    }
+
+    /// Add a door from `room_a` to `room_b` at `edge`, at `access_level` or
+    /// stricter if either room requires it.
+    fn door(&mut self, room_a: u32, room_b: u32, edge: SharedEdge, width: f32, access_level: u8) {
+        let required = door_access_level(self.room_type(room_a), self.room_type(room_b));
+        self.plan.doors.push(LayoutDoor {
+            room_a,
+            room_b,
+            wall_a: edge.wall_a,
+            wall_b: edge.wall_b,
+            x: edge.x,
+            y: edge.y,
+            width,
+            access_level: access_level.max(required),
+        });
+    }
+}
+
+/// Lay out every deck of the ship and join the shafts between them.
+pub fn generate_layout(nodes: &[FacilityNode], config: &LayoutConfig) -> ShipLayoutPlan {
+    let mut plan = generate_deck_layouts(nodes, config, ..);
+    let ShipLayoutPlan {
+        rooms,
+        shafts,
+        deck_count,
+        ..
+    } = &mut plan;
+    let doors = join_shafts(shafts, *deck_count, |name| {
+        rooms.iter().find(|r| r.name == name).map(|r| r.id)
+    });
+    plan.doors.extend(doors);
+    plan.shafts.retain(|s| !s.deck_rooms.is_empty());
+    plan
+}
+
+/// Lay out the ship's `decks` only, for hosts that generate a ship a few
+/// decks at a time. The whole ship is planned on every call, so a deck is
+/// laid out the same whichever call it falls in; shafts are joined across
+/// decks by [`join_shafts`] once every deck is laid out.
+pub fn generate_deck_layouts(
+    nodes: &[FacilityNode],
+    config: &LayoutConfig,
+    decks: impl RangeBounds<u32>,
+) -> ShipLayoutPlan {
+    // ---- Compute shaft requirements from population ----
+    let shaft_templates = compute_shaft_templates(config.population);
+
+    // ---- Hull sizing from total room area ----
+    let total_area: f32 = nodes.iter().map(|n| n.target_area).sum();
+    let max_room_area: f32 = nodes.iter().map(|n| n.target_area).fold(0.0f32, f32::max);
+    let shaft_area_per_deck: f32 = shaft_templates
+        .iter()
+        .map(|(_, _, _, w, h)| (*w * *h) as f32)
+        .sum();
+
+    let deck_count = if config.deck_count == 0 {
+        compute_optimal_deck_count(
+            total_area,
+            shaft_area_per_deck,
+            &shaft_templates,
+            max_room_area,
+        )
+    } else {
+        config.deck_count
+    };
+
+    let (ship_beam, ship_length) = if config.ship_beam == 0 || config.ship_length == 0 {
+        compute_hull_dimensions(
+            total_area / deck_count as f32,
+            shaft_area_per_deck,
+            max_room_area,
+        )
+    } else {
+        (config.ship_beam, config.ship_length)
+    };
+
+    // ---- Build per-zone room request lists ----
+    let mut zone_requests: Vec<Vec<RoomRequest>> = vec![Vec::new(); 7];
+    for (i, node) in nodes.iter().enumerate() {
+        zone_requests[node.deck_zone.min(6) as usize].push(RoomRequest {
+            node: i,
+            name: node.name.clone(),
+            room_type: node.room_type,
+            target_area: node.target_area,
+            capacity: node.capacity,
+            placement: placement::room_placement(node.room_type),
+        });
+    }
+    for zr in zone_requests.iter_mut() {
+        zr.sort_by(|a, b| {
+            b.target_area
+                .partial_cmp(&a.target_area)
+                .unwrap_or(core::cmp::Ordering::Equal)
+        });
+    }
+
+    // ---- Demand-driven zone-to-deck assignment (outside-in) ----
+    let est_strip_area = {
+        let uw = ship_beam.saturating_sub(SPINE_WIDTH + 2 * RING_WIDTH);
+        let nc = ((ship_length as f32 / 35.0).round() as usize).max(1);
+        let ul = ship_length.saturating_sub(2 * RING_WIDTH + nc * CROSS_CORRIDOR_WIDTH);
+        uw as f32 * ul as f32 * 0.8 - shaft_area_per_deck
+    };
+    let est_strip_area = est_strip_area.max(100.0);
+
+    let mut zone_decks_needed = [0u32; 7];
+    for (needed, zr) in zone_decks_needed.iter_mut().zip(&zone_requests) {
+        let area: f32 = zr.iter().map(|r| r.target_area).sum();
+        if area > 0.0 {
+            *needed = (area / est_strip_area).ceil().max(1.0) as u32;
+        }
+    }
+    let deck_zone_map =
+        deck_zones_with_overrides(&zone_decks_needed, deck_count, &config.zone_overrides);
+
+    // Hand out every deck's room requests up front so corridors can be sized
+    // for the traffic they will carry
+    let mut zones = ZoneQueues::new(zone_requests, &deck_zone_map);
+    let mut planned_requests: Vec<Vec<RoomRequest>> = (0..deck_count)
+        .map(|deck| {
+            deck_inner_size(&config.hull, deck, deck_count, ship_beam, ship_length)
+                .map(|(w, h)| {
+                    zones.take_for_deck(deck_zone_map[deck as usize], deck_area_budget(w, h))
+                })
+                .unwrap_or_default()
+        })
+        .collect();
+    let mut unplaced = Vec::new();
+    if let Some(cap) = config.max_rooms_per_deck {
+        for (deck, reqs) in planned_requests.iter_mut().enumerate() {
+            let over = reqs.split_off(reqs.len().min(cap as usize));
+            if decks.contains(&(deck as u32)) {
+                unplaced.extend(over.iter().map(|r| r.node));
+            }
+        }
+    }
+    let deck_traffic: Vec<f32> = planned_requests
+        .iter()
+        .map(|reqs| corridor_traffic(reqs.iter().map(|r| r.capacity)))
+        .collect();
+    let busiest_deck = deck_traffic.iter().cloned().fold(0.0, f32::max);
+    let spine_width = corridor_width_for_traffic(busiest_deck, SPINE_WIDTH, MAX_SPINE_WIDTH);
+
+    // ---- Compute global shaft positions from midship deck ----
+    // Grid is expanded by HULL_BAND_WIDTH on each side for hull-facing rooms.
+    // All interior positions are offset by HULL_BAND_WIDTH in the grid.
+    let mid_deck = deck_count / 2;
+    let mid_hw_hull = config.hull.width(mid_deck, deck_count, ship_beam);
+    let mid_hl_hull = config.hull.length(mid_deck, deck_count, ship_length);
+    let mid_hw = mid_hw_hull; // no hull band on east/west
+    let mid_hl = mid_hl_hull + 2 * HULL_BAND_WIDTH; // hull band on north/south only
+    let widest_taper = (0..deck_count)
+        .filter(|&d| deck_inner_size(&config.hull, d, deck_count, ship_beam, ship_length).is_some())
+        .map(|d| mid_hw_hull.saturating_sub(config.hull.width(d, deck_count, ship_beam)) / 2)
+        .max()
+        .unwrap_or(0);
+    let (topology, spine_xs) = ship_spines(config.topology, mid_hw, spine_width, widest_taper);
+    // Shafts cluster around the port spine
+    let mid_spine_left = spine_xs[0];
+    let mid_spine_right = mid_spine_left + spine_width;
+    // Cross-corridors are inside the ring; compute in interior space then offset
+    let interior_hl = mid_hl_hull.saturating_sub(2 * RING_WIDTH);
+    // Wider decks need more cross-corridors (rooms further from corridors).
+    // Base: every 35m for full-width decks, up to every 50m for narrow tapered decks.
+    let cross_spacing_base = if mid_hw > 60 {
+        35.0
+    } else if mid_hw > 40 {
+        40.0
+    } else {
+        50.0
+    };
+    let mid_num_cross = ((interior_hl as f32 / cross_spacing_base).round() as usize).max(1);
+    let mid_cross_spacing = interior_hl / (mid_num_cross + 1);
+    let inner_y0_ref = HULL_BAND_WIDTH + RING_WIDTH; // ring outer + ring width
+    let mid_cross_ys: Vec<usize> = (1..=mid_num_cross)
+        .map(|i| inner_y0_ref + i * mid_cross_spacing)
+        .filter(|&cy| cy + CROSS_CORRIDOR_WIDTH <= mid_hl - HULL_BAND_WIDTH - RING_WIDTH)
+        .collect();
+    let shafts = compute_shaft_placements(
+        &shaft_templates,
+        mid_spine_right,
+        mid_spine_left,
+        &mid_cross_ys,
+        mid_hw,
+        mid_hl,
+    );
+
+    // Each shaft serves the unbroken run of decks around midship that fit its
+    // footprint inside the ring and give it a corridor to open onto, so it
+    // stacks at the same (x, y) on every deck it serves
+    let beside_spine = |sp: &ShaftPlacement| {
+        spine_xs
+            .iter()
+            .any(|&sx| sp.x == sx + spine_width || sp.x + sp.w == sx)
+    };
+    let shaft_decks: Vec<Range<usize>> = shafts
+        .iter()
+        .map(|sp| {
+            let fits: Vec<bool> = (0..deck_count)
+                .map(|d| {
+                    let deck_hw = config.hull.width(d, deck_count, ship_beam);
+                    let deck_hl = config.hull.length(d, deck_count, ship_length);
+                    let x_margin = mid_hw_hull.saturating_sub(deck_hw) / 2 + RING_WIDTH;
+                    let inner_y0 =
+                        HULL_BAND_WIDTH + mid_hl_hull.saturating_sub(deck_hl) / 2 + RING_WIDTH;
+                    let inner_y1 = mid_hl.saturating_sub(inner_y0);
+                    // A cross-corridor grows north, ending where the shafts
+                    // south of it start
+                    let cross_width =
+                        deck_cross_width(deck_traffic[d as usize], mid_cross_ys.len());
+                    let below_cross = mid_cross_ys.iter().any(|&cy| {
+                        let cross_end = cy + CROSS_CORRIDOR_WIDTH;
+                        sp.y == cross_end
+                            && cross_end >= inner_y0 + cross_width
+                            && cross_end <= inner_y1
+                    });
+                    deck_hw >= 12
+                        && deck_hl >= 30
+                        && shaft_fits(sp, x_margin, inner_y0, mid_hw, mid_hl)
+                        && (beside_spine(sp) || below_cross)
+                })
+                .collect();
+            shaft_deck_span(&fits, mid_deck as usize)
+        })
+        .collect();
+
+    let ship = ShipGrid {
+        config,
+        deck_count,
+        ship_beam,
+        ship_length,
+        mid_hw_hull,
+        mid_hl_hull,
+        hw: mid_hw,
+        hl: mid_hl,
+        spine_width,
+        topology,
+        spine_xs,
+        mid_cross_ys,
+        shafts,
+        shaft_decks,
+    };
+    let mut builder = PlanBuilder {
+        plan: ShipLayoutPlan {
+            deck_count,
+            ship_beam,
+            ship_length,
+            deck_zones: deck_zone_map,
+            unplaced,
+            ..Default::default()
+        },
+        first_room_id: config.first_room_id,
+    };
+
+    // ---- Per-deck generation ----
+    for deck in 0..deck_count {
+        if decks.contains(&deck) {
+            let requests = std::mem::take(&mut planned_requests[deck as usize]);
+            lay_out_deck(
+                &mut builder,
+                &ship,
+                deck,
+                deck_traffic[deck as usize],
+                requests,
+            );
+        }
+    }
+    if decks.contains(&deck_count.saturating_sub(1)) {
+        // Rooms no deck had space for
+        builder.plan.unplaced.extend(zones.leftover());
+    }
+
+    builder.plan.shafts = ship
+        .shafts
+        .iter()
+        .map(|sp| LayoutShaft {
+            name: sp.name.to_string(),
+            shaft_type: sp.shaft_type,
+            is_main: sp.is_main,
+            x: sp.x as f32 + sp.w as f32 / 2.0,
+            y: sp.y as f32 + sp.h as f32 / 2.0,
+            width: sp.w as f32,
+            height: sp.h as f32,
+            deck_rooms: Vec::new(),
+            decks_served: Vec::new(),
+        })
+        .collect();
+    builder.plan
+}
+
+/// Name of a shaft's room on `deck`, which [`join_shafts`] finds it by.
+pub fn shaft_room_name(shaft: &str, deck: i32) -> String {
+    format!("{} D{}", shaft, deck + 1)
+}
+
+/// Find each shaft's room on every deck with `room_id` (by
+/// [`shaft_room_name`]), fill in the shaft's stack, and return the doors
+/// joining its rooms on neighboring decks at the center of the shared
+/// footprint — the only cross-deck doors in the ship.
+pub fn join_shafts(
+    shafts: &mut [LayoutShaft],
+    deck_count: u32,
+    room_id: impl Fn(&str) -> Option<u32>,
+) -> Vec<LayoutDoor> {
+    let mut doors = Vec::new();
+    for shaft in shafts.iter_mut() {
+        let stack: Vec<(i32, u32)> = (0..deck_count as i32)
+            .filter_map(|d| room_id(&shaft_room_name(&shaft.name, d)).map(|id| (d, id)))
+            .collect();
+        let access_level = if shaft.is_main {
+            access_levels::PUBLIC
+        } else {
+            access_levels::CREW_ONLY
+        };
+        for pair in stack.windows(2) {
+            if pair[1].0 != pair[0].0 + 1 {
+                continue;
+            }
+            doors.push(LayoutDoor {
+                room_a: pair[0].1,
+                room_b: pair[1].1,
+                wall_a: wall_sides::SOUTH,
+                wall_b: wall_sides::NORTH,
+                x: shaft.x,
+                y: shaft.y,
+                width: 3.0,
+                access_level,
+            });
+        }
+        shaft.decks_served = stack.iter().map(|&(d, _)| d).collect();
+        shaft.deck_rooms = stack.into_iter().map(|(_, id)| id).collect();
+    }
+    doors
+}
+
+/// Grid tag of a placed room's cells.
+fn cell_tag(room_id: u32) -> u8 {
+    CELL_ROOM_BASE + (room_id as u8 % 246)
+}
+
+/// Stamp `tag` into the empty cells of `rect`, within the grid.
+fn stamp_empty(grid: &mut [Vec<u8>], (x, y, w, h): Cells, tag: u8) {
+    let (hw, hl) = (grid.len(), grid.first().map_or(0, Vec::len));
+    for col in grid.iter_mut().take((x + w).min(hw)).skip(x) {
+        for cell in col.iter_mut().take((y + h).min(hl)).skip(y) {
+            if *cell == CELL_EMPTY {
+                *cell = tag;
+            }
+        }
+    }
+}
+
+/// Width of each of a deck's `num_cross` cross-corridors, sharing its
+/// `traffic`.
+fn deck_cross_width(traffic: f32, num_cross: usize) -> usize {
+    corridor_width_for_traffic(
+        traffic / num_cross.max(1) as f32,
+        CROSS_CORRIDOR_WIDTH,
+        MAX_CROSS_CORRIDOR_WIDTH,
+    )
+}
+
+/// Y ranges between the cross-corridors that fit inside `y0..y1`.
+fn between_cross_corridors(
+    y0: usize,
+    y1: usize,
+    cross_ys: &[usize],
+    cross_width: usize,
+) -> Vec<(usize, usize)> {
+    let mut bounds: Vec<usize> = vec![y0];
+    for &cy in cross_ys {
+        if cy >= y0 && cy + cross_width <= y1 {
+            bounds.push(cy);
+            bounds.push(cy + cross_width);
+        }
+    }
+    bounds.push(y1);
+    bounds
+        .chunks(2)
+        .filter(|c| c.len() == 2 && c[0] < c[1])
+        .map(|c| (c[0], c[1]))
+        .collect()
+}
+
+/// Lay out one deck into the plan.
+fn lay_out_deck(
+    b: &mut PlanBuilder,
+    ship: &ShipGrid,
+    deck: u32,
+    traffic: f32,
+    deck_requests: Vec<RoomRequest>,
+) {
+    let config = ship.config;
+    let deck_i = deck as i32;
+    let deck_hw = config.hull.width(deck, ship.deck_count, ship.ship_beam);
+    let deck_hl = config.hull.length(deck, ship.deck_count, ship.ship_length);
+    let wanted: Vec<usize> = deck_requests.iter().map(|r| r.node).collect();
+    if deck_hw < 12 || deck_hl < 30 {
+        b.plan.unplaced.extend(wanted);
+        return;
+    }
+
+    let hw = ship.hw;
+    let hl = ship.hl;
+    let mut grid: Vec<Vec<u8>> = vec![vec![CELL_EMPTY; hl]; hw];
+
+    // Mask cells outside tapered hull (hull band cells stay CELL_EMPTY)
+    let taper_margin_x = ship.mid_hw_hull.saturating_sub(deck_hw) / 2;
+    let taper_margin_y = ship.mid_hl_hull.saturating_sub(deck_hl) / 2;
+    let hull_x0 = taper_margin_x; // outer hull edge in expanded grid
+    let hull_x1 = hw - taper_margin_x;
+    let hull_y0 = taper_margin_y;
+    let hull_y1 = hl - taper_margin_y;
+    for (x, col) in grid.iter_mut().enumerate() {
+        for (y, cell) in col.iter_mut().enumerate() {
+            if x < hull_x0 || x >= hull_x1 || y < hull_y0 || y >= hull_y1 {
+                *cell = CELL_HULL;
+            }
+        }
+    }
+
+    // Round the fore/aft deck ends into elliptical caps. Only the hull band
+    // rows are trimmed, so the ring corridor rectangle stays intact.
+    let deck_w = hull_x1 - hull_x0;
+    for d in 0..HULL_BAND_WIDTH.min((hull_y1 - hull_y0) / 2) {
+        let inset = config.hull.end_cap_inset(d, HULL_BAND_WIDTH, deck_w);
+        let north_y = hull_y0 + HULL_BAND_WIDTH - 1 - d;
+        let south_y = hull_y1 - HULL_BAND_WIDTH + d;
+        for x in (hull_x0..hull_x0 + inset).chain(hull_x1 - inset..hull_x1) {
+            grid[x][north_y] = CELL_HULL;
+            grid[x][south_y] = CELL_HULL;
+        }
+    }
+
+    // Ring corridor: no hull band on X (ring touches hull), hull band on Y (N/S)
+    let ring_x0 = taper_margin_x;
+    let ring_x1 = hw - taper_margin_x;
+    let ring_y0 = HULL_BAND_WIDTH + taper_margin_y;
+    let ring_y1 = hl - HULL_BAND_WIDTH - taper_margin_y;
+    let inner_x0 = ring_x0 + RING_WIDTH;
+    let inner_x1 = ring_x1.saturating_sub(RING_WIDTH);
+    let inner_y0 = ring_y0 + RING_WIDTH;
+    let inner_y1 = ring_y1.saturating_sub(RING_WIDTH);
+
+    // Busy decks get wider cross-corridors, grown north so the shafts
+    // south of them stay put
+    let cross_width = deck_cross_width(traffic, ship.mid_cross_ys.len());
+    let cross_ys: Vec<usize> = ship
+        .mid_cross_ys
+        .iter()
+        .map(|&cy| cy - (cross_width - CROSS_CORRIDOR_WIDTH))
+        .collect();
+
+    // ---- Phase 1: Ring corridor (perimeter) ----
+    for (x, col) in grid.iter_mut().enumerate().take(ring_x1).skip(ring_x0) {
+        for (y, cell) in col.iter_mut().enumerate().take(ring_y1).skip(ring_y0) {
+            if *cell == CELL_HULL {
+                continue;
+            }
+            if x < inner_x0 || x >= inner_x1 || y < inner_y0 || y >= inner_y1 {
+                *cell = CELL_MAIN_CORRIDOR;
+            }
+        }
+    }
+
+    // Ring Room entries (4 segments: N, S, W, E)
+    let ring_w = ring_x1 - ring_x0;
+    let ring_grids: [Cells; 4] = [
+        (ring_x0, ring_y0, ring_w, RING_WIDTH),
+        (ring_x0, inner_y1, ring_w, RING_WIDTH),
+        (ring_x0, inner_y0, RING_WIDTH, inner_y1 - inner_y0),
+        (
+            ring_x1 - RING_WIDTH,
+            inner_y0,
+            RING_WIDTH,
+            inner_y1 - inner_y0,
+        ),
+    ];
+    let mut ring_ids = [0u32; 4];
+    for (i, side) in ["North", "South", "West", "East"].into_iter().enumerate() {
+        ring_ids[i] = b.room(
+            None,
+            format!("Ring {} D{}", side, deck + 1),
+            room_types::CORRIDOR,
+            deck_i,
+            0,
+            ring_grids[i],
+        );
+    }
+    let [ring_n_id, ring_s_id, ring_w_id, ring_e_id] = ring_ids;
+    let [ring_n_grid, ring_s_grid, ring_w_grid, ring_e_grid] = ring_grids;
+
+    // Ring corner doors (N↔W, N↔E, S↔W, S↔E)
+    for (a_id, a_grid, b_id, b_grid) in [
+        (ring_n_id, ring_n_grid, ring_w_id, ring_w_grid),
+        (ring_n_id, ring_n_grid, ring_e_id, ring_e_grid),
+        (ring_s_id, ring_s_grid, ring_w_id, ring_w_grid),
+        (ring_s_id, ring_s_grid, ring_e_id, ring_e_grid),
+    ] {
+        if let Some(edge) = shared_edge(a_grid, b_grid) {
+            b.door(a_id, b_id, edge, RING_WIDTH as f32, access_levels::PUBLIC);
+        }
+    }
+    b.plan.corridors.push(LayoutCorridor {
+        deck: deck_i,
+        corridor_type: corridor_types::MAIN,
+        x: ring_x0 as f32,
+        y: ring_y0 as f32,
+        width: ring_w as f32,
+        length: RING_WIDTH as f32,
+        orientation: 0,
+        carries: carries_flags::CREW_PATH,
+    });
+
+    // ---- Phase 2: Spine corridors ----
+    // Each spine runs from ring-north inner edge to ring-south inner edge
+    let topology = ship.topology;
+    let spine_width = ship.spine_width;
+    let spine_xs = ship.spine_xs.as_slice();
+    let bands = between_cross_corridors(inner_y0, inner_y1, &cross_ys, cross_width);
+    let mut spine_segments: Vec<(u32, usize, usize, usize)> = Vec::new();
+    for (&spine_x, spine_name) in spine_xs.iter().zip(topology.spine_names()) {
+        stamp_empty(
+            &mut grid,
+            (spine_x, inner_y0, spine_width, inner_y1 - inner_y0),
+            CELL_MAIN_CORRIDOR,
+        );
+        // Spine segment Room entries (between cross-corridors)
+        for &(y0, y1) in &bands {
+            let seg_id = b.room(
+                None,
+                format!("{} D{} Y{}-{}", spine_name, deck + 1, y0, y1),
+                room_types::CORRIDOR,
+                deck_i,
+                0,
+                (spine_x, y0, spine_width, y1 - y0),
+            );
+            spine_segments.push((seg_id, spine_x, y0, y1));
+        }
+        b.plan.corridors.push(LayoutCorridor {
+            deck: deck_i,
+            corridor_type: corridor_types::MAIN,
+            x: spine_x as f32,
+            y: inner_y0 as f32,
+            width: spine_width as f32,
+            length: (inner_y1 - inner_y0) as f32,
+            orientation: 1,
+            carries: carries_flags::CREW_PATH | carries_flags::POWER | carries_flags::DATA,
+        });
+    }
+
+    // Room columns between the ring and the spines, port to starboard
+    let mut column_edges = vec![inner_x0];
+    for &spine_x in spine_xs {
+        column_edges.push(spine_x);
+        column_edges.push(spine_x + spine_width);
+    }
+    column_edges.push(inner_x1);
+    let columns: Vec<(usize, usize)> = column_edges.chunks(2).map(|c| (c[0], c[1])).collect();
+
+    // Cross-corridor Room entries (from ring-west to ring-east)
+    let mut cross_rooms: Vec<(u32, usize)> = Vec::new();
+    for &cy in &cross_ys {
+        if cy < inner_y0 || cy + cross_width > inner_y1 {
+            continue;
+        }
+        let cc_w = inner_x1.saturating_sub(inner_x0);
+        if cc_w < MIN_ROOM_DIM {
+            continue;
+        }
+        let cc = (inner_x0, cy, cc_w, cross_width);
+        stamp_empty(&mut grid, cc, CELL_MAIN_CORRIDOR);
+        let cc_id = b.room(
+            None,
+            format!("Cross-Corridor D{} Y{}", deck + 1, cy),
+            room_types::CROSS_CORRIDOR,
+            deck_i,
+            0,
+            cc,
+        );
+        b.plan.corridors.push(LayoutCorridor {
+            deck: deck_i,
+            corridor_type: corridor_types::BRANCH,
+            x: inner_x0 as f32,
+            y: cy as f32,
+            width: cc_w as f32,
+            length: cross_width as f32,
+            orientation: 0,
+            carries: carries_flags::CREW_PATH,
+        });
+        cross_rooms.push((cc_id, cy));
+    }
+
+    // ---- Phase 2.5: Spur corridors into wide segments ----
+    // When a column is too wide for rooms to reach from the spine or ring,
+    // a spur runs across it through the middle of each segment.
+    let mut spur_rooms: Vec<(u32, Cells)> = Vec::new();
+    for &(seg_y0, seg_y1) in &bands {
+        let seg_h = seg_y1 - seg_y0;
+        if seg_h < SPUR_WIDTH + 2 * MIN_ROOM_DIM {
+            continue; // too short for a spur + rooms on both sides
+        }
+        // Spur Y position: centered in segment
+        let spur_y = seg_y0 + (seg_h - SPUR_WIDTH) / 2;
+        for (&(x0, x1), column_name) in columns.iter().zip(topology.column_names()) {
+            // Only add a spur if rooms can't reach a corridor without one, and
+            // the segment keeps MIN_ROOM_DIM of depth on each side of it
+            let spur_len = x1.saturating_sub(x0);
+            if spur_len > SPUR_THRESHOLD && seg_h > 2 * SPUR_WIDTH + 2 * MIN_ROOM_DIM {
+                let spur = (x0, spur_y, spur_len, SPUR_WIDTH);
+                stamp_empty(&mut grid, spur, CELL_MAIN_CORRIDOR);
+                let spur_id = b.room(
+                    None,
+                    format!("Spur {} D{} Y{}", column_name, deck + 1, spur_y),
+                    room_types::CORRIDOR,
+                    deck_i,
+                    0,
+                    spur,
+                );
+                spur_rooms.push((spur_id, spur));
+            }
+        }
+    }
+
+    // Doors: spur ↔ each spine it meets, spur ↔ ring
+    let ring_side_h = ring_y1 - ring_y0;
+    for &(spur_id, spur) in &spur_rooms {
+        for &spine_x in spine_xs {
+            let joint = spine_segments.iter().filter(|s| s.1 == spine_x).find_map(
+                |&(seg_id, x, y0, y1)| {
+                    shared_edge(spur, (x, y0, spine_width, y1 - y0)).map(|e| (seg_id, e))
+                },
+            );
+            if let Some((seg_id, edge)) = joint {
+                b.door(
+                    spur_id,
+                    seg_id,
+                    edge,
+                    SPUR_WIDTH as f32,
+                    access_levels::PUBLIC,
+                );
+            }
+        }
+        for (ring_id, ring) in [
+            (ring_w_id, (ring_x0, ring_y0, RING_WIDTH, ring_side_h)),
+            (
+                ring_e_id,
+                (ring_x1 - RING_WIDTH, ring_y0, RING_WIDTH, ring_side_h),
+            ),
+        ] {
+            if let Some(edge) = shared_edge(spur, ring) {
+                b.door(
+                    spur_id,
+                    ring_id,
+                    edge,
+                    SPUR_WIDTH as f32,
+                    access_levels::PUBLIC,
+                );
+            }
+        }
+    }
+
+    // ---- Corridor-to-corridor doors ----
+    let spine_door_x = |x: usize| x as f32 + spine_width as f32 / 2.0;
+
+    // Spine ↔ cross-corridors
+    for &(cc_id, cy) in &cross_rooms {
+        for &(seg_id, x, seg_y0, seg_y1) in &spine_segments {
+            if seg_y1 == cy {
+                b.door(
+                    seg_id,
+                    cc_id,
+                    junction(
+                        spine_door_x(x),
+                        cy as f32,
+                        wall_sides::SOUTH,
+                        wall_sides::NORTH,
+                    ),
+                    spine_width as f32,
+                    access_levels::PUBLIC,
+                );
+            }
+            if seg_y0 == cy + cross_width {
+                b.door(
+                    cc_id,
+                    seg_id,
+                    junction(
+                        spine_door_x(x),
+                        (cy + cross_width) as f32,
+                        wall_sides::SOUTH,
+                        wall_sides::NORTH,
+                    ),
+                    spine_width as f32,
+                    access_levels::PUBLIC,
+                );
+            }
+        }
+    }
+
+    // Consecutive segments of the same spine
+    for pair in spine_segments.windows(2) {
+        let (seg_a, a_x, _, seg_a_end) = pair[0];
+        let (seg_b, b_x, seg_b_start, _) = pair[1];
+        if a_x == b_x && seg_a_end == seg_b_start {
+            b.door(
+                seg_a,
+                seg_b,
+                junction(
+                    spine_door_x(a_x),
+                    seg_a_end as f32,
+                    wall_sides::SOUTH,
+                    wall_sides::NORTH,
+                ),
+                spine_width as f32,
+                access_levels::PUBLIC,
+            );
+        }
+    }
+
+    // Spine ↔ ring (north and south ends)
+    for &spine_x in spine_xs {
+        let mut segs = spine_segments.iter().filter(|s| s.1 == spine_x);
+        if let Some(&(first_seg, ..)) = segs.clone().next() {
+            b.door(
+                ring_n_id,
+                first_seg,
+                junction(
+                    spine_door_x(spine_x),
+                    inner_y0 as f32,
+                    wall_sides::SOUTH,
+                    wall_sides::NORTH,
+                ),
+                spine_width as f32,
+                access_levels::PUBLIC,
+            );
+        }
+        if let Some(&(last_seg, ..)) = segs.next_back() {
+            b.door(
+                last_seg,
+                ring_s_id,
+                junction(
+                    spine_door_x(spine_x),
+                    inner_y1 as f32,
+                    wall_sides::SOUTH,
+                    wall_sides::NORTH,
+                ),
+                spine_width as f32,
+                access_levels::PUBLIC,
+            );
+        }
+    }
+
+    // Cross-corridors ↔ ring (west and east ends)
+    for &(cc_id, cy) in &cross_rooms {
+        let cc_mid_y = cy as f32 + cross_width as f32 / 2.0;
+        b.door(
+            ring_w_id,
+            cc_id,
+            junction(
+                inner_x0 as f32,
+                cc_mid_y,
+                wall_sides::EAST,
+                wall_sides::WEST,
+            ),
+            cross_width as f32,
+            access_levels::PUBLIC,
+        );
+        b.door(
+            cc_id,
+            ring_e_id,
+            junction(
+                inner_x1 as f32,
+                cc_mid_y,
+                wall_sides::EAST,
+                wall_sides::WEST,
+            ),
+            cross_width as f32,
+            access_levels::PUBLIC,
+        );
+    }
+
+    let corridors = DeckCorridors {
+        spine_width,
+        spine_segments,
+        cross_rooms,
+        cross_width,
+        spur_rooms,
+        inner_x0,
+        inner_x1,
+        inner_y1,
+        ring_x0,
+        ring_x1,
+        ring_y0,
+        ring_y1,
+        ring_ids,
+    };
+
+    // ---- Phase 3: Stamp shafts ----
+    let deck_shafts: Vec<&ShaftPlacement> = ship
+        .shafts
+        .iter()
+        .zip(&ship.shaft_decks)
+        .filter(|(_, span)| span.contains(&(deck as usize)))
+        .map(|(sp, _)| sp)
+        .collect();
+    for sp in &deck_shafts {
+        for col in grid.iter_mut().take((sp.x + sp.w).min(hw)).skip(sp.x) {
+            for cell in col.iter_mut().take((sp.y + sp.h).min(hl)).skip(sp.y) {
+                *cell = CELL_SHAFT;
+            }
+        }
+    }
+
+    // Shaft Room entries + doors to corridors
+    for sp in &deck_shafts {
+        let srt = if sp.shaft_type == shaft_types::ELEVATOR {
+            room_types::ELEVATOR_SHAFT
+        } else if sp.shaft_type == shaft_types::SERVICE_ELEVATOR {
+            room_types::SERVICE_ELEVATOR_SHAFT
+        } else {
+            room_types::LADDER_SHAFT
+        };
+        let shaft_room_id = b.room(
+            None,
+            shaft_room_name(sp.name, deck_i),
+            srt,
+            deck_i,
+            0,
+            (sp.x, sp.y, sp.w, sp.h),
+        );
+        let access = if sp.is_main {
+            access_levels::PUBLIC
+        } else {
+            access_levels::CREW_ONLY
+        };
+        connect_shaft_to_corridor(
+            b,
+            shaft_room_id,
+            sp,
+            &corridors,
+            ring_ids,
+            ring_grids,
+            access,
+        );
+    }
+
+    // ---- Phase 3.5: Pre-place oversized rooms ----
+    // Rooms >200m² get placed directly into the grid as large rectangles before
+    // segment decomposition. This lets them claim the space they need instead
+    // of being squeezed into narrow strips.
+    let mut placed_rooms: Vec<PlacedRoom> = Vec::new();
+
+    let inner_w = inner_x1 - inner_x0;
+    let inner_h = inner_y1 - inner_y0;
+    let mut deck_requests = deck_requests;
+    // Sort largest first
+    deck_requests.sort_by(|a, b| {
+        b.target_area
+            .partial_cmp(&a.target_area)
+            .unwrap_or(core::cmp::Ordering::Equal)
+    });
+
+    // Minimum area threshold for pre-placement (smaller rooms go through BSP)
+    const LARGE_ROOM_THRESHOLD: f32 = 200.0;
+
+    // Separate oversized rooms AND rooms with special placement constraints
+    // Hull-facing rooms are excluded here — they go to the hull band phase.
+    let (mut hull_facing_requests, non_hull_requests): (Vec<RoomRequest>, Vec<RoomRequest>) =
+        deck_requests
+            .into_iter()
+            .partition(|r| r.placement == placement::HULL_FACING);
+    // Airlocks, then observation rooms, claim hull band space first
+    hull_facing_requests.sort_by_key(|r| hull_band_priority(r.room_type));
+    let (large_requests, mut normal_requests): (Vec<RoomRequest>, Vec<RoomRequest>) =
+        non_hull_requests.into_iter().partition(|r| {
+            r.target_area >= LARGE_ROOM_THRESHOLD
+                || r.placement == placement::AFT
+                || r.placement == placement::FORWARD
+                || r.placement == placement::INTERIOR
+        });
+
+    // Place each large room directly into the grid
+    let mid_y = (inner_y0 + inner_y1) / 2;
+    for req in &large_requests {
+        let target_side = (req.target_area.sqrt()) as usize;
+        let want_w = target_side.max(MIN_ROOM_DIM);
+        let want_h = ((req.target_area / want_w as f32).ceil() as usize).max(MIN_ROOM_DIM);
+
+        // Determine preferred region based on placement constraint
+        let search_region = match req.placement {
+            p if p == placement::AFT => (inner_x0, mid_y, inner_w, inner_y1 - mid_y),
+            p if p == placement::FORWARD => (inner_x0, inner_y0, inner_w, mid_y - inner_y0),
+            p if p == placement::INTERIOR => {
+                let margin = RING_WIDTH * 2;
+                (
+                    inner_x0 + margin,
+                    inner_y0 + margin,
+                    inner_w.saturating_sub(margin * 2),
+                    inner_h.saturating_sub(margin * 2),
+                )
+            }
+            _ => (inner_x0, inner_y0, inner_w, inner_h),
+        };
+
+        // Fall back to the entire inner area if the constrained search fails
+        let best_pos =
+            find_clear_rect_for_room(&grid, search_region, want_w, want_h).or_else(|| {
+                find_clear_rect_for_room(
+                    &grid,
+                    (inner_x0, inner_y0, inner_w, inner_h),
+                    want_w,
+                    want_h,
+                )
+            });
+
+        // A room with no corridor to open onto is left to the segment packer
+        let Some(rect) = best_pos.filter(|&rect| corridors.touches_any(rect)) else {
+            normal_requests.push(req.clone());
+            continue;
+        };
+        let room_id = b.room(
+            Some(req.node),
+            req.name.clone(),
+            req.room_type,
+            deck_i,
+            req.capacity,
+            rect,
+        );
+        stamp_empty(&mut grid, rect, cell_tag(room_id));
+        create_corridor_door(b, room_id, rect, &corridors);
+        placed_rooms.push(PlacedRoom {
+            id: room_id,
+            x: rect.0,
+            y: rect.1,
+            w: rect.2,
+            h: rect.3,
+            room_type: req.room_type,
+            placement: req.placement,
+        });
+    }
+
+    // Remaining requests for BSP (normal-sized rooms, and large rooms that found no spot)
+    let deck_requests = normal_requests;
+
+    // ---- Phase 3.75: Maintenance crawlways ----
+    // A crawlway runs down the seam between the rooms facing the ring and
+    // those facing a spine, cut into runs by cross-corridors, spurs,
+    // shafts and pre-placed rooms. Each run is hatched into the corridors
+    // at its ends; runs that reach no corridor are left to the rooms.
+    let crawl_xs: Vec<usize> = columns
+        .iter()
+        .filter_map(|&(x0, x1)| crawlway_column(x0, x1, MIN_ROOM_DIM))
+        .collect();
+    let mut hatch_targets: Vec<(u32, Cells)> =
+        vec![(ring_n_id, ring_n_grid), (ring_s_id, ring_s_grid)];
+    hatch_targets.extend(
+        corridors
+            .cross_rooms
+            .iter()
+            .map(|&(cc_id, cy)| (cc_id, (inner_x0, cy, inner_x1 - inner_x0, cross_width))),
+    );
+    hatch_targets.extend(corridors.spur_rooms.iter().copied());
+    for &cx in &crawl_xs {
+        for (y0, y1) in crawlway_runs(inner_y0, inner_y1, |y| grid[cx][y] == CELL_EMPTY) {
+            let crawl = (cx, y0, CRAWLWAY_WIDTH, y1 - y0);
+            let hatches: Vec<(u32, SharedEdge)> = hatch_targets
+                .iter()
+                .filter_map(|&(target_id, target)| {
+                    shared_edge(crawl, target).map(|edge| (target_id, edge))
+                })
+                .collect();
+            if hatches.is_empty() {
+                continue;
+            }
+            for cell in &mut grid[cx][y0..y1] {
+                *cell = CELL_CRAWLWAY;
+            }
+            let crawl_id = b.room(
+                None,
+                format!("Crawlway D{} X{} Y{}-{}", deck + 1, cx, y0, y1),
+                room_types::CRAWLWAY,
+                deck_i,
+                0,
+                crawl,
+            );
+            b.plan.corridors.push(LayoutCorridor {
+                deck: deck_i,
+                corridor_type: corridor_types::CRAWLWAY,
+                x: cx as f32,
+                y: y0 as f32,
+                width: CRAWLWAY_WIDTH as f32,
+                length: (y1 - y0) as f32,
+                orientation: 1,
+                carries: carries_flags::POWER | carries_flags::HVAC | carries_flags::COOLANT,
+            });
+            for (target_id, edge) in hatches {
+                b.door(
+                    crawl_id,
+                    target_id,
+                    edge,
+                    CRAWLWAY_WIDTH as f32,
+                    access_levels::CREW_ONLY,
+                );
+            }
+        }
+    }
+
+    // ---- Phase 4: Identify rectangular segments between corridors ----
+    // Segments are computed AFTER oversized rooms so they naturally work around them.
+    let segments = find_segments(&grid, &columns, &bands, &crawl_xs, spine_xs, spine_width);
+
+    // ---- Phase 6: BSP room placement into segments ----
+    // Match requests to segments by area ratio instead of sequential consumption.
+    let total_request_area: f32 = deck_requests.iter().map(|r| r.target_area).sum();
+    let total_seg_area: usize = segments.iter().map(|s| s.2 * s.3).sum();
+
+    // Sort segments largest-first so big rooms get big segments
+    let mut seg_order: Vec<usize> = (0..segments.len()).collect();
+    seg_order.sort_by(|&a, &b| {
+        let area_a = segments[a].2 * segments[a].3;
+        let area_b = segments[b].2 * segments[b].3;
+        area_b.cmp(&area_a)
+    });
+
+    // Track which requests have been placed
+    let mut request_used: Vec<bool> = vec![false; deck_requests.len()];
+
+    for &si in &seg_order {
+        let (seg_x, seg_y, seg_w, seg_h) = segments[si];
+        let seg_area = (seg_w * seg_h) as f32;
+        // How many requests should this segment get (proportional to area)
+        let seg_share = if total_request_area > 0.0 {
+            (seg_area / total_seg_area.max(1) as f32 * deck_requests.len() as f32).ceil() as usize
+        } else {
+            0
+        };
+        // Collect best-fitting unused requests for this segment (sorted by area match)
+        let mut candidates: Vec<(usize, f32)> = deck_requests
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !request_used[*i])
+            .map(|(i, r)| {
+                let ratio = (r.target_area / seg_area - 1.0 / seg_share.max(1) as f32).abs();
+                (i, ratio)
+            })
+            .collect();
+        candidates.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(core::cmp::Ordering::Equal));
+        let selected: Vec<usize> = candidates
+            .iter()
+            .take(seg_share.max(1))
+            .map(|(i, _)| *i)
+            .collect();
+        if selected.is_empty() {
+            continue;
+        }
+
+        // Requests for BSP, sorted largest-first within the selection
+        let mut seg_requests: Vec<&RoomRequest> =
+            selected.iter().map(|&i| &deck_requests[i]).collect();
+        seg_requests.sort_by(|a, b| {
+            b.target_area
+                .partial_cmp(&a.target_area)
+                .unwrap_or(core::cmp::Ordering::Equal)
+        });
+        let seg_areas: Vec<f32> = seg_requests.iter().map(|r| r.target_area).collect();
+        let mut sub_rects: Vec<Cells> = Vec::new();
+        bsp_subdivide(seg_x, seg_y, seg_w, seg_h, &seg_areas, &mut sub_rects);
+
+        let mut seg_req_idx = 0usize;
+        for &(rx, ry, rw, rh) in &sub_rects {
+            if seg_req_idx >= selected.len() {
+                break;
+            }
+            let has_conflict = (rx..(rx + rw).min(hw))
+                .any(|gx| (ry..(ry + rh).min(hl)).any(|gy| grid[gx][gy] != CELL_EMPTY));
+            // Only place if room touches a corridor
+            if has_conflict || !corridors.touches_any((rx, ry, rw, rh)) {
+                continue;
+            }
+
+            let orig_idx = selected[seg_req_idx];
+            let req = &deck_requests[orig_idx];
+            let room_id = b.room(
+                Some(req.node),
+                req.name.clone(),
+                req.room_type,
+                deck_i,
+                req.capacity,
+                (rx, ry, rw, rh),
+            );
+            stamp_empty(&mut grid, (rx, ry, rw, rh), cell_tag(room_id));
+            create_corridor_door(b, room_id, (rx, ry, rw, rh), &corridors);
+            placed_rooms.push(PlacedRoom {
+                id: room_id,
+                x: rx,
+                y: ry,
+                w: rw,
+                h: rh,
+                room_type: req.room_type,
+                placement: req.placement,
+            });
+            request_used[orig_idx] = true;
+            seg_req_idx += 1;
+        }
+    }
+
+    let inner_bounds: Bounds = (inner_x0, inner_y0, inner_x1, inner_y1);
+    let band_bounds: Bounds = (ring_x0, hull_y0, ring_x1, hull_y1);
+
+    // ---- Phase 6.5: Expand BSP-placed rooms into empty space ----
+    expand_rooms_into_empty(b, &mut grid, &mut placed_rooms, inner_bounds, band_bounds);
+
+    // ---- Phase 7: Wavefront BFS gap fill ----
+    // Grow corridor into remaining empty cells, place rooms from remaining requests
+    {
+        let remaining_requests: Vec<&RoomRequest> = deck_requests
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !request_used[*i])
+            .map(|(_, r)| r)
+            .collect();
+        let mut req_cursor = 0usize;
+
+        // BFS frontier: all corridor cells adjacent to empty cells
+        let mut frontier: Vec<(usize, usize)> = Vec::new();
+        for x in inner_x0..inner_x1 {
+            for y in inner_y0..inner_y1 {
+                if grid[x][y] == CELL_MAIN_CORRIDOR {
+                    for &(dx, dy) in &[(0isize, 1isize), (0, -1), (1, 0), (-1, 0)] {
+                        let nx = (x as isize + dx) as usize;
+                        let ny = (y as isize + dy) as usize;
+                        if nx < hw && ny < hl && grid[nx][ny] == CELL_EMPTY {
+                            frontier.push((x, y));
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        // Expand from each frontier position: find largest empty rect touching corridor
+        let mut visited: Vec<Vec<bool>> = vec![vec![false; hl]; hw];
+        for &(fx, fy) in &frontier {
+            if req_cursor >= remaining_requests.len() {
+                break;
+            }
+            for &(dx, dy) in &[(0isize, 1isize), (0, -1), (1, 0), (-1, 0)] {
+                let start_x = (fx as isize + dx) as usize;
+                let start_y = (fy as isize + dy) as usize;
+                if start_x >= hw
+                    || start_y >= hl
+                    || grid[start_x][start_y] != CELL_EMPTY
+                    || visited[start_x][start_y]
+                {
+                    continue;
+                }
+
+                // Expand to largest empty rectangle from this cell
+                let mut max_w = 0usize;
+                for ddx in 0..inner_x1.saturating_sub(start_x) {
+                    if start_x + ddx >= hw || grid[start_x + ddx][start_y] != CELL_EMPTY {
+                        break;
+                    }
+                    max_w = ddx + 1;
+                }
+                let mut max_h = inner_y1.saturating_sub(start_y);
+                for ddy in 0..max_h {
+                    if start_y + ddy >= hl {
+                        max_h = ddy;
+                        break;
+                    }
+                    let row_clear = (0..max_w).all(|ddx| {
+                        start_x + ddx < hw && grid[start_x + ddx][start_y + ddy] == CELL_EMPTY
+                    });
+                    if !row_clear {
+                        max_h = ddy;
+                        break;
+                    }
+                }
+
+                // The room gets the full available rectangle
+                let rect = (start_x, start_y, max_w, max_h);
+                if max_w >= MIN_ROOM_DIM
+                    && max_h >= MIN_ROOM_DIM
+                    && req_cursor < remaining_requests.len()
+                    && corridors.touches_any(rect)
+                {
+                    let req = remaining_requests[req_cursor];
+                    let room_id = b.room(
+                        Some(req.node),
+                        req.name.clone(),
+                        req.room_type,
+                        deck_i,
+                        req.capacity,
+                        rect,
+                    );
+                    for gx in start_x..(start_x + max_w).min(hw) {
+                        for gy in start_y..(start_y + max_h).min(hl) {
+                            grid[gx][gy] = cell_tag(room_id);
+                            visited[gx][gy] = true;
+                        }
+                    }
+                    create_corridor_door(b, room_id, rect, &corridors);
+                    placed_rooms.push(PlacedRoom {
+                        id: room_id,
+                        x: start_x,
+                        y: start_y,
+                        w: max_w,
+                        h: max_h,
+                        room_type: req.room_type,
+                        placement: req.placement,
+                    });
+                    req_cursor += 1;
+                }
+            }
+        }
+    }
+
+    // ---- Phase 7.5: Expand wavefront-placed rooms into empty space ----
+    expand_rooms_into_empty(b, &mut grid, &mut placed_rooms, inner_bounds, band_bounds);
+
+    // ---- Phase 8: Filler backfill ----
+    {
+        let mut filler_idx = 0usize;
+        let mut filler_count = 0u32;
+
+        let mut y = inner_y0;
+        while y < inner_y1 {
+            let mut x = inner_x0;
+            while x < inner_x1 {
+                if grid[x][y] != CELL_EMPTY {
+                    x += 1;
+                    continue;
+                }
+                // Expand to largest empty rectangle
+                let mut max_w = 0;
+                for dx in 0..(inner_x1 - x) {
+                    if grid[x + dx][y] != CELL_EMPTY {
+                        break;
+                    }
+                    max_w = dx + 1;
+                }
+                let mut max_h = inner_y1 - y;
+                for dy in 0..max_h {
+                    let row_clear = (0..max_w).all(|dx| grid[x + dx][y + dy] == CELL_EMPTY);
+                    if !row_clear {
+                        max_h = dy;
+                        break;
+                    }
+                }
+
+                if max_w >= MIN_ROOM_DIM && max_h >= MIN_ROOM_DIM {
+                    let (frt, fname, _ftarget, fcap) = FILLER_POOL[filler_idx % FILLER_POOL.len()];
+                    filler_idx += 1;
+                    // Fillers use the full available rectangle
+                    let rect = (x, y, max_w, max_h);
+                    if corridors.touches_any(rect) {
+                        filler_count += 1;
+                        let room_id = b.room(
+                            None,
+                            format!("{} {}", fname, filler_count),
+                            frt,
+                            deck_i,
+                            fcap,
+                            rect,
+                        );
+                        for col in &mut grid[x..x + max_w] {
+                            for cell in &mut col[y..y + max_h] {
+                                *cell = cell_tag(room_id);
+                            }
+                        }
+                        placed_rooms.push(PlacedRoom {
+                            id: room_id,
+                            x,
+                            y,
+                            w: max_w,
+                            h: max_h,
+                            room_type: frt,
+                            placement: placement::NONE,
+                        });
+                        create_corridor_door(b, room_id, rect, &corridors);
+                    }
+                }
+                x += max_w.max(1);
+            }
+            y += 1;
+        }
+
+        // ---- Phase 8b: Unified hull band placement (N/S strips) ----
+        // Places hull-facing specialty rooms first, then fills gaps with fillers.
+        // All rooms are a single row directly touching the ring outer wall.
+        let hull_band_regions: [(usize, usize, usize, usize, u32); 2] = [
+            // North band: hull_y0..ring_y0, door to ring_n
+            (ring_x0, hull_y0, ring_x1, ring_y0, ring_n_id),
+            // South band: ring_y1..hull_y1, door to ring_s
+            (ring_x0, ring_y1, ring_x1, hull_y1, ring_s_id),
+        ];
+        let mut hull_placed: Vec<usize> = Vec::new();
+        for (bx0, by0, bx1, by1, ring_id) in hull_band_regions {
+            if bx1 <= bx0 || by1 <= by0 {
+                continue;
+            }
+            let band_h = by1 - by0;
+            if band_h < MIN_ROOM_DIM {
+                continue;
+            }
+            let north = ring_id == ring_n_id;
+            let ring_edge_y = if north { by1.saturating_sub(1) } else { by0 };
+            let (wall_a, wall_b) = if north {
+                (wall_sides::SOUTH, wall_sides::NORTH)
+            } else {
+                (wall_sides::NORTH, wall_sides::SOUTH)
+            };
+            let door_y = if north { by1 as f32 } else { by0 as f32 };
+
+            // Place hull-facing specialty rooms first along this band
+            for req in &hull_facing_requests {
+                if hull_placed.contains(&req.node) {
+                    continue;
+                }
+                let want_w = (req.target_area / band_h as f32).ceil() as usize;
+                let want_w = want_w.max(MIN_ROOM_DIM).min(bx1 - bx0);
+                let ry = if north {
+                    by1.saturating_sub(band_h)
+                } else {
+                    by0
+                };
+                // Scan the ring edge for a clear span of want_w
+                let mut sx = bx0;
+                while sx + want_w <= bx1 {
+                    let edge_clear = (0..want_w).all(|dx| grid[sx + dx][ring_edge_y] == CELL_EMPTY);
+                    let rect_clear = edge_clear
+                        && (0..want_w)
+                            .all(|dx| (0..band_h).all(|dy| grid[sx + dx][ry + dy] == CELL_EMPTY));
+                    if !rect_clear {
+                        sx += 1;
+                        continue;
+                    }
+                    let room_id = b.room(
+                        Some(req.node),
+                        req.name.clone(),
+                        req.room_type,
+                        deck_i,
+                        req.capacity,
+                        (sx, ry, want_w, band_h),
+                    );
+                    for col in &mut grid[sx..sx + want_w] {
+                        for cell in &mut col[ry..ry + band_h] {
+                            *cell = cell_tag(room_id);
+                        }
+                    }
+                    placed_rooms.push(PlacedRoom {
+                        id: room_id,
+                        x: sx,
+                        y: ry,
+                        w: want_w,
+                        h: band_h,
+                        room_type: req.room_type,
+                        placement: req.placement,
+                    });
+                    b.door(
+                        room_id,
+                        ring_id,
+                        junction(sx as f32 + want_w as f32 / 2.0, door_y, wall_a, wall_b),
+                        (want_w as f32 * 0.3).clamp(1.5, 3.0),
+                        access_levels::PUBLIC,
+                    );
+                    hull_placed.push(req.node);
+                    break;
+                }
+            }
+
+            // Fill remaining gaps with filler rooms
+            let mut x = bx0;
+            let max_filler_w = 20usize;
+            while x < bx1 {
+                if grid[x][ring_edge_y] != CELL_EMPTY {
+                    x += 1;
+                    continue;
+                }
+                // Find widest empty span at ring edge
+                let mut w = 0;
+                for dx in 0..(bx1 - x) {
+                    if grid[x + dx][ring_edge_y] != CELL_EMPTY {
+                        break;
+                    }
+                    w = dx + 1;
+                }
+                let rw = w.min(max_filler_w);
+                if rw < MIN_ROOM_DIM {
+                    x += w.max(1);
+                    continue;
+                }
+                // Room depth is limited by the rounded hull cap over this span
+                let room_h = (0..rw)
+                    .map(|dx| {
+                        (0..band_h)
+                            .take_while(|&dy| {
+                                let gy = if north { by1 - 1 - dy } else { by0 + dy };
+                                grid[x + dx][gy] == CELL_EMPTY
+                            })
+                            .count()
+                    })
+                    .min()
+                    .unwrap_or(0);
+                if room_h < MIN_ROOM_DIM {
+                    x += 1;
+                    continue;
+                }
+                let ry = if north { by1 - room_h } else { by0 };
+                let (frt, fname, _ftarget, fcap) = FILLER_POOL[filler_idx % FILLER_POOL.len()];
+                filler_idx += 1;
+                filler_count += 1;
+                let room_id = b.room(
+                    None,
+                    format!("{} {}", fname, filler_count),
+                    frt,
+                    deck_i,
+                    fcap,
+                    (x, ry, rw, room_h),
+                );
+                for col in &mut grid[x..x + rw] {
+                    for cell in &mut col[ry..ry + room_h] {
+                        *cell = cell_tag(room_id);
+                    }
+                }
+                placed_rooms.push(PlacedRoom {
+                    id: room_id,
+                    x,
+                    y: ry,
+                    w: rw,
+                    h: room_h,
+                    room_type: frt,
+                    placement: placement::NONE,
+                });
+                b.door(
+                    room_id,
+                    ring_id,
+                    junction(x as f32 + rw as f32 / 2.0, door_y, wall_a, wall_b),
+                    (rw as f32 * 0.3).clamp(1.5, 3.0),
+                    access_levels::PUBLIC,
+                );
+                x += rw;
+            }
+        }
+    }
+
+    // ---- Phase 8.5: Final expansion pass (all rooms including fillers) ----
+    expand_rooms_into_empty(b, &mut grid, &mut placed_rooms, inner_bounds, band_bounds);
+
+    // ---- Deadspace audit ----
+    {
+        let mut empty_cells = 0u32;
+        let mut total_inner = 0u32;
+        for col in &grid[inner_x0..inner_x1] {
+            for &cell in &col[inner_y0..inner_y1] {
+                total_inner += 1;
+                if cell == CELL_EMPTY {
+                    empty_cells += 1;
+                }
+            }
+        }
+        // Also count hull band (excluding cells trimmed by the rounded hull)
+        for col in &grid[ring_x0..ring_x1] {
+            for y in (hull_y0..inner_y0).chain(inner_y1..hull_y1) {
+                if col[y] == CELL_HULL {
+                    continue;
+                }
+                total_inner += 1;
+                if col[y] == CELL_EMPTY {
+                    empty_cells += 1;
+                }
+            }
+        }
+        if total_inner > 0 && empty_cells as f32 / total_inner as f32 > 0.05 {
+            // Emergency expansion pass to fill remaining deadspace
+            expand_rooms_into_empty(b, &mut grid, &mut placed_rooms, inner_bounds, band_bounds);
+        }
+    }
+
+    // ---- Cell-level irregular expansion ----
+    // Grow rooms one cell at a time into adjacent empty cells, enabling L/T/U shapes.
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for pr in placed_rooms.iter_mut() {
+            let tag = cell_tag(pr.id);
+            let (exp_x0, exp_y0, exp_x1, exp_y1) = if pr.placement == placement::HULL_FACING {
+                band_bounds
+            } else {
+                inner_bounds
+            };
+            let (rx, ry, rw, rh) = (pr.x, pr.y, pr.w, pr.h);
+            for x in rx..(rx + rw).min(hw) {
+                for y in ry..(ry + rh).min(hl) {
+                    if grid[x][y] != tag {
+                        continue;
+                    }
+                    for &(dx, dy) in &[(0isize, 1isize), (0, -1), (1, 0), (-1, 0)] {
+                        let nx = x as isize + dx;
+                        let ny = y as isize + dy;
+                        if nx < exp_x0 as isize
+                            || nx >= exp_x1 as isize
+                            || ny < exp_y0 as isize
+                            || ny >= exp_y1 as isize
+                        {
+                            continue;
+                        }
+                        let nx = nx as usize;
+                        let ny = ny as usize;
+                        if grid[nx][ny] == CELL_EMPTY {
+                            grid[nx][ny] = tag;
+                            changed = true;
+                            if nx < pr.x {
+                                pr.w += pr.x - nx;
+                                pr.x = nx;
+                            }
+                            if nx >= pr.x + pr.w {
+                                pr.w = nx - pr.x + 1;
+                            }
+                            if ny < pr.y {
+                                pr.h += pr.y - ny;
+                                pr.y = ny;
+                            }
+                            if ny >= pr.y + pr.h {
+                                pr.h = ny - pr.y + 1;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // ---- Populate cells field from grid ----
+    for pr in &placed_rooms {
+        let cells = encode_cell_mask(&grid, cell_tag(pr.id), (pr.x, pr.y, pr.w, pr.h));
+        if let Some(room) = b.room_mut(pr.id) {
+            room.x = pr.x as f32 + pr.w as f32 / 2.0;
+            room.y = pr.y as f32 + pr.h as f32 / 2.0;
+            room.width = pr.w as f32;
+            room.height = pr.h as f32;
+            room.cells = cells;
+        }
+    }
+
+    // ---- Hull adjacency and hull hatches ----
+    let is_hull = |x: i32, y: i32| {
+        x < 0
+            || y < 0
+            || x >= hw as i32
+            || y >= hl as i32
+            || grid[x as usize][y as usize] == CELL_HULL
+    };
+    let ShipLayoutPlan { rooms, hatches, .. } = &mut b.plan;
+    for room in rooms.iter_mut().filter(|r| r.deck == deck_i) {
+        let rx = (room.x - room.width / 2.0).round() as i32;
+        let ry = (room.y - room.height / 2.0).round() as i32;
+        let (rw, rh) = (room.width.round() as i32, room.height.round() as i32);
+        let cells = &room.cells;
+        let in_room = |x: i32, y: i32| {
+            cells.is_empty() || cell_mask_contains(cells, x as f32 + 0.5, y as f32 + 0.5)
+        };
+        let spans = hull_spans(rx, ry, rw, rh, in_room, is_hull);
+        if let Some(hatch_type) = hatch_type(room.room_type) {
+            if let Some(span) = spans.iter().max_by_key(|s| s.len) {
+                let (hx, hy) = span.center(rx, ry, rw, rh);
+                hatches.push(LayoutHatch {
+                    room_id: room.id,
+                    hatch_type,
+                    wall: span.wall,
+                    x: hx,
+                    y: hy,
+                    width: hatch_width(hatch_type, span.len),
+                });
+            }
+        }
+        room.hull_sides = hull_sides(&spans);
+        room.has_window = has_window(room.room_type, room.hull_sides);
+    }
+
+    // ---- Phase 9: Room-to-room doors (adjacent logical pairs) ----
+    for (i, a) in placed_rooms.iter().enumerate() {
+        for c in &placed_rooms[i + 1..] {
+            if !should_have_room_door(a.room_type, c.room_type) {
+                continue;
+            }
+            if let Some(edge) = shared_edge((a.x, a.y, a.w, a.h), (c.x, c.y, c.w, c.h)) {
+                b.door(
+                    a.id,
+                    c.id,
+                    edge,
+                    door_width_from_overlap(edge.overlap),
+                    access_levels::PUBLIC,
+                );
+            }
+        }
+    }
+
+    // Rooms planned for this deck that found no space
+    b.plan.unplaced.extend(
+        wanted
+            .into_iter()
+            .filter(|&n| !b.plan.rooms.iter().any(|r| r.node == Some(n))),
+    );
+}
+
+/// Expand placed rooms into adjacent empty cells (rectangular expansion).
+/// Called after BSP, after the wavefront fill and after fillers. Hull-facing
+/// rooms grow within `band`, the rest within `inner`.
+fn expand_rooms_into_empty(
+    b: &mut PlanBuilder,
+    grid: &mut [Vec<u8>],
+    placed_rooms: &mut [PlacedRoom],
+    inner: Bounds,
+    band: Bounds,
+) {
+    for pr in placed_rooms.iter_mut() {
+        let tag = cell_tag(pr.id);
+        let (mut rx, mut ry, mut rw, mut rh) = (pr.x, pr.y, pr.w, pr.h);
+        let (exp_x0, exp_y0, exp_x1, exp_y1) = if pr.placement == placement::HULL_FACING {
+            band
+        } else {
+            inner
+        };
+        let mut changed = true;
+        while changed {
+            changed = false;
+            // Try expand east (+x)
+            let new_x1 = rx + rw;
+            if new_x1 < exp_x1 && (ry..ry + rh).all(|y| grid[new_x1][y] == CELL_EMPTY) {
+                grid[new_x1][ry..ry + rh].fill(tag);
+                rw += 1;
+                changed = true;
+            }
+            // Try expand west (-x)
+            if rx > exp_x0 && (ry..ry + rh).all(|y| grid[rx - 1][y] == CELL_EMPTY) {
+                rx -= 1;
+                grid[rx][ry..ry + rh].fill(tag);
+                rw += 1;
+                changed = true;
+            }
+            // Try expand south (+y)
+            let new_y1 = ry + rh;
+            if new_y1 < exp_y1 && (rx..rx + rw).all(|x| grid[x][new_y1] == CELL_EMPTY) {
+                for col in &mut grid[rx..rx + rw] {
+                    col[new_y1] = tag;
+                }
+                rh += 1;
+                changed = true;
+            }
+            // Try expand north (-y)
+            if ry > exp_y0 && (rx..rx + rw).all(|x| grid[x][ry - 1] == CELL_EMPTY) {
+                ry -= 1;
+                for col in &mut grid[rx..rx + rw] {
+                    col[ry] = tag;
+                }
+                rh += 1;
+                changed = true;
+            }
+        }
+        if (rx, ry, rw, rh) != (pr.x, pr.y, pr.w, pr.h) {
+            (pr.x, pr.y, pr.w, pr.h) = (rx, ry, rw, rh);
+            if let Some(room) = b.room_mut(pr.id) {
+                room.x = rx as f32 + rw as f32 / 2.0;
+                room.y = ry as f32 + rh as f32 / 2.0;
+                room.width = rw as f32;
+                room.height = rh as f32;
+            }
+        }
+    }
+}
+
+/// Per-zone room request queues, handed out deck by deck.
+///
+/// Each deck takes a fair share of its zone's remaining requests up to its area
+/// budget, topped up from other zones when underfilled. The hand-out depends
+/// only on deck geometry, so every deck's share is planned before any deck is
+/// laid out, including by decks laid out in a later call.
+struct ZoneQueues {
+    requests: Vec<Vec<RoomRequest>>,
+    cursors: Vec<usize>,
+    deck_counts: Vec<u32>,
+    decks_seen: Vec<u32>,
+}
+
+impl ZoneQueues {
+    fn new(requests: Vec<Vec<RoomRequest>>, deck_zone_map: &[u8]) -> Self {
+        // Count how many decks each zone has, for proportional distribution
+        let mut deck_counts = vec![0; 7];
+        for &z in deck_zone_map {
+            deck_counts[z as usize] += 1;
+        }
+        Self {
+            requests,
+            cursors: vec![0; 7],
+            deck_counts,
+            decks_seen: vec![0; 7],
+        }
+    }
+
+    /// Take the requests for the next deck of `zone` with `area_budget` m².
+    fn take_for_deck(&mut self, zone: u8, area_budget: f32) -> Vec<RoomRequest> {
+        let primary_zone = zone as usize;
+        let zone_deck_num = self.decks_seen[primary_zone];
+        self.decks_seen[primary_zone] += 1;
+        let remaining_zone_decks = self.deck_counts[primary_zone].saturating_sub(zone_deck_num);
+        let remaining_rooms = self.requests[primary_zone].len() - self.cursors[primary_zone];
+        let fair_share = if remaining_zone_decks > 0 {
+            remaining_rooms.div_ceil(remaining_zone_decks as usize)
+        } else {
+            remaining_rooms
+        };
+
+        let mut deck_requests: Vec<RoomRequest> = Vec::new();
+        let mut filled_area = 0.0f32;
+        {
+            let cursor = &mut self.cursors[primary_zone];
+            let mut taken = 0usize;
+            while *cursor < self.requests[primary_zone].len()
+                && filled_area < area_budget
+                && taken < fair_share
+            {
+                let req = self.requests[primary_zone][*cursor].clone();
+                filled_area += req.target_area;
+                deck_requests.push(req);
+                *cursor += 1;
+                taken += 1;
+            }
+        }
+        // Overflow from other zones if underfilled
+        if filled_area < area_budget * 0.7 {
+            let overflow_order = [1u8, 2, 3, 4, 5, 0, 6];
+            for &oz in &overflow_order {
+                if oz as usize == primary_zone {
+                    continue;
+                }
+                let oc = &mut self.cursors[oz as usize];
+                while *oc < self.requests[oz as usize].len() && filled_area < area_budget {
+                    let req = self.requests[oz as usize][*oc].clone();
+                    filled_area += req.target_area;
+                    deck_requests.push(req);
+                    *oc += 1;
+                }
+                if filled_area >= area_budget {
+                    break;
+                }
+            }
+        }
+        deck_requests
+    }
+
+    /// Nodes of the requests no deck took.
+    fn leftover(&self) -> impl Iterator<Item = usize> + '_ {
+        self.requests
+            .iter()
+            .zip(&self.cursors)
+            .flat_map(|(reqs, &cursor)| reqs[cursor..].iter().map(|r| r.node))
+    }
+}
+
+/// Room area budget of a deck from its inner dimensions, before segments are
+/// known: corridors and shafts take ~20%.
+fn deck_area_budget(inner_w: usize, inner_h: usize) -> f32 {
+    (inner_w * inner_h) as f32 * 0.80
+}
+
+/// Inner width and length (inside the ring corridor) of `deck`, or `None` if
+/// the deck is too small to lay out. Matches the per-deck grid in
+/// [`generate_deck_layouts`].
+fn deck_inner_size(
+    hull: &HullProfile,
+    deck: u32,
+    deck_count: u32,
+    ship_beam: usize,
+    ship_length: usize,
+) -> Option<(usize, usize)> {
+    let deck_hw = hull.width(deck, deck_count, ship_beam);
+    let deck_hl = hull.length(deck, deck_count, ship_length);
+    if deck_hw < 12 || deck_hl < 30 {
+        return None;
+    }
+    let mid_hw = hull.width(deck_count / 2, deck_count, ship_beam);
+    let mid_hl = hull.length(deck_count / 2, deck_count, ship_length);
+    let taper_x = mid_hw.saturating_sub(deck_hw) / 2;
+    let taper_y = mid_hl.saturating_sub(deck_hl) / 2;
+    Some((
+        (mid_hw - 2 * taper_x).saturating_sub(2 * RING_WIDTH),
+        (mid_hl - 2 * taper_y).saturating_sub(2 * RING_WIDTH),
+    ))
+}
+
+/// Identify rectangular segments between corridors where rooms can be
+/// placed: each room column cut by the cross-corridor `bands`, split around
+/// crawlways and shafts. Segments beside a spine come first, then larger ones.
+fn find_segments(
+    grid: &[Vec<u8>],
+    columns: &[(usize, usize)],
+    bands: &[(usize, usize)],
+    crawl_xs: &[usize],
+    spine_xs: &[usize],
+    spine_width: usize,
+) -> Vec<Cells> {
+    let mut segments = Vec::new();
+    for &(seg_y0, seg_y1) in bands {
+        if seg_y1 - seg_y0 < MIN_ROOM_DIM {
+            continue;
+        }
+        for &(x0, x1) in columns {
+            if x1 <= x0 || x1 - x0 < MIN_ROOM_DIM {
+                continue;
+            }
+            segments.extend(
+                clear_rects_beside_crawlways(grid, crawl_xs, x0, x1, seg_y0, seg_y1)
+                    .into_iter()
+                    .filter(|&(_, _, rw, rh)| rw >= MIN_ROOM_DIM && rh >= MIN_ROOM_DIM),
+            );
+        }
+    }
+
+    let beside_spine = |&(x, _, w, _): &Cells| {
+        spine_xs
+            .iter()
+            .any(|&sx| x + w == sx || x == sx + spine_width)
+    };
+    segments.sort_by(|a, b| {
+        beside_spine(b)
+            .cmp(&beside_spine(a))
+            .then((b.2 * b.3).cmp(&(a.2 * a.3)))
+    });
+    segments
+}
+
+/// Clear rectangles in a region, each side of any crawlway running through it
+/// split around shaft obstacles separately.
+fn clear_rects_beside_crawlways(
+    grid: &[Vec<u8>],
+    crawl_xs: &[usize],
+    x0: usize,
+    x1: usize,
+    y0: usize,
+    y1: usize,
+) -> Vec<Cells> {
+    let cols: Vec<usize> = crawl_xs
+        .iter()
+        .copied()
+        .filter(|&cx| (x0..x1).contains(&cx) && (y0..y1).any(|y| grid[cx][y] == CELL_CRAWLWAY))
+        .collect();
+    split_around(x0, x1, &cols)
+        .into_iter()
+        .flat_map(|(sx0, sx1)| find_clear_rects_in_region(grid, sx0, sx1, y0, y1))
+        .collect()
+}
+
+/// Find clear rectangles in a region, splitting around shaft obstacles.
+fn find_clear_rects_in_region(
+    grid: &[Vec<u8>],
+    x0: usize,
+    x1: usize,
+    y0: usize,
+    y1: usize,
+) -> Vec<Cells> {
+    let mut results = Vec::new();
+    if x1 <= x0 || y1 <= y0 {
+        return results;
+    }
+    let blocked = |x: usize, y: usize| y >= grid[x].len() || grid[x][y] != CELL_EMPTY;
+
+    // Scan columns for clear runs, splitting around obstacles
+    let mut col_start = x0;
+    let mut x = x0;
+    while x <= x1 {
+        let col_has_obstacle = x >= x1 || (y0..y1).any(|y| blocked(x, y));
+        if col_has_obstacle {
+            let run_w = x - col_start;
+            if run_w >= MIN_ROOM_DIM {
+                results.push((col_start, y0, run_w, y1 - y0));
+            }
+
+            if x < x1 {
+                let obs_col_start = x;
+                let mut obs_end = x + 1;
+                while obs_end < x1 && (y0..y1).any(|y| blocked(obs_end, y)) {
+                    obs_end += 1;
+                }
+                let obs_w = obs_end - obs_col_start;
+
+                // Find clear Y sub-ranges in obstacle columns
+                let mut row_start = y0;
+                for ry in y0..=y1 {
+                    let row_clear = ry < y1 && (obs_col_start..obs_end).all(|cx| !blocked(cx, ry));
+                    if !row_clear {
+                        let run_h = ry - row_start;
+                        if run_h >= MIN_ROOM_DIM && obs_w >= MIN_ROOM_DIM {
+                            results.push((obs_col_start, row_start, obs_w, run_h));
+                        }
+                        row_start = ry + 1;
+                    }
+                }
+
+                x = obs_end;
+                col_start = obs_end;
+                continue;
+            }
+            col_start = x + 1;
+        }
+        x += 1;
+    }
+
+    results
+}
+
+/// Give a room a door to the first corridor it touches. Returns true if a
+/// door was created.
+fn create_corridor_door(
+    b: &mut PlanBuilder,
+    room_id: u32,
+    rect: Cells,
+    corridors: &DeckCorridors,
+) -> bool {
+    let target = corridors
+        .door_targets()
+        .into_iter()
+        .find_map(|(id, corridor)| shared_edge(rect, corridor).map(|edge| (id, edge)));
+    match target {
+        Some((corridor_id, edge)) => {
+            b.door(
+                room_id,
+                corridor_id,
+                edge,
+                door_width_from_overlap(edge.overlap),
+                access_levels::PUBLIC,
+            );
+            true
+        }
+        None => false,
+    }
+}
+
+/// BSP subdivide a rectangle into one sub-rectangle per requested area.
+/// Splits along the longer axis so rooms don't become impossibly thin.
+fn bsp_subdivide(x: usize, y: usize, w: usize, h: usize, areas: &[f32], out: &mut Vec<Cells>) {
+    if areas.is_empty() || w < MIN_ROOM_DIM || h < MIN_ROOM_DIM {
+        return;
+    }
+
+    if areas.len() == 1 {
+        // Use the full sub-rect — rooms expand later anyway.
+        out.push((x, y, w, h));
+        return;
+    }
+
+    let split_at = areas.len() / 2;
+    let area_ratio = areas[..split_at].iter().sum::<f32>() / areas.iter().sum::<f32>();
+
+    // Split along the longer axis for better packing
+    if h >= w {
+        let split_h = (h as f32 * area_ratio).round() as usize;
+        let split_h = split_h
+            .max(MIN_ROOM_DIM)
+            .min(h.saturating_sub(MIN_ROOM_DIM));
+        if split_h >= MIN_ROOM_DIM && h - split_h >= MIN_ROOM_DIM {
+            bsp_subdivide(x, y, w, split_h, &areas[..split_at], out);
+            bsp_subdivide(x, y + split_h, w, h - split_h, &areas[split_at..], out);
+        } else {
+            out.push((x, y, w, h));
+        }
+    } else {
+        let split_w = (w as f32 * area_ratio).round() as usize;
+        let split_w = split_w
+            .max(MIN_ROOM_DIM)
+            .min(w.saturating_sub(MIN_ROOM_DIM));
+        if split_w >= MIN_ROOM_DIM && w - split_w >= MIN_ROOM_DIM {
+            bsp_subdivide(x, y, split_w, h, &areas[..split_at], out);
+            bsp_subdivide(x + split_w, y, w - split_w, h, &areas[split_at..], out);
+        } else {
+            out.push((x, y, w, h));
+        }
+    }
+}
+
+/// Connect a shaft room to an adjacent corridor via a door, preferring the
+/// shaft's designated wall for consistent orientation across all decks.
+fn connect_shaft_to_corridor(
+    b: &mut PlanBuilder,
+    shaft_room_id: u32,
+    sp: &ShaftPlacement,
+    corridors: &DeckCorridors,
+    ring_ids: [u32; 4],
+    ring_grids: [Cells; 4],
+    access: u8,
+) {
+    let shaft = (sp.x, sp.y, sp.w, sp.h);
+    let mut targets: Vec<(u32, Cells)> = corridors
+        .cross_rooms
+        .iter()
+        .map(|&(cc_id, cy)| {
+            (
+                cc_id,
+                (
+                    corridors.inner_x0,
+                    cy,
+                    corridors.inner_x1 - corridors.inner_x0,
+                    corridors.cross_width,
+                ),
+            )
+        })
+        .collect();
+    targets.extend(
+        corridors
+            .spine_segments
+            .iter()
+            .map(|&(seg_id, x, y0, y1)| (seg_id, (x, y0, corridors.spine_width, y1 - y0))),
+    );
+    targets.extend(corridors.spur_rooms.iter().copied());
+    targets.extend(ring_ids.into_iter().zip(ring_grids));
+    let candidates: Vec<(u32, SharedEdge)> = targets
+        .into_iter()
+        .filter_map(|(id, corridor)| shared_edge(shaft, corridor).map(|edge| (id, edge)))
+        .collect();
+
+    // Prefer the designated wall, else any wall
+    let chosen = candidates
+        .iter()
+        .find(|(_, e)| e.wall_a == sp.preferred_wall)
+        .or(candidates.first());
+    if let Some(&(corridor_id, edge)) = chosen {
+        b.door(
+            shaft_room_id,
+            corridor_id,
+            edge,
+            door_width_from_overlap(edge.overlap),
+            access,
+        );
+    }
+}
+
+/// Compute door width from overlap length to match client rendering.
+/// Width = min(2.0, overlap - 0.5), clamped to at least 0.5.
+fn door_width_from_overlap(overlap: f32) -> f32 {
+    2.0_f32.min(overlap - 0.5).max(0.5)
+}
+
+/// Find the shared edge between two axis-aligned rectangles, seen from `a`.
+fn shared_edge((ax, ay, aw, ah): Cells, (bx, by, bw, bh): Cells) -> Option<SharedEdge> {
+    let overlap = |a0: usize, a1: usize, b0: usize, b1: usize| {
+        let (lo, hi) = (a0.max(b0), a1.min(b1));
+        (hi > lo).then_some((lo, hi))
+    };
+    let edge = |x: f32, y: f32, wall_a: u8, wall_b: u8, (lo, hi): (usize, usize)| SharedEdge {
+        x,
+        y,
+        wall_a,
+        wall_b,
+        overlap: (hi - lo) as f32,
+    };
+    let mid = |(lo, hi): (usize, usize)| (lo + hi) as f32 / 2.0;
+    if ax + aw == bx {
+        if let Some(o) = overlap(ay, ay + ah, by, by + bh) {
+            return Some(edge(
+                bx as f32,
+                mid(o),
+                wall_sides::EAST,
+                wall_sides::WEST,
+                o,
+            ));
+        }
+    }
+    if bx + bw == ax {
+        if let Some(o) = overlap(ay, ay + ah, by, by + bh) {
+            return Some(edge(
+                ax as f32,
+                mid(o),
+                wall_sides::WEST,
+                wall_sides::EAST,
+                o,
+            ));
+        }
+    }
+    if ay + ah == by {
+        if let Some(o) = overlap(ax, ax + aw, bx, bx + bw) {
+            return Some(edge(
+                mid(o),
+                by as f32,
+                wall_sides::SOUTH,
+                wall_sides::NORTH,
+                o,
+            ));
+        }
+    }
+    if by + bh == ay {
+        if let Some(o) = overlap(ax, ax + aw, bx, bx + bw) {
+            return Some(edge(
+                mid(o),
+                ay as f32,
+                wall_sides::NORTH,
+                wall_sides::SOUTH,
+                o,
+            ));
+        }
+    }
+    None
+}
+
+/// Compute shaft templates scaled to population.
+fn compute_shaft_templates(total_pop: u32) -> Vec<(&'static str, u8, bool, usize, usize)> {
+    let main_count = (total_pop as f32 / 200.0).ceil().max(2.0) as usize;
+    let svc_count = (total_pop as f32 / 500.0).ceil().max(1.0) as usize;
+    let ladder_count = (total_pop as f32 / 500.0).ceil().max(2.0) as usize;
+
+    const MAIN_NAMES: &[&str] = &[
+        "Fore Elevator",
+        "Aft Elevator",
+        "Midship Elevator",
+        "Elevator 4",
+        "Elevator 5",
+        "Elevator 6",
+        "Elevator 7",
+        "Elevator 8",
+        "Elevator 9",
+        "Elevator 10",
+        "Elevator 11",
+        "Elevator 12",
+        "Elevator 13",
+        "Elevator 14",
+        "Elevator 15",
+        "Elevator 16",
+        "Elevator 17",
+        "Elevator 18",
+        "Elevator 19",
+        "Elevator 20",
+        "Elevator 21",
+        "Elevator 22",
+        "Elevator 23",
+        "Elevator 24",
+        "Elevator 25",
+        "Elevator 26",
+        "Elevator 27",
+        "Elevator 28",
+    ];
+    const SVC_NAMES: &[&str] = &[
+        "Service Elevator A",
+        "Service Elevator B",
+        "Service Elevator C",
+        "Service Elevator D",
+        "Service Elevator E",
+        "Service Elevator F",
+        "Service Elevator G",
+        "Service Elevator H",
+        "Service Elevator I",
+        "Service Elevator J",
+    ];
+    const LADDER_NAMES: &[&str] = &[
+        "Ladder A", "Ladder B", "Ladder C", "Ladder D", "Ladder E", "Ladder F", "Ladder G",
+        "Ladder H", "Ladder I", "Ladder J",
+    ];
+
+    let mut templates = Vec::new();
+    for name in MAIN_NAMES.iter().take(main_count) {
+        templates.push((*name, shaft_types::ELEVATOR, true, 3, 3));
+    }
+    for name in SVC_NAMES.iter().take(svc_count) {
+        templates.push((*name, shaft_types::SERVICE_ELEVATOR, false, 3, 3));
+    }
+    for name in LADDER_NAMES.iter().take(ladder_count) {
+        templates.push((*name, shaft_types::LADDER, false, 3, 3));
+    }
+    templates
+}
+
+/// Whether shaft `sp` fits inside a deck's ring corridor, given the margins
+/// of the deck's inner area within the midship grid.
+fn shaft_fits(sp: &ShaftPlacement, x_margin: usize, y_margin: usize, hw: usize, hl: usize) -> bool {
+    sp.x >= x_margin
+        && sp.x + sp.w <= hw.saturating_sub(x_margin)
+        && sp.y >= y_margin
+        && sp.y + sp.h <= hl.saturating_sub(y_margin)
+}
+
+/// The unbroken run of decks around `anchor` where a shaft `fits`, so a shaft
+/// never skips a deck. Empty if it does not fit the anchor deck.
+fn shaft_deck_span(fits: &[bool], anchor: usize) -> Range<usize> {
+    if !fits.get(anchor).copied().unwrap_or(false) {
+        return anchor..anchor;
+    }
+    let start = fits[..anchor]
+        .iter()
+        .rposition(|&f| !f)
+        .map_or(0, |i| i + 1);
+    let end = fits[anchor..]
+        .iter()
+        .position(|&f| !f)
+        .map_or(fits.len(), |i| anchor + i);
+    start..end
+}
+
+/// Compute shaft placements by distributing templates across cross-corridor intersections.
+fn compute_shaft_placements(
+    templates: &[(&'static str, u8, bool, usize, usize)],
+    spine_right: usize,
+    spine_left: usize,
+    cross_ys: &[usize],
+    hw: usize,
+    hl: usize,
+) -> Vec<ShaftPlacement> {
+    let mut placements = Vec::new();
+    let cross_end_offset = CROSS_CORRIDOR_WIDTH;
+
+    if cross_ys.is_empty() {
+        if let Some(&(name, st, is_main, w, h)) = templates.first() {
+            // Shaft right of spine → door on WEST wall (facing spine)
+            placements.push(ShaftPlacement {
+                x: spine_right,
+                y: hl / 4,
+                w,
+                h,
+                shaft_type: st,
+                name,
+                is_main,
+                preferred_wall: wall_sides::WEST,
+            });
+        }
+        return placements;
+    }
+
+    let of_type = |shaft_type: u8| -> Vec<&(&'static str, u8, bool, usize, usize)> {
+        templates
+            .iter()
+            .filter(|(_, st, _, _, _)| *st == shaft_type)
+            .collect()
+    };
+    let main_elevators = of_type(shaft_types::ELEVATOR);
+    let service_elevators = of_type(shaft_types::SERVICE_ELEVATOR);
+    let ladders = of_type(shaft_types::LADDER);
+
+    let num_positions = cross_ys.len();
+
+    // Main elevators: starboard of spine → door faces NORTH (into cross-corridor)
+    for (i, &&(name, st, is_main, w, h)) in main_elevators.iter().enumerate() {
+        let cross_idx = if main_elevators.len() <= num_positions {
+            i * num_positions / main_elevators.len()
+        } else {
+            i % num_positions
+        };
+        let cy = cross_ys[cross_idx.min(num_positions - 1)];
+        let stack_offset = if main_elevators.len() > num_positions {
+            (i / num_positions) * h
+        } else {
+            0
+        };
+        let sy = cy + cross_end_offset + stack_offset;
+        // Determine preferred wall: adjacent to cross-corridor (NORTH) or spine (WEST)
+        let pwall = if sy == cy + cross_end_offset {
+            wall_sides::NORTH // adjacent to cross-corridor above
+        } else {
+            wall_sides::WEST // stacked below — face spine
+        };
+        placements.push(ShaftPlacement {
+            x: spine_right,
+            y: sy,
+            w,
+            h,
+            shaft_type: st,
+            name,
+            is_main,
+            preferred_wall: pwall,
+        });
+    }
+
+    // Service elevators: offset from spine → door faces WEST (toward spine)
+    let svc_x = spine_right + 3 + 1;
+    if svc_x + 2 < hw {
+        for (i, &&(name, st, is_main, w, h)) in service_elevators.iter().enumerate() {
+            let cross_idx = if service_elevators.len() <= num_positions {
+                (i * num_positions / service_elevators.len().max(1)).min(num_positions - 1)
+            } else {
+                i % num_positions
+            };
+            let cy = cross_ys[cross_idx];
+            placements.push(ShaftPlacement {
+                x: svc_x,
+                y: cy + cross_end_offset,
+                w,
+                h,
+                shaft_type: st,
+                name,
+                is_main,
+                preferred_wall: wall_sides::WEST,
+            });
+        }
+    }
+
+    // Ladders: port side of spine → door faces EAST (toward spine)
+    for (i, &&(name, st, is_main, w, h)) in ladders.iter().enumerate() {
+        let cross_idx = if ladders.len() <= num_positions {
+            (i * num_positions / ladders.len().max(1)).min(num_positions - 1)
+        } else {
+            i % num_positions
+        };
+        let cy = cross_ys[cross_idx];
+        placements.push(ShaftPlacement {
+            x: spine_left.saturating_sub(w),
+            y: cy + cross_end_offset,
+            w,
+            h,
+            shaft_type: st,
+            name,
+            is_main,
+            preferred_wall: wall_sides::EAST,
+        });
+    }
+
+    placements
+}
+
+/// Compute hull dimensions using iterative overhead calculation.
+fn compute_hull_dimensions(
+    room_area_per_deck: f32,
+    shaft_area_per_deck: f32,
+    max_room_area: f32,
+) -> (usize, usize) {
+    let aspect_ratio = 3.5f32;
+    let mut mult = 1.4f32;
+
+    // Minimum beam: largest room must fit in segment between ring and spine
+    // segment_width = (beam - SPINE_WIDTH - 2*RING_WIDTH) / 2
+    let min_strip_w = (max_room_area.sqrt()).max(MIN_ROOM_DIM as f32);
+    let min_beam = (min_strip_w * 2.0 + SPINE_WIDTH as f32 + 2.0 * RING_WIDTH as f32).max(30.0);
+
+    for _ in 0..5 {
+        let apd = room_area_per_deck * mult;
+        let b = (apd.sqrt() / aspect_ratio.sqrt()).max(min_beam);
+        let l = (apd / b).max(100.0);
+
+        let num_cross = (l / 35.0).round().max(1.0);
+        // Ring perimeter + spine + cross-corridors
+        let corridor_area = SPINE_WIDTH as f32 * l
+            + num_cross * CROSS_CORRIDOR_WIDTH as f32 * b
+            + 2.0 * (b + l) * RING_WIDTH as f32;
+
+        let actual_need = room_area_per_deck + corridor_area + shaft_area_per_deck;
+        let new_mult = actual_need / room_area_per_deck;
+
+        if (new_mult - mult).abs() < 0.01 {
+            break;
+        }
+        mult = new_mult;
+    }
+
+    let apd = room_area_per_deck * mult;
+    let b = (apd.sqrt() / aspect_ratio.sqrt()).max(min_beam) as usize;
+    let l = (apd / b as f32).max(100.0) as usize;
+    (b, l)
+}
+
+/// Auto-compute optimal deck count.
+fn compute_optimal_deck_count(
+    total_room_area: f32,
+    shaft_area_per_deck: f32,
+    shaft_templates: &[(&'static str, u8, bool, usize, usize)],
+    max_room_area: f32,
+) -> u32 {
+    let num_banks = shaft_templates
+        .iter()
+        .filter(|(_, st, _, _, _)| *st == shaft_types::ELEVATOR)
+        .count()
+        .max(1);
+
+    let mut best = 7u32;
+    for d in 7..=30u32 {
+        let room_per_deck = total_room_area / d as f32;
+        let (b, l) = compute_hull_dimensions(room_per_deck, shaft_area_per_deck, max_room_area);
+
+        let num_cross = (l as f32 / 35.0).round().max(1.0) as usize;
+        let usable_width = b.saturating_sub(SPINE_WIDTH + 2 * RING_WIDTH);
+        let usable_length = l.saturating_sub(2 * RING_WIDTH) - num_cross * CROSS_CORRIDOR_WIDTH;
+        let strip_area = usable_width as f32 * usable_length as f32 * 0.8 - shaft_area_per_deck;
+
+        let fill = if strip_area > 0.0 {
+            room_per_deck / strip_area
+        } else {
+            99.0
+        };
+        let max_walk = l as f32 / (2.0 * num_banks as f32) + b as f32 / 2.0;
+
+        best = d;
+        if fill <= 0.90 && max_walk <= 50.0 {
+            break;
+        }
+    }
+    best
+}
+
+/// Find the best clear rectangle within a search region for placing a large room.
+///
+/// Scans the search region for the largest contiguous empty rectangle that can
+/// accommodate the requested room dimensions.
+fn find_clear_rect_for_room(
+    grid: &[Vec<u8>],
+    (search_x, search_y, search_w, search_h): Cells,
+    want_w: usize,
+    want_h: usize,
+) -> Option<Cells> {
+    let (hw, hl) = (grid.len(), grid.first().map_or(0, Vec::len));
+    let mut best: Option<(Cells, usize)> = None;
+
+    let max_x = (search_x + search_w).min(hw);
+    let max_y = (search_y + search_h).min(hl);
+
+    let step = 2;
+    let mut y = search_y;
+    while y + MIN_ROOM_DIM <= max_y {
+        let mut x = search_x;
+        while x + MIN_ROOM_DIM <= max_x {
+            // Find max clear width from this position
+            let clear_w = (x..max_x)
+                .take_while(|&gx| grid[gx][y] == CELL_EMPTY)
+                .count();
+            if clear_w < MIN_ROOM_DIM {
+                x += step;
+                continue;
+            }
+
+            // Find max clear height for this width
+            let rw = clear_w.min(want_w);
+            let clear_h = (y..max_y)
+                .take_while(|&gy| (x..x + rw).all(|gx| grid[gx][gy] == CELL_EMPTY))
+                .count();
+            if clear_h < MIN_ROOM_DIM {
+                x += step;
+                continue;
+            }
+
+            // Score: prefer larger area
+            let rh = clear_h.min(want_h);
+            let score = rw * rh;
+            if best.is_none_or(|(_, s)| score > s) {
+                best = Some(((x, y, rw, rh), score));
+            }
+
+            x += step;
+        }
+        y += step;
+    }
+
+    best.map(|(rect, _)| rect)
+}
+
+/// Encode a room's grid footprint as packed axis-aligned rects.
+/// Scans grid within bbox for cells matching `tag`, produces a minimal
+/// set of rects (greedy row-merge) encoded as [(x0,y0,x1,y1) u16] bytes.
+fn encode_cell_mask(grid: &[Vec<u8>], tag: u8, (rx, ry, rw, rh): Cells) -> Vec<u8> {
+    let (hw, hl) = (grid.len(), grid.first().map_or(0, Vec::len));
+    let mut rects: Vec<(u16, u16, u16, u16)> = Vec::new();
+    let mut used = vec![vec![false; rh]; rw];
+
+    for y in 0..rh {
+        let gy = ry + y;
+        if gy >= hl {
+            break;
+        }
+        let mut x = 0;
+        while x < rw {
+            let gx = rx + x;
+            if gx >= hw || used[x][y] || grid[gx][gy] != tag {
+                x += 1;
+                continue;
+            }
+            // Find max width of this run
+            let mut run_w = 0;
+            while x + run_w < rw
+                && rx + x + run_w < hw
+                && !used[x + run_w][y]
+                && grid[rx + x + run_w][gy] == tag
+            {
+                run_w += 1;
+            }
+            // Extend downward as far as all columns match
+            let mut run_h = 1;
+            'outer: while y + run_h < rh && ry + y + run_h < hl {
+                for dx in 0..run_w {
+                    if rx + x + dx >= hw
+                        || used[x + dx][y + run_h]
+                        || grid[rx + x + dx][ry + y + run_h] != tag
+                    {
+                        break 'outer;
+                    }
+                }
+                run_h += 1;
+            }
+            for col in &mut used[x..x + run_w] {
+                for cell in &mut col[y..y + run_h] {
+                    *cell = true;
+                }
+            }
+            rects.push((
+                (rx + x) as u16,
+                (ry + y) as u16,
+                (rx + x + run_w) as u16,
+                (ry + y + run_h) as u16,
+            ));
+            x += run_w;
+        }
+    }
+
+    // Encode as bytes: 4 × u16 per rect = 8 bytes per rect
+    let mut bytes = Vec::with_capacity(rects.len() * 8);
+    for (x0, y0, x1, y1) in &rects {
+        bytes.extend_from_slice(&x0.to_le_bytes());
+        bytes.extend_from_slice(&y0.to_le_bytes());
+        bytes.extend_from_slice(&x1.to_le_bytes());
+        bytes.extend_from_slice(&y1.to_le_bytes());
+    }
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genlib::facilities::get_facility_manifest;
+    use crate::genlib::graph::{build_facility_graph, FacilityGraph};
+    use crate::genlib::hull::TaperCurve;
+    use crate::geometry::{self, DoorInfo, RoomRect, Severity};
+    use crate::movement::decode_cell_rects;
+
+    #[test]
+    fn test_corridor_width_grows_with_traffic() {
+        let cabins = corridor_traffic([2; 6]);
+        let mess_halls = corridor_traffic([120; 4]);
+        assert_eq!(corridor_traffic([0, 4]), 5.0);
+        assert_eq!(corridor_width_for_traffic(cabins, 4, 8), 4);
+        assert_eq!(corridor_width_for_traffic(mess_halls, 4, 8), 8);
+        assert_eq!(corridor_width_for_traffic(260.0, 4, 8), 6);
+        assert_eq!(corridor_width_for_traffic(f32::NAN, 4, 8), 4);
+    }
+
+    fn request(node: usize, target_area: f32) -> RoomRequest {
+        RoomRequest {
+            node,
+            name: String::new(),
+            room_type: room_types::STORAGE,
+            target_area,
+            capacity: 0,
+            placement: 0,
+        }
+    }
+
+    fn queues() -> ZoneQueues {
+        let mut requests = vec![Vec::new(); 7];
+        requests[1] = (0..10).map(|i| request(i, 100.0)).collect();
+        requests[2] = (10..14).map(|i| request(i, 50.0)).collect();
+        ZoneQueues::new(requests, &[1, 1, 2])
+    }
+
+    #[test]
+    fn test_zone_queues_share_rooms_between_decks() {
+        let mut zones = queues();
+        let nodes = |reqs: Vec<RoomRequest>| reqs.iter().map(|r| r.node).collect::<Vec<_>>();
+        // Half the habitation rooms each deck
+        assert_eq!(nodes(zones.take_for_deck(1, 500.0)), vec![0, 1, 2, 3, 4]);
+        assert_eq!(nodes(zones.take_for_deck(1, 500.0)), vec![5, 6, 7, 8, 9]);
+        assert_eq!(nodes(zones.take_for_deck(2, 500.0)), vec![10, 11, 12, 13]);
+        assert_eq!(zones.leftover().count(), 0);
+
+        // An underfilled deck tops up from the other zones
+        let mut zones = queues();
+        assert_eq!(zones.take_for_deck(2, 1000.0).len(), 4 + 8);
+        assert_eq!(zones.leftover().collect::<Vec<_>>(), vec![8, 9]);
+    }
+
+    #[test]
+    fn test_zone_queues_respect_area_budget() {
+        let mut zones = queues();
+        assert_eq!(zones.take_for_deck(1, 250.0).len(), 3);
+        assert_eq!(deck_area_budget(10, 20), 160.0);
+    }
+
+    #[test]
+    fn test_deck_inner_size_inside_the_ring() {
+        let hull = HullProfile::default();
+        let (w, h) = deck_inner_size(&hull, 5, 10, 65, 400).unwrap();
+        assert_eq!(w, hull.width(5, 10, 65) - 2 * RING_WIDTH);
+        assert_eq!(h, hull.length(5, 10, 400) - 2 * RING_WIDTH);
+        // Tapered decks are narrower than midship
+        let (end_w, _) = deck_inner_size(&hull, 0, 10, 65, 400).unwrap();
+        assert!(end_w <= w);
+        assert_eq!(deck_inner_size(&hull, 0, 10, 10, 400), None);
+    }
+
+    #[test]
+    fn test_shaft_deck_span_is_unbroken() {
+        let fits = [false, true, true, false, true, true, true, false, true];
+        assert_eq!(shaft_deck_span(&fits, 5), 4..7);
+        assert_eq!(shaft_deck_span(&fits, 1), 1..3);
+        assert_eq!(shaft_deck_span(&[true; 4], 2), 0..4);
+        assert!(shaft_deck_span(&fits, 3).is_empty());
+    }
+
+    fn small_ship() -> (FacilityGraph, LayoutConfig) {
+        let graph = build_facility_graph(&get_facility_manifest(), 40, 120);
+        let config = LayoutConfig {
+            deck_count: 6,
+            ship_beam: 60,
+            ship_length: 240,
+            population: 160,
+            ..Default::default()
+        };
+        (graph, config)
+    }
+
+    /// Rooms as geometry rects, one per cell rect for irregular rooms.
+    fn to_geometry(plan: &ShipLayoutPlan) -> (Vec<RoomRect>, Vec<DoorInfo>) {
+        let rooms = plan
+            .rooms
+            .iter()
+            .flat_map(|r| {
+                let mut rects: Vec<(f32, f32, f32, f32)> = decode_cell_rects(&r.cells)
+                    .into_iter()
+                    .map(|(x0, y0, x1, y1)| {
+                        (x0 as f32, y0 as f32, (x1 - x0) as f32, (y1 - y0) as f32)
+                    })
+                    .collect();
+                if rects.is_empty() {
+                    rects.push((r.x - r.width / 2.0, r.y - r.height / 2.0, r.width, r.height));
+                }
+                rects.into_iter().map(|(x, y, width, height)| RoomRect {
+                    id: r.id,
+                    deck: r.deck,
+                    x,
+                    y,
+                    width,
+                    height,
+                    room_type: r.room_type,
+                    capacity: r.capacity,
+                })
+            })
+            .collect();
+        let doors = plan
+            .doors
+            .iter()
+            .enumerate()
+            .map(|(i, d)| DoorInfo {
+                id: i as u64,
+                room_a: d.room_a,
+                room_b: d.room_b,
+                door_x: d.x,
+                door_y: d.y,
+                wall_a: d.wall_a,
+                wall_b: d.wall_b,
+            })
+            .collect();
+        (rooms, doors)
+    }
+
+    #[test]
+    fn test_every_deck_has_ring_spine_and_shafts() {
+        let (graph, config) = small_ship();
+        let plan = generate_layout(&graph.nodes, &config);
+        for deck in 0..config.deck_count as i32 {
+            let named = |prefix: &str| {
+                plan.rooms
+                    .iter()
+                    .filter(|r| r.deck == deck && r.name.starts_with(prefix))
+                    .count()
+            };
+            assert_eq!(named("Ring "), 4, "deck {}", deck);
+            assert!(named("Spine ") >= 2, "deck {}", deck);
+            assert!(named("Cross-Corridor ") >= 1, "deck {}", deck);
+        }
+        let elevators = plan
+            .shafts
+            .iter()
+            .filter(|s| s.shaft_type == shaft_types::ELEVATOR)
+            .count();
+        assert!(elevators >= 2);
+        for shaft in &plan.shafts {
+            assert_eq!(shaft.deck_rooms.len(), shaft.decks_served.len());
+            assert!(shaft.decks_served.windows(2).all(|d| d[1] == d[0] + 1));
+        }
+    }
+
+    #[test]
+    fn test_layout_passes_geometry_validation() {
+        let (graph, config) = small_ship();
+        let plan = generate_layout(&graph.nodes, &config);
+        let (rooms, doors) = to_geometry(&plan);
+        let stacks: Vec<Vec<u32>> = plan.shafts.iter().map(|s| s.deck_rooms.clone()).collect();
+        let errors = geometry::check_shaft_alignment(&stacks, &rooms, &doors);
+        assert!(errors.is_empty(), "{:?}", errors);
+
+        let errors: Vec<_> = geometry::validate_all(
+            &rooms,
+            &doors,
+            plan.ship_beam as f32,
+            (plan.ship_length + 2 * HULL_BAND_WIDTH) as f32,
+        )
+        .into_iter()
+        .filter(|e| e.severity == Severity::Error)
+        .collect();
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_rooms_inside_tapered_outline() {
+        let (graph, config) = small_ship();
+        let plan = generate_layout(&graph.nodes, &config);
+        let mid = config.deck_count / 2;
+        let mid_w = config.hull.width(mid, config.deck_count, config.ship_beam);
+        let mid_l = config
+            .hull
+            .length(mid, config.deck_count, config.ship_length);
+        for r in &plan.rooms {
+            let deck = r.deck as u32;
+            let w = config.hull.width(deck, config.deck_count, config.ship_beam);
+            let l = config
+                .hull
+                .length(deck, config.deck_count, config.ship_length);
+            let x0 = ((mid_w - w) / 2) as f32;
+            let y0 = ((mid_l - l) / 2) as f32;
+            let y1 = (mid_l + 2 * HULL_BAND_WIDTH) as f32 - y0;
+            assert!(r.x - r.width / 2.0 >= x0, "{} west of hull", r.name);
+            assert!(
+                r.x + r.width / 2.0 <= mid_w as f32 - x0,
+                "{} east of hull",
+                r.name
+            );
+            assert!(r.y - r.height / 2.0 >= y0, "{} fore of hull", r.name);
+            assert!(r.y + r.height / 2.0 <= y1, "{} aft of hull", r.name);
+        }
+    }
+
+    #[test]
+    fn test_graph_nodes_become_rooms() {
+        let (graph, config) = small_ship();
+        let plan = generate_layout(&graph.nodes, &config);
+        let mut placed: Vec<usize> = plan.rooms.iter().filter_map(|r| r.node).collect();
+        placed.sort_unstable();
+        assert!(placed.windows(2).all(|p| p[0] != p[1]), "node placed twice");
+        let mut all: Vec<usize> = placed.iter().chain(&plan.unplaced).copied().collect();
+        all.sort_unstable();
+        all.dedup();
+        assert_eq!(all.len(), graph.nodes.len());
+        assert!(
+            placed.len() > graph.nodes.len() / 2,
+            "placed {}",
+            placed.len()
+        );
+        let bridge = plan
+            .rooms
+            .iter()
+            .find(|r| r.room_type == room_types::BRIDGE);
+        assert_eq!(bridge.map(|r| r.deck), Some(0), "bridge on top deck");
+    }
+
+    #[test]
+    fn test_decks_taper_toward_bow_and_stern() {
+        let (graph, config) = small_ship();
+        let plan = generate_layout(&graph.nodes, &config);
+        let ring_width = |deck: i32| {
+            plan.rooms
+                .iter()
+                .find(|r| r.deck == deck && r.name.starts_with("Ring North"))
+                .map(|r| r.width)
+                .unwrap_or(0.0)
+        };
+        let mid = config.deck_count as i32 / 2;
+        assert!(ring_width(0) < ring_width(mid));
+        assert!(ring_width(config.deck_count as i32 - 1) < ring_width(mid));
+    }
+
+    #[test]
+    fn test_taper_curve_changes_deck_footprints() {
+        let (graph, mut config) = small_ship();
+        let ring_widths = |config: &LayoutConfig| {
+            let plan = generate_layout(&graph.nodes, config);
+            (0..config.deck_count as i32)
+                .map(|deck| {
+                    plan.rooms
+                        .iter()
+                        .find(|r| r.deck == deck && r.name.starts_with("Ring North"))
+                        .map(|r| r.width)
+                        .unwrap_or(0.0)
+                })
+                .collect::<Vec<_>>()
+        };
+        let elliptical = ring_widths(&config);
+        config.hull = HullProfile::with_taper(TaperCurve::Step);
+        let step = ring_widths(&config);
+        assert_ne!(step, elliptical);
+        for (s, e) in step.iter().zip(&elliptical) {
+            assert!(
+                s <= e,
+                "step {:?} wider than elliptical {:?}",
+                step,
+                elliptical
+            );
+        }
+    }
+
+    #[test]
+    fn test_room_cap_per_deck() {
+        let (graph, mut config) = small_ship();
+        config.max_rooms_per_deck = Some(3);
+        let plan = generate_layout(&graph.nodes, &config);
+        for deck in 0..config.deck_count as i32 {
+            let facility = plan
+                .rooms
+                .iter()
+                .filter(|r| r.deck == deck && r.node.is_some())
+                .count();
+            assert!(facility <= 3, "deck {} has {} rooms", deck, facility);
+        }
+        let placed = plan.rooms.iter().filter(|r| r.node.is_some()).count();
+        assert!(placed + plan.unplaced.len() >= graph.nodes.len());
+    }
+
+    #[test]
+    fn test_deck_by_deck_matches_whole_ship() {
+        let (graph, config) = small_ship();
+        let whole = generate_layout(&graph.nodes, &config);
+
+        let mut config = config.clone();
+        let mut rooms = Vec::new();
+        let mut doors = Vec::new();
+        let mut shafts = Vec::new();
+        for deck in 0..config.deck_count {
+            let plan = generate_deck_layouts(&graph.nodes, &config, deck..=deck);
+            config.first_room_id += plan.rooms.len() as u32;
+            rooms.extend(plan.rooms);
+            doors.extend(plan.doors);
+            shafts = plan.shafts;
+        }
+        doors.extend(join_shafts(&mut shafts, config.deck_count, |name| {
+            rooms.iter().find(|r| r.name == name).map(|r| r.id)
+        }));
+
+        let key = |r: &LayoutRoom| (r.id, r.name.clone(), r.x, r.y, r.width, r.height);
+        assert_eq!(
+            rooms.iter().map(key).collect::<Vec<_>>(),
+            whole.rooms.iter().map(key).collect::<Vec<_>>()
+        );
+        assert_eq!(doors.len(), whole.doors.len());
+    }
+
+    #[test]
+    fn test_deck_too_narrow_for_rooms() {
+        let (graph, mut config) = small_ship();
+        config.ship_beam = 8;
+        let plan = generate_layout(&graph.nodes, &config);
+        assert!(plan.rooms.is_empty());
+        let mut unplaced = plan.unplaced.clone();
+        unplaced.sort_unstable();
+        unplaced.dedup();
+        assert_eq!(unplaced.len(), graph.nodes.len());
+    }
+
+    /// Farthest any point of a facility room lies from the nearest spine, in meters.
+    fn max_spine_reach(plan: &ShipLayoutPlan) -> f32 {
+        let spines: Vec<&LayoutRoom> = plan
+            .rooms
+            .iter()
+            .filter(|r| r.name.contains("Spine D"))
+            .collect();
+        let reach = |deck: i32, x: f32| {
+            spines
+                .iter()
+                .filter(|s| s.deck == deck)
+                .map(|s| {
+                    let (a, b) = (s.x - s.width / 2.0, s.x + s.width / 2.0);
+                    (a - x).max(x - b).max(0.0)
+                })
+                .fold(f32::MAX, f32::min)
+        };
+        plan.rooms
+            .iter()
+            .filter(|r| r.node.is_some() && r.y > HULL_BAND_WIDTH as f32)
+            .filter(|r| r.y < (plan.ship_length + HULL_BAND_WIDTH) as f32)
+            .flat_map(|r| {
+                let x0 = r.x - r.width / 2.0;
+                (0..=r.width as usize).map(move |i| (r.deck, x0 + i as f32))
+            })
+            .map(|(deck, x)| reach(deck, x))
+            .fold(0.0, f32::max)
+    }
+
+    #[test]
+    fn test_twin_spine_topology() {
+        let (graph, mut config) = small_ship();
+        config.ship_beam = 65;
+        config.topology = CorridorTopology::TwinSpine;
+        let plan = generate_layout(&graph.nodes, &config);
+
+        let mid = config.deck_count as i32 / 2;
+        let spine_xs = |deck: i32| {
+            let mut xs: Vec<i32> = plan
+                .rooms
+                .iter()
+                .filter(|r| r.deck == deck && r.name.contains("Spine D"))
+                .map(|r| r.x as i32)
+                .collect();
+            xs.dedup();
+            xs
+        };
+        assert_eq!(spine_xs(mid).len(), 2);
+
+        // Cross-corridors join the two spines
+        let spine_doors = |room: &LayoutRoom| {
+            plan.doors
+                .iter()
+                .filter_map(|d| match (d.room_a, d.room_b) {
+                    (a, b) if a == room.id => Some(b),
+                    (a, b) if b == room.id => Some(a),
+                    _ => None,
+                })
+                .filter_map(|id| plan.rooms.iter().find(|r| r.id == id))
+                .filter(|r| r.name.contains("Spine D"))
+                .map(|r| r.x as i32)
+                .collect::<Vec<_>>()
+        };
+        for cross in plan
+            .rooms
+            .iter()
+            .filter(|r| r.deck == mid && r.room_type == room_types::CROSS_CORRIDOR)
+        {
+            let mut xs = spine_doors(cross);
+            xs.sort_unstable();
+            xs.dedup();
+            assert_eq!(xs, spine_xs(mid), "{}", cross.name);
+        }
+
+        let (rooms, doors) = to_geometry(&plan);
+        let errors: Vec<_> = geometry::validate_all(
+            &rooms,
+            &doors,
+            plan.ship_beam as f32,
+            (plan.ship_length + 2 * HULL_BAND_WIDTH) as f32,
+        )
+        .into_iter()
+        .filter(|e| e.severity == Severity::Error)
+        .collect();
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_twin_spine_shortens_room_reach() {
+        let (graph, mut config) = small_ship();
+        config.ship_beam = 65;
+        let central = generate_layout(&graph.nodes, &config);
+        config.topology = CorridorTopology::TwinSpine;
+        let twin = generate_layout(&graph.nodes, &config);
+        assert!(max_spine_reach(&twin) < max_spine_reach(&central));
+    }
+
+    #[test]
+    fn test_twin_spine_narrow_beam_falls_back() {
+        assert_eq!(CorridorTopology::TwinSpine.spine_xs(16, 4).len(), 1);
+        assert_eq!(CorridorTopology::TwinSpine.spine_xs(65, 4), vec![14, 47]);
+        assert_eq!(CorridorTopology::CentralSpine.spine_xs(65, 4), vec![30]);
+    }
+
+    #[test]
+    fn test_corridor_topology_from_u8() {
+        for topology in [CorridorTopology::CentralSpine, CorridorTopology::TwinSpine] {
+            assert_eq!(CorridorTopology::from_u8(topology as u8), Some(topology));
+        }
+        assert_eq!(CorridorTopology::from_u8(2), None);
+    }
+}
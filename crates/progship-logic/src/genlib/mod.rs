@@ -0,0 +1,21 @@
+//! Database-free ship generation shared by the server and the core engine.
+//!
+//! The pipeline runs facility manifest → [`graph`] → [`hull`] outline →
+//! ring-and-spur corridors, BSP room packing, doors and shafts ([`layout`],
+//! with direct room doors from [`room_doors`]), with [`crawlways`] threaded
+//! behind the rooms for maintenance.
+//! Callers turn the resulting plan into their own rows or entities, and
+//! [`decks`] names and themes each deck from the rooms placed on it while
+//! [`furniture`] fills each room with beds, tables, consoles and racks and
+//! [`anchors`] marks where people stand or sit to use them.
+
+pub mod anchors;
+pub mod crawlways;
+pub mod decks;
+pub mod facilities;
+pub mod furniture;
+pub mod graph;
+pub mod hull;
+pub mod layout;
+pub mod room_doors;
+pub mod treemap;
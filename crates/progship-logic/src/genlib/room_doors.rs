@@ -3,12 +3,12 @@
 //! Most rooms connect only to corridors. This module defines the special cases
 //! where direct doors between rooms make logical sense (e.g., galley↔mess hall).
 
-use crate::tables::room_types;
+use crate::constants::room_types;
 
 /// Returns true if two room types should have a direct door between them.
 /// Most rooms connect to corridors only; direct room-to-room doors are for
 /// logically connected pairs (e.g., galley↔mess, surgery↔hospital).
-pub(super) fn should_have_room_door(a: u8, b: u8) -> bool {
+pub fn should_have_room_door(a: u8, b: u8) -> bool {
     let (lo, hi) = if a < b { (a, b) } else { (b, a) };
     matches!(
         (lo, hi),
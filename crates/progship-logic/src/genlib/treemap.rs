@@ -3,33 +3,10 @@
 //! Implements the classic squarified treemap algorithm to pack weighted rectangles
 //! into available grid zones, minimizing aspect ratio distortion.
 
-/// Room request for treemap placement.
-#[derive(Clone)]
-pub(super) struct RoomRequest {
-    pub node_id: u64,
-    pub name: String,
-    pub room_type: u8,
-    pub target_area: f32,
-    pub capacity: u32,
-    pub group: u8,
-    pub placement: u8,
-}
-
-/// Placed room result from treemap.
-pub(super) struct PlacedRoom {
-    pub room_id: u32,
-    pub node_id: u64,
-    pub x: usize,
-    pub y: usize,
-    pub w: usize,
-    pub h: usize,
-    pub room_type: u8,
-}
-
 /// Cap room dimensions so area doesn't exceed `max_area`.
 /// Scales both dimensions proportionally, preserving aspect ratio.
 /// Returns (capped_w, capped_h), each at least `min_dim`.
-pub(super) fn cap_room_dimensions(
+pub fn cap_room_dimensions(
     w: usize,
     h: usize,
     target_area: f32,
@@ -50,7 +27,7 @@ pub(super) fn cap_room_dimensions(
 
 /// Squarified treemap: packs weighted rectangles into a zone.
 /// Returns (original_index, x, y, w, h) for each room.
-pub(super) fn squarified_treemap(
+pub fn squarified_treemap(
     rooms: &[(f32, usize)], // (area_weight, original_index)
     zone_x: usize,
     zone_y: usize,
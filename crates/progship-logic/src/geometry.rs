@@ -1,7 +1,8 @@
 //! Geometry validation for generated ship layouts.
 //!
-//! Pure functions that take room/door data and return validation errors.
-//! No database dependency — works with plain structs.
+//! Pure functions that take room/door data and return validation errors,
+//! plus [`layout_stats`] for balancing the generator. No database
+//! dependency — works with plain structs.
 
 use crate::constants::room_types;
 use std::collections::{HashMap, HashSet, VecDeque};
@@ -371,6 +372,181 @@ pub fn check_inter_deck_connectivity(
     errors
 }
 
+/// Check vertical shafts, each given as its room ids ordered by deck: every
+/// room has the first room's footprint, the decks are consecutive, and each
+/// pair of neighboring decks is joined by a door inside the footprint.
+pub fn check_shaft_alignment(
+    shafts: &[Vec<u32>],
+    rooms: &[RoomRect],
+    doors: &[DoorInfo],
+) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+    let by_id: HashMap<u32, &RoomRect> = rooms.iter().map(|r| (r.id, r)).collect();
+    let error = |message: String| ValidationError {
+        category: "shaft",
+        severity: Severity::Error,
+        message,
+    };
+    let same = |a: f32, b: f32| (a - b).abs() < 0.01;
+
+    for stack in shafts {
+        let stack: Vec<&RoomRect> = stack
+            .iter()
+            .filter_map(|id| by_id.get(id).copied())
+            .collect();
+        let Some(&base) = stack.first() else {
+            continue;
+        };
+        for pair in stack.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            if !(same(b.x, base.x)
+                && same(b.y, base.y)
+                && same(b.width, base.width)
+                && same(b.height, base.height))
+            {
+                errors.push(error(format!(
+                    "Shaft room #{} (deck {}) is offset from room #{} (deck {})",
+                    b.id, b.deck, base.id, base.deck
+                )));
+            }
+            if b.deck != a.deck + 1 {
+                errors.push(error(format!(
+                    "Shaft rooms #{} and #{} skip from deck {} to deck {}",
+                    a.id, b.id, a.deck, b.deck
+                )));
+                continue;
+            }
+            let joining: Vec<&DoorInfo> = doors
+                .iter()
+                .filter(|d| {
+                    (d.room_a, d.room_b) == (a.id, b.id) || (d.room_a, d.room_b) == (b.id, a.id)
+                })
+                .collect();
+            let inside = joining.iter().any(|d| {
+                (a.x..=a.x + a.width).contains(&d.door_x)
+                    && (a.y..=a.y + a.height).contains(&d.door_y)
+            });
+            if joining.is_empty() {
+                errors.push(error(format!(
+                    "No door between shaft rooms #{} (deck {}) and #{} (deck {})",
+                    a.id, a.deck, b.id, b.deck
+                )));
+            } else if !inside {
+                errors.push(error(format!(
+                    "Door between shaft rooms #{} and #{} lies outside the shaft",
+                    a.id, b.id
+                )));
+            }
+        }
+    }
+    errors
+}
+
+// ── Generation metrics ──────────────────────────────────────────────────
+
+/// Summary numbers for balancing the generator.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LayoutStats {
+    pub room_count: u32,
+    pub door_count: u32,
+    /// Bounding-box floor area of every room except shafts, in m².
+    pub total_floor_area: f32,
+    /// Floor area per person aboard, in m².
+    pub area_per_person: f32,
+    /// Share of the floor area that is corridor.
+    pub corridor_fraction: f32,
+    /// Mean long-side to short-side ratio of the non-corridor rooms.
+    pub mean_aspect_ratio: f32,
+    /// Rooms outside the largest group of door-connected rooms.
+    pub unreachable_rooms: u32,
+    /// Doors by access level (index = `access_levels` value).
+    pub doors_by_access: Vec<u32>,
+}
+
+/// Compute [`LayoutStats`] for a generated ship. `door_access_levels` holds
+/// each door's access level, in any order.
+pub fn layout_stats(
+    rooms: &[RoomRect],
+    doors: &[DoorInfo],
+    door_access_levels: &[u8],
+    population: u32,
+) -> LayoutStats {
+    let area = |r: &RoomRect| r.width.max(0.0) * r.height.max(0.0);
+    let floor: Vec<&RoomRect> = rooms
+        .iter()
+        .filter(|r| !room_types::is_shaft(r.room_type))
+        .collect();
+    let total_floor_area: f32 = floor.iter().map(|r| area(r)).sum();
+    let corridor_area: f32 = floor
+        .iter()
+        .filter(|r| room_types::is_plain_corridor(r.room_type))
+        .map(|r| area(r))
+        .sum();
+
+    let ratios: Vec<f32> = rooms
+        .iter()
+        .filter(|r| !room_types::is_corridor(r.room_type) && r.width > 0.0 && r.height > 0.0)
+        .map(|r| r.width.max(r.height) / r.width.min(r.height))
+        .collect();
+
+    // Largest connected group, by BFS over the doors
+    let mut adj: HashMap<u32, Vec<u32>> = HashMap::new();
+    for d in doors {
+        adj.entry(d.room_a).or_default().push(d.room_b);
+        adj.entry(d.room_b).or_default().push(d.room_a);
+    }
+    let mut seen: HashSet<u32> = HashSet::new();
+    let mut largest = 0;
+    for r in rooms {
+        if !seen.insert(r.id) {
+            continue;
+        }
+        let mut size = 0;
+        let mut queue = VecDeque::from([r.id]);
+        while let Some(current) = queue.pop_front() {
+            size += 1;
+            for &next in adj.get(&current).into_iter().flatten() {
+                if seen.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        largest = largest.max(size);
+    }
+
+    let mut doors_by_access = Vec::new();
+    for &level in door_access_levels {
+        let level = level as usize;
+        if doors_by_access.len() <= level {
+            doors_by_access.resize(level + 1, 0);
+        }
+        doors_by_access[level] += 1;
+    }
+
+    LayoutStats {
+        room_count: rooms.len() as u32,
+        door_count: doors.len() as u32,
+        total_floor_area,
+        area_per_person: if population > 0 {
+            total_floor_area / population as f32
+        } else {
+            0.0
+        },
+        corridor_fraction: if total_floor_area > 0.0 {
+            corridor_area / total_floor_area
+        } else {
+            0.0
+        },
+        mean_aspect_ratio: if ratios.is_empty() {
+            0.0
+        } else {
+            ratios.iter().sum::<f32>() / ratios.len() as f32
+        },
+        unreachable_rooms: rooms.len().saturating_sub(largest) as u32,
+        doors_by_access,
+    }
+}
+
 // ── Master validation ───────────────────────────────────────────────────
 
 /// Run all geometry validations and return combined results.
@@ -543,6 +719,66 @@ mod tests {
         assert_eq!(errs.len(), 1);
     }
 
+    #[test]
+    fn test_shaft_alignment() {
+        let rooms = vec![
+            make_room(1, 0, 10.0, 10.0, 4.0, 4.0),
+            make_room(2, 1, 10.0, 10.0, 4.0, 4.0),
+            make_room(3, 2, 10.0, 10.0, 4.0, 4.0),
+            make_room(4, 3, 11.0, 10.0, 4.0, 4.0),
+            make_room(5, 5, 10.0, 10.0, 4.0, 4.0),
+        ];
+        let doors = vec![
+            make_door(1, 1, 2, 12.0, 12.0),
+            make_door(2, 3, 2, 12.0, 12.0),
+            make_door(3, 3, 4, 30.0, 12.0),
+        ];
+        assert!(check_shaft_alignment(&[vec![1, 2, 3]], &rooms, &doors).is_empty());
+
+        let errs = check_shaft_alignment(&[vec![1, 2, 3, 4, 5]], &rooms, &doors);
+        let messages: Vec<&str> = errs.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(errs.len(), 3, "{messages:?}");
+        assert!(messages[0].contains("#4 (deck 3) is offset"));
+        assert!(messages[1].contains("lies outside the shaft"));
+        assert!(messages[2].contains("skip from deck 3 to deck 5"));
+        assert!(errs.iter().all(|e| e.category == "shaft"));
+
+        let errs = check_shaft_alignment(&[vec![1, 2, 3]], &rooms, &doors[..1]);
+        assert_eq!(errs.len(), 1);
+        assert!(errs[0]
+            .message
+            .starts_with("No door between shaft rooms #2"));
+    }
+
+    #[test]
+    fn test_layout_stats() {
+        let mut corridor = make_room(3, 0, 0.0, 10.0, 20.0, 2.0);
+        corridor.room_type = room_types::CORRIDOR;
+        let mut shaft = make_room(4, 0, 0.0, 20.0, 3.0, 3.0);
+        shaft.room_type = room_types::ELEVATOR_SHAFT;
+        let rooms = vec![
+            make_room(1, 0, 0.0, 0.0, 10.0, 10.0),
+            make_room(2, 0, 10.0, 0.0, 10.0, 5.0),
+            corridor,
+            shaft,
+            make_room(5, 0, 40.0, 0.0, 4.0, 4.0),
+        ];
+        let doors = vec![
+            make_door(1, 1, 3, 5.0, 10.0),
+            make_door(2, 2, 3, 15.0, 10.0),
+            make_door(3, 3, 4, 1.0, 12.0),
+        ];
+        let stats = layout_stats(&rooms, &doors, &[0, 0, 2], 10);
+        assert_eq!((stats.room_count, stats.door_count), (5, 3));
+        assert_eq!(stats.total_floor_area, 206.0);
+        assert!((stats.area_per_person - 20.6).abs() < 1e-4);
+        assert!((stats.corridor_fraction - 40.0 / 206.0).abs() < 1e-6);
+        assert!((stats.mean_aspect_ratio - 4.0 / 3.0).abs() < 1e-6);
+        assert_eq!(stats.unreachable_rooms, 1);
+        assert_eq!(stats.doors_by_access, vec![2, 0, 1]);
+        assert_eq!(layout_stats(&[], &[], &[], 0), LayoutStats::default());
+    }
+
     #[test]
     fn test_validate_all_clean() {
         let rooms = vec![
@@ -0,0 +1,268 @@
+//! Governance — council elections and the policies the council sets.
+//!
+//! Passengers elect a civilian council of [`COUNCIL_SEATS`] a week into the
+//! voyage and every [`TERM_HOURS`] after ([`election_due`]). The most
+//! outgoing, dependable and well-liked passengers stand ([`candidacy`]);
+//! every passenger votes for the candidate they score highest
+//! ([`vote_score`]): a friend, someone of their own faction, and an
+//! incumbent if life is going well or a challenger if it isn't.
+//!
+//! The council meets weekly and sets the ship's [`Policy`] from how things
+//! stand ([`decide_policy`]): rationing ahead of the automatic levels
+//! ([`effective_rationing`]), longer or shorter watches, and a curfew that
+//! keeps passengers in quarters overnight ([`curfew_pick`]).
+
+use crate::constants::activity_types;
+use crate::economy::{rationing_to_u8, u8_to_rationing, RationingLevel};
+use crate::utility::{RoomCategory, RoomTarget};
+
+/// Seats on the civilian council.
+pub const COUNCIL_SEATS: usize = 5;
+
+/// Candidates standing in each election.
+pub const CANDIDATES: usize = 10;
+
+/// Hours into the voyage of the first election.
+pub const FIRST_ELECTION_HOURS: f64 = 7.0 * 24.0;
+
+/// Hours between elections: a council's term.
+pub const TERM_HOURS: f64 = 90.0 * 24.0;
+
+/// Hours between council sessions.
+pub const SESSION_HOURS: f64 = 7.0 * 24.0;
+
+/// The regular length of a watch (hours).
+pub const REGULAR_WORK_HOURS: u8 = 8;
+
+/// Watch length when the ship needs all hands.
+pub const EXTENDED_WORK_HOURS: u8 = 10;
+
+/// Watch length when the crew needs rest.
+pub const SHORT_WORK_HOURS: u8 = 6;
+
+/// Incidents per hundred people a week that bring in a curfew.
+pub const CURFEW_CRIME_RATE: f32 = 1.0;
+
+/// How the ship is run: a floor on rationing (see `economy`), the length
+/// of a watch and whether passengers keep to quarters overnight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Policy {
+    pub rationing: u8,
+    pub work_hours: u8,
+    pub curfew: bool,
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Self {
+            rationing: 0,
+            work_hours: REGULAR_WORK_HOURS,
+            curfew: false,
+        }
+    }
+}
+
+/// What the council weighs at a session: the food reserve (0.0–1.0 of
+/// capacity), average morale and equipment health, incidents per hundred
+/// people over the past week, and how cautious (conscientious) the
+/// councillors are on average.
+#[derive(Debug, Clone, Copy)]
+pub struct CouncilBrief {
+    pub food: f32,
+    pub morale: f32,
+    pub system_health: f32,
+    pub crime_rate: f32,
+    pub caution: f32,
+}
+
+/// Whether an election is due at `sim_time`, the last one having been
+/// held at `last`.
+pub fn election_due(sim_time: f64, last: Option<f64>) -> bool {
+    match last {
+        Some(held_at) => sim_time - held_at >= TERM_HOURS,
+        None => sim_time >= FIRST_ELECTION_HOURS,
+    }
+}
+
+/// How strong a candidate someone with `extraversion`, `conscientiousness`
+/// and `friends` friends aboard makes (0.0–1.0).
+pub fn candidacy(extraversion: f32, conscientiousness: f32, friends: u32) -> f32 {
+    0.5 * extraversion.clamp(0.0, 1.0)
+        + 0.3 * conscientiousness.clamp(0.0, 1.0)
+        + 0.2 * (friends as f32 / 10.0).min(1.0)
+}
+
+/// How much a voter with `morale` favors a candidate they have a
+/// `relationship` of that strength with (0.0 for strangers), who may share
+/// their faction and may hold a seat, with `appeal` their [`candidacy`].
+pub fn vote_score(
+    relationship: f32,
+    same_faction: bool,
+    incumbent: bool,
+    morale: f32,
+    appeal: f32,
+) -> f32 {
+    let loyalty = if same_faction { 0.3 } else { 0.0 };
+    let record = if incumbent {
+        (morale.clamp(0.0, 1.0) - 0.5) * 0.6
+    } else {
+        0.0
+    };
+    relationship.clamp(-1.0, 1.0) + loyalty + record + 0.1 * appeal
+}
+
+/// The policy the council sets given `brief`: rationing early when food
+/// runs low (earlier the more cautious they are), longer watches while
+/// equipment is failing or shorter ones for a worn-out ship, and a curfew
+/// once crime reaches [`CURFEW_CRIME_RATE`].
+pub fn decide_policy(brief: &CouncilBrief) -> Policy {
+    let caution = brief.caution.clamp(0.0, 1.0);
+    let rationing = if brief.food < 0.35 + 0.1 * caution {
+        2
+    } else if brief.food < 0.6 + 0.2 * caution {
+        1
+    } else {
+        0
+    };
+    let work_hours = if brief.system_health < 0.6 {
+        EXTENDED_WORK_HOURS
+    } else if brief.morale < 0.4 {
+        SHORT_WORK_HOURS
+    } else {
+        REGULAR_WORK_HOURS
+    };
+    Policy {
+        rationing,
+        work_hours,
+        curfew: brief.crime_rate >= CURFEW_CRIME_RATE,
+    }
+}
+
+/// The rationing in force: the council's `ordered` level where it is
+/// stricter than the `automatic` one.
+pub fn effective_rationing(automatic: RationingLevel, ordered: u8) -> RationingLevel {
+    u8_to_rationing(rationing_to_u8(automatic).max(ordered))
+}
+
+/// An activity pick as kept under curfew: anything done in quarters
+/// stands, anything else becomes relaxing in quarters for up to an hour.
+pub fn curfew_pick(pick: (u8, f32, RoomTarget)) -> (u8, f32, RoomTarget) {
+    match pick.2 {
+        RoomTarget::Category(RoomCategory::Quarters) => pick,
+        _ => (
+            activity_types::RELAXING,
+            pick.1.min(1.0),
+            RoomTarget::Category(RoomCategory::Quarters),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brief() -> CouncilBrief {
+        CouncilBrief {
+            food: 0.9,
+            morale: 0.7,
+            system_health: 0.9,
+            crime_rate: 0.0,
+            caution: 0.5,
+        }
+    }
+
+    #[test]
+    fn test_election_due() {
+        assert!(!election_due(FIRST_ELECTION_HOURS - 1.0, None));
+        assert!(election_due(FIRST_ELECTION_HOURS, None));
+        assert!(!election_due(500.0, Some(400.0)));
+        assert!(election_due(400.0 + TERM_HOURS, Some(400.0)));
+    }
+
+    #[test]
+    fn test_candidacy() {
+        assert!(candidacy(0.9, 0.5, 5) > candidacy(0.2, 0.5, 5));
+        assert!(candidacy(0.5, 0.5, 10) > candidacy(0.5, 0.5, 0));
+        assert_eq!(candidacy(0.5, 0.5, 10), candidacy(0.5, 0.5, 40));
+        assert!((candidacy(1.0, 1.0, 10) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_voting() {
+        // Friends and faction win votes
+        assert!(vote_score(0.6, false, false, 0.5, 0.5) > vote_score(0.0, false, false, 0.5, 0.5));
+        assert!(vote_score(0.0, true, false, 0.5, 0.5) > vote_score(0.0, false, false, 0.5, 0.5));
+        // Happy voters keep incumbents, unhappy ones throw them out
+        assert!(vote_score(0.0, false, true, 0.9, 0.5) > vote_score(0.0, false, false, 0.9, 0.5));
+        assert!(vote_score(0.0, false, true, 0.1, 0.5) < vote_score(0.0, false, false, 0.1, 0.5));
+    }
+
+    #[test]
+    fn test_council_policy() {
+        assert_eq!(decide_policy(&brief()), Policy::default());
+        let hungry = |food, caution| {
+            decide_policy(&CouncilBrief {
+                food,
+                caution,
+                ..brief()
+            })
+            .rationing
+        };
+        assert_eq!(hungry(0.65, 0.0), 0);
+        assert_eq!(hungry(0.65, 1.0), 1);
+        assert_eq!(hungry(0.3, 0.0), 2);
+
+        let failing = CouncilBrief {
+            system_health: 0.4,
+            morale: 0.2,
+            ..brief()
+        };
+        assert_eq!(decide_policy(&failing).work_hours, EXTENDED_WORK_HOURS);
+        let weary = CouncilBrief {
+            morale: 0.2,
+            ..brief()
+        };
+        assert_eq!(decide_policy(&weary).work_hours, SHORT_WORK_HOURS);
+        let lawless = CouncilBrief {
+            crime_rate: CURFEW_CRIME_RATE,
+            ..brief()
+        };
+        assert!(decide_policy(&lawless).curfew);
+    }
+
+    #[test]
+    fn test_effective_rationing() {
+        assert_eq!(
+            effective_rationing(RationingLevel::Normal, 1),
+            RationingLevel::Light
+        );
+        assert_eq!(
+            effective_rationing(RationingLevel::Heavy, 1),
+            RationingLevel::Heavy
+        );
+        assert_eq!(
+            effective_rationing(RationingLevel::Light, 0),
+            RationingLevel::Light
+        );
+    }
+
+    #[test]
+    fn test_curfew_keeps_passengers_in_quarters() {
+        let kept = curfew_pick((
+            activity_types::SLEEPING,
+            8.0,
+            RoomTarget::Category(RoomCategory::Quarters),
+        ));
+        assert_eq!((kept.0, kept.1), (activity_types::SLEEPING, 8.0));
+        let out = curfew_pick((
+            activity_types::SOCIALIZING,
+            2.0,
+            RoomTarget::Category(RoomCategory::Recreation),
+        ));
+        assert_eq!((out.0, out.1), (activity_types::RELAXING, 1.0));
+        assert!(matches!(
+            out.2,
+            RoomTarget::Category(RoomCategory::Quarters)
+        ));
+    }
+}
@@ -0,0 +1,123 @@
+//! Voyage hazards — the micrometeorites and solar flares space throws at
+//! the ship on the way.
+//!
+//! How much of either depends on where the voyage is ([`voyage_phase`]):
+//! for the first and last [`SYSTEM_HOURS`] the ship is crossing a star
+//! system, thick with dust and close to a star that flares, and both come
+//! several times as often ([`impact_scale`], [`flare_scale`]) as in the
+//! interstellar cruise between. A micrometeorite strikes a random stretch
+//! of hull facing outward ([`impact_site`]), tearing the plating and
+//! damaging what is mounted there; one past [`BREACH_SEVERITY`] punches
+//! through into the room behind it, and some tear into the radiators and
+//! antenna outside instead ([`hits_mount`]).
+
+use crate::numeric::roll;
+
+/// Hours either end of the voyage spent crossing a star system.
+pub const SYSTEM_HOURS: f64 = 720.0;
+
+/// Chance a micrometeorite strikes in any given hour of cruise (about one
+/// a fortnight).
+pub const IMPACT_CHANCE_PER_HOUR: f32 = 1.0 / 336.0;
+
+/// Impact severity past which the hull is holed and the room behind it
+/// breached.
+pub const BREACH_SEVERITY: f32 = 0.7;
+
+/// Subsystem health a severity-1.0 impact takes.
+pub const IMPACT_DAMAGE: f32 = 0.5;
+
+/// Share of impacts that hit the radiators or antenna mounted outside.
+const MOUNT_SHARE: f32 = 0.3;
+
+/// Where the voyage is, for how often hazards come.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoyagePhase {
+    /// Leaving the home system.
+    Departure,
+    /// Between the stars.
+    Cruise,
+    /// Crossing the destination system.
+    Arrival,
+}
+
+/// Phase of a voyage of `voyage_hours`, `elapsed` hours after launch.
+pub fn voyage_phase(elapsed: f64, voyage_hours: f64) -> VoyagePhase {
+    if elapsed < SYSTEM_HOURS {
+        VoyagePhase::Departure
+    } else if elapsed > voyage_hours - SYSTEM_HOURS {
+        VoyagePhase::Arrival
+    } else {
+        VoyagePhase::Cruise
+    }
+}
+
+/// How many times the cruise rate micrometeorites strike in `phase`.
+pub fn impact_scale(phase: VoyagePhase) -> f32 {
+    match phase {
+        VoyagePhase::Cruise => 1.0,
+        VoyagePhase::Departure | VoyagePhase::Arrival => 4.0,
+    }
+}
+
+/// How many times the cruise rate solar flares are sighted in `phase`.
+pub fn flare_scale(phase: VoyagePhase) -> f32 {
+    match phase {
+        VoyagePhase::Cruise => 1.0,
+        VoyagePhase::Departure | VoyagePhase::Arrival => 3.0,
+    }
+}
+
+/// Whether a micrometeorite strikes in the hour starting at `hour`.
+pub fn impact_struck(hour: u64, chance: f32) -> bool {
+    roll(0, hour as f64, 0x3e7e0) < chance
+}
+
+/// Severity (0.1–1.0) of the micrometeorite striking at `hour`: mostly
+/// grazes, now and then a hole.
+pub fn impact_severity(hour: u64) -> f32 {
+    let strike = roll(0, hour as f64, 0x5e7e1);
+    0.1 + strike * strike * 0.9
+}
+
+/// Index among `count` stretches of outward-facing hull of the one the
+/// micrometeorite at `hour` strikes.
+pub fn impact_site(hour: u64, count: usize) -> usize {
+    (roll(0, hour as f64, 0x517e2) * count as f32) as usize % count.max(1)
+}
+
+/// Whether the micrometeorite at `hour` hits the mounts outside the hull
+/// rather than the plating.
+pub fn hits_mount(hour: u64) -> bool {
+    roll(0, hour as f64, 0x3a573) < MOUNT_SHARE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_voyage_phase() {
+        let voyage = 100_000.0;
+        assert_eq!(voyage_phase(0.0, voyage), VoyagePhase::Departure);
+        assert_eq!(voyage_phase(50_000.0, voyage), VoyagePhase::Cruise);
+        assert_eq!(voyage_phase(99_900.0, voyage), VoyagePhase::Arrival);
+        assert!(impact_scale(VoyagePhase::Departure) > impact_scale(VoyagePhase::Cruise));
+        assert!(flare_scale(VoyagePhase::Arrival) > flare_scale(VoyagePhase::Cruise));
+    }
+
+    #[test]
+    fn test_impacts() {
+        let struck = (0..100_000u64)
+            .filter(|&h| impact_struck(h, IMPACT_CHANCE_PER_HOUR))
+            .count();
+        assert!((150..450).contains(&struck), "{struck} impacts");
+        for hour in 0..1000 {
+            assert!((0.1..=1.0).contains(&impact_severity(hour)));
+            assert!(impact_site(hour, 7) < 7);
+        }
+        assert_eq!(impact_site(5, 0), 0);
+        let mounts = (0..1000u64).filter(|&h| hits_mount(h)).count();
+        assert!((200..400).contains(&mounts), "{mounts} mount hits");
+    }
+}
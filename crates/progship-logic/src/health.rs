@@ -1,9 +1,10 @@
 //! Pure health, medical, and death logic.
 //!
 //! Injury severity tiers, sickbay healing rates, natural recovery,
-//! and death determination — all as pure functions.
+//! death determination and cause of death — all as pure functions.
 
-use crate::constants::room_types;
+use crate::constants::{death_causes, event_types, room_types};
+use crate::numeric::{finite_or, time_step, unit};
 
 /// Injury severity tiers based on health value.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -68,6 +69,11 @@ pub fn compute_health_recovery(
     medical_skill_nearby: f32,
     delta_hours: f32,
 ) -> f32 {
+    let health = unit(health, 1.0);
+    let hunger = unit(hunger, 1.0);
+    let fatigue = unit(fatigue, 1.0);
+    let medical_skill_nearby = finite_or(medical_skill_nearby, 0.0).max(0.0);
+    let delta_hours = time_step(delta_hours);
     if health >= 1.0 {
         return 1.0;
     }
@@ -85,12 +91,34 @@ pub fn compute_health_recovery(
         0.0
     };
 
-    (health + recovery).min(1.0)
+    unit(health + recovery, health)
 }
 
 /// Check if a person should be considered dead.
 pub fn is_dead(health: f32) -> bool {
-    health <= 0.0
+    finite_or(health, 1.0) <= 0.0
+}
+
+/// Most likely cause of a death (see `death_causes`), from the hazard event
+/// in the person's room, if any, their room's oxygen fraction and pressure
+/// (kPa), and their hunger and fatigue.
+pub fn death_cause(
+    room_event: Option<u8>,
+    oxygen: f32,
+    pressure: f32,
+    hunger: f32,
+    fatigue: f32,
+) -> u8 {
+    match room_event {
+        Some(event_types::FIRE) => death_causes::FIRE,
+        Some(event_types::HULL_BREACH) => death_causes::DECOMPRESSION,
+        Some(event_types::MEDICAL_EMERGENCY) => death_causes::ILLNESS,
+        _ if pressure < 80.0 => death_causes::DECOMPRESSION,
+        _ if oxygen < 0.16 => death_causes::ASPHYXIATION,
+        _ if hunger >= 1.0 => death_causes::STARVATION,
+        _ if fatigue >= 1.0 => death_causes::EXHAUSTION,
+        _ => death_causes::UNKNOWN,
+    }
 }
 
 /// Determine if an NPC should seek medical attention based on current health.
@@ -125,6 +153,26 @@ mod tests {
         assert_eq!(InjurySeverity::from_health(0.0), InjurySeverity::Critical);
     }
 
+    #[test]
+    fn test_death_cause() {
+        let cause = |event, o2, kpa, hunger| death_cause(event, o2, kpa, hunger, 0.2);
+        assert_eq!(
+            cause(Some(event_types::FIRE), 0.1, 101.0, 1.0),
+            death_causes::FIRE
+        );
+        assert_eq!(
+            cause(Some(event_types::CELEBRATION), 0.21, 40.0, 0.0),
+            death_causes::DECOMPRESSION
+        );
+        assert_eq!(cause(None, 0.1, 101.0, 1.0), death_causes::ASPHYXIATION);
+        assert_eq!(cause(None, 0.21, 101.0, 1.0), death_causes::STARVATION);
+        assert_eq!(cause(None, 0.21, 101.0, 0.0), death_causes::UNKNOWN);
+        assert_eq!(
+            death_cause(None, 0.21, 101.0, 0.5, 1.0),
+            death_causes::EXHAUSTION
+        );
+    }
+
     #[test]
     fn test_needs_medical() {
         assert!(!InjurySeverity::Healthy.needs_medical());
@@ -0,0 +1,149 @@
+//! History compaction — keeping long runs' tables bounded.
+//!
+//! Resolved events, ended conversations and closed work orders are kept
+//! for a retention window, then folded into one aggregate row per
+//! simulation day and removed. [`DayTally`] is that aggregate, built up with
+//! the `record_*` methods.
+
+/// Default days finished rows are kept before compaction.
+pub const DEFAULT_RETENTION_DAYS: f64 = 3.0;
+
+/// Hours between compaction passes.
+pub const COMPACTION_INTERVAL_HOURS: f64 = 6.0;
+
+/// Simulation day (0-based) that `sim_time` falls on.
+pub fn sim_day(sim_time: f64) -> u32 {
+    (sim_time.max(0.0) / 24.0) as u32
+}
+
+/// Whether a row that finished at `finished_at` has outlived the retention
+/// window at `sim_time`.
+pub fn past_retention(finished_at: f64, sim_time: f64, retention_days: f64) -> bool {
+    sim_time - finished_at > retention_days.max(0.0) * 24.0
+}
+
+/// Aggregate of one simulation day's compacted rows.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DayTally {
+    /// Events by event type (index = `event_types` ID).
+    pub events: Vec<u32>,
+    /// Events that escalated to an officer.
+    pub escalated_events: u32,
+    /// Conversations by topic (index = `conversation_topics` ID).
+    pub conversations: Vec<u32>,
+    /// Repair work orders done.
+    pub repairs: u32,
+    /// Planned hours of those repairs.
+    pub repair_hours: f32,
+}
+
+fn bump(counts: &mut Vec<u32>, index: u8) {
+    let index = index as usize;
+    if counts.len() <= index {
+        counts.resize(index + 1, 0);
+    }
+    counts[index] += 1;
+}
+
+impl DayTally {
+    /// Count a resolved event.
+    pub fn record_event(&mut self, event_type: u8, escalated: bool) {
+        bump(&mut self.events, event_type);
+        self.escalated_events += u32::from(escalated);
+    }
+
+    /// Count an ended conversation.
+    pub fn record_conversation(&mut self, topic: u8) {
+        bump(&mut self.conversations, topic);
+    }
+
+    /// Count a repair work order done.
+    pub fn record_repair(&mut self, duration_hours: f32) {
+        self.repairs += 1;
+        self.repair_hours += duration_hours;
+    }
+
+    /// Add another tally for the same day.
+    pub fn merge(&mut self, other: &DayTally) {
+        for (counts, extra) in [
+            (&mut self.events, &other.events),
+            (&mut self.conversations, &other.conversations),
+        ] {
+            if counts.len() < extra.len() {
+                counts.resize(extra.len(), 0);
+            }
+            for (n, m) in counts.iter_mut().zip(extra) {
+                *n += m;
+            }
+        }
+        self.escalated_events += other.escalated_events;
+        self.repairs += other.repairs;
+        self.repair_hours += other.repair_hours;
+    }
+
+    /// Whether nothing has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.events.iter().all(|&n| n == 0)
+            && self.conversations.iter().all(|&n| n == 0)
+            && self.repairs == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{conversation_topics, event_types};
+
+    #[test]
+    fn test_sim_day() {
+        assert_eq!(sim_day(0.0), 0);
+        assert_eq!(sim_day(23.9), 0);
+        assert_eq!(sim_day(24.0), 1);
+        assert_eq!(sim_day(-5.0), 0);
+    }
+
+    #[test]
+    fn test_past_retention() {
+        assert!(!past_retention(10.0, 10.0 + 72.0, 3.0));
+        assert!(past_retention(10.0, 10.0 + 72.5, 3.0));
+        // Zero retention compacts anything finished
+        assert!(past_retention(10.0, 10.1, 0.0));
+    }
+
+    #[test]
+    fn test_day_tally_counts_by_kind() {
+        let mut tally = DayTally::default();
+        assert!(tally.is_empty());
+        tally.record_event(event_types::FIRE, false);
+        tally.record_event(event_types::FIRE, true);
+        tally.record_conversation(conversation_topics::GOSSIP);
+        tally.record_repair(2.5);
+        tally.record_repair(1.5);
+        assert_eq!(tally.events[event_types::FIRE as usize], 2);
+        assert_eq!(tally.escalated_events, 1);
+        assert_eq!(
+            tally.conversations.len(),
+            conversation_topics::GOSSIP as usize + 1
+        );
+        assert_eq!(tally.repairs, 2);
+        assert_eq!(tally.repair_hours, 4.0);
+        assert!(!tally.is_empty());
+    }
+
+    #[test]
+    fn test_day_tally_merge() {
+        let mut earlier = DayTally::default();
+        earlier.record_event(event_types::DEATH, false);
+        earlier.record_repair(1.0);
+        let mut later = DayTally::default();
+        later.record_event(event_types::FIRE, true);
+        later.record_event(event_types::DEATH, false);
+        later.record_conversation(conversation_topics::WORK);
+        earlier.merge(&later);
+        assert_eq!(earlier.events[event_types::DEATH as usize], 2);
+        assert_eq!(earlier.events[event_types::FIRE as usize], 1);
+        assert_eq!(earlier.escalated_events, 1);
+        assert_eq!(earlier.conversations[conversation_topics::WORK as usize], 1);
+        assert_eq!(earlier.repairs, 1);
+    }
+}
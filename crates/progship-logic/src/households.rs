@@ -0,0 +1,167 @@
+//! Passenger households — couples, children and grandparents traveling
+//! together.
+//!
+//! [`plan_households`] splits a passenger count into family units with a
+//! fixed, deterministic mix of singles, couples, young families and
+//! three-generation households. [`kinship`] lists the relationships a
+//! household starts the voyage with, so social simulation does not begin
+//! from a ship full of strangers.
+
+use serde::{Deserialize, Serialize};
+
+use crate::constants::{family_roles as fr, relationship_types as rel};
+
+/// Household compositions, cycled in order. Member 0 is always the head.
+const COMPOSITIONS: &[&[u8]] = &[
+    &[fr::HEAD],
+    &[fr::HEAD, fr::PARTNER],
+    &[fr::HEAD, fr::PARTNER, fr::CHILD],
+    &[fr::HEAD, fr::PARTNER, fr::CHILD, fr::CHILD],
+    &[fr::HEAD],
+    &[fr::HEAD, fr::CHILD],
+    &[fr::HEAD, fr::PARTNER, fr::CHILD, fr::GRANDPARENT],
+    &[fr::HEAD, fr::PARTNER],
+    &[fr::HEAD, fr::PARTNER, fr::CHILD, fr::CHILD, fr::CHILD],
+    &[
+        fr::HEAD,
+        fr::PARTNER,
+        fr::CHILD,
+        fr::GRANDPARENT,
+        fr::GRANDPARENT,
+    ],
+];
+
+/// One household: the role of each member (see `family_roles`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Household {
+    pub roles: Vec<u8>,
+}
+
+impl Household {
+    /// Number of members.
+    pub fn size(&self) -> usize {
+        self.roles.len()
+    }
+}
+
+/// A pre-seeded relationship between two members of one household,
+/// identified by their index in [`Household::roles`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Kinship {
+    pub a: usize,
+    pub b: usize,
+    /// Relationship type (see `relationship_types`).
+    pub relationship_type: u8,
+    /// Starting strength (-1.0 hostile – 1.0 close).
+    pub strength: f32,
+    /// Starting familiarity (0.0 strangers – 1.0 well-known).
+    pub familiarity: f32,
+}
+
+/// Split `count` passengers into households. Members always add up to
+/// `count`; the last household is cut short if the passengers run out.
+pub fn plan_households(count: u32) -> Vec<Household> {
+    let mut remaining = count as usize;
+    let mut households = Vec::new();
+    for composition in COMPOSITIONS.iter().cycle() {
+        if remaining == 0 {
+            break;
+        }
+        let take = composition.len().min(remaining);
+        households.push(Household {
+            roles: composition[..take].to_vec(),
+        });
+        remaining -= take;
+    }
+    households
+}
+
+/// Relationship type, strength and familiarity between two household
+/// roles, or `None` for unrelated pairs. The grandparents are the head's
+/// parents.
+pub fn tie(a: u8, b: u8) -> Option<(u8, f32, f32)> {
+    let (a, b) = (a.min(b), a.max(b));
+    let tie = match (a, b) {
+        (fr::HEAD, fr::PARTNER) => (rel::ROMANTIC, 0.8, 1.0),
+        (fr::HEAD | fr::PARTNER, fr::CHILD) => (rel::FAMILY, 0.9, 1.0),
+        (fr::CHILD, fr::CHILD) => (rel::FAMILY, 0.6, 1.0),
+        (fr::HEAD, fr::GRANDPARENT) => (rel::FAMILY, 0.8, 1.0),
+        (fr::PARTNER, fr::GRANDPARENT) => (rel::FAMILY, 0.5, 0.8),
+        (fr::CHILD, fr::GRANDPARENT) => (rel::FAMILY, 0.8, 0.9),
+        (fr::GRANDPARENT, fr::GRANDPARENT) => (rel::ROMANTIC, 0.7, 1.0),
+        _ => return None,
+    };
+    Some(tie)
+}
+
+/// Every relationship within a household, one per related pair.
+pub fn kinship(household: &Household) -> Vec<Kinship> {
+    let roles = &household.roles;
+    let mut ties = Vec::new();
+    for a in 0..roles.len() {
+        for b in a + 1..roles.len() {
+            if let Some((relationship_type, strength, familiarity)) = tie(roles[a], roles[b]) {
+                ties.push(Kinship {
+                    a,
+                    b,
+                    relationship_type,
+                    strength,
+                    familiarity,
+                });
+            }
+        }
+    }
+    ties
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_households_cover_every_passenger() {
+        for count in [0, 1, 7, 100, 4001] {
+            let households = plan_households(count);
+            let total: usize = households.iter().map(Household::size).sum();
+            assert_eq!(total, count as usize);
+            assert!(households.iter().all(|h| h.roles[0] == fr::HEAD));
+        }
+    }
+
+    #[test]
+    fn test_household_mix() {
+        let households = plan_households(1000);
+        let singles = households.iter().filter(|h| h.size() == 1).count();
+        let with_children = households
+            .iter()
+            .filter(|h| h.roles.contains(&fr::CHILD))
+            .count();
+        let three_gen = households
+            .iter()
+            .filter(|h| h.roles.contains(&fr::GRANDPARENT))
+            .count();
+        assert!(singles > 0 && with_children > singles && three_gen > 0);
+    }
+
+    #[test]
+    fn test_kinship_for_three_generations() {
+        let household = Household {
+            roles: vec![fr::HEAD, fr::PARTNER, fr::CHILD, fr::CHILD, fr::GRANDPARENT],
+        };
+        let ties = kinship(&household);
+        // Every pair in this household is related
+        assert_eq!(ties.len(), 10);
+        let couple = ties.iter().find(|t| (t.a, t.b) == (0, 1)).unwrap();
+        assert_eq!(couple.relationship_type, rel::ROMANTIC);
+        let siblings = ties.iter().find(|t| (t.a, t.b) == (2, 3)).unwrap();
+        assert_eq!(siblings.relationship_type, rel::FAMILY);
+        assert!(ties.iter().all(|t| t.a < t.b && t.strength > 0.0));
+        assert_eq!(tie(fr::CHILD, fr::HEAD), tie(fr::HEAD, fr::CHILD));
+        assert_eq!(tie(fr::PARTNER, fr::PARTNER), None);
+    }
+
+    #[test]
+    fn test_single_has_no_kin() {
+        assert!(kinship(&plan_households(1)[0]).is_empty());
+    }
+}
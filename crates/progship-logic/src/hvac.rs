@@ -0,0 +1,146 @@
+//! HVAC — the air handlers in HVAC Control and the ducts out to every room.
+//!
+//! The handlers draw each room's air back through its duct, mix it
+//! ([`return_air`]) and blow it out again freshened by life support and
+//! chilled by the cooling plant, so air and heat move between the rooms
+//! they serve. What reaches a room is what the handlers put out through
+//! what its duct lets by ([`airflow`]): ducts foul with the dust and lint
+//! they carry ([`fouled`]) and are due cleaning past [`CLEANING_FOULING`],
+//! a fouled duct strains its fans and fails now and then
+//! ([`failure_chance`]), and one worn below [`FAILED_HEALTH`] carries
+//! nothing. A room cut off keeps its own stale air and the heat of those in
+//! it.
+
+use crate::atmosphere::RoomAtmosphere;
+
+/// Fouling a duct carrying full airflow gathers in an hour: clogged in
+/// about twelve weeks.
+pub const FOULING_PER_HOUR: f32 = 0.0005;
+
+/// Fouling at which a duct is due cleaning.
+pub const CLEANING_FOULING: f32 = 0.5;
+
+/// Duct health below which it carries no air at all.
+pub const FAILED_HEALTH: f32 = 0.2;
+
+/// Chance an hour a duct fouled solid fails.
+pub const FAILURE_RATE: f32 = 0.002;
+
+/// Health a duct loses when it fails.
+pub const FAILURE_DAMAGE: f32 = 0.5;
+
+/// How freely air mixes with the handlers' along a duct at full airflow,
+/// as a share of an open door's.
+pub const DUCT_CONDUCTANCE: f32 = 0.25;
+
+/// Priority of cleaning a duct on the board.
+pub const CLEANING_PRIORITY: f32 = 0.3;
+
+/// Hours cleaning a duct takes.
+pub const CLEANING_HOURS: f32 = 1.0;
+
+/// Share (0–1) of full airflow reaching a room from handlers putting out
+/// `handlers` (0–1) through a duct in `health` with `fouling`.
+pub fn airflow(handlers: f32, health: f32, fouling: f32) -> f32 {
+    if health < FAILED_HEALTH {
+        return 0.0;
+    }
+    handlers.clamp(0.0, 1.0) * health.clamp(0.0, 1.0) * (1.0 - fouling.clamp(0.0, 1.0))
+}
+
+/// Fouling of a duct after `hours` carrying `airflow`.
+pub fn fouled(fouling: f32, airflow: f32, hours: f32) -> f32 {
+    (fouling + FOULING_PER_HOUR * airflow.clamp(0.0, 1.0) * hours.max(0.0)).clamp(0.0, 1.0)
+}
+
+/// Whether a duct with `fouling` is due cleaning.
+pub fn needs_cleaning(fouling: f32) -> bool {
+    fouling >= CLEANING_FOULING
+}
+
+/// Chance a duct with `fouling` fails in `hours`.
+pub fn failure_chance(fouling: f32, hours: f32) -> f32 {
+    (FAILURE_RATE * fouling.clamp(0.0, 1.0).powi(2) * hours.max(0.0)).min(1.0)
+}
+
+/// Health of a duct in `health` after it fails.
+pub fn failed(health: f32) -> f32 {
+    (health - FAILURE_DAMAGE).max(0.0)
+}
+
+/// The handlers' mixed air from `rooms`, each room's air with the airflow
+/// it returns; `None` with no air coming back.
+pub fn return_air(rooms: &[(RoomAtmosphere, f32)]) -> Option<RoomAtmosphere> {
+    let total: f32 = rooms.iter().map(|(_, flow)| flow.max(0.0)).sum();
+    if total <= 0.0 || !total.is_finite() {
+        return None;
+    }
+    let mean = |f: fn(&RoomAtmosphere) -> f32| {
+        rooms
+            .iter()
+            .map(|(air, flow)| f(&air.sanitized()) * flow.max(0.0))
+            .sum::<f32>()
+            / total
+    };
+    Some(RoomAtmosphere {
+        o2: mean(|a| a.o2),
+        co2: mean(|a| a.co2),
+        temperature: mean(|a| a.temperature),
+        pressure: mean(|a| a.pressure),
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_airflow() {
+        assert_eq!(airflow(1.0, 1.0, 0.0), 1.0);
+        assert_eq!(airflow(0.0, 1.0, 0.0), 0.0);
+        assert_eq!(airflow(1.0, 1.0, 0.5), 0.5);
+        assert_eq!(airflow(0.5, 0.5, 0.0), 0.25);
+        assert_eq!(airflow(1.0, FAILED_HEALTH - 0.01, 0.0), 0.0);
+        assert_eq!(airflow(1.0, 1.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_fouling_and_failures() {
+        assert_eq!(fouled(0.0, 0.0, 100.0), 0.0);
+        assert!(fouled(0.0, 1.0, 100.0) > fouled(0.0, 0.5, 100.0));
+        assert_eq!(fouled(0.99, 1.0, 100.0), 1.0);
+        assert!(!needs_cleaning(fouled(0.0, 1.0, 24.0 * 7.0 * 4.0)));
+        assert!(needs_cleaning(fouled(0.0, 1.0, 24.0 * 7.0 * 12.0)));
+        assert_eq!(failure_chance(0.0, 1.0), 0.0);
+        assert!(failure_chance(1.0, 1.0) > failure_chance(0.5, 1.0));
+        assert_eq!(failed(1.0), 0.5);
+        assert_eq!(failed(0.3), 0.0);
+    }
+
+    #[test]
+    fn test_return_air() {
+        assert!(return_air(&[]).is_none());
+        let hot = RoomAtmosphere {
+            temperature: 30.0,
+            co2: 0.02,
+            ..Default::default()
+        };
+        let cool = RoomAtmosphere {
+            temperature: 20.0,
+            co2: 0.0,
+            ..Default::default()
+        };
+        assert!(return_air(&[(hot, 0.0)]).is_none());
+        let mixed = return_air(&[(hot, 1.0), (cool, 1.0)]).unwrap();
+        assert_eq!(mixed.temperature, 25.0);
+        assert_eq!(mixed.co2, 0.01);
+        let mixed = return_air(&[(hot, 1.0), (cool, 3.0)]).unwrap();
+        assert_eq!(mixed.temperature, 22.5);
+        // A cut-off room sends nothing back
+        assert_eq!(
+            return_air(&[(hot, 0.0), (cool, 1.0)]).unwrap().temperature,
+            20.0
+        );
+    }
+}
@@ -0,0 +1,144 @@
+//! Inventory — the few things people carry and how they change hands.
+//!
+//! Everyone boards with a keepsake, crew with a ration and engineers with
+//! a toolkit; a few smuggle contraband aboard ([`starting_items`]). Repairs
+//! needing engineering skill go at [`NO_TOOLKIT_SPEED`] without a toolkit
+//! ([`tool_speed`]). Crew eating at a stocked galley pack a ration for
+//! later, and eat it ([`RATION_HUNGER`]) when their galley runs dry. What
+//! people carry can be stolen ([`steal_pick`]), given to a hungry friend
+//! ([`shares_ration`]) or lost when a fire or breach gets out of hand
+//! ([`LOSS_CHANCE`]); losing a keepsake hurts ([`KEEPSAKE_MORALE`]).
+//! Security returns stolen goods and confiscates contraband
+//! ([`is_confiscated`]) from those it detains.
+
+use crate::constants::{departments, item_kinds};
+use crate::skills::SkillCategory;
+
+/// Most items anyone carries.
+pub const MAX_ITEMS: usize = 6;
+
+/// Repair speed without a toolkit for work that needs one.
+pub const NO_TOOLKIT_SPEED: f32 = 0.5;
+
+/// Hunger a ration eases.
+pub const RATION_HUNGER: f32 = 0.4;
+
+/// Chance each item someone carries is lost when a fire or breach in
+/// their room escalates.
+pub const LOSS_CHANCE: f32 = 0.5;
+
+/// Morale losing a keepsake costs.
+pub const KEEPSAKE_MORALE: f32 = 0.05;
+
+/// Relationship strength a gift adds.
+pub const GIFT_BOND: f32 = 0.05;
+
+/// Share of people who smuggle contraband aboard.
+const CONTRABAND_SHARE: f32 = 0.05;
+
+/// Salt of the [`roll`](crate::numeric::roll) for losing an item.
+pub const LOSS_SALT: u64 = 0x1055;
+
+/// What someone boards with (see `item_kinds`), given their department
+/// (`None` for passengers) and a `roll` in 0..1.
+pub fn starting_items(department: Option<u8>, roll: f32) -> Vec<u8> {
+    let mut items = vec![item_kinds::KEEPSAKE];
+    if department.is_some() {
+        items.push(item_kinds::RATION);
+    }
+    if department == Some(departments::ENGINEERING) {
+        items.push(item_kinds::TOOLKIT);
+    }
+    if roll < CONTRABAND_SHARE {
+        items.push(item_kinds::CONTRABAND);
+    }
+    items
+}
+
+/// Repair speed for work needing `skill`: engineering work goes at
+/// [`NO_TOOLKIT_SPEED`] without a toolkit.
+pub fn tool_speed(skill: SkillCategory, has_toolkit: bool) -> f32 {
+    if skill == SkillCategory::Engineering && !has_toolkit {
+        NO_TOOLKIT_SPEED
+    } else {
+        1.0
+    }
+}
+
+/// Worth of an item of `kind` to a thief.
+pub fn item_value(kind: u8) -> u32 {
+    match kind {
+        item_kinds::CONTRABAND => 3,
+        item_kinds::TOOLKIT => 2,
+        item_kinds::RATION => 1,
+        _ => 0,
+    }
+}
+
+/// Index into `kinds` of what a thief takes: the most valuable, the first
+/// of equals. `None` when there is nothing to take.
+pub fn steal_pick(kinds: &[u8]) -> Option<usize> {
+    kinds
+        .iter()
+        .enumerate()
+        .max_by(|a, b| item_value(*a.1).cmp(&item_value(*b.1)).then(b.0.cmp(&a.0)))
+        .map(|(i, _)| i)
+}
+
+/// Whether someone at `giver_hunger` gives a ration to a friend at
+/// `receiver_hunger` they have a tie of `strength` with: friends who are
+/// not hungry themselves share with the hungry.
+pub fn shares_ration(giver_hunger: f32, receiver_hunger: f32, strength: f32) -> bool {
+    strength > 0.3 && giver_hunger < 0.4 && receiver_hunger > 0.6
+}
+
+/// Whether security takes an item of `kind` away for good.
+pub fn is_confiscated(kind: u8) -> bool {
+    kind == item_kinds::CONTRABAND
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_starting_items() {
+        let engineer = starting_items(Some(departments::ENGINEERING), 0.5);
+        assert!(engineer.contains(&item_kinds::TOOLKIT));
+        assert!(engineer.contains(&item_kinds::RATION));
+        let passenger = starting_items(None, 0.5);
+        assert_eq!(passenger, vec![item_kinds::KEEPSAKE]);
+        assert!(starting_items(None, 0.01).contains(&item_kinds::CONTRABAND));
+        assert!(starting_items(Some(departments::ENGINEERING), 0.0).len() <= MAX_ITEMS);
+    }
+
+    #[test]
+    fn test_tool_speed() {
+        assert_eq!(tool_speed(SkillCategory::Engineering, true), 1.0);
+        assert_eq!(
+            tool_speed(SkillCategory::Engineering, false),
+            NO_TOOLKIT_SPEED
+        );
+        assert_eq!(tool_speed(SkillCategory::Medical, false), 1.0);
+    }
+
+    #[test]
+    fn test_changing_hands() {
+        let kinds = [
+            item_kinds::KEEPSAKE,
+            item_kinds::TOOLKIT,
+            item_kinds::RATION,
+        ];
+        assert_eq!(steal_pick(&kinds), Some(1));
+        assert_eq!(
+            steal_pick(&[item_kinds::RATION, item_kinds::RATION]),
+            Some(0)
+        );
+        assert_eq!(steal_pick(&[]), None);
+        assert!(shares_ration(0.2, 0.8, 0.5));
+        assert!(!shares_ration(0.2, 0.8, 0.1));
+        assert!(!shares_ration(0.7, 0.8, 0.9));
+        assert!(is_confiscated(item_kinds::CONTRABAND));
+        assert!(!is_confiscated(item_kinds::KEEPSAKE));
+    }
+}
@@ -10,50 +10,192 @@
 //! | Module | Purpose |
 //! |--------|---------|
 //! | [`actions`] | Room-type–validated player actions and needs effects |
+//! | [`advisor`] | The ship's computer: store trends, days left and advisories on stores, systems, power and crowding |
+//! | [`aging`] | Birthdays, slower healing with age and death of old age |
+//! | [`arboretum`] | Arboretum plantings on water, light and CO2, their gardeners, the air they freshen and their visitors |
 //! | [`archetypes`] | Personality-derived behavioral archetypes (7 types) |
 //! | [`atmosphere`] | Per-room O2/CO2/temperature/pressure simulation |
+//! | [`backstory`] | Backstory tags and quirks rolled per person, and their utility nudges |
+//! | [`balance`] | Need decay multipliers by age, personality and fitness |
+//! | [`bar`] | Bar hours, drinks, intoxication, dependence and drunken scenes |
+//! | [`cargo`] | Cargo lots from the supply manifest, stowage and draws |
+//! | [`careers`] | Crew experience, certification and promotion into open ranks |
+//! | [`command`] | Chain of command and escalation to the responsible officer |
 //! | [`config`] | System selection algorithm (weighted scoring) |
 //! | [`constants`] | Room types, activity types, groups, shifts (u8 IDs) |
 //! | [`conversation`] | Conversation memory, topic avoidance, gossip propagation |
+//! | [`crime`] | Theft, vandalism and assault by the unhappy, and detention in the brig |
+//! | [`cryo`] | Passengers asleep in the cryo bays, their pods, checks and revival |
 //! | [`cylinder`] | O'Neill cylinder ship geometry, sectors, ring corridors |
+//! | [`demographics`] | Passenger age curve, birth dates and life stages |
+//! | [`doors`] | Door states (open, closed, locked, welded) and who may change them |
 //! | [`duty`] | Shift scheduling, duty fitness, sleep windows |
-//! | [`economy`] | Resource scarcity, rationing, production rates |
+//! | [`economy`] | Resource scarcity, rationing, production rates, wages and prices |
+//! | [`education`] | School hours, teachers, lessons and the professions school leavers take up |
+//! | [`emotions`] | Moods derived from needs, grief and good news |
+//! | [`encyclopedia`] | In-game encyclopedia of rooms, systems and mechanics, built from spec data |
+//! | [`eva`] | Suited repairs outside the hull: hatches, suit oxygen and accidents |
+//! | [`evacuation`] | Emergency hazard areas and muster stations for evacuation routes |
+//! | [`fabrication`] | Spare parts by kind and the workshops that fabricate them |
+//! | [`factions`] | Faction loyalty swayed by events and conversations, and the tension it breeds |
+//! | [`features`] | Per-ship feature flags for optional simulation systems |
+//! | [`final_report`] | End-of-voyage report: population, casualties, incidents, colony readiness |
+//! | [`food`] | Crop growth cycles, galley cooking and meal service from per-galley buffers |
+//! | [`frame`] | Compact binary world frames and deltas for external renderers |
+//! | [`funerals`] | Morgue, chapel funerals, mourners and grief that lifts over weeks |
+//! | [`genlib`] | Graph-first ship generation (facilities, hull, ring-and-spur layout) |
 //! | [`geometry`] | Ship layout validation (room bounds, doors, connectivity) |
+//! | [`governance`] | Council elections and the rationing, watch and curfew policies it sets |
+//! | [`hazards`] | Micrometeorite impacts and how often hazards come by voyage phase |
 //! | [`health`] | Injury severity, medical recovery, death determination |
+//! | [`history`] | Compaction of finished events, conversations and tasks into daily history |
+//! | [`households`] | Passenger family units and their pre-seeded relationships |
+//! | [`hvac`] | Air handlers and ducts: airflow to each room, fouling, failures and cleaning |
+//! | [`inventory`] | Belongings people carry: toolkits, keepsakes, rations, contraband |
+//! | [`lifecycle`] | Conception, pregnancy, delivery rooms and inherited traits |
+//! | [`lighting`] | Circadian deck lighting, shift-offset schedules, sleeping quarters |
 //! | [`lod`] | Level-of-detail tiers for 5,000+ agent simulation scale-up |
 //! | [`manifest`] | Dynamic facility manifest from systems + population |
+//! | [`mental_health`] | Stress building over days, counseling sessions and breakdowns |
+//! | [`milestones`] | Once-per-voyage achievements and the end-of-voyage report |
 //! | [`mission`] | Mission config, destinations, propulsion, voyage profile |
+//! | [`morale`] | Department and ship-wide morale, moods and what they set off |
 //! | [`movement`] | Room-bounded movement, door traversal, wall-sliding |
-//! | [`pathfinding`] | BFS pathfinding over door connectivity graph |
+//! | [`mutiny`] | Departments in despair refusing duty, their demands and how it ends |
+//! | [`names`] | Culture-weighted name pools dealt out by population mix |
+//! | [`navigation`] | Burns along the voyage plan: distance covered, velocity, delta-v and ETA |
+//! | [`nicknames`] | Room and deck nicknames shown in place of generated names, and who may set them |
+//! | [`numeric`] | NaN/infinity guards with a diagnostics counter, and hash rolls |
+//! | [`objectives`] | Scripted scenario objectives and player progress through them |
+//! | [`orders`] | Bulk orders from command roles and who complies with them |
+//! | [`outfit`] | Physical plant built for the selected systems, sized to the population |
+//! | [`pathfinding`] | Weighted pathfinding over door connectivity graph |
+//! | [`plumbing`] | Water distribution to wet rooms, leaking and freezing pipes, dry rooms |
 //! | [`population`] | Crew sizing, department allocation, genetic diversity |
+//! | [`power_grid`] | Power load flow over the cable graph, priority load shedding |
+//! | [`quarantine`] | Contagious outbreaks, spread between room-mates and isolation until recovered |
+//! | [`radiation`] | Cosmic rays, solar flares, deck shielding and radiation doses |
+//! | [`relationships`] | Ties fading without contact, how many close ones people keep, whom they seek out |
+//! | [`robots`] | Maintenance robots: the orders they take, their batteries, wear and breakdowns |
+//! | [`room_effects`] | Smoke, coolant spills and frost: build-up, seepage, cleanup and slips |
+//! | [`scenarios`] | Curated scenario gallery, scenario scripts, difficulty and the daily ship |
 //! | [`security`] | Access control, lockdown, patrol routing |
+//! | [`sensors`] | Sensor detection and the warning it gives of flares and micrometeorites |
 //! | [`ship_config`] | Player-facing ship configuration builder and validation |
+//! | [`shuttles`] | Shuttles per bay, launch fuel checks, burn and refueling |
 //! | [`skills`] | Skill checks, experience gain, training, and decay |
+//! | [`sleep`] | Sleep quality from crowding, noisy neighbors, temperature and schedule |
+//! | [`snapshot`] | JSON state snapshots exported by the server for offline inspection |
+//! | [`stream`] | Stream chat hooks: viewer votes, newborn names, spotlights, cooldowns |
 //! | [`supplies`] | Voyage supply manifest and mass budget validation |
+//! | [`surgery`] | Operations on the critically injured, surgeons, theatre condition and outcomes |
 //! | [`systems`] | System variant definitions (power, life support, etc.) |
+//! | [`thermal`] | Heat from reactors, engines, people and galleys, the coolant loop and radiators |
+//! | [`timeline`] | Per-person ring of recent activities, rooms and conversations |
+//! | [`triage`] | Ward beds by triage level, medics per patient and treatment times |
 //! | [`utility`] | Personality-driven utility AI for activity selection |
+//! | [`warm_start`] | Fast-forwarding a new ship so it starts with history |
+//! | [`watchdog`] | Hourly invariant checks and diagnostic severity |
+//! | [`work_orders`] | Job board priority, claiming and stalled claims |
+//!
+//! # Example Gallery
+//!
+//! Small programs in `examples/` drive the modules through a longer story
+//! and check the outcome; `cargo test -p progship-logic` runs those checks
+//! too.
+//!
+//! ```text
+//! cargo run -p progship-logic --example duty_week      # a week of watches
+//! cargo run -p progship-logic --example economy_shock  # rationing through a crop blight
+//! cargo run -p progship-logic --example gossip_spread  # a rumor among 100 crew
+//! ```
 
 pub mod actions;
+pub mod advisor;
+pub mod aging;
+pub mod arboretum;
 pub mod archetypes;
 pub mod atmosphere;
+pub mod backstory;
+pub mod balance;
+pub mod bar;
+pub mod careers;
+pub mod cargo;
+pub mod command;
 pub mod config;
 pub mod constants;
 pub mod conversation;
+pub mod crime;
+pub mod cryo;
 pub mod cylinder;
+pub mod demographics;
+pub mod doors;
 pub mod duty;
 pub mod economy;
+pub mod education;
+pub mod emotions;
+pub mod encyclopedia;
+pub mod eva;
+pub mod evacuation;
+pub mod fabrication;
+pub mod factions;
+pub mod features;
+pub mod final_report;
+pub mod food;
+pub mod frame;
+pub mod funerals;
+pub mod genlib;
 pub mod geometry;
+pub mod governance;
+pub mod hazards;
 pub mod health;
+pub mod history;
+pub mod households;
+pub mod hvac;
+pub mod inventory;
+pub mod lifecycle;
+pub mod lighting;
 pub mod lod;
 pub mod manifest;
+pub mod mental_health;
+pub mod milestones;
 pub mod mission;
+pub mod morale;
 pub mod movement;
+pub mod mutiny;
+pub mod names;
+pub mod navigation;
+pub mod nicknames;
+pub mod numeric;
+pub mod objectives;
+pub mod orders;
+pub mod outfit;
 pub mod pathfinding;
+pub mod plumbing;
 pub mod population;
+pub mod power_grid;
+pub mod quarantine;
+pub mod radiation;
+pub mod relationships;
+pub mod robots;
+pub mod room_effects;
+pub mod scenarios;
 pub mod security;
+pub mod sensors;
 pub mod service_decks;
 pub mod ship_config;
+pub mod shuttles;
 pub mod skills;
+pub mod sleep;
+pub mod snapshot;
+pub mod stream;
 pub mod supplies;
+pub mod surgery;
 pub mod systems;
+pub mod thermal;
+pub mod timeline;
+pub mod triage;
 pub mod utility;
+pub mod warm_start;
+pub mod watchdog;
+pub mod work_orders;
@@ -0,0 +1,129 @@
+//! Lifecycle — couples having children, pregnancy and inheritance.
+//!
+//! The head of a household and their partner may start a family of their
+//! own: once a day a couple who [`can_conceive`] rolls [`conceives`], more
+//! often the happier they are. A pregnancy lasts [`PREGNANCY_HOURS`] and
+//! ends with a delivery in the first of [`DELIVERY_ROOMS`] the ship has.
+//! The newborn joins the household as a child, takes their personality
+//! after both parents ([`inherit_trait`]) and a given name from the
+//! family's naming culture ([`newborn_name`]); aging then carries them
+//! through childhood into adulthood.
+
+use crate::constants::room_types;
+use crate::demographics::HOURS_PER_YEAR;
+use crate::names::{culture_of, NAME_POOLS};
+use crate::numeric::roll;
+
+/// How long a pregnancy lasts (hours): nine months.
+pub const PREGNANCY_HOURS: f64 = HOURS_PER_YEAR * 0.75;
+
+/// Youngest age at which someone has children.
+pub const MIN_PARENT_AGE: u8 = 18;
+
+/// Oldest age at which someone carries a child.
+pub const MAX_CARRIER_AGE: u8 = 45;
+
+/// Most children a household raises at once.
+pub const MAX_CHILDREN: usize = 4;
+
+/// Youngest a household's last child may be before another is conceived.
+pub const BIRTH_SPACING_YEARS: u8 = 2;
+
+/// Rooms a baby is delivered in, best first.
+pub const DELIVERY_ROOMS: [u8; 3] = [
+    room_types::HOSPITAL_WARD,
+    room_types::MEDBAY,
+    room_types::NURSERY,
+];
+
+/// Yearly chance of conceiving for a couple in full spirits.
+const YEARLY_CONCEPTION: f32 = 0.3;
+
+/// Furthest a child's trait strays from their parents' average.
+const INHERITANCE_SPREAD: f32 = 0.15;
+
+/// Whether a couple aged `ages` with `children` living children, the
+/// youngest `youngest` years old, may conceive: both adults, one young
+/// enough to carry, room in the household and the last child past
+/// [`BIRTH_SPACING_YEARS`].
+pub fn can_conceive(ages: (u8, u8), children: usize, youngest: Option<u8>) -> bool {
+    ages.0.min(ages.1) >= MIN_PARENT_AGE
+        && ages.0.min(ages.1) <= MAX_CARRIER_AGE
+        && children < MAX_CHILDREN
+        && youngest.is_none_or(|age| age >= BIRTH_SPACING_YEARS)
+}
+
+/// Whether the couple of `family_id` conceives on sim day `day`, with
+/// `morale` (0.0–1.0) their average spirits.
+pub fn conceives(family_id: u64, day: u64, morale: f32) -> bool {
+    let yearly = YEARLY_CONCEPTION * morale.clamp(0.0, 1.0);
+    let daily = 1.0 - (1.0 - yearly).powf(1.0 / 365.0);
+    roll(family_id, day as f64 * 24.0, 0xc0c) < daily
+}
+
+/// A child's personality trait from their parents' `a` and `b`: the
+/// average, strayed by up to [`INHERITANCE_SPREAD`] either way as `u`
+/// (0.0–1.0) goes from low to high.
+pub fn inherit_trait(a: f32, b: f32, u: f32) -> f32 {
+    ((a + b) / 2.0 + (u.clamp(0.0, 1.0) * 2.0 - 1.0) * INHERITANCE_SPREAD).clamp(0.0, 1.0)
+}
+
+/// A given name for a baby of the `family_name` household from the
+/// family's naming culture (the first pool if the surname is unknown),
+/// picked by `seed`.
+pub fn newborn_name(family_name: &str, seed: u64) -> &'static str {
+    let pool = &NAME_POOLS[culture_of(family_name).unwrap_or(0)];
+    pool.given[(seed % pool.given.len() as u64) as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_can_conceive() {
+        assert!(can_conceive((30, 28), 0, None));
+        assert!(can_conceive((50, 40), 2, Some(BIRTH_SPACING_YEARS)));
+        assert!(!can_conceive((17, 30), 0, None));
+        assert!(!can_conceive((50, 46), 0, None));
+        assert!(!can_conceive((30, 28), MAX_CHILDREN, Some(10)));
+        assert!(!can_conceive((30, 28), 1, Some(0)));
+    }
+
+    #[test]
+    fn test_conception_follows_morale() {
+        let couples = |morale| {
+            (0..1000u64)
+                .filter(|&id| (0..365).any(|day| conceives(id, day, morale)))
+                .count()
+        };
+        let happy = couples(1.0);
+        assert!(
+            (happy as f32 - YEARLY_CONCEPTION * 1000.0).abs() < 60.0,
+            "{happy} of 1000"
+        );
+        assert!(couples(0.3) < happy / 2);
+        assert_eq!(couples(0.0), 0);
+    }
+
+    #[test]
+    fn test_inherit_trait() {
+        assert!((inherit_trait(0.2, 0.6, 0.5) - 0.4).abs() < 1e-6);
+        assert!((inherit_trait(0.2, 0.6, 0.0) - 0.25).abs() < 1e-6);
+        assert!((inherit_trait(0.2, 0.6, 1.0) - 0.55).abs() < 1e-6);
+        assert_eq!(inherit_trait(1.0, 1.0, 1.0), 1.0);
+        assert_eq!(inherit_trait(0.0, 0.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_newborn_name_follows_family_culture() {
+        for (culture, pool) in NAME_POOLS.iter().enumerate() {
+            let family = pool.family[0];
+            if culture_of(family) != Some(culture) {
+                continue;
+            }
+            assert!(pool.given.contains(&newborn_name(family, 12345)));
+        }
+        assert!(NAME_POOLS[0].given.contains(&newborn_name("Nobody", 7)));
+    }
+}
@@ -0,0 +1,283 @@
+//! Circadian deck lighting — day/night cycles, per-deck schedules and
+//! sleeping quarters.
+//!
+//! Every deck's lights follow the day of one duty shift: most decks keep ship
+//! day with dawn at 06:00, while engineering and life support decks run on the
+//! gamma watch's day so the night shift keeping them running works in full
+//! light. Quarters dim further as their occupants fall asleep. Renderers turn
+//! the resulting [`Ambience`] into light color and intensity.
+//!
+//! The day runs through three modes ([`lighting_mode`]): day, evening and
+//! night. Command can shift a deck's cycle by up to [`MAX_CYCLE_SHIFT`]
+//! hours ([`shifted_hour`]). The mode sets how well people sleep under it
+//! ([`sleep_factor`]), lifts or wears down the morale of those awake or
+//! asleep out of step with it ([`circadian_morale`]) and nudges what people
+//! choose to do ([`activity_bonus`]).
+
+use crate::constants::{activity_types, lighting_modes, shifts};
+
+/// Light level at night, as a fraction of the deck's daytime level.
+pub const NIGHT_LEVEL: f32 = 0.25;
+/// Color temperature of night lighting in kelvin.
+pub const NIGHT_KELVIN: u32 = 2200;
+/// Light level of quarters whose occupants are all asleep.
+pub const SLEEP_LEVEL: f32 = 0.05;
+/// Hours of daylight in a schedule's day, including dawn but not dusk.
+const DAY_HOURS: f32 = 14.0;
+/// Hours for lights to ramp up at dawn and down at dusk.
+const TWILIGHT_HOURS: f32 = 2.0;
+/// Hours of evening lighting after the day, dusk included.
+const EVENING_HOURS: f32 = 4.0;
+/// Most hours command can shift a deck's cycle either way.
+pub const MAX_CYCLE_SHIFT: f32 = 12.0;
+
+/// Light level (0.0–1.0) and color temperature at one moment.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ambience {
+    pub level: f32,
+    pub kelvin: u32,
+}
+
+/// Shift whose day a deck's lights follow, from the deck's primary zone
+/// (0=command … 6=engineering).
+pub fn zone_light_schedule(zone: u8) -> u8 {
+    match zone {
+        4 | 6 => shifts::GAMMA,
+        _ => shifts::ALPHA,
+    }
+}
+
+/// Ship hour at which a schedule's dawn begins.
+pub fn schedule_dawn(schedule: u8) -> f32 {
+    match schedule {
+        shifts::BETA => 14.0,
+        shifts::GAMMA => 22.0,
+        _ => 6.0,
+    }
+}
+
+/// How far into its day a schedule is at `hour`: 0.0 night – 1.0 full day,
+/// ramping through dawn and dusk.
+pub fn daylight(schedule: u8, hour: f32) -> f32 {
+    let t = (hour - schedule_dawn(schedule)).rem_euclid(24.0);
+    if t < TWILIGHT_HOURS {
+        t / TWILIGHT_HOURS
+    } else if t < DAY_HOURS {
+        1.0
+    } else if t < DAY_HOURS + TWILIGHT_HOURS {
+        1.0 - (t - DAY_HOURS) / TWILIGHT_HOURS
+    } else {
+        0.0
+    }
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+/// Deck ambience at ship `hour`, from the deck's daytime level and color
+/// temperature and its light schedule.
+pub fn deck_ambience(light_level: f32, light_kelvin: u32, schedule: u8, hour: f32) -> Ambience {
+    let day = daylight(schedule, hour);
+    Ambience {
+        level: light_level * lerp(NIGHT_LEVEL, 1.0, day),
+        kelvin: lerp(NIGHT_KELVIN as f32, light_kelvin as f32, day) as u32,
+    }
+}
+
+/// Ambience of quarters holding `occupants` people of whom `sleeping` are
+/// asleep; lights fade toward [`SLEEP_LEVEL`] as more of them sleep.
+pub fn quarters_ambience(deck: Ambience, sleeping: u32, occupants: u32) -> Ambience {
+    if occupants == 0 {
+        return deck;
+    }
+    let asleep = (sleeping as f32 / occupants as f32).min(1.0);
+    Ambience {
+        level: lerp(deck.level, SLEEP_LEVEL.min(deck.level), asleep),
+        kelvin: lerp(deck.kelvin as f32, NIGHT_KELVIN as f32, asleep) as u32,
+    }
+}
+
+/// Ship `hour` as a deck's lights see it with the cycle shifted later by
+/// `shift_hours` (at most [`MAX_CYCLE_SHIFT`] either way).
+pub fn shifted_hour(hour: f32, shift_hours: f32) -> f32 {
+    let shift = if shift_hours.is_finite() {
+        shift_hours.clamp(-MAX_CYCLE_SHIFT, MAX_CYCLE_SHIFT)
+    } else {
+        0.0
+    };
+    (hour - shift).rem_euclid(24.0)
+}
+
+/// Lighting mode (see `lighting_modes`) of a schedule at `hour`.
+pub fn lighting_mode(schedule: u8, hour: f32) -> u8 {
+    let t = (hour - schedule_dawn(schedule)).rem_euclid(24.0);
+    if t < DAY_HOURS {
+        lighting_modes::DAY
+    } else if t < DAY_HOURS + EVENING_HOURS {
+        lighting_modes::EVENING
+    } else {
+        lighting_modes::NIGHT
+    }
+}
+
+/// How well people sleep under lights in `mode` (0.0–1.0): soundly at
+/// night, worse under daylight.
+pub fn sleep_factor(mode: u8) -> f32 {
+    match mode {
+        lighting_modes::DAY => 0.75,
+        lighting_modes::EVENING => 0.9,
+        _ => 1.0,
+    }
+}
+
+/// Morale change an hour under lights in `mode` for someone whose body
+/// clock says they should be asleep (`sleep_window`) or awake: daylight
+/// lifts the waking and keeps the sleeping up, darkness in waking hours
+/// wears people down.
+pub fn circadian_morale(mode: u8, sleep_window: bool) -> f32 {
+    match (mode, sleep_window) {
+        (lighting_modes::DAY, false) => 0.005,
+        (lighting_modes::DAY, true) => -0.005,
+        (lighting_modes::NIGHT, false) => -0.01,
+        _ => 0.0,
+    }
+}
+
+/// Score nudge for an activity of `activity_type` under lights in `mode`:
+/// night draws people to bed, evening to company and rest, daylight to the
+/// gym.
+pub fn activity_bonus(mode: u8, activity_type: u8) -> f32 {
+    match (mode, activity_type) {
+        (lighting_modes::NIGHT, activity_types::SLEEPING) => 1.0,
+        (lighting_modes::NIGHT, activity_types::EXERCISING) => -1.0,
+        (lighting_modes::EVENING, activity_types::SOCIALIZING) => 1.0,
+        (lighting_modes::EVENING, activity_types::RELAXING) => 1.0,
+        (lighting_modes::DAY, activity_types::EXERCISING) => 0.5,
+        _ => 0.0,
+    }
+}
+
+/// Normalized linear-ish RGB (0.0–1.0) for a color temperature, using the
+/// usual blackbody curve fit; valid for roughly 1000–40000 K.
+pub fn kelvin_to_rgb(kelvin: u32) -> [f32; 3] {
+    let t = kelvin.clamp(1000, 40000) as f32 / 100.0;
+    let red = if t <= 66.0 {
+        255.0
+    } else {
+        329.699 * (t - 60.0).powf(-0.133_205)
+    };
+    let green = if t <= 66.0 {
+        99.470_8 * t.ln() - 161.119_57
+    } else {
+        288.122_17 * (t - 60.0).powf(-0.075_514_85)
+    };
+    let blue = if t >= 66.0 {
+        255.0
+    } else if t <= 19.0 {
+        0.0
+    } else {
+        138.517_73 * (t - 10.0).ln() - 305.044_8
+    };
+    [red, green, blue].map(|c| (c / 255.0).clamp(0.0, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_daylight_cycle() {
+        assert_eq!(daylight(shifts::ALPHA, 12.0), 1.0);
+        assert_eq!(daylight(shifts::ALPHA, 2.0), 0.0);
+        assert_eq!(daylight(shifts::ALPHA, 7.0), 0.5);
+        assert_eq!(daylight(shifts::ALPHA, 21.0), 0.5);
+        // The gamma day is offset: lit at 02:00, dark at 18:00
+        assert_eq!(daylight(shifts::GAMMA, 2.0), 1.0);
+        assert_eq!(daylight(shifts::GAMMA, 18.0), 0.0);
+    }
+
+    #[test]
+    fn test_night_lights_follow_sleep_windows() {
+        use crate::duty::is_crew_sleep_time;
+        for schedule in [shifts::ALPHA, shifts::GAMMA] {
+            for h in 0..24 {
+                let hour = h as f32 + 0.5;
+                if is_crew_sleep_time(schedule, hour) {
+                    assert!(
+                        daylight(schedule, hour) < 1.0,
+                        "shift {} {}h",
+                        schedule,
+                        hour
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_deck_ambience() {
+        let noon = deck_ambience(0.8, 5000, shifts::ALPHA, 12.0);
+        assert_eq!(
+            noon,
+            Ambience {
+                level: 0.8,
+                kelvin: 5000
+            }
+        );
+        let night = deck_ambience(0.8, 5000, shifts::ALPHA, 0.0);
+        assert!((night.level - 0.8 * NIGHT_LEVEL).abs() < 1e-6);
+        assert_eq!(night.kelvin, NIGHT_KELVIN);
+        assert_eq!(zone_light_schedule(6), shifts::GAMMA);
+        assert_eq!(zone_light_schedule(1), shifts::ALPHA);
+    }
+
+    #[test]
+    fn test_quarters_dim_with_sleepers() {
+        let deck = Ambience {
+            level: 0.7,
+            kelvin: 3200,
+        };
+        assert_eq!(quarters_ambience(deck, 0, 0), deck);
+        assert_eq!(quarters_ambience(deck, 0, 4), deck);
+        let half = quarters_ambience(deck, 2, 4);
+        let all = quarters_ambience(deck, 4, 4);
+        assert!(all.level < half.level && half.level < deck.level);
+        assert!((all.level - SLEEP_LEVEL).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_lighting_modes() {
+        assert_eq!(lighting_mode(shifts::ALPHA, 12.0), lighting_modes::DAY);
+        assert_eq!(lighting_mode(shifts::ALPHA, 21.0), lighting_modes::EVENING);
+        assert_eq!(lighting_mode(shifts::ALPHA, 2.0), lighting_modes::NIGHT);
+        assert_eq!(lighting_mode(shifts::GAMMA, 2.0), lighting_modes::DAY);
+        // Shifted three hours later, 21:00 looks like 18:00
+        assert_eq!(shifted_hour(21.0, 3.0), 18.0);
+        assert_eq!(shifted_hour(1.0, 2.0), 23.0);
+        assert_eq!(shifted_hour(12.0, 100.0), 0.0);
+        assert_eq!(shifted_hour(12.0, f32::NAN), 12.0);
+    }
+
+    #[test]
+    fn test_mode_effects() {
+        assert!(sleep_factor(lighting_modes::NIGHT) > sleep_factor(lighting_modes::DAY));
+        assert!(circadian_morale(lighting_modes::DAY, false) > 0.0);
+        assert!(circadian_morale(lighting_modes::NIGHT, false) < 0.0);
+        assert_eq!(circadian_morale(lighting_modes::NIGHT, true), 0.0);
+        assert!(activity_bonus(lighting_modes::NIGHT, activity_types::SLEEPING) > 0.0);
+        assert_eq!(
+            activity_bonus(lighting_modes::DAY, activity_types::SLEEPING),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_kelvin_to_rgb() {
+        let warm = kelvin_to_rgb(2200);
+        let daylight = kelvin_to_rgb(6500);
+        assert_eq!(warm[0], 1.0);
+        assert!(warm[2] < daylight[2]);
+        assert!(daylight.iter().all(|&c| c > 0.9));
+    }
+}
@@ -0,0 +1,171 @@
+//! Mental health — stress that builds over days, counseling, breakdowns.
+//!
+//! Unlike the passing stress behind a person's mood (see `emotions`), this
+//! stress accumulates: distressing events on their deck, grief, isolation
+//! and overwork build it up, faster for the neurotic, and rest wears it
+//! down ([`stress_change`]). Anyone off duty from [`COUNSELING_STRESS`] is
+//! booked into a session in a Mental Health room with medical crew who
+//! have the social skill for it ([`MIN_COUNSELOR_SKILL`]), up to
+//! [`CLIENTS_PER_COUNSELOR`] at a time, and sessions ease stress the more
+//! skilled the counselor ([`counseling_relief`]). From
+//! [`BREAKDOWN_STRESS`] people may break down ([`breakdown_chance`]): for
+//! [`BREAKDOWN_HOURS`] they refuse duty, and the disagreeable lash out at
+//! those around them ([`lashes_out`]).
+
+use crate::constants::{event_types, room_types};
+
+/// Stress from which someone off duty is booked for counseling.
+pub const COUNSELING_STRESS: f32 = 0.5;
+
+/// Stress from which someone may break down.
+pub const BREAKDOWN_STRESS: f32 = 0.85;
+
+/// Stress someone is left with after breaking down.
+pub const AFTER_BREAKDOWN_STRESS: f32 = 0.6;
+
+/// Hours a counseling session lasts.
+pub const SESSION_HOURS: f64 = 1.0;
+
+/// Hours a breakdown lasts.
+pub const BREAKDOWN_HOURS: f64 = 8.0;
+
+/// Clients one counselor sees at once.
+pub const CLIENTS_PER_COUNSELOR: usize = 3;
+
+/// Social skill medical crew need to counsel.
+pub const MIN_COUNSELOR_SKILL: f32 = 0.4;
+
+/// Salt of the [`roll`](crate::numeric::roll) for breaking down.
+pub const BREAKDOWN_SALT: u64 = 0xb4ea;
+
+/// Whether counseling sessions are held in a room of `room_type`.
+pub fn is_counseling_room(room_type: u8) -> bool {
+    room_type == room_types::MENTAL_HEALTH
+}
+
+/// What someone's stress responds to over an hour — pure data, no DB
+/// access.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StressInput {
+    /// Neuroticism (0.0–1.0): how strongly they feel it.
+    pub neuroticism: f32,
+    /// A distressing event (see [`is_distressing`]) is under way on their
+    /// deck.
+    pub distress_nearby: bool,
+    /// Still mourning a death.
+    pub grieving: bool,
+    /// Starved of company (see [`is_isolated`]).
+    pub isolated: bool,
+    /// Worn out on watch (see [`is_overworked`]).
+    pub overworked: bool,
+    /// Asleep or relaxing.
+    pub resting: bool,
+}
+
+/// Whether an event of `event_type` distresses those near it.
+pub fn is_distressing(event_type: u8) -> bool {
+    matches!(
+        event_type,
+        event_types::FIRE
+            | event_types::HULL_BREACH
+            | event_types::MEDICAL_EMERGENCY
+            | event_types::SYSTEM_FAILURE
+            | event_types::ALTERCATION
+            | event_types::DEATH
+            | event_types::MUTINY
+    )
+}
+
+/// Whether someone with social need `social` (1.0 desperate for company)
+/// is isolated.
+pub fn is_isolated(social: f32) -> bool {
+    social > 0.7
+}
+
+/// Whether someone on watch at `fatigue` is overworked.
+pub fn is_overworked(on_duty: bool, fatigue: f32) -> bool {
+    on_duty && fatigue > 0.7
+}
+
+/// Change in stress over `hours` given `input`: what weighs on them,
+/// scaled by neuroticism, less what rest wears away.
+pub fn stress_change(input: &StressInput, hours: f32) -> f32 {
+    let weigh = |yes: bool, per_hour: f32| if yes { per_hour } else { 0.0 };
+    let load = weigh(input.distress_nearby, 0.04)
+        + weigh(input.grieving, 0.02)
+        + weigh(input.isolated, 0.01)
+        + weigh(input.overworked, 0.015);
+    let relief = if input.resting { 0.01 } else { 0.004 };
+    (load * (0.5 + input.neuroticism.clamp(0.0, 1.0)) - relief) * hours.max(0.0)
+}
+
+/// Stress a counseling session eases over `hours` with a counselor of
+/// social skill `skill`.
+pub fn counseling_relief(skill: f32, hours: f32) -> f32 {
+    (0.1 + 0.2 * skill.clamp(0.0, 1.0)) * hours.max(0.0)
+}
+
+/// Chance over `hours` that someone at `stress` with `neuroticism` breaks
+/// down: none below [`BREAKDOWN_STRESS`], rising to a third an hour for
+/// the most neurotic at the limit.
+pub fn breakdown_chance(stress: f32, neuroticism: f32, hours: f32) -> f32 {
+    if stress < BREAKDOWN_STRESS {
+        return 0.0;
+    }
+    let over = ((stress - BREAKDOWN_STRESS) / (1.0 - BREAKDOWN_STRESS)).clamp(0.0, 1.0);
+    let per_hour = (0.05 + 0.1 * over) * (1.0 + 1.2 * neuroticism.clamp(0.0, 1.0));
+    (per_hour * hours.max(0.0)).clamp(0.0, 1.0)
+}
+
+/// Whether someone of `agreeableness` lashes out when they break down.
+pub fn lashes_out(agreeableness: f32) -> bool {
+    agreeableness < 0.35
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stress_change() {
+        let calm = StressInput {
+            resting: true,
+            ..Default::default()
+        };
+        assert!(stress_change(&calm, 1.0) < 0.0, "rest wears stress down");
+        let fire = StressInput {
+            distress_nearby: true,
+            neuroticism: 0.5,
+            ..Default::default()
+        };
+        let anxious = StressInput {
+            neuroticism: 1.0,
+            ..fire
+        };
+        assert!(stress_change(&fire, 1.0) > 0.0);
+        assert!(stress_change(&anxious, 1.0) > stress_change(&fire, 1.0));
+        assert!(is_distressing(event_types::FIRE));
+        assert!(!is_distressing(event_types::CELEBRATION));
+        assert!(is_counseling_room(room_types::MENTAL_HEALTH));
+        assert!(!is_counseling_room(room_types::HOSPITAL_WARD));
+        assert!(is_isolated(0.8) && !is_isolated(0.5));
+        assert!(is_overworked(true, 0.8) && !is_overworked(false, 0.9));
+    }
+
+    #[test]
+    fn test_counseling_relief() {
+        assert!(counseling_relief(0.9, 1.0) > counseling_relief(0.4, 1.0));
+        assert!(counseling_relief(0.0, 1.0) > 0.0);
+        assert_eq!(counseling_relief(0.5, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_breakdown_chance() {
+        assert_eq!(breakdown_chance(0.8, 1.0, 1.0), 0.0);
+        assert!(breakdown_chance(0.9, 0.5, 1.0) > 0.0);
+        assert!(breakdown_chance(1.0, 0.5, 1.0) > breakdown_chance(0.9, 0.5, 1.0));
+        assert!(breakdown_chance(1.0, 1.0, 1.0) > breakdown_chance(1.0, 0.0, 1.0));
+        assert!(breakdown_chance(1.0, 1.0, 100.0) <= 1.0);
+        assert!(lashes_out(0.2) && !lashes_out(0.6));
+    }
+}
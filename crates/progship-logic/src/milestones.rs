@@ -0,0 +1,237 @@
+//! Milestones — once-per-voyage achievements kept in the ship's record.
+//!
+//! The server checks for each [`milestone_kinds`] entry as the simulation
+//! runs and records it the first time it is reached, with the people
+//! involved: the first child born after departure, a hundred days without a
+//! death, the reactor coming back after a scram, and arriving at the
+//! destination. [`voyage_report`] lists them at the end of the voyage.
+//!
+//! The voyage itself has milestones too ([`voyage_milestones`]): leaving
+//! the home system behind, main engine cutoff, the mid-course correction
+//! at the halfway mark, turnover to braking, the flyby of the destination's
+//! outer planets and the last [`ARRIVAL_PREP_HOURS`] of preparations. The
+//! whole ship gathers in the mess halls for each one, in better spirits
+//! for it ([`milestone_morale`]).
+
+use serde::{Deserialize, Serialize};
+
+use crate::constants::{milestone_kinds, system_statuses, voyage_phases};
+use crate::hazards::SYSTEM_HOURS;
+use crate::history::sim_day;
+
+/// Simulation hours between milestone checks.
+pub const MILESTONE_CHECK_HOURS: f64 = 1.0;
+
+/// Fatality-free stretch, in simulation hours, for
+/// [`milestone_kinds::FATALITY_FREE_100_DAYS`].
+pub const FATALITY_FREE_HOURS: f64 = 100.0 * 24.0;
+
+/// Hours before arrival that
+/// [`milestone_kinds::ARRIVAL_PREP`] begins.
+pub const ARRIVAL_PREP_HOURS: f64 = 7.0 * 24.0;
+
+/// Hours the ship gathers for a voyage milestone.
+pub const GATHERING_HOURS: f32 = 2.0;
+
+/// Morale an hour at a voyage milestone gathering gives.
+pub const GATHERING_MORALE: f32 = 0.05;
+
+/// Whether someone with `birth_date` (sim hours) was born after departure.
+pub fn born_aboard(birth_date: f64) -> bool {
+    birth_date > 0.0
+}
+
+/// Whether the ship has gone [`FATALITY_FREE_HOURS`] since the last death
+/// (or departure, if nobody has died).
+pub fn fatality_free(sim_time: f64, last_fatality_at: f64) -> bool {
+    sim_time - last_fatality_at >= FATALITY_FREE_HOURS
+}
+
+/// Whether a power system in `status` has scrammed, i.e. is off line.
+pub fn scrammed(status: u8) -> bool {
+    status >= system_statuses::OFFLINE
+}
+
+/// Whether a power system back in `status` has recovered from a scram.
+pub fn recovered(status: u8) -> bool {
+    status <= system_statuses::DEGRADED
+}
+
+/// Whether a voyage of `voyage_hours` is over at `sim_time`.
+pub fn arrived(sim_time: f64, voyage_hours: f64) -> bool {
+    voyage_hours.is_finite() && voyage_hours > 0.0 && sim_time >= voyage_hours
+}
+
+/// How far along the voyage the ship is, for [`voyage_milestones`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VoyageProgress {
+    /// Sim hours since departure.
+    pub elapsed: f64,
+    /// Phase of the voyage (see `voyage_phases`).
+    pub phase: u8,
+    /// Light-years covered.
+    pub covered_ly: f64,
+    /// Light-years from home to the destination.
+    pub total_ly: f64,
+    /// Hours left to arrival.
+    pub hours_left: f64,
+}
+
+/// Voyage milestones (see `milestone_kinds`) the ship has reached at
+/// `progress`, in the order the voyage reaches them.
+pub fn voyage_milestones(progress: &VoyageProgress) -> Vec<u8> {
+    let p = progress;
+    let underway = p.phase != voyage_phases::ACCELERATING;
+    [
+        (milestone_kinds::HELIOPAUSE, p.elapsed >= SYSTEM_HOURS),
+        (milestone_kinds::ENGINE_CUTOFF, underway),
+        (
+            milestone_kinds::MIDCOURSE_CORRECTION,
+            p.total_ly > 0.0 && p.covered_ly >= p.total_ly * 0.5,
+        ),
+        (
+            milestone_kinds::TURNOVER,
+            matches!(
+                p.phase,
+                voyage_phases::DECELERATING | voyage_phases::ARRIVED
+            ),
+        ),
+        (
+            milestone_kinds::DESTINATION_FLYBY,
+            p.hours_left <= SYSTEM_HOURS,
+        ),
+        (
+            milestone_kinds::ARRIVAL_PREP,
+            p.hours_left <= ARRIVAL_PREP_HOURS,
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(kind, reached)| reached.then_some(kind))
+    .collect()
+}
+
+/// Morale everyone aboard gains on reaching the voyage milestone `kind`:
+/// the most for the halfway mark and the last stretch.
+pub fn milestone_morale(kind: u8) -> f32 {
+    match kind {
+        milestone_kinds::MIDCOURSE_CORRECTION | milestone_kinds::ARRIVAL_PREP => 0.1,
+        milestone_kinds::HELIOPAUSE
+        | milestone_kinds::ENGINE_CUTOFF
+        | milestone_kinds::TURNOVER
+        | milestone_kinds::DESTINATION_FLYBY => 0.05,
+        _ => 0.0,
+    }
+}
+
+/// One recorded milestone, for [`voyage_report`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MilestoneEntry {
+    pub kind: u8,
+    /// Sim time reached, in hours.
+    pub achieved_at: f64,
+    pub detail: String,
+}
+
+/// End-of-voyage summary: the ship, its losses and its milestones in the
+/// order they were reached.
+pub fn voyage_report(
+    ship_name: &str,
+    sim_time: f64,
+    death_count: u32,
+    milestones: &[MilestoneEntry],
+) -> String {
+    let mut report = format!(
+        "=== Voyage report: {} ===\nDay {}, {} death{}\n",
+        ship_name,
+        sim_day(sim_time) + 1,
+        death_count,
+        if death_count == 1 { "" } else { "s" }
+    );
+    let mut sorted: Vec<&MilestoneEntry> = milestones.iter().collect();
+    sorted.sort_by(|a, b| a.achieved_at.total_cmp(&b.achieved_at));
+    if sorted.is_empty() {
+        report.push_str("No milestones\n");
+    }
+    for m in sorted {
+        report.push_str(&format!(
+            "Day {:>5}  {}",
+            sim_day(m.achieved_at) + 1,
+            milestone_kinds::name(m.kind)
+        ));
+        if !m.detail.is_empty() {
+            report.push_str(&format!(" ({})", m.detail));
+        }
+        report.push('\n');
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_milestone_conditions() {
+        assert!(born_aboard(12.0));
+        assert!(!born_aboard(-24.0 * 365.0));
+        assert!(fatality_free(2400.0, 0.0));
+        assert!(!fatality_free(2500.0, 200.0));
+        assert!(scrammed(system_statuses::OFFLINE));
+        assert!(scrammed(system_statuses::DESTROYED));
+        assert!(!scrammed(system_statuses::CRITICAL));
+        assert!(recovered(system_statuses::DEGRADED));
+        assert!(!recovered(system_statuses::CRITICAL));
+        assert!(arrived(100.0, 100.0));
+        assert!(!arrived(99.0, 100.0));
+        assert!(!arrived(1e9, f64::INFINITY));
+    }
+
+    #[test]
+    fn test_voyage_milestones() {
+        let mut progress = VoyageProgress {
+            elapsed: 10.0,
+            phase: voyage_phases::ACCELERATING,
+            covered_ly: 0.0,
+            total_ly: 4.0,
+            hours_left: 1e6,
+        };
+        assert!(voyage_milestones(&progress).is_empty());
+        progress.elapsed = SYSTEM_HOURS;
+        progress.phase = voyage_phases::CRUISING;
+        progress.covered_ly = 2.0;
+        assert_eq!(
+            voyage_milestones(&progress),
+            [
+                milestone_kinds::HELIOPAUSE,
+                milestone_kinds::ENGINE_CUTOFF,
+                milestone_kinds::MIDCOURSE_CORRECTION
+            ]
+        );
+        progress.phase = voyage_phases::DECELERATING;
+        progress.hours_left = ARRIVAL_PREP_HOURS;
+        assert_eq!(voyage_milestones(&progress).len(), 6);
+        assert!(milestone_morale(milestone_kinds::ARRIVAL_PREP) > 0.0);
+        assert_eq!(milestone_morale(milestone_kinds::FIRST_BIRTH), 0.0);
+    }
+
+    #[test]
+    fn test_voyage_report_lists_milestones_in_order() {
+        let milestones = [
+            MilestoneEntry {
+                kind: milestone_kinds::ARRIVAL,
+                achieved_at: 500.0,
+                detail: String::new(),
+            },
+            MilestoneEntry {
+                kind: milestone_kinds::FIRST_BIRTH,
+                achieved_at: 30.0,
+                detail: "Ada Marsh".into(),
+            },
+        ];
+        let report = voyage_report("Test", 500.0, 1, &milestones);
+        assert!(report.contains("Day 21, 1 death\n"), "{report}");
+        let birth = report.find("First Birth Aboard (Ada Marsh)").unwrap();
+        assert!(birth < report.find("Arrival").unwrap());
+        assert!(voyage_report("Test", 0.0, 0, &[]).contains("No milestones"));
+    }
+}
@@ -5,6 +5,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::demographics::DemographicCurve;
+
 // ============================================================================
 // DESTINATIONS
 // ============================================================================
@@ -235,6 +237,9 @@ pub struct MissionConfig {
     pub seed: u64,
     /// Propulsion system selection.
     pub propulsion: u8,
+    /// Age distribution of passengers.
+    #[serde(default)]
+    pub demographics: DemographicCurve,
 }
 
 /// Mission priority weighting — what matters most for this colony.
@@ -274,6 +279,7 @@ impl Default for MissionConfig {
             mission_priority: MissionPriority::default(),
             seed: 42,
             propulsion: PropulsionType::FusionTorch as u8,
+            demographics: DemographicCurve::default(),
         }
     }
 }
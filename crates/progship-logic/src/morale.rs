@@ -0,0 +1,159 @@
+//! Morale — how each department and the ship as a whole are holding up.
+//!
+//! Everyone's morale is averaged by department, passengers counting as
+//! civilian, and across the ship ([`summarize`]). Each index has a mood
+//! (see `morale_moods`) that follows it with a little hysteresis
+//! ([`mood`]), and moving into a mood can set something off
+//! ([`mood_action`]): a ship in high spirits throws a celebration, a ship
+//! losing heart rallies in the mess hall, despairing passengers rally too,
+//! and a crew department in crisis goes on strike. Rallies lift those who
+//! come ([`RALLY_MORALE`]) and strikers feel heard ([`STRIKE_MORALE`]).
+
+use crate::constants::{departments, event_types, morale_moods};
+
+/// Scope of the ship-wide index, alongside the department IDs.
+pub const SHIP_WIDE: u8 = u8::MAX;
+
+/// Morale below which someone despairs and an index is critical.
+pub const CRITICAL_MORALE: f32 = 0.25;
+
+/// Morale below which an index is low.
+pub const LOW_MORALE: f32 = 0.4;
+
+/// Morale at which an index is in high spirits.
+pub const HIGH_MORALE: f32 = 0.75;
+
+/// How far an index has to move back past a threshold to leave a mood.
+const HYSTERESIS: f32 = 0.05;
+
+/// How long a morale celebration lasts (hours).
+pub const CELEBRATION_HOURS: f32 = 3.0;
+
+/// How long a rally lasts (hours).
+pub const RALLY_HOURS: f32 = 2.0;
+
+/// How long a strike lasts (hours): a full watch.
+pub const STRIKE_HOURS: f32 = 8.0;
+
+/// Morale an hour of a rally gives those at it.
+pub const RALLY_MORALE: f32 = 0.03;
+
+/// Morale an hour on strike gives the strikers.
+pub const STRIKE_MORALE: f32 = 0.01;
+
+/// One morale index: the people counted, their average morale and the
+/// share of them below [`CRITICAL_MORALE`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Index {
+    pub people: u32,
+    pub morale: f32,
+    pub despairing: f32,
+}
+
+/// The index over everyone's `morale`; all zero for nobody.
+pub fn summarize(morale: &[f32]) -> Index {
+    if morale.is_empty() {
+        return Index {
+            people: 0,
+            morale: 0.0,
+            despairing: 0.0,
+        };
+    }
+    let people = morale.len() as f32;
+    let despairing = morale.iter().filter(|&&m| m < CRITICAL_MORALE).count() as f32;
+    Index {
+        people: morale.len() as u32,
+        morale: morale.iter().sum::<f32>() / people,
+        despairing: despairing / people,
+    }
+}
+
+/// The mood of an index at `morale` that was in the `previous` mood:
+/// crossing a threshold changes it, but it takes [`HYSTERESIS`] back past
+/// the threshold to leave again.
+pub fn mood(previous: u8, morale: f32) -> u8 {
+    let margin = |held: bool| if held { HYSTERESIS } else { 0.0 };
+    let down = previous == morale_moods::LOW || previous == morale_moods::CRITICAL;
+    if morale < CRITICAL_MORALE + margin(previous == morale_moods::CRITICAL) {
+        morale_moods::CRITICAL
+    } else if morale < LOW_MORALE + margin(down) {
+        morale_moods::LOW
+    } else if morale >= HIGH_MORALE - margin(previous == morale_moods::HIGH) {
+        morale_moods::HIGH
+    } else {
+        morale_moods::STEADY
+    }
+}
+
+/// The event (see `event_types`) an index of `scope` sets off moving from
+/// the `previous` mood to `next`, if any.
+pub fn mood_action(scope: u8, previous: u8, next: u8) -> Option<u8> {
+    if next == previous {
+        return None;
+    }
+    let falling = previous == morale_moods::STEADY || previous == morale_moods::HIGH;
+    match (scope, next) {
+        (SHIP_WIDE, morale_moods::HIGH) => Some(event_types::CELEBRATION),
+        (SHIP_WIDE, morale_moods::LOW | morale_moods::CRITICAL) if falling => {
+            Some(event_types::RALLY)
+        }
+        (SHIP_WIDE, _) => None,
+        (departments::CIVILIAN, morale_moods::CRITICAL) => Some(event_types::RALLY),
+        (_, morale_moods::CRITICAL) => Some(event_types::STRIKE),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarize() {
+        assert_eq!(summarize(&[]).people, 0);
+        let index = summarize(&[0.1, 0.5, 0.9, 0.2]);
+        assert_eq!(index.people, 4);
+        assert!((index.morale - 0.425).abs() < 1e-6);
+        assert!((index.despairing - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_mood_hysteresis() {
+        use morale_moods::*;
+        assert_eq!(mood(STEADY, 0.5), STEADY);
+        assert_eq!(mood(STEADY, 0.38), LOW);
+        assert_eq!(mood(LOW, 0.42), LOW);
+        assert_eq!(mood(LOW, 0.46), STEADY);
+        assert_eq!(mood(LOW, 0.2), CRITICAL);
+        assert_eq!(mood(CRITICAL, 0.28), CRITICAL);
+        assert_eq!(mood(CRITICAL, 0.32), LOW);
+        assert_eq!(mood(STEADY, 0.8), HIGH);
+        assert_eq!(mood(HIGH, 0.72), HIGH);
+        assert_eq!(mood(HIGH, 0.68), STEADY);
+    }
+
+    #[test]
+    fn test_mood_actions() {
+        use morale_moods::*;
+        assert_eq!(
+            mood_action(SHIP_WIDE, STEADY, HIGH),
+            Some(event_types::CELEBRATION)
+        );
+        assert_eq!(
+            mood_action(SHIP_WIDE, STEADY, LOW),
+            Some(event_types::RALLY)
+        );
+        assert_eq!(mood_action(SHIP_WIDE, LOW, CRITICAL), None);
+        assert_eq!(mood_action(SHIP_WIDE, LOW, LOW), None);
+        assert_eq!(
+            mood_action(departments::ENGINEERING, LOW, CRITICAL),
+            Some(event_types::STRIKE)
+        );
+        assert_eq!(
+            mood_action(departments::CIVILIAN, LOW, CRITICAL),
+            Some(event_types::RALLY)
+        );
+        assert_eq!(mood_action(departments::ENGINEERING, STEADY, LOW), None);
+        assert_eq!(mood_action(departments::MEDICAL, CRITICAL, LOW), None);
+    }
+}
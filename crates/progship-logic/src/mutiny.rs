@@ -0,0 +1,135 @@
+//! Mutiny — a department that has despaired too long stops work and
+//! holds its station.
+//!
+//! A crew department whose morale has been critical (see `morale`) for
+//! [`MUTINY_HOURS`] mutinies ([`mutiny_due`]): its crew refuse duty, occupy
+//! their station and make a [`demand`] of the ship's policy. Command either
+//! gives in ([`concede`]), lifting the mutineers' spirits, or sends security
+//! in; once enough of them reach the station ([`suppression_force`]) the
+//! mutiny is put down and its [`RINGLEADERS`] go to the brig. Left alone,
+//! the responsible officer decides after [`RESPONSE_HOURS`]
+//! ([`command_suppresses`]), and security that cannot take the station
+//! within [`SUPPRESSION_HOURS`] leaves command no choice but to concede.
+
+use crate::constants::mutiny_demands;
+use crate::governance::{Policy, REGULAR_WORK_HOURS, SHORT_WORK_HOURS};
+
+/// Hours a department's morale has to stay critical before it mutinies,
+/// and the least time between two of its mutinies.
+pub const MUTINY_HOURS: f64 = 48.0;
+
+/// Hours command leaves a mutiny before deciding on its own.
+pub const RESPONSE_HOURS: f64 = 6.0;
+
+/// Hours security has to take back a station before command concedes.
+pub const SUPPRESSION_HOURS: f64 = 12.0;
+
+/// Mutineers detained when a mutiny is put down.
+pub const RINGLEADERS: usize = 3;
+
+/// Morale mutineers regain when their demand is met.
+pub const CONCESSION_MORALE: f32 = 0.2;
+
+/// Morale a department loses when its mutiny is put down.
+pub const SUPPRESSION_MORALE: f32 = 0.1;
+
+/// Whether a department whose morale has been critical for `critical_for`
+/// hours mutinies, its last mutiny having ended `since_last` hours ago.
+pub fn mutiny_due(critical_for: f64, since_last: Option<f64>) -> bool {
+    critical_for >= MUTINY_HOURS && since_last.is_none_or(|h| h >= MUTINY_HOURS)
+}
+
+/// What mutineers demand (see `mutiny_demands`) under `policy`: longer
+/// watches gone, then rationing, then the curfew, else shorter watches.
+pub fn demand(policy: &Policy) -> u8 {
+    if policy.work_hours > REGULAR_WORK_HOURS {
+        mutiny_demands::SHORTER_WATCHES
+    } else if policy.rationing > 0 {
+        mutiny_demands::END_RATIONING
+    } else if policy.curfew {
+        mutiny_demands::LIFT_CURFEW
+    } else {
+        mutiny_demands::SHORTER_WATCHES
+    }
+}
+
+/// `policy` with `demand` met.
+pub fn concede(policy: Policy, demand: u8) -> Policy {
+    match demand {
+        mutiny_demands::END_RATIONING => Policy {
+            rationing: 0,
+            ..policy
+        },
+        mutiny_demands::LIFT_CURFEW => Policy {
+            curfew: false,
+            ..policy
+        },
+        _ => Policy {
+            work_hours: SHORT_WORK_HOURS,
+            ..policy
+        },
+    }
+}
+
+/// Security crew it takes at the station to put down `mutineers`: one for
+/// every three, at least one.
+pub fn suppression_force(mutineers: u32) -> u32 {
+    mutineers.div_ceil(3).max(1)
+}
+
+/// Whether an officer of `agreeableness`, with `security` crew on duty to
+/// send, puts down `mutineers` rather than conceding: the disagreeable do
+/// if they have the force to.
+pub fn command_suppresses(agreeableness: f32, security: u32, mutineers: u32) -> bool {
+    agreeableness < 0.5 && security >= suppression_force(mutineers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mutiny_due() {
+        assert!(!mutiny_due(MUTINY_HOURS - 1.0, None));
+        assert!(mutiny_due(MUTINY_HOURS, None));
+        assert!(!mutiny_due(MUTINY_HOURS * 2.0, Some(10.0)));
+        assert!(mutiny_due(MUTINY_HOURS * 2.0, Some(MUTINY_HOURS)));
+    }
+
+    #[test]
+    fn test_demands() {
+        let policy = Policy::default();
+        assert_eq!(demand(&policy), mutiny_demands::SHORTER_WATCHES);
+        let rationed = Policy {
+            rationing: 2,
+            curfew: true,
+            ..policy
+        };
+        assert_eq!(demand(&rationed), mutiny_demands::END_RATIONING);
+        let conceded = concede(rationed, demand(&rationed));
+        assert_eq!(conceded.rationing, 0);
+        assert!(conceded.curfew);
+        assert_eq!(demand(&conceded), mutiny_demands::LIFT_CURFEW);
+        assert!(!concede(conceded, mutiny_demands::LIFT_CURFEW).curfew);
+        let worked = Policy {
+            work_hours: 10,
+            rationing: 1,
+            ..policy
+        };
+        assert_eq!(demand(&worked), mutiny_demands::SHORTER_WATCHES);
+        assert_eq!(
+            concede(worked, mutiny_demands::SHORTER_WATCHES).work_hours,
+            SHORT_WORK_HOURS
+        );
+    }
+
+    #[test]
+    fn test_suppression() {
+        assert_eq!(suppression_force(0), 1);
+        assert_eq!(suppression_force(3), 1);
+        assert_eq!(suppression_force(7), 3);
+        assert!(command_suppresses(0.2, 3, 7));
+        assert!(!command_suppresses(0.2, 2, 7));
+        assert!(!command_suppresses(0.8, 10, 7));
+    }
+}
@@ -0,0 +1,380 @@
+//! Name pools — culture-weighted given and family names for generation.
+//!
+//! Each [`NamePool`] is one naming culture with its share of the population.
+//! A [`NameGenerator`] deals cultures out in proportion to a mix of weights
+//! (the pool defaults, or the server's culture_mix rows), then walks each
+//! culture's lists so names rarely repeat. A household picks its culture
+//! once, so parents and children share a surname and a naming tradition.
+
+/// One naming culture.
+#[derive(Debug, Clone, Copy)]
+pub struct NamePool {
+    pub culture: &'static str,
+    /// Default share of the population, relative to the other pools.
+    pub weight: f32,
+    pub given: &'static [&'static str],
+    pub family: &'static [&'static str],
+}
+
+/// Every naming culture, indexed by culture ID.
+pub const NAME_POOLS: [NamePool; 8] = [
+    NamePool {
+        culture: "Spacer",
+        weight: 2.0,
+        given: &[
+            "Alex", "Jordan", "Morgan", "Casey", "Riley", "Quinn", "Avery", "Taylor", "Skyler",
+            "Kai", "Rowan", "Sage", "River", "Phoenix", "Eden", "Harper", "Wren", "Vesper", "Lark",
+            "Cedar", "Onyx", "Zephyr", "Kestrel", "Indigo",
+        ],
+        family: &[
+            "Ashford",
+            "Blackwood",
+            "Harrow",
+            "Voss",
+            "Kane",
+            "Marlowe",
+            "Sterling",
+            "Hale",
+            "Cross",
+            "Drake",
+            "Frost",
+            "Locke",
+            "Mercer",
+            "Pryce",
+            "Quill",
+            "Rhodes",
+        ],
+    },
+    NamePool {
+        culture: "East Asian",
+        weight: 1.0,
+        given: &[
+            "Hana", "Ren", "Yuki", "Haruto", "Mei", "Jun", "Sora", "Aiko", "Wei", "Lian", "Minjun",
+            "Jiwoo", "Kenji", "Xiu", "Daiki", "Seo-yeon",
+        ],
+        family: &[
+            "Chen",
+            "Nakamura",
+            "Kim",
+            "Park",
+            "Yamamoto",
+            "Tanaka",
+            "Sato",
+            "Watanabe",
+            "Suzuki",
+            "Takahashi",
+            "Wang",
+            "Li",
+            "Zhang",
+            "Choi",
+            "Hayashi",
+            "Mori",
+        ],
+    },
+    NamePool {
+        culture: "South Asian",
+        weight: 1.0,
+        given: &[
+            "Aarav", "Priya", "Rohan", "Ananya", "Vikram", "Kavya", "Arjun", "Isha", "Dev",
+            "Meera", "Sanjay", "Nisha", "Ravi", "Lakshmi",
+        ],
+        family: &[
+            "Singh", "Patel", "Sharma", "Gupta", "Iyer", "Reddy", "Nair", "Rao", "Mehta", "Das",
+            "Kapoor", "Joshi",
+        ],
+    },
+    NamePool {
+        culture: "Latin",
+        weight: 1.0,
+        given: &[
+            "Mateo",
+            "Lucia",
+            "Diego",
+            "Camila",
+            "Santiago",
+            "Valentina",
+            "Javier",
+            "Sofia",
+            "Emilio",
+            "Ines",
+            "Rafael",
+            "Paloma",
+            "Tomas",
+            "Marisol",
+        ],
+        family: &[
+            "Santos",
+            "Torres",
+            "Fernandez",
+            "Costa",
+            "Mendez",
+            "Ortiz",
+            "Reyes",
+            "Delgado",
+            "Herrera",
+            "Morales",
+            "Vargas",
+            "Castillo",
+        ],
+    },
+    NamePool {
+        culture: "West African",
+        weight: 1.0,
+        given: &[
+            "Chidi", "Amara", "Kwame", "Ama", "Tunde", "Ngozi", "Kofi", "Adaeze", "Emeka", "Yaa",
+            "Sekou", "Fatou", "Obi", "Zainab",
+        ],
+        family: &[
+            "Okafor", "Mensah", "Adeyemi", "Diallo", "Boateng", "Nwosu", "Traore", "Owusu", "Eze",
+            "Keita", "Asante", "Balogun",
+        ],
+    },
+    NamePool {
+        culture: "Arabic",
+        weight: 0.8,
+        given: &[
+            "Omar", "Layla", "Yusuf", "Amira", "Karim", "Noor", "Tariq", "Salma", "Hassan",
+            "Yasmin", "Samir", "Rania",
+        ],
+        family: &[
+            "Ibrahim", "Ali", "Haddad", "Nasser", "Khalil", "Mansour", "Saleh", "Aziz", "Farouk",
+            "Hamdan", "Qureshi", "Rahman",
+        ],
+    },
+    NamePool {
+        culture: "Slavic",
+        weight: 0.8,
+        given: &[
+            "Dmitri", "Anya", "Pavel", "Katya", "Milan", "Zofia", "Ivan", "Mila", "Bogdan",
+            "Vesna", "Lev", "Irina",
+        ],
+        family: &[
+            "Petrov",
+            "Novak",
+            "Kowalski",
+            "Popov",
+            "Ivanov",
+            "Volkov",
+            "Horvat",
+            "Dvorak",
+            "Sokolov",
+            "Nowak",
+            "Kuznetsov",
+            "Lebedev",
+        ],
+    },
+    NamePool {
+        culture: "European",
+        weight: 1.2,
+        given: &[
+            "Lena", "Lars", "Elise", "Matteo", "Freya", "Henrik", "Chloe", "Luca", "Ingrid",
+            "Julien", "Astrid", "Niels", "Clara", "Sven", "Greta", "Pieter",
+        ],
+        family: &[
+            "Hansen",
+            "Moreau",
+            "Andersen",
+            "Johansson",
+            "Larsson",
+            "Bakker",
+            "Müller",
+            "Rossi",
+            "Fischer",
+            "Dubois",
+            "Virtanen",
+            "Colombo",
+            "Eriksson",
+            "Hoffmann",
+            "Bianchi",
+            "Jensen",
+        ],
+    },
+];
+
+/// The default population mix: each pool's own weight.
+pub fn default_mix() -> Vec<f32> {
+    NAME_POOLS.iter().map(|p| p.weight).collect()
+}
+
+/// The culture whose family names include `family_name`, if any.
+pub fn culture_of(family_name: &str) -> Option<usize> {
+    NAME_POOLS
+        .iter()
+        .position(|p| p.family.contains(&family_name))
+}
+
+/// Deals cultures and names out for a batch of people.
+#[derive(Debug, Clone)]
+pub struct NameGenerator {
+    mix: Vec<f32>,
+    cultures_dealt: u32,
+    given_dealt: Vec<u32>,
+    family_dealt: Vec<u32>,
+}
+
+impl NameGenerator {
+    /// A generator for `mix`, one weight per [`NAME_POOLS`] entry; missing,
+    /// negative or non-finite weights count as zero. An all-zero mix falls
+    /// back to [`default_mix`].
+    pub fn new(mix: &[f32]) -> Self {
+        let mut mix: Vec<f32> = (0..NAME_POOLS.len())
+            .map(|c| {
+                mix.get(c)
+                    .copied()
+                    .filter(|w| w.is_finite())
+                    .unwrap_or(0.0)
+                    .max(0.0)
+            })
+            .collect();
+        if mix.iter().sum::<f32>() <= 0.0 {
+            mix = default_mix();
+        }
+        Self {
+            mix,
+            cultures_dealt: 0,
+            given_dealt: vec![0; NAME_POOLS.len()],
+            family_dealt: vec![0; NAME_POOLS.len()],
+        }
+    }
+
+    /// The next culture ID; over many calls each culture comes up in
+    /// proportion to its weight.
+    pub fn culture(&mut self) -> usize {
+        // Golden-ratio steps spread the picks evenly over the mix
+        let u = ((self.cultures_dealt as f32 + 0.5) * 0.618_034) % 1.0;
+        self.cultures_dealt += 1;
+        let mut target = u * self.mix.iter().sum::<f32>();
+        for (culture, &weight) in self.mix.iter().enumerate() {
+            if target < weight {
+                return culture;
+            }
+            target -= weight;
+        }
+        self.mix.iter().rposition(|&w| w > 0.0).unwrap_or(0)
+    }
+
+    /// The next given name from `culture`.
+    pub fn given(&mut self, culture: usize) -> &'static str {
+        let pool = &NAME_POOLS[culture % NAME_POOLS.len()];
+        let n = &mut self.given_dealt[culture % NAME_POOLS.len()];
+        *n += 1;
+        pool.given[(*n as usize - 1) % pool.given.len()]
+    }
+
+    /// The next family name from `culture`; strides through the list so
+    /// consecutive people don't share a surname with consecutive given names.
+    pub fn family(&mut self, culture: usize) -> &'static str {
+        let pool = &NAME_POOLS[culture % NAME_POOLS.len()];
+        let n = &mut self.family_dealt[culture % NAME_POOLS.len()];
+        *n += 1;
+        let k = *n as usize - 1;
+        pool.family[(k * 7 + k / pool.family.len()) % pool.family.len()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_name_pools_not_empty() {
+        let given: usize = NAME_POOLS.iter().map(|p| p.given.len()).sum();
+        let family: usize = NAME_POOLS.iter().map(|p| p.family.len()).sum();
+        assert!(given > 0, "Given names pool should not be empty");
+        assert!(family > 0, "Family names pool should not be empty");
+        assert!(given >= 20, "Should have diverse given names");
+        assert!(family >= 20, "Should have diverse family names");
+    }
+
+    #[test]
+    fn test_names_are_valid() {
+        for pool in &NAME_POOLS {
+            for name in pool.given {
+                assert!(!name.is_empty(), "Given names should not be empty");
+                assert!(
+                    name.chars().count() >= 2,
+                    "Given name '{}' should be at least 2 characters",
+                    name
+                );
+            }
+
+            for name in pool.family {
+                assert!(!name.is_empty(), "Family names should not be empty");
+                assert!(
+                    name.chars().count() >= 2,
+                    "Family name '{}' should be at least 2 characters",
+                    name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_name_generation_uniqueness() {
+        // Generate several names and check for some diversity
+        let mut generator = NameGenerator::new(&default_mix());
+        let mut names = std::collections::HashSet::new();
+
+        for _ in 0..100 {
+            let culture = generator.culture();
+            let full_name = format!("{} {}", generator.given(culture), generator.family(culture));
+            names.insert(full_name);
+        }
+
+        // Should have good variety (at least 80% unique in first 100)
+        assert!(
+            names.len() >= 80,
+            "Should generate diverse names, got {} unique out of 100",
+            names.len()
+        );
+    }
+
+    #[test]
+    fn test_name_pools_are_valid() {
+        for pool in &NAME_POOLS {
+            assert!(pool.given.len() >= 12, "{} given names", pool.culture);
+            assert!(pool.family.len() >= 12, "{} family names", pool.culture);
+            assert!(pool.weight > 0.0);
+            for name in pool.given.iter().chain(pool.family) {
+                assert!(name.chars().count() >= 2, "{name:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_cultures_follow_the_mix() {
+        let mut mix = vec![0.0; NAME_POOLS.len()];
+        mix[1] = 3.0;
+        mix[4] = 1.0;
+        let mut names = NameGenerator::new(&mix);
+        let mut counts = vec![0; NAME_POOLS.len()];
+        for _ in 0..400 {
+            counts[names.culture()] += 1;
+        }
+        assert_eq!(counts.iter().sum::<usize>(), 400);
+        assert_eq!(counts[1] + counts[4], 400);
+        assert!((290..=310).contains(&counts[1]), "{counts:?}");
+
+        // A useless mix falls back to the defaults
+        let mut fallback = NameGenerator::new(&[f32::NAN, -1.0]);
+        let picked: std::collections::HashSet<_> = (0..100).map(|_| fallback.culture()).collect();
+        assert_eq!(picked.len(), NAME_POOLS.len());
+    }
+
+    #[test]
+    fn test_names_rarely_repeat() {
+        let mut names = NameGenerator::new(&default_mix());
+        let mut seen = std::collections::HashSet::new();
+        for _ in 0..100 {
+            let culture = names.culture();
+            let given = names.given(culture);
+            let family = names.family(culture);
+            assert!(NAME_POOLS[culture].given.contains(&given));
+            assert!(NAME_POOLS[culture].family.contains(&family));
+            assert!(culture_of(family).is_some());
+            seen.insert(format!("{given} {family}"));
+        }
+        assert!(seen.len() >= 95, "{} unique of 100", seen.len());
+        assert_eq!(culture_of("Nobody"), None);
+    }
+}
@@ -0,0 +1,219 @@
+//! Navigation — the ship's burns along the voyage [`crate::mission`]
+//! plans, and how far along it the ship really is.
+//!
+//! The drive burns for the first and last [`BURN_SHARE`] of the planned
+//! voyage and the ship coasts between, at the peak velocity that covers the
+//! distance in the planned time ([`burn_plan`]). Burning takes fuel at
+//! [`BURN_FUEL_FACTOR`] times the drive's rated rate, so the fuel stocked
+//! for the voyage covers both burns. Each step the ship thrusts as hard as
+//! its engines' efficiency allows ([`step`]): accelerating up to peak,
+//! coasting, and braking once the distance left is no more than it takes
+//! to stop ([`burn_phase`]). Weak engines stretch the burns and push out
+//! the arrival ([`hours_to_arrival`]); what speed change the fuel left can
+//! still buy is the remaining delta-v ([`delta_v`]).
+
+use crate::constants::voyage_phases;
+use crate::mission::VoyageProfile;
+
+/// Hours in a year, for velocities as fractions of c (light-years a
+/// year).
+pub const HOURS_PER_YEAR: f64 = 365.25 * 24.0;
+
+/// Share of the planned voyage each burn takes.
+pub const BURN_SHARE: f64 = 0.1;
+
+/// Times the drive's rated fuel rate it burns while thrusting.
+pub const BURN_FUEL_FACTOR: f64 = 1.0 / (2.0 * BURN_SHARE);
+
+/// Distance left in light-years at which the ship counts as arrived.
+const ARRIVAL_LY: f64 = 1e-6;
+
+/// How the voyage is to be flown.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BurnPlan {
+    /// Distance to the destination in light-years.
+    pub distance_ly: f64,
+    /// Coasting velocity as a fraction of c.
+    pub peak_c: f64,
+    /// Velocity change a full-thrust hour gives, as a fraction of c.
+    pub accel_c: f64,
+    /// Fuel a full-thrust hour burns.
+    pub fuel_per_hour: f64,
+}
+
+/// The burns flying `profile` with a drive rated at `fuel_rate` fuel an
+/// hour.
+pub fn burn_plan(profile: &VoyageProfile, fuel_rate: f64) -> BurnPlan {
+    let burn_hours = (profile.duration_hours * BURN_SHARE).max(1.0);
+    let coast_years = (profile.duration_hours - burn_hours).max(1.0) / HOURS_PER_YEAR;
+    let peak_c = profile.distance_ly / coast_years;
+    BurnPlan {
+        distance_ly: profile.distance_ly,
+        peak_c,
+        accel_c: peak_c / burn_hours,
+        fuel_per_hour: fuel_rate.max(0.0) * BURN_FUEL_FACTOR,
+    }
+}
+
+/// Distance in light-years it takes to brake from `velocity_c` at full
+/// thrust.
+pub fn braking_ly(velocity_c: f64, plan: &BurnPlan) -> f64 {
+    if plan.accel_c <= 0.0 {
+        return f64::INFINITY;
+    }
+    velocity_c * velocity_c / (2.0 * plan.accel_c * HOURS_PER_YEAR)
+}
+
+/// Phase of the voyage (see `voyage_phases`) `covered_ly` along at
+/// `velocity_c`.
+pub fn burn_phase(covered_ly: f64, velocity_c: f64, plan: &BurnPlan) -> u8 {
+    let remaining = plan.distance_ly - covered_ly;
+    if remaining <= ARRIVAL_LY {
+        voyage_phases::ARRIVED
+    } else if velocity_c > 0.0 && remaining <= braking_ly(velocity_c, plan) {
+        voyage_phases::DECELERATING
+    } else if velocity_c < plan.peak_c {
+        voyage_phases::ACCELERATING
+    } else {
+        voyage_phases::CRUISING
+    }
+}
+
+/// Distance covered and velocity after `hours` in `phase` with the drive
+/// at `thrust` (0–1) of full. Braking to a stop completes the voyage.
+pub fn step(
+    covered_ly: f64,
+    velocity_c: f64,
+    phase: u8,
+    thrust: f64,
+    plan: &BurnPlan,
+    hours: f64,
+) -> (f64, f64) {
+    let dv = plan.accel_c * thrust.clamp(0.0, 1.0) * hours;
+    let next = match phase {
+        voyage_phases::ACCELERATING => (velocity_c + dv).min(plan.peak_c),
+        voyage_phases::DECELERATING => (velocity_c - dv).max(0.0),
+        voyage_phases::ARRIVED => 0.0,
+        _ => velocity_c,
+    };
+    if phase == voyage_phases::DECELERATING && next <= 0.0 {
+        // Braked to a stop: the last of the way is the approach into orbit
+        return (plan.distance_ly, 0.0);
+    }
+    let covered = covered_ly + (velocity_c + next) * 0.5 * hours / HOURS_PER_YEAR;
+    (covered.min(plan.distance_ly), next)
+}
+
+/// Whether the drive burns in `phase`.
+pub fn is_burning(phase: u8) -> bool {
+    matches!(
+        phase,
+        voyage_phases::ACCELERATING | voyage_phases::DECELERATING
+    )
+}
+
+/// Velocity change as a fraction of c that `fuel` buys at full thrust;
+/// unlimited for a drive needing none.
+pub fn delta_v(fuel: f64, plan: &BurnPlan) -> f64 {
+    if plan.fuel_per_hour <= 0.0 {
+        return f64::INFINITY;
+    }
+    fuel.max(0.0) / plan.fuel_per_hour * plan.accel_c
+}
+
+/// Hours left to arrive from `covered_ly` along at `velocity_c` in
+/// `phase`, flying the rest of the plan at full thrust.
+pub fn hours_to_arrival(covered_ly: f64, velocity_c: f64, phase: u8, plan: &BurnPlan) -> f64 {
+    let remaining = (plan.distance_ly - covered_ly).max(0.0) * HOURS_PER_YEAR;
+    if phase == voyage_phases::ARRIVED || remaining <= 0.0 {
+        return 0.0;
+    }
+    let a = plan.accel_c;
+    if a <= 0.0 {
+        return f64::INFINITY;
+    }
+    let v = velocity_c.max(0.0);
+    if phase == voyage_phases::DECELERATING {
+        return v / a;
+    }
+    let peak = plan.peak_c.max(v);
+    let up = (peak * peak - v * v) / (2.0 * a);
+    let down = peak * peak / (2.0 * a);
+    if up + down <= remaining {
+        (peak - v) / a + (remaining - up - down) / peak + peak / a
+    } else {
+        // Turning over before reaching peak
+        let top = ((2.0 * a * remaining + v * v) / 2.0).sqrt();
+        (top - v) / a + top / a
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mission::{compute_voyage, MissionConfig, PropulsionType};
+
+    fn plan() -> BurnPlan {
+        let profile = compute_voyage(&MissionConfig::default());
+        burn_plan(&profile, PropulsionType::FusionTorch.spec().fuel_rate)
+    }
+
+    #[test]
+    fn test_burn_plan() {
+        let profile = compute_voyage(&MissionConfig::default());
+        let plan = plan();
+        assert!(plan.peak_c > profile.cruise_velocity_c);
+        assert!((plan.peak_c * 0.9 - profile.cruise_velocity_c).abs() < 1e-9);
+        // Both burns at the planned rate use the fuel for the whole voyage
+        let burns = 2.0 * plan.peak_c / plan.accel_c * plan.fuel_per_hour;
+        let rated = PropulsionType::FusionTorch.spec().fuel_rate * profile.duration_hours;
+        assert!((burns / rated - 1.0).abs() < 1e-6);
+        assert_eq!(delta_v(0.0, &plan), 0.0);
+        assert!((delta_v(burns, &plan) - 2.0 * plan.peak_c).abs() < 1e-9);
+        assert!(delta_v(1.0, &burn_plan(&profile, 0.0)).is_infinite());
+    }
+
+    #[test]
+    fn test_phases() {
+        let plan = plan();
+        assert_eq!(burn_phase(0.0, 0.0, &plan), voyage_phases::ACCELERATING);
+        assert_eq!(burn_phase(1.0, plan.peak_c, &plan), voyage_phases::CRUISING);
+        let braking = braking_ly(plan.peak_c, &plan);
+        assert_eq!(
+            burn_phase(plan.distance_ly - braking * 0.5, plan.peak_c, &plan),
+            voyage_phases::DECELERATING
+        );
+        assert_eq!(
+            burn_phase(plan.distance_ly, 0.0, &plan),
+            voyage_phases::ARRIVED
+        );
+        assert!(is_burning(voyage_phases::ACCELERATING) && !is_burning(voyage_phases::CRUISING));
+    }
+
+    #[test]
+    fn test_flying_the_plan() {
+        let plan = plan();
+        let profile = compute_voyage(&MissionConfig::default());
+        let (mut covered, mut velocity, mut hours) = (0.0, 0.0, 0.0);
+        let mut phase = burn_phase(covered, velocity, &plan);
+        let planned = hours_to_arrival(covered, velocity, phase, &plan);
+        while phase != voyage_phases::ARRIVED && hours < profile.duration_hours * 1.1 {
+            (covered, velocity) = step(covered, velocity, phase, 1.0, &plan, 24.0);
+            hours += 24.0;
+            phase = burn_phase(covered, velocity, &plan);
+        }
+        assert!(
+            (hours / profile.duration_hours - 1.0).abs() < 0.02,
+            "{hours}"
+        );
+        assert!((planned / profile.duration_hours - 1.0).abs() < 0.01);
+        // Half thrust takes longer
+        let (_, slow) = step(0.0, 0.0, voyage_phases::ACCELERATING, 0.5, &plan, 10.0);
+        let (_, fast) = step(0.0, 0.0, voyage_phases::ACCELERATING, 1.0, &plan, 10.0);
+        assert!(slow < fast);
+        assert_eq!(
+            hours_to_arrival(plan.distance_ly, 0.0, voyage_phases::ARRIVED, &plan),
+            0.0
+        );
+    }
+}
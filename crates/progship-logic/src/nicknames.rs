@@ -0,0 +1,94 @@
+//! Nicknames — presentation names the crew give rooms and decks.
+//!
+//! Generated room and deck names are simulation identifiers: generation,
+//! recovery and the renderer match on them. A nickname is shown instead of
+//! the generated name but never replaces it, so a community can call the
+//! mess hall "The Galley Slave" without touching any simulation key.
+
+use crate::security::{access_levels, department_for_room};
+
+/// Longest nickname, in characters.
+pub const MAX_NICKNAME_LEN: usize = 32;
+
+/// Nickname as stored: trimmed, control characters dropped and cut to
+/// [`MAX_NICKNAME_LEN`]. `None` when nothing is left, which clears it.
+pub fn clean_nickname(name: &str) -> Option<String> {
+    let cleaned: String = name
+        .chars()
+        .filter(|c| !c.is_control())
+        .collect::<String>()
+        .trim()
+        .chars()
+        .take(MAX_NICKNAME_LEN)
+        .collect();
+    let cleaned = cleaned.trim_end().to_string();
+    (!cleaned.is_empty()).then_some(cleaned)
+}
+
+/// Whether someone with door `clearance` (see [`crate::security::clearance`])
+/// in `department` may nickname a room of `room_type`: officers may name any
+/// room, other crew only the rooms their department runs.
+pub fn may_name_room(clearance: u8, department: Option<u8>, room_type: u8) -> bool {
+    clearance >= access_levels::OFFICER
+        || (clearance >= access_levels::DEPARTMENT
+            && department.is_some()
+            && department_for_room(room_type) == department)
+}
+
+/// Whether someone with door `clearance` may nickname a deck (officers only).
+pub fn may_name_deck(clearance: u8) -> bool {
+    clearance >= access_levels::OFFICER
+}
+
+/// The name to show: the nickname if there is one, else the generated name.
+pub fn display_name<'a>(generated: &'a str, nickname: Option<&'a str>) -> &'a str {
+    nickname.unwrap_or(generated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{departments, room_types};
+
+    #[test]
+    fn test_clean_nickname() {
+        assert_eq!(clean_nickname("  The Pit\n").as_deref(), Some("The Pit"));
+        assert_eq!(clean_nickname(" \t "), None);
+        let long = "x".repeat(40);
+        assert_eq!(clean_nickname(&long).unwrap().len(), MAX_NICKNAME_LEN);
+        assert_eq!(display_name("Mess Hall 1", Some("The Pit")), "The Pit");
+        assert_eq!(display_name("Mess Hall 1", None), "Mess Hall 1");
+    }
+
+    #[test]
+    fn test_naming_permissions() {
+        let engineer = Some(departments::ENGINEERING);
+        assert!(may_name_room(
+            access_levels::DEPARTMENT,
+            engineer,
+            room_types::REACTOR
+        ));
+        assert!(!may_name_room(
+            access_levels::DEPARTMENT,
+            engineer,
+            room_types::BRIDGE
+        ));
+        assert!(!may_name_room(
+            access_levels::DEPARTMENT,
+            None,
+            room_types::MESS_HALL
+        ));
+        assert!(!may_name_room(
+            access_levels::PUBLIC,
+            None,
+            room_types::CABIN_SINGLE
+        ));
+        assert!(may_name_room(
+            access_levels::OFFICER,
+            None,
+            room_types::MESS_HALL
+        ));
+        assert!(may_name_deck(access_levels::CAPTAIN));
+        assert!(!may_name_deck(access_levels::DEPARTMENT));
+    }
+}
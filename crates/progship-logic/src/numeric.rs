@@ -0,0 +1,107 @@
+//! Numeric guards — keeping NaN and infinities out of simulation state.
+//!
+//! One NaN in a need or a gas fraction spreads to everything that reads it.
+//! The atmosphere, economy, health and utility math run their inputs and
+//! results through these guards, which swap a non-finite value for a sane
+//! default and count the substitution. The watchdog drains the count with
+//! [`take_guard_hits`] and reports it alongside the other invariants.
+//! [`roll`] is the fixed 0..1 roll the chance checks share, seeded by
+//! who is rolling and when.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static GUARD_HITS: AtomicU64 = AtomicU64::new(0);
+
+/// Non-finite values replaced since the last [`take_guard_hits`].
+pub fn guard_hits() -> u64 {
+    GUARD_HITS.load(Ordering::Relaxed)
+}
+
+/// Return and reset the number of non-finite values replaced.
+pub fn take_guard_hits() -> u64 {
+    GUARD_HITS.swap(0, Ordering::Relaxed)
+}
+
+/// `value`, or `default` (counted as a guard hit) if it isn't finite.
+pub fn finite_or(value: f32, default: f32) -> f32 {
+    if value.is_finite() {
+        value
+    } else {
+        GUARD_HITS.fetch_add(1, Ordering::Relaxed);
+        default
+    }
+}
+
+/// `value` clamped to `min..=max`, or `default` if it isn't finite.
+pub fn clamp_finite(value: f32, min: f32, max: f32, default: f32) -> f32 {
+    finite_or(value, default).clamp(min, max)
+}
+
+/// A 0..=1 fraction (need, level, efficiency), `default` if not finite.
+pub fn unit(value: f32, default: f32) -> f32 {
+    clamp_finite(value, 0.0, 1.0, default)
+}
+
+/// A time step in hours: never negative, zero if not finite.
+pub fn time_step(dt: f32) -> f32 {
+    finite_or(dt, 0.0).max(0.0)
+}
+
+/// A fixed roll in [0, 1) from the top 24 bits of `hash`.
+pub fn unit_roll(hash: u64) -> f32 {
+    (hash >> 40) as f32 / (1u64 << 24) as f32
+}
+
+/// A fixed roll in [0, 1) for `id` at `sim_time` (hours), so a chance
+/// check comes out the same however often it is asked. `salt` tells the
+/// kinds of check apart, so a person who breaks down doesn't also always
+/// slip at the same moment.
+pub fn roll(id: u64, sim_time: f64, salt: u64) -> f32 {
+    let seconds = (sim_time * 3600.0) as u64;
+    let hash = (id.wrapping_mul(0x9E37_79B9_7F4A_7C15) ^ seconds ^ salt.rotate_left(32))
+        .wrapping_mul(6364136223846793005)
+        .wrapping_add(1442695040888963407);
+    unit_roll(hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finite_or_replaces_non_finite() {
+        // Counting is covered by the watchdog tests, the only ones that reset it
+        assert_eq!(finite_or(2.5, 0.0), 2.5);
+        assert_eq!(finite_or(f32::NAN, 1.0), 1.0);
+        assert_eq!(finite_or(f32::NEG_INFINITY, 0.0), 0.0);
+        assert_eq!(clamp_finite(f32::INFINITY, 0.0, 10.0, 5.0), 5.0);
+    }
+
+    #[test]
+    fn test_unit_and_time_step() {
+        assert_eq!(unit(1.7, 0.0), 1.0);
+        assert_eq!(unit(-0.2, 0.0), 0.0);
+        assert_eq!(unit(f32::NAN, 0.5), 0.5);
+        assert_eq!(time_step(-1.0), 0.0);
+        assert_eq!(time_step(f32::INFINITY), 0.0);
+        assert_eq!(time_step(0.25), 0.25);
+    }
+
+    #[test]
+    fn test_unit_roll() {
+        assert_eq!(unit_roll(0), 0.0);
+        assert_eq!(unit_roll(1 << 63), 0.5);
+        assert_eq!(unit_roll((1 << 40) - 1), 0.0);
+        assert!(unit_roll(u64::MAX) < 1.0);
+    }
+
+    #[test]
+    fn test_roll_is_fixed_per_id_time_and_salt() {
+        let r = roll(7, 12.5, 1);
+        assert!((0.0..1.0).contains(&r));
+        assert_eq!(r, roll(7, 12.5, 1));
+        assert_ne!(r, roll(8, 12.5, 1));
+        assert_ne!(r, roll(7, 13.5, 1));
+        assert_ne!(r, roll(7, 12.5, 2));
+    }
+}
@@ -0,0 +1,148 @@
+//! Objectives — scripted goals that walk a player through a scenario.
+//!
+//! A scenario script (see [`crate::scenarios::scenario_script`]) lists
+//! [`Objective`]s in the order they are taken on. The server reports what
+//! the player does as a [`Deed`], and [`advance`] moves on to the next
+//! objective once the current one's [`Goal`] is met.
+
+use serde::{Deserialize, Serialize};
+
+/// What the player has to do to complete an objective.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Goal {
+    /// Walk into a room, of `room_type` if given.
+    EnterRoom { room_type: Option<u8> },
+    /// Reach another deck by elevator or ladder.
+    ChangeDeck,
+    /// Open or close a door.
+    ToggleDoor,
+    /// Start a conversation.
+    Talk,
+    /// Perform a context action (see `activity_types`); MAINTENANCE is a
+    /// successful repair.
+    Act { action: u8 },
+    /// Open a client screen (see `ui_screens`).
+    ViewScreen { screen: u8 },
+}
+
+/// Something the player did.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Deed {
+    EnteredRoom { room_type: u8 },
+    ChangedDeck,
+    ToggledDoor,
+    Talked,
+    Acted { action: u8 },
+    ViewedScreen { screen: u8 },
+}
+
+impl Goal {
+    /// Whether `deed` completes this goal.
+    pub fn met_by(&self, deed: Deed) -> bool {
+        match (*self, deed) {
+            (Goal::EnterRoom { room_type }, Deed::EnteredRoom { room_type: entered }) => {
+                room_type.is_none_or(|rt| rt == entered)
+            }
+            (Goal::ChangeDeck, Deed::ChangedDeck) => true,
+            (Goal::ToggleDoor, Deed::ToggledDoor) => true,
+            (Goal::Talk, Deed::Talked) => true,
+            (Goal::Act { action }, Deed::Acted { action: done }) => action == done,
+            (Goal::ViewScreen { screen }, Deed::ViewedScreen { screen: opened }) => {
+                screen == opened
+            }
+            _ => false,
+        }
+    }
+}
+
+/// One step of a scenario script.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Objective {
+    pub title: String,
+    /// How to do it, shown with the title.
+    pub hint: String,
+    pub goal: Goal,
+}
+
+/// Index of the objective in progress after `deed`: one past `current` if
+/// the deed meets its goal. `objectives.len()` means all are done.
+pub fn advance(objectives: &[Objective], current: usize, deed: Deed) -> usize {
+    match objectives.get(current) {
+        Some(objective) if objective.goal.met_by(deed) => current + 1,
+        _ => current,
+    }
+}
+
+/// HUD line for the objective in progress, e.g.
+/// `Objective 2/8: Open a door - Stand by a door and press E`.
+pub fn progress_label(objectives: &[Objective], current: usize) -> String {
+    match objectives.get(current) {
+        Some(objective) => format!(
+            "Objective {}/{}: {} - {}",
+            current + 1,
+            objectives.len(),
+            objective.title,
+            objective.hint
+        ),
+        None => format!("All {} objectives complete", objectives.len()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{activity_types, room_types, ui_screens};
+
+    fn objective(goal: Goal) -> Objective {
+        Objective {
+            title: "Title".into(),
+            hint: "Hint".into(),
+            goal,
+        }
+    }
+
+    #[test]
+    fn test_goals_match_deeds() {
+        let any_room = Goal::EnterRoom { room_type: None };
+        let mess = Goal::EnterRoom {
+            room_type: Some(room_types::MESS_HALL),
+        };
+        let corridor = Deed::EnteredRoom {
+            room_type: room_types::CORRIDOR,
+        };
+        assert!(any_room.met_by(corridor));
+        assert!(!mess.met_by(corridor));
+        assert!(mess.met_by(Deed::EnteredRoom {
+            room_type: room_types::MESS_HALL
+        }));
+        let repair = Goal::Act {
+            action: activity_types::MAINTENANCE,
+        };
+        assert!(repair.met_by(Deed::Acted {
+            action: activity_types::MAINTENANCE
+        }));
+        assert!(!repair.met_by(Deed::Acted {
+            action: activity_types::EATING
+        }));
+        assert!(!Goal::Talk.met_by(Deed::ToggledDoor));
+        assert!(Goal::ViewScreen {
+            screen: ui_screens::SHIP_OVERVIEW
+        }
+        .met_by(Deed::ViewedScreen {
+            screen: ui_screens::SHIP_OVERVIEW
+        }));
+    }
+
+    #[test]
+    fn test_advance_in_order() {
+        let script = [objective(Goal::ToggleDoor), objective(Goal::Talk)];
+        // Talking first does not skip ahead
+        assert_eq!(advance(&script, 0, Deed::Talked), 0);
+        assert_eq!(advance(&script, 0, Deed::ToggledDoor), 1);
+        assert_eq!(advance(&script, 1, Deed::Talked), 2);
+        assert_eq!(advance(&script, 2, Deed::Talked), 2);
+        assert_eq!(progress_label(&script, 1), "Objective 2/2: Title - Hint");
+        assert_eq!(progress_label(&script, 2), "All 2 objectives complete");
+    }
+}
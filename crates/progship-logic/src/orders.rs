@@ -0,0 +1,215 @@
+//! Bulk orders — officers directing a whole group of NPCs at once.
+//!
+//! An order ([`order_kinds`]) names who it is for, what they do and for how
+//! long. Each person it reaches decides whether to comply
+//! ([`compliance_chance`]); those who do set aside their utility-AI pick
+//! until the order expires or is cancelled.
+
+use crate::constants::{activity_types, command_posts, departments, order_kinds, person_traits};
+use crate::numeric::{roll, unit};
+
+/// Default time an order stays in force, in sim hours.
+pub const ORDER_HOURS: f64 = 2.0;
+
+/// Longest time an order can be given for, in sim hours.
+pub const MAX_ORDER_HOURS: f64 = 12.0;
+
+/// Who a person is, as far as an order is concerned.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Recipient {
+    /// Crew department, `None` for passengers.
+    pub department: Option<u8>,
+    pub morale: f32,
+    pub conscientiousness: f32,
+    /// Backstory and quirk bitmask (see `person_traits`).
+    pub traits: u16,
+}
+
+/// Whether an officer holding `post` for `post_department` may give an
+/// order of `kind` to `department`: the captain and XO may give any order,
+/// a department head only damage control for their own department or, for
+/// security, a sweep.
+pub fn may_issue(kind: u8, post: u8, post_department: u8, department: u8) -> bool {
+    match post {
+        command_posts::CAPTAIN | command_posts::EXECUTIVE_OFFICER => true,
+        command_posts::DEPARTMENT_HEAD => match kind {
+            order_kinds::DAMAGE_CONTROL => post_department == department,
+            order_kinds::SECURITY_SWEEP => post_department == departments::SECURITY,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Whether an order of `kind` aimed at `department` reaches `recipient`.
+pub fn addressed_to(kind: u8, department: u8, recipient: &Recipient) -> bool {
+    let Some(own) = recipient.department else {
+        return false;
+    };
+    match kind {
+        order_kinds::DAMAGE_CONTROL => own == department,
+        order_kinds::SECURITY_SWEEP => own == departments::SECURITY,
+        order_kinds::TO_QUARTERS => !is_essential(own),
+        _ => false,
+    }
+}
+
+/// Departments that keep working when non-essential crew are sent to
+/// quarters.
+pub fn is_essential(department: u8) -> bool {
+    matches!(
+        department,
+        departments::COMMAND
+            | departments::ENGINEERING
+            | departments::MEDICAL
+            | departments::SECURITY
+    )
+}
+
+/// Activity people carry out under an order of `kind`.
+pub fn order_activity(kind: u8) -> u8 {
+    match kind {
+        order_kinds::DAMAGE_CONTROL => activity_types::MAINTENANCE,
+        order_kinds::SECURITY_SWEEP => activity_types::ON_DUTY,
+        _ => activity_types::RELAXING,
+    }
+}
+
+/// Chance (0–1) that `recipient` follows an order. Happy, disciplined
+/// people nearly always do; ex-military crew are more disciplined.
+pub fn compliance_chance(recipient: &Recipient) -> f32 {
+    let mut discipline = unit(recipient.conscientiousness, 0.5);
+    if person_traits::has(recipient.traits, person_traits::EX_MILITARY) {
+        discipline = (discipline + 0.25).min(1.0);
+    }
+    (0.35 + 0.35 * unit(recipient.morale, 0.5) + 0.3 * discipline).min(1.0)
+}
+
+/// Whether `person_id` follows order `order_id`: a fixed roll per person
+/// and order against [`compliance_chance`].
+pub fn complies(order_id: u64, person_id: u64, recipient: &Recipient) -> bool {
+    roll(person_id, 0.0, order_id) < compliance_chance(recipient)
+}
+
+/// Hours an order given for `hours` stays in force: [`ORDER_HOURS`] when
+/// not positive, capped at [`MAX_ORDER_HOURS`].
+pub fn order_duration(hours: f64) -> f64 {
+    if hours.is_finite() && hours > 0.0 {
+        hours.min(MAX_ORDER_HOURS)
+    } else {
+        ORDER_HOURS
+    }
+}
+
+/// Which of `rooms` rooms a sweeper checks at `sim_time`: sweepers start
+/// spread out by `person_id` and move on to the next room every hour.
+pub fn sweep_stop(rooms: usize, person_id: u64, sim_time: f64) -> usize {
+    if rooms == 0 {
+        return 0;
+    }
+    let hour = sim_time.max(0.0) as u64;
+    (person_id.wrapping_add(hour) % rooms as u64) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn crew(department: u8, morale: f32, conscientiousness: f32) -> Recipient {
+        Recipient {
+            department: Some(department),
+            morale,
+            conscientiousness,
+            traits: 0,
+        }
+    }
+
+    #[test]
+    fn test_who_may_issue() {
+        let dc = order_kinds::DAMAGE_CONTROL;
+        let eng = departments::ENGINEERING;
+        assert!(may_issue(
+            order_kinds::TO_QUARTERS,
+            command_posts::CAPTAIN,
+            departments::COMMAND,
+            0
+        ));
+        assert!(may_issue(dc, command_posts::DEPARTMENT_HEAD, eng, eng));
+        assert!(!may_issue(
+            dc,
+            command_posts::DEPARTMENT_HEAD,
+            departments::MEDICAL,
+            eng
+        ));
+        assert!(!may_issue(
+            order_kinds::TO_QUARTERS,
+            command_posts::DEPARTMENT_HEAD,
+            eng,
+            eng
+        ));
+        assert!(may_issue(
+            order_kinds::SECURITY_SWEEP,
+            command_posts::DEPARTMENT_HEAD,
+            departments::SECURITY,
+            0
+        ));
+        assert!(!may_issue(dc, command_posts::WATCH_OFFICER, eng, eng));
+    }
+
+    #[test]
+    fn test_addressees() {
+        let engineer = crew(departments::ENGINEERING, 0.5, 0.5);
+        let scientist = crew(departments::SCIENCE, 0.5, 0.5);
+        let passenger = Recipient {
+            department: None,
+            ..engineer
+        };
+        let eng = departments::ENGINEERING;
+        assert!(addressed_to(order_kinds::DAMAGE_CONTROL, eng, &engineer));
+        assert!(!addressed_to(order_kinds::DAMAGE_CONTROL, eng, &scientist));
+        assert!(addressed_to(order_kinds::TO_QUARTERS, 0, &scientist));
+        assert!(!addressed_to(order_kinds::TO_QUARTERS, 0, &engineer));
+        assert!(!addressed_to(order_kinds::TO_QUARTERS, 0, &passenger));
+        assert!(!addressed_to(order_kinds::SECURITY_SWEEP, 0, &engineer));
+    }
+
+    #[test]
+    fn test_compliance_follows_morale_and_discipline() {
+        let sullen = crew(departments::SCIENCE, 0.0, 0.0);
+        let keen = crew(departments::SCIENCE, 1.0, 1.0);
+        assert!((compliance_chance(&sullen) - 0.35).abs() < 1e-6);
+        assert_eq!(compliance_chance(&keen), 1.0);
+        let veteran = Recipient {
+            traits: 1 << person_traits::EX_MILITARY,
+            ..sullen
+        };
+        assert!(compliance_chance(&veteran) > compliance_chance(&sullen));
+
+        // Everyone keen complies; about a third of the sullen do
+        assert!((0..200).all(|id| complies(7, id, &keen)));
+        let sullen_count = (0..1000).filter(|&id| complies(7, id, &sullen)).count();
+        assert!((250..450).contains(&sullen_count), "{sullen_count}");
+        assert_eq!(complies(7, 3, &sullen), complies(7, 3, &sullen));
+    }
+
+    #[test]
+    fn test_sweep_moves_on_hourly() {
+        assert_eq!(sweep_stop(0, 5, 1.0), 0);
+        assert_eq!(sweep_stop(4, 1, 0.5), 1);
+        assert_eq!(sweep_stop(4, 1, 1.5), 2);
+        assert_eq!(sweep_stop(4, 2, 0.5), 2);
+        assert_eq!(sweep_stop(4, 1, 3.2), 0);
+    }
+
+    #[test]
+    fn test_order_duration() {
+        assert_eq!(order_duration(0.0), ORDER_HOURS);
+        assert_eq!(order_duration(f64::NAN), ORDER_HOURS);
+        assert_eq!(order_duration(3.0), 3.0);
+        assert_eq!(order_duration(100.0), MAX_ORDER_HOURS);
+        assert_eq!(
+            order_activity(order_kinds::TO_QUARTERS),
+            activity_types::RELAXING
+        );
+    }
+}
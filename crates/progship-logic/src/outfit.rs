@@ -0,0 +1,261 @@
+//! Outfitting — the physical plant built for a [`SystemSelection`].
+//!
+//! [`outfit_ship`] turns each selected variant into a number of identical
+//! units sized to the population, each with the variant's rated output,
+//! power draw and crew. Ship generation builds one subsystem per unit, so
+//! picking a fusion reactor over a solar array, or bioregenerative life
+//! support over electrolysis, changes what is aboard and how much it makes.
+
+use crate::config::SystemSelection;
+use crate::mission::{PropulsionSpec, PropulsionType};
+use crate::systems::{
+    FoodVariant, GravityVariant, LifeSupportVariant, MedicalVariant, PowerVariant, SystemSpec,
+    WaterVariant,
+};
+
+/// Oxygen breathed per person per hour (kg).
+const O2_PER_PERSON_HOUR: f32 = 0.84 / 24.0;
+/// Water used per person per hour (L).
+const WATER_PER_PERSON_HOUR: f32 = 3.0 / 24.0;
+/// Food eaten per person per hour (kg).
+const FOOD_PER_PERSON_HOUR: f32 = 2.0 / 24.0;
+/// Share of used water the recyclers are sized to recover.
+const WATER_RECOVERY: f32 = 0.9;
+/// Headroom the power plant is sized for over the ship's total draw.
+const POWER_MARGIN: f32 = 1.25;
+/// Draw of the supporting subsystems around the plants (pumps, buses,
+/// sensors, navigation) in kW.
+pub const SUPPORT_LOAD_KW: f32 = 300.0;
+
+/// Most units of a producing plant.
+pub const MAX_UNITS: u32 = 8;
+/// Most power plant units (weak sources like solar arrays need many).
+pub const MAX_POWER_UNITS: u32 = 16;
+
+/// Identical units of one selected variant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Plant {
+    /// Variant name, e.g. "Fusion Reactor".
+    pub name: &'static str,
+    /// Room type the plant is installed in.
+    pub room_type: u8,
+    pub units: u32,
+    /// Rated output of one unit per hour, in the variant's units (kW, kg O2,
+    /// L of water, kg of food, patients).
+    pub output_per_unit: f32,
+    /// Power drawn by one unit (kW).
+    pub power_draw_per_unit: f32,
+    /// Crew needed to run one unit.
+    pub crew_per_unit: u32,
+}
+
+impl Plant {
+    /// Enough units of `spec` to meet `demand` per hour, between 1 and
+    /// `max_units`.
+    fn sized(spec: SystemSpec, demand: f32, max_units: u32) -> Self {
+        let units = if spec.output > 0.0 {
+            (demand / spec.output).ceil().clamp(1.0, max_units as f32) as u32
+        } else {
+            1
+        };
+        Self {
+            name: spec.name,
+            room_type: spec.room_type,
+            units,
+            output_per_unit: spec.output,
+            power_draw_per_unit: spec.power_draw,
+            crew_per_unit: spec.crew_needed,
+        }
+    }
+
+    /// Rated output of all units per hour.
+    pub fn total_output(&self) -> f32 {
+        self.output_per_unit * self.units as f32
+    }
+
+    /// Power drawn by all units (kW).
+    pub fn total_power_draw(&self) -> f32 {
+        self.power_draw_per_unit * self.units as f32
+    }
+}
+
+/// Everything generation builds for a mission's system choices.
+#[derive(Debug, Clone)]
+pub struct ShipOutfit {
+    pub power_variant: PowerVariant,
+    pub life_support_variant: LifeSupportVariant,
+    pub water_variant: WaterVariant,
+    pub power: Plant,
+    pub life_support: Plant,
+    pub water: Plant,
+    pub food: Plant,
+    pub medical: Plant,
+    pub gravity: Plant,
+    pub propulsion: PropulsionSpec,
+}
+
+impl ShipOutfit {
+    /// Power drawn by every plant except the power plant itself, plus the
+    /// supporting load (kW).
+    pub fn power_demand(&self) -> f32 {
+        let plants: f32 = [
+            &self.life_support,
+            &self.water,
+            &self.food,
+            &self.medical,
+            &self.gravity,
+        ]
+        .iter()
+        .map(|p| p.total_power_draw())
+        .sum();
+        plants + SUPPORT_LOAD_KW
+    }
+}
+
+fn pick<T: Copy>(all: &[T], id: u8, index: impl Fn(T) -> u8) -> T {
+    all.iter()
+        .copied()
+        .find(|v| index(*v) == id)
+        .unwrap_or(all[0])
+}
+
+/// Plant for the selected systems and `propulsion` (see
+/// [`PropulsionType`]), sized for `population` people. Unknown IDs fall back
+/// to the first variant of their category.
+pub fn outfit_ship(sel: &SystemSelection, propulsion: u8, population: u32) -> ShipOutfit {
+    let people = population as f32;
+    let power_variant = pick(PowerVariant::all(), sel.power, |v| v as u8);
+    let life_support_variant = pick(LifeSupportVariant::all(), sel.life_support, |v| v as u8);
+    let water_variant = pick(WaterVariant::all(), sel.water, |v| v as u8);
+    let food = pick(FoodVariant::all(), sel.food, |v| v as u8);
+    let medical = pick(MedicalVariant::all(), sel.medical, |v| v as u8);
+    let gravity = pick(GravityVariant::all(), sel.gravity, |v| v as u8);
+
+    let mut outfit = ShipOutfit {
+        power_variant,
+        life_support_variant,
+        water_variant,
+        power: Plant::sized(power_variant.spec(), 0.0, MAX_POWER_UNITS),
+        life_support: Plant::sized(
+            life_support_variant.spec(),
+            people * O2_PER_PERSON_HOUR,
+            MAX_UNITS,
+        ),
+        water: Plant::sized(
+            water_variant.spec(),
+            people * WATER_PER_PERSON_HOUR * WATER_RECOVERY,
+            MAX_UNITS,
+        ),
+        food: Plant::sized(food.spec(), people * FOOD_PER_PERSON_HOUR, MAX_UNITS),
+        medical: Plant::sized(medical.spec(), 0.0, 1),
+        gravity: Plant::sized(gravity.spec(), 0.0, 1),
+        propulsion: PropulsionType::from_u8(propulsion)
+            .unwrap_or(PropulsionType::FusionTorch)
+            .spec(),
+    };
+    outfit.power = Plant::sized(
+        power_variant.spec(),
+        outfit.power_demand() * POWER_MARGIN,
+        MAX_POWER_UNITS,
+    );
+    outfit
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{select_systems, SystemOverrides};
+    use crate::mission::MissionConfig;
+
+    fn selection(power: PowerVariant, life_support: LifeSupportVariant) -> SystemSelection {
+        let overrides = SystemOverrides {
+            power: Some(power as u8),
+            life_support: Some(life_support as u8),
+            ..Default::default()
+        };
+        select_systems(&MissionConfig::default(), &overrides)
+    }
+
+    #[test]
+    fn test_plants_follow_selected_variants() {
+        let sel = selection(
+            PowerVariant::FusionReactor,
+            LifeSupportVariant::BioregenerativeLSS,
+        );
+        let outfit = outfit_ship(&sel, PropulsionType::NuclearPulse as u8, 1000);
+        assert_eq!(outfit.power.name, "Fusion Reactor");
+        assert_eq!(
+            outfit.life_support_variant,
+            LifeSupportVariant::BioregenerativeLSS
+        );
+        assert_eq!(outfit.propulsion.name, "Nuclear Pulse Drive");
+        let chemical = outfit_ship(
+            &selection(
+                PowerVariant::FusionReactor,
+                LifeSupportVariant::BasicElectrolysis,
+            ),
+            PropulsionType::FusionTorch as u8,
+            1000,
+        );
+        assert_ne!(chemical.life_support.name, outfit.life_support.name);
+        assert_eq!(chemical.propulsion.name, "Fusion Torch Drive");
+    }
+
+    #[test]
+    fn test_plants_sized_to_population() {
+        let sel = selection(
+            PowerVariant::FusionReactor,
+            LifeSupportVariant::BasicElectrolysis,
+        );
+        let small = outfit_ship(&sel, 1, 100);
+        let large = outfit_ship(&sel, 1, 5000);
+        assert_eq!(small.life_support.units, 1);
+        assert!(large.life_support.units > small.life_support.units);
+        // Enough oxygen for everyone, up to the unit cap
+        assert!(large.life_support.total_output() >= 5000.0 * O2_PER_PERSON_HOUR);
+        assert!(large.food.units <= MAX_UNITS);
+    }
+
+    #[test]
+    fn test_power_plant_covers_demand() {
+        for power in [PowerVariant::FissionReactor, PowerVariant::SolarArray] {
+            let sel = selection(power, LifeSupportVariant::AdvancedMOXIE);
+            let outfit = outfit_ship(&sel, 1, 2000);
+            assert!(outfit.power.units <= MAX_POWER_UNITS);
+            if outfit.power.units < MAX_POWER_UNITS {
+                assert!(outfit.power.total_output() >= outfit.power_demand());
+            }
+        }
+        // Weak sources need more units than strong ones
+        let solar = outfit_ship(
+            &selection(PowerVariant::SolarArray, LifeSupportVariant::AdvancedMOXIE),
+            1,
+            2000,
+        );
+        let antimatter = outfit_ship(
+            &selection(
+                PowerVariant::AntimatterReactor,
+                LifeSupportVariant::AdvancedMOXIE,
+            ),
+            1,
+            2000,
+        );
+        assert!(solar.power.units > antimatter.power.units);
+    }
+
+    #[test]
+    fn test_unknown_ids_fall_back() {
+        let sel = SystemSelection {
+            power: 99,
+            life_support: 99,
+            food: 99,
+            water: 99,
+            defense: 99,
+            medical: 99,
+            gravity: 99,
+        };
+        let outfit = outfit_ship(&sel, 99, 10);
+        assert_eq!(outfit.power_variant, PowerVariant::FissionReactor);
+        assert_eq!(outfit.propulsion.name, "Fusion Torch Drive");
+    }
+}
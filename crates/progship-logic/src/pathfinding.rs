@@ -1,9 +1,30 @@
 //! Pure pathfinding over the door connectivity graph.
 //!
-//! `NavGraph` holds a pre-built adjacency list from door data and provides
-//! BFS pathfinding with an optional LRU-style cache.
-
-use std::collections::{HashMap, HashSet, VecDeque};
+//! `NavGraph` holds a pre-built adjacency list from door data and finds the
+//! quickest path with Dijkstra's algorithm, with an optional LRU-style cache.
+//! Each step costs the seconds to walk door to door across a room, slowed by
+//! its [`RoomWeight`] (narrow corridors, crawlways, crowds), plus any wait
+//! for a shaft. [`NavGraph::flow_field`] routes every room to its nearest
+//! goal at once, for mass movements like evacuations that would otherwise
+//! need a search per person. [`transit_waits`] prices the vertical legs of a
+//! path (waiting for an elevator car, riding or climbing between decks).
+
+use crate::constants::room_types;
+use crate::genlib::crawlways::walk_speed_factor;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+/// Walking speed (m/s) path costs assume, matching NPC movement.
+pub const NAV_WALK_SPEED: f32 = 5.0;
+/// Corridor width (m) people walk at full speed in; narrower is slower.
+pub const FULL_SPEED_CORRIDOR_WIDTH: f32 = 4.0;
+/// Extra crossing time per person per m² of floor.
+pub const CONGESTION_SLOWDOWN: f32 = 4.0;
+/// Slowest a crowd makes a room, as a multiple of the empty crossing time.
+pub const MAX_CONGESTION: f32 = 5.0;
+/// Seconds added for entering a room a flow field avoids, so routes only
+/// pass through it when there's no other way.
+pub const AVOID_PENALTY_SECONDS: f32 = 600.0;
 
 /// A door edge in the navigation graph.
 #[derive(Debug, Clone, Copy)]
@@ -22,10 +43,55 @@ pub struct Waypoint {
     pub room_id: u32,
 }
 
-/// Pre-built navigation graph with BFS pathfinding and path cache.
+/// How hard a room is to cross, for weighting paths through it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoomWeight {
+    /// Walking speed relative to an open corridor.
+    pub speed_factor: f32,
+    /// Crowding slowdown, 1.0 when empty.
+    pub congestion: f32,
+    /// Shaft room type (`room_types::*_SHAFT`) if the room is a shaft.
+    pub shaft_type: Option<u8>,
+}
+
+impl Default for RoomWeight {
+    fn default() -> Self {
+        Self {
+            speed_factor: 1.0,
+            congestion: 1.0,
+            shaft_type: None,
+        }
+    }
+}
+
+/// Weight of a `width`×`height` room of `room_type` holding `occupants`.
+/// Crawlways and corridors narrower than [`FULL_SPEED_CORRIDOR_WIDTH`] are
+/// slower; crowds slow everything.
+pub fn room_weight(room_type: u8, width: f32, height: f32, occupants: u32) -> RoomWeight {
+    let mut speed_factor = walk_speed_factor(room_type);
+    if room_types::is_plain_corridor(room_type) {
+        let lane = width.min(height).max(0.0);
+        speed_factor *= (lane / FULL_SPEED_CORRIDOR_WIDTH).clamp(0.5, 1.0);
+    }
+    let area = (width * height).max(1.0);
+    let congestion = (1.0 + CONGESTION_SLOWDOWN * occupants as f32 / area).min(MAX_CONGESTION);
+    RoomWeight {
+        speed_factor,
+        congestion: if congestion.is_finite() {
+            congestion
+        } else {
+            1.0
+        },
+        shaft_type: room_types::is_shaft(room_type).then_some(room_type),
+    }
+}
+
+/// Pre-built navigation graph with weighted pathfinding and path cache.
 pub struct NavGraph {
     /// room_id → list of (neighbor_room_id, door_x, door_y)
     adj: HashMap<u32, Vec<(u32, f32, f32)>>,
+    /// room_id → weight; rooms without one are open corridor.
+    weights: HashMap<u32, RoomWeight>,
     /// (from, to) → cached path. Simple bounded cache.
     cache: HashMap<(u32, u32), Vec<Waypoint>>,
     cache_capacity: usize,
@@ -50,12 +116,19 @@ impl NavGraph {
         }
         Self {
             adj,
+            weights: HashMap::new(),
             cache: HashMap::new(),
             cache_capacity,
         }
     }
 
-    /// Find a path from `from_room` to `to_room` via BFS.
+    /// Set how hard each room is to cross. Clears the path cache.
+    pub fn set_room_weights(&mut self, weights: HashMap<u32, RoomWeight>) {
+        self.weights = weights;
+        self.cache.clear();
+    }
+
+    /// Find the quickest path from `from_room` to `to_room`.
     ///
     /// Returns a list of waypoints (door positions + room entered).
     /// Returns empty vec if same room. Returns `None` if unreachable.
@@ -70,8 +143,7 @@ impl NavGraph {
             return Some(cached.clone());
         }
 
-        // BFS
-        let result = self.bfs(from_room, to_room);
+        let result = self.dijkstra(from_room, to_room);
 
         // Cache result if found
         if let Some(ref path) = result {
@@ -112,38 +184,272 @@ impl NavGraph {
         self.cache.len()
     }
 
-    fn bfs(&self, from_room: u32, to_room: u32) -> Option<Vec<Waypoint>> {
-        let mut visited = HashSet::new();
-        let mut queue: VecDeque<(u32, Vec<Waypoint>)> = VecDeque::new();
-        visited.insert(from_room);
-        queue.push_back((from_room, vec![]));
+    fn weight(&self, room_id: u32) -> RoomWeight {
+        self.weights.get(&room_id).copied().unwrap_or_default()
+    }
 
-        while let Some((current, path)) = queue.pop_front() {
-            if let Some(neighbors) = self.adj.get(&current) {
-                for &(next_room, door_x, door_y) in neighbors {
-                    if next_room == to_room {
-                        let mut result = path.clone();
-                        result.push(Waypoint {
-                            door_x,
-                            door_y,
-                            room_id: next_room,
-                        });
-                        return Some(result);
-                    }
-                    if visited.insert(next_room) {
-                        let mut new_path = path.clone();
-                        new_path.push(Waypoint {
+    /// Seconds to cross `room` between the door at `entry` (or anywhere, if
+    /// none) and the door at `door`.
+    fn walk_cost(&self, room: u32, entry: Option<(f32, f32)>, door: (f32, f32)) -> f32 {
+        let here = self.weight(room);
+        entry.map_or(0.0, |(x, y)| {
+            let dist = ((door.0 - x).powi(2) + (door.1 - y).powi(2)).sqrt();
+            dist * here.congestion / (NAV_WALK_SPEED * here.speed_factor.max(0.1))
+        })
+    }
+
+    /// Shaft wait for stepping from `from` into `into`.
+    fn exit_wait(&self, from: u32, into: u32) -> f32 {
+        match self.weight(into).shaft_type {
+            Some(shaft) => transit_wait(self.weight(from).shaft_type, shaft, 1),
+            None => 0.0,
+        }
+    }
+
+    fn dijkstra(&self, from_room: u32, to_room: u32) -> Option<Vec<Waypoint>> {
+        let mut best: HashMap<u32, f32> = HashMap::new();
+        // room → (room it was entered from, door used)
+        let mut came_from: HashMap<u32, (u32, f32, f32)> = HashMap::new();
+        let mut frontier = BinaryHeap::new();
+        best.insert(from_room, 0.0);
+        frontier.push(Frontier {
+            cost: 0.0,
+            room: from_room,
+            entry: None,
+        });
+
+        while let Some(Frontier { cost, room, entry }) = frontier.pop() {
+            if room == to_room {
+                let mut path = Vec::new();
+                let mut current = to_room;
+                while let Some(&(prev, door_x, door_y)) = came_from.get(&current) {
+                    path.push(Waypoint {
+                        door_x,
+                        door_y,
+                        room_id: current,
+                    });
+                    current = prev;
+                }
+                path.reverse();
+                return Some(path);
+            }
+            if cost > best[&room] {
+                continue;
+            }
+            for &(next_room, door_x, door_y) in self.neighbors(room) {
+                let next_cost = cost
+                    + self.walk_cost(room, entry, (door_x, door_y))
+                    + self.exit_wait(room, next_room);
+                if best.get(&next_room).is_none_or(|&c| next_cost < c) {
+                    best.insert(next_room, next_cost);
+                    came_from.insert(next_room, (room, door_x, door_y));
+                    frontier.push(Frontier {
+                        cost: next_cost,
+                        room: next_room,
+                        entry: Some((door_x, door_y)),
+                    });
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Route every room that can reach one of `goals` to the quickest of
+    /// them. Rooms in `avoid` are only crossed when there's no other way
+    /// out; a route can always start in one.
+    pub fn flow_field(&self, goals: &[u32], avoid: &HashSet<u32>) -> FlowField {
+        let mut steps: HashMap<u32, FlowStep> = HashMap::new();
+        let mut best: HashMap<u32, f32> = HashMap::new();
+        let mut goal_of: HashMap<u32, u32> = HashMap::new();
+        let mut frontier = BinaryHeap::new();
+        for &goal in goals {
+            best.insert(goal, 0.0);
+            goal_of.insert(goal, goal);
+            frontier.push(Frontier {
+                cost: 0.0,
+                room: goal,
+                entry: None,
+            });
+        }
+
+        // Expand outward from the goals; `entry` is where each room's route
+        // leaves it
+        while let Some(Frontier { cost, room, entry }) = frontier.pop() {
+            if cost > best[&room] {
+                continue;
+            }
+            let goal = goal_of[&room];
+            let penalty = if avoid.contains(&room) {
+                AVOID_PENALTY_SECONDS
+            } else {
+                0.0
+            };
+            for &(prev_room, door_x, door_y) in self.neighbors(room) {
+                let prev_cost = cost
+                    + self.walk_cost(room, entry, (door_x, door_y))
+                    + self.exit_wait(prev_room, room)
+                    + penalty;
+                if best.get(&prev_room).is_none_or(|&c| prev_cost < c) {
+                    best.insert(prev_room, prev_cost);
+                    goal_of.insert(prev_room, goal);
+                    steps.insert(
+                        prev_room,
+                        FlowStep {
+                            next_room: room,
                             door_x,
                             door_y,
-                            room_id: next_room,
-                        });
-                        queue.push_back((next_room, new_path));
-                    }
+                            goal,
+                            seconds: prev_cost,
+                        },
+                    );
+                    frontier.push(Frontier {
+                        cost: prev_cost,
+                        room: prev_room,
+                        entry: Some((door_x, door_y)),
+                    });
                 }
             }
         }
+        FlowField { steps }
+    }
+}
+
+/// One room's step toward its goal in a [`FlowField`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlowStep {
+    /// Room to walk into next (the goal itself on the last step).
+    pub next_room: u32,
+    pub door_x: f32,
+    pub door_y: f32,
+    /// Goal room the route ends in.
+    pub goal: u32,
+    /// Seconds from here to the goal.
+    pub seconds: f32,
+}
+
+/// Precomputed routes from rooms to their nearest goal, followed without
+/// searching. Goal rooms have no step.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FlowField {
+    steps: HashMap<u32, FlowStep>,
+}
+
+impl FlowField {
+    /// Rebuild a field from stored `(room, step)` pairs.
+    pub fn from_steps(steps: impl IntoIterator<Item = (u32, FlowStep)>) -> Self {
+        Self {
+            steps: steps.into_iter().collect(),
+        }
+    }
 
-        None
+    /// The next step out of `room`, if it has a route and isn't a goal.
+    pub fn step(&self, room: u32) -> Option<&FlowStep> {
+        self.steps.get(&room)
+    }
+
+    /// Every routed room and its step.
+    pub fn steps(&self) -> impl Iterator<Item = (u32, &FlowStep)> {
+        self.steps.iter().map(|(&room, step)| (room, step))
+    }
+
+    /// Waypoints from `room` to its goal; empty for a goal or a room with no
+    /// route.
+    pub fn path_from(&self, room: u32) -> Vec<Waypoint> {
+        let mut path = Vec::new();
+        let mut current = room;
+        while let Some(step) = self.steps.get(&current) {
+            path.push(Waypoint {
+                door_x: step.door_x,
+                door_y: step.door_y,
+                room_id: step.next_room,
+            });
+            current = step.next_room;
+            if path.len() > self.steps.len() {
+                return Vec::new(); // corrupt field with a cycle
+            }
+        }
+        path
+    }
+}
+
+/// A room waiting in the Dijkstra frontier; the heap pops the cheapest.
+#[derive(Debug, PartialEq)]
+struct Frontier {
+    cost: f32,
+    room: u32,
+    entry: Option<(f32, f32)>,
+}
+
+impl Eq for Frontier {}
+
+impl Ord for Frontier {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .cost
+            .total_cmp(&self.cost)
+            .then_with(|| other.room.cmp(&self.room))
+    }
+}
+
+impl PartialOrd for Frontier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Seconds spent waiting for an elevator car after stepping into the shaft.
+pub const ELEVATOR_CALL_WAIT: f32 = 8.0;
+/// Call wait for the slower freight car in a service elevator.
+pub const SERVICE_ELEVATOR_CALL_WAIT: f32 = 12.0;
+/// Seconds an elevator car takes per deck travelled.
+pub const ELEVATOR_SECONDS_PER_DECK: f32 = 3.0;
+/// Seconds a service elevator car takes per deck travelled.
+pub const SERVICE_ELEVATOR_SECONDS_PER_DECK: f32 = 4.0;
+/// Seconds to climb a ladder one deck.
+pub const LADDER_SECONDS_PER_DECK: f32 = 6.0;
+
+/// One room along a path, as seen by [`transit_waits`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransitStep {
+    /// Shaft room type (`room_types::*_SHAFT`) if this room is a shaft.
+    pub shaft_type: Option<u8>,
+    pub deck: i32,
+}
+
+/// Seconds to pause on entering each room of a path.
+///
+/// Boarding a shaft from a deck costs the call wait; each shaft-to-shaft hop
+/// costs the per-deck travel time. Walking rooms cost nothing here.
+pub fn transit_waits(steps: &[TransitStep]) -> Vec<f32> {
+    let mut waits = vec![0.0; steps.len()];
+    for i in 1..steps.len() {
+        let (prev, step) = (steps[i - 1], steps[i]);
+        if let Some(shaft) = step.shaft_type {
+            waits[i] = transit_wait(prev.shaft_type, shaft, prev.deck.abs_diff(step.deck));
+        }
+    }
+    waits
+}
+
+/// Seconds to enter a `shaft` from a room of shaft type `from_shaft`: the
+/// call wait when boarding from a deck, or the ride or climb across `decks`
+/// from the shaft on another deck.
+pub fn transit_wait(from_shaft: Option<u8>, shaft: u8, decks: u32) -> f32 {
+    match from_shaft {
+        Some(_) => {
+            let per_deck = match shaft {
+                room_types::LADDER_SHAFT => LADDER_SECONDS_PER_DECK,
+                room_types::SERVICE_ELEVATOR_SHAFT => SERVICE_ELEVATOR_SECONDS_PER_DECK,
+                _ => ELEVATOR_SECONDS_PER_DECK,
+            };
+            per_deck * decks as f32
+        }
+        None => match shaft {
+            room_types::LADDER_SHAFT => 0.0,
+            room_types::SERVICE_ELEVATOR_SHAFT => SERVICE_ELEVATOR_CALL_WAIT,
+            _ => ELEVATOR_CALL_WAIT,
+        },
     }
 }
 
@@ -365,4 +671,124 @@ mod tests {
         assert_eq!(path.len(), 3);
         assert_eq!(path[2].room_id, 4);
     }
+
+    fn door(room_a: u32, room_b: u32, door_x: f32, door_y: f32) -> DoorEdge {
+        DoorEdge {
+            room_a,
+            room_b,
+            door_x,
+            door_y,
+        }
+    }
+
+    /// Start 1 and end 4 joined by two equally long routes, through 2 or 3.
+    fn diamond() -> NavGraph {
+        NavGraph::from_doors(&[
+            door(1, 2, 0.0, 0.0),
+            door(2, 4, 10.0, 0.0),
+            door(1, 3, 0.0, 1.0),
+            door(3, 4, 10.0, 1.0),
+        ])
+    }
+
+    #[test]
+    fn test_weighted_path_avoids_crowds_and_crawlways() {
+        let mut graph = diamond();
+        assert_eq!(graph.find_path(1, 4).unwrap()[0].room_id, 2);
+
+        let crowded = room_weight(room_types::CORRIDOR, 4.0, 10.0, 40);
+        assert!(crowded.congestion > 1.0);
+        graph.set_room_weights(HashMap::from([(2, crowded)]));
+        assert_eq!(graph.find_path(1, 4).unwrap()[0].room_id, 3);
+
+        let crawlway = room_weight(room_types::CRAWLWAY, 1.0, 10.0, 0);
+        graph.set_room_weights(HashMap::from([(2, crawlway)]));
+        assert_eq!(graph.find_path(1, 4).unwrap()[0].room_id, 3);
+    }
+
+    #[test]
+    fn test_weighted_path_prefers_wide_corridors() {
+        let narrow = room_weight(room_types::CORRIDOR, 2.0, 30.0, 0);
+        let wide = room_weight(room_types::CORRIDOR, 6.0, 30.0, 0);
+        assert!(narrow.speed_factor < wide.speed_factor);
+        assert_eq!(wide, RoomWeight::default());
+
+        let mut graph = diamond();
+        graph.set_room_weights(HashMap::from([(2, narrow), (3, wide)]));
+        assert_eq!(graph.find_path(1, 4).unwrap()[0].room_id, 3);
+    }
+
+    #[test]
+    fn test_weighted_path_prices_elevator_waits() {
+        // Corridor 1 has a ladder (10..12) and an elevator (20..22) to corridor 2
+        let elevator = room_weight(room_types::ELEVATOR_SHAFT, 3.0, 3.0, 0);
+        let ladder = room_weight(room_types::LADDER_SHAFT, 2.0, 2.0, 0);
+        let shafts = |decks: u32| {
+            let mut doors = vec![door(1, 10, 0.0, 0.0), door(1, 20, 0.0, 0.0)];
+            let mut weights = HashMap::new();
+            for d in 0..decks {
+                doors.push(door(10 + d, 11 + d, 0.0, 0.0));
+                doors.push(door(20 + d, 21 + d, 0.0, 0.0));
+            }
+            doors.push(door(10 + decks, 2, 0.0, 0.0));
+            doors.push(door(20 + decks, 2, 0.0, 0.0));
+            for d in 0..=decks {
+                weights.insert(10 + d, ladder);
+                weights.insert(20 + d, elevator);
+            }
+            let mut graph = NavGraph::from_doors(&doors);
+            graph.set_room_weights(weights);
+            graph.find_path(1, 2).unwrap()[0].room_id
+        };
+        // One deck: climbing beats waiting for the car; far decks: ride it
+        assert_eq!(shafts(1), 10);
+        assert_eq!(shafts(3), 20);
+    }
+
+    fn step(shaft_type: Option<u8>, deck: i32) -> TransitStep {
+        TransitStep { shaft_type, deck }
+    }
+
+    #[test]
+    fn test_transit_waits_elevator_ride() {
+        let elevator = Some(room_types::ELEVATOR_SHAFT);
+        // Corridor → shaft D0 → shaft D1 → shaft D2 → corridor
+        let waits = transit_waits(&[
+            step(None, 0),
+            step(elevator, 0),
+            step(elevator, 1),
+            step(elevator, 2),
+            step(None, 2),
+        ]);
+        assert_eq!(
+            waits,
+            vec![
+                0.0,
+                ELEVATOR_CALL_WAIT,
+                ELEVATOR_SECONDS_PER_DECK,
+                ELEVATOR_SECONDS_PER_DECK,
+                0.0
+            ]
+        );
+    }
+
+    #[test]
+    fn test_transit_waits_ladder_has_no_call_wait() {
+        let ladder = Some(room_types::LADDER_SHAFT);
+        let waits = transit_waits(&[step(None, 3), step(ladder, 3), step(ladder, 1)]);
+        assert_eq!(waits, vec![0.0, 0.0, 2.0 * LADDER_SECONDS_PER_DECK]);
+    }
+
+    #[test]
+    fn test_transit_waits_same_deck() {
+        assert!(transit_waits(&[step(None, 0), step(None, 0)])
+            .iter()
+            .all(|&w| w == 0.0));
+        assert!(transit_waits(&[]).is_empty());
+        let service = Some(room_types::SERVICE_ELEVATOR_SHAFT);
+        assert_eq!(
+            transit_waits(&[step(None, 0), step(service, 0)]),
+            vec![0.0, SERVICE_ELEVATOR_CALL_WAIT]
+        );
+    }
 }
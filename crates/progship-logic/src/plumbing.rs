@@ -0,0 +1,162 @@
+//! Plumbing — water from the recyclers out to the rooms that use it.
+//!
+//! Wet rooms ([`water_demand`]) draw on the WATER_PIPE infra edges, solved
+//! like the power grid (see [`crate::power_grid::solve`]) with the
+//! recyclers as sources. A worn pipe leaks part of what it carries
+//! ([`leak_share`]) and may burst ([`bursts`]); one running through a
+//! frosted room freezes solid ([`freezes`]) and carries nothing until it
+//! thaws. A room left dry offers no washing and only cold meals
+//! ([`dry_rates`], [`dry_action_effect`]) until the pipe is repaired.
+
+use crate::actions::ActionEffect;
+use crate::balance::{NeedRates, IDLE_RATES};
+use crate::constants::{activity_types, power_priorities, room_types};
+use crate::numeric::roll;
+
+/// Pipe health below which a pipe leaks and gets a repair task.
+pub const LEAK_HEALTH: f32 = 0.5;
+
+/// Frost intensity at which a pipe through the room freezes.
+pub const FREEZE_FROST: f32 = 0.5;
+
+/// Pipe health lost in a burst.
+pub const BURST_DAMAGE: f32 = 0.35;
+
+/// Share of its usual hunger relief a meal gives in a dry room.
+pub const DRY_MEAL_SHARE: f32 = 0.5;
+
+/// Water a room of `room_type` draws per hour and the priority it is cut
+/// off at (see `power_priorities`), or `None` for rooms without taps.
+pub fn water_demand(room_type: u8) -> Option<(f32, u8)> {
+    let demand = match room_type {
+        room_types::HOSPITAL_WARD | room_types::SURGERY | room_types::MEDBAY => {
+            (1.0, power_priorities::CRITICAL)
+        }
+        room_types::GALLEY => (2.0, power_priorities::HIGH),
+        room_types::BAKERY => (1.0, power_priorities::HIGH),
+        room_types::MESS_HALL | room_types::WARDROOM | room_types::CAFE => {
+            (0.5, power_priorities::HIGH)
+        }
+        room_types::SHARED_BATHROOM => (2.0, power_priorities::NORMAL),
+        room_types::HYDROPONICS => (3.0, power_priorities::NORMAL),
+        room_types::SHARED_LAUNDRY => (1.0, power_priorities::LOW),
+        room_types::POOL => (1.0, power_priorities::LOW),
+        _ => return None,
+    };
+    Some(demand)
+}
+
+/// Share of the water a pipe at `health` carries that it leaks.
+pub fn leak_share(health: f32) -> f32 {
+    (1.0 - health / LEAK_HEALTH).clamp(0.0, 1.0) * 0.5
+}
+
+/// Whether a pipe through a room with frost of `intensity` is frozen.
+pub fn freezes(frost: f32) -> bool {
+    frost >= FREEZE_FROST
+}
+
+/// Water a pipe of rated `capacity` can carry at `health`.
+pub fn pipe_capacity(capacity: f32, health: f32, frozen: bool) -> f32 {
+    if frozen {
+        0.0
+    } else {
+        capacity * health.clamp(0.0, 1.0)
+    }
+}
+
+/// Chance per hour that a pipe bursts: worn pipes go more often, frozen
+/// ones far more.
+pub fn burst_chance(health: f32, frozen: bool) -> f32 {
+    let wear = 0.002 * (1.0 - health.clamp(0.0, 1.0));
+    if frozen {
+        wear + 0.05
+    } else {
+        wear
+    }
+}
+
+/// Whether pipe `edge_id` bursts in the hour ending at `sim_time` given
+/// `chance`: a fixed roll per pipe and hour.
+pub fn bursts(edge_id: u64, sim_time: f64, chance: f32) -> bool {
+    roll(edge_id, sim_time.floor(), 0xb125) < chance
+}
+
+/// Need rates of someone in a dry room: washing does nothing and meals
+/// only go half as far.
+pub fn dry_rates(rates: NeedRates, activity_type: Option<u8>) -> NeedRates {
+    let (mut hunger, fatigue, social, comfort, mut hygiene) = rates;
+    match activity_type {
+        Some(activity_types::HYGIENE) => hygiene = IDLE_RATES.4,
+        Some(activity_types::EATING) if hunger < 0.0 => hunger *= DRY_MEAL_SHARE,
+        _ => {}
+    }
+    (hunger, fatigue, social, comfort, hygiene)
+}
+
+/// A player action's effect in a dry room, or `None` if it needs water.
+pub fn dry_action_effect(effect: ActionEffect) -> Option<ActionEffect> {
+    match effect.activity_type {
+        activity_types::HYGIENE => None,
+        activity_types::EATING => Some(ActionEffect {
+            hunger_delta: effect.hunger_delta * DRY_MEAL_SHARE,
+            ..effect
+        }),
+        _ => Some(effect),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actions::compute_action_effect;
+
+    #[test]
+    fn test_wet_rooms() {
+        let (_, medical) = water_demand(room_types::HOSPITAL_WARD).unwrap();
+        let (_, laundry) = water_demand(room_types::SHARED_LAUNDRY).unwrap();
+        assert!(medical < laundry, "laundry is cut off first");
+        assert!(water_demand(room_types::SHARED_BATHROOM).is_some());
+        assert_eq!(water_demand(room_types::BRIDGE), None);
+        assert_eq!(water_demand(room_types::CORRIDOR), None);
+    }
+
+    #[test]
+    fn test_worn_and_frozen_pipes() {
+        assert_eq!(leak_share(1.0), 0.0);
+        assert_eq!(leak_share(LEAK_HEALTH), 0.0);
+        assert!((leak_share(0.25) - 0.25).abs() < 1e-6);
+        assert_eq!(leak_share(0.0), 0.5);
+        assert!(!freezes(0.3));
+        assert!(freezes(0.6));
+        assert_eq!(pipe_capacity(10.0, 0.8, false), 8.0);
+        assert_eq!(pipe_capacity(10.0, 1.0, true), 0.0);
+        assert!(burst_chance(0.2, false) > burst_chance(0.9, false));
+        assert!(burst_chance(1.0, true) > burst_chance(0.0, false));
+    }
+
+    #[test]
+    fn test_bursts() {
+        assert!(!bursts(1, 5.0, 0.0));
+        assert!(bursts(1, 5.0, 1.0));
+        let burst = (0..1000).filter(|&id| bursts(id, 12.0, 0.2)).count();
+        assert!((120..280).contains(&burst), "{burst}");
+    }
+
+    #[test]
+    fn test_dry_rooms() {
+        let washing = (0.02, 0.01, 0.0, -0.03, -0.3);
+        let dry = dry_rates(washing, Some(activity_types::HYGIENE));
+        assert_eq!(dry.4, IDLE_RATES.4);
+        let eating = (-0.3, 0.01, -0.05, -0.02, 0.02);
+        let dry = dry_rates(eating, Some(activity_types::EATING));
+        assert!((dry.0 + 0.15).abs() < 1e-6);
+        assert_eq!(dry_rates(eating, Some(activity_types::SLEEPING)), eating);
+
+        let wash = compute_action_effect(6, room_types::SHARED_BATHROOM).unwrap();
+        assert_eq!(dry_action_effect(wash), None);
+        let meal = compute_action_effect(2, room_types::MESS_HALL).unwrap();
+        let cold = dry_action_effect(meal.clone()).unwrap();
+        assert!((cold.hunger_delta - meal.hunger_delta * DRY_MEAL_SHARE).abs() < 1e-6);
+    }
+}
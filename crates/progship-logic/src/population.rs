@@ -9,8 +9,9 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::command::{HEADED_DEPARTMENTS, WATCHES};
 use crate::config::{total_system_crew, SystemSelection};
-use crate::constants::departments;
+use crate::constants::{departments, ranks};
 use crate::mission::{compute_voyage, MissionConfig};
 
 /// Population breakdown for the ship.
@@ -143,6 +144,65 @@ pub fn compute_crew(system_crew: u32, departure_pop: u32, budget_class: u8) -> D
     }
 }
 
+/// Share of the crew below the command posts holding each rank, from
+/// lieutenant down; crewmen make up the rest.
+const RANK_SHARES: [(u8, f32); 5] = [
+    (ranks::LIEUTENANT, 0.05),
+    (ranks::ENSIGN, 0.08),
+    (ranks::CHIEF, 0.05),
+    (ranks::PETTY, 0.12),
+    (ranks::SPECIALIST, 0.25),
+];
+
+/// Crew count per rank (indexed by rank ID) for a crew of `total_crew`.
+///
+/// The command chain is staffed first — a captain, commanders for the
+/// executive officer and each department head, and a lieutenant per watch
+/// (see `command`) — and the remaining crew split by [`RANK_SHARES`].
+/// Counts always add up to `total_crew`.
+pub fn rank_distribution(total_crew: u32) -> [u32; 8] {
+    fn staff(counts: &mut [u32; 8], remaining: &mut u32, rank: u8, n: u32) {
+        let n = n.min(*remaining);
+        counts[rank as usize] += n;
+        *remaining -= n;
+    }
+    let mut counts = [0u32; 8];
+    let mut remaining = total_crew;
+    let commanders = 1 + HEADED_DEPARTMENTS.len() as u32;
+    staff(&mut counts, &mut remaining, ranks::CAPTAIN, 1);
+    staff(&mut counts, &mut remaining, ranks::COMMANDER, commanders);
+    staff(
+        &mut counts,
+        &mut remaining,
+        ranks::LIEUTENANT,
+        WATCHES.len() as u32,
+    );
+
+    let pool = remaining as f32;
+    for (rank, share) in RANK_SHARES {
+        staff(
+            &mut counts,
+            &mut remaining,
+            rank,
+            (pool * share).round() as u32,
+        );
+    }
+    let crewmen = remaining;
+    staff(&mut counts, &mut remaining, ranks::CREWMAN, crewmen);
+    counts
+}
+
+/// Rank of every crew member, highest first, following
+/// [`rank_distribution`]. The first entries line up with the posts of
+/// [`plan_command_chain`](crate::command::plan_command_chain).
+pub fn crew_ranks(total_crew: u32) -> Vec<u8> {
+    let counts = rank_distribution(total_crew);
+    (0..counts.len() as u8)
+        .rev()
+        .flat_map(|rank| std::iter::repeat_n(rank, counts[rank as usize] as usize))
+        .collect()
+}
+
 /// Full population sizing from mission config and system selection.
 pub fn compute_population(config: &MissionConfig, systems: &SystemSelection) -> PopulationProfile {
     let voyage = compute_voyage(config);
@@ -281,4 +341,28 @@ mod tests {
         assert_eq!(dept.by_department(departments::MEDICAL), dept.medical);
         assert_eq!(dept.by_department(99), 0); // Unknown dept
     }
+
+    #[test]
+    fn test_rank_distribution_staffs_command_first() {
+        let counts = rank_distribution(200);
+        assert_eq!(counts.iter().sum::<u32>(), 200);
+        assert_eq!(counts[ranks::CAPTAIN as usize], 1);
+        assert_eq!(counts[ranks::COMMANDER as usize], 6);
+        assert!(counts[ranks::CREWMAN as usize] > counts[ranks::LIEUTENANT as usize]);
+
+        // A tiny crew is all officers, and still adds up
+        let small = rank_distribution(4);
+        assert_eq!(small.iter().sum::<u32>(), 4);
+        assert_eq!(small[ranks::CAPTAIN as usize], 1);
+        assert_eq!(small[ranks::COMMANDER as usize], 3);
+        assert_eq!(rank_distribution(0), [0; 8]);
+    }
+
+    #[test]
+    fn test_crew_ranks_highest_first() {
+        let ranks_list = crew_ranks(50);
+        assert_eq!(ranks_list.len(), 50);
+        assert_eq!(ranks_list[0], ranks::CAPTAIN);
+        assert!(ranks_list.windows(2).all(|w| w[0] >= w[1]));
+    }
 }
@@ -0,0 +1,186 @@
+//! Power grid — load flow over the ship's power cables.
+//!
+//! Generation enters the grid at the reactor nodes and flows out along the
+//! POWER infra edges. Loads are served in priority order (see
+//! `power_priorities`) while generation lasts and every cable on the way
+//! has headroom; the rest are shed until the next tick. Loads at nodes no
+//! cable reaches sit on the main bus and only compete for generation.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::constants::{power_priorities, room_types};
+
+/// Lighting and hotel load of a room, in kW per m² of floor.
+pub const LIGHTING_KW_PER_M2: f32 = 0.001;
+
+/// A power cable from `from` to `to` that carries up to `capacity` kW.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Line {
+    pub from: u64,
+    pub to: u64,
+    pub capacity: f32,
+}
+
+/// Power wanted at a graph node.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Load {
+    pub node: u64,
+    /// Demand in kW.
+    pub draw: f32,
+    /// Shedding order (see `power_priorities`); higher values go first.
+    pub priority: u8,
+}
+
+/// Outcome of [`solve`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LoadFlow {
+    /// Whether each load was served, in input order.
+    pub served: Vec<bool>,
+    /// kW carried by each line, in input order.
+    pub line_flow: Vec<f32>,
+    /// Total kW wanted.
+    pub demand: f32,
+    /// Total kW delivered.
+    pub supplied: f32,
+}
+
+impl LoadFlow {
+    /// Whether any demand went unserved.
+    pub fn brownout(&self) -> bool {
+        self.supplied + 1e-3 < self.demand
+    }
+}
+
+/// Serve `loads` from `supply` kW entering at the `sources` nodes. Cables
+/// without capacity are cut; a node only they reached goes dark.
+pub fn solve(sources: &[u64], supply: f32, loads: &[Load], lines: &[Line]) -> LoadFlow {
+    // Feeding line of every node the grid reaches, breadth-first from the
+    // sources
+    let mut feed: HashMap<u64, Option<usize>> = sources.iter().map(|&n| (n, None)).collect();
+    let mut queue: VecDeque<u64> = sources.iter().copied().collect();
+    while let Some(node) = queue.pop_front() {
+        for (i, line) in lines.iter().enumerate() {
+            if line.from == node && line.capacity > 0.0 && !feed.contains_key(&line.to) {
+                feed.insert(line.to, Some(i));
+                queue.push_back(line.to);
+            }
+        }
+    }
+    let wired: HashSet<u64> = lines.iter().flat_map(|l| [l.from, l.to]).collect();
+    let path = |node: u64| -> Option<Vec<usize>> {
+        if !feed.contains_key(&node) {
+            return (!wired.contains(&node)).then(Vec::new);
+        }
+        let mut path = Vec::new();
+        let mut at = node;
+        while let Some(&Some(line)) = feed.get(&at) {
+            path.push(line);
+            at = lines[line].from;
+        }
+        Some(path)
+    };
+
+    let mut order: Vec<usize> = (0..loads.len()).collect();
+    order.sort_by_key(|&i| loads[i].priority);
+    let mut flow = LoadFlow {
+        served: vec![false; loads.len()],
+        line_flow: vec![0.0; lines.len()],
+        demand: loads.iter().map(|l| l.draw.max(0.0)).sum(),
+        supplied: 0.0,
+    };
+    let mut left = supply.max(0.0);
+    for i in order {
+        let draw = loads[i].draw.max(0.0);
+        let Some(path) = path(loads[i].node) else {
+            continue;
+        };
+        let headroom = path
+            .iter()
+            .all(|&l| flow.line_flow[l] + draw <= lines[l].capacity);
+        if draw <= left && headroom {
+            left -= draw;
+            flow.supplied += draw;
+            for &l in &path {
+                flow.line_flow[l] += draw;
+            }
+            flow.served[i] = true;
+        }
+    }
+    flow
+}
+
+/// Shedding priority of a room's own load, its lighting and any cryo pods:
+/// recreation goes dark first, and cryo bays keep their sleepers cold to
+/// the last.
+pub fn lighting_priority(room_type: u8) -> u8 {
+    if room_type == room_types::CRYO_BAY {
+        power_priorities::CRITICAL
+    } else if room_types::is_recreation(room_type) {
+        power_priorities::LOW
+    } else {
+        power_priorities::NORMAL
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::power_priorities::{CRITICAL, LOW, NORMAL};
+
+    fn load(node: u64, draw: f32, priority: u8) -> Load {
+        Load {
+            node,
+            draw,
+            priority,
+        }
+    }
+
+    fn line(from: u64, to: u64, capacity: f32) -> Line {
+        Line { from, to, capacity }
+    }
+
+    #[test]
+    fn test_enough_power_serves_everything() {
+        let lines = [line(1, 2, 100.0), line(2, 3, 50.0)];
+        let loads = [load(3, 40.0, NORMAL), load(2, 30.0, LOW), load(9, 5.0, LOW)];
+        let flow = solve(&[1], 200.0, &loads, &lines);
+        assert_eq!(flow.served, vec![true, true, true]);
+        assert_eq!(flow.line_flow, vec![70.0, 40.0]);
+        assert_eq!(flow.supplied, 75.0);
+        assert!(!flow.brownout());
+    }
+
+    #[test]
+    fn test_shortfall_sheds_lowest_priority_first() {
+        let loads = [
+            load(5, 30.0, LOW),
+            load(6, 50.0, CRITICAL),
+            load(7, 30.0, NORMAL),
+        ];
+        let flow = solve(&[1], 85.0, &loads, &[]);
+        assert_eq!(flow.served, vec![false, true, true]);
+        assert!(flow.brownout());
+        assert_eq!(flow.demand, 110.0);
+    }
+
+    #[test]
+    fn test_cable_limits_and_cuts() {
+        // A thin cable to node 3 can't carry both loads there
+        let lines = [line(1, 2, 100.0), line(2, 3, 25.0), line(2, 4, 0.0)];
+        let loads = [
+            load(3, 20.0, CRITICAL),
+            load(3, 10.0, NORMAL),
+            load(4, 1.0, CRITICAL),
+        ];
+        let flow = solve(&[1], 1000.0, &loads, &lines);
+        assert_eq!(flow.served, vec![true, false, false]);
+        assert_eq!(flow.line_flow, vec![20.0, 20.0, 0.0]);
+    }
+
+    #[test]
+    fn test_lighting_priority() {
+        assert_eq!(lighting_priority(room_types::GYM), LOW);
+        assert_eq!(lighting_priority(room_types::CABIN_SINGLE), NORMAL);
+        assert_eq!(lighting_priority(room_types::CRYO_BAY), CRITICAL);
+    }
+}
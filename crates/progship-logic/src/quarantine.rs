@@ -0,0 +1,107 @@
+//! Quarantine — contagious outbreaks and isolating the people they reach.
+//!
+//! A medical emergency of at least [`CONTAGIOUS_SEVERITY`] is an outbreak
+//! of contagious illness ([`is_contagious`]): the worst hurt in its room is
+//! flagged for quarantine, and so is anyone who catches it from a flagged
+//! carrier still at large in the same room ([`catch_chance`], [`catches`]),
+//! losing [`INFECTION_INJURY`] health as they fall ill. The flagged are
+//! sent to the Quarantine room and kept there, its doors locked once they
+//! are all inside and nobody else is ([`door_state`]), until the illness
+//! has run its course and they have recovered ([`may_release`]).
+
+use crate::constants::{door_states, event_types};
+use crate::numeric::roll;
+
+/// Severity from which a medical emergency is a contagious outbreak.
+pub const CONTAGIOUS_SEVERITY: f32 = 0.5;
+
+/// Chance an hour of catching the illness from one carrier in the room.
+pub const INFECTION_CHANCE: f32 = 0.05;
+
+/// Health lost on falling ill.
+pub const INFECTION_INJURY: f32 = 0.2;
+
+/// Hours the illness runs its course, the least anyone is isolated for.
+pub const ISOLATION_HOURS: f64 = 72.0;
+
+/// Health someone in quarantine must be back at to be let out.
+pub const RECOVERED_HEALTH: f32 = 0.7;
+
+/// Salt of the [`roll`] for catching a contagious illness.
+pub const QUARANTINE_INFECTION_SALT: u64 = 0xca7c;
+
+/// Whether an event of `event_type` and `severity` is a contagious
+/// outbreak.
+pub fn is_contagious(event_type: u8, severity: f32) -> bool {
+    event_type == event_types::MEDICAL_EMERGENCY && severity >= CONTAGIOUS_SEVERITY
+}
+
+/// Chance of catching the illness over `hours` in a room with `carriers`.
+pub fn catch_chance(carriers: u32, hours: f32) -> f32 {
+    let exposure = carriers as f32 * hours.max(0.0);
+    1.0 - (1.0 - INFECTION_CHANCE).powf(exposure)
+}
+
+/// Whether `person_id` catches the illness at `sim_time` given `chance`: a
+/// fixed roll per person and moment.
+pub fn catches(person_id: u64, sim_time: f64, chance: f32) -> bool {
+    roll(person_id, sim_time, QUARANTINE_INFECTION_SALT) < chance
+}
+
+/// Whether someone at `health`, `hours` into quarantine, may be let out.
+pub fn may_release(health: f32, hours: f64) -> bool {
+    hours >= ISOLATION_HOURS && health >= RECOVERED_HEALTH
+}
+
+/// State the Quarantine room's doors should be in, with `flagged` people
+/// isolated there, `inside` of them in the room and `others` in it who are
+/// not: locked while all of them and nobody else are inside, open to let
+/// the rest of them in, anyone else out, or when it stands empty.
+pub fn door_state(flagged: usize, inside: usize, others: usize) -> u8 {
+    if flagged > 0 && inside >= flagged && others == 0 {
+        door_states::LOCKED
+    } else {
+        door_states::OPEN
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_contagious() {
+        assert!(is_contagious(event_types::MEDICAL_EMERGENCY, 0.6));
+        assert!(!is_contagious(event_types::MEDICAL_EMERGENCY, 0.3));
+        assert!(!is_contagious(event_types::FIRE, 0.9));
+    }
+
+    #[test]
+    fn test_catch_chance() {
+        assert_eq!(catch_chance(0, 1.0), 0.0);
+        assert!((catch_chance(1, 1.0) - INFECTION_CHANCE).abs() < 0.0001);
+        assert!(catch_chance(3, 1.0) > catch_chance(1, 1.0));
+        assert!(catch_chance(100, 10.0) <= 1.0);
+        assert!(!catches(7, 12.0, 0.0));
+        assert!(catches(7, 12.0, 1.0));
+    }
+
+    #[test]
+    fn test_may_release() {
+        assert!(!may_release(0.9, ISOLATION_HOURS - 1.0));
+        assert!(!may_release(0.5, ISOLATION_HOURS + 1.0));
+        assert!(may_release(RECOVERED_HEALTH, ISOLATION_HOURS));
+    }
+
+    #[test]
+    fn test_door_state() {
+        assert_eq!(door_state(0, 0, 0), door_states::OPEN);
+        assert_eq!(door_state(2, 1, 0), door_states::OPEN);
+        assert_eq!(
+            door_state(2, 2, 1),
+            door_states::OPEN,
+            "nobody else shut in"
+        );
+        assert_eq!(door_state(2, 2, 0), door_states::LOCKED);
+    }
+}
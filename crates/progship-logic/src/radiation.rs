@@ -0,0 +1,187 @@
+//! Radiation — galactic cosmic rays, solar flares and the decks that shield
+//! the crew from them.
+//!
+//! Cosmic rays give everyone aboard a steady background dose; a solar
+//! flare adds a storm of particles on top for several hours, after whatever
+//! warning the sensors give (see [`crate::sensors`]), in which people make
+//! for the storm shelter deck. Each deck lets through a share of the radiation outside
+//! ([`deck_transmission`]): the decks against the hull the most, the
+//! middle decks less and the shelter ([`shelter_deck`]), walled in water
+//! tanks, hardly any. Everyone carries two running doses: a recent
+//! ([`acute_dose`]) one the body repairs over days, which makes people sick
+//! past [`SICKNESS_MSV`] ([`sickness_effects`]), and a lifetime one that
+//! lowers how healthy they can ever be again past [`LIFETIME_LIMIT_MSV`]
+//! ([`health_ceiling`]).
+
+use crate::numeric::roll;
+
+/// Cosmic ray dose rate outside the hull in mSv per hour.
+pub const COSMIC_MSV_PER_HOUR: f32 = 0.075;
+
+/// Dose rate outside the hull at the height of a severity-1.0 flare.
+pub const FLARE_PEAK_MSV_PER_HOUR: f32 = 100.0;
+
+/// Chance a flare is sighted in any given hour (about one a month).
+pub const FLARE_CHANCE_PER_HOUR: f32 = 1.0 / 720.0;
+
+/// Recent dose in mSv past which people fall sick.
+pub const SICKNESS_MSV: f32 = 250.0;
+
+/// Lifetime dose in mSv past which health no longer fully recovers.
+pub const LIFETIME_LIMIT_MSV: f32 = 1000.0;
+
+/// Hours for the body to repair half of a recent dose.
+const ACUTE_HALF_LIFE_HOURS: f32 = 72.0;
+
+/// Share of outside radiation the hull lets through to the outer decks.
+const HULL_TRANSMISSION: f32 = 0.5;
+
+/// Share each deck between a deck and the hull lets through.
+const DECK_TRANSMISSION: f32 = 0.6;
+
+/// Share the shelter's water walls let through on top of its decks.
+const SHELTER_TRANSMISSION: f32 = 0.2;
+
+/// Health ceiling lost per Sv of lifetime dose past the limit.
+const CEILING_LOSS_PER_SV: f32 = 0.1;
+
+/// Lowest the health ceiling falls.
+const MIN_CEILING: f32 = 0.5;
+
+/// The storm shelter of a ship with `deck_count` decks: the middle deck,
+/// furthest from the hull.
+pub fn shelter_deck(deck_count: u32) -> i32 {
+    (deck_count.saturating_sub(1) / 2) as i32
+}
+
+/// Share (0–1) of the radiation outside the hull that reaches `deck`.
+pub fn deck_transmission(deck: i32, deck_count: u32) -> f32 {
+    let last = deck_count.saturating_sub(1) as i32;
+    let layers = deck.min(last - deck).max(0);
+    let shielded = HULL_TRANSMISSION * DECK_TRANSMISSION.powi(layers);
+    if deck == shelter_deck(deck_count) {
+        shielded * SHELTER_TRANSMISSION
+    } else {
+        shielded
+    }
+}
+
+/// Whether a flare is sighted in the hour starting at `hour`.
+pub fn flare_sighted(hour: u64, chance: f32) -> bool {
+    roll(0, hour as f64, 0xf1a4e) < chance
+}
+
+/// Severity (0.3–1.0) of the flare sighted at `hour`.
+pub fn flare_severity(hour: u64) -> f32 {
+    0.3 + roll(0, hour as f64, 0xf1a4f) * 0.7
+}
+
+/// Hours a flare of `severity` lasts once sighted, `warning` hours before
+/// its particles arrive included.
+pub fn flare_hours(severity: f32, warning: f32) -> f32 {
+    warning.max(0.0) + 4.0 + 8.0 * severity.clamp(0.0, 1.0)
+}
+
+/// Dose rate outside the hull in mSv per hour, with the particle storm of
+/// the flare of `flare_severity` raging, if any.
+pub fn outside_dose_rate(flare_severity: Option<f32>) -> f32 {
+    COSMIC_MSV_PER_HOUR + flare_severity.map_or(0.0, |s| s.max(0.0) * FLARE_PEAK_MSV_PER_HOUR)
+}
+
+/// Recent dose after `hours` repairing `acute` and taking `dose` more.
+pub fn acute_dose(acute: f32, dose: f32, hours: f32) -> f32 {
+    acute * 0.5f32.powf(hours / ACUTE_HALF_LIFE_HOURS) + dose
+}
+
+/// Health, fatigue and comfort after `hours` sick from a recent dose of
+/// `acute` mSv; untouched below [`SICKNESS_MSV`].
+pub fn sickness_effects(
+    health: f32,
+    fatigue: f32,
+    comfort: f32,
+    acute: f32,
+    hours: f32,
+) -> (f32, f32, f32) {
+    if acute <= SICKNESS_MSV {
+        return (health, fatigue, comfort);
+    }
+    let sv = (acute - SICKNESS_MSV) / 1000.0;
+    (
+        (health - 0.01 * sv * hours).max(0.0),
+        (fatigue + 0.02 * hours).min(1.0),
+        (comfort + 0.05 * hours).min(1.0),
+    )
+}
+
+/// Highest health someone with a lifetime dose of `lifetime` mSv recovers
+/// to.
+pub fn health_ceiling(lifetime: f32) -> f32 {
+    let excess = (lifetime - LIFETIME_LIMIT_MSV).max(0.0) / 1000.0;
+    (1.0 - excess * CEILING_LOSS_PER_SV).max(MIN_CEILING)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deck_shielding() {
+        assert_eq!(shelter_deck(5), 2);
+        assert_eq!(shelter_deck(1), 0);
+        assert_eq!(deck_transmission(0, 5), HULL_TRANSMISSION);
+        assert_eq!(deck_transmission(4, 5), HULL_TRANSMISSION);
+        assert!(deck_transmission(1, 5) < deck_transmission(0, 5));
+        // The shelter is the best-shielded deck
+        let shelter = deck_transmission(2, 5);
+        assert!((0..5).all(|d| d == 2 || deck_transmission(d, 5) > shelter));
+        assert!(shelter < 0.05);
+        // Even decks: the shelter sits just above the middle
+        assert_eq!(shelter_deck(6), 2);
+        assert!(deck_transmission(2, 6) < deck_transmission(3, 6));
+    }
+
+    #[test]
+    fn test_flares() {
+        let sighted = (0..720 * 100).filter(|&h| flare_sighted(h, FLARE_CHANCE_PER_HOUR));
+        let count = sighted.count();
+        assert!(
+            (60..=140).contains(&count),
+            "{} flares in 100 months",
+            count
+        );
+        assert!(!(0..1000).any(|h| flare_sighted(h, 0.0)));
+        for hour in 0..200 {
+            assert!((0.3..=1.0).contains(&flare_severity(hour)));
+        }
+        assert_eq!(flare_hours(1.0, 0.5), 12.5);
+        assert_eq!(flare_hours(0.0, -1.0), 4.0);
+        assert_eq!(outside_dose_rate(None), COSMIC_MSV_PER_HOUR);
+        assert!(outside_dose_rate(Some(0.5)) > 50.0);
+    }
+
+    #[test]
+    fn test_sheltering_avoids_sickness() {
+        // Twelve hours of the worst flare, on an outer deck and in the shelter
+        let storm = outside_dose_rate(Some(1.0)) * 12.0;
+        let exposed = acute_dose(0.0, storm * deck_transmission(0, 5), 12.0);
+        let sheltered = acute_dose(0.0, storm * deck_transmission(2, 5), 12.0);
+        assert!(exposed > SICKNESS_MSV && sheltered < SICKNESS_MSV);
+
+        let (health, fatigue, comfort) = sickness_effects(1.0, 0.2, 0.2, exposed, 1.0);
+        assert!(health < 1.0 && fatigue > 0.2 && comfort > 0.2);
+        assert_eq!(
+            sickness_effects(1.0, 0.2, 0.2, sheltered, 1.0),
+            (1.0, 0.2, 0.2)
+        );
+        // The body repairs half a recent dose in three days
+        assert!((acute_dose(400.0, 0.0, 72.0) - 200.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_health_ceiling() {
+        assert_eq!(health_ceiling(0.0), 1.0);
+        assert_eq!(health_ceiling(LIFETIME_LIMIT_MSV), 1.0);
+        assert!((health_ceiling(3000.0) - 0.8).abs() < 1e-6);
+        assert_eq!(health_ceiling(1e6), MIN_CEILING);
+    }
+}
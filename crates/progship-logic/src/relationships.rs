@@ -0,0 +1,156 @@
+//! Relationships — how ties fade without contact and how many close ones
+//! a person can keep up.
+//!
+//! Past [`GRACE_HOURS`] without talking a tie fades toward indifference,
+//! strength and familiarity alike, kin far more slowly than anyone else
+//! ([`decay`]); grudges fade too. Nobody keeps up more close ties than their
+//! [`social_capacity`], which grows with extraversion, so the weakest close
+//! ties past it fade faster ([`crowded_out`]). Ties that have faded to
+//! nothing are forgotten ([`forgotten`]). When choosing whom to talk to,
+//! people favor those they are close to ([`partner_score`]).
+
+/// Hours without talking before a tie starts to fade.
+pub const GRACE_HOURS: f64 = 72.0;
+
+/// Strength of a tie that counts as close.
+pub const CLOSE_STRENGTH: f32 = 0.3;
+
+/// Strength a day a tie that isn't kin loses.
+const STRENGTH_DECAY: f32 = 0.01;
+
+/// Familiarity a day a tie that isn't kin loses.
+const FAMILIARITY_DECAY: f32 = 0.005;
+
+/// Share of the usual fading kin suffer.
+const KIN_DECAY: f32 = 0.2;
+
+/// Extra strength a day a close tie past someone's capacity loses.
+const CROWDING_DECAY: f32 = 0.02;
+
+/// Close ties the most introverted can keep up.
+const MIN_CAPACITY: f32 = 5.0;
+
+/// Further close ties the most extraverted can keep up.
+const EXTRA_CAPACITY: f32 = 10.0;
+
+/// A tie `idle_hours` since they last talked, after `days` more days
+/// without contact: its new strength and familiarity. Nothing fades within
+/// [`GRACE_HOURS`], and `kin` fade at only a fraction of the rate.
+pub fn decay(strength: f32, familiarity: f32, idle_hours: f64, days: f32, kin: bool) -> (f32, f32) {
+    if idle_hours < GRACE_HOURS {
+        return (strength, familiarity);
+    }
+    let rate = if kin { KIN_DECAY } else { 1.0 } * days.max(0.0);
+    let toward_zero = |value: f32, step: f32| {
+        if value > 0.0 {
+            (value - step).max(0.0)
+        } else {
+            (value + step).min(0.0)
+        }
+    };
+    (
+        toward_zero(strength, STRENGTH_DECAY * rate),
+        (familiarity - FAMILIARITY_DECAY * rate).max(0.0),
+    )
+}
+
+/// How many close ties someone of `extraversion` can keep up.
+pub fn social_capacity(extraversion: f32) -> usize {
+    (MIN_CAPACITY + EXTRA_CAPACITY * extraversion.clamp(0.0, 1.0)).round() as usize
+}
+
+/// Of someone's close ties that aren't kin, given as (tie, strength), the
+/// ones past their `capacity`, weakest first; each loses
+/// [`crowding_decay`] a day.
+pub fn crowded_out(ties: &[(u64, f32)], capacity: usize) -> Vec<u64> {
+    let mut close: Vec<(u64, f32)> = ties
+        .iter()
+        .copied()
+        .filter(|&(_, s)| s > CLOSE_STRENGTH)
+        .collect();
+    if close.len() <= capacity {
+        return Vec::new();
+    }
+    close.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+    close
+        .iter()
+        .take(close.len() - capacity)
+        .map(|&(id, _)| id)
+        .collect()
+}
+
+/// Strength a crowded-out tie loses over `days`.
+pub fn crowding_decay(days: f32) -> f32 {
+    CROWDING_DECAY * days.max(0.0)
+}
+
+/// Whether a tie that isn't kin has faded to nothing and can be forgotten.
+pub fn forgotten(strength: f32, familiarity: f32) -> bool {
+    strength.abs() < 0.01 && familiarity < 0.01
+}
+
+/// How much someone of `extraversion` wants to talk to a person they have
+/// a tie of `strength` and `familiarity` with (none for strangers): close
+/// ties first, strangers by how outgoing they are, those they dislike
+/// last.
+pub fn partner_score(tie: Option<(f32, f32)>, extraversion: f32) -> f32 {
+    match tie {
+        Some((strength, familiarity)) if familiarity >= 0.05 => {
+            1.0 + 2.0 * strength.clamp(-1.0, 1.0) + familiarity.clamp(0.0, 1.0)
+        }
+        _ => 0.5 + extraversion.clamp(0.0, 1.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ties_fade_without_contact() {
+        assert_eq!(decay(0.6, 0.5, 24.0, 1.0, false), (0.6, 0.5));
+        let (strength, familiarity) = decay(0.6, 0.5, 100.0, 10.0, false);
+        assert!((strength - 0.5).abs() < 1e-6);
+        assert!((familiarity - 0.45).abs() < 1e-6);
+        // Grudges fade toward zero too, never past it
+        assert!((decay(-0.4, 0.5, 100.0, 10.0, false).0 + 0.3).abs() < 1e-6);
+        assert_eq!(decay(0.05, 0.5, 100.0, 10.0, false).0, 0.0);
+        // Kin fade far more slowly
+        assert!(decay(0.6, 0.5, 100.0, 10.0, true).0 > strength);
+    }
+
+    #[test]
+    fn test_social_capacity() {
+        assert_eq!(social_capacity(0.0), 5);
+        assert_eq!(social_capacity(1.0), 15);
+        assert!(social_capacity(0.7) > social_capacity(0.3));
+    }
+
+    #[test]
+    fn test_weakest_close_ties_crowded_out() {
+        let ties = [(1, 0.9), (2, 0.4), (3, 0.6), (4, 0.2), (5, 0.35)];
+        assert!(crowded_out(&ties, 4).is_empty());
+        assert_eq!(crowded_out(&ties, 2), vec![5, 2]);
+        assert_eq!(crowding_decay(2.0), 2.0 * CROWDING_DECAY);
+    }
+
+    #[test]
+    fn test_forgotten() {
+        assert!(forgotten(0.0, 0.0));
+        assert!(!forgotten(0.2, 0.0));
+        assert!(!forgotten(0.0, 0.3));
+    }
+
+    #[test]
+    fn test_partners_favor_close_ties() {
+        let friend = partner_score(Some((0.8, 0.6)), 0.5);
+        let acquaintance = partner_score(Some((0.1, 0.2)), 0.5);
+        let rival = partner_score(Some((-0.4, 0.5)), 0.5);
+        assert!(friend > acquaintance && acquaintance > rival);
+        assert!(partner_score(None, 0.9) > partner_score(None, 0.1));
+        assert_eq!(
+            partner_score(Some((0.0, 0.01)), 0.5),
+            partner_score(None, 0.5)
+        );
+    }
+}
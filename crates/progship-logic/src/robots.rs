@@ -0,0 +1,167 @@
+//! Robots — maintenance drones that take low-priority work off the crew.
+//!
+//! Each Robotics Bay turns out [`ROBOTS_PER_BAY`] robots at launch
+//! ([`designation`]). Robots claim the routine repairs to systems and pipes
+//! the board holds ([`can_take`]) and work them at [`WORK_SPEED`], leaving
+//! emergencies, EVAs and anything urgent to the crew. They cross the ship
+//! at walking pace ([`travel_hours`]) and run down their batteries on the
+//! move and at work ([`charge_after`]): once low ([`needs_charge`]) they
+//! drop what they are doing for the nearest Power Distribution room and
+//! recharge there until full, which takes power. Working wears them
+//! ([`worn`]) and the worn break down ([`breakdown_chance`]); a broken or
+//! flat robot waits where it stopped for an engineer to repair it
+//! ([`repaired_health`]).
+
+use crate::constants::{departments, work_kinds};
+
+/// Robots each Robotics Bay builds at launch.
+pub const ROBOTS_PER_BAY: u32 = 6;
+
+/// Priority above which an order is left to the crew: that of repairing a
+/// subsystem at half health.
+pub const MAX_PRIORITY: f32 = 0.5;
+
+/// Pace a robot works at, as a repair speed multiplier (that of a fairly
+/// skilled engineer).
+pub const WORK_SPEED: f32 = 1.0;
+
+/// Metres a robot covers in an hour (half a metre a second).
+pub const SPEED_M_PER_HOUR: f32 = 1800.0;
+
+/// Hours a robot takes to change decks.
+pub const DECK_HOURS: f64 = 0.05;
+
+/// Charge an hour a robot uses on the move or at work.
+pub const WORK_DRAIN: f32 = 0.08;
+
+/// Charge an hour a robot uses standing by.
+pub const IDLE_DRAIN: f32 = 0.01;
+
+/// Charge an hour a robot takes on at a charging point.
+pub const CHARGE_RATE: f32 = 0.25;
+
+/// Charge at which a robot heads off to recharge.
+pub const LOW_CHARGE: f32 = 0.25;
+
+/// Charge a robot needs to take on a job.
+pub const READY_CHARGE: f32 = 0.5;
+
+/// kW a charging robot draws.
+pub const CHARGE_KW: f32 = 2.0;
+
+/// Condition lost an hour at work.
+pub const WEAR_PER_HOUR: f32 = 0.005;
+
+/// Chance an hour of a breakdown for a robot worn to nothing.
+pub const BREAKDOWN_RATE: f32 = 0.02;
+
+/// Priority of repairing broken-down robots on the board.
+pub const REPAIR_PRIORITY: f32 = 0.4;
+
+/// Hours repairing the broken-down robots in a room takes.
+pub const REPAIR_HOURS: f32 = 2.0;
+
+/// Salt of the [`roll`](crate::numeric::roll) for a robot breaking down.
+pub const BREAKDOWN_SALT: u64 = 0x7f4a;
+
+/// Designation of the `index`-th robot, e.g. "MR-03".
+pub fn designation(index: u32) -> String {
+    format!("MR-{:02}", index + 1)
+}
+
+/// Whether a robot may claim an order of `kind` (see `work_kinds`) for
+/// `department` at `priority`.
+pub fn can_take(kind: u8, department: u8, priority: f32) -> bool {
+    matches!(kind, work_kinds::REPAIR | work_kinds::PIPE_REPAIR)
+        && department == departments::ENGINEERING
+        && priority <= MAX_PRIORITY
+}
+
+/// Hours a robot takes from `from` to `to`, each a (deck, x, y).
+pub fn travel_hours(from: (i32, f32, f32), to: (i32, f32, f32)) -> f64 {
+    let distance = ((to.1 - from.1).powi(2) + (to.2 - from.2).powi(2)).sqrt();
+    distance as f64 / SPEED_M_PER_HOUR as f64 + (to.0 - from.0).unsigned_abs() as f64 * DECK_HOURS
+}
+
+/// Charge left after `hours` busy or standing by.
+pub fn charge_after(charge: f32, busy: bool, hours: f32) -> f32 {
+    let drain = if busy { WORK_DRAIN } else { IDLE_DRAIN };
+    (charge - drain * hours.max(0.0)).clamp(0.0, 1.0)
+}
+
+/// Charge after `hours` at a charging point.
+pub fn charged(charge: f32, hours: f32) -> f32 {
+    (charge + CHARGE_RATE * hours.max(0.0)).clamp(0.0, 1.0)
+}
+
+/// Whether a robot with `charge` must go and recharge.
+pub fn needs_charge(charge: f32) -> bool {
+    charge <= LOW_CHARGE
+}
+
+/// Condition after `hours` at work.
+pub fn worn(health: f32, hours: f32) -> f32 {
+    (health - WEAR_PER_HOUR * hours.max(0.0)).clamp(0.0, 1.0)
+}
+
+/// Chance a robot in `health` breaks down in `hours` at work.
+pub fn breakdown_chance(health: f32, hours: f32) -> f32 {
+    ((1.0 - health.clamp(0.0, 1.0)) * BREAKDOWN_RATE * hours.max(0.0)).min(1.0)
+}
+
+/// Condition a repaired robot is back in, with spare parts or patched up
+/// without.
+pub fn repaired_health(with_parts: bool) -> f32 {
+    if with_parts {
+        1.0
+    } else {
+        0.6
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_orders_robots_take() {
+        let eng = departments::ENGINEERING;
+        assert!(can_take(work_kinds::REPAIR, eng, 0.3));
+        assert!(can_take(work_kinds::PIPE_REPAIR, eng, MAX_PRIORITY));
+        assert!(!can_take(work_kinds::REPAIR, eng, 0.8));
+        assert!(!can_take(work_kinds::REPAIR, departments::MEDICAL, 0.3));
+        assert!(!can_take(work_kinds::EMERGENCY, eng, 0.3));
+        assert!(!can_take(work_kinds::EVA_REPAIR, eng, 0.3));
+        assert!(!can_take(work_kinds::ROBOT_REPAIR, eng, 0.3));
+        assert_eq!(designation(2), "MR-03");
+    }
+
+    #[test]
+    fn test_travel() {
+        assert_eq!(travel_hours((0, 0.0, 0.0), (0, 0.0, 0.0)), 0.0);
+        assert!((travel_hours((0, 0.0, 0.0), (0, 1800.0, 0.0)) - 1.0).abs() < 1e-9);
+        assert!(
+            travel_hours((0, 0.0, 0.0), (3, 0.0, 0.0)) > travel_hours((0, 0.0, 0.0), (1, 0.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn test_battery() {
+        assert!(charge_after(1.0, true, 1.0) < charge_after(1.0, false, 1.0));
+        assert_eq!(charge_after(0.05, true, 1.0), 0.0);
+        assert_eq!(charged(0.9, 1.0), 1.0);
+        assert!(needs_charge(LOW_CHARGE));
+        assert!(!needs_charge(READY_CHARGE));
+        // A full charge lasts long enough to see a job through
+        assert!(!needs_charge(charge_after(1.0, true, 6.0)));
+    }
+
+    #[test]
+    fn test_wear_and_breakdowns() {
+        assert!(worn(1.0, 10.0) < 1.0);
+        assert_eq!(worn(0.01, 10.0), 0.0);
+        assert_eq!(breakdown_chance(1.0, 1.0), 0.0);
+        assert!(breakdown_chance(0.2, 1.0) > breakdown_chance(0.6, 1.0));
+        assert!(repaired_health(true) > repaired_health(false));
+    }
+}
@@ -0,0 +1,188 @@
+//! Room effects — smoke, coolant spills and frost left behind by incidents.
+//!
+//! An effect ([`effect_kinds`]) has an intensity (0–1) in one room. It
+//! builds while its source lasts (a fire, a burst coolant line, failed
+//! climate control), fades once the source is gone and fades faster with
+//! someone cleaning up ([`step`]). Smoke and frost seep through open doors
+//! into neighbouring rooms ([`seepage`]). People walk slower through an
+//! effect ([`walk_factor`]) and may slip on a wet or icy floor
+//! ([`slip_chance`]).
+
+use crate::constants::{departments, effect_kinds, event_types};
+use crate::numeric::roll;
+
+/// Intensity below which an effect has cleared.
+pub const CLEARED: f32 = 0.02;
+
+/// Intensity from which someone is sent to clean an effect up.
+pub const CLEANUP_THRESHOLD: f32 = 0.2;
+
+/// Health lost in a slip.
+pub const SLIP_INJURY: f32 = 0.03;
+
+/// Salt of the [`roll`] for slipping on a spill or frost.
+pub const SLIP_SALT: u64 = 0x5119;
+
+/// Per-hour rates of an effect kind.
+struct Rates {
+    /// Build-up while the source lasts.
+    build: f32,
+    /// Fading on its own once the source is gone.
+    fade: f32,
+    /// Extra fading per person cleaning up.
+    cleanup: f32,
+    /// Share of the difference with a neighbour that seeps across.
+    seep: f32,
+}
+
+fn rates(kind: u8) -> Rates {
+    match kind {
+        // Scrubbers clear smoke on their own
+        effect_kinds::SMOKE => Rates {
+            build: 0.8,
+            fade: 0.3,
+            cleanup: 0.6,
+            seep: 0.4,
+        },
+        // A spill only goes when mopped up
+        effect_kinds::COOLANT_SPILL => Rates {
+            build: 0.6,
+            fade: 0.02,
+            cleanup: 0.5,
+            seep: 0.0,
+        },
+        _ => Rates {
+            build: 0.3,
+            fade: 0.15,
+            cleanup: 0.3,
+            seep: 0.1,
+        },
+    }
+}
+
+/// Effect an event of `event_type` leaves in its room while it lasts, if
+/// any: fires fill it with smoke, system failures burst coolant lines.
+pub fn event_effect(event_type: u8) -> Option<u8> {
+    match event_type {
+        event_types::FIRE => Some(effect_kinds::SMOKE),
+        event_types::SYSTEM_FAILURE => Some(effect_kinds::COOLANT_SPILL),
+        _ => None,
+    }
+}
+
+/// Intensity of an effect of `kind` after `hours`, building while its
+/// `source` lasts and fading otherwise, less any `cleaners` at work.
+pub fn step(kind: u8, intensity: f32, source: bool, cleaners: u32, hours: f32) -> f32 {
+    let rates = rates(kind);
+    let drift = if source { rates.build } else { -rates.fade };
+    let cleaned = rates.cleanup * cleaners as f32;
+    (intensity + (drift - cleaned) * hours).clamp(0.0, 1.0)
+}
+
+/// Intensity that seeps in `hours` from a room at `from` into an open
+/// neighbour at `to`; never so much that the neighbour ends up worse off.
+pub fn seepage(kind: u8, from: f32, to: f32, hours: f32) -> f32 {
+    let gap = (from - to).max(0.0);
+    (gap * rates(kind).seep * hours).min(gap / 2.0)
+}
+
+/// Walking speed multiplier through an effect of `kind`.
+pub fn walk_factor(kind: u8, intensity: f32) -> f32 {
+    let slowdown = match kind {
+        effect_kinds::SMOKE => 0.4,
+        effect_kinds::COOLANT_SPILL => 0.3,
+        _ => 0.25,
+    };
+    1.0 - slowdown * intensity.clamp(0.0, 1.0)
+}
+
+/// How much of a room's light gets through smoke of `intensity`.
+pub fn visibility(smoke: f32) -> f32 {
+    1.0 - 0.8 * smoke.clamp(0.0, 1.0)
+}
+
+/// Chance that someone walking `hours` over an effect of `kind` slips.
+pub fn slip_chance(kind: u8, intensity: f32, hours: f32) -> f32 {
+    let per_hour = match kind {
+        effect_kinds::COOLANT_SPILL => 0.5,
+        effect_kinds::FROST => 0.3,
+        _ => 0.0,
+    };
+    (per_hour * intensity.clamp(0.0, 1.0) * hours).clamp(0.0, 1.0)
+}
+
+/// Whether `person_id` slips at `sim_time` given `chance`: a fixed roll
+/// per person and moment.
+pub fn slips(person_id: u64, sim_time: f64, chance: f32) -> bool {
+    roll(person_id, sim_time, SLIP_SALT) < chance
+}
+
+/// Department sent to clean up an effect of `kind`: engineering vents
+/// smoke, operations mops spills and clears frost.
+pub fn cleanup_department(kind: u8) -> u8 {
+    match kind {
+        effect_kinds::SMOKE => departments::ENGINEERING,
+        _ => departments::OPERATIONS,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_effects_build_and_fade() {
+        let smoke = effect_kinds::SMOKE;
+        assert!((step(smoke, 0.2, true, 0, 0.5) - 0.6).abs() < 1e-6);
+        assert!((step(smoke, 0.6, false, 0, 1.0) - 0.3).abs() < 1e-6);
+        assert!(step(smoke, 0.6, false, 1, 1.0) < 0.01);
+        assert_eq!(step(smoke, 0.9, true, 0, 1.0), 1.0);
+
+        // A spill barely dries up without a mop
+        let spill = effect_kinds::COOLANT_SPILL;
+        assert!(step(spill, 0.5, false, 0, 1.0) > 0.45);
+        assert!(step(spill, 0.5, false, 1, 1.0) < CLEARED);
+        assert_eq!(event_effect(event_types::FIRE), Some(smoke));
+        assert_eq!(event_effect(event_types::SYSTEM_FAILURE), Some(spill));
+        assert_eq!(event_effect(event_types::CELEBRATION), None);
+    }
+
+    #[test]
+    fn test_seepage_evens_out() {
+        let smoke = effect_kinds::SMOKE;
+        assert!((seepage(smoke, 0.8, 0.0, 0.5) - 0.16).abs() < 1e-6);
+        // Never overshoots, even over a long step
+        assert!((seepage(smoke, 0.8, 0.2, 10.0) - 0.3).abs() < 1e-6);
+        assert_eq!(seepage(smoke, 0.2, 0.8, 1.0), 0.0);
+        assert_eq!(seepage(effect_kinds::COOLANT_SPILL, 1.0, 0.0, 1.0), 0.0);
+        assert!(seepage(effect_kinds::FROST, 1.0, 0.0, 1.0) > 0.0);
+    }
+
+    #[test]
+    fn test_walking_and_seeing() {
+        assert_eq!(walk_factor(effect_kinds::SMOKE, 0.0), 1.0);
+        assert!((walk_factor(effect_kinds::SMOKE, 1.0) - 0.6).abs() < 1e-6);
+        assert!(walk_factor(effect_kinds::FROST, 0.5) < 1.0);
+        assert!((visibility(1.0) - 0.2).abs() < 1e-6);
+        assert_eq!(visibility(0.0), 1.0);
+    }
+
+    #[test]
+    fn test_slipping() {
+        assert_eq!(slip_chance(effect_kinds::SMOKE, 1.0, 1.0), 0.0);
+        let spill = slip_chance(effect_kinds::COOLANT_SPILL, 1.0, 1.0);
+        assert!(spill > slip_chance(effect_kinds::FROST, 1.0, 1.0));
+        assert!(!slips(1, 2.0, 0.0));
+        assert!(slips(1, 2.0, 1.0));
+        let fallen = (0..1000).filter(|&id| slips(id, 5.0, 0.3)).count();
+        assert!((200..400).contains(&fallen), "{fallen}");
+        assert_eq!(
+            cleanup_department(effect_kinds::SMOKE),
+            departments::ENGINEERING
+        );
+        assert_eq!(
+            cleanup_department(effect_kinds::FROST),
+            departments::OPERATIONS
+        );
+    }
+}
@@ -0,0 +1,292 @@
+//! Scenario gallery — curated ship presets and the daily ship.
+//!
+//! A [`Scenario`] is everything `init_ship` needs plus a generation seed, so
+//! two servers starting the same scenario get the same ship. The gallery is
+//! a handful of hand-picked starts; [`daily_scenario`] derives one more from
+//! the UTC date, so everyone who starts a daily ship that day gets the same
+//! one and can compare runs.
+//!
+//! A scenario may also have a script in `data/scenarios/<id>.json` (see
+//! [`scenario_script`]): objectives for the player and how the ship starts
+//! out. The tutorial is a scripted scenario.
+
+use serde::{Deserialize, Serialize};
+
+use crate::constants::difficulties;
+use crate::mission::MissionConfig;
+use crate::objectives::Objective;
+
+/// A named set of generation parameters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Scenario {
+    /// Stable identifier passed to `init_scenario`.
+    pub id: &'static str,
+    /// Ship name.
+    pub name: &'static str,
+    /// One-line pitch for the menu.
+    pub summary: &'static str,
+    /// Generation seed (see [`MissionConfig::seed`]).
+    pub seed: u64,
+    pub deck_count: u32,
+    pub crew_count: u32,
+    pub passenger_count: u32,
+    /// Days fast-forwarded before boarding (see `warm_start`).
+    pub warm_start_days: f32,
+    /// See `difficulties`; scales how often things go wrong
+    /// ([`event_chance_per_mille`]).
+    pub difficulty: u8,
+}
+
+/// Objectives and starting conditions for a scripted scenario.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScenarioScript {
+    /// Objectives, in the order they are taken on.
+    pub objectives: Vec<Objective>,
+    /// Subsystems in repair rooms that start worn, so there is something to
+    /// fix.
+    #[serde(default)]
+    pub worn_subsystems: u32,
+}
+
+/// Identifier of the daily ship.
+pub const DAILY_ID: &str = "daily";
+
+/// Identifier of the tutorial.
+pub const TUTORIAL_ID: &str = "tutorial";
+
+/// The curated gallery, in menu order.
+pub const SCENARIOS: [Scenario; 6] = [
+    Scenario {
+        id: "standard",
+        name: "ISV Prometheus",
+        summary: "The reference ship: twelve decks, a thousand souls",
+        seed: 42,
+        deck_count: 12,
+        crew_count: 200,
+        passenger_count: 800,
+        warm_start_days: 0.0,
+        difficulty: difficulties::NORMAL,
+    },
+    Scenario {
+        id: "skeleton-crew",
+        name: "ISV Long Haul",
+        summary: "Too few hands for too many systems",
+        seed: 7,
+        deck_count: 8,
+        crew_count: 60,
+        passenger_count: 400,
+        warm_start_days: 0.0,
+        difficulty: difficulties::HARD,
+    },
+    Scenario {
+        id: "lived-in",
+        name: "ISV Covenant",
+        summary: "Two weeks out, with friendships and grudges already formed",
+        seed: 1138,
+        deck_count: 10,
+        crew_count: 150,
+        passenger_count: 600,
+        warm_start_days: 14.0,
+        difficulty: difficulties::NORMAL,
+    },
+    Scenario {
+        id: "ark",
+        name: "ISV Generations",
+        summary: "A crowded ark, generated deck by deck",
+        seed: 2024,
+        deck_count: 16,
+        crew_count: 300,
+        passenger_count: 2500,
+        warm_start_days: 0.0,
+        difficulty: difficulties::HARD,
+    },
+    Scenario {
+        id: "scout",
+        name: "ISV Wren",
+        summary: "A small ship for quick runs",
+        seed: 3,
+        deck_count: 5,
+        crew_count: 40,
+        passenger_count: 120,
+        warm_start_days: 0.0,
+        difficulty: difficulties::EASY,
+    },
+    Scenario {
+        id: TUTORIAL_ID,
+        name: "ISV Lantern",
+        summary: "Learn the ropes on a small, quiet ship, one objective at a time",
+        seed: 11,
+        deck_count: 4,
+        crew_count: 30,
+        passenger_count: 60,
+        warm_start_days: 0.0,
+        difficulty: difficulties::TUTORIAL,
+    },
+];
+
+/// Ship names the daily ship draws from.
+const DAILY_NAMES: [&str; 8] = [
+    "ISV Meridian",
+    "ISV Halcyon",
+    "ISV Perihelion",
+    "ISV Tern",
+    "ISV Lodestar",
+    "ISV Anselm",
+    "ISV Farholm",
+    "ISV Quiet Harbor",
+];
+
+/// The gallery scenario with `id`.
+pub fn find_scenario(id: &str) -> Option<&'static Scenario> {
+    SCENARIOS.iter().find(|s| s.id == id)
+}
+
+/// Seed of any mission not started from a scenario.
+pub fn default_seed() -> u64 {
+    MissionConfig::default().seed
+}
+
+/// Difficulty of any mission not started from a scenario.
+pub fn default_difficulty() -> u8 {
+    difficulties::NORMAL
+}
+
+/// Whole UTC days since 1970-01-01 at `unix_micros`.
+pub fn day_number(unix_micros: i64) -> i64 {
+    unix_micros.div_euclid(86_400_000_000)
+}
+
+/// `day` (see [`day_number`]) as an ISO date, e.g. `2026-10-14`.
+pub fn date_label(day: i64) -> String {
+    // Days-to-civil conversion over 400-year eras
+    let z = day + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400 + i64::from(m <= 2);
+    format!("{y:04}-{m:02}-{d:02}")
+}
+
+/// The daily ship for `day` (see [`day_number`]): the same for everyone on
+/// that date, different from one day to the next.
+pub fn daily_scenario(day: i64) -> Scenario {
+    // SplitMix64 of the day number
+    let mut z = (day as u64).wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    let seed = z ^ (z >> 31);
+    let pick = |shift: u32, n: u64| (seed >> shift) % n;
+    Scenario {
+        id: DAILY_ID,
+        name: DAILY_NAMES[pick(0, DAILY_NAMES.len() as u64) as usize],
+        summary: "Today's ship, the same for everyone",
+        seed,
+        deck_count: 6 + pick(8, 9) as u32,
+        crew_count: 80 + 10 * pick(16, 23) as u32,
+        passenger_count: 300 + 50 * pick(24, 25) as u32,
+        warm_start_days: pick(32, 4) as f32,
+        difficulty: difficulties::NORMAL,
+    }
+}
+
+/// The script for scenario `id`, if it has one.
+pub fn scenario_script(id: &str) -> Option<ScenarioScript> {
+    const TUTORIAL_JSON: &str = include_str!("../../../data/scenarios/tutorial.json");
+    let json = match id {
+        TUTORIAL_ID => TUTORIAL_JSON,
+        _ => return None,
+    };
+    Some(serde_json::from_str(json).expect("scenario script is invalid"))
+}
+
+/// Chance per thousand, each simulation tick, of a random event at
+/// `difficulty`. The tutorial has none.
+pub fn event_chance_per_mille(difficulty: u8) -> u64 {
+    match difficulty {
+        difficulties::TUTORIAL => 0,
+        difficulties::EASY => 2,
+        difficulties::HARD => 5,
+        _ => 3,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{activity_types, ui_screens};
+    use crate::objectives::Goal;
+
+    #[test]
+    fn test_gallery_is_valid() {
+        for (i, s) in SCENARIOS.iter().enumerate() {
+            assert_eq!(find_scenario(s.id), Some(s));
+            assert!(SCENARIOS[..i]
+                .iter()
+                .all(|o| o.id != s.id && o.seed != s.seed));
+            assert_ne!(s.id, DAILY_ID);
+            assert!(s.deck_count > 0 && s.crew_count > 0);
+        }
+        assert_eq!(
+            find_scenario("standard").map(|s| s.seed),
+            Some(default_seed())
+        );
+        assert!(find_scenario("missing").is_none());
+    }
+
+    #[test]
+    fn test_tutorial_script() {
+        let tutorial = find_scenario(TUTORIAL_ID).unwrap();
+        assert_eq!(tutorial.difficulty, difficulties::TUTORIAL);
+        assert_eq!(event_chance_per_mille(tutorial.difficulty), 0);
+        assert!(
+            event_chance_per_mille(difficulties::HARD) > event_chance_per_mille(difficulties::EASY)
+        );
+
+        let script = scenario_script(TUTORIAL_ID).unwrap();
+        assert_eq!(script.objectives.len(), 8);
+        assert_eq!(script.worn_subsystems, 1);
+        assert_eq!(
+            script.objectives[6].goal,
+            Goal::Act {
+                action: activity_types::MAINTENANCE
+            }
+        );
+        assert_eq!(
+            script.objectives.last().map(|o| o.goal),
+            Some(Goal::ViewScreen {
+                screen: ui_screens::SHIP_OVERVIEW
+            })
+        );
+        assert!(scenario_script("standard").is_none());
+    }
+
+    #[test]
+    fn test_dates() {
+        assert_eq!(day_number(0), 0);
+        assert_eq!(day_number(-1), -1);
+        assert_eq!(day_number(20_740 * 86_400_000_000 + 5), 20_740);
+        assert_eq!(date_label(0), "1970-01-01");
+        assert_eq!(date_label(-1), "1969-12-31");
+        assert_eq!(date_label(11_016), "2000-02-29");
+        assert_eq!(date_label(20_740), "2026-10-14");
+    }
+
+    #[test]
+    fn test_daily_scenario_is_stable_per_day() {
+        assert_eq!(daily_scenario(20_740), daily_scenario(20_740));
+        let seeds: std::collections::HashSet<_> =
+            (20_740..20_770).map(|d| daily_scenario(d).seed).collect();
+        assert_eq!(seeds.len(), 30);
+        for day in 20_740..20_770 {
+            let s = daily_scenario(day);
+            assert!((6..=14).contains(&s.deck_count));
+            assert!((80..=300).contains(&s.crew_count));
+            assert!((300..=1500).contains(&s.passenger_count));
+            assert!((0.0..=3.0).contains(&s.warm_start_days));
+        }
+    }
+}
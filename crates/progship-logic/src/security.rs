@@ -278,7 +278,8 @@ pub fn default_access_for_room(room_type: u8) -> u8 {
         | rt::ELEVATOR_SHAFT
         | rt::LADDER_SHAFT
         | rt::SERVICE_ELEVATOR_SHAFT
-        | rt::SERVICE_DECK => access_levels::CREW_ONLY,
+        | rt::SERVICE_DECK
+        | rt::CRAWLWAY => access_levels::CREW_ONLY,
 
         // Department-restricted — engineering
         rt::ENGINEERING
@@ -301,7 +302,8 @@ pub fn default_access_for_room(room_type: u8) -> u8 {
         | rt::MENTAL_HEALTH
         | rt::QUARANTINE
         | rt::MORGUE
-        | rt::MEDBAY => access_levels::DEPARTMENT,
+        | rt::MEDBAY
+        | rt::CRYO_BAY => access_levels::DEPARTMENT,
 
         // Department-restricted — science / life support
         rt::LABORATORY
@@ -326,6 +328,97 @@ pub fn default_access_for_room(room_type: u8) -> u8 {
     }
 }
 
+/// Department that owns a department-restricted room type, if any.
+///
+/// Matches the department groups of [`default_access_for_room`]; use it as
+/// [`AccessRequest::door_department`] for doors into that room.
+pub fn department_for_room(room_type: u8) -> Option<u8> {
+    use crate::constants::departments;
+    use crate::constants::room_types as rt;
+    match room_type {
+        rt::ENGINEERING
+        | rt::REACTOR
+        | rt::BACKUP_REACTOR
+        | rt::ENGINE_ROOM
+        | rt::POWER_DISTRIBUTION
+        | rt::MACHINE_SHOP
+        | rt::ELECTRONICS_LAB
+        | rt::FUEL_STORAGE
+        | rt::ROBOTICS_BAY
+        | rt::MAINTENANCE_BAY
+        | rt::COOLING_PLANT => Some(departments::ENGINEERING),
+
+        rt::HOSPITAL_WARD
+        | rt::SURGERY
+        | rt::DENTAL_CLINIC
+        | rt::PHARMACY
+        | rt::MENTAL_HEALTH
+        | rt::QUARANTINE
+        | rt::MORGUE
+        | rt::MEDBAY
+        | rt::CRYO_BAY => Some(departments::MEDICAL),
+
+        rt::LABORATORY
+        | rt::HYDROPONICS
+        | rt::ATMOSPHERE_PROCESSING
+        | rt::WATER_RECYCLING
+        | rt::WATER_PURIFICATION
+        | rt::WASTE_PROCESSING
+        | rt::ENV_MONITORING
+        | rt::LIFE_SUPPORT
+        | rt::HVAC_CONTROL
+        | rt::COMMS_ROOM => Some(departments::SCIENCE),
+
+        _ => None,
+    }
+}
+
+/// Whether a room type is a vertical shaft, whose doors take the shaft's own
+/// access level (public main elevators, crew-only service shafts).
+fn is_shaft(room_type: u8) -> bool {
+    use crate::constants::room_types as rt;
+    matches!(
+        room_type,
+        rt::ELEVATOR_SHAFT | rt::LADDER_SHAFT | rt::SERVICE_ELEVATOR_SHAFT
+    )
+}
+
+/// Access level of a door between rooms of types `a` and `b`: the stricter
+/// of the two rooms' [`default_access_for_room`]. Shaft rooms don't count,
+/// so a door from a shaft into a corridor stays at the shaft's level.
+pub fn door_access_level(a: u8, b: u8) -> u8 {
+    [a, b]
+        .into_iter()
+        .filter(|&t| !is_shaft(t))
+        .map(default_access_for_room)
+        .max()
+        .unwrap_or(access_levels::PUBLIC)
+}
+
+/// Department owning a department-restricted door between rooms of types
+/// `a` and `b`, for [`AccessRequest::door_department`].
+pub fn door_department(a: u8, b: u8) -> Option<u8> {
+    department_for_room(a).or_else(|| department_for_room(b))
+}
+
+/// Highest door access level a person is cleared for.
+///
+/// Passengers are cleared for public doors only. Crew below Ensign pass
+/// department doors — [`check_access`] still requires their own department
+/// unless they are security — officers pass officer doors and the captain
+/// passes everything.
+pub fn clearance(is_crew: bool, rank: u8) -> u8 {
+    if !is_crew {
+        access_levels::PUBLIC
+    } else if rank >= CAPTAIN_RANK {
+        access_levels::CAPTAIN
+    } else if rank >= OFFICER_RANK {
+        access_levels::OFFICER
+    } else {
+        access_levels::DEPARTMENT
+    }
+}
+
 /// Patrol route types for security crew.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PatrolType {
@@ -542,6 +635,26 @@ mod tests {
         assert_eq!(default_access_for_room(rt::BRIDGE), access_levels::OFFICER);
     }
 
+    #[test]
+    fn department_rooms_have_owner() {
+        use crate::constants::{departments, room_types as rt};
+        assert_eq!(
+            department_for_room(rt::REACTOR),
+            Some(departments::ENGINEERING)
+        );
+        assert_eq!(department_for_room(rt::MEDBAY), Some(departments::MEDICAL));
+        assert_eq!(
+            department_for_room(rt::LABORATORY),
+            Some(departments::SCIENCE)
+        );
+        assert_eq!(department_for_room(rt::MESS_HALL), None);
+        for room_type in 0..=255u8 {
+            if default_access_for_room(room_type) == access_levels::DEPARTMENT {
+                assert!(department_for_room(room_type).is_some(), "{}", room_type);
+            }
+        }
+    }
+
     #[test]
     fn patrol_public_areas() {
         let types = patrol_room_types(PatrolType::PublicAreas);
@@ -556,6 +669,48 @@ mod tests {
         assert!(types.contains(&crate::constants::room_types::REACTOR));
     }
 
+    #[test]
+    fn test_door_access_level_takes_stricter_room() {
+        use crate::constants::room_types as rt;
+        assert_eq!(
+            door_access_level(rt::CORRIDOR, rt::MESS_HALL),
+            access_levels::PUBLIC
+        );
+        assert_eq!(
+            door_access_level(rt::CORRIDOR, rt::REACTOR),
+            access_levels::DEPARTMENT
+        );
+        assert_eq!(
+            door_access_level(rt::BRIDGE, rt::CORRIDOR),
+            access_levels::OFFICER
+        );
+        assert_eq!(
+            door_access_level(rt::CROSS_CORRIDOR, rt::CRAWLWAY),
+            access_levels::CREW_ONLY
+        );
+        // Shafts keep their own level
+        assert_eq!(
+            door_access_level(rt::ELEVATOR_SHAFT, rt::CORRIDOR),
+            access_levels::PUBLIC
+        );
+        assert_eq!(
+            door_department(rt::CORRIDOR, rt::REACTOR),
+            Some(crate::constants::departments::ENGINEERING)
+        );
+        assert_eq!(door_department(rt::CORRIDOR, rt::BRIDGE), None);
+    }
+
+    #[test]
+    fn test_clearance_by_rank() {
+        assert_eq!(clearance(false, 0), access_levels::PUBLIC);
+        assert_eq!(clearance(true, 0), access_levels::DEPARTMENT);
+        assert_eq!(clearance(true, OFFICER_RANK), access_levels::OFFICER);
+        assert_eq!(clearance(true, CAPTAIN_RANK), access_levels::CAPTAIN);
+        // Clearance agrees with check_access for officer doors
+        let req = crew_request(OFFICER_RANK, 0, access_levels::OFFICER);
+        assert!(check_access(&req).allowed);
+    }
+
     #[test]
     fn acceptance_passenger_cannot_enter_crew_area() {
         let result = check_access(&passenger_request(1));
@@ -0,0 +1,89 @@
+//! Sensors — how far ahead the ship sees the hazards coming at it.
+//!
+//! A solar flare's particles arrive [`FLARE_LEAD_HOURS`] after its flash,
+//! and a micrometeorite crosses the sensors' range in
+//! [`IMPACT_LEAD_HOURS`]. How much of that lead the crew gets depends on
+//! the sensor array's health and on who is watching it ([`detection`]):
+//! healthy sensors alone catch a contact halfway in, and a full watch of
+//! [`FULL_WATCH`] on the bridge and in the CIC ([`is_watch_post`]) catches
+//! it as soon as it is in range. A contact is spotted once it is no further
+//! off than the warning detection affords ([`is_spotted`]); dead sensors
+//! see nothing coming at all.
+
+use crate::constants::{contact_kinds, room_types};
+
+/// Hours between a flare's flash and its particles arriving: the most
+/// warning of one the sensors can give.
+pub const FLARE_LEAD_HOURS: f32 = 1.0;
+
+/// Hours a micrometeorite takes to cross the sensors' range.
+pub const IMPACT_LEAD_HOURS: f32 = 0.5;
+
+/// Crew on watch at the sensor posts for the sensors to see all they can.
+pub const FULL_WATCH: u32 = 3;
+
+/// Share of the lead healthy sensors give with no one watching them.
+const UNWATCHED_SHARE: f32 = 0.5;
+
+/// Hours of approach the sensors could see a contact of `kind` (see
+/// `contact_kinds`) over.
+pub fn lead_hours(kind: u8) -> f32 {
+    match kind {
+        contact_kinds::SOLAR_FLARE => FLARE_LEAD_HOURS,
+        _ => IMPACT_LEAD_HOURS,
+    }
+}
+
+/// Whether crew on duty in a room of `room_type` watch the sensors.
+pub fn is_watch_post(room_type: u8) -> bool {
+    matches!(room_type, room_types::BRIDGE | room_types::CIC)
+}
+
+/// Share (0–1) of a hazard's lead the sensors catch it with, at
+/// `sensor_health` (0 when offline) with `watch` crew on duty at the
+/// sensor posts.
+pub fn detection(sensor_health: f32, watch: u32) -> f32 {
+    let watched = watch.min(FULL_WATCH) as f32 / FULL_WATCH as f32;
+    sensor_health.clamp(0.0, 1.0) * (UNWATCHED_SHARE + (1.0 - UNWATCHED_SHARE) * watched)
+}
+
+/// Hours of warning of a hazard with `lead` hours of approach at
+/// `detection`.
+pub fn warning_hours(lead: f32, detection: f32) -> f32 {
+    lead.max(0.0) * detection.clamp(0.0, 1.0)
+}
+
+/// Whether a hazard with `lead` hours of approach, `remaining` hours off,
+/// has been spotted at `detection`.
+pub fn is_spotted(remaining: f32, lead: f32, detection: f32) -> bool {
+    detection > 0.0 && remaining <= warning_hours(lead, detection)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detection() {
+        assert_eq!(detection(1.0, FULL_WATCH), 1.0);
+        assert_eq!(detection(1.0, 10), 1.0);
+        assert_eq!(detection(1.0, 0), UNWATCHED_SHARE);
+        assert_eq!(detection(0.0, FULL_WATCH), 0.0);
+        assert!(detection(0.5, 2) < detection(1.0, 2));
+        assert!(detection(1.0, 1) < detection(1.0, 2));
+        assert!(is_watch_post(room_types::BRIDGE) && is_watch_post(room_types::CIC));
+        assert!(!is_watch_post(room_types::GALLEY));
+    }
+
+    #[test]
+    fn test_spotting() {
+        assert_eq!(lead_hours(contact_kinds::SOLAR_FLARE), FLARE_LEAD_HOURS);
+        assert_eq!(lead_hours(contact_kinds::MICROMETEORITE), IMPACT_LEAD_HOURS);
+        assert_eq!(warning_hours(FLARE_LEAD_HOURS, 0.5), 0.5);
+        assert!(is_spotted(0.4, FLARE_LEAD_HOURS, 0.5));
+        assert!(!is_spotted(0.6, FLARE_LEAD_HOURS, 0.5));
+        assert!(is_spotted(IMPACT_LEAD_HOURS, IMPACT_LEAD_HOURS, 1.0));
+        // Dead sensors miss even what is already upon the ship
+        assert!(!is_spotted(0.0, IMPACT_LEAD_HOURS, 0.0));
+    }
+}
@@ -310,6 +310,7 @@ pub fn randomize_config(seed: u64) -> ShipConfigBuilder {
 }
 
 #[cfg(test)]
+#[allow(clippy::field_reassign_with_default)]
 mod tests {
     use super::*;
 
@@ -0,0 +1,91 @@
+//! Shuttles — small craft docked in the shuttle bays.
+//!
+//! Each bay holds as many shuttles as its deck area allows (see
+//! [`shuttles_for_bay`]). A launched shuttle burns fuel until it is recalled,
+//! and is recalled automatically once it is down to the reserve it needs to
+//! fly home; a docked shuttle tops its tanks up from the ship's fuel.
+
+/// Deck area one docked shuttle takes up, with clearance (m²).
+pub const BAY_AREA_PER_SHUTTLE: f32 = 60.0;
+/// Most shuttles a single bay holds.
+pub const MAX_SHUTTLES_PER_BAY: u32 = 4;
+/// Seats per shuttle.
+pub const SHUTTLE_CAPACITY: u32 = 12;
+/// Tank size (kg).
+pub const SHUTTLE_FUEL_CAPACITY_KG: f32 = 2000.0;
+/// Fuel burned per hour away from the ship (kg).
+pub const SHUTTLE_BURN_KG_PER_HOUR: f32 = 40.0;
+/// Share of the tank kept back for the flight home.
+pub const SHUTTLE_RESERVE_FRACTION: f32 = 0.2;
+/// Fuel pumped into a docked shuttle per hour (kg).
+pub const SHUTTLE_REFUEL_KG_PER_HOUR: f32 = 200.0;
+
+/// Number of shuttles a bay of `floor_area` m² holds (at least one).
+pub fn shuttles_for_bay(floor_area: f32) -> u32 {
+    ((floor_area / BAY_AREA_PER_SHUTTLE) as u32).clamp(1, MAX_SHUTTLES_PER_BAY)
+}
+
+/// Fuel a shuttle must keep for the flight home (kg).
+pub fn reserve_fuel(fuel_capacity: f32) -> f32 {
+    fuel_capacity * SHUTTLE_RESERVE_FRACTION
+}
+
+/// Why a docked shuttle can't launch with `fuel` kg aboard, if it can't.
+pub fn launch_blocker(fuel: f32, fuel_capacity: f32) -> Option<&'static str> {
+    if fuel <= reserve_fuel(fuel_capacity) * 2.0 {
+        Some("not enough fuel for a sortie")
+    } else {
+        None
+    }
+}
+
+/// Fuel left after `hours` away, and whether the shuttle must now turn back.
+pub fn burn(fuel: f32, fuel_capacity: f32, hours: f32) -> (f32, bool) {
+    let left = (fuel - SHUTTLE_BURN_KG_PER_HOUR * hours).max(0.0);
+    (left, left <= reserve_fuel(fuel_capacity))
+}
+
+/// Fuel to pump into a docked shuttle over `hours`, given what the ship has
+/// to spare (kg).
+pub fn refuel_amount(fuel: f32, fuel_capacity: f32, ship_fuel: f32, hours: f32) -> f32 {
+    (SHUTTLE_REFUEL_KG_PER_HOUR * hours)
+        .min(fuel_capacity - fuel)
+        .min(ship_fuel)
+        .max(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shuttles_for_bay() {
+        assert_eq!(shuttles_for_bay(20.0), 1);
+        assert_eq!(shuttles_for_bay(130.0), 2);
+        assert_eq!(shuttles_for_bay(1000.0), MAX_SHUTTLES_PER_BAY);
+    }
+
+    #[test]
+    fn test_launch_needs_fuel_beyond_reserve() {
+        assert!(launch_blocker(SHUTTLE_FUEL_CAPACITY_KG, SHUTTLE_FUEL_CAPACITY_KG).is_none());
+        assert!(launch_blocker(700.0, SHUTTLE_FUEL_CAPACITY_KG).is_some());
+    }
+
+    #[test]
+    fn test_burn_turns_back_at_reserve() {
+        let (left, recall) = burn(2000.0, 2000.0, 10.0);
+        assert_eq!(left, 1600.0);
+        assert!(!recall);
+        let (left, recall) = burn(450.0, 2000.0, 2.0);
+        assert_eq!(left, 370.0);
+        assert!(recall);
+    }
+
+    #[test]
+    fn test_refuel_limited_by_tank_and_ship() {
+        assert_eq!(refuel_amount(1000.0, 2000.0, 10_000.0, 1.0), 200.0);
+        assert_eq!(refuel_amount(1900.0, 2000.0, 10_000.0, 1.0), 100.0);
+        assert_eq!(refuel_amount(1000.0, 2000.0, 50.0, 1.0), 50.0);
+        assert_eq!(refuel_amount(2000.0, 2000.0, 50.0, 1.0), 0.0);
+    }
+}
@@ -20,9 +20,24 @@
 //! Performing activities grants experience that slowly raises the
 //! corresponding skill. Training rooms provide a multiplier. Skills
 //! decay when unused but never below their initial floor.
+//!
+//! # Long Voyages
+//!
+//! Over sim-years a competency nobody uses fades ([`disuse_decay`]) toward
+//! half the level its holder qualified at. Crew whose skills have slipped
+//! are due refresher training ([`refresher_due`]) in the school or
+//! library, which wins the skill back ([`refresher_gain`]).
+//!
+//! # Starting Skills
+//!
+//! [`crew_skills`] seeds crew from their department and rank, and
+//! [`passenger_skills`] seeds passengers from their profession and life
+//! stage, so who turns up to a repair or a sickbed changes the outcome.
 
 use serde::{Deserialize, Serialize};
 
+use crate::constants::{activity_types, departments, life_stages, ranks, room_types};
+
 /// All skill categories an agent can have.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SkillCategory {
@@ -153,6 +168,19 @@ impl SkillProfile {
         }
     }
 
+    /// Set a skill floor (clamped to 0.0–1.0).
+    fn set_floor(&mut self, cat: SkillCategory, value: f32) {
+        let v = value.clamp(0.0, 1.0);
+        match cat {
+            SkillCategory::Engineering => self.floor_engineering = v,
+            SkillCategory::Medical => self.floor_medical = v,
+            SkillCategory::Piloting => self.floor_piloting = v,
+            SkillCategory::Science => self.floor_science = v,
+            SkillCategory::Social => self.floor_social = v,
+            SkillCategory::Combat => self.floor_combat = v,
+        }
+    }
+
     /// Add practice hours to a skill.
     fn add_hours(&mut self, cat: SkillCategory, hours: f32) {
         match cat {
@@ -343,6 +371,161 @@ pub fn healing_rate_multiplier(medical_skill: f32) -> f32 {
     0.5 + medical_skill * 1.5
 }
 
+/// Starting skills for a crew member of `department` at `rank`. Training in
+/// the department sets the profile; each rank above crewman adds 0.03 to
+/// the department's specialty and to social. `seed` (0.0–1.0) varies
+/// individuals by up to ±0.05.
+pub fn crew_skills(department: u8, rank: u8, seed: f32) -> SkillProfile {
+    let (eng, med, pilot, sci, soc, combat) = match department {
+        departments::ENGINEERING => (0.7, 0.1, 0.2, 0.3, 0.2, 0.1),
+        departments::MEDICAL => (0.1, 0.8, 0.1, 0.4, 0.5, 0.1),
+        departments::SCIENCE => (0.3, 0.2, 0.1, 0.8, 0.3, 0.1),
+        departments::SECURITY => (0.2, 0.2, 0.2, 0.1, 0.3, 0.8),
+        departments::COMMAND => (0.3, 0.2, 0.5, 0.3, 0.6, 0.3),
+        _ => (0.3, 0.2, 0.2, 0.2, 0.3, 0.2),
+    };
+    let seniority = rank.min(ranks::CAPTAIN) as f32 * 0.03;
+    let vary = |v: f32| (v + (seed.clamp(0.0, 1.0) - 0.5) * 0.1).clamp(0.0, 1.0);
+    let mut p = SkillProfile::new(
+        vary(eng),
+        vary(med),
+        vary(pilot),
+        vary(sci),
+        vary(soc + seniority),
+        vary(combat),
+    );
+    if let Some(cat) = department_specialty(department) {
+        let level = p.get(cat) + seniority;
+        p.set(cat, level);
+        p.set_floor(cat, level);
+    }
+    p
+}
+
+/// The skill a crew `department` works in day to day, if any.
+pub fn department_specialty(department: u8) -> Option<SkillCategory> {
+    match department {
+        departments::ENGINEERING => Some(SkillCategory::Engineering),
+        departments::MEDICAL => Some(SkillCategory::Medical),
+        departments::SCIENCE => Some(SkillCategory::Science),
+        departments::SECURITY => Some(SkillCategory::Combat),
+        departments::COMMAND => Some(SkillCategory::Piloting),
+        _ => None,
+    }
+}
+
+/// Days a skill can go unpracticed before it starts to fade.
+pub const DISUSE_GRACE_DAYS: f64 = 60.0;
+
+/// Skill lost per sim-year of disuse past the grace period.
+pub const DISUSE_DECAY_PER_YEAR: f32 = 0.06;
+
+/// Share of its qualified level a skill never fades below.
+pub const RETAINED_SHARE: f32 = 0.5;
+
+/// Share of its qualified level below which a skill is due a refresher.
+pub const REFRESHER_SHARE: f32 = 0.85;
+
+/// Qualified level below which a skill is not worth a refresher.
+pub const REFRESHER_MIN_LEVEL: f32 = 0.3;
+
+/// Length of one refresher session in hours.
+pub const REFRESHER_HOURS: f32 = 3.0;
+
+/// Skill regained per hour of refresher training.
+pub const REFRESHER_GAIN_PER_HOUR: f32 = 0.01;
+
+/// Skill practiced by someone doing `activity_type`, given their crew
+/// `department` (`None` for passengers): duty exercises the department's
+/// specialty, repairs engineering, operating medical and company social
+/// skills.
+pub fn practiced_skill(activity_type: u8, department: Option<u8>) -> Option<SkillCategory> {
+    match activity_type {
+        activity_types::ON_DUTY => department.and_then(department_specialty),
+        activity_types::MAINTENANCE => Some(SkillCategory::Engineering),
+        activity_types::SURGERY => Some(SkillCategory::Medical),
+        activity_types::SOCIALIZING => Some(SkillCategory::Social),
+        _ => None,
+    }
+}
+
+/// Level of a skill qualified at `qualified` after `hours` more without
+/// practice, `days_unused` days since it was last used. Fades by
+/// [`DISUSE_DECAY_PER_YEAR`] once the grace period is over, never below
+/// [`RETAINED_SHARE`] of the qualified level.
+pub fn disuse_decay(level: f32, qualified: f32, days_unused: f64, hours: f32) -> f32 {
+    let floor = qualified * RETAINED_SHARE;
+    if days_unused < DISUSE_GRACE_DAYS || level <= floor {
+        return level;
+    }
+    (level - DISUSE_DECAY_PER_YEAR * hours / (365.0 * 24.0)).max(floor)
+}
+
+/// Whether a skill qualified at `qualified` has slipped far enough to be
+/// due a refresher.
+pub fn refresher_due(level: f32, qualified: f32) -> bool {
+    qualified >= REFRESHER_MIN_LEVEL && level < qualified * REFRESHER_SHARE
+}
+
+/// Index (in [`SkillCategory::ALL`] order) of the skill most in need of a
+/// refresher, if any is due.
+pub fn most_lapsed(levels: &[f32], qualified: &[f32]) -> Option<usize> {
+    levels
+        .iter()
+        .zip(qualified)
+        .enumerate()
+        .filter(|(_, (&level, &q))| refresher_due(level, q))
+        .min_by(|(_, (la, qa)), (_, (lb, qb))| (*la / *qa).total_cmp(&(*lb / *qb)))
+        .map(|(i, _)| i)
+}
+
+/// Level after `hours` of refresher training, back up to `qualified` at most.
+pub fn refresher_gain(level: f32, qualified: f32, hours: f32) -> f32 {
+    (level + REFRESHER_GAIN_PER_HOUR * hours).min(qualified.max(level))
+}
+
+/// Whether refresher training can be held in a room of `room_type`.
+pub fn is_training_room(room_type: u8) -> bool {
+    matches!(room_type, room_types::SCHOOL | room_types::LIBRARY)
+}
+
+/// Starting skills for a passenger. Adults get their profession's
+/// specialty; children have a fraction of an adult's skills and infants
+/// none. `seed` (0.0–1.0) varies individuals.
+pub fn passenger_skills(profession: &str, life_stage: u8, seed: f32) -> SkillProfile {
+    let seed = seed.clamp(0.0, 1.0);
+    let (mut eng, mut med, pilot, mut sci, mut soc, combat) = (
+        0.1 + seed * 0.2,
+        0.1 + ((seed * 3.0) % 1.0) * 0.15,
+        0.05,
+        0.2 + ((seed * 5.0) % 1.0) * 0.2,
+        0.3 + ((seed * 7.0) % 1.0) * 0.2,
+        0.05,
+    );
+    match profession {
+        "Engineer" | "Architect" => eng = 0.6 + seed * 0.2,
+        "Doctor" => med = 0.65 + seed * 0.2,
+        "Scientist" => sci = 0.65 + seed * 0.2,
+        "Farmer" => sci = sci.max(0.4),
+        "Colonist" => eng = eng.max(0.3),
+        "Teacher" | "Merchant" | "Writer" | "Artist" => soc = 0.55 + seed * 0.2,
+        _ => {}
+    }
+    let scale = match life_stage {
+        life_stages::INFANT => 0.0,
+        life_stages::CHILD => 0.3,
+        _ => 1.0,
+    };
+    SkillProfile::new(
+        eng * scale,
+        med * scale,
+        pilot * scale,
+        sci * scale,
+        soc * scale,
+        combat * scale,
+    )
+}
+
 /// Task difficulty mapping for common ship activities.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskDifficulty {
@@ -643,6 +826,82 @@ mod tests {
         }
     }
 
+    #[test]
+    fn crew_skills_follow_department_and_rank() {
+        let engineer = crew_skills(departments::ENGINEERING, ranks::CREWMAN, 0.5);
+        let medic = crew_skills(departments::MEDICAL, ranks::CREWMAN, 0.5);
+        assert!(engineer.engineering > medic.engineering);
+        assert!(medic.medical > engineer.medical);
+        let chief = crew_skills(departments::ENGINEERING, ranks::COMMANDER, 0.5);
+        assert!(chief.engineering > engineer.engineering);
+        assert_eq!(chief.floor(SkillCategory::Engineering), chief.engineering);
+        // Seeds vary individuals without leaving 0.0–1.0
+        let low = crew_skills(departments::SECURITY, ranks::CAPTAIN, 0.0);
+        let high = crew_skills(departments::SECURITY, ranks::CAPTAIN, 1.0);
+        assert!(high.combat > low.combat);
+        assert!(high.combat <= 1.0);
+    }
+
+    #[test]
+    fn passenger_skills_follow_profession_and_age() {
+        let doctor = passenger_skills("Doctor", life_stages::ADULT, 0.3);
+        let writer = passenger_skills("Writer", life_stages::ADULT, 0.3);
+        assert!(doctor.medical > writer.medical);
+        assert!(writer.social > doctor.social);
+        let student = passenger_skills("Student", life_stages::CHILD, 0.3);
+        assert!(student.engineering < doctor.engineering);
+        let infant = passenger_skills("Infant", life_stages::INFANT, 0.3);
+        for cat in SkillCategory::ALL {
+            assert_eq!(infant.get(cat), 0.0);
+        }
+    }
+
+    #[test]
+    fn unused_skills_fade_after_grace() {
+        let year = 365.0 * 24.0;
+        assert_eq!(disuse_decay(0.6, 0.6, 30.0, year), 0.6);
+        let faded = disuse_decay(0.6, 0.6, 90.0, year);
+        assert!((faded - 0.54).abs() < 1e-5, "{faded}");
+        // Decades of disuse leave half the qualified level
+        assert_eq!(disuse_decay(0.6, 0.6, 9000.0, 20.0 * year), 0.3);
+        assert_eq!(disuse_decay(0.2, 0.6, 9000.0, year), 0.2);
+    }
+
+    #[test]
+    fn refreshers_target_the_most_lapsed_skill() {
+        assert!(!refresher_due(0.55, 0.6));
+        assert!(refresher_due(0.45, 0.6));
+        // Minor skills are not worth the classroom time
+        assert!(!refresher_due(0.1, 0.2));
+        let levels = [0.7, 0.3, 0.1, 0.35, 0.5, 0.1];
+        let qualified = [0.7, 0.4, 0.2, 0.5, 0.5, 0.1];
+        assert_eq!(most_lapsed(&levels, &qualified), Some(3));
+        assert_eq!(most_lapsed(&qualified, &qualified), None);
+        assert!((refresher_gain(0.4, 0.6, 3.0) - 0.43).abs() < 1e-6);
+        assert_eq!(refresher_gain(0.59, 0.6, 3.0), 0.6);
+        assert!(is_training_room(room_types::SCHOOL));
+        assert!(!is_training_room(room_types::GYM));
+    }
+
+    #[test]
+    fn practice_follows_activity_and_department() {
+        assert_eq!(
+            practiced_skill(activity_types::ON_DUTY, Some(departments::MEDICAL)),
+            Some(SkillCategory::Medical)
+        );
+        assert_eq!(practiced_skill(activity_types::ON_DUTY, None), None);
+        assert_eq!(
+            practiced_skill(activity_types::MAINTENANCE, None),
+            Some(SkillCategory::Engineering)
+        );
+        assert_eq!(
+            practiced_skill(activity_types::SURGERY, None),
+            Some(SkillCategory::Medical)
+        );
+        assert_eq!(practiced_skill(activity_types::SLEEPING, None), None);
+        assert_eq!(department_specialty(departments::OPERATIONS), None);
+    }
+
     #[test]
     fn all_categories_enum() {
         assert_eq!(SkillCategory::ALL.len(), 6);
@@ -0,0 +1,165 @@
+//! Sleep quality — how well people rest where and when they sleep.
+//!
+//! Sleep restores fatigue in proportion to its quality ([`sleep_quality`]):
+//! a crowded cabin, loud rooms next door ([`is_loud`], [`touches`]), a deck
+//! too hot or too cold ([`COMFORTABLE_TEMPERATURE`]) and sleeping out of
+//! step with one's watch ([`on_schedule`]) all cost some of it, as do the
+//! deck's lights outside their night (`lighting::sleep_factor`), down to
+//! [`MIN_QUALITY`] at worst.
+
+use std::ops::RangeInclusive;
+
+use crate::constants::room_types;
+use crate::duty;
+use crate::lighting::sleep_factor;
+use crate::utility::{noise_level, overcrowding_factor};
+
+/// Least sleep quality: even the worst night restores a little.
+pub const MIN_QUALITY: f32 = 0.2;
+
+/// Temperatures (°C) people sleep best in.
+pub const COMFORTABLE_TEMPERATURE: RangeInclusive<f32> = 20.0..=24.0;
+
+/// Gap (m) between rooms still close enough to hear through the wall.
+pub const WALL_GAP: f32 = 1.0;
+
+/// Where and when someone sleeps — pure data, no DB access.
+#[derive(Debug, Clone, Copy)]
+pub struct SleepConditions {
+    /// People in the room.
+    pub occupants: u32,
+    /// Room capacity.
+    pub capacity: u32,
+    /// Loudest neighboring room's noise (see [`noise_level`]), 0.0 without
+    /// any loud neighbors.
+    pub neighbor_noise: f32,
+    /// Temperature on the deck (°C).
+    pub temperature: f32,
+    /// Sleeping in their scheduled sleep window.
+    pub on_schedule: bool,
+    /// Lighting mode on the deck (see `lighting_modes`).
+    pub light_mode: u8,
+}
+
+/// Whether a room of `room_type` is loud enough to keep its neighbors
+/// awake: recreation and engineering spaces, not corridors.
+pub fn is_loud(room_type: u8) -> bool {
+    !room_types::is_corridor(room_type) && noise_level(room_type) >= 0.5
+}
+
+/// Whether rooms `a` and `b`, each (x, y, width, height), lie within
+/// [`WALL_GAP`] of each other.
+pub fn touches(a: (f32, f32, f32, f32), b: (f32, f32, f32, f32)) -> bool {
+    let near = |a0: f32, a_len: f32, b0: f32, b_len: f32| {
+        a0 <= b0 + b_len + WALL_GAP && b0 <= a0 + a_len + WALL_GAP
+    };
+    near(a.0, a.2, b.0, b.2) && near(a.1, a.3, b.1, b.3)
+}
+
+/// Whether sleeping at ship `hour` is in step with `shift` (`None` for
+/// passengers, who sleep overnight).
+pub fn on_schedule(shift: Option<u8>, hour: f32) -> bool {
+    match shift {
+        Some(shift) => duty::is_crew_sleep_time(shift, hour),
+        None => duty::is_passenger_sleep_time(hour),
+    }
+}
+
+/// Quality (MIN_QUALITY–1.0) of sleep under `conditions`.
+pub fn sleep_quality(conditions: &SleepConditions) -> f32 {
+    let crowding =
+        1.0 - 0.25 * overcrowding_factor(conditions.occupants, conditions.capacity).min(2.0);
+    let noise = 1.0 - 0.5 * ((conditions.neighbor_noise - 0.3) / 0.6).clamp(0.0, 1.0);
+    let degrees_off = if conditions.temperature < *COMFORTABLE_TEMPERATURE.start() {
+        COMFORTABLE_TEMPERATURE.start() - conditions.temperature
+    } else {
+        (conditions.temperature - COMFORTABLE_TEMPERATURE.end()).max(0.0)
+    };
+    let warmth = (1.0 - 0.05 * degrees_off).max(0.5);
+    let schedule = if conditions.on_schedule { 1.0 } else { 0.7 };
+    let quality = crowding * noise * warmth * schedule * sleep_factor(conditions.light_mode);
+    if quality.is_finite() {
+        quality.clamp(MIN_QUALITY, 1.0)
+    } else {
+        MIN_QUALITY
+    }
+}
+
+/// Fatigue change an hour `rate` while sleeping at `quality`: recovery
+/// scales with quality.
+pub fn rested_rate(rate: f32, quality: f32) -> f32 {
+    if rate < 0.0 {
+        rate * quality.clamp(0.0, 1.0)
+    } else {
+        rate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::lighting_modes;
+
+    const GOOD_NIGHT: SleepConditions = SleepConditions {
+        occupants: 1,
+        capacity: 2,
+        neighbor_noise: 0.0,
+        temperature: 21.0,
+        on_schedule: true,
+        light_mode: lighting_modes::NIGHT,
+    };
+
+    #[test]
+    fn test_sleep_quality() {
+        assert_eq!(sleep_quality(&GOOD_NIGHT), 1.0);
+        let crowded = SleepConditions {
+            occupants: 4,
+            ..GOOD_NIGHT
+        };
+        let noisy = SleepConditions {
+            neighbor_noise: 0.9,
+            ..GOOD_NIGHT
+        };
+        let cold = SleepConditions {
+            temperature: 10.0,
+            ..GOOD_NIGHT
+        };
+        let off_shift = SleepConditions {
+            on_schedule: false,
+            ..GOOD_NIGHT
+        };
+        let daylight = SleepConditions {
+            light_mode: lighting_modes::DAY,
+            ..GOOD_NIGHT
+        };
+        for worse in [crowded, noisy, cold, off_shift, daylight] {
+            assert!(sleep_quality(&worse) < 1.0);
+        }
+        let worst = SleepConditions {
+            occupants: 10,
+            capacity: 1,
+            neighbor_noise: 1.0,
+            temperature: 40.0,
+            on_schedule: false,
+            light_mode: lighting_modes::DAY,
+        };
+        assert_eq!(sleep_quality(&worst), MIN_QUALITY);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        assert!(is_loud(room_types::ENGINEERING));
+        assert!(is_loud(room_types::BAR));
+        assert!(!is_loud(room_types::LIBRARY));
+        assert!(touches((0.0, 0.0, 4.0, 4.0), (4.5, 0.0, 4.0, 4.0)));
+        assert!(!touches((0.0, 0.0, 4.0, 4.0), (10.0, 0.0, 4.0, 4.0)));
+        assert!(on_schedule(None, 23.0) && !on_schedule(None, 12.0));
+    }
+
+    #[test]
+    fn test_rested_rate() {
+        assert_eq!(rested_rate(-0.15, 1.0), -0.15);
+        assert!(rested_rate(-0.15, 0.5) > -0.15);
+        assert_eq!(rested_rate(0.1, 0.5), 0.1);
+    }
+}
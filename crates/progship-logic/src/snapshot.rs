@@ -0,0 +1,353 @@
+//! Portable simulation state snapshots.
+//!
+//! The server's `export_state` reducer writes a [`StateSnapshot`] as JSON so a
+//! live ship can be pulled out of SpacetimeDB and opened offline, e.g. in the
+//! native viewer. Coordinates and IDs are the server's: rooms are placed by
+//! their center in world meters and people carry absolute world positions.
+//!
+//! Snapshots also carry what the server needs to rebuild a wiped ship: the
+//! generation parameters, the clock, the stores and each person's state.
+//! Generation is deterministic, so `recover_from_snapshot` regenerates the
+//! same ship and lays the snapshot back over it. The server can take them
+//! automatically every [`AUTOSNAPSHOT_INTERVAL_HOURS`] (see
+//! [`autosnapshot_due`]).
+
+use serde::{Deserialize, Serialize};
+
+/// Snapshot format version (increment when the format changes).
+pub const SNAPSHOT_VERSION: u32 = 2;
+
+/// Default simulation hours between automatic snapshots.
+pub const AUTOSNAPSHOT_INTERVAL_HOURS: f64 = 24.0;
+
+/// Default number of automatic snapshots kept on the server.
+pub const AUTOSNAPSHOT_KEEP: u32 = 7;
+
+/// Whole-ship state at one moment of simulation time.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StateSnapshot {
+    /// Snapshot format version.
+    pub version: u32,
+    /// Ship name.
+    pub ship_name: String,
+    /// Simulation time in hours.
+    pub sim_time: f64,
+    /// Number of decks.
+    pub deck_count: u32,
+    /// Crew the ship was generated with.
+    pub crew_count: u32,
+    /// Passengers the ship was generated with.
+    pub passenger_count: u32,
+    /// Generation seed (older snapshots predate seeds and used the default).
+    #[serde(default = "crate::scenarios::default_seed")]
+    pub seed: u64,
+    /// Corridor topology the decks were laid out with
+    /// (`genlib::layout::CorridorTopology` as u8; older snapshots predate
+    /// twin spines and used the central spine).
+    #[serde(default)]
+    pub corridor_topology: u8,
+    /// Curve the bow and stern decks taper along (`genlib::hull::TaperCurve`
+    /// as u8; older snapshots predate taper choices and used elliptical).
+    #[serde(default)]
+    pub hull_taper: u8,
+    /// Scenario the ship was started from ("" for a custom ship, and for
+    /// older snapshots, which predate scenarios).
+    #[serde(default)]
+    pub scenario: String,
+    /// Difficulty (see `difficulties`; older snapshots played on normal).
+    #[serde(default = "crate::scenarios::default_difficulty")]
+    pub difficulty: u8,
+    /// Time acceleration factor.
+    pub time_scale: f32,
+    /// Deaths since mission start.
+    pub death_count: u32,
+    /// Ship-wide stores.
+    pub resources: SnapshotResources,
+    /// Every room, in server ID order.
+    pub rooms: Vec<SnapshotRoom>,
+    /// Every door as a pair of connected room IDs.
+    pub doors: Vec<(u32, u32)>,
+    /// Every living person.
+    pub people: Vec<SnapshotPerson>,
+    /// (room ID, nickname) for rooms the crew renamed.
+    #[serde(default)]
+    pub room_nicknames: Vec<(u32, String)>,
+    /// (deck, nickname) for decks the crew renamed.
+    #[serde(default)]
+    pub deck_nicknames: Vec<(i32, String)>,
+}
+
+/// One room.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotRoom {
+    /// Server room ID.
+    pub id: u32,
+    /// Display name.
+    pub name: String,
+    /// Room type (see `room_types`).
+    pub room_type: u8,
+    /// Deck number (0 = top deck).
+    pub deck: i32,
+    /// Center X in world meters.
+    pub x: f32,
+    /// Center Y in world meters.
+    pub y: f32,
+    /// Extent along X in meters.
+    pub width: f32,
+    /// Extent along Y in meters.
+    pub height: f32,
+    /// Maximum occupancy.
+    pub capacity: u32,
+}
+
+/// Ship-wide stores, in the server's units.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotResources {
+    pub power: f32,
+    pub water: f32,
+    pub oxygen: f32,
+    pub food: f32,
+    pub fuel: f32,
+    pub spare_parts: f32,
+}
+
+/// Crew role of a person.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotCrew {
+    /// Department (see `departments`).
+    pub department: u8,
+    /// Rank (see `ranks`).
+    pub rank: u8,
+    /// Shift (see `shifts`).
+    pub shift: u8,
+    /// Room ID of the duty station.
+    pub duty_station_id: u32,
+}
+
+/// One person.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotPerson {
+    /// Server person ID.
+    pub id: u64,
+    /// First name.
+    pub given_name: String,
+    /// Last name.
+    pub family_name: String,
+    /// Crew role, or `None` for passengers.
+    pub crew: Option<SnapshotCrew>,
+    /// Room the person is in.
+    pub room_id: u32,
+    /// World X in meters.
+    pub x: f32,
+    /// World Y in meters.
+    pub y: f32,
+    /// Hunger, fatigue, social, comfort and hygiene (0.0 satisfied – 1.0 desperate).
+    pub needs: [f32; 5],
+    /// Health (1.0 healthy – 0.0 dead).
+    pub health: f32,
+    /// Morale (1.0 happy – 0.0 despairing).
+    pub morale: f32,
+    /// Current activity (see `activity_types`).
+    pub activity_type: u8,
+}
+
+impl StateSnapshot {
+    /// Encode as JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("snapshot serializes")
+    }
+
+    /// Decode from JSON, rejecting other format versions.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let snapshot: Self = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        if snapshot.version != SNAPSHOT_VERSION {
+            return Err(format!(
+                "snapshot version mismatch: expected {}, found {}",
+                SNAPSHOT_VERSION, snapshot.version
+            ));
+        }
+        Ok(snapshot)
+    }
+
+    /// Nickname of room `id`, if it has one.
+    pub fn room_nickname(&self, id: u32) -> Option<&str> {
+        self.room_nicknames
+            .iter()
+            .find(|(room_id, _)| *room_id == id)
+            .map(|(_, name)| name.as_str())
+    }
+
+    /// Nickname of `deck`, if it has one.
+    pub fn deck_nickname(&self, deck: i32) -> Option<&str> {
+        self.deck_nicknames
+            .iter()
+            .find(|(d, _)| *d == deck)
+            .map(|(_, name)| name.as_str())
+    }
+
+    /// Whether `bytes` look like a JSON snapshot rather than a binary save.
+    pub fn sniff(bytes: &[u8]) -> bool {
+        bytes
+            .iter()
+            .find(|b| !b.is_ascii_whitespace())
+            .is_some_and(|&b| b == b'{')
+    }
+}
+
+/// Whether an automatic snapshot falls due in the tick that advanced the
+/// clock by `delta_hours` to `sim_time`. A zero interval disables them.
+pub fn autosnapshot_due(sim_time: f64, delta_hours: f64, interval_hours: f64) -> bool {
+    if interval_hours <= 0.0 {
+        return false;
+    }
+    let interval = |t: f64| (t / interval_hours).floor();
+    interval(sim_time) != interval(sim_time - delta_hours)
+}
+
+/// Which of the export `ids` to delete so only the newest `keep` remain.
+pub fn exports_to_prune(mut ids: Vec<u64>, keep: u32) -> Vec<u64> {
+    ids.sort_unstable();
+    let excess = ids.len().saturating_sub(keep as usize);
+    ids.truncate(excess);
+    ids
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{departments, difficulties, room_types};
+
+    fn sample() -> StateSnapshot {
+        StateSnapshot {
+            version: SNAPSHOT_VERSION,
+            ship_name: "Test".into(),
+            sim_time: 12.5,
+            deck_count: 1,
+            crew_count: 1,
+            passenger_count: 0,
+            seed: 42,
+            corridor_topology: 1,
+            hull_taper: 2,
+            scenario: "tutorial".into(),
+            difficulty: difficulties::TUTORIAL,
+            time_scale: 1.0,
+            death_count: 0,
+            resources: SnapshotResources {
+                food: 100.0,
+                ..Default::default()
+            },
+            rooms: vec![SnapshotRoom {
+                id: 0,
+                name: "Bridge".into(),
+                room_type: room_types::BRIDGE,
+                deck: 0,
+                x: 10.0,
+                y: 5.0,
+                width: 8.0,
+                height: 6.0,
+                capacity: 10,
+            }],
+            doors: vec![(0, 1)],
+            people: vec![SnapshotPerson {
+                id: 7,
+                given_name: "Ada".into(),
+                family_name: "Marsh".into(),
+                crew: Some(SnapshotCrew {
+                    department: departments::COMMAND,
+                    rank: 7,
+                    shift: 0,
+                    duty_station_id: 0,
+                }),
+                room_id: 0,
+                x: 11.0,
+                y: 4.0,
+                needs: [0.1, 0.2, 0.3, 0.4, 0.5],
+                health: 0.9,
+                morale: 0.7,
+                activity_type: 9,
+            }],
+            room_nicknames: vec![(0, "The Perch".into())],
+            deck_nicknames: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let snapshot = sample();
+        let json = snapshot.to_json();
+        assert!(StateSnapshot::sniff(json.as_bytes()));
+        assert_eq!(StateSnapshot::from_json(&json).unwrap(), snapshot);
+
+        // Snapshots from before generation seeds get the default seed
+        let mut unseeded = snapshot;
+        unseeded.seed = 9;
+        let json = unseeded.to_json().replace("\"seed\":9,", "");
+        assert_eq!(StateSnapshot::from_json(&json).unwrap().seed, 42);
+
+        // and a central spine
+        let json = sample().to_json().replace("\"corridor_topology\":1,", "");
+        assert_eq!(
+            StateSnapshot::from_json(&json).unwrap().corridor_topology,
+            0
+        );
+
+        // and an elliptical taper
+        let json = sample().to_json().replace("\"hull_taper\":2,", "");
+        assert_eq!(StateSnapshot::from_json(&json).unwrap().hull_taper, 0);
+
+        // and a custom ship on normal difficulty
+        let json = sample()
+            .to_json()
+            .replace("\"scenario\":\"tutorial\",\"difficulty\":0,", "");
+        let custom = StateSnapshot::from_json(&json).unwrap();
+        assert_eq!(custom.scenario, "");
+        assert_eq!(custom.difficulty, difficulties::NORMAL);
+    }
+
+    #[test]
+    fn test_nicknames_are_optional() {
+        let snapshot = sample();
+        assert_eq!(snapshot.room_nickname(0), Some("The Perch"));
+        assert_eq!(snapshot.room_nickname(1), None);
+        assert_eq!(snapshot.deck_nickname(0), None);
+        let json = snapshot.to_json().replace(
+            ",\"room_nicknames\":[[0,\"The Perch\"]],\"deck_nicknames\":[]",
+            "",
+        );
+        assert!(StateSnapshot::from_json(&json)
+            .unwrap()
+            .room_nicknames
+            .is_empty());
+    }
+
+    #[test]
+    fn test_version_mismatch_rejected() {
+        let mut snapshot = sample();
+        snapshot.version = SNAPSHOT_VERSION + 1;
+        let err = StateSnapshot::from_json(&snapshot.to_json()).unwrap_err();
+        assert!(err.contains("version"));
+        assert!(StateSnapshot::from_json("not json").is_err());
+    }
+
+    #[test]
+    fn test_sniff() {
+        assert!(StateSnapshot::sniff(b"  \n{\"version\":1}"));
+        assert!(!StateSnapshot::sniff(&[1, 0, 0, 0, b'{']));
+        assert!(!StateSnapshot::sniff(b""));
+    }
+
+    #[test]
+    fn test_autosnapshot_due() {
+        assert!(!autosnapshot_due(23.9, 0.1, 24.0));
+        assert!(autosnapshot_due(24.05, 0.1, 24.0));
+        assert!(autosnapshot_due(50.0, 30.0, 24.0));
+        assert!(!autosnapshot_due(24.05, 0.1, 0.0));
+    }
+
+    #[test]
+    fn test_exports_to_prune_keeps_newest() {
+        assert_eq!(exports_to_prune(vec![5, 2, 9, 7], 2), vec![2, 5]);
+        assert!(exports_to_prune(vec![1, 2], 3).is_empty());
+        assert_eq!(exports_to_prune(vec![3, 1], 0), vec![1, 3]);
+    }
+}
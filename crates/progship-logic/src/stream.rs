@@ -0,0 +1,196 @@
+//! Stream hooks — what a live stream's chat can do to the ship.
+//!
+//! A chat bot on the stream operator allow-list (the server's
+//! `configure_stream`) passes on chat's wishes: a vote for one of a
+//! handful of ship events ([`vote_effect`]), a subscriber's name for the
+//! next newborn ([`clean_viewer_name`], [`is_newborn`]) or an NPC to put
+//! in the spotlight for [`SPOTLIGHT_HOURS`]. Only the votes the streamer
+//! allowed go through ([`vote_allowed`]), and each kind of action waits out
+//! a cooldown in real time ([`cooldown_remaining`]) so chat cannot flood
+//! the ship.
+
+use crate::constants::{departments, event_types, order_kinds, stream_actions, stream_votes};
+use crate::milestones::born_aboard;
+
+/// Votes allowed until the streamer says otherwise: everything but fights.
+pub const DEFAULT_ALLOWED_VOTES: u32 = 1 << stream_votes::SURPRISE_INSPECTION
+    | 1 << stream_votes::CELEBRATION
+    | 1 << stream_votes::DISCOVERY
+    | 1 << stream_votes::QUARTERS_DRILL;
+
+/// Seconds between stream actions of each kind, indexed by stream_actions.
+pub const DEFAULT_COOLDOWN_SECONDS: [u32; stream_actions::COUNT] = [300, 3600, 60];
+
+/// Sim hours an NPC stays in the spotlight.
+pub const SPOTLIGHT_HOURS: f64 = 2.0;
+
+/// Sim hours after birth a baby can still be named after a subscriber.
+pub const NEWBORN_HOURS: f64 = 7.0 * 24.0;
+
+/// Longest subscriber name given to a newborn, in characters.
+pub const MAX_VIEWER_NAME_LEN: usize = 20;
+
+/// Stream actions kept in the log.
+pub const STREAM_ACTION_KEEP: u32 = 50;
+
+/// Salt of the [`roll`](crate::numeric::roll) that picks where a vote
+/// plays out.
+pub const VOTE_SALT: u64 = 0x7073;
+
+/// Sim hours a voted order lasts.
+const VOTED_ORDER_HOURS: f64 = 2.0;
+
+/// What a chat vote does to the ship.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VoteEffect {
+    /// Give a standing order (see order_kinds) to `department` for `hours`.
+    Order {
+        kind: u8,
+        department: u8,
+        hours: f64,
+    },
+    /// Start an event (see event_types) of `severity`.
+    Event { event_type: u8, severity: f32 },
+}
+
+/// The effect of a chat `vote` (see stream_votes), or `None` for an
+/// unknown vote.
+pub fn vote_effect(vote: u8) -> Option<VoteEffect> {
+    let order = |kind, department| VoteEffect::Order {
+        kind,
+        department,
+        hours: VOTED_ORDER_HOURS,
+    };
+    let event = |event_type, severity| VoteEffect::Event {
+        event_type,
+        severity,
+    };
+    match vote {
+        stream_votes::SURPRISE_INSPECTION => {
+            Some(order(order_kinds::SECURITY_SWEEP, departments::SECURITY))
+        }
+        stream_votes::CELEBRATION => Some(event(event_types::CELEBRATION, 0.5)),
+        stream_votes::DISCOVERY => Some(event(event_types::DISCOVERY, 0.5)),
+        stream_votes::QUARTERS_DRILL => Some(order(order_kinds::TO_QUARTERS, departments::COMMAND)),
+        stream_votes::ALTERCATION => Some(event(event_types::ALTERCATION, 0.3)),
+        _ => None,
+    }
+}
+
+/// Whether `vote` is on the `allowed` bitmask (one bit per stream_votes
+/// ID).
+pub fn vote_allowed(allowed: u32, vote: u8) -> bool {
+    vote < 32 && allowed & (1 << vote) != 0
+}
+
+/// Whether `caller` may configure the stream: only its `owner` once it has
+/// one, and before that only the `module` itself, so no client can claim a
+/// stream nobody owns.
+pub fn may_configure<T: PartialEq>(owner: Option<&T>, caller: &T, module: &T) -> bool {
+    owner.unwrap_or(module) == caller
+}
+
+/// Seconds until an action last taken at `last_micros` (microseconds since
+/// the Unix epoch, `None` if never) may be taken again at `now_micros`.
+pub fn cooldown_remaining(last_micros: Option<i64>, now_micros: i64, cooldown_seconds: u32) -> u64 {
+    let Some(last) = last_micros else {
+        return 0;
+    };
+    let ready_at = last.saturating_add(cooldown_seconds as i64 * 1_000_000);
+    (ready_at.saturating_sub(now_micros).max(0) as u64).div_ceil(1_000_000)
+}
+
+/// A subscriber's name fit for a newborn: letters, digits and hyphens only,
+/// at most [`MAX_VIEWER_NAME_LEN`] characters and capitalized, so
+/// "xX_stargazer_Xx" becomes "Xxstargazerxx". `None` when nothing is left.
+pub fn clean_viewer_name(name: &str) -> Option<String> {
+    let kept: String = name
+        .chars()
+        .filter(|c| c.is_alphanumeric() || *c == '-')
+        .take(MAX_VIEWER_NAME_LEN)
+        .collect();
+    let kept = kept.trim_matches('-').to_lowercase();
+    let mut chars = kept.chars();
+    let first = chars.next()?;
+    Some(first.to_uppercase().chain(chars).collect())
+}
+
+/// Whether someone born at `birth_date` is still a newborn at `sim_time`.
+pub fn is_newborn(birth_date: f64, sim_time: f64) -> bool {
+    born_aboard(birth_date) && sim_time - birth_date <= NEWBORN_HOURS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_votes() {
+        assert_eq!(
+            vote_effect(stream_votes::SURPRISE_INSPECTION),
+            Some(VoteEffect::Order {
+                kind: order_kinds::SECURITY_SWEEP,
+                department: departments::SECURITY,
+                hours: VOTED_ORDER_HOURS,
+            })
+        );
+        assert!(matches!(
+            vote_effect(stream_votes::CELEBRATION),
+            Some(VoteEffect::Event {
+                event_type: event_types::CELEBRATION,
+                ..
+            })
+        ));
+        assert_eq!(vote_effect(99), None);
+
+        assert!(vote_allowed(DEFAULT_ALLOWED_VOTES, stream_votes::DISCOVERY));
+        assert!(!vote_allowed(
+            DEFAULT_ALLOWED_VOTES,
+            stream_votes::ALTERCATION
+        ));
+        assert!(!vote_allowed(u32::MAX, 40));
+    }
+
+    #[test]
+    fn test_only_the_owner_configures() {
+        let (module, owner, viewer) = (0u8, 1u8, 2u8);
+        assert!(may_configure(Some(&owner), &owner, &module));
+        assert!(!may_configure(Some(&owner), &viewer, &module));
+        // Nobody can claim an unowned stream but the module
+        assert!(!may_configure(None, &viewer, &module));
+        assert!(may_configure(None, &module, &module));
+    }
+
+    #[test]
+    fn test_cooldown_remaining() {
+        assert_eq!(cooldown_remaining(None, 5_000_000, 60), 0);
+        assert_eq!(cooldown_remaining(Some(0), 30_000_000, 60), 30);
+        assert_eq!(cooldown_remaining(Some(0), 59_500_000, 60), 1);
+        assert_eq!(cooldown_remaining(Some(0), 60_000_000, 60), 0);
+        assert_eq!(cooldown_remaining(Some(0), 90_000_000, 60), 0);
+    }
+
+    #[test]
+    fn test_clean_viewer_name() {
+        assert_eq!(
+            clean_viewer_name("xX_stargazer_Xx").as_deref(),
+            Some("Xxstargazerxx")
+        );
+        assert_eq!(clean_viewer_name("mary-jane").as_deref(), Some("Mary-jane"));
+        assert_eq!(clean_viewer_name("  Ünal ").as_deref(), Some("Ünal"));
+        assert_eq!(clean_viewer_name("__--__"), None);
+        assert_eq!(
+            clean_viewer_name(&"a".repeat(40)).map(|n| n.chars().count()),
+            Some(MAX_VIEWER_NAME_LEN)
+        );
+    }
+
+    #[test]
+    fn test_is_newborn() {
+        assert!(is_newborn(100.0, 101.0));
+        assert!(is_newborn(100.0, 100.0 + NEWBORN_HOURS));
+        assert!(!is_newborn(100.0, 101.0 + NEWBORN_HOURS));
+        // Born before departure
+        assert!(!is_newborn(-10.0, 0.0));
+    }
+}
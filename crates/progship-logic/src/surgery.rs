@@ -0,0 +1,147 @@
+//! Surgery — operating on the critically injured, and how it turns out.
+//!
+//! A patient at critical triage needs an operation ([`needs_surgery`]) in
+//! an operating theatre, by medical crew with at least
+//! [`MIN_SURGEON_SKILL`], the best placed of them first
+//! ([`surgeon_score`]). The operation takes longer the less skilled the
+//! surgeon ([`operation_hours`]). Its outcome ([`surgery_outcome`]) is a
+//! skill check against the theatre's condition ([`facility_condition`]:
+//! the medical systems' health, power and supplies) and how far gone the
+//! patient is: a success brings them out of danger, a complication leaves
+//! them no better off and at worst they die on the table
+//! ([`health_after`]). Nobody is operated on again within
+//! [`RECOVERY_HOURS`] of their last operation.
+
+use crate::constants::{room_types, surgery_outcomes, triage_levels};
+
+/// Medical skill a crew member needs to operate.
+pub const MIN_SURGEON_SKILL: f32 = 0.5;
+
+/// Hours after an operation before the patient may be operated on again.
+pub const RECOVERY_HOURS: f64 = 24.0;
+
+/// Hours a scheduled operation waits for surgeon and patient to reach the
+/// theatre before it is called off.
+pub const SCHEDULE_TIMEOUT_HOURS: f64 = 4.0;
+
+/// Health a successful operation brings a patient up to at least: out of
+/// critical danger.
+pub const RECOVERED_HEALTH: f32 = 0.35;
+
+/// Salt of the [`roll`](crate::numeric::roll) for an operation's outcome.
+pub const OUTCOME_SALT: u64 = 0x5e6e;
+
+/// Whether operations are performed in a room of `room_type`.
+pub fn is_theatre(room_type: u8) -> bool {
+    room_type == room_types::SURGERY
+}
+
+/// Whether a patient at `triage` level needs an operation.
+pub fn needs_surgery(triage: u8) -> bool {
+    triage == triage_levels::CRITICAL
+}
+
+/// How well placed a crew member with `skill` is to operate (higher
+/// first): the skilled, then those on watch.
+pub fn surgeon_score(skill: f32, on_duty: bool) -> f32 {
+    skill + if on_duty { 0.2 } else { 0.0 }
+}
+
+/// Hours an operation takes a surgeon of `skill`: two for a master, up to
+/// four.
+pub fn operation_hours(skill: f32) -> f32 {
+    2.0 + 2.0 * (1.0 - skill.clamp(0.0, 1.0))
+}
+
+/// Condition of an operating theatre (0.0-1.0): the medical systems'
+/// `system_health`, halved without power and again without supplies.
+pub fn facility_condition(system_health: f32, powered: bool, stocked: bool) -> f32 {
+    let halved = |yes: bool| if yes { 1.0 } else { 0.5 };
+    system_health.clamp(0.0, 1.0) * halved(powered) * halved(stocked)
+}
+
+/// Chances (complication, death) of an operation by a surgeon of `skill`
+/// in a theatre in `condition` on a patient at `health`. Death is likelier
+/// the worse the patient, and both the less skill and the poorer the
+/// theatre.
+pub fn outcome_chances(skill: f32, condition: f32, health: f32) -> (f32, f32) {
+    let mastery = (skill * condition).clamp(0.0, 1.0);
+    // How far below the critical threshold they are
+    let frailty = (1.0 - health / 0.2).clamp(0.0, 1.0);
+    let death = 0.02 + 0.25 * (1.0 - mastery) * (0.5 + 0.5 * frailty);
+    let complication = 0.1 + 0.4 * (1.0 - mastery);
+    (complication, death)
+}
+
+/// Outcome (see `surgery_outcomes`) of an operation given `roll` in
+/// [0, 1) and the chances from [`outcome_chances`].
+pub fn surgery_outcome(roll: f32, skill: f32, condition: f32, health: f32) -> u8 {
+    let (complication, death) = outcome_chances(skill, condition, health);
+    if roll < death {
+        surgery_outcomes::DEATH
+    } else if roll < death + complication {
+        surgery_outcomes::COMPLICATION
+    } else {
+        surgery_outcomes::SUCCESS
+    }
+}
+
+/// Health of a patient at `health` after an operation with `outcome`.
+pub fn health_after(outcome: u8, health: f32) -> f32 {
+    match outcome {
+        surgery_outcomes::SUCCESS => health.max(RECOVERED_HEALTH),
+        surgery_outcomes::DEATH => 0.0,
+        _ => health,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_who_needs_surgery() {
+        assert!(needs_surgery(triage_levels::CRITICAL));
+        assert!(!needs_surgery(triage_levels::SERIOUS));
+        assert!(is_theatre(room_types::SURGERY));
+        assert!(!is_theatre(room_types::HOSPITAL_WARD));
+        assert!(surgeon_score(0.7, false) > surgeon_score(0.5, true) - 0.1);
+        assert!(operation_hours(1.0) < operation_hours(0.5));
+        assert_eq!(operation_hours(2.0), 2.0);
+    }
+
+    #[test]
+    fn test_facility_condition() {
+        assert_eq!(facility_condition(1.0, true, true), 1.0);
+        assert_eq!(facility_condition(0.8, false, true), 0.4);
+        assert_eq!(facility_condition(1.0, false, false), 0.25);
+    }
+
+    #[test]
+    fn test_outcome_chances() {
+        let (complication, death) = outcome_chances(0.9, 1.0, 0.15);
+        let (poor_complication, poor_death) = outcome_chances(0.9, 0.25, 0.15);
+        assert!(poor_death > death && poor_complication > complication);
+        assert!(outcome_chances(0.6, 1.0, 0.02).1 > outcome_chances(0.6, 1.0, 0.18).1);
+        let (complication, death) = outcome_chances(0.0, 0.0, 0.0);
+        assert!(complication + death < 1.0, "some chance of success");
+    }
+
+    #[test]
+    fn test_surgery_outcome() {
+        let (complication, death) = outcome_chances(0.7, 1.0, 0.1);
+        let outcome = |roll: f32| surgery_outcome(roll, 0.7, 1.0, 0.1);
+        assert_eq!(outcome(0.0), surgery_outcomes::DEATH);
+        assert_eq!(
+            outcome(death + complication / 2.0),
+            surgery_outcomes::COMPLICATION
+        );
+        assert_eq!(outcome(0.99), surgery_outcomes::SUCCESS);
+        assert_eq!(
+            health_after(surgery_outcomes::SUCCESS, 0.1),
+            RECOVERED_HEALTH
+        );
+        assert_eq!(health_after(surgery_outcomes::COMPLICATION, 0.1), 0.1);
+        assert_eq!(health_after(surgery_outcomes::DEATH, 0.1), 0.0);
+    }
+}
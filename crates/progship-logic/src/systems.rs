@@ -112,6 +112,19 @@ impl PowerVariant {
             Self::RTG,
         ]
     }
+
+    /// Whether the plant is a reactor fed by a fuel injection system.
+    pub fn burns_fuel(&self) -> bool {
+        matches!(
+            self,
+            Self::FissionReactor | Self::FusionReactor | Self::AntimatterReactor
+        )
+    }
+
+    /// Whether the reactor needs magnetic containment.
+    pub fn needs_containment(&self) -> bool {
+        matches!(self, Self::FusionReactor | Self::AntimatterReactor)
+    }
 }
 
 // ============================================================================
@@ -593,7 +606,7 @@ mod tests {
         // All variants should have tech level 1-5
         for v in PowerVariant::all() {
             let tl = v.spec().min_tech_level;
-            assert!(tl >= 1 && tl <= 5, "{}: tech level {tl}", v.spec().name);
+            assert!((1..=5).contains(&tl), "{}: tech level {tl}", v.spec().name);
         }
     }
 
@@ -0,0 +1,226 @@
+//! Thermal model — where the ship's heat comes from and how it is shed.
+//!
+//! The reactors give off heat for every kilowatt drawn from them, the
+//! thrust chambers while the engines burn, and people and the cooks at
+//! work in the galleys warm the rooms they are in ([`HeatSources`]). The
+//! coolant pumps carry machinery heat, and the air handlers' chillers cabin
+//! heat, into one coolant loop, whose radiators shed it to space the
+//! faster the hotter it runs ([`radiated_kw`], [`coolant_after`]).
+//! Radiators are sized at launch over the heat of the engines burning
+//! ([`design_heat_kw`]), so the loop settles well below [`RATED_C`] on a
+//! sound ship. With radiators or pumps failing it runs hot: past
+//! [`RATED_C`] the chillers lose their grip on the rooms ([`chilling`])
+//! and past [`OVERHEAT_C`] the machinery on the loop is damaged
+//! ([`overheat_damage`]); machinery whose heat the pumps cannot carry off
+//! wears and warms its room ([`uncooled_damage`], [`MACHINERY_HEAT_C`]).
+
+use crate::atmosphere::atmo_constants::STANDARD_ROOM_M2;
+
+/// kW of heat the reactors give off per kW drawn from them.
+pub const REACTOR_HEAT_PER_KW: f32 = 1.0;
+
+/// kW of heat a thrust chamber gives off at full thrust.
+pub const ENGINE_HEAT_KW: f32 = 1000.0;
+
+/// kW of heat a person gives off.
+pub const BODY_HEAT_KW: f32 = 0.1;
+
+/// kW of heat a cook at work in a galley gives off, stoves and all.
+pub const GALLEY_HEAT_KW: f32 = 1.0;
+
+/// Temperature (°C) the radiators shed heat toward.
+pub const SINK_C: f32 = -50.0;
+
+/// Coolant temperature (°C) at which radiators shed their rated heat.
+pub const RATED_C: f32 = 40.0;
+
+/// Coolant temperature (°C) past which the machinery on the loop is
+/// damaged.
+pub const OVERHEAT_C: f32 = 60.0;
+
+/// Hottest the coolant can run (°C).
+pub const MAX_COOLANT_C: f32 = 300.0;
+
+/// Headroom the radiators are sized with over the design heat.
+pub const RADIATOR_MARGIN: f32 = 1.25;
+
+/// Hours of design heat that warm the coolant loop by a degree.
+pub const THERMAL_HOURS: f32 = 0.25;
+
+/// Health lost an hour per degree the coolant runs over `OVERHEAT_C`.
+pub const OVERHEAT_DAMAGE: f32 = 0.002;
+
+/// Health lost an hour by machinery none of whose heat is carried off.
+pub const UNCOOLED_DAMAGE: f32 = 0.02;
+
+/// Degrees an hour machinery none of whose heat is carried off warms its
+/// room.
+pub const MACHINERY_HEAT_C: f32 = 5.0;
+
+/// Degrees a kWh of heat warms a standard cabin's air.
+pub const ROOM_C_PER_KWH: f32 = 2.5;
+
+/// The ship's heat by where it comes from, in kW.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct HeatSources {
+    pub reactor_kw: f32,
+    pub engine_kw: f32,
+    pub cabin_kw: f32,
+    pub galley_kw: f32,
+}
+
+impl HeatSources {
+    /// The ship's heat from reactors drawn on for `drawn_kw`, `thrust`
+    /// (0–1 of full, across `chambers` thrust chambers), `people` awake and
+    /// `cooks` at work.
+    pub fn new(drawn_kw: f32, chambers: f32, thrust: f32, people: f32, cooks: f32) -> Self {
+        Self {
+            reactor_kw: drawn_kw.max(0.0) * REACTOR_HEAT_PER_KW,
+            engine_kw: chambers.max(0.0) * thrust.clamp(0.0, 1.0) * ENGINE_HEAT_KW,
+            cabin_kw: people.max(0.0) * BODY_HEAT_KW,
+            galley_kw: cooks.max(0.0) * GALLEY_HEAT_KW,
+        }
+    }
+
+    /// Heat of the reactors and engines, carried by the coolant pumps.
+    pub fn machinery_kw(&self) -> f32 {
+        self.reactor_kw + self.engine_kw
+    }
+
+    /// Heat of people and galleys, taken out of the rooms' air by the air
+    /// handlers' chillers.
+    pub fn rooms_kw(&self) -> f32 {
+        self.cabin_kw + self.galley_kw
+    }
+
+    /// All the ship's heat.
+    pub fn total_kw(&self) -> f32 {
+        self.machinery_kw() + self.rooms_kw()
+    }
+}
+
+/// Heat the radiators are designed to shed at `RATED_C`: reactors of
+/// `reactor_kw` at full output, `chambers` thrust chambers burning and
+/// `people` aboard.
+pub fn design_heat_kw(reactor_kw: f32, chambers: f32, people: f32) -> f32 {
+    HeatSources::new(reactor_kw, chambers, 1.0, people, 0.0).total_kw() * RADIATOR_MARGIN
+}
+
+/// Heat (kW) radiators rated for `radiator_kw` at `efficiency` (0–1) shed
+/// with the coolant at `temperature`.
+pub fn radiated_kw(radiator_kw: f32, efficiency: f32, temperature: f32) -> f32 {
+    let drive = ((temperature - SINK_C) / (RATED_C - SINK_C)).max(0.0);
+    radiator_kw.max(0.0) * efficiency.clamp(0.0, 1.0) * drive
+}
+
+/// Coolant temperature at which radiators rated for `radiator_kw` shed
+/// `heat_kw`.
+pub fn equilibrium_c(heat_kw: f32, radiator_kw: f32) -> f32 {
+    if radiator_kw <= 0.0 {
+        return MAX_COOLANT_C;
+    }
+    (SINK_C + (RATED_C - SINK_C) * heat_kw.max(0.0) / radiator_kw).clamp(SINK_C, MAX_COOLANT_C)
+}
+
+/// Coolant temperature after `hours` taking in `heat_kw` and radiating
+/// `radiated_kw`, in a loop of `heat_capacity` kWh per degree.
+pub fn coolant_after(
+    temperature: f32,
+    heat_kw: f32,
+    radiated_kw: f32,
+    heat_capacity: f32,
+    hours: f32,
+) -> f32 {
+    if heat_capacity <= 0.0 || !temperature.is_finite() {
+        return equilibrium_c(0.0, 1.0);
+    }
+    let change = (heat_kw - radiated_kw) * hours.max(0.0) / heat_capacity;
+    (temperature + change).clamp(SINK_C, MAX_COOLANT_C)
+}
+
+/// Share (0–1) of their grip the chillers keep on the rooms' air with the
+/// coolant at `temperature`: all of it up to `RATED_C`, none at
+/// `OVERHEAT_C`.
+pub fn chilling(temperature: f32) -> f32 {
+    ((OVERHEAT_C - temperature) / (OVERHEAT_C - RATED_C)).clamp(0.0, 1.0)
+}
+
+/// Health machinery on a loop at `temperature` loses in `hours`.
+pub fn overheat_damage(temperature: f32, hours: f32) -> f32 {
+    (temperature - OVERHEAT_C).max(0.0) * OVERHEAT_DAMAGE * hours.max(0.0)
+}
+
+/// Health working machinery loses in `hours` with `coolant_flow` (0–1) of
+/// its heat carried off.
+pub fn uncooled_damage(coolant_flow: f32, hours: f32) -> f32 {
+    (1.0 - coolant_flow.clamp(0.0, 1.0)) * UNCOOLED_DAMAGE * hours.max(0.0)
+}
+
+/// Degrees an hour `heat_kw` warms the air of a room of `floor_area` m².
+pub fn room_heating(heat_kw: f32, floor_area: f32) -> f32 {
+    heat_kw.max(0.0) * ROOM_C_PER_KWH * STANDARD_ROOM_M2 / floor_area.max(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_heat_sources() {
+        let heat = HeatSources::new(2000.0, 2.0, 0.5, 100.0, 4.0);
+        assert_eq!(heat.reactor_kw, 2000.0);
+        assert_eq!(heat.engine_kw, ENGINE_HEAT_KW);
+        assert_eq!(heat.cabin_kw, 10.0);
+        assert_eq!(heat.galley_kw, 4.0);
+        assert_eq!(heat.machinery_kw(), 3000.0);
+        assert_eq!(heat.rooms_kw(), 14.0);
+        assert_eq!(heat.total_kw(), 3014.0);
+        assert_eq!(HeatSources::new(-1.0, 2.0, 0.0, 0.0, 0.0).total_kw(), 0.0);
+        assert!(
+            design_heat_kw(1000.0, 1.0, 100.0)
+                > HeatSources::new(1000.0, 1.0, 1.0, 100.0, 0.0).total_kw()
+        );
+    }
+
+    #[test]
+    fn test_radiators() {
+        assert_eq!(radiated_kw(1000.0, 1.0, RATED_C), 1000.0);
+        assert_eq!(radiated_kw(1000.0, 0.5, RATED_C), 500.0);
+        assert_eq!(radiated_kw(1000.0, 1.0, SINK_C), 0.0);
+        assert!(radiated_kw(1000.0, 1.0, 80.0) > 1000.0);
+        assert_eq!(equilibrium_c(1000.0, 1000.0), RATED_C);
+        // A sound ship at its design heat settles below the rating
+        let design = design_heat_kw(1000.0, 1.0, 100.0);
+        assert!(equilibrium_c(design / RADIATOR_MARGIN, design) < RATED_C);
+        assert_eq!(equilibrium_c(100.0, 0.0), MAX_COOLANT_C);
+    }
+
+    #[test]
+    fn test_coolant_loop() {
+        assert_eq!(coolant_after(20.0, 100.0, 100.0, 10.0, 1.0), 20.0);
+        assert_eq!(coolant_after(20.0, 200.0, 100.0, 10.0, 1.0), 30.0);
+        assert_eq!(coolant_after(20.0, 0.0, 1e9, 10.0, 1.0), SINK_C);
+        assert_eq!(coolant_after(20.0, 1e9, 0.0, 10.0, 1.0), MAX_COOLANT_C);
+        assert!(coolant_after(f32::NAN, 0.0, 0.0, 10.0, 1.0).is_finite());
+        // Left to itself the loop settles where the radiators shed its heat
+        let mut temperature = 20.0;
+        for _ in 0..200 {
+            let radiated = radiated_kw(1000.0, 1.0, temperature);
+            temperature = coolant_after(temperature, 500.0, radiated, 250.0, 1.0);
+        }
+        assert!((temperature - equilibrium_c(500.0, 1000.0)).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_overheating() {
+        assert_eq!(chilling(RATED_C), 1.0);
+        assert_eq!(chilling(OVERHEAT_C), 0.0);
+        assert_eq!(chilling(50.0), 0.5);
+        assert_eq!(overheat_damage(OVERHEAT_C, 1.0), 0.0);
+        assert!(overheat_damage(OVERHEAT_C + 10.0, 1.0) > overheat_damage(OVERHEAT_C + 5.0, 1.0));
+        assert_eq!(uncooled_damage(1.0, 1.0), 0.0);
+        assert_eq!(uncooled_damage(0.0, 1.0), UNCOOLED_DAMAGE);
+        assert_eq!(room_heating(0.1, STANDARD_ROOM_M2), 0.25);
+        assert_eq!(room_heating(1.0, STANDARD_ROOM_M2 * 2.0), 1.25);
+    }
+}
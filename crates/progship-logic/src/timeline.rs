@@ -0,0 +1,160 @@
+//! Per-person timeline — what someone has been doing today.
+//!
+//! Each person keeps a short ring of what they started doing, where they
+//! went and who they talked to over the last [`TIMELINE_WINDOW_HOURS`], packed
+//! one `u64` per entry so the whole ring fits in a single column. The
+//! inspector decodes it with [`TimelineEntry::unpack`].
+
+/// Hours of history kept per person.
+pub const TIMELINE_WINDOW_HOURS: f64 = 24.0;
+/// Most entries kept per person, however busy their day.
+pub const TIMELINE_MAX_ENTRIES: usize = 64;
+
+/// What a timeline entry records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimelineKind {
+    /// Started an activity (value = `activity_types` ID).
+    Activity,
+    /// Entered a room (value = Room ID).
+    Location,
+    /// Started a conversation (value = the other Person ID).
+    Conversation,
+}
+
+impl TimelineKind {
+    fn bits(self) -> u64 {
+        match self {
+            TimelineKind::Activity => 0,
+            TimelineKind::Location => 1,
+            TimelineKind::Conversation => 2,
+        }
+    }
+
+    fn from_bits(bits: u64) -> Option<Self> {
+        match bits {
+            0 => Some(TimelineKind::Activity),
+            1 => Some(TimelineKind::Location),
+            2 => Some(TimelineKind::Conversation),
+            _ => None,
+        }
+    }
+}
+
+/// One decoded timeline entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimelineEntry {
+    /// Simulation time in whole minutes.
+    pub minute: u32,
+    pub kind: TimelineKind,
+    pub value: u32,
+}
+
+impl TimelineEntry {
+    /// Entry at simulation time `sim_time` (hours).
+    pub fn new(sim_time: f64, kind: TimelineKind, value: u32) -> Self {
+        Self {
+            minute: (sim_time.max(0.0) * 60.0) as u32,
+            kind,
+            value,
+        }
+    }
+
+    /// Simulation time of the entry in hours.
+    pub fn sim_time(&self) -> f64 {
+        self.minute as f64 / 60.0
+    }
+
+    /// Pack as minute (high 30 bits), kind (2 bits), value (low 32 bits).
+    pub fn pack(&self) -> u64 {
+        ((self.minute as u64 & 0x3FFF_FFFF) << 34) | (self.kind.bits() << 32) | self.value as u64
+    }
+
+    /// Inverse of [`pack`](Self::pack).
+    pub fn unpack(packed: u64) -> Option<Self> {
+        Some(Self {
+            minute: (packed >> 34) as u32,
+            kind: TimelineKind::from_bits((packed >> 32) & 0b11)?,
+            value: packed as u32,
+        })
+    }
+}
+
+/// Append `entry` to a packed ring, then drop entries older than the window
+/// and the oldest ones past [`TIMELINE_MAX_ENTRIES`]. Repeating the latest
+/// entry of the same kind and value is a no-op.
+pub fn record(entries: &mut Vec<u64>, entry: TimelineEntry) {
+    let repeat = entries
+        .iter()
+        .rev()
+        .filter_map(|&e| TimelineEntry::unpack(e))
+        .find(|e| e.kind == entry.kind)
+        .is_some_and(|e| e.value == entry.value);
+    if !repeat {
+        entries.push(entry.pack());
+    }
+    let cutoff = entry.sim_time() - TIMELINE_WINDOW_HOURS;
+    entries.retain(|&e| TimelineEntry::unpack(e).is_some_and(|e| e.sim_time() >= cutoff));
+    let excess = entries.len().saturating_sub(TIMELINE_MAX_ENTRIES);
+    entries.drain(..excess);
+}
+
+/// Decoded entries, oldest first.
+pub fn entries(packed: &[u64]) -> Vec<TimelineEntry> {
+    packed
+        .iter()
+        .filter_map(|&e| TimelineEntry::unpack(e))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pack_round_trip() {
+        let entry = TimelineEntry::new(1234.5, TimelineKind::Conversation, u32::MAX);
+        assert_eq!(entry.minute, 74070);
+        assert_eq!(TimelineEntry::unpack(entry.pack()), Some(entry));
+        assert_eq!(TimelineEntry::unpack(3 << 32), None);
+    }
+
+    #[test]
+    fn test_record_keeps_last_day() {
+        let mut ring = Vec::new();
+        record(
+            &mut ring,
+            TimelineEntry::new(1.0, TimelineKind::Activity, 3),
+        );
+        record(
+            &mut ring,
+            TimelineEntry::new(2.0, TimelineKind::Location, 7),
+        );
+        // Same activity again is folded into the earlier entry
+        record(
+            &mut ring,
+            TimelineEntry::new(3.0, TimelineKind::Activity, 3),
+        );
+        assert_eq!(ring.len(), 2);
+        record(
+            &mut ring,
+            TimelineEntry::new(26.0, TimelineKind::Activity, 4),
+        );
+        let kept = entries(&ring);
+        assert_eq!(kept.len(), 2);
+        assert_eq!(kept[0].kind, TimelineKind::Location);
+        assert_eq!(kept[1].value, 4);
+    }
+
+    #[test]
+    fn test_record_caps_entries() {
+        let mut ring = Vec::new();
+        for i in 0..100 {
+            record(
+                &mut ring,
+                TimelineEntry::new(i as f64 * 0.1, TimelineKind::Location, i),
+            );
+        }
+        assert_eq!(ring.len(), TIMELINE_MAX_ENTRIES);
+        assert_eq!(entries(&ring)[0].value, 100 - TIMELINE_MAX_ENTRIES as u32);
+    }
+}
@@ -0,0 +1,127 @@
+//! Triage — who gets a ward bed, who treats them and for how long.
+//!
+//! Anyone hurt enough to seek medical care (see `health`) becomes a patient
+//! at a [`triage_level`] set by their injuries. Ward beds go to the waiting
+//! patients in [`admission_order`], most urgent first; when the wards are
+//! full, a patient who [`outranks`] the least urgent one in a bed takes it
+//! from them. Each on-duty medic looks after up to [`PATIENTS_PER_MEDIC`]
+//! patients, the most skilled taking the most urgent ([`medic_for`]), and
+//! a patient is expected to be in bed for as long as sickbay healing with
+//! their medic's skill takes to bring them to [`DISCHARGE_HEALTH`]
+//! ([`treatment_hours`]).
+
+use std::cmp::Ordering;
+
+use crate::constants::{room_types, triage_levels};
+use crate::health::{compute_health_recovery, InjurySeverity};
+
+/// Health at which a patient is discharged: recovered enough to heal
+/// naturally.
+pub const DISCHARGE_HEALTH: f32 = 0.7;
+
+/// Patients one medic can look after at once.
+pub const PATIENTS_PER_MEDIC: usize = 4;
+
+/// Whether patients are put to bed in a room of `room_type`.
+pub fn is_ward(room_type: u8) -> bool {
+    matches!(room_type, room_types::HOSPITAL_WARD | room_types::MEDBAY)
+}
+
+/// Triage level (see `triage_levels`) of a patient at `health`.
+pub fn triage_level(health: f32) -> u8 {
+    match InjurySeverity::from_health(health) {
+        InjurySeverity::Critical => triage_levels::CRITICAL,
+        InjurySeverity::Moderate => triage_levels::SERIOUS,
+        InjurySeverity::Light | InjurySeverity::Healthy => triage_levels::MINOR,
+    }
+}
+
+/// Order in which patients, given as (triage level, health, arrived at),
+/// are seen: the most urgent level first, then the worst hurt, then the
+/// longest waiting.
+pub fn admission_order(a: (u8, f32, f64), b: (u8, f32, f64)) -> Ordering {
+    b.0.cmp(&a.0)
+        .then(a.1.total_cmp(&b.1))
+        .then(a.2.total_cmp(&b.2))
+}
+
+/// Whether a waiting patient at `waiting` triage takes the bed of one at
+/// `admitted`: only for a strictly more urgent level, so patients of the
+/// same level are never moved out for each other.
+pub fn outranks(waiting: u8, admitted: u8) -> bool {
+    waiting > admitted
+}
+
+/// Medic (an index into medics by skill, best first) who looks after the
+/// patient `rank` places into the admission order, of `medics` on duty.
+pub fn medic_for(rank: usize, medics: usize) -> Option<usize> {
+    let medic = rank / PATIENTS_PER_MEDIC;
+    (medic < medics).then_some(medic)
+}
+
+/// Hours in bed a patient at `health` should take to reach
+/// [`DISCHARGE_HEALTH`], healing at the sickbay rate with a medic of
+/// `medical_skill` (0.0 when none) tending them.
+pub fn treatment_hours(health: f32, medical_skill: f32) -> f32 {
+    let deficit = (DISCHARGE_HEALTH - health).max(0.0);
+    if deficit <= 0.0 {
+        return 0.0;
+    }
+    let rate = compute_health_recovery(health, 0.0, 0.0, true, medical_skill, 1.0) - health;
+    if rate <= 0.0 {
+        return f32::MAX;
+    }
+    deficit / rate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triage_level() {
+        assert_eq!(triage_level(0.1), triage_levels::CRITICAL);
+        assert_eq!(triage_level(0.3), triage_levels::SERIOUS);
+        assert_eq!(triage_level(0.5), triage_levels::MINOR);
+        assert!(is_ward(room_types::HOSPITAL_WARD));
+        assert!(is_ward(room_types::MEDBAY));
+        assert!(!is_ward(room_types::PHARMACY));
+    }
+
+    #[test]
+    fn test_admission_order() {
+        let mut queue = [
+            (triage_levels::MINOR, 0.5, 1.0),
+            (triage_levels::CRITICAL, 0.15, 5.0),
+            (triage_levels::SERIOUS, 0.3, 3.0),
+            (triage_levels::SERIOUS, 0.3, 2.0),
+            (triage_levels::CRITICAL, 0.05, 9.0),
+        ];
+        queue.sort_by(|a, b| admission_order(*a, *b));
+        assert_eq!(
+            queue.map(|p| p.2),
+            [9.0, 5.0, 2.0, 3.0, 1.0],
+            "level, then health, then arrival"
+        );
+        assert!(outranks(triage_levels::CRITICAL, triage_levels::MINOR));
+        assert!(!outranks(triage_levels::SERIOUS, triage_levels::SERIOUS));
+    }
+
+    #[test]
+    fn test_medic_for() {
+        assert_eq!(medic_for(0, 2), Some(0));
+        assert_eq!(medic_for(PATIENTS_PER_MEDIC - 1, 2), Some(0));
+        assert_eq!(medic_for(PATIENTS_PER_MEDIC, 2), Some(1));
+        assert_eq!(medic_for(PATIENTS_PER_MEDIC * 2, 2), None);
+        assert_eq!(medic_for(0, 0), None);
+    }
+
+    #[test]
+    fn test_treatment_hours() {
+        assert_eq!(treatment_hours(0.8, 0.5), 0.0);
+        let untended = treatment_hours(0.2, 0.0);
+        let tended = treatment_hours(0.2, 0.8);
+        assert!(tended > 0.0 && tended < untended);
+        assert!(treatment_hours(0.1, 0.5) > treatment_hours(0.4, 0.5));
+    }
+}
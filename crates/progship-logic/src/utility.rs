@@ -34,6 +34,14 @@ pub struct UtilityInput {
     pub current_room: Option<RoomContext>,
     pub fit_for_duty: bool,
     pub should_be_on_duty: bool,
+    /// Life stage (see `life_stages`); adults for crew.
+    pub life_stage: u8,
+    /// Backstory and quirk bitmask (see `person_traits`).
+    pub traits: u16,
+    /// Lighting mode on their deck (see `lighting_modes`).
+    pub lighting: u8,
+    /// Rationing in force (see `economy::RationingLevel`).
+    pub rationing: u8,
 }
 
 /// A scored activity candidate.
@@ -43,6 +51,34 @@ pub struct ScoredActivity {
     pub score: f32,
     pub duration: f32,
     pub room_type_hint: RoomTarget,
+    /// Terms the score was built from; only filled by [`explain_activities`].
+    pub factors: Vec<ScoreFactor>,
+}
+
+/// One named term of an activity's score.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreFactor {
+    pub name: &'static str,
+    pub value: f32,
+}
+
+impl ScoredActivity {
+    /// One-line summary, e.g. `Exercising 3.1 (exercise drive +4.0, fatigue -0.9)`.
+    pub fn describe(&self) -> String {
+        let factors = self
+            .factors
+            .iter()
+            .filter(|f| f.value != 0.0)
+            .map(|f| format!("{} {:+.1}", f.name, f.value))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let name = activity_types::name(self.activity_type);
+        if factors.is_empty() {
+            format!("{} {:.1}", name, self.score)
+        } else {
+            format!("{} {:.1} ({})", name, self.score, factors)
+        }
+    }
 }
 
 /// What kind of room the activity needs.
@@ -67,7 +103,11 @@ pub enum RoomCategory {
     Dining,
 }
 
-use crate::constants::{activity_types, room_types};
+use crate::backstory::trait_bonus;
+use crate::constants::{activity_types, life_stages, person_traits, room_types};
+use crate::economy::{is_mess_open, u8_to_rationing};
+use crate::lighting::activity_bonus;
+use crate::numeric::{finite_or, unit};
 
 /// Compute the overcrowding stress factor for a room.
 /// Returns 0.0 (empty) to 1.0+ (severely overcrowded).
@@ -130,9 +170,72 @@ pub fn room_comfort(room_type: u8) -> f32 {
     }
 }
 
+/// Sim hours between room ambience updates.
+pub const AMBIENCE_INTERVAL_HOURS: f64 = 0.1;
+
+/// How lively a room feels (0.0 = dead, 1.0 = buzzing), from how full it
+/// is, how many of its occupants are talking and their average morale.
+pub fn liveliness(occupants: u32, capacity: u32, conversations: u32, average_mood: f32) -> f32 {
+    if occupants == 0 {
+        return 0.0;
+    }
+    let fullness = (occupants as f32 / capacity.max(1) as f32).min(1.0);
+    let chatter = (conversations as f32 * 2.0 / occupants as f32).min(1.0);
+    (fullness * 0.5 + chatter * 0.3 + unit(average_mood, 0.5) * 0.2).clamp(0.0, 1.0)
+}
+
+/// How much someone with `extraversion` (0.0–1.0) wants to spend time in a
+/// venue of `liveliness`: extraverts seek lively rooms, introverts quiet ones.
+pub fn venue_appeal(liveliness: f32, extraversion: f32) -> f32 {
+    1.0 - (unit(liveliness, 0.0) - unit(extraversion, 0.5)).abs()
+}
+
+impl UtilityInput {
+    /// The same input with needs, personality and health in 0..=1 and the
+    /// hour in 0..24; NaN and infinities become neutral values.
+    pub fn sanitized(&self) -> Self {
+        Self {
+            hunger: unit(self.hunger, 0.0),
+            fatigue: unit(self.fatigue, 0.0),
+            social: unit(self.social, 0.0),
+            comfort: unit(self.comfort, 0.0),
+            hygiene: unit(self.hygiene, 0.0),
+            health: unit(self.health, 1.0),
+            morale: unit(self.morale, 0.5),
+            hour: finite_or(self.hour, 0.0).rem_euclid(24.0),
+            extraversion: unit(self.extraversion, 0.5),
+            neuroticism: unit(self.neuroticism, 0.5),
+            conscientiousness: unit(self.conscientiousness, 0.5),
+            openness: unit(self.openness, 0.5),
+            agreeableness: unit(self.agreeableness, 0.5),
+            ..self.clone()
+        }
+    }
+}
+
 /// Score all candidate activities and return them sorted best-first.
 pub fn score_activities(input: &UtilityInput) -> Vec<ScoredActivity> {
+    score_candidates(input, false)
+}
+
+/// Explain mode for [`pick_best`]: every candidate, best-first (the first is
+/// the pick), with the factors behind its score.
+pub fn explain_activities(input: &UtilityInput) -> Vec<ScoredActivity> {
+    score_candidates(input, true)
+}
+
+fn score_candidates(input: &UtilityInput, explain: bool) -> Vec<ScoredActivity> {
+    let input = &input.sanitized();
     let mut candidates = Vec::with_capacity(10);
+    let factors = |terms: &[(&'static str, f32)]| -> Vec<ScoreFactor> {
+        if !explain {
+            return Vec::new();
+        }
+        terms
+            .iter()
+            .map(|&(name, value)| ScoreFactor { name, value })
+            .collect()
+    };
 
     // Environmental stress from current room
     let crowd_stress = input
@@ -153,6 +256,7 @@ pub fn score_activities(input: &UtilityInput) -> Vec<ScoredActivity> {
             score: 100.0,
             duration: 1.0,
             room_type_hint: RoomTarget::Category(RoomCategory::Medical),
+            factors: factors(&[("needs medical care", 100.0)]),
         });
         return candidates;
     }
@@ -167,9 +271,26 @@ pub fn score_activities(input: &UtilityInput) -> Vec<ScoredActivity> {
             score: duty_score,
             duration: 2.0,
             room_type_hint: RoomTarget::DutyStation(dept),
+            factors: factors(&[
+                ("shift", 8.0),
+                ("conscientiousness", input.conscientiousness * 3.0),
+            ]),
         });
     }
 
+    // --- School (infants go to the nursery) ---
+    if crate::education::is_school_time(input.hour) {
+        if let Some(room) = crate::education::classroom_for(input.life_stage) {
+            candidates.push(ScoredActivity {
+                activity_type: activity_types::WORKING,
+                score: 9.0,
+                duration: 2.0,
+                room_type_hint: RoomTarget::Exact(room),
+                factors: factors(&[("school hours", 9.0)]),
+            });
+        }
+    }
+
     // --- Sleep ---
     {
         // Exponential urgency curve for fatigue
@@ -196,19 +317,28 @@ pub fn score_activities(input: &UtilityInput) -> Vec<ScoredActivity> {
             score: sleep_score,
             duration: 8.0,
             room_type_hint: RoomTarget::Category(RoomCategory::Quarters),
+            factors: factors(&[
+                ("fatigue", fatigue_urgency),
+                ("sleep hours", schedule_bonus),
+            ]),
         });
     }
 
     // --- Eating ---
     {
         let hunger_urgency = input.hunger * input.hunger * 12.0;
-        let meal_bonus = if is_meal_time(input.hour) { 2.0 } else { 0.0 };
+        let meal_bonus = if is_mess_open(u8_to_rationing(input.rationing), input.hour) {
+            2.0
+        } else {
+            0.0
+        };
         let eat_score = hunger_urgency + meal_bonus;
         candidates.push(ScoredActivity {
             activity_type: activity_types::EATING,
             score: eat_score,
             duration: 0.5,
             room_type_hint: RoomTarget::Exact(room_types::MESS_HALL),
+            factors: factors(&[("hunger", hunger_urgency), ("mealtime", meal_bonus)]),
         });
     }
 
@@ -220,6 +350,7 @@ pub fn score_activities(input: &UtilityInput) -> Vec<ScoredActivity> {
             score: hygiene_urgency,
             duration: 0.3,
             room_type_hint: RoomTarget::Exact(room_types::SHARED_BATHROOM),
+            factors: factors(&[("hygiene", hygiene_urgency)]),
         });
     }
 
@@ -236,6 +367,11 @@ pub fn score_activities(input: &UtilityInput) -> Vec<ScoredActivity> {
             score: social_score,
             duration: 1.0,
             room_type_hint: RoomTarget::Category(RoomCategory::Recreation),
+            factors: factors(&[
+                ("loneliness", social_urgency),
+                ("extraversion", personality_mod),
+                ("crowding", -crowd_penalty),
+            ]),
         });
     }
 
@@ -247,11 +383,22 @@ pub fn score_activities(input: &UtilityInput) -> Vec<ScoredActivity> {
         // Overcrowding stress
         let crowd_comfort_penalty = crowd_stress * 2.0;
         let relax_score = comfort_urgency + noise_stress + crowd_comfort_penalty;
+        // Green thumbs unwind among the plants
+        let relax_room = if person_traits::has(input.traits, person_traits::GREEN_THUMB) {
+            RoomTarget::Exact(room_types::ARBORETUM)
+        } else {
+            RoomTarget::Category(RoomCategory::Recreation)
+        };
         candidates.push(ScoredActivity {
             activity_type: activity_types::RELAXING,
             score: relax_score,
             duration: 1.0,
-            room_type_hint: RoomTarget::Category(RoomCategory::Recreation),
+            room_type_hint: relax_room,
+            factors: factors(&[
+                ("discomfort", comfort_urgency),
+                ("noise", noise_stress),
+                ("crowding", crowd_comfort_penalty),
+            ]),
         });
     }
 
@@ -261,14 +408,44 @@ pub fn score_activities(input: &UtilityInput) -> Vec<ScoredActivity> {
         let exercise_base = 1.5 + input.openness * 1.5 + input.conscientiousness;
         let fatigue_dampen = input.fatigue * 3.0;
         let exercise_score = (exercise_base - fatigue_dampen).max(0.0);
+        // Elders skip the gym for a gentler walk in the arboretum
+        let (elder_score, exercise_room) = if input.life_stage == life_stages::ELDER {
+            (exercise_score * 0.5, room_types::ARBORETUM)
+        } else {
+            (exercise_score, room_types::GYM)
+        };
         candidates.push(ScoredActivity {
             activity_type: activity_types::EXERCISING,
-            score: exercise_score,
+            score: elder_score,
             duration: 1.0,
-            room_type_hint: RoomTarget::Exact(room_types::GYM),
+            room_type_hint: RoomTarget::Exact(exercise_room),
+            factors: factors(&[
+                ("exercise drive", exercise_base),
+                ("fatigue", -fatigue_dampen),
+                ("age", elder_score - exercise_score),
+            ]),
         });
     }
 
+    // --- Backstory, quirks and the deck's lights ---
+    for candidate in &mut candidates {
+        let bonuses = [
+            ("traits", trait_bonus(input.traits, candidate.activity_type)),
+            (
+                "lighting",
+                activity_bonus(input.lighting, candidate.activity_type),
+            ),
+        ];
+        for (name, bonus) in bonuses {
+            if bonus != 0.0 {
+                candidate.score = (candidate.score + bonus).max(0.0);
+                if explain {
+                    candidate.factors.push(ScoreFactor { name, value: bonus });
+                }
+            }
+        }
+    }
+
     // Sort descending by score
     candidates.sort_by(|a, b| {
         b.score
@@ -289,13 +466,10 @@ pub fn pick_best(input: &UtilityInput) -> (u8, f32, RoomTarget) {
     }
 }
 
-fn is_meal_time(hour: f32) -> bool {
-    (7.0..8.0).contains(&hour) || (12.0..13.0).contains(&hour) || (18.0..19.0).contains(&hour)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::constants::lighting_modes;
 
     fn default_input() -> UtilityInput {
         UtilityInput {
@@ -318,6 +492,10 @@ mod tests {
             current_room: None,
             fit_for_duty: false,
             should_be_on_duty: false,
+            life_stage: life_stages::ADULT,
+            traits: 0,
+            lighting: lighting_modes::DAY,
+            rationing: 0,
         }
     }
 
@@ -538,4 +716,134 @@ mod tests {
             .find(|s| s.activity_type == activity_types::ON_DUTY);
         assert!(duty.is_none(), "Unfit crew should not have duty candidate");
     }
+
+    #[test]
+    fn test_children_go_to_school() {
+        let mut input = default_input();
+        input.life_stage = life_stages::CHILD;
+        let (act, _, target) = pick_best(&input);
+        assert_eq!(act, activity_types::WORKING);
+        assert!(matches!(target, RoomTarget::Exact(room_types::SCHOOL)));
+
+        input.life_stage = life_stages::INFANT;
+        let (_, _, target) = pick_best(&input);
+        assert!(matches!(target, RoomTarget::Exact(room_types::NURSERY)));
+
+        // No school in the evening
+        input.hour = 20.0;
+        let (act, _, _) = pick_best(&input);
+        assert_ne!(act, activity_types::WORKING);
+
+        // Adults never get a school slot
+        input.hour = 10.0;
+        input.life_stage = life_stages::ADULT;
+        let scored = score_activities(&input);
+        assert!(scored
+            .iter()
+            .all(|s| s.activity_type != activity_types::WORKING));
+    }
+
+    #[test]
+    fn test_elders_avoid_gym() {
+        let mut input = default_input();
+        input.life_stage = life_stages::ELDER;
+        let scored = score_activities(&input);
+        assert!(scored
+            .iter()
+            .all(|s| !matches!(s.room_type_hint, RoomTarget::Exact(room_types::GYM))));
+        let exercise = scored
+            .iter()
+            .find(|s| s.activity_type == activity_types::EXERCISING)
+            .unwrap();
+        assert!(matches!(
+            exercise.room_type_hint,
+            RoomTarget::Exact(room_types::ARBORETUM)
+        ));
+    }
+
+    #[test]
+    fn test_liveliness() {
+        assert_eq!(liveliness(0, 20, 0, 1.0), 0.0);
+        let quiet = liveliness(2, 20, 0, 0.5);
+        let buzzing = liveliness(18, 20, 8, 0.8);
+        assert!(buzzing > 0.8 && quiet < 0.3);
+        assert!(liveliness(10, 0, 0, 0.5) <= 1.0);
+    }
+
+    #[test]
+    fn test_venue_appeal_follows_extraversion() {
+        let (quiet, lively) = (0.1, 0.9);
+        assert!(venue_appeal(lively, 0.9) > venue_appeal(quiet, 0.9));
+        assert!(venue_appeal(quiet, 0.1) > venue_appeal(lively, 0.1));
+    }
+
+    #[test]
+    fn test_explain_matches_pick_best() {
+        let mut input = default_input();
+        input.fatigue = 0.9;
+        input.hour = 3.0;
+        let explained = explain_activities(&input);
+        assert_eq!(explained[0].activity_type, pick_best(&input).0);
+        assert_eq!(explained.len(), score_activities(&input).len());
+        assert!(score_activities(&input)
+            .iter()
+            .all(|s| s.factors.is_empty()));
+
+        let sleep = &explained[0];
+        assert_eq!(sleep.activity_type, activity_types::SLEEPING);
+        let total: f32 = sleep.factors.iter().map(|f| f.value).sum();
+        assert!((total - sleep.score).abs() < 1e-4);
+        assert!(sleep.describe().starts_with("Sleeping"));
+        assert!(sleep.describe().contains("fatigue +"));
+    }
+
+    #[test]
+    fn test_traits_nudge_scores() {
+        let score = |input: &UtilityInput, activity| {
+            score_activities(input)
+                .iter()
+                .find(|s| s.activity_type == activity)
+                .unwrap()
+                .score
+        };
+        let plain = default_input();
+        let mut quirky = default_input();
+        quirky.traits = (1 << person_traits::INSOMNIAC) | (1 << person_traits::GREEN_THUMB);
+        assert!(score(&quirky, activity_types::SLEEPING) < score(&plain, activity_types::SLEEPING));
+        assert!(score(&quirky, activity_types::RELAXING) > score(&plain, activity_types::RELAXING));
+        let relax = explain_activities(&quirky)
+            .into_iter()
+            .find(|s| s.activity_type == activity_types::RELAXING)
+            .unwrap();
+        assert!(matches!(
+            relax.room_type_hint,
+            RoomTarget::Exact(room_types::ARBORETUM)
+        ));
+        assert!(relax.factors.contains(&ScoreFactor {
+            name: "traits",
+            value: 1.0
+        }));
+    }
+
+    #[test]
+    fn test_lighting_nudges_scores() {
+        let score = |input: &UtilityInput, activity| {
+            score_activities(input)
+                .iter()
+                .find(|s| s.activity_type == activity)
+                .unwrap()
+                .score
+        };
+        let day = default_input();
+        let night = UtilityInput {
+            lighting: lighting_modes::NIGHT,
+            ..default_input()
+        };
+        assert!(score(&night, activity_types::SLEEPING) > score(&day, activity_types::SLEEPING));
+        let sleep = explain_activities(&night)
+            .into_iter()
+            .find(|s| s.activity_type == activity_types::SLEEPING)
+            .unwrap();
+        assert!(sleep.factors.iter().any(|f| f.name == "lighting"));
+    }
 }
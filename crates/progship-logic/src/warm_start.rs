@@ -0,0 +1,41 @@
+//! Warm start — fast-forwarding a new ship before anyone joins.
+//!
+//! A freshly generated ship has no relationships, wear or history. Warm
+//! starting runs it for a few days in coarse steps first: the engines use
+//! [`fast_forward_steps`] to split the requested days into steps of their own
+//! size, and give up per-frame detail such as smooth movement along the way.
+
+use crate::numeric::finite_or;
+
+/// Longest warm start, in days.
+pub const MAX_WARM_START_DAYS: f32 = 30.0;
+
+/// Warm start length in days: clamped to 0..=[`MAX_WARM_START_DAYS`], zero
+/// if not finite.
+pub fn warm_start_days(days: f32) -> f32 {
+    finite_or(days, 0.0).clamp(0.0, MAX_WARM_START_DAYS)
+}
+
+/// Steps of `step_hours` needed to cover `days` (see [`warm_start_days`]).
+pub fn fast_forward_steps(days: f32, step_hours: f64) -> u32 {
+    if !step_hours.is_finite() || step_hours <= 0.0 {
+        return 0;
+    }
+    (warm_start_days(days) as f64 * 24.0 / step_hours).ceil() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fast_forward_steps() {
+        assert_eq!(fast_forward_steps(1.0, 0.25), 96);
+        assert_eq!(fast_forward_steps(0.01, 0.25), 1);
+        assert_eq!(fast_forward_steps(0.0, 0.25), 0);
+        assert_eq!(fast_forward_steps(-3.0, 0.25), 0);
+        assert_eq!(fast_forward_steps(f32::NAN, 0.25), 0);
+        assert_eq!(fast_forward_steps(1.0, 0.0), 0);
+        assert_eq!(fast_forward_steps(1000.0, 1.0), 720);
+    }
+}
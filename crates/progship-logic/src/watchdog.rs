@@ -0,0 +1,185 @@
+//! Simulation invariants — catching corrupt state before it spreads.
+//!
+//! Once per sim-hour the server and the core engine walk their state and
+//! collect every broken invariant as a [`Violation`]: people in rooms that
+//! don't exist, living people missing a component, needs outside 0..=1,
+//! negative resources, conversations nobody is in and NaNs caught by the
+//! [`numeric`](crate::numeric) guards since the last pass. Any violations
+//! are reported as one diagnostic event whose severity grows with their
+//! number (see [`diagnostic_severity`]). The server also keeps them, with
+//! the problems its systems recovered from, as its last [`WARNING_KEEP`]
+//! simulation warnings.
+
+use crate::numeric::take_guard_hits;
+use std::fmt;
+
+/// Hours between watchdog passes.
+pub const WATCHDOG_INTERVAL_HOURS: f64 = 1.0;
+
+/// Most distinct simulation warnings the server keeps.
+pub const WARNING_KEEP: usize = 100;
+
+/// A broken simulation invariant.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Violation {
+    /// A person's position refers to a room that doesn't exist.
+    MissingRoom { person_id: u64, room_id: u32 },
+    /// A living person lacks a component every person has.
+    MissingComponent {
+        person_id: u64,
+        component: &'static str,
+    },
+    /// A need is outside 0..=1 (or not a number).
+    NeedOutOfRange {
+        person_id: u64,
+        need: &'static str,
+        value: f32,
+    },
+    /// A ship resource is negative (or not a number).
+    NegativeResource { resource: &'static str, value: f32 },
+    /// A conversation without participants, or a participant pointing at a
+    /// conversation that isn't running.
+    OrphanedConversation { conversation_id: u64 },
+    /// Math functions were handed (or produced) NaN or infinity.
+    NonFiniteMath { count: u64 },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::MissingRoom { person_id, room_id } => {
+                write!(f, "person {person_id} is in missing room {room_id}")
+            }
+            Violation::MissingComponent {
+                person_id,
+                component,
+            } => write!(f, "person {person_id} has no {component}"),
+            Violation::NeedOutOfRange {
+                person_id,
+                need,
+                value,
+            } => write!(f, "person {person_id} has {need} {value}"),
+            Violation::NegativeResource { resource, value } => {
+                write!(f, "{resource} is {value}")
+            }
+            Violation::OrphanedConversation { conversation_id } => {
+                write!(f, "conversation {conversation_id} is orphaned")
+            }
+            Violation::NonFiniteMath { count } => {
+                write!(f, "{count} non-finite values replaced by math guards")
+            }
+        }
+    }
+}
+
+/// Components `person_id` lacks, given as (name, present) pairs.
+pub fn missing_components(person_id: u64, components: &[(&'static str, bool)]) -> Vec<Violation> {
+    components
+        .iter()
+        .filter(|(_, present)| !present)
+        .map(|&(component, _)| Violation::MissingComponent {
+            person_id,
+            component,
+        })
+        .collect()
+}
+
+/// Needs of `person_id` outside 0..=1, given as (name, value) pairs.
+pub fn needs_violations(person_id: u64, needs: &[(&'static str, f32)]) -> Vec<Violation> {
+    needs
+        .iter()
+        .filter(|(_, value)| !(0.0..=1.0).contains(value))
+        .map(|&(need, value)| Violation::NeedOutOfRange {
+            person_id,
+            need,
+            value,
+        })
+        .collect()
+}
+
+/// Negative resources among (name, value) pairs.
+pub fn resource_violations(resources: &[(&'static str, f32)]) -> Vec<Violation> {
+    resources
+        .iter()
+        .filter(|(_, value)| value.is_nan() || *value < 0.0)
+        .map(|&(resource, value)| Violation::NegativeResource { resource, value })
+        .collect()
+}
+
+/// Non-finite values the numeric guards replaced since the last call, if any.
+pub fn guard_violation() -> Option<Violation> {
+    match take_guard_hits() {
+        0 => None,
+        count => Some(Violation::NonFiniteMath { count }),
+    }
+}
+
+/// Severity (0..=1) of the diagnostic event reporting `count` violations.
+pub fn diagnostic_severity(count: usize) -> f32 {
+    (0.2 + count as f32 * 0.05).min(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_needs_violations() {
+        let found = needs_violations(
+            7,
+            &[("hunger", 0.5), ("fatigue", 1.2), ("health", f32::NAN)],
+        );
+        assert_eq!(found.len(), 2);
+        assert_eq!(
+            found[0],
+            Violation::NeedOutOfRange {
+                person_id: 7,
+                need: "fatigue",
+                value: 1.2
+            }
+        );
+        assert!(needs_violations(7, &[("hunger", 0.0), ("social", 1.0)]).is_empty());
+    }
+
+    #[test]
+    fn test_missing_components() {
+        let found = missing_components(3, &[("position", true), ("needs", false)]);
+        assert_eq!(
+            found,
+            vec![Violation::MissingComponent {
+                person_id: 3,
+                component: "needs"
+            }]
+        );
+        assert_eq!(found[0].to_string(), "person 3 has no needs");
+        assert!(missing_components(3, &[("activity", true)]).is_empty());
+    }
+
+    #[test]
+    fn test_resource_violations() {
+        let found = resource_violations(&[("food", 10.0), ("water", -0.5), ("fuel", 0.0)]);
+        assert_eq!(
+            found,
+            vec![Violation::NegativeResource {
+                resource: "water",
+                value: -0.5
+            }]
+        );
+        assert_eq!(found[0].to_string(), "water is -0.5");
+    }
+
+    #[test]
+    fn test_guard_violation_drains_counter() {
+        crate::numeric::finite_or(f32::NAN, 0.0);
+        assert!(matches!(
+            guard_violation(),
+            Some(Violation::NonFiniteMath { count }) if count >= 1
+        ));
+    }
+
+    #[test]
+    fn test_diagnostic_severity() {
+        assert!(diagnostic_severity(1) < diagnostic_severity(5));
+        assert_eq!(diagnostic_severity(100), 1.0);
+    }
+}
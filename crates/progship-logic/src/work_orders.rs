@@ -0,0 +1,157 @@
+//! Work orders — the job board crew take their work from.
+//!
+//! Failing systems, leaking pipes and emergencies file orders on the board,
+//! each with a priority, the skill it takes and the spare parts it needs;
+//! emergencies ([`emergency_order`]) come before any repair
+//! ([`emergency_priority`]). Crew claim open orders most urgent first
+//! ([`board_order`]) when they are fit for them ([`can_claim`]): their own
+//! department's work, or anyone's with the skill for it. The best placed
+//! crew member ([`claim_score`]) gets each one. A claim that has come to
+//! nothing well past the job's expected length goes back on the board
+//! ([`claim_stalled`]).
+
+use std::cmp::Ordering;
+
+use crate::cargo::REPAIR_PARTS_TONS;
+use crate::constants::{departments, event_types, part_kinds};
+use crate::skills::SkillCategory;
+
+/// Skill anyone outside an order's department needs to claim it.
+pub const MIN_CLAIM_SKILL: f32 = 0.4;
+
+/// Hours past a job's expected length a claim may go without progress.
+pub const CLAIM_TIMEOUT_HOURS: f64 = 4.0;
+
+/// Work an emergency of `event_type` puts on the board, if any: the skill
+/// it takes, and the spare parts in tons it needs and their kind (see
+/// `part_kinds`). Fires are put out with what is at hand; breaches are
+/// patched and failed systems rewired; medics see to casualties and to
+/// sleepers being revived.
+pub fn emergency_order(event_type: u8) -> Option<(SkillCategory, f32, u8)> {
+    let engineering = SkillCategory::Engineering;
+    match event_type {
+        event_types::FIRE => Some((engineering, 0.0, part_kinds::MECHANICAL)),
+        event_types::HULL_BREACH => Some((engineering, REPAIR_PARTS_TONS, part_kinds::SEALS)),
+        event_types::SYSTEM_FAILURE => {
+            Some((engineering, REPAIR_PARTS_TONS, part_kinds::ELECTRICAL))
+        }
+        event_types::MEDICAL_EMERGENCY | event_types::REVIVAL => {
+            Some((SkillCategory::Medical, 0.0, part_kinds::MECHANICAL))
+        }
+        _ => None,
+    }
+}
+
+/// Priority of an emergency of `severity`: above every repair, whose
+/// priority runs up to 1.0.
+pub fn emergency_priority(severity: f32) -> f32 {
+    1.0 + severity.clamp(0.0, 1.0)
+}
+
+/// Department whose work a job needing `skill` is.
+pub fn skill_department(skill: SkillCategory) -> u8 {
+    match skill {
+        SkillCategory::Engineering => departments::ENGINEERING,
+        SkillCategory::Medical => departments::MEDICAL,
+        SkillCategory::Piloting => departments::COMMAND,
+        SkillCategory::Science => departments::SCIENCE,
+        SkillCategory::Social => departments::OPERATIONS,
+        SkillCategory::Combat => departments::SECURITY,
+    }
+}
+
+/// Order in which open orders, given as (priority, filed at), are claimed:
+/// the most urgent first, the longest waiting on a tie.
+pub fn board_order(a: (f32, f64), b: (f32, f64)) -> Ordering {
+    b.0.total_cmp(&a.0).then(a.1.total_cmp(&b.1))
+}
+
+/// Whether a crew member of `department` with `skill_level` in what an
+/// order of `order_department` takes may claim it.
+pub fn can_claim(department: u8, order_department: u8, skill_level: f32) -> bool {
+    department == order_department || skill_level >= MIN_CLAIM_SKILL
+}
+
+/// How well placed a crew member is to take an order (higher first): the
+/// skilled, then those whose department's work it is, on watch and on the
+/// same deck.
+pub fn claim_score(skill_level: f32, own_department: bool, on_duty: bool, same_deck: bool) -> f32 {
+    let bonus = |yes: bool, amount: f32| if yes { amount } else { 0.0 };
+    skill_level + bonus(own_department, 0.3) + bonus(on_duty, 0.2) + bonus(same_deck, 0.1)
+}
+
+/// Whether a claim held for `claimed_hours` on a job expected to take
+/// `duration_hours`, with `progress` made, has stalled and should go back
+/// on the board.
+pub fn claim_stalled(claimed_hours: f64, progress: f32, duration_hours: f32) -> bool {
+    progress <= 0.0 && claimed_hours > duration_hours as f64 + CLAIM_TIMEOUT_HOURS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_emergency_orders() {
+        assert_eq!(
+            emergency_order(event_types::FIRE).map(|o| (o.0, o.1)),
+            Some((SkillCategory::Engineering, 0.0))
+        );
+        assert_eq!(
+            emergency_order(event_types::HULL_BREACH),
+            Some((
+                SkillCategory::Engineering,
+                REPAIR_PARTS_TONS,
+                part_kinds::SEALS
+            ))
+        );
+        assert_eq!(
+            emergency_order(event_types::REVIVAL).map(|o| o.0),
+            Some(SkillCategory::Medical)
+        );
+        assert_eq!(
+            emergency_order(event_types::MEDICAL_EMERGENCY).map(|o| o.0),
+            Some(SkillCategory::Medical)
+        );
+        assert_eq!(emergency_order(event_types::CELEBRATION), None);
+        assert!(emergency_priority(0.0) >= 1.0);
+        assert!(emergency_priority(0.9) > emergency_priority(0.3));
+    }
+
+    #[test]
+    fn test_skill_department() {
+        assert_eq!(
+            skill_department(SkillCategory::Engineering),
+            departments::ENGINEERING
+        );
+        assert_eq!(
+            skill_department(SkillCategory::Combat),
+            departments::SECURITY
+        );
+    }
+
+    #[test]
+    fn test_board_order() {
+        let mut board = [(0.4, 5.0), (0.9, 8.0), (0.4, 2.0), (1.5, 9.0)];
+        board.sort_by(|a, b| board_order(*a, *b));
+        assert_eq!(board, [(1.5, 9.0), (0.9, 8.0), (0.4, 2.0), (0.4, 5.0)]);
+    }
+
+    #[test]
+    fn test_claims() {
+        let eng = departments::ENGINEERING;
+        assert!(can_claim(eng, eng, 0.1));
+        assert!(can_claim(departments::SCIENCE, eng, 0.5));
+        assert!(!can_claim(departments::SCIENCE, eng, 0.2));
+        // Skill counts most; department, watch and deck break near ties
+        assert!(claim_score(0.8, false, false, false) > claim_score(0.4, true, false, false));
+        assert!(claim_score(0.6, true, true, false) > claim_score(0.6, true, false, true));
+    }
+
+    #[test]
+    fn test_claim_stalled() {
+        assert!(!claim_stalled(5.0, 0.0, 3.0));
+        assert!(claim_stalled(8.0, 0.0, 3.0));
+        assert!(!claim_stalled(20.0, 0.1, 3.0));
+    }
+}
@@ -0,0 +1,193 @@
+//! Property tests for the numeric guards.
+//!
+//! Feeds every guarded atmosphere, economy, health and utility function each
+//! combination of extreme inputs (NaN, ±infinity, ±f32::MAX, zero, negative
+//! and in-range values) and checks nothing non-finite comes back out.
+//!
+//! All tests are pure logic — no SpacetimeDB, no rendering.
+
+use progship_logic::atmosphere::{
+    assess_hazard, atmosphere_health_damage, exchange_gas, update_room_atmosphere, RoomAtmosphere,
+};
+use progship_logic::constants::life_stages;
+use progship_logic::economy::{
+    compute_levels, compute_rationing, food_production_rate, power_balance, water_recycling_rate,
+    ResourceValues,
+};
+use progship_logic::health::{compute_health_recovery, is_dead};
+use progship_logic::utility::{
+    liveliness, pick_best, score_activities, venue_appeal, UtilityInput,
+};
+
+// ── Helpers ────────────────────────────────────────────────────────────
+
+const EXTREMES: [f32; 10] = [
+    f32::NAN,
+    f32::INFINITY,
+    f32::NEG_INFINITY,
+    f32::MAX,
+    f32::MIN,
+    0.0,
+    -1.0,
+    0.5,
+    1.0,
+    1e-30,
+];
+
+fn assert_finite_atmosphere(atmo: &RoomAtmosphere) {
+    assert!(
+        atmo.o2.is_finite() && (0.0..=1.0).contains(&atmo.o2),
+        "{atmo:?}"
+    );
+    assert!(
+        atmo.co2.is_finite() && (0.0..=1.0).contains(&atmo.co2),
+        "{atmo:?}"
+    );
+    assert!(atmo.temperature.is_finite(), "{atmo:?}");
+    assert!(
+        atmo.pressure.is_finite() && atmo.pressure >= 0.0,
+        "{atmo:?}"
+    );
+}
+
+fn extreme_atmospheres() -> Vec<RoomAtmosphere> {
+    let mut out = Vec::new();
+    for &v in &EXTREMES {
+        for fire in [false, true] {
+            out.push(RoomAtmosphere {
+                o2: v,
+                co2: v,
+                temperature: v,
+                pressure: v,
+                fire,
+                ..Default::default()
+            });
+            out.push(RoomAtmosphere {
+                temperature: v,
+                fire,
+                ..Default::default()
+            });
+        }
+    }
+    out
+}
+
+fn input(value: f32) -> UtilityInput {
+    UtilityInput {
+        hunger: value,
+        fatigue: value,
+        social: value,
+        comfort: value,
+        hygiene: value,
+        health: value,
+        morale: value,
+        hour: value,
+        is_crew: true,
+        shift: Some(0),
+        department: Some(0),
+        extraversion: value,
+        neuroticism: value,
+        conscientiousness: value,
+        openness: value,
+        agreeableness: value,
+        current_room: None,
+        fit_for_duty: true,
+        should_be_on_duty: false,
+        life_stage: life_stages::ADULT,
+        traits: u16::MAX,
+        lighting: u8::MAX,
+        rationing: u8::MAX,
+    }
+}
+
+// ── Atmosphere ─────────────────────────────────────────────────────────
+
+#[test]
+fn atmosphere_updates_stay_finite() {
+    for atmo in extreme_atmospheres() {
+        for &dt in &EXTREMES {
+            let mut a = atmo;
+            update_room_atmosphere(&mut a, u32::MAX, dt);
+            assert_finite_atmosphere(&a);
+
+            let mut b = RoomAtmosphere::default();
+            let mut a = atmo;
+            exchange_gas(&mut a, &mut b, dt);
+            assert_finite_atmosphere(&a);
+            assert_finite_atmosphere(&b);
+        }
+        assert!(atmosphere_health_damage(&atmo).is_finite());
+        assess_hazard(&atmo);
+    }
+}
+
+// ── Economy ────────────────────────────────────────────────────────────
+
+#[test]
+fn economy_math_stays_finite() {
+    for &v in &EXTREMES {
+        for &cap in &EXTREMES {
+            let levels = compute_levels(&ResourceValues {
+                food: v,
+                food_cap: cap,
+                water: v,
+                water_cap: cap,
+                oxygen: v,
+                oxygen_cap: cap,
+                power: v,
+                power_cap: cap,
+                fuel: v,
+                fuel_cap: cap,
+                spare_parts: v,
+                spare_parts_cap: cap,
+            });
+            for level in [
+                levels.food,
+                levels.water,
+                levels.oxygen,
+                levels.power,
+                levels.fuel,
+                levels.spare_parts,
+            ] {
+                assert!((0.0..=1.0).contains(&level), "{v} / {cap} -> {level}");
+            }
+            compute_rationing(&levels);
+            assert!(power_balance(v, cap).is_finite());
+            assert!(water_recycling_rate(3, v, cap, v).is_finite());
+        }
+        assert!(food_production_rate(u32::MAX, v).is_finite());
+    }
+}
+
+// ── Health ─────────────────────────────────────────────────────────────
+
+#[test]
+fn health_recovery_stays_in_range() {
+    for &health in &EXTREMES {
+        for &other in &EXTREMES {
+            for in_medical in [false, true] {
+                let h = compute_health_recovery(health, other, other, in_medical, other, other);
+                assert!((0.0..=1.0).contains(&h), "{health} {other} -> {h}");
+            }
+        }
+        assert!(!is_dead(f32::NAN));
+    }
+}
+
+// ── Utility ────────────────────────────────────────────────────────────
+
+#[test]
+fn utility_scores_stay_finite() {
+    for &v in &EXTREMES {
+        for scored in score_activities(&input(v)) {
+            assert!(scored.score.is_finite(), "{v}: {scored:?}");
+            assert!(scored.duration.is_finite());
+        }
+        let (_, duration, _) = pick_best(&input(v));
+        assert!(duration.is_finite());
+        assert!(liveliness(10, 20, 2, v).is_finite());
+        for &w in &EXTREMES {
+            assert!(venue_appeal(v, w).is_finite());
+        }
+    }
+}
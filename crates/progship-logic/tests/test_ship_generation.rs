@@ -9,6 +9,7 @@ use progship_logic::config::{
     select_systems, total_power_draw, total_system_crew, total_system_mass, SystemOverrides,
     SystemSelection,
 };
+use progship_logic::demographics::DemographicCurve;
 use progship_logic::manifest::{
     estimate_deck_count, generate_manifest, total_area, total_rooms, RoomRequirement,
 };
@@ -38,6 +39,7 @@ fn default_config() -> MissionConfig {
         },
         seed: 42,
         propulsion: PropulsionType::FusionTorch as u8,
+        demographics: DemographicCurve::default(),
     }
 }
 
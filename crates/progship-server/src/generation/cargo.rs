@@ -0,0 +1,66 @@
+//! Cargo stowage.
+//!
+//! Splits the supply manifest and the colony's freight into lots (see
+//! [`progship_logic::cargo`]) and stows each one in a hold as a CargoItem,
+//! and shares the spare parts out by kind between the components aboard.
+
+use crate::tables::*;
+use progship_logic::cargo::{cargo_manifest, holds, stow};
+use progship_logic::fabrication::{launch_shares, PART_KIND_COUNT};
+use progship_logic::supplies::SupplyManifest;
+use spacetimedb::{ReducerContext, Table};
+
+pub(super) fn generate_cargo(ctx: &ReducerContext, supplies: &SupplyManifest, population: u32) {
+    let lots = cargo_manifest(supplies, population);
+    let rooms: Vec<Room> = ctx
+        .db
+        .room()
+        .iter()
+        .filter(|r| {
+            lots.iter()
+                .any(|l| holds(l.category).contains(&r.room_type))
+        })
+        .collect();
+    let volumes: Vec<(u8, f32)> = rooms
+        .iter()
+        .map(|r| (r.room_type, r.width * r.height * r.ceiling_height))
+        .collect();
+
+    let mut unstowed = 0;
+    for (lot, hold) in lots.iter().zip(stow(&lots, &volumes)) {
+        let Some(index) = hold else {
+            unstowed += 1;
+            continue;
+        };
+        ctx.db.cargo_item().insert(CargoItem {
+            id: 0,
+            category: lot.category,
+            room_id: rooms[index].id,
+            mass_tons: lot.mass_tons,
+            volume_m3: lot.volume_m3,
+        });
+    }
+    if unstowed > 0 {
+        log::warn!("{} cargo lots had no hold to go in", unstowed);
+    }
+    stock_parts(ctx, supplies.spare_parts.stockpile_tons as f32);
+}
+
+/// Split `tons` of spare parts between the kinds by how many components
+/// of each kind the ship's systems have.
+fn stock_parts(ctx: &ReducerContext, tons: f32) {
+    let mut counts = [0u32; PART_KIND_COUNT];
+    for component in ctx.db.system_component().iter() {
+        let kind = crate::simulation::part_kind(component.component_type);
+        if let Some(count) = counts.get_mut(kind as usize) {
+            *count += 1;
+        }
+    }
+    for (kind, share) in launch_shares(&counts).into_iter().enumerate() {
+        ctx.db.part_stock().insert(PartStock {
+            kind: kind as u8,
+            mass_tons: tons * share,
+            launch_tons: tons * share,
+        });
+    }
+}
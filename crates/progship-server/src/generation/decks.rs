@@ -0,0 +1,69 @@
+//! Deck naming and zone theming.
+//!
+//! Counts the rooms each deck received per facility zone and stores the
+//! resulting [`DeckTheme`](progship_logic::genlib::decks::DeckTheme) as Deck rows.
+
+use crate::tables::*;
+use progship_logic::genlib::decks::{deck_themes, ZONE_COUNT};
+use progship_logic::radiation::{deck_transmission, shelter_deck};
+use spacetimedb::{ReducerContext, Table};
+
+pub(super) fn generate_decks(ctx: &ReducerContext, deck_count: u32) {
+    let zone_rooms = zone_rooms_per_deck(
+        ctx.db.room().iter().filter_map(|room| {
+            let node = ctx.db.graph_node().id().find(room.node_id)?;
+            (node.node_type == node_types::ROOM).then_some((room.deck, node.deck_preference))
+        }),
+        deck_count,
+    );
+
+    for (deck, theme) in deck_themes(&zone_rooms).into_iter().enumerate() {
+        log::info!("{}", theme.name);
+        ctx.db.deck().insert(Deck {
+            deck: deck as i32,
+            name: theme.name,
+            primary_zone: theme.primary_zone,
+            zone_rooms: theme.zone_rooms.to_vec(),
+            gravity: theme.gravity,
+            light_level: theme.light_level,
+            light_kelvin: theme.light_kelvin,
+            light_schedule: theme.light_schedule,
+            radiation_transmission: deck_transmission(deck as i32, deck_count),
+            storm_shelter: deck as i32 == shelter_deck(deck_count),
+        });
+    }
+}
+
+/// Tally `(deck, zone)` pairs into per-deck zone room counts, dropping rooms
+/// outside the deck range.
+fn zone_rooms_per_deck(
+    rooms: impl Iterator<Item = (i32, i32)>,
+    deck_count: u32,
+) -> Vec<[u32; ZONE_COUNT]> {
+    let mut counts = vec![[0u32; ZONE_COUNT]; deck_count as usize];
+    for (deck, zone) in rooms {
+        if deck >= 0 && (deck as u32) < deck_count {
+            let z = (zone.max(0) as usize).min(ZONE_COUNT - 1);
+            counts[deck as usize][z] += 1;
+        }
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zone_rooms_per_deck() {
+        let counts = zone_rooms_per_deck(
+            [(0, 0), (0, 0), (1, 1), (1, 9), (-1, 2), (5, 2)].into_iter(),
+            2,
+        );
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts[0][0], 2);
+        assert_eq!(counts[1][1], 1);
+        assert_eq!(counts[1][ZONE_COUNT - 1], 1);
+        assert_eq!(counts.iter().flatten().sum::<u32>(), 4);
+    }
+}
@@ -0,0 +1,103 @@
+//! Food chain generation.
+//!
+//! Plants crop beds in every hydroponics room, staggered through the growth
+//! cycle so harvests come in steadily, stocks a meal buffer in every
+//! galley (see [`progship_logic::food`]) and plants every arboretum (see
+//! [`progship_logic::arboretum`]).
+
+use crate::tables::*;
+use progship_logic::food::{crop_beds, CROP_CYCLE_HOURS, MEAL_BUFFER_PER_PERSON};
+use spacetimedb::{ReducerContext, Table};
+
+pub(super) fn generate_food_chain(ctx: &ReducerContext, total_pop: u32) {
+    // Beds share the growth chambers' rated output between them; a ship
+    // without hydroponics grows in the chambers' own rooms
+    let chambers: Vec<Subsystem> = ctx
+        .db
+        .subsystem()
+        .iter()
+        .filter(|s| s.subsystem_type == subsystem_types::GROWTH_CHAMBER)
+        .collect();
+    let rated: f32 = chambers.iter().map(|s| s.output).sum();
+    let mut farms: Vec<Room> = ctx
+        .db
+        .room()
+        .iter()
+        .filter(|r| r.room_type == room_types::HYDROPONICS)
+        .collect();
+    if farms.is_empty() {
+        farms = ctx
+            .db
+            .room()
+            .iter()
+            .filter(|r| chambers.iter().any(|s| s.node_id == r.node_id))
+            .collect();
+    }
+    let beds: Vec<u32> = farms
+        .iter()
+        .flat_map(|r| (0..crop_beds(r.width * r.height)).map(move |_| r.id))
+        .collect();
+    let expected_yield = rated * CROP_CYCLE_HOURS / beds.len().max(1) as f32;
+    for (i, &room_id) in beds.iter().enumerate() {
+        let growth = i as f32 / beds.len() as f32;
+        ctx.db.crop().insert(Crop {
+            id: 0,
+            room_id,
+            growth,
+            health: 1.0,
+            expected_yield,
+            planted_at: -(growth * CROP_CYCLE_HOURS) as f64,
+        });
+    }
+
+    // Every galley feeds an equal share of the ship; without one, the
+    // other kitchens cook
+    let mut galleys: Vec<Room> = ctx
+        .db
+        .room()
+        .iter()
+        .filter(|r| r.room_type == room_types::GALLEY)
+        .collect();
+    if galleys.is_empty() {
+        galleys = ctx
+            .db
+            .room()
+            .iter()
+            .filter(|r| r.room_type == room_types::BAKERY || room_types::is_dining(r.room_type))
+            .collect();
+    }
+    let capacity = total_pop as f32 / galleys.len().max(1) as f32 * MEAL_BUFFER_PER_PERSON;
+    for galley in &galleys {
+        ctx.db.meal_buffer().insert(MealBuffer {
+            galley_room_id: galley.id,
+            deck: galley.deck,
+            meals: capacity,
+            capacity,
+            cooks: 0,
+        });
+    }
+
+    let parks: Vec<Room> = ctx
+        .db
+        .room()
+        .iter()
+        .filter(|r| r.room_type == room_types::ARBORETUM)
+        .collect();
+    for park in &parks {
+        ctx.db.garden().insert(Garden {
+            room_id: park.id,
+            deck: park.deck,
+            area: park.width * park.height,
+            health: 1.0,
+            conditions: 1.0,
+            tended_at: 0.0,
+        });
+    }
+    log::info!(
+        "Planted {} crop beds in {} rooms and {} arboretums, stocked {} galleys",
+        beds.len(),
+        farms.len(),
+        parks.len(),
+        galleys.len()
+    );
+}
@@ -0,0 +1,105 @@
+//! Furniture, prop and activity anchor placement.
+//!
+//! Furnishes every placed room with the shared
+//! [`furnish_room`](progship_logic::genlib::furniture::furnish_room) plan and
+//! stores the pieces as Furniture rows in world coordinates. Pieces that would
+//! poke outside a shaped room's cell mask are dropped. The kept pieces then
+//! yield the room's [`room_anchors`](progship_logic::genlib::anchors::room_anchors),
+//! stored as ActivityAnchor rows.
+
+use crate::tables::*;
+use progship_logic::genlib::anchors::room_anchors;
+use progship_logic::genlib::furniture::{furnish_room, FurniturePlacement};
+use progship_logic::movement::cell_mask_contains;
+use spacetimedb::{ReducerContext, Table};
+use std::ops::RangeBounds;
+
+/// Furnish the rooms on `decks`, so a lazily generated ship is furnished
+/// deck by deck as it is laid out.
+pub(super) fn generate_furniture(ctx: &ReducerContext, decks: impl RangeBounds<i32>) {
+    let (mut placed, mut anchored) = (0, 0);
+    for room in ctx.db.room().iter().filter(|r| decks.contains(&r.deck)) {
+        let fits = |x: f32, y: f32, piece: &FurniturePlacement| {
+            room.cells.is_empty() || fits_cell_mask(&room.cells, x, y, piece)
+        };
+        let pieces: Vec<FurniturePlacement> =
+            furnish_room(room.room_type, room.width, room.height, room.capacity)
+                .into_iter()
+                .filter(|piece| fits(room.x + piece.x, room.y + piece.y, piece))
+                .collect();
+        for piece in &pieces {
+            ctx.db.furniture().insert(Furniture {
+                id: 0,
+                room_id: room.id,
+                furniture_type: piece.furniture_type,
+                x: room.x + piece.x,
+                y: room.y + piece.y,
+                width: piece.width,
+                height: piece.height,
+            });
+            placed += 1;
+        }
+        for anchor in room_anchors(room.room_type, room.width, room.height, &pieces) {
+            let (x, y) = (room.x + anchor.x, room.y + anchor.y);
+            if !room.cells.is_empty() && !cell_mask_contains(&room.cells, x, y) {
+                continue;
+            }
+            ctx.db.activity_anchor().insert(ActivityAnchor {
+                id: 0,
+                room_id: room.id,
+                anchor_type: anchor.anchor_type,
+                activity_type: anchor.activity_type,
+                x,
+                y,
+            });
+            anchored += 1;
+        }
+    }
+    log::info!(
+        "Placed {} pieces of furniture and {} activity anchors",
+        placed,
+        anchored
+    );
+}
+
+/// Whether every corner of a piece centered at `(x, y)` lies inside the mask.
+fn fits_cell_mask(cells: &[u8], x: f32, y: f32, piece: &FurniturePlacement) -> bool {
+    // Pull corners in slightly so pieces flush with a cell edge still count
+    let hw = piece.width / 2.0 - 0.01;
+    let hh = piece.height / 2.0 - 0.01;
+    [(-hw, -hh), (hw, -hh), (-hw, hh), (hw, hh)]
+        .iter()
+        .all(|&(dx, dy)| cell_mask_contains(cells, x + dx, y + dy))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mask(rects: &[(u16, u16, u16, u16)]) -> Vec<u8> {
+        rects
+            .iter()
+            .flat_map(|&(x0, y0, x1, y1)| [x0, y0, x1, y1])
+            .flat_map(u16::to_le_bytes)
+            .collect()
+    }
+
+    #[test]
+    fn test_fits_cell_mask() {
+        // L-shaped room: 10×4 bottom bar plus a 4×6 arm on the left
+        let cells = mask(&[(0, 0, 10, 4), (0, 4, 4, 10)]);
+        let bed = FurniturePlacement {
+            furniture_type: furniture_types::BED,
+            x: 0.0,
+            y: 0.0,
+            width: 1.0,
+            height: 2.0,
+        };
+        assert!(fits_cell_mask(&cells, 8.0, 2.0, &bed));
+        assert!(fits_cell_mask(&cells, 2.0, 7.0, &bed));
+        // Inside the bounding box but in the missing corner
+        assert!(!fits_cell_mask(&cells, 7.0, 7.0, &bed));
+        // Straddles the inner corner
+        assert!(!fits_cell_mask(&cells, 4.0, 4.5, &bed));
+    }
+}
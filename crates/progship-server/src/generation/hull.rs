@@ -1,41 +1,45 @@
 //! Hull dimension calculations for ship tapering.
 //!
-//! Provides functions to compute hull width and length per deck, implementing
-//! bow and stern tapering for the colony ship's aerodynamic profile.
-
-/// Hull width for a given deck, applying taper at bow (top decks) and stern (bottom decks).
-pub(super) fn hull_width(deck: u32, deck_count: u32, ship_beam: usize) -> usize {
-    match deck {
-        0..=1 => (ship_beam * 60 / 100).max(10), // bow: 60% of full beam
-        d if d >= deck_count.saturating_sub(2) => (ship_beam * 75 / 100).max(10), // stern: 75%
-        _ => ship_beam,
-    }
+//! Wraps the shared [`HullProfile`] from `progship_logic::genlib` to compute
+//! hull width and length per deck, tapering toward the bow (top decks) and
+//! stern (bottom decks) along the ship's `TaperCurve`.
+
+pub(super) use progship_logic::genlib::hull::{HullProfile, TaperCurve};
+
+/// Hull width for a given deck, tapering along `taper`.
+pub(super) fn hull_width(deck: u32, deck_count: u32, ship_beam: usize, taper: TaperCurve) -> usize {
+    HullProfile::with_taper(taper).width(deck, deck_count, ship_beam)
 }
 
-/// Hull length for a given deck, applying taper at bow (top decks) and stern (bottom decks).
-pub(super) fn hull_length(deck: u32, deck_count: u32, ship_length: usize) -> usize {
-    match deck {
-        0..=1 => (ship_length * 50 / 100).max(30), // bow: 50% of full length
-        d if d >= deck_count.saturating_sub(2) => (ship_length * 75 / 100).max(30), // stern: 75%
-        _ => ship_length,
-    }
+/// Hull length for a given deck, tapering along `taper`.
+pub(super) fn hull_length(
+    deck: u32,
+    deck_count: u32,
+    ship_length: usize,
+    taper: TaperCurve,
+) -> usize {
+    HullProfile::with_taper(taper).length(deck, deck_count, ship_length)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use TaperCurve::{Elliptical, Step};
 
     #[test]
     fn test_hull_width_taper_at_bow() {
         let deck_count = 20;
         let ship_beam = 100;
 
-        // Top decks (bow) should be 60% of full beam
-        assert_eq!(hull_width(0, deck_count, ship_beam), 60);
-        assert_eq!(hull_width(1, deck_count, ship_beam), 60);
+        // Bow tip deck is 60% of full beam
+        assert_eq!(hull_width(0, deck_count, ship_beam, Elliptical), 60);
+
+        // Taper widens toward the equator
+        let w1 = hull_width(1, deck_count, ship_beam, Elliptical);
+        assert!(w1 > 60 && w1 < ship_beam, "deck 1 width {}", w1);
 
         // Middle decks should use full beam
-        assert_eq!(hull_width(10, deck_count, ship_beam), ship_beam);
+        assert_eq!(hull_width(10, deck_count, ship_beam, Elliptical), ship_beam);
     }
 
     #[test]
@@ -43,9 +47,10 @@ mod tests {
         let deck_count = 20;
         let ship_beam = 100;
 
-        // Bottom decks (stern) should be 75% of full beam
-        assert_eq!(hull_width(18, deck_count, ship_beam), 75);
-        assert_eq!(hull_width(19, deck_count, ship_beam), 75);
+        // Stern tip deck is 75% of full beam
+        assert_eq!(hull_width(19, deck_count, ship_beam, Elliptical), 75);
+        let w18 = hull_width(18, deck_count, ship_beam, Elliptical);
+        assert!(w18 > 75 && w18 < ship_beam, "deck 18 width {}", w18);
     }
 
     #[test]
@@ -56,7 +61,7 @@ mod tests {
         // Middle decks should use full beam (equator)
         for deck in 5..15 {
             assert_eq!(
-                hull_width(deck, deck_count, ship_beam),
+                hull_width(deck, deck_count, ship_beam, Elliptical),
                 ship_beam,
                 "Deck {} should have full beam",
                 deck
@@ -64,14 +69,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_hull_width_monotonic_taper() {
+        let deck_count = 20;
+        let ship_beam = 100;
+        let widths: Vec<usize> = (0..deck_count)
+            .map(|d| hull_width(d, deck_count, ship_beam, Elliptical))
+            .collect();
+
+        // Non-decreasing from bow to mid, non-increasing from mid to stern
+        let mid = (deck_count / 2) as usize;
+        assert!(widths[..=mid].windows(2).all(|w| w[0] <= w[1]));
+        assert!(widths[mid..].windows(2).all(|w| w[0] >= w[1]));
+
+        // Shape visibly changes across the taper decks
+        let mut distinct = widths.clone();
+        distinct.dedup();
+        assert!(distinct.len() >= 6, "widths {:?}", widths);
+    }
+
     #[test]
     fn test_hull_length_taper_at_bow() {
         let deck_count = 20;
         let ship_length = 200;
 
-        // Top decks (bow) should be 50% of full length
-        assert_eq!(hull_length(0, deck_count, ship_length), 100);
-        assert_eq!(hull_length(1, deck_count, ship_length), 100);
+        // Bow tip deck is 50% of full length
+        assert_eq!(hull_length(0, deck_count, ship_length, Elliptical), 100);
+        assert!(hull_length(1, deck_count, ship_length, Elliptical) > 100);
     }
 
     #[test]
@@ -79,9 +103,9 @@ mod tests {
         let deck_count = 20;
         let ship_length = 200;
 
-        // Bottom decks (stern) should be 75% of full length
-        assert_eq!(hull_length(18, deck_count, ship_length), 150);
-        assert_eq!(hull_length(19, deck_count, ship_length), 150);
+        // Stern tip deck is 75% of full length
+        assert_eq!(hull_length(19, deck_count, ship_length, Elliptical), 150);
+        assert!(hull_length(18, deck_count, ship_length, Elliptical) > 150);
     }
 
     #[test]
@@ -92,7 +116,7 @@ mod tests {
         // Middle decks should use full length
         for deck in 5..15 {
             assert_eq!(
-                hull_length(deck, deck_count, ship_length),
+                hull_length(deck, deck_count, ship_length, Elliptical),
                 ship_length,
                 "Deck {} should have full length",
                 deck
@@ -107,9 +131,24 @@ mod tests {
         let ship_length = 100;
 
         // Taper is proportional
-        assert_eq!(hull_width(0, deck_count, ship_beam), 18); // 60%
-        assert_eq!(hull_width(4, deck_count, ship_beam), 22); // 75%
-        assert_eq!(hull_length(0, deck_count, ship_length), 50); // 50%
-        assert_eq!(hull_length(4, deck_count, ship_length), 75); // 75%
+        assert_eq!(hull_width(0, deck_count, ship_beam, Elliptical), 18); // 60%
+        assert_eq!(hull_width(4, deck_count, ship_beam, Elliptical), 22); // 75%
+        assert_eq!(hull_length(0, deck_count, ship_length, Elliptical), 50); // 50%
+        assert_eq!(hull_length(4, deck_count, ship_length, Elliptical), 75); // 75%
+
+        // Midship deck is never tapered
+        assert_eq!(hull_width(2, deck_count, ship_beam, Elliptical), ship_beam);
+    }
+
+    #[test]
+    fn test_taper_curve_changes_deck_widths() {
+        let deck_count = 20;
+        let ship_beam = 100;
+
+        // A stepped bow keeps the tip beam across its taper decks
+        assert_eq!(hull_width(0, deck_count, ship_beam, Step), 60);
+        assert_eq!(hull_width(1, deck_count, ship_beam, Step), 60);
+        assert!(hull_width(1, deck_count, ship_beam, Elliptical) > 60);
+        assert_eq!(hull_width(10, deck_count, ship_beam, Step), ship_beam);
     }
 }
@@ -1,2369 +1,93 @@
-//! Ring-and-spur ship layout generation (Wave 14).
+//! Ship layout: lays out the facility graph through the shared ring-and-spur
+//! generator in `progship_logic::genlib::layout` and stores the result as
+//! Room, Door, Corridor, HullHatch and VerticalShaft rows.
 //!
-//! Pipeline: hull sizing → perimeter ring corridor → spine + cross-corridors as spurs →
-//! shafts at intersections → segment identification → BSP room packing →
-//! wavefront BFS gap fill → filler backfill → room-to-room doors.
-//!
-//! The ring corridor wraps the entire deck perimeter as a first-class public
-//! walkway (same width as spine). Rooms fill rectangular segments between
-//! corridors — every room touches at least one corridor by construction.
+//! The core engine lays out its ships with the same generator, so both hosts
+//! build the same decks from the same graph.
 
-use super::doors::should_have_room_door;
-use super::hull::{hull_length, hull_width};
-use super::treemap::RoomRequest;
 use crate::tables::*;
-use progship_logic::constants::deck_heights;
-use progship_logic::constants::placement;
+use progship_logic::genlib::facilities::{zone_deck_ranges, ZoneDecks};
+use progship_logic::genlib::graph::FacilityNode;
+use progship_logic::genlib::hull::{HullProfile, TaperCurve};
+use progship_logic::genlib::layout::{
+    generate_deck_layouts, join_shafts, CorridorTopology, LayoutConfig, LayoutDoor, ShipLayoutPlan,
+};
+use progship_logic::geometry::{check_shaft_alignment, DoorInfo, RoomRect};
 use spacetimedb::{ReducerContext, Table};
+use std::ops::RangeBounds;
 
-// Grid cell type markers
-const CELL_EMPTY: u8 = 0;
-const CELL_MAIN_CORRIDOR: u8 = 1;
-const CELL_SHAFT: u8 = 3;
-const CELL_HULL: u8 = 4;
-const CELL_ROOM_BASE: u8 = 10;
-
-// Corridor geometry
-const SPINE_WIDTH: usize = 4;
-const CROSS_CORRIDOR_WIDTH: usize = 4;
-const RING_WIDTH: usize = 4; // perimeter ring — same as spine
-const SPUR_WIDTH: usize = 3; // spur corridors — narrower than spine
-const MIN_ROOM_DIM: usize = 4;
-const SPUR_THRESHOLD: usize = 12; // add spurs when segment wider than this
-const HULL_BAND_WIDTH: usize = 15; // hull-facing room zone outside ring corridor (N/S only)
-
-/// Filler room pool: used to backfill empty deck space after zone rooms are placed.
-const FILLER_POOL: &[(u8, &str, f32, u32)] = &[
-    (room_types::STORAGE, "Storage", 60.0, 0),
-    (room_types::MAINTENANCE_BAY, "Maintenance Bay", 40.0, 4),
-    (room_types::PARTS_STORAGE, "Parts Storage", 30.0, 0),
-    (room_types::WORKSHOP, "Workshop", 35.0, 6),
-    (room_types::UTILITY, "Utility Room", 20.0, 2),
-    (room_types::EMERGENCY_SUPPLY, "Emergency Supply", 25.0, 0),
-];
-
-/// Returns true if room type is habitation (cabins, quarters, suites).
-fn is_habitation(rt: u8) -> bool {
-    matches!(
-        rt,
-        room_types::CABIN_SINGLE
-            | room_types::CABIN_DOUBLE
-            | room_types::FAMILY_SUITE
-            | room_types::VIP_SUITE
-            | room_types::QUARTERS_CREW
-            | room_types::QUARTERS_OFFICER
-            | room_types::QUARTERS_PASSENGER
-            | room_types::SHARED_BATHROOM
-            | room_types::SHARED_LAUNDRY
-    )
-}
-
-/// J7: Expand placed rooms into adjacent empty cells (rectangular expansion).
-/// Called multiple times: after BSP, after wavefront BFS, and after fillers.
-#[allow(clippy::too_many_arguments)]
-fn expand_rooms_into_empty(
+/// Lay out the ship's `decks`, returning the deck count (sized from the room
+/// area when `deck_count` is 0). Shafts are joined across decks once the last
+/// deck is laid out, so decks can be generated in order over several calls.
+pub(super) fn layout_ship(
     ctx: &ReducerContext,
-    grid: &mut [Vec<u8>],
-    placed_rooms: &mut [(u32, usize, usize, usize, usize, u8, f32, u8)],
-    inner_x0: usize,
-    inner_y0: usize,
-    inner_x1: usize,
-    inner_y1: usize,
-    ring_x0: usize,
-    hull_y0: usize,
-    ring_x1: usize,
-    hull_y1: usize,
-    deck: i32,
-    label: &str,
-) {
-    let mut expanded = 0u32;
-    for i in 0..placed_rooms.len() {
-        let (room_id, mut rx, mut ry, mut rw, mut rh, _rt, _target_area, room_placement) =
-            placed_rooms[i];
-        let cell_tag = CELL_ROOM_BASE + (room_id as u8 % 246);
-        let (exp_x0, exp_y0, exp_x1, exp_y1) = if room_placement == placement::HULL_FACING {
-            (ring_x0, hull_y0, ring_x1, hull_y1)
-        } else {
-            (inner_x0, inner_y0, inner_x1, inner_y1)
-        };
-        let mut changed = true;
-        while changed {
-            changed = false;
-            // Try expand east (+x)
-            let new_x1 = rx + rw;
-            if new_x1 < exp_x1 {
-                let col_clear = (ry..ry + rh).all(|y| grid[new_x1][y] == CELL_EMPTY);
-                if col_clear {
-                    for y in ry..ry + rh {
-                        grid[new_x1][y] = cell_tag;
-                    }
-                    rw += 1;
-                    changed = true;
-                }
-            }
-            // Try expand west (-x)
-            if rx > exp_x0 {
-                let col_clear = (ry..ry + rh).all(|y| grid[rx - 1][y] == CELL_EMPTY);
-                if col_clear {
-                    rx -= 1;
-                    for y in ry..ry + rh {
-                        grid[rx][y] = cell_tag;
-                    }
-                    rw += 1;
-                    changed = true;
-                }
-            }
-            // Try expand south (+y)
-            let new_y1 = ry + rh;
-            if new_y1 < exp_y1 {
-                let row_clear = (rx..rx + rw).all(|x| grid[x][new_y1] == CELL_EMPTY);
-                if row_clear {
-                    for x in rx..rx + rw {
-                        grid[x][new_y1] = cell_tag;
-                    }
-                    rh += 1;
-                    changed = true;
-                }
-            }
-            // Try expand north (-y)
-            if ry > exp_y0 {
-                let row_clear = (rx..rx + rw).all(|x| grid[x][ry - 1] == CELL_EMPTY);
-                if row_clear {
-                    ry -= 1;
-                    for x in rx..rx + rw {
-                        grid[x][ry] = cell_tag;
-                    }
-                    rh += 1;
-                    changed = true;
-                }
-            }
-        }
-        if (rx, ry, rw, rh)
-            != (
-                placed_rooms[i].1,
-                placed_rooms[i].2,
-                placed_rooms[i].3,
-                placed_rooms[i].4,
-            )
-        {
-            expanded += 1;
-            placed_rooms[i].1 = rx;
-            placed_rooms[i].2 = ry;
-            placed_rooms[i].3 = rw;
-            placed_rooms[i].4 = rh;
-            if let Some(mut room) = ctx.db.room().id().find(room_id) {
-                room.x = rx as f32 + rw as f32 / 2.0;
-                room.y = ry as f32 + rh as f32 / 2.0;
-                room.width = rw as f32;
-                room.height = rh as f32;
-                ctx.db.room().id().update(room);
-            }
-        }
-    }
-    if expanded > 0 {
-        log::info!("Deck {}: {} expanded {} rooms", deck + 1, label, expanded);
-    }
-}
-
-/// Shaft definition for placement at corridor intersections.
-struct ShaftPlacement {
-    x: usize,
-    y: usize,
-    w: usize,
-    h: usize,
-    shaft_type: u8,
-    name: &'static str,
-    is_main: bool,
-    /// Preferred door wall (NORTH/SOUTH/EAST/WEST) — consistent across all decks.
-    preferred_wall: u8,
-}
-
-/// A rectangular segment between corridors where rooms can be placed.
-/// Every room in a segment touches at least one corridor edge.
-struct Segment {
-    x: usize,
-    y: usize,
-    w: usize,
-    h: usize,
-    /// Which corridor the rooms should get their door to (spine/cross/ring)
-    corridor_id: u32,
-    /// Which wall side of the corridor the segment is on
-    wall_side: u8,
-}
-
-pub(super) fn layout_ship(ctx: &ReducerContext, deck_count: u32, total_pop: u32) {
-    let nodes: Vec<GraphNode> = ctx.db.graph_node().iter().collect();
-
-    // ---- Compute shaft requirements from population ----
-    let shaft_templates = compute_shaft_templates(total_pop);
-
-    // ---- Hull sizing from total room area ----
-    let total_area: f32 = nodes.iter().map(|n| n.required_area).sum();
-    let max_room_area: f32 = nodes.iter().map(|n| n.required_area).fold(0.0f32, f32::max);
-    let shaft_area_per_deck: f32 = shaft_templates
-        .iter()
-        .map(|(_, _, _, w, h)| (*w * *h) as f32)
-        .sum();
-
-    let deck_count = if deck_count == 0 {
-        compute_optimal_deck_count(
-            total_area,
-            shaft_area_per_deck,
-            &shaft_templates,
-            max_room_area,
-        )
-    } else {
-        deck_count
-    };
-
-    let room_area_per_deck = total_area / deck_count as f32;
-    let (ship_beam, ship_length) =
-        compute_hull_dimensions(room_area_per_deck, shaft_area_per_deck, max_room_area);
-    log::info!(
-        "Hull sizing: {:.0}m² total room area, {}×{} hull ({} decks, {} shafts, {:.0}m² shaft overhead/deck)",
-        total_area, ship_beam, ship_length, deck_count, shaft_templates.len(), shaft_area_per_deck
-    );
-
-    // ---- Build per-zone room request lists ----
-    let mut zone_requests: Vec<Vec<RoomRequest>> = vec![Vec::new(); 7];
-    for node in &nodes {
-        let zone = (node.deck_preference as u8).min(6);
-        zone_requests[zone as usize].push(RoomRequest {
-            node_id: node.id,
-            name: node.name.clone(),
-            room_type: node.function,
-            target_area: node.required_area,
-            capacity: node.capacity,
-            group: node.group,
-            placement: placement::room_placement(node.function),
-        });
-    }
-    for zr in zone_requests.iter_mut() {
-        zr.sort_by(|a, b| {
-            b.target_area
-                .partial_cmp(&a.target_area)
-                .unwrap_or(core::cmp::Ordering::Equal)
-        });
-    }
-
-    // ---- Demand-driven zone-to-deck assignment (outside-in) ----
-    let est_strip_area = {
-        let uw = ship_beam.saturating_sub(SPINE_WIDTH + 2 * RING_WIDTH);
-        let nc = ((ship_length as f32 / 35.0).round() as usize).max(1);
-        let ul = ship_length.saturating_sub(2 * RING_WIDTH + nc * CROSS_CORRIDOR_WIDTH);
-        uw as f32 * ul as f32 * 0.8 - shaft_area_per_deck
-    };
-    let est_strip_area = est_strip_area.max(100.0);
-
-    let zone_areas: Vec<f32> = zone_requests
-        .iter()
-        .map(|zr| zr.iter().map(|r| r.target_area).sum())
-        .collect();
-    let zone_decks_needed: Vec<u32> = zone_areas
-        .iter()
-        .map(|&a| {
-            if a > 0.0 {
-                (a / est_strip_area).ceil().max(1.0) as u32
-            } else {
-                0
-            }
-        })
-        .collect();
-
-    let mut deck_zone_map: Vec<u8> = vec![1; deck_count as usize];
-    let dc = deck_count as usize;
-
-    // Top: COMMAND
-    let cmd_decks = zone_decks_needed[0].min(deck_count) as usize;
-    for d in 0..cmd_decks.min(dc) {
-        deck_zone_map[d] = 0;
-    }
-    // Bottom: ENGINEERING
-    let eng_decks = zone_decks_needed[6].min(deck_count) as usize;
-    for d in 0..eng_decks.min(dc) {
-        let idx = dc - 1 - d;
-        if deck_zone_map[idx] == 1 {
-            deck_zone_map[idx] = 6;
-        }
-    }
-    // Above engineering: CARGO
-    let cargo_start = dc.saturating_sub(eng_decks);
-    let cargo_decks = zone_decks_needed[5].min(deck_count) as usize;
-    for d in 0..cargo_decks {
-        let idx = cargo_start.saturating_sub(1 + d);
-        if idx < dc && deck_zone_map[idx] == 1 {
-            deck_zone_map[idx] = 5;
-        }
-    }
-    // Below command: LIFE_SUPPORT
-    let life_start = cmd_decks;
-    let life_decks = zone_decks_needed[4].min(deck_count) as usize;
-    for d in 0..life_decks {
-        let idx = life_start + d;
-        if idx < dc && deck_zone_map[idx] == 1 {
-            deck_zone_map[idx] = 4;
-        }
-    }
-    // REC and SVC in middle of remaining HAB slots
-    let hab_slots: Vec<usize> = (0..dc).filter(|&d| deck_zone_map[d] == 1).collect();
-    if !hab_slots.is_empty() {
-        let rec_decks = zone_decks_needed[3].min(hab_slots.len() as u32) as usize;
-        for d in 0..rec_decks {
-            let idx_pos = (hab_slots.len() / 2 + d).min(hab_slots.len() - 1);
-            let idx = hab_slots[idx_pos];
-            deck_zone_map[idx] = 3;
-        }
-        let svc_decks = zone_decks_needed[2].min(hab_slots.len() as u32) as usize;
-        let hab_slots2: Vec<usize> = (0..dc).filter(|&d| deck_zone_map[d] == 1).collect();
-        for d in 0..svc_decks {
-            if let Some(&idx) = hab_slots2.get(hab_slots2.len() / 2 + d) {
-                deck_zone_map[idx] = 2;
-            }
-        }
-    }
-
-    let zone_names = ["CMD", "HAB", "SVC", "REC", "LIFE", "CARGO", "ENG"];
-    for (d, &z) in deck_zone_map.iter().enumerate() {
-        log::info!("Deck {} → Zone {} ({})", d, z, zone_names[z as usize]);
-    }
-
-    let mut zone_cursors: Vec<usize> = vec![0; 7];
-
-    // Count how many decks each zone has, for proportional distribution
-    let mut zone_deck_counts: Vec<u32> = vec![0; 7];
-    for &z in &deck_zone_map {
-        zone_deck_counts[z as usize] += 1;
-    }
-    let mut zone_deck_seen: Vec<u32> = vec![0; 7];
-
-    let mut room_id_counter: u32 = 0;
-    let mut next_id = || {
-        let id = room_id_counter;
-        room_id_counter += 1;
-        id
-    };
-
-    // Track shaft positions across decks for VerticalShaft entries
-    struct ShaftInfo {
-        name: &'static str,
-        shaft_type: u8,
-        is_main: bool,
-        deck_room_ids: Vec<Option<u32>>,
-        ref_x: f32,
-        ref_y: f32,
-        ref_w: f32,
-        ref_h: f32,
-    }
-
-    let mut shaft_infos: Vec<ShaftInfo> = shaft_templates
+    deck_count: u32,
+    total_pop: u32,
+    topology: CorridorTopology,
+    taper: TaperCurve,
+    decks: impl RangeBounds<u32>,
+) -> u32 {
+    let decks = (decks.start_bound().cloned(), decks.end_bound().cloned());
+    let mut graph_nodes: Vec<GraphNode> = ctx.db.graph_node().iter().collect();
+    graph_nodes.sort_by_key(|n| n.id);
+    let nodes: Vec<FacilityNode> = graph_nodes
         .iter()
-        .map(|(name, st, is_main, w, h)| ShaftInfo {
-            name,
-            shaft_type: *st,
-            is_main: *is_main,
-            deck_room_ids: vec![None; deck_count as usize],
-            ref_x: 0.0,
-            ref_y: 0.0,
-            ref_w: *w as f32,
-            ref_h: *h as f32,
+        .map(|n| FacilityNode {
+            name: n.name.clone(),
+            room_type: n.function,
+            target_area: n.required_area,
+            capacity: n.capacity,
+            // No preference (-1) lands in the last zone
+            deck_zone: (n.deck_preference as u8).min(6),
+            group: n.group,
         })
         .collect();
 
-    // ---- Compute global shaft positions from midship deck ----
-    // Grid is expanded by HULL_BAND_WIDTH on each side for hull-facing rooms.
-    // All interior positions are offset by HULL_BAND_WIDTH in the grid.
-    let mid_deck = deck_count / 2;
-    let mid_hw_hull = hull_width(mid_deck, deck_count, ship_beam);
-    let mid_hl_hull = hull_length(mid_deck, deck_count, ship_length);
-    let mid_hw = mid_hw_hull; // no hull band on east/west
-    let mid_hl = mid_hl_hull + 2 * HULL_BAND_WIDTH; // hull band on north/south only
-    let mid_spine_left = mid_hw / 2 - SPINE_WIDTH / 2;
-    let mid_spine_right = mid_spine_left + SPINE_WIDTH;
-    // Cross-corridors are inside the ring; compute in interior space then offset
-    let interior_hl = mid_hl_hull.saturating_sub(2 * RING_WIDTH);
-    // J3: Factor deck width into cross-corridor spacing.
-    // Wider decks need more cross-corridors (rooms further from corridors).
-    // Base: every 35m for full-width decks, up to every 50m for narrow tapered decks.
-    let cross_spacing_base = if mid_hw > 60 {
-        35.0
-    } else if mid_hw > 40 {
-        40.0
-    } else {
-        50.0
+    let config = LayoutConfig {
+        deck_count,
+        population: total_pop,
+        topology,
+        hull: HullProfile::with_taper(taper),
+        zone_overrides: ctx
+            .db
+            .zone_config()
+            .iter()
+            .filter(|z| z.overridden)
+            .map(|z| ZoneDecks {
+                zone: z.zone,
+                first_deck: z.first_deck,
+                end_deck: z.end_deck,
+            })
+            .collect(),
+        // Lazily generated decks continue numbering after the rooms already laid out
+        first_room_id: ctx.db.room().iter().map(|r| r.id + 1).max().unwrap_or(0),
+        ..Default::default()
     };
-    let mid_num_cross = ((interior_hl as f32 / cross_spacing_base).round() as usize).max(1);
-    let mid_cross_spacing = interior_hl / (mid_num_cross + 1);
-    let inner_y0_ref = HULL_BAND_WIDTH + RING_WIDTH; // ring outer + ring width
-    let mut mid_cross_ys: Vec<usize> = Vec::new();
-    for i in 1..=mid_num_cross {
-        let cy = inner_y0_ref + i * mid_cross_spacing;
-        if cy + CROSS_CORRIDOR_WIDTH <= mid_hl - HULL_BAND_WIDTH - RING_WIDTH {
-            mid_cross_ys.push(cy);
-        }
-    }
-    let global_shaft_placements = compute_shaft_placements(
-        &shaft_templates,
-        mid_spine_right,
-        mid_spine_left,
-        &mid_cross_ys,
-        mid_hw,
-        mid_hl,
-    );
-
-    // ---- Per-deck generation ----
-    let spine_left = mid_spine_left;
-    let spine_right = mid_spine_right;
-
-    for deck in 0..deck_count as i32 {
-        let deck_hw = hull_width(deck as u32, deck_count, ship_beam);
-        let deck_hl = hull_length(deck as u32, deck_count, ship_length);
-
-        if deck_hw < 12 || deck_hl < 30 {
-            log::warn!(
-                "Deck {} too small ({}×{}), skipping",
-                deck + 1,
-                deck_hw,
-                deck_hl
-            );
-            continue;
-        }
-
-        let hw = mid_hw;
-        let hl = mid_hl;
-        let mut grid: Vec<Vec<u8>> = vec![vec![CELL_EMPTY; hl]; hw];
-
-        // Mask cells outside tapered hull (hull band cells stay CELL_EMPTY)
-        let taper_margin_x = (mid_hw_hull - deck_hw) / 2;
-        let taper_margin_y = (mid_hl_hull - deck_hl) / 2;
-        let hull_x0 = taper_margin_x; // outer hull edge in expanded grid
-        let hull_x1 = hw - taper_margin_x;
-        let hull_y0 = taper_margin_y;
-        let hull_y1 = hl - taper_margin_y;
-        for x in 0..hw {
-            for y in 0..hl {
-                if x < hull_x0 || x >= hull_x1 || y < hull_y0 || y >= hull_y1 {
-                    grid[x][y] = CELL_HULL;
-                }
-            }
-        }
-
-        // Ring corridor: no hull band on X (ring touches hull), hull band on Y (N/S)
-        let x_margin = taper_margin_x;
-        let y_margin = HULL_BAND_WIDTH + taper_margin_y;
-
-        // ---- Phase 1: Ring corridor (perimeter) ----
-        let ring_x0 = x_margin;
-        let ring_x1 = hw - x_margin;
-        let ring_y0 = y_margin;
-        let ring_y1 = hl - y_margin;
-        let inner_x0 = ring_x0 + RING_WIDTH;
-        let inner_x1 = ring_x1.saturating_sub(RING_WIDTH);
-        let inner_y0 = ring_y0 + RING_WIDTH;
-        let inner_y1 = ring_y1.saturating_sub(RING_WIDTH);
-
-        // Stamp ring cells
-        for x in ring_x0..ring_x1 {
-            for y in ring_y0..ring_y1 {
-                if grid[x][y] == CELL_HULL {
-                    continue;
-                }
-                let in_ring = x < inner_x0 || x >= inner_x1 || y < inner_y0 || y >= inner_y1;
-                if in_ring {
-                    grid[x][y] = CELL_MAIN_CORRIDOR;
-                }
-            }
-        }
-
-        // Ring Room entries (4 segments: N, S, W, E)
-        let ring_n_id = next_id();
-        let ring_n_w = (ring_x1 - ring_x0) as f32;
-        ctx.db.room().insert(Room {
-            id: ring_n_id,
-            node_id: 0,
-            name: format!("Ring North D{}", deck + 1),
-            room_type: room_types::CORRIDOR,
-            deck,
-            x: ring_x0 as f32 + ring_n_w / 2.0,
-            y: ring_y0 as f32 + RING_WIDTH as f32 / 2.0,
-            width: ring_n_w,
-            height: RING_WIDTH as f32,
-            capacity: 0,
-            ceiling_height: deck_heights::MIN_DECK_HEIGHT,
-            deck_span: 1,
-            cells: Vec::new(),
-        });
-        let ring_s_id = next_id();
-        ctx.db.room().insert(Room {
-            id: ring_s_id,
-            node_id: 0,
-            name: format!("Ring South D{}", deck + 1),
-            room_type: room_types::CORRIDOR,
-            deck,
-            x: ring_x0 as f32 + ring_n_w / 2.0,
-            y: (ring_y1 - RING_WIDTH) as f32 + RING_WIDTH as f32 / 2.0,
-            width: ring_n_w,
-            height: RING_WIDTH as f32,
-            capacity: 0,
-            ceiling_height: deck_heights::MIN_DECK_HEIGHT,
-            deck_span: 1,
-            cells: Vec::new(),
-        });
-        let ring_w_id = next_id();
-        let ring_side_h = (inner_y1 - inner_y0) as f32;
-        ctx.db.room().insert(Room {
-            id: ring_w_id,
-            node_id: 0,
-            name: format!("Ring West D{}", deck + 1),
-            room_type: room_types::CORRIDOR,
-            deck,
-            x: ring_x0 as f32 + RING_WIDTH as f32 / 2.0,
-            y: inner_y0 as f32 + ring_side_h / 2.0,
-            width: RING_WIDTH as f32,
-            height: ring_side_h,
-            capacity: 0,
-            ceiling_height: deck_heights::MIN_DECK_HEIGHT,
-            deck_span: 1,
-            cells: Vec::new(),
-        });
-        let ring_e_id = next_id();
-        ctx.db.room().insert(Room {
-            id: ring_e_id,
-            node_id: 0,
-            name: format!("Ring East D{}", deck + 1),
-            room_type: room_types::CORRIDOR,
-            deck,
-            x: (ring_x1 - RING_WIDTH) as f32 + RING_WIDTH as f32 / 2.0,
-            y: inner_y0 as f32 + ring_side_h / 2.0,
-            width: RING_WIDTH as f32,
-            height: ring_side_h,
-            capacity: 0,
-            ceiling_height: deck_heights::MIN_DECK_HEIGHT,
-            deck_span: 1,
-            cells: Vec::new(),
-        });
-
-        // Ring corner doors (N↔W, N↔E, S↔W, S↔E) — use find_shared_edge for correct walls
-        // Ring grid bounds (top-left corner, width, height):
-        let ring_n_grid = (ring_x0, ring_y0, ring_x1 - ring_x0, RING_WIDTH);
-        let ring_s_grid = (ring_x0, inner_y1, ring_x1 - ring_x0, RING_WIDTH);
-        let ring_w_grid = (ring_x0, inner_y0, RING_WIDTH, inner_y1 - inner_y0);
-        let ring_e_grid = (
-            ring_x1 - RING_WIDTH,
-            inner_y0,
-            RING_WIDTH,
-            inner_y1 - inner_y0,
-        );
-        for &(a_id, a_grid, b_id, b_grid) in &[
-            (ring_n_id, ring_n_grid, ring_w_id, ring_w_grid),
-            (ring_n_id, ring_n_grid, ring_e_id, ring_e_grid),
-            (ring_s_id, ring_s_grid, ring_w_id, ring_w_grid),
-            (ring_s_id, ring_s_grid, ring_e_id, ring_e_grid),
-        ] {
-            if let Some((dx, dy, wa, wb, _ol)) = find_shared_edge(
-                a_grid.0, a_grid.1, a_grid.2, a_grid.3, b_grid.0, b_grid.1, b_grid.2, b_grid.3,
-            ) {
-                ctx.db.door().insert(Door {
-                    id: 0,
-                    room_a: a_id,
-                    room_b: b_id,
-                    wall_a: wa,
-                    wall_b: wb,
-                    position_along_wall: 0.5,
-                    width: RING_WIDTH as f32,
-                    access_level: access_levels::PUBLIC,
-                    door_x: dx,
-                    door_y: dy,
-                    is_open: true,
-                    is_locked: false,
-                });
-            }
-        }
-
-        // Ring Corridor table entries
-        ctx.db.corridor().insert(Corridor {
-            id: 0,
-            deck,
-            corridor_type: corridor_types::MAIN,
-            x: ring_x0 as f32,
-            y: ring_y0 as f32,
-            width: (ring_x1 - ring_x0) as f32,
-            length: RING_WIDTH as f32,
-            orientation: 0,
-            carries: carries_flags::CREW_PATH,
-        });
-
-        // ---- Phase 2: Spine corridor (center spur) ----
-        // Spine runs from ring-north inner edge to ring-south inner edge
-        for x in spine_left..spine_right.min(hw) {
-            for y in inner_y0..inner_y1 {
-                if grid[x][y] == CELL_EMPTY {
-                    grid[x][y] = CELL_MAIN_CORRIDOR;
-                }
-            }
-        }
-
-        // Spine segment Room entries (between cross-corridors)
-        let cross_ys = &mid_cross_ys;
-        let mut spine_segments: Vec<(u32, usize, usize)> = Vec::new();
-        {
-            let mut seg_boundaries: Vec<usize> = vec![inner_y0];
-            for &cy in cross_ys.iter() {
-                if cy >= inner_y0 && cy + CROSS_CORRIDOR_WIDTH <= inner_y1 {
-                    seg_boundaries.push(cy);
-                    seg_boundaries.push(cy + CROSS_CORRIDOR_WIDTH);
-                }
-            }
-            seg_boundaries.push(inner_y1);
-
-            for chunk in seg_boundaries.chunks(2) {
-                if chunk.len() < 2 || chunk[0] >= chunk[1] {
-                    continue;
-                }
-                let y0 = chunk[0];
-                let y1 = chunk[1];
-                let seg_id = next_id();
-                ctx.db.room().insert(Room {
-                    id: seg_id,
-                    node_id: 0,
-                    name: format!("Spine D{} Y{}-{}", deck + 1, y0, y1),
-                    room_type: room_types::CORRIDOR,
-                    deck,
-                    x: spine_left as f32 + SPINE_WIDTH as f32 / 2.0,
-                    y: y0 as f32 + (y1 - y0) as f32 / 2.0,
-                    width: SPINE_WIDTH as f32,
-                    height: (y1 - y0) as f32,
-                    capacity: 0,
-                    ceiling_height: deck_heights::MIN_DECK_HEIGHT,
-                    deck_span: 1,
-                    cells: Vec::new(),
-                });
-                spine_segments.push((seg_id, y0, y1));
-            }
-        }
-
-        ctx.db.corridor().insert(Corridor {
-            id: 0,
-            deck,
-            corridor_type: corridor_types::MAIN,
-            x: spine_left as f32,
-            y: inner_y0 as f32,
-            width: SPINE_WIDTH as f32,
-            length: (inner_y1 - inner_y0) as f32,
-            orientation: 1,
-            carries: carries_flags::CREW_PATH | carries_flags::POWER | carries_flags::DATA,
-        });
-
-        // Cross-corridor Room entries (from ring-west to ring-east)
-        let mut cross_rooms: Vec<(u32, usize)> = Vec::new();
-        for &cy in cross_ys.iter() {
-            if cy < inner_y0 || cy + CROSS_CORRIDOR_WIDTH > inner_y1 {
-                continue;
-            }
-            let cc_x0 = inner_x0;
-            let cc_x1 = inner_x1;
-            let cc_w = cc_x1.saturating_sub(cc_x0);
-            if cc_w < MIN_ROOM_DIM {
-                continue;
-            }
-            for x in cc_x0..cc_x1 {
-                for y in cy..cy + CROSS_CORRIDOR_WIDTH {
-                    if y < hl && grid[x][y] == CELL_EMPTY {
-                        grid[x][y] = CELL_MAIN_CORRIDOR;
-                    }
-                }
-            }
-            let cc_id = next_id();
-            ctx.db.room().insert(Room {
-                id: cc_id,
-                node_id: 0,
-                name: format!("Cross-Corridor D{} Y{}", deck + 1, cy),
-                room_type: room_types::CROSS_CORRIDOR,
-                deck,
-                x: cc_x0 as f32 + cc_w as f32 / 2.0,
-                y: cy as f32 + CROSS_CORRIDOR_WIDTH as f32 / 2.0,
-                width: cc_w as f32,
-                height: CROSS_CORRIDOR_WIDTH as f32,
-                capacity: 0,
-                ceiling_height: deck_heights::MIN_DECK_HEIGHT,
-                deck_span: 1,
-                cells: Vec::new(),
-            });
-            ctx.db.corridor().insert(Corridor {
-                id: 0,
-                deck,
-                corridor_type: corridor_types::BRANCH,
-                x: cc_x0 as f32,
-                y: cy as f32,
-                width: cc_w as f32,
-                length: CROSS_CORRIDOR_WIDTH as f32,
-                orientation: 0,
-                carries: carries_flags::CREW_PATH,
-            });
-            cross_rooms.push((cc_id, cy));
-        }
-
-        // ---- Phase 2.5: Spur corridors into wide segments ----
-        // When segments are too wide for rooms to reach from spine/ring,
-        // add perpendicular spur corridors from the spine into each segment.
-        let mut spur_rooms: Vec<(u32, usize, usize, usize, usize)> = Vec::new(); // (id, x, y, w, h)
-        {
-            // Build Y boundaries for segments (same logic as find_segments)
-            let mut y_bounds: Vec<usize> = vec![inner_y0];
-            for &cy in cross_ys.iter() {
-                if cy >= inner_y0 && cy + CROSS_CORRIDOR_WIDTH <= inner_y1 {
-                    y_bounds.push(cy);
-                    y_bounds.push(cy + CROSS_CORRIDOR_WIDTH);
-                }
-            }
-            y_bounds.push(inner_y1);
-
-            for chunk in y_bounds.chunks(2) {
-                if chunk.len() < 2 || chunk[0] >= chunk[1] {
-                    continue;
-                }
-                let seg_y0 = chunk[0];
-                let seg_y1 = chunk[1];
-                let seg_h = seg_y1 - seg_y0;
-                if seg_h < SPUR_WIDTH + 2 * MIN_ROOM_DIM {
-                    continue; // too short for a spur + rooms on both sides
-                }
-
-                // Spur Y position: centered in segment
-                let spur_y = seg_y0 + (seg_h - SPUR_WIDTH) / 2;
-
-                // Port spur: from spine_left toward ring-west
-                let port_w = spine_left.saturating_sub(inner_x0);
-                // J4: Only add spur if segment is wide enough that interior rooms
-                // can't reach both spine and ring corridor without a spur.
-                // Rooms need at least MIN_ROOM_DIM depth on each side of the spur,
-                // so skip if segment width <= 2*MIN_ROOM_DIM (rooms already touch corridors).
-                if port_w > SPUR_THRESHOLD && seg_h > 2 * SPUR_WIDTH + 2 * MIN_ROOM_DIM {
-                    let spur_x = inner_x0;
-                    let spur_len = port_w;
-                    // Stamp grid
-                    for x in spur_x..spur_x + spur_len {
-                        for y in spur_y..spur_y + SPUR_WIDTH {
-                            if x < hw && y < hl && grid[x][y] == CELL_EMPTY {
-                                grid[x][y] = CELL_MAIN_CORRIDOR;
-                            }
-                        }
-                    }
-                    let spur_id = next_id();
-                    ctx.db.room().insert(Room {
-                        id: spur_id,
-                        node_id: 0,
-                        name: format!("Spur Port D{} Y{}", deck + 1, spur_y),
-                        room_type: room_types::CORRIDOR,
-                        deck,
-                        x: spur_x as f32 + spur_len as f32 / 2.0,
-                        y: spur_y as f32 + SPUR_WIDTH as f32 / 2.0,
-                        width: spur_len as f32,
-                        height: SPUR_WIDTH as f32,
-                        capacity: 0,
-                        ceiling_height: deck_heights::MIN_DECK_HEIGHT,
-                        deck_span: 1,
-                        cells: Vec::new(),
-                    });
-                    spur_rooms.push((spur_id, spur_x, spur_y, spur_len, SPUR_WIDTH));
-                }
-
-                // Starboard spur: from spine_right toward ring-east
-                let starb_w = inner_x1.saturating_sub(spine_right);
-                // J4: Same spur check as port side
-                if starb_w > SPUR_THRESHOLD && seg_h > 2 * SPUR_WIDTH + 2 * MIN_ROOM_DIM {
-                    let spur_x = spine_right;
-                    let spur_len = starb_w;
-                    for x in spur_x..spur_x + spur_len {
-                        for y in spur_y..spur_y + SPUR_WIDTH {
-                            if x < hw && y < hl && grid[x][y] == CELL_EMPTY {
-                                grid[x][y] = CELL_MAIN_CORRIDOR;
-                            }
-                        }
-                    }
-                    let spur_id = next_id();
-                    ctx.db.room().insert(Room {
-                        id: spur_id,
-                        node_id: 0,
-                        name: format!("Spur Starb D{} Y{}", deck + 1, spur_y),
-                        room_type: room_types::CORRIDOR,
-                        deck,
-                        x: spur_x as f32 + spur_len as f32 / 2.0,
-                        y: spur_y as f32 + SPUR_WIDTH as f32 / 2.0,
-                        width: spur_len as f32,
-                        height: SPUR_WIDTH as f32,
-                        capacity: 0,
-                        ceiling_height: deck_heights::MIN_DECK_HEIGHT,
-                        deck_span: 1,
-                        cells: Vec::new(),
-                    });
-                    spur_rooms.push((spur_id, spur_x, spur_y, spur_len, SPUR_WIDTH));
-                }
-            }
-
-            // Create doors: spur ↔ spine, spur ↔ ring
-            for &(spur_id, sx, sy, sw, sh) in &spur_rooms {
-                // Spur ↔ spine
-                for &(seg_id, seg_y0, seg_y1) in &spine_segments {
-                    if let Some((dx, dy, wa, wb, _ol)) = find_shared_edge(
-                        sx,
-                        sy,
-                        sw,
-                        sh,
-                        spine_left,
-                        seg_y0,
-                        SPINE_WIDTH,
-                        seg_y1 - seg_y0,
-                    ) {
-                        ctx.db.door().insert(Door {
-                            id: 0,
-                            room_a: spur_id,
-                            room_b: seg_id,
-                            wall_a: wa,
-                            wall_b: wb,
-                            position_along_wall: 0.5,
-                            width: SPUR_WIDTH as f32,
-                            access_level: access_levels::PUBLIC,
-                            door_x: dx,
-                            door_y: dy,
-                            is_open: true,
-                            is_locked: false,
-                        });
-                        break;
-                    }
-                }
-                // Spur ↔ ring west
-                if let Some((dx, dy, wa, wb, _ol)) = find_shared_edge(
-                    sx,
-                    sy,
-                    sw,
-                    sh,
-                    ring_x0,
-                    ring_y0,
-                    RING_WIDTH,
-                    ring_y1 - ring_y0,
-                ) {
-                    ctx.db.door().insert(Door {
-                        id: 0,
-                        room_a: spur_id,
-                        room_b: ring_w_id,
-                        wall_a: wa,
-                        wall_b: wb,
-                        position_along_wall: 0.5,
-                        width: SPUR_WIDTH as f32,
-                        access_level: access_levels::PUBLIC,
-                        door_x: dx,
-                        door_y: dy,
-                        is_open: true,
-                        is_locked: false,
-                    });
-                }
-                // Spur ↔ ring east
-                if let Some((dx, dy, wa, wb, _ol)) = find_shared_edge(
-                    sx,
-                    sy,
-                    sw,
-                    sh,
-                    ring_x1 - RING_WIDTH,
-                    ring_y0,
-                    RING_WIDTH,
-                    ring_y1 - ring_y0,
-                ) {
-                    ctx.db.door().insert(Door {
-                        id: 0,
-                        room_a: spur_id,
-                        room_b: ring_e_id,
-                        wall_a: wa,
-                        wall_b: wb,
-                        position_along_wall: 0.5,
-                        width: SPUR_WIDTH as f32,
-                        access_level: access_levels::PUBLIC,
-                        door_x: dx,
-                        door_y: dy,
-                        is_open: true,
-                        is_locked: false,
-                    });
-                }
-            }
-        }
-
-        // ---- Corridor-to-corridor doors ----
-
-        // Spine ↔ cross-corridors
-        for &(cc_id, cy) in &cross_rooms {
-            for &(seg_id, seg_y0, seg_y1) in &spine_segments {
-                if seg_y1 == cy {
-                    let dx = spine_left as f32 + SPINE_WIDTH as f32 / 2.0;
-                    ctx.db.door().insert(Door {
-                        id: 0,
-                        room_a: seg_id,
-                        room_b: cc_id,
-                        wall_a: wall_sides::SOUTH,
-                        wall_b: wall_sides::NORTH,
-                        position_along_wall: 0.5,
-                        width: SPINE_WIDTH as f32,
-                        access_level: access_levels::PUBLIC,
-                        door_x: dx,
-                        door_y: cy as f32,
-                        is_open: true,
-                        is_locked: false,
-                    });
-                }
-                if seg_y0 == cy + CROSS_CORRIDOR_WIDTH {
-                    let dx = spine_left as f32 + SPINE_WIDTH as f32 / 2.0;
-                    ctx.db.door().insert(Door {
-                        id: 0,
-                        room_a: cc_id,
-                        room_b: seg_id,
-                        wall_a: wall_sides::SOUTH,
-                        wall_b: wall_sides::NORTH,
-                        position_along_wall: 0.5,
-                        width: SPINE_WIDTH as f32,
-                        access_level: access_levels::PUBLIC,
-                        door_x: dx,
-                        door_y: (cy + CROSS_CORRIDOR_WIDTH) as f32,
-                        is_open: true,
-                        is_locked: false,
-                    });
-                }
-            }
-        }
-
-        // Consecutive spine segments
-        for i in 0..spine_segments.len().saturating_sub(1) {
-            let (seg_a, _, seg_a_end) = spine_segments[i];
-            let (seg_b, seg_b_start, _) = spine_segments[i + 1];
-            if seg_a_end == seg_b_start {
-                ctx.db.door().insert(Door {
-                    id: 0,
-                    room_a: seg_a,
-                    room_b: seg_b,
-                    wall_a: wall_sides::SOUTH,
-                    wall_b: wall_sides::NORTH,
-                    position_along_wall: 0.5,
-                    width: SPINE_WIDTH as f32,
-                    access_level: access_levels::PUBLIC,
-                    door_x: spine_left as f32 + SPINE_WIDTH as f32 / 2.0,
-                    door_y: seg_a_end as f32,
-                    is_open: true,
-                    is_locked: false,
-                });
-            }
-        }
-
-        // Spine ↔ ring (north and south ends)
-        if let Some(&(first_seg, _, _)) = spine_segments.first() {
-            ctx.db.door().insert(Door {
-                id: 0,
-                room_a: ring_n_id,
-                room_b: first_seg,
-                wall_a: wall_sides::SOUTH,
-                wall_b: wall_sides::NORTH,
-                position_along_wall: 0.5,
-                width: SPINE_WIDTH as f32,
-                access_level: access_levels::PUBLIC,
-                door_x: spine_left as f32 + SPINE_WIDTH as f32 / 2.0,
-                door_y: inner_y0 as f32,
-                is_open: true,
-                is_locked: false,
-            });
-        }
-        if let Some(&(last_seg, _, _)) = spine_segments.last() {
-            ctx.db.door().insert(Door {
-                id: 0,
-                room_a: last_seg,
-                room_b: ring_s_id,
-                wall_a: wall_sides::SOUTH,
-                wall_b: wall_sides::NORTH,
-                position_along_wall: 0.5,
-                width: SPINE_WIDTH as f32,
-                access_level: access_levels::PUBLIC,
-                door_x: spine_left as f32 + SPINE_WIDTH as f32 / 2.0,
-                door_y: inner_y1 as f32,
-                is_open: true,
-                is_locked: false,
-            });
-        }
-
-        // Cross-corridors ↔ ring (west and east ends)
-        for &(cc_id, cy) in &cross_rooms {
-            let cc_mid_y = cy as f32 + CROSS_CORRIDOR_WIDTH as f32 / 2.0;
-            // West end
-            ctx.db.door().insert(Door {
-                id: 0,
-                room_a: ring_w_id,
-                room_b: cc_id,
-                wall_a: wall_sides::EAST,
-                wall_b: wall_sides::WEST,
-                position_along_wall: 0.5,
-                width: CROSS_CORRIDOR_WIDTH as f32,
-                access_level: access_levels::PUBLIC,
-                door_x: inner_x0 as f32,
-                door_y: cc_mid_y,
-                is_open: true,
-                is_locked: false,
-            });
-            // East end
-            ctx.db.door().insert(Door {
-                id: 0,
-                room_a: cc_id,
-                room_b: ring_e_id,
-                wall_a: wall_sides::EAST,
-                wall_b: wall_sides::WEST,
-                position_along_wall: 0.5,
-                width: CROSS_CORRIDOR_WIDTH as f32,
-                access_level: access_levels::PUBLIC,
-                door_x: inner_x1 as f32,
-                door_y: cc_mid_y,
-                is_open: true,
-                is_locked: false,
-            });
-        }
-
-        // ---- Phase 3: Stamp shafts ----
-        for sp in &global_shaft_placements {
-            if sp.x >= x_margin
-                && sp.x + sp.w <= hw - x_margin
-                && sp.y >= y_margin
-                && sp.y + sp.h <= hl - y_margin
-            {
-                for sx in sp.x..((sp.x + sp.w).min(hw)) {
-                    for sy in sp.y..((sp.y + sp.h).min(hl)) {
-                        grid[sx][sy] = CELL_SHAFT;
-                    }
-                }
-            }
-        }
-
-        // Shaft Room entries + doors to corridors
-        for (global_idx, sp) in global_shaft_placements.iter().enumerate() {
-            if sp.x < x_margin
-                || sp.x + sp.w > hw - x_margin
-                || sp.y < y_margin
-                || sp.y + sp.h > hl - y_margin
-            {
-                continue;
-            }
-            let shaft_room_id = next_id();
-            let srt = if sp.shaft_type == shaft_types::ELEVATOR {
-                room_types::ELEVATOR_SHAFT
-            } else if sp.shaft_type == shaft_types::SERVICE_ELEVATOR {
-                room_types::SERVICE_ELEVATOR_SHAFT
-            } else {
-                room_types::LADDER_SHAFT
-            };
-            ctx.db.room().insert(Room {
-                id: shaft_room_id,
-                node_id: 0,
-                name: format!("{} D{}", sp.name, deck + 1),
-                room_type: srt,
-                deck,
-                x: sp.x as f32 + sp.w as f32 / 2.0,
-                y: sp.y as f32 + sp.h as f32 / 2.0,
-                width: sp.w as f32,
-                height: sp.h as f32,
-                capacity: 0,
-                ceiling_height: deck_heights::room_ceiling_height(srt),
-                deck_span: deck_heights::room_deck_span(srt),
-                cells: Vec::new(),
-            });
-
-            if global_idx < shaft_infos.len() {
-                shaft_infos[global_idx].deck_room_ids[deck as usize] = Some(shaft_room_id);
-                shaft_infos[global_idx].ref_x = sp.x as f32 + sp.w as f32 / 2.0;
-                shaft_infos[global_idx].ref_y = sp.y as f32 + sp.h as f32 / 2.0;
-                shaft_infos[global_idx].ref_w = sp.w as f32;
-                shaft_infos[global_idx].ref_h = sp.h as f32;
-            }
-
-            let access = if sp.is_main {
-                access_levels::PUBLIC
-            } else {
-                access_levels::CREW_ONLY
-            };
-            connect_shaft_to_corridor(
-                ctx,
-                shaft_room_id,
-                sp,
-                &spine_segments,
-                &cross_rooms,
-                &spur_rooms,
-                [ring_n_id, ring_s_id, ring_w_id, ring_e_id],
-                [ring_n_grid, ring_s_grid, ring_w_grid, ring_e_grid],
-                spine_left,
-                inner_x0,
-                inner_x1,
-                access,
-            );
-        }
-
-        // ---- Phase 3.5: Pre-place oversized rooms ----
-        // Rooms >200m² get placed directly into the grid as large rectangles before
-        // segment decomposition. This lets them claim the space they need instead
-        // of being squeezed into narrow strips.
-        // (room_id, x, y, w, h, room_type, target_area, placement)
-        let mut placed_rooms: Vec<(u32, usize, usize, usize, usize, u8, f32, u8)> = Vec::new();
-        let mut pre_placed_node_ids: Vec<u64> = Vec::new();
-
-        // Estimate area budget from inner dimensions (before segments are known)
-        let inner_w = inner_x1 - inner_x0;
-        let inner_h = inner_y1 - inner_y0;
-        let gross_inner_area = (inner_w * inner_h) as f32;
-        let est_area_budget = gross_inner_area * 0.80; // corridors+shafts take ~20%
-
-        // Collect this deck's zone rooms temporarily (peek without advancing cursors)
-        let primary_zone = deck_zone_map[deck as usize] as usize;
-        let zone_decks_total = zone_deck_counts[primary_zone];
-        let zone_deck_num = zone_deck_seen[primary_zone];
-        zone_deck_seen[primary_zone] += 1;
-        let remaining_zone_decks = zone_decks_total - zone_deck_num;
-        let cursor_start = zone_cursors[primary_zone];
-        let remaining_rooms = zone_requests[primary_zone].len() - cursor_start;
-        let fair_share = if remaining_zone_decks > 0 {
-            remaining_rooms.div_ceil(remaining_zone_decks as usize)
-        } else {
-            remaining_rooms
-        };
-
-        // Collect deck requests (all sizes) via same logic as old Phase 5
-        let mut deck_requests: Vec<RoomRequest> = Vec::new();
-        let mut filled_area = 0.0f32;
-        {
-            let cursor = &mut zone_cursors[primary_zone];
-            let mut taken = 0usize;
-            while *cursor < zone_requests[primary_zone].len()
-                && filled_area < est_area_budget
-                && taken < fair_share
-            {
-                let req = zone_requests[primary_zone][*cursor].clone();
-                filled_area += req.target_area;
-                deck_requests.push(req);
-                *cursor += 1;
-                taken += 1;
-            }
-        }
-        // Overflow from other zones if underfilled
-        if filled_area < est_area_budget * 0.7 {
-            let overflow_order = [1u8, 2, 3, 4, 5, 0, 6];
-            for &oz in &overflow_order {
-                if oz as usize == primary_zone {
-                    continue;
-                }
-                let oc = &mut zone_cursors[oz as usize];
-                while *oc < zone_requests[oz as usize].len() && filled_area < est_area_budget {
-                    let req = zone_requests[oz as usize][*oc].clone();
-                    filled_area += req.target_area;
-                    deck_requests.push(req);
-                    *oc += 1;
-                }
-                if filled_area >= est_area_budget {
-                    break;
-                }
-            }
-        }
-        // Sort largest first
-        deck_requests.sort_by(|a, b| {
-            b.target_area
-                .partial_cmp(&a.target_area)
-                .unwrap_or(core::cmp::Ordering::Equal)
-        });
-
-        // Minimum area threshold for pre-placement (smaller rooms go through BSP)
-        const LARGE_ROOM_THRESHOLD: f32 = 200.0;
-
-        // Separate oversized rooms AND rooms with special placement constraints
-        // Hull-facing rooms are excluded here — they go to the hull band phase.
-        let (hull_facing_requests, non_hull_requests): (Vec<RoomRequest>, Vec<RoomRequest>) =
-            deck_requests
-                .into_iter()
-                .partition(|r| r.placement == placement::HULL_FACING);
-        let (large_requests, normal_requests): (Vec<RoomRequest>, Vec<RoomRequest>) =
-            non_hull_requests.into_iter().partition(|r| {
-                r.target_area >= LARGE_ROOM_THRESHOLD
-                    || r.placement == placement::AFT
-                    || r.placement == placement::FORWARD
-                    || r.placement == placement::INTERIOR
-            });
-
-        // Place each large room directly into the grid
-        let mid_y = (inner_y0 + inner_y1) / 2;
-        let _mid_x = (inner_x0 + inner_x1) / 2;
-
-        for req in &large_requests {
-            let target_side = (req.target_area.sqrt()) as usize;
-            let want_w = target_side.max(MIN_ROOM_DIM);
-            let want_h = ((req.target_area / want_w as f32).ceil() as usize).max(MIN_ROOM_DIM);
-            log::info!(
-                "LARGE ROOM: {} (type={}) area={} want={}x{} placement={}",
-                req.name,
-                req.room_type,
-                req.target_area,
-                want_w,
-                want_h,
-                req.placement
-            );
-
-            // Determine preferred region based on placement constraint
-            let search_regions = match req.placement {
-                p if p == placement::AFT => {
-                    vec![(inner_x0, mid_y, inner_w, inner_y1 - mid_y)]
-                }
-                p if p == placement::FORWARD => {
-                    vec![(inner_x0, inner_y0, inner_w, mid_y - inner_y0)]
-                }
-                p if p == placement::INTERIOR => {
-                    let margin = RING_WIDTH * 2;
-                    let cx0 = inner_x0 + margin;
-                    let cy0 = inner_y0 + margin;
-                    let cw = inner_w.saturating_sub(margin * 2);
-                    let ch = inner_h.saturating_sub(margin * 2);
-                    vec![(cx0, cy0, cw, ch)]
-                }
-                _ => {
-                    vec![(inner_x0, inner_y0, inner_w, inner_h)]
-                }
-            };
-
-            // Try to find a clear rectangle in each search region
-            let mut best_pos: Option<(usize, usize, usize, usize)> = None;
-
-            for (rx, ry, rw, rh) in &search_regions {
-                if let Some(pos) =
-                    find_clear_rect_for_room(&grid, hw, hl, *rx, *ry, *rw, *rh, want_w, want_h)
-                {
-                    best_pos = Some(pos);
-                    break;
-                }
-            }
-
-            // Fallback: try entire inner area if constrained search failed
-            if best_pos.is_none() {
-                best_pos = find_clear_rect_for_room(
-                    &grid, hw, hl, inner_x0, inner_y0, inner_w, inner_h, want_w, want_h,
-                );
-            }
-
-            if let Some((px, py, pw, ph)) = best_pos {
-                let room_id = next_id();
-
-                // Stamp grid
-                for gx in px..(px + pw).min(hw) {
-                    for gy in py..(py + ph).min(hl) {
-                        if grid[gx][gy] == CELL_EMPTY {
-                            grid[gx][gy] = CELL_ROOM_BASE + (room_id as u8 % 246);
-                        }
-                    }
-                }
-
-                ctx.db.room().insert(Room {
-                    id: room_id,
-                    node_id: req.node_id,
-                    name: req.name.clone(),
-                    room_type: req.room_type,
-                    deck,
-                    x: px as f32 + pw as f32 / 2.0,
-                    y: py as f32 + ph as f32 / 2.0,
-                    width: pw as f32,
-                    height: ph as f32,
-                    capacity: req.capacity,
-                    ceiling_height: deck_heights::room_ceiling_height(req.room_type),
-                    deck_span: deck_heights::room_deck_span(req.room_type),
-                    cells: Vec::new(),
-                });
-
-                // Door to corridor
-                create_corridor_door(
-                    ctx,
-                    room_id,
-                    px,
-                    py,
-                    pw,
-                    ph,
-                    spine_left,
-                    spine_right,
-                    &spine_segments,
-                    &cross_rooms,
-                    &spur_rooms,
-                    inner_x0,
-                    inner_x1,
-                    inner_y0,
-                    inner_y1,
-                    ring_x0,
-                    ring_x1,
-                    ring_y0,
-                    ring_y1,
-                    ring_n_id,
-                    ring_s_id,
-                    ring_w_id,
-                    ring_e_id,
-                );
-
-                placed_rooms.push((
-                    room_id,
-                    px,
-                    py,
-                    pw,
-                    ph,
-                    req.room_type,
-                    req.target_area,
-                    req.placement,
-                ));
-                pre_placed_node_ids.push(req.node_id);
-
-                log::info!(
-                    "Deck {}: pre-placed {} ({}m²) at ({},{}) {}×{} = {}m²",
-                    deck + 1,
-                    req.name,
-                    req.target_area,
-                    px,
-                    py,
-                    pw,
-                    ph,
-                    pw * ph
-                );
-            } else {
-                log::warn!(
-                    "Deck {}: could not pre-place {} ({}m²)",
-                    deck + 1,
-                    req.name,
-                    req.target_area
-                );
-            }
-        }
-
-        // Remaining requests for BSP (normal-sized rooms only)
-        let deck_requests = normal_requests;
-
-        // ---- Phase 4: Identify rectangular segments between corridors ----
-        // Segments are computed AFTER oversized rooms so they naturally work around them.
-        let segments = find_segments(
-            &grid,
-            hw,
-            hl,
-            spine_left,
-            spine_right,
-            inner_x0,
-            inner_x1,
-            inner_y0,
-            inner_y1,
-            cross_ys,
-            &spine_segments,
-            &cross_rooms,
-            ring_w_id,
-            ring_e_id,
-            ring_n_id,
-            ring_s_id,
-        );
-
-        // ---- Phase 6: BSP room placement into segments ----
-        // J9: Match requests to segments by area ratio instead of sequential consumption.
-        let total_request_area: f32 = deck_requests.iter().map(|r| r.target_area).sum();
-        let total_seg_area: usize = segments.iter().map(|s| s.w * s.h).sum();
-
-        // Sort segments largest-first so big rooms get big segments
-        let mut seg_order: Vec<usize> = (0..segments.len()).collect();
-        seg_order.sort_by(|&a, &b| {
-            let area_a = segments[a].w * segments[a].h;
-            let area_b = segments[b].w * segments[b].h;
-            area_b.cmp(&area_a)
-        });
-
-        // Track which requests have been placed
-        let mut request_used: Vec<bool> = vec![false; deck_requests.len()];
-
-        for &si in &seg_order {
-            let seg = &segments[si];
-            let seg_area = (seg.w * seg.h) as f32;
-            // How many requests should this segment get (proportional to area)
-            let seg_share = if total_request_area > 0.0 {
-                (seg_area / total_seg_area.max(1) as f32 * deck_requests.len() as f32).ceil()
-                    as usize
-            } else {
-                0
-            };
-            // Collect best-fitting unused requests for this segment (sorted by area match)
-            let mut candidates: Vec<(usize, f32)> = deck_requests
-                .iter()
-                .enumerate()
-                .filter(|(i, _)| !request_used[*i])
-                .map(|(i, r)| {
-                    let ratio = (r.target_area / seg_area - 1.0 / seg_share.max(1) as f32).abs();
-                    (i, ratio)
-                })
-                .collect();
-            candidates.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(core::cmp::Ordering::Equal));
-            let selected: Vec<usize> = candidates
-                .iter()
-                .take(seg_share.max(1))
-                .map(|(i, _)| *i)
-                .collect();
-            if selected.is_empty() {
-                continue;
-            }
-
-            // Build requests slice for BSP (sorted largest-first within selection)
-            let mut seg_requests: Vec<&RoomRequest> =
-                selected.iter().map(|&i| &deck_requests[i]).collect();
-            seg_requests.sort_by(|a, b| {
-                b.target_area
-                    .partial_cmp(&a.target_area)
-                    .unwrap_or(core::cmp::Ordering::Equal)
-            });
-
-            let mut sub_rects: Vec<(usize, usize, usize, usize)> = Vec::new();
-            // Build temporary owned requests for BSP
-            let seg_reqs_owned: Vec<RoomRequest> =
-                seg_requests.iter().map(|r| (*r).clone()).collect();
-            bsp_subdivide(seg.x, seg.y, seg.w, seg.h, &seg_reqs_owned, &mut sub_rects);
-
-            let mut seg_req_idx = 0usize;
-            for (rx, ry, rw, rh) in &sub_rects {
-                if seg_req_idx >= selected.len() {
-                    break;
-                }
-                // Check for conflicts
-                let mut has_conflict = false;
-                for gx in *rx..(*rx + *rw).min(hw) {
-                    for gy in *ry..(*ry + *rh).min(hl) {
-                        if grid[gx][gy] != CELL_EMPTY {
-                            has_conflict = true;
-                            break;
-                        }
-                    }
-                    if has_conflict {
-                        break;
-                    }
-                }
-                if has_conflict {
-                    continue;
-                }
-
-                // Only place if room touches a corridor
-                if !touches_any_corridor(
-                    *rx,
-                    *ry,
-                    *rw,
-                    *rh,
-                    spine_left,
-                    &spine_segments,
-                    &cross_rooms,
-                    &spur_rooms,
-                    inner_x0,
-                    inner_x1,
-                    inner_y1,
-                    ring_x0,
-                    ring_x1,
-                    ring_y0,
-                    ring_y1,
-                ) {
-                    continue;
-                }
-
-                let orig_idx = selected[seg_req_idx];
-                let req = &deck_requests[orig_idx];
-                let room_id = next_id();
-
-                for gx in *rx..(*rx + *rw).min(hw) {
-                    for gy in *ry..(*ry + *rh).min(hl) {
-                        if grid[gx][gy] == CELL_EMPTY {
-                            grid[gx][gy] = CELL_ROOM_BASE + (room_id as u8 % 246);
-                        }
-                    }
-                }
-
-                ctx.db.room().insert(Room {
-                    id: room_id,
-                    node_id: req.node_id,
-                    name: req.name.clone(),
-                    room_type: req.room_type,
-                    deck,
-                    x: *rx as f32 + *rw as f32 / 2.0,
-                    y: *ry as f32 + *rh as f32 / 2.0,
-                    width: *rw as f32,
-                    height: *rh as f32,
-                    capacity: req.capacity,
-                    ceiling_height: deck_heights::room_ceiling_height(req.room_type),
-                    deck_span: deck_heights::room_deck_span(req.room_type),
-                    cells: Vec::new(),
-                });
-
-                create_corridor_door(
-                    ctx,
-                    room_id,
-                    *rx,
-                    *ry,
-                    *rw,
-                    *rh,
-                    spine_left,
-                    spine_right,
-                    &spine_segments,
-                    &cross_rooms,
-                    &spur_rooms,
-                    inner_x0,
-                    inner_x1,
-                    inner_y0,
-                    inner_y1,
-                    ring_x0,
-                    ring_x1,
-                    ring_y0,
-                    ring_y1,
-                    ring_n_id,
-                    ring_s_id,
-                    ring_w_id,
-                    ring_e_id,
-                );
-
-                placed_rooms.push((
-                    room_id,
-                    *rx,
-                    *ry,
-                    *rw,
-                    *rh,
-                    req.room_type,
-                    req.target_area,
-                    req.placement,
-                ));
-                request_used[orig_idx] = true;
-                seg_req_idx += 1;
-            }
-        }
-
-        // ---- Phase 6.5: Expand BSP-placed rooms into empty space ----
-        expand_rooms_into_empty(
-            ctx,
-            &mut grid,
-            &mut placed_rooms,
-            inner_x0,
-            inner_y0,
-            inner_x1,
-            inner_y1,
-            ring_x0,
-            hull_y0,
-            ring_x1,
-            hull_y1,
-            deck,
-            "post-BSP",
-        );
-
-        // ---- Phase 7: Wavefront BFS gap fill ----
-        // Grow corridor into remaining empty cells, place rooms from remaining requests
-        {
-            // J9: Collect remaining unplaced requests
-            let remaining_requests: Vec<RoomRequest> = deck_requests
-                .iter()
-                .enumerate()
-                .filter(|(i, _)| !request_used[*i])
-                .map(|(_, r)| r.clone())
-                .collect();
-            let mut req_cursor = 0usize;
-
-            // BFS frontier: all corridor cells adjacent to empty cells
-            let mut frontier: Vec<(usize, usize)> = Vec::new();
-            for x in inner_x0..inner_x1 {
-                for y in inner_y0..inner_y1 {
-                    if grid[x][y] == CELL_MAIN_CORRIDOR {
-                        // Check if any neighbor is empty
-                        for &(dx, dy) in &[(0isize, 1isize), (0, -1), (1, 0), (-1, 0)] {
-                            let nx = (x as isize + dx) as usize;
-                            let ny = (y as isize + dy) as usize;
-                            if nx < hw && ny < hl && grid[nx][ny] == CELL_EMPTY {
-                                frontier.push((x, y));
-                                break;
-                            }
-                        }
-                    }
-                }
-            }
-
-            // Expand from each frontier position: find largest empty rect touching corridor
-            let mut wave_placed = 0u32;
-            let mut visited: Vec<Vec<bool>> = vec![vec![false; hl]; hw];
-
-            for &(fx, fy) in &frontier {
-                if req_cursor >= remaining_requests.len() {
-                    break;
-                }
-                // For each direction from this corridor cell, try to find an empty rectangle
-                for &(dx, dy) in &[(0isize, 1isize), (0, -1), (1, 0), (-1, 0)] {
-                    let start_x = (fx as isize + dx) as usize;
-                    let start_y = (fy as isize + dy) as usize;
-                    if start_x >= hw
-                        || start_y >= hl
-                        || grid[start_x][start_y] != CELL_EMPTY
-                        || visited[start_x][start_y]
-                    {
-                        continue;
-                    }
-
-                    // Expand to largest empty rectangle from this cell
-                    let mut max_w = 0usize;
-                    for ddx in 0..(inner_x1 - start_x) {
-                        if start_x + ddx >= hw || grid[start_x + ddx][start_y] != CELL_EMPTY {
-                            break;
-                        }
-                        max_w = ddx + 1;
-                    }
-                    let mut max_h = inner_y1 - start_y;
-                    for ddy in 0..max_h {
-                        if start_y + ddy >= hl {
-                            max_h = ddy;
-                            break;
-                        }
-                        let row_clear = (0..max_w).all(|ddx| {
-                            start_x + ddx < hw && grid[start_x + ddx][start_y + ddy] == CELL_EMPTY
-                        });
-                        if !row_clear {
-                            max_h = ddy;
-                            break;
-                        }
-                    }
-
-                    if max_w >= MIN_ROOM_DIM
-                        && max_h >= MIN_ROOM_DIM
-                        && req_cursor < remaining_requests.len()
-                    {
-                        let req = &remaining_requests[req_cursor];
-                        // J8: Use full available rectangle — room gets whatever size fits.
-                        let rw = max_w;
-                        let rh = max_h;
-
-                        if rw >= MIN_ROOM_DIM
-                            && rh >= MIN_ROOM_DIM
-                            && touches_any_corridor(
-                                start_x,
-                                start_y,
-                                rw,
-                                rh,
-                                spine_left,
-                                &spine_segments,
-                                &cross_rooms,
-                                &spur_rooms,
-                                inner_x0,
-                                inner_x1,
-                                inner_y1,
-                                ring_x0,
-                                ring_x1,
-                                ring_y0,
-                                ring_y1,
-                            )
-                        {
-                            let room_id = next_id();
-                            for gx in start_x..(start_x + rw).min(hw) {
-                                for gy in start_y..(start_y + rh).min(hl) {
-                                    grid[gx][gy] = CELL_ROOM_BASE + (room_id as u8 % 246);
-                                    visited[gx][gy] = true;
-                                }
-                            }
-                            ctx.db.room().insert(Room {
-                                id: room_id,
-                                node_id: req.node_id,
-                                name: req.name.clone(),
-                                room_type: req.room_type,
-                                deck,
-                                x: start_x as f32 + rw as f32 / 2.0,
-                                y: start_y as f32 + rh as f32 / 2.0,
-                                width: rw as f32,
-                                height: rh as f32,
-                                capacity: req.capacity,
-                                ceiling_height: deck_heights::room_ceiling_height(req.room_type),
-                                deck_span: deck_heights::room_deck_span(req.room_type),
-                                cells: Vec::new(),
-                            });
-
-                            create_corridor_door(
-                                ctx,
-                                room_id,
-                                start_x,
-                                start_y,
-                                rw,
-                                rh,
-                                spine_left,
-                                spine_right,
-                                &spine_segments,
-                                &cross_rooms,
-                                &spur_rooms,
-                                inner_x0,
-                                inner_x1,
-                                inner_y0,
-                                inner_y1,
-                                ring_x0,
-                                ring_x1,
-                                ring_y0,
-                                ring_y1,
-                                ring_n_id,
-                                ring_s_id,
-                                ring_w_id,
-                                ring_e_id,
-                            );
-
-                            placed_rooms.push((
-                                room_id,
-                                start_x,
-                                start_y,
-                                rw,
-                                rh,
-                                req.room_type,
-                                req.target_area,
-                                req.placement,
-                            ));
-                            req_cursor += 1;
-                            wave_placed += 1;
-                        }
-                    }
-                }
-            }
-            if wave_placed > 0 {
-                log::info!("Deck {}: wavefront placed {} rooms", deck + 1, wave_placed);
-            }
-        }
-
-        // ---- Phase 7.5: Expand wavefront-placed rooms into empty space ----
-        expand_rooms_into_empty(
-            ctx,
-            &mut grid,
-            &mut placed_rooms,
-            inner_x0,
-            inner_y0,
-            inner_x1,
-            inner_y1,
-            ring_x0,
-            hull_y0,
-            ring_x1,
-            hull_y1,
-            deck,
-            "post-wavefront",
-        );
-
-        // ---- Phase 8: Filler backfill ----
-        {
-            let mut filler_idx = 0usize;
-            let mut filler_count = 0u32;
-
-            let mut y = inner_y0;
-            while y < inner_y1 {
-                let mut x = inner_x0;
-                while x < inner_x1 {
-                    if grid[x][y] != CELL_EMPTY {
-                        x += 1;
-                        continue;
-                    }
-                    // Expand to largest empty rectangle
-                    let mut max_w = 0;
-                    for dx in 0..(inner_x1 - x) {
-                        if grid[x + dx][y] != CELL_EMPTY {
-                            break;
-                        }
-                        max_w = dx + 1;
-                    }
-                    let mut max_h = inner_y1 - y;
-                    for dy in 0..max_h {
-                        let row_clear = (0..max_w).all(|dx| grid[x + dx][y + dy] == CELL_EMPTY);
-                        if !row_clear {
-                            max_h = dy;
-                            break;
-                        }
-                    }
-
-                    if max_w >= MIN_ROOM_DIM && max_h >= MIN_ROOM_DIM {
-                        let (frt, fname, ftarget, fcap) =
-                            FILLER_POOL[filler_idx % FILLER_POOL.len()];
-                        filler_idx += 1;
-                        // J1: Fillers use the full available rectangle — no target_area clipping.
-                        let rw = max_w;
-                        let rh = max_h;
-
-                        if rw >= MIN_ROOM_DIM
-                            && rh >= MIN_ROOM_DIM
-                            && touches_any_corridor(
-                                x,
-                                y,
-                                rw,
-                                rh,
-                                spine_left,
-                                &spine_segments,
-                                &cross_rooms,
-                                &spur_rooms,
-                                inner_x0,
-                                inner_x1,
-                                inner_y1,
-                                ring_x0,
-                                ring_x1,
-                                ring_y0,
-                                ring_y1,
-                            )
-                        {
-                            filler_count += 1;
-                            let room_id = next_id();
-                            for gx in x..(x + rw) {
-                                for gy in y..(y + rh) {
-                                    grid[gx][gy] = CELL_ROOM_BASE + (room_id as u8 % 246);
-                                }
-                            }
-                            ctx.db.room().insert(Room {
-                                id: room_id,
-                                node_id: 0,
-                                name: format!("{} {}", fname, filler_count),
-                                room_type: frt,
-                                deck,
-                                x: x as f32 + rw as f32 / 2.0,
-                                y: y as f32 + rh as f32 / 2.0,
-                                width: rw as f32,
-                                height: rh as f32,
-                                capacity: fcap,
-                                ceiling_height: deck_heights::room_ceiling_height(frt),
-                                deck_span: deck_heights::room_deck_span(frt),
-                                cells: Vec::new(),
-                            });
-                            placed_rooms.push((
-                                room_id,
-                                x,
-                                y,
-                                rw,
-                                rh,
-                                frt,
-                                ftarget,
-                                placement::NONE,
-                            ));
-
-                            create_corridor_door(
-                                ctx,
-                                room_id,
-                                x,
-                                y,
-                                rw,
-                                rh,
-                                spine_left,
-                                spine_right,
-                                &spine_segments,
-                                &cross_rooms,
-                                &spur_rooms,
-                                inner_x0,
-                                inner_x1,
-                                inner_y0,
-                                inner_y1,
-                                ring_x0,
-                                ring_x1,
-                                ring_y0,
-                                ring_y1,
-                                ring_n_id,
-                                ring_s_id,
-                                ring_w_id,
-                                ring_e_id,
-                            );
-                        }
-                    }
-                    x += max_w.max(1);
-                }
-                y += 1;
-            }
-            if filler_count > 0 {
-                log::info!("Deck {}: placed {} filler rooms", deck + 1, filler_count);
-            }
-
-            // ---- Phase 8b: Unified hull band placement (N/S strips) ----
-            // Places hull-facing specialty rooms first, then fills gaps with fillers.
-            // All rooms are a single row directly touching the ring outer wall.
-            let hull_band_regions: [(usize, usize, usize, usize, u32); 2] = [
-                // North band: hull_y0..ring_y0, door to ring_n
-                (ring_x0, hull_y0, ring_x1, ring_y0, ring_n_id),
-                // South band: ring_y1..hull_y1, door to ring_s
-                (ring_x0, ring_y1, ring_x1, hull_y1, ring_s_id),
-            ];
-            let mut hull_band_count = 0u32;
-            for (bx0, by0, bx1, by1, ring_id) in &hull_band_regions {
-                let (bx0, by0, bx1, by1) = (*bx0, *by0, *bx1, *by1);
-                if bx1 <= bx0 || by1 <= by0 {
-                    continue;
-                }
-                let band_h = by1 - by0;
-                if band_h < MIN_ROOM_DIM {
-                    continue;
-                }
-
-                // Place hull-facing specialty rooms first along this band
-                for req in &hull_facing_requests {
-                    let want_w = (req.target_area / band_h as f32).ceil() as usize;
-                    let want_w = want_w.max(MIN_ROOM_DIM).min(bx1 - bx0);
-                    // Scan ring edge for a clear span of want_w
-                    let ring_edge_y = if *ring_id == ring_n_id {
-                        by1.saturating_sub(1)
-                    } else {
-                        by0
-                    };
-                    let mut placed = false;
-                    let mut sx = bx0;
-                    while sx + want_w <= bx1 && !placed {
-                        // Check ring edge is clear
-                        let edge_clear =
-                            (0..want_w).all(|dx| grid[sx + dx][ring_edge_y] == CELL_EMPTY);
-                        if !edge_clear {
-                            sx += 1;
-                            continue;
-                        }
-                        // Check full rectangle is clear
-                        let ry = if *ring_id == ring_n_id {
-                            by1.saturating_sub(band_h)
-                        } else {
-                            by0
-                        };
-                        let rect_clear = (0..want_w)
-                            .all(|dx| (0..band_h).all(|dy| grid[sx + dx][ry + dy] == CELL_EMPTY));
-                        if !rect_clear {
-                            sx += 1;
-                            continue;
-                        }
-                        // Place the room
-                        let room_id = next_id();
-                        hull_band_count += 1;
-                        for gx in sx..sx + want_w {
-                            for gy in ry..ry + band_h {
-                                grid[gx][gy] = CELL_ROOM_BASE + (room_id as u8 % 246);
-                            }
-                        }
-                        ctx.db.room().insert(Room {
-                            id: room_id,
-                            node_id: req.node_id,
-                            name: req.name.clone(),
-                            room_type: req.room_type,
-                            deck,
-                            x: sx as f32 + want_w as f32 / 2.0,
-                            y: ry as f32 + band_h as f32 / 2.0,
-                            width: want_w as f32,
-                            height: band_h as f32,
-                            capacity: req.capacity,
-                            ceiling_height: deck_heights::room_ceiling_height(req.room_type),
-                            deck_span: deck_heights::room_deck_span(req.room_type),
-                            cells: Vec::new(),
-                        });
-                        placed_rooms.push((
-                            room_id,
-                            sx,
-                            ry,
-                            want_w,
-                            band_h,
-                            req.room_type,
-                            req.target_area,
-                            req.placement,
-                        ));
-                        // Door to ring
-                        ctx.db.door().insert(Door {
-                            id: 0,
-                            room_a: room_id,
-                            room_b: *ring_id,
-                            wall_a: if *ring_id == ring_n_id { 1 } else { 0 },
-                            wall_b: if *ring_id == ring_n_id { 0 } else { 1 },
-                            position_along_wall: 0.5,
-                            width: (want_w as f32 * 0.3).clamp(1.5, 3.0),
-                            access_level: access_levels::PUBLIC,
-                            door_x: sx as f32 + want_w as f32 / 2.0,
-                            door_y: if *ring_id == ring_n_id {
-                                by1 as f32
-                            } else {
-                                by0 as f32
-                            },
-                            is_open: true,
-                            is_locked: false,
-                        });
-                        placed = true;
-                    }
-                }
-
-                // Fill remaining gaps with filler rooms
-                let mut x = bx0;
-                let max_filler_w = 20usize;
-                while x < bx1 {
-                    let ring_edge_y = if *ring_id == ring_n_id {
-                        by1.saturating_sub(1)
-                    } else {
-                        by0
-                    };
-                    if grid[x][ring_edge_y] != CELL_EMPTY {
-                        x += 1;
-                        continue;
-                    }
-                    // Find widest empty span at ring edge
-                    let mut w = 0;
-                    for dx in 0..(bx1 - x) {
-                        if grid[x + dx][ring_edge_y] != CELL_EMPTY {
-                            break;
-                        }
-                        w = dx + 1;
-                    }
-                    let rw = w.min(max_filler_w);
-                    if rw < MIN_ROOM_DIM {
-                        x += w.max(1);
-                        continue;
-                    }
-                    let ry = if *ring_id == ring_n_id {
-                        by1.saturating_sub(band_h)
-                    } else {
-                        by0
-                    };
-                    // Verify full rect is clear
-                    let rect_clear =
-                        (0..rw).all(|dx| (0..band_h).all(|dy| grid[x + dx][ry + dy] == CELL_EMPTY));
-                    if !rect_clear {
-                        x += 1;
-                        continue;
-                    }
-                    let (frt, fname, _ftarget, fcap) = FILLER_POOL[filler_idx % FILLER_POOL.len()];
-                    filler_idx += 1;
-                    filler_count += 1;
-                    hull_band_count += 1;
-                    let room_id = next_id();
-                    for gx in x..x + rw {
-                        for gy in ry..ry + band_h {
-                            grid[gx][gy] = CELL_ROOM_BASE + (room_id as u8 % 246);
-                        }
-                    }
-                    ctx.db.room().insert(Room {
-                        id: room_id,
-                        node_id: 0,
-                        name: format!("{} {}", fname, filler_count),
-                        room_type: frt,
-                        deck,
-                        x: x as f32 + rw as f32 / 2.0,
-                        y: ry as f32 + band_h as f32 / 2.0,
-                        width: rw as f32,
-                        height: band_h as f32,
-                        capacity: fcap,
-                        ceiling_height: deck_heights::room_ceiling_height(frt),
-                        deck_span: deck_heights::room_deck_span(frt),
-                        cells: Vec::new(),
-                    });
-                    placed_rooms.push((
-                        room_id,
-                        x,
-                        ry,
-                        rw,
-                        band_h,
-                        frt,
-                        (rw * band_h) as f32,
-                        placement::NONE,
-                    ));
-                    // Door to ring
-                    ctx.db.door().insert(Door {
-                        id: 0,
-                        room_a: room_id,
-                        room_b: *ring_id,
-                        wall_a: if *ring_id == ring_n_id { 1 } else { 0 },
-                        wall_b: if *ring_id == ring_n_id { 0 } else { 1 },
-                        position_along_wall: 0.5,
-                        width: (rw as f32 * 0.3).clamp(1.5, 3.0),
-                        access_level: access_levels::PUBLIC,
-                        door_x: x as f32 + rw as f32 / 2.0,
-                        door_y: if *ring_id == ring_n_id {
-                            by1 as f32
-                        } else {
-                            by0 as f32
-                        },
-                        is_open: true,
-                        is_locked: false,
-                    });
-                    x += rw;
-                }
-            }
-            if hull_band_count > 0 {
-                log::info!(
-                    "Deck {}: placed {} hull band rooms",
-                    deck + 1,
-                    hull_band_count
-                );
-            }
-        }
-
-        // ---- Phase 8.5: Final expansion pass (all rooms including fillers) ----
-        expand_rooms_into_empty(
-            ctx,
-            &mut grid,
-            &mut placed_rooms,
-            inner_x0,
-            inner_y0,
-            inner_x1,
-            inner_y1,
-            ring_x0,
-            hull_y0,
-            ring_x1,
-            hull_y1,
-            deck,
-            "post-filler",
-        );
-
-        // ---- J6: Deadspace audit ----
-        {
-            let mut empty_cells = 0u32;
-            let mut total_inner = 0u32;
-            for x in inner_x0..inner_x1 {
-                for y in inner_y0..inner_y1 {
-                    total_inner += 1;
-                    if grid[x][y] == CELL_EMPTY {
-                        empty_cells += 1;
-                    }
-                }
-            }
-            // Also count hull band
-            for x in ring_x0..ring_x1 {
-                for y in hull_y0..inner_y0 {
-                    total_inner += 1;
-                    if grid[x][y] == CELL_EMPTY {
-                        empty_cells += 1;
-                    }
-                }
-                for y in inner_y1..hull_y1 {
-                    total_inner += 1;
-                    if grid[x][y] == CELL_EMPTY {
-                        empty_cells += 1;
-                    }
-                }
-            }
-            let pct = if total_inner > 0 {
-                empty_cells as f32 / total_inner as f32 * 100.0
-            } else {
-                0.0
-            };
-            if empty_cells > 0 {
-                log::info!(
-                    "Deck {}: deadspace audit — {} empty cells ({:.1}% of {})",
-                    deck + 1,
-                    empty_cells,
-                    pct,
-                    total_inner
-                );
-            }
-            if pct > 5.0 {
-                // Emergency expansion pass to fill remaining deadspace
-                expand_rooms_into_empty(
-                    ctx,
-                    &mut grid,
-                    &mut placed_rooms,
-                    inner_x0,
-                    inner_y0,
-                    inner_x1,
-                    inner_y1,
-                    ring_x0,
-                    hull_y0,
-                    ring_x1,
-                    hull_y1,
-                    deck,
-                    "deadspace-rescue",
-                );
-            }
-        }
-
-        // ---- J10b: Cell-level irregular expansion ----
-        // Grow rooms one cell at a time into adjacent empty cells, enabling L/T/U shapes.
-        {
-            let mut claimed = 0u32;
-            let mut changed = true;
-            while changed {
-                changed = false;
-                for i in 0..placed_rooms.len() {
-                    let (room_id, rx, ry, rw, rh, _rt, _ta, room_placement) = placed_rooms[i];
-                    let cell_tag = CELL_ROOM_BASE + (room_id as u8 % 246);
-                    let (exp_x0, exp_y0, exp_x1, exp_y1) =
-                        if room_placement == placement::HULL_FACING {
-                            (ring_x0, hull_y0, ring_x1, hull_y1)
-                        } else {
-                            (inner_x0, inner_y0, inner_x1, inner_y1)
-                        };
-                    for x in rx..(rx + rw).min(hw) {
-                        for y in ry..(ry + rh).min(hl) {
-                            if grid[x][y] != cell_tag {
-                                continue;
-                            }
-                            for &(dx, dy) in &[(0isize, 1isize), (0, -1), (1, 0), (-1, 0)] {
-                                let nx = x as isize + dx;
-                                let ny = y as isize + dy;
-                                if nx < exp_x0 as isize
-                                    || nx >= exp_x1 as isize
-                                    || ny < exp_y0 as isize
-                                    || ny >= exp_y1 as isize
-                                {
-                                    continue;
-                                }
-                                let nx = nx as usize;
-                                let ny = ny as usize;
-                                if grid[nx][ny] == CELL_EMPTY {
-                                    grid[nx][ny] = cell_tag;
-                                    claimed += 1;
-                                    changed = true;
-                                    let pr = &mut placed_rooms[i];
-                                    if nx < pr.1 {
-                                        pr.3 += pr.1 - nx;
-                                        pr.1 = nx;
-                                    }
-                                    if nx >= pr.1 + pr.3 {
-                                        pr.3 = nx - pr.1 + 1;
-                                    }
-                                    if ny < pr.2 {
-                                        pr.4 += pr.2 - ny;
-                                        pr.2 = ny;
-                                    }
-                                    if ny >= pr.2 + pr.4 {
-                                        pr.4 = ny - pr.2 + 1;
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-            if claimed > 0 {
-                log::info!(
-                    "Deck {}: cell-level expansion claimed {} cells",
-                    deck + 1,
-                    claimed
-                );
-            }
-        }
-
-        // ---- J10a: Populate cells field from grid ----
-        for &(room_id, rx, ry, rw, rh, ..) in &placed_rooms {
-            let cell_tag = CELL_ROOM_BASE + (room_id as u8 % 246);
-            let cells_data = encode_cell_mask(&grid, cell_tag, rx, ry, rw, rh, hw, hl);
-            if let Some(mut room) = ctx.db.room().id().find(room_id) {
-                room.x = rx as f32 + rw as f32 / 2.0;
-                room.y = ry as f32 + rh as f32 / 2.0;
-                room.width = rw as f32;
-                room.height = rh as f32;
-                room.cells = cells_data;
-                ctx.db.room().id().update(room);
-            }
-        }
-
-        // ---- Phase 9: Room-to-room doors (adjacent logical pairs) ----
-        for i in 0..placed_rooms.len() {
-            for j in (i + 1)..placed_rooms.len() {
-                let (id_a, ax, ay, aw, ah, rt_a, ..) = placed_rooms[i];
-                let (id_b, bx, by, bw, bh, rt_b, ..) = placed_rooms[j];
-                if !should_have_room_door(rt_a, rt_b) {
-                    continue;
-                }
-                if let Some((dx, dy, wa, wb, ol)) = find_shared_edge(ax, ay, aw, ah, bx, by, bw, bh)
-                {
-                    ctx.db.door().insert(Door {
-                        id: 0,
-                        room_a: id_a,
-                        room_b: id_b,
-                        wall_a: wa,
-                        wall_b: wb,
-                        position_along_wall: 0.5,
-                        width: door_width_from_overlap(ol),
-                        access_level: access_levels::PUBLIC,
-                        door_x: dx,
-                        door_y: dy,
-                        is_open: true,
-                        is_locked: false,
-                    });
-                }
-            }
-        }
-
-        // ---- Grid dump (debug) ----
-        let max_rows = 60;
-        let mut dump = format!(
-            "Deck {} grid ({}x{}, {} rooms, {} segments, {} cross-corridors):\n",
-            deck + 1,
-            hw,
-            hl,
-            placed_rooms.len(),
-            segments.len(),
-            cross_ys.len(),
-        );
-        for y in 0..hl.min(max_rows) {
-            for x in 0..hw {
-                let ch = match grid[x][y] {
-                    CELL_EMPTY => '.',
-                    CELL_MAIN_CORRIDOR => '=',
-                    CELL_SHAFT => '#',
-                    CELL_HULL => ' ',
-                    v if v >= CELL_ROOM_BASE => {
-                        let idx = (v - CELL_ROOM_BASE) as usize;
-                        (b'A' + (idx % 26) as u8) as char
-                    }
-                    _ => '?',
-                };
-                dump.push(ch);
-            }
-            dump.push('\n');
-        }
-        if hl > max_rows {
-            dump.push_str(&format!("... ({} more rows)\n", hl - max_rows));
-        }
-        log::info!("{}", dump);
-        let placed_count = request_used.iter().filter(|&&u| u).count();
+    let plan = generate_deck_layouts(&nodes, &config, decks);
+    let deck_count = plan.deck_count;
+    if decks.contains(&0) {
         log::info!(
-            "Deck {}: placed {}/{} rooms ({:.0}/{:.0}m² area, {} segment area available)",
-            deck + 1,
-            placed_count,
-            deck_requests.len(),
-            deck_requests
-                .iter()
-                .enumerate()
-                .filter(|(i, _)| request_used[*i])
-                .map(|(_, r)| r.target_area)
-                .sum::<f32>(),
-            total_request_area,
-            total_seg_area,
+            "Hull sizing: {:.0}m² total room area, {}×{} hull ({} decks)",
+            nodes.iter().map(|n| n.target_area).sum::<f32>(),
+            plan.ship_beam,
+            plan.ship_length,
+            deck_count
         );
-    } // end per-deck loop
-
-    // ---- VerticalShaft entries + cross-deck doors ----
-    for si in &shaft_infos {
-        let placed_decks: Vec<String> = si
-            .deck_room_ids
-            .iter()
-            .enumerate()
-            .filter_map(|(d, opt)| opt.map(|_| d.to_string()))
-            .collect();
-        if placed_decks.is_empty() {
-            continue;
+        let zone_names = ["CMD", "HAB", "SVC", "REC", "LIFE", "CARGO", "ENG"];
+        for (d, &z) in plan.deck_zones.iter().enumerate() {
+            log::info!("Deck {} → Zone {} ({})", d, z, zone_names[z as usize]);
         }
-        ctx.db.vertical_shaft().insert(VerticalShaft {
-            id: 0,
-            shaft_type: si.shaft_type,
-            name: si.name.to_string(),
-            x: si.ref_x,
-            y: si.ref_y,
-            decks_served: placed_decks.join(","),
-            width: si.ref_w,
-            height: si.ref_h,
-        });
+        record_zone_decks(ctx, &plan.deck_zones);
+    }
 
-        let access = if si.is_main {
-            access_levels::PUBLIC
-        } else {
-            access_levels::CREW_ONLY
-        };
-        for d in 0..deck_count.saturating_sub(1) {
-            if let (Some(room_a), Some(room_b)) = (
-                si.deck_room_ids[d as usize],
-                si.deck_room_ids[(d + 1) as usize],
-            ) {
-                if let (Some(ra), Some(rb)) = (
-                    ctx.db.room().id().find(room_a),
-                    ctx.db.room().id().find(room_b),
-                ) {
-                    let mid_x = (ra.x + ra.width / 2.0 + rb.x + rb.width / 2.0) / 2.0;
-                    let mid_y = (ra.y + ra.height / 2.0 + rb.y + rb.height / 2.0) / 2.0;
-                    ctx.db.door().insert(Door {
-                        id: 0,
-                        room_a,
-                        room_b,
-                        wall_a: wall_sides::SOUTH,
-                        wall_b: wall_sides::NORTH,
-                        position_along_wall: 0.5,
-                        width: 3.0,
-                        access_level: access,
-                        door_x: mid_x,
-                        door_y: mid_y,
-                        is_open: true,
-                        is_locked: false,
-                    });
-                }
-            }
-        }
+    insert_plan(ctx, &plan, &graph_nodes);
+
+    if !decks.contains(&deck_count.saturating_sub(1)) {
+        return deck_count;
     }
 
+    join_shafts_across_decks(ctx, plan);
+
     let total_rooms: usize = ctx.db.room().iter().count();
     let total_doors: usize = ctx.db.door().iter().count();
     log::info!(
@@ -2372,1135 +96,176 @@ pub(super) fn layout_ship(ctx: &ReducerContext, deck_count: u32, total_pop: u32)
         total_doors,
         deck_count
     );
+    deck_count
 }
 
-// ---- Helper functions ----
-
-/// Identify rectangular segments between corridors where rooms can be placed.
-/// Each segment is bounded by the ring, spine, and/or cross-corridors.
-#[allow(clippy::too_many_arguments)]
-fn find_segments(
-    grid: &[Vec<u8>],
-    _hw: usize,
-    _hl: usize,
-    spine_left: usize,
-    spine_right: usize,
-    inner_x0: usize,
-    inner_x1: usize,
-    inner_y0: usize,
-    inner_y1: usize,
-    cross_ys: &[usize],
-    spine_segments: &[(u32, usize, usize)],
-    cross_rooms: &[(u32, usize)],
-    ring_w_id: u32,
-    ring_e_id: u32,
-    _ring_n_id: u32,
-    _ring_s_id: u32,
-) -> Vec<Segment> {
-    let mut segments = Vec::new();
-
-    // Y boundaries: inner_y0, cross-corridor edges, inner_y1
-    let mut y_bounds: Vec<usize> = vec![inner_y0];
-    for &cy in cross_ys {
-        if cy >= inner_y0 && cy + CROSS_CORRIDOR_WIDTH <= inner_y1 {
-            y_bounds.push(cy);
-            y_bounds.push(cy + CROSS_CORRIDOR_WIDTH);
-        }
-    }
-    y_bounds.push(inner_y1);
-
-    for chunk in y_bounds.chunks(2) {
-        if chunk.len() < 2 || chunk[0] >= chunk[1] {
-            continue;
-        }
-        let seg_y0 = chunk[0];
-        let seg_y1 = chunk[1];
-        let seg_h = seg_y1 - seg_y0;
-        if seg_h < MIN_ROOM_DIM {
-            continue;
-        }
-
-        // Port side: ring-west inner edge to spine-left
-        let port_x0 = inner_x0;
-        let port_x1 = spine_left;
-        if port_x1 > port_x0 {
-            let port_w = port_x1 - port_x0;
-            if port_w >= MIN_ROOM_DIM {
-                // Find the corridor this segment touches
-                let corridor_id =
-                    find_corridor_for_y(seg_y0, seg_y1, spine_segments, cross_rooms, ring_w_id);
-                // Split around shafts
-                let sub_rects = find_clear_rects_in_region(grid, port_x0, port_x1, seg_y0, seg_y1);
-                for (rx, ry, rw, rh) in sub_rects {
-                    if rw >= MIN_ROOM_DIM && rh >= MIN_ROOM_DIM {
-                        // Determine which corridor edge this sub-rect touches
-                        let (cid, ws) = if rx + rw == spine_left {
-                            // Touches spine — door on east wall
-                            let sid = find_spine_segment(seg_y0, seg_y1, spine_segments);
-                            (sid, wall_sides::EAST)
-                        } else if rx == inner_x0 {
-                            // Touches ring west — door on west wall
-                            (ring_w_id, wall_sides::WEST)
-                        } else {
-                            (corridor_id, wall_sides::EAST)
-                        };
-                        segments.push(Segment {
-                            x: rx,
-                            y: ry,
-                            w: rw,
-                            h: rh,
-                            corridor_id: cid,
-                            wall_side: ws,
-                        });
-                    }
-                }
-            }
-        }
-
-        // Starboard side: spine-right to ring-east inner edge
-        let stbd_x0 = spine_right;
-        let stbd_x1 = inner_x1;
-        if stbd_x1 > stbd_x0 {
-            let stbd_w = stbd_x1 - stbd_x0;
-            if stbd_w >= MIN_ROOM_DIM {
-                let corridor_id =
-                    find_corridor_for_y(seg_y0, seg_y1, spine_segments, cross_rooms, ring_e_id);
-                let sub_rects = find_clear_rects_in_region(grid, stbd_x0, stbd_x1, seg_y0, seg_y1);
-                for (rx, ry, rw, rh) in sub_rects {
-                    if rw >= MIN_ROOM_DIM && rh >= MIN_ROOM_DIM {
-                        let (cid, ws) = if rx == spine_right {
-                            let sid = find_spine_segment(seg_y0, seg_y1, spine_segments);
-                            (sid, wall_sides::WEST)
-                        } else if rx + rw == inner_x1 {
-                            (ring_e_id, wall_sides::EAST)
-                        } else {
-                            (corridor_id, wall_sides::WEST)
-                        };
-                        segments.push(Segment {
-                            x: rx,
-                            y: ry,
-                            w: rw,
-                            h: rh,
-                            corridor_id: cid,
-                            wall_side: ws,
-                        });
-                    }
-                }
-            }
-        }
-    }
-
-    // Sort: spine-touching segments first, then by area descending
-    segments.sort_by(|a, b| {
-        let a_spine = if a.x + a.w == spine_left || a.x == spine_right {
-            0
-        } else {
-            1
-        };
-        let b_spine = if b.x + b.w == spine_left || b.x == spine_right {
-            0
-        } else {
-            1
-        };
-        let spine_cmp = a_spine.cmp(&b_spine);
-        if spine_cmp != core::cmp::Ordering::Equal {
-            return spine_cmp;
-        }
-        (b.w * b.h).cmp(&(a.w * a.h))
-    });
-
-    segments
-}
-
-/// Find the spine segment whose Y range overlaps [y0, y1).
-fn find_spine_segment(y0: usize, y1: usize, spine_segments: &[(u32, usize, usize)]) -> u32 {
-    let mid_y = (y0 + y1) / 2;
-    for &(seg_id, seg_y0, seg_y1) in spine_segments {
-        if mid_y >= seg_y0 && mid_y < seg_y1 {
-            return seg_id;
-        }
-    }
-    spine_segments.first().map(|s| s.0).unwrap_or(0)
-}
-
-/// Find a corridor ID for a Y range (spine segment or cross-corridor).
-fn find_corridor_for_y(
-    y0: usize,
-    y1: usize,
-    spine_segments: &[(u32, usize, usize)],
-    cross_rooms: &[(u32, usize)],
-    ring_fallback: u32,
-) -> u32 {
-    // Try spine
-    let mid_y = (y0 + y1) / 2;
-    for &(seg_id, seg_y0, seg_y1) in spine_segments {
-        if mid_y >= seg_y0 && mid_y < seg_y1 {
-            return seg_id;
-        }
-    }
-    // Try cross-corridor
-    for &(cc_id, cy) in cross_rooms {
-        if y0 <= cy + CROSS_CORRIDOR_WIDTH && y1 >= cy {
-            return cc_id;
-        }
-    }
-    ring_fallback
-}
-
-/// Find clear rectangles in a region, splitting around shaft obstacles.
-fn find_clear_rects_in_region(
-    grid: &[Vec<u8>],
-    x0: usize,
-    x1: usize,
-    y0: usize,
-    y1: usize,
-) -> Vec<(usize, usize, usize, usize)> {
-    let mut results = Vec::new();
-    if x1 <= x0 || y1 <= y0 {
-        return results;
-    }
-
-    // Scan columns for clear runs, splitting around obstacles
-    let mut col_start = x0;
-    let mut x = x0;
-    while x <= x1 {
-        let col_has_obstacle = if x < x1 {
-            (y0..y1).any(|y| y >= grid[x].len() || grid[x][y] != CELL_EMPTY)
-        } else {
-            true
-        };
-
-        if col_has_obstacle {
-            let run_w = x - col_start;
-            if run_w >= MIN_ROOM_DIM {
-                results.push((col_start, y0, run_w, y1 - y0));
-            }
-
-            if x < x1 {
-                let obs_col_start = x;
-                let mut obs_end = x + 1;
-                while obs_end < x1 {
-                    let has_obs = (y0..y1)
-                        .any(|y| y >= grid[obs_end].len() || grid[obs_end][y] != CELL_EMPTY);
-                    if !has_obs {
-                        break;
-                    }
-                    obs_end += 1;
-                }
-                let obs_w = obs_end - obs_col_start;
-
-                // Find clear Y sub-ranges in obstacle columns
-                let mut row_start = y0;
-                let mut ry = y0;
-                while ry <= y1 {
-                    let row_clear = if ry < y1 {
-                        (obs_col_start..obs_end)
-                            .all(|cx| ry < grid[cx].len() && grid[cx][ry] == CELL_EMPTY)
-                    } else {
-                        false
-                    };
-                    if !row_clear {
-                        let run_h = ry - row_start;
-                        if run_h >= MIN_ROOM_DIM && obs_w >= MIN_ROOM_DIM {
-                            results.push((obs_col_start, row_start, obs_w, run_h));
-                        }
-                        row_start = ry + 1;
-                    }
-                    ry += 1;
-                }
-
-                x = obs_end;
-                col_start = obs_end;
-                continue;
-            }
-            col_start = x + 1;
-        }
-        x += 1;
-    }
-
-    results
-}
-
-/// Check if a rectangle touches any corridor (spine, cross-corridor, or ring).
-/// Pure geometry check — no side effects.
-#[allow(clippy::too_many_arguments)]
-fn touches_any_corridor(
-    rx: usize,
-    ry: usize,
-    rw: usize,
-    rh: usize,
-    spine_left: usize,
-    spine_segments: &[(u32, usize, usize)],
-    cross_rooms: &[(u32, usize)],
-    spur_rooms: &[(u32, usize, usize, usize, usize)],
-    inner_x0: usize,
-    inner_x1: usize,
-    inner_y1: usize,
-    ring_x0: usize,
-    ring_x1: usize,
-    ring_y0: usize,
-    ring_y1: usize,
-) -> bool {
-    for &(_, seg_y0, seg_y1) in spine_segments {
-        if find_shared_edge(
-            rx,
-            ry,
-            rw,
-            rh,
-            spine_left,
-            seg_y0,
-            SPINE_WIDTH,
-            seg_y1 - seg_y0,
-        )
-        .is_some()
-        {
-            return true;
-        }
-    }
-    for &(_, cy) in cross_rooms {
-        if find_shared_edge(
-            rx,
-            ry,
-            rw,
-            rh,
-            inner_x0,
-            cy,
-            inner_x1 - inner_x0,
-            CROSS_CORRIDOR_WIDTH,
-        )
-        .is_some()
-        {
-            return true;
-        }
-    }
-    for &(_, sx, sy, sw, sh) in spur_rooms {
-        if find_shared_edge(rx, ry, rw, rh, sx, sy, sw, sh).is_some() {
-            return true;
-        }
-    }
-    let ring_checks: [(usize, usize, usize, usize); 4] = [
-        (ring_x0, ring_y0, RING_WIDTH, ring_y1 - ring_y0),
-        (ring_x1 - RING_WIDTH, ring_y0, RING_WIDTH, ring_y1 - ring_y0),
-        (ring_x0, ring_y0, ring_x1 - ring_x0, RING_WIDTH),
-        (ring_x0, inner_y1, ring_x1 - ring_x0, RING_WIDTH),
-    ];
-    for (rcx, rcy, rcw, rch) in ring_checks {
-        if find_shared_edge(rx, ry, rw, rh, rcx, rcy, rcw, rch).is_some() {
-            return true;
-        }
-    }
-    false
-}
-
-/// Try to create a door from a room to the nearest corridor it actually touches.
-/// Returns true if a door was created.
-#[allow(clippy::too_many_arguments)]
-fn create_corridor_door(
-    ctx: &ReducerContext,
-    room_id: u32,
-    rx: usize,
-    ry: usize,
-    rw: usize,
-    rh: usize,
-    spine_left: usize,
-    _spine_right: usize,
-    spine_segments: &[(u32, usize, usize)],
-    cross_rooms: &[(u32, usize)],
-    spur_rooms: &[(u32, usize, usize, usize, usize)],
-    inner_x0: usize,
-    inner_x1: usize,
-    _inner_y0: usize,
-    inner_y1: usize,
-    ring_x0: usize,
-    ring_x1: usize,
-    ring_y0: usize,
-    ring_y1: usize,
-    ring_n_id: u32,
-    ring_s_id: u32,
-    ring_w_id: u32,
-    ring_e_id: u32,
-) -> bool {
-    // Try spine segments
-    for &(seg_id, seg_y0, seg_y1) in spine_segments {
-        if let Some((dx, dy, wa, wb, ol)) = find_shared_edge(
-            rx,
-            ry,
-            rw,
-            rh,
-            spine_left,
-            seg_y0,
-            SPINE_WIDTH,
-            seg_y1 - seg_y0,
-        ) {
-            ctx.db.door().insert(Door {
-                id: 0,
-                room_a: room_id,
-                room_b: seg_id,
-                wall_a: wa,
-                wall_b: wb,
-                position_along_wall: 0.5,
-                width: door_width_from_overlap(ol),
-                access_level: access_levels::PUBLIC,
-                door_x: dx,
-                door_y: dy,
-                is_open: true,
-                is_locked: false,
-            });
-            return true;
-        }
-    }
-    // Try cross-corridors
-    for &(cr_id, cy) in cross_rooms {
-        if let Some((dx, dy, wa, wb, ol)) = find_shared_edge(
-            rx,
-            ry,
-            rw,
-            rh,
-            inner_x0,
-            cy,
-            inner_x1 - inner_x0,
-            CROSS_CORRIDOR_WIDTH,
-        ) {
-            ctx.db.door().insert(Door {
-                id: 0,
-                room_a: room_id,
-                room_b: cr_id,
-                wall_a: wa,
-                wall_b: wb,
-                position_along_wall: 0.5,
-                width: door_width_from_overlap(ol),
-                access_level: access_levels::PUBLIC,
-                door_x: dx,
-                door_y: dy,
-                is_open: true,
-                is_locked: false,
-            });
-            return true;
-        }
-    }
-    // Try spur corridors
-    for &(spur_id, sx, sy, sw, sh) in spur_rooms {
-        if let Some((dx, dy, wa, wb, ol)) = find_shared_edge(rx, ry, rw, rh, sx, sy, sw, sh) {
-            ctx.db.door().insert(Door {
-                id: 0,
-                room_a: room_id,
-                room_b: spur_id,
-                wall_a: wa,
-                wall_b: wb,
-                position_along_wall: 0.5,
-                width: door_width_from_overlap(ol),
-                access_level: access_levels::PUBLIC,
-                door_x: dx,
-                door_y: dy,
-                is_open: true,
-                is_locked: false,
-            });
-            return true;
-        }
-    }
-    // Try ring corridors
-    let ring_checks: [(u32, usize, usize, usize, usize); 4] = [
-        (ring_w_id, ring_x0, ring_y0, RING_WIDTH, ring_y1 - ring_y0),
-        (
-            ring_e_id,
-            ring_x1 - RING_WIDTH,
-            ring_y0,
-            RING_WIDTH,
-            ring_y1 - ring_y0,
-        ),
-        (ring_n_id, ring_x0, ring_y0, ring_x1 - ring_x0, RING_WIDTH),
-        (ring_s_id, ring_x0, inner_y1, ring_x1 - ring_x0, RING_WIDTH),
-    ];
-    for (rid, rcx, rcy, rcw, rch) in ring_checks {
-        if let Some((dx, dy, wa, wb, ol)) = find_shared_edge(rx, ry, rw, rh, rcx, rcy, rcw, rch) {
-            ctx.db.door().insert(Door {
-                id: 0,
-                room_a: room_id,
-                room_b: rid,
-                wall_a: wa,
-                wall_b: wb,
-                position_along_wall: 0.5,
-                width: door_width_from_overlap(ol),
-                access_level: access_levels::PUBLIC,
-                door_x: dx,
-                door_y: dy,
-                is_open: true,
-                is_locked: false,
-            });
-            return true;
-        }
-    }
-    false
-}
-
-/// BSP subdivide a rectangle into sub-rectangles for room packing.
-/// Splits in both X and Y directions — chooses the longer axis so rooms
-/// don't become impossibly thin.
-fn bsp_subdivide(
-    x: usize,
-    y: usize,
-    w: usize,
-    h: usize,
-    requests: &[RoomRequest],
-    out: &mut Vec<(usize, usize, usize, usize)>,
-) {
-    if requests.is_empty() || w < MIN_ROOM_DIM || h < MIN_ROOM_DIM {
-        return;
-    }
-
-    if requests.len() == 1 {
-        // J5: Use full available sub-rect — rooms expand later anyway.
-        out.push((x, y, w, h));
-        return;
-    }
-
-    let split_at = requests.len() / 2;
-    let area_ratio = requests[..split_at]
-        .iter()
-        .map(|r| r.target_area)
-        .sum::<f32>()
-        / requests.iter().map(|r| r.target_area).sum::<f32>();
-
-    // Split along the longer axis for better packing
-    if h >= w {
-        let split_h = (h as f32 * area_ratio).round() as usize;
-        let split_h = split_h
-            .max(MIN_ROOM_DIM)
-            .min(h.saturating_sub(MIN_ROOM_DIM));
-        if split_h >= MIN_ROOM_DIM && h - split_h >= MIN_ROOM_DIM {
-            bsp_subdivide(x, y, w, split_h, &requests[..split_at], out);
-            bsp_subdivide(x, y + split_h, w, h - split_h, &requests[split_at..], out);
-        } else {
-            out.push((x, y, w, h));
-        }
-    } else {
-        let split_w = (w as f32 * area_ratio).round() as usize;
-        let split_w = split_w
-            .max(MIN_ROOM_DIM)
-            .min(w.saturating_sub(MIN_ROOM_DIM));
-        if split_w >= MIN_ROOM_DIM && w - split_w >= MIN_ROOM_DIM {
-            bsp_subdivide(x, y, split_w, h, &requests[..split_at], out);
-            bsp_subdivide(x + split_w, y, w - split_w, h, &requests[split_at..], out);
-        } else {
-            out.push((x, y, w, h));
-        }
-    }
-}
-
-/// Connect a shaft room to the nearest corridor via a door, preferring the shaft's
-/// designated wall for consistent orientation across all decks.
-#[allow(clippy::too_many_arguments)]
-fn connect_shaft_to_corridor(
-    ctx: &ReducerContext,
-    shaft_room_id: u32,
-    sp: &ShaftPlacement,
-    spine_segments: &[(u32, usize, usize)],
-    cross_rooms: &[(u32, usize)],
-    spur_rooms: &[(u32, usize, usize, usize, usize)],
-    ring_ids: [u32; 4],
-    ring_grids: [(usize, usize, usize, usize); 4],
-    spine_left: usize,
-    inner_x0: usize,
-    inner_x1: usize,
-    access: u8,
-) {
-    let sx = sp.x;
-    let sy = sp.y;
-    let sw = sp.w;
-    let sh = sp.h;
-    let pref = sp.preferred_wall;
-
-    // Collect all candidate connections (corridor_id, door_x, door_y, wall_a, wall_b, overlap)
-    let mut candidates: Vec<(u32, f32, f32, u8, u8, f32)> = Vec::new();
-
-    // Cross-corridors
-    for &(cc_id, cy) in cross_rooms {
-        let cc_w = inner_x1 - inner_x0;
-        if let Some((dx, dy, wa, wb, ol)) =
-            find_shared_edge(sx, sy, sw, sh, inner_x0, cy, cc_w, CROSS_CORRIDOR_WIDTH)
-        {
-            candidates.push((cc_id, dx, dy, wa, wb, ol));
-        }
-    }
-    // Spine segments
-    for &(seg_id, seg_y0, seg_y1) in spine_segments {
-        let seg_h = seg_y1 - seg_y0;
-        if let Some((dx, dy, wa, wb, ol)) =
-            find_shared_edge(sx, sy, sw, sh, spine_left, seg_y0, SPINE_WIDTH, seg_h)
-        {
-            candidates.push((seg_id, dx, dy, wa, wb, ol));
-        }
+/// Insert the rooms, doors, corridors and hull hatches of the laid-out decks.
+fn insert_plan(ctx: &ReducerContext, plan: &ShipLayoutPlan, graph_nodes: &[GraphNode]) {
+    for room in &plan.rooms {
+        ctx.db.room().insert(Room {
+            id: room.id,
+            node_id: room.node.map_or(0, |i| graph_nodes[i].id),
+            name: room.name.clone(),
+            room_type: room.room_type,
+            deck: room.deck,
+            x: room.x,
+            y: room.y,
+            width: room.width,
+            height: room.height,
+            capacity: room.capacity,
+            ceiling_height: room.ceiling_height,
+            deck_span: room.deck_span,
+            cells: room.cells.clone(),
+            hull_sides: room.hull_sides,
+            has_window: room.has_window,
+        });
     }
-    // Spur corridors
-    for &(spur_id, spur_x, spur_y, spur_w, spur_h) in spur_rooms {
-        if let Some((dx, dy, wa, wb, ol)) =
-            find_shared_edge(sx, sy, sw, sh, spur_x, spur_y, spur_w, spur_h)
-        {
-            candidates.push((spur_id, dx, dy, wa, wb, ol));
-        }
+    for door in &plan.doors {
+        insert_door(ctx, door);
     }
-    // Ring corridors
-    for i in 0..4 {
-        let (rx, ry, rw, rh) = ring_grids[i];
-        if let Some((dx, dy, wa, wb, ol)) = find_shared_edge(sx, sy, sw, sh, rx, ry, rw, rh) {
-            candidates.push((ring_ids[i], dx, dy, wa, wb, ol));
-        }
-    }
-
-    // First pass: prefer the designated wall
-    if let Some(&(cid, dx, dy, wa, wb, ol)) = candidates.iter().find(|c| c.3 == pref) {
-        ctx.db.door().insert(Door {
+    for c in &plan.corridors {
+        ctx.db.corridor().insert(Corridor {
             id: 0,
-            room_a: shaft_room_id,
-            room_b: cid,
-            wall_a: wa,
-            wall_b: wb,
-            position_along_wall: 0.5,
-            width: door_width_from_overlap(ol),
-            access_level: access,
-            door_x: dx,
-            door_y: dy,
-            is_open: true,
-            is_locked: false,
+            deck: c.deck,
+            corridor_type: c.corridor_type,
+            x: c.x,
+            y: c.y,
+            width: c.width,
+            length: c.length,
+            orientation: c.orientation,
+            carries: c.carries,
         });
-        return;
     }
-    // Fallback: any wall
-    if let Some(&(cid, dx, dy, wa, wb, ol)) = candidates.first() {
-        ctx.db.door().insert(Door {
+    for h in &plan.hatches {
+        ctx.db.hull_hatch().insert(HullHatch {
             id: 0,
-            room_a: shaft_room_id,
-            room_b: cid,
-            wall_a: wa,
-            wall_b: wb,
-            position_along_wall: 0.5,
-            width: door_width_from_overlap(ol),
-            access_level: access,
-            door_x: dx,
-            door_y: dy,
-            is_open: true,
-            is_locked: false,
+            room_id: h.room_id,
+            hatch_type: h.hatch_type,
+            wall: h.wall,
+            x: h.x,
+            y: h.y,
+            width: h.width,
+            is_open: false,
         });
-        return;
     }
 
-    log::warn!(
-        "Shaft {} at ({},{}) {}x{} has no adjacent corridor",
-        sp.name,
-        sx,
-        sy,
-        sw,
-        sh
-    );
-}
-
-/// Compute door width from overlap length to match client rendering.
-/// Width = min(2.0, overlap - 0.5), clamped to at least 0.5.
-fn door_width_from_overlap(overlap: f32) -> f32 {
-    2.0_f32.min(overlap - 0.5).max(0.5)
-}
-
-/// Find shared edge between two axis-aligned rectangles.
-/// Returns (door_x, door_y, wall_a, wall_b, overlap_length).
-#[allow(clippy::too_many_arguments)]
-fn find_shared_edge(
-    ax: usize,
-    ay: usize,
-    aw: usize,
-    ah: usize,
-    bx: usize,
-    by: usize,
-    bw: usize,
-    bh: usize,
-) -> Option<(f32, f32, u8, u8, f32)> {
-    if ax + aw == bx {
-        let oy0 = ay.max(by);
-        let oy1 = (ay + ah).min(by + bh);
-        if oy1 > oy0 {
-            return Some((
-                (ax + aw) as f32,
-                (oy0 + oy1) as f32 / 2.0,
-                wall_sides::EAST,
-                wall_sides::WEST,
-                (oy1 - oy0) as f32,
-            ));
-        }
-    }
-    if bx + bw == ax {
-        let oy0 = ay.max(by);
-        let oy1 = (ay + ah).min(by + bh);
-        if oy1 > oy0 {
-            return Some((
-                ax as f32,
-                (oy0 + oy1) as f32 / 2.0,
-                wall_sides::WEST,
-                wall_sides::EAST,
-                (oy1 - oy0) as f32,
-            ));
-        }
-    }
-    if ay + ah == by {
-        let ox0 = ax.max(bx);
-        let ox1 = (ax + aw).min(bx + bw);
-        if ox1 > ox0 {
-            return Some((
-                (ox0 + ox1) as f32 / 2.0,
-                (ay + ah) as f32,
-                wall_sides::SOUTH,
-                wall_sides::NORTH,
-                (ox1 - ox0) as f32,
-            ));
+    for deck in 0..plan.deck_count as i32 {
+        let rooms = plan.rooms.iter().filter(|r| r.deck == deck).count();
+        if rooms > 0 {
+            log::info!("Deck {}: laid out {} rooms", deck + 1, rooms);
         }
     }
-    if by + bh == ay {
-        let ox0 = ax.max(bx);
-        let ox1 = (ax + aw).min(bx + bw);
-        if ox1 > ox0 {
-            return Some((
-                (ox0 + ox1) as f32 / 2.0,
-                ay as f32,
-                wall_sides::NORTH,
-                wall_sides::SOUTH,
-                (ox1 - ox0) as f32,
-            ));
-        }
+    for &node in &plan.unplaced {
+        log::warn!("No deck space for {}", graph_nodes[node].name);
     }
-    None
 }
 
-/// Compute shaft templates scaled to population.
-fn compute_shaft_templates(total_pop: u32) -> Vec<(&'static str, u8, bool, usize, usize)> {
-    let main_count = (total_pop as f32 / 200.0).ceil().max(2.0) as usize;
-    let svc_count = (total_pop as f32 / 500.0).ceil().max(1.0) as usize;
-    let ladder_count = (total_pop as f32 / 500.0).ceil().max(2.0) as usize;
-
-    const MAIN_NAMES: &[&str] = &[
-        "Fore Elevator",
-        "Aft Elevator",
-        "Midship Elevator",
-        "Elevator 4",
-        "Elevator 5",
-        "Elevator 6",
-        "Elevator 7",
-        "Elevator 8",
-        "Elevator 9",
-        "Elevator 10",
-        "Elevator 11",
-        "Elevator 12",
-        "Elevator 13",
-        "Elevator 14",
-        "Elevator 15",
-        "Elevator 16",
-        "Elevator 17",
-        "Elevator 18",
-        "Elevator 19",
-        "Elevator 20",
-        "Elevator 21",
-        "Elevator 22",
-        "Elevator 23",
-        "Elevator 24",
-        "Elevator 25",
-        "Elevator 26",
-        "Elevator 27",
-        "Elevator 28",
-    ];
-    const SVC_NAMES: &[&str] = &[
-        "Service Elevator A",
-        "Service Elevator B",
-        "Service Elevator C",
-        "Service Elevator D",
-        "Service Elevator E",
-        "Service Elevator F",
-        "Service Elevator G",
-        "Service Elevator H",
-        "Service Elevator I",
-        "Service Elevator J",
-    ];
-    const LADDER_NAMES: &[&str] = &[
-        "Ladder A", "Ladder B", "Ladder C", "Ladder D", "Ladder E", "Ladder F", "Ladder G",
-        "Ladder H", "Ladder I", "Ladder J",
-    ];
-
-    let mut templates = Vec::new();
-    for i in 0..main_count.min(MAIN_NAMES.len()) {
-        templates.push((MAIN_NAMES[i], shaft_types::ELEVATOR, true, 3, 3));
-    }
-    for i in 0..svc_count.min(SVC_NAMES.len()) {
-        templates.push((SVC_NAMES[i], shaft_types::SERVICE_ELEVATOR, false, 3, 3));
-    }
-    for i in 0..ladder_count.min(LADDER_NAMES.len()) {
-        templates.push((LADDER_NAMES[i], shaft_types::LADDER, false, 3, 3));
-    }
-
-    log::info!(
-        "Shaft templates for {} people: {} main elevators, {} service, {} ladders",
-        total_pop,
-        main_count,
-        svc_count,
-        ladder_count
-    );
-    templates
+fn insert_door(ctx: &ReducerContext, door: &LayoutDoor) {
+    ctx.db.door().insert(Door {
+        id: 0,
+        room_a: door.room_a,
+        room_b: door.room_b,
+        wall_a: door.wall_a,
+        wall_b: door.wall_b,
+        position_along_wall: 0.5,
+        width: door.width,
+        access_level: door.access_level,
+        door_x: door.x,
+        door_y: door.y,
+        state: door_states::OPEN,
+    });
 }
 
-/// Compute shaft placements by distributing templates across cross-corridor intersections.
-fn compute_shaft_placements(
-    templates: &[(&'static str, u8, bool, usize, usize)],
-    spine_right: usize,
-    spine_left: usize,
-    cross_ys: &[usize],
-    hw: usize,
-    hl: usize,
-) -> Vec<ShaftPlacement> {
-    let mut placements = Vec::new();
-    let cross_end_offset = CROSS_CORRIDOR_WIDTH;
-
-    if cross_ys.is_empty() {
-        if let Some((name, st, is_main, w, h)) = templates.first() {
-            // Shaft right of spine → door on WEST wall (facing spine)
-            placements.push(ShaftPlacement {
-                x: spine_right,
-                y: hl / 4,
-                w: *w,
-                h: *h,
-                shaft_type: *st,
-                name,
-                is_main: *is_main,
-                preferred_wall: wall_sides::WEST,
+/// Replace the layout-chosen zone_config rows with the ranges in
+/// `deck_zone_map`, keeping the overridden ones.
+fn record_zone_decks(ctx: &ReducerContext, deck_zone_map: &[u8]) {
+    for row in ctx.db.zone_config().iter().filter(|z| !z.overridden) {
+        ctx.db.zone_config().zone().delete(row.zone);
+    }
+    for range in zone_deck_ranges(deck_zone_map) {
+        if ctx.db.zone_config().zone().find(range.zone).is_none() {
+            ctx.db.zone_config().insert(ZoneConfig {
+                zone: range.zone,
+                first_deck: range.first_deck,
+                end_deck: range.end_deck,
+                overridden: false,
             });
         }
-        return placements;
     }
+}
 
-    let main_elevators: Vec<_> = templates
+/// Stack each shaft's rooms, laid out over however many calls, into a
+/// VerticalShaft row and join neighboring decks with a door, the only
+/// cross-deck doors in the ship. The result is checked with
+/// [`check_shaft_alignment`] and any problem logged.
+fn join_shafts_across_decks(ctx: &ReducerContext, mut plan: ShipLayoutPlan) {
+    let shaft_rooms: Vec<Room> = ctx
+        .db
+        .room()
         .iter()
-        .filter(|(_, st, _, _, _)| *st == shaft_types::ELEVATOR)
-        .collect();
-    let service_elevators: Vec<_> = templates
-        .iter()
-        .filter(|(_, st, _, _, _)| *st == shaft_types::SERVICE_ELEVATOR)
-        .collect();
-    let ladders: Vec<_> = templates
-        .iter()
-        .filter(|(_, st, _, _, _)| *st == shaft_types::LADDER)
+        .filter(|r| {
+            matches!(
+                r.room_type,
+                room_types::ELEVATOR_SHAFT
+                    | room_types::SERVICE_ELEVATOR_SHAFT
+                    | room_types::LADDER_SHAFT
+            )
+        })
         .collect();
-
-    let num_positions = cross_ys.len();
-
-    // Main elevators: starboard of spine → door faces NORTH (into cross-corridor)
-    for (i, (name, st, is_main, w, h)) in main_elevators.iter().enumerate() {
-        let cross_idx = if main_elevators.len() <= num_positions {
-            i * num_positions / main_elevators.len()
-        } else {
-            i % num_positions
-        };
-        let cy = cross_ys[cross_idx.min(num_positions - 1)];
-        let stack_offset = if main_elevators.len() > num_positions {
-            (i / num_positions) * *h
-        } else {
-            0
-        };
-        let sy = cy + cross_end_offset + stack_offset;
-        // Determine preferred wall: adjacent to cross-corridor (NORTH) or spine (WEST)
-        let pwall = if sy == cy + cross_end_offset {
-            wall_sides::NORTH // adjacent to cross-corridor above
-        } else {
-            wall_sides::WEST // stacked below — face spine
-        };
-        placements.push(ShaftPlacement {
-            x: spine_right,
-            y: sy,
-            w: *w,
-            h: *h,
-            shaft_type: *st,
-            name,
-            is_main: *is_main,
-            preferred_wall: pwall,
-        });
-    }
-
-    // Service elevators: offset from spine → door faces WEST (toward spine)
-    let svc_x = spine_right + 3 + 1;
-    if svc_x + 2 < hw {
-        for (i, (name, st, is_main, w, h)) in service_elevators.iter().enumerate() {
-            let cross_idx = if service_elevators.len() <= num_positions {
-                (i * num_positions / service_elevators.len().max(1)).min(num_positions - 1)
-            } else {
-                i % num_positions
-            };
-            let cy = cross_ys[cross_idx];
-            placements.push(ShaftPlacement {
-                x: svc_x,
-                y: cy + cross_end_offset,
-                w: *w,
-                h: *h,
-                shaft_type: *st,
-                name,
-                is_main: *is_main,
-                preferred_wall: wall_sides::WEST,
-            });
-        }
+    let doors = join_shafts(&mut plan.shafts, plan.deck_count, |name| {
+        shaft_rooms.iter().find(|r| r.name == name).map(|r| r.id)
+    });
+    for door in &doors {
+        insert_door(ctx, door);
     }
-
-    // Ladders: port side of spine → door faces EAST (toward spine)
-    for (i, (name, st, is_main, w, h)) in ladders.iter().enumerate() {
-        let cross_idx = if ladders.len() <= num_positions {
-            (i * num_positions / ladders.len().max(1)).min(num_positions - 1)
-        } else {
-            i % num_positions
-        };
-        let cy = cross_ys[cross_idx];
-        placements.push(ShaftPlacement {
-            x: spine_left.saturating_sub(*w),
-            y: cy + cross_end_offset,
-            w: *w,
-            h: *h,
-            shaft_type: *st,
-            name,
-            is_main: *is_main,
-            preferred_wall: wall_sides::EAST,
+    let mut stacks: Vec<Vec<u32>> = Vec::new();
+    for shaft in plan.shafts.iter().filter(|s| !s.deck_rooms.is_empty()) {
+        ctx.db.vertical_shaft().insert(VerticalShaft {
+            id: 0,
+            shaft_type: shaft.shaft_type,
+            name: shaft.name.clone(),
+            x: shaft.x,
+            y: shaft.y,
+            decks_served: shaft
+                .decks_served
+                .iter()
+                .map(|d| d.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+            width: shaft.width,
+            height: shaft.height,
         });
+        stacks.push(shaft.deck_rooms.clone());
     }
 
-    placements
-}
-
-/// Compute hull dimensions using iterative overhead calculation.
-fn compute_hull_dimensions(
-    room_area_per_deck: f32,
-    shaft_area_per_deck: f32,
-    max_room_area: f32,
-) -> (usize, usize) {
-    let aspect_ratio = 3.5f32;
-    let mut mult = 1.4f32;
-
-    // Minimum beam: largest room must fit in segment between ring and spine
-    // segment_width = (beam - SPINE_WIDTH - 2*RING_WIDTH) / 2
-    let min_strip_w = (max_room_area.sqrt()).max(MIN_ROOM_DIM as f32);
-    let min_beam = (min_strip_w * 2.0 + SPINE_WIDTH as f32 + 2.0 * RING_WIDTH as f32).max(30.0);
-
-    for _ in 0..5 {
-        let apd = room_area_per_deck * mult;
-        let b = (apd.sqrt() / aspect_ratio.sqrt()).max(min_beam);
-        let l = (apd / b).max(100.0);
-
-        let num_cross = (l / 35.0).round().max(1.0);
-        // Ring (3-wide perimeter) + spine + cross-corridors
-        let corridor_area = SPINE_WIDTH as f32 * l
-            + num_cross * CROSS_CORRIDOR_WIDTH as f32 * b
-            + 2.0 * (b + l) * RING_WIDTH as f32;
-
-        let actual_need = room_area_per_deck + corridor_area + shaft_area_per_deck;
-        let new_mult = actual_need / room_area_per_deck;
-
-        if (new_mult - mult).abs() < 0.01 {
-            break;
-        }
-        mult = new_mult;
-    }
-
-    let apd = room_area_per_deck * mult;
-    let b = (apd.sqrt() / aspect_ratio.sqrt()).max(min_beam) as usize;
-    let l = (apd / b as f32).max(100.0) as usize;
-    (b, l)
-}
-
-/// Auto-compute optimal deck count.
-fn compute_optimal_deck_count(
-    total_room_area: f32,
-    shaft_area_per_deck: f32,
-    shaft_templates: &[(&'static str, u8, bool, usize, usize)],
-    max_room_area: f32,
-) -> u32 {
-    let num_banks = shaft_templates
+    let rooms: Vec<RoomRect> = shaft_rooms
         .iter()
-        .filter(|(_, st, _, _, _)| *st == shaft_types::ELEVATOR)
-        .count()
-        .max(1);
-
-    let mut best = 7u32;
-    for d in 7..=30u32 {
-        let room_per_deck = total_room_area / d as f32;
-        let (b, l) = compute_hull_dimensions(room_per_deck, shaft_area_per_deck, max_room_area);
-
-        let num_cross = (l as f32 / 35.0).round().max(1.0) as usize;
-        let usable_width = b.saturating_sub(SPINE_WIDTH + 2 * RING_WIDTH);
-        let usable_length = l.saturating_sub(2 * RING_WIDTH) - num_cross * CROSS_CORRIDOR_WIDTH;
-        let strip_area = usable_width as f32 * usable_length as f32 * 0.8 - shaft_area_per_deck;
-
-        let fill = if strip_area > 0.0 {
-            room_per_deck / strip_area
-        } else {
-            99.0
-        };
-        let max_walk = l as f32 / (2.0 * num_banks as f32) + b as f32 / 2.0;
-
-        if fill <= 0.90 && max_walk <= 50.0 {
-            best = d;
-            log::info!(
-                "Auto deck count: {} decks ({}x{}, fill {:.0}%, walk {:.0}m)",
-                d,
-                b,
-                l,
-                fill * 100.0,
-                max_walk
-            );
-            break;
-        }
-        best = d;
-    }
-    best
-}
-
-/// Find the best clear rectangle within a search region for placing a large room.
-///
-/// Scans the search region for the largest contiguous empty rectangle that can
-/// accommodate the requested room dimensions.
-///
-/// Returns `Some((x, y, w, h))` if a valid placement is found.
-#[allow(clippy::too_many_arguments)]
-fn find_clear_rect_for_room(
-    grid: &[Vec<u8>],
-    hw: usize,
-    hl: usize,
-    search_x: usize,
-    search_y: usize,
-    search_w: usize,
-    search_h: usize,
-    want_w: usize,
-    want_h: usize,
-) -> Option<(usize, usize, usize, usize)> {
-    let mut best: Option<(usize, usize, usize, usize, usize)> = None; // (x, y, w, h, score)
-
-    let max_x = (search_x + search_w).min(hw);
-    let max_y = (search_y + search_h).min(hl);
-
-    let step = 2;
-    let mut y = search_y;
-    while y + MIN_ROOM_DIM <= max_y {
-        let mut x = search_x;
-        while x + MIN_ROOM_DIM <= max_x {
-            // Find max clear width from this position
-            let mut clear_w = 0usize;
-            for dx in 0..(max_x - x) {
-                if grid[x + dx][y] != CELL_EMPTY {
-                    break;
-                }
-                clear_w = dx + 1;
-            }
-            if clear_w < MIN_ROOM_DIM {
-                x += step;
-                continue;
-            }
-
-            // Find max clear height for this width
-            let rw = clear_w.min(want_w);
-            let mut clear_h = 0usize;
-            'height: for dy in 0..(max_y - y) {
-                for dx in 0..rw {
-                    if grid[x + dx][y + dy] != CELL_EMPTY {
-                        break 'height;
-                    }
-                }
-                clear_h = dy + 1;
-            }
-            if clear_h < MIN_ROOM_DIM {
-                x += step;
-                continue;
-            }
-
-            let rh = clear_h.min(want_h);
-            let area = rw * rh;
-
-            // Score: prefer larger area
-            let score = area;
-
-            if best.is_none() || score > best.unwrap().4 {
-                best = Some((x, y, rw, rh, score));
-            }
-
-            x += step;
-        }
-        y += step;
-    }
-
-    best.map(|(x, y, w, h, _)| (x, y, w, h))
-}
-
-/// Encode a room's grid footprint as packed axis-aligned rects.
-/// Scans grid within bbox for cells matching `cell_tag`, produces a minimal
-/// set of rects (greedy row-merge) encoded as [(x0,y0,x1,y1) u16] bytes.
-#[allow(clippy::too_many_arguments)]
-fn encode_cell_mask(
-    grid: &[Vec<u8>],
-    cell_tag: u8,
-    rx: usize,
-    ry: usize,
-    rw: usize,
-    rh: usize,
-    hw: usize,
-    hl: usize,
-) -> Vec<u8> {
-    // Greedy row-based rect merging
-    let mut rects: Vec<(u16, u16, u16, u16)> = Vec::new();
-    let mut used = vec![vec![false; rh]; rw];
-
-    for y in 0..rh {
-        let gy = ry + y;
-        if gy >= hl {
-            break;
-        }
-        let mut x = 0;
-        while x < rw {
-            let gx = rx + x;
-            if gx >= hw || used[x][y] || grid[gx][gy] != cell_tag {
-                x += 1;
-                continue;
-            }
-            // Find max width of this run
-            let mut run_w = 0;
-            while x + run_w < rw
-                && rx + x + run_w < hw
-                && !used[x + run_w][y]
-                && grid[rx + x + run_w][gy] == cell_tag
-            {
-                run_w += 1;
-            }
-            // Extend downward as far as all columns match
-            let mut run_h = 1;
-            'outer: while y + run_h < rh && ry + y + run_h < hl {
-                for dx in 0..run_w {
-                    if rx + x + dx >= hw
-                        || used[x + dx][y + run_h]
-                        || grid[rx + x + dx][ry + y + run_h] != cell_tag
-                    {
-                        break 'outer;
-                    }
-                }
-                run_h += 1;
-            }
-            // Mark used
-            for dy in 0..run_h {
-                for dx in 0..run_w {
-                    used[x + dx][y + dy] = true;
-                }
-            }
-            rects.push((
-                (rx + x) as u16,
-                (ry + y) as u16,
-                (rx + x + run_w) as u16,
-                (ry + y + run_h) as u16,
-            ));
-            x += run_w;
-        }
-    }
-
-    // Encode as bytes: 4 × u16 per rect = 8 bytes per rect
-    let mut bytes = Vec::with_capacity(rects.len() * 8);
-    for (x0, y0, x1, y1) in &rects {
-        bytes.extend_from_slice(&x0.to_le_bytes());
-        bytes.extend_from_slice(&y0.to_le_bytes());
-        bytes.extend_from_slice(&x1.to_le_bytes());
-        bytes.extend_from_slice(&y1.to_le_bytes());
+        .map(|r| RoomRect {
+            id: r.id,
+            deck: r.deck,
+            x: r.x - r.width / 2.0,
+            y: r.y - r.height / 2.0,
+            width: r.width,
+            height: r.height,
+            room_type: r.room_type,
+            capacity: r.capacity,
+        })
+        .collect();
+    let doors: Vec<DoorInfo> = ctx
+        .db
+        .door()
+        .iter()
+        .map(|d| DoorInfo {
+            id: d.id,
+            room_a: d.room_a,
+            room_b: d.room_b,
+            door_x: d.door_x,
+            door_y: d.door_y,
+            wall_a: d.wall_a,
+            wall_b: d.wall_b,
+        })
+        .collect();
+    for e in check_shaft_alignment(&stacks, &rooms, &doors) {
+        log::error!("Shaft alignment: {}", e.message);
     }
-    bytes
 }
@@ -2,31 +2,65 @@
 //!
 //! Graph-first ship layout pipeline:
 //!   1. build_ship_graph      -- creates GraphNode + GraphEdge entries
-//!   2. layout_ship           -- creates Room, Corridor, Door, VerticalShaft from graph
-//!   3. generate_ship_systems -- creates ShipSystem, Subsystem, SystemComponent, InfraEdge
-//!   4. generate_atmospheres  -- per-deck atmosphere state
-//!   5. generate_crew         -- crew members
-//!   6. generate_passengers   -- passengers
+//!   2. layout_ship           -- lays out the graph with `progship_logic::genlib::layout`
+//!      into Room, Corridor, Door, HullHatch and VerticalShaft rows
+//!   3. generate_furniture    -- furniture inside each room plus activity anchors
+//!   4. generate_ship_systems -- creates ShipSystem, Subsystem, SystemComponent, InfraEdge,
+//!      AirDuct for the selected system variants (see `progship_logic::outfit`), then
+//!      generate_coolant_loop sizes the CoolantLoop's radiators; a scripted
+//!      scenario's worn subsystems are applied with wear_subsystems
+//!   5. generate_cargo        -- CargoItem lots stowed in the holds
+//!   6. generate_food_chain   -- Crop beds in hydroponics, MealBuffer stocks in the galleys,
+//!      Garden plantings in the arboretums
+//!   7. generate_shuttles     -- fueled Shuttle rows docked in the shuttle bays
+//!   8. generate_decks        -- named, zone-themed Deck rows
+//!   9. generate_atmospheres  -- per-room atmosphere state
+//!  10. generate_crew         -- crew members
+//!  11. generate_passengers   -- passengers in households, with family ties
+//!  12. refresh_evacuation_routes -- EvacuationRoute steps to the muster stations
+//!  13. record_generation_stats -- GenerationStats layout metrics
+//!  14. fast_forward          -- optional warm start (see `init_warm_ship`)
+//!
+//! Ships of `LAZY_GENERATION_MIN_POPULATION` or more run steps 2-3 for deck 0
+//! only; the scheduled `generate_pending_deck` reducer lays out and furnishes
+//! one more deck per call and runs steps 4-9 after the last, then boards
+//! `BOARDING_BATCH` people per call (steps 10-11) and runs steps 12-14 after
+//! the last batch.
 //!
 //! Uses progship-logic for population sizing and supply manifest calculation.
 
 use crate::tables::*;
+use progship_logic::genlib::hull::TaperCurve;
+use progship_logic::genlib::layout::CorridorTopology;
 use spacetimedb::{reducer, ReducerContext, Table};
+use std::ops::Range;
 
-mod doors;
-mod facilities;
+mod cargo;
+mod decks;
+mod food;
+mod furniture;
 mod graph;
 pub(crate) mod hull;
 mod infrastructure;
 mod people;
+mod shuttles;
+mod stats;
 mod systems;
 pub mod traits;
-mod treemap;
 
+use cargo::generate_cargo;
+use decks::generate_decks;
+use food::generate_food_chain;
+use furniture::generate_furniture;
 use graph::build_ship_graph;
 use infrastructure::layout_ship;
+pub(crate) use people::spawn_newborn;
 use people::{generate_crew, generate_passengers};
-use systems::{generate_atmospheres, generate_ship_systems};
+use shuttles::generate_shuttles;
+use stats::record_generation_stats;
+use systems::{
+    generate_atmospheres, generate_coolant_loop, generate_ship_systems, wear_subsystems,
+};
 
 const CORRIDOR_WIDTH: f32 = 6.0;
 const CORRIDOR_HALF: f32 = CORRIDOR_WIDTH / 2.0;
@@ -78,6 +112,7 @@ fn base_area(function: u8) -> f32 {
         | room_types::MENTAL_HEALTH
         | room_types::MORGUE
         | room_types::MEDBAY => 35.0,
+        room_types::CRYO_BAY => 200.0,
         room_types::SURGERY | room_types::ELECTRONICS_LAB | room_types::ROBOTICS_BAY => 55.0,
         room_types::NURSERY | room_types::SCHOOL | room_types::CHAPEL | room_types::HOLODECK => {
             60.0
@@ -101,7 +136,47 @@ fn compute_room_dims(required_area: f32) -> (f32, f32) {
     (w.max(4.0), h.max(4.0))
 }
 
+/// Ships at least this populous lay out deck 0 in `init_ship` and stream the
+/// remaining decks through scheduled `generate_pending_deck` calls, keeping
+/// each reducer call within time limits.
+const LAZY_GENERATION_MIN_POPULATION: u32 = 2000;
+
+/// People a lazily generated ship boards per scheduled `generate_pending_deck`
+/// call once its decks are laid out.
+const BOARDING_BATCH: u32 = 500;
+
+/// Mission parameters, outfit and supplies shared by the generation steps.
+struct ShipPlan {
+    mission: progship_logic::mission::MissionConfig,
+    outfit: progship_logic::outfit::ShipOutfit,
+    supplies: progship_logic::supplies::SupplyManifest,
+}
+
+fn plan_ship(total_pop: u32, seed: u64) -> ShipPlan {
+    // Use progship-logic to compute population profile and supply manifest
+    let mission = progship_logic::mission::MissionConfig {
+        seed,
+        ..Default::default()
+    };
+    let overrides = progship_logic::config::SystemOverrides::default();
+    let systems = progship_logic::config::select_systems(&mission, &overrides);
+    let population = progship_logic::population::compute_population(&mission, &systems);
+    let supplies =
+        progship_logic::supplies::compute_supply_manifest(&mission, &systems, &population);
+    let outfit = progship_logic::outfit::outfit_ship(&systems, mission.propulsion, total_pop);
+    ShipPlan {
+        mission,
+        outfit,
+        supplies,
+    }
+}
+
 /// Initialize a full ship with rooms, crew, passengers, systems, and atmosphere
+///
+/// Large ships (see [`LAZY_GENERATION_MIN_POPULATION`]) lay out deck 0 here
+/// and the rest deck by deck afterwards; `ship_config.pending_decks` counts
+/// the decks still to come. Once it reaches 0 crew and passengers board
+/// [`BOARDING_BATCH`] at a time, counted down by `ship_config.pending_people`.
 #[reducer]
 pub fn init_ship(
     ctx: &ReducerContext,
@@ -109,6 +184,116 @@ pub fn init_ship(
     deck_count: u32,
     crew_count: u32,
     passenger_count: u32,
+    corridor_topology: u8,
+    hull_taper: u8,
+) {
+    init_warm_ship(
+        ctx,
+        name,
+        deck_count,
+        crew_count,
+        passenger_count,
+        corridor_topology,
+        hull_taper,
+        0.0,
+    );
+}
+
+/// Initialize a ship like [`init_ship`], then fast-forward it
+/// `warm_start_days` days (up to 30) so players board a ship with
+/// established relationships, wear and history
+#[reducer]
+#[allow(clippy::too_many_arguments)]
+pub fn init_warm_ship(
+    ctx: &ReducerContext,
+    name: String,
+    deck_count: u32,
+    crew_count: u32,
+    passenger_count: u32,
+    corridor_topology: u8,
+    hull_taper: u8,
+    warm_start_days: f32,
+) {
+    let Some(topology) = CorridorTopology::from_u8(corridor_topology) else {
+        log::warn!("Unknown corridor topology {}", corridor_topology);
+        return;
+    };
+    let Some(taper) = TaperCurve::from_u8(hull_taper) else {
+        log::warn!("Unknown hull taper {}", hull_taper);
+        return;
+    };
+    generate_ship(
+        ctx,
+        name,
+        deck_count,
+        crew_count,
+        passenger_count,
+        topology,
+        taper,
+        progship_logic::scenarios::default_seed(),
+        warm_start_days,
+        "",
+        difficulties::NORMAL,
+        crew_count + passenger_count >= LAZY_GENERATION_MIN_POPULATION,
+    );
+}
+
+/// Initialize a ship from the scenario gallery by ID (see
+/// `progship_logic::scenarios::SCENARIOS`)
+#[reducer]
+pub fn init_scenario(ctx: &ReducerContext, scenario_id: String) {
+    let Some(scenario) = progship_logic::scenarios::find_scenario(&scenario_id) else {
+        log::warn!("Unknown scenario '{}'", scenario_id);
+        return;
+    };
+    init_from_scenario(ctx, scenario);
+}
+
+/// Initialize today's daily ship: the same ship for everyone on a given UTC
+/// date, for comparing runs
+#[reducer]
+pub fn init_daily_ship(ctx: &ReducerContext) {
+    let day = progship_logic::scenarios::day_number(ctx.timestamp.to_micros_since_unix_epoch());
+    log::info!(
+        "Daily ship for {}",
+        progship_logic::scenarios::date_label(day)
+    );
+    init_from_scenario(ctx, &progship_logic::scenarios::daily_scenario(day));
+}
+
+fn init_from_scenario(ctx: &ReducerContext, scenario: &progship_logic::scenarios::Scenario) {
+    generate_ship(
+        ctx,
+        scenario.name.to_string(),
+        scenario.deck_count,
+        scenario.crew_count,
+        scenario.passenger_count,
+        CorridorTopology::CentralSpine,
+        TaperCurve::default(),
+        scenario.seed,
+        scenario.warm_start_days,
+        scenario.id,
+        scenario.difficulty,
+        scenario.crew_count + scenario.passenger_count >= LAZY_GENERATION_MIN_POPULATION,
+    );
+}
+
+/// Generate a ship, all at once or (`lazy`) deck 0 now and the rest through
+/// scheduled [`generate_pending_deck`] jobs, warm starting it once populated.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn generate_ship(
+    ctx: &ReducerContext,
+    name: String,
+    deck_count: u32,
+    crew_count: u32,
+    passenger_count: u32,
+    topology: CorridorTopology,
+    taper: TaperCurve,
+    seed: u64,
+    warm_start_days: f32,
+    scenario: &str,
+    difficulty: u8,
+    lazy: bool,
 ) {
     log::info!(
         "Initializing ship: {} ({} decks, {} crew, {} passengers)",
@@ -123,42 +308,25 @@ pub fn init_ship(
         return;
     }
 
-    // Use progship-logic to compute population profile and supply manifest
-    let mission = progship_logic::mission::MissionConfig::default();
-    let overrides = progship_logic::config::SystemOverrides::default();
-    let systems = progship_logic::config::select_systems(&mission, &overrides);
-    let population = progship_logic::population::compute_population(&mission, &systems);
-    let supplies =
-        progship_logic::supplies::compute_supply_manifest(&mission, &systems, &population);
+    let total_pop = crew_count + passenger_count;
+    let ShipPlan {
+        outfit, supplies, ..
+    } = plan_ship(total_pop, seed);
 
     // Scale supplies to game units (tons → game units, roughly 1:1000)
     let scale = 1000.0;
 
-    // Ship config
-    ctx.db.ship_config().insert(ShipConfig {
-        id: 0,
-        name: name.clone(),
-        deck_count,
-        crew_count,
-        passenger_count,
-        sim_time: 0.0,
-        time_scale: 1.0,
-        paused: false,
-        death_count: 0,
-        rationing_level: 0,
-    });
-
     // Resources from supply manifest
     let reserve_factor = 1.5; // cap = stockpile × factor
     ctx.db.ship_resources().insert(ShipResources {
         id: 0,
-        power: (progship_logic::config::total_power_draw(&systems) as f64 * 1.2) as f32,
+        power: outfit.power_demand() * 1.2,
         water: (supplies.water.stockpile_tons * scale) as f32,
         oxygen: (supplies.oxygen.stockpile_tons * scale) as f32,
         food: (supplies.food.stockpile_tons * scale) as f32,
         fuel: (supplies.fuel.stockpile_tons * scale) as f32,
         spare_parts: (supplies.spare_parts.stockpile_tons * scale) as f32,
-        power_cap: (progship_logic::config::total_power_draw(&systems) as f64 * 1.5) as f32,
+        power_cap: outfit.power_demand() * 1.5,
         water_cap: (supplies.water.stockpile_tons * scale * reserve_factor) as f32,
         oxygen_cap: (supplies.oxygen.stockpile_tons * scale * reserve_factor) as f32,
         food_cap: (supplies.food.stockpile_tons * scale * reserve_factor) as f32,
@@ -167,16 +335,197 @@ pub fn init_ship(
     });
 
     build_ship_graph(ctx, deck_count, crew_count, passenger_count);
-    layout_ship(ctx, deck_count, crew_count + passenger_count);
-    generate_ship_systems(ctx);
-    generate_atmospheres(ctx, deck_count);
-    generate_crew(ctx, crew_count);
-    generate_passengers(ctx, passenger_count, deck_count);
+    let deck_count = if lazy {
+        let deck_count = layout_ship(ctx, deck_count, total_pop, topology, taper, 0..1);
+        generate_furniture(ctx, 0..1);
+        deck_count
+    } else {
+        let deck_count = layout_ship(ctx, deck_count, total_pop, topology, taper, ..);
+        generate_furniture(ctx, ..);
+        deck_count
+    };
+    let (pending_decks, pending_people) = if lazy {
+        (deck_count.saturating_sub(1), total_pop)
+    } else {
+        (0, 0)
+    };
+
+    // Ship config
+    ctx.db.ship_config().insert(ShipConfig {
+        id: 0,
+        name: name.clone(),
+        deck_count,
+        crew_count,
+        passenger_count,
+        sim_time: 0.0,
+        time_scale: 1.0,
+        paused: false,
+        death_count: 0,
+        rationing_level: 0,
+        rationing_since: 0.0,
+        pending_decks,
+        pending_people,
+        warm_start_days: progship_logic::warm_start::warm_start_days(warm_start_days),
+        seed,
+        scenario: scenario.to_string(),
+        difficulty,
+        corridor_topology: topology as u8,
+        hull_taper: taper as u8,
+    });
+
+    if pending_decks > 0 {
+        log::info!(
+            "Ship '{}': deck 1 laid out, {} pending",
+            name,
+            pending_decks
+        );
+        schedule_deck(ctx, 1);
+        return;
+    }
+    equip_ship(ctx);
+    if lazy {
+        schedule_deck(ctx, deck_count);
+        return;
+    }
+    board_people(ctx, 0..total_pop);
+    finish_ship(ctx);
+}
+
+fn schedule_deck(ctx: &ReducerContext, deck: u32) {
+    ctx.db.deck_generation_job().insert(DeckGenerationJob {
+        scheduled_id: 0,
+        scheduled_at: ctx.timestamp.into(),
+        deck,
+    });
+}
+
+/// Lay out and furnish the next pending deck of a lazily generated ship, or
+/// board its next batch of people (scheduled)
+///
+/// After the last deck the ship's systems are generated; after the last
+/// batch of people the ship is finished and warm started.
+#[reducer]
+pub fn generate_pending_deck(ctx: &ReducerContext, job: DeckGenerationJob) {
+    if ctx.sender != ctx.identity() {
+        log::warn!("generate_pending_deck is scheduled by the module only");
+        return;
+    }
+    let Some(mut config) = ctx.db.ship_config().id().find(0) else {
+        return;
+    };
+    let total_pop = config.crew_count + config.passenger_count;
+
+    if config.pending_decks > 0 {
+        layout_ship(
+            ctx,
+            config.deck_count,
+            total_pop,
+            CorridorTopology::from_u8(config.corridor_topology).unwrap_or_default(),
+            TaperCurve::from_u8(config.hull_taper).unwrap_or_default(),
+            job.deck..=job.deck,
+        );
+        generate_furniture(ctx, job.deck as i32..=job.deck as i32);
+        config.pending_decks -= 1;
+        ctx.db.ship_config().id().update(config.clone());
+        log::info!(
+            "Deck {} laid out, {} pending",
+            job.deck + 1,
+            config.pending_decks
+        );
+
+        if config.pending_decks > 0 {
+            schedule_deck(ctx, job.deck + 1);
+        } else {
+            equip_ship(ctx);
+            schedule_deck(ctx, config.deck_count);
+        }
+        return;
+    }
+    if config.pending_people == 0 {
+        return;
+    }
+
+    let first = total_pop.saturating_sub(config.pending_people);
+    let end = first.saturating_add(BOARDING_BATCH).min(total_pop);
+    board_people(ctx, first..end);
+    config.pending_people = total_pop - end;
+    ctx.db.ship_config().id().update(config.clone());
+    log::info!(
+        "{} of {} people aboard, {} pending",
+        end,
+        total_pop,
+        config.pending_people
+    );
+
+    if config.pending_people > 0 {
+        schedule_deck(ctx, config.deck_count);
+    } else {
+        finish_ship(ctx);
+    }
+}
+
+/// Everything between the room layout and the people: systems, cargo,
+/// shuttles, robots, decks and atmospheres.
+fn equip_ship(ctx: &ReducerContext) {
+    let Some(config) = ctx.db.ship_config().id().find(0) else {
+        return;
+    };
+    let total_pop = config.crew_count + config.passenger_count;
+    let ShipPlan {
+        outfit, supplies, ..
+    } = plan_ship(total_pop, config.seed);
+
+    generate_ship_systems(ctx, &outfit);
+    generate_coolant_loop(ctx, total_pop);
+    crate::simulation::size_power_cables(ctx);
+    crate::simulation::size_water_pipes(ctx);
+    if let Some(script) = progship_logic::scenarios::scenario_script(&config.scenario) {
+        wear_subsystems(ctx, script.worn_subsystems);
+    }
+    generate_cargo(ctx, &supplies, total_pop);
+    generate_food_chain(ctx, total_pop);
+    generate_shuttles(ctx);
+    crate::simulation::build_robots(ctx);
+    generate_decks(ctx, config.deck_count);
+    generate_atmospheres(ctx);
+}
+
+/// Board people `batch` of the ship, by index: the crew first, then the
+/// passengers.
+fn board_people(ctx: &ReducerContext, batch: Range<u32>) {
+    let Some(config) = ctx.db.ship_config().id().find(0) else {
+        return;
+    };
+    let crew = config.crew_count;
+    generate_crew(ctx, crew, batch.start.min(crew)..batch.end.min(crew));
+    let mission = plan_ship(crew + config.passenger_count, config.seed).mission;
+    generate_passengers(
+        ctx,
+        config.passenger_count,
+        config.deck_count,
+        &mission.demographics,
+        batch.start.saturating_sub(crew)..batch.end.saturating_sub(crew),
+    );
+}
+
+/// Everything once everyone is aboard: the cryo sleepers, evacuation routes
+/// and generation stats, then the warm start.
+fn finish_ship(ctx: &ReducerContext) {
+    let Some(config) = ctx.db.ship_config().id().find(0) else {
+        return;
+    };
+    let total_pop = config.crew_count + config.passenger_count;
+    let ShipPlan { supplies, .. } = plan_ship(total_pop, config.seed);
+
+    crate::simulation::fill_cryo_bays(ctx);
+    crate::simulation::refresh_evacuation_routes(ctx);
+    record_generation_stats(ctx, total_pop);
+    crate::simulation::fast_forward(ctx, config.warm_start_days);
 
     log::info!(
         "Ship '{}' initialized with {} people (supplies: {:.0}t food, {:.0}t water, {:.0}t fuel)",
-        name,
-        crew_count + passenger_count,
+        config.name,
+        total_pop,
         supplies.food.stockpile_tons,
         supplies.water.stockpile_tons,
         supplies.fuel.stockpile_tons,
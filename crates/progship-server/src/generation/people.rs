@@ -1,99 +1,27 @@
 //! Crew and passenger generation with name pools and RNG utilities.
 //!
-//! Generates crew members with departments/ranks/skills and passengers with
-//! cabin classes/professions. Uses deterministic name distribution.
+//! Generates crew members with departments/ranks/skills, staffing the chain
+//! of command first, and passengers with cabin classes/professions.
+//! Passengers travel in households (see [`plan_households`]) that share a
+//! cabin, a Family row and pre-seeded relationships; ages come from the
+//! mission's demographic curve, and everyone rolls a backstory and quirks
+//! (see [`pick_traits`]). Names come from culture-weighted pools in the
+//! culture_mix proportions (see [`NameGenerator`]); a household shares one
+//! culture and surname. Babies born aboard join their parents' household
+//! (see [`spawn_newborn`]).
 
 use crate::tables::*;
+use progship_logic::backstory::pick_traits;
+use progship_logic::command::plan_command_chain;
+use progship_logic::demographics::{birth_date, DemographicCurve};
+use progship_logic::households::{kinship, plan_households, tie};
+use progship_logic::inventory::starting_items;
+use progship_logic::lifecycle::{inherit_trait, newborn_name};
+use progship_logic::names::{default_mix, NameGenerator};
+use progship_logic::population::crew_ranks;
+use progship_logic::skills::{crew_skills, passenger_skills};
 use spacetimedb::{ReducerContext, Table};
-
-// Name pools for generation (deterministic, no rand needed)
-pub(super) const GIVEN_NAMES: &[&str] = &[
-    "Alex", "Jordan", "Morgan", "Casey", "Riley", "Quinn", "Avery", "Taylor", "Skyler", "Kai",
-    "Rowan", "Sage", "River", "Phoenix", "Eden", "Harper", "Blake", "Logan", "Reese", "Cameron",
-    "Dakota", "Emery", "Finley", "Hayden", "Jaden", "Kendall", "Lane", "Marley", "Noel", "Parker",
-    "Remy", "Shay", "Tatum", "Val", "Wren", "Zion", "Arden", "Bay", "Cedar", "Drew", "Ellis",
-    "Flynn", "Grey", "Hollis", "Indigo", "Jules", "Kit", "Lark", "Milan", "Nico", "Oakley",
-    "Peyton", "Raven", "Sol", "Teagan", "Uri", "Vesper", "Winter", "Xen", "Yael", "Zephyr", "Ash",
-    "Briar", "Cove", "Dune", "Ever", "Fern", "Glen", "Haven", "Ivy", "Jade", "Kestrel", "Linden",
-    "Moss", "North", "Onyx", "Pine", "Rain", "Stone", "Thorn",
-];
-
-pub(super) const FAMILY_NAMES: &[&str] = &[
-    "Chen",
-    "Nakamura",
-    "Petrov",
-    "Santos",
-    "Kim",
-    "Hansen",
-    "Okafor",
-    "Moreau",
-    "Singh",
-    "Torres",
-    "Andersen",
-    "Park",
-    "Johansson",
-    "Fernandez",
-    "Larsson",
-    "Novak",
-    "Ibrahim",
-    "Costa",
-    "Yamamoto",
-    "Kowalski",
-    "Bakker",
-    "Tanaka",
-    "Müller",
-    "Svensson",
-    "Rossi",
-    "Fischer",
-    "Jansen",
-    "Dubois",
-    "Schmidt",
-    "Popov",
-    "Mendez",
-    "Nguyen",
-    "Ali",
-    "Jensen",
-    "Virtanen",
-    "Colombo",
-    "Takahashi",
-    "Olsen",
-    "Nieminen",
-    "Bianchi",
-    "Wagner",
-    "Eriksson",
-    "Morel",
-    "Ivanov",
-    "Ortiz",
-    "Reyes",
-    "Hoffmann",
-    "Nilsson",
-    "Russo",
-    "Delgado",
-    "Berger",
-    "Wolf",
-    "Richter",
-    "Stein",
-    "Hahn",
-    "Krause",
-    "Bauer",
-    "Maier",
-    "Vogt",
-    "Sato",
-    "Watanabe",
-    "Suzuki",
-    "Kato",
-    "Yoshida",
-    "Yamada",
-    "Sasaki",
-    "Hayashi",
-    "Mori",
-    "Ikeda",
-    "Abe",
-    "Ishikawa",
-    "Ogawa",
-    "Goto",
-    "Hasegawa",
-];
+use std::ops::Range;
 
 pub(super) struct SimpleRng {
     state: u64,
@@ -126,7 +54,42 @@ impl SimpleRng {
     }
 }
 
-pub(super) fn generate_crew(ctx: &ReducerContext, count: u32) {
+/// Population mix for names: the pool defaults with any culture_mix rows
+/// applied.
+fn culture_mix(ctx: &ReducerContext) -> Vec<f32> {
+    let mut mix = default_mix();
+    for row in ctx.db.culture_mix().iter() {
+        if let Some(weight) = mix.get_mut(row.culture as usize) {
+            *weight = row.weight;
+        }
+    }
+    mix
+}
+
+/// Roll a person's backstory and quirks, seeded by their ID.
+fn insert_traits(ctx: &ReducerContext, person_id: u64) {
+    let mut rng = SimpleRng::from_name(&format!("traits-{person_id}"));
+    ctx.db.person_trait().insert(PersonTrait {
+        person_id,
+        traits: pick_traits(|| rng.next_f32()),
+    });
+}
+
+/// Pack what a person boards with, seeded by their ID.
+fn insert_items(ctx: &ReducerContext, person_id: u64, department: Option<u8>) {
+    let mut rng = SimpleRng::from_name(&format!("items-{person_id}"));
+    for kind in starting_items(department, rng.next_f32()) {
+        crate::simulation::add_item(ctx, person_id, kind, 0.0);
+    }
+}
+
+/// Generate crew `batch` of a crew of `count`. Earlier batches' names are
+/// dealt again without inserting anyone, so a crew boarded over several calls
+/// matches one boarded at once.
+pub(super) fn generate_crew(ctx: &ReducerContext, count: u32, batch: Range<u32>) {
+    if batch.is_empty() {
+        return;
+    }
     let dept_cycle = [
         departments::ENGINEERING,
         departments::MEDICAL,
@@ -136,33 +99,56 @@ pub(super) fn generate_crew(ctx: &ReducerContext, count: u32) {
         departments::COMMAND,
     ];
 
-    for i in 0..count {
-        let given_idx = i as usize % GIVEN_NAMES.len();
-        let family_idx = (i as usize / GIVEN_NAMES.len() + i as usize * 7) % FAMILY_NAMES.len();
+    // Ranks run highest first, so the first crew fill the command posts
+    let crew_rank = crew_ranks(count);
+    let chain = plan_command_chain(count);
+    // Posts are filled in chain order, so earlier batches' holders sort first
+    let mut post_holders: Vec<u64> = ctx.db.command_chain().iter().map(|c| c.person_id).collect();
+    post_holders.sort_unstable();
+    let mut names = NameGenerator::new(&culture_mix(ctx));
+
+    for i in 0..batch.end.min(count) {
+        let culture = names.culture();
+        let given_name = names.given(culture).to_string();
+        let family_name = names.family(culture).to_string();
+        if i < batch.start {
+            continue;
+        }
+        let post = chain.get(i as usize);
+        let rank = crew_rank[i as usize];
+        let crew_age = 22 + rank * 3 + (i * 7 % 24) as u8;
 
         let person_id = ctx
             .db
             .person()
             .insert(Person {
                 id: 0,
-                given_name: GIVEN_NAMES[given_idx].to_string(),
-                family_name: FAMILY_NAMES[family_idx].to_string(),
+                given_name,
+                family_name,
                 is_crew: true,
                 is_player: false,
                 is_alive: true,
                 owner_identity: None,
+                age: crew_age,
+                birth_date: birth_date(crew_age, (i as f32 * 0.381_966) % 1.0),
+                life_stage: progship_logic::constants::life_stages::from_age(crew_age),
+                clearance: progship_logic::security::clearance(true, rank),
             })
             .id;
 
-        let dept = dept_cycle[i as usize % dept_cycle.len()];
-        let rank = if i < 3 {
-            ranks::LIEUTENANT
-        } else if i < 10 {
-            ranks::SPECIALIST
-        } else {
-            ranks::CREWMAN
-        };
-        let shift = (i % 3) as u8;
+        let dept = post
+            .map(|p| p.department)
+            .unwrap_or(dept_cycle[i as usize % dept_cycle.len()]);
+        let shift = post.map(|p| p.shift).unwrap_or((i % 3) as u8);
+        if let Some(post) = post {
+            ctx.db.command_chain().insert(CommandChain {
+                person_id,
+                post: post.post,
+                department: post.department,
+                superior_id: post.superior.map(|s| post_holders[s]),
+            });
+            post_holders.push(person_id);
+        }
 
         // Assign duty station based on department — distribute across all matching rooms
         let duty_room_type = match dept {
@@ -226,6 +212,7 @@ pub(super) fn generate_crew(ctx: &ReducerContext, count: u32) {
             agreeableness: 0.4 + ((base * 7.0) % 1.0) * 0.3,
             neuroticism: 0.2 + ((base * 11.0) % 1.0) * 0.3,
         });
+        insert_traits(ctx, person_id);
 
         ctx.db.crew().insert(Crew {
             person_id,
@@ -235,23 +222,17 @@ pub(super) fn generate_crew(ctx: &ReducerContext, count: u32) {
             duty_station_id,
             on_duty: shift == shifts::ALPHA,
         });
+        insert_items(ctx, person_id, Some(dept));
 
-        let (eng, med, pilot, sci, soc, combat) = match dept {
-            departments::ENGINEERING => (0.7, 0.1, 0.2, 0.3, 0.2, 0.1),
-            departments::MEDICAL => (0.1, 0.8, 0.1, 0.4, 0.5, 0.1),
-            departments::SCIENCE => (0.3, 0.2, 0.1, 0.8, 0.3, 0.1),
-            departments::SECURITY => (0.2, 0.2, 0.2, 0.1, 0.3, 0.8),
-            departments::COMMAND => (0.3, 0.2, 0.5, 0.3, 0.6, 0.3),
-            _ => (0.3, 0.2, 0.2, 0.2, 0.3, 0.2),
-        };
+        let profile = crew_skills(dept, rank, base);
         ctx.db.skills().insert(Skills {
             person_id,
-            engineering: eng,
-            medical: med,
-            piloting: pilot,
-            science: sci,
-            social: soc,
-            combat,
+            engineering: profile.engineering,
+            medical: profile.medical,
+            piloting: profile.piloting,
+            science: profile.science,
+            social: profile.social,
+            combat: profile.combat,
         });
 
         ctx.db.activity().insert(Activity {
@@ -260,24 +241,26 @@ pub(super) fn generate_crew(ctx: &ReducerContext, count: u32) {
             started_at: 0.0,
             duration: 0.5,
             target_room_id: None,
+            animation_state: postures::STANDING,
+            target_anchor_id: None,
+            target_person_id: None,
         });
     }
 }
 
-pub(super) fn generate_passengers(ctx: &ReducerContext, count: u32, _deck_count: u32) {
-    let professions = [
-        "Colonist",
-        "Scientist",
-        "Engineer",
-        "Teacher",
-        "Doctor",
-        "Artist",
-        "Farmer",
-        "Merchant",
-        "Writer",
-        "Architect",
-    ];
-
+/// Seat the households of `count` passengers whose first member falls in
+/// `batch`, dealing earlier batches' names and cabins again so passengers
+/// boarded over several calls match those boarded at once.
+pub(super) fn generate_passengers(
+    ctx: &ReducerContext,
+    count: u32,
+    _deck_count: u32,
+    demographics: &DemographicCurve,
+    batch: Range<u32>,
+) {
+    if batch.is_empty() {
+        return;
+    }
     // Collect all cabin/quarters rooms for passenger distribution
     let cabin_room_types = [
         room_types::CABIN_SINGLE,
@@ -311,68 +294,53 @@ pub(super) fn generate_passengers(ctx: &ReducerContext, count: u32, _deck_count:
         &passenger_rooms
     };
 
-    for i in 0..count {
-        let given_idx = (i as usize + 40) % GIVEN_NAMES.len();
-        let family_idx = (i as usize * 13 + 5) % FAMILY_NAMES.len();
-
-        let person_id = ctx
-            .db
-            .person()
-            .insert(Person {
-                id: 0,
-                given_name: GIVEN_NAMES[given_idx].to_string(),
-                family_name: FAMILY_NAMES[family_idx].to_string(),
-                is_crew: false,
-                is_player: false,
-                is_alive: true,
-                owner_identity: None,
-            })
-            .id;
+    // Couples and families share a cabin, preferring rooms built for more than one
+    let shared_room_types = [
+        room_types::FAMILY_SUITE,
+        room_types::CABIN_DOUBLE,
+        room_types::VIP_SUITE,
+        room_types::QUARTERS_PASSENGER,
+    ];
+    let shared_rooms: Vec<u32> = ctx
+        .db
+        .room()
+        .iter()
+        .filter(|r| shared_room_types.contains(&r.room_type))
+        .map(|r| r.id)
+        .collect();
+    let shared_rooms = if shared_rooms.is_empty() {
+        pax_rooms
+    } else {
+        &shared_rooms
+    };
 
-        // Distribute passengers round-robin across available rooms
-        let assigned_room_id = if pax_rooms.is_empty() {
-            0
+    let mut names = NameGenerator::new(&culture_mix(ctx));
+    let (mut i, mut singles, mut families) = (0u32, 0usize, 0usize);
+    let (mut seated, mut seated_families) = (0u32, 0usize);
+    for household in &plan_households(count) {
+        if i >= batch.end {
+            break;
+        }
+        // The whole household shares a surname and naming culture
+        let culture = names.culture();
+        let family_name = names.family(culture);
+        let home_room_id = if household.size() > 1 {
+            families += 1;
+            shared_rooms.get((families - 1) % shared_rooms.len().max(1))
         } else {
-            pax_rooms[i as usize % pax_rooms.len()]
-        };
-        let (rx, ry, rw, rh) = ctx
-            .db
-            .room()
-            .id()
-            .find(assigned_room_id)
-            .map(|r| (r.x, r.y, r.width, r.height))
-            .unwrap_or((0.0, 0.0, 24.0, 18.0));
-        let spread_x = ((i as f32 * 1.7) % (rw - 2.0).max(1.0)) - ((rw - 2.0).max(1.0) / 2.0);
-        let spread_y = ((i as f32 * 2.3) % (rh - 2.0).max(1.0)) - ((rh - 2.0).max(1.0) / 2.0);
-        ctx.db.position().insert(Position {
-            person_id,
-            room_id: assigned_room_id,
-            x: rx + spread_x.clamp(-rw / 2.0 + 0.5, rw / 2.0 - 0.5),
-            y: ry + spread_y.clamp(-rh / 2.0 + 0.5, rh / 2.0 - 0.5),
-            z: 0.0,
-        });
-
-        ctx.db.needs().insert(Needs {
-            person_id,
-            hunger: 0.2 + (i % 4) as f32 * 0.05,
-            fatigue: 0.15 + (i % 5) as f32 * 0.04,
-            social: 0.4 + (i % 3) as f32 * 0.1,
-            comfort: 0.2 + (i % 6) as f32 * 0.03,
-            hygiene: 0.15 + (i % 7) as f32 * 0.02,
-            health: 1.0,
-            morale: 0.7 + (i % 4) as f32 * 0.06,
-        });
-
-        let base = ((i + 40) as f32 * 0.618_034) % 1.0;
-        ctx.db.personality().insert(Personality {
-            person_id,
-            openness: 0.4 + base * 0.3,
-            conscientiousness: 0.3 + ((base * 3.0) % 1.0) * 0.4,
-            extraversion: 0.4 + ((base * 5.0) % 1.0) * 0.3,
-            agreeableness: 0.5 + ((base * 7.0) % 1.0) * 0.2,
-            neuroticism: 0.2 + ((base * 11.0) % 1.0) * 0.4,
-        });
-
+            singles += 1;
+            pax_rooms.get((singles - 1) % pax_rooms.len().max(1))
+        }
+        .copied()
+        .unwrap_or(0);
+        if i < batch.start {
+            for _ in &household.roles {
+                names.given(culture);
+            }
+            i += household.size() as u32;
+            continue;
+        }
+        // The whole household travels in the head's cabin class
         let cabin = if i < count / 10 {
             cabin_classes::FIRST
         } else if i < count / 2 {
@@ -380,73 +348,314 @@ pub(super) fn generate_passengers(ctx: &ReducerContext, count: u32, _deck_count:
         } else {
             cabin_classes::STEERAGE
         };
-
-        ctx.db.passenger().insert(Passenger {
-            person_id,
-            cabin_class: cabin,
-            destination: "Kepler-442b".to_string(),
-            profession: professions[i as usize % professions.len()].to_string(),
+        let family_id = (household.size() > 1).then(|| {
+            ctx.db
+                .family()
+                .insert(Family {
+                    id: 0,
+                    family_name: family_name.to_string(),
+                    home_room_id,
+                })
+                .id
         });
 
-        ctx.db.skills().insert(Skills {
-            person_id,
-            engineering: 0.1 + ((i as f32 * 0.3) % 0.3),
-            medical: 0.1 + ((i as f32 * 0.2) % 0.2),
-            piloting: 0.05,
-            science: 0.2 + ((i as f32 * 0.25) % 0.3),
-            social: 0.3 + ((i as f32 * 0.15) % 0.3),
-            combat: 0.05,
-        });
+        let mut members = Vec::with_capacity(household.size());
+        for &role in &household.roles {
+            let age = demographics.sample_age(role, ((i as f32 + 0.5) * 0.618_034) % 1.0);
+            let name = (names.given(culture), family_name);
+            let person_id = spawn_passenger(ctx, i, age, name, home_room_id, cabin);
+            if let Some(family_id) = family_id {
+                ctx.db.family_member().insert(FamilyMember {
+                    person_id,
+                    family_id,
+                    role,
+                });
+            }
+            members.push(person_id);
+            i += 1;
+            seated += 1;
+        }
+        seated_families += usize::from(family_id.is_some());
+        for tie in kinship(household) {
+            ctx.db.relationship().insert(Relationship {
+                id: 0,
+                person_a: members[tie.a],
+                person_b: members[tie.b],
+                relationship_type: tie.relationship_type,
+                strength: tie.strength,
+                familiarity: tie.familiarity,
+                last_interaction: 0.0,
+            });
+        }
+    }
+    log::info!(
+        "Seated {} passengers in {} families",
+        seated,
+        seated_families
+    );
+}
 
-        ctx.db.activity().insert(Activity {
-            person_id,
-            activity_type: activity_types::IDLE,
-            started_at: 0.0,
-            duration: 0.5,
-            target_room_id: None,
+/// Insert one passenger and their components into `room_id`.
+fn spawn_passenger(
+    ctx: &ReducerContext,
+    i: u32,
+    age: u8,
+    (given_name, family_name): (&str, &str),
+    room_id: u32,
+    cabin: u8,
+) -> u64 {
+    let professions = [
+        "Colonist",
+        "Scientist",
+        "Engineer",
+        "Teacher",
+        "Doctor",
+        "Artist",
+        "Farmer",
+        "Merchant",
+        "Writer",
+        "Architect",
+    ];
+
+    let life_stage = progship_logic::constants::life_stages::from_age(age);
+    let person_id = ctx
+        .db
+        .person()
+        .insert(Person {
+            id: 0,
+            given_name: given_name.to_string(),
+            family_name: family_name.to_string(),
+            is_crew: false,
+            is_player: false,
+            is_alive: true,
+            owner_identity: None,
+            age,
+            birth_date: birth_date(age, (i as f32 * 0.381_966) % 1.0),
+            life_stage,
+            clearance: access_levels::PUBLIC,
+        })
+        .id;
+    let (rx, ry, rw, rh) = ctx
+        .db
+        .room()
+        .id()
+        .find(room_id)
+        .map(|r| (r.x, r.y, r.width, r.height))
+        .unwrap_or((0.0, 0.0, 24.0, 18.0));
+    let spread_x = ((i as f32 * 1.7) % (rw - 2.0).max(1.0)) - ((rw - 2.0).max(1.0) / 2.0);
+    let spread_y = ((i as f32 * 2.3) % (rh - 2.0).max(1.0)) - ((rh - 2.0).max(1.0) / 2.0);
+    ctx.db.position().insert(Position {
+        person_id,
+        room_id,
+        x: rx + spread_x.clamp(-rw / 2.0 + 0.5, rw / 2.0 - 0.5),
+        y: ry + spread_y.clamp(-rh / 2.0 + 0.5, rh / 2.0 - 0.5),
+        z: 0.0,
+    });
+
+    ctx.db.needs().insert(Needs {
+        person_id,
+        hunger: 0.2 + (i % 4) as f32 * 0.05,
+        fatigue: 0.15 + (i % 5) as f32 * 0.04,
+        social: 0.4 + (i % 3) as f32 * 0.1,
+        comfort: 0.2 + (i % 6) as f32 * 0.03,
+        hygiene: 0.15 + (i % 7) as f32 * 0.02,
+        health: 1.0,
+        morale: 0.7 + (i % 4) as f32 * 0.06,
+    });
+
+    let base = ((i + 40) as f32 * 0.618_034) % 1.0;
+    ctx.db.personality().insert(Personality {
+        person_id,
+        openness: 0.4 + base * 0.3,
+        conscientiousness: 0.3 + ((base * 3.0) % 1.0) * 0.4,
+        extraversion: 0.4 + ((base * 5.0) % 1.0) * 0.3,
+        agreeableness: 0.5 + ((base * 7.0) % 1.0) * 0.2,
+        neuroticism: 0.2 + ((base * 11.0) % 1.0) * 0.4,
+    });
+    insert_traits(ctx, person_id);
+    insert_items(ctx, person_id, None);
+
+    let profession = match life_stage {
+        life_stages::INFANT => "Infant",
+        life_stages::CHILD => "Student",
+        life_stages::ELDER => "Retiree",
+        _ => professions[i as usize % professions.len()],
+    };
+    ctx.db.passenger().insert(Passenger {
+        person_id,
+        cabin_class: cabin,
+        destination: "Kepler-442b".to_string(),
+        profession: profession.to_string(),
+    });
+
+    let profile = passenger_skills(profession, life_stage, base);
+    ctx.db.skills().insert(Skills {
+        person_id,
+        engineering: profile.engineering,
+        medical: profile.medical,
+        piloting: profile.piloting,
+        science: profile.science,
+        social: profile.social,
+        combat: profile.combat,
+    });
+
+    ctx.db.activity().insert(Activity {
+        person_id,
+        activity_type: activity_types::IDLE,
+        started_at: 0.0,
+        duration: 0.5,
+        target_room_id: None,
+        animation_state: postures::STANDING,
+        target_anchor_id: None,
+        target_person_id: None,
+    });
+
+    person_id
+}
+
+/// Insert a baby born at `sim_time` in `room_id` to `parents` of `family`:
+/// an infant passenger in their parents' cabin class who takes after them
+/// both, joins the household as a child and starts out close to its other
+/// members. Returns their ID.
+pub(crate) fn spawn_newborn(
+    ctx: &ReducerContext,
+    family: &Family,
+    parents: (u64, u64),
+    room_id: u32,
+    sim_time: f64,
+) -> u64 {
+    let mut rng = SimpleRng::from_name(&format!("newborn-{}-{}", family.id, sim_time));
+    let given_name = newborn_name(&family.family_name, (rng.next_f32() * 1e6) as u64);
+    let person_id = ctx
+        .db
+        .person()
+        .insert(Person {
+            id: 0,
+            given_name: given_name.to_string(),
+            family_name: family.family_name.clone(),
+            is_crew: false,
+            is_player: false,
+            is_alive: true,
+            owner_identity: None,
+            age: 0,
+            birth_date: sim_time,
+            life_stage: life_stages::INFANT,
+            clearance: access_levels::PUBLIC,
+        })
+        .id;
+    let (x, y) = ctx
+        .db
+        .room()
+        .id()
+        .find(room_id)
+        .map(|r| (r.x, r.y))
+        .unwrap_or((0.0, 0.0));
+    ctx.db.position().insert(Position {
+        person_id,
+        room_id,
+        x,
+        y,
+        z: 0.0,
+    });
+    ctx.db.needs().insert(Needs {
+        person_id,
+        hunger: 0.2,
+        fatigue: 0.2,
+        social: 0.3,
+        comfort: 0.1,
+        hygiene: 0.1,
+        health: 1.0,
+        morale: 0.8,
+    });
+
+    let [a, b] = [parents.0, parents.1].map(|id| ctx.db.personality().person_id().find(id));
+    let mut inherit = |trait_of: fn(&Personality) -> f32| {
+        let parent = |p: &Option<Personality>| p.as_ref().map_or(0.5, trait_of);
+        inherit_trait(parent(&a), parent(&b), rng.next_f32())
+    };
+    ctx.db.personality().insert(Personality {
+        person_id,
+        openness: inherit(|p| p.openness),
+        conscientiousness: inherit(|p| p.conscientiousness),
+        extraversion: inherit(|p| p.extraversion),
+        agreeableness: inherit(|p| p.agreeableness),
+        neuroticism: inherit(|p| p.neuroticism),
+    });
+    insert_traits(ctx, person_id);
+
+    let parent_passage = [parents.0, parents.1]
+        .iter()
+        .find_map(|&id| ctx.db.passenger().person_id().find(id));
+    ctx.db.passenger().insert(Passenger {
+        person_id,
+        cabin_class: parent_passage
+            .as_ref()
+            .map_or(cabin_classes::STANDARD, |p| p.cabin_class),
+        destination: parent_passage.map_or("Kepler-442b".to_string(), |p| p.destination),
+        profession: "Infant".to_string(),
+    });
+    let profile = passenger_skills("Infant", life_stages::INFANT, rng.next_f32());
+    ctx.db.skills().insert(Skills {
+        person_id,
+        engineering: profile.engineering,
+        medical: profile.medical,
+        piloting: profile.piloting,
+        science: profile.science,
+        social: profile.social,
+        combat: profile.combat,
+    });
+    ctx.db.activity().insert(Activity {
+        person_id,
+        activity_type: activity_types::IDLE,
+        started_at: sim_time,
+        duration: 0.5,
+        target_room_id: None,
+        animation_state: postures::STANDING,
+        target_anchor_id: None,
+        target_person_id: None,
+    });
+
+    let household: Vec<FamilyMember> = ctx
+        .db
+        .family_member()
+        .iter()
+        .filter(|m| m.family_id == family.id)
+        .collect();
+    for member in household {
+        let alive = ctx
+            .db
+            .person()
+            .id()
+            .find(member.person_id)
+            .is_some_and(|p| p.is_alive);
+        let Some((relationship_type, strength, familiarity)) = alive
+            .then(|| tie(family_roles::CHILD, member.role))
+            .flatten()
+        else {
+            continue;
+        };
+        ctx.db.relationship().insert(Relationship {
+            id: 0,
+            person_a: member.person_id,
+            person_b: person_id,
+            relationship_type,
+            strength,
+            familiarity,
+            last_interaction: sim_time,
         });
     }
+    ctx.db.family_member().insert(FamilyMember {
+        person_id,
+        family_id: family.id,
+        role: family_roles::CHILD,
+    });
+    person_id
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_name_pools_not_empty() {
-        assert!(
-            !GIVEN_NAMES.is_empty(),
-            "Given names pool should not be empty"
-        );
-        assert!(
-            !FAMILY_NAMES.is_empty(),
-            "Family names pool should not be empty"
-        );
-        assert!(GIVEN_NAMES.len() >= 20, "Should have diverse given names");
-        assert!(FAMILY_NAMES.len() >= 20, "Should have diverse family names");
-    }
-
-    #[test]
-    fn test_names_are_valid() {
-        for name in GIVEN_NAMES {
-            assert!(!name.is_empty(), "Given names should not be empty");
-            assert!(
-                name.len() >= 2,
-                "Given name '{}' should be at least 2 characters",
-                name
-            );
-        }
-
-        for name in FAMILY_NAMES {
-            assert!(!name.is_empty(), "Family names should not be empty");
-            assert!(
-                name.len() >= 2,
-                "Family name '{}' should be at least 2 characters",
-                name
-            );
-        }
-    }
-
     #[test]
     fn test_simple_rng_deterministic() {
         let mut rng1 = SimpleRng::from_name("TestPerson");
@@ -479,7 +688,7 @@ mod tests {
         for _ in 0..50 {
             let val = rng.next_f32();
             assert!(
-                val >= 0.0 && val <= 1.0,
+                (0.0..=1.0).contains(&val),
                 "RNG value {} should be in [0, 1]",
                 val
             );
@@ -493,7 +702,7 @@ mod tests {
         for _ in 0..50 {
             let val = rng.next_range(10.0, 20.0);
             assert!(
-                val >= 10.0 && val <= 20.0,
+                (10.0..=20.0).contains(&val),
                 "Value {} should be in [10, 20]",
                 val
             );
@@ -506,7 +715,7 @@ mod tests {
 
         for _ in 0..50 {
             let val = rng.next_usize(5, 15);
-            assert!(val >= 5 && val < 15, "Value {} should be in [5, 15)", val);
+            assert!((5..15).contains(&val), "Value {} should be in [5, 15)", val);
         }
     }
 
@@ -522,24 +731,4 @@ mod tests {
         let val = rng.next_usize(10, 5);
         assert_eq!(val, 10);
     }
-
-    #[test]
-    fn test_name_generation_uniqueness() {
-        // Generate several names and check for some diversity
-        let mut names = std::collections::HashSet::new();
-
-        for i in 0..100 {
-            let given_idx = i % GIVEN_NAMES.len();
-            let family_idx = (i / GIVEN_NAMES.len() + i * 7) % FAMILY_NAMES.len();
-            let full_name = format!("{} {}", GIVEN_NAMES[given_idx], FAMILY_NAMES[family_idx]);
-            names.insert(full_name);
-        }
-
-        // Should have good variety (at least 80% unique in first 100)
-        assert!(
-            names.len() >= 80,
-            "Should generate diverse names, got {} unique out of 100",
-            names.len()
-        );
-    }
 }
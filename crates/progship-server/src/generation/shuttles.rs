@@ -0,0 +1,44 @@
+//! Shuttle generation.
+//!
+//! Docks a fueled flight of shuttles in every shuttle bay, as many as the
+//! bay's floor holds (see [`progship_logic::shuttles`]).
+
+use crate::tables::*;
+use progship_logic::shuttles::{shuttles_for_bay, SHUTTLE_CAPACITY, SHUTTLE_FUEL_CAPACITY_KG};
+use spacetimedb::{ReducerContext, Table};
+
+const SHUTTLE_NAMES: &[&str] = &[
+    "Kestrel", "Osprey", "Heron", "Swift", "Petrel", "Tern", "Merlin", "Plover",
+];
+
+pub(super) fn generate_shuttles(ctx: &ReducerContext) {
+    let bays: Vec<Room> = ctx
+        .db
+        .room()
+        .iter()
+        .filter(|r| r.room_type == room_types::SHUTTLE_BAY)
+        .collect();
+    let mut count = 0;
+    for bay in &bays {
+        for _ in 0..shuttles_for_bay(bay.width * bay.height) {
+            let name = SHUTTLE_NAMES[count % SHUTTLE_NAMES.len()];
+            let name = if count < SHUTTLE_NAMES.len() {
+                name.to_string()
+            } else {
+                format!("{} {}", name, count / SHUTTLE_NAMES.len() + 1)
+            };
+            ctx.db.shuttle().insert(Shuttle {
+                id: 0,
+                name,
+                bay_room_id: bay.id,
+                state: shuttle_states::DOCKED,
+                capacity: SHUTTLE_CAPACITY,
+                fuel: SHUTTLE_FUEL_CAPACITY_KG,
+                fuel_cap: SHUTTLE_FUEL_CAPACITY_KG,
+                launched_at: None,
+            });
+            count += 1;
+        }
+    }
+    log::info!("Docked {} shuttles in {} bays", count, bays.len());
+}
@@ -0,0 +1,73 @@
+//! Generation metrics: the generation_stats row summarizing the layout.
+
+use crate::tables::*;
+use progship_logic::geometry::{layout_stats, DoorInfo, RoomRect};
+use spacetimedb::{ReducerContext, Table};
+
+/// Compute layout metrics for the finished ship and store them in the
+/// generation_stats row.
+pub(super) fn record_generation_stats(ctx: &ReducerContext, population: u32) {
+    let rooms: Vec<RoomRect> = ctx
+        .db
+        .room()
+        .iter()
+        .map(|r| RoomRect {
+            id: r.id,
+            deck: r.deck,
+            x: r.x,
+            y: r.y,
+            width: r.width,
+            height: r.height,
+            room_type: r.room_type,
+            capacity: r.capacity,
+        })
+        .collect();
+    let (doors, access): (Vec<DoorInfo>, Vec<u8>) = ctx
+        .db
+        .door()
+        .iter()
+        .map(|d| {
+            (
+                DoorInfo {
+                    id: d.id,
+                    room_a: d.room_a,
+                    room_b: d.room_b,
+                    door_x: d.door_x,
+                    door_y: d.door_y,
+                    wall_a: d.wall_a,
+                    wall_b: d.wall_b,
+                },
+                d.access_level,
+            )
+        })
+        .unzip();
+    let stats = layout_stats(&rooms, &doors, &access, population);
+    log::info!(
+        "Generation stats: {} rooms, {} doors, {:.0} m² ({:.1} m²/person), {:.0}% corridor, \
+         mean aspect {:.2}, {} unreachable",
+        stats.room_count,
+        stats.door_count,
+        stats.total_floor_area,
+        stats.area_per_person,
+        stats.corridor_fraction * 100.0,
+        stats.mean_aspect_ratio,
+        stats.unreachable_rooms
+    );
+
+    let row = GenerationStats {
+        id: 0,
+        room_count: stats.room_count,
+        door_count: stats.door_count,
+        total_floor_area: stats.total_floor_area,
+        area_per_person: stats.area_per_person,
+        corridor_fraction: stats.corridor_fraction,
+        mean_aspect_ratio: stats.mean_aspect_ratio,
+        unreachable_rooms: stats.unreachable_rooms,
+        doors_by_access: stats.doors_by_access,
+    };
+    if ctx.db.generation_stats().id().find(0).is_some() {
+        ctx.db.generation_stats().id().update(row);
+    } else {
+        ctx.db.generation_stats().insert(row);
+    }
+}
@@ -1,12 +1,44 @@
 //! Ship systems and atmosphere generation.
 //!
 //! Creates ShipSystem/Subsystem/SystemComponent hierarchy with infrastructure
-//! connectivity (InfraEdge) and per-deck atmosphere initialization.
+//! connectivity (InfraEdge), HVAC ducts out to every room (AirDuct), the coolant
+//! loop and its radiators (CoolantLoop) and per-room atmosphere initialization
+//! from each deck's zone theme.
+
+use std::collections::HashMap;
 
 use crate::tables::*;
+use progship_logic::atmosphere::atmo_constants::{KPA_PER_ATM, NORMAL_CO2, NORMAL_O2};
+use progship_logic::genlib::decks::zone_atmosphere;
+use progship_logic::outfit::{Plant, ShipOutfit};
+use progship_logic::plumbing::water_demand;
+use progship_logic::systems::{LifeSupportVariant, PowerVariant, WaterVariant};
+use progship_logic::thermal::{design_heat_kw, equilibrium_c, HeatSources, THERMAL_HOURS};
 use spacetimedb::{ReducerContext, Table};
 
-pub(super) fn generate_ship_systems(ctx: &ReducerContext) {
+/// Subsystem name for one unit of a plant: numbered when there are several.
+fn unit_name(plant: &Plant, unit: u32) -> String {
+    if plant.units > 1 {
+        format!("{} {}", plant.name, unit + 1)
+    } else {
+        plant.name.to_string()
+    }
+}
+
+/// Corridor `carries_flags` bit for an `infra_types` kind.
+fn infra_carries_flag(infra: u8) -> u8 {
+    match infra {
+        infra_types::POWER_CABLE => carries_flags::POWER,
+        infra_types::WATER_PIPE => carries_flags::WATER,
+        infra_types::COOLANT_PIPE => carries_flags::COOLANT,
+        infra_types::HVAC_DUCT => carries_flags::HVAC,
+        infra_types::DATA_CABLE => carries_flags::DATA,
+        _ => 0,
+    }
+}
+
+/// Build the ship's systems, instantiating the variants chosen in `outfit`.
+pub(super) fn generate_ship_systems(ctx: &ReducerContext, outfit: &ShipOutfit) {
     let insert_system = |name: &str, sys_type: u8, priority: u8| -> u64 {
         ctx.db
             .ship_system()
@@ -45,12 +77,13 @@ pub(super) fn generate_ship_systems(ctx: &ReducerContext) {
     let comms_node = find_node(room_types::COMMS_ROOM);
     let medical_node = find_node(room_types::HOSPITAL_WARD);
 
-    let insert_subsystem = |system_id: u64,
-                            name: &str,
-                            sub_type: u8,
-                            node_id: u64,
-                            power_draw: f32,
-                            crew_req: u8|
+    let insert_rated = |system_id: u64,
+                        name: &str,
+                        sub_type: u8,
+                        node_id: u64,
+                        power_draw: f32,
+                        crew_req: u8,
+                        output: f32|
      -> u64 {
         ctx.db
             .subsystem()
@@ -64,9 +97,22 @@ pub(super) fn generate_ship_systems(ctx: &ReducerContext) {
                 node_id,
                 power_draw,
                 crew_required: crew_req,
+                output,
             })
             .id
     };
+    let insert_subsystem =
+        |system_id: u64, name: &str, sub_type: u8, node_id: u64, power_draw: f32, crew_req: u8| {
+            insert_rated(
+                system_id, name, sub_type, node_id, power_draw, crew_req, 0.0,
+            )
+        };
+    // A plant's own room if the ship has one, else the usual system room
+    let plant_node = |plant: &Plant, fallback: u64| match find_node(plant.room_type) {
+        0 => fallback,
+        id => id,
+    };
+    let crew = |plant: &Plant| plant.crew_per_unit.min(u8::MAX as u32) as u8;
 
     let insert_component =
         |subsystem_id: u64, name: &str, comp_type: u8, px: f32, py: f32, maint_hours: f32| {
@@ -93,8 +139,32 @@ pub(super) fn generate_ship_systems(ctx: &ReducerContext) {
         .map(|c| c.id)
         .unwrap_or(0);
 
+    // Run each infra edge through a crawlway carrying its kind on the deck it
+    // leaves from, else any such crawlway, else the service corridor
+    let crawlways: Vec<Corridor> = ctx
+        .db
+        .corridor()
+        .iter()
+        .filter(|c| c.corridor_type == corridor_types::CRAWLWAY)
+        .collect();
+    let route = |from_node: u64, infra: u8| -> u64 {
+        let flag = infra_carries_flag(infra);
+        let deck = ctx
+            .db
+            .room()
+            .iter()
+            .find(|r| r.node_id == from_node)
+            .map(|r| r.deck);
+        let carrying = || crawlways.iter().filter(|c| c.carries & flag != 0);
+        carrying()
+            .find(|c| Some(c.deck) == deck)
+            .or_else(|| carrying().next())
+            .map(|c| c.id)
+            .unwrap_or(svc_corridor_id)
+    };
+
     // Helper: create GraphEdge + InfraEdge for system connections
-    let insert_infra = |from_node: u64, to_node: u64, etype: u8, infra: u8, capacity: f32| {
+    let insert_infra = |from_node: u64, to_node: u64, etype: u8, infra: u8, capacity: f32| -> u64 {
         let ge = ctx.db.graph_edge().insert(GraphEdge {
             id: 0,
             from_node,
@@ -103,122 +173,171 @@ pub(super) fn generate_ship_systems(ctx: &ReducerContext) {
             weight: capacity,
             bidirectional: false,
         });
-        ctx.db.infra_edge().insert(InfraEdge {
-            id: 0,
-            graph_edge_id: ge.id,
-            edge_type: infra,
-            corridor_id: svc_corridor_id,
-            capacity,
-            current_flow: capacity,
-            health: 1.0,
-        });
+        ctx.db
+            .infra_edge()
+            .insert(InfraEdge {
+                id: 0,
+                graph_edge_id: ge.id,
+                edge_type: infra,
+                corridor_id: route(from_node, infra),
+                capacity,
+                current_flow: capacity,
+                health: 1.0,
+            })
+            .id
     };
 
     // ---- POWER SYSTEM ----
     let power_sys = insert_system(
-        "Power System",
+        &format!("Power System ({})", outfit.power.name),
         system_types::POWER,
         power_priorities::CRITICAL,
     );
 
-    let reactor_core = insert_subsystem(
-        power_sys,
-        "Reactor Core",
-        subsystem_types::REACTOR_CORE,
-        reactor_node,
-        0.0,
-        2,
-    );
-    insert_component(
-        reactor_core,
-        "Primary Fuel Injector",
-        component_types::FUEL_INJECTOR,
-        -2.0,
-        0.0,
-        500.0,
-    );
-    insert_component(
-        reactor_core,
-        "Secondary Fuel Injector",
-        component_types::FUEL_INJECTOR,
-        2.0,
-        0.0,
-        500.0,
-    );
-    insert_component(
-        reactor_core,
-        "Containment Coil A",
-        component_types::CONTAINMENT_COIL,
-        -1.0,
-        -2.0,
-        1000.0,
-    );
-    insert_component(
-        reactor_core,
-        "Containment Coil B",
-        component_types::CONTAINMENT_COIL,
-        1.0,
-        -2.0,
-        1000.0,
-    );
-    insert_component(
-        reactor_core,
-        "Core Temperature Sensor",
-        component_types::SENSOR,
-        0.0,
-        0.0,
-        200.0,
-    );
+    // One core per unit of the selected power plant
+    let power_node = plant_node(&outfit.power, reactor_node);
+    for unit in 0..outfit.power.units {
+        let core = insert_rated(
+            power_sys,
+            &unit_name(&outfit.power, unit),
+            subsystem_types::REACTOR_CORE,
+            power_node,
+            0.0,
+            crew(&outfit.power),
+            outfit.power.output_per_unit,
+        );
+        let mut parts = match outfit.power_variant {
+            PowerVariant::SolarArray => vec![
+                (
+                    "Photovoltaic Panel A",
+                    component_types::GENERATOR,
+                    -2.0,
+                    0.0,
+                    2000.0,
+                ),
+                (
+                    "Photovoltaic Panel B",
+                    component_types::GENERATOR,
+                    2.0,
+                    0.0,
+                    2000.0,
+                ),
+                ("Sun Tracker", component_types::ACTUATOR, 0.0, 1.0, 600.0),
+            ],
+            PowerVariant::RTG => vec![
+                (
+                    "Thermocouple Stack",
+                    component_types::GENERATOR,
+                    0.0,
+                    0.0,
+                    5000.0,
+                ),
+                (
+                    "Isotope Heat Sensor",
+                    component_types::SENSOR,
+                    0.0,
+                    1.0,
+                    1000.0,
+                ),
+            ],
+            _ => vec![
+                (
+                    "Primary Fuel Injector",
+                    component_types::FUEL_INJECTOR,
+                    -2.0,
+                    0.0,
+                    500.0,
+                ),
+                (
+                    "Secondary Fuel Injector",
+                    component_types::FUEL_INJECTOR,
+                    2.0,
+                    0.0,
+                    500.0,
+                ),
+                (
+                    "Core Temperature Sensor",
+                    component_types::SENSOR,
+                    0.0,
+                    0.0,
+                    200.0,
+                ),
+            ],
+        };
+        if outfit.power_variant.needs_containment() {
+            parts.push((
+                "Containment Coil A",
+                component_types::CONTAINMENT_COIL,
+                -1.0,
+                -2.0,
+                1000.0,
+            ));
+            parts.push((
+                "Containment Coil B",
+                component_types::CONTAINMENT_COIL,
+                1.0,
+                -2.0,
+                1000.0,
+            ));
+        }
+        for (name, comp_type, px, py, hours) in parts {
+            insert_component(core, name, comp_type, px, py, hours);
+        }
+    }
 
-    let fuel_inj = insert_subsystem(
-        power_sys,
-        "Fuel Injection System",
-        subsystem_types::FUEL_INJECTION,
-        reactor_node,
-        2.0,
-        1,
-    );
-    insert_component(
-        fuel_inj,
-        "Fuel Pump",
-        component_types::PUMP,
-        -1.0,
-        1.0,
-        300.0,
-    );
-    insert_component(
-        fuel_inj,
-        "Flow Regulator",
-        component_types::REGULATOR,
-        1.0,
-        1.0,
-        400.0,
-    );
+    if outfit.power_variant.burns_fuel() {
+        let fuel_inj = insert_subsystem(
+            power_sys,
+            "Fuel Injection System",
+            subsystem_types::FUEL_INJECTION,
+            reactor_node,
+            2.0,
+            1,
+        );
+        insert_component(
+            fuel_inj,
+            "Fuel Pump",
+            component_types::PUMP,
+            -1.0,
+            1.0,
+            300.0,
+        );
+        insert_component(
+            fuel_inj,
+            "Flow Regulator",
+            component_types::REGULATOR,
+            1.0,
+            1.0,
+            400.0,
+        );
+    }
 
-    let containment = insert_subsystem(
-        power_sys,
-        "Magnetic Containment",
-        subsystem_types::MAGNETIC_CONTAINMENT,
-        reactor_node,
-        15.0,
-        1,
-    );
-    insert_component(
-        containment,
-        "Containment Field Generator",
-        component_types::GENERATOR,
-        0.0,
-        -1.0,
-        800.0,
-    );
-    insert_component(
-        containment,
-        "Field Strength Sensor",
-        component_types::SENSOR,
-        0.0,
-        1.0,
-        200.0,
-    );
+    if outfit.power_variant.needs_containment() {
+        let containment = insert_subsystem(
+            power_sys,
+            "Magnetic Containment",
+            subsystem_types::MAGNETIC_CONTAINMENT,
+            reactor_node,
+            15.0,
+            1,
+        );
+        insert_component(
+            containment,
+            "Containment Field Generator",
+            component_types::GENERATOR,
+            0.0,
+            -1.0,
+            800.0,
+        );
+        insert_component(
+            containment,
+            "Field Strength Sensor",
+            component_types::SENSOR,
+            0.0,
+            1.0,
+            200.0,
+        );
+    }
 
     let reactor_cool = insert_subsystem(
         power_sys,
@@ -355,43 +474,42 @@ pub(super) fn generate_ship_systems(ctx: &ReducerContext) {
 
     // ---- LIFE SUPPORT ----
     let ls_sys = insert_system(
-        "Life Support",
+        &format!("Life Support ({})", outfit.life_support.name),
         system_types::LIFE_SUPPORT,
         power_priorities::CRITICAL,
     );
 
-    let o2_gen = insert_subsystem(
-        ls_sys,
-        "O2 Generation",
-        subsystem_types::O2_GENERATION,
-        ls_node,
-        20.0,
-        1,
-    );
-    insert_component(
-        o2_gen,
-        "Electrolysis Cell A",
-        component_types::GENERATOR,
-        -2.0,
-        0.0,
-        400.0,
-    );
-    insert_component(
-        o2_gen,
-        "Electrolysis Cell B",
-        component_types::GENERATOR,
-        2.0,
-        0.0,
-        400.0,
-    );
-    insert_component(
-        o2_gen,
-        "O2 Level Sensor",
-        component_types::SENSOR,
-        0.0,
-        1.0,
-        150.0,
-    );
+    // One O2 plant per unit of the selected life support
+    let o2_node = plant_node(&outfit.life_support, ls_node);
+    for unit in 0..outfit.life_support.units {
+        let o2_gen = insert_rated(
+            ls_sys,
+            &unit_name(&outfit.life_support, unit),
+            subsystem_types::O2_GENERATION,
+            o2_node,
+            outfit.life_support.power_draw_per_unit,
+            crew(&outfit.life_support),
+            outfit.life_support.output_per_unit,
+        );
+        let (cell, cell_type) = match outfit.life_support_variant {
+            LifeSupportVariant::BioregenerativeLSS => ("Algae Bioreactor", component_types::TANK),
+            LifeSupportVariant::AdvancedMOXIE => ("Solid Oxide Cell", component_types::GENERATOR),
+            LifeSupportVariant::CryogenicSeparation => ("Cryocooler", component_types::COMPRESSOR),
+            LifeSupportVariant::BasicElectrolysis => {
+                ("Electrolysis Cell", component_types::GENERATOR)
+            }
+        };
+        insert_component(o2_gen, &format!("{} A", cell), cell_type, -2.0, 0.0, 400.0);
+        insert_component(o2_gen, &format!("{} B", cell), cell_type, 2.0, 0.0, 400.0);
+        insert_component(
+            o2_gen,
+            "O2 Level Sensor",
+            component_types::SENSOR,
+            0.0,
+            1.0,
+            150.0,
+        );
+    }
 
     let co2_scrub = insert_subsystem(
         ls_sys,
@@ -561,35 +679,44 @@ pub(super) fn generate_ship_systems(ctx: &ReducerContext) {
 
     // ---- WATER SYSTEM ----
     let water_sys = insert_system(
-        "Water System",
+        &format!("Water System ({})", outfit.water.name),
         system_types::WATER_RECYCLING,
         power_priorities::NORMAL,
     );
 
-    let water_filt = insert_subsystem(
-        water_sys,
-        "Water Filtration",
-        subsystem_types::WATER_FILTRATION,
-        water_node,
-        8.0,
-        1,
-    );
-    insert_component(
-        water_filt,
-        "Filtration Membrane",
-        component_types::FILTER,
-        0.0,
-        -1.0,
-        200.0,
-    );
-    insert_component(
-        water_filt,
-        "Sediment Filter",
-        component_types::FILTER,
-        0.0,
-        1.0,
-        150.0,
-    );
+    // One recycler per unit of the selected water system
+    let recycler_node = plant_node(&outfit.water, water_node);
+    let recycler_type = match outfit.water_variant {
+        WaterVariant::AdvancedDistillation => subsystem_types::WATER_DISTILLATION,
+        _ => subsystem_types::WATER_FILTRATION,
+    };
+    for unit in 0..outfit.water.units {
+        let water_filt = insert_rated(
+            water_sys,
+            &unit_name(&outfit.water, unit),
+            recycler_type,
+            recycler_node,
+            outfit.water.power_draw_per_unit,
+            crew(&outfit.water),
+            outfit.water.output_per_unit,
+        );
+        insert_component(
+            water_filt,
+            "Filtration Membrane",
+            component_types::FILTER,
+            0.0,
+            -1.0,
+            200.0,
+        );
+        insert_component(
+            water_filt,
+            "Sediment Filter",
+            component_types::FILTER,
+            0.0,
+            1.0,
+            150.0,
+        );
+    }
 
     let water_dist_sub = insert_subsystem(
         water_sys,
@@ -718,36 +845,41 @@ pub(super) fn generate_ship_systems(ctx: &ReducerContext) {
 
     // ---- FOOD PRODUCTION ----
     let food_sys = insert_system(
-        "Food Production",
+        &format!("Food Production ({})", outfit.food.name),
         system_types::FOOD_PRODUCTION,
         power_priorities::NORMAL,
     );
 
-    let growth = insert_subsystem(
-        food_sys,
-        "Growth Chambers",
-        subsystem_types::GROWTH_CHAMBER,
-        hydro_node,
-        12.0,
-        2,
-    );
-    insert_component(
-        growth,
-        "Grow Bed A",
-        component_types::TANK,
-        -2.0,
-        0.0,
-        600.0,
-    );
-    insert_component(growth, "Grow Bed B", component_types::TANK, 2.0, 0.0, 600.0);
-    insert_component(
-        growth,
-        "Soil Moisture Sensor",
-        component_types::SENSOR,
-        0.0,
-        1.0,
-        100.0,
-    );
+    // One growth chamber per unit of the selected food system
+    let growth_node = plant_node(&outfit.food, hydro_node);
+    for unit in 0..outfit.food.units {
+        let growth = insert_rated(
+            food_sys,
+            &unit_name(&outfit.food, unit),
+            subsystem_types::GROWTH_CHAMBER,
+            growth_node,
+            outfit.food.power_draw_per_unit,
+            crew(&outfit.food),
+            outfit.food.output_per_unit,
+        );
+        insert_component(
+            growth,
+            "Grow Bed A",
+            component_types::TANK,
+            -2.0,
+            0.0,
+            600.0,
+        );
+        insert_component(growth, "Grow Bed B", component_types::TANK, 2.0, 0.0, 600.0);
+        insert_component(
+            growth,
+            "Soil Moisture Sensor",
+            component_types::SENSOR,
+            0.0,
+            1.0,
+            100.0,
+        );
+    }
 
     let nutrients = insert_subsystem(
         food_sys,
@@ -843,18 +975,20 @@ pub(super) fn generate_ship_systems(ctx: &ReducerContext) {
 
     // ---- PROPULSION ----
     let prop_sys = insert_system(
-        "Propulsion",
+        &format!("Propulsion ({})", outfit.propulsion.name),
         system_types::PROPULSION,
         power_priorities::HIGH,
     );
 
-    let thrust = insert_subsystem(
+    // The drive burns its fuel rate while under way
+    let thrust = insert_rated(
         prop_sys,
-        "Thrust Chambers",
+        outfit.propulsion.name,
         subsystem_types::THRUST_CHAMBER,
         engineering_node,
         0.0,
-        2,
+        outfit.propulsion.crew_required.min(u8::MAX as u32) as u8,
+        outfit.propulsion.fuel_rate as f32,
     );
     insert_component(
         thrust,
@@ -988,6 +1122,36 @@ pub(super) fn generate_ship_systems(ctx: &ReducerContext) {
         500.0,
     );
 
+    // Watched from the CIC where the ship has one, else from the bridge
+    let sensor_node = match find_node(room_types::CIC) {
+        0 => bridge_node,
+        id => id,
+    };
+    let sensors = insert_subsystem(
+        nav_sys,
+        "Sensor Array",
+        subsystem_types::SENSOR_ARRAY,
+        sensor_node,
+        4.0,
+        1,
+    );
+    insert_component(
+        sensors,
+        "Long-Range Scanner",
+        component_types::SCANNER_HEAD,
+        0.0,
+        0.0,
+        300.0,
+    );
+    insert_component(
+        sensors,
+        "Track Processor",
+        component_types::PROCESSOR,
+        1.0,
+        0.0,
+        200.0,
+    );
+
     let att_thrust = insert_subsystem(
         nav_sys,
         "Attitude Thrusters",
@@ -1114,7 +1278,7 @@ pub(super) fn generate_ship_systems(ctx: &ReducerContext) {
 
     // ---- GRAVITY ----
     let grav_sys = insert_system(
-        "Gravity System",
+        &format!("Gravity System ({})", outfit.gravity.name),
         system_types::GRAVITY,
         power_priorities::NORMAL,
     );
@@ -1138,11 +1302,11 @@ pub(super) fn generate_ship_systems(ctx: &ReducerContext) {
 
     let grav_plate = insert_subsystem(
         grav_sys,
-        "Gravity Plates",
+        outfit.gravity.name,
         subsystem_types::GRAVITY_PLATE,
-        engineering_node,
-        50.0,
-        0,
+        plant_node(&outfit.gravity, engineering_node),
+        outfit.gravity.power_draw_per_unit,
+        crew(&outfit.gravity),
     );
     insert_component(
         grav_plate,
@@ -1172,7 +1336,7 @@ pub(super) fn generate_ship_systems(ctx: &ReducerContext) {
 
     // ---- MEDICAL ----
     let med_sys = insert_system(
-        "Medical Systems",
+        &format!("Medical Systems ({})", outfit.medical.name),
         system_types::MEDICAL,
         power_priorities::HIGH,
     );
@@ -1224,8 +1388,8 @@ pub(super) fn generate_ship_systems(ctx: &ReducerContext) {
         "Surgical Suite",
         subsystem_types::SURGICAL_SUITE,
         medical_node,
-        8.0,
-        2,
+        outfit.medical.power_draw_per_unit,
+        crew(&outfit.medical),
     );
     insert_component(
         surgery_sub,
@@ -1394,29 +1558,59 @@ pub(super) fn generate_ship_systems(ctx: &ReducerContext) {
         infra_types::WATER_PIPE,
         5.0,
     );
+    // Mains out to every other wet room, sized by `size_water_pipes`
+    if water_node != 0 {
+        let mut wet: Vec<u64> = ctx
+            .db
+            .room()
+            .iter()
+            .filter(|r| water_demand(r.room_type).is_some())
+            .map(|r| r.node_id)
+            .filter(|&n| ![water_node, galley_node, hydro_node, medical_node].contains(&n))
+            .collect();
+        wet.sort_unstable();
+        wet.dedup();
+        for node in wet {
+            insert_infra(
+                water_node,
+                node,
+                edge_types::WATER,
+                infra_types::WATER_PIPE,
+                1.0,
+            );
+        }
+    }
 
-    // HVAC flow
-    insert_infra(
-        hvac_node,
-        ls_node,
-        edge_types::HVAC,
-        infra_types::HVAC_DUCT,
-        40.0,
-    );
-    insert_infra(
-        hvac_node,
-        bridge_node,
-        edge_types::HVAC,
-        infra_types::HVAC_DUCT,
-        10.0,
-    );
-    insert_infra(
-        hvac_node,
-        medical_node,
-        edge_types::HVAC,
-        infra_types::HVAC_DUCT,
-        10.0,
-    );
+    // HVAC flow: a duct from the air handlers to every other room's node,
+    // sized by the floor area it serves
+    if hvac_node != 0 {
+        let mut served: HashMap<u64, f32> = HashMap::new();
+        for room in ctx
+            .db
+            .room()
+            .iter()
+            .filter(|r| r.node_id != 0 && r.node_id != hvac_node)
+        {
+            *served.entry(room.node_id).or_default() += room.width * room.height;
+        }
+        let mut served: Vec<(u64, f32)> = served.into_iter().collect();
+        served.sort_unstable_by_key(|&(node, _)| node);
+        for (node, area) in served {
+            let duct = insert_infra(
+                hvac_node,
+                node,
+                edge_types::HVAC,
+                infra_types::HVAC_DUCT,
+                area.max(1.0),
+            );
+            ctx.db.air_duct().insert(AirDuct {
+                infra_edge_id: duct,
+                node_id: node,
+                fouling: 0.0,
+                airflow: 1.0,
+            });
+        }
+    }
 
     // Data connections
     insert_infra(
@@ -1442,15 +1636,99 @@ pub(super) fn generate_ship_systems(ctx: &ReducerContext) {
     );
 }
 
-pub(super) fn generate_atmospheres(ctx: &ReducerContext, deck_count: u32) {
-    for deck in 0..deck_count as i32 {
-        ctx.db.deck_atmosphere().insert(DeckAtmosphere {
-            deck,
-            oxygen: 0.21,
-            co2: 0.0004,
-            humidity: 0.45,
-            temperature: 22.0,
-            pressure: 101.3,
+/// Start `count` subsystems in repair rooms (engineering through the cooling
+/// plant) worn and degraded, for a scenario that teaches repairs.
+pub(super) fn wear_subsystems(ctx: &ReducerContext, count: u32) {
+    let repair_nodes: Vec<u64> = ctx
+        .db
+        .room()
+        .iter()
+        .filter(|r| (room_types::ENGINEERING..=room_types::COOLING_PLANT).contains(&r.room_type))
+        .map(|r| r.node_id)
+        .collect();
+    let worn: Vec<Subsystem> = ctx
+        .db
+        .subsystem()
+        .iter()
+        .filter(|s| repair_nodes.contains(&s.node_id))
+        .take(count as usize)
+        .collect();
+    for mut sub in worn {
+        log::info!("Scenario: {} starts worn", sub.name);
+        sub.health = 0.6;
+        sub.status = system_statuses::DEGRADED;
+        ctx.db.subsystem().id().update(sub);
+    }
+}
+
+/// Lay the coolant loop with radiators sized for the reactors at full
+/// output, the engines burning and `population` aboard, starting at the
+/// temperature it settles at with the engines cold.
+pub(super) fn generate_coolant_loop(ctx: &ReducerContext, population: u32) {
+    let (mut reactor_kw, mut chambers) = (0.0, 0.0);
+    for sub in ctx.db.subsystem().iter() {
+        match sub.subsystem_type {
+            subsystem_types::REACTOR_CORE => reactor_kw += sub.output,
+            subsystem_types::THRUST_CHAMBER => chambers += 1.0,
+            _ => {}
+        }
+    }
+    let people = population as f32;
+    let radiator_kw = design_heat_kw(reactor_kw, chambers, people);
+    let cruising = HeatSources::new(reactor_kw, chambers, 0.0, people, 0.0);
+    ctx.db.coolant_loop().insert(CoolantLoop {
+        id: 0,
+        temperature: equilibrium_c(cruising.total_kw(), radiator_kw),
+        radiator_kw,
+        heat_capacity: radiator_kw * THERMAL_HOURS,
+        coolant_flow: 1.0,
+        reactor_kw: cruising.reactor_kw,
+        engine_kw: 0.0,
+        cabin_kw: cruising.cabin_kw,
+        galley_kw: 0.0,
+        absorbed_kw: cruising.total_kw(),
+        radiated_kw: cruising.total_kw(),
+    });
+}
+
+/// Fill every room with normal air at its deck zone's setpoints.
+pub(super) fn generate_atmospheres(ctx: &ReducerContext) {
+    let zones: HashMap<i32, u8> = ctx
+        .db
+        .deck()
+        .iter()
+        .map(|d| (d.deck, d.primary_zone))
+        .collect();
+    for room in ctx.db.room().iter() {
+        let setpoint = zone_atmosphere(zones.get(&room.deck).copied().unwrap_or(0));
+        ctx.db.room_air().insert(RoomAir {
+            room_id: room.id,
+            deck: room.deck,
+            oxygen: NORMAL_O2,
+            co2: NORMAL_CO2,
+            humidity: setpoint.humidity,
+            temperature: setpoint.temperature,
+            pressure: KPA_PER_ATM,
+            target_temperature: setpoint.temperature,
+            target_humidity: setpoint.humidity,
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_infra_carries_flag() {
+        assert_eq!(
+            infra_carries_flag(infra_types::COOLANT_PIPE),
+            carries_flags::COOLANT
+        );
+        assert_eq!(
+            infra_carries_flag(infra_types::HVAC_DUCT),
+            carries_flags::HVAC
+        );
+        assert_eq!(infra_carries_flag(99), 0);
+    }
+}
@@ -4,6 +4,7 @@
 //! infrastructure layout, room packing, and door placement.
 //! Each enum variant wraps configuration for one strategy.
 
+use super::hull::HullProfile;
 use serde::{Deserialize, Serialize};
 
 // ============================================================================
@@ -15,6 +16,8 @@ use serde::{Deserialize, Serialize};
 pub enum HullShape {
     /// Rectangular hull with bow/stern taper.
     Rectangular(RectangularConfig),
+    /// Parametric hull with curved bow/stern taper and rounded deck ends.
+    Tapered(TaperedConfig),
 }
 
 /// Configuration for a rectangular hull with taper.
@@ -53,10 +56,32 @@ impl Default for RectangularConfig {
     }
 }
 
+/// Configuration for a parametric tapered hull.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaperedConfig {
+    /// Maximum beam (width) in meters at equator decks.
+    pub ship_beam: usize,
+    /// Maximum length in meters at equator decks.
+    pub ship_length: usize,
+    /// Taper curves and tip proportions.
+    pub profile: HullProfile,
+}
+
+impl Default for TaperedConfig {
+    fn default() -> Self {
+        Self {
+            ship_beam: 65,
+            ship_length: 400,
+            profile: HullProfile::default(),
+        }
+    }
+}
+
 impl HullShape {
     /// Get hull width for a given deck.
     pub fn width(&self, deck: u32, deck_count: u32) -> usize {
         match self {
+            HullShape::Tapered(cfg) => cfg.profile.width(deck, deck_count, cfg.ship_beam),
             HullShape::Rectangular(cfg) => {
                 if deck < cfg.bow_taper_decks {
                     cfg.bow_beam
@@ -72,6 +97,7 @@ impl HullShape {
     /// Get hull length for a given deck.
     pub fn length(&self, deck: u32, deck_count: u32) -> usize {
         match self {
+            HullShape::Tapered(cfg) => cfg.profile.length(deck, deck_count, cfg.ship_length),
             HullShape::Rectangular(cfg) => {
                 if deck < cfg.bow_taper_decks {
                     cfg.bow_length
@@ -217,7 +243,7 @@ pub struct ShipGenConfig {
 impl Default for ShipGenConfig {
     fn default() -> Self {
         Self {
-            hull: HullShape::Rectangular(RectangularConfig::default()),
+            hull: HullShape::Tapered(TaperedConfig::default()),
             infrastructure: InfraLayout::Spine(SpineConfig::default()),
             room_packer: RoomPacker::Treemap(TreemapConfig::default()),
             door_placer: DoorPlacer::GridAligned(GridDoorConfig::default()),
@@ -261,7 +287,8 @@ mod tests {
     fn test_default_ship_gen_config() {
         let config = ShipGenConfig::default();
         match &config.hull {
-            HullShape::Rectangular(cfg) => assert_eq!(cfg.ship_beam, 65),
+            HullShape::Tapered(cfg) => assert_eq!(cfg.ship_beam, 65),
+            HullShape::Rectangular(_) => panic!("Expected tapered hull"),
         }
         match &config.room_packer {
             RoomPacker::Treemap(cfg) => assert!((cfg.cap_factor - 1.5).abs() < 0.01),
@@ -290,17 +317,23 @@ mod tests {
 
     #[test]
     fn test_hull_matches_legacy() {
-        // Verify default config matches the old hull.rs hardcoded values
-        let hull = HullShape::Rectangular(RectangularConfig::default());
+        // Verify each tapered config matches the hull.rs profile used by layout
+        use crate::generation::hull::{hull_length, hull_width, TaperCurve};
         let deck_count = 20;
-        for deck in 0..deck_count {
-            let old_w = crate::generation::hull::hull_width(deck, deck_count, 65);
-            let new_w = hull.width(deck, deck_count);
-            assert_eq!(old_w, new_w, "Width mismatch at deck {deck}");
-
-            let old_l = crate::generation::hull::hull_length(deck, deck_count, 400);
-            let new_l = hull.length(deck, deck_count);
-            assert_eq!(old_l, new_l, "Length mismatch at deck {deck}");
+        for curve in [TaperCurve::Elliptical, TaperCurve::Linear, TaperCurve::Step] {
+            let hull = HullShape::Tapered(TaperedConfig {
+                profile: HullProfile::with_taper(curve),
+                ..Default::default()
+            });
+            for deck in 0..deck_count {
+                let old_w = hull_width(deck, deck_count, 65, curve);
+                let new_w = hull.width(deck, deck_count);
+                assert_eq!(old_w, new_w, "{curve:?} width mismatch at deck {deck}");
+
+                let old_l = hull_length(deck, deck_count, 400, curve);
+                let new_l = hull.length(deck, deck_count);
+                assert_eq!(old_l, new_l, "{curve:?} length mismatch at deck {deck}");
+            }
         }
     }
 }
@@ -8,6 +8,11 @@
 #![allow(clippy::type_complexity)]
 #![allow(dead_code)]
 #![allow(unused_assignments)]
+// A panic aborts the whole tick; recover and report with simulation::warn
+#![cfg_attr(
+    not(test),
+    deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)
+)]
 
 mod generation;
 mod reducers;
@@ -3,17 +3,59 @@
 use crate::simulation;
 use crate::tables::*;
 use progship_logic::actions::{apply_needs_deltas, compute_action_effect, NeedsValues};
+use progship_logic::command;
+use progship_logic::doors;
+use progship_logic::frame::{self, Frame, FrameEvent, FRAME_EXPORT_KEEP};
+use progship_logic::genlib::hull::TaperCurve;
+use progship_logic::genlib::layout::CorridorTopology;
+use progship_logic::inventory::starting_items;
 use progship_logic::movement::{compute_move, DoorInfo, MoveInput, MoveResult, RoomBounds};
-use spacetimedb::{reducer, ReducerContext, Table};
+use progship_logic::names::NAME_POOLS;
+use progship_logic::nicknames;
+use progship_logic::objectives::Deed;
+use progship_logic::orders;
+use progship_logic::plumbing::dry_action_effect;
+use progship_logic::shuttles::launch_blocker;
+use progship_logic::snapshot::{
+    autosnapshot_due, exports_to_prune, SnapshotCrew, SnapshotPerson, SnapshotResources,
+    SnapshotRoom, StateSnapshot, AUTOSNAPSHOT_INTERVAL_HOURS, AUTOSNAPSHOT_KEEP, SNAPSHOT_VERSION,
+};
+use progship_logic::stream;
+use progship_logic::timeline::TimelineKind;
+use spacetimedb::{reducer, Identity, ReducerContext, Table};
 
 // ============================================================================
 // PLAYER REDUCERS
 // ============================================================================
 
+/// Called when the module is first published: the publisher owns the
+/// ship, and the stream starts with no operators and the default votes and
+/// cooldowns
+#[reducer(init)]
+pub fn init(ctx: &ReducerContext) {
+    ctx.db.ship_owner().insert(ShipOwner {
+        id: 0,
+        owner: ctx.sender,
+    });
+    ctx.db.stream_config().insert(StreamConfig {
+        id: 0,
+        operators: Vec::new(),
+        allowed_votes: stream::DEFAULT_ALLOWED_VOTES,
+        cooldown_seconds: Vec::new(),
+    });
+}
+
 /// Called when a client connects
 #[reducer(client_connected)]
 pub fn client_connected(ctx: &ReducerContext) {
     log::info!("Client connected: {:?}", ctx.sender);
+    // A crash can skip client_disconnected; keep the old row's character
+    if let Some(mut player) = ctx.db.connected_player().identity().find(ctx.sender) {
+        log::warn!("{:?} was still connected", ctx.sender);
+        player.connected_at = ctx.timestamp;
+        ctx.db.connected_player().identity().update(player);
+        return;
+    }
     ctx.db.connected_player().insert(ConnectedPlayer {
         identity: ctx.sender,
         person_id: None,
@@ -58,6 +100,10 @@ pub fn player_join(ctx: &ReducerContext, given_name: String, family_name: String
             is_player: true,
             is_alive: true,
             owner_identity: Some(ctx.sender),
+            age: 30,
+            birth_date: progship_logic::demographics::birth_date(30, 0.0),
+            life_stage: life_stages::ADULT,
+            clearance: progship_logic::security::clearance(is_crew, ranks::CREWMAN),
         })
         .id;
 
@@ -133,6 +179,9 @@ pub fn player_join(ctx: &ReducerContext, given_name: String, family_name: String
         started_at: 0.0,
         duration: 0.5,
         target_room_id: None,
+        animation_state: postures::STANDING,
+        target_anchor_id: None,
+        target_person_id: None,
     });
 
     if is_crew {
@@ -152,12 +201,18 @@ pub fn player_join(ctx: &ReducerContext, given_name: String, family_name: String
             profession: "Colonist".to_string(),
         });
     }
+    // Players board with the same kit as anyone, minus contraband
+    let department = is_crew.then_some(departments::OPERATIONS);
+    for kind in starting_items(department, 1.0) {
+        simulation::add_item(ctx, person_id, kind, current_sim_time(ctx));
+    }
 
     // Link player to connection
     if let Some(mut player) = ctx.db.connected_player().identity().find(ctx.sender) {
         player.person_id = Some(person_id);
         ctx.db.connected_player().identity().update(player);
     }
+    simulation::start_objectives(ctx, person_id);
 
     log::info!("Player character created with id {}", person_id);
 }
@@ -165,6 +220,10 @@ pub fn player_join(ctx: &ReducerContext, given_name: String, family_name: String
 /// Player movement input — bounded to room, can move through doors
 #[reducer]
 pub fn player_move(ctx: &ReducerContext, dx: f32, dy: f32) {
+    if !dx.is_finite() || !dy.is_finite() {
+        log::warn!("Ignoring non-finite move ({}, {})", dx, dy);
+        return;
+    }
     let Some(player) = ctx.db.connected_player().identity().find(ctx.sender) else {
         return;
     };
@@ -205,7 +264,7 @@ pub fn player_move(ctx: &ReducerContext, dx: f32, dy: f32) {
                 door_x: d.door_x,
                 door_y: d.door_y,
                 width: d.width,
-                is_open: d.is_open,
+                is_open: doors::is_passable(d.state),
             })
             .collect();
 
@@ -273,10 +332,26 @@ pub fn player_move(ctx: &ReducerContext, dx: f32, dy: f32) {
             final_y = cy;
         }
 
+        let entered = new_room != pos.room_id;
         pos.room_id = new_room;
         pos.x = final_x;
         pos.y = final_y;
         ctx.db.position().person_id().update(pos);
+        if entered {
+            if let Some(room) = ctx.db.room().id().find(new_room) {
+                let room_type = room.room_type;
+                simulation::record_deed(ctx, person_id, Deed::EnteredRoom { room_type });
+            }
+        }
+
+        // Walking gets the player off the bed or out of the chair
+        if let Some(mut act) = ctx.db.activity().person_id().find(person_id) {
+            if act.animation_state != postures::STANDING {
+                act.animation_state = postures::STANDING;
+                act.target_anchor_id = None;
+                ctx.db.activity().person_id().update(act);
+            }
+        }
     }
 }
 
@@ -289,6 +364,10 @@ pub fn player_interact(ctx: &ReducerContext, target_person_id: u64) {
     let Some(person_id) = player.person_id else {
         return;
     };
+    if person_id == target_person_id {
+        log::warn!("Can't interact with yourself");
+        return;
+    }
 
     // Check they're in the same room
     let Some(my_pos) = ctx.db.position().person_id().find(person_id) else {
@@ -350,9 +429,51 @@ pub fn player_interact(ctx: &ReducerContext, target_person_id: u64) {
         person_id: target_person_id,
         conversation_id: conv_id,
     });
+    simulation::set_interaction_target(ctx, person_id, Some(target_person_id));
+    simulation::set_interaction_target(ctx, target_person_id, Some(person_id));
+    simulation::record_deed(ctx, person_id, Deed::Talked);
+    for (person, other) in [(person_id, target_person_id), (target_person_id, person_id)] {
+        simulation::record_timeline(
+            ctx,
+            person,
+            TimelineKind::Conversation,
+            other as u32,
+            sim_time,
+        );
+    }
 }
 
-/// Player toggles a nearby door open/closed
+/// Player hands something they carry to a person in the same room, in
+/// trade or as a gift.
+#[reducer]
+pub fn give_item(ctx: &ReducerContext, item_id: u64, target_person_id: u64) {
+    let Some(person_id) = ctx
+        .db
+        .connected_player()
+        .identity()
+        .find(ctx.sender)
+        .and_then(|p| p.person_id)
+    else {
+        return;
+    };
+    let room_of = |id: u64| ctx.db.position().person_id().find(id).map(|p| p.room_id);
+    if person_id == target_person_id || room_of(person_id) != room_of(target_person_id) {
+        log::warn!("Can't give - not in same room");
+        return;
+    }
+    if !simulation::give_item(
+        ctx,
+        item_id,
+        person_id,
+        target_person_id,
+        current_sim_time(ctx),
+    ) {
+        log::warn!("Can't give item {} to person {}", item_id, target_person_id);
+    }
+}
+
+/// Player toggles a nearby door open/closed; locked and welded doors need
+/// `set_door_state`.
 #[reducer]
 pub fn toggle_door(ctx: &ReducerContext, door_id: u64) {
     let Some(player) = ctx.db.connected_player().identity().find(ctx.sender) else {
@@ -373,22 +494,68 @@ pub fn toggle_door(ctx: &ReducerContext, door_id: u64) {
         return;
     }
 
-    // Check access level for locked doors
-    if door.is_locked {
-        let Some(_person) = ctx.db.person().id().find(person_id) else {
-            return;
-        };
-        // Crew can unlock doors matching their access level
-        let Some(crew) = ctx.db.crew().person_id().find(person_id) else {
-            return; // Non-crew can't unlock
-        };
-        if crew.rank < door.access_level {
-            return; // Insufficient rank
-        }
+    let next = doors::toggled(door.state);
+    if next == door.state {
+        log::warn!(
+            "Door {} is {}",
+            door_id,
+            progship_logic::constants::door_states::name(door.state)
+        );
+        return;
     }
+    door.state = next;
+    ctx.db.door().id().update(door);
+    simulation::refresh_evacuation_routes(ctx);
+    simulation::record_deed(ctx, person_id, Deed::ToggledDoor);
+}
 
-    door.is_open = !door.is_open;
+/// Open, close, lock or weld a door (see door_states module). Players must
+/// stand by the door: security crew lock and unlock, engineering crew weld
+/// and cut free, officers do either. Clients that never joined a character
+/// may not change doors.
+#[reducer]
+pub fn set_door_state(ctx: &ReducerContext, door_id: u64, state: u8) {
+    let Some(mut door) = ctx.db.door().id().find(door_id) else {
+        log::warn!("No door {}", door_id);
+        return;
+    };
+    let player = sender_person_id(ctx);
+    let joined = match player {
+        Some(_) => {
+            let Some((person, department)) = player_with_department(ctx) else {
+                return;
+            };
+            let beside = ctx
+                .db
+                .position()
+                .person_id()
+                .find(person.id)
+                .is_some_and(|p| p.room_id == door.room_a || p.room_id == door.room_b);
+            if !beside {
+                return;
+            }
+            Some((person.clearance, department))
+        }
+        None => None,
+    };
+    let Some((clearance, department)) =
+        doors::caller_clearance(joined, ctx.sender == ctx.identity())
+    else {
+        log::warn!("{:?} has no character to change doors with", ctx.sender);
+        return;
+    };
+    if !doors::may_set_state(door.state, state, clearance, department) {
+        log::warn!(
+            "Door {} can't go from {} to {}",
+            door_id,
+            progship_logic::constants::door_states::name(door.state),
+            progship_logic::constants::door_states::name(state)
+        );
+        return;
+    }
+    door.state = state;
     ctx.db.door().id().update(door);
+    simulation::refresh_evacuation_routes(ctx);
 }
 
 /// Player performs an action at their current location
@@ -438,18 +605,41 @@ pub fn player_action(ctx: &ReducerContext, action: u8) {
         }
         if repaired {
             if let Some(mut act) = ctx.db.activity().person_id().find(person_id) {
-                act.activity_type = activity_types::MAINTENANCE;
-                act.started_at = sim_time;
-                act.duration = 0.25;
+                simulation::restart_activity(&mut act, activity_types::MAINTENANCE, sim_time, 0.25);
                 ctx.db.activity().person_id().update(act);
             }
+            simulation::record_deed(ctx, person_id, Deed::Acted { action });
         }
         return;
     }
 
     // All other actions use the extracted pure logic
+    let dry = ctx
+        .db
+        .room_water()
+        .room_id()
+        .find(room.id)
+        .is_some_and(|w| !w.supplied);
     match compute_action_effect(action, room.room_type) {
         Some(effect) => {
+            // No washing and only cold meals while the room is dry
+            let Some(effect) = (if dry {
+                dry_action_effect(effect)
+            } else {
+                Some(effect)
+            }) else {
+                log::info!("No water for action {} in room {}", action, room.id);
+                return;
+            };
+            // A meal has to come out of the galley serving this deck
+            if effect.activity_type == activity_types::EATING {
+                let mut servery = simulation::Servery::load(ctx);
+                if !servery.serve(room.deck, 1.0) {
+                    log::info!("No meals ready for deck {}", room.deck);
+                    return;
+                }
+                servery.save(ctx);
+            }
             let result = apply_needs_deltas(
                 &NeedsValues {
                     hunger: needs.hunger,
@@ -472,11 +662,15 @@ pub fn player_action(ctx: &ReducerContext, action: u8) {
             ctx.db.needs().person_id().update(needs);
 
             if let Some(mut act) = ctx.db.activity().person_id().find(person_id) {
-                act.activity_type = effect.activity_type;
-                act.started_at = sim_time;
-                act.duration = effect.duration;
+                simulation::restart_activity(
+                    &mut act,
+                    effect.activity_type,
+                    sim_time,
+                    effect.duration,
+                );
                 ctx.db.activity().person_id().update(act);
             }
+            simulation::record_deed(ctx, person_id, Deed::Acted { action });
         }
         None => {
             log::warn!("Invalid action {} for room type {}", action, room.room_type);
@@ -537,6 +731,7 @@ pub fn player_use_elevator(ctx: &ReducerContext, target_deck: i32) {
             p.y = target_room.y;
             ctx.db.position().person_id().update(p);
             log::info!("Player took elevator to deck {}", target_deck + 1);
+            simulation::record_deed(ctx, person_id, Deed::ChangedDeck);
         }
     } else {
         log::warn!("No elevator connection to deck {}", target_deck + 1);
@@ -583,6 +778,7 @@ pub fn player_use_ladder(ctx: &ReducerContext, direction: i32) {
                 p.y = other_room.y;
                 ctx.db.position().person_id().update(p);
                 log::info!("Player climbed ladder to deck {}", target_deck + 1);
+                simulation::record_deed(ctx, person_id, Deed::ChangedDeck);
                 return;
             }
         }
@@ -590,6 +786,96 @@ pub fn player_use_ladder(ctx: &ReducerContext, direction: i32) {
     log::warn!("No ladder connection in that direction");
 }
 
+/// Player opened a client screen (see ui_screens module), for scenario
+/// objectives
+#[reducer]
+pub fn player_view_screen(ctx: &ReducerContext, screen: u8) {
+    let Some(person_id) = ctx
+        .db
+        .connected_player()
+        .identity()
+        .find(ctx.sender)
+        .and_then(|p| p.person_id)
+    else {
+        return;
+    };
+    simulation::record_deed(ctx, person_id, Deed::ViewedScreen { screen });
+}
+
+/// Give a room a nickname shown in place of its generated name; an empty
+/// name clears it. Officers may name any room, other crew the rooms their
+/// department runs.
+#[reducer]
+pub fn set_room_nickname(ctx: &ReducerContext, room_id: u32, name: String) {
+    let Some((person, department)) = player_with_department(ctx) else {
+        return;
+    };
+    let Some(room) = ctx.db.room().id().find(room_id) else {
+        return;
+    };
+    if !nicknames::may_name_room(person.clearance, department, room.room_type) {
+        log::warn!("{} may not rename {}", person.given_name, room.name);
+        return;
+    }
+    ctx.db.room_nickname().room_id().delete(room_id);
+    if let Some(name) = nicknames::clean_nickname(&name) {
+        log::info!("{} renamed {} to {}", person.given_name, room.name, name);
+        ctx.db.room_nickname().insert(RoomNickname {
+            room_id,
+            name,
+            named_by: person.id,
+        });
+    }
+}
+
+/// Give a deck a nickname shown in place of its generated name; an empty
+/// name clears it. Officers only.
+#[reducer]
+pub fn set_deck_nickname(ctx: &ReducerContext, deck: i32, name: String) {
+    let Some((person, _)) = player_with_department(ctx) else {
+        return;
+    };
+    if ctx.db.deck().deck().find(deck).is_none() {
+        return;
+    }
+    if !nicknames::may_name_deck(person.clearance) {
+        log::warn!("{} may not rename deck {}", person.given_name, deck + 1);
+        return;
+    }
+    ctx.db.deck_nickname().deck().delete(deck);
+    if let Some(name) = nicknames::clean_nickname(&name) {
+        log::info!(
+            "{} renamed deck {} to {}",
+            person.given_name,
+            deck + 1,
+            name
+        );
+        ctx.db.deck_nickname().insert(DeckNickname {
+            deck,
+            name,
+            named_by: person.id,
+        });
+    }
+}
+
+/// The calling player's Person and crew department (`None` for passengers).
+fn player_with_department(ctx: &ReducerContext) -> Option<(Person, Option<u8>)> {
+    let person_id = ctx
+        .db
+        .connected_player()
+        .identity()
+        .find(ctx.sender)?
+        .person_id?;
+    let person = ctx.db.person().id().find(person_id)?;
+    let department = ctx
+        .db
+        .crew()
+        .person_id()
+        .find(person_id)
+        .map(|c| c.department);
+    Some((person, department))
+}
+
 /// Find an elevator room on target_deck connected (possibly through chain) to start_room
 fn find_elevator_on_deck(ctx: &ReducerContext, start_room: u32, target_deck: i32) -> Option<u32> {
     // BFS through elevator connections
@@ -636,9 +922,13 @@ fn find_elevator_on_deck(ctx: &ReducerContext, start_room: u32, target_deck: i32
 // SIMULATION CONTROL REDUCERS
 // ============================================================================
 
-/// Pause/unpause the simulation
+/// Pause/unpause the simulation. A lost ship stays paused.
 #[reducer]
 pub fn set_paused(ctx: &ReducerContext, paused: bool) {
+    if !paused && simulation::ship_was_lost(ctx) {
+        log::warn!("Ship lost - the voyage is over");
+        return;
+    }
     if let Some(mut config) = ctx.db.ship_config().id().find(0) {
         config.paused = paused;
         ctx.db.ship_config().id().update(config);
@@ -656,6 +946,921 @@ pub fn set_time_scale(ctx: &ReducerContext, scale: f32) {
     }
 }
 
+/// Launch a docked shuttle from its bay. A player must hold a command post.
+#[reducer]
+pub fn launch_shuttle(ctx: &ReducerContext, shuttle_id: u64) {
+    if !may_command(ctx) {
+        return;
+    }
+    let Some(mut shuttle) = ctx.db.shuttle().id().find(shuttle_id) else {
+        log::warn!("No shuttle {}", shuttle_id);
+        return;
+    };
+    if shuttle.state != shuttle_states::DOCKED {
+        log::warn!("Shuttle {} is already away", shuttle.name);
+        return;
+    }
+    if let Some(reason) = launch_blocker(shuttle.fuel, shuttle.fuel_cap) {
+        log::warn!("Shuttle {} can't launch: {}", shuttle.name, reason);
+        return;
+    }
+    let sim_time = ctx
+        .db
+        .ship_config()
+        .id()
+        .find(0)
+        .map(|c| c.sim_time)
+        .unwrap_or(0.0);
+    shuttle.state = shuttle_states::LAUNCHED;
+    shuttle.launched_at = Some(sim_time);
+    log::info!("Shuttle {} launched", shuttle.name);
+    ctx.db.shuttle().id().update(shuttle);
+}
+
+/// Recall a launched shuttle to its bay. A player must hold a command post.
+#[reducer]
+pub fn recall_shuttle(ctx: &ReducerContext, shuttle_id: u64) {
+    if !may_command(ctx) {
+        return;
+    }
+    let Some(mut shuttle) = ctx.db.shuttle().id().find(shuttle_id) else {
+        log::warn!("No shuttle {}", shuttle_id);
+        return;
+    };
+    if shuttle.state != shuttle_states::LAUNCHED {
+        log::warn!("Shuttle {} is already docked", shuttle.name);
+        return;
+    }
+    simulation::dock(&mut shuttle);
+    log::info!("Shuttle {} recalled", shuttle.name);
+    ctx.db.shuttle().id().update(shuttle);
+}
+
+/// Set how many simulation days finished events, conversations and
+/// work orders are kept before being compacted into daily history. Only the
+/// ship's owner may.
+#[reducer]
+pub fn set_history_retention(ctx: &ReducerContext, retention_days: f64) {
+    if !may_configure_ship(ctx) {
+        return;
+    }
+    let config = HistoryConfig {
+        id: 0,
+        retention_days: retention_days.max(0.0),
+    };
+    log::info!("History retention set to {:.1} days", config.retention_days);
+    if ctx.db.history_config().id().find(0).is_some() {
+        ctx.db.history_config().id().update(config);
+    } else {
+        ctx.db.history_config().insert(config);
+    }
+}
+
+/// Set how fast needs build up by age, personality and fitness (multipliers;
+/// see `progship_logic::balance::BalanceConfig` for the defaults). Only the
+/// ship's owner may.
+#[reducer]
+pub fn set_balance_config(
+    ctx: &ReducerContext,
+    child_hunger: f32,
+    infant_fatigue: f32,
+    elder_fatigue: f32,
+    extraversion_social: f32,
+    neuroticism_comfort: f32,
+    unfit_fatigue: f32,
+) {
+    if !may_configure_ship(ctx) {
+        return;
+    }
+    let config = BalanceConfig {
+        id: 0,
+        child_hunger: child_hunger.max(0.0),
+        infant_fatigue: infant_fatigue.max(0.0),
+        elder_fatigue: elder_fatigue.max(0.0),
+        extraversion_social: extraversion_social.max(0.0),
+        neuroticism_comfort: neuroticism_comfort.max(0.0),
+        unfit_fatigue: unfit_fatigue.max(0.0),
+    };
+    log::info!(
+        "Balance config set: {:?}",
+        simulation::balance_from_row(&config)
+    );
+    if ctx.db.balance_config().id().find(0).is_some() {
+        ctx.db.balance_config().id().update(config);
+    } else {
+        ctx.db.balance_config().insert(config);
+    }
+}
+
+/// Turn optional simulation systems on or off: illness outbreaks, aging
+/// and old age, crime, politics, and hardcore atmosphere (see
+/// `progship_logic::features::FeatureFlags` for the defaults). Only the
+/// ship's owner may.
+#[reducer]
+pub fn set_feature_flags(
+    ctx: &ReducerContext,
+    disease: bool,
+    generational: bool,
+    crime: bool,
+    politics: bool,
+    hardcore_atmosphere: bool,
+) {
+    if !may_configure_ship(ctx) {
+        return;
+    }
+    let flags = FeatureFlags {
+        id: 0,
+        disease,
+        generational,
+        crime,
+        politics,
+        hardcore_atmosphere,
+    };
+    log::info!(
+        "Feature flags set: {:?}",
+        simulation::features_from_row(&flags)
+    );
+    if ctx.db.feature_flags().id().find(0).is_some() {
+        ctx.db.feature_flags().id().update(flags);
+    } else {
+        ctx.db.feature_flags().insert(flags);
+    }
+}
+
+/// Pin a zone (0=command .. 6=engineering) to decks `first_deck..end_deck`
+/// for the next init_ship; the other zones are laid out around it. Only the
+/// ship's owner may.
+#[reducer]
+pub fn set_zone_decks(ctx: &ReducerContext, zone: u8, first_deck: u32, end_deck: u32) {
+    if !may_configure_ship(ctx) {
+        return;
+    }
+    if zone > 6 || end_deck <= first_deck {
+        log::warn!("set_zone_decks: invalid zone {zone} decks {first_deck}..{end_deck}");
+        return;
+    }
+    if ctx.db.ship_config().id().find(0).is_some() {
+        log::warn!("set_zone_decks: ship already generated, applies from the next init_ship");
+    }
+    let row = ZoneConfig {
+        zone,
+        first_deck,
+        end_deck,
+        overridden: true,
+    };
+    if ctx.db.zone_config().zone().find(zone).is_some() {
+        ctx.db.zone_config().zone().update(row);
+    } else {
+        ctx.db.zone_config().insert(row);
+    }
+}
+
+/// Set a naming culture's share of the population (relative weight; see
+/// `progship_logic::names::NAME_POOLS`) for the next init_ship. Only the
+/// ship's owner may.
+#[reducer]
+pub fn set_culture_weight(ctx: &ReducerContext, culture: u8, weight: f32) {
+    if !may_configure_ship(ctx) {
+        return;
+    }
+    let Some(pool) = NAME_POOLS.get(culture as usize) else {
+        log::warn!("set_culture_weight: no culture {culture}");
+        return;
+    };
+    if !weight.is_finite() || weight < 0.0 {
+        log::warn!("set_culture_weight: invalid weight {weight}");
+        return;
+    }
+    if ctx.db.ship_config().id().find(0).is_some() {
+        log::warn!("set_culture_weight: ship already generated, applies from the next init_ship");
+    }
+    log::info!("{} names weighted {:.2}", pool.culture, weight);
+    let row = CultureMix { culture, weight };
+    if ctx.db.culture_mix().culture().find(culture).is_some() {
+        ctx.db.culture_mix().culture().update(row);
+    } else {
+        ctx.db.culture_mix().insert(row);
+    }
+}
+
+/// Debug: explain why an NPC picked their current activity
+///
+/// Writes every candidate with its score factors to ActivityExplanation and
+/// keeps the row current as the NPC picks new activities.
+#[reducer]
+pub fn explain_activity(ctx: &ReducerContext, person_id: u64) {
+    let sim_time = ctx
+        .db
+        .ship_config()
+        .id()
+        .find(0)
+        .map(|c| c.sim_time)
+        .unwrap_or(0.0);
+    if !simulation::explain_current_activity(ctx, person_id, sim_time) {
+        log::warn!("Cannot explain activity of person {}", person_id);
+    }
+}
+
+/// Give a bulk order (see order_kinds module) for `hours` of sim time: all
+/// of `department` to damage control, security to sweep `deck`, or
+/// non-essential crew to quarters. A player must hold a command post that
+/// covers the order; crew comply according to morale and discipline.
+#[reducer]
+pub fn issue_order(ctx: &ReducerContext, kind: u8, department: u8, deck: i32, hours: f64) {
+    if kind > order_kinds::TO_QUARTERS {
+        log::warn!("Unknown order kind {}", kind);
+        return;
+    }
+    let issuer = sender_person_id(ctx);
+    let Some(person_id) = issuer else {
+        log::warn!("{:?} has no character to give orders with", ctx.sender);
+        return;
+    };
+    let allowed = ctx
+        .db
+        .command_chain()
+        .person_id()
+        .find(person_id)
+        .is_some_and(|c| orders::may_issue(kind, c.post, c.department, department));
+    if !allowed {
+        log::warn!(
+            "Person {} may not order {}",
+            person_id,
+            progship_logic::constants::order_kinds::name(kind)
+        );
+        return;
+    }
+    let sim_time = ctx
+        .db
+        .ship_config()
+        .id()
+        .find(0)
+        .map(|c| c.sim_time)
+        .unwrap_or(0.0);
+    let (order_id, complied) =
+        simulation::give_order(ctx, kind, department, deck, hours, issuer, sim_time);
+    log::info!(
+        "Order {} ({}) given; {} crew complying",
+        order_id,
+        progship_logic::constants::order_kinds::name(kind),
+        complied
+    );
+}
+
+/// Withdraw a standing order early; only the officer who gave it may
+#[reducer]
+pub fn cancel_order(ctx: &ReducerContext, order_id: u64) {
+    let Some(order) = ctx.db.standing_order().id().find(order_id) else {
+        log::warn!("No order {}", order_id);
+        return;
+    };
+    let issuer = sender_person_id(ctx);
+    if issuer.is_none() || issuer != order.issued_by {
+        log::warn!("Only the issuing officer may withdraw order {}", order_id);
+        return;
+    }
+    simulation::withdraw_order(ctx, order_id);
+}
+
+/// Meet a mutiny's demand so the mutineers go back to work. A player must
+/// hold a command post.
+#[reducer]
+pub fn negotiate_mutiny(ctx: &ReducerContext, mutiny_id: u64) {
+    if !may_command(ctx) {
+        return;
+    }
+    if !simulation::negotiate_mutiny(ctx, mutiny_id, current_sim_time(ctx)) {
+        log::warn!("No mutiny {} going on", mutiny_id);
+    }
+}
+
+/// Send security in to retake a mutinous department's station and detain
+/// its ringleaders. A player must hold a command post.
+#[reducer]
+pub fn suppress_mutiny(ctx: &ReducerContext, mutiny_id: u64) {
+    if !may_command(ctx) {
+        return;
+    }
+    if !simulation::suppress_mutiny(ctx, mutiny_id, current_sim_time(ctx)) {
+        log::warn!("No mutiny {} going on", mutiny_id);
+    }
+}
+
+/// Shift a deck's day, evening and night later by `hours` (earlier when
+/// negative), up to 12 hours from the ship day. A player must hold a
+/// command post.
+#[reducer]
+pub fn shift_lighting(ctx: &ReducerContext, deck: i32, hours: f32) {
+    if !may_command(ctx) {
+        return;
+    }
+    if !simulation::shift_lighting(ctx, deck, hours, current_sim_time(ctx)) {
+        log::warn!("No deck {}", deck);
+    }
+}
+
+/// Whether the sender's character holds a command post. Clients that never
+/// joined a character hold none.
+fn may_command(ctx: &ReducerContext) -> bool {
+    let person_id = sender_person_id(ctx);
+    let holds_post =
+        person_id.is_some_and(|id| ctx.db.command_chain().person_id().find(id).is_some());
+    if let Some(reason) = command::command_refusal(person_id, holds_post) {
+        log::warn!("{:?} {}", ctx.sender, reason);
+        return false;
+    }
+    true
+}
+
+/// Whether the sender may change ship-wide configuration: only the ship's
+/// owner, whoever published the module, may.
+fn may_configure_ship(ctx: &ReducerContext) -> bool {
+    let owner = ctx.db.ship_owner().id().find(0).map(|o| o.owner);
+    if !stream::may_configure(owner.as_ref(), &ctx.sender, &ctx.identity()) {
+        log::warn!("{:?} does not own the ship", ctx.sender);
+        return false;
+    }
+    true
+}
+
+/// The character the sender plays, or `None` for a client that never
+/// joined one (permission checks refuse those).
+fn sender_person_id(ctx: &ReducerContext) -> Option<u64> {
+    ctx.db
+        .connected_player()
+        .identity()
+        .find(ctx.sender)
+        .and_then(|p| p.person_id)
+}
+
+// ============================================================================
+// STREAM HOOKS
+// ============================================================================
+
+/// Set who may send stream actions besides the caller, which chat votes
+/// go through (bitmask of stream_votes IDs) and the real seconds between
+/// actions of each kind (indexed by stream_actions; missing kinds keep
+/// their defaults). Only the ship's owner, whoever published the module,
+/// may configure it.
+#[reducer]
+pub fn configure_stream(
+    ctx: &ReducerContext,
+    operators: Vec<Identity>,
+    allowed_votes: u32,
+    cooldown_seconds: Vec<u32>,
+) {
+    if !may_configure_ship(ctx) {
+        return;
+    }
+    let config = StreamConfig {
+        id: 0,
+        operators,
+        allowed_votes,
+        cooldown_seconds,
+    };
+    log::info!(
+        "Stream configured: {} operators, votes {:#b}, cooldowns {:?}",
+        config.operators.len(),
+        config.allowed_votes,
+        config.cooldown_seconds
+    );
+    if ctx.db.stream_config().id().find(0).is_some() {
+        ctx.db.stream_config().id().update(config);
+    } else {
+        ctx.db.stream_config().insert(config);
+    }
+}
+
+/// Carry out a chat vote (see stream_votes), e.g. a surprise inspection
+/// of a deck or a celebration, if the streamer allows it
+#[reducer]
+pub fn stream_vote(ctx: &ReducerContext, vote: u8) {
+    let allowed = ctx
+        .db
+        .stream_config()
+        .id()
+        .find(0)
+        .is_some_and(|c| stream::vote_allowed(c.allowed_votes, vote));
+    if !simulation::may_stream(ctx, stream_actions::VOTE) {
+        return;
+    }
+    if !allowed {
+        log::warn!("Vote {} is not allowed on this stream", vote);
+        return;
+    }
+    let sim_time = current_sim_time(ctx);
+    if simulation::carry_out_vote(ctx, vote, sim_time) {
+        let name = progship_logic::constants::stream_votes::name(vote);
+        simulation::record_stream_action(ctx, stream_actions::VOTE, name.into(), sim_time);
+    }
+}
+
+/// Give a subscriber's name to the next baby born aboard (or one born in
+/// the last week still without one)
+#[reducer]
+pub fn stream_name_newborn(ctx: &ReducerContext, name: String) {
+    let Some(name) = stream::clean_viewer_name(&name) else {
+        log::warn!("Subscriber name has no usable letters");
+        return;
+    };
+    if !simulation::may_stream(ctx, stream_actions::NAME_NEWBORN) {
+        return;
+    }
+    let sim_time = current_sim_time(ctx);
+    ctx.db.newborn_name().insert(NewbornName {
+        id: 0,
+        name: name.clone(),
+        queued_at: sim_time,
+        person_id: None,
+    });
+    simulation::name_newborns(ctx, sim_time);
+    simulation::record_stream_action(ctx, stream_actions::NAME_NEWBORN, name, sim_time);
+}
+
+/// Put an NPC in the stream's spotlight with a caption for a couple of sim
+/// hours, replacing whoever was there
+#[reducer]
+pub fn stream_spotlight(ctx: &ReducerContext, person_id: u64, caption: String) {
+    let Some(person) = ctx
+        .db
+        .person()
+        .id()
+        .find(person_id)
+        .filter(|p| p.is_alive && !p.is_player)
+    else {
+        log::warn!("No living NPC {} to spotlight", person_id);
+        return;
+    };
+    if !simulation::may_stream(ctx, stream_actions::SPOTLIGHT) {
+        return;
+    }
+    let sim_time = current_sim_time(ctx);
+    let spotlight = Spotlight {
+        id: 0,
+        person_id,
+        caption: nicknames::clean_nickname(&caption).unwrap_or_default(),
+        until: sim_time + stream::SPOTLIGHT_HOURS,
+    };
+    if ctx.db.spotlight().id().find(0).is_some() {
+        ctx.db.spotlight().id().update(spotlight);
+    } else {
+        ctx.db.spotlight().insert(spotlight);
+    }
+    let name = format!("{} {}", person.given_name, person.family_name);
+    simulation::record_stream_action(ctx, stream_actions::SPOTLIGHT, name, sim_time);
+}
+
+/// Simulation time in hours, or 0 before the ship exists
+fn current_sim_time(ctx: &ReducerContext) -> f64 {
+    ctx.db
+        .ship_config()
+        .id()
+        .find(0)
+        .map(|c| c.sim_time)
+        .unwrap_or(0.0)
+}
+
+// ============================================================================
+// STATE EXPORT
+// ============================================================================
+
+/// Snapshot the whole ship into the state_export table as JSON, so it can be
+/// pulled with `spacetime sql` and opened offline in the viewer
+#[reducer]
+pub fn export_state(ctx: &ReducerContext) {
+    let snapshot = build_snapshot(ctx);
+    log::info!(
+        "Exported state at t={:.2}: {} rooms, {} people",
+        snapshot.sim_time,
+        snapshot.rooms.len(),
+        snapshot.people.len()
+    );
+    ctx.db.state_export().insert(StateExport {
+        id: 0,
+        sim_time: snapshot.sim_time,
+        data: snapshot.to_json(),
+        automatic: false,
+    });
+}
+
+/// Write one compact binary frame of the ship to the frame_export table for
+/// renderers outside SpacetimeDB: a key frame, or with `delta` only what
+/// changed since the last export (a key frame when there is nothing to
+/// build on). Only the ship's owner may.
+#[reducer]
+pub fn export_frame(ctx: &ReducerContext, delta: bool) {
+    if !may_configure_ship(ctx) {
+        return;
+    }
+    let frame = build_frame(ctx);
+    let mut exports: Vec<FrameExport> = ctx.db.frame_export().iter().collect();
+    exports.sort_by_key(|e| e.id);
+    let base = if delta { replay_frames(&exports) } else { None };
+    let (data, base_id) = match base {
+        Some((base_id, base)) => (base.diff(&frame).encode(), Some(base_id)),
+        None => (frame.encode(), None),
+    };
+    log::info!(
+        "Exported {} frame at t={:.2}: {} bytes",
+        if base_id.is_some() { "delta" } else { "key" },
+        frame.sim_time,
+        data.len()
+    );
+    ctx.db.frame_export().insert(FrameExport {
+        id: 0,
+        sim_time: frame.sim_time,
+        data,
+        base_id,
+    });
+    let ids = ctx.db.frame_export().iter().map(|e| e.id).collect();
+    for id in exports_to_prune(ids, FRAME_EXPORT_KEEP) {
+        ctx.db.frame_export().id().delete(id);
+    }
+}
+
+/// The newest exported frame and its export ID, rebuilt from the last key
+/// frame onward; None when no key frame is left
+fn replay_frames(exports: &[FrameExport]) -> Option<(u64, Frame)> {
+    let key = exports.iter().rposition(|e| e.base_id.is_none())?;
+    let latest = exports.last()?.id;
+    match frame::replay(exports[key..].iter().map(|e| e.data.as_slice())) {
+        Ok(frame) => Some((latest, frame)),
+        Err(e) => {
+            log::warn!("Can't rebuild the last frame: {}", e);
+            None
+        }
+    }
+}
+
+/// A key frame of the ship: the snapshot's rooms and people, with players
+/// flagged and every event
+fn build_frame(ctx: &ReducerContext) -> Frame {
+    let mut frame = frame::frame_from_snapshot(&build_snapshot(ctx));
+    for person in &mut frame.people {
+        if ctx
+            .db
+            .person()
+            .id()
+            .find(person.id)
+            .is_some_and(|p| p.is_player)
+        {
+            person.flags |= frame::person_flags::PLAYER;
+        }
+    }
+    frame.events = ctx
+        .db
+        .event()
+        .iter()
+        .map(|e| FrameEvent {
+            id: e.id,
+            event_type: e.event_type,
+            room_id: e.room_id,
+            state: e.state,
+            severity: frame::quantize_unit(e.severity),
+        })
+        .collect();
+    frame.events.sort_by_key(|e| e.id);
+    frame
+}
+
+/// File a voyage report for the voyage so far into the voyage_report table,
+/// as JSON and Markdown (arrival and the loss of the ship file one
+/// automatically). Only the ship's owner may.
+#[reducer]
+pub fn generate_voyage_report(ctx: &ReducerContext) {
+    if !may_configure_ship(ctx) {
+        return;
+    }
+    let Some(config) = ctx.db.ship_config().id().find(0) else {
+        log::warn!("No ship to report on");
+        return;
+    };
+    simulation::file_report(
+        ctx,
+        config.sim_time,
+        voyage_outcomes::IN_PROGRESS,
+        loss_causes::NONE,
+    );
+}
+
+/// Set how often the ship snapshots itself, how many automatic snapshots the
+/// state_export table keeps, and where they go (snapshot_sinks flags). Only
+/// the ship's owner may.
+#[reducer]
+pub fn configure_autosnapshot(ctx: &ReducerContext, interval_hours: f64, keep: u32, sinks: u8) {
+    if !may_configure_ship(ctx) {
+        return;
+    }
+    let config = SnapshotConfig {
+        id: 0,
+        interval_hours: interval_hours.max(0.0),
+        keep,
+        sinks,
+    };
+    log::info!(
+        "Autosnapshot every {:.1}h, keeping {}, sinks {:#04b}",
+        config.interval_hours,
+        config.keep,
+        config.sinks
+    );
+    if ctx.db.snapshot_config().id().find(0).is_some() {
+        ctx.db.snapshot_config().id().update(config);
+    } else {
+        ctx.db.snapshot_config().insert(config);
+    }
+}
+
+/// Take an automatic snapshot if one falls due this tick
+fn autosnapshot(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    let (interval_hours, keep, sinks) = ctx
+        .db
+        .snapshot_config()
+        .id()
+        .find(0)
+        .map(|c| (c.interval_hours, c.keep, c.sinks))
+        .unwrap_or((
+            AUTOSNAPSHOT_INTERVAL_HOURS,
+            AUTOSNAPSHOT_KEEP,
+            snapshot_sinks::TABLE | snapshot_sinks::LOG,
+        ));
+    if !autosnapshot_due(sim_time, delta_hours, interval_hours) {
+        return;
+    }
+
+    let data = build_snapshot(ctx).to_json();
+    if sinks & snapshot_sinks::LOG != 0 {
+        // One line per snapshot so it can be cut out of `spacetime logs`
+        log::info!("AUTOSNAPSHOT {}", data);
+    }
+    if sinks & snapshot_sinks::TABLE != 0 {
+        ctx.db.state_export().insert(StateExport {
+            id: 0,
+            sim_time,
+            data,
+            automatic: true,
+        });
+        let automatic = ctx
+            .db
+            .state_export()
+            .iter()
+            .filter(|e| e.automatic)
+            .map(|e| e.id)
+            .collect();
+        for id in exports_to_prune(automatic, keep) {
+            ctx.db.state_export().id().delete(id);
+        }
+    }
+}
+
+/// Rebuild a wiped ship from an exported snapshot (the `data` of a
+/// state_export row or an AUTOSNAPSHOT log line)
+///
+/// Regenerates the ship with the snapshot's generation parameters, then
+/// restores the clock, stores and everyone's whereabouts, needs and health.
+/// People missing from the snapshot had died. Only runs on an empty module.
+#[reducer]
+pub fn recover_from_snapshot(ctx: &ReducerContext, data: String) {
+    if ctx.db.ship_config().id().find(0).is_some() {
+        log::warn!("Ship already exists; recovery only runs on an empty module");
+        return;
+    }
+    let snapshot = match StateSnapshot::from_json(&data) {
+        Ok(snapshot) => snapshot,
+        Err(e) => {
+            log::error!("Cannot recover from snapshot: {}", e);
+            return;
+        }
+    };
+
+    crate::generation::generate_ship(
+        ctx,
+        snapshot.ship_name.clone(),
+        snapshot.deck_count,
+        snapshot.crew_count,
+        snapshot.passenger_count,
+        CorridorTopology::from_u8(snapshot.corridor_topology).unwrap_or_default(),
+        TaperCurve::from_u8(snapshot.hull_taper).unwrap_or_default(),
+        snapshot.seed,
+        0.0,
+        &snapshot.scenario,
+        snapshot.difficulty,
+        false,
+    );
+    let Some(mut config) = ctx.db.ship_config().id().find(0) else {
+        return;
+    };
+    config.sim_time = snapshot.sim_time;
+    config.time_scale = snapshot.time_scale;
+    config.death_count = snapshot.death_count;
+    config.paused = true;
+    ctx.db.ship_config().id().update(config);
+
+    if let Some(mut res) = ctx.db.ship_resources().id().find(0) {
+        let r = snapshot.resources;
+        res.power = r.power.min(res.power_cap);
+        res.water = r.water.min(res.water_cap);
+        res.oxygen = r.oxygen.min(res.oxygen_cap);
+        res.food = r.food.min(res.food_cap);
+        res.fuel = r.fuel.min(res.fuel_cap);
+        res.spare_parts = r.spare_parts.min(res.spare_parts_cap);
+        ctx.db.ship_resources().id().update(res);
+    }
+
+    let room_count = ctx.db.room().iter().count();
+    if room_count != snapshot.rooms.len() {
+        log::warn!(
+            "Regenerated {} rooms but the snapshot has {}; the layout may differ",
+            room_count,
+            snapshot.rooms.len()
+        );
+    }
+
+    let generated: Vec<Person> = ctx.db.person().iter().collect();
+    let (mut restored, mut died) = (0, 0);
+    for mut person in generated {
+        let saved = snapshot.people.iter().find(|p| {
+            p.id == person.id
+                && p.given_name == person.given_name
+                && p.family_name == person.family_name
+        });
+        ctx.db.movement().person_id().delete(person.id);
+        let Some(saved) = saved else {
+            if let Some(mut needs) = ctx.db.needs().person_id().find(person.id) {
+                needs.health = 0.0;
+                ctx.db.needs().person_id().update(needs);
+            }
+            person.is_alive = false;
+            ctx.db.person().id().update(person);
+            died += 1;
+            continue;
+        };
+        if let Some(mut pos) = ctx.db.position().person_id().find(person.id) {
+            if ctx.db.room().id().find(saved.room_id).is_some() {
+                pos.room_id = saved.room_id;
+                pos.x = saved.x;
+                pos.y = saved.y;
+                ctx.db.position().person_id().update(pos);
+            }
+        }
+        if let Some(mut needs) = ctx.db.needs().person_id().find(person.id) {
+            [
+                needs.hunger,
+                needs.fatigue,
+                needs.social,
+                needs.comfort,
+                needs.hygiene,
+            ] = saved.needs;
+            needs.health = saved.health;
+            needs.morale = saved.morale;
+            ctx.db.needs().person_id().update(needs);
+        }
+        restored += 1;
+    }
+    for (room_id, name) in snapshot.room_nicknames {
+        if ctx.db.room().id().find(room_id).is_some() {
+            ctx.db.room_nickname().room_id().delete(room_id);
+            ctx.db.room_nickname().insert(RoomNickname {
+                room_id,
+                name,
+                named_by: 0,
+            });
+        }
+    }
+    for (deck, name) in snapshot.deck_nicknames {
+        if ctx.db.deck().deck().find(deck).is_some() {
+            ctx.db.deck_nickname().deck().delete(deck);
+            ctx.db.deck_nickname().insert(DeckNickname {
+                deck,
+                name,
+                named_by: 0,
+            });
+        }
+    }
+    log::info!(
+        "Recovered '{}' at t={:.2}: {} people restored, {} lost since departure, {} not regenerated (paused)",
+        snapshot.ship_name,
+        snapshot.sim_time,
+        restored,
+        died,
+        snapshot.people.len().saturating_sub(restored),
+    );
+}
+
+fn build_snapshot(ctx: &ReducerContext) -> StateSnapshot {
+    let config = ctx.db.ship_config().id().find(0);
+    let mut rooms: Vec<SnapshotRoom> = ctx
+        .db
+        .room()
+        .iter()
+        .map(|r| SnapshotRoom {
+            id: r.id,
+            name: r.name,
+            room_type: r.room_type,
+            deck: r.deck,
+            x: r.x,
+            y: r.y,
+            width: r.width,
+            height: r.height,
+            capacity: r.capacity,
+        })
+        .collect();
+    rooms.sort_by_key(|r| r.id);
+
+    let people = ctx
+        .db
+        .person()
+        .iter()
+        .filter(|p| p.is_alive)
+        .filter_map(|p| {
+            let pos = ctx.db.position().person_id().find(p.id)?;
+            let n = ctx.db.needs().person_id().find(p.id);
+            let needs = n
+                .as_ref()
+                .map(|n| [n.hunger, n.fatigue, n.social, n.comfort, n.hygiene])
+                .unwrap_or_default();
+            let (health, morale) = n.map(|n| (n.health, n.morale)).unwrap_or((1.0, 0.5));
+            let crew = ctx.db.crew().person_id().find(p.id).map(|c| SnapshotCrew {
+                department: c.department,
+                rank: c.rank,
+                shift: c.shift,
+                duty_station_id: c.duty_station_id,
+            });
+            let activity_type = ctx
+                .db
+                .activity()
+                .person_id()
+                .find(p.id)
+                .map(|a| a.activity_type)
+                .unwrap_or(activity_types::IDLE);
+            Some(SnapshotPerson {
+                id: p.id,
+                given_name: p.given_name,
+                family_name: p.family_name,
+                crew,
+                room_id: pos.room_id,
+                x: pos.x,
+                y: pos.y,
+                needs,
+                health,
+                morale,
+                activity_type,
+            })
+        })
+        .collect();
+
+    StateSnapshot {
+        version: SNAPSHOT_VERSION,
+        ship_name: config.as_ref().map(|c| c.name.clone()).unwrap_or_default(),
+        sim_time: config.as_ref().map(|c| c.sim_time).unwrap_or(0.0),
+        deck_count: config.as_ref().map(|c| c.deck_count).unwrap_or(0),
+        crew_count: config.as_ref().map(|c| c.crew_count).unwrap_or(0),
+        passenger_count: config.as_ref().map(|c| c.passenger_count).unwrap_or(0),
+        seed: config
+            .as_ref()
+            .map_or_else(progship_logic::scenarios::default_seed, |c| c.seed),
+        corridor_topology: config.as_ref().map_or(0, |c| c.corridor_topology),
+        hull_taper: config.as_ref().map_or(0, |c| c.hull_taper),
+        scenario: config
+            .as_ref()
+            .map(|c| c.scenario.clone())
+            .unwrap_or_default(),
+        difficulty: config
+            .as_ref()
+            .map_or(difficulties::NORMAL, |c| c.difficulty),
+        time_scale: config.as_ref().map(|c| c.time_scale).unwrap_or(1.0),
+        death_count: config.as_ref().map(|c| c.death_count).unwrap_or(0),
+        resources: ctx
+            .db
+            .ship_resources()
+            .id()
+            .find(0)
+            .map(|r| SnapshotResources {
+                power: r.power,
+                water: r.water,
+                oxygen: r.oxygen,
+                food: r.food,
+                fuel: r.fuel,
+                spare_parts: r.spare_parts,
+            })
+            .unwrap_or_default(),
+        rooms,
+        doors: ctx.db.door().iter().map(|d| (d.room_a, d.room_b)).collect(),
+        people,
+        room_nicknames: ctx
+            .db
+            .room_nickname()
+            .iter()
+            .map(|n| (n.room_id, n.name))
+            .collect(),
+        deck_nicknames: ctx
+            .db
+            .deck_nickname()
+            .iter()
+            .map(|n| (n.deck, n.name))
+            .collect(),
+    }
+}
+
 // ============================================================================
 // SIMULATION TICK
 // ============================================================================
@@ -666,7 +1871,11 @@ pub fn tick(ctx: &ReducerContext, delta_seconds: f32) {
     let Some(mut config) = ctx.db.ship_config().id().find(0) else {
         return;
     };
-    if config.paused {
+    if config.paused || config.pending_decks > 0 || config.pending_people > 0 {
+        return;
+    }
+    if !delta_seconds.is_finite() || delta_seconds < 0.0 {
+        simulation::warn(ctx, "tick", format!("ignored a tick of {}s", delta_seconds));
         return;
     }
 
@@ -679,21 +1888,15 @@ pub fn tick(ctx: &ReducerContext, delta_seconds: f32) {
     let sim_time = config.sim_time;
 
     // T0: Movement (every tick)
-    simulation::tick_movement(ctx, scaled_delta);
+    simulation::tick_movement(ctx, scaled_delta, sim_time);
 
     // T1: Activities & wandering (every tick, internally throttled)
     simulation::tick_activities(ctx, sim_time);
     simulation::tick_wandering(ctx, sim_time);
 
-    // T2: Slower systems (needs, social, duty, death)
-    simulation::tick_needs(ctx, delta_hours as f32);
-    simulation::tick_death(ctx, sim_time);
-    simulation::tick_social(ctx, sim_time);
-    simulation::tick_duty(ctx, sim_time);
-
-    // T3: Ship systems (resources, atmosphere, events, maintenance)
-    simulation::tick_ship_systems(ctx, delta_hours as f32);
-    simulation::tick_atmosphere(ctx, delta_hours as f32);
-    simulation::tick_events(ctx, sim_time, delta_hours as f32);
-    simulation::tick_maintenance(ctx, sim_time, delta_hours as f32);
+    // T2-T4: Needs, social life, ship systems, events and bookkeeping
+    simulation::tick_slow_systems(ctx, sim_time, delta_hours);
+
+    // Automatic snapshot (internally throttled)
+    autosnapshot(ctx, sim_time, delta_hours);
 }
@@ -1,20 +1,47 @@
 //! Activity selection system - NPCs choose activities based on utility scoring.
 
 use crate::tables::*;
+use progship_logic::bar::nightlife_appeal;
 use progship_logic::duty as duty_logic;
+use progship_logic::economy::{is_mess_open, RationingLevel};
+use progship_logic::genlib::anchors::activity_posture;
+use progship_logic::governance::curfew_pick;
+use progship_logic::timeline::TimelineKind;
 use progship_logic::utility::{self, RoomCategory, RoomTarget, UtilityInput};
 use spacetimedb::{ReducerContext, Table};
 
-use super::movement::start_movement_to;
+use super::bar::{drinking_activity, is_drunk};
+use super::crime::{detained_activity, response_activity};
+use super::cryo::is_hibernating;
+use super::education::{is_teacher, teaching_activity};
+use super::funerals::funeral_activity;
+use super::governance::current_policy;
+use super::lighting::deck_mode;
+use super::mental_health::{counseling_activity, is_breaking_down};
+use super::milestones::gathering_activity;
+use super::morale::on_strike;
+use super::movement::{start_movement_to, start_movement_to_point};
+use super::mutiny::{mutiny_activity, suppression_activity};
+use super::orders::ordered_activity;
+use super::quarantine::quarantine_activity;
+use super::radiation::sheltering_activity;
+use super::surgery::surgery_activity;
+use super::timeline::record_timeline;
+use super::triage::{medic_activity, patient_activity};
 
 /// Select new activities when current ones complete, and handle activity effects.
 pub fn tick_activities(ctx: &ReducerContext, sim_time: f64) {
     for activity in ctx.db.activity().iter() {
-        // Skip player-controlled and dead characters
+        // Skip player-controlled and dead characters and those in cryosleep
+        if is_hibernating(ctx, activity.person_id) {
+            continue;
+        }
+        let mut life_stage = life_stages::ADULT;
         if let Some(person) = ctx.db.person().id().find(activity.person_id) {
             if person.is_player || !person.is_alive {
                 continue;
             }
+            life_stage = person.life_stage;
         }
         let elapsed = sim_time - activity.started_at;
         if elapsed < activity.duration as f64 {
@@ -22,85 +49,64 @@ pub fn tick_activities(ctx: &ReducerContext, sim_time: f64) {
         }
 
         // Activity complete - select new one based on utility scoring
-        let Some(needs) = ctx.db.needs().person_id().find(activity.person_id) else {
+        let Some(input) = utility_input(ctx, activity.person_id, life_stage, sim_time) else {
             continue;
         };
-
-        let crew_opt = ctx.db.crew().person_id().find(activity.person_id);
-        let is_crew = crew_opt.is_some();
-        let current_hour = (sim_time % 24.0) as f32;
-
-        // Build room context for current position
-        let current_room = ctx
+        let (ext, hour) = (input.extraversion, input.hour);
+        if ctx
             .db
-            .position()
+            .activity_explanation()
             .person_id()
             .find(activity.person_id)
-            .and_then(|pos| {
-                ctx.db.room().id().find(pos.room_id).map(|room| {
-                    let occupants = ctx
-                        .db
-                        .position()
-                        .iter()
-                        .filter(|p| p.room_id == pos.room_id)
-                        .count() as u32;
-                    utility::RoomContext {
-                        room_type: room.room_type,
-                        occupants,
-                        capacity: room.capacity,
-                    }
-                })
-            });
-
-        // Get personality (default to neutral 0.5 if missing)
-        let personality = ctx.db.personality().person_id().find(activity.person_id);
-        let (ext, neu, con, opn, agr) = personality
-            .as_ref()
-            .map(|p| {
-                (
-                    p.extraversion,
-                    p.neuroticism,
-                    p.conscientiousness,
-                    p.openness,
-                    p.agreeableness,
-                )
-            })
-            .unwrap_or((0.5, 0.5, 0.5, 0.5, 0.5));
-
-        let (shift, department) = crew_opt
-            .as_ref()
-            .map(|c| (Some(c.shift), Some(c.department)))
-            .unwrap_or((None, None));
-
-        let fit = duty_logic::is_fit_for_duty(needs.hunger, needs.fatigue, needs.health);
-        let on_duty = shift
-            .map(|s| duty_logic::should_be_on_duty(s, current_hour))
-            .unwrap_or(false);
-
-        let input = UtilityInput {
-            hunger: needs.hunger,
-            fatigue: needs.fatigue,
-            social: needs.social,
-            comfort: needs.comfort,
-            hygiene: needs.hygiene,
-            health: needs.health,
-            morale: needs.morale,
-            hour: current_hour,
-            is_crew,
-            shift,
-            department,
-            extraversion: ext,
-            neuroticism: neu,
-            conscientiousness: con,
-            openness: opn,
-            agreeableness: agr,
-            current_room,
-            fit_for_duty: fit,
-            should_be_on_duty: on_duty,
-        };
+            .is_some()
+        {
+            explain_activity_choice(ctx, activity.person_id, &input, sim_time);
+        }
 
-        let (new_type, duration, room_target) = utility::pick_best(&input);
-        let target_room = resolve_room_target(ctx, &room_target);
+        // A solar flare sends everyone to the storm shelter, orders or not;
+        // the flagged keep to quarantine, patients and surgeons booked for an
+        // operation go to the theatre, patients with a ward bed stay in it,
+        // detainees stay in the brig, mutineers hold their station,
+        // security crew go after suspects and mutineers, mourners off duty go
+        // to the funeral, the rest off duty gather to mark a voyage
+        // milestone, those in counseling and their counselors keep the
+        // session, medics tend their patients, teachers take their classes
+        // and those dependent on drink head for the bar, curfew allowing
+        let (new_type, duration, target_room) =
+            match sheltering_activity(ctx, activity.person_id, sim_time)
+                .or_else(|| quarantine_activity(ctx, activity.person_id, &input))
+                .or_else(|| surgery_activity(ctx, activity.person_id))
+                .or_else(|| patient_activity(ctx, activity.person_id))
+                .or_else(|| detained_activity(ctx, activity.person_id, &input))
+                .or_else(|| mutiny_activity(ctx, activity.person_id, &input))
+                .or_else(|| ordered_activity(ctx, activity.person_id, sim_time))
+                .or_else(|| response_activity(ctx, activity.person_id))
+                .or_else(|| suppression_activity(ctx, activity.person_id))
+                .or_else(|| funeral_activity(ctx, activity.person_id, sim_time))
+                .or_else(|| gathering_activity(ctx, activity.person_id, sim_time))
+                .or_else(|| counseling_activity(ctx, activity.person_id))
+                .or_else(|| medic_activity(ctx, activity.person_id, &input))
+                .or_else(|| teaching_activity(ctx, activity.person_id, &input))
+                .or_else(|| {
+                    drinking_activity(ctx, activity.person_id, &input)
+                        .filter(|_| !under_curfew(ctx, &input))
+                }) {
+                Some(ordered) => ordered,
+                None => {
+                    // Under curfew passengers keep to quarters overnight
+                    let pick = utility::pick_best(&input);
+                    let (new_type, duration, room_target) = if under_curfew(ctx, &input) {
+                        curfew_pick(pick)
+                    } else {
+                        pick
+                    };
+                    (
+                        new_type,
+                        duration,
+                        resolve_room_target(ctx, &room_target, ext, hour),
+                    )
+                }
+            };
 
         let mut a = activity;
         let person_id = a.person_id;
@@ -108,28 +114,247 @@ pub fn tick_activities(ctx: &ReducerContext, sim_time: f64) {
         a.started_at = sim_time;
         a.duration = duration;
         a.target_room_id = target_room;
-        ctx.db.activity().person_id().update(a);
+        a.target_anchor_id = None;
+        a.target_person_id = None;
 
-        // If activity requires a different room, start movement
+        // Head for an anchor serving the activity (bed, seat, console);
+        // without one only move when the target is a different room.
+        // People stand while walking and settle into a posture on arrival.
+        let mut walking = false;
         if let Some(target) = target_room {
-            let Some(pos) = ctx.db.position().person_id().find(person_id) else {
-                continue;
-            };
-            if pos.room_id != target {
-                start_movement_to(ctx, person_id, target);
+            if let Some(pos) = ctx.db.position().person_id().find(person_id) {
+                if let Some(anchor) = pick_room_anchor(ctx, target, new_type, person_id) {
+                    a.target_anchor_id = Some(anchor.id);
+                    start_movement_to_point(ctx, person_id, target, anchor.x, anchor.y);
+                    walking = true;
+                } else if pos.room_id != target {
+                    start_movement_to(ctx, person_id, target);
+                    walking = true;
+                }
             }
         }
+        a.animation_state = if walking {
+            postures::STANDING
+        } else {
+            activity_posture(new_type, None)
+        };
+        ctx.db.activity().person_id().update(a);
+        record_timeline(
+            ctx,
+            person_id,
+            TimelineKind::Activity,
+            new_type as u32,
+            sim_time,
+        );
     }
 }
 
-/// Resolve a RoomTarget to an actual room ID.
-fn resolve_room_target(ctx: &ReducerContext, target: &RoomTarget) -> Option<u32> {
+/// Whether a curfew keeps this person in quarters right now: passengers
+/// during the night hours while the council has one in force.
+fn under_curfew(ctx: &ReducerContext, input: &UtilityInput) -> bool {
+    !input.is_crew && duty_logic::is_passenger_sleep_time(input.hour) && current_policy(ctx).curfew
+}
+
+/// Everything the utility scorer needs to know about a person right now.
+fn utility_input(
+    ctx: &ReducerContext,
+    person_id: u64,
+    life_stage: u8,
+    sim_time: f64,
+) -> Option<UtilityInput> {
+    let needs = ctx.db.needs().person_id().find(person_id)?;
+
+    let crew_opt = ctx.db.crew().person_id().find(person_id);
+    let is_crew = crew_opt.is_some();
+    let current_hour = (sim_time % 24.0) as f32;
+
+    // Build room context for current position
+    let current_room = ctx
+        .db
+        .position()
+        .person_id()
+        .find(person_id)
+        .and_then(|pos| {
+            ctx.db.room().id().find(pos.room_id).map(|room| {
+                let occupants = ctx
+                    .db
+                    .position()
+                    .iter()
+                    .filter(|p| p.room_id == pos.room_id)
+                    .count() as u32;
+                utility::RoomContext {
+                    room_type: room.room_type,
+                    occupants,
+                    capacity: room.capacity,
+                }
+            })
+        });
+
+    // Get personality (default to neutral 0.5 if missing)
+    let personality = ctx.db.personality().person_id().find(person_id);
+    let (ext, neu, con, opn, agr) = personality
+        .as_ref()
+        .map(|p| {
+            (
+                p.extraversion,
+                p.neuroticism,
+                p.conscientiousness,
+                p.openness,
+                p.agreeableness,
+            )
+        })
+        .unwrap_or((0.5, 0.5, 0.5, 0.5, 0.5));
+
+    let traits = ctx
+        .db
+        .person_trait()
+        .person_id()
+        .find(person_id)
+        .map(|t| t.traits)
+        .unwrap_or(0);
+
+    let lighting = ctx
+        .db
+        .position()
+        .person_id()
+        .find(person_id)
+        .and_then(|pos| ctx.db.room().id().find(pos.room_id))
+        .map_or(lighting_modes::DAY, |room| deck_mode(ctx, room.deck));
+
+    let rationing = ctx
+        .db
+        .ship_config()
+        .id()
+        .find(0)
+        .map_or(0, |c| c.rationing_level);
+
+    let (shift, department) = crew_opt
+        .as_ref()
+        .map(|c| (Some(c.shift), Some(c.department)))
+        .unwrap_or((None, None));
+
+    // Someone breaking down refuses duty; the drunk are in no state for it
+    let breaking_down = is_breaking_down(ctx, person_id);
+    let fit = duty_logic::is_fit_for_duty(needs.hunger, needs.fatigue, needs.health)
+        && !breaking_down
+        && !is_drunk(ctx, person_id);
+    let work_hours = current_policy(ctx).work_hours as f32;
+    let on_duty = shift
+        .map(|s| duty_logic::should_be_on_duty_for(s, current_hour, work_hours))
+        .unwrap_or(false)
+        && !department.is_some_and(|d| on_strike(ctx, d))
+        && !is_teacher(ctx, person_id)
+        && !breaking_down;
+
+    Some(UtilityInput {
+        hunger: needs.hunger,
+        fatigue: needs.fatigue,
+        social: needs.social,
+        comfort: needs.comfort,
+        hygiene: needs.hygiene,
+        health: needs.health,
+        morale: needs.morale,
+        hour: current_hour,
+        is_crew,
+        shift,
+        department,
+        extraversion: ext,
+        neuroticism: neu,
+        conscientiousness: con,
+        openness: opn,
+        agreeableness: agr,
+        current_room,
+        fit_for_duty: fit,
+        should_be_on_duty: on_duty,
+        life_stage,
+        traits,
+        lighting,
+        rationing,
+    })
+}
+
+/// Record every candidate behind a person's activity pick, best first.
+fn explain_activity_choice(
+    ctx: &ReducerContext,
+    person_id: u64,
+    input: &UtilityInput,
+    sim_time: f64,
+) {
+    let row = ActivityExplanation {
+        person_id,
+        sim_time,
+        candidates: utility::explain_activities(input)
+            .iter()
+            .map(|c| c.describe())
+            .collect(),
+    };
+    if ctx
+        .db
+        .activity_explanation()
+        .person_id()
+        .find(person_id)
+        .is_some()
+    {
+        ctx.db.activity_explanation().person_id().update(row);
+    } else {
+        ctx.db.activity_explanation().insert(row);
+    }
+}
+
+/// Explain the current pick of an NPC (`explain_activity` reducer).
+pub fn explain_current_activity(ctx: &ReducerContext, person_id: u64, sim_time: f64) -> bool {
+    let Some(person) = ctx.db.person().id().find(person_id) else {
+        return false;
+    };
+    let Some(input) = utility_input(ctx, person_id, person.life_stage, sim_time) else {
+        return false;
+    };
+    explain_activity_choice(ctx, person_id, &input, sim_time);
+    true
+}
+
+/// Put a person who just arrived into the posture of their activity, at
+/// their anchor if they were heading to one.
+pub fn settle_posture(ctx: &ReducerContext, person_id: u64) {
+    let Some(mut activity) = ctx.db.activity().person_id().find(person_id) else {
+        return;
+    };
+    let anchor_type = activity
+        .target_anchor_id
+        .and_then(|id| ctx.db.activity_anchor().id().find(id))
+        .map(|a| a.anchor_type);
+    let posture = activity_posture(activity.activity_type, anchor_type);
+    if activity.animation_state != posture {
+        activity.animation_state = posture;
+        ctx.db.activity().person_id().update(activity);
+    }
+}
+
+/// Switch a person to a new activity in place, e.g. from a player action or
+/// a maintenance assignment, dropping any anchor or partner they were using.
+pub fn restart_activity(activity: &mut Activity, activity_type: u8, sim_time: f64, duration: f32) {
+    activity.activity_type = activity_type;
+    activity.started_at = sim_time;
+    activity.duration = duration;
+    activity.animation_state = activity_posture(activity_type, None);
+    activity.target_anchor_id = None;
+    activity.target_person_id = None;
+}
+
+/// Resolve a RoomTarget to an actual room ID. Recreation venues are picked
+/// by how well their liveliness suits the person's `extraversion`.
+fn resolve_room_target(
+    ctx: &ReducerContext,
+    target: &RoomTarget,
+    extraversion: f32,
+    hour: f32,
+) -> Option<u32> {
     match target {
         RoomTarget::None => None,
         RoomTarget::Exact(rt) => find_room_of_type(ctx, *rt),
         RoomTarget::Category(cat) => match cat {
             RoomCategory::Quarters => find_room_of_type_pred(ctx, room_types::is_quarters),
-            RoomCategory::Recreation => find_room_of_type_pred(ctx, room_types::is_recreation),
+            RoomCategory::Recreation => find_venue(ctx, extraversion, hour),
             RoomCategory::Medical => find_room_of_type(ctx, room_types::HOSPITAL_WARD),
             RoomCategory::Dining => find_room_of_type_pred(ctx, room_types::is_dining),
         },
@@ -144,8 +369,8 @@ pub fn should_be_on_duty(shift: u8, hour: f32) -> bool {
     duty_logic::should_be_on_duty(shift, hour)
 }
 
-pub fn is_meal_time(hour: f32) -> bool {
-    (7.0..8.0).contains(&hour) || (12.0..13.0).contains(&hour) || (18.0..19.0).contains(&hour)
+pub fn is_meal_time(rationing: RationingLevel, hour: f32) -> bool {
+    is_mess_open(rationing, hour)
 }
 
 pub fn department_to_room_type(department: u8) -> u8 {
@@ -160,6 +385,31 @@ pub fn department_to_room_type(department: u8) -> u8 {
     }
 }
 
+/// The anchor in `room_id` serving `activity_type` assigned to a person,
+/// spreading occupants across the room's anchors by person ID.
+fn pick_room_anchor(
+    ctx: &ReducerContext,
+    room_id: u32,
+    activity_type: u8,
+    person_id: u64,
+) -> Option<ActivityAnchor> {
+    let anchors: Vec<ActivityAnchor> = ctx
+        .db
+        .activity_anchor()
+        .iter()
+        .filter(|a| a.room_id == room_id && a.activity_type == activity_type)
+        .collect();
+    pick_anchor(&anchors, person_id).cloned()
+}
+
+/// Spread people over a room's anchors by person ID.
+fn pick_anchor<T>(anchors: &[T], person_id: u64) -> Option<&T> {
+    if anchors.is_empty() {
+        return None;
+    }
+    Some(&anchors[(person_id % anchors.len() as u64) as usize])
+}
+
 fn find_room_of_type(ctx: &ReducerContext, room_type: u8) -> Option<u32> {
     ctx.db
         .room()
@@ -168,6 +418,28 @@ fn find_room_of_type(ctx: &ReducerContext, room_type: u8) -> Option<u32> {
         .map(|r| r.id)
 }
 
+/// The recreation room whose liveliness best suits `extraversion`, with
+/// the bars drawing the crowds while open at ship `hour`.
+fn find_venue(ctx: &ReducerContext, extraversion: f32, hour: f32) -> Option<u32> {
+    let appeal = |room_id: u32| {
+        let lively = ctx
+            .db
+            .room_ambience()
+            .room_id()
+            .find(room_id)
+            .map(|a| a.liveliness)
+            .unwrap_or(0.0);
+        utility::venue_appeal(lively, extraversion)
+    };
+    ctx.db
+        .room()
+        .iter()
+        .filter(|r| room_types::is_recreation(r.room_type))
+        .map(|r| (r.id, appeal(r.id) + nightlife_appeal(r.room_type, hour)))
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(id, _)| id)
+}
+
 fn find_room_of_type_pred(ctx: &ReducerContext, pred: fn(u8) -> bool) -> Option<u32> {
     ctx.db
         .room()
@@ -198,11 +470,13 @@ mod tests {
 
     #[test]
     fn test_is_meal_time() {
-        assert!(is_meal_time(7.0));
-        assert!(is_meal_time(12.5));
-        assert!(is_meal_time(18.0));
-        assert!(!is_meal_time(10.0));
-        assert!(!is_meal_time(15.0));
+        assert!(is_meal_time(RationingLevel::Normal, 7.0));
+        assert!(is_meal_time(RationingLevel::Normal, 12.5));
+        assert!(is_meal_time(RationingLevel::Normal, 18.0));
+        assert!(!is_meal_time(RationingLevel::Normal, 10.0));
+        assert!(!is_meal_time(RationingLevel::Normal, 15.0));
+        assert!(!is_meal_time(RationingLevel::Heavy, 12.5));
+        assert!(!is_meal_time(RationingLevel::Emergency, 7.0));
     }
 
     #[test]
@@ -221,4 +495,32 @@ mod tests {
         );
         assert_eq!(department_to_room_type(99), room_types::CORRIDOR);
     }
+
+    #[test]
+    fn test_pick_anchor_spreads_people() {
+        let seats = [(1.0, 1.0), (3.0, 1.0)];
+        assert_eq!(pick_anchor(&seats, 4), Some(&(1.0, 1.0)));
+        assert_eq!(pick_anchor(&seats, 5), Some(&(3.0, 1.0)));
+        assert_eq!(pick_anchor::<(f32, f32)>(&[], 5), None);
+    }
+
+    #[test]
+    fn test_restart_activity_resets_pose() {
+        let mut activity = Activity {
+            person_id: 1,
+            activity_type: activity_types::IDLE,
+            started_at: 0.0,
+            duration: 1.0,
+            target_room_id: None,
+            animation_state: postures::STANDING,
+            target_anchor_id: Some(3),
+            target_person_id: Some(2),
+        };
+        restart_activity(&mut activity, activity_types::SLEEPING, 22.0, 8.0);
+        assert_eq!(activity.activity_type, activity_types::SLEEPING);
+        assert_eq!(activity.started_at, 22.0);
+        assert_eq!(activity.animation_state, postures::LYING);
+        assert_eq!(activity.target_anchor_id, None);
+        assert_eq!(activity.target_person_id, None);
+    }
 }
@@ -0,0 +1,225 @@
+//! Ship's computer - advisories on stores running down, failing systems,
+//! the grid shedding loads and crowded rooms, kept up to date each tick.
+
+use crate::tables::*;
+use progship_logic::advisor::{
+    braking_share, crowding_level, crowding_message, days_left, days_urgency, fuel_message,
+    power_message, priority, smoothed_rate, stock_level, stock_message, system_level,
+    system_message, MAX_CROWDED_ROOMS,
+};
+use progship_logic::constants::stocks::ALL as STOCKS;
+use spacetimedb::{ReducerContext, Table};
+use std::collections::HashMap;
+
+/// An advisory as the computer wants it listed.
+struct Wanted {
+    kind: u8,
+    subject: u64,
+    level: u8,
+    priority: f32,
+    message: String,
+}
+
+/// Look the ship over: follow the stores' trends, then raise, update and
+/// clear advisories to match what is wrong.
+pub fn tick_advisor(ctx: &ReducerContext, sim_time: f64) {
+    let mut wanted = Vec::new();
+    advise_stocks(ctx, sim_time, &mut wanted);
+    advise_systems(ctx, &mut wanted);
+    advise_power(ctx, &mut wanted);
+    advise_crowding(ctx, &mut wanted);
+
+    let existing: Vec<Advisory> = ctx.db.advisory().iter().collect();
+    for advisory in &existing {
+        if !wanted
+            .iter()
+            .any(|w| w.kind == advisory.kind && w.subject == advisory.subject)
+        {
+            ctx.db.advisory().id().delete(advisory.id);
+        }
+    }
+    for w in wanted {
+        let current = existing
+            .iter()
+            .find(|a| a.kind == w.kind && a.subject == w.subject);
+        match current {
+            Some(a) => {
+                if a.level != w.level && w.level == advisory_levels::CRITICAL {
+                    log::warn!("Ship's computer: {}", w.message);
+                }
+                ctx.db.advisory().id().update(Advisory {
+                    id: a.id,
+                    kind: w.kind,
+                    subject: w.subject,
+                    level: w.level,
+                    priority: w.priority,
+                    message: w.message,
+                    raised_at: a.raised_at,
+                    updated_at: sim_time,
+                });
+            }
+            None => {
+                if w.level == advisory_levels::CRITICAL {
+                    log::warn!("Ship's computer: {}", w.message);
+                }
+                ctx.db.advisory().insert(Advisory {
+                    id: 0,
+                    kind: w.kind,
+                    subject: w.subject,
+                    level: w.level,
+                    priority: w.priority,
+                    message: w.message,
+                    raised_at: sim_time,
+                    updated_at: sim_time,
+                });
+            }
+        }
+    }
+}
+
+/// Bring each store's trend up to date and advise on those that run out
+/// too soon, and on fuel short of the braking burn.
+fn advise_stocks(ctx: &ReducerContext, sim_time: f64, wanted: &mut Vec<Wanted>) {
+    let Some(resources) = ctx.db.ship_resources().id().find(0) else {
+        return;
+    };
+    let voyage = ctx.db.voyage_state().id().find(0);
+    let days_to_arrival = voyage
+        .as_ref()
+        .map_or(f64::INFINITY, |v| (v.eta - sim_time).max(0.0) / 24.0);
+    let short = voyage
+        .filter(|v| v.phase != voyage_phases::ACCELERATING)
+        .and_then(|v| braking_share(v.delta_v_c, v.velocity_c));
+    if let Some(share) = short {
+        wanted.push(Wanted {
+            kind: advisory_kinds::STOCK,
+            subject: stocks::FUEL as u64,
+            level: advisory_levels::CRITICAL,
+            priority: priority(advisory_levels::CRITICAL, 1.0 - share),
+            message: fuel_message(share),
+        });
+    }
+    for stock in STOCKS {
+        let level = match stock {
+            stocks::FOOD => resources.food,
+            stocks::WATER => resources.water,
+            stocks::OXYGEN => resources.oxygen,
+            stocks::POWER => resources.power,
+            _ => resources.fuel,
+        } as f64;
+        let rate = match ctx.db.stock_trend().stock().find(stock) {
+            Some(mut trend) => {
+                let hours = sim_time - trend.sampled_at;
+                if hours > 0.0 {
+                    let observed = (level - trend.level) / hours;
+                    trend.rate_per_hour = smoothed_rate(trend.rate_per_hour, observed, hours);
+                    trend.level = level;
+                    trend.sampled_at = sim_time;
+                }
+                let rate = trend.rate_per_hour;
+                ctx.db.stock_trend().stock().update(trend);
+                rate
+            }
+            None => {
+                ctx.db.stock_trend().insert(StockTrend {
+                    stock,
+                    level,
+                    rate_per_hour: 0.0,
+                    sampled_at: sim_time,
+                });
+                0.0
+            }
+        };
+        if stock == stocks::FUEL {
+            continue;
+        }
+        let Some(days) = days_left(level, rate) else {
+            continue;
+        };
+        if let Some(advice) = stock_level(days, days_to_arrival) {
+            wanted.push(Wanted {
+                kind: advisory_kinds::STOCK,
+                subject: stock as u64,
+                level: advice,
+                priority: priority(advice, days_urgency(days)),
+                message: stock_message(stock, days),
+            });
+        }
+    }
+}
+
+/// Advise on ship systems in poor health or with subsystems offline.
+fn advise_systems(ctx: &ReducerContext, wanted: &mut Vec<Wanted>) {
+    let mut offline: HashMap<u64, u32> = HashMap::new();
+    for sub in ctx.db.subsystem().iter().filter(|s| {
+        matches!(
+            s.status,
+            system_statuses::OFFLINE | system_statuses::DESTROYED
+        )
+    }) {
+        *offline.entry(sub.system_id).or_default() += 1;
+    }
+    for system in ctx.db.ship_system().iter() {
+        let down = offline.get(&system.id).copied().unwrap_or(0);
+        if let Some(level) = system_level(system.overall_health, down) {
+            wanted.push(Wanted {
+                kind: advisory_kinds::SYSTEM,
+                subject: system.id,
+                level,
+                priority: priority(level, 1.0 - system.overall_health),
+                message: system_message(&system.name, system.overall_health, down),
+            });
+        }
+    }
+}
+
+/// Advise while the grid is shedding loads.
+fn advise_power(ctx: &ReducerContext, wanted: &mut Vec<Wanted>) {
+    let Some(grid) = ctx.db.power_grid().id().find(0) else {
+        return;
+    };
+    if !grid.brownout {
+        return;
+    }
+    let shortfall = if grid.demand_kw > 0.0 {
+        1.0 - grid.generation_kw / grid.demand_kw
+    } else {
+        0.0
+    };
+    wanted.push(Wanted {
+        kind: advisory_kinds::POWER,
+        subject: 0,
+        level: advisory_levels::WARNING,
+        priority: priority(advisory_levels::WARNING, shortfall),
+        message: power_message(grid.demand_kw, grid.generation_kw),
+    });
+}
+
+/// Advise on the most crowded rooms.
+fn advise_crowding(ctx: &ReducerContext, wanted: &mut Vec<Wanted>) {
+    let mut occupants: HashMap<u32, u32> = HashMap::new();
+    for pos in ctx.db.position().iter() {
+        *occupants.entry(pos.room_id).or_default() += 1;
+    }
+    let mut crowded: Vec<(f32, u8, Room, u32)> = occupants
+        .into_iter()
+        .filter_map(|(room_id, count)| {
+            let room = ctx.db.room().id().find(room_id)?;
+            if room.room_type >= room_types::CORRIDOR {
+                return None;
+            }
+            let level = crowding_level(count, room.capacity)?;
+            Some((count as f32 / room.capacity as f32, level, room, count))
+        })
+        .collect();
+    crowded.sort_by(|a, b| b.0.total_cmp(&a.0).then(a.2.id.cmp(&b.2.id)));
+    for (ratio, level, room, count) in crowded.into_iter().take(MAX_CROWDED_ROOMS) {
+        wanted.push(Wanted {
+            kind: advisory_kinds::CROWDING,
+            subject: room.id as u64,
+            level,
+            priority: priority(level, 1.0 - 1.0 / ratio),
+            message: crowding_message(&room.name, count, room.capacity),
+        });
+    }
+}
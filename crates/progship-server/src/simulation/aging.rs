@@ -0,0 +1,112 @@
+//! Aging system - birthdays, growing up and dying of old age.
+
+use crate::tables::*;
+use progship_logic::aging::{dies_of_old_age, had_birthday};
+use progship_logic::constants::life_stages;
+use progship_logic::demographics::age_at;
+use progship_logic::education::profession_for;
+use spacetimedb::{ReducerContext, Table};
+
+use super::death::record_death;
+use super::features::feature_flags;
+use super::training::skill_levels;
+
+/// Hourly: everyone whose birthday has come turns a year older, moving to
+/// the next life stage when it is time, and gets a celebration where they
+/// are. Once a day the elderly may die of old age. Nobody ages with
+/// generational mode off.
+pub fn tick_aging(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    if sim_time.floor() == (sim_time - delta_hours).floor() || !feature_flags(ctx).generational {
+        return;
+    }
+    let day = (sim_time / 24.0).floor();
+    let new_day = day != ((sim_time - delta_hours) / 24.0).floor() && day >= 0.0;
+
+    let living: Vec<Person> = ctx.db.person().iter().filter(|p| p.is_alive).collect();
+    for person in living {
+        if new_day && !person.is_player && dies_of_old_age(person.id, day as u64, person.age) {
+            log::info!(
+                "{} {} died of old age at {}",
+                person.given_name,
+                person.family_name,
+                person.age
+            );
+            if let Some(mut needs) = ctx.db.needs().person_id().find(person.id) {
+                needs.health = 0.0;
+                ctx.db.needs().person_id().update(needs);
+            }
+            record_death(ctx, person, death_causes::OLD_AGE, sim_time);
+            continue;
+        }
+        if had_birthday(person.birth_date, person.age, sim_time) {
+            celebrate_birthday(ctx, person, sim_time);
+        }
+    }
+}
+
+/// Age `person` to their age at `sim_time`, with a small celebration in
+/// their room and a lift to their morale.
+fn celebrate_birthday(ctx: &ReducerContext, mut person: Person, sim_time: f64) {
+    person.age = age_at(person.birth_date, sim_time);
+    let stage = life_stages::from_age(person.age);
+    if stage != person.life_stage {
+        log::info!(
+            "{} {} is now {} ({})",
+            person.given_name,
+            person.family_name,
+            person.age,
+            life_stages::name(stage)
+        );
+        person.life_stage = stage;
+        grow_into(ctx, person.id, stage);
+    }
+    let person_id = person.id;
+    ctx.db.person().id().update(person);
+
+    if let Some(mut needs) = ctx.db.needs().person_id().find(person_id) {
+        needs.morale = (needs.morale + 0.1).min(1.0);
+        ctx.db.needs().person_id().update(needs);
+    }
+    let Some(room_id) = ctx
+        .db
+        .position()
+        .person_id()
+        .find(person_id)
+        .map(|p| p.room_id)
+    else {
+        return;
+    };
+    ctx.db.event().insert(Event {
+        id: 0,
+        event_type: event_types::CELEBRATION,
+        room_id,
+        started_at: sim_time,
+        duration: 1.0,
+        state: event_states::ACTIVE,
+        responders_needed: 0,
+        responders_assigned: 0,
+        severity: 0.2,
+        escalated_to: None,
+    });
+}
+
+/// Move a passenger born aboard on to what someone of `stage` does: infants
+/// start school, and school leavers take up the profession of their best
+/// subject.
+fn grow_into(ctx: &ReducerContext, person_id: u64, stage: u8) {
+    let Some(mut passenger) = ctx.db.passenger().person_id().find(person_id) else {
+        return;
+    };
+    let profession = match (passenger.profession.as_str(), stage) {
+        ("Infant", life_stages::CHILD) => "Student",
+        ("Student", life_stages::ADULT) => ctx
+            .db
+            .skills()
+            .person_id()
+            .find(person_id)
+            .map_or("Colonist", |s| profession_for(&skill_levels(&s))),
+        _ => return,
+    };
+    passenger.profession = profession.to_string();
+    ctx.db.passenger().person_id().update(passenger);
+}
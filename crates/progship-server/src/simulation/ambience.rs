@@ -0,0 +1,70 @@
+//! Room ambience system - per-room occupancy, chatter and mood.
+
+use crate::tables::*;
+use progship_logic::utility::{liveliness, AMBIENCE_INTERVAL_HOURS};
+use spacetimedb::{ReducerContext, Table};
+
+/// Refresh every room's ambience once per ambience interval.
+pub fn tick_room_ambience(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    let interval = |t: f64| (t / AMBIENCE_INTERVAL_HOURS).floor();
+    if interval(sim_time) == interval(sim_time - delta_hours) {
+        return;
+    }
+
+    // (occupants, people talking, morale sum) indexed by room ID
+    let room_count = ctx.db.room().iter().map(|r| r.id + 1).max().unwrap_or(0);
+    let mut tally = vec![(0u32, 0u32, 0.0f32); room_count as usize];
+    for pos in ctx.db.position().iter() {
+        let Some(entry) = tally.get_mut(pos.room_id as usize) else {
+            continue;
+        };
+        entry.0 += 1;
+        if ctx
+            .db
+            .in_conversation()
+            .person_id()
+            .find(pos.person_id)
+            .is_some()
+        {
+            entry.1 += 1;
+        }
+        entry.2 += ctx
+            .db
+            .needs()
+            .person_id()
+            .find(pos.person_id)
+            .map(|n| n.morale)
+            .unwrap_or(0.5);
+    }
+
+    for room in ctx.db.room().iter() {
+        let (occupants, talking, mood_sum) = tally[room.id as usize];
+        let conversations = talking / 2;
+        let average_mood = if occupants > 0 {
+            mood_sum / occupants as f32
+        } else {
+            0.0
+        };
+        let ambience = RoomAmbience {
+            room_id: room.id,
+            occupants,
+            conversations,
+            average_mood,
+            liveliness: liveliness(occupants, room.capacity, conversations, average_mood),
+        };
+        match ctx.db.room_ambience().room_id().find(room.id) {
+            // Only replicate real changes
+            Some(old)
+                if old.occupants == occupants
+                    && old.conversations == conversations
+                    && (old.average_mood - average_mood).abs() < 0.02 => {}
+            Some(_) => {
+                ctx.db.room_ambience().room_id().update(ambience);
+            }
+            None if occupants > 0 => {
+                ctx.db.room_ambience().insert(ambience);
+            }
+            None => {}
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! Arboretum system - the plantings in the ship's parks: growing on water,
+//! light and CO2, tended by gardeners off the work order board, freshening
+//! the air and easing those who visit.
+
+use crate::tables::*;
+use progship_logic::arboretum::{
+    breathers_offset, growing_conditions, neglected, plant_health, tended, tending_hours,
+    TENDING_PRIORITY,
+};
+use spacetimedb::{ReducerContext, Table};
+use std::collections::{HashMap, HashSet};
+
+use super::water::dry_rooms;
+
+/// Grow the plantings in each arboretum on the water, light and CO2 they
+/// get, wilting in a dry or dark room and going to seed untended. Tending
+/// that is due is put on the work order board hourly.
+pub fn tick_arboretum(ctx: &ReducerContext, sim_time: f64, delta_hours: f32) {
+    let gardens: Vec<Garden> = ctx.db.garden().iter().collect();
+    if gardens.is_empty() {
+        return;
+    }
+    let dark: HashSet<u32> = ctx
+        .db
+        .room_power()
+        .iter()
+        .filter(|p| !p.powered)
+        .map(|p| p.room_id)
+        .collect();
+    let dry = dry_rooms(ctx);
+    for mut garden in gardens {
+        let light = if dark.contains(&garden.room_id) {
+            0.0
+        } else {
+            ctx.db
+                .lighting()
+                .deck()
+                .find(garden.deck)
+                .map_or(1.0, |l| l.level)
+        };
+        let co2 = ctx
+            .db
+            .room_air()
+            .room_id()
+            .find(garden.room_id)
+            .map_or(0.0, |a| a.co2);
+        garden.conditions = growing_conditions(!dry.contains(&garden.room_id), light, co2);
+        garden.health = plant_health(
+            garden.health,
+            garden.conditions,
+            neglected(garden.tended_at, sim_time),
+            delta_hours,
+        );
+        ctx.db.garden().room_id().update(garden);
+    }
+
+    if sim_time.floor() != (sim_time - delta_hours as f64).floor() {
+        file_tending(ctx, sim_time);
+    }
+}
+
+/// People whose breathing each arboretum's plantings offset, by room.
+pub(super) fn room_breathers(ctx: &ReducerContext) -> HashMap<u32, f32> {
+    ctx.db
+        .garden()
+        .iter()
+        .map(|g| (g.room_id, breathers_offset(g.health, g.conditions, g.area)))
+        .collect()
+}
+
+/// Plant health in each arboretum, by room, for its visitors.
+pub(super) fn garden_health(ctx: &ReducerContext) -> HashMap<u32, f32> {
+    ctx.db
+        .garden()
+        .iter()
+        .map(|g| (g.room_id, g.health))
+        .collect()
+}
+
+/// Whether the plantings in `room_id` are due tending.
+pub(super) fn tending_due(ctx: &ReducerContext, room_id: u32, sim_time: f64) -> bool {
+    ctx.db
+        .garden()
+        .room_id()
+        .find(room_id)
+        .is_some_and(|g| neglected(g.tended_at, sim_time))
+}
+
+/// A gardener has tended the plantings in `room_id`.
+pub(super) fn tend_garden(ctx: &ReducerContext, room_id: u32, sim_time: f64) {
+    if let Some(mut garden) = ctx.db.garden().room_id().find(room_id) {
+        garden.tended_at = sim_time;
+        garden.health = tended(garden.health);
+        ctx.db.garden().room_id().update(garden);
+    }
+}
+
+/// File a gardening order for each arboretum due tending with none on the
+/// board.
+fn file_tending(ctx: &ReducerContext, sim_time: f64) {
+    let filed: HashSet<u32> = ctx
+        .db
+        .work_order()
+        .iter()
+        .filter(|o| {
+            o.kind == work_kinds::GARDENING
+                && matches!(
+                    o.state,
+                    work_order_states::OPEN | work_order_states::CLAIMED
+                )
+        })
+        .filter_map(|o| o.room_id)
+        .collect();
+    let due: Vec<Garden> = ctx
+        .db
+        .garden()
+        .iter()
+        .filter(|g| neglected(g.tended_at, sim_time) && !filed.contains(&g.room_id))
+        .collect();
+    for garden in due {
+        let place = ctx
+            .db
+            .room()
+            .id()
+            .find(garden.room_id)
+            .map_or_else(|| "an arboretum".to_string(), |r| r.name);
+        ctx.db.work_order().insert(WorkOrder {
+            id: 0,
+            kind: work_kinds::GARDENING,
+            state: work_order_states::OPEN,
+            reason: format!("Plantings due tending in {}", place),
+            department: departments::SCIENCE,
+            required_skill: skill_types::SCIENCE,
+            required_parts: 0.0,
+            part_kind: part_kinds::MECHANICAL,
+            priority: TENDING_PRIORITY,
+            room_id: Some(garden.room_id),
+            subsystem_id: 0,
+            component_id: 0,
+            infra_edge_id: None,
+            event_id: None,
+            hull_damage_id: None,
+            assigned_crew_id: None,
+            assigned_robot_id: None,
+            progress: 0.0,
+            duration_hours: tending_hours(garden.area),
+            created_at: sim_time,
+            claimed_at: None,
+            completed_at: None,
+        });
+    }
+}
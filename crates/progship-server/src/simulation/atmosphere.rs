@@ -1,8 +1,27 @@
-//! Atmosphere simulation - per-deck O2, CO2, temperature, humidity.
+//! Atmosphere simulation - per-room O2, CO2, temperature, humidity and
+//! pressure, regulated through the HVAC ducts and mixing through them and
+//! open doors.
+
+use std::collections::{HashMap, HashSet};
 
 use crate::tables::*;
+use progship_logic::arboretum::freshened;
+use progship_logic::atmosphere::atmo_constants::{
+    CO2_PRODUCTION_PER_PERSON, EXERCISE_BREATHING, KPA_PER_ATM, O2_CONSUMPTION_PER_PERSON,
+    STANDARD_ROOM_M2,
+};
+use progship_logic::atmosphere::{
+    breathers, exchange_gas, exchange_gas_through, humidity_after, step_room_atmosphere,
+    RoomAtmosphere,
+};
+use progship_logic::hvac::{return_air, DUCT_CONDUCTANCE};
 use spacetimedb::{ReducerContext, Table};
 
+use super::arboretum::room_breathers;
+use super::cryo::sleepers;
+use super::hvac::{air_cooling, air_handlers, node_airflow};
+use super::thermal::room_heat;
+
 /// Calculate life support efficiency from subsystems
 pub fn calculate_life_support_efficiency(subsystems: &[Subsystem]) -> f32 {
     if subsystems.is_empty() {
@@ -26,68 +45,58 @@ pub fn calculate_life_support_efficiency(subsystems: &[Subsystem]) -> f32 {
     total / subsystems.len() as f32
 }
 
-/// Calculate metabolic impact on atmosphere
-pub fn calculate_metabolic_impact(
-    population: f32,
-    exercising: f32,
-    delta_hours: f32,
-) -> (f32, f32, f32, f32) {
-    let o2_consumption = (population * 0.035 + exercising * 0.07) * delta_hours;
-    let co2_production = (population * 0.043 + exercising * 0.09) * delta_hours;
-    let humidity_add = (population * 0.005 + exercising * 0.015) * delta_hours;
-    let heat_add = (population * 0.1 + exercising * 0.3) * delta_hours;
-    (o2_consumption, co2_production, humidity_add, heat_add)
-}
-
-/// Apply atmosphere changes with life support counteraction
-pub fn apply_atmosphere_changes(
-    oxygen: f32,
-    co2: f32,
-    humidity: f32,
-    temperature: f32,
-    metabolic: (f32, f32, f32, f32),
-    ls_efficiency: f32,
-) -> (f32, f32, f32, f32) {
-    let (o2_consumption, co2_production, humidity_add, heat_add) = metabolic;
-
-    let mut o2 = oxygen - o2_consumption;
-    let mut co2_val = co2 + co2_production;
-    let mut hum = humidity + humidity_add;
-    let mut temp = temperature + heat_add;
-
-    // Life support counteraction
-    o2 += o2_consumption * ls_efficiency;
-    co2_val -= co2_production * ls_efficiency * 0.95;
-    hum -= humidity_add * ls_efficiency * 0.8;
-    temp -= heat_add * ls_efficiency * 0.9;
-
-    // Clamp values
-    o2 = o2.clamp(0.0, 0.25);
-    co2_val = co2_val.clamp(0.0, 0.1);
-    hum = hum.clamp(0.0, 1.0);
-    temp = temp.clamp(-10.0, 50.0);
-
-    (o2, co2_val, hum, temp)
+/// A room's air as the logic crate models it, vented by life support or
+/// not and on fire or not.
+fn air_model(air: &RoomAir, vented: bool, fire: bool) -> RoomAtmosphere {
+    RoomAtmosphere {
+        o2: air.oxygen,
+        co2: air.co2,
+        temperature: air.temperature,
+        pressure: air.pressure / KPA_PER_ATM,
+        sealed: false,
+        has_life_support: vented,
+        fire,
+    }
 }
 
-/// Update per-deck atmosphere based on occupancy and life support.
+/// Update every room's air: people breathing and warming it, galleys and
+/// machinery the coolant does not carry off warming it, fires burning,
+/// life support and the cooling plant regulating it as far as its HVAC duct
+/// carries their air and its vents have power, and the arboretums' plantings
+/// freshening their own; then air mixing through open doors and, with the
+/// air handlers' mixed return air, along the ducts.
 pub fn tick_atmosphere(ctx: &ReducerContext, delta_hours: f32) {
-    // Count people per deck
-    let mut deck_population: std::collections::HashMap<i32, u32> = std::collections::HashMap::new();
-    let mut deck_exercising: std::collections::HashMap<i32, u32> = std::collections::HashMap::new();
-
+    // People breathing in each room, harder while exercising; sleepers in
+    // the cryo bays hardly breathe at all
+    let asleep = sleepers(ctx);
+    let mut people: HashMap<u32, f32> = HashMap::new();
     for pos in ctx.db.position().iter() {
-        if let Some(room) = ctx.db.room().id().find(pos.room_id) {
-            *deck_population.entry(room.deck).or_insert(0) += 1;
-
-            // Check if exercising (high metabolic output)
-            if let Some(act) = ctx.db.activity().person_id().find(pos.person_id) {
-                if act.activity_type == activity_types::EXERCISING {
-                    *deck_exercising.entry(room.deck).or_insert(0) += 1;
-                }
-            }
+        if asleep.contains(&pos.person_id) {
+            continue;
         }
+        let exercising = ctx
+            .db
+            .activity()
+            .person_id()
+            .find(pos.person_id)
+            .is_some_and(|a| a.activity_type == activity_types::EXERCISING);
+        *people.entry(pos.room_id).or_default() +=
+            if exercising { EXERCISE_BREATHING } else { 1.0 };
     }
+    let fires: HashSet<u32> = ctx
+        .db
+        .event()
+        .iter()
+        .filter(|e| e.event_type == event_types::FIRE && e.state != event_states::RESOLVED)
+        .map(|e| e.room_id)
+        .collect();
+    let unpowered: HashSet<u32> = ctx
+        .db
+        .room_power()
+        .iter()
+        .filter(|p| !p.powered)
+        .map(|p| p.room_id)
+        .collect();
 
     // Check life support efficiency from subsystems
     let ls_subsystems: Vec<Subsystem> = ctx
@@ -97,28 +106,97 @@ pub fn tick_atmosphere(ctx: &ReducerContext, delta_hours: f32) {
         .filter(|s| {
             s.subsystem_type == subsystem_types::O2_GENERATION
                 || s.subsystem_type == subsystem_types::CO2_SCRUBBING
-                || s.subsystem_type == subsystem_types::AIR_CIRCULATION
         })
         .collect();
     let ls_efficiency = calculate_life_support_efficiency(&ls_subsystems);
+    let cooling = air_cooling(ctx);
+    // Rooms no duct serves take the air handlers' air directly
+    let handlers = air_handlers(ctx);
+    let ducts = node_airflow(ctx);
+    let plants = room_breathers(ctx);
+    let heating = room_heat(ctx);
 
-    for atmo in ctx.db.deck_atmosphere().iter() {
-        let pop = *deck_population.get(&atmo.deck).unwrap_or(&0) as f32;
-        let exercising = *deck_exercising.get(&atmo.deck).unwrap_or(&0) as f32;
+    let mut rooms: Vec<(RoomAir, RoomAtmosphere, f32)> = Vec::new();
+    let mut index: HashMap<u32, usize> = HashMap::new();
+    for mut air in ctx.db.room_air().iter() {
+        let room = ctx.db.room().id().find(air.room_id);
+        let area = room
+            .as_ref()
+            .map_or(STANDARD_ROOM_M2, |r| r.width * r.height);
+        let vented = !unpowered.contains(&air.room_id);
+        let flow = if vented {
+            room.and_then(|r| ducts.get(&r.node_id).copied())
+                .unwrap_or(handlers)
+        } else {
+            0.0
+        };
+        let life_support = ls_efficiency * flow;
+        let breathing = breathers(people.get(&air.room_id).copied().unwrap_or(0.0), area);
+        let mut model = air_model(&air, vented, fires.contains(&air.room_id));
+        model.temperature += heating.get(&air.room_id).copied().unwrap_or(0.0) * delta_hours;
+        step_room_atmosphere(
+            &mut model,
+            breathing,
+            life_support,
+            cooling * flow,
+            air.target_temperature,
+            delta_hours,
+        );
+        air.humidity = humidity_after(
+            air.humidity,
+            breathing,
+            life_support,
+            air.target_humidity,
+            delta_hours,
+        );
+        if let Some(&offset) = plants.get(&air.room_id) {
+            let fresh = breathers(offset, area) * delta_hours;
+            (model.o2, model.co2) = freshened(
+                model.o2,
+                model.co2,
+                fresh * O2_CONSUMPTION_PER_PERSON,
+                fresh * CO2_PRODUCTION_PER_PERSON,
+            );
+        }
+        index.insert(air.room_id, rooms.len());
+        rooms.push((air, model, flow));
+    }
 
-        let mut a = atmo;
+    // Air mixes freely through open doors
+    for door in ctx
+        .db
+        .door()
+        .iter()
+        .filter(|d| d.state == door_states::OPEN)
+    {
+        let (Some(&a), Some(&b)) = (index.get(&door.room_a), index.get(&door.room_b)) else {
+            continue;
+        };
+        if a == b {
+            continue;
+        }
+        let (mut air_a, mut air_b) = (rooms[a].1, rooms[b].1);
+        exchange_gas(&mut air_a, &mut air_b, delta_hours);
+        rooms[a].1 = air_a;
+        rooms[b].1 = air_b;
+    }
 
-        let metabolic = calculate_metabolic_impact(pop, exercising, delta_hours);
-        (a.oxygen, a.co2, a.humidity, a.temperature) = apply_atmosphere_changes(
-            a.oxygen,
-            a.co2,
-            a.humidity,
-            a.temperature,
-            metabolic,
-            ls_efficiency,
-        );
+    // And more slowly along the ducts with the air handlers' mixed air,
+    // carrying heat and stale air from room to room
+    let returned: Vec<(RoomAtmosphere, f32)> = rooms.iter().map(|(_, m, f)| (*m, *f)).collect();
+    if let Some(supply) = return_air(&returned) {
+        for (_, model, flow) in rooms.iter_mut().filter(|(_, _, f)| *f > 0.0) {
+            let mut handled = supply;
+            exchange_gas_through(model, &mut handled, DUCT_CONDUCTANCE * *flow, delta_hours);
+        }
+    }
 
-        ctx.db.deck_atmosphere().deck().update(a);
+    for (mut air, model, _) in rooms {
+        air.oxygen = model.o2;
+        air.co2 = model.co2;
+        air.temperature = model.temperature;
+        air.pressure = model.pressure * KPA_PER_ATM;
+        ctx.db.room_air().room_id().update(air);
     }
 }
 
@@ -145,6 +223,7 @@ mod tests {
                 node_id: 0,
                 power_draw: 10.0,
                 crew_required: 0,
+                output: 0.0,
             },
             Subsystem {
                 id: 2,
@@ -156,6 +235,7 @@ mod tests {
                 node_id: 0,
                 power_draw: 8.0,
                 crew_required: 0,
+                output: 0.0,
             },
         ];
         assert_eq!(calculate_life_support_efficiency(&subsystems), 1.0);
@@ -173,6 +253,7 @@ mod tests {
             node_id: 0,
             power_draw: 10.0,
             crew_required: 0,
+            output: 0.0,
         }];
         assert_eq!(calculate_life_support_efficiency(&subsystems), 0.5);
     }
@@ -189,6 +270,7 @@ mod tests {
             node_id: 0,
             power_draw: 10.0,
             crew_required: 0,
+            output: 0.0,
         }];
         assert_eq!(calculate_life_support_efficiency(&subsystems), 0.0);
     }
@@ -206,6 +288,7 @@ mod tests {
                 node_id: 0,
                 power_draw: 10.0,
                 crew_required: 0,
+                output: 0.0,
             },
             Subsystem {
                 id: 2,
@@ -217,81 +300,10 @@ mod tests {
                 node_id: 0,
                 power_draw: 8.0,
                 crew_required: 0,
+                output: 0.0,
             },
         ];
         // (1.0 + 0.5 * 0.5) / 2 = 1.25 / 2 = 0.625
         assert_eq!(calculate_life_support_efficiency(&subsystems), 0.625);
     }
-
-    #[test]
-    fn test_calculate_metabolic_impact_no_population() {
-        let (o2, co2, hum, heat) = calculate_metabolic_impact(0.0, 0.0, 1.0);
-        assert_eq!(o2, 0.0);
-        assert_eq!(co2, 0.0);
-        assert_eq!(hum, 0.0);
-        assert_eq!(heat, 0.0);
-    }
-
-    #[test]
-    fn test_calculate_metabolic_impact_population_only() {
-        let (o2, co2, hum, heat) = calculate_metabolic_impact(10.0, 0.0, 1.0);
-        assert_eq!(o2, 10.0 * 0.035);
-        assert_eq!(co2, 10.0 * 0.043);
-        assert_eq!(hum, 10.0 * 0.005);
-        assert_eq!(heat, 10.0 * 0.1);
-    }
-
-    #[test]
-    fn test_calculate_metabolic_impact_with_exercising() {
-        let (o2, co2, hum, heat) = calculate_metabolic_impact(10.0, 2.0, 1.0);
-        assert_eq!(o2, 10.0 * 0.035 + 2.0 * 0.07);
-        assert_eq!(co2, 10.0 * 0.043 + 2.0 * 0.09);
-        assert_eq!(hum, 10.0 * 0.005 + 2.0 * 0.015);
-        assert_eq!(heat, 10.0 * 0.1 + 2.0 * 0.3);
-    }
-
-    #[test]
-    fn test_apply_atmosphere_changes_no_life_support() {
-        let (o2, co2, hum, temp) =
-            apply_atmosphere_changes(0.21, 0.02, 0.4, 20.0, (0.05, 0.06, 0.02, 0.5), 0.0);
-        // No life support, only metabolic impact
-        assert!((o2 - 0.16).abs() < 0.001); // 0.21 - 0.05
-        assert!((co2 - 0.08).abs() < 0.001); // 0.02 + 0.06
-        assert!((hum - 0.42).abs() < 0.001); // 0.4 + 0.02
-        assert!((temp - 20.5).abs() < 0.001); // 20.0 + 0.5
-    }
-
-    #[test]
-    fn test_apply_atmosphere_changes_full_life_support() {
-        let (o2, co2, hum, temp) =
-            apply_atmosphere_changes(0.21, 0.02, 0.4, 20.0, (0.05, 0.06, 0.02, 0.5), 1.0);
-        // Full life support (100% efficiency)
-        assert!((o2 - 0.21).abs() < 0.001); // 0.21 - 0.05 + 0.05 * 1.0
-        assert!((co2 - (0.02 + 0.06 - 0.06 * 0.95)).abs() < 0.001); // 0.02 + 0.06 - 0.057
-        assert!((hum - (0.4 + 0.02 - 0.02 * 0.8)).abs() < 0.001); // 0.4 + 0.02 - 0.016
-        assert!((temp - (20.0 + 0.5 - 0.5 * 0.9)).abs() < 0.001); // 20.0 + 0.5 - 0.45
-    }
-
-    #[test]
-    fn test_apply_atmosphere_changes_clamping() {
-        // Test oxygen clamp at 0.25
-        let (o2, _, _, _) =
-            apply_atmosphere_changes(0.25, 0.02, 0.4, 20.0, (-0.1, 0.0, 0.0, 0.0), 1.0);
-        assert_eq!(o2, 0.25);
-
-        // Test CO2 clamp at 0.1
-        let (_, co2, _, _) =
-            apply_atmosphere_changes(0.21, 0.09, 0.4, 20.0, (0.0, 0.02, 0.0, 0.0), 0.0);
-        assert_eq!(co2, 0.1);
-
-        // Test humidity clamp at 1.0
-        let (_, _, hum, _) =
-            apply_atmosphere_changes(0.21, 0.02, 0.95, 20.0, (0.0, 0.0, 0.1, 0.0), 0.0);
-        assert_eq!(hum, 1.0);
-
-        // Test temp clamp at 50.0
-        let (_, _, _, temp) =
-            apply_atmosphere_changes(0.21, 0.02, 0.4, 48.0, (0.0, 0.0, 0.0, 5.0), 0.0);
-        assert_eq!(temp, 50.0);
-    }
 }
@@ -0,0 +1,295 @@
+//! Bar system - drinks served off duty in the evening, sobering up,
+//! dependence and the scenes the drunk cause.
+
+use crate::tables::*;
+use progship_logic::bar::{
+    dependence_gain, disorderly_chance, drinks_wanted, is_bar, is_impaired, is_open,
+    DEPENDENCE_FADE_PER_HOUR, DEPENDENT, DRINK_COMFORT, DRINK_FOOD, DRINK_INTOXICATION, DRINK_SALT,
+    DRINK_SOCIAL, DRINK_WATER, SOBER_PER_HOUR,
+};
+use progship_logic::economy::{compute_levels, price, DRINK_PRICE};
+use progship_logic::numeric::roll;
+use progship_logic::utility::UtilityInput;
+use spacetimedb::{ReducerContext, Table};
+
+use super::economy::charge;
+use super::ship_systems::resource_values;
+
+/// Hourly: everyone sobers up a little and dependence fades, those off
+/// duty in an open bar have their drinks while the ship has the food and
+/// water for them and they can pay, and the drunk may cause a scene.
+pub fn tick_bar(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    if sim_time.floor() == (sim_time - delta_hours).floor() {
+        return;
+    }
+    for mut drinking in ctx.db.drinking().iter() {
+        let alive = ctx
+            .db
+            .person()
+            .id()
+            .find(drinking.person_id)
+            .is_some_and(|p| p.is_alive);
+        if !alive {
+            ctx.db.drinking().person_id().delete(drinking.person_id);
+            continue;
+        }
+        drinking.intoxication = (drinking.intoxication - SOBER_PER_HOUR).max(0.0);
+        drinking.dependence = (drinking.dependence - DEPENDENCE_FADE_PER_HOUR).max(0.0);
+        ctx.db.drinking().person_id().update(drinking);
+    }
+    let hour = (sim_time % 24.0) as f32;
+    if is_open(hour) {
+        serve_drinks(ctx, sim_time);
+    }
+    cause_scenes(ctx, sim_time);
+}
+
+/// What someone dependent on drink does next while off duty and the bars
+/// are open: head for one. `None` for anyone else.
+pub fn drinking_activity(
+    ctx: &ReducerContext,
+    person_id: u64,
+    input: &UtilityInput,
+) -> Option<(u8, f32, Option<u32>)> {
+    if input.should_be_on_duty || !is_open(input.hour) {
+        return None;
+    }
+    let drinking = ctx.db.drinking().person_id().find(person_id)?;
+    if drinking.dependence < DEPENDENT {
+        return None;
+    }
+    let deck = ctx
+        .db
+        .position()
+        .person_id()
+        .find(person_id)
+        .and_then(|p| ctx.db.room().id().find(p.room_id))
+        .map(|r| r.deck);
+    let bar = ctx
+        .db
+        .room()
+        .iter()
+        .filter(|r| is_bar(r.room_type))
+        .min_by_key(|r| (Some(r.deck) != deck, r.id))?;
+    Some((activity_types::SOCIALIZING, 1.0, Some(bar.id)))
+}
+
+/// Whether `person_id` has had too much to drink to be fit for duty.
+pub fn is_drunk(ctx: &ReducerContext, person_id: u64) -> bool {
+    ctx.db
+        .drinking()
+        .person_id()
+        .find(person_id)
+        .is_some_and(|d| is_impaired(d.intoxication))
+}
+
+/// Serve everyone socializing or relaxing off duty in a powered bar their
+/// drinks for the hour, until the food or water runs short or they run out
+/// of credits. Drinks cost more as the food stores run low.
+fn serve_drinks(ctx: &ReducerContext, sim_time: f64) {
+    let Some(mut resources) = ctx.db.ship_resources().id().find(0) else {
+        return;
+    };
+    let bars: Vec<u32> = ctx
+        .db
+        .room()
+        .iter()
+        .filter(|r| is_bar(r.room_type))
+        .filter(|r| {
+            ctx.db
+                .room_power()
+                .room_id()
+                .find(r.id)
+                .is_none_or(|p| p.powered)
+        })
+        .map(|r| r.id)
+        .collect();
+    let may_drink =
+        |person_id: u64| {
+            let adult =
+                ctx.db.person().id().find(person_id).is_some_and(|p| {
+                    p.is_alive && !p.is_player && p.life_stage >= life_stages::ADULT
+                });
+            let unwinding = ctx
+                .db
+                .activity()
+                .person_id()
+                .find(person_id)
+                .is_some_and(|a| {
+                    matches!(
+                        a.activity_type,
+                        activity_types::SOCIALIZING | activity_types::RELAXING
+                    )
+                });
+            let on_duty = ctx
+                .db
+                .crew()
+                .person_id()
+                .find(person_id)
+                .is_some_and(|c| c.on_duty);
+            adult && unwinding && !on_duty
+        };
+    let drinkers: Vec<u64> = ctx
+        .db
+        .position()
+        .iter()
+        .filter(|p| bars.contains(&p.room_id) && may_drink(p.person_id))
+        .map(|p| p.person_id)
+        .collect();
+    if drinkers.is_empty() {
+        return;
+    }
+
+    let drink_price = price(
+        DRINK_PRICE,
+        compute_levels(&resource_values(&resources)).food,
+    );
+    let mut served = 0;
+    for person_id in drinkers {
+        let Some(mut needs) = ctx.db.needs().person_id().find(person_id) else {
+            continue;
+        };
+        let existing = ctx.db.drinking().person_id().find(person_id);
+        let wanted = drinks_wanted(existing.as_ref().map_or(0.0, |d| d.dependence));
+        let poured = pour(wanted, &mut resources.food, &mut resources.water, || {
+            charge(ctx, person_id, drink_price)
+        });
+        if poured == 0 {
+            continue;
+        }
+        let neuroticism = ctx
+            .db
+            .personality()
+            .person_id()
+            .find(person_id)
+            .map_or(0.5, |p| p.neuroticism);
+        let mut drinking = existing.unwrap_or_else(|| {
+            ctx.db.drinking().insert(Drinking {
+                person_id,
+                intoxication: 0.0,
+                dependence: 0.0,
+                drinks: 0,
+                last_drink_at: sim_time,
+            })
+        });
+        for _ in 0..poured {
+            needs.social = (needs.social - DRINK_SOCIAL).max(0.0);
+            needs.comfort = (needs.comfort - DRINK_COMFORT).max(0.0);
+            drinking.intoxication += DRINK_INTOXICATION;
+            drinking.dependence = (drinking.dependence + dependence_gain(neuroticism)).min(1.0);
+            drinking.drinks += 1;
+            drinking.last_drink_at = sim_time;
+        }
+        served += poured;
+        ctx.db.needs().person_id().update(needs);
+        ctx.db.drinking().person_id().update(drinking);
+    }
+    if served > 0 {
+        ctx.db.ship_resources().id().update(resources);
+    }
+}
+
+/// Pour up to `wanted` drinks from the `food` and `water` stores, each
+/// paid for with `pay` before it is poured. Stops when the stores run dry
+/// or the drinker can't pay; returns how many were poured.
+fn pour(wanted: u32, food: &mut f32, water: &mut f32, mut pay: impl FnMut() -> bool) -> u32 {
+    let mut poured = 0;
+    while poured < wanted && *food >= DRINK_FOOD && *water >= DRINK_WATER && pay() {
+        *food -= DRINK_FOOD;
+        *water -= DRINK_WATER;
+        poured += 1;
+    }
+    poured
+}
+
+/// Roll a scene for everyone drunk enough to cause one who is not already
+/// held or wanted for it, and open an incident security has to deal with.
+fn cause_scenes(ctx: &ReducerContext, sim_time: f64) {
+    for drinking in ctx.db.drinking().iter() {
+        let agreeableness = ctx
+            .db
+            .personality()
+            .person_id()
+            .find(drinking.person_id)
+            .map_or(0.5, |p| p.agreeableness);
+        let chance = disorderly_chance(drinking.intoxication, agreeableness);
+        if roll(drinking.person_id, sim_time, DRINK_SALT) >= chance {
+            continue;
+        }
+        let held = ctx
+            .db
+            .detention()
+            .person_id()
+            .find(drinking.person_id)
+            .is_some();
+        let wanted = ctx.db.incident().iter().any(|i| {
+            i.closed_at.is_none()
+                && i.offender_id == drinking.person_id
+                && i.kind == incident_kinds::DRUNKENNESS
+        });
+        let Some(room_id) = ctx
+            .db
+            .position()
+            .person_id()
+            .find(drinking.person_id)
+            .map(|p| p.room_id)
+        else {
+            continue;
+        };
+        if held || wanted {
+            continue;
+        }
+        log::info!(
+            "Person {} drunk and disorderly in room {}",
+            drinking.person_id,
+            room_id
+        );
+        ctx.db.incident().insert(Incident {
+            id: 0,
+            kind: incident_kinds::DRUNKENNESS,
+            room_id,
+            offender_id: drinking.person_id,
+            victim_id: None,
+            occurred_at: sim_time,
+            responder_id: None,
+            closed_at: None,
+            detained: false,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_broke_patron_is_not_served() {
+        let (mut food, mut water) = (10.0, 10.0);
+        assert_eq!(pour(2, &mut food, &mut water, || false), 0);
+        assert_eq!((food, water), (10.0, 10.0));
+
+        // Credits for one drink of the two wanted
+        let mut credits = DRINK_PRICE;
+        let mut pay = || {
+            let paid = credits >= DRINK_PRICE;
+            credits = credits.saturating_sub(DRINK_PRICE);
+            paid
+        };
+        assert_eq!(pour(2, &mut food, &mut water, &mut pay), 1);
+        assert!(food < 10.0 && water < 10.0);
+    }
+
+    #[test]
+    fn test_dry_stores_pour_nothing() {
+        let (mut food, mut water) = (0.0, 10.0);
+        let mut charged = false;
+        assert_eq!(
+            pour(1, &mut food, &mut water, || {
+                charged = true;
+                true
+            }),
+            0
+        );
+        assert!(!charged);
+    }
+}
@@ -0,0 +1,230 @@
+//! Career system - crew experience on duty and in training, certification
+//! in their department skill and promotion into ranks that open up.
+
+use crate::tables::*;
+use progship_logic::careers::{
+    best_candidate, career_skill, certification, practice, Candidate, PROMOTABLE, PROMOTION_MORALE,
+};
+use progship_logic::population::rank_distribution;
+use progship_logic::security::clearance;
+use progship_logic::skills::{is_training_room, SkillCategory};
+use spacetimedb::{ReducerContext, Table};
+
+use super::training::{set_skill_levels, skill_levels};
+
+/// Hourly: crew on duty or in a classroom gain experience in their career
+/// skill and earn the certification it reaches. Once a day, rank
+/// vacancies below the command posts are filled by promotion.
+pub fn tick_careers(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    let hours = (sim_time.floor() - (sim_time - delta_hours).floor()) as f32;
+    if hours <= 0.0 {
+        return;
+    }
+    let classrooms: Vec<u32> = ctx
+        .db
+        .room()
+        .iter()
+        .filter(|r| is_training_room(r.room_type))
+        .map(|r| r.id)
+        .collect();
+
+    for crew in ctx.db.crew().iter() {
+        let alive = ctx
+            .db
+            .person()
+            .id()
+            .find(crew.person_id)
+            .is_some_and(|p| p.is_alive);
+        if !alive {
+            continue;
+        }
+        let Some(mut skills) = ctx.db.skills().person_id().find(crew.person_id) else {
+            continue;
+        };
+        let activity = ctx.db.activity().person_id().find(crew.person_id);
+        let working = crew.on_duty
+            && activity
+                .as_ref()
+                .is_some_and(|a| a.activity_type == activity_types::ON_DUTY);
+        let in_class = activity
+            .as_ref()
+            .is_some_and(|a| a.activity_type == activity_types::TRAINING)
+            && ctx
+                .db
+                .position()
+                .person_id()
+                .find(crew.person_id)
+                .is_some_and(|p| classrooms.contains(&p.room_id));
+
+        let skill = career_skill(crew.department);
+        let mut levels = skill_levels(&skills);
+        let Some(level) = SkillCategory::ALL
+            .iter()
+            .position(|&c| c == skill)
+            .and_then(|i| levels.get_mut(i))
+        else {
+            continue;
+        };
+        let existing = ctx.db.career().person_id().find(crew.person_id);
+        let is_new = existing.is_none();
+        // Crew aboard at launch have held their rank since the voyage began
+        let mut career = existing.unwrap_or(Career {
+            person_id: crew.person_id,
+            service_hours: 0.0,
+            certification: certification(*level),
+            rank_since: 0.0,
+        });
+        if working || in_class {
+            *level = practice(*level, skill, hours, in_class);
+            career.service_hours += hours;
+            let earned = certification(*level);
+            set_skill_levels(&mut skills, &levels);
+            ctx.db.skills().person_id().update(skills);
+            if earned > career.certification {
+                career.certification = earned;
+                log::info!(
+                    "Crew member {} earned {} certification",
+                    crew.person_id,
+                    progship_logic::constants::certifications::name(earned)
+                );
+            }
+        }
+        if is_new {
+            ctx.db.career().insert(career);
+        } else {
+            ctx.db.career().person_id().update(career);
+        }
+    }
+
+    let day = |t: f64| (t / 24.0).floor();
+    if day(sim_time) != day(sim_time - delta_hours) {
+        fill_vacancies(ctx, sim_time);
+    }
+}
+
+/// Promote into every rank the living crew holds fewer of than a crew its
+/// size should, highest first.
+fn fill_vacancies(ctx: &ReducerContext, sim_time: f64) {
+    let crew: Vec<Crew> = ctx
+        .db
+        .crew()
+        .iter()
+        .filter(|c| {
+            ctx.db
+                .person()
+                .id()
+                .find(c.person_id)
+                .is_some_and(|p| p.is_alive)
+        })
+        .collect();
+    let quotas = rank_distribution(crew.len() as u32);
+    let mut held = [0u32; 8];
+    for c in &crew {
+        if let Some(count) = held.get_mut(c.rank as usize) {
+            *count += 1;
+        }
+    }
+
+    let mut candidates: Vec<Candidate> = crew
+        .iter()
+        .filter(|c| {
+            !ctx.db
+                .person()
+                .id()
+                .find(c.person_id)
+                .is_some_and(|p| p.is_player)
+        })
+        .filter(|c| ctx.db.detention().person_id().find(c.person_id).is_none())
+        .filter_map(|c| {
+            let career = ctx.db.career().person_id().find(c.person_id)?;
+            let skills = ctx.db.skills().person_id().find(c.person_id)?;
+            let skill = SkillCategory::ALL
+                .iter()
+                .position(|&cat| cat == career_skill(c.department))
+                .and_then(|i| skill_levels(&skills).get(i).copied())?;
+            Some(Candidate {
+                person_id: c.person_id,
+                rank: c.rank,
+                certification: career.certification,
+                days_in_rank: (sim_time - career.rank_since) / 24.0,
+                skill,
+                service_hours: career.service_hours,
+            })
+        })
+        .collect();
+
+    for rank in PROMOTABLE {
+        let (Some(&quota), Some(&have)) = (quotas.get(rank as usize), held.get(rank as usize))
+        else {
+            continue;
+        };
+        for _ in have..quota {
+            let Some(person_id) = best_candidate(&candidates, rank) else {
+                break;
+            };
+            promote(ctx, person_id, rank, sim_time);
+            if let Some(c) = candidates.iter_mut().find(|c| c.person_id == person_id) {
+                c.rank = rank;
+                c.days_in_rank = 0.0;
+            }
+            if let Some(count) = held.get_mut(rank as usize) {
+                *count += 1;
+            }
+            if let Some(count) = rank
+                .checked_sub(1)
+                .and_then(|below| held.get_mut(below as usize))
+            {
+                *count = count.saturating_sub(1);
+            }
+        }
+    }
+}
+
+/// Raise `person_id` to `rank`, with the clearance and lift to morale that
+/// go with it and a promotion where they are.
+fn promote(ctx: &ReducerContext, person_id: u64, rank: u8, sim_time: f64) {
+    let Some(mut crew) = ctx.db.crew().person_id().find(person_id) else {
+        return;
+    };
+    crew.rank = rank;
+    ctx.db.crew().person_id().update(crew);
+    if let Some(mut person) = ctx.db.person().id().find(person_id) {
+        log::info!(
+            "{} {} promoted to {}",
+            person.given_name,
+            person.family_name,
+            progship_logic::constants::ranks::name(rank)
+        );
+        person.clearance = clearance(true, rank);
+        ctx.db.person().id().update(person);
+    }
+    if let Some(mut career) = ctx.db.career().person_id().find(person_id) {
+        career.rank_since = sim_time;
+        ctx.db.career().person_id().update(career);
+    }
+    if let Some(mut needs) = ctx.db.needs().person_id().find(person_id) {
+        needs.morale = (needs.morale + PROMOTION_MORALE).min(1.0);
+        ctx.db.needs().person_id().update(needs);
+    }
+    let Some(room_id) = ctx
+        .db
+        .position()
+        .person_id()
+        .find(person_id)
+        .map(|p| p.room_id)
+    else {
+        return;
+    };
+    ctx.db.event().insert(Event {
+        id: 0,
+        event_type: event_types::PROMOTION,
+        room_id,
+        started_at: sim_time,
+        duration: 1.0,
+        state: event_states::ACTIVE,
+        responders_needed: 0,
+        responders_assigned: 0,
+        severity: 0.1,
+        escalated_to: None,
+    });
+}
@@ -0,0 +1,88 @@
+//! Cargo system - drawing stores out of the holds.
+
+use crate::tables::*;
+use progship_logic::cargo::{
+    density, draw, CARGO_DRAW_INTERVAL_HOURS, LOT_MAX_TONS, MEDICAL_TONS_PER_PERSON_HOUR,
+};
+use spacetimedb::{ReducerContext, Table};
+
+/// Take up to `tons` of `category` out of the holds, emptying lots on
+/// `deck` first. Returns the tons actually taken.
+pub fn draw_cargo(ctx: &ReducerContext, category: u8, tons: f32, deck: Option<i32>) -> f32 {
+    let mut lots: Vec<(bool, CargoItem)> = ctx
+        .db
+        .cargo_item()
+        .iter()
+        .filter(|c| c.category == category && c.mass_tons > 0.0)
+        .map(|c| {
+            let nearby =
+                deck.is_some() && ctx.db.room().id().find(c.room_id).map(|r| r.deck) == deck;
+            (nearby, c)
+        })
+        .collect();
+    lots.sort_by_key(|(nearby, c)| (!nearby, c.id));
+
+    let mut stock: Vec<f32> = lots.iter().map(|(_, c)| c.mass_tons).collect();
+    let taken = draw(&mut stock, tons);
+    for ((_, mut lot), left) in lots.into_iter().zip(stock) {
+        if left == lot.mass_tons {
+            continue;
+        }
+        if left <= 0.0 {
+            ctx.db.cargo_item().id().delete(lot.id);
+        } else {
+            lot.mass_tons = left;
+            lot.volume_m3 = left / density(category);
+            ctx.db.cargo_item().id().update(lot);
+        }
+    }
+    taken
+}
+
+/// Put `tons` of `category` down in `room_id`, topping up a lot already
+/// there that has room for it.
+pub fn stow_cargo(ctx: &ReducerContext, category: u8, room_id: u32, tons: f32) {
+    if tons <= 0.0 {
+        return;
+    }
+    let lot = ctx.db.cargo_item().iter().find(|c| {
+        c.category == category && c.room_id == room_id && c.mass_tons + tons <= LOT_MAX_TONS
+    });
+    match lot {
+        Some(mut lot) => {
+            lot.mass_tons += tons;
+            lot.volume_m3 = lot.mass_tons / density(category);
+            ctx.db.cargo_item().id().update(lot);
+        }
+        None => {
+            ctx.db.cargo_item().insert(CargoItem {
+                id: 0,
+                category,
+                room_id,
+                mass_tons: tons,
+                volume_m3: tons / density(category),
+            });
+        }
+    }
+}
+
+/// Whether any of `category` is left in the holds.
+pub fn has_cargo(ctx: &ReducerContext, category: u8) -> bool {
+    ctx.db
+        .cargo_item()
+        .iter()
+        .any(|c| c.category == category && c.mass_tons > 0.0)
+}
+
+/// Draw the day-to-day medical supplies for everyone aboard, once per draw
+/// interval.
+pub fn tick_cargo(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    let interval = |t: f64| (t / CARGO_DRAW_INTERVAL_HOURS).floor();
+    if interval(sim_time) == interval(sim_time - delta_hours) {
+        return;
+    }
+
+    let alive = ctx.db.person().iter().filter(|p| p.is_alive).count() as f32;
+    let wanted = alive * MEDICAL_TONS_PER_PERSON_HOUR * CARGO_DRAW_INTERVAL_HOURS as f32;
+    draw_cargo(ctx, cargo_categories::MEDICAL_SUPPLIES, wanted, None);
+}
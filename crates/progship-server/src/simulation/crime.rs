@@ -0,0 +1,385 @@
+//! Crime system - offenses by the unhappy, the security response and the
+//! brig.
+
+use crate::tables::*;
+use progship_logic::constants::incident_kinds as kinds;
+use progship_logic::crime::{
+    detention_hours, offense, ASSAULT_INJURY, COLD_CASE_HOURS, HOLDING_ROOMS, INCIDENT_KEEP,
+    VANDALISM_DAMAGE, VICTIM_MORALE,
+};
+use progship_logic::utility::UtilityInput;
+use spacetimedb::{ReducerContext, Table};
+use std::collections::HashSet;
+
+use super::features::feature_flags;
+use super::inventory::{confiscate, steal};
+use super::ship_systems::health_to_status;
+use super::warn;
+use super::{confined_activity, drop_everything};
+
+/// Every tick: responders who have caught up with their suspect detain
+/// them. Hourly: detainees who have served their time are released, cold
+/// cases closed, idle security crew sent to open incidents and, with crime
+/// enabled, the unhappy may offend.
+pub fn tick_crime(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    make_arrests(ctx, sim_time);
+    if sim_time.floor() == (sim_time - delta_hours).floor() {
+        return;
+    }
+    release_detainees(ctx, sim_time);
+    assign_responders(ctx, sim_time);
+    if feature_flags(ctx).crime && sim_time >= 0.0 {
+        commit_offenses(ctx, sim_time, sim_time as u64);
+    }
+}
+
+/// What a detainee does next: their own pick, minus any duty, but in the
+/// room they are held in. `None` for anyone not detained.
+pub fn detained_activity(
+    ctx: &ReducerContext,
+    person_id: u64,
+    input: &UtilityInput,
+) -> Option<(u8, f32, Option<u32>)> {
+    let detention = ctx.db.detention().person_id().find(person_id)?;
+    Some(confined_activity(input, detention.room_id))
+}
+
+/// What a security crew member responding to an incident does next: go
+/// after the suspect, wherever they are now.
+pub fn response_activity(ctx: &ReducerContext, person_id: u64) -> Option<(u8, f32, Option<u32>)> {
+    let incident = ctx
+        .db
+        .incident()
+        .iter()
+        .find(|i| i.closed_at.is_none() && i.responder_id == Some(person_id))?;
+    let room_id = ctx
+        .db
+        .position()
+        .person_id()
+        .find(incident.offender_id)
+        .map_or(incident.room_id, |p| p.room_id);
+    Some((activity_types::ON_DUTY, 0.5, Some(room_id)))
+}
+
+/// Roll an offense for every living adult not already detained.
+fn commit_offenses(ctx: &ReducerContext, sim_time: f64, hour: u64) {
+    let detained: HashSet<u64> = ctx.db.detention().iter().map(|d| d.person_id).collect();
+    let suspects: Vec<(u64, u8)> = ctx
+        .db
+        .person()
+        .iter()
+        .filter(|p| p.is_alive && !p.is_player && p.life_stage >= life_stages::ADULT)
+        .filter(|p| !detained.contains(&p.id))
+        .filter_map(|p| {
+            let morale = ctx.db.needs().person_id().find(p.id)?.morale;
+            let traits = ctx.db.personality().person_id().find(p.id)?;
+            let kind = offense(p.id, hour, morale, traits.neuroticism, traits.agreeableness)?;
+            Some((p.id, kind))
+        })
+        .collect();
+    for (offender_id, kind) in suspects {
+        let Some(room_id) = ctx
+            .db
+            .position()
+            .person_id()
+            .find(offender_id)
+            .map(|p| p.room_id)
+        else {
+            continue;
+        };
+        let victim_id = ctx
+            .db
+            .position()
+            .iter()
+            .filter(|p| p.room_id == room_id && p.person_id != offender_id)
+            .map(|p| p.person_id)
+            .find(|&id| ctx.db.person().id().find(id).is_some_and(|p| p.is_alive));
+        // Theft and assault need someone to steal from or attack
+        let kind = if victim_id.is_none() {
+            kinds::VANDALISM
+        } else {
+            kind
+        };
+        let victim_id = victim_id.filter(|_| kind != kinds::VANDALISM);
+        if let Some(mut needs) = victim_id.and_then(|id| ctx.db.needs().person_id().find(id)) {
+            needs.morale = (needs.morale - VICTIM_MORALE).max(0.0);
+            if kind == kinds::ASSAULT {
+                needs.health = (needs.health - ASSAULT_INJURY).max(0.0);
+            }
+            ctx.db.needs().person_id().update(needs);
+        }
+        match kind {
+            kinds::ASSAULT => {
+                ctx.db.event().insert(Event {
+                    id: 0,
+                    event_type: event_types::ALTERCATION,
+                    room_id,
+                    started_at: sim_time,
+                    duration: 1.0,
+                    state: event_states::ACTIVE,
+                    responders_needed: 1,
+                    responders_assigned: 0,
+                    severity: 0.4,
+                    escalated_to: None,
+                });
+            }
+            kinds::VANDALISM => vandalize(ctx, room_id),
+            kinds::THEFT => {
+                if let Some(victim_id) = victim_id {
+                    steal(ctx, offender_id, victim_id, sim_time);
+                }
+            }
+            _ => {}
+        }
+        log::info!(
+            "{} by person {} in room {}",
+            kinds::name(kind),
+            offender_id,
+            room_id
+        );
+        ctx.db.incident().insert(Incident {
+            id: 0,
+            kind,
+            room_id,
+            offender_id,
+            victim_id,
+            occurred_at: sim_time,
+            responder_id: None,
+            closed_at: None,
+            detained: false,
+        });
+    }
+}
+
+/// Damage the first piece of equipment served by `room_id`'s node.
+fn vandalize(ctx: &ReducerContext, room_id: u32) {
+    let Some(node_id) = ctx.db.room().id().find(room_id).map(|r| r.node_id) else {
+        return;
+    };
+    let target = ctx
+        .db
+        .subsystem()
+        .iter()
+        .filter(|s| s.node_id == node_id)
+        .min_by_key(|s| s.id);
+    if let Some(mut sub) = target {
+        sub.health = (sub.health - VANDALISM_DAMAGE).max(0.0);
+        sub.status = health_to_status(sub.health);
+        ctx.db.subsystem().id().update(sub);
+    }
+}
+
+/// Close cold cases and send an on-duty security crew member who is free,
+/// nearest by deck, to every open incident that has nobody on it.
+fn assign_responders(ctx: &ReducerContext, sim_time: f64) {
+    let deck_of = |person_id: u64| {
+        ctx.db
+            .position()
+            .person_id()
+            .find(person_id)
+            .and_then(|p| ctx.db.room().id().find(p.room_id))
+            .map(|r| r.deck)
+    };
+    let open: Vec<Incident> = ctx
+        .db
+        .incident()
+        .iter()
+        .filter(|i| i.closed_at.is_none())
+        .collect();
+    let mut busy: HashSet<u64> = open.iter().filter_map(|i| i.responder_id).collect();
+    busy.extend(ctx.db.detention().iter().map(|d| d.person_id));
+    busy.extend(ctx.db.order_assignment().iter().map(|a| a.person_id));
+    let mut closed = false;
+
+    for mut incident in open {
+        // A suspect already held for something else is held for this too
+        let held = ctx
+            .db
+            .detention()
+            .person_id()
+            .find(incident.offender_id)
+            .is_some();
+        let alive = ctx
+            .db
+            .person()
+            .id()
+            .find(incident.offender_id)
+            .is_some_and(|p| p.is_alive);
+        if held || !alive || sim_time - incident.occurred_at >= COLD_CASE_HOURS {
+            if !held {
+                log::info!("Incident {} closed unsolved", incident.id);
+            }
+            incident.closed_at = Some(sim_time);
+            incident.detained = held;
+            ctx.db.incident().id().update(incident);
+            closed = true;
+            continue;
+        }
+        let on_case = incident.responder_id.is_some_and(|id| {
+            ctx.db
+                .crew()
+                .person_id()
+                .find(id)
+                .is_some_and(|c| c.on_duty)
+                && ctx.db.person().id().find(id).is_some_and(|p| p.is_alive)
+        });
+        if on_case {
+            continue;
+        }
+        let deck = deck_of(incident.offender_id);
+        let responder = ctx
+            .db
+            .crew()
+            .iter()
+            .filter(|c| c.department == departments::SECURITY && c.on_duty)
+            .filter(|c| !busy.contains(&c.person_id) && c.person_id != incident.offender_id)
+            .filter(|c| {
+                ctx.db
+                    .person()
+                    .id()
+                    .find(c.person_id)
+                    .is_some_and(|p| p.is_alive && !p.is_player)
+            })
+            .min_by_key(|c| {
+                let distance = match (deck_of(c.person_id), deck) {
+                    (Some(a), Some(b)) => (a - b).abs(),
+                    _ => i32::MAX,
+                };
+                (distance, c.person_id)
+            })
+            .map(|c| c.person_id);
+        if responder == incident.responder_id {
+            continue;
+        }
+        if let Some(id) = responder {
+            busy.insert(id);
+            drop_everything(ctx, id);
+        }
+        incident.responder_id = responder;
+        ctx.db.incident().id().update(incident);
+    }
+
+    if closed {
+        prune_incidents(ctx);
+    }
+}
+
+/// Detain every suspect whose responder has reached the room they are in.
+fn make_arrests(ctx: &ReducerContext, sim_time: f64) {
+    let room_of = |person_id: u64| {
+        ctx.db
+            .position()
+            .person_id()
+            .find(person_id)
+            .map(|p| p.room_id)
+    };
+    let caught: Vec<Incident> = ctx
+        .db
+        .incident()
+        .iter()
+        .filter(|i| i.closed_at.is_none())
+        .filter(|i| {
+            i.responder_id.is_some_and(|responder| {
+                room_of(responder).is_some() && room_of(responder) == room_of(i.offender_id)
+            })
+        })
+        .collect();
+    if caught.is_empty() {
+        return;
+    }
+
+    for mut incident in caught {
+        let Some(room_id) = holding_room(ctx, incident.offender_id) else {
+            warn(
+                ctx,
+                "crime",
+                "no brig or security office to hold suspects in".into(),
+            );
+            incident.closed_at = Some(sim_time);
+            ctx.db.incident().id().update(incident);
+            continue;
+        };
+        if ctx
+            .db
+            .detention()
+            .person_id()
+            .find(incident.offender_id)
+            .is_none()
+        {
+            ctx.db.detention().insert(Detention {
+                person_id: incident.offender_id,
+                incident_id: incident.id,
+                room_id,
+                until: sim_time + detention_hours(incident.kind),
+            });
+            drop_everything(ctx, incident.offender_id);
+        }
+        // Stolen goods go back to their owners, contraband is taken away
+        confiscate(ctx, incident.offender_id, sim_time);
+        log::info!(
+            "Person {} detained for {}",
+            incident.offender_id,
+            kinds::name(incident.kind)
+        );
+        if let Some(responder) = incident.responder_id {
+            drop_everything(ctx, responder);
+        }
+        incident.closed_at = Some(sim_time);
+        incident.detained = true;
+        ctx.db.incident().id().update(incident);
+    }
+    prune_incidents(ctx);
+}
+
+/// Release everyone who has served their time.
+fn release_detainees(ctx: &ReducerContext, sim_time: f64) {
+    let served: Vec<u64> = ctx
+        .db
+        .detention()
+        .iter()
+        .filter(|d| d.until <= sim_time)
+        .map(|d| d.person_id)
+        .collect();
+    for person_id in served {
+        log::info!("Person {} released from the brig", person_id);
+        ctx.db.detention().person_id().delete(person_id);
+        drop_everything(ctx, person_id);
+    }
+}
+
+/// The best of [`HOLDING_ROOMS`] to hold `person_id` in, on their deck if
+/// there is one there.
+pub fn holding_room(ctx: &ReducerContext, person_id: u64) -> Option<u32> {
+    let deck = ctx
+        .db
+        .position()
+        .person_id()
+        .find(person_id)
+        .and_then(|p| ctx.db.room().id().find(p.room_id))
+        .map(|r| r.deck);
+    ctx.db
+        .room()
+        .iter()
+        .filter(|r| HOLDING_ROOMS.contains(&r.room_type))
+        .min_by_key(|r| {
+            let rank = HOLDING_ROOMS.iter().position(|&t| t == r.room_type);
+            (rank, Some(r.deck) != deck, r.id)
+        })
+        .map(|r| r.id)
+}
+
+/// Drop the oldest closed incidents past [`INCIDENT_KEEP`].
+fn prune_incidents(ctx: &ReducerContext) {
+    let mut closed: Vec<(f64, u64)> = ctx
+        .db
+        .incident()
+        .iter()
+        .filter_map(|i| i.closed_at.map(|at| (at, i.id)))
+        .collect();
+    if closed.len() > INCIDENT_KEEP {
+        closed.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+        for &(_, id) in &closed[..closed.len() - INCIDENT_KEEP] {
+            ctx.db.incident().id().delete(id);
+        }
+    }
+}
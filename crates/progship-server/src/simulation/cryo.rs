@@ -0,0 +1,298 @@
+//! Cryosleep system - passengers asleep in the cryo bays: putting them to
+//! sleep at launch, their pods' power, the medical checks they need and
+//! their revival, on schedule or in an emergency.
+
+use crate::tables::*;
+use progship_logic::cryo::{
+    check_due, check_hours, revival_due, revival_harm, sleeper_count, vitals_after, wake_time,
+    CHECK_PRIORITY, REVIVAL_HOURS, WAKING_FATIGUE, WAKING_HUNGER,
+};
+use spacetimedb::{ReducerContext, Table};
+use std::collections::{BTreeMap, HashSet};
+
+use super::activities::restart_activity;
+use super::features::feature_flags;
+use super::milestones::voyage_hours;
+
+/// Put passengers travelling alone to sleep in the cryo bays' pods, as
+/// many as the wanted share of the passengers and the pods allow.
+pub fn fill_cryo_bays(ctx: &ReducerContext) {
+    let Some(config) = ctx.db.ship_config().id().find(0) else {
+        return;
+    };
+    let bays: HashSet<u32> = ctx
+        .db
+        .room()
+        .iter()
+        .filter(|r| r.room_type == room_types::CRYO_BAY)
+        .map(|r| r.id)
+        .collect();
+    let mut pods: Vec<Furniture> = ctx
+        .db
+        .furniture()
+        .iter()
+        .filter(|f| f.furniture_type == furniture_types::CRYO_POD && bays.contains(&f.room_id))
+        .collect();
+    pods.sort_by_key(|f| (f.room_id, f.id));
+    let mut candidates: Vec<u64> =
+        ctx.db
+            .passenger()
+            .iter()
+            .map(|p| p.person_id)
+            .filter(|&id| ctx.db.family_member().person_id().find(id).is_none())
+            .filter(|&id| {
+                ctx.db.person().id().find(id).is_some_and(|p| {
+                    p.is_alive && !p.is_player && p.life_stage == life_stages::ADULT
+                })
+            })
+            .collect();
+    candidates.sort_unstable();
+    let count = sleeper_count(
+        config.passenger_count,
+        candidates.len() as u32,
+        pods.len() as u32,
+    );
+    let voyage = voyage_hours(config.seed);
+    let sim_time = config.sim_time;
+    for (index, (person_id, pod)) in candidates.into_iter().zip(pods).enumerate() {
+        if index as u32 >= count {
+            break;
+        }
+        let wake_at = wake_time(voyage, index as u32);
+        ctx.db.hibernation().insert(Hibernation {
+            person_id,
+            room_id: pod.room_id,
+            pod_id: pod.id,
+            since: sim_time,
+            wake_at,
+            checked_at: sim_time,
+            vitals: 1.0,
+            reviving_since: None,
+            emergency: false,
+            revival_event: None,
+        });
+        if let Some(mut pos) = ctx.db.position().person_id().find(person_id) {
+            pos.room_id = pod.room_id;
+            pos.x = pod.x;
+            pos.y = pod.y;
+            ctx.db.position().person_id().update(pos);
+        }
+        ctx.db.movement().person_id().delete(person_id);
+        if let Some(mut activity) = ctx.db.activity().person_id().find(person_id) {
+            let hours = (wake_at - sim_time).max(0.0) as f32;
+            restart_activity(&mut activity, activity_types::SLEEPING, sim_time, hours);
+            activity.target_room_id = Some(pod.room_id);
+            ctx.db.activity().person_id().update(activity);
+        }
+    }
+    log::info!("{} passengers asleep in {} cryo bays", count, bays.len());
+}
+
+/// Everyone asleep in the cryo bays, who have no needs or social life.
+pub fn sleepers(ctx: &ReducerContext) -> HashSet<u64> {
+    ctx.db.hibernation().iter().map(|h| h.person_id).collect()
+}
+
+/// Whether `person_id` is asleep in a cryo bay.
+pub fn is_hibernating(ctx: &ReducerContext, person_id: u64) -> bool {
+    ctx.db.hibernation().person_id().find(person_id).is_some()
+}
+
+/// Keep the sleepers: their vitals fall while their checks are overdue and
+/// fast without power to their pods, and they are revived, on schedule or
+/// once their vitals are too low, waking [`REVIVAL_HOURS`] later. Checks
+/// that are due are put on the work order board hourly.
+pub fn tick_cryo(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    let pods: Vec<Hibernation> = ctx.db.hibernation().iter().collect();
+    if pods.is_empty() {
+        return;
+    }
+    let unpowered: HashSet<u32> = ctx
+        .db
+        .room_power()
+        .iter()
+        .filter(|p| !p.powered)
+        .map(|p| p.room_id)
+        .collect();
+    for mut sleeper in pods {
+        if let Some(since) = sleeper.reviving_since {
+            if sim_time - since >= REVIVAL_HOURS as f64 {
+                wake(ctx, &sleeper);
+            }
+            continue;
+        }
+        let powered = !unpowered.contains(&sleeper.room_id);
+        let overdue = check_due(sleeper.checked_at, sim_time);
+        sleeper.vitals = vitals_after(sleeper.vitals, powered, overdue, delta_hours as f32);
+        if let Some(emergency) = revival_due(sim_time, sleeper.wake_at, sleeper.vitals) {
+            sleeper.reviving_since = Some(sim_time);
+            sleeper.emergency = emergency;
+            sleeper.revival_event = revival_event(ctx, sleeper.room_id, emergency, sim_time);
+            log::info!(
+                "Reviving person {} from cryosleep{}",
+                sleeper.person_id,
+                if emergency { " in an emergency" } else { "" }
+            );
+        }
+        ctx.db.hibernation().person_id().update(sleeper);
+    }
+
+    if sim_time.floor() != (sim_time - delta_hours).floor() {
+        file_checks(ctx, sim_time);
+    }
+}
+
+/// Whether any sleeper in the cryo bay `room_id` is due a check.
+pub(super) fn checks_due(ctx: &ReducerContext, room_id: u32, sim_time: f64) -> bool {
+    ctx.db.hibernation().iter().any(|h| {
+        h.room_id == room_id && h.reviving_since.is_none() && check_due(h.checked_at, sim_time)
+    })
+}
+
+/// A medic has checked on every sleeper in the cryo bay `room_id`, putting
+/// right what has slipped in their pods.
+pub(super) fn check_sleepers(ctx: &ReducerContext, room_id: u32, sim_time: f64) {
+    let checked: Vec<Hibernation> = ctx
+        .db
+        .hibernation()
+        .iter()
+        .filter(|h| h.room_id == room_id && h.reviving_since.is_none())
+        .collect();
+    for mut sleeper in checked {
+        sleeper.checked_at = sim_time;
+        sleeper.vitals = 1.0;
+        ctx.db.hibernation().person_id().update(sleeper);
+    }
+}
+
+/// File a check for each cryo bay with sleepers due one and none on the
+/// board.
+fn file_checks(ctx: &ReducerContext, sim_time: f64) {
+    // Sleepers in each bay, and whether any of them is due
+    let mut bays: BTreeMap<u32, (u32, bool)> = BTreeMap::new();
+    for sleeper in ctx.db.hibernation().iter() {
+        if sleeper.reviving_since.is_none() {
+            let (count, due) = bays.entry(sleeper.room_id).or_default();
+            *count += 1;
+            *due |= check_due(sleeper.checked_at, sim_time);
+        }
+    }
+    let filed: HashSet<u32> = ctx
+        .db
+        .work_order()
+        .iter()
+        .filter(|o| {
+            o.kind == work_kinds::CRYO_CHECK
+                && matches!(
+                    o.state,
+                    work_order_states::OPEN | work_order_states::CLAIMED
+                )
+        })
+        .filter_map(|o| o.room_id)
+        .collect();
+    for (room_id, (sleepers, due)) in bays {
+        if !due || filed.contains(&room_id) {
+            continue;
+        }
+        let place = ctx
+            .db
+            .room()
+            .id()
+            .find(room_id)
+            .map_or_else(|| "a cryo bay".to_string(), |r| r.name);
+        ctx.db.work_order().insert(WorkOrder {
+            id: 0,
+            kind: work_kinds::CRYO_CHECK,
+            state: work_order_states::OPEN,
+            reason: format!("Sleepers due a check in {}", place),
+            department: departments::MEDICAL,
+            required_skill: skill_types::MEDICAL,
+            required_parts: 0.0,
+            part_kind: part_kinds::MECHANICAL,
+            priority: CHECK_PRIORITY,
+            room_id: Some(room_id),
+            subsystem_id: 0,
+            component_id: 0,
+            infra_edge_id: None,
+            event_id: None,
+            hull_damage_id: None,
+            assigned_crew_id: None,
+            assigned_robot_id: None,
+            progress: 0.0,
+            duration_hours: check_hours(sleepers),
+            created_at: sim_time,
+            claimed_at: None,
+            completed_at: None,
+        });
+    }
+}
+
+/// The revival under way in the cryo bay `room_id` for medics to see to,
+/// started if there is none and made an emergency if this one is.
+fn revival_event(
+    ctx: &ReducerContext,
+    room_id: u32,
+    emergency: bool,
+    sim_time: f64,
+) -> Option<u64> {
+    let severity = if emergency { 0.7 } else { 0.2 };
+    let under_way = ctx.db.event().iter().find(|e| {
+        e.event_type == event_types::REVIVAL
+            && e.room_id == room_id
+            && e.state != event_states::RESOLVED
+    });
+    if let Some(mut event) = under_way {
+        let id = event.id;
+        if event.severity < severity {
+            event.severity = severity;
+            event.responders_needed = 2;
+            ctx.db.event().id().update(event);
+        }
+        return Some(id);
+    }
+    if !feature_flags(ctx).allows_event(event_types::REVIVAL) {
+        return None;
+    }
+    let event = ctx.db.event().insert(Event {
+        id: 0,
+        event_type: event_types::REVIVAL,
+        room_id,
+        started_at: sim_time,
+        duration: REVIVAL_HOURS,
+        state: event_states::ACTIVE,
+        responders_needed: if emergency { 2 } else { 1 },
+        responders_assigned: 0,
+        severity,
+        escalated_to: None,
+    });
+    Some(event.id)
+}
+
+/// Bring a sleeper out of their pod, hungry and groggy and hurt by the
+/// revival, the more so if it was an emergency or no medic came.
+fn wake(ctx: &ReducerContext, sleeper: &Hibernation) {
+    ctx.db.hibernation().person_id().delete(sleeper.person_id);
+    let attended = sleeper
+        .revival_event
+        .and_then(|id| ctx.db.event().id().find(id))
+        .is_none_or(|e| e.state != event_states::ESCALATED);
+    if let Some(mut needs) = ctx.db.needs().person_id().find(sleeper.person_id) {
+        needs.health = (needs.health - revival_harm(sleeper.emergency, attended)).max(0.0);
+        needs.hunger = needs.hunger.max(WAKING_HUNGER);
+        needs.fatigue = needs.fatigue.max(WAKING_FATIGUE);
+        ctx.db.needs().person_id().update(needs);
+    }
+    if let Some(mut activity) = ctx.db.activity().person_id().find(sleeper.person_id) {
+        activity.duration = 0.0;
+        ctx.db.activity().person_id().update(activity);
+    }
+    log::info!(
+        "Person {} woke from cryosleep{}",
+        sleeper.person_id,
+        if attended {
+            ""
+        } else {
+            " with no medic at hand"
+        }
+    );
+}
@@ -4,6 +4,9 @@ use crate::tables::*;
 use progship_logic::health;
 use spacetimedb::{ReducerContext, Table};
 
+use super::emotions::start_mourning;
+use super::funerals::lay_to_rest;
+
 /// Check all living people for death conditions and process deaths.
 /// Should run after `tick_needs` so health values are current.
 pub fn tick_death(ctx: &ReducerContext, sim_time: f64) {
@@ -24,88 +27,143 @@ pub fn tick_death(ctx: &ReducerContext, sim_time: f64) {
         if !person.is_alive {
             continue;
         }
+        let cause = cause_of_death(ctx, person_id);
+        record_death(ctx, person, cause, sim_time);
+    }
+}
 
-        // Mark as dead
-        let mut p = person;
-        p.is_alive = false;
-        ctx.db.person().id().update(p);
+/// What killed `person_id`, judged before their death event joins their
+/// room's events.
+fn cause_of_death(ctx: &ReducerContext, person_id: u64) -> u8 {
+    let room_id = ctx
+        .db
+        .position()
+        .person_id()
+        .find(person_id)
+        .map(|pos| pos.room_id)
+        .unwrap_or(0);
+    let room_event = ctx
+        .db
+        .event()
+        .iter()
+        .filter(|e| e.room_id == room_id && e.state != event_states::RESOLVED)
+        .map(|e| e.event_type)
+        .find(|&t| {
+            matches!(
+                t,
+                event_types::FIRE | event_types::HULL_BREACH | event_types::MEDICAL_EMERGENCY
+            )
+        });
+    let (oxygen, pressure) = ctx
+        .db
+        .room_air()
+        .room_id()
+        .find(room_id)
+        .map_or((0.21, 101.3), |a| (a.oxygen, a.pressure));
+    let (hunger, fatigue) = ctx
+        .db
+        .needs()
+        .person_id()
+        .find(person_id)
+        .map_or((0.0, 0.0), |n| (n.hunger, n.fatigue));
+    health::death_cause(room_event, oxygen, pressure, hunger, fatigue)
+}
 
-        // Get room for event placement
-        let room_id = ctx
-            .db
-            .position()
-            .person_id()
-            .find(person_id)
-            .map(|pos| pos.room_id)
-            .unwrap_or(0);
+/// Mark `person` dead of `cause` (see death_causes): record the casualty,
+/// raise a death event where they fell, start the mourning and shake
+/// everyone's morale, lay them to rest in the morgue with a funeral to
+/// come, and drop their movement and conversation.
+pub fn record_death(ctx: &ReducerContext, person: Person, cause: u8, sim_time: f64) {
+    let person_id = person.id;
+    let mut p = person;
+    p.is_alive = false;
+    ctx.db.person().id().update(p);
 
-        // Create death event
-        ctx.db.event().insert(Event {
-            id: 0,
-            event_type: event_types::DEATH,
-            room_id,
-            started_at: sim_time,
-            duration: 1.0,
-            state: event_states::ACTIVE,
-            responders_needed: 1,
-            responders_assigned: 0,
-            severity: 0.8,
-        });
+    let room_id = ctx
+        .db
+        .position()
+        .person_id()
+        .find(person_id)
+        .map(|pos| pos.room_id)
+        .unwrap_or(0);
+    ctx.db.casualty().insert(Casualty {
+        person_id,
+        died_at: sim_time,
+        room_id,
+        cause,
+    });
 
-        // Apply morale impact to people in the same room (witnesses)
-        let (witness_delta, shipwide_delta) = health::death_morale_impact();
-        for mut needs in ctx.db.needs().iter() {
-            if needs.person_id == person_id {
-                continue;
-            }
-            let is_witness = ctx
-                .db
-                .position()
-                .person_id()
-                .find(needs.person_id)
-                .map(|pos| pos.room_id == room_id)
-                .unwrap_or(false);
+    // Create death event
+    ctx.db.event().insert(Event {
+        id: 0,
+        event_type: event_types::DEATH,
+        room_id,
+        started_at: sim_time,
+        duration: 1.0,
+        state: event_states::ACTIVE,
+        responders_needed: 1,
+        responders_assigned: 0,
+        severity: 0.8,
+        escalated_to: None,
+    });
 
-            let delta = if is_witness {
-                witness_delta
-            } else {
-                shipwide_delta
-            };
-            needs.morale = (needs.morale + delta).clamp(0.0, 1.0);
-            ctx.db.needs().person_id().update(needs);
-        }
+    start_mourning(ctx, person_id, room_id, sim_time);
+    lay_to_rest(ctx, person_id, sim_time);
 
-        // Cancel any active movement
-        if ctx.db.movement().person_id().find(person_id).is_some() {
-            ctx.db.movement().person_id().delete(person_id);
+    // Apply morale impact to people in the same room (witnesses)
+    let (witness_delta, shipwide_delta) = health::death_morale_impact();
+    for mut needs in ctx.db.needs().iter() {
+        if needs.person_id == person_id {
+            continue;
         }
+        let is_witness = ctx
+            .db
+            .position()
+            .person_id()
+            .find(needs.person_id)
+            .map(|pos| pos.room_id == room_id)
+            .unwrap_or(false);
 
-        // Cancel any conversation
-        if let Some(ic) = ctx.db.in_conversation().person_id().find(person_id) {
-            ctx.db.in_conversation().person_id().delete(person_id);
-            // End the conversation
-            if let Some(mut conv) = ctx.db.conversation().id().find(ic.conversation_id) {
-                conv.state = conversation_states::ENDED;
-                ctx.db.conversation().id().update(conv);
-            }
-        }
+        let delta = if is_witness {
+            witness_delta
+        } else {
+            shipwide_delta
+        };
+        needs.morale = (needs.morale + delta).clamp(0.0, 1.0);
+        ctx.db.needs().person_id().update(needs);
+    }
 
-        // Update death count
-        if let Some(mut config) = ctx.db.ship_config().id().find(0) {
-            config.death_count += 1;
-            ctx.db.ship_config().id().update(config);
+    // Cancel any active movement
+    if ctx.db.movement().person_id().find(person_id).is_some() {
+        ctx.db.movement().person_id().delete(person_id);
+    }
+
+    // Cancel any conversation
+    if let Some(ic) = ctx.db.in_conversation().person_id().find(person_id) {
+        ctx.db.in_conversation().person_id().delete(person_id);
+        // End the conversation
+        if let Some(mut conv) = ctx.db.conversation().id().find(ic.conversation_id) {
+            conv.state = conversation_states::ENDED;
+            ctx.db.conversation().id().update(conv);
         }
+    }
 
-        log::info!(
-            "Person {} has died (room {}). Total deaths: {}",
-            person_id,
-            room_id,
-            ctx.db
-                .ship_config()
-                .id()
-                .find(0)
-                .map(|c| c.death_count)
-                .unwrap_or(0)
-        );
+    // Update death count
+    if let Some(mut config) = ctx.db.ship_config().id().find(0) {
+        config.death_count += 1;
+        ctx.db.ship_config().id().update(config);
     }
+
+    log::info!(
+        "Person {} has died of {} (room {}). Total deaths: {}",
+        person_id,
+        progship_logic::constants::death_causes::name(cause),
+        room_id,
+        ctx.db
+            .ship_config()
+            .id()
+            .find(0)
+            .map(|c| c.death_count)
+            .unwrap_or(0)
+    );
 }
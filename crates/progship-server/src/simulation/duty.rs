@@ -1,12 +1,24 @@
 //! Duty system - crew shift management.
 
 use crate::tables::*;
+use progship_logic::command::{escalation_order, CommandPost};
 use progship_logic::duty as duty_logic;
 use spacetimedb::{ReducerContext, Table};
 
-/// Update crew on/off duty status based on shift, time, and fitness.
+use super::bar::is_drunk;
+use super::education::is_teacher;
+use super::governance::current_policy;
+use super::mental_health::is_breaking_down;
+use super::morale::on_strike;
+
+/// Update crew on/off duty status based on shift, time, fitness, strikes,
+/// breakdowns and teaching, which takes crew off their watch.
 pub fn tick_duty(ctx: &ReducerContext, sim_time: f64) {
     let hour = (sim_time % 24.0) as f32;
+    let work_hours = current_policy(ctx).work_hours as f32;
+    let striking: Vec<u8> = (departments::COMMAND..departments::CIVILIAN)
+        .filter(|&d| on_strike(ctx, d))
+        .collect();
 
     for crew in ctx.db.crew().iter() {
         // Skip dead crew
@@ -21,16 +33,21 @@ pub fn tick_duty(ctx: &ReducerContext, sim_time: f64) {
             }
         }
 
-        // Check fitness: injured/exhausted crew can't work
+        // Check fitness: injured, exhausted or drunk crew can't work
         let fit = ctx
             .db
             .needs()
             .person_id()
             .find(crew.person_id)
             .map(|n| duty_logic::is_fit_for_duty(n.hunger, n.fatigue, n.health))
-            .unwrap_or(false);
+            .unwrap_or(false)
+            && !is_drunk(ctx, crew.person_id);
 
-        let should_work = duty_logic::should_be_on_duty(crew.shift, hour) && fit;
+        let should_work = duty_logic::should_be_on_duty_for(crew.shift, hour, work_hours)
+            && fit
+            && !striking.contains(&crew.department)
+            && !is_teacher(ctx, crew.person_id)
+            && !is_breaking_down(ctx, crew.person_id);
         if crew.on_duty != should_work {
             let mut c = crew;
             c.on_duty = should_work;
@@ -38,3 +55,34 @@ pub fn tick_duty(ctx: &ReducerContext, sim_time: f64) {
         }
     }
 }
+
+/// Living officer a `department` problem escalates to at ship `hour`: the
+/// department head if on watch, else the watch officer, up to the captain.
+pub fn responsible_officer(ctx: &ReducerContext, department: u8, hour: f32) -> Option<u64> {
+    let (holders, posts): (Vec<u64>, Vec<CommandPost>) = ctx
+        .db
+        .command_chain()
+        .iter()
+        .filter(|c| {
+            ctx.db
+                .person()
+                .id()
+                .find(c.person_id)
+                .is_some_and(|p| p.is_alive)
+        })
+        .filter_map(|c| {
+            let crew = ctx.db.crew().person_id().find(c.person_id)?;
+            let post = CommandPost {
+                post: c.post,
+                department: c.department,
+                shift: crew.shift,
+                rank: crew.rank,
+                superior: None,
+            };
+            Some((c.person_id, post))
+        })
+        .unzip();
+    escalation_order(&posts, department, hour)
+        .first()
+        .map(|&i| holders[i])
+}
@@ -0,0 +1,175 @@
+//! Economy system - wages for the crew on duty, the shops and the credits
+//! every purchase aboard is paid in.
+
+use crate::tables::*;
+use progship_logic::economy::{
+    compute_levels, hourly_wage, shop_purchase, starting_credits, RATION_FOOD, SHOPPING_COMFORT,
+    TOOLKIT_PARTS,
+};
+use spacetimedb::{ReducerContext, Table};
+
+use super::inventory::{add_item, items_of};
+use super::ship_systems::resource_values;
+
+/// Hourly: crew on duty are paid their wage, and those unwinding off duty
+/// in a powered shop buy what they are missing while they can afford it
+/// and the stores have it.
+pub fn tick_economy(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    if sim_time.floor() == (sim_time - delta_hours).floor() {
+        return;
+    }
+    pay_wages(ctx);
+    run_shops(ctx, sim_time);
+}
+
+/// Take `amount` credits from `person_id`; false, and nothing taken, if
+/// they cannot pay.
+pub fn charge(ctx: &ReducerContext, person_id: u64, amount: u32) -> bool {
+    let mut wallet = wallet(ctx, person_id);
+    if wallet.credits < amount {
+        return false;
+    }
+    wallet.credits -= amount;
+    wallet.spent = wallet.spent.saturating_add(amount);
+    ctx.db.wallet().person_id().update(wallet);
+    true
+}
+
+/// `person_id`'s wallet, opened with their savings if they have none yet.
+fn wallet(ctx: &ReducerContext, person_id: u64) -> Wallet {
+    ctx.db
+        .wallet()
+        .person_id()
+        .find(person_id)
+        .unwrap_or_else(|| {
+            let is_crew = ctx.db.crew().person_id().find(person_id).is_some();
+            ctx.db.wallet().insert(Wallet {
+                person_id,
+                credits: starting_credits(is_crew),
+                earned: 0,
+                spent: 0,
+            })
+        })
+}
+
+/// Pay every living crew member on duty an hour's wage for their rank.
+fn pay_wages(ctx: &ReducerContext) {
+    let paid: Vec<(u64, u32)> = ctx
+        .db
+        .crew()
+        .iter()
+        .filter(|c| c.on_duty)
+        .filter(|c| {
+            ctx.db
+                .person()
+                .id()
+                .find(c.person_id)
+                .is_some_and(|p| p.is_alive)
+        })
+        .map(|c| (c.person_id, hourly_wage(c.rank)))
+        .collect();
+    for (person_id, wage) in paid {
+        let mut wallet = wallet(ctx, person_id);
+        wallet.credits = wallet.credits.saturating_add(wage);
+        wallet.earned = wallet.earned.saturating_add(wage);
+        ctx.db.wallet().person_id().update(wallet);
+    }
+}
+
+/// Sell everyone socializing or relaxing off duty in a powered shop the
+/// first thing they are missing and can afford, while the stores last.
+fn run_shops(ctx: &ReducerContext, sim_time: f64) {
+    let Some(mut resources) = ctx.db.ship_resources().id().find(0) else {
+        return;
+    };
+    let shops: Vec<u32> = ctx
+        .db
+        .room()
+        .iter()
+        .filter(|r| r.room_type == room_types::SHOPS)
+        .filter(|r| {
+            ctx.db
+                .room_power()
+                .room_id()
+                .find(r.id)
+                .is_none_or(|p| p.powered)
+        })
+        .map(|r| r.id)
+        .collect();
+    let browsing =
+        |person_id: u64| {
+            let adult =
+                ctx.db.person().id().find(person_id).is_some_and(|p| {
+                    p.is_alive && !p.is_player && p.life_stage >= life_stages::ADULT
+                });
+            let unwinding = ctx
+                .db
+                .activity()
+                .person_id()
+                .find(person_id)
+                .is_some_and(|a| {
+                    matches!(
+                        a.activity_type,
+                        activity_types::SOCIALIZING | activity_types::RELAXING
+                    )
+                });
+            let on_duty = ctx
+                .db
+                .crew()
+                .person_id()
+                .find(person_id)
+                .is_some_and(|c| c.on_duty);
+            adult && unwinding && !on_duty
+        };
+    let shoppers: Vec<u64> = ctx
+        .db
+        .position()
+        .iter()
+        .filter(|p| shops.contains(&p.room_id) && browsing(p.person_id))
+        .map(|p| p.person_id)
+        .collect();
+
+    let mut sold = false;
+    for person_id in shoppers {
+        let levels = compute_levels(&resource_values(&resources));
+        let department = ctx
+            .db
+            .crew()
+            .person_id()
+            .find(person_id)
+            .map(|c| c.department);
+        let carried: Vec<u8> = items_of(ctx, person_id).iter().map(|i| i.kind).collect();
+        let credits = wallet(ctx, person_id).credits;
+        let Some((kind, price)) = shop_purchase(department, &carried, credits, &levels) else {
+            continue;
+        };
+        let in_stock = match kind {
+            item_kinds::RATION => resources.food >= RATION_FOOD,
+            item_kinds::TOOLKIT => resources.spare_parts >= TOOLKIT_PARTS,
+            _ => true,
+        };
+        if !in_stock || !add_item(ctx, person_id, kind, sim_time) {
+            continue;
+        }
+        charge(ctx, person_id, price);
+        match kind {
+            item_kinds::RATION => resources.food -= RATION_FOOD,
+            item_kinds::TOOLKIT => resources.spare_parts -= TOOLKIT_PARTS,
+            _ => {}
+        }
+        if let Some(mut needs) = ctx.db.needs().person_id().find(person_id) {
+            needs.comfort = (needs.comfort - SHOPPING_COMFORT).max(0.0);
+            ctx.db.needs().person_id().update(needs);
+        }
+        log::info!(
+            "Person {} bought a {} for {} credits",
+            person_id,
+            progship_logic::constants::item_kinds::name(kind),
+            price
+        );
+        sold = true;
+    }
+    if sold {
+        ctx.db.ship_resources().id().update(resources);
+    }
+}
@@ -0,0 +1,220 @@
+//! Education system - children in the nursery and school, the teachers
+//! who staff them and lessons that build up a child's skills.
+
+use std::collections::HashMap;
+
+use crate::tables::*;
+use progship_logic::education::{
+    classroom_for, is_school_time, lesson_gain, teacher_fitness, teachers_needed,
+};
+use progship_logic::utility::UtilityInput;
+use spacetimedb::{ReducerContext, Table};
+
+use super::quarantine::is_quarantined;
+use super::training::{set_skill_levels, skill_levels};
+
+/// Hourly: keep the nursery and school staffed with enough teachers for
+/// their pupils, and over school hours count the time each child spends
+/// in class and teach those in the school.
+pub fn tick_education(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    let hours = (sim_time.floor() - (sim_time - delta_hours).floor()) as f32;
+    if hours <= 0.0 {
+        return;
+    }
+    let classrooms: Vec<Room> = ctx
+        .db
+        .room()
+        .iter()
+        .filter(|r| matches!(r.room_type, room_types::NURSERY | room_types::SCHOOL))
+        .collect();
+    if classrooms.is_empty() {
+        return;
+    }
+    staff_classrooms(ctx, &classrooms, sim_time);
+    // The hour just gone is the one being counted
+    if is_school_time((sim_time.floor() - 1.0).rem_euclid(24.0) as f32) {
+        attend_class(ctx, &classrooms, hours);
+    }
+}
+
+/// What a teacher does in school hours: teach in their classroom, if fit
+/// to.
+pub fn teaching_activity(
+    ctx: &ReducerContext,
+    person_id: u64,
+    input: &UtilityInput,
+) -> Option<(u8, f32, Option<u32>)> {
+    if !is_school_time(input.hour) || !input.fit_for_duty {
+        return None;
+    }
+    let teacher = ctx.db.teacher().person_id().find(person_id)?;
+    Some((activity_types::ON_DUTY, 1.0, Some(teacher.room_id)))
+}
+
+/// Whether `person_id` is assigned to teach, which takes crew off their
+/// watch.
+pub fn is_teacher(ctx: &ReducerContext, person_id: u64) -> bool {
+    ctx.db.teacher().person_id().find(person_id).is_some()
+}
+
+/// Stand down teachers who died, lost their classroom or are no longer
+/// needed, and assign the best suited adults to fill any shortfall.
+fn staff_classrooms(ctx: &ReducerContext, classrooms: &[Room], sim_time: f64) {
+    for teacher in ctx.db.teacher().iter() {
+        let alive = ctx
+            .db
+            .person()
+            .id()
+            .find(teacher.person_id)
+            .is_some_and(|p| p.is_alive);
+        if !alive || !classrooms.iter().any(|r| r.id == teacher.room_id) {
+            ctx.db.teacher().person_id().delete(teacher.person_id);
+        }
+    }
+
+    let living: Vec<Person> = ctx.db.person().iter().filter(|p| p.is_alive).collect();
+    let mut candidates: Option<Vec<(f32, u64)>> = None;
+    for room_type in [room_types::NURSERY, room_types::SCHOOL] {
+        let rooms: Vec<&Room> = classrooms
+            .iter()
+            .filter(|r| r.room_type == room_type)
+            .collect();
+        if rooms.is_empty() {
+            continue;
+        }
+        let pupils = living
+            .iter()
+            .filter(|p| classroom_for(p.life_stage) == Some(room_type))
+            .count() as u32;
+        let needed = teachers_needed(pupils) as usize;
+        let mut staff: Vec<Teacher> = ctx
+            .db
+            .teacher()
+            .iter()
+            .filter(|t| rooms.iter().any(|r| r.id == t.room_id))
+            .collect();
+        staff.sort_by(|a, b| {
+            a.assigned_at
+                .total_cmp(&b.assigned_at)
+                .then(a.person_id.cmp(&b.person_id))
+        });
+        for surplus in staff.iter().skip(needed) {
+            ctx.db.teacher().person_id().delete(surplus.person_id);
+        }
+
+        for slot in staff.len()..needed {
+            let pool = candidates.get_or_insert_with(|| teacher_candidates(ctx, &living));
+            let Some((_, person_id)) = pool.pop() else {
+                break;
+            };
+            let Some(room) = rooms.get(slot % rooms.len()) else {
+                break;
+            };
+            ctx.db.teacher().insert(Teacher {
+                person_id,
+                room_id: room.id,
+                assigned_at: sim_time,
+            });
+            if let Some(person) = living.iter().find(|p| p.id == person_id) {
+                log::info!(
+                    "{} {} now teaches in {}",
+                    person.given_name,
+                    person.family_name,
+                    room.name
+                );
+            }
+        }
+    }
+}
+
+/// Adults free to teach who have the skill for it, best suited last.
+fn teacher_candidates(ctx: &ReducerContext, living: &[Person]) -> Vec<(f32, u64)> {
+    let mut candidates: Vec<(f32, u64)> = living
+        .iter()
+        .filter(|p| !p.is_player && p.life_stage >= life_stages::ADULT)
+        .filter(|p| !is_teacher(ctx, p.id))
+        .filter(|p| ctx.db.command_chain().person_id().find(p.id).is_none())
+        .filter(|p| ctx.db.detention().person_id().find(p.id).is_none())
+        .filter(|p| !is_quarantined(ctx, p.id))
+        .filter_map(|p| {
+            let social = ctx.db.skills().person_id().find(p.id)?.social;
+            let by_profession = ctx
+                .db
+                .passenger()
+                .person_id()
+                .find(p.id)
+                .is_some_and(|pass| pass.profession == "Teacher");
+            let fitness = teacher_fitness(social, by_profession, p.is_crew)?;
+            Some((fitness, p.id))
+        })
+        .collect();
+    candidates.sort_by(|a, b| a.0.total_cmp(&b.0).then(b.1.cmp(&a.1)));
+    candidates
+}
+
+/// Count `hours` in class for every child in their classroom, teaching
+/// those in the school, best with a teacher in the room.
+fn attend_class(ctx: &ReducerContext, classrooms: &[Room], hours: f32) {
+    // The most socially skilled teacher present in each classroom
+    let mut teaching: HashMap<u32, f32> = HashMap::new();
+    for teacher in ctx.db.teacher().iter() {
+        let present = ctx
+            .db
+            .position()
+            .person_id()
+            .find(teacher.person_id)
+            .is_some_and(|p| p.room_id == teacher.room_id);
+        let Some(skills) = ctx.db.skills().person_id().find(teacher.person_id) else {
+            continue;
+        };
+        if present {
+            let best = teaching.entry(teacher.room_id).or_insert(skills.social);
+            *best = best.max(skills.social);
+        }
+    }
+
+    for pos in ctx.db.position().iter() {
+        let Some(room) = classrooms.iter().find(|r| r.id == pos.room_id) else {
+            continue;
+        };
+        let pupil = ctx
+            .db
+            .person()
+            .id()
+            .find(pos.person_id)
+            .is_some_and(|p| p.is_alive && classroom_for(p.life_stage) == Some(room.room_type));
+        let in_class = ctx
+            .db
+            .activity()
+            .person_id()
+            .find(pos.person_id)
+            .is_some_and(|a| a.activity_type == activity_types::WORKING);
+        if !pupil || !in_class {
+            continue;
+        }
+        let teacher_social = teaching.get(&room.id).copied();
+        let taught = if teacher_social.is_some() { hours } else { 0.0 };
+        match ctx.db.schooling().person_id().find(pos.person_id) {
+            Some(mut schooling) => {
+                schooling.hours += hours;
+                schooling.taught_hours += taught;
+                ctx.db.schooling().person_id().update(schooling);
+            }
+            None => {
+                ctx.db.schooling().insert(Schooling {
+                    person_id: pos.person_id,
+                    hours,
+                    taught_hours: taught,
+                });
+            }
+        }
+        if room.room_type != room_types::SCHOOL {
+            continue;
+        }
+        if let Some(mut skills) = ctx.db.skills().person_id().find(pos.person_id) {
+            let levels = lesson_gain(&skill_levels(&skills), teacher_social, hours);
+            set_skill_levels(&mut skills, &levels);
+            ctx.db.skills().person_id().update(skills);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//! Emotion system - periodic moods from needs, grief and nearby events.
+
+use crate::tables::*;
+use progship_logic::emotions::{
+    compute_emotion, grief_hours, EmotionInput, EMOTION_INTERVAL_HOURS,
+};
+use spacetimedb::{ReducerContext, Table};
+
+/// Recompute everyone's emotion once per emotion interval.
+pub fn tick_emotions(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    let interval = |t: f64| (t / EMOTION_INTERVAL_HOURS).floor();
+    if interval(sim_time) == interval(sim_time - delta_hours) {
+        return;
+    }
+
+    // Decks with a celebration or discovery under way
+    let good_news_decks: Vec<i32> = ctx
+        .db
+        .event()
+        .iter()
+        .filter(|e| {
+            e.state != event_states::RESOLVED
+                && (e.event_type == event_types::CELEBRATION
+                    || e.event_type == event_types::DISCOVERY)
+        })
+        .filter_map(|e| ctx.db.room().id().find(e.room_id).map(|r| r.deck))
+        .collect();
+
+    for needs in ctx.db.needs().iter() {
+        let alive = ctx
+            .db
+            .person()
+            .id()
+            .find(needs.person_id)
+            .is_some_and(|p| p.is_alive);
+        if !alive {
+            continue;
+        }
+        let existing = ctx.db.emotion().person_id().find(needs.person_id);
+        let grieving_until = existing.as_ref().map(|e| e.grieving_until).unwrap_or(0.0);
+        let good_news = ctx
+            .db
+            .position()
+            .person_id()
+            .find(needs.person_id)
+            .and_then(|pos| ctx.db.room().id().find(pos.room_id))
+            .is_some_and(|room| good_news_decks.contains(&room.deck));
+
+        let (emotion, intensity) = compute_emotion(&EmotionInput {
+            hunger: needs.hunger,
+            fatigue: needs.fatigue,
+            comfort: needs.comfort,
+            hygiene: needs.hygiene,
+            health: needs.health,
+            morale: needs.morale,
+            grieving: sim_time < grieving_until,
+            good_news,
+        });
+
+        match existing {
+            // Only replicate real changes
+            Some(e) if e.emotion == emotion && (e.intensity - intensity).abs() < 0.05 => {}
+            Some(mut e) => {
+                e.emotion = emotion;
+                e.intensity = intensity;
+                ctx.db.emotion().person_id().update(e);
+            }
+            None => {
+                ctx.db.emotion().insert(Emotion {
+                    person_id: needs.person_id,
+                    emotion,
+                    intensity,
+                    grieving_until,
+                });
+            }
+        }
+    }
+}
+
+/// Start mourning `deceased` among the people close to them and anyone who
+/// saw them die in `room_id`.
+pub fn start_mourning(ctx: &ReducerContext, deceased: u64, room_id: u32, sim_time: f64) {
+    let bonds: Vec<(u64, u8)> = ctx
+        .db
+        .relationship()
+        .iter()
+        .filter_map(|r| {
+            if r.person_a == deceased {
+                Some((r.person_b, r.relationship_type))
+            } else if r.person_b == deceased {
+                Some((r.person_a, r.relationship_type))
+            } else {
+                None
+            }
+        })
+        .collect();
+    for pos in ctx.db.position().iter() {
+        if pos.person_id == deceased {
+            continue;
+        }
+        let bond = bonds
+            .iter()
+            .find(|(other, _)| *other == pos.person_id)
+            .map(|&(_, relationship_type)| relationship_type);
+        let hours = grief_hours(bond, pos.room_id == room_id);
+        if hours <= 0.0 {
+            continue;
+        }
+        let until = sim_time + hours;
+        match ctx.db.emotion().person_id().find(pos.person_id) {
+            Some(mut e) => {
+                if until > e.grieving_until {
+                    e.grieving_until = until;
+                    ctx.db.emotion().person_id().update(e);
+                }
+            }
+            None => {
+                ctx.db.emotion().insert(Emotion {
+                    person_id: pos.person_id,
+                    emotion: emotions::CONTENT,
+                    intensity: 0.5,
+                    grieving_until: until,
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,370 @@
+//! EVA system - crew suited up outside the hull for the repairs only it
+//! can reach, their suits' oxygen, the accidents that befall them, and the
+//! air damaged plating leaks until it is patched.
+
+use std::collections::HashSet;
+
+use crate::tables::*;
+use progship_logic::eva::{
+    accident_chance, hull_distance, hull_leak, must_turn_back, nearest_hatch, traverse_hours,
+    ACCIDENT_HEALTH, ACCIDENT_SALT, CYCLE_HOURS, MOUNT_REACH, NO_O2_HEALTH, SUIT_O2_HOURS,
+};
+use progship_logic::numeric::roll;
+use spacetimedb::{ReducerContext, Table};
+
+use super::activities::restart_activity;
+use super::maintenance::skill_level;
+use super::radiation::storm_flare;
+
+/// What a crew member on an EVA work order got done this tick.
+pub enum EvaStep {
+    /// Hours of work on the job (none while still on the way out).
+    Work(f32),
+    /// They had to turn back; the job goes back on the board.
+    Abort,
+}
+
+/// Whether subsystems of `subsystem_type` are mounted outside the hull.
+pub fn is_external(subsystem_type: u8) -> bool {
+    matches!(
+        subsystem_type,
+        subsystem_types::RADIATOR_PANEL | subsystem_types::ANTENNA_ARRAY
+    )
+}
+
+/// Crew suited up for an EVA, who are busy with it until back inside.
+pub fn crew_on_eva(ctx: &ReducerContext) -> HashSet<u64> {
+    ctx.db.eva_mission().iter().map(|m| m.person_id).collect()
+}
+
+/// Whether `person_id` is out on the hull, past the airlock.
+pub fn is_outside(ctx: &ReducerContext, person_id: u64) -> bool {
+    ctx.db
+        .eva_mission()
+        .person_id()
+        .find(person_id)
+        .is_some_and(|m| m.phase != eva_phases::CYCLING_OUT)
+}
+
+/// Record damage of `severity` to the hull on `deck` at `(x, y)`.
+pub fn damage_hull(ctx: &ReducerContext, deck: i32, x: f32, y: f32, severity: f32, sim_time: f64) {
+    ctx.db.hull_damage().insert(HullDamage {
+        id: 0,
+        deck,
+        x,
+        y,
+        severity: severity.clamp(0.0, 1.0),
+        damaged_at: sim_time,
+    });
+    log::info!(
+        "Hull damaged on deck {} ({:.0}%)",
+        deck + 1,
+        severity * 100.0
+    );
+}
+
+/// Where the work of an EVA order is, as `(deck, x, y)`: the hull damage
+/// it is for, or the room its subsystem is mounted outside of.
+pub fn eva_site(ctx: &ReducerContext, order: &WorkOrder) -> Option<(i32, f32, f32)> {
+    if let Some(damage_id) = order.hull_damage_id {
+        return ctx
+            .db
+            .hull_damage()
+            .id()
+            .find(damage_id)
+            .map(|d| (d.deck, d.x, d.y));
+    }
+    let sub = ctx.db.subsystem().id().find(order.subsystem_id)?;
+    ctx.db
+        .room()
+        .iter()
+        .find(|r| r.node_id == sub.node_id)
+        .map(|r| (r.deck, r.x, r.y))
+}
+
+/// The airlock outer door or EVA hatch nearest `site`.
+pub fn exit_hatch(ctx: &ReducerContext, site: (i32, f32, f32)) -> Option<HullHatch> {
+    let hatches: Vec<(HullHatch, (i32, f32, f32))> = ctx
+        .db
+        .hull_hatch()
+        .iter()
+        .filter(|h| matches!(h.hatch_type, hatch_types::AIRLOCK | hatch_types::EVA))
+        .filter_map(|h| {
+            let deck = ctx.db.room().id().find(h.room_id)?.deck;
+            let point = (deck, h.x, h.y);
+            Some((h, point))
+        })
+        .collect();
+    let points: Vec<(i32, f32, f32)> = hatches.iter().map(|h| h.1).collect();
+    let index = nearest_hatch(&points, site)?;
+    hatches.into_iter().nth(index).map(|h| h.0)
+}
+
+/// Take an EVA order's crew member, on site at its airlock, through their
+/// trip for the tick: suit up and cycle out, cross the hull and work,
+/// turning back when the suit runs low, a flare is sighted or they are
+/// hurt in an accident.
+pub fn step_outside(
+    ctx: &ReducerContext,
+    order: &WorkOrder,
+    crew_id: u64,
+    sim_time: f64,
+    delta_hours: f32,
+) -> EvaStep {
+    let mut mission = match ctx.db.eva_mission().person_id().find(crew_id) {
+        Some(mission) if mission.work_order_id == order.id => mission,
+        Some(_) => return EvaStep::Abort,
+        None => match suit_up(ctx, order, crew_id, sim_time) {
+            Some(mission) => mission,
+            None => return EvaStep::Abort,
+        },
+    };
+    if mission.phase == eva_phases::RETURNING {
+        return EvaStep::Abort;
+    }
+    mission.oxygen_hours -= delta_hours;
+
+    let skill = ctx
+        .db
+        .skills()
+        .person_id()
+        .find(crew_id)
+        .map_or(0.0, |s| skill_level(&s, order.required_skill));
+    let outside = mission.phase != eva_phases::CYCLING_OUT;
+    if outside && roll(crew_id, sim_time, ACCIDENT_SALT) < accident_chance(skill, delta_hours) {
+        injure(ctx, &mission, sim_time);
+        turn_back(ctx, mission, sim_time);
+        return EvaStep::Abort;
+    }
+    if storm_flare(ctx).is_some() {
+        log::info!(
+            "Person {} heads back inside ahead of a solar flare",
+            crew_id
+        );
+        turn_back(ctx, mission, sim_time);
+        return EvaStep::Abort;
+    }
+
+    let step = match mission.phase {
+        eva_phases::CYCLING_OUT | eva_phases::OUTBOUND => {
+            mission.phase_hours -= delta_hours;
+            if mission.phase_hours <= 0.0 {
+                if mission.phase == eva_phases::CYCLING_OUT {
+                    mission.phase = eva_phases::OUTBOUND;
+                    mission.phase_hours = mission.traverse_hours;
+                    set_hatch(ctx, mission.hatch_id, false);
+                } else {
+                    mission.phase = eva_phases::WORKING;
+                    mission.phase_hours = 0.0;
+                }
+            }
+            EvaStep::Work(0.0)
+        }
+        _ => {
+            if must_turn_back(mission.oxygen_hours, mission.traverse_hours + CYCLE_HOURS) {
+                log::info!("Person {} heads back inside low on oxygen", crew_id);
+                turn_back(ctx, mission, sim_time);
+                return EvaStep::Abort;
+            }
+            EvaStep::Work(delta_hours)
+        }
+    };
+    ctx.db.eva_mission().person_id().update(mission);
+    step
+}
+
+/// Patch the hull damage `damage_id`: mended with the seals for it, or
+/// half as bad when patched without.
+pub fn patch_hull(ctx: &ReducerContext, damage_id: u64, with_parts: bool) {
+    let Some(mut damage) = ctx.db.hull_damage().id().find(damage_id) else {
+        return;
+    };
+    if with_parts {
+        ctx.db.hull_damage().id().delete(damage_id);
+    } else {
+        damage.severity *= 0.5;
+        ctx.db.hull_damage().id().update(damage);
+    }
+}
+
+/// Bring back everyone outside whose job is over or was taken from them,
+/// end the trips of those who made it back inside, and leak the ship's
+/// oxygen through unpatched hull damage.
+pub fn tick_eva(ctx: &ReducerContext, sim_time: f64, delta_hours: f32) {
+    let missions: Vec<EvaMission> = ctx.db.eva_mission().iter().collect();
+    for mut mission in missions {
+        let person_id = mission.person_id;
+        let alive = ctx
+            .db
+            .person()
+            .id()
+            .find(person_id)
+            .is_some_and(|p| p.is_alive);
+        let hatch_room = ctx
+            .db
+            .hull_hatch()
+            .id()
+            .find(mission.hatch_id)
+            .map(|h| h.room_id);
+        let at_hatch = ctx
+            .db
+            .position()
+            .person_id()
+            .find(person_id)
+            .is_some_and(|p| Some(p.room_id) == hatch_room);
+        if !alive || !at_hatch {
+            set_hatch(ctx, mission.hatch_id, false);
+            ctx.db.eva_mission().person_id().delete(person_id);
+            continue;
+        }
+        if mission.phase != eva_phases::RETURNING {
+            let on_job = ctx
+                .db
+                .work_order()
+                .id()
+                .find(mission.work_order_id)
+                .is_some_and(|o| {
+                    o.state == work_order_states::CLAIMED && o.assigned_crew_id == Some(person_id)
+                });
+            if !on_job {
+                turn_back(ctx, mission, sim_time);
+            }
+            continue;
+        }
+
+        mission.oxygen_hours -= delta_hours;
+        if mission.oxygen_hours < 0.0 {
+            if let Some(mut needs) = ctx.db.needs().person_id().find(person_id) {
+                needs.health = (needs.health - NO_O2_HEALTH * delta_hours).max(0.0);
+                ctx.db.needs().person_id().update(needs);
+            }
+        }
+        mission.phase_hours -= delta_hours;
+        if mission.phase_hours <= 0.0 {
+            set_hatch(ctx, mission.hatch_id, false);
+            ctx.db.eva_mission().person_id().delete(person_id);
+            log::info!("Person {} is back inside from EVA", person_id);
+            continue;
+        }
+        set_hatch(ctx, mission.hatch_id, mission.phase_hours <= CYCLE_HOURS);
+        ctx.db.eva_mission().person_id().update(mission);
+    }
+
+    let severity: f32 = ctx.db.hull_damage().iter().map(|d| d.severity).sum();
+    if severity <= 0.0 {
+        return;
+    }
+    if let Some(mut resources) = ctx.db.ship_resources().id().find(0) {
+        let leak = hull_leak(resources.oxygen_cap, severity, delta_hours);
+        resources.oxygen = (resources.oxygen - leak).max(0.0);
+        ctx.db.ship_resources().id().update(resources);
+    }
+}
+
+/// Suit `crew_id` up at the hatch nearest an EVA order's site and start
+/// cycling out. `None` if there is no site or no hatch to go out by.
+fn suit_up(
+    ctx: &ReducerContext,
+    order: &WorkOrder,
+    crew_id: u64,
+    sim_time: f64,
+) -> Option<EvaMission> {
+    let site = eva_site(ctx, order)?;
+    let hatch = exit_hatch(ctx, site)?;
+    let deck = ctx.db.room().id().find(hatch.room_id)?.deck;
+    let reach = if order.hull_damage_id.is_some() {
+        0.0
+    } else {
+        MOUNT_REACH
+    };
+    let distance = hull_distance((deck, hatch.x, hatch.y), site) + reach;
+    set_hatch(ctx, hatch.id, true);
+    log::info!(
+        "Person {} suits up for EVA: {} ({:.0} m out)",
+        crew_id,
+        order.reason,
+        distance
+    );
+    Some(ctx.db.eva_mission().insert(EvaMission {
+        person_id: crew_id,
+        work_order_id: order.id,
+        hatch_id: hatch.id,
+        phase: eva_phases::CYCLING_OUT,
+        phase_hours: CYCLE_HOURS,
+        oxygen_hours: SUIT_O2_HOURS,
+        traverse_hours: traverse_hours(distance),
+        started_at: sim_time,
+    }))
+}
+
+/// Send `mission`'s crew member back inside from wherever they have got
+/// to, holding them at the airlock until they are through it.
+fn turn_back(ctx: &ReducerContext, mut mission: EvaMission, sim_time: f64) {
+    let hours = match mission.phase {
+        eva_phases::CYCLING_OUT => (CYCLE_HOURS - mission.phase_hours).max(0.0),
+        eva_phases::OUTBOUND => {
+            (mission.traverse_hours - mission.phase_hours).max(0.0) + CYCLE_HOURS
+        }
+        _ => mission.traverse_hours + CYCLE_HOURS,
+    };
+    mission.phase = eva_phases::RETURNING;
+    mission.phase_hours = hours;
+    if let Some(mut activity) = ctx.db.activity().person_id().find(mission.person_id) {
+        restart_activity(&mut activity, activity_types::MAINTENANCE, sim_time, hours);
+        activity.target_room_id = ctx
+            .db
+            .hull_hatch()
+            .id()
+            .find(mission.hatch_id)
+            .map(|h| h.room_id);
+        ctx.db.activity().person_id().update(activity);
+    }
+    ctx.db.eva_mission().person_id().update(mission);
+}
+
+/// An accident outside: the crew member is hurt, and medics are called to
+/// the airlock to meet them.
+fn injure(ctx: &ReducerContext, mission: &EvaMission, sim_time: f64) {
+    if let Some(mut needs) = ctx.db.needs().person_id().find(mission.person_id) {
+        needs.health = (needs.health - ACCIDENT_HEALTH).max(0.0);
+        ctx.db.needs().person_id().update(needs);
+    }
+    log::warn!("Person {} was hurt in an EVA accident", mission.person_id);
+    let Some(hatch) = ctx.db.hull_hatch().id().find(mission.hatch_id) else {
+        return;
+    };
+    ctx.db.event().insert(Event {
+        id: 0,
+        event_type: event_types::MEDICAL_EMERGENCY,
+        room_id: hatch.room_id,
+        started_at: sim_time,
+        duration: 2.0,
+        state: event_states::ACTIVE,
+        responders_needed: 1,
+        responders_assigned: 0,
+        severity: 0.5,
+        escalated_to: None,
+    });
+}
+
+/// Open or close `hatch_id`, writing only on a change.
+fn set_hatch(ctx: &ReducerContext, hatch_id: u64, open: bool) {
+    if let Some(mut hatch) = ctx.db.hull_hatch().id().find(hatch_id) {
+        if hatch.is_open != open {
+            hatch.is_open = open;
+            ctx.db.hull_hatch().id().update(hatch);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_external_subsystems() {
+        assert!(is_external(subsystem_types::RADIATOR_PANEL));
+        assert!(is_external(subsystem_types::ANTENNA_ARRAY));
+        assert!(!is_external(subsystem_types::COOLANT_PUMP));
+    }
+}
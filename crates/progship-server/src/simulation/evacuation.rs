@@ -0,0 +1,137 @@
+//! Evacuation system - precomputed routes out of danger to muster stations.
+//!
+//! Every room's step toward the nearest safe muster station is cached in the
+//! evacuation_route table and rebuilt only when doors or hazards change, so
+//! clearing a deck follows stored routes instead of searching per person.
+
+use crate::tables::*;
+use progship_logic::doors::is_passable;
+use progship_logic::evacuation::{hazard_scope, is_muster_station, HazardScope};
+use progship_logic::pathfinding::{room_weight, DoorEdge, FlowField, FlowStep, NavGraph};
+use spacetimedb::{ReducerContext, Table};
+use std::collections::{HashMap, HashSet};
+
+use super::movement::start_movement_along;
+
+/// Rooms made dangerous by active fires and hull breaches.
+pub fn hazard_rooms(ctx: &ReducerContext) -> HashSet<u32> {
+    let mut hazards = HashSet::new();
+    for event in ctx.db.event().iter() {
+        if event.state == event_states::RESOLVED {
+            continue;
+        }
+        match hazard_scope(event.event_type) {
+            Some(HazardScope::Room) => {
+                hazards.insert(event.room_id);
+            }
+            Some(HazardScope::Deck) => {
+                if let Some(room) = ctx.db.room().id().find(event.room_id) {
+                    hazards.extend(
+                        ctx.db
+                            .room()
+                            .iter()
+                            .filter(|r| r.deck == room.deck)
+                            .map(|r| r.id),
+                    );
+                }
+            }
+            None => {}
+        }
+    }
+    hazards
+}
+
+/// Rebuild the evacuation_route table from the open doors and the
+/// current hazards. Crew-only doors count: they release for evacuations.
+pub fn refresh_evacuation_routes(ctx: &ReducerContext) {
+    let hazards = hazard_rooms(ctx);
+    let edges: Vec<DoorEdge> = ctx
+        .db
+        .door()
+        .iter()
+        .filter(|d| is_passable(d.state))
+        .map(|d| DoorEdge {
+            room_a: d.room_a,
+            room_b: d.room_b,
+            door_x: d.door_x,
+            door_y: d.door_y,
+        })
+        .collect();
+    let mut graph = NavGraph::from_doors(&edges);
+    let mut stations = Vec::new();
+    let mut weights = HashMap::new();
+    for room in ctx.db.room().iter() {
+        if is_muster_station(room.room_type) && !hazards.contains(&room.id) {
+            stations.push(room.id);
+        }
+        weights.insert(
+            room.id,
+            room_weight(room.room_type, room.width, room.height, 0),
+        );
+    }
+    graph.set_room_weights(weights);
+    let field = graph.flow_field(&stations, &hazards);
+
+    let stale: Vec<u32> = ctx
+        .db
+        .evacuation_route()
+        .iter()
+        .map(|r| r.room_id)
+        .collect();
+    for room_id in stale {
+        ctx.db.evacuation_route().room_id().delete(room_id);
+    }
+    for (room_id, step) in field.steps() {
+        ctx.db.evacuation_route().insert(EvacuationRoute {
+            room_id,
+            next_room: step.next_room,
+            door_x: step.door_x,
+            door_y: step.door_y,
+            muster_room: step.goal,
+            seconds: step.seconds,
+        });
+    }
+    log::info!(
+        "Evacuation routes rebuilt: {} rooms to {} muster stations, {} hazard rooms",
+        field.steps().count(),
+        stations.len(),
+        hazards.len()
+    );
+}
+
+/// Send everyone in `rooms` along the cached routes to their muster
+/// stations. Returns how many people were moved.
+pub fn evacuate(ctx: &ReducerContext, rooms: &HashSet<u32>) -> u32 {
+    let field = FlowField::from_steps(ctx.db.evacuation_route().iter().map(|r| {
+        (
+            r.room_id,
+            FlowStep {
+                next_room: r.next_room,
+                door_x: r.door_x,
+                door_y: r.door_y,
+                goal: r.muster_room,
+                seconds: r.seconds,
+            },
+        )
+    }));
+    let evacuees: Vec<Position> = ctx
+        .db
+        .position()
+        .iter()
+        .filter(|p| rooms.contains(&p.room_id))
+        .collect();
+    let mut moved = 0;
+    for pos in evacuees {
+        let Some(step) = field.step(pos.room_id) else {
+            continue; // no way out
+        };
+        let Some(muster) = ctx.db.room().id().find(step.goal) else {
+            continue;
+        };
+        let path = field.path_from(pos.room_id);
+        ctx.db.movement().person_id().delete(pos.person_id);
+        start_movement_along(ctx, pos.person_id, &path, muster.id, muster.x, muster.y);
+        moved += 1;
+    }
+    moved
+}
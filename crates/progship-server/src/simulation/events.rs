@@ -1,17 +1,34 @@
 //! Event system - random ship events with real consequences.
 
 use crate::tables::*;
+use progship_logic::command;
+use progship_logic::doors::escalation_door_state;
 use spacetimedb::{ReducerContext, Table};
 
+use super::duty::responsible_officer;
+use super::eva::damage_hull;
+use super::evacuation::{evacuate, hazard_rooms, refresh_evacuation_routes};
+use super::factions::faction_event_frequency;
+use super::features::feature_flags;
+use super::hazards::roll_micrometeorites;
+use super::inventory::lose_belongings;
+use super::morale::striking_department;
+use super::radiation::roll_flares;
+use progship_logic::evacuation::hazard_scope;
+use progship_logic::milestones::GATHERING_MORALE;
+use progship_logic::morale::{RALLY_MORALE, STRIKE_MORALE};
+use progship_logic::scenarios::event_chance_per_mille;
+
 /// Generate random events and progress existing ones with real consequences.
 pub fn tick_events(ctx: &ReducerContext, sim_time: f64, delta_hours: f32) {
     // Progress existing events and apply consequences
     let events: Vec<Event> = ctx.db.event().iter().collect();
     let mut active_count = 0u32;
+    let mut hazards_ended = false;
+    let mut doors_changed = false;
     for event in events {
         if event.state == event_states::RESOLVED {
-            // Clean up resolved events immediately
-            ctx.db.event().id().delete(event.id);
+            // Kept until compacted into daily history
             continue;
         }
 
@@ -19,28 +36,82 @@ pub fn tick_events(ctx: &ReducerContext, sim_time: f64, delta_hours: f32) {
         let elapsed = sim_time - event.started_at;
         let mut e = event.clone();
 
+        // Solar flares, funerals, rallies, strikes, promotions and voyage
+        // milestone gatherings pass on their own and mutinies end in
+        // tick_mutiny; a flare's dose is taken in tick_needs
+        if matches!(
+            e.event_type,
+            event_types::SOLAR_FLARE
+                | event_types::FUNERAL
+                | event_types::RALLY
+                | event_types::STRIKE
+                | event_types::MUTINY
+                | event_types::PROMOTION
+                | event_types::VOYAGE_MILESTONE
+        ) {
+            apply_event_effects(ctx, &event, delta_hours);
+            if elapsed > e.duration as f64 && e.event_type != event_types::MUTINY {
+                e.state = event_states::RESOLVED;
+                log::info!(
+                    "{} {} has passed",
+                    progship_logic::constants::event_types::name(e.event_type),
+                    e.id
+                );
+                ctx.db.event().id().update(e);
+            }
+            continue;
+        }
+
         // Apply ongoing event effects based on type
         apply_event_effects(ctx, &event, delta_hours);
 
         // Events resolve when handled long enough or expire
         if e.state == event_states::BEING_HANDLED && elapsed > e.duration as f64 * 0.5 {
             e.state = event_states::RESOLVED;
+            hazards_ended |= hazard_scope(e.event_type).is_some();
+            doors_changed |= release_doors(ctx, &e);
             log::info!("Event {} resolved (handled)", e.id);
         } else if elapsed > e.duration as f64 {
             // Unhandled events escalate then resolve with damage
             if e.state == event_states::ACTIVE {
                 e.state = event_states::ESCALATED;
                 e.severity = (e.severity * 1.5).min(1.0);
+                let department = command::event_department(e.event_type);
+                e.escalated_to = responsible_officer(ctx, department, (sim_time % 24.0) as f32);
                 apply_escalation_effects(ctx, &e);
-                log::info!("Event {} escalated! severity={:.2}", e.id, e.severity);
+                // What people carry burns or is blown out with the air
+                if matches!(e.event_type, event_types::FIRE | event_types::HULL_BREACH) {
+                    lose_belongings(ctx, e.room_id, sim_time);
+                }
+                doors_changed |= force_doors(ctx, &e);
+                log::info!(
+                    "Event {} escalated to {:?}! severity={:.2}",
+                    e.id,
+                    e.escalated_to,
+                    e.severity
+                );
             } else {
                 e.state = event_states::RESOLVED;
+                hazards_ended |= hazard_scope(e.event_type).is_some();
+                doors_changed |= release_doors(ctx, &e);
                 log::info!("Event {} resolved (expired with damage)", e.id);
             }
         }
+        // A breach sealed from inside still leaves the plating to patch on EVA
+        if e.state == event_states::RESOLVED && e.event_type == event_types::HULL_BREACH {
+            if let Some(room) = ctx.db.room().id().find(e.room_id) {
+                damage_hull(ctx, room.deck, room.x, room.y, e.severity, sim_time);
+            }
+        }
 
         ctx.db.event().id().update(e);
     }
+    if hazards_ended || doors_changed {
+        refresh_evacuation_routes(ctx);
+    }
+
+    roll_flares(ctx, sim_time, delta_hours);
+    roll_micrometeorites(ctx, sim_time, delta_hours);
 
     // Cap active events to prevent runaway accumulation
     if active_count >= 10 {
@@ -53,12 +124,23 @@ pub fn tick_events(ctx: &ReducerContext, sim_time: f64, delta_hours: f32) {
         .wrapping_mul(6364136223846793005)
         .wrapping_add(1442695040888963407);
     let event_chance = (hash >> 32) % 1000; // Use upper bits for better distribution
+    let difficulty = ctx
+        .db
+        .ship_config()
+        .id()
+        .find(0)
+        .map_or(difficulties::NORMAL, |c| c.difficulty);
 
-    if event_chance < 3 {
-        // ~0.3% chance per tick (reduced from 0.5%)
+    // Tension between factions makes trouble more frequent
+    let per_mille = event_chance_per_mille(difficulty) as f32 * faction_event_frequency(ctx);
+    if (event_chance as f32) < per_mille {
+        // ~0.3% chance per tick at normal difficulty
         let hash2 = hash.wrapping_mul(2862933555777941757);
         let event_type = (hash2 % 8) as u8;
         let severity = 0.3 + ((hash2 / 8 % 50) as f32 * 0.01);
+        if !feature_flags(ctx).allows_event(event_type) {
+            return;
+        }
 
         // Pick a random room (only content rooms, not corridors)
         let rooms: Vec<Room> = ctx.db.room().iter().filter(|r| r.room_type < 100).collect();
@@ -83,6 +165,7 @@ pub fn tick_events(ctx: &ReducerContext, sim_time: f64, delta_hours: f32) {
             responders_needed,
             responders_assigned: 0,
             severity,
+            escalated_to: None,
         });
 
         log::info!(
@@ -91,9 +174,58 @@ pub fn tick_events(ctx: &ReducerContext, sim_time: f64, delta_hours: f32) {
             rooms[room_idx].name,
             severity
         );
+
+        // Clear the danger along routes rebuilt around it
+        if hazard_scope(event_type).is_some() {
+            refresh_evacuation_routes(ctx);
+            let evacuees = evacuate(ctx, &hazard_rooms(ctx));
+            log::info!("Evacuating {} people", evacuees);
+        }
     }
 }
 
+/// Seal or weld the doors of an escalated event's room (see
+/// `doors::escalation_door_state`). Returns whether any door changed.
+fn force_doors(ctx: &ReducerContext, event: &Event) -> bool {
+    let Some(state) = escalation_door_state(event.event_type) else {
+        return false;
+    };
+    let mut changed = false;
+    for mut door in room_doors(ctx, event.room_id) {
+        if door.state != door_states::WELDED && door.state != state {
+            door.state = state;
+            ctx.db.door().id().update(door);
+            changed = true;
+        }
+    }
+    changed
+}
+
+/// Reopen the doors a resolved event sealed or welded. Returns whether any
+/// door changed.
+fn release_doors(ctx: &ReducerContext, event: &Event) -> bool {
+    let Some(state) = escalation_door_state(event.event_type) else {
+        return false;
+    };
+    let mut changed = false;
+    for mut door in room_doors(ctx, event.room_id) {
+        if door.state == state {
+            door.state = door_states::OPEN;
+            ctx.db.door().id().update(door);
+            changed = true;
+        }
+    }
+    changed
+}
+
+fn room_doors(ctx: &ReducerContext, room_id: u32) -> Vec<Door> {
+    ctx.db
+        .door()
+        .iter()
+        .filter(|d| d.room_a == room_id || d.room_b == room_id)
+        .collect()
+}
+
 /// Apply ongoing effects of active events each tick
 fn apply_event_effects(ctx: &ReducerContext, event: &Event, delta_hours: f32) {
     let severity = event.severity;
@@ -113,42 +245,26 @@ fn apply_event_effects(ctx: &ReducerContext, event: &Event, delta_hours: f32) {
                     }
                 }
             }
-            // Fire affects deck atmosphere
-            if let Some(room) = ctx.db.room().id().find(event.room_id) {
-                if let Some(mut atmo) = ctx.db.deck_atmosphere().deck().find(room.deck) {
-                    atmo.oxygen -= severity * 0.01 * damage_mult * delta_hours;
-                    atmo.co2 += severity * 0.015 * damage_mult * delta_hours;
-                    atmo.temperature += severity * 2.0 * damage_mult * delta_hours;
-                    atmo.oxygen = atmo.oxygen.clamp(0.0, 0.25);
-                    atmo.co2 = atmo.co2.clamp(0.0, 0.1);
-                    atmo.temperature = atmo.temperature.clamp(-10.0, 50.0);
-                    ctx.db.deck_atmosphere().deck().update(atmo);
-                }
-            }
+            // The fire burns the room's own air (see tick_atmosphere)
         }
         event_types::HULL_BREACH => {
-            // Hull breach: rapid pressure/O2 loss on deck, severe health damage
-            if let Some(room) = ctx.db.room().id().find(event.room_id) {
-                if let Some(mut atmo) = ctx.db.deck_atmosphere().deck().find(room.deck) {
-                    atmo.pressure -= severity * 5.0 * damage_mult * delta_hours;
-                    atmo.oxygen -= severity * 0.02 * damage_mult * delta_hours;
-                    atmo.temperature -= severity * 3.0 * damage_mult * delta_hours;
-                    atmo.pressure = atmo.pressure.max(0.0);
-                    atmo.oxygen = atmo.oxygen.max(0.0);
-                    atmo.temperature = atmo.temperature.max(-40.0);
-                    ctx.db.deck_atmosphere().deck().update(atmo);
-                }
-                // Damage everyone on that deck
-                for pos in ctx.db.position().iter() {
-                    if let Some(r) = ctx.db.room().id().find(pos.room_id) {
-                        if r.deck == room.deck {
-                            if let Some(mut needs) = ctx.db.needs().person_id().find(pos.person_id)
-                            {
-                                needs.health -= severity * 0.1 * damage_mult * delta_hours;
-                                needs.health = needs.health.max(0.0);
-                                ctx.db.needs().person_id().update(needs);
-                            }
-                        }
+            // Hull breach: the room's air vents to space, drawing down its
+            // neighbors' through open doors, and those in it are hurt
+            if let Some(mut air) = ctx.db.room_air().room_id().find(event.room_id) {
+                air.pressure -= severity * 5.0 * damage_mult * delta_hours;
+                air.oxygen -= severity * 0.02 * damage_mult * delta_hours;
+                air.temperature -= severity * 3.0 * damage_mult * delta_hours;
+                air.pressure = air.pressure.max(0.0);
+                air.oxygen = air.oxygen.max(0.0);
+                air.temperature = air.temperature.max(-40.0);
+                ctx.db.room_air().room_id().update(air);
+            }
+            for pos in ctx.db.position().iter() {
+                if pos.room_id == event.room_id {
+                    if let Some(mut needs) = ctx.db.needs().person_id().find(pos.person_id) {
+                        needs.health -= severity * 0.1 * damage_mult * delta_hours;
+                        needs.health = needs.health.max(0.0);
+                        ctx.db.needs().person_id().update(needs);
                     }
                 }
             }
@@ -239,6 +355,40 @@ fn apply_event_effects(ctx: &ReducerContext, event: &Event, delta_hours: f32) {
                 }
             }
         }
+        event_types::RALLY => {
+            // Rally: those who come take heart
+            for pos in ctx.db.position().iter() {
+                if pos.room_id == event.room_id {
+                    if let Some(mut needs) = ctx.db.needs().person_id().find(pos.person_id) {
+                        needs.morale = (needs.morale + RALLY_MORALE * delta_hours).min(1.0);
+                        ctx.db.needs().person_id().update(needs);
+                    }
+                }
+            }
+        }
+        event_types::STRIKE => {
+            // Strike: the strikers feel heard
+            if let Some(department) = striking_department(ctx, event.id) {
+                for crew in ctx.db.crew().iter().filter(|c| c.department == department) {
+                    if let Some(mut needs) = ctx.db.needs().person_id().find(crew.person_id) {
+                        needs.morale = (needs.morale + STRIKE_MORALE * delta_hours).min(1.0);
+                        ctx.db.needs().person_id().update(needs);
+                    }
+                }
+            }
+        }
+        event_types::VOYAGE_MILESTONE => {
+            // Voyage milestone: those gathered to mark it take heart
+            for pos in ctx.db.position().iter() {
+                if pos.room_id == event.room_id {
+                    if let Some(mut needs) = ctx.db.needs().person_id().find(pos.person_id) {
+                        needs.morale = (needs.morale + GATHERING_MORALE * delta_hours).min(1.0);
+                        needs.social = (needs.social - 0.05 * delta_hours).max(0.0);
+                        ctx.db.needs().person_id().update(needs);
+                    }
+                }
+            }
+        }
         _ => {} // Other events: no special effects yet
     }
 }
@@ -0,0 +1,188 @@
+//! Fabrication system - spare parts stocked by kind, drawn by repairs and
+//! made from raw materials in the Machine Shop and Robotics Bay when a kind
+//! runs low.
+
+use crate::tables::*;
+use progship_logic::fabrication::{
+    fabricates, fabrication_priority, parts_made, raw_needed, stock_low, BATCH_HOURS, BATCH_TONS,
+};
+use spacetimedb::{ReducerContext, Table};
+
+use super::cargo::{draw_cargo, stow_cargo};
+
+/// Kind of spare part (see `part_kinds`) a component of `component_type`
+/// takes.
+pub fn part_kind(component_type: u8) -> u8 {
+    use component_types::*;
+    match component_type {
+        GENERATOR | CAPACITOR | TRANSFORMER | CIRCUIT_BREAKER | LAMP | CONTAINMENT_COIL
+        | GRAVITY_EMITTER => part_kinds::ELECTRICAL,
+        SENSOR | PROCESSOR | ANTENNA | DISPLAY | SCANNER_HEAD => part_kinds::ELECTRONIC,
+        FILTER | SEAL => part_kinds::SEALS,
+        _ => part_kinds::MECHANICAL,
+    }
+}
+
+/// Kind of spare part a repair of `component_id` in `subsystem_id` takes:
+/// the component's own, or failing that the first in the subsystem's.
+pub fn repair_part_kind(ctx: &ReducerContext, subsystem_id: u64, component_id: u64) -> u8 {
+    ctx.db
+        .system_component()
+        .id()
+        .find(component_id)
+        .or_else(|| {
+            ctx.db
+                .system_component()
+                .iter()
+                .find(|c| c.subsystem_id == subsystem_id)
+        })
+        .map_or(part_kinds::MECHANICAL, |c| part_kind(c.component_type))
+}
+
+/// Take up to `tons` of spare parts of `kind` from the stores, nearest to
+/// `deck` first. Returns the tons actually taken.
+pub fn take_parts(ctx: &ReducerContext, kind: u8, tons: f32, deck: Option<i32>) -> f32 {
+    let Some(mut stock) = ctx.db.part_stock().kind().find(kind) else {
+        return 0.0;
+    };
+    let taken = draw_cargo(
+        ctx,
+        cargo_categories::SPARE_PARTS,
+        tons.min(stock.mass_tons),
+        deck,
+    );
+    if taken <= 0.0 {
+        return 0.0;
+    }
+    stock.mass_tons = (stock.mass_tons - taken).max(0.0);
+    ctx.db.part_stock().kind().update(stock);
+    if let Some(mut res) = ctx.db.ship_resources().id().find(0) {
+        res.spare_parts = (res.spare_parts - taken * 1000.0).max(0.0);
+        ctx.db.ship_resources().id().update(res);
+    }
+    taken
+}
+
+/// Hourly: file a fabrication order at a workshop for every kind of part
+/// run low that has none on the board, while the holds have the raw
+/// materials for a batch.
+pub fn tick_fabrication(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    if sim_time.floor() == (sim_time - delta_hours).floor() {
+        return;
+    }
+    let raw: f32 = ctx
+        .db
+        .cargo_item()
+        .iter()
+        .filter(|c| c.category == cargo_categories::RAW_MATERIALS)
+        .map(|c| c.mass_tons)
+        .sum();
+    if raw < raw_needed(BATCH_TONS) {
+        return;
+    }
+    for stock in ctx.db.part_stock().iter() {
+        if !stock_low(stock.mass_tons, stock.launch_tons) {
+            continue;
+        }
+        let filed = ctx.db.work_order().iter().any(|o| {
+            o.kind == work_kinds::FABRICATION
+                && o.part_kind == stock.kind
+                && matches!(
+                    o.state,
+                    work_order_states::OPEN | work_order_states::CLAIMED
+                )
+        });
+        if filed {
+            continue;
+        }
+        let Some(workshop) = ctx
+            .db
+            .room()
+            .iter()
+            .filter(|r| fabricates(r.room_type, stock.kind))
+            .min_by_key(|r| r.id)
+        else {
+            continue;
+        };
+        ctx.db.work_order().insert(WorkOrder {
+            id: 0,
+            kind: work_kinds::FABRICATION,
+            state: work_order_states::OPEN,
+            reason: format!(
+                "{} down to {:.0}%",
+                progship_logic::constants::part_kinds::name(stock.kind),
+                stock.mass_tons / stock.launch_tons * 100.0
+            ),
+            department: departments::ENGINEERING,
+            required_skill: skill_types::ENGINEERING,
+            required_parts: BATCH_TONS,
+            part_kind: stock.kind,
+            priority: fabrication_priority(stock.mass_tons, stock.launch_tons),
+            room_id: Some(workshop.id),
+            subsystem_id: 0,
+            component_id: 0,
+            infra_edge_id: None,
+            event_id: None,
+            hull_damage_id: None,
+            assigned_crew_id: None,
+            assigned_robot_id: None,
+            progress: 0.0,
+            duration_hours: BATCH_HOURS,
+            created_at: sim_time,
+            claimed_at: None,
+            completed_at: None,
+        });
+    }
+}
+
+/// Finish a fabrication order: turn raw materials from the nearest holds
+/// into a batch of its kind of part, left on the workshop floor.
+pub fn finish_fabrication(ctx: &ReducerContext, order: &WorkOrder, deck: Option<i32>) {
+    let raw = draw_cargo(
+        ctx,
+        cargo_categories::RAW_MATERIALS,
+        raw_needed(order.required_parts),
+        deck,
+    );
+    let made = parts_made(raw);
+    if made <= 0.0 {
+        log::warn!("Work order {} had no raw materials to work", order.id);
+        return;
+    }
+    if let Some(room_id) = order.room_id {
+        stow_cargo(ctx, cargo_categories::SPARE_PARTS, room_id, made);
+    }
+    if let Some(mut stock) = ctx.db.part_stock().kind().find(order.part_kind) {
+        stock.mass_tons += made;
+        ctx.db.part_stock().kind().update(stock);
+    }
+    if let Some(mut res) = ctx.db.ship_resources().id().find(0) {
+        res.spare_parts = (res.spare_parts + made * 1000.0).min(res.spare_parts_cap);
+        ctx.db.ship_resources().id().update(res);
+    }
+    log::info!(
+        "Fabricated {:.2}t of {}",
+        made,
+        progship_logic::constants::part_kinds::name(order.part_kind)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_kind() {
+        assert_eq!(part_kind(component_types::PUMP), part_kinds::MECHANICAL);
+        assert_eq!(
+            part_kind(component_types::TRANSFORMER),
+            part_kinds::ELECTRICAL
+        );
+        assert_eq!(
+            part_kind(component_types::PROCESSOR),
+            part_kinds::ELECTRONIC
+        );
+        assert_eq!(part_kind(component_types::SEAL), part_kinds::SEALS);
+        assert_eq!(part_kind(99), part_kinds::MECHANICAL);
+    }
+}
@@ -0,0 +1,235 @@
+//! Faction system - political blocs, the loyalty of their members and the
+//! tension between them.
+
+use crate::tables::*;
+use progship_logic::constants::factions as faction_ids;
+use progship_logic::factions::{
+    event_frequency, event_swing, founding_faction, sway, tension, Standing, DEFECTION_LOYALTY,
+    STARTING_LOYALTY,
+};
+use spacetimedb::{ReducerContext, Table};
+use std::collections::HashMap;
+
+use super::features::feature_flags;
+
+/// Hourly, with politics enabled: adults without a faction take a side,
+/// the dead leave theirs, the events of the last hour swing loyalties and
+/// every faction's standing is refreshed.
+pub fn tick_factions(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    if sim_time.floor() == (sim_time - delta_hours).floor() || !feature_flags(ctx).politics {
+        return;
+    }
+    enlist(ctx, sim_time);
+    swing_with_events(ctx, sim_time);
+    refresh_standings(ctx);
+}
+
+/// Let a conversation on `topic` between `person_a` and `person_b` sway
+/// their loyalties, with the less loyal defecting if they are won over.
+pub fn sway_factions(ctx: &ReducerContext, person_a: u64, person_b: u64, topic: u8, sim_time: f64) {
+    if !feature_flags(ctx).politics {
+        return;
+    }
+    let (Some(a), Some(b)) = (
+        ctx.db.faction_membership().person_id().find(person_a),
+        ctx.db.faction_membership().person_id().find(person_b),
+    ) else {
+        return;
+    };
+    let relationship = ctx
+        .db
+        .relationship()
+        .iter()
+        .find(|r| {
+            (r.person_a == person_a && r.person_b == person_b)
+                || (r.person_a == person_b && r.person_b == person_a)
+        })
+        .map_or(0.0, |r| r.strength);
+    let same = a.faction == b.faction;
+    let swayed = [
+        (
+            sway(topic, same, relationship, a.loyalty, b.loyalty),
+            b.faction,
+        ),
+        (
+            sway(topic, same, relationship, b.loyalty, a.loyalty),
+            a.faction,
+        ),
+    ];
+    for (mut member, (delta, other_faction)) in [a, b].into_iter().zip(swayed) {
+        if delta == 0.0 {
+            continue;
+        }
+        member.loyalty = (member.loyalty + delta).clamp(0.0, 1.0);
+        if !same && delta < 0.0 && member.loyalty < DEFECTION_LOYALTY {
+            log::info!(
+                "Person {} defects from the {} to the {}",
+                member.person_id,
+                faction_ids::name(member.faction),
+                faction_ids::name(other_faction)
+            );
+            member.faction = other_faction;
+            member.loyalty = STARTING_LOYALTY;
+            member.joined_at = sim_time;
+        }
+        ctx.db.faction_membership().person_id().update(member);
+    }
+}
+
+/// How often random events break out given the tension between factions:
+/// 1.0 with politics off.
+pub fn faction_event_frequency(ctx: &ReducerContext) -> f32 {
+    if !feature_flags(ctx).politics {
+        return 1.0;
+    }
+    let standings: Vec<Standing> = ctx
+        .db
+        .faction()
+        .iter()
+        .map(|f| Standing {
+            members: f.members,
+            loyalty: f.loyalty,
+            morale: f.morale,
+        })
+        .collect();
+    event_frequency(tension(&standings))
+}
+
+/// Drop the memberships of the dead and sign up every living adult who
+/// has none: with their household's faction if a parent has one, else
+/// with the one [`founding_faction`] picks.
+fn enlist(ctx: &ReducerContext, sim_time: f64) {
+    let departed: Vec<u64> = ctx
+        .db
+        .faction_membership()
+        .iter()
+        .filter(|m| {
+            !ctx.db
+                .person()
+                .id()
+                .find(m.person_id)
+                .is_some_and(|p| p.is_alive)
+        })
+        .map(|m| m.person_id)
+        .collect();
+    for person_id in departed {
+        ctx.db.faction_membership().person_id().delete(person_id);
+    }
+
+    let recruits: Vec<u64> = ctx
+        .db
+        .person()
+        .iter()
+        .filter(|p| p.is_alive && p.life_stage >= life_stages::ADULT)
+        .filter(|p| ctx.db.faction_membership().person_id().find(p.id).is_none())
+        .map(|p| p.id)
+        .collect();
+    for person_id in recruits {
+        let faction = household_faction(ctx, person_id).unwrap_or_else(|| {
+            let crew = ctx.db.crew().person_id().find(person_id);
+            let first_class = ctx
+                .db
+                .passenger()
+                .person_id()
+                .find(person_id)
+                .is_some_and(|p| p.cabin_class == cabin_classes::FIRST);
+            founding_faction(
+                crew.is_some(),
+                crew.map_or(0, |c| c.rank),
+                first_class,
+                person_id,
+            )
+        });
+        ctx.db.faction_membership().insert(FactionMembership {
+            person_id,
+            faction,
+            loyalty: STARTING_LOYALTY,
+            joined_at: sim_time,
+        });
+    }
+}
+
+/// The faction of the head (or else the partner) of `person_id`'s
+/// household, if they are a child in one and that parent has a faction.
+fn household_faction(ctx: &ReducerContext, person_id: u64) -> Option<u8> {
+    let family_id = ctx
+        .db
+        .family_member()
+        .person_id()
+        .find(person_id)
+        .filter(|m| m.role == family_roles::CHILD)?
+        .family_id;
+    let parents: Vec<FamilyMember> = ctx
+        .db
+        .family_member()
+        .iter()
+        .filter(|m| m.family_id == family_id)
+        .filter(|m| m.role == family_roles::HEAD || m.role == family_roles::PARTNER)
+        .collect();
+    [family_roles::HEAD, family_roles::PARTNER]
+        .iter()
+        .filter_map(|&role| parents.iter().find(|m| m.role == role))
+        .find_map(|m| ctx.db.faction_membership().person_id().find(m.person_id))
+        .map(|m| m.faction)
+}
+
+/// Swing every member's loyalty by the events that broke out in the last
+/// hour.
+fn swing_with_events(ctx: &ReducerContext, sim_time: f64) {
+    let recent: Vec<u8> = ctx
+        .db
+        .event()
+        .iter()
+        .filter(|e| e.started_at > sim_time - 1.0 && e.started_at <= sim_time)
+        .map(|e| e.event_type)
+        .collect();
+    if recent.is_empty() {
+        return;
+    }
+    let members: Vec<FactionMembership> = ctx.db.faction_membership().iter().collect();
+    for mut member in members {
+        let delta: f32 = recent.iter().map(|&e| event_swing(e, member.faction)).sum();
+        if delta != 0.0 {
+            member.loyalty = (member.loyalty + delta).clamp(0.0, 1.0);
+            ctx.db.faction_membership().person_id().update(member);
+        }
+    }
+}
+
+/// Recount every faction's members, average loyalty and average morale.
+fn refresh_standings(ctx: &ReducerContext) {
+    let mut totals: HashMap<u8, (u32, f32, f32)> = HashMap::new();
+    for member in ctx.db.faction_membership().iter() {
+        let morale = ctx
+            .db
+            .needs()
+            .person_id()
+            .find(member.person_id)
+            .map_or(0.5, |n| n.morale);
+        let total = totals.entry(member.faction).or_default();
+        total.0 += 1;
+        total.1 += member.loyalty;
+        total.2 += morale;
+    }
+    for id in 0..faction_ids::COUNT as u8 {
+        let (members, loyalty, morale) = totals.get(&id).copied().unwrap_or_default();
+        let average = |sum: f32| {
+            if members > 0 {
+                sum / members as f32
+            } else {
+                0.0
+            }
+        };
+        let row = Faction {
+            id,
+            members,
+            loyalty: average(loyalty),
+            morale: average(morale),
+        };
+        if ctx.db.faction().id().find(id).is_some() {
+            ctx.db.faction().id().update(row);
+        } else {
+            ctx.db.faction().insert(row);
+        }
+    }
+}
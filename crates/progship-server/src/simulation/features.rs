@@ -0,0 +1,46 @@
+//! Feature flags - which optional systems this ship runs.
+
+use crate::tables::*;
+use progship_logic::features;
+use spacetimedb::ReducerContext;
+
+/// The feature flags in effect: the feature_flags row, or the defaults.
+pub fn feature_flags(ctx: &ReducerContext) -> features::FeatureFlags {
+    ctx.db
+        .feature_flags()
+        .id()
+        .find(0)
+        .map(|row| features_from_row(&row))
+        .unwrap_or_default()
+}
+
+/// Convert a feature_flags row to the logic type.
+pub fn features_from_row(row: &FeatureFlags) -> features::FeatureFlags {
+    features::FeatureFlags {
+        disease: row.disease,
+        generational: row.generational,
+        crime: row.crime,
+        politics: row.politics,
+        hardcore_atmosphere: row.hardcore_atmosphere,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feature_row_converts_to_logic_flags() {
+        let row = FeatureFlags {
+            id: 0,
+            disease: false,
+            generational: true,
+            crime: false,
+            politics: true,
+            hardcore_atmosphere: true,
+        };
+        let flags = features_from_row(&row);
+        assert!(!flags.disease && !flags.crime);
+        assert!(flags.generational && flags.politics && flags.hardcore_atmosphere);
+    }
+}
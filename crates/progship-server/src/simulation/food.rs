@@ -0,0 +1,308 @@
+//! Food system - crops ripening in hydroponics, cooks in the galleys and
+//! meals served from each galley's buffer.
+
+use crate::tables::*;
+use progship_logic::economy::{self, is_mess_open, RationingLevel};
+use progship_logic::food::{
+    cook, cooks_wanted, crop_growth, crop_health, harvest_yield, serving_galley,
+};
+use progship_logic::governance::effective_rationing;
+use spacetimedb::{ReducerContext, Table};
+use std::collections::HashSet;
+
+use super::activities::restart_activity;
+use super::governance::current_policy;
+use super::maintenance::crew_on_work_orders;
+use super::movement::start_movement_to;
+use super::ship_systems::{calculate_subsystem_efficiency, resource_values, shed_nodes};
+use super::water::dry_rooms;
+
+/// Sim hours between rounds of sending cooks to short-handed galleys.
+const STAFFING_INTERVAL_HOURS: f64 = 1.0;
+
+/// Hours a cook works a galley before picking what to do next.
+const COOK_SHIFT_HOURS: f32 = 2.0;
+
+/// Grow and harvest crops, cook meals in the galleys and keep them staffed.
+pub fn tick_food(ctx: &ReducerContext, sim_time: f64, delta_hours: f32) {
+    let Some(mut resources) = ctx.db.ship_resources().id().find(0) else {
+        return;
+    };
+    let dark: HashSet<u32> = ctx
+        .db
+        .room_power()
+        .iter()
+        .filter(|p| !p.powered)
+        .map(|p| p.room_id)
+        .collect();
+    let dry = dry_rooms(ctx);
+    let working = |room_id: u32| !dark.contains(&room_id) && !dry.contains(&room_id);
+
+    // The growth chambers tend every bed; their efficiency sets the pace
+    let shed = shed_nodes(ctx);
+    let chambers: Vec<f32> = ctx
+        .db
+        .subsystem()
+        .iter()
+        .filter(|s| s.subsystem_type == subsystem_types::GROWTH_CHAMBER)
+        .map(|s| {
+            if shed.contains(&s.node_id) {
+                0.0
+            } else {
+                calculate_subsystem_efficiency(s.health, s.status)
+            }
+        })
+        .collect();
+    let tending = if chambers.is_empty() {
+        0.0
+    } else {
+        chambers.iter().sum::<f32>() / chambers.len() as f32
+    };
+    let crops: Vec<Crop> = ctx.db.crop().iter().collect();
+    for mut crop in crops {
+        let thriving = working(crop.room_id);
+        let conditions = if thriving { tending } else { 0.0 };
+        crop.health = crop_health(crop.health, thriving, delta_hours);
+        crop.growth = crop_growth(crop.growth, conditions, delta_hours);
+        if crop.growth >= 1.0 {
+            let harvest = harvest_yield(crop.expected_yield, crop.health);
+            resources.food = (resources.food + harvest).min(resources.food_cap);
+            crop.growth = 0.0;
+            crop.health = 1.0;
+            crop.planted_at = sim_time;
+        }
+        ctx.db.crop().id().update(crop);
+    }
+
+    // Cooks on duty in a lit, watered galley turn raw food into meals,
+    // smaller portions while food is rationed
+    let levels = economy::compute_levels(&resource_values(&resources));
+    let portion = economy::rationing_consumption_factor(effective_rationing(
+        economy::compute_rationing(&levels),
+        current_policy(ctx).rationing,
+    ));
+    let buffers: Vec<MealBuffer> = ctx.db.meal_buffer().iter().collect();
+    for mut buffer in buffers {
+        let cooks = cooks_at_work(ctx, buffer.galley_room_id);
+        let (meals, raw) = if working(buffer.galley_room_id) {
+            cook(
+                cooks.len() as u32,
+                resources.food,
+                portion,
+                buffer.capacity - buffer.meals,
+                delta_hours,
+            )
+        } else {
+            (0.0, 0.0)
+        };
+        resources.food = (resources.food - raw).max(0.0);
+        let changed = meals > 0.0 || buffer.cooks != cooks.len() as u32;
+        if changed {
+            buffer.meals += meals;
+            buffer.cooks = cooks.len() as u32;
+            ctx.db.meal_buffer().galley_room_id().update(buffer);
+        }
+    }
+    ctx.db.ship_resources().id().update(resources);
+
+    let interval = |t: f64| (t / STAFFING_INTERVAL_HOURS).floor();
+    if interval(sim_time) != interval(sim_time - delta_hours as f64) {
+        staff_galleys(ctx, sim_time);
+    }
+}
+
+/// On-duty crew at work in the galley in `galley_room_id`.
+fn cooks_at_work(ctx: &ReducerContext, galley_room_id: u32) -> Vec<u64> {
+    ctx.db
+        .activity()
+        .iter()
+        .filter(|a| {
+            a.activity_type == activity_types::ON_DUTY && a.target_room_id == Some(galley_room_id)
+        })
+        .filter(|a| {
+            ctx.db
+                .crew()
+                .person_id()
+                .find(a.person_id)
+                .is_some_and(|c| c.on_duty)
+        })
+        .filter(|a| {
+            ctx.db
+                .position()
+                .person_id()
+                .find(a.person_id)
+                .is_some_and(|p| p.room_id == galley_room_id)
+        })
+        .map(|a| a.person_id)
+        .collect()
+}
+
+/// Send on-duty operations crew to every galley short of the cooks it
+/// needs to feed its share of the ship, preferring crew on the same deck.
+fn staff_galleys(ctx: &ReducerContext, sim_time: f64) {
+    let buffers: Vec<MealBuffer> = ctx.db.meal_buffer().iter().collect();
+    if buffers.is_empty() {
+        return;
+    }
+    let alive = ctx.db.person().iter().filter(|p| p.is_alive).count() as u32;
+    let wanted = cooks_wanted(alive / buffers.len() as u32);
+
+    let mut busy = crew_on_work_orders(ctx);
+    busy.extend(ctx.db.room_effect().iter().filter_map(|e| e.cleaner_id));
+    busy.extend(ctx.db.order_assignment().iter().map(|o| o.person_id));
+    let deck_of = |person_id: u64| {
+        ctx.db
+            .position()
+            .person_id()
+            .find(person_id)
+            .and_then(|p| ctx.db.room().id().find(p.room_id))
+            .map(|r| r.deck)
+    };
+
+    for buffer in &buffers {
+        let galley = buffer.galley_room_id;
+        let mut staffed = ctx
+            .db
+            .activity()
+            .iter()
+            .filter(|a| {
+                a.activity_type == activity_types::ON_DUTY && a.target_room_id == Some(galley)
+            })
+            .count() as u32;
+        while staffed < wanted {
+            let cook = ctx
+                .db
+                .crew()
+                .iter()
+                .filter(|c| {
+                    c.on_duty
+                        && c.department == departments::OPERATIONS
+                        && !busy.contains(&c.person_id)
+                })
+                .filter(|c| {
+                    ctx.db
+                        .person()
+                        .id()
+                        .find(c.person_id)
+                        .is_some_and(|p| p.is_alive && !p.is_player)
+                })
+                .min_by_key(|c| (deck_of(c.person_id) != Some(buffer.deck), c.person_id))
+                .map(|c| c.person_id);
+            let Some(cook) = cook else {
+                return;
+            };
+            busy.insert(cook);
+            let Some(mut activity) = ctx.db.activity().person_id().find(cook) else {
+                continue;
+            };
+            let cooking = activity.activity_type == activity_types::ON_DUTY
+                && buffers
+                    .iter()
+                    .any(|b| activity.target_room_id == Some(b.galley_room_id));
+            if cooking {
+                continue;
+            }
+            restart_activity(
+                &mut activity,
+                activity_types::ON_DUTY,
+                sim_time,
+                COOK_SHIFT_HOURS,
+            );
+            activity.target_room_id = Some(galley);
+            ctx.db.activity().person_id().update(activity);
+            start_movement_to(ctx, cook, galley);
+            staffed += 1;
+        }
+    }
+}
+
+/// The meal buffers as diners draw on them within one tick.
+pub struct Servery {
+    buffers: Vec<MealBuffer>,
+    galleys: Vec<(u32, i32)>,
+    served: HashSet<u32>,
+    open: bool,
+}
+
+impl Servery {
+    pub fn load(ctx: &ReducerContext) -> Self {
+        let buffers: Vec<MealBuffer> = ctx.db.meal_buffer().iter().collect();
+        let galleys = buffers.iter().map(|b| (b.galley_room_id, b.deck)).collect();
+        Self {
+            buffers,
+            galleys,
+            served: HashSet::new(),
+            open: true,
+        }
+    }
+
+    /// Keep the mess halls to the meal hours rationing at `level` allows
+    /// at `hour`, serving nothing outside them.
+    pub fn at_mess_hours(mut self, level: RationingLevel, hour: f32) -> Self {
+        self.open = is_mess_open(level, hour);
+        self
+    }
+
+    /// Serve `meals` to someone on `deck` from the galley that feeds it;
+    /// false when the mess is closed or that galley has run out.
+    pub fn serve(&mut self, deck: i32, meals: f32) -> bool {
+        if !self.open {
+            return false;
+        }
+        let Some(galley) = serving_galley(deck, &self.galleys) else {
+            return false;
+        };
+        let Some(buffer) = self.buffers.iter_mut().find(|b| b.galley_room_id == galley) else {
+            return false;
+        };
+        if buffer.meals < meals {
+            return false;
+        }
+        buffer.meals -= meals;
+        self.served.insert(galley);
+        true
+    }
+
+    /// Write the buffers meals were served from back.
+    pub fn save(self, ctx: &ReducerContext) {
+        for buffer in self.buffers {
+            if self.served.contains(&buffer.galley_room_id) {
+                ctx.db.meal_buffer().galley_room_id().update(buffer);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn servery() -> Servery {
+        Servery {
+            buffers: vec![MealBuffer {
+                galley_room_id: 1,
+                deck: 0,
+                meals: 100.0,
+                capacity: 100.0,
+                cooks: 1,
+            }],
+            galleys: vec![(1, 0)],
+            served: HashSet::new(),
+            open: true,
+        }
+    }
+
+    #[test]
+    fn test_servery_keeps_mess_hours() {
+        let at = |level, hour| servery().at_mess_hours(level, hour).serve(0, 1.0);
+        for hour in [7.5, 12.5, 18.5] {
+            assert!(at(RationingLevel::Normal, hour));
+        }
+        assert!(!at(RationingLevel::Normal, 15.0));
+        assert!(!at(RationingLevel::Heavy, 12.5));
+        assert!(at(RationingLevel::Heavy, 7.5));
+        assert!(!at(RationingLevel::Emergency, 7.5));
+        assert!(!at(RationingLevel::Emergency, 12.5));
+        assert!(at(RationingLevel::Emergency, 18.5));
+    }
+}
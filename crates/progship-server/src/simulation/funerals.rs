@@ -0,0 +1,205 @@
+//! Funeral system - the morgue, chapel funerals and grief that lasts weeks.
+
+use crate::tables::*;
+use progship_logic::funerals::{
+    grief_ceiling, grief_depth, mourns, FUNERAL_DELAY_HOURS, FUNERAL_HOURS, GRIEF_HOURS,
+};
+use spacetimedb::{ReducerContext, Table};
+use std::collections::HashMap;
+
+/// Lay `deceased` to rest: carry the body to the morgue, start grieving
+/// among everyone who mourns them, and schedule their funeral in the
+/// chapel. Morgue and chapel are looked for on the deck they died on first.
+pub fn lay_to_rest(ctx: &ReducerContext, deceased: u64, sim_time: f64) {
+    let body = ctx.db.position().person_id().find(deceased);
+    let deck = body
+        .as_ref()
+        .and_then(|p| ctx.db.room().id().find(p.room_id))
+        .map(|r| r.deck);
+    let nearest = |room_type: u8| {
+        ctx.db
+            .room()
+            .iter()
+            .filter(|r| r.room_type == room_type)
+            .min_by_key(|r| (Some(r.deck) != deck, r.id))
+    };
+    if let (Some(mut body), Some(morgue)) = (body, nearest(room_types::MORGUE)) {
+        body.room_id = morgue.id;
+        body.x = morgue.x;
+        body.y = morgue.y;
+        ctx.db.position().person_id().update(body);
+    }
+
+    let mut mourners = 0;
+    let bonds: Vec<(u64, u8, f32)> = ctx
+        .db
+        .relationship()
+        .iter()
+        .filter_map(|r| match (r.person_a == deceased, r.person_b == deceased) {
+            (true, _) => Some((r.person_b, r.relationship_type, r.strength)),
+            (_, true) => Some((r.person_a, r.relationship_type, r.strength)),
+            _ => None,
+        })
+        .collect();
+    for (mourner, relationship_type, strength) in bonds {
+        let alive = ctx
+            .db
+            .person()
+            .id()
+            .find(mourner)
+            .is_some_and(|p| p.is_alive);
+        if !alive || !mourns(relationship_type, strength) {
+            continue;
+        }
+        ctx.db.grief().insert(Grief {
+            id: 0,
+            person_id: mourner,
+            deceased_id: deceased,
+            depth: grief_depth(relationship_type, strength),
+            since: sim_time,
+            attended_funeral: false,
+        });
+        mourners += 1;
+    }
+
+    let Some(chapel) = nearest(room_types::CHAPEL) else {
+        return;
+    };
+    if mourners > 0 && ctx.db.funeral().person_id().find(deceased).is_none() {
+        ctx.db.funeral().insert(Funeral {
+            person_id: deceased,
+            chapel_id: chapel.id,
+            scheduled_at: sim_time + FUNERAL_DELAY_HOURS,
+            event_id: None,
+        });
+    }
+}
+
+/// Hourly: begin funerals that are due, mark the mourners who came to say
+/// goodbye, clear funerals that are over and the griefs that have run
+/// their course.
+pub fn tick_funerals(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    if sim_time.floor() == (sim_time - delta_hours).floor() {
+        return;
+    }
+    let funerals: Vec<Funeral> = ctx.db.funeral().iter().collect();
+    for mut funeral in funerals {
+        if funeral.scheduled_at > sim_time {
+            continue;
+        }
+        if funeral.event_id.is_none() {
+            let event = ctx.db.event().insert(Event {
+                id: 0,
+                event_type: event_types::FUNERAL,
+                room_id: funeral.chapel_id,
+                started_at: sim_time,
+                duration: FUNERAL_HOURS,
+                state: event_states::ACTIVE,
+                responders_needed: 0,
+                responders_assigned: 0,
+                severity: 0.3,
+                escalated_to: None,
+            });
+            log::info!("Funeral for person {} begins", funeral.person_id);
+            funeral.scheduled_at = sim_time;
+            funeral.event_id = Some(event.id);
+            let deceased = funeral.person_id;
+            ctx.db.funeral().person_id().update(funeral);
+            // Mourners drop what they are doing to attend
+            for grief in ctx.db.grief().iter().filter(|g| g.deceased_id == deceased) {
+                if let Some(mut activity) = ctx.db.activity().person_id().find(grief.person_id) {
+                    activity.duration = 0.0;
+                    ctx.db.activity().person_id().update(activity);
+                }
+            }
+            continue;
+        }
+
+        let present: Vec<Grief> = ctx
+            .db
+            .grief()
+            .iter()
+            .filter(|g| g.deceased_id == funeral.person_id && !g.attended_funeral)
+            .filter(|g| {
+                ctx.db
+                    .position()
+                    .person_id()
+                    .find(g.person_id)
+                    .is_some_and(|p| p.room_id == funeral.chapel_id)
+            })
+            .collect();
+        for mut grief in present {
+            grief.attended_funeral = true;
+            ctx.db.grief().id().update(grief);
+        }
+        if sim_time >= funeral.scheduled_at + FUNERAL_HOURS as f64 {
+            ctx.db.funeral().person_id().delete(funeral.person_id);
+        }
+    }
+
+    let spent: Vec<u64> = ctx
+        .db
+        .grief()
+        .iter()
+        .filter(|g| sim_time - g.since >= GRIEF_HOURS)
+        .map(|g| g.id)
+        .collect();
+    for id in spent {
+        ctx.db.grief().id().delete(id);
+    }
+}
+
+/// What a mourner does while a funeral they mourn is under way: attend it
+/// (SOCIALIZING) in the chapel until it ends. Crew on duty stay at their
+/// posts.
+pub fn funeral_activity(
+    ctx: &ReducerContext,
+    person_id: u64,
+    sim_time: f64,
+) -> Option<(u8, f32, Option<u32>)> {
+    let under_way: Vec<Funeral> = ctx
+        .db
+        .funeral()
+        .iter()
+        .filter(|f| f.event_id.is_some())
+        .collect();
+    if under_way.is_empty()
+        || ctx
+            .db
+            .crew()
+            .person_id()
+            .find(person_id)
+            .is_some_and(|c| c.on_duty)
+    {
+        return None;
+    }
+    let funeral = under_way.into_iter().find(|f| {
+        ctx.db
+            .grief()
+            .iter()
+            .any(|g| g.person_id == person_id && g.deceased_id == f.person_id)
+    })?;
+    let remaining = funeral.scheduled_at + FUNERAL_HOURS as f64 - sim_time;
+    (remaining > 0.0).then_some((
+        activity_types::SOCIALIZING,
+        remaining as f32,
+        Some(funeral.chapel_id),
+    ))
+}
+
+/// Every mourner's morale ceiling under their griefs at `sim_time` (see
+/// [`grief_ceiling`]).
+pub fn grief_ceilings(ctx: &ReducerContext, sim_time: f64) -> HashMap<u64, f32> {
+    let mut griefs: HashMap<u64, Vec<(f32, f64, bool)>> = HashMap::new();
+    for g in ctx.db.grief().iter() {
+        griefs.entry(g.person_id).or_default().push((
+            g.depth,
+            sim_time - g.since,
+            g.attended_funeral,
+        ));
+    }
+    griefs
+        .into_iter()
+        .map(|(person_id, losses)| (person_id, grief_ceiling(&losses)))
+        .collect()
+}
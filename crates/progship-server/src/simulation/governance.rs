@@ -0,0 +1,303 @@
+//! Governance system - civilian council elections and the policies the
+//! simulation runs under.
+
+use crate::tables::*;
+use progship_logic::economy;
+use progship_logic::governance::{
+    self, candidacy, decide_policy, election_due, vote_score, CouncilBrief, CANDIDATES,
+    COUNCIL_SEATS, SESSION_HOURS,
+};
+use spacetimedb::{ReducerContext, Table};
+use std::collections::{HashMap, HashSet};
+
+use super::features::feature_flags;
+use super::ship_systems::resource_values;
+
+/// Daily, with politics enabled: hold an election when one is due, and
+/// have the council sit after it and once a week to set policy.
+pub fn tick_governance(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    let day = (sim_time / 24.0).floor();
+    if day == ((sim_time - delta_hours) / 24.0).floor() || !feature_flags(ctx).politics {
+        return;
+    }
+    let last = ctx
+        .db
+        .election()
+        .iter()
+        .map(|e| e.held_at)
+        .max_by(|a, b| a.total_cmp(b));
+    let elected = election_due(sim_time, last) && hold_election(ctx, sim_time);
+    let last_session = ctx.db.policy().id().find(0).map(|p| p.decided_at);
+    if elected || last_session.is_some_and(|at| sim_time - at >= SESSION_HOURS) {
+        hold_session(ctx, sim_time);
+    }
+}
+
+/// The policy in force: the council's, or the defaults with politics off
+/// or before the first session.
+pub fn current_policy(ctx: &ReducerContext) -> governance::Policy {
+    if !feature_flags(ctx).politics {
+        return governance::Policy::default();
+    }
+    ctx.db
+        .policy()
+        .id()
+        .find(0)
+        .map(|p| governance::Policy {
+            rationing: p.rationing,
+            work_hours: p.work_hours,
+            curfew: p.curfew,
+        })
+        .unwrap_or_default()
+}
+
+/// Put `policy` in force outside a council session, e.g. a concession to
+/// mutineers. It stands until the council next sits.
+pub fn amend_policy(ctx: &ReducerContext, policy: governance::Policy, sim_time: f64) {
+    let decided_at = ctx
+        .db
+        .policy()
+        .id()
+        .find(0)
+        .map_or(sim_time, |p| p.decided_at);
+    let row = Policy {
+        id: 0,
+        rationing: policy.rationing,
+        work_hours: policy.work_hours,
+        curfew: policy.curfew,
+        decided_at,
+    };
+    if ctx.db.policy().id().find(0).is_some() {
+        ctx.db.policy().id().update(row);
+    } else {
+        ctx.db.policy().insert(row);
+    }
+}
+
+/// Living councillors: the winners of the latest election.
+fn council(ctx: &ReducerContext) -> Vec<u64> {
+    let Some(latest) = ctx
+        .db
+        .election()
+        .iter()
+        .max_by(|a, b| a.held_at.total_cmp(&b.held_at).then(a.id.cmp(&b.id)))
+    else {
+        return Vec::new();
+    };
+    ctx.db
+        .candidate()
+        .iter()
+        .filter(|c| c.election_id == latest.id && c.elected)
+        .map(|c| c.person_id)
+        .filter(|&id| ctx.db.person().id().find(id).is_some_and(|p| p.is_alive))
+        .collect()
+}
+
+/// Hold a council election among the passengers: the most appealing
+/// stand, everyone votes and the top [`COUNCIL_SEATS`] win. Returns
+/// whether one was held (there have to be candidates).
+fn hold_election(ctx: &ReducerContext, sim_time: f64) -> bool {
+    let detained: HashSet<u64> = ctx.db.detention().iter().map(|d| d.person_id).collect();
+    let voters: Vec<u64> = ctx
+        .db
+        .passenger()
+        .iter()
+        .map(|p| p.person_id)
+        .filter(|id| !detained.contains(id))
+        .filter(|&id| {
+            ctx.db
+                .person()
+                .id()
+                .find(id)
+                .is_some_and(|p| p.is_alive && p.life_stage >= life_stages::ADULT)
+        })
+        .collect();
+
+    let mut friends: HashMap<u64, u32> = HashMap::new();
+    for r in ctx.db.relationship().iter().filter(|r| r.strength > 0.3) {
+        *friends.entry(r.person_a).or_default() += 1;
+        *friends.entry(r.person_b).or_default() += 1;
+    }
+    let mut standing: Vec<(u64, f32)> = voters
+        .iter()
+        .filter_map(|&id| {
+            let p = ctx.db.personality().person_id().find(id)?;
+            let appeal = candidacy(
+                p.extraversion,
+                p.conscientiousness,
+                friends.get(&id).copied().unwrap_or(0),
+            );
+            Some((id, appeal))
+        })
+        .collect();
+    standing.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+    standing.truncate(CANDIDATES);
+    if standing.is_empty() {
+        return false;
+    }
+
+    let running: HashSet<u64> = standing.iter().map(|&(id, _)| id).collect();
+    let mut ties: HashMap<(u64, u64), f32> = HashMap::new();
+    for r in ctx.db.relationship().iter() {
+        if running.contains(&r.person_a) || running.contains(&r.person_b) {
+            ties.insert((r.person_a, r.person_b), r.strength);
+            ties.insert((r.person_b, r.person_a), r.strength);
+        }
+    }
+    let faction = |id: u64| {
+        ctx.db
+            .faction_membership()
+            .person_id()
+            .find(id)
+            .map(|m| m.faction)
+    };
+    let factions: HashMap<u64, Option<u8>> =
+        standing.iter().map(|&(id, _)| (id, faction(id))).collect();
+    let incumbents: HashSet<u64> = council(ctx).into_iter().collect();
+
+    let mut votes: HashMap<u64, u32> = HashMap::new();
+    for &voter in &voters {
+        let choice = if running.contains(&voter) {
+            voter
+        } else {
+            let morale = ctx
+                .db
+                .needs()
+                .person_id()
+                .find(voter)
+                .map_or(0.5, |n| n.morale);
+            let own = faction(voter);
+            let score = |&(id, appeal): &(u64, f32)| {
+                vote_score(
+                    ties.get(&(voter, id)).copied().unwrap_or(0.0),
+                    own.is_some() && factions.get(&id).copied().flatten() == own,
+                    incumbents.contains(&id),
+                    morale,
+                    appeal,
+                )
+            };
+            let Some(best) = standing.iter().max_by(|a, b| score(a).total_cmp(&score(b))) else {
+                continue;
+            };
+            best.0
+        };
+        *votes.entry(choice).or_default() += 1;
+    }
+
+    let election = ctx.db.election().insert(Election {
+        id: 0,
+        held_at: sim_time,
+        turnout: voters.len() as u32,
+    });
+    let mut results: Vec<(u64, f32, u32)> = standing
+        .iter()
+        .map(|&(id, appeal)| (id, appeal, votes.get(&id).copied().unwrap_or(0)))
+        .collect();
+    results.sort_by(|a, b| b.2.cmp(&a.2).then(b.1.total_cmp(&a.1)));
+    for (rank, &(person_id, _, votes)) in results.iter().enumerate() {
+        ctx.db.candidate().insert(Candidate {
+            id: 0,
+            election_id: election.id,
+            person_id,
+            votes,
+            elected: rank < COUNCIL_SEATS,
+        });
+    }
+    log::info!(
+        "Election {}: {} voted, council seats to {:?}",
+        election.id,
+        voters.len(),
+        results
+            .iter()
+            .take(COUNCIL_SEATS)
+            .map(|r| r.0)
+            .collect::<Vec<_>>()
+    );
+    true
+}
+
+/// The council sits: weigh how the ship stands and set policy.
+fn hold_session(ctx: &ReducerContext, sim_time: f64) {
+    let members = council(ctx);
+    if members.is_empty() {
+        return;
+    }
+    let caution = members
+        .iter()
+        .map(|&id| {
+            ctx.db
+                .personality()
+                .person_id()
+                .find(id)
+                .map_or(0.5, |p| p.conscientiousness)
+        })
+        .sum::<f32>()
+        / members.len() as f32;
+    let food = ctx
+        .db
+        .ship_resources()
+        .id()
+        .find(0)
+        .map_or(1.0, |r| economy::compute_levels(&resource_values(&r)).food);
+    let morale: Vec<f32> = ctx
+        .db
+        .needs()
+        .iter()
+        .filter(|n| {
+            ctx.db
+                .person()
+                .id()
+                .find(n.person_id)
+                .is_some_and(|p| p.is_alive)
+        })
+        .map(|n| n.morale)
+        .collect();
+    let health: Vec<f32> = ctx.db.subsystem().iter().map(|s| s.health).collect();
+    let average = |values: &[f32]| {
+        if values.is_empty() {
+            1.0
+        } else {
+            values.iter().sum::<f32>() / values.len() as f32
+        }
+    };
+    let incidents = ctx
+        .db
+        .incident()
+        .iter()
+        .filter(|i| sim_time - i.occurred_at < SESSION_HOURS)
+        .count();
+    let brief = CouncilBrief {
+        food,
+        morale: average(&morale),
+        system_health: average(&health),
+        crime_rate: incidents as f32 * 100.0 / morale.len().max(1) as f32,
+        caution,
+    };
+
+    let policy = decide_policy(&brief);
+    let row = Policy {
+        id: 0,
+        rationing: policy.rationing,
+        work_hours: policy.work_hours,
+        curfew: policy.curfew,
+        decided_at: sim_time,
+    };
+    match ctx.db.policy().id().find(0) {
+        Some(old) => {
+            if (old.rationing, old.work_hours, old.curfew)
+                != (row.rationing, row.work_hours, row.curfew)
+            {
+                log::info!(
+                    "Council sets rationing {}, {}-hour watches, curfew {}",
+                    row.rationing,
+                    row.work_hours,
+                    if row.curfew { "on" } else { "off" }
+                );
+            }
+            ctx.db.policy().id().update(row);
+        }
+        None => {
+            ctx.db.policy().insert(row);
+        }
+    }
+}
@@ -0,0 +1,143 @@
+//! Hazard system - micrometeorites on course for the hull and what they
+//! do when they strike, and how often they and solar flares come at each
+//! stage of the voyage.
+
+use crate::tables::*;
+use progship_logic::hazards::{
+    hits_mount, impact_scale, impact_severity, impact_site, impact_struck, voyage_phase,
+    VoyagePhase, BREACH_SEVERITY, IMPACT_CHANCE_PER_HOUR, IMPACT_DAMAGE,
+};
+use progship_logic::sensors::IMPACT_LEAD_HOURS;
+use spacetimedb::{ReducerContext, Table};
+use std::collections::HashSet;
+
+use super::eva::{damage_hull, is_external};
+use super::evacuation::{evacuate, hazard_rooms, refresh_evacuation_routes};
+use super::features::feature_flags;
+use super::milestones::voyage_hours;
+use super::sensors::incoming;
+
+/// Where the voyage is at `sim_time`: departing, cruising or arriving.
+pub fn current_phase(ctx: &ReducerContext, sim_time: f64) -> VoyagePhase {
+    let voyage = ctx
+        .db
+        .ship_config()
+        .id()
+        .find(0)
+        .map_or(f64::MAX, |c| voyage_hours(c.seed));
+    voyage_phase(sim_time, voyage)
+}
+
+/// Roll for a micrometeorite each hour: one on course for a room against
+/// the hull becomes a contact for the sensors to spot, striking
+/// [`IMPACT_LEAD_HOURS`] later (see [`strike`]).
+pub fn roll_micrometeorites(ctx: &ReducerContext, sim_time: f64, delta_hours: f32) {
+    let hour = sim_time.floor();
+    if hour == (sim_time - delta_hours as f64).floor() || hour < 0.0 {
+        return;
+    }
+    let hour = hour as u64;
+    let chance = IMPACT_CHANCE_PER_HOUR * impact_scale(current_phase(ctx, sim_time));
+    if !impact_struck(hour, chance) {
+        return;
+    }
+    let mut hull_rooms: Vec<Room> = ctx
+        .db
+        .room()
+        .iter()
+        .filter(|r| r.hull_sides != 0 && r.room_type < room_types::CORRIDOR)
+        .collect();
+    hull_rooms.sort_by_key(|r| r.id);
+    let Some(room) = hull_rooms.get(impact_site(hour, hull_rooms.len())) else {
+        return;
+    };
+    incoming(
+        ctx,
+        contact_kinds::MICROMETEORITE,
+        impact_severity(hour),
+        Some(room.id),
+        sim_time + IMPACT_LEAD_HOURS as f64,
+    );
+}
+
+/// Clear the room a spotted micrometeorite heavy enough to hole the hull
+/// is headed for.
+pub(super) fn brace_for_impact(ctx: &ReducerContext, contact: &SensorContact) {
+    let Some(room_id) = contact.room_id else {
+        return;
+    };
+    if contact.severity < BREACH_SEVERITY {
+        return;
+    }
+    let evacuees = evacuate(ctx, &HashSet::from([room_id]));
+    log::info!(
+        "Bracing for impact: {} people cleared from room {}",
+        evacuees,
+        room_id
+    );
+}
+
+/// A micrometeorite arrives: it tears the plating by its room for an EVA
+/// crew to patch and damages the subsystems there (or the radiators and
+/// antenna mounted outside); a heavy one holes the hull and breaches the
+/// room.
+pub(super) fn strike(ctx: &ReducerContext, contact: &SensorContact, sim_time: f64) {
+    let Some(room) = contact.room_id.and_then(|id| ctx.db.room().id().find(id)) else {
+        return;
+    };
+    let hour = contact.arrives_at.max(0.0) as u64;
+    let severity = contact.severity;
+    log::warn!(
+        "Micrometeorite impact on deck {} by {} ({:.0}%)",
+        room.deck + 1,
+        room.name,
+        severity * 100.0
+    );
+    damage_hull(ctx, room.deck, room.x, room.y, severity, sim_time);
+
+    let struck: Vec<Subsystem> = if hits_mount(hour) {
+        let mut mounts: Vec<Subsystem> = ctx
+            .db
+            .subsystem()
+            .iter()
+            .filter(|s| is_external(s.subsystem_type))
+            .collect();
+        mounts.sort_by_key(|s| s.id);
+        let pick = impact_site(hour.wrapping_add(1), mounts.len());
+        mounts.into_iter().skip(pick).take(1).collect()
+    } else {
+        ctx.db
+            .subsystem()
+            .iter()
+            .filter(|s| s.node_id == room.node_id)
+            .collect()
+    };
+    for mut sub in struck {
+        sub.health = (sub.health - severity * IMPACT_DAMAGE).max(0.0);
+        if sub.health < 0.3 {
+            sub.status = system_statuses::OFFLINE;
+        } else if sub.health < 0.7 {
+            sub.status = system_statuses::DEGRADED;
+        }
+        log::info!("Micrometeorite damaged {} ({:.2})", sub.name, sub.health);
+        ctx.db.subsystem().id().update(sub);
+    }
+
+    if severity >= BREACH_SEVERITY && feature_flags(ctx).allows_event(event_types::HULL_BREACH) {
+        ctx.db.event().insert(Event {
+            id: 0,
+            event_type: event_types::HULL_BREACH,
+            room_id: room.id,
+            started_at: sim_time,
+            duration: 1.0 + severity * 2.0,
+            state: event_states::ACTIVE,
+            responders_needed: 3,
+            responders_assigned: 0,
+            severity,
+            escalated_to: None,
+        });
+        refresh_evacuation_routes(ctx);
+        let evacuees = evacuate(ctx, &hazard_rooms(ctx));
+        log::info!("Hull holed: evacuating {} people", evacuees);
+    }
+}
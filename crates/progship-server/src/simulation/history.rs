@@ -0,0 +1,114 @@
+//! History system - compacts finished rows into daily history.
+
+use crate::tables::*;
+use progship_logic::history::{
+    past_retention, sim_day, DayTally, COMPACTION_INTERVAL_HOURS, DEFAULT_RETENTION_DAYS,
+};
+use spacetimedb::{ReducerContext, Table};
+use std::collections::BTreeMap;
+
+/// Fold resolved events, ended conversations and closed work orders older
+/// than the retention window into DailyHistory and remove them, once per
+/// compaction interval.
+pub fn tick_history(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    let interval = |t: f64| (t / COMPACTION_INTERVAL_HOURS).floor();
+    if interval(sim_time) == interval(sim_time - delta_hours) {
+        return;
+    }
+    let retention_days = ctx
+        .db
+        .history_config()
+        .id()
+        .find(0)
+        .map(|c| c.retention_days)
+        .unwrap_or(DEFAULT_RETENTION_DAYS);
+
+    let mut days: BTreeMap<u32, DayTally> = BTreeMap::new();
+    let finished_events: Vec<Event> = ctx
+        .db
+        .event()
+        .iter()
+        .filter(|e| {
+            e.state == event_states::RESOLVED
+                && past_retention(e.started_at + e.duration as f64, sim_time, retention_days)
+        })
+        .collect();
+    for e in finished_events {
+        days.entry(sim_day(e.started_at))
+            .or_default()
+            .record_event(e.event_type, e.escalated_to.is_some());
+        ctx.db.event().id().delete(e.id);
+    }
+
+    let ended: Vec<Conversation> = ctx
+        .db
+        .conversation()
+        .iter()
+        .filter(|c| {
+            c.state == conversation_states::ENDED
+                && past_retention(c.started_at, sim_time, retention_days)
+        })
+        .collect();
+    for c in ended {
+        days.entry(sim_day(c.started_at))
+            .or_default()
+            .record_conversation(c.topic);
+        ctx.db.conversation().id().delete(c.id);
+    }
+
+    let closed: Vec<WorkOrder> = ctx
+        .db
+        .work_order()
+        .iter()
+        .filter(|o| {
+            matches!(
+                o.state,
+                work_order_states::DONE | work_order_states::CANCELLED
+            ) && o
+                .completed_at
+                .is_some_and(|t| past_retention(t, sim_time, retention_days))
+        })
+        .collect();
+    for o in closed {
+        let repair = matches!(o.kind, work_kinds::REPAIR | work_kinds::PIPE_REPAIR);
+        if o.state == work_order_states::DONE && repair {
+            days.entry(sim_day(o.created_at))
+                .or_default()
+                .record_repair(o.duration_hours);
+        }
+        ctx.db.work_order().id().delete(o.id);
+    }
+
+    for (day, tally) in days {
+        match ctx.db.daily_history().day().find(day) {
+            Some(row) => {
+                let mut merged = DayTally {
+                    events: row.events,
+                    escalated_events: row.escalated_events,
+                    conversations: row.conversations,
+                    repairs: row.repairs,
+                    repair_hours: row.repair_hours,
+                };
+                merged.merge(&tally);
+                ctx.db
+                    .daily_history()
+                    .day()
+                    .update(history_row(day, merged));
+            }
+            None => {
+                ctx.db.daily_history().insert(history_row(day, tally));
+            }
+        }
+    }
+}
+
+fn history_row(day: u32, tally: DayTally) -> DailyHistory {
+    DailyHistory {
+        day,
+        events: tally.events,
+        escalated_events: tally.escalated_events,
+        conversations: tally.conversations,
+        repairs: tally.repairs,
+        repair_hours: tally.repair_hours,
+    }
+}
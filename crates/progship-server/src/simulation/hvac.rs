@@ -0,0 +1,178 @@
+//! HVAC system - the air handlers in HVAC Control blowing air out along
+//! the ducts to every room, the cooling plant chilling it, and ducts that
+//! foul, fail and are cleaned off the work order board.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::tables::*;
+use progship_logic::hvac::{
+    airflow, failed, failure_chance, fouled, needs_cleaning, CLEANING_HOURS, CLEANING_PRIORITY,
+};
+use progship_logic::plumbing::bursts;
+use spacetimedb::{ReducerContext, Table};
+
+use super::atmosphere::calculate_life_support_efficiency;
+use super::thermal::coolant_chilling;
+
+/// Run the ducts for `delta_hours`: carry what the air handlers put out,
+/// foul with it, and roll failures of fouled ducts hourly, when cleaning
+/// that is due is also put on the work order board.
+pub fn tick_hvac(ctx: &ReducerContext, sim_time: f64, delta_hours: f32) {
+    let handlers = air_handlers(ctx);
+    let hourly = sim_time.floor() != (sim_time - delta_hours as f64).floor();
+    let ducts: Vec<AirDuct> = ctx.db.air_duct().iter().collect();
+    for mut duct in ducts {
+        let Some(mut edge) = ctx.db.infra_edge().id().find(duct.infra_edge_id) else {
+            continue;
+        };
+        if hourly && bursts(edge.id, sim_time, failure_chance(duct.fouling, 1.0)) {
+            edge.health = failed(edge.health);
+            log::warn!(
+                "HVAC duct {} failed, {:.0}% fouled, health now {:.2}",
+                edge.id,
+                duct.fouling * 100.0,
+                edge.health
+            );
+        }
+        duct.airflow = airflow(handlers, edge.health, duct.fouling);
+        duct.fouling = fouled(duct.fouling, duct.airflow, delta_hours);
+        edge.current_flow = edge.capacity * duct.airflow;
+        ctx.db.infra_edge().id().update(edge);
+        ctx.db.air_duct().infra_edge_id().update(duct);
+    }
+
+    if hourly {
+        file_cleaning(ctx, sim_time);
+    }
+}
+
+/// Share (0–1) of full output the subsystems of `subsystem_type` give,
+/// counting those in unpowered rooms as offline.
+pub(super) fn plant_output(ctx: &ReducerContext, subsystem_type: u8) -> f32 {
+    let unpowered: HashSet<u64> = ctx
+        .db
+        .room_power()
+        .iter()
+        .filter(|p| !p.powered)
+        .filter_map(|p| ctx.db.room().id().find(p.room_id))
+        .map(|r| r.node_id)
+        .collect();
+    let plant: Vec<Subsystem> = ctx
+        .db
+        .subsystem()
+        .iter()
+        .filter(|s| s.subsystem_type == subsystem_type)
+        .map(|mut s| {
+            if unpowered.contains(&s.node_id) {
+                s.status = system_statuses::OFFLINE;
+            }
+            s
+        })
+        .collect();
+    calculate_life_support_efficiency(&plant)
+}
+
+/// Share (0–1) of full airflow the air handlers put out.
+pub(super) fn air_handlers(ctx: &ReducerContext) -> f32 {
+    plant_output(ctx, subsystem_types::AIR_CIRCULATION)
+}
+
+/// Share (0–1) of full cooling the cooling plant's heat exchangers give the
+/// air handlers, as far as the coolant loop lets them.
+pub(super) fn air_cooling(ctx: &ReducerContext) -> f32 {
+    plant_output(ctx, subsystem_types::HEAT_EXCHANGE) * coolant_chilling(ctx)
+}
+
+/// Share of full airflow reaching the rooms of each graph node a duct
+/// serves, by node.
+pub(super) fn node_airflow(ctx: &ReducerContext) -> HashMap<u64, f32> {
+    ctx.db
+        .air_duct()
+        .iter()
+        .map(|d| (d.node_id, d.airflow))
+        .collect()
+}
+
+/// Whether duct `infra_edge_id` is due cleaning.
+pub(super) fn cleaning_due(ctx: &ReducerContext, infra_edge_id: u64) -> bool {
+    ctx.db
+        .air_duct()
+        .infra_edge_id()
+        .find(infra_edge_id)
+        .is_some_and(|d| needs_cleaning(d.fouling))
+}
+
+/// Duct `infra_edge_id` has been cleaned out.
+pub(super) fn clean_duct(ctx: &ReducerContext, infra_edge_id: u64) {
+    if let Some(mut duct) = ctx.db.air_duct().infra_edge_id().find(infra_edge_id) {
+        duct.fouling = 0.0;
+        ctx.db.air_duct().infra_edge_id().update(duct);
+    }
+}
+
+/// File a cleaning order for each duct due cleaning with none on the
+/// board, worked from the first room it serves.
+fn file_cleaning(ctx: &ReducerContext, sim_time: f64) {
+    let filed: HashSet<u64> = ctx
+        .db
+        .work_order()
+        .iter()
+        .filter(|o| {
+            o.kind == work_kinds::DUCT_CLEANING
+                && matches!(
+                    o.state,
+                    work_order_states::OPEN | work_order_states::CLAIMED
+                )
+        })
+        .filter_map(|o| o.infra_edge_id)
+        .collect();
+    let due: Vec<AirDuct> = ctx
+        .db
+        .air_duct()
+        .iter()
+        .filter(|d| needs_cleaning(d.fouling) && !filed.contains(&d.infra_edge_id))
+        .collect();
+    if due.is_empty() {
+        return;
+    }
+    let mut first_rooms: HashMap<u64, Room> = HashMap::new();
+    for room in ctx.db.room().iter() {
+        match first_rooms.get(&room.node_id) {
+            Some(first) if first.id < room.id => {}
+            _ => {
+                first_rooms.insert(room.node_id, room);
+            }
+        }
+    }
+    for duct in due {
+        let room = first_rooms.get(&duct.node_id);
+        ctx.db.work_order().insert(WorkOrder {
+            id: 0,
+            kind: work_kinds::DUCT_CLEANING,
+            state: work_order_states::OPEN,
+            reason: format!(
+                "HVAC duct to {} {:.0}% fouled",
+                room.map_or("a room", |r| r.name.as_str()),
+                duct.fouling * 100.0
+            ),
+            department: departments::ENGINEERING,
+            required_skill: skill_types::ENGINEERING,
+            required_parts: 0.0,
+            part_kind: part_kinds::MECHANICAL,
+            priority: CLEANING_PRIORITY,
+            room_id: room.map(|r| r.id),
+            subsystem_id: 0,
+            component_id: 0,
+            infra_edge_id: Some(duct.infra_edge_id),
+            event_id: None,
+            hull_damage_id: None,
+            assigned_crew_id: None,
+            assigned_robot_id: None,
+            progress: 0.0,
+            duration_hours: CLEANING_HOURS,
+            created_at: sim_time,
+            claimed_at: None,
+            completed_at: None,
+        });
+    }
+}
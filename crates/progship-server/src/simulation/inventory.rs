@@ -0,0 +1,194 @@
+//! Inventory system - what people carry and how it changes hands: packed
+//! rations, theft and its return, gifts between friends, belongings lost
+//! to fire and breaches and what players hand over.
+
+use crate::tables::*;
+use progship_logic::inventory::{
+    is_confiscated, shares_ration, steal_pick, KEEPSAKE_MORALE, LOSS_CHANCE, LOSS_SALT, MAX_ITEMS,
+};
+use progship_logic::numeric::roll;
+use spacetimedb::{ReducerContext, Table};
+
+/// Everything `person_id` carries.
+pub fn items_of(ctx: &ReducerContext, person_id: u64) -> Vec<InventoryItem> {
+    ctx.db
+        .inventory_item()
+        .iter()
+        .filter(|i| i.owner_id == person_id)
+        .collect()
+}
+
+/// Whether `person_id` carries an item of `kind`.
+pub fn carries(ctx: &ReducerContext, person_id: u64, kind: u8) -> bool {
+    ctx.db
+        .inventory_item()
+        .iter()
+        .any(|i| i.owner_id == person_id && i.kind == kind)
+}
+
+/// Give `person_id` a new item of `kind` unless their hands are full.
+pub fn add_item(ctx: &ReducerContext, person_id: u64, kind: u8, sim_time: f64) -> bool {
+    if items_of(ctx, person_id).len() >= MAX_ITEMS {
+        return false;
+    }
+    ctx.db.inventory_item().insert(InventoryItem {
+        id: 0,
+        owner_id: person_id,
+        kind,
+        acquired_at: sim_time,
+        stolen_from: None,
+    });
+    true
+}
+
+/// Eat a ration `person_id` carries; false if they have none.
+pub fn eat_ration(ctx: &ReducerContext, person_id: u64) -> bool {
+    let ration = ctx
+        .db
+        .inventory_item()
+        .iter()
+        .filter(|i| i.owner_id == person_id && i.kind == item_kinds::RATION)
+        .min_by_key(|i| i.id);
+    match ration {
+        Some(ration) => {
+            ctx.db.inventory_item().id().delete(ration.id);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Hand `item_id` from `giver_id` to `receiver_id` unless the receiver's
+/// hands are full. False when the giver does not carry it.
+pub fn give_item(
+    ctx: &ReducerContext,
+    item_id: u64,
+    giver_id: u64,
+    receiver_id: u64,
+    sim_time: f64,
+) -> bool {
+    let Some(item) = ctx.db.inventory_item().id().find(item_id) else {
+        return false;
+    };
+    if item.owner_id != giver_id {
+        return false;
+    }
+    hand_over(ctx, item, receiver_id, None, sim_time)
+}
+
+/// Have `thief_id` take the most valuable thing `victim_id` carries. The
+/// kind of item taken, if there was anything.
+pub fn steal(ctx: &ReducerContext, thief_id: u64, victim_id: u64, sim_time: f64) -> Option<u8> {
+    let mut items = items_of(ctx, victim_id);
+    items.sort_by_key(|i| i.id);
+    let kinds: Vec<u8> = items.iter().map(|i| i.kind).collect();
+    let item = items.get(steal_pick(&kinds)?)?.clone();
+    let kind = item.kind;
+    hand_over(ctx, item, thief_id, Some(victim_id), sim_time).then_some(kind)
+}
+
+/// Return what `person_id` stole to its owners and take away their
+/// contraband, as security does with those it detains.
+pub fn confiscate(ctx: &ReducerContext, person_id: u64, sim_time: f64) {
+    for item in items_of(ctx, person_id) {
+        if is_confiscated(item.kind) {
+            log::info!(
+                "Security confiscated {} from person {}",
+                progship_logic::constants::item_kinds::name(item.kind),
+                person_id
+            );
+            ctx.db.inventory_item().id().delete(item.id);
+        } else if let Some(owner_id) = item.stolen_from {
+            let alive = ctx
+                .db
+                .person()
+                .id()
+                .find(owner_id)
+                .is_some_and(|p| p.is_alive);
+            if alive {
+                hand_over(ctx, item, owner_id, None, sim_time);
+            }
+        }
+    }
+}
+
+/// After a conversation between friends, whoever is not hungry gives a
+/// ration to the other if they are. Whether a ration changed hands.
+pub fn share_rations(ctx: &ReducerContext, a: u64, b: u64, sim_time: f64) -> bool {
+    let hunger = |id: u64| ctx.db.needs().person_id().find(id).map(|n| n.hunger);
+    let (Some(hunger_a), Some(hunger_b)) = (hunger(a), hunger(b)) else {
+        return false;
+    };
+    let strength = ctx
+        .db
+        .relationship()
+        .iter()
+        .find(|r| (r.person_a == a && r.person_b == b) || (r.person_a == b && r.person_b == a))
+        .map_or(0.0, |r| r.strength);
+    let (giver, receiver) = if shares_ration(hunger_a, hunger_b, strength) {
+        (a, b)
+    } else if shares_ration(hunger_b, hunger_a, strength) {
+        (b, a)
+    } else {
+        return false;
+    };
+    let Some(ration) = items_of(ctx, giver)
+        .into_iter()
+        .filter(|i| i.kind == item_kinds::RATION)
+        .min_by_key(|i| i.id)
+    else {
+        return false;
+    };
+    let given = hand_over(ctx, ration, receiver, None, sim_time);
+    if given {
+        log::info!("Person {} gave person {} a ration", giver, receiver);
+    }
+    given
+}
+
+/// Destroy what each person in `room_id` carries with [`LOSS_CHANCE`],
+/// as a fire or breach there gets out of hand. Losing a keepsake costs
+/// morale.
+pub fn lose_belongings(ctx: &ReducerContext, room_id: u32, sim_time: f64) {
+    let present: Vec<u64> = ctx
+        .db
+        .position()
+        .iter()
+        .filter(|p| p.room_id == room_id)
+        .map(|p| p.person_id)
+        .collect();
+    for person_id in present {
+        for item in items_of(ctx, person_id) {
+            if roll(item.id, sim_time, LOSS_SALT) >= LOSS_CHANCE {
+                continue;
+            }
+            ctx.db.inventory_item().id().delete(item.id);
+            if item.kind != item_kinds::KEEPSAKE {
+                continue;
+            }
+            if let Some(mut needs) = ctx.db.needs().person_id().find(person_id) {
+                needs.morale = (needs.morale - KEEPSAKE_MORALE).max(0.0);
+                ctx.db.needs().person_id().update(needs);
+            }
+        }
+    }
+}
+
+/// Make `receiver_id` the owner of `item`, taken from `stolen_from` if it
+/// was, unless their hands are full.
+fn hand_over(
+    ctx: &ReducerContext,
+    mut item: InventoryItem,
+    receiver_id: u64,
+    stolen_from: Option<u64>,
+    sim_time: f64,
+) -> bool {
+    if items_of(ctx, receiver_id).len() >= MAX_ITEMS {
+        return false;
+    }
+    item.owner_id = receiver_id;
+    item.acquired_at = sim_time;
+    item.stolen_from = stolen_from;
+    ctx.db.inventory_item().id().update(item);
+    true
+}
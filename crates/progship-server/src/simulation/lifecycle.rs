@@ -0,0 +1,177 @@
+//! Lifecycle system - conception, pregnancy and births aboard.
+
+use crate::generation::spawn_newborn;
+use crate::tables::*;
+use progship_logic::lifecycle::{can_conceive, conceives, DELIVERY_ROOMS, PREGNANCY_HOURS};
+use spacetimedb::{ReducerContext, Table};
+
+use super::features::feature_flags;
+use super::stream::name_newborns;
+
+/// Hourly: babies who are due are delivered, and once a day couples at
+/// the head of a household may conceive. Nobody has children with
+/// generational mode off.
+pub fn tick_lifecycle(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    if sim_time.floor() == (sim_time - delta_hours).floor() || !feature_flags(ctx).generational {
+        return;
+    }
+    let due: Vec<Pregnancy> = ctx
+        .db
+        .pregnancy()
+        .iter()
+        .filter(|p| p.due_at <= sim_time)
+        .collect();
+    let mut born = 0;
+    for pregnancy in due {
+        born += u32::from(deliver(ctx, pregnancy, sim_time));
+    }
+    if born > 0 {
+        name_newborns(ctx, sim_time);
+    }
+
+    let day = (sim_time / 24.0).floor();
+    if day != ((sim_time - delta_hours) / 24.0).floor() && day >= 0.0 {
+        start_pregnancies(ctx, sim_time, day as u64);
+    }
+}
+
+/// Roll conception for every family whose head and partner are alive and
+/// [`can_conceive`], and is not already expecting.
+fn start_pregnancies(ctx: &ReducerContext, sim_time: f64, day: u64) {
+    let expecting: Vec<u64> = ctx.db.pregnancy().iter().map(|p| p.family_id).collect();
+    let members: Vec<FamilyMember> = ctx.db.family_member().iter().collect();
+    let living = |id: u64| ctx.db.person().id().find(id).filter(|p| p.is_alive);
+
+    for family in ctx.db.family().iter() {
+        if expecting.contains(&family.id) {
+            continue;
+        }
+        let household: Vec<&FamilyMember> = members
+            .iter()
+            .filter(|m| m.family_id == family.id)
+            .collect();
+        let parent = |role: u8| {
+            household
+                .iter()
+                .find(|m| m.role == role)
+                .and_then(|m| living(m.person_id))
+        };
+        let (Some(head), Some(partner)) =
+            (parent(family_roles::HEAD), parent(family_roles::PARTNER))
+        else {
+            continue;
+        };
+        let children: Vec<u8> = household
+            .iter()
+            .filter(|m| m.role == family_roles::CHILD)
+            .filter_map(|m| living(m.person_id))
+            .map(|p| p.age)
+            .collect();
+        if !can_conceive(
+            (head.age, partner.age),
+            children.len(),
+            children.iter().min().copied(),
+        ) {
+            continue;
+        }
+        let morale = [head.id, partner.id]
+            .iter()
+            .map(|&id| {
+                ctx.db
+                    .needs()
+                    .person_id()
+                    .find(id)
+                    .map_or(0.5, |n| n.morale)
+            })
+            .sum::<f32>()
+            / 2.0;
+        if !conceives(family.id, day, morale) {
+            continue;
+        }
+        // The younger partner carries the child
+        let (carrier, other) = if partner.age <= head.age {
+            (partner, head)
+        } else {
+            (head, partner)
+        };
+        log::info!(
+            "{} {} and {} {} are expecting",
+            carrier.given_name,
+            carrier.family_name,
+            other.given_name,
+            other.family_name
+        );
+        ctx.db.pregnancy().insert(Pregnancy {
+            person_id: carrier.id,
+            partner_id: other.id,
+            family_id: family.id,
+            conceived_at: sim_time,
+            due_at: sim_time + PREGNANCY_HOURS,
+        });
+    }
+}
+
+/// Deliver `pregnancy`'s baby in the best delivery room on the carrier's
+/// deck (or any deck), lifting the household's spirits. Returns whether a
+/// baby was born: none is if the carrier has died or the family is gone.
+fn deliver(ctx: &ReducerContext, pregnancy: Pregnancy, sim_time: f64) -> bool {
+    ctx.db.pregnancy().person_id().delete(pregnancy.person_id);
+    let carrier_alive = ctx
+        .db
+        .person()
+        .id()
+        .find(pregnancy.person_id)
+        .is_some_and(|p| p.is_alive);
+    let Some(family) = ctx
+        .db
+        .family()
+        .id()
+        .find(pregnancy.family_id)
+        .filter(|_| carrier_alive)
+    else {
+        return false;
+    };
+    let deck = ctx
+        .db
+        .position()
+        .person_id()
+        .find(pregnancy.person_id)
+        .and_then(|p| ctx.db.room().id().find(p.room_id))
+        .map(|r| r.deck);
+    let rooms: Vec<Room> = ctx
+        .db
+        .room()
+        .iter()
+        .filter(|r| DELIVERY_ROOMS.contains(&r.room_type))
+        .collect();
+    let room_id = rooms
+        .iter()
+        .min_by_key(|r| {
+            let rank = DELIVERY_ROOMS.iter().position(|&t| t == r.room_type);
+            (Some(r.deck) != deck, rank, r.id)
+        })
+        .map_or(family.home_room_id, |r| r.id);
+
+    let baby_id = spawn_newborn(
+        ctx,
+        &family,
+        (pregnancy.person_id, pregnancy.partner_id),
+        room_id,
+        sim_time,
+    );
+    log::info!("Baby {} born to the {} family", baby_id, family.family_name);
+    let household: Vec<u64> = ctx
+        .db
+        .family_member()
+        .iter()
+        .filter(|m| m.family_id == family.id && m.person_id != baby_id)
+        .map(|m| m.person_id)
+        .collect();
+    for id in household {
+        if let Some(mut needs) = ctx.db.needs().person_id().find(id) {
+            needs.morale = (needs.morale + 0.2).min(1.0);
+            ctx.db.needs().person_id().update(needs);
+        }
+    }
+    true
+}
@@ -0,0 +1,85 @@
+//! Lighting system - each deck's day, evening and night through the ship
+//! day, on a cycle command can shift.
+
+use crate::tables::*;
+use progship_logic::lighting::{deck_ambience, lighting_mode, shifted_hour, MAX_CYCLE_SHIFT};
+use spacetimedb::{ReducerContext, Table};
+
+/// Bring every deck's lights up to date with the hour, creating the rows
+/// for decks that have none yet. Rows are only rewritten when they change.
+pub fn tick_lighting(ctx: &ReducerContext, sim_time: f64) {
+    let hour = (sim_time % 24.0) as f32;
+    for deck in ctx.db.deck().iter() {
+        let lighting = ctx.db.lighting().deck().find(deck.deck);
+        let shift_hours = lighting.as_ref().map_or(0.0, |l| l.shift_hours);
+        let next = deck_lighting(&deck, shift_hours, hour);
+        match lighting {
+            Some(current) => {
+                let changed = current.mode != next.mode
+                    || current.kelvin != next.kelvin
+                    || (current.level - next.level).abs() > 0.001;
+                if changed {
+                    ctx.db.lighting().deck().update(next);
+                }
+            }
+            None => {
+                ctx.db.lighting().insert(next);
+            }
+        }
+    }
+}
+
+/// Shift `deck`'s cycle by `hours` (later when positive), at most
+/// `MAX_CYCLE_SHIFT` from the ship day either way. False if there is no
+/// such deck.
+pub fn shift_lighting(ctx: &ReducerContext, deck: i32, hours: f32, sim_time: f64) -> bool {
+    let Some(deck_row) = ctx.db.deck().deck().find(deck) else {
+        return false;
+    };
+    let current = ctx.db.lighting().deck().find(deck);
+    let shift_hours = current.as_ref().map_or(0.0, |l| l.shift_hours) + hours;
+    let shift_hours = if shift_hours.is_finite() {
+        shift_hours.clamp(-MAX_CYCLE_SHIFT, MAX_CYCLE_SHIFT)
+    } else {
+        0.0
+    };
+    let next = deck_lighting(&deck_row, shift_hours, (sim_time % 24.0) as f32);
+    log::info!(
+        "Deck {} lights shifted to {:+.1}h ({})",
+        deck,
+        shift_hours,
+        progship_logic::constants::lighting_modes::name(next.mode)
+    );
+    if current.is_some() {
+        ctx.db.lighting().deck().update(next);
+    } else {
+        ctx.db.lighting().insert(next);
+    }
+    true
+}
+
+/// Lighting mode on `deck` now, day until its lights are first set.
+pub fn deck_mode(ctx: &ReducerContext, deck: i32) -> u8 {
+    ctx.db
+        .lighting()
+        .deck()
+        .find(deck)
+        .map_or(lighting_modes::DAY, |l| l.mode)
+}
+
+fn deck_lighting(deck: &Deck, shift_hours: f32, hour: f32) -> Lighting {
+    let hour = shifted_hour(hour, shift_hours);
+    let ambience = deck_ambience(
+        deck.light_level,
+        deck.light_kelvin,
+        deck.light_schedule,
+        hour,
+    );
+    Lighting {
+        deck: deck.deck,
+        shift_hours,
+        mode: lighting_mode(deck.light_schedule, hour),
+        level: ambience.level,
+        kelvin: ambience.kelvin,
+    }
+}
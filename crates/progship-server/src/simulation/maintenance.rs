@@ -1,8 +1,41 @@
-//! Maintenance system - task creation, crew assignment, repair progress.
+//! Maintenance system - the work order board. Failing systems, leaking
+//! pipes and failing ducts, emergencies, damaged hull, workshops short of
+//! parts, cryo bays due a check, arboretums due tending, fouled ducts and
+//! broken-down robots file orders; robots take the routine ones first, and
+//! crew claim those they are fit for and work them on site until the job
+//! is done, going outside on EVA for what only the outside of the hull can
+//! reach.
+
+use std::collections::HashSet;
 
 use crate::tables::*;
+use progship_logic::cargo::REPAIR_PARTS_TONS;
+use progship_logic::eva::{can_go_outside, SUIT_O2_HOURS};
+use progship_logic::fabrication::stock_low;
+use progship_logic::inventory::tool_speed;
+use progship_logic::plumbing::LEAK_HEALTH;
+use progship_logic::skills::{repair_speed_multiplier, SkillCategory};
+use progship_logic::timeline::TimelineKind;
+use progship_logic::work_orders::{
+    board_order, can_claim, claim_score, claim_stalled, emergency_order, emergency_priority,
+    skill_department,
+};
 use spacetimedb::{ReducerContext, Table};
 
+use super::activities::restart_activity;
+use super::arboretum::{tend_garden, tending_due};
+use super::cryo::{check_sleepers, checks_due};
+use super::education::is_teacher;
+use super::eva::{crew_on_eva, exit_hatch, is_external, patch_hull, step_outside, EvaStep};
+use super::fabrication::{finish_fabrication, repair_part_kind, take_parts};
+use super::hvac::{clean_duct, cleaning_due};
+use super::inventory::carries;
+use super::morale::on_strike;
+use super::movement::start_movement_to;
+use super::quarantine::is_quarantined;
+use super::robots::{fix_robots, robots_broken, robots_claim};
+use super::timeline::record_timeline;
+
 /// Calculate task priority based on subsystem health (1.0 = max priority)
 pub fn calculate_task_priority(subsystem_health: f32) -> f32 {
     1.0 - subsystem_health
@@ -27,6 +60,11 @@ pub fn apply_repair(health: f32) -> f32 {
     (health + 0.3).min(1.0)
 }
 
+/// Apply a repair made without spare parts (patched, not fixed)
+pub fn apply_makeshift_repair(health: f32) -> f32 {
+    (health + 0.1).min(1.0)
+}
+
 /// Determine required skill for system type
 pub fn system_type_to_skill(system_type: u8) -> u8 {
     match system_type {
@@ -36,129 +74,645 @@ pub fn system_type_to_skill(system_type: u8) -> u8 {
     }
 }
 
-/// Check subsystems/components for maintenance needs, assign crew, progress repairs.
+/// Level (0.0–1.0) of the skill a task requires (see `skill_types`).
+pub fn skill_level(skills: &Skills, skill_type: u8) -> f32 {
+    match skill_type {
+        skill_types::MEDICAL => skills.medical,
+        skill_types::PILOTING => skills.piloting,
+        skill_types::SCIENCE => skills.science,
+        skill_types::SOCIAL => skills.social,
+        skill_types::COMBAT => skills.combat,
+        _ => skills.engineering,
+    }
+}
+
+/// Category of a skill type (see `skill_types`), which follow
+/// `SkillCategory::ALL` in order.
+pub fn skill_category(skill_type: u8) -> SkillCategory {
+    SkillCategory::ALL
+        .get(skill_type as usize)
+        .copied()
+        .unwrap_or(SkillCategory::Engineering)
+}
+
+/// Skill type (see `skill_types`) of a skill category.
+pub fn skill_type(category: SkillCategory) -> u8 {
+    SkillCategory::ALL
+        .iter()
+        .position(|&c| c == category)
+        .map_or(skill_types::ENGINEERING, |i| i as u8)
+}
+
+/// Crew holding a claim on a work order, who are busy with it.
+pub fn crew_on_work_orders(ctx: &ReducerContext) -> HashSet<u64> {
+    ctx.db
+        .work_order()
+        .iter()
+        .filter(|o| o.state == work_order_states::CLAIMED)
+        .filter_map(|o| o.assigned_crew_id)
+        .collect()
+}
+
+/// File work orders for what needs it, close those no longer needed, put
+/// abandoned claims back on the board, hand open orders to robots and crew
+/// and progress the work of crew on site.
 pub fn tick_maintenance(ctx: &ReducerContext, sim_time: f64, delta_hours: f32) {
-    // Generate tasks for degraded subsystems
+    file_orders(ctx, sim_time);
+    close_orders(ctx, sim_time);
+    let released = release_claims(ctx, sim_time);
+    robots_claim(ctx, sim_time);
+    claim_orders(ctx, sim_time, &released);
+    progress_orders(ctx, sim_time, delta_hours);
+}
+
+/// Whether an order is still on the board, open or claimed.
+fn is_live(order: &WorkOrder) -> bool {
+    matches!(
+        order.state,
+        work_order_states::OPEN | work_order_states::CLAIMED
+    )
+}
+
+/// File an order for each degraded subsystem, leaking water pipe, failing
+/// HVAC duct, emergency and hull damage that has none. Subsystems mounted outside the
+/// hull are repaired on EVA from the nearest airlock.
+fn file_orders(ctx: &ReducerContext, sim_time: f64) {
+    let live: Vec<WorkOrder> = ctx.db.work_order().iter().filter(is_live).collect();
+
     for sub in ctx.db.subsystem().iter() {
-        if sub.health < 0.7 {
-            let has_task = ctx
-                .db
-                .maintenance_task()
+        if sub.health >= 0.7 || live.iter().any(|o| o.subsystem_id == sub.id) {
+            continue;
+        }
+        // The parent system type determines the required skill
+        let skill = ctx
+            .db
+            .ship_system()
+            .id()
+            .find(sub.system_id)
+            .map(|sys| system_type_to_skill(sys.system_type))
+            .unwrap_or(skill_types::ENGINEERING);
+        // Target a degraded component within this subsystem
+        let component_id = ctx
+            .db
+            .system_component()
+            .iter()
+            .find(|c| c.subsystem_id == sub.id && c.health < 0.7)
+            .map_or(0, |c| c.id);
+        let room = ctx.db.room().iter().find(|r| r.node_id == sub.node_id);
+        let hatch = room
+            .as_ref()
+            .filter(|_| is_external(sub.subsystem_type))
+            .and_then(|r| exit_hatch(ctx, (r.deck, r.x, r.y)));
+        let (kind, room_id) = match hatch {
+            Some(hatch) => (work_kinds::EVA_REPAIR, Some(hatch.room_id)),
+            None => (work_kinds::REPAIR, room.map(|r| r.id)),
+        };
+        ctx.db.work_order().insert(WorkOrder {
+            id: 0,
+            kind,
+            state: work_order_states::OPEN,
+            reason: format!("{} at {:.0}%", sub.name, sub.health * 100.0),
+            department: skill_department(skill_category(skill)),
+            required_skill: skill,
+            required_parts: REPAIR_PARTS_TONS,
+            part_kind: repair_part_kind(ctx, sub.id, component_id),
+            priority: calculate_task_priority(sub.health),
+            room_id,
+            subsystem_id: sub.id,
+            component_id,
+            infra_edge_id: None,
+            event_id: None,
+            hull_damage_id: None,
+            assigned_crew_id: None,
+            assigned_robot_id: None,
+            progress: 0.0,
+            duration_hours: calculate_task_duration(sub.health),
+            created_at: sim_time,
+            claimed_at: None,
+            completed_at: None,
+        });
+    }
+
+    for pipe in ctx.db.infra_edge().iter() {
+        let what = match pipe.edge_type {
+            infra_types::WATER_PIPE => "Water pipe leaking",
+            infra_types::HVAC_DUCT => "HVAC duct failing",
+            _ => continue,
+        };
+        if pipe.health >= LEAK_HEALTH
+            || live
                 .iter()
-                .any(|t| t.subsystem_id == sub.id && t.progress < 1.0);
-            if has_task {
-                continue;
-            }
+                .any(|o| o.kind == work_kinds::PIPE_REPAIR && o.infra_edge_id == Some(pipe.id))
+        {
+            continue;
+        }
+        ctx.db.work_order().insert(WorkOrder {
+            id: 0,
+            kind: work_kinds::PIPE_REPAIR,
+            state: work_order_states::OPEN,
+            reason: format!("{} at {:.0}%", what, pipe.health * 100.0),
+            department: departments::ENGINEERING,
+            required_skill: skill_types::ENGINEERING,
+            required_parts: REPAIR_PARTS_TONS,
+            part_kind: part_kinds::SEALS,
+            priority: calculate_task_priority(pipe.health),
+            room_id: None,
+            subsystem_id: 0,
+            component_id: 0,
+            infra_edge_id: Some(pipe.id),
+            event_id: None,
+            hull_damage_id: None,
+            assigned_crew_id: None,
+            assigned_robot_id: None,
+            progress: 0.0,
+            duration_hours: calculate_task_duration(pipe.health),
+            created_at: sim_time,
+            claimed_at: None,
+            completed_at: None,
+        });
+    }
 
-            // Find the parent system type to determine required skill
-            let skill = ctx
-                .db
-                .ship_system()
-                .id()
-                .find(sub.system_id)
-                .map(|sys| system_type_to_skill(sys.system_type))
-                .unwrap_or(skill_types::ENGINEERING);
+    let filed: HashSet<u64> = ctx
+        .db
+        .work_order()
+        .iter()
+        .filter_map(|o| o.event_id)
+        .collect();
+    for event in ctx.db.event().iter() {
+        if event.state == event_states::RESOLVED || filed.contains(&event.id) {
+            continue;
+        }
+        let Some((skill, parts, part_kind)) = emergency_order(event.event_type) else {
+            continue;
+        };
+        let place = ctx
+            .db
+            .room()
+            .id()
+            .find(event.room_id)
+            .map_or_else(|| "the ship".to_string(), |r| r.name);
+        ctx.db.work_order().insert(WorkOrder {
+            id: 0,
+            kind: work_kinds::EMERGENCY,
+            state: work_order_states::OPEN,
+            reason: format!(
+                "{} in {}",
+                progship_logic::constants::event_types::name(event.event_type),
+                place
+            ),
+            department: skill_department(skill),
+            required_skill: skill_type(skill),
+            required_parts: parts,
+            part_kind,
+            priority: emergency_priority(event.severity),
+            room_id: Some(event.room_id),
+            subsystem_id: 0,
+            component_id: 0,
+            infra_edge_id: None,
+            event_id: Some(event.id),
+            hull_damage_id: None,
+            assigned_crew_id: None,
+            assigned_robot_id: None,
+            progress: 0.0,
+            duration_hours: (event.duration * 0.5).max(0.5),
+            created_at: sim_time,
+            claimed_at: None,
+            completed_at: None,
+        });
+    }
 
-            // Find a degraded component within this subsystem to target
-            let target_comp = ctx
-                .db
-                .system_component()
-                .iter()
-                .find(|c| c.subsystem_id == sub.id && c.health < 0.7);
-            let comp_id = target_comp.map(|c| c.id).unwrap_or(0);
-
-            let priority = calculate_task_priority(sub.health);
-            let duration = calculate_task_duration(sub.health);
-
-            ctx.db.maintenance_task().insert(MaintenanceTask {
-                id: 0,
-                component_id: comp_id,
-                subsystem_id: sub.id,
-                assigned_crew_id: None,
-                priority,
-                progress: 0.0,
-                created_at: sim_time,
-                required_skill: skill,
-                duration_hours: duration,
+    for damage in ctx.db.hull_damage().iter() {
+        if live.iter().any(|o| o.hull_damage_id == Some(damage.id)) {
+            continue;
+        }
+        let Some(hatch) = exit_hatch(ctx, (damage.deck, damage.x, damage.y)) else {
+            continue;
+        };
+        ctx.db.work_order().insert(WorkOrder {
+            id: 0,
+            kind: work_kinds::EVA_REPAIR,
+            state: work_order_states::OPEN,
+            reason: format!(
+                "Hull damage on deck {} ({:.0}%)",
+                damage.deck + 1,
+                damage.severity * 100.0
+            ),
+            department: departments::ENGINEERING,
+            required_skill: skill_types::ENGINEERING,
+            required_parts: REPAIR_PARTS_TONS,
+            part_kind: part_kinds::SEALS,
+            priority: damage.severity,
+            room_id: Some(hatch.room_id),
+            subsystem_id: 0,
+            component_id: 0,
+            infra_edge_id: None,
+            event_id: None,
+            hull_damage_id: Some(damage.id),
+            assigned_crew_id: None,
+            assigned_robot_id: None,
+            progress: 0.0,
+            duration_hours: 1.0 + 2.0 * damage.severity,
+            created_at: sim_time,
+            claimed_at: None,
+            completed_at: None,
+        });
+    }
+}
+
+/// Close orders whose need went away: emergencies that are over (done if
+/// anyone got to work on them), and repairs, hull patches, fabrication,
+/// cryo checks, gardening, duct cleaning and robot repairs not yet started
+/// on targets that are gone or recovered by other hands.
+fn close_orders(ctx: &ReducerContext, sim_time: f64) {
+    let live: Vec<WorkOrder> = ctx.db.work_order().iter().filter(is_live).collect();
+    for mut order in live {
+        let state = match order.kind {
+            work_kinds::EMERGENCY => {
+                let over = order
+                    .event_id
+                    .and_then(|id| ctx.db.event().id().find(id))
+                    .is_none_or(|e| e.state == event_states::RESOLVED);
+                if !over {
+                    continue;
+                }
+                if order.progress > 0.0 {
+                    work_order_states::DONE
+                } else {
+                    work_order_states::CANCELLED
+                }
+            }
+            work_kinds::PIPE_REPAIR => {
+                let recovered = order
+                    .infra_edge_id
+                    .and_then(|id| ctx.db.infra_edge().id().find(id))
+                    .is_none_or(|p| p.health >= LEAK_HEALTH);
+                if !recovered || order.progress > 0.0 {
+                    continue;
+                }
+                work_order_states::CANCELLED
+            }
+            work_kinds::EVA_REPAIR if order.hull_damage_id.is_some() => {
+                let patched = order
+                    .hull_damage_id
+                    .and_then(|id| ctx.db.hull_damage().id().find(id))
+                    .is_none();
+                if !patched || order.progress > 0.0 {
+                    continue;
+                }
+                work_order_states::CANCELLED
+            }
+            work_kinds::CRYO_CHECK => {
+                let due = order
+                    .room_id
+                    .is_some_and(|room_id| checks_due(ctx, room_id, sim_time));
+                if due || order.progress > 0.0 {
+                    continue;
+                }
+                work_order_states::CANCELLED
+            }
+            work_kinds::GARDENING => {
+                let due = order
+                    .room_id
+                    .is_some_and(|room_id| tending_due(ctx, room_id, sim_time));
+                if due || order.progress > 0.0 {
+                    continue;
+                }
+                work_order_states::CANCELLED
+            }
+            work_kinds::DUCT_CLEANING => {
+                let due = order.infra_edge_id.is_some_and(|id| cleaning_due(ctx, id));
+                if due || order.progress > 0.0 {
+                    continue;
+                }
+                work_order_states::CANCELLED
+            }
+            work_kinds::ROBOT_REPAIR => {
+                let broken = order
+                    .room_id
+                    .is_some_and(|room_id| robots_broken(ctx, room_id));
+                if broken || order.progress > 0.0 {
+                    continue;
+                }
+                work_order_states::CANCELLED
+            }
+            work_kinds::FABRICATION => {
+                let stocked = ctx
+                    .db
+                    .part_stock()
+                    .kind()
+                    .find(order.part_kind)
+                    .is_none_or(|p| !stock_low(p.mass_tons, p.launch_tons));
+                if !stocked || order.progress > 0.0 {
+                    continue;
+                }
+                work_order_states::CANCELLED
+            }
+            _ => {
+                let recovered = ctx
+                    .db
+                    .subsystem()
+                    .id()
+                    .find(order.subsystem_id)
+                    .is_none_or(|s| s.health >= 0.7);
+                if !recovered || order.progress > 0.0 {
+                    continue;
+                }
+                work_order_states::CANCELLED
+            }
+        };
+        order.state = state;
+        order.completed_at = Some(sim_time);
+        ctx.db.work_order().id().update(order);
+    }
+}
+
+/// Put claimed orders back on the board when the crew member holding them
+/// died, was called away or has got nowhere with them. Returns who was
+/// released, so the same hands are not given them straight back.
+fn release_claims(ctx: &ReducerContext, sim_time: f64) -> HashSet<u64> {
+    let mut released = HashSet::new();
+    let claimed: Vec<WorkOrder> = ctx
+        .db
+        .work_order()
+        .iter()
+        .filter(|o| o.state == work_order_states::CLAIMED)
+        .collect();
+    for mut order in claimed {
+        let Some(crew_id) = order.assigned_crew_id else {
+            continue;
+        };
+        let alive = ctx
+            .db
+            .person()
+            .id()
+            .find(crew_id)
+            .is_some_and(|p| p.is_alive);
+        let at_work = ctx
+            .db
+            .activity()
+            .person_id()
+            .find(crew_id)
+            .is_some_and(|a| {
+                a.activity_type == activity_types::MAINTENANCE && a.target_room_id == order.room_id
             });
+        let stalled = order
+            .claimed_at
+            .is_some_and(|t| claim_stalled(sim_time - t, order.progress, order.duration_hours));
+        if alive && at_work && !stalled {
+            continue;
         }
+        order.state = work_order_states::OPEN;
+        order.assigned_crew_id = None;
+        order.claimed_at = None;
+        ctx.db.work_order().id().update(order);
+        released.insert(crew_id);
     }
+    released
+}
 
-    // Assign unassigned tasks to available crew
-    let tasks: Vec<MaintenanceTask> = ctx
+/// Hand each open order, most urgent first, to the best placed crew
+/// member fit for it who is free: on watch, or for an emergency anyone.
+/// Only the fit and trained go outside on EVA.
+fn claim_orders(ctx: &ReducerContext, sim_time: f64, released: &HashSet<u64>) {
+    let mut open: Vec<WorkOrder> = ctx
+        .db
+        .work_order()
+        .iter()
+        .filter(|o| o.state == work_order_states::OPEN)
+        .collect();
+    if open.is_empty() {
+        return;
+    }
+    open.sort_by(|a, b| board_order((a.priority, a.created_at), (b.priority, b.created_at)));
+
+    let mut busy = crew_on_work_orders(ctx);
+    busy.extend(released);
+    busy.extend(crew_on_eva(ctx));
+    busy.extend(ctx.db.room_effect().iter().filter_map(|e| e.cleaner_id));
+    busy.extend(ctx.db.order_assignment().iter().map(|o| o.person_id));
+    let deck_of = |room_id: u32| ctx.db.room().id().find(room_id).map(|r| r.deck);
+    let crew: Vec<(Crew, Skills, Option<i32>)> = ctx
         .db
-        .maintenance_task()
+        .crew()
         .iter()
-        .filter(|t| t.assigned_crew_id.is_none() && t.progress < 1.0)
+        .filter(|c| {
+            ctx.db
+                .person()
+                .id()
+                .find(c.person_id)
+                .is_some_and(|p| p.is_alive && !p.is_player)
+        })
+        .filter(|c| !is_teacher(ctx, c.person_id))
+        .filter(|c| ctx.db.detention().person_id().find(c.person_id).is_none())
+        .filter(|c| !is_quarantined(ctx, c.person_id))
+        .filter(|c| !on_strike(ctx, c.department))
+        .filter_map(|c| {
+            let skills = ctx.db.skills().person_id().find(c.person_id)?;
+            let deck = ctx
+                .db
+                .position()
+                .person_id()
+                .find(c.person_id)
+                .and_then(|p| deck_of(p.room_id));
+            Some((c, skills, deck))
+        })
         .collect();
 
-    for task in tasks {
-        let assigned = ctx
-            .db
-            .crew()
+    for mut order in open {
+        let deck = order.room_id.and_then(deck_of);
+        let claimant = crew
             .iter()
-            .find(|c| !c.on_duty)
-            .map(|c| c.person_id);
-
-        if let Some(crew_id) = assigned {
-            let duration_hours = task.duration_hours;
-            let mut t = task;
-            t.assigned_crew_id = Some(crew_id);
-            ctx.db.maintenance_task().id().update(t);
-
-            if let Some(mut act) = ctx.db.activity().person_id().find(crew_id) {
-                act.activity_type = activity_types::MAINTENANCE;
-                act.started_at = sim_time;
-                act.duration = duration_hours;
-                ctx.db.activity().person_id().update(act);
-            }
+            .filter(|(c, _, _)| !busy.contains(&c.person_id))
+            .filter(|(c, _, _)| c.on_duty || order.kind == work_kinds::EMERGENCY)
+            .filter_map(|(c, skills, crew_deck)| {
+                let level = skill_level(skills, order.required_skill);
+                if !can_claim(c.department, order.department, level) {
+                    return None;
+                }
+                let fit_outside = || {
+                    ctx.db
+                        .needs()
+                        .person_id()
+                        .find(c.person_id)
+                        .is_some_and(|n| can_go_outside(level, n.health))
+                };
+                if order.kind == work_kinds::EVA_REPAIR && !fit_outside() {
+                    return None;
+                }
+                let same_deck = deck.is_some() && *crew_deck == deck;
+                let score = claim_score(
+                    level,
+                    c.department == order.department,
+                    c.on_duty,
+                    same_deck,
+                );
+                Some((score, c.person_id))
+            })
+            .max_by(|a, b| a.0.total_cmp(&b.0).then(b.1.cmp(&a.1)))
+            .map(|(_, id)| id);
+        let Some(crew_id) = claimant else {
+            continue;
+        };
+        let Some(mut activity) = ctx.db.activity().person_id().find(crew_id) else {
+            continue;
+        };
+        // Long enough to get there and see the rest of the job through, or
+        // a suit's worth of oxygen outside
+        let hours = if order.kind == work_kinds::EVA_REPAIR {
+            SUIT_O2_HOURS + 1.0
+        } else {
+            order.duration_hours * (1.0 - order.progress) + 1.0
+        };
+        restart_activity(&mut activity, activity_types::MAINTENANCE, sim_time, hours);
+        activity.target_room_id = order.room_id;
+        ctx.db.activity().person_id().update(activity);
+        if let Some(room_id) = order.room_id {
+            start_movement_to(ctx, crew_id, room_id);
         }
+        record_timeline(
+            ctx,
+            crew_id,
+            TimelineKind::Activity,
+            activity_types::MAINTENANCE as u32,
+            sim_time,
+        );
+        busy.insert(crew_id);
+        order.state = work_order_states::CLAIMED;
+        order.assigned_crew_id = Some(crew_id);
+        order.claimed_at = Some(sim_time);
+        ctx.db.work_order().id().update(order);
     }
+}
 
-    // Progress active repairs
-    let active_tasks: Vec<MaintenanceTask> = ctx
+/// Progress claimed orders whose crew member is on site, taking charge of
+/// an emergency on arrival and taking EVA crew out from the airlock, and
+/// finish those that are done.
+fn progress_orders(ctx: &ReducerContext, sim_time: f64, delta_hours: f32) {
+    let claimed: Vec<WorkOrder> = ctx
         .db
-        .maintenance_task()
+        .work_order()
         .iter()
-        .filter(|t| t.assigned_crew_id.is_some() && t.progress < 1.0)
+        .filter(|o| o.state == work_order_states::CLAIMED)
         .collect();
-
-    for task in active_tasks {
-        let mut t = task;
-        t.progress = calculate_repair_progress(t.progress, delta_hours, t.duration_hours);
-
-        if t.progress >= 1.0 {
-            // Repair complete - restore component and subsystem health
-            if t.component_id > 0 {
-                if let Some(mut comp) = ctx.db.system_component().id().find(t.component_id) {
-                    comp.health = apply_repair(comp.health);
-                    comp.status = if comp.health > 0.7 {
-                        system_statuses::NOMINAL
-                    } else {
-                        system_statuses::DEGRADED
-                    };
-                    comp.last_maintenance = ctx
-                        .db
-                        .ship_config()
-                        .id()
-                        .find(0)
-                        .map(|c| c.sim_time)
-                        .unwrap_or(0.0);
-                    ctx.db.system_component().id().update(comp);
+    for mut order in claimed {
+        let Some(crew_id) = order.assigned_crew_id else {
+            continue;
+        };
+        let Some(pos) = ctx.db.position().person_id().find(crew_id) else {
+            continue;
+        };
+        if order.room_id.is_some_and(|r| r != pos.room_id) {
+            continue; // Still on the way
+        }
+        // EVA crew work only once they are out at the site
+        let hours = if order.kind == work_kinds::EVA_REPAIR {
+            match step_outside(ctx, &order, crew_id, sim_time, delta_hours) {
+                EvaStep::Work(hours) => hours,
+                EvaStep::Abort => {
+                    order.state = work_order_states::OPEN;
+                    order.assigned_crew_id = None;
+                    order.claimed_at = None;
+                    ctx.db.work_order().id().update(order);
+                    continue;
                 }
             }
-            if let Some(mut sub) = ctx.db.subsystem().id().find(t.subsystem_id) {
-                sub.health = apply_repair(sub.health);
-                sub.status = if sub.health > 0.7 {
-                    system_statuses::NOMINAL
-                } else {
-                    system_statuses::DEGRADED
-                };
-                ctx.db.subsystem().id().update(sub);
+        } else {
+            delta_hours
+        };
+        if let Some(mut event) = order.event_id.and_then(|id| ctx.db.event().id().find(id)) {
+            if event.state == event_states::ACTIVE {
+                event.state = event_states::BEING_HANDLED;
+                event.responders_assigned = event.responders_assigned.max(1);
+                ctx.db.event().id().update(event);
+            }
+        }
+        // Skilled hands work faster, engineers slower without their tools
+        let speed = ctx
+            .db
+            .skills()
+            .person_id()
+            .find(crew_id)
+            .map(|s| repair_speed_multiplier(skill_level(&s, order.required_skill)))
+            .unwrap_or(1.0)
+            * tool_speed(
+                skill_category(order.required_skill),
+                carries(ctx, crew_id, item_kinds::TOOLKIT),
+            );
+        order.progress =
+            calculate_repair_progress(order.progress, hours * speed, order.duration_hours);
+        if order.progress >= 1.0 {
+            let deck = ctx.db.room().id().find(pos.room_id).map(|r| r.deck);
+            if order.kind == work_kinds::FABRICATION {
+                finish_fabrication(ctx, &order, deck);
+            } else if order.kind == work_kinds::CRYO_CHECK {
+                check_sleepers(ctx, pos.room_id, sim_time);
+            } else if order.kind == work_kinds::GARDENING {
+                tend_garden(ctx, pos.room_id, sim_time);
+            } else if order.kind == work_kinds::DUCT_CLEANING {
+                if let Some(duct) = order.infra_edge_id {
+                    clean_duct(ctx, duct);
+                }
+            } else if order.kind == work_kinds::ROBOT_REPAIR {
+                fix_robots(ctx, &order, deck);
+            } else {
+                finish_repair(ctx, &order, deck, sim_time);
             }
+            order.state = work_order_states::DONE;
+            order.completed_at = Some(sim_time);
         }
+        ctx.db.work_order().id().update(order);
+    }
+}
 
-        ctx.db.maintenance_task().id().update(t);
+/// Fit the kind of parts a finished order needs from the nearest stores,
+/// patching up without them when short, and restore the health of what it
+/// was for.
+pub(super) fn finish_repair(
+    ctx: &ReducerContext,
+    order: &WorkOrder,
+    deck: Option<i32>,
+    sim_time: f64,
+) {
+    if order.kind == work_kinds::EMERGENCY && order.required_parts <= 0.0 {
+        return;
+    }
+    let parts = take_parts(ctx, order.part_kind, order.required_parts, deck);
+    let with_parts = parts >= order.required_parts;
+    let repair = if with_parts {
+        apply_repair
+    } else {
+        apply_makeshift_repair
+    };
+    if let Some(damage_id) = order.hull_damage_id {
+        patch_hull(ctx, damage_id, with_parts);
+    }
+    if order.component_id > 0 {
+        if let Some(mut comp) = ctx.db.system_component().id().find(order.component_id) {
+            comp.health = repair(comp.health);
+            comp.status = if comp.health > 0.7 {
+                system_statuses::NOMINAL
+            } else {
+                system_statuses::DEGRADED
+            };
+            comp.last_maintenance = sim_time;
+            ctx.db.system_component().id().update(comp);
+        }
+    }
+    if let Some(mut pipe) = order
+        .infra_edge_id
+        .and_then(|id| ctx.db.infra_edge().id().find(id))
+    {
+        pipe.health = repair(pipe.health);
+        ctx.db.infra_edge().id().update(pipe);
+    }
+    if let Some(mut sub) = ctx.db.subsystem().id().find(order.subsystem_id) {
+        sub.health = repair(sub.health);
+        sub.status = if sub.health > 0.7 {
+            system_statuses::NOMINAL
+        } else {
+            system_statuses::DEGRADED
+        };
+        ctx.db.subsystem().id().update(sub);
     }
 }
 
@@ -202,6 +756,22 @@ mod tests {
         assert!((duration - 2.4).abs() < 0.001); // 2.0 + 0.1 * 4.0
     }
 
+    #[test]
+    fn test_skill_level_matches_required_skill() {
+        let skills = Skills {
+            person_id: 1,
+            engineering: 0.7,
+            medical: 0.2,
+            piloting: 0.4,
+            science: 0.3,
+            social: 0.5,
+            combat: 0.1,
+        };
+        assert_eq!(skill_level(&skills, skill_types::ENGINEERING), 0.7);
+        assert_eq!(skill_level(&skills, skill_types::MEDICAL), 0.2);
+        assert_eq!(skill_level(&skills, skill_types::PILOTING), 0.4);
+    }
+
     #[test]
     fn test_calculate_repair_progress_partial() {
         let progress = calculate_repair_progress(0.0, 1.0, 4.0);
@@ -230,6 +800,23 @@ mod tests {
         assert_eq!(apply_repair(1.0), 1.0);
     }
 
+    #[test]
+    fn test_makeshift_repair_is_weaker() {
+        assert!((apply_makeshift_repair(0.5) - 0.6).abs() < 0.001);
+        assert!(apply_makeshift_repair(0.5) < apply_repair(0.5));
+        assert_eq!(apply_makeshift_repair(0.95), 1.0);
+    }
+
+    #[test]
+    fn test_skill_type_round_trip() {
+        assert_eq!(skill_category(skill_types::MEDICAL), SkillCategory::Medical);
+        assert_eq!(skill_category(skill_types::COMBAT), SkillCategory::Combat);
+        assert_eq!(skill_category(99), SkillCategory::Engineering);
+        for skill in 0..6 {
+            assert_eq!(skill_type(skill_category(skill)), skill);
+        }
+    }
+
     #[test]
     fn test_system_type_to_skill() {
         assert_eq!(
@@ -0,0 +1,349 @@
+//! Mental health system - stress built up from distressing events, grief,
+//! isolation and overwork, counseling sessions in the Mental Health rooms
+//! and the breakdowns of those pushed too far.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::tables::*;
+use progship_logic::mental_health::{
+    breakdown_chance, counseling_relief, is_counseling_room, is_distressing, is_isolated,
+    is_overworked, lashes_out, stress_change, StressInput, AFTER_BREAKDOWN_STRESS, BREAKDOWN_HOURS,
+    BREAKDOWN_SALT, CLIENTS_PER_COUNSELOR, COUNSELING_STRESS, MIN_COUNSELOR_SKILL, SESSION_HOURS,
+};
+use progship_logic::numeric::roll;
+use spacetimedb::{ReducerContext, Table};
+
+use super::drop_everything;
+use super::education::is_teacher;
+use super::morale::on_strike;
+use super::quarantine::is_quarantined;
+
+/// Hourly: build up or wear down everyone's stress, ease it for those in
+/// session with their counselor, end finished sessions and breakdowns,
+/// break down those pushed too far and book the stressed into counseling.
+pub fn tick_mental_health(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    if sim_time.floor() == (sim_time - delta_hours).floor() {
+        return;
+    }
+    let deck_of_room: HashMap<u32, i32> = ctx.db.room().iter().map(|r| (r.id, r.deck)).collect();
+    let distressed_decks: HashSet<i32> = ctx
+        .db
+        .event()
+        .iter()
+        .filter(|e| e.state != event_states::RESOLVED && is_distressing(e.event_type))
+        .filter_map(|e| deck_of_room.get(&e.room_id).copied())
+        .collect();
+    let room_of = |person_id: u64| {
+        ctx.db
+            .position()
+            .person_id()
+            .find(person_id)
+            .map(|p| p.room_id)
+    };
+
+    for person in ctx.db.person().iter() {
+        if !person.is_alive || person.is_player {
+            if !person.is_alive {
+                ctx.db.mental_health().person_id().delete(person.id);
+            }
+            continue;
+        }
+        let Some(needs) = ctx.db.needs().person_id().find(person.id) else {
+            continue;
+        };
+        let mut mind = ctx
+            .db
+            .mental_health()
+            .person_id()
+            .find(person.id)
+            .unwrap_or_else(|| {
+                ctx.db.mental_health().insert(MentalHealth {
+                    person_id: person.id,
+                    stress: 0.0,
+                    session_room: None,
+                    counselor_id: None,
+                    session_until: None,
+                    breakdown_until: None,
+                    breakdowns: 0,
+                })
+            });
+        let personality = ctx.db.personality().person_id().find(person.id);
+        let (neuroticism, agreeableness) = personality
+            .as_ref()
+            .map_or((0.5, 0.5), |p| (p.neuroticism, p.agreeableness));
+        let on_duty = ctx
+            .db
+            .crew()
+            .person_id()
+            .find(person.id)
+            .is_some_and(|c| c.on_duty);
+        let resting = ctx
+            .db
+            .activity()
+            .person_id()
+            .find(person.id)
+            .is_some_and(|a| {
+                matches!(
+                    a.activity_type,
+                    activity_types::SLEEPING | activity_types::RELAXING
+                )
+            });
+        let room_id = room_of(person.id);
+        let input = StressInput {
+            neuroticism,
+            distress_nearby: room_id
+                .and_then(|r| deck_of_room.get(&r))
+                .is_some_and(|d| distressed_decks.contains(d)),
+            grieving: ctx
+                .db
+                .emotion()
+                .person_id()
+                .find(person.id)
+                .is_some_and(|e| sim_time < e.grieving_until),
+            isolated: is_isolated(needs.social),
+            overworked: is_overworked(on_duty, needs.fatigue),
+            resting,
+        };
+        mind.stress += stress_change(&input, 1.0);
+
+        // A session only helps with both client and counselor in the room
+        if let (Some(room), Some(counselor_id)) = (mind.session_room, mind.counselor_id) {
+            if room_id == Some(room) && room_of(counselor_id) == Some(room) {
+                let skill = ctx
+                    .db
+                    .skills()
+                    .person_id()
+                    .find(counselor_id)
+                    .map_or(0.0, |s| s.social);
+                mind.stress -= counseling_relief(skill, 1.0);
+            }
+        }
+        if mind.session_until.is_some_and(|until| sim_time >= until) {
+            end_session(ctx, &mut mind);
+        }
+        mind.stress = mind.stress.clamp(0.0, 1.0);
+
+        if mind.breakdown_until.is_some_and(|until| sim_time >= until) {
+            mind.breakdown_until = None;
+            log::info!(
+                "{} {} has pulled themselves together",
+                person.given_name,
+                person.family_name
+            );
+        }
+        let chance = breakdown_chance(mind.stress, neuroticism, 1.0);
+        if mind.breakdown_until.is_none() && roll(person.id, sim_time, BREAKDOWN_SALT) < chance {
+            mind.stress = AFTER_BREAKDOWN_STRESS;
+            mind.breakdown_until = Some(sim_time + BREAKDOWN_HOURS);
+            mind.breakdowns += 1;
+            end_session(ctx, &mut mind);
+            drop_everything(ctx, person.id);
+            log::info!(
+                "{} {} has broken down and refuses duty",
+                person.given_name,
+                person.family_name
+            );
+            if let Some(room_id) = room_id.filter(|_| lashes_out(agreeableness)) {
+                ctx.db.event().insert(Event {
+                    id: 0,
+                    event_type: event_types::ALTERCATION,
+                    room_id,
+                    started_at: sim_time,
+                    duration: 1.0,
+                    state: event_states::ACTIVE,
+                    responders_needed: 1,
+                    responders_assigned: 0,
+                    severity: 0.4,
+                    escalated_to: None,
+                });
+            }
+        }
+        ctx.db.mental_health().person_id().update(mind);
+    }
+
+    book_sessions(ctx, sim_time);
+}
+
+/// What someone in a counseling session does next: the client talks it
+/// through in the Mental Health room, the counselor sees them there.
+/// `None` for anyone else.
+pub fn counseling_activity(ctx: &ReducerContext, person_id: u64) -> Option<(u8, f32, Option<u32>)> {
+    if let Some(mind) = ctx.db.mental_health().person_id().find(person_id) {
+        if let Some(room) = mind.session_room {
+            return Some((activity_types::RELAXING, SESSION_HOURS as f32, Some(room)));
+        }
+    }
+    let session = ctx
+        .db
+        .mental_health()
+        .iter()
+        .find(|m| m.counselor_id == Some(person_id))?;
+    Some((activity_types::ON_DUTY, 1.0, session.session_room))
+}
+
+/// Whether `person_id` is in the middle of a breakdown, which keeps them
+/// from any work.
+pub fn is_breaking_down(ctx: &ReducerContext, person_id: u64) -> bool {
+    ctx.db
+        .mental_health()
+        .person_id()
+        .find(person_id)
+        .is_some_and(|m| m.breakdown_until.is_some())
+}
+
+/// Book everyone off duty from [`COUNSELING_STRESS`], the most stressed
+/// first, into a session with the most socially skilled medical crew on
+/// watch who are free, in a Mental Health room on their deck if there is
+/// one.
+fn book_sessions(ctx: &ReducerContext, sim_time: f64) {
+    let rooms: Vec<Room> = ctx
+        .db
+        .room()
+        .iter()
+        .filter(|r| is_counseling_room(r.room_type))
+        .collect();
+    if rooms.is_empty() || on_strike(ctx, departments::MEDICAL) {
+        return;
+    }
+    let mut clients: Vec<MentalHealth> = ctx
+        .db
+        .mental_health()
+        .iter()
+        .filter(|m| m.session_room.is_none() && m.breakdown_until.is_none())
+        .filter(|m| m.stress >= COUNSELING_STRESS)
+        .filter(|m| {
+            !ctx.db
+                .crew()
+                .person_id()
+                .find(m.person_id)
+                .is_some_and(|c| c.on_duty)
+        })
+        .filter(|m| is_free(ctx, m.person_id))
+        .collect();
+    if clients.is_empty() {
+        return;
+    }
+    clients.sort_by(|a, b| {
+        b.stress
+            .total_cmp(&a.stress)
+            .then(a.person_id.cmp(&b.person_id))
+    });
+
+    let mut caseload: HashMap<u64, usize> = HashMap::new();
+    for mind in ctx.db.mental_health().iter() {
+        if let Some(counselor_id) = mind.counselor_id {
+            *caseload.entry(counselor_id).or_default() += 1;
+        }
+    }
+    let mut counselors: Vec<(u64, f32)> = ctx
+        .db
+        .crew()
+        .iter()
+        .filter(|c| c.department == departments::MEDICAL && c.on_duty)
+        .filter(|c| {
+            ctx.db
+                .person()
+                .id()
+                .find(c.person_id)
+                .is_some_and(|p| p.is_alive && !p.is_player)
+        })
+        .filter(|c| is_free(ctx, c.person_id) && !is_breaking_down(ctx, c.person_id))
+        .filter(|c| {
+            !ctx.db
+                .patient()
+                .iter()
+                .any(|p| p.medic_id == Some(c.person_id))
+        })
+        .filter(|c| {
+            !ctx.db
+                .surgery()
+                .iter()
+                .any(|s| s.surgeon_id == c.person_id && s.finished_at.is_none())
+        })
+        .filter(|c| {
+            ctx.db
+                .mental_health()
+                .person_id()
+                .find(c.person_id)
+                .is_none_or(|m| m.session_room.is_none())
+        })
+        .filter_map(|c| {
+            let skill = ctx.db.skills().person_id().find(c.person_id)?.social;
+            (skill >= MIN_COUNSELOR_SKILL).then_some((c.person_id, skill))
+        })
+        .collect();
+    counselors.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+
+    let deck_of = |person_id: u64| {
+        ctx.db
+            .position()
+            .person_id()
+            .find(person_id)
+            .and_then(|p| ctx.db.room().id().find(p.room_id))
+            .map(|r| r.deck)
+    };
+    for mut client in clients {
+        let Some(&(counselor_id, _)) = counselors
+            .iter()
+            .find(|(id, _)| caseload.get(id).copied().unwrap_or(0) < CLIENTS_PER_COUNSELOR)
+        else {
+            break;
+        };
+        // Share a room with the counselor's other clients
+        let room = ctx
+            .db
+            .mental_health()
+            .iter()
+            .find(|m| m.counselor_id == Some(counselor_id))
+            .and_then(|m| m.session_room)
+            .or_else(|| {
+                let deck = deck_of(client.person_id);
+                rooms
+                    .iter()
+                    .min_by_key(|r| (Some(r.deck) != deck, r.id))
+                    .map(|r| r.id)
+            });
+        let Some(room) = room else {
+            break;
+        };
+        *caseload.entry(counselor_id).or_default() += 1;
+        client.session_room = Some(room);
+        client.counselor_id = Some(counselor_id);
+        client.session_until = Some(sim_time + SESSION_HOURS);
+        drop_everything(ctx, client.person_id);
+        drop_everything(ctx, counselor_id);
+        log::info!(
+            "Person {} booked for counseling in room {} with {}",
+            client.person_id,
+            room,
+            counselor_id
+        );
+        ctx.db.mental_health().person_id().update(client);
+    }
+}
+
+/// Whether `person_id` is free of the care, detention and classes that
+/// come before counseling.
+fn is_free(ctx: &ReducerContext, person_id: u64) -> bool {
+    ctx.db.patient().person_id().find(person_id).is_none()
+        && ctx
+            .db
+            .surgery()
+            .person_id()
+            .find(person_id)
+            .is_none_or(|s| s.finished_at.is_some())
+        && ctx.db.detention().person_id().find(person_id).is_none()
+        && !is_quarantined(ctx, person_id)
+        && !is_teacher(ctx, person_id)
+}
+
+/// End `mind`'s counseling session, if any.
+fn end_session(ctx: &ReducerContext, mind: &mut MentalHealth) {
+    if mind.session_room.is_none() {
+        return;
+    }
+    mind.session_room = None;
+    mind.counselor_id = None;
+    mind.session_until = None;
+    drop_everything(ctx, mind.person_id);
+}
@@ -0,0 +1,304 @@
+//! Milestone system - records voyage milestones the first time they are
+//! reached, and gathers the ship in the mess halls to mark those of the
+//! voyage itself.
+
+use crate::tables::*;
+use progship_logic::milestones::{
+    arrived, born_aboard, fatality_free, milestone_morale, recovered, scrammed, voyage_milestones,
+    VoyageProgress, GATHERING_HOURS, MILESTONE_CHECK_HOURS,
+};
+use spacetimedb::{ReducerContext, Table};
+
+use super::features::feature_flags;
+use super::file_report;
+
+/// Check for new milestones once per sim-hour: the first birth aboard, 100
+/// fatality-free days, recovery from a reactor scram, the stages of the
+/// voyage (see [`mark_voyage`]) and arrival. Arrival also files the final
+/// voyage report.
+pub fn tick_milestones(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    let interval = |t: f64| (t / MILESTONE_CHECK_HOURS).floor();
+    if interval(sim_time) == interval(sim_time - delta_hours) {
+        return;
+    }
+    let Some(config) = ctx.db.ship_config().id().find(0) else {
+        return;
+    };
+    let mut tracker = ctx
+        .db
+        .milestone_tracker()
+        .id()
+        .find(0)
+        .unwrap_or(MilestoneTracker {
+            id: 0,
+            death_count: config.death_count,
+            // Deaths before tracking began (e.g. a recovered ship) count as recent
+            last_fatality_at: if config.death_count > 0 {
+                sim_time
+            } else {
+                0.0
+            },
+            scram_started_at: None,
+        });
+    if config.death_count != tracker.death_count {
+        tracker.death_count = config.death_count;
+        tracker.last_fatality_at = sim_time;
+    }
+
+    if !reached(ctx, milestone_kinds::FIRST_BIRTH) {
+        let newborn = ctx
+            .db
+            .person()
+            .iter()
+            .filter(|p| born_aboard(p.birth_date))
+            .min_by(|a, b| a.birth_date.total_cmp(&b.birth_date));
+        if let Some(baby) = newborn {
+            let mut people = vec![baby.id];
+            if let Some(member) = ctx.db.family_member().person_id().find(baby.id) {
+                people.extend(
+                    ctx.db
+                        .family_member()
+                        .iter()
+                        .filter(|m| {
+                            m.family_id == member.family_id
+                                && matches!(m.role, family_roles::HEAD | family_roles::PARTNER)
+                        })
+                        .map(|m| m.person_id),
+                );
+            }
+            let name = format!("{} {}", baby.given_name, baby.family_name);
+            record(ctx, milestone_kinds::FIRST_BIRTH, sim_time, people, name);
+        }
+    }
+
+    if !reached(ctx, milestone_kinds::FATALITY_FREE_100_DAYS)
+        && fatality_free(sim_time, tracker.last_fatality_at)
+    {
+        let aboard = ctx.db.person().count();
+        record(
+            ctx,
+            milestone_kinds::FATALITY_FREE_100_DAYS,
+            sim_time,
+            Vec::new(),
+            format!("{} aboard", aboard),
+        );
+    }
+
+    let power_status = ctx
+        .db
+        .ship_system()
+        .iter()
+        .filter(|s| s.system_type == system_types::POWER)
+        .map(|s| s.overall_status)
+        .max();
+    match (tracker.scram_started_at, power_status) {
+        (None, Some(status)) if scrammed(status) => tracker.scram_started_at = Some(sim_time),
+        (Some(started_at), Some(status)) if recovered(status) => {
+            tracker.scram_started_at = None;
+            if !reached(ctx, milestone_kinds::REACTOR_SCRAM_SURVIVED) {
+                let engineers = ctx
+                    .db
+                    .crew()
+                    .iter()
+                    .filter(|c| c.on_duty && c.department == departments::ENGINEERING)
+                    .map(|c| c.person_id)
+                    .collect();
+                record(
+                    ctx,
+                    milestone_kinds::REACTOR_SCRAM_SURVIVED,
+                    sim_time,
+                    engineers,
+                    format!("power restored after {:.1} h", sim_time - started_at),
+                );
+            }
+        }
+        _ => {}
+    }
+
+    let voyage = ctx.db.voyage_state().id().find(0);
+    if let Some(state) = &voyage {
+        mark_voyage(ctx, state, sim_time);
+    }
+
+    // The ship arrives when it has flown the voyage, or at the planned time
+    // before it has begun flying
+    let landed = match &voyage {
+        Some(state) => state.phase == voyage_phases::ARRIVED,
+        None => arrived(sim_time, voyage_hours(config.seed)),
+    };
+    if !reached(ctx, milestone_kinds::ARRIVAL) && landed {
+        let captain = ctx
+            .db
+            .command_chain()
+            .iter()
+            .filter(|c| c.post == command_posts::CAPTAIN)
+            .map(|c| c.person_id)
+            .collect();
+        record(
+            ctx,
+            milestone_kinds::ARRIVAL,
+            sim_time,
+            captain,
+            String::new(),
+        );
+        file_report(ctx, sim_time, voyage_outcomes::ARRIVED, loss_causes::NONE);
+    }
+
+    if ctx.db.milestone_tracker().id().find(0).is_some() {
+        ctx.db.milestone_tracker().id().update(tracker);
+    } else {
+        ctx.db.milestone_tracker().insert(tracker);
+    }
+}
+
+/// Record each stage of the voyage the ship has newly reached, lifting
+/// everyone's spirits and gathering them in the mess halls to mark it.
+fn mark_voyage(ctx: &ReducerContext, state: &VoyageState, sim_time: f64) {
+    let progress = VoyageProgress {
+        elapsed: sim_time,
+        phase: state.phase,
+        covered_ly: state.covered_ly,
+        total_ly: state.total_ly,
+        hours_left: (state.eta - sim_time).max(0.0),
+    };
+    for kind in voyage_milestones(&progress) {
+        if reached(ctx, kind) {
+            continue;
+        }
+        let detail = format!(
+            "{:.2} of {:.2} ly at {:.3}c",
+            state.covered_ly, state.total_ly, state.velocity_c
+        );
+        record(ctx, kind, sim_time, Vec::new(), detail);
+
+        let lift = milestone_morale(kind);
+        let alive: Vec<u64> = ctx
+            .db
+            .person()
+            .iter()
+            .filter(|p| p.is_alive)
+            .map(|p| p.id)
+            .collect();
+        for person_id in alive {
+            if let Some(mut needs) = ctx.db.needs().person_id().find(person_id) {
+                needs.morale = (needs.morale + lift).min(1.0);
+                ctx.db.needs().person_id().update(needs);
+            }
+        }
+        gather(ctx, kind, sim_time);
+    }
+}
+
+/// Hold a gathering in every mess hall to mark the milestone `kind`.
+fn gather(ctx: &ReducerContext, kind: u8, sim_time: f64) {
+    if !feature_flags(ctx).allows_event(event_types::VOYAGE_MILESTONE) {
+        return;
+    }
+    let halls: Vec<Room> = ctx
+        .db
+        .room()
+        .iter()
+        .filter(|r| room_types::is_dining(r.room_type))
+        .collect();
+    if halls.is_empty() {
+        log::warn!(
+            "No mess hall to mark {} in",
+            progship_logic::constants::milestone_kinds::name(kind)
+        );
+        return;
+    }
+    for hall in halls {
+        ctx.db.event().insert(Event {
+            id: 0,
+            event_type: event_types::VOYAGE_MILESTONE,
+            room_id: hall.id,
+            started_at: sim_time,
+            duration: GATHERING_HOURS,
+            state: event_states::ACTIVE,
+            responders_needed: 0,
+            responders_assigned: 0,
+            severity: 0.3,
+            escalated_to: None,
+        });
+    }
+}
+
+/// Off duty, join the gathering for a voyage milestone, in the mess hall
+/// on your own deck if there is one there.
+pub fn gathering_activity(
+    ctx: &ReducerContext,
+    person_id: u64,
+    sim_time: f64,
+) -> Option<(u8, f32, Option<u32>)> {
+    let gatherings: Vec<Event> = ctx
+        .db
+        .event()
+        .iter()
+        .filter(|e| {
+            e.event_type == event_types::VOYAGE_MILESTONE && e.state != event_states::RESOLVED
+        })
+        .collect();
+    if gatherings.is_empty()
+        || ctx
+            .db
+            .crew()
+            .person_id()
+            .find(person_id)
+            .is_some_and(|c| c.on_duty)
+    {
+        return None;
+    }
+    let deck = ctx
+        .db
+        .position()
+        .person_id()
+        .find(person_id)
+        .and_then(|p| ctx.db.room().id().find(p.room_id))
+        .map(|r| r.deck);
+    let on_deck = |e: &&Event| {
+        ctx.db
+            .room()
+            .id()
+            .find(e.room_id)
+            .is_some_and(|r| Some(r.deck) == deck)
+    };
+    let gathering = gatherings
+        .iter()
+        .find(on_deck)
+        .or_else(|| gatherings.first())?;
+    let remaining = gathering.started_at + gathering.duration as f64 - sim_time;
+    (remaining > 0.0).then_some((
+        activity_types::SOCIALIZING,
+        remaining as f32,
+        Some(gathering.room_id),
+    ))
+}
+
+/// Voyage length in sim hours for a ship generated from `seed`.
+pub(super) fn voyage_hours(seed: u64) -> f64 {
+    let mission = progship_logic::mission::MissionConfig {
+        seed,
+        ..Default::default()
+    };
+    progship_logic::mission::compute_voyage(&mission).duration_hours
+}
+
+fn reached(ctx: &ReducerContext, kind: u8) -> bool {
+    ctx.db.milestone().iter().any(|m| m.kind == kind)
+}
+
+fn record(ctx: &ReducerContext, kind: u8, sim_time: f64, people: Vec<u64>, detail: String) {
+    log::info!(
+        "Milestone reached at {:.1}h: {} {}",
+        sim_time,
+        progship_logic::constants::milestone_kinds::name(kind),
+        detail
+    );
+    ctx.db.milestone().insert(Milestone {
+        id: 0,
+        kind,
+        achieved_at: sim_time,
+        people,
+        detail,
+    });
+}
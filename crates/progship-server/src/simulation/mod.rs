@@ -2,27 +2,224 @@
 //!
 //! Systems are called by the `tick` reducer at appropriate frequencies.
 
+use crate::tables::activity;
+use progship_logic::utility::{self, UtilityInput};
+use spacetimedb::ReducerContext;
+
 mod activities;
+mod advisor;
+mod aging;
+mod ambience;
+mod arboretum;
 mod atmosphere;
+mod bar;
+mod careers;
+mod cargo;
+mod crime;
+mod cryo;
 mod death;
 mod duty;
+mod economy;
+mod education;
+mod emotions;
+mod eva;
+mod evacuation;
 mod events;
+mod fabrication;
+mod factions;
+mod features;
+mod food;
+mod funerals;
+mod governance;
+mod hazards;
+mod history;
+mod hvac;
+mod inventory;
+mod lifecycle;
+mod lighting;
 mod maintenance;
+mod mental_health;
+mod milestones;
+mod morale;
 mod movement;
+mod mutiny;
+mod navigation;
 mod needs;
+mod objectives;
+mod orders;
+mod power;
+mod quarantine;
+mod radiation;
+mod robots;
+mod room_effects;
+mod sensors;
 mod ship_systems;
+mod shuttles;
+mod sleep;
 mod social;
+mod stream;
+mod surgery;
+mod thermal;
+mod timeline;
+mod training;
+mod triage;
+mod voyage_report;
 mod wandering;
+mod warm_start;
+mod warnings;
+mod watchdog;
+mod water;
 
 // Re-export all public tick functions
-pub use activities::tick_activities;
+pub use activities::{explain_current_activity, restart_activity, tick_activities};
+pub use advisor::tick_advisor;
+pub use aging::tick_aging;
+pub use ambience::tick_room_ambience;
+pub use arboretum::tick_arboretum;
 pub use atmosphere::tick_atmosphere;
+pub use bar::tick_bar;
+pub use careers::tick_careers;
+pub use cargo::tick_cargo;
+pub use crime::tick_crime;
+pub use cryo::{fill_cryo_bays, tick_cryo};
 pub use death::tick_death;
 pub use duty::tick_duty;
+pub use economy::tick_economy;
+pub use education::tick_education;
+pub use emotions::tick_emotions;
+pub use eva::tick_eva;
+pub use evacuation::refresh_evacuation_routes;
 pub use events::tick_events;
+pub use fabrication::{part_kind, tick_fabrication};
+pub use factions::tick_factions;
+pub use features::features_from_row;
+pub use food::{tick_food, Servery};
+pub use funerals::tick_funerals;
+pub use governance::tick_governance;
+pub use history::tick_history;
+pub use hvac::tick_hvac;
+pub use inventory::{add_item, give_item};
+pub use lifecycle::tick_lifecycle;
+pub use lighting::{shift_lighting, tick_lighting};
 pub use maintenance::tick_maintenance;
-pub use movement::tick_movement;
-pub use needs::tick_needs;
+pub use mental_health::tick_mental_health;
+pub use milestones::tick_milestones;
+pub use morale::tick_morale;
+pub use movement::{finish_movements, tick_movement};
+pub use mutiny::{negotiate_mutiny, suppress_mutiny, tick_mutiny};
+pub use navigation::tick_navigation;
+pub use needs::{balance_from_row, tick_needs};
+pub use objectives::{record_deed, start_objectives};
+pub use orders::{give_order, tick_orders, withdraw_order};
+pub use power::{size_power_cables, tick_power};
+pub use quarantine::tick_quarantine;
+pub use robots::{build_robots, tick_robots};
+pub use room_effects::tick_room_effects;
+pub use sensors::tick_sensors;
 pub use ship_systems::tick_ship_systems;
-pub use social::tick_social;
+pub use shuttles::{dock, tick_shuttles};
+pub use social::{set_interaction_target, tick_social};
+pub use stream::{carry_out_vote, may_stream, name_newborns, record_stream_action, tick_stream};
+pub use surgery::tick_surgery;
+pub use thermal::tick_thermal;
+pub use timeline::record_timeline;
+pub use training::tick_training;
+pub use triage::tick_triage;
+pub use voyage_report::{file_report, ship_was_lost, tick_voyage_report};
 pub use wandering::tick_wandering;
+pub use warm_start::fast_forward;
+pub use warnings::warn;
+pub use watchdog::tick_watchdog;
+pub use water::{size_water_pipes, tick_water};
+
+/// Every system after movement and activity picks, for one step of
+/// `delta_hours` ending at `sim_time`: deck lighting, needs, aging, births
+/// and funerals, triage, surgery, quarantine and the cryo bays, stress and
+/// counseling, the bars, wages and shops, social life, duty, orders and
+/// training, crime, factions and the council, morale and mutinies, the
+/// power grid and water network, ship systems, the voyage's burns, the food
+/// chain, the arboretums, the coolant loop, HVAC, events, sensor contacts and room effects, robots, repairs and
+/// EVAs, the watchdog, the ship's computer, milestones, the stream hooks,
+/// the voyage report and history compaction.
+pub fn tick_slow_systems(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    // T2: Slower systems (lighting, needs, death, aging, births, funerals,
+    // quarantine, triage, surgery, cryosleep, mental health, bars, economy,
+    // social, duty, training, crime, factions, governance, morale, mutiny,
+    // emotions, ambience)
+    tick_lighting(ctx, sim_time);
+    tick_needs(ctx, sim_time, delta_hours as f32);
+    tick_death(ctx, sim_time);
+    tick_aging(ctx, sim_time, delta_hours);
+    tick_lifecycle(ctx, sim_time, delta_hours);
+    tick_funerals(ctx, sim_time, delta_hours);
+    tick_quarantine(ctx, sim_time, delta_hours);
+    tick_triage(ctx, sim_time, delta_hours);
+    tick_surgery(ctx, sim_time, delta_hours);
+    tick_cryo(ctx, sim_time, delta_hours);
+    tick_mental_health(ctx, sim_time, delta_hours);
+    tick_bar(ctx, sim_time, delta_hours);
+    tick_economy(ctx, sim_time, delta_hours);
+    tick_social(ctx, sim_time, delta_hours);
+    tick_duty(ctx, sim_time);
+    tick_orders(ctx, sim_time);
+    tick_training(ctx, sim_time, delta_hours);
+    tick_education(ctx, sim_time, delta_hours);
+    tick_careers(ctx, sim_time, delta_hours);
+    tick_crime(ctx, sim_time, delta_hours);
+    tick_factions(ctx, sim_time, delta_hours);
+    tick_governance(ctx, sim_time, delta_hours);
+    tick_morale(ctx, sim_time);
+    tick_mutiny(ctx, sim_time, delta_hours);
+    tick_emotions(ctx, sim_time, delta_hours);
+    tick_room_ambience(ctx, sim_time, delta_hours);
+
+    // T3: Ship systems (power, water, resources, navigation, food,
+    // arboretums, cargo, shuttles, heat, HVAC, atmosphere, events, sensors,
+    // room effects, fabrication, robots, maintenance, EVA)
+    tick_power(ctx, delta_hours as f32);
+    tick_water(ctx, sim_time, delta_hours as f32);
+    tick_ship_systems(ctx, delta_hours as f32);
+    tick_navigation(ctx, sim_time, delta_hours);
+    tick_food(ctx, sim_time, delta_hours as f32);
+    tick_arboretum(ctx, sim_time, delta_hours as f32);
+    tick_cargo(ctx, sim_time, delta_hours);
+    tick_shuttles(ctx, delta_hours);
+    tick_thermal(ctx, delta_hours as f32);
+    tick_hvac(ctx, sim_time, delta_hours as f32);
+    tick_atmosphere(ctx, delta_hours as f32);
+    tick_events(ctx, sim_time, delta_hours as f32);
+    tick_sensors(ctx, sim_time);
+    tick_room_effects(ctx, sim_time, delta_hours as f32);
+    tick_fabrication(ctx, sim_time, delta_hours);
+    tick_robots(ctx, sim_time, delta_hours);
+    tick_maintenance(ctx, sim_time, delta_hours as f32);
+    tick_eva(ctx, sim_time, delta_hours as f32);
+
+    // T4: Invariant watchdog, ship's computer, milestones, stream hooks,
+    // voyage report and history compaction (internally throttled)
+    tick_watchdog(ctx, sim_time, delta_hours);
+    tick_advisor(ctx, sim_time);
+    tick_milestones(ctx, sim_time, delta_hours);
+    tick_stream(ctx, sim_time, delta_hours);
+    tick_voyage_report(ctx, sim_time, delta_hours);
+    tick_history(ctx, sim_time, delta_hours);
+}
+
+/// What someone confined to `room_id` does next: their own pick from
+/// `input`, minus any duty, but in that room.
+pub(super) fn confined_activity(input: &UtilityInput, room_id: u32) -> (u8, f32, Option<u32>) {
+    let (activity_type, duration, _) = utility::pick_best(&UtilityInput {
+        fit_for_duty: false,
+        should_be_on_duty: false,
+        ..input.clone()
+    });
+    (activity_type, duration, Some(room_id))
+}
+
+/// End `person_id`'s current activity so they choose again next tick.
+pub(super) fn drop_everything(ctx: &ReducerContext, person_id: u64) {
+    if let Some(mut activity) = ctx.db.activity().person_id().find(person_id) {
+        activity.duration = 0.0;
+        ctx.db.activity().person_id().update(activity);
+    }
+}
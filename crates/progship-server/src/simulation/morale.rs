@@ -0,0 +1,173 @@
+//! Morale system - department and ship-wide morale and the celebrations,
+//! rallies and strikes it sets off.
+
+use crate::tables::*;
+use progship_logic::morale::{
+    mood, mood_action, summarize, CELEBRATION_HOURS, RALLY_HOURS, SHIP_WIDE, STRIKE_HOURS,
+};
+use spacetimedb::{ReducerContext, Table};
+use std::collections::HashMap;
+
+use super::activities::department_to_room_type;
+use super::features::feature_flags;
+use super::mutiny::in_mutiny;
+use super::warnings::warn;
+
+/// Every tick: refresh each department's and the ship's morale index and
+/// set off whatever a change of mood calls for.
+pub fn tick_morale(ctx: &ReducerContext, sim_time: f64) {
+    let mut by_scope: HashMap<u8, Vec<f32>> = HashMap::new();
+    for needs in ctx.db.needs().iter() {
+        if !ctx
+            .db
+            .person()
+            .id()
+            .find(needs.person_id)
+            .is_some_and(|p| p.is_alive)
+        {
+            continue;
+        }
+        let department = ctx
+            .db
+            .crew()
+            .person_id()
+            .find(needs.person_id)
+            .map_or(departments::CIVILIAN, |c| c.department);
+        by_scope.entry(department).or_default().push(needs.morale);
+        by_scope.entry(SHIP_WIDE).or_default().push(needs.morale);
+    }
+
+    for scope in (departments::COMMAND..=departments::CIVILIAN).chain([SHIP_WIDE]) {
+        let index = summarize(by_scope.get(&scope).map_or(&[], Vec::as_slice));
+        let old = ctx.db.morale_summary().scope().find(scope);
+        let next = if index.people == 0 {
+            morale_moods::STEADY
+        } else {
+            mood(
+                old.as_ref().map_or(morale_moods::STEADY, |o| o.mood),
+                index.morale,
+            )
+        };
+        let mut row = MoraleSummary {
+            scope,
+            people: index.people,
+            morale: index.morale,
+            despairing: index.despairing,
+            mood: next,
+            mood_since: sim_time,
+            event_id: None,
+        };
+        let Some(old) = old else {
+            // The first reading sets the mood without setting anything off
+            ctx.db.morale_summary().insert(row);
+            continue;
+        };
+        row.event_id = old.event_id;
+        if old.mood == next {
+            row.mood_since = old.mood_since;
+        } else if let Some(event_type) = mood_action(scope, old.mood, next) {
+            let ongoing = old
+                .event_id
+                .and_then(|id| ctx.db.event().id().find(id))
+                .is_some_and(|e| e.state != event_states::RESOLVED);
+            if !ongoing {
+                row.event_id = stir(ctx, scope, event_type, sim_time).or(old.event_id);
+            }
+        }
+        ctx.db.morale_summary().scope().update(row);
+    }
+}
+
+/// Whether `department`'s crew have stopped work: out on strike or in
+/// mutiny.
+pub fn on_strike(ctx: &ReducerContext, department: u8) -> bool {
+    in_mutiny(ctx, department)
+        || ctx
+            .db
+            .morale_summary()
+            .scope()
+            .find(department)
+            .and_then(|s| s.event_id)
+            .and_then(|id| ctx.db.event().id().find(id))
+            .is_some_and(|e| {
+                e.event_type == event_types::STRIKE && e.state != event_states::RESOLVED
+            })
+}
+
+/// The department out on the strike `event_id`, if it is one.
+pub fn striking_department(ctx: &ReducerContext, event_id: u64) -> Option<u8> {
+    ctx.db
+        .morale_summary()
+        .iter()
+        .find(|s| s.scope != SHIP_WIDE && s.event_id == Some(event_id))
+        .map(|s| s.scope)
+}
+
+/// Start the `event_type` event a change of `scope`'s mood calls for: a
+/// strike at the department's station, anything else in a mess hall.
+/// Returns the event's ID.
+fn stir(ctx: &ReducerContext, scope: u8, event_type: u8, sim_time: f64) -> Option<u64> {
+    if !feature_flags(ctx).allows_event(event_type) {
+        return None;
+    }
+    let room = if event_type == event_types::STRIKE {
+        let station = department_to_room_type(scope);
+        ctx.db.room().iter().find(|r| r.room_type == station)
+    } else {
+        ctx.db
+            .room()
+            .iter()
+            .find(|r| room_types::is_dining(r.room_type))
+    };
+    let Some(room) = room else {
+        warn(
+            ctx,
+            "morale",
+            format!(
+                "no room to hold a {} in",
+                progship_logic::constants::event_types::name(event_type).to_lowercase()
+            ),
+        );
+        return None;
+    };
+    let (duration, responders_needed) = match event_type {
+        event_types::CELEBRATION => (CELEBRATION_HOURS, 1),
+        event_types::RALLY => (RALLY_HOURS, 0),
+        _ => (STRIKE_HOURS, 0),
+    };
+    let event = ctx.db.event().insert(Event {
+        id: 0,
+        event_type,
+        room_id: room.id,
+        started_at: sim_time,
+        duration,
+        state: event_states::ACTIVE,
+        responders_needed,
+        responders_assigned: 0,
+        severity: 0.5,
+        escalated_to: None,
+    });
+    log::info!(
+        "{} in {} over {} morale",
+        progship_logic::constants::event_types::name(event_type),
+        room.name,
+        if scope == SHIP_WIDE {
+            "ship-wide"
+        } else {
+            progship_logic::constants::departments::name(scope)
+        }
+    );
+
+    if event_type == event_types::STRIKE {
+        // Strikers walk off the job
+        for crew in ctx.db.crew().iter().filter(|c| c.department == scope) {
+            if let Some(mut activity) = ctx.db.activity().person_id().find(crew.person_id) {
+                if activity.activity_type == activity_types::ON_DUTY {
+                    activity.duration = 0.0;
+                    ctx.db.activity().person_id().update(activity);
+                }
+            }
+        }
+    }
+    Some(event.id)
+}
@@ -1,12 +1,21 @@
 //! Movement and pathfinding system - moves people through rooms via doors.
 
 use crate::tables::*;
-use progship_logic::pathfinding::{DoorEdge, NavGraph};
+use progship_logic::doors::is_passable;
+use progship_logic::genlib::crawlways::walk_speed_factor;
+use progship_logic::pathfinding::{room_weight, DoorEdge, NavGraph, Waypoint};
+use progship_logic::timeline::TimelineKind;
 use spacetimedb::{ReducerContext, Table};
+use std::collections::HashMap;
+
+use super::activities::settle_posture;
+use super::room_effects::walk_factors;
+use super::timeline::record_timeline;
 
 /// Move people toward their destinations, following door waypoints.
-pub fn tick_movement(ctx: &ReducerContext, delta_seconds: f32) {
+pub fn tick_movement(ctx: &ReducerContext, delta_seconds: f32, sim_time: f64) {
     let movements: Vec<Movement> = ctx.db.movement().iter().collect();
+    let effect_factors = walk_factors(ctx);
 
     for mov in movements {
         let Some(mut pos) = ctx.db.position().person_id().find(mov.person_id) else {
@@ -21,16 +30,33 @@ pub fn tick_movement(ctx: &ReducerContext, delta_seconds: f32) {
         let dy = wp_y - pos.y;
         let dist = (dx * dx + dy * dy).sqrt();
 
+        if dist < 1.5 && wp_room_id != pos.room_id && door_shut(ctx, pos.room_id, wp_room_id) {
+            // The door ahead was shut since the path was planned
+            ctx.db.movement().person_id().delete(mov.person_id);
+            settle_posture(ctx, mov.person_id);
+            continue;
+        }
         if dist < 1.5 {
             // Reached current waypoint
             pos.x = wp_x;
             pos.y = wp_y;
+            let entered = pos.room_id != wp_room_id;
             pos.room_id = wp_room_id;
             ctx.db.position().person_id().update(pos);
+            if entered {
+                record_timeline(
+                    ctx,
+                    mov.person_id,
+                    TimelineKind::Location,
+                    wp_room_id,
+                    sim_time,
+                );
+            }
 
             if is_final {
                 // Arrived at final destination
                 ctx.db.movement().person_id().delete(mov.person_id);
+                settle_posture(ctx, mov.person_id);
             } else {
                 // Advance to next waypoint
                 let mut updated = mov.clone();
@@ -38,8 +64,17 @@ pub fn tick_movement(ctx: &ReducerContext, delta_seconds: f32) {
                 ctx.db.movement().person_id().update(updated);
             }
         } else {
-            // Move toward current waypoint
-            let move_dist = mov.speed * delta_seconds;
+            // Move toward current waypoint, slower through crawlways,
+            // smoke, spills and frost
+            let factor = ctx
+                .db
+                .room()
+                .id()
+                .find(pos.room_id)
+                .map(|r| walk_speed_factor(r.room_type))
+                .unwrap_or(1.0)
+                * effect_factors.get(&pos.room_id).copied().unwrap_or(1.0);
+            let move_dist = mov.speed * factor * delta_seconds;
             let ratio = (move_dist / dist).min(1.0);
             pos.x += dx * ratio;
             pos.y += dy * ratio;
@@ -48,6 +83,33 @@ pub fn tick_movement(ctx: &ReducerContext, delta_seconds: f32) {
     }
 }
 
+/// Put everyone who is moving straight at their destination; the coarse
+/// steps of a warm start are longer than any walk.
+pub fn finish_movements(ctx: &ReducerContext, sim_time: f64) {
+    let movements: Vec<Movement> = ctx.db.movement().iter().collect();
+    for mov in movements {
+        ctx.db.movement().person_id().delete(mov.person_id);
+        let Some(mut pos) = ctx.db.position().person_id().find(mov.person_id) else {
+            continue;
+        };
+        let entered = pos.room_id != mov.target_room_id;
+        pos.x = mov.target_x;
+        pos.y = mov.target_y;
+        pos.room_id = mov.target_room_id;
+        ctx.db.position().person_id().update(pos);
+        if entered {
+            record_timeline(
+                ctx,
+                mov.person_id,
+                TimelineKind::Location,
+                mov.target_room_id,
+                sim_time,
+            );
+        }
+        settle_posture(ctx, mov.person_id);
+    }
+}
+
 /// Parse the path string and return (x, y, room_id, is_final_waypoint) for the current step
 fn get_current_waypoint(mov: &Movement) -> (f32, f32, u32, bool) {
     if mov.path.is_empty() {
@@ -74,13 +136,14 @@ fn get_current_waypoint(mov: &Movement) -> (f32, f32, u32, bool) {
     }
 }
 
-/// Build a NavGraph from the current door table.
-fn build_nav_graph(ctx: &ReducerContext) -> NavGraph {
+/// Build a NavGraph from the open doors a person with `clearance` may use,
+/// weighted by each room's width, type and current crowd.
+fn build_nav_graph(ctx: &ReducerContext, clearance: u8) -> NavGraph {
     let edges: Vec<DoorEdge> = ctx
         .db
         .door()
         .iter()
-        .filter(|d| d.is_open) // Only open doors are traversable
+        .filter(|d| is_passable(d.state) && d.access_level <= clearance)
         .map(|d| DoorEdge {
             room_a: d.room_a,
             room_b: d.room_b,
@@ -88,11 +151,41 @@ fn build_nav_graph(ctx: &ReducerContext) -> NavGraph {
             door_y: d.door_y,
         })
         .collect();
-    NavGraph::from_doors(&edges)
+    let mut occupants: HashMap<u32, u32> = HashMap::new();
+    for pos in ctx.db.position().iter() {
+        *occupants.entry(pos.room_id).or_default() += 1;
+    }
+    let weights = ctx
+        .db
+        .room()
+        .iter()
+        .map(|r| {
+            let crowd = occupants.get(&r.id).copied().unwrap_or(0);
+            (r.id, room_weight(r.room_type, r.width, r.height, crowd))
+        })
+        .collect();
+    let mut graph = NavGraph::from_doors(&edges);
+    graph.set_room_weights(weights);
+    graph
 }
 
 /// Start movement for a person to a target room, using pathfinding
 pub fn start_movement_to(ctx: &ReducerContext, person_id: u64, target_room_id: u32) {
+    let Some(target_room) = ctx.db.room().id().find(target_room_id) else {
+        return;
+    };
+    start_movement_to_point(ctx, person_id, target_room_id, target_room.x, target_room.y);
+}
+
+/// Start movement for a person to a point inside a target room (e.g. a bed),
+/// using pathfinding to reach the room
+pub fn start_movement_to_point(
+    ctx: &ReducerContext,
+    person_id: u64,
+    target_room_id: u32,
+    target_x: f32,
+    target_y: f32,
+) {
     if ctx.db.movement().person_id().find(person_id).is_some() {
         return;
     }
@@ -100,35 +193,89 @@ pub fn start_movement_to(ctx: &ReducerContext, person_id: u64, target_room_id: u
     let Some(pos) = ctx.db.position().person_id().find(person_id) else {
         return;
     };
-    let Some(target_room) = ctx.db.room().id().find(target_room_id) else {
-        return;
+
+    // Find the quickest path through doors using pure NavGraph, keeping to
+    // doors the person is cleared for (so passengers stay on the main
+    // corridors while crew take service shortcuts) unless the destination
+    // can't be reached that way
+    let clearance = ctx
+        .db
+        .person()
+        .id()
+        .find(person_id)
+        .map(|p| p.clearance)
+        .unwrap_or(access_levels::CAPTAIN);
+    let mut waypoints = build_nav_graph(ctx, clearance).find_path(pos.room_id, target_room_id);
+    if waypoints.is_none() && clearance < access_levels::CAPTAIN {
+        waypoints =
+            build_nav_graph(ctx, access_levels::CAPTAIN).find_path(pos.room_id, target_room_id);
+    }
+
+    // Unreachable destination — move directly, unless a shut door seals
+    // either end off
+    let waypoints = match waypoints {
+        Some(waypoints) => waypoints,
+        None if sealed_off(ctx, pos.room_id) || sealed_off(ctx, target_room_id) => return,
+        None => Vec::new(),
     };
+    start_movement_along(
+        ctx,
+        person_id,
+        &waypoints,
+        target_room_id,
+        target_x,
+        target_y,
+    );
+}
+
+/// Whether every door between rooms `a` and `b` is shut (false if no door
+/// joins them).
+fn door_shut(ctx: &ReducerContext, a: u32, b: u32) -> bool {
+    let mut joining = ctx
+        .db
+        .door()
+        .iter()
+        .filter(|d| (d.room_a == a && d.room_b == b) || (d.room_a == b && d.room_b == a))
+        .peekable();
+    joining.peek().is_some() && joining.all(|d| !is_passable(d.state))
+}
 
-    // Find path through doors using pure NavGraph
-    let mut graph = build_nav_graph(ctx);
-    let waypoints = graph.find_path(pos.room_id, target_room_id);
+/// Whether a room has doors and every one of them is shut.
+fn sealed_off(ctx: &ReducerContext, room_id: u32) -> bool {
+    let mut doors = ctx
+        .db
+        .door()
+        .iter()
+        .filter(|d| d.room_a == room_id || d.room_b == room_id)
+        .peekable();
+    doors.peek().is_some() && doors.all(|d| !is_passable(d.state))
+}
 
+/// Start movement along already-found door `waypoints`, ending at a point
+/// inside `target_room_id`
+pub(super) fn start_movement_along(
+    ctx: &ReducerContext,
+    person_id: u64,
+    waypoints: &[Waypoint],
+    target_room_id: u32,
+    target_x: f32,
+    target_y: f32,
+) {
     // Build path string from waypoints
-    let mut path_parts: Vec<String> = match &waypoints {
-        Some(wps) => wps
-            .iter()
-            .map(|wp| format!("{},{},{}", wp.door_x, wp.door_y, wp.room_id))
-            .collect(),
-        None => vec![], // unreachable destination — move directly
-    };
-    // Add final destination (center of target room)
-    path_parts.push(format!(
-        "{},{},{}",
-        target_room.x, target_room.y, target_room_id
-    ));
+    let mut path_parts: Vec<String> = waypoints
+        .iter()
+        .map(|wp| format!("{},{},{}", wp.door_x, wp.door_y, wp.room_id))
+        .collect();
+    // Add final destination inside the target room
+    path_parts.push(format!("{},{},{}", target_x, target_y, target_room_id));
 
     let path = path_parts.join(";");
 
     ctx.db.movement().insert(Movement {
         person_id,
         target_room_id,
-        target_x: target_room.x,
-        target_y: target_room.y,
+        target_x,
+        target_y,
         target_z: 0.0,
         speed: 5.0,
         path,
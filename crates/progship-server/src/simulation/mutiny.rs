@@ -0,0 +1,388 @@
+//! Mutiny system - departments that have despaired too long refusing duty
+//! and holding their stations until command gives in or security puts
+//! them down.
+
+use crate::tables::*;
+use progship_logic::constants::mutiny_demands;
+use progship_logic::crime::detention_hours;
+use progship_logic::mutiny::{
+    command_suppresses, concede, demand, mutiny_due, suppression_force, CONCESSION_MORALE,
+    RESPONSE_HOURS, RINGLEADERS, SUPPRESSION_HOURS, SUPPRESSION_MORALE,
+};
+use progship_logic::utility::UtilityInput;
+use spacetimedb::{ReducerContext, Table};
+
+use super::activities::department_to_room_type;
+use super::crime::holding_room;
+use super::duty::responsible_officer;
+use super::features::feature_flags;
+use super::governance::{amend_policy, current_policy};
+use super::warn;
+use super::{confined_activity, drop_everything};
+
+/// Every tick: security that has reached an occupied station puts the
+/// mutiny down. Hourly: mutinies with nobody left end, command answers
+/// those left alone and gives in where security has failed, and with
+/// politics enabled departments critical for too long mutiny.
+pub fn tick_mutiny(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    put_down(ctx, sim_time);
+    if sim_time.floor() == (sim_time - delta_hours).floor() {
+        return;
+    }
+    respond(ctx, sim_time);
+    if feature_flags(ctx).politics {
+        start_mutinies(ctx, sim_time);
+    }
+}
+
+/// Whether `department`'s crew are in mutiny.
+pub fn in_mutiny(ctx: &ReducerContext, department: u8) -> bool {
+    open_mutiny(ctx, department).is_some()
+}
+
+/// What a mutineer does next: their own pick, minus any duty, but in the
+/// station they hold. `None` for anyone not in a mutiny.
+pub fn mutiny_activity(
+    ctx: &ReducerContext,
+    person_id: u64,
+    input: &UtilityInput,
+) -> Option<(u8, f32, Option<u32>)> {
+    let department = ctx.db.crew().person_id().find(person_id)?.department;
+    let mutiny = open_mutiny(ctx, department)?;
+    Some(confined_activity(input, mutiny.room_id))
+}
+
+/// What a security crew member on duty does while security is sent in
+/// against a mutiny: head for the occupied station.
+pub fn suppression_activity(
+    ctx: &ReducerContext,
+    person_id: u64,
+) -> Option<(u8, f32, Option<u32>)> {
+    let crew = ctx.db.crew().person_id().find(person_id)?;
+    if crew.department != departments::SECURITY || !crew.on_duty {
+        return None;
+    }
+    let mutiny = ctx
+        .db
+        .mutiny()
+        .iter()
+        .filter(|m| m.ended_at.is_none() && m.suppressing_since.is_some())
+        .min_by_key(|m| m.id)?;
+    Some((activity_types::ON_DUTY, 0.5, Some(mutiny.room_id)))
+}
+
+/// Give the mutineers of `mutiny_id` what they demand (`negotiate_mutiny`
+/// reducer). Returns whether there was such a mutiny still going.
+pub fn negotiate_mutiny(ctx: &ReducerContext, mutiny_id: u64, sim_time: f64) -> bool {
+    let Some(mutiny) = ctx
+        .db
+        .mutiny()
+        .id()
+        .find(mutiny_id)
+        .filter(|m| m.ended_at.is_none())
+    else {
+        return false;
+    };
+    concede_mutiny(ctx, mutiny, sim_time);
+    true
+}
+
+/// Send security in against `mutiny_id` (`suppress_mutiny` reducer).
+/// Returns whether there was such a mutiny still going.
+pub fn suppress_mutiny(ctx: &ReducerContext, mutiny_id: u64, sim_time: f64) -> bool {
+    let Some(mutiny) = ctx
+        .db
+        .mutiny()
+        .id()
+        .find(mutiny_id)
+        .filter(|m| m.ended_at.is_none())
+    else {
+        return false;
+    };
+    if mutiny.suppressing_since.is_none() {
+        send_security(ctx, mutiny, sim_time);
+    }
+    true
+}
+
+fn open_mutiny(ctx: &ReducerContext, department: u8) -> Option<Mutiny> {
+    ctx.db
+        .mutiny()
+        .iter()
+        .find(|m| m.department == department && m.ended_at.is_none())
+}
+
+/// The living, free NPC crew of `department`.
+fn mutineers(ctx: &ReducerContext, department: u8) -> Vec<u64> {
+    ctx.db
+        .crew()
+        .iter()
+        .filter(|c| c.department == department)
+        .map(|c| c.person_id)
+        .filter(|&id| {
+            ctx.db
+                .person()
+                .id()
+                .find(id)
+                .is_some_and(|p| p.is_alive && !p.is_player)
+        })
+        .filter(|&id| ctx.db.detention().person_id().find(id).is_none())
+        .collect()
+}
+
+/// Living, free security crew on duty who are not in mutiny themselves.
+fn security_on_duty(ctx: &ReducerContext) -> Vec<u64> {
+    if in_mutiny(ctx, departments::SECURITY) {
+        return Vec::new();
+    }
+    ctx.db
+        .crew()
+        .iter()
+        .filter(|c| c.department == departments::SECURITY && c.on_duty)
+        .map(|c| c.person_id)
+        .filter(|&id| ctx.db.person().id().find(id).is_some_and(|p| p.is_alive))
+        .filter(|&id| ctx.db.detention().person_id().find(id).is_none())
+        .collect()
+}
+
+/// Start a mutiny in every crew department whose morale has been critical
+/// long enough and has not mutinied lately.
+fn start_mutinies(ctx: &ReducerContext, sim_time: f64) {
+    for department in departments::COMMAND..departments::CIVILIAN {
+        let Some(summary) = ctx
+            .db
+            .morale_summary()
+            .scope()
+            .find(department)
+            .filter(|s| s.mood == morale_moods::CRITICAL)
+        else {
+            continue;
+        };
+        let mutinies: Vec<Mutiny> = ctx
+            .db
+            .mutiny()
+            .iter()
+            .filter(|m| m.department == department)
+            .collect();
+        if mutinies.iter().any(|m| m.ended_at.is_none()) {
+            continue;
+        }
+        let since_last = mutinies
+            .iter()
+            .filter_map(|m| m.ended_at)
+            .max_by(|a, b| a.total_cmp(b))
+            .map(|at| sim_time - at);
+        if !mutiny_due(sim_time - summary.mood_since, since_last) {
+            continue;
+        }
+        let crew = mutineers(ctx, department);
+        if crew.is_empty() {
+            continue;
+        }
+        let station = department_to_room_type(department);
+        let Some(room) = ctx.db.room().iter().find(|r| r.room_type == station) else {
+            warn(ctx, "mutiny", "no station for mutineers to hold".into());
+            continue;
+        };
+
+        let demand = demand(&current_policy(ctx));
+        let event = ctx.db.event().insert(Event {
+            id: 0,
+            event_type: event_types::MUTINY,
+            room_id: room.id,
+            started_at: sim_time,
+            duration: RESPONSE_HOURS as f32,
+            state: event_states::ACTIVE,
+            responders_needed: 0,
+            responders_assigned: 0,
+            severity: 0.8,
+            escalated_to: None,
+        });
+        ctx.db.mutiny().insert(Mutiny {
+            id: 0,
+            event_id: event.id,
+            department,
+            room_id: room.id,
+            demand,
+            started_at: sim_time,
+            suppressing_since: None,
+            ended_at: None,
+            conceded: false,
+        });
+        log::info!(
+            "{} mutinies and holds {}, demanding: {}",
+            progship_logic::constants::departments::name(department),
+            room.name,
+            mutiny_demands::name(demand)
+        );
+        for person_id in crew {
+            drop_everything(ctx, person_id);
+        }
+    }
+}
+
+/// End mutinies nobody is left in, concede those security could not take
+/// in time, and have the responsible officer answer those left alone.
+fn respond(ctx: &ReducerContext, sim_time: f64) {
+    let open: Vec<Mutiny> = ctx
+        .db
+        .mutiny()
+        .iter()
+        .filter(|m| m.ended_at.is_none())
+        .collect();
+    for mutiny in open {
+        let crew = mutineers(ctx, mutiny.department);
+        if crew.is_empty() {
+            end_mutiny(ctx, mutiny, sim_time, false);
+            continue;
+        }
+        match mutiny.suppressing_since {
+            Some(since) if sim_time - since >= SUPPRESSION_HOURS => {
+                log::info!("Security could not retake room {}", mutiny.room_id);
+                concede_mutiny(ctx, mutiny, sim_time);
+            }
+            Some(_) => {}
+            None if sim_time - mutiny.started_at >= RESPONSE_HOURS => {
+                let hour = (sim_time % 24.0) as f32;
+                let agreeableness = responsible_officer(ctx, mutiny.department, hour)
+                    .and_then(|id| ctx.db.personality().person_id().find(id))
+                    .map_or(0.5, |p| p.agreeableness);
+                let security = security_on_duty(ctx).len() as u32;
+                if command_suppresses(agreeableness, security, crew.len() as u32) {
+                    send_security(ctx, mutiny, sim_time);
+                } else {
+                    concede_mutiny(ctx, mutiny, sim_time);
+                }
+            }
+            None => {}
+        }
+    }
+}
+
+/// Send the security crew on duty to retake the station.
+fn send_security(ctx: &ReducerContext, mut mutiny: Mutiny, sim_time: f64) {
+    let security = security_on_duty(ctx);
+    log::info!(
+        "Security sent against mutiny {} ({} crew)",
+        mutiny.id,
+        security.len()
+    );
+    for &person_id in &security {
+        drop_everything(ctx, person_id);
+    }
+    mutiny.suppressing_since = Some(sim_time);
+    ctx.db.mutiny().id().update(mutiny);
+}
+
+/// Meet the mutineers' demand until the council next sits; their spirits
+/// lift and they go back to work.
+fn concede_mutiny(ctx: &ReducerContext, mutiny: Mutiny, sim_time: f64) {
+    amend_policy(ctx, concede(current_policy(ctx), mutiny.demand), sim_time);
+    for person_id in mutineers(ctx, mutiny.department) {
+        if let Some(mut needs) = ctx.db.needs().person_id().find(person_id) {
+            needs.morale = (needs.morale + CONCESSION_MORALE).min(1.0);
+            ctx.db.needs().person_id().update(needs);
+        }
+    }
+    log::info!(
+        "Mutiny {} ends: command agrees to {}",
+        mutiny.id,
+        mutiny_demands::name(mutiny.demand)
+    );
+    end_mutiny(ctx, mutiny, sim_time, true);
+}
+
+/// Put down every mutiny whose station enough security crew have reached:
+/// the angriest [`RINGLEADERS`] go to the brig and the department's
+/// morale sinks further.
+fn put_down(ctx: &ReducerContext, sim_time: f64) {
+    let suppressing: Vec<Mutiny> = ctx
+        .db
+        .mutiny()
+        .iter()
+        .filter(|m| m.ended_at.is_none() && m.suppressing_since.is_some())
+        .collect();
+    for mutiny in suppressing {
+        let security = security_on_duty(ctx);
+        let arrived: Vec<u64> = security
+            .iter()
+            .copied()
+            .filter(|&id| {
+                ctx.db
+                    .position()
+                    .person_id()
+                    .find(id)
+                    .is_some_and(|p| p.room_id == mutiny.room_id)
+            })
+            .collect();
+        let mut crew = mutineers(ctx, mutiny.department);
+        if (arrived.len() as u32) < suppression_force(crew.len() as u32) {
+            continue;
+        }
+        let morale = |id: u64| {
+            ctx.db
+                .needs()
+                .person_id()
+                .find(id)
+                .map_or(0.5, |n| n.morale)
+        };
+        crew.sort_by(|&a, &b| morale(a).total_cmp(&morale(b)).then(a.cmp(&b)));
+        for &person_id in crew.iter().take(RINGLEADERS) {
+            let Some(room_id) = holding_room(ctx, person_id) else {
+                warn(
+                    ctx,
+                    "mutiny",
+                    "no brig or security office to hold ringleaders in".into(),
+                );
+                break;
+            };
+            let incident = ctx.db.incident().insert(Incident {
+                id: 0,
+                kind: incident_kinds::MUTINY,
+                room_id: mutiny.room_id,
+                offender_id: person_id,
+                victim_id: None,
+                occurred_at: mutiny.started_at,
+                responder_id: arrived.first().copied(),
+                closed_at: Some(sim_time),
+                detained: true,
+            });
+            ctx.db.detention().insert(Detention {
+                person_id,
+                incident_id: incident.id,
+                room_id,
+                until: sim_time + detention_hours(incident_kinds::MUTINY),
+            });
+        }
+        for &person_id in &crew {
+            if let Some(mut needs) = ctx.db.needs().person_id().find(person_id) {
+                needs.morale = (needs.morale - SUPPRESSION_MORALE).max(0.0);
+                ctx.db.needs().person_id().update(needs);
+            }
+        }
+        for &person_id in &arrived {
+            drop_everything(ctx, person_id);
+        }
+        log::info!(
+            "Mutiny {} put down; {} ringleaders detained",
+            mutiny.id,
+            crew.len().min(RINGLEADERS)
+        );
+        end_mutiny(ctx, mutiny, sim_time, false);
+    }
+}
+
+/// Close `mutiny` and its event and send its crew back to their own plans.
+fn end_mutiny(ctx: &ReducerContext, mut mutiny: Mutiny, sim_time: f64, conceded: bool) {
+    if let Some(mut event) = ctx.db.event().id().find(mutiny.event_id) {
+        event.state = event_states::RESOLVED;
+        ctx.db.event().id().update(event);
+    }
+    let department = mutiny.department;
+    mutiny.ended_at = Some(sim_time);
+    mutiny.conceded = conceded;
+    ctx.db.mutiny().id().update(mutiny);
+    for person_id in mutineers(ctx, department) {
+        drop_everything(ctx, person_id);
+    }
+}
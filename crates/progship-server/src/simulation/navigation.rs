@@ -0,0 +1,116 @@
+//! Navigation system - the ship's burns along its voyage, the fuel they
+//! take and how far it has come.
+
+use crate::tables::*;
+use progship_logic::mission::{compute_voyage, MissionConfig, PropulsionType};
+use progship_logic::navigation::{
+    burn_phase, burn_plan, delta_v, hours_to_arrival, is_burning, step, BurnPlan,
+};
+use spacetimedb::{ReducerContext, Table};
+
+use super::ship_systems::calculate_subsystem_efficiency;
+
+/// Fly the voyage: burn fuel while accelerating or braking, as hard as the
+/// thrust chambers and the fuel left allow, and move the ship along.
+pub fn tick_navigation(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    let Some(config) = ctx.db.ship_config().id().find(0) else {
+        return;
+    };
+    let plan = plan_for(config.seed);
+    let mut state = voyage_state(ctx, &plan, sim_time);
+    if state.phase == voyage_phases::ARRIVED {
+        return;
+    }
+    let Some(mut resources) = ctx.db.ship_resources().id().find(0) else {
+        return;
+    };
+    let mut fuel = resources.fuel.max(0.0) as f64;
+
+    let phase = burn_phase(state.covered_ly, state.velocity_c, &plan);
+    let mut thrust = 0.0;
+    if is_burning(phase) {
+        let engines = engine_efficiency(ctx);
+        let wanted = plan.fuel_per_hour * engines * delta_hours;
+        if wanted > 0.0 {
+            thrust = engines * (fuel / wanted).min(1.0);
+            fuel = (fuel - wanted).max(0.0);
+            resources.fuel = fuel as f32;
+            ctx.db.ship_resources().id().update(resources);
+        } else {
+            thrust = engines;
+        }
+    }
+    let (covered, velocity) = step(
+        state.covered_ly,
+        state.velocity_c,
+        phase,
+        thrust,
+        &plan,
+        delta_hours,
+    );
+    let next = burn_phase(covered, velocity, &plan);
+    if next != state.phase {
+        log::info!(
+            "Voyage: {} at {:.3} ly, {:.4}c",
+            progship_logic::constants::voyage_phases::name(next),
+            covered,
+            velocity
+        );
+    }
+    state.phase = next;
+    state.covered_ly = covered;
+    state.velocity_c = velocity;
+    state.delta_v_c = delta_v(fuel, &plan);
+    state.eta = sim_time + hours_to_arrival(covered, velocity, next, &plan);
+    ctx.db.voyage_state().id().update(state);
+}
+
+/// The flight plan of a ship generated from `seed`.
+fn plan_for(seed: u64) -> BurnPlan {
+    let mission = MissionConfig {
+        seed,
+        ..Default::default()
+    };
+    let fuel_rate = PropulsionType::from_u8(mission.propulsion)
+        .unwrap_or(PropulsionType::FusionTorch)
+        .spec()
+        .fuel_rate;
+    burn_plan(&compute_voyage(&mission), fuel_rate)
+}
+
+/// The voyage so far, started at launch if not yet.
+fn voyage_state(ctx: &ReducerContext, plan: &BurnPlan, sim_time: f64) -> VoyageState {
+    ctx.db.voyage_state().id().find(0).unwrap_or_else(|| {
+        let phase = burn_phase(0.0, 0.0, plan);
+        let fuel = ctx
+            .db
+            .ship_resources()
+            .id()
+            .find(0)
+            .map_or(0.0, |r| r.fuel as f64);
+        ctx.db.voyage_state().insert(VoyageState {
+            id: 0,
+            phase,
+            covered_ly: 0.0,
+            total_ly: plan.distance_ly,
+            velocity_c: 0.0,
+            delta_v_c: delta_v(fuel, plan),
+            eta: sim_time + hours_to_arrival(0.0, 0.0, phase, plan),
+        })
+    })
+}
+
+/// Share of full thrust the thrust chambers give, on average.
+fn engine_efficiency(ctx: &ReducerContext) -> f64 {
+    let chambers: Vec<f32> = ctx
+        .db
+        .subsystem()
+        .iter()
+        .filter(|s| s.subsystem_type == subsystem_types::THRUST_CHAMBER)
+        .map(|s| calculate_subsystem_efficiency(s.health, s.status))
+        .collect();
+    if chambers.is_empty() {
+        return 0.0;
+    }
+    chambers.iter().sum::<f32>() as f64 / chambers.len() as f64
+}
@@ -1,28 +1,137 @@
 //! Need decay system - hunger, fatigue, social, comfort, hygiene.
 
+use std::collections::HashSet;
+
 use crate::tables::*;
+use progship_logic::aging::age_recovery;
+use progship_logic::arboretum::visit_effects;
+use progship_logic::balance::{self, DecayTraits, IDLE_RATES};
+use progship_logic::economy::{rationed_meal_rate, u8_to_rationing};
+use progship_logic::food::{unfed_rates, MEAL_HOURS};
 use progship_logic::health;
+use progship_logic::inventory::RATION_HUNGER;
+use progship_logic::plumbing::dry_rates;
+use progship_logic::sleep::rested_rate;
 use spacetimedb::{ReducerContext, Table};
 
-/// Decay needs over time, with rates modified by current activity.
-/// Also applies atmosphere effects on health.
-pub fn tick_needs(ctx: &ReducerContext, delta_hours: f32) {
-    // Pre-collect atmosphere data for lookups
-    let atmospheres: Vec<DeckAtmosphere> = ctx.db.deck_atmosphere().iter().collect();
+use super::arboretum::garden_health;
+use super::cargo::has_cargo;
+use super::cryo::sleepers;
+use super::eva::is_outside;
+use super::features::feature_flags;
+use super::food::Servery;
+use super::funerals::grief_ceilings;
+use super::inventory::{add_item, eat_ration};
+use super::radiation::Dosimetry;
+use super::sleep::Bedrooms;
+use super::water::dry_rooms;
+
+/// Decay needs over time, with rates modified by current activity and
+/// sleep resting in proportion to its quality. Crew eating at a stocked
+/// galley pack a ration, eaten in place of a meal when the galley is out
+/// or the mess is closed; rationing closes the mess at some meal hours and
+/// rationed meals ease hunger more slowly. Also applies atmosphere
+/// effects and radiation doses on health, lets the deck's lights lift or
+/// wear down morale, eases visitors to the arboretums the more the
+/// healthier its plantings and holds mourners' morale down while they
+/// grieve.
+pub fn tick_needs(ctx: &ReducerContext, sim_time: f64, delta_hours: f32) {
+    // Sickbays can only treat patients while medical supplies last
+    let medical_stocked = has_cargo(ctx, cargo_categories::MEDICAL_SUPPLIES);
+    let dry = dry_rooms(ctx);
+    let dosimetry = Dosimetry::load(ctx);
+    let mut bedrooms = Bedrooms::load(ctx, sim_time);
+    let griefs = grief_ceilings(ctx, sim_time);
+    let asleep = sleepers(ctx);
+    let gardens = garden_health(ctx);
+    let mut ration_carriers: HashSet<u64> = ctx
+        .db
+        .inventory_item()
+        .iter()
+        .filter(|i| i.kind == item_kinds::RATION)
+        .map(|i| i.owner_id)
+        .collect();
+
+    let rationing = u8_to_rationing(
+        ctx.db
+            .ship_config()
+            .id()
+            .find(0)
+            .map_or(0, |c| c.rationing_level),
+    );
+    // Mess halls serve only at the meal hours rationing leaves
+    let mut servery = Servery::load(ctx).at_mess_hours(rationing, (sim_time % 24.0) as f32);
+
+    let balance = balance_config(ctx);
+    let atmosphere_hours = delta_hours * feature_flags(ctx).atmosphere_factor();
 
     for needs in ctx.db.needs().iter() {
         let mut n = needs;
 
-        // Skip dead people
+        // Skip dead people and those asleep in the cryo bays
+        if asleep.contains(&n.person_id) {
+            continue;
+        }
+        let mut life_stage = life_stages::ADULT;
+        let mut age = 30;
         if let Some(person) = ctx.db.person().id().find(n.person_id) {
             if !person.is_alive {
                 continue;
             }
+            life_stage = person.life_stage;
+            age = person.age;
         }
 
-        // Look up activity for modified decay rates
+        // Activity sets the base rates, a dry room or an empty galley blunts
+        // them, and age, personality and fitness scale them
         let activity = ctx.db.activity().person_id().find(n.person_id);
-        let rates = activity_decay_rates(activity.as_ref());
+        let personality = ctx.db.personality().person_id().find(n.person_id);
+        let traits = DecayTraits {
+            life_stage,
+            extraversion: personality.as_ref().map_or(0.5, |p| p.extraversion),
+            neuroticism: personality.as_ref().map_or(0.5, |p| p.neuroticism),
+            fitness: n.health,
+        };
+        let mut base = activity_decay_rates(activity.as_ref());
+        let room = ctx
+            .db
+            .position()
+            .person_id()
+            .find(n.person_id)
+            .and_then(|p| ctx.db.room().id().find(p.room_id));
+        if room.as_ref().is_some_and(|r| dry.contains(&r.id)) {
+            base = dry_rates(base, activity.as_ref().map(|a| a.activity_type));
+        }
+        // Sleep rests in proportion to how well they sleep there
+        let sleeping = activity
+            .as_ref()
+            .is_some_and(|a| a.activity_type == activity_types::SLEEPING);
+        if let Some(room) = room.as_ref().filter(|_| sleeping) {
+            base.1 = rested_rate(base.1, bedrooms.quality(ctx, n.person_id, room));
+        }
+        let eating = activity
+            .as_ref()
+            .is_some_and(|a| a.activity_type == activity_types::EATING);
+        let mut ate_ration = false;
+        if eating {
+            let deck = room.as_ref().map_or(0, |r| r.deck);
+            if servery.serve(deck, delta_hours / MEAL_HOURS) {
+                base.0 = rationed_meal_rate(base.0, rationing);
+                let crew = ctx.db.crew().person_id().find(n.person_id).is_some();
+                if crew
+                    && !ration_carriers.contains(&n.person_id)
+                    && servery.serve(deck, 1.0)
+                    && add_item(ctx, n.person_id, item_kinds::RATION, sim_time)
+                {
+                    ration_carriers.insert(n.person_id);
+                }
+            } else if ration_carriers.remove(&n.person_id) && eat_ration(ctx, n.person_id) {
+                ate_ration = true;
+            } else {
+                base = unfed_rates(base);
+            }
+        }
+        let rates = balance.personal_rates(base, &traits);
 
         // Apply need decay
         (n.hunger, n.fatigue, n.social, n.comfort, n.hygiene) = apply_need_decay(
@@ -34,39 +143,48 @@ pub fn tick_needs(ctx: &ReducerContext, delta_hours: f32) {
             delta_hours,
             rates,
         );
+        // A ration makes a quick meal of it
+        if ate_ration {
+            n.hunger = (n.hunger - RATION_HUNGER).max(0.0);
+            if let Some(mut meal) = activity.clone() {
+                meal.duration = (sim_time - meal.started_at) as f32;
+                ctx.db.activity().person_id().update(meal);
+            }
+        }
 
         // Health recovery — sickbay-aware with injury severity
-        let (in_medical, medical_skill) =
-            if let Some(pos) = ctx.db.position().person_id().find(n.person_id) {
-                if let Some(room) = ctx.db.room().id().find(pos.room_id) {
-                    let is_med = health::is_healing_room(room.room_type);
-                    // Find highest medical skill in the room (simplified: check if any medical crew)
-                    let skill = if is_med {
-                        ctx.db
-                            .position()
-                            .iter()
-                            .filter(|p| p.room_id == pos.room_id && p.person_id != n.person_id)
-                            .filter_map(|p| {
-                                let crew = ctx.db.crew().person_id().find(p.person_id)?;
-                                if crew.department == departments::MEDICAL {
-                                    ctx.db.skills().person_id().find(p.person_id)
-                                } else {
-                                    None
-                                }
-                            })
-                            .map(|s| s.medical)
-                            .fold(0.0f32, f32::max)
-                    } else {
-                        0.0
-                    };
-                    (is_med, skill)
+        let (in_medical, medical_skill) = if let Some(pos) =
+            ctx.db.position().person_id().find(n.person_id)
+        {
+            if let Some(room) = ctx.db.room().id().find(pos.room_id) {
+                // Patients heal only in their own ward bed
+                let healing_room = match ctx.db.patient().person_id().find(n.person_id) {
+                    Some(p) => p.state == patient_states::ADMITTED && p.room_id == Some(room.id),
+                    None => health::is_healing_room(room.room_type),
+                };
+                let is_med = medical_stocked && healing_room;
+                // Healing depends on the most skilled crew member tending
+                let skill = if is_med {
+                    ctx.db
+                        .position()
+                        .iter()
+                        .filter(|p| p.room_id == pos.room_id && p.person_id != n.person_id)
+                        .filter(|p| ctx.db.crew().person_id().find(p.person_id).is_some())
+                        .filter_map(|p| ctx.db.skills().person_id().find(p.person_id))
+                        .map(|s| s.medical)
+                        .fold(0.0f32, f32::max)
                 } else {
-                    (false, 0.0)
-                }
+                    0.0
+                };
+                (is_med, skill)
             } else {
                 (false, 0.0)
-            };
-        n.health = health::compute_health_recovery(
+            }
+        } else {
+            (false, 0.0)
+        };
+        // The old heal slower
+        let recovered = health::compute_health_recovery(
             n.health,
             n.hunger,
             n.fatigue,
@@ -74,17 +192,31 @@ pub fn tick_needs(ctx: &ReducerContext, delta_hours: f32) {
             medical_skill,
             delta_hours,
         );
+        n.health = age_recovery(n.health, recovered, age);
         n.health = starvation_damage(n.health, n.hunger, delta_hours);
         n.health = exhaustion_damage(n.health, n.fatigue, delta_hours);
 
         // Morale affected by needs satisfaction
         let avg_needs = (n.hunger + n.fatigue + n.social + n.comfort + n.hygiene) / 5.0;
         n.morale = morale_change(n.morale, avg_needs, delta_hours);
+        if let Some(deck) = room.as_ref().map(|r| r.deck) {
+            let light = bedrooms.light_morale(ctx, n.person_id, deck) * delta_hours;
+            n.morale = (n.morale + light).clamp(0.0, 1.0);
+        }
+        if let Some(&ceiling) = griefs.get(&n.person_id) {
+            n.morale = n.morale.min(ceiling);
+        }
 
         // Atmosphere effects on health
         if let Some(pos) = ctx.db.position().person_id().find(n.person_id) {
             if let Some(room) = ctx.db.room().id().find(pos.room_id) {
-                if let Some(atmo) = atmospheres.iter().find(|a| a.deck == room.deck) {
+                if room.has_window {
+                    (n.comfort, n.morale) = window_view_effects(n.comfort, n.morale, delta_hours);
+                }
+                if let Some(&plants) = gardens.get(&room.id) {
+                    (n.comfort, n.morale) = visit_effects(n.comfort, n.morale, plants, delta_hours);
+                }
+                if let Some(atmo) = ctx.db.room_air().room_id().find(room.id) {
                     (n.health, n.fatigue, n.comfort) = atmosphere_effects(
                         n.health,
                         n.fatigue,
@@ -93,19 +225,28 @@ pub fn tick_needs(ctx: &ReducerContext, delta_hours: f32) {
                         atmo.co2,
                         atmo.temperature,
                         atmo.pressure,
-                        delta_hours,
+                        atmosphere_hours,
                     );
                 }
             }
         }
 
+        // Radiation through the deck's shielding, or the suit's out on the
+        // hull; a big enough dose sickens and caps how far health recovers
+        if is_outside(ctx, n.person_id) {
+            dosimetry.expose_outside(ctx, &mut n, delta_hours);
+        } else if let Some(deck) = room.as_ref().map(|r| r.deck) {
+            dosimetry.expose(ctx, deck, &mut n, delta_hours);
+        }
+
         n.health = n.health.clamp(0.0, 1.0);
         ctx.db.needs().person_id().update(n);
     }
+    servery.save(ctx);
 }
 
 /// Returns (hunger, fatigue, social, comfort, hygiene) decay rates per hour
-pub fn activity_decay_rates(activity: Option<&Activity>) -> (f32, f32, f32, f32, f32) {
+pub fn activity_decay_rates(activity: Option<&Activity>) -> balance::NeedRates {
     match activity.map(|a| a.activity_type) {
         Some(activity_types::SLEEPING) => (0.02, -0.15, 0.01, -0.02, 0.01),
         Some(activity_types::EATING) => (-0.3, 0.01, -0.05, -0.02, 0.02),
@@ -117,7 +258,29 @@ pub fn activity_decay_rates(activity: Option<&Activity>) -> (f32, f32, f32, f32,
             (0.05, 0.06, 0.02, 0.03, 0.03)
         }
         Some(activity_types::MAINTENANCE) => (0.06, 0.08, 0.01, 0.04, 0.05),
-        _ => (0.04, 0.03, 0.02, 0.02, 0.02),
+        _ => IDLE_RATES,
+    }
+}
+
+/// The balance config in effect: the balance_config row, or the defaults.
+pub fn balance_config(ctx: &ReducerContext) -> balance::BalanceConfig {
+    ctx.db
+        .balance_config()
+        .id()
+        .find(0)
+        .map(|row| balance_from_row(&row))
+        .unwrap_or_default()
+}
+
+/// Convert a balance_config row to the logic type.
+pub fn balance_from_row(row: &BalanceConfig) -> balance::BalanceConfig {
+    balance::BalanceConfig {
+        child_hunger: row.child_hunger,
+        infant_fatigue: row.infant_fatigue,
+        elder_fatigue: row.elder_fatigue,
+        extraversion_social: row.extraversion_social,
+        neuroticism_comfort: row.neuroticism_comfort,
+        unfit_fatigue: row.unfit_fatigue,
     }
 }
 
@@ -169,6 +332,14 @@ pub fn morale_change(morale: f32, avg_needs: f32, delta_hours: f32) -> f32 {
     }
 }
 
+/// A window onto the stars eases discomfort and lifts morale a little.
+pub fn window_view_effects(comfort: f32, morale: f32, delta_hours: f32) -> (f32, f32) {
+    (
+        (comfort - 0.02 * delta_hours).max(0.0),
+        (morale + 0.005 * delta_hours).min(1.0),
+    )
+}
+
 /// Calculate atmosphere effects on health, fatigue, and comfort
 #[allow(clippy::too_many_arguments)]
 pub fn atmosphere_effects(
@@ -232,6 +403,9 @@ mod tests {
             started_at: 0.0,
             duration: 8.0,
             target_room_id: None,
+            animation_state: postures::STANDING,
+            target_anchor_id: None,
+            target_person_id: None,
         };
         let sleeping_rates = activity_decay_rates(Some(&sleeping_activity));
         assert_eq!(sleeping_rates.1, -0.15); // Fatigue decreases
@@ -246,6 +420,9 @@ mod tests {
             started_at: 0.0,
             duration: 0.5,
             target_room_id: None,
+            animation_state: postures::STANDING,
+            target_anchor_id: None,
+            target_person_id: None,
         };
         let rates = activity_decay_rates(Some(&eating_activity));
         assert_eq!(rates.0, -0.3); // Hunger decreases
@@ -260,6 +437,9 @@ mod tests {
             started_at: 0.0,
             duration: 1.0,
             target_room_id: None,
+            animation_state: postures::STANDING,
+            target_anchor_id: None,
+            target_person_id: None,
         };
         let rates = activity_decay_rates(Some(&exercising_activity));
         assert!(rates.0 > 0.05); // Hunger increases more
@@ -295,6 +475,23 @@ mod tests {
         assert_eq!(result.4, 0.0);
     }
 
+    #[test]
+    fn test_balance_row_converts_to_logic_config() {
+        let d = balance::BalanceConfig::default();
+        let row = BalanceConfig {
+            id: 0,
+            child_hunger: d.child_hunger,
+            infant_fatigue: d.infant_fatigue,
+            elder_fatigue: d.elder_fatigue,
+            extraversion_social: d.extraversion_social,
+            neuroticism_comfort: d.neuroticism_comfort,
+            unfit_fatigue: 2.0,
+        };
+        let config = balance_from_row(&row);
+        assert_eq!(config.unfit_fatigue, 2.0);
+        assert_eq!(config.child_hunger, d.child_hunger);
+    }
+
     #[test]
     fn test_apply_need_decay_normal() {
         let result = apply_need_decay(0.0, 0.0, 0.0, 0.0, 0.0, 1.0, (0.04, 0.03, 0.02, 0.02, 0.02));
@@ -372,6 +569,14 @@ mod tests {
         assert_eq!(morale, 0.5);
     }
 
+    #[test]
+    fn test_window_view_effects() {
+        let (c, m) = window_view_effects(0.5, 0.5, 1.0);
+        assert!(c < 0.5);
+        assert!(m > 0.5);
+        assert_eq!(window_view_effects(0.0, 1.0, 10.0), (0.0, 1.0));
+    }
+
     #[test]
     fn test_atmosphere_effects_low_oxygen() {
         let (h, f, c) = atmosphere_effects(1.0, 0.0, 0.0, 0.10, 0.02, 20.0, 100.0, 1.0);
@@ -382,7 +587,7 @@ mod tests {
 
     #[test]
     fn test_atmosphere_effects_high_co2() {
-        let (h, f, c) = atmosphere_effects(1.0, 0.0, 0.0, 0.21, 0.08, 20.0, 100.0, 1.0);
+        let (h, f, _c) = atmosphere_effects(1.0, 0.0, 0.0, 0.21, 0.08, 20.0, 100.0, 1.0);
         assert!(h < 1.0); // Health damage (CO2 > 0.06)
         assert!(f > 0.0); // Fatigue increase
     }
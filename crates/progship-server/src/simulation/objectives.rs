@@ -0,0 +1,49 @@
+//! Objectives system - a player's progress through a scripted scenario.
+
+use crate::tables::*;
+use progship_logic::objectives::{advance, Deed};
+use progship_logic::scenarios::scenario_script;
+use spacetimedb::{ReducerContext, Table};
+
+/// Give a newly joined player the scenario's first objective, if the ship
+/// was started from a scripted scenario.
+pub fn start_objectives(ctx: &ReducerContext, person_id: u64) {
+    let Some(config) = ctx.db.ship_config().id().find(0) else {
+        return;
+    };
+    if scenario_script(&config.scenario).is_some() {
+        ctx.db.player_objective().insert(PlayerObjective {
+            person_id,
+            current: 0,
+            completed_at: config.sim_time,
+        });
+    }
+}
+
+/// Move the player on to their next objective if `deed` completes the
+/// current one.
+pub fn record_deed(ctx: &ReducerContext, person_id: u64, deed: Deed) {
+    let Some(mut progress) = ctx.db.player_objective().person_id().find(person_id) else {
+        return;
+    };
+    let Some(config) = ctx.db.ship_config().id().find(0) else {
+        return;
+    };
+    let Some(script) = scenario_script(&config.scenario) else {
+        return;
+    };
+    let current = progress.current as usize;
+    if advance(&script.objectives, current, deed) == current {
+        return;
+    }
+    log::info!(
+        "Player {} completed objective {}/{}: {}",
+        person_id,
+        current + 1,
+        script.objectives.len(),
+        script.objectives[current].title
+    );
+    progress.current += 1;
+    progress.completed_at = config.sim_time;
+    ctx.db.player_objective().person_id().update(progress);
+}
@@ -0,0 +1,193 @@
+//! Order system - crew setting aside their own plans to follow bulk orders.
+
+use crate::tables::*;
+use progship_logic::health::should_seek_medical;
+use progship_logic::orders::{self, Recipient};
+use spacetimedb::{ReducerContext, Table};
+
+use super::activities::department_to_room_type;
+
+/// Give an order to everyone it is addressed to. Those who comply drop
+/// what they are doing on the next activity pick. Returns the order ID and
+/// how many complied.
+pub fn give_order(
+    ctx: &ReducerContext,
+    kind: u8,
+    department: u8,
+    deck: i32,
+    hours: f64,
+    issued_by: Option<u64>,
+    sim_time: f64,
+) -> (u64, u32) {
+    let order = ctx.db.standing_order().insert(StandingOrder {
+        id: 0,
+        kind,
+        department,
+        deck,
+        issued_by,
+        issued_at: sim_time,
+        expires_at: sim_time + orders::order_duration(hours),
+    });
+    let mut complied = 0;
+    for crew in ctx.db.crew().iter() {
+        let alive_npc = ctx
+            .db
+            .person()
+            .id()
+            .find(crew.person_id)
+            .is_some_and(|p| p.is_alive && !p.is_player);
+        if !alive_npc || Some(crew.person_id) == issued_by {
+            continue;
+        }
+        let recipient = recipient(ctx, &crew);
+        if !orders::addressed_to(kind, department, &recipient)
+            || !orders::complies(order.id, crew.person_id, &recipient)
+        {
+            continue;
+        }
+        ctx.db.order_assignment().person_id().delete(crew.person_id);
+        ctx.db.order_assignment().insert(OrderAssignment {
+            person_id: crew.person_id,
+            order_id: order.id,
+        });
+        if let Some(mut activity) = ctx.db.activity().person_id().find(crew.person_id) {
+            activity.duration = 0.0;
+            ctx.db.activity().person_id().update(activity);
+        }
+        complied += 1;
+    }
+    (order.id, complied)
+}
+
+/// Withdraw an order; everyone following it goes back to their own plans.
+pub fn withdraw_order(ctx: &ReducerContext, order_id: u64) {
+    let followers: Vec<u64> = ctx
+        .db
+        .order_assignment()
+        .iter()
+        .filter(|a| a.order_id == order_id)
+        .map(|a| a.person_id)
+        .collect();
+    for person_id in followers {
+        ctx.db.order_assignment().person_id().delete(person_id);
+    }
+    ctx.db.standing_order().id().delete(order_id);
+}
+
+/// Drop orders that have lapsed.
+pub fn tick_orders(ctx: &ReducerContext, sim_time: f64) {
+    let lapsed: Vec<u64> = ctx
+        .db
+        .standing_order()
+        .iter()
+        .filter(|o| o.expires_at <= sim_time)
+        .map(|o| o.id)
+        .collect();
+    for order_id in lapsed {
+        log::info!("Order {} lapsed", order_id);
+        withdraw_order(ctx, order_id);
+    }
+}
+
+/// The activity, duration and room a person under orders takes up next,
+/// or `None` to let them choose for themselves. The injured see to their
+/// health first.
+pub fn ordered_activity(
+    ctx: &ReducerContext,
+    person_id: u64,
+    sim_time: f64,
+) -> Option<(u8, f32, Option<u32>)> {
+    let assignment = ctx.db.order_assignment().person_id().find(person_id)?;
+    let Some(order) = ctx.db.standing_order().id().find(assignment.order_id) else {
+        ctx.db.order_assignment().person_id().delete(person_id);
+        return None;
+    };
+    if order.expires_at <= sim_time {
+        return None;
+    }
+    let health = ctx.db.needs().person_id().find(person_id)?.health;
+    if should_seek_medical(health) {
+        return None;
+    }
+    let duration = (order.expires_at - sim_time).min(1.0) as f32;
+    Some((
+        orders::order_activity(order.kind),
+        duration,
+        order_room(ctx, &order, person_id, sim_time),
+    ))
+}
+
+/// Where an order sends a person: the worst incident for damage control,
+/// the next room of the deck for a sweep, or a cabin.
+fn order_room(
+    ctx: &ReducerContext,
+    order: &StandingOrder,
+    person_id: u64,
+    sim_time: f64,
+) -> Option<u32> {
+    match order.kind {
+        order_kinds::DAMAGE_CONTROL => ctx
+            .db
+            .event()
+            .iter()
+            .filter(|e| e.state != event_states::RESOLVED)
+            .max_by(|a, b| a.severity.total_cmp(&b.severity))
+            .map(|e| e.room_id)
+            .or_else(|| {
+                let room_type = department_to_room_type(order.department);
+                ctx.db
+                    .room()
+                    .iter()
+                    .find(|r| r.room_type == room_type)
+                    .map(|r| r.id)
+            }),
+        order_kinds::SECURITY_SWEEP => {
+            let rooms: Vec<u32> = ctx
+                .db
+                .room()
+                .iter()
+                .filter(|r| r.deck == order.deck && r.room_type < room_types::CORRIDOR)
+                .map(|r| r.id)
+                .collect();
+            rooms
+                .get(orders::sweep_stop(rooms.len(), person_id, sim_time))
+                .copied()
+        }
+        _ => {
+            let cabins: Vec<u32> = ctx
+                .db
+                .room()
+                .iter()
+                .filter(|r| room_types::is_quarters(r.room_type))
+                .map(|r| r.id)
+                .collect();
+            (!cabins.is_empty()).then(|| cabins[(person_id % cabins.len() as u64) as usize])
+        }
+    }
+}
+
+fn recipient(ctx: &ReducerContext, crew: &Crew) -> Recipient {
+    let (morale, conscientiousness) = (
+        ctx.db
+            .needs()
+            .person_id()
+            .find(crew.person_id)
+            .map_or(0.5, |n| n.morale),
+        ctx.db
+            .personality()
+            .person_id()
+            .find(crew.person_id)
+            .map_or(0.5, |p| p.conscientiousness),
+    );
+    Recipient {
+        department: Some(crew.department),
+        morale,
+        conscientiousness,
+        traits: ctx
+            .db
+            .person_trait()
+            .person_id()
+            .find(crew.person_id)
+            .map_or(0, |t| t.traits),
+    }
+}
@@ -0,0 +1,244 @@
+//! Power system - load flow from the reactors over the power cables.
+
+use crate::tables::*;
+use progship_logic::cryo::pod_draw;
+use progship_logic::power_grid::{
+    lighting_priority, solve, Line, Load, LoadFlow, LIGHTING_KW_PER_M2,
+};
+use progship_logic::robots::CHARGE_KW;
+use spacetimedb::{ReducerContext, Table};
+use std::collections::HashMap;
+
+use super::ship_systems::calculate_subsystem_efficiency;
+
+/// Emergency generator output in kW, only while every reactor is down.
+const EMERGENCY_OUTPUT_KW: f32 = 30.0;
+
+/// Headroom cables are laid with over the load they carry at launch.
+const CABLE_MARGIN: f32 = 1.25;
+
+/// The grid as the solver sees it.
+struct GridModel {
+    sources: Vec<u64>,
+    /// kW generated.
+    generation: f32,
+    loads: Vec<Load>,
+    /// Room each load feeds, `None` for subsystems outside any room.
+    load_rooms: Vec<Option<u32>>,
+    lines: Vec<Line>,
+    /// InfraEdge ID of each line.
+    line_edges: Vec<u64>,
+}
+
+fn grid_model(ctx: &ReducerContext) -> GridModel {
+    let subsystems: Vec<Subsystem> = ctx.db.subsystem().iter().collect();
+    let reactor_down = subsystems.iter().any(|s| {
+        s.subsystem_type == subsystem_types::REACTOR_CORE
+            && (s.status == system_statuses::OFFLINE || s.status == system_statuses::DESTROYED)
+    });
+    let mut sources = Vec::new();
+    let mut generation = 0.0;
+    for sub in &subsystems {
+        let efficiency = calculate_subsystem_efficiency(sub.health, sub.status);
+        let output = match sub.subsystem_type {
+            subsystem_types::REACTOR_CORE => sub.output,
+            subsystem_types::EMERGENCY_GENERATOR if reactor_down => EMERGENCY_OUTPUT_KW,
+            _ => continue,
+        };
+        generation += output * efficiency;
+        sources.push(sub.node_id);
+    }
+
+    // One load per room: its lighting, cryo pods and robots on charge plus
+    // every working subsystem in it, shed at the priority of the most
+    // important
+    let mut charging: HashMap<u32, f32> = HashMap::new();
+    for robot in ctx
+        .db
+        .robot()
+        .iter()
+        .filter(|r| r.state == robot_states::CHARGING && r.target_room_id.is_none())
+    {
+        *charging.entry(robot.room_id).or_default() += CHARGE_KW;
+    }
+    let priorities: HashMap<u64, u8> = ctx
+        .db
+        .ship_system()
+        .iter()
+        .map(|s| (s.id, s.priority))
+        .collect();
+    let mut by_node: HashMap<u64, (Option<u32>, Load)> = ctx
+        .db
+        .room()
+        .iter()
+        .map(|r| {
+            let load = Load {
+                node: r.node_id,
+                draw: r.width * r.height * LIGHTING_KW_PER_M2
+                    + pod_draw(r.room_type, r.capacity)
+                    + charging.get(&r.id).copied().unwrap_or(0.0),
+                priority: lighting_priority(r.room_type),
+            };
+            (r.node_id, (Some(r.id), load))
+        })
+        .collect();
+    for sub in subsystems
+        .iter()
+        .filter(|s| s.power_draw > 0.0 && s.health > 0.0)
+    {
+        let priority = priorities
+            .get(&sub.system_id)
+            .copied()
+            .unwrap_or(power_priorities::NORMAL);
+        let (_, load) = by_node.entry(sub.node_id).or_insert((
+            None,
+            Load {
+                node: sub.node_id,
+                draw: 0.0,
+                priority,
+            },
+        ));
+        load.draw += sub.power_draw;
+        load.priority = load.priority.min(priority);
+    }
+    let (load_rooms, loads) = by_node.into_values().unzip();
+
+    let cable_edges: HashMap<u64, (u64, u64)> = ctx
+        .db
+        .graph_edge()
+        .iter()
+        .filter(|e| e.edge_type == edge_types::POWER)
+        .map(|e| (e.id, (e.from_node, e.to_node)))
+        .collect();
+    let (lines, line_edges): (Vec<Line>, Vec<u64>) = ctx
+        .db
+        .infra_edge()
+        .iter()
+        .filter(|e| e.edge_type == infra_types::POWER_CABLE)
+        .filter_map(|e| {
+            let &(from, to) = cable_edges.get(&e.graph_edge_id)?;
+            let line = Line {
+                from,
+                to,
+                capacity: e.capacity * e.health,
+            };
+            Some((line, e.id))
+        })
+        .unzip();
+    // Cables leaving a node no cable feeds start at the main switchboard
+    for line in &lines {
+        if !lines.iter().any(|l| l.to == line.from) && !sources.contains(&line.from) {
+            sources.push(line.from);
+        }
+    }
+
+    GridModel {
+        sources,
+        generation,
+        loads,
+        load_rooms,
+        lines,
+        line_edges,
+    }
+}
+
+/// Run the grid for `delta_hours`: serve loads from generation, bridging a
+/// shortfall from the power reserves while they last, and shed the rest.
+/// Shed rooms go dark and the subsystems in them stop (see `tick_ship_systems`).
+pub fn tick_power(ctx: &ReducerContext, delta_hours: f32) {
+    let Some(mut resources) = ctx.db.ship_resources().id().find(0) else {
+        return;
+    };
+    let model = grid_model(ctx);
+    let reserve_kw = if delta_hours > 0.0 {
+        resources.power / delta_hours
+    } else {
+        0.0
+    };
+    let flow = solve(
+        &model.sources,
+        model.generation + reserve_kw,
+        &model.loads,
+        &model.lines,
+    );
+    resources.power = (resources.power + (model.generation - flow.supplied) * delta_hours)
+        .clamp(0.0, resources.power_cap);
+    ctx.db.ship_resources().id().update(resources);
+
+    for (&edge_id, &carried) in model.line_edges.iter().zip(&flow.line_flow) {
+        if let Some(mut edge) = ctx.db.infra_edge().id().find(edge_id) {
+            edge.current_flow = carried;
+            ctx.db.infra_edge().id().update(edge);
+        }
+    }
+    record_room_power(ctx, &model, &flow);
+    record_grid(ctx, &model, &flow);
+}
+
+fn record_room_power(ctx: &ReducerContext, model: &GridModel, flow: &LoadFlow) {
+    for ((room, load), &powered) in model.load_rooms.iter().zip(&model.loads).zip(&flow.served) {
+        let Some(room_id) = *room else {
+            continue;
+        };
+        let row = RoomPower {
+            room_id,
+            load_kw: load.draw,
+            powered,
+        };
+        match ctx.db.room_power().room_id().find(room_id) {
+            Some(old) if old.powered == row.powered && old.load_kw == row.load_kw => {}
+            Some(_) => {
+                ctx.db.room_power().room_id().update(row);
+            }
+            None => {
+                ctx.db.room_power().insert(row);
+            }
+        }
+    }
+}
+
+fn record_grid(ctx: &ReducerContext, model: &GridModel, flow: &LoadFlow) {
+    let row = PowerGrid {
+        id: 0,
+        generation_kw: model.generation,
+        demand_kw: flow.demand,
+        supplied_kw: flow.supplied,
+        brownout: flow.brownout(),
+    };
+    match ctx.db.power_grid().id().find(0) {
+        Some(old) => {
+            if old.brownout != row.brownout {
+                if row.brownout {
+                    log::warn!(
+                        "Brownout: {:.0} kW of {:.0} kW demand served, shedding loads",
+                        row.supplied_kw,
+                        row.demand_kw
+                    );
+                } else {
+                    log::info!("Brownout over: all {:.0} kW served", row.demand_kw);
+                }
+            }
+            ctx.db.power_grid().id().update(row);
+        }
+        None => {
+            ctx.db.power_grid().insert(row);
+        }
+    }
+}
+
+/// Lay every power cable with headroom over the load it carries with the
+/// whole ship powered, so a new ship starts without a brownout.
+pub fn size_power_cables(ctx: &ReducerContext) {
+    let mut model = grid_model(ctx);
+    for line in &mut model.lines {
+        line.capacity = f32::MAX;
+    }
+    let flow = solve(&model.sources, f32::MAX, &model.loads, &model.lines);
+    for (&edge_id, &carried) in model.line_edges.iter().zip(&flow.line_flow) {
+        if let Some(mut edge) = ctx.db.infra_edge().id().find(edge_id) {
+            edge.capacity = edge.capacity.max(carried * CABLE_MARGIN);
+            edge.current_flow = carried;
+            ctx.db.infra_edge().id().update(edge);
+        }
+    }
+}
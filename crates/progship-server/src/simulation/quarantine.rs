@@ -0,0 +1,235 @@
+//! Quarantine system - contagious outbreaks flagged from medical
+//! emergencies, spread between room-mates, the flagged isolated in the
+//! Quarantine room behind locked doors and released once recovered.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::tables::*;
+use progship_logic::doors::escalation_door_state;
+use progship_logic::quarantine::{
+    catch_chance, catches, door_state, is_contagious, may_release, INFECTION_INJURY,
+};
+use progship_logic::utility::UtilityInput;
+use spacetimedb::{ReducerContext, Table};
+
+use super::evacuation::refresh_evacuation_routes;
+use super::{confined_activity, drop_everything};
+
+/// Hourly: flag the first case of every new outbreak, let carriers not yet
+/// isolated pass the illness on, release the recovered and lock or open
+/// the Quarantine room's doors.
+pub fn tick_quarantine(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    if sim_time.floor() == (sim_time - delta_hours).floor() {
+        return;
+    }
+    release_recovered(ctx, sim_time);
+    let Some(ward) = ctx
+        .db
+        .room()
+        .iter()
+        .filter(|r| r.room_type == room_types::QUARANTINE)
+        .min_by_key(|r| r.id)
+        .map(|r| r.id)
+    else {
+        return;
+    };
+    detect_outbreaks(ctx, ward, sim_time);
+    spread_illness(ctx, ward, sim_time);
+    seal_ward(ctx, ward);
+}
+
+/// What someone in quarantine does next: their own pick, minus any duty,
+/// but in the room they are isolated in. `None` for anyone not in
+/// quarantine.
+pub fn quarantine_activity(
+    ctx: &ReducerContext,
+    person_id: u64,
+    input: &UtilityInput,
+) -> Option<(u8, f32, Option<u32>)> {
+    let quarantine = ctx.db.quarantine().person_id().find(person_id)?;
+    Some(confined_activity(input, quarantine.room_id))
+}
+
+/// Whether `person_id` is flagged for quarantine, which keeps them from
+/// any work.
+pub fn is_quarantined(ctx: &ReducerContext, person_id: u64) -> bool {
+    ctx.db.quarantine().person_id().find(person_id).is_some()
+}
+
+/// Flag the worst hurt in the room of every contagious medical emergency
+/// that has nobody flagged yet.
+fn detect_outbreaks(ctx: &ReducerContext, ward: u32, sim_time: f64) {
+    let outbreaks: Vec<Event> = ctx
+        .db
+        .event()
+        .iter()
+        .filter(|e| e.state != event_states::RESOLVED && is_contagious(e.event_type, e.severity))
+        .filter(|e| !ctx.db.quarantine().iter().any(|q| q.event_id == e.id))
+        .collect();
+    for outbreak in outbreaks {
+        let first_case = ctx
+            .db
+            .position()
+            .iter()
+            .filter(|p| p.room_id == outbreak.room_id && may_catch(ctx, p.person_id))
+            .filter_map(|p| ctx.db.needs().person_id().find(p.person_id))
+            .min_by(|a, b| {
+                a.health
+                    .total_cmp(&b.health)
+                    .then(a.person_id.cmp(&b.person_id))
+            });
+        if let Some(needs) = first_case {
+            flag(ctx, needs.person_id, outbreak.id, ward, sim_time);
+        }
+    }
+}
+
+/// Give the illness a chance to pass from every carrier not yet in
+/// quarantine to the others in their room.
+fn spread_illness(ctx: &ReducerContext, ward: u32, sim_time: f64) {
+    // Carriers at large and the outbreak they carry, by room
+    let mut at_large: HashMap<u32, (u32, u64)> = HashMap::new();
+    for q in ctx.db.quarantine().iter() {
+        let Some(pos) = ctx.db.position().person_id().find(q.person_id) else {
+            continue;
+        };
+        if pos.room_id != q.room_id {
+            let entry = at_large.entry(pos.room_id).or_insert((0, q.event_id));
+            entry.0 += 1;
+        }
+    }
+    for (room_id, (carriers, event_id)) in at_large {
+        let chance = catch_chance(carriers, 1.0);
+        let caught: Vec<u64> = ctx
+            .db
+            .position()
+            .iter()
+            .filter(|p| p.room_id == room_id && may_catch(ctx, p.person_id))
+            .map(|p| p.person_id)
+            .filter(|&id| catches(id, sim_time, chance))
+            .collect();
+        for person_id in caught {
+            if let Some(mut needs) = ctx.db.needs().person_id().find(person_id) {
+                needs.health = (needs.health - INFECTION_INJURY).max(0.0);
+                ctx.db.needs().person_id().update(needs);
+            }
+            flag(ctx, person_id, event_id, ward, sim_time);
+        }
+    }
+}
+
+/// Whether `person_id` can be flagged: alive, not a player and not
+/// flagged already.
+fn may_catch(ctx: &ReducerContext, person_id: u64) -> bool {
+    !is_quarantined(ctx, person_id)
+        && ctx
+            .db
+            .person()
+            .id()
+            .find(person_id)
+            .is_some_and(|p| p.is_alive && !p.is_player)
+}
+
+/// Flag `person_id` for quarantine in `ward` and send them there from
+/// whatever they were doing, giving up any ward bed.
+fn flag(ctx: &ReducerContext, person_id: u64, event_id: u64, ward: u32, sim_time: f64) {
+    ctx.db.quarantine().insert(Quarantine {
+        person_id,
+        event_id,
+        room_id: ward,
+        flagged_at: sim_time,
+    });
+    ctx.db.patient().person_id().delete(person_id);
+    drop_everything(ctx, person_id);
+    log::info!(
+        "Person {} flagged for quarantine (outbreak {})",
+        person_id,
+        event_id
+    );
+}
+
+/// Release everyone who has recovered once the illness has run its
+/// course, and drop the dead.
+fn release_recovered(ctx: &ReducerContext, sim_time: f64) {
+    let released: Vec<u64> = ctx
+        .db
+        .quarantine()
+        .iter()
+        .filter(|q| {
+            let alive = ctx
+                .db
+                .person()
+                .id()
+                .find(q.person_id)
+                .is_some_and(|p| p.is_alive);
+            let health = ctx
+                .db
+                .needs()
+                .person_id()
+                .find(q.person_id)
+                .map_or(0.0, |n| n.health);
+            !alive || may_release(health, sim_time - q.flagged_at)
+        })
+        .map(|q| q.person_id)
+        .collect();
+    for person_id in released {
+        log::info!("Person {} released from quarantine", person_id);
+        ctx.db.quarantine().person_id().delete(person_id);
+        drop_everything(ctx, person_id);
+    }
+}
+
+/// Lock the Quarantine room's doors while everyone flagged and nobody else
+/// is inside, and open them while anyone is still to arrive or leave. Doors
+/// shut for anything else, or held by an emergency on either side, are
+/// left alone.
+fn seal_ward(ctx: &ReducerContext, ward: u32) {
+    let flagged: Vec<u64> = ctx
+        .db
+        .quarantine()
+        .iter()
+        .filter(|q| q.room_id == ward)
+        .map(|q| q.person_id)
+        .collect();
+    let occupants: Vec<u64> = ctx
+        .db
+        .position()
+        .iter()
+        .filter(|p| p.room_id == ward)
+        .map(|p| p.person_id)
+        .collect();
+    let inside = occupants.iter().filter(|id| flagged.contains(id)).count();
+    let others = occupants.len() - inside;
+    let state = door_state(flagged.len(), inside, others);
+    let emergencies: HashSet<u32> = ctx
+        .db
+        .event()
+        .iter()
+        .filter(|e| e.state != event_states::RESOLVED)
+        .filter(|e| escalation_door_state(e.event_type).is_some())
+        .map(|e| e.room_id)
+        .collect();
+    let mut changed = false;
+    for mut door in ctx
+        .db
+        .door()
+        .iter()
+        .filter(|d| d.room_a == ward || d.room_b == ward)
+    {
+        let ours = matches!(door.state, door_states::OPEN | door_states::LOCKED)
+            && !emergencies.contains(&door.room_a)
+            && !emergencies.contains(&door.room_b);
+        if ours && door.state != state {
+            door.state = state;
+            ctx.db.door().id().update(door);
+            changed = true;
+        }
+    }
+    if changed {
+        log::info!(
+            "Quarantine doors {}",
+            progship_logic::constants::door_states::name(state)
+        );
+        refresh_evacuation_routes(ctx);
+    }
+}
@@ -0,0 +1,218 @@
+//! Radiation system - solar flares, the storm shelter and everyone's dose.
+
+use crate::tables::*;
+use progship_logic::evacuation::is_muster_station;
+use progship_logic::hazards::flare_scale;
+use progship_logic::health::should_seek_medical;
+use progship_logic::radiation::{
+    acute_dose, flare_hours, flare_severity, flare_sighted, health_ceiling, outside_dose_rate,
+    sickness_effects, FLARE_CHANCE_PER_HOUR,
+};
+use progship_logic::sensors::FLARE_LEAD_HOURS;
+use spacetimedb::{ReducerContext, Table};
+use std::collections::HashMap;
+
+use super::hazards::current_phase;
+use super::sensors::{flare_incoming, incoming};
+
+/// Roll for a solar flare each hour, more often near a star (see
+/// `progship_logic::hazards::flare_scale`). Its particles follow the flash
+/// [`FLARE_LEAD_HOURS`] later, a contact for the sensors to spot.
+pub fn roll_flares(ctx: &ReducerContext, sim_time: f64, delta_hours: f32) {
+    let hour = sim_time.floor();
+    if hour == (sim_time - delta_hours as f64).floor() || hour < 0.0 {
+        return;
+    }
+    let chance = FLARE_CHANCE_PER_HOUR * flare_scale(current_phase(ctx, sim_time));
+    if !flare_sighted(hour as u64, chance) || storm_flare(ctx).is_some() || flare_incoming(ctx) {
+        return;
+    }
+    let severity = flare_severity(hour as u64);
+    incoming(
+        ctx,
+        contact_kinds::SOLAR_FLARE,
+        severity,
+        None,
+        sim_time + FLARE_LEAD_HOURS as f64,
+    );
+}
+
+/// Raise the alarm for a flare of `severity` whose particles arrive in
+/// `warning` hours: a SOLAR_FLARE event in the storm shelter, and everyone
+/// drops what they are doing to head there (see [`sheltering_activity`]).
+pub(super) fn warn_of_flare(ctx: &ReducerContext, sim_time: f64, severity: f32, warning: f32) {
+    let Some(shelter) = shelter_rooms(ctx).into_iter().next() else {
+        return;
+    };
+    ctx.db.event().insert(Event {
+        id: 0,
+        event_type: event_types::SOLAR_FLARE,
+        room_id: shelter,
+        started_at: sim_time,
+        duration: flare_hours(severity, warning),
+        state: event_states::ACTIVE,
+        responders_needed: 0,
+        responders_assigned: 0,
+        severity,
+        escalated_to: None,
+    });
+
+    let mut sent = 0;
+    for person in ctx
+        .db
+        .person()
+        .iter()
+        .filter(|p| p.is_alive && !p.is_player)
+    {
+        if let Some(mut activity) = ctx.db.activity().person_id().find(person.id) {
+            activity.duration = 0.0;
+            ctx.db.activity().person_id().update(activity);
+            sent += 1;
+        }
+    }
+    log::warn!(
+        "Solar flare sighted, severity {:.2}, {:.1}h out: {} people making for the storm shelter",
+        severity,
+        warning,
+        sent
+    );
+}
+
+/// The unresolved solar flare, if any.
+pub(super) fn storm_flare(ctx: &ReducerContext) -> Option<Event> {
+    ctx.db
+        .event()
+        .iter()
+        .find(|e| e.event_type == event_types::SOLAR_FLARE && e.state != event_states::RESOLVED)
+}
+
+/// Rooms on the storm shelter deck, muster stations first.
+fn shelter_rooms(ctx: &ReducerContext) -> Vec<u32> {
+    let Some(deck) = ctx.db.deck().iter().find(|d| d.storm_shelter) else {
+        return Vec::new();
+    };
+    let mut rooms: Vec<Room> = ctx
+        .db
+        .room()
+        .iter()
+        .filter(|r| r.deck == deck.deck && r.room_type < room_types::CORRIDOR)
+        .collect();
+    rooms.sort_by_key(|r| (!is_muster_station(r.room_type), r.id));
+    rooms.into_iter().map(|r| r.id).collect()
+}
+
+/// What someone does during a solar flare: shelter (EMERGENCY) in a room
+/// on the shelter deck until it blows over, staying put if already there.
+/// `None` when there is no flare, or for the injured, who see to their
+/// health first.
+pub fn sheltering_activity(
+    ctx: &ReducerContext,
+    person_id: u64,
+    sim_time: f64,
+) -> Option<(u8, f32, Option<u32>)> {
+    let flare = storm_flare(ctx)?;
+    let remaining = flare.started_at + flare.duration as f64 - sim_time;
+    if remaining <= 0.0 {
+        return None;
+    }
+    if ctx
+        .db
+        .needs()
+        .person_id()
+        .find(person_id)
+        .is_some_and(|n| should_seek_medical(n.health))
+    {
+        return None;
+    }
+    let shelter_deck = ctx.db.deck().iter().find(|d| d.storm_shelter)?.deck;
+    let here = ctx
+        .db
+        .position()
+        .person_id()
+        .find(person_id)
+        .and_then(|p| ctx.db.room().id().find(p.room_id));
+    let room = match here {
+        Some(room) if room.deck == shelter_deck => room.id,
+        _ => {
+            let rooms = shelter_rooms(ctx);
+            *rooms.get((person_id % rooms.len().max(1) as u64) as usize)?
+        }
+    };
+    Some((
+        activity_types::EMERGENCY,
+        remaining.min(1.0) as f32,
+        Some(room),
+    ))
+}
+
+/// Radiation exposure within one `tick_needs` pass: each deck's shielding
+/// and the dose rate outside the hull.
+pub struct Dosimetry {
+    transmission: HashMap<i32, f32>,
+    outside: f32,
+}
+
+impl Dosimetry {
+    pub fn load(ctx: &ReducerContext) -> Self {
+        let storm = storm_flare(ctx)
+            .filter(|_| !flare_incoming(ctx))
+            .map(|f| f.severity);
+        Self {
+            transmission: ctx
+                .db
+                .deck()
+                .iter()
+                .map(|d| (d.deck, d.radiation_transmission))
+                .collect(),
+            outside: outside_dose_rate(storm),
+        }
+    }
+
+    /// Dose someone on `deck` for `hours` and apply radiation sickness and
+    /// the lifetime limit to their `needs`.
+    pub fn expose(&self, ctx: &ReducerContext, deck: i32, needs: &mut Needs, hours: f32) {
+        let share = self.transmission.get(&deck).copied().unwrap_or(1.0);
+        self.dose(ctx, needs, share, hours);
+    }
+
+    /// Dose someone out on the hull, shielded only by their suit, for
+    /// `hours`.
+    pub fn expose_outside(&self, ctx: &ReducerContext, needs: &mut Needs, hours: f32) {
+        self.dose(ctx, needs, 1.0, hours);
+    }
+
+    fn dose(&self, ctx: &ReducerContext, needs: &mut Needs, share: f32, hours: f32) {
+        let dose = self.outside * share * hours;
+        let mut record = ctx
+            .db
+            .radiation_dose()
+            .person_id()
+            .find(needs.person_id)
+            .unwrap_or(RadiationDose {
+                person_id: needs.person_id,
+                lifetime_msv: 0.0,
+                acute_msv: 0.0,
+            });
+        record.lifetime_msv += dose;
+        record.acute_msv = acute_dose(record.acute_msv, dose, hours);
+        (needs.health, needs.fatigue, needs.comfort) = sickness_effects(
+            needs.health,
+            needs.fatigue,
+            needs.comfort,
+            record.acute_msv,
+            hours,
+        );
+        needs.health = needs.health.min(health_ceiling(record.lifetime_msv));
+        if ctx
+            .db
+            .radiation_dose()
+            .person_id()
+            .find(needs.person_id)
+            .is_some()
+        {
+            ctx.db.radiation_dose().person_id().update(record);
+        } else {
+            ctx.db.radiation_dose().insert(record);
+        }
+    }
+}
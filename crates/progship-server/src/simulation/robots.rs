@@ -0,0 +1,380 @@
+//! Robot system - maintenance robots working routine orders off the board,
+//! recharging in the Power Distribution rooms and breaking down, for crew
+//! to repair.
+
+use crate::tables::*;
+use progship_logic::cargo::REPAIR_PARTS_TONS;
+use progship_logic::numeric::roll;
+use progship_logic::robots::{
+    breakdown_chance, can_take, charge_after, charged, designation, needs_charge, repaired_health,
+    travel_hours, worn, BREAKDOWN_SALT, READY_CHARGE, REPAIR_HOURS, REPAIR_PRIORITY,
+    ROBOTS_PER_BAY, WORK_SPEED,
+};
+use progship_logic::work_orders::board_order;
+use spacetimedb::{ReducerContext, Table};
+use std::collections::{BTreeMap, HashSet};
+
+use super::fabrication::take_parts;
+use super::maintenance::{calculate_repair_progress, finish_repair};
+
+/// Build each Robotics Bay's robots, standing by in their bay.
+pub fn build_robots(ctx: &ReducerContext) {
+    let mut bays: Vec<u32> = ctx
+        .db
+        .room()
+        .iter()
+        .filter(|r| r.room_type == room_types::ROBOTICS_BAY)
+        .map(|r| r.id)
+        .collect();
+    bays.sort_unstable();
+    let mut built = 0;
+    for bay in bays {
+        for _ in 0..ROBOTS_PER_BAY {
+            ctx.db.robot().insert(Robot {
+                id: 0,
+                name: designation(built),
+                home_room_id: bay,
+                room_id: bay,
+                state: robot_states::IDLE,
+                work_order_id: None,
+                target_room_id: None,
+                arrives_at: 0.0,
+                charge: 1.0,
+                health: 1.0,
+            });
+            built += 1;
+        }
+    }
+    log::info!("{} maintenance robots built", built);
+}
+
+/// Run the robots: move them along, run down their batteries and wear
+/// them at work, progress the orders they are on site for, send the low
+/// off to recharge and break down the worn. Repairs of broken-down robots
+/// are put on the work order board hourly.
+pub fn tick_robots(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    let mut robots: Vec<Robot> = ctx.db.robot().iter().collect();
+    if robots.is_empty() {
+        return;
+    }
+    robots.sort_by_key(|r| r.id);
+    for robot in robots {
+        if robot.state != robot_states::BROKEN {
+            step_robot(ctx, robot, sim_time, delta_hours as f32);
+        }
+    }
+
+    if sim_time.floor() != (sim_time - delta_hours).floor() {
+        file_repairs(ctx, sim_time);
+    }
+}
+
+/// Hand the routine orders on the board, most urgent first, to the nearest
+/// robot standing by with charge enough, before the crew are given theirs.
+pub(super) fn robots_claim(ctx: &ReducerContext, sim_time: f64) {
+    let mut ready: Vec<Robot> = ctx
+        .db
+        .robot()
+        .iter()
+        .filter(|r| {
+            r.state == robot_states::IDLE && r.target_room_id.is_none() && r.charge >= READY_CHARGE
+        })
+        .collect();
+    if ready.is_empty() {
+        return;
+    }
+    ready.sort_by_key(|r| r.id);
+    let mut open: Vec<WorkOrder> = ctx
+        .db
+        .work_order()
+        .iter()
+        .filter(|o| {
+            o.state == work_order_states::OPEN && can_take(o.kind, o.department, o.priority)
+        })
+        .collect();
+    open.sort_by(|a, b| board_order((a.priority, a.created_at), (b.priority, b.created_at)));
+
+    for mut order in open {
+        let site = order.room_id.and_then(|id| place(ctx, id));
+        let hours_to = |robot: &Robot| match (site, place(ctx, robot.room_id)) {
+            (Some(site), Some(at)) => travel_hours(at, site),
+            _ => 0.0,
+        };
+        let Some(index) = (0..ready.len()).min_by(|&a, &b| {
+            hours_to(&ready[a])
+                .total_cmp(&hours_to(&ready[b]))
+                .then(ready[a].id.cmp(&ready[b].id))
+        }) else {
+            break;
+        };
+        let mut robot = ready.swap_remove(index);
+        order.state = work_order_states::CLAIMED;
+        order.assigned_robot_id = Some(robot.id);
+        order.claimed_at = Some(sim_time);
+        robot.state = robot_states::WORKING;
+        robot.work_order_id = Some(order.id);
+        if let Some(room_id) = order.room_id {
+            head_to(ctx, &mut robot, room_id, sim_time);
+        }
+        ctx.db.work_order().id().update(order);
+        ctx.db.robot().id().update(robot);
+    }
+}
+
+/// Whether any robot in `room_id` is broken down.
+pub(super) fn robots_broken(ctx: &ReducerContext, room_id: u32) -> bool {
+    ctx.db
+        .robot()
+        .iter()
+        .any(|r| r.state == robot_states::BROKEN && r.room_id == room_id)
+}
+
+/// Crew have repaired the broken-down robots where `order` was filed,
+/// fitting electronics from the nearest stores on `deck` or patching them
+/// up without. Flat robots get charge enough to reach a charging point.
+pub(super) fn fix_robots(ctx: &ReducerContext, order: &WorkOrder, deck: Option<i32>) {
+    let Some(room_id) = order.room_id else {
+        return;
+    };
+    let parts = take_parts(ctx, order.part_kind, order.required_parts, deck);
+    let health = repaired_health(parts >= order.required_parts);
+    let broken: Vec<Robot> = ctx
+        .db
+        .robot()
+        .iter()
+        .filter(|r| r.state == robot_states::BROKEN && r.room_id == room_id)
+        .collect();
+    for mut robot in broken {
+        robot.state = robot_states::IDLE;
+        robot.health = health;
+        robot.charge = robot.charge.max(READY_CHARGE);
+        log::info!("Robot {} repaired", robot.name);
+        ctx.db.robot().id().update(robot);
+    }
+}
+
+/// One step of a working robot: arriving, charging or running down,
+/// breaking down, working and heading off to recharge.
+fn step_robot(ctx: &ReducerContext, mut robot: Robot, sim_time: f64, hours: f32) {
+    let on_the_way = robot.target_room_id.is_some();
+    if let Some(target) = robot.target_room_id {
+        if sim_time >= robot.arrives_at {
+            robot.room_id = target;
+            robot.target_room_id = None;
+        }
+    }
+
+    if robot.state == robot_states::CHARGING && !on_the_way {
+        if is_powered(ctx, robot.room_id) {
+            robot.charge = charged(robot.charge, hours);
+        }
+        if robot.charge >= 1.0 {
+            robot.state = robot_states::IDLE;
+        }
+        ctx.db.robot().id().update(robot);
+        return;
+    }
+
+    let busy = on_the_way || robot.state == robot_states::WORKING;
+    robot.charge = charge_after(robot.charge, busy, hours);
+    if busy {
+        robot.health = worn(robot.health, hours);
+        let flat = robot.charge <= 0.0;
+        if flat || roll(robot.id, sim_time, BREAKDOWN_SALT) < breakdown_chance(robot.health, hours)
+        {
+            log::warn!(
+                "Robot {} {}",
+                robot.name,
+                if flat { "ran flat" } else { "broke down" }
+            );
+            release(ctx, &mut robot);
+            robot.state = robot_states::BROKEN;
+            robot.target_room_id = None;
+            ctx.db.robot().id().update(robot);
+            return;
+        }
+    }
+
+    match robot.state {
+        robot_states::WORKING if needs_charge(robot.charge) => {
+            release(ctx, &mut robot);
+            head_to_charge(ctx, &mut robot, sim_time);
+        }
+        robot_states::WORKING if robot.target_room_id.is_none() => {
+            work(ctx, &mut robot, sim_time, hours);
+        }
+        robot_states::IDLE if robot.charge < READY_CHARGE => {
+            head_to_charge(ctx, &mut robot, sim_time);
+        }
+        _ => {}
+    }
+    ctx.db.robot().id().update(robot);
+}
+
+/// Progress the order a robot is on site for, finishing the repair once it
+/// is done. A robot whose order was closed under it stands by.
+fn work(ctx: &ReducerContext, robot: &mut Robot, sim_time: f64, hours: f32) {
+    let order = robot
+        .work_order_id
+        .and_then(|id| ctx.db.work_order().id().find(id))
+        .filter(|o| o.state == work_order_states::CLAIMED && o.assigned_robot_id == Some(robot.id));
+    let Some(mut order) = order else {
+        robot.state = robot_states::IDLE;
+        robot.work_order_id = None;
+        return;
+    };
+    order.progress =
+        calculate_repair_progress(order.progress, hours * WORK_SPEED, order.duration_hours);
+    if order.progress >= 1.0 {
+        let deck = ctx.db.room().id().find(robot.room_id).map(|r| r.deck);
+        finish_repair(ctx, &order, deck, sim_time);
+        order.state = work_order_states::DONE;
+        order.completed_at = Some(sim_time);
+        robot.state = robot_states::IDLE;
+        robot.work_order_id = None;
+    }
+    ctx.db.work_order().id().update(order);
+}
+
+/// Put the order a robot holds back on the board.
+fn release(ctx: &ReducerContext, robot: &mut Robot) {
+    let Some(order_id) = robot.work_order_id.take() else {
+        return;
+    };
+    if let Some(mut order) = ctx.db.work_order().id().find(order_id) {
+        if order.state == work_order_states::CLAIMED && order.assigned_robot_id == Some(robot.id) {
+            order.state = work_order_states::OPEN;
+            order.assigned_robot_id = None;
+            order.claimed_at = None;
+            ctx.db.work_order().id().update(order);
+        }
+    }
+}
+
+/// Send a robot to the nearest Power Distribution room with power, any
+/// with none, or back to its bay on a ship without one.
+fn head_to_charge(ctx: &ReducerContext, robot: &mut Robot, sim_time: f64) {
+    robot.state = robot_states::CHARGING;
+    let Some(at) = place(ctx, robot.room_id) else {
+        return;
+    };
+    let points: Vec<Room> = ctx
+        .db
+        .room()
+        .iter()
+        .filter(|r| r.room_type == room_types::POWER_DISTRIBUTION)
+        .collect();
+    let live: Vec<&Room> = points.iter().filter(|r| is_powered(ctx, r.id)).collect();
+    let choices: Vec<&Room> = if live.is_empty() {
+        points.iter().collect()
+    } else {
+        live
+    };
+    let charger = choices
+        .into_iter()
+        .min_by(|a, b| {
+            travel_hours(at, (a.deck, a.x, a.y))
+                .total_cmp(&travel_hours(at, (b.deck, b.x, b.y)))
+                .then(a.id.cmp(&b.id))
+        })
+        .map_or(robot.home_room_id, |r| r.id);
+    head_to(ctx, robot, charger, sim_time);
+}
+
+/// Set a robot off for `room_id`.
+fn head_to(ctx: &ReducerContext, robot: &mut Robot, room_id: u32, sim_time: f64) {
+    if room_id == robot.room_id {
+        robot.target_room_id = None;
+        return;
+    }
+    let hours = match (place(ctx, robot.room_id), place(ctx, room_id)) {
+        (Some(from), Some(to)) => travel_hours(from, to),
+        _ => 0.0,
+    };
+    robot.target_room_id = Some(room_id);
+    robot.arrives_at = sim_time + hours;
+}
+
+/// File a repair for each room with broken-down robots and none on the
+/// board.
+fn file_repairs(ctx: &ReducerContext, sim_time: f64) {
+    let mut rooms: BTreeMap<u32, u32> = BTreeMap::new();
+    for robot in ctx
+        .db
+        .robot()
+        .iter()
+        .filter(|r| r.state == robot_states::BROKEN)
+    {
+        *rooms.entry(robot.room_id).or_default() += 1;
+    }
+    if rooms.is_empty() {
+        return;
+    }
+    let filed: HashSet<u32> = ctx
+        .db
+        .work_order()
+        .iter()
+        .filter(|o| {
+            o.kind == work_kinds::ROBOT_REPAIR
+                && matches!(
+                    o.state,
+                    work_order_states::OPEN | work_order_states::CLAIMED
+                )
+        })
+        .filter_map(|o| o.room_id)
+        .collect();
+    for (room_id, broken) in rooms {
+        if filed.contains(&room_id) {
+            continue;
+        }
+        let place = ctx
+            .db
+            .room()
+            .id()
+            .find(room_id)
+            .map_or_else(|| "the ship".to_string(), |r| r.name);
+        ctx.db.work_order().insert(WorkOrder {
+            id: 0,
+            kind: work_kinds::ROBOT_REPAIR,
+            state: work_order_states::OPEN,
+            reason: format!(
+                "{} broken-down robot{} in {}",
+                broken,
+                if broken == 1 { "" } else { "s" },
+                place
+            ),
+            department: departments::ENGINEERING,
+            required_skill: skill_types::ENGINEERING,
+            required_parts: REPAIR_PARTS_TONS,
+            part_kind: part_kinds::ELECTRONIC,
+            priority: REPAIR_PRIORITY,
+            room_id: Some(room_id),
+            subsystem_id: 0,
+            component_id: 0,
+            infra_edge_id: None,
+            event_id: None,
+            hull_damage_id: None,
+            assigned_crew_id: None,
+            assigned_robot_id: None,
+            progress: 0.0,
+            duration_hours: REPAIR_HOURS,
+            created_at: sim_time,
+            claimed_at: None,
+            completed_at: None,
+        });
+    }
+}
+
+/// Where `room_id` is, as a (deck, x, y).
+fn place(ctx: &ReducerContext, room_id: u32) -> Option<(i32, f32, f32)> {
+    ctx.db.room().id().find(room_id).map(|r| (r.deck, r.x, r.y))
+}
+
+/// Whether `room_id` has power; so it has until the grid says otherwise.
+fn is_powered(ctx: &ReducerContext, room_id: u32) -> bool {
+    ctx.db
+        .room_power()
+        .room_id()
+        .find(room_id)
+        .is_none_or(|p| p.powered)
+}
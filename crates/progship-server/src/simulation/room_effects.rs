@@ -0,0 +1,265 @@
+//! Room effect system - smoke, coolant spills and frost that build up,
+//! seep through open doors, fade and get cleaned up.
+
+use crate::tables::*;
+use progship_logic::doors::is_passable;
+use progship_logic::room_effects::{
+    cleanup_department, event_effect, seepage, slip_chance, slips, step, walk_factor,
+    CLEANUP_THRESHOLD, CLEARED, SLIP_INJURY,
+};
+use spacetimedb::{ReducerContext, Table};
+use std::collections::{HashMap, HashSet};
+
+use super::activities::restart_activity;
+use super::maintenance::crew_on_work_orders;
+use super::movement::start_movement_to;
+
+/// Hours a cleaner works on an effect before picking what to do next.
+const CLEANUP_HOURS: f32 = 1.0;
+
+/// Build, seep and fade every room effect, send crew to clean up the worst
+/// and trip people walking over wet or icy floors.
+pub fn tick_room_effects(ctx: &ReducerContext, sim_time: f64, delta_hours: f32) {
+    let sources = effect_sources(ctx);
+    let mut effects: HashMap<(u32, u8), RoomEffect> = ctx
+        .db
+        .room_effect()
+        .iter()
+        .map(|e| ((e.room_id, e.kind), e))
+        .collect();
+    for &(room_id, kind) in &sources {
+        effects.entry((room_id, kind)).or_insert(RoomEffect {
+            id: 0,
+            room_id,
+            kind,
+            intensity: 0.0,
+            cleaner_id: None,
+        });
+    }
+    let before: HashMap<(u32, u8), f32> = effects.iter().map(|(&k, e)| (k, e.intensity)).collect();
+
+    for effect in effects.values_mut() {
+        let cleaners = match effect.cleaner_id {
+            Some(id) if still_cleaning(ctx, id, effect.room_id) => {
+                let here = ctx
+                    .db
+                    .position()
+                    .person_id()
+                    .find(id)
+                    .is_some_and(|p| p.room_id == effect.room_id);
+                u32::from(here)
+            }
+            _ => {
+                effect.cleaner_id = None;
+                0
+            }
+        };
+        let source = sources.contains(&(effect.room_id, effect.kind));
+        effect.intensity = step(effect.kind, effect.intensity, source, cleaners, delta_hours);
+    }
+
+    // Seep through open doors, measured against intensities before this step
+    let mut seeped: HashMap<(u32, u8), f32> = HashMap::new();
+    for door in ctx.db.door().iter().filter(|d| is_passable(d.state)) {
+        for (&(room_id, kind), &intensity) in &before {
+            let neighbor = if room_id == door.room_a {
+                door.room_b
+            } else if room_id == door.room_b {
+                door.room_a
+            } else {
+                continue;
+            };
+            let there = before.get(&(neighbor, kind)).copied().unwrap_or(0.0);
+            let amount = seepage(kind, intensity, there, delta_hours);
+            if amount > 0.0 {
+                *seeped.entry((room_id, kind)).or_default() -= amount;
+                *seeped.entry((neighbor, kind)).or_default() += amount;
+            }
+        }
+    }
+    for ((room_id, kind), amount) in seeped {
+        let effect = effects.entry((room_id, kind)).or_insert(RoomEffect {
+            id: 0,
+            room_id,
+            kind,
+            intensity: 0.0,
+            cleaner_id: None,
+        });
+        effect.intensity = (effect.intensity + amount).clamp(0.0, 1.0);
+    }
+
+    for (key, effect) in effects {
+        let was = before.get(&key).copied();
+        let building = was.is_none_or(|w| effect.intensity > w);
+        if effect.intensity < CLEARED && !building {
+            if effect.id != 0 {
+                ctx.db.room_effect().id().delete(effect.id);
+            }
+        } else if effect.id == 0 {
+            if effect.intensity > 0.0 {
+                ctx.db.room_effect().insert(effect);
+            }
+        } else {
+            ctx.db.room_effect().id().update(effect);
+        }
+    }
+
+    assign_cleaners(ctx, sim_time);
+    trip_walkers(ctx, sim_time, delta_hours);
+}
+
+/// Walking speed multiplier per room with an effect in it.
+pub fn walk_factors(ctx: &ReducerContext) -> HashMap<u32, f32> {
+    let mut factors: HashMap<u32, f32> = HashMap::new();
+    for effect in ctx.db.room_effect().iter() {
+        *factors.entry(effect.room_id).or_insert(1.0) *= walk_factor(effect.kind, effect.intensity);
+    }
+    factors
+}
+
+/// (room, effect kind) pairs whose source is still going: rooms with a
+/// live fire or system failure, and the rooms of failed coolant and
+/// climate-control plant.
+fn effect_sources(ctx: &ReducerContext) -> HashSet<(u32, u8)> {
+    let mut sources: HashSet<(u32, u8)> = ctx
+        .db
+        .event()
+        .iter()
+        .filter(|e| e.state != event_states::RESOLVED)
+        .filter_map(|e| event_effect(e.event_type).map(|kind| (e.room_id, kind)))
+        .collect();
+    let room_of_node: HashMap<u64, u32> = ctx.db.room().iter().map(|r| (r.node_id, r.id)).collect();
+    for sub in
+        ctx.db.subsystem().iter().filter(|s| {
+            s.status == system_statuses::OFFLINE || s.status == system_statuses::DESTROYED
+        })
+    {
+        let kind = match sub.subsystem_type {
+            subsystem_types::COOLANT_PUMP | subsystem_types::REACTOR_COOLING => {
+                effect_kinds::COOLANT_SPILL
+            }
+            subsystem_types::AIR_CIRCULATION | subsystem_types::HEAT_EXCHANGE => {
+                effect_kinds::FROST
+            }
+            _ => continue,
+        };
+        if let Some(&room_id) = room_of_node.get(&sub.node_id) {
+            sources.insert((room_id, kind));
+        }
+    }
+    sources
+}
+
+/// Whether `person_id` is still on the cleanup of `room_id` they were sent to.
+fn still_cleaning(ctx: &ReducerContext, person_id: u64, room_id: u32) -> bool {
+    let alive = ctx
+        .db
+        .person()
+        .id()
+        .find(person_id)
+        .is_some_and(|p| p.is_alive);
+    alive
+        && ctx
+            .db
+            .activity()
+            .person_id()
+            .find(person_id)
+            .is_some_and(|a| {
+                a.activity_type == activity_types::MAINTENANCE && a.target_room_id == Some(room_id)
+            })
+}
+
+/// Send an on-duty crew member of the right department to each effect
+/// strong enough to need cleaning up, preferring someone on the same deck.
+fn assign_cleaners(ctx: &ReducerContext, sim_time: f64) {
+    let mut busy: HashSet<u64> = ctx
+        .db
+        .room_effect()
+        .iter()
+        .filter_map(|e| e.cleaner_id)
+        .collect();
+    busy.extend(crew_on_work_orders(ctx));
+    let deck_of = |person_id: u64| {
+        ctx.db
+            .position()
+            .person_id()
+            .find(person_id)
+            .and_then(|p| ctx.db.room().id().find(p.room_id))
+            .map(|r| r.deck)
+    };
+
+    let dirty: Vec<RoomEffect> = ctx
+        .db
+        .room_effect()
+        .iter()
+        .filter(|e| e.cleaner_id.is_none() && e.intensity >= CLEANUP_THRESHOLD)
+        .collect();
+    for mut effect in dirty {
+        let department = cleanup_department(effect.kind);
+        let deck = ctx.db.room().id().find(effect.room_id).map(|r| r.deck);
+        let cleaner = ctx
+            .db
+            .crew()
+            .iter()
+            .filter(|c| c.on_duty && c.department == department && !busy.contains(&c.person_id))
+            .filter(|c| {
+                ctx.db
+                    .person()
+                    .id()
+                    .find(c.person_id)
+                    .is_some_and(|p| p.is_alive && !p.is_player)
+            })
+            .min_by_key(|c| (deck_of(c.person_id) != deck, c.person_id))
+            .map(|c| c.person_id);
+        let Some(cleaner) = cleaner else {
+            continue;
+        };
+        let Some(mut activity) = ctx.db.activity().person_id().find(cleaner) else {
+            continue;
+        };
+        restart_activity(
+            &mut activity,
+            activity_types::MAINTENANCE,
+            sim_time,
+            CLEANUP_HOURS,
+        );
+        activity.target_room_id = Some(effect.room_id);
+        ctx.db.activity().person_id().update(activity);
+        start_movement_to(ctx, cleaner, effect.room_id);
+        busy.insert(cleaner);
+        effect.cleaner_id = Some(cleaner);
+        ctx.db.room_effect().id().update(effect);
+    }
+}
+
+/// Roll a slip for everyone walking through a spill or frost.
+fn trip_walkers(ctx: &ReducerContext, sim_time: f64, delta_hours: f32) {
+    let slippery: Vec<RoomEffect> = ctx
+        .db
+        .room_effect()
+        .iter()
+        .filter(|e| slip_chance(e.kind, e.intensity, 1.0) > 0.0)
+        .collect();
+    if slippery.is_empty() {
+        return;
+    }
+    for mov in ctx.db.movement().iter() {
+        let Some(pos) = ctx.db.position().person_id().find(mov.person_id) else {
+            continue;
+        };
+        let chance = slippery
+            .iter()
+            .filter(|e| e.room_id == pos.room_id)
+            .map(|e| slip_chance(e.kind, e.intensity, delta_hours))
+            .fold(0.0, f32::max);
+        if !slips(mov.person_id, sim_time, chance) {
+            continue;
+        }
+        if let Some(mut needs) = ctx.db.needs().person_id().find(mov.person_id) {
+            needs.health = (needs.health - SLIP_INJURY).max(0.0);
+            needs.comfort = (needs.comfort + 0.1).min(1.0);
+            ctx.db.needs().person_id().update(needs);
+            log::info!("Person {} slipped in room {}", mov.person_id, pos.room_id);
+        }
+    }
+}
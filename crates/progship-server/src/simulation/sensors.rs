@@ -0,0 +1,109 @@
+//! Sensor system - contacts on their way to the ship, spotted in time or
+//! not depending on the sensor array's health and the watch kept on the
+//! bridge and in the CIC.
+
+use crate::tables::*;
+use progship_logic::sensors::{detection, is_spotted, is_watch_post, lead_hours};
+use spacetimedb::{ReducerContext, Table};
+
+use super::hazards::{brace_for_impact, strike};
+use super::radiation::warn_of_flare;
+
+/// Track every contact: raise the alarm for those the sensors spot, and
+/// let those that arrive strike, with no warning at all if they were
+/// never spotted.
+pub fn tick_sensors(ctx: &ReducerContext, sim_time: f64) {
+    let contacts: Vec<SensorContact> = ctx.db.sensor_contact().iter().collect();
+    if contacts.is_empty() {
+        return;
+    }
+    let seeing = detection(sensor_health(ctx), watch(ctx));
+    for mut contact in contacts {
+        let remaining = (contact.arrives_at - sim_time) as f32;
+        if contact.spotted_at.is_none() && is_spotted(remaining, lead_hours(contact.kind), seeing) {
+            contact.spotted_at = Some(sim_time);
+            log::warn!(
+                "Sensor contact: {} ({:.0}%), {:.1}h out",
+                progship_logic::constants::contact_kinds::name(contact.kind),
+                contact.severity * 100.0,
+                remaining.max(0.0)
+            );
+            match contact.kind {
+                contact_kinds::SOLAR_FLARE => {
+                    warn_of_flare(ctx, sim_time, contact.severity, remaining.max(0.0))
+                }
+                _ => brace_for_impact(ctx, &contact),
+            }
+            ctx.db.sensor_contact().id().update(contact.clone());
+        }
+        if remaining > 0.0 {
+            continue;
+        }
+        ctx.db.sensor_contact().id().delete(contact.id);
+        match contact.kind {
+            contact_kinds::SOLAR_FLARE if contact.spotted_at.is_none() => {
+                log::warn!("Solar flare arrived unseen");
+                warn_of_flare(ctx, sim_time, contact.severity, 0.0);
+            }
+            contact_kinds::SOLAR_FLARE => {}
+            _ => strike(ctx, &contact, sim_time),
+        }
+    }
+}
+
+/// Track a hazard of `kind` (see `contact_kinds`) arriving at `arrives_at`,
+/// aimed at `room_id` if it strikes a room.
+pub(super) fn incoming(
+    ctx: &ReducerContext,
+    kind: u8,
+    severity: f32,
+    room_id: Option<u32>,
+    arrives_at: f64,
+) {
+    ctx.db.sensor_contact().insert(SensorContact {
+        id: 0,
+        kind,
+        severity,
+        room_id,
+        arrives_at,
+        spotted_at: None,
+    });
+}
+
+/// Whether a flare's particles are still on their way.
+pub(super) fn flare_incoming(ctx: &ReducerContext) -> bool {
+    ctx.db
+        .sensor_contact()
+        .iter()
+        .any(|c| c.kind == contact_kinds::SOLAR_FLARE)
+}
+
+/// Health of the best working sensor array; 0 with none online.
+fn sensor_health(ctx: &ReducerContext) -> f32 {
+    ctx.db
+        .subsystem()
+        .iter()
+        .filter(|s| {
+            s.subsystem_type == subsystem_types::SENSOR_ARRAY
+                && s.status != system_statuses::OFFLINE
+        })
+        .map(|s| s.health)
+        .fold(0.0, f32::max)
+}
+
+/// Crew on duty at the sensor posts.
+fn watch(ctx: &ReducerContext) -> u32 {
+    ctx.db
+        .crew()
+        .iter()
+        .filter(|c| c.on_duty)
+        .filter(|c| {
+            ctx.db
+                .position()
+                .person_id()
+                .find(c.person_id)
+                .and_then(|p| ctx.db.room().id().find(p.room_id))
+                .is_some_and(|r| is_watch_post(r.room_type))
+        })
+        .count() as u32
+}
@@ -2,14 +2,18 @@
 
 use crate::tables::*;
 use progship_logic::economy;
+use progship_logic::governance::effective_rationing;
 use spacetimedb::{ReducerContext, Table};
+use std::collections::HashSet;
+
+use super::governance::current_policy;
 
 // Resource consumption rates (per person per hour)
 const FOOD_RATE: f32 = 2.0 / 24.0;
 const WATER_RATE: f32 = 3.0 / 24.0;
 const OXYGEN_RATE: f32 = 0.84 / 24.0;
 
-fn resource_values(r: &ShipResources) -> economy::ResourceValues {
+pub(super) fn resource_values(r: &ShipResources) -> economy::ResourceValues {
     economy::ResourceValues {
         food: r.food,
         food_cap: r.food_cap,
@@ -62,6 +66,17 @@ pub fn health_to_status(health: f32) -> u8 {
     }
 }
 
+/// Graph nodes of the rooms the power grid shed on its last tick.
+pub(super) fn shed_nodes(ctx: &ReducerContext) -> HashSet<u64> {
+    ctx.db
+        .room_power()
+        .iter()
+        .filter(|p| !p.powered)
+        .filter_map(|p| ctx.db.room().id().find(p.room_id))
+        .map(|r| r.node_id)
+        .collect()
+}
+
 /// Update ship systems: resource production, consumption, degradation, economy.
 pub fn tick_ship_systems(ctx: &ReducerContext, delta_hours: f32) {
     let Some(mut resources) = ctx.db.ship_resources().id().find(0) else {
@@ -72,62 +87,42 @@ pub fn tick_ship_systems(ctx: &ReducerContext, delta_hours: f32) {
 
     // Compute current rationing level
     let levels = economy::compute_levels(&resource_values(&resources));
-    let rationing = economy::compute_rationing(&levels);
+    let rationing = effective_rationing(
+        economy::compute_rationing(&levels),
+        current_policy(ctx).rationing,
+    );
     let consumption_factor = economy::rationing_consumption_factor(rationing);
 
-    // Base consumption adjusted by rationing
-    let (food_consumed, water_consumed, oxygen_consumed) =
+    // Base consumption adjusted by rationing; food is eaten as meals from
+    // the galleys (see `tick_food`)
+    let (_, water_consumed, oxygen_consumed) =
         calculate_resource_consumption(alive_count, delta_hours);
 
-    resources.food = (resources.food - food_consumed * consumption_factor).max(0.0);
     resources.water = (resources.water - water_consumed * consumption_factor).max(0.0);
     resources.oxygen = (resources.oxygen - oxygen_consumed).max(0.0); // O2 can't be rationed
 
-    // Subsystem-level production/consumption and degradation
+    // Subsystem-level production; power itself flows in `tick_power`,
+    // crops ripen in `tick_food`, the drive burns in `tick_navigation`, and
+    // subsystems in rooms it shed stand idle
+    let shed = shed_nodes(ctx);
     let subsystems: Vec<Subsystem> = ctx.db.subsystem().iter().collect();
     for sub in &subsystems {
         let efficiency = calculate_subsystem_efficiency(sub.health, sub.status);
-        if efficiency == 0.0 {
+        if efficiency == 0.0 || (sub.power_draw > 0.0 && shed.contains(&sub.node_id)) {
             continue;
         }
 
-        // Production based on subsystem type
+        // Production at the subsystem's rated output
+        let produced = sub.output * efficiency * delta_hours;
         match sub.subsystem_type {
-            subsystem_types::REACTOR_CORE => {
-                resources.power =
-                    (resources.power + 100.0 * efficiency * delta_hours).min(resources.power_cap);
-            }
-            subsystem_types::EMERGENCY_GENERATOR => {
-                // Only produces if main reactor is down
-                let reactor_down = subsystems.iter().any(|s| {
-                    s.subsystem_type == subsystem_types::REACTOR_CORE
-                        && (s.status == system_statuses::OFFLINE
-                            || s.status == system_statuses::DESTROYED)
-                });
-                if reactor_down {
-                    resources.power = (resources.power + 30.0 * efficiency * delta_hours)
-                        .min(resources.power_cap);
-                }
-            }
             subsystem_types::O2_GENERATION => {
-                let o2_produced = alive_count * OXYGEN_RATE * efficiency * delta_hours;
-                resources.oxygen = (resources.oxygen + o2_produced).min(resources.oxygen_cap);
+                resources.oxygen = (resources.oxygen + produced).min(resources.oxygen_cap);
             }
             subsystem_types::WATER_FILTRATION | subsystem_types::WATER_DISTILLATION => {
-                let recycled = alive_count * WATER_RATE * 0.45 * efficiency * delta_hours;
-                resources.water = (resources.water + recycled).min(resources.water_cap);
-            }
-            subsystem_types::GROWTH_CHAMBER => {
-                resources.food =
-                    (resources.food + 5.0 * efficiency * delta_hours).min(resources.food_cap);
+                resources.water = (resources.water + produced).min(resources.water_cap);
             }
             _ => {}
         }
-
-        // Power consumption from subsystem power_draw
-        if sub.power_draw > 0.0 {
-            resources.power = (resources.power - sub.power_draw * delta_hours).max(0.0);
-        }
     }
 
     // Degrade subsystems slowly, update their status
@@ -135,7 +130,11 @@ pub fn tick_ship_systems(ctx: &ReducerContext, delta_hours: f32) {
     for sub in subsystems_for_update {
         let mut s = sub;
         s.health = (s.health - 0.0001 * delta_hours).max(0.0);
-        s.status = health_to_status(s.health);
+        s.status = if s.power_draw > 0.0 && shed.contains(&s.node_id) {
+            system_statuses::OFFLINE
+        } else {
+            health_to_status(s.health)
+        };
         ctx.db.subsystem().id().update(s);
     }
 
@@ -179,8 +178,12 @@ pub fn tick_ship_systems(ctx: &ReducerContext, delta_hours: f32) {
     let all_infra_edges: Vec<InfraEdge> = ctx.db.infra_edge().iter().collect();
     let graph_edges: Vec<GraphEdge> = ctx.db.graph_edge().iter().collect();
     for ge in graph_edges {
-        // Skip crew paths — only infrastructure edges
-        if ge.edge_type == edge_types::CREW_PATH {
+        // Skip crew paths — only infrastructure edges; power, water and
+        // HVAC flow come from their own networks
+        if matches!(
+            ge.edge_type,
+            edge_types::CREW_PATH | edge_types::POWER | edge_types::WATER | edge_types::HVAC
+        ) {
             continue;
         }
         let infra_health = all_infra_edges
@@ -200,20 +203,30 @@ pub fn tick_ship_systems(ctx: &ReducerContext, delta_hours: f32) {
         }
     }
 
-    ctx.db.ship_resources().id().update(resources);
+    let res = ctx.db.ship_resources().id().update(resources);
 
     // --- Economy effects: scarcity, rationing, morale, health ---
 
     // Recompute levels after production/consumption
-    let res = ctx.db.ship_resources().id().find(0).unwrap();
     let updated_levels = economy::compute_levels(&resource_values(&res));
-    let new_rationing = economy::compute_rationing(&updated_levels);
+    let new_rationing = effective_rationing(
+        economy::compute_rationing(&updated_levels),
+        current_policy(ctx).rationing,
+    );
 
-    // Update rationing level on ShipConfig
+    // Update rationing level on ShipConfig, noting when rationing began
+    let mut rationed_hours = 0.0;
     if let Some(config) = ctx.db.ship_config().id().find(0) {
         let old_rationing = economy::u8_to_rationing(config.rationing_level);
+        let mut c = config;
+        if old_rationing == economy::RationingLevel::Normal && new_rationing != old_rationing {
+            c.rationing_since = c.sim_time;
+        }
+        // Weariness keeps building through changes between rationed levels
+        if new_rationing != economy::RationingLevel::Normal {
+            rationed_hours = (c.sim_time - c.rationing_since).max(0.0) as f32;
+        }
         if new_rationing != old_rationing {
-            let mut c = config;
             c.rationing_level = economy::rationing_to_u8(new_rationing);
             ctx.db.ship_config().id().update(c);
         }
@@ -245,6 +258,7 @@ pub fn tick_ship_systems(ctx: &ReducerContext, delta_hours: f32) {
                 responders_needed: 0,
                 responders_assigned: 0,
                 severity,
+                escalated_to: None,
             });
             log::warn!(
                 "Resource shortage: {} at {:.0}%",
@@ -255,8 +269,10 @@ pub fn tick_ship_systems(ctx: &ReducerContext, delta_hours: f32) {
         }
     }
 
-    // Morale and health effects from rationing/depletion
-    let morale_penalty = economy::rationing_morale_penalty(new_rationing) * delta_hours;
+    // Morale and health effects from rationing/depletion, rationing wearing
+    // harder the longer it lasts
+    let morale_penalty =
+        economy::rationing_morale_penalty_over(new_rationing, rationed_hours) * delta_hours;
     let health_damage = economy::resource_health_damage(&updated_levels) * delta_hours;
 
     if morale_penalty > 0.0 || health_damage > 0.0 {
@@ -0,0 +1,44 @@
+//! Shuttle system - fuel burn away from the ship and refueling in the bay.
+
+use crate::tables::*;
+use progship_logic::shuttles::{burn, refuel_amount};
+use spacetimedb::{ReducerContext, Table};
+
+/// Burn fuel on launched shuttles, recalling any down to their reserve,
+/// and refuel docked shuttles from the ship's fuel.
+pub fn tick_shuttles(ctx: &ReducerContext, delta_hours: f64) {
+    let hours = delta_hours as f32;
+    let mut resources = ctx.db.ship_resources().id().find(0);
+    let mut drawn = 0.0;
+    let shuttles: Vec<Shuttle> = ctx.db.shuttle().iter().collect();
+    for mut shuttle in shuttles {
+        if shuttle.state == shuttle_states::LAUNCHED {
+            let (fuel, recall) = burn(shuttle.fuel, shuttle.fuel_cap, hours);
+            shuttle.fuel = fuel;
+            if recall {
+                log::info!("Shuttle {} is turning back on reserve fuel", shuttle.name);
+                dock(&mut shuttle);
+            }
+            ctx.db.shuttle().id().update(shuttle);
+        } else if let Some(res) = resources.as_mut() {
+            let pumped = refuel_amount(shuttle.fuel, shuttle.fuel_cap, res.fuel, hours);
+            if pumped > 0.0 {
+                res.fuel -= pumped;
+                drawn += pumped;
+                shuttle.fuel += pumped;
+                ctx.db.shuttle().id().update(shuttle);
+            }
+        }
+    }
+    if drawn > 0.0 {
+        if let Some(res) = resources {
+            ctx.db.ship_resources().id().update(res);
+        }
+    }
+}
+
+/// Bring a shuttle back into its bay.
+pub fn dock(shuttle: &mut Shuttle) {
+    shuttle.state = shuttle_states::DOCKED;
+    shuttle.launched_at = None;
+}
@@ -0,0 +1,90 @@
+//! Sleep system - how well the sleeping rest given their room's crowding,
+//! its loud neighbors, the deck's temperature and lights and their watch.
+
+use std::collections::HashMap;
+
+use crate::tables::*;
+use progship_logic::lighting::circadian_morale;
+use progship_logic::sleep::{is_loud, on_schedule, sleep_quality, touches, SleepConditions};
+use progship_logic::utility::noise_level;
+use spacetimedb::{ReducerContext, Table};
+
+/// Sleeping conditions within one `tick_needs` pass: who is in each room,
+/// the loud rooms on each deck and each deck's temperature and lights.
+pub struct Bedrooms {
+    hour: f32,
+    occupants: HashMap<u32, u32>,
+    loud: Vec<(u32, i32, (f32, f32, f32, f32), f32)>,
+    temperatures: HashMap<u32, f32>,
+    light_modes: HashMap<i32, u8>,
+    neighbor_noise: HashMap<u32, f32>,
+}
+
+impl Bedrooms {
+    pub fn load(ctx: &ReducerContext, sim_time: f64) -> Self {
+        let mut occupants = HashMap::new();
+        for position in ctx.db.position().iter() {
+            *occupants.entry(position.room_id).or_default() += 1;
+        }
+        Self {
+            hour: (sim_time % 24.0) as f32,
+            occupants,
+            loud: ctx
+                .db
+                .room()
+                .iter()
+                .filter(|r| is_loud(r.room_type))
+                .map(|r| {
+                    let rect = (r.x, r.y, r.width, r.height);
+                    (r.id, r.deck, rect, noise_level(r.room_type))
+                })
+                .collect(),
+            temperatures: ctx
+                .db
+                .room_air()
+                .iter()
+                .map(|a| (a.room_id, a.temperature))
+                .collect(),
+            light_modes: ctx.db.lighting().iter().map(|l| (l.deck, l.mode)).collect(),
+            neighbor_noise: HashMap::new(),
+        }
+    }
+
+    /// Quality of sleep for `person_id` in `room` now (see
+    /// `sleep::sleep_quality`).
+    pub fn quality(&mut self, ctx: &ReducerContext, person_id: u64, room: &Room) -> f32 {
+        let loud = &self.loud;
+        let neighbor_noise = *self.neighbor_noise.entry(room.id).or_insert_with(|| {
+            let rect = (room.x, room.y, room.width, room.height);
+            loud.iter()
+                .filter(|(id, deck, _, _)| *id != room.id && *deck == room.deck)
+                .filter(|(_, _, other, _)| touches(rect, *other))
+                .map(|(_, _, _, noise)| *noise)
+                .fold(0.0, f32::max)
+        });
+        let shift = ctx.db.crew().person_id().find(person_id).map(|c| c.shift);
+        sleep_quality(&SleepConditions {
+            occupants: self.occupants.get(&room.id).copied().unwrap_or(1),
+            capacity: room.capacity,
+            neighbor_noise,
+            temperature: self.temperatures.get(&room.id).copied().unwrap_or(21.0),
+            on_schedule: on_schedule(shift, self.hour),
+            light_mode: self.light_mode(room.deck),
+        })
+    }
+
+    /// Morale change an hour for `person_id` on `deck` from its lights
+    /// being in or out of step with their body clock (see
+    /// `lighting::circadian_morale`).
+    pub fn light_morale(&self, ctx: &ReducerContext, person_id: u64, deck: i32) -> f32 {
+        let shift = ctx.db.crew().person_id().find(person_id).map(|c| c.shift);
+        circadian_morale(self.light_mode(deck), on_schedule(shift, self.hour))
+    }
+
+    fn light_mode(&self, deck: i32) -> u8 {
+        self.light_modes
+            .get(&deck)
+            .copied()
+            .unwrap_or(lighting_modes::DAY)
+    }
+}
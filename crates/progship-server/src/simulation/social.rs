@@ -1,10 +1,30 @@
 //! Social system - conversations and relationships between people.
 
 use crate::tables::*;
+use progship_logic::constants::relationship_types::is_kin;
+use progship_logic::emotions::emotion_topic;
+use progship_logic::inventory::GIFT_BOND;
+use progship_logic::relationships::{
+    crowded_out, crowding_decay, decay, forgotten, partner_score, social_capacity,
+};
+use progship_logic::timeline::TimelineKind;
 use spacetimedb::{ReducerContext, Table};
+use std::collections::HashMap;
+
+use super::cryo::sleepers;
+use super::factions::sway_factions;
+use super::inventory::share_rations;
+use super::timeline::record_timeline;
+
+/// Start and end conversations between nearby people, each seeking out
+/// whoever in the room they are closest to. Daily: ties fade without
+/// contact and past what people can keep up.
+pub fn tick_social(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    let day = (sim_time / 24.0).floor();
+    if day != ((sim_time - delta_hours) / 24.0).floor() {
+        maintain_relationships(ctx, sim_time);
+    }
 
-/// Start and end conversations between nearby people.
-pub fn tick_social(ctx: &ReducerContext, sim_time: f64) {
     // End expired conversations
     let conversations: Vec<Conversation> = ctx.db.conversation().iter().collect();
     for conv in conversations {
@@ -20,11 +40,14 @@ pub fn tick_social(ctx: &ReducerContext, sim_time: f64) {
 
     // Find people in the same room who aren't in conversations
     let positions: Vec<Position> = ctx.db.position().iter().collect();
-    let mut room_occupants: std::collections::HashMap<u32, Vec<u64>> =
-        std::collections::HashMap::new();
+    let mut room_occupants: HashMap<u32, Vec<u64>> = HashMap::new();
+    let asleep = sleepers(ctx);
 
     for pos in &positions {
-        // Skip people already in conversations or moving
+        // Skip people already in conversations, moving or in cryosleep
+        if asleep.contains(&pos.person_id) {
+            continue;
+        }
         if ctx
             .db
             .in_conversation()
@@ -43,15 +66,42 @@ pub fn tick_social(ctx: &ReducerContext, sim_time: f64) {
             .push(pos.person_id);
     }
 
-    // Start conversations between pairs in the same room
+    // Start conversations between pairs in the same room: the first
+    // available person and whoever there they most want to talk to
+    let initiators: Vec<u64> = room_occupants
+        .values()
+        .filter(|people| people.len() >= 2)
+        .filter_map(|people| people.first().copied())
+        .collect();
+    if initiators.is_empty() {
+        return;
+    }
+    let mut ties: HashMap<(u64, u64), (f32, f32)> = HashMap::new();
+    for r in ctx.db.relationship().iter() {
+        if initiators.contains(&r.person_a) || initiators.contains(&r.person_b) {
+            ties.insert((r.person_a, r.person_b), (r.strength, r.familiarity));
+            ties.insert((r.person_b, r.person_a), (r.strength, r.familiarity));
+        }
+    }
     for people in room_occupants.values() {
-        if people.len() < 2 {
+        let [a, rest @ ..] = people.as_slice() else {
             continue;
-        }
-
-        // Deterministic pairing: first two available
-        let a = people[0];
-        let b = people[1];
+        };
+        let a = *a;
+        let extraversion = ctx
+            .db
+            .personality()
+            .person_id()
+            .find(a)
+            .map_or(0.5, |p| p.extraversion);
+        let score = |b: u64| partner_score(ties.get(&(a, b)).copied(), extraversion);
+        let Some(b) = rest
+            .iter()
+            .copied()
+            .max_by(|&x, &y| score(x).total_cmp(&score(y)).then(y.cmp(&x)))
+        else {
+            continue;
+        };
 
         // Check social need - only start if someone is lonely enough
         let needs_a = ctx.db.needs().person_id().find(a);
@@ -95,6 +145,17 @@ fn start_conversation(ctx: &ReducerContext, person_a: u64, person_b: u64, sim_ti
         person_id: person_b,
         conversation_id: conv_id,
     });
+    set_interaction_target(ctx, person_a, Some(person_b));
+    set_interaction_target(ctx, person_b, Some(person_a));
+    for (person, other) in [(person_a, person_b), (person_b, person_a)] {
+        record_timeline(
+            ctx,
+            person,
+            TimelineKind::Conversation,
+            other as u32,
+            sim_time,
+        );
+    }
 
     // Update or create relationship
     update_relationship(ctx, person_a, person_b, sim_time, 0.02);
@@ -135,8 +196,16 @@ fn end_conversation(ctx: &ReducerContext, conv_id: u64, sim_time: f64) {
             }
         }
 
+        // Friends share their rations with the hungry, and grow closer for it
+        let strength_delta = if share_rations(ctx, participant_a, participant_b, sim_time) {
+            strength_delta + GIFT_BOND
+        } else {
+            strength_delta
+        };
+
         // Update relationship
         update_relationship(ctx, participant_a, participant_b, sim_time, strength_delta);
+        sway_factions(ctx, participant_a, participant_b, conv.topic, sim_time);
 
         ctx.db.conversation().id().update(conv);
 
@@ -151,10 +220,22 @@ fn end_conversation(ctx: &ReducerContext, conv_id: u64, sim_time: f64) {
                 ctx.db.in_conversation().person_id().delete(participant_b);
             }
         }
+        set_interaction_target(ctx, participant_a, None);
+        set_interaction_target(ctx, participant_b, None);
     }
 }
 
-/// Select conversation topic based on relationship, personality, and context
+/// Point a person's activity at who they are talking to, or clear it.
+pub fn set_interaction_target(ctx: &ReducerContext, person_id: u64, target: Option<u64>) {
+    if let Some(mut activity) = ctx.db.activity().person_id().find(person_id) {
+        if activity.target_person_id != target {
+            activity.target_person_id = target;
+            ctx.db.activity().person_id().update(activity);
+        }
+    }
+}
+
+/// Select conversation topic based on relationship, mood, personality, and context
 fn select_conversation_topic(
     ctx: &ReducerContext,
     person_a: u64,
@@ -213,6 +294,18 @@ fn select_conversation_topic(
         return conversation_topics::GREETING;
     }
 
+    // Mood colors what people want to talk about
+    let emotion_a = ctx
+        .db
+        .emotion()
+        .person_id()
+        .find(person_a)
+        .map(|e| e.emotion)
+        .unwrap_or(emotions::CONTENT);
+    if let Some(topic) = emotion_topic(emotion_a, seed) {
+        return topic;
+    }
+
     // Low morale + high neuroticism → complaints or arguments
     if morale_a < 0.3 && neuroticism_a > 0.6 {
         if agreeableness_b < 0.4 && seed < 3.0 {
@@ -267,8 +360,10 @@ fn update_relationship(
             r.strength = (r.strength + strength_delta).clamp(-1.0, 1.0);
             r.familiarity = (r.familiarity + 0.01).min(1.0);
             r.last_interaction = sim_time;
-            // Update relationship type based on strength
-            r.relationship_type = classify_relationship(r.strength, r.familiarity);
+            // Update relationship type based on strength; family ties stay family
+            if !is_kin(r.relationship_type) {
+                r.relationship_type = classify_relationship(r.strength, r.familiarity);
+            }
             ctx.db.relationship().id().update(r);
             return;
         }
@@ -286,6 +381,70 @@ fn update_relationship(
     });
 }
 
+/// Let every tie fade by a day without contact, close ties past what
+/// someone can keep up fade faster, and forget ties that are gone.
+fn maintain_relationships(ctx: &ReducerContext, sim_time: f64) {
+    let relationships: Vec<Relationship> = ctx.db.relationship().iter().collect();
+    let mut close: HashMap<u64, Vec<(u64, f32)>> = HashMap::new();
+    for r in relationships
+        .iter()
+        .filter(|r| !is_kin(r.relationship_type))
+    {
+        close
+            .entry(r.person_a)
+            .or_default()
+            .push((r.id, r.strength));
+        close
+            .entry(r.person_b)
+            .or_default()
+            .push((r.id, r.strength));
+    }
+    let mut crowded: HashMap<u64, u32> = HashMap::new();
+    for (person_id, ties) in &close {
+        let extraversion = ctx
+            .db
+            .personality()
+            .person_id()
+            .find(*person_id)
+            .map_or(0.5, |p| p.extraversion);
+        for id in crowded_out(ties, social_capacity(extraversion)) {
+            *crowded.entry(id).or_default() += 1;
+        }
+    }
+
+    let mut faded = 0;
+    for mut r in relationships {
+        let kin = is_kin(r.relationship_type);
+        let (mut strength, familiarity) = decay(
+            r.strength,
+            r.familiarity,
+            sim_time - r.last_interaction,
+            1.0,
+            kin,
+        );
+        if let Some(&sides) = crowded.get(&r.id) {
+            strength = (strength - crowding_decay(1.0) * sides as f32).max(0.0);
+        }
+        if strength == r.strength && familiarity == r.familiarity {
+            continue;
+        }
+        if !kin && forgotten(strength, familiarity) {
+            ctx.db.relationship().id().delete(r.id);
+            faded += 1;
+            continue;
+        }
+        r.strength = strength;
+        r.familiarity = familiarity;
+        if !kin {
+            r.relationship_type = classify_relationship(strength, familiarity);
+        }
+        ctx.db.relationship().id().update(r);
+    }
+    if faded > 0 {
+        log::info!("{} relationships forgotten", faded);
+    }
+}
+
 fn classify_relationship(strength: f32, familiarity: f32) -> u8 {
     if familiarity < 0.1 {
         return relationship_types::STRANGER;
@@ -0,0 +1,213 @@
+//! Stream system - chat votes, subscriber-named newborns and the spotlight.
+
+use crate::tables::*;
+use progship_logic::constants::stream_votes;
+use progship_logic::evacuation::is_muster_station;
+use progship_logic::numeric::roll;
+use progship_logic::stream::{
+    cooldown_remaining, is_newborn, vote_effect, VoteEffect, DEFAULT_COOLDOWN_SECONDS,
+    STREAM_ACTION_KEEP, VOTE_SALT,
+};
+use spacetimedb::{ReducerContext, Table};
+
+use super::features::feature_flags;
+use super::orders::give_order;
+
+/// Whether the sender may take stream `action` now: they must be the
+/// ship's owner or a stream operator, and the action's cooldown must have
+/// run out. Logs why not.
+pub fn may_stream(ctx: &ReducerContext, action: u8) -> bool {
+    let (Some(owner), Some(config)) = (
+        ctx.db.ship_owner().id().find(0),
+        ctx.db.stream_config().id().find(0),
+    ) else {
+        log::warn!("Stream actions are off: the ship has no owner");
+        return false;
+    };
+    if ctx.sender != owner.owner && !config.operators.contains(&ctx.sender) {
+        log::warn!("{:?} is not a stream operator", ctx.sender);
+        return false;
+    }
+    let cooldown = config
+        .cooldown_seconds
+        .get(action as usize)
+        .or(DEFAULT_COOLDOWN_SECONDS.get(action as usize))
+        .copied()
+        .unwrap_or(0);
+    let last = ctx
+        .db
+        .stream_action()
+        .iter()
+        .filter(|a| a.action == action)
+        .map(|a| a.taken_at.to_micros_since_unix_epoch())
+        .max();
+    let wait = cooldown_remaining(last, ctx.timestamp.to_micros_since_unix_epoch(), cooldown);
+    if wait > 0 {
+        log::warn!(
+            "{} on cooldown for {}s",
+            progship_logic::constants::stream_actions::name(action),
+            wait
+        );
+        return false;
+    }
+    true
+}
+
+/// Record a stream action taken, dropping the oldest past
+/// [`STREAM_ACTION_KEEP`].
+pub fn record_stream_action(ctx: &ReducerContext, action: u8, detail: String, sim_time: f64) {
+    log::info!(
+        "Stream {}: {}",
+        progship_logic::constants::stream_actions::name(action),
+        detail
+    );
+    ctx.db.stream_action().insert(StreamAction {
+        id: 0,
+        action,
+        detail,
+        sent_by: ctx.sender,
+        taken_at: ctx.timestamp,
+        sim_time,
+    });
+    let mut ids: Vec<u64> = ctx.db.stream_action().iter().map(|a| a.id).collect();
+    if ids.len() > STREAM_ACTION_KEEP as usize {
+        ids.sort_unstable();
+        for id in &ids[..ids.len() - STREAM_ACTION_KEEP as usize] {
+            ctx.db.stream_action().id().delete(*id);
+        }
+    }
+}
+
+/// Carry out chat's `vote` (see stream_votes): a standing order to the crew
+/// or an event in a fitting room. Returns false if there is nowhere for it
+/// to happen.
+pub fn carry_out_vote(ctx: &ReducerContext, vote: u8, sim_time: f64) -> bool {
+    let Some(effect) = vote_effect(vote) else {
+        return false;
+    };
+    let pick = roll(vote as u64, sim_time, VOTE_SALT);
+    match effect {
+        VoteEffect::Order {
+            kind,
+            department,
+            hours,
+        } => {
+            let decks = ctx
+                .db
+                .ship_config()
+                .id()
+                .find(0)
+                .map_or(1, |c| c.deck_count.max(1));
+            let deck = ((pick * decks as f32) as u32).min(decks - 1) as i32;
+            let (order_id, complied) =
+                give_order(ctx, kind, department, deck, hours, None, sim_time);
+            log::info!("Voted order {}: {} crew complying", order_id, complied);
+        }
+        VoteEffect::Event {
+            event_type,
+            severity,
+        } => {
+            if !feature_flags(ctx).allows_event(event_type) {
+                return false;
+            }
+            let fits = |room_type: u8| match vote {
+                stream_votes::CELEBRATION => is_muster_station(room_type),
+                stream_votes::DISCOVERY => room_type == room_types::LABORATORY,
+                _ => true,
+            };
+            let content: Vec<Room> = ctx
+                .db
+                .room()
+                .iter()
+                .filter(|r| r.room_type < room_types::CORRIDOR)
+                .collect();
+            let fitting: Vec<&Room> = content.iter().filter(|r| fits(r.room_type)).collect();
+            let rooms: Vec<&Room> = if fitting.is_empty() {
+                content.iter().collect()
+            } else {
+                fitting
+            };
+            if rooms.is_empty() {
+                return false;
+            }
+            let room = rooms[((pick * rooms.len() as f32) as usize).min(rooms.len() - 1)];
+            ctx.db.event().insert(Event {
+                id: 0,
+                event_type,
+                room_id: room.id,
+                started_at: sim_time,
+                duration: 1.0 + severity * 2.0,
+                state: event_states::ACTIVE,
+                responders_needed: 1,
+                responders_assigned: 0,
+                severity,
+                escalated_to: None,
+            });
+        }
+    }
+    true
+}
+
+/// Give waiting subscriber names to the babies born aboard in the last
+/// week that have none yet, oldest name and oldest baby first. Returns how
+/// many were named.
+pub fn name_newborns(ctx: &ReducerContext, sim_time: f64) -> u32 {
+    let mut waiting: Vec<NewbornName> = ctx
+        .db
+        .newborn_name()
+        .iter()
+        .filter(|n| n.person_id.is_none())
+        .collect();
+    if waiting.is_empty() {
+        return 0;
+    }
+    waiting.sort_by_key(|n| n.id);
+    let named: Vec<u64> = ctx
+        .db
+        .newborn_name()
+        .iter()
+        .filter_map(|n| n.person_id)
+        .collect();
+    let mut babies: Vec<Person> = ctx
+        .db
+        .person()
+        .iter()
+        .filter(|p| p.is_alive && !p.is_player && is_newborn(p.birth_date, sim_time))
+        .filter(|p| !named.contains(&p.id))
+        .collect();
+    babies.sort_by(|a, b| a.birth_date.total_cmp(&b.birth_date));
+
+    let mut count = 0;
+    for (mut name, mut baby) in waiting.into_iter().zip(babies) {
+        log::info!(
+            "{} {} named {} after a subscriber",
+            baby.given_name,
+            baby.family_name,
+            name.name
+        );
+        baby.given_name = name.name.clone();
+        name.person_id = Some(baby.id);
+        ctx.db.person().id().update(baby);
+        ctx.db.newborn_name().id().update(name);
+        count += 1;
+    }
+    count
+}
+
+/// Hourly: name any new babies after waiting subscribers, and end the
+/// spotlight once its time is up.
+pub fn tick_stream(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    if sim_time.floor() == (sim_time - delta_hours).floor() {
+        return;
+    }
+    name_newborns(ctx, sim_time);
+    if ctx
+        .db
+        .spotlight()
+        .id()
+        .find(0)
+        .is_some_and(|s| s.until <= sim_time)
+    {
+        ctx.db.spotlight().id().delete(0);
+    }
+}
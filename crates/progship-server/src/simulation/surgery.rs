@@ -0,0 +1,293 @@
+//! Surgery system - critical patients booked into an operating theatre
+//! with the best placed surgeon, operations that start once both are there
+//! and the skill check that decides how they turn out.
+
+use std::collections::HashSet;
+
+use crate::tables::*;
+use progship_logic::numeric::roll;
+use progship_logic::surgery::{
+    facility_condition, health_after, is_theatre, needs_surgery, operation_hours, surgeon_score,
+    surgery_outcome, MIN_SURGEON_SKILL, OUTCOME_SALT, RECOVERY_HOURS, SCHEDULE_TIMEOUT_HOURS,
+};
+use progship_logic::triage::admission_order;
+use spacetimedb::{ReducerContext, Table};
+
+use super::cargo::has_cargo;
+use super::death::record_death;
+use super::drop_everything;
+use super::education::is_teacher;
+use super::morale::on_strike;
+use super::quarantine::is_quarantined;
+
+/// Hourly: finish operations that have run their length, start those whose
+/// surgeon and patient have reached the theatre, call off those that never
+/// began, forget old ones and book critical patients into free theatres.
+pub fn tick_surgery(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    if sim_time.floor() == (sim_time - delta_hours).floor() {
+        return;
+    }
+    finish_operations(ctx, sim_time);
+    start_operations(ctx, sim_time);
+    schedule_operations(ctx, sim_time);
+}
+
+/// What someone booked for an operation does next: the patient lies on the
+/// table, the surgeon operates. `None` for anyone else.
+pub fn surgery_activity(ctx: &ReducerContext, person_id: u64) -> Option<(u8, f32, Option<u32>)> {
+    if let Some(surgery) = ctx.db.surgery().person_id().find(person_id) {
+        if surgery.finished_at.is_none() {
+            return Some((activity_types::SLEEPING, 1.0, Some(surgery.room_id)));
+        }
+    }
+    let surgery = ctx
+        .db
+        .surgery()
+        .iter()
+        .find(|s| s.surgeon_id == person_id && s.finished_at.is_none())?;
+    Some((activity_types::SURGERY, 1.0, Some(surgery.room_id)))
+}
+
+/// Decide every operation that has run its length, and drop the records of
+/// those finished more than [`RECOVERY_HOURS`] ago.
+fn finish_operations(ctx: &ReducerContext, sim_time: f64) {
+    for mut surgery in ctx.db.surgery().iter() {
+        if let Some(finished_at) = surgery.finished_at {
+            if sim_time - finished_at > RECOVERY_HOURS {
+                ctx.db.surgery().person_id().delete(surgery.person_id);
+            }
+            continue;
+        }
+        let Some(started_at) = surgery.started_at else {
+            continue;
+        };
+        if sim_time - started_at < surgery.duration_hours as f64 {
+            continue;
+        }
+        let outcome = operate(ctx, &surgery, sim_time);
+        surgery.outcome = Some(outcome);
+        surgery.finished_at = Some(sim_time);
+        drop_everything(ctx, surgery.person_id);
+        drop_everything(ctx, surgery.surgeon_id);
+        ctx.db.surgery().person_id().update(surgery);
+    }
+}
+
+/// Roll the outcome of `surgery` (see `surgery_outcomes`) on the surgeon's
+/// skill and the theatre's condition, and apply it to the patient.
+fn operate(ctx: &ReducerContext, surgery: &Surgery, sim_time: f64) -> u8 {
+    let alive = ctx
+        .db
+        .person()
+        .id()
+        .find(surgery.person_id)
+        .filter(|p| p.is_alive);
+    let (Some(person), Some(mut needs)) =
+        (alive, ctx.db.needs().person_id().find(surgery.person_id))
+    else {
+        return surgery_outcomes::DEATH;
+    };
+    let skill = ctx
+        .db
+        .skills()
+        .person_id()
+        .find(surgery.surgeon_id)
+        .map_or(0.0, |s| s.medical);
+    let condition = theatre_condition(ctx, surgery.room_id);
+    let roll = roll(surgery.person_id, sim_time, OUTCOME_SALT);
+    let outcome = surgery_outcome(roll, skill, condition, needs.health);
+    log::info!(
+        "Surgery on {} {}: {} (skill {:.2}, theatre {:.0}%)",
+        person.given_name,
+        person.family_name,
+        progship_logic::constants::surgery_outcomes::name(outcome),
+        skill,
+        condition * 100.0
+    );
+    needs.health = health_after(outcome, needs.health);
+    ctx.db.needs().person_id().update(needs);
+    if outcome == surgery_outcomes::DEATH {
+        record_death(ctx, person, death_causes::SURGERY, sim_time);
+    }
+    outcome
+}
+
+/// Condition of the theatre `room_id` (see `surgery::facility_condition`):
+/// the medical systems' health, whether the room has power and whether any
+/// medical supplies are left.
+fn theatre_condition(ctx: &ReducerContext, room_id: u32) -> f32 {
+    let system_health = ctx
+        .db
+        .ship_system()
+        .iter()
+        .find(|s| s.system_type == system_types::MEDICAL)
+        .map_or(1.0, |s| s.overall_health);
+    let powered = ctx
+        .db
+        .room_power()
+        .room_id()
+        .find(room_id)
+        .is_none_or(|p| p.powered);
+    let stocked = has_cargo(ctx, cargo_categories::MEDICAL_SUPPLIES);
+    facility_condition(system_health, powered, stocked)
+}
+
+/// Begin every booked operation whose surgeon and patient are both in the
+/// theatre, and call off those still waiting after
+/// [`SCHEDULE_TIMEOUT_HOURS`] or that lost their patient or surgeon.
+fn start_operations(ctx: &ReducerContext, sim_time: f64) {
+    let room_of = |person_id: u64| {
+        ctx.db
+            .position()
+            .person_id()
+            .find(person_id)
+            .map(|p| p.room_id)
+    };
+    let fit = |person_id: u64| {
+        ctx.db
+            .person()
+            .id()
+            .find(person_id)
+            .is_some_and(|p| p.is_alive)
+            && !is_quarantined(ctx, person_id)
+    };
+    for mut surgery in ctx.db.surgery().iter() {
+        if surgery.started_at.is_some() {
+            continue;
+        }
+        let stale = sim_time - surgery.scheduled_at > SCHEDULE_TIMEOUT_HOURS;
+        let discharged = ctx
+            .db
+            .patient()
+            .person_id()
+            .find(surgery.person_id)
+            .is_none();
+        if stale || discharged || !fit(surgery.person_id) || !fit(surgery.surgeon_id) {
+            log::info!("Surgery on person {} called off", surgery.person_id);
+            ctx.db.surgery().person_id().delete(surgery.person_id);
+            drop_everything(ctx, surgery.person_id);
+            drop_everything(ctx, surgery.surgeon_id);
+            continue;
+        }
+        let theatre = Some(surgery.room_id);
+        if room_of(surgery.person_id) == theatre && room_of(surgery.surgeon_id) == theatre {
+            surgery.started_at = Some(sim_time);
+            ctx.db.surgery().person_id().update(surgery);
+        }
+    }
+}
+
+/// Book every critical patient not recently operated on, most urgent
+/// first, into a free theatre with the best placed surgeon free, their own
+/// deck's theatre first.
+fn schedule_operations(ctx: &ReducerContext, sim_time: f64) {
+    let booked: Vec<Surgery> = ctx.db.surgery().iter().collect();
+    let busy_theatres: HashSet<u32> = booked
+        .iter()
+        .filter(|s| s.finished_at.is_none())
+        .map(|s| s.room_id)
+        .collect();
+    let mut theatres: Vec<Room> = ctx
+        .db
+        .room()
+        .iter()
+        .filter(|r| is_theatre(r.room_type) && !busy_theatres.contains(&r.id))
+        .collect();
+    if theatres.is_empty() {
+        return;
+    }
+
+    let health_of = |person_id: u64| {
+        ctx.db
+            .needs()
+            .person_id()
+            .find(person_id)
+            .map_or(1.0, |n| n.health)
+    };
+    let mut patients: Vec<Patient> = ctx
+        .db
+        .patient()
+        .iter()
+        .filter(|p| needs_surgery(p.triage))
+        .filter(|p| !booked.iter().any(|s| s.person_id == p.person_id))
+        .collect();
+    if patients.is_empty() {
+        return;
+    }
+    patients.sort_by(|a, b| {
+        admission_order(
+            (a.triage, health_of(a.person_id), a.arrived_at),
+            (b.triage, health_of(b.person_id), b.arrived_at),
+        )
+    });
+
+    let operating: HashSet<u64> = booked
+        .iter()
+        .filter(|s| s.finished_at.is_none())
+        .map(|s| s.surgeon_id)
+        .collect();
+    let mut surgeons: Vec<(f32, u64, f32)> = ctx
+        .db
+        .crew()
+        .iter()
+        .filter(|c| !operating.contains(&c.person_id) && !on_strike(ctx, c.department))
+        .filter(|c| {
+            ctx.db
+                .person()
+                .id()
+                .find(c.person_id)
+                .is_some_and(|p| p.is_alive && !p.is_player)
+        })
+        .filter(|c| ctx.db.patient().person_id().find(c.person_id).is_none())
+        .filter(|c| ctx.db.detention().person_id().find(c.person_id).is_none())
+        .filter(|c| !is_quarantined(ctx, c.person_id) && !is_teacher(ctx, c.person_id))
+        .filter_map(|c| {
+            let skill = ctx.db.skills().person_id().find(c.person_id)?.medical;
+            (skill >= MIN_SURGEON_SKILL).then_some((
+                surgeon_score(skill, c.on_duty),
+                c.person_id,
+                skill,
+            ))
+        })
+        .collect();
+    surgeons.sort_by(|a, b| b.0.total_cmp(&a.0).then(a.1.cmp(&b.1)));
+
+    let deck_of = |person_id: u64| {
+        ctx.db
+            .position()
+            .person_id()
+            .find(person_id)
+            .and_then(|p| ctx.db.room().id().find(p.room_id))
+            .map(|r| r.deck)
+    };
+    for (patient, (_, surgeon_id, skill)) in patients.iter().zip(surgeons) {
+        let deck = deck_of(patient.person_id);
+        let Some(index) = theatres
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, r)| (Some(r.deck) != deck, r.id))
+            .map(|(i, _)| i)
+        else {
+            break;
+        };
+        let theatre = theatres.swap_remove(index);
+        ctx.db.surgery().insert(Surgery {
+            person_id: patient.person_id,
+            surgeon_id,
+            room_id: theatre.id,
+            scheduled_at: sim_time,
+            started_at: None,
+            duration_hours: operation_hours(skill),
+            outcome: None,
+            finished_at: None,
+        });
+        drop_everything(ctx, patient.person_id);
+        drop_everything(ctx, surgeon_id);
+        log::info!(
+            "Person {} booked for surgery in {} with surgeon {}",
+            patient.person_id,
+            theatre.name,
+            surgeon_id
+        );
+    }
+}
@@ -0,0 +1,194 @@
+//! Thermal system - heat from the reactors, engines, people and galleys
+//! carried by the coolant loop to the radiators, with overheated machinery
+//! damaged and rooms warmed by what is not carried off.
+
+use std::collections::HashMap;
+
+use crate::tables::*;
+use progship_logic::thermal::{
+    chilling, coolant_after, overheat_damage, radiated_kw, room_heating, uncooled_damage,
+    HeatSources, GALLEY_HEAT_KW, MACHINERY_HEAT_C, OVERHEAT_C,
+};
+use spacetimedb::{ReducerContext, Table};
+
+use super::cryo::sleepers;
+use super::hvac::{air_cooling, plant_output};
+use super::ship_systems::calculate_subsystem_efficiency;
+
+/// Subsystems the coolant loop runs through, damaged when it overheats.
+const ON_LOOP: [u8; 6] = [
+    subsystem_types::REACTOR_CORE,
+    subsystem_types::REACTOR_COOLING,
+    subsystem_types::HEAT_EXCHANGE,
+    subsystem_types::COOLANT_PUMP,
+    subsystem_types::RADIATOR_PANEL,
+    subsystem_types::THRUST_CHAMBER,
+];
+
+/// Run the coolant loop for `delta_hours`: take in the heat the pumps and
+/// chillers carry, shed what the radiators can and damage the machinery
+/// running hot.
+pub fn tick_thermal(ctx: &ReducerContext, delta_hours: f32) {
+    let Some(mut coolant) = ctx.db.coolant_loop().id().find(0) else {
+        return;
+    };
+    let heat = heat_sources(ctx);
+    let flow = coolant_flow(ctx);
+    let absorbed = heat.machinery_kw() * flow + heat.rooms_kw() * air_cooling(ctx);
+    let radiated = radiated_kw(
+        coolant.radiator_kw,
+        plant_output(ctx, subsystem_types::RADIATOR_PANEL),
+        coolant.temperature,
+    );
+    let temperature = coolant_after(
+        coolant.temperature,
+        absorbed,
+        radiated,
+        coolant.heat_capacity,
+        delta_hours,
+    );
+    if temperature > OVERHEAT_C && coolant.temperature <= OVERHEAT_C {
+        log::warn!(
+            "Coolant loop overheating at {:.0}C: {:.0} kW in, {:.0} kW radiated",
+            temperature,
+            absorbed,
+            radiated
+        );
+    }
+
+    let overheat = overheat_damage(temperature, delta_hours);
+    let uncooled = uncooled_damage(flow, delta_hours);
+    let hot: Vec<Subsystem> = ctx
+        .db
+        .subsystem()
+        .iter()
+        .filter(|s| ON_LOOP.contains(&s.subsystem_type))
+        .collect();
+    for mut sub in hot {
+        let working = match sub.subsystem_type {
+            subsystem_types::REACTOR_CORE => heat.reactor_kw > 0.0,
+            subsystem_types::THRUST_CHAMBER => heat.engine_kw > 0.0,
+            _ => false,
+        };
+        let damage = overheat + if working { uncooled } else { 0.0 };
+        if damage > 0.0 {
+            sub.health = (sub.health - damage).max(0.0);
+            ctx.db.subsystem().id().update(sub);
+        }
+    }
+
+    coolant.temperature = temperature;
+    coolant.coolant_flow = flow;
+    coolant.reactor_kw = heat.reactor_kw;
+    coolant.engine_kw = heat.engine_kw;
+    coolant.cabin_kw = heat.cabin_kw;
+    coolant.galley_kw = heat.galley_kw;
+    coolant.absorbed_kw = absorbed;
+    coolant.radiated_kw = radiated;
+    ctx.db.coolant_loop().id().update(coolant);
+}
+
+/// The ship's heat as it stands: the reactors by what is drawn from them,
+/// the engines while they burn, people awake and the cooks at work.
+fn heat_sources(ctx: &ReducerContext) -> HeatSources {
+    let drawn = ctx
+        .db
+        .power_grid()
+        .id()
+        .find(0)
+        .map_or(0.0, |g| g.supplied_kw);
+    let burning = ctx.db.voyage_state().id().find(0).is_some_and(|v| {
+        matches!(
+            v.phase,
+            voyage_phases::ACCELERATING | voyage_phases::DECELERATING
+        )
+    });
+    let chambers: f32 = ctx
+        .db
+        .subsystem()
+        .iter()
+        .filter(|s| s.subsystem_type == subsystem_types::THRUST_CHAMBER)
+        .map(|s| calculate_subsystem_efficiency(s.health, s.status))
+        .sum();
+    let asleep = sleepers(ctx);
+    let awake = ctx
+        .db
+        .position()
+        .iter()
+        .filter(|p| !asleep.contains(&p.person_id))
+        .count();
+    let cooks: u32 = ctx.db.meal_buffer().iter().map(|m| m.cooks).sum();
+    HeatSources::new(
+        drawn,
+        chambers,
+        if burning { 1.0 } else { 0.0 },
+        awake as f32,
+        cooks as f32,
+    )
+}
+
+/// Share (0–1) of machinery heat the coolant pumps carry off along the
+/// coolant pipes.
+fn coolant_flow(ctx: &ReducerContext) -> f32 {
+    let pipes: Vec<f32> = ctx
+        .db
+        .infra_edge()
+        .iter()
+        .filter(|e| e.edge_type == infra_types::COOLANT_PIPE)
+        .map(|e| e.health)
+        .collect();
+    let pipes = if pipes.is_empty() {
+        1.0
+    } else {
+        pipes.iter().sum::<f32>() / pipes.len() as f32
+    };
+    plant_output(ctx, subsystem_types::COOLANT_PUMP) * pipes
+}
+
+/// Share (0–1) of their grip the air handlers' chillers keep with the
+/// coolant loop as it runs.
+pub(super) fn coolant_chilling(ctx: &ReducerContext) -> f32 {
+    ctx.db
+        .coolant_loop()
+        .id()
+        .find(0)
+        .map_or(1.0, |c| chilling(c.temperature) * c.coolant_flow)
+}
+
+/// Degrees an hour each room is warmed by its own heat: galleys by the
+/// cooks at work and machinery rooms by what the pumps do not carry off.
+pub(super) fn room_heat(ctx: &ReducerContext) -> HashMap<u32, f32> {
+    let mut heating: HashMap<u32, f32> = HashMap::new();
+    for buffer in ctx.db.meal_buffer().iter().filter(|m| m.cooks > 0) {
+        let area = ctx
+            .db
+            .room()
+            .id()
+            .find(buffer.galley_room_id)
+            .map_or(0.0, |r| r.width * r.height);
+        *heating.entry(buffer.galley_room_id).or_default() +=
+            room_heating(buffer.cooks as f32 * GALLEY_HEAT_KW, area);
+    }
+    let Some(coolant) = ctx.db.coolant_loop().id().find(0) else {
+        return heating;
+    };
+    let uncarried = MACHINERY_HEAT_C * (1.0 - coolant.coolant_flow.clamp(0.0, 1.0));
+    if uncarried <= 0.0 {
+        return heating;
+    }
+    for sub in ctx.db.subsystem().iter() {
+        let working = match sub.subsystem_type {
+            subsystem_types::REACTOR_CORE => coolant.reactor_kw > 0.0,
+            subsystem_types::THRUST_CHAMBER => coolant.engine_kw > 0.0,
+            _ => false,
+        };
+        if !working {
+            continue;
+        }
+        for room in ctx.db.room().iter().filter(|r| r.node_id == sub.node_id) {
+            let warmth = heating.entry(room.id).or_default();
+            *warmth = warmth.max(uncarried);
+        }
+    }
+    heating
+}
@@ -0,0 +1,33 @@
+//! Timeline system - per-person ring of recent activities, rooms and
+//! conversations.
+
+use crate::tables::*;
+use progship_logic::timeline::{record, TimelineEntry, TimelineKind};
+use spacetimedb::{ReducerContext, Table};
+
+/// Add an entry to a person's timeline, dropping anything older than a day.
+pub fn record_timeline(
+    ctx: &ReducerContext,
+    person_id: u64,
+    kind: TimelineKind,
+    value: u32,
+    sim_time: f64,
+) {
+    let entry = TimelineEntry::new(sim_time, kind, value);
+    match ctx.db.person_timeline().person_id().find(person_id) {
+        Some(mut timeline) => {
+            let before = timeline.entries.clone();
+            record(&mut timeline.entries, entry);
+            if timeline.entries != before {
+                ctx.db.person_timeline().person_id().update(timeline);
+            }
+        }
+        None => {
+            let mut entries = Vec::new();
+            record(&mut entries, entry);
+            ctx.db
+                .person_timeline()
+                .insert(PersonTimeline { person_id, entries });
+        }
+    }
+}
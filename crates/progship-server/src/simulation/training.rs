@@ -0,0 +1,209 @@
+//! Training system - crew skills fading with disuse over a long voyage and
+//! refresher training in the school and library to win them back.
+
+use crate::tables::*;
+use progship_logic::skills::{
+    disuse_decay, is_training_room, most_lapsed, practiced_skill, refresher_gain, SkillCategory,
+    REFRESHER_HOURS,
+};
+use progship_logic::timeline::TimelineKind;
+use spacetimedb::{ReducerContext, Table};
+
+use super::activities::restart_activity;
+use super::movement::start_movement_to;
+use super::timeline::record_timeline;
+use super::warnings::warn;
+
+/// Sim hours between skill upkeep passes.
+const UPKEEP_INTERVAL_HOURS: f64 = 1.0;
+
+/// Activities crew may be pulled out of for a refresher.
+const FREE_TIME: [u8; 5] = [
+    activity_types::IDLE,
+    activity_types::OFF_DUTY,
+    activity_types::RELAXING,
+    activity_types::SOCIALIZING,
+    activity_types::EXERCISING,
+];
+
+/// Hourly: note which skill each crew member is practicing, fade the ones
+/// left unused, train up those in a refresher and book off-duty crew with
+/// a lapsed skill into a classroom while seats last.
+pub fn tick_training(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    let interval = |t: f64| (t / UPKEEP_INTERVAL_HOURS).floor();
+    let hours =
+        ((interval(sim_time) - interval(sim_time - delta_hours)) * UPKEEP_INTERVAL_HOURS) as f32;
+    if hours <= 0.0 {
+        return;
+    }
+
+    let classrooms: Vec<Room> = ctx
+        .db
+        .room()
+        .iter()
+        .filter(|r| is_training_room(r.room_type))
+        .collect();
+    let seats: u32 = classrooms.iter().map(|r| r.capacity).sum();
+    let mut trainees = ctx
+        .db
+        .activity()
+        .iter()
+        .filter(|a| a.activity_type == activity_types::TRAINING)
+        .count() as u32;
+
+    for crew in ctx.db.crew().iter() {
+        let Some(person) = ctx.db.person().id().find(crew.person_id) else {
+            continue;
+        };
+        if !person.is_alive {
+            continue;
+        }
+        let Some(mut skills) = ctx.db.skills().person_id().find(crew.person_id) else {
+            continue;
+        };
+        let mut levels = skill_levels(&skills);
+        let existing = ctx.db.skill_upkeep().person_id().find(crew.person_id);
+        let is_new = existing.is_none();
+        let mut upkeep = existing.unwrap_or_else(|| SkillUpkeep {
+            person_id: crew.person_id,
+            qualified: levels.to_vec(),
+            last_practiced: vec![sim_time; levels.len()],
+        });
+        if upkeep.qualified.len() != levels.len() || upkeep.last_practiced.len() != levels.len() {
+            warn(
+                ctx,
+                "training",
+                format!("skill upkeep of {} has the wrong length", crew.person_id),
+            );
+            upkeep.qualified = (0..levels.len())
+                .map(|i| upkeep.qualified.get(i).copied().unwrap_or(levels[i]))
+                .collect();
+            upkeep.last_practiced.resize(levels.len(), sim_time);
+        }
+        let activity = ctx.db.activity().person_id().find(crew.person_id);
+
+        // Practicing or training counts as use; everything else fades
+        let practiced = activity
+            .as_ref()
+            .and_then(|a| practiced_skill(a.activity_type, Some(crew.department)))
+            .and_then(|cat| SkillCategory::ALL.iter().position(|&c| c == cat));
+        let in_class = activity
+            .as_ref()
+            .is_some_and(|a| a.activity_type == activity_types::TRAINING)
+            && ctx
+                .db
+                .position()
+                .person_id()
+                .find(crew.person_id)
+                .is_some_and(|p| classrooms.iter().any(|r| r.id == p.room_id));
+        let lapsed = most_lapsed(&levels, &upkeep.qualified);
+        let refreshing = lapsed.filter(|_| in_class);
+        for i in 0..levels.len() {
+            let qualified = upkeep.qualified[i];
+            if Some(i) == refreshing {
+                levels[i] = refresher_gain(levels[i], qualified, hours);
+            } else if Some(i) != practiced {
+                let days_unused = (sim_time - upkeep.last_practiced[i]) / 24.0;
+                levels[i] = disuse_decay(levels[i], qualified, days_unused, hours);
+                continue;
+            }
+            upkeep.last_practiced[i] = sim_time;
+            upkeep.qualified[i] = qualified.max(levels[i]);
+        }
+        set_skill_levels(&mut skills, &levels);
+        ctx.db.skills().person_id().update(skills);
+        if is_new {
+            ctx.db.skill_upkeep().insert(upkeep);
+        } else {
+            ctx.db.skill_upkeep().person_id().update(upkeep);
+        }
+
+        let free = activity
+            .as_ref()
+            .is_some_and(|a| FREE_TIME.contains(&a.activity_type));
+        let ordered = ctx
+            .db
+            .order_assignment()
+            .person_id()
+            .find(crew.person_id)
+            .is_some();
+        if crew.on_duty
+            || person.is_player
+            || ordered
+            || !free
+            || lapsed.is_none()
+            || trainees >= seats
+        {
+            continue;
+        }
+        let Some(mut activity) = activity else {
+            continue;
+        };
+        let room = &classrooms[crew.person_id as usize % classrooms.len()];
+        restart_activity(
+            &mut activity,
+            activity_types::TRAINING,
+            sim_time,
+            REFRESHER_HOURS,
+        );
+        activity.target_room_id = Some(room.id);
+        ctx.db.activity().person_id().update(activity);
+        start_movement_to(ctx, crew.person_id, room.id);
+        record_timeline(
+            ctx,
+            crew.person_id,
+            TimelineKind::Activity,
+            activity_types::TRAINING as u32,
+            sim_time,
+        );
+        trainees += 1;
+    }
+}
+
+/// Skill levels in `SkillCategory::ALL` (and skill_types) order.
+pub(super) fn skill_levels(skills: &Skills) -> [f32; 6] {
+    [
+        skills.engineering,
+        skills.medical,
+        skills.piloting,
+        skills.science,
+        skills.social,
+        skills.combat,
+    ]
+}
+
+/// Set `skills` from levels in `SkillCategory::ALL` order.
+pub(super) fn set_skill_levels(skills: &mut Skills, levels: &[f32; 6]) {
+    [
+        skills.engineering,
+        skills.medical,
+        skills.piloting,
+        skills.science,
+        skills.social,
+        skills.combat,
+    ] = *levels;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skill_levels_round_trip() {
+        let mut skills = Skills {
+            person_id: 1,
+            engineering: 0.7,
+            medical: 0.2,
+            piloting: 0.4,
+            science: 0.3,
+            social: 0.5,
+            combat: 0.1,
+        };
+        let levels = skill_levels(&skills);
+        assert_eq!(levels[skill_types::PILOTING as usize], 0.4);
+        assert_eq!(levels[skill_types::COMBAT as usize], 0.1);
+        set_skill_levels(&mut skills, &[0.1, 0.2, 0.3, 0.4, 0.5, 0.6]);
+        assert_eq!(skills.engineering, 0.1);
+        assert_eq!(skills.combat, 0.6);
+    }
+}
@@ -0,0 +1,275 @@
+//! Triage system - the injured queued for ward beds by how badly they are
+//! hurt, medical crew assigned to those in bed and discharge once they are
+//! well enough to recover on their own.
+
+use std::collections::HashMap;
+
+use crate::tables::*;
+use progship_logic::health::should_seek_medical;
+use progship_logic::triage::{
+    admission_order, is_ward, medic_for, outranks, treatment_hours, triage_level, DISCHARGE_HEALTH,
+};
+use progship_logic::utility::UtilityInput;
+use spacetimedb::{ReducerContext, Table};
+
+use super::education::is_teacher;
+use super::morale::on_strike;
+use super::quarantine::is_quarantined;
+
+/// Hourly: take in the newly hurt as patients, discharge the recovered,
+/// give ward beds to the most urgent and share the patients in bed out
+/// between the medical crew on duty.
+pub fn tick_triage(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    if sim_time.floor() == (sim_time - delta_hours).floor() {
+        return;
+    }
+    take_in_patients(ctx, sim_time);
+
+    let health_of = |person_id: u64| {
+        ctx.db
+            .needs()
+            .person_id()
+            .find(person_id)
+            .map_or(1.0, |n| n.health)
+    };
+    let mut patients: Vec<Patient> = ctx.db.patient().iter().collect();
+    if patients.is_empty() {
+        return;
+    }
+    patients.sort_by(|a, b| {
+        admission_order(
+            (a.triage, health_of(a.person_id), a.arrived_at),
+            (b.triage, health_of(b.person_id), b.arrived_at),
+        )
+    });
+    admit_patients(ctx, &mut patients, sim_time);
+    assign_medics(ctx, &mut patients, sim_time);
+    for patient in patients {
+        ctx.db.patient().person_id().update(patient);
+    }
+}
+
+/// What a patient with a bed does next: lie in it.
+pub fn patient_activity(ctx: &ReducerContext, person_id: u64) -> Option<(u8, f32, Option<u32>)> {
+    let patient = ctx.db.patient().person_id().find(person_id)?;
+    if patient.state != patient_states::ADMITTED {
+        return None;
+    }
+    Some((activity_types::SLEEPING, 1.0, patient.room_id))
+}
+
+/// What a medic on watch with patients does next: tend the most urgent of
+/// them, on their ward, if fit to.
+pub fn medic_activity(
+    ctx: &ReducerContext,
+    person_id: u64,
+    input: &UtilityInput,
+) -> Option<(u8, f32, Option<u32>)> {
+    if !input.should_be_on_duty || !input.fit_for_duty {
+        return None;
+    }
+    let ward = ctx
+        .db
+        .patient()
+        .iter()
+        .filter(|p| p.medic_id == Some(person_id))
+        .max_by_key(|p| (p.triage, std::cmp::Reverse(p.person_id)))?
+        .room_id?;
+    Some((activity_types::ON_DUTY, 1.0, Some(ward)))
+}
+
+/// Make patients of the living who need medical care, and discharge the
+/// dead and those recovered.
+fn take_in_patients(ctx: &ReducerContext, sim_time: f64) {
+    for needs in ctx.db.needs().iter() {
+        let Some(person) = ctx.db.person().id().find(needs.person_id) else {
+            continue;
+        };
+        let existing = ctx.db.patient().person_id().find(needs.person_id);
+        match existing {
+            // The flagged are cared for in quarantine
+            Some(_) if !person.is_alive || is_quarantined(ctx, needs.person_id) => {
+                ctx.db.patient().person_id().delete(needs.person_id);
+            }
+            Some(_) if needs.health >= DISCHARGE_HEALTH => {
+                ctx.db.patient().person_id().delete(needs.person_id);
+                log::info!("{} {} discharged", person.given_name, person.family_name);
+            }
+            Some(mut patient) => {
+                let triage = triage_level(needs.health);
+                if triage != patient.triage {
+                    patient.triage = triage;
+                    ctx.db.patient().person_id().update(patient);
+                }
+            }
+            None if person.is_alive
+                && !person.is_player
+                && !is_quarantined(ctx, needs.person_id)
+                && should_seek_medical(needs.health) =>
+            {
+                ctx.db.patient().insert(Patient {
+                    person_id: needs.person_id,
+                    triage: triage_level(needs.health),
+                    state: patient_states::WAITING,
+                    room_id: None,
+                    medic_id: None,
+                    arrived_at: sim_time,
+                    admitted_at: None,
+                    expected_discharge: None,
+                });
+            }
+            None => {}
+        }
+    }
+}
+
+/// Give ward beds to the waiting `patients` (in admission order), nearest
+/// ward first, taking the bed of a less urgent patient when the wards are
+/// full.
+fn admit_patients(ctx: &ReducerContext, patients: &mut [Patient], sim_time: f64) {
+    // Beds free on each ward, counting those taken
+    let mut free: HashMap<u32, usize> = ctx
+        .db
+        .room()
+        .iter()
+        .filter(|r| is_ward(r.room_type))
+        .map(|r| {
+            let beds = ctx
+                .db
+                .activity_anchor()
+                .iter()
+                .filter(|a| a.room_id == r.id && a.anchor_type == anchor_types::BED)
+                .count();
+            (r.id, beds)
+        })
+        .collect();
+    for patient in patients.iter_mut() {
+        let Some(ward) = patient.room_id else {
+            continue;
+        };
+        match free.get_mut(&ward) {
+            Some(beds) if *beds > 0 => *beds -= 1,
+            // The ward is gone or has lost beds
+            _ => discharge_to_waiting(patient),
+        }
+    }
+
+    let deck_of = |room_id: u32| ctx.db.room().id().find(room_id).map(|r| r.deck);
+    for i in 0..patients.len() {
+        let Some(patient) = patients.get(i) else {
+            break;
+        };
+        if patient.state != patient_states::WAITING {
+            continue;
+        }
+        let triage = patient.triage;
+        let deck = ctx
+            .db
+            .position()
+            .person_id()
+            .find(patient.person_id)
+            .and_then(|p| deck_of(p.room_id));
+        let open_ward = free
+            .iter()
+            .filter(|(_, &beds)| beds > 0)
+            .min_by_key(|(&ward, &beds)| (deck_of(ward) != deck, std::cmp::Reverse(beds), ward))
+            .map(|(&ward, _)| ward);
+        let ward =
+            match open_ward {
+                Some(ward) => {
+                    if let Some(beds) = free.get_mut(&ward) {
+                        *beds = beds.saturating_sub(1);
+                    }
+                    ward
+                }
+                None => {
+                    // The least urgent patient in a bed gives it up, if less
+                    // urgent than this one
+                    let Some(bumped) = patients.iter_mut().skip(i + 1).rev().find(|p| {
+                        p.state == patient_states::ADMITTED && outranks(triage, p.triage)
+                    }) else {
+                        continue;
+                    };
+                    let Some(ward) = bumped.room_id else {
+                        continue;
+                    };
+                    discharge_to_waiting(bumped);
+                    ward
+                }
+            };
+        let Some(patient) = patients.get_mut(i) else {
+            break;
+        };
+        patient.state = patient_states::ADMITTED;
+        patient.room_id = Some(ward);
+        patient.admitted_at = Some(sim_time);
+        if let (Some(person), Some(room)) = (
+            ctx.db.person().id().find(patient.person_id),
+            ctx.db.room().id().find(ward),
+        ) {
+            log::info!(
+                "{} {} admitted to {} ({})",
+                person.given_name,
+                person.family_name,
+                room.name,
+                progship_logic::constants::triage_levels::name(patient.triage)
+            );
+        }
+    }
+}
+
+/// Send an admitted patient back to waiting for a bed.
+fn discharge_to_waiting(patient: &mut Patient) {
+    patient.state = patient_states::WAITING;
+    patient.room_id = None;
+    patient.medic_id = None;
+    patient.admitted_at = None;
+    patient.expected_discharge = None;
+}
+
+/// Share the admitted `patients` (in admission order) out between the
+/// medical crew on watch, the most skilled taking the most urgent, and set
+/// when each should be well enough to leave.
+fn assign_medics(ctx: &ReducerContext, patients: &mut [Patient], sim_time: f64) {
+    let mut medics: Vec<(u64, f32)> = if on_strike(ctx, departments::MEDICAL) {
+        Vec::new()
+    } else {
+        ctx.db
+            .crew()
+            .iter()
+            .filter(|c| c.department == departments::MEDICAL && c.on_duty)
+            .filter(|c| {
+                ctx.db
+                    .person()
+                    .id()
+                    .find(c.person_id)
+                    .is_some_and(|p| p.is_alive && !p.is_player)
+            })
+            .filter(|c| ctx.db.patient().person_id().find(c.person_id).is_none())
+            .filter(|c| ctx.db.detention().person_id().find(c.person_id).is_none())
+            .filter(|c| !is_teacher(ctx, c.person_id))
+            .filter(|c| !is_quarantined(ctx, c.person_id))
+            .filter_map(|c| {
+                let skill = ctx.db.skills().person_id().find(c.person_id)?.medical;
+                Some((c.person_id, skill))
+            })
+            .collect()
+    };
+    medics.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+
+    let admitted = patients
+        .iter_mut()
+        .filter(|p| p.state == patient_states::ADMITTED);
+    for (rank, patient) in admitted.enumerate() {
+        let medic = medic_for(rank, medics.len()).and_then(|i| medics.get(i));
+        patient.medic_id = medic.map(|m| m.0);
+        let health = ctx
+            .db
+            .needs()
+            .person_id()
+            .find(patient.person_id)
+            .map_or(DISCHARGE_HEALTH, |n| n.health);
+        let hours = treatment_hours(health, medic.map_or(0.0, |m| m.1));
+        patient.expected_discharge = Some(sim_time + hours as f64);
+    }
+}
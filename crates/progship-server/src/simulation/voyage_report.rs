@@ -0,0 +1,282 @@
+//! Voyage report - daily population samples and the final report, filed at
+//! arrival or when the ship is lost. A loss ends the run: the simulation is
+//! paused and cannot be resumed.
+
+use crate::tables::*;
+use progship_logic::constants::{death_causes, departments};
+use progship_logic::final_report::{
+    loss_cause, tally, FactionSummary, FinalReport, Incident, PopulationPoint, ResourceMargin,
+    ShipCondition, NOTABLE_SEVERITY,
+};
+use progship_logic::history::sim_day;
+use progship_logic::milestones::{born_aboard, MilestoneEntry, MILESTONE_CHECK_HOURS};
+use spacetimedb::{ReducerContext, Table};
+
+/// Sample the population once per sim day and, once per sim hour, check
+/// whether the ship is lost (see [`loss_cause`]). A loss files the final
+/// report and pauses the simulation for good.
+pub fn tick_voyage_report(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    let day = sim_day(sim_time);
+    if ctx.db.population_sample().day().find(day).is_none() {
+        let alive = ctx.db.person().iter().filter(|p| p.is_alive).count() as u32;
+        ctx.db
+            .population_sample()
+            .insert(PopulationSample { day, alive });
+    }
+
+    let interval = |t: f64| (t / MILESTONE_CHECK_HOURS).floor();
+    if interval(sim_time) == interval(sim_time - delta_hours) || voyage_over(ctx) {
+        return;
+    }
+    let cause = loss_cause(&ship_condition(ctx));
+    if cause == loss_causes::NONE {
+        return;
+    }
+    log::warn!(
+        "Ship lost at {:.1}h: {}",
+        sim_time,
+        progship_logic::constants::loss_causes::name(cause)
+    );
+    file_report(ctx, sim_time, voyage_outcomes::LOST, cause);
+    if let Some(mut config) = ctx.db.ship_config().id().find(0) {
+        config.paused = true;
+        ctx.db.ship_config().id().update(config);
+    }
+}
+
+/// Build the report for the voyage as it stands at `sim_time`, store it in
+/// the voyage_report table and log it as Markdown.
+pub fn file_report(ctx: &ReducerContext, sim_time: f64, outcome: u8, loss_cause: u8) {
+    let report = build_report(ctx, sim_time, outcome, loss_cause);
+    log::info!("{}", report.to_markdown());
+    ctx.db.voyage_report().insert(VoyageReport {
+        id: 0,
+        sim_time,
+        outcome,
+        loss_cause,
+        json: report.to_json(),
+        markdown: report.to_markdown(),
+    });
+}
+
+/// Whether the ship has been lost, ending the run.
+pub fn ship_was_lost(ctx: &ReducerContext) -> bool {
+    ctx.db
+        .voyage_report()
+        .iter()
+        .any(|r| r.outcome == voyage_outcomes::LOST)
+}
+
+/// Whether an arrival or loss report has been filed.
+fn voyage_over(ctx: &ReducerContext) -> bool {
+    ctx.db
+        .voyage_report()
+        .iter()
+        .any(|r| r.outcome != voyage_outcomes::IN_PROGRESS)
+}
+
+/// The state of the ship that decides whether it is lost.
+fn ship_condition(ctx: &ReducerContext) -> ShipCondition {
+    let (departed, alive) = headcount(ctx);
+    let resources = ctx.db.ship_resources().id().find(0);
+    ShipCondition {
+        departed,
+        alive,
+        life_support_status: ctx
+            .db
+            .ship_system()
+            .iter()
+            .filter(|s| s.system_type == system_types::LIFE_SUPPORT)
+            .map(|s| s.overall_status)
+            .min(),
+        oxygen: resources.as_ref().map_or(0.0, |r| r.oxygen),
+        fuel: resources.as_ref().map_or(0.0, |r| r.fuel),
+        fuel_cap: resources.as_ref().map_or(0.0, |r| r.fuel_cap),
+        arrived: ctx
+            .db
+            .milestone()
+            .iter()
+            .any(|m| m.kind == milestone_kinds::ARRIVAL),
+    }
+}
+
+/// (people who set out, people alive now).
+fn headcount(ctx: &ReducerContext) -> (u32, u32) {
+    ctx.db.person().iter().fold((0, 0), |(departed, alive), p| {
+        (
+            departed + u32::from(!born_aboard(p.birth_date)),
+            alive + u32::from(p.is_alive),
+        )
+    })
+}
+
+fn build_report(ctx: &ReducerContext, sim_time: f64, outcome: u8, loss_cause: u8) -> FinalReport {
+    let (departed, alive) = headcount(ctx);
+    let config = ctx.db.ship_config().id().find(0);
+
+    let living: Vec<Needs> = ctx
+        .db
+        .needs()
+        .iter()
+        .filter(|n| {
+            ctx.db
+                .person()
+                .id()
+                .find(n.person_id)
+                .is_some_and(|p| p.is_alive)
+        })
+        .collect();
+    let mean = |f: fn(&Needs) -> f32| {
+        if living.is_empty() {
+            0.0
+        } else {
+            living.iter().map(f).sum::<f32>() / living.len() as f32
+        }
+    };
+
+    let mut event_ids: Vec<u8> = Vec::new();
+    for day in ctx.db.daily_history().iter() {
+        for (event_type, &count) in day.events.iter().enumerate() {
+            event_ids.extend(std::iter::repeat_n(event_type as u8, count as usize));
+        }
+    }
+    event_ids.extend(ctx.db.event().iter().map(|e| e.event_type));
+    event_ids.retain(|&t| t != event_types::DIAGNOSTIC);
+
+    let incidents = ctx
+        .db
+        .event()
+        .iter()
+        .filter(|e| e.severity >= NOTABLE_SEVERITY && e.event_type != event_types::DIAGNOSTIC)
+        .map(|e| Incident {
+            event_type: e.event_type,
+            started_at: e.started_at,
+            severity: e.severity,
+            place: ctx
+                .db
+                .room()
+                .id()
+                .find(e.room_id)
+                .map(|r| r.name)
+                .unwrap_or_else(|| format!("Room {}", e.room_id)),
+        })
+        .collect();
+
+    FinalReport {
+        ship_name: config.as_ref().map(|c| c.name.clone()).unwrap_or_default(),
+        outcome,
+        loss_cause,
+        sim_time,
+        departed,
+        alive,
+        population: ctx
+            .db
+            .population_sample()
+            .iter()
+            .map(|s| PopulationPoint {
+                day: s.day,
+                alive: s.alive,
+            })
+            .collect(),
+        casualties: config.as_ref().map_or(0, |c| c.death_count),
+        causes: tally(
+            ctx.db.casualty().iter().map(|c| c.cause),
+            death_causes::name,
+        ),
+        incident_counts: tally(event_ids, progship_logic::constants::event_types::name),
+        incidents,
+        factions: faction_summaries(ctx),
+        resources: ctx
+            .db
+            .ship_resources()
+            .id()
+            .find(0)
+            .map(|r| {
+                [
+                    ("Power", r.power, r.power_cap),
+                    ("Water", r.water, r.water_cap),
+                    ("Oxygen", r.oxygen, r.oxygen_cap),
+                    ("Food", r.food, r.food_cap),
+                    ("Fuel", r.fuel, r.fuel_cap),
+                    ("Spare Parts", r.spare_parts, r.spare_parts_cap),
+                ]
+                .into_iter()
+                .map(|(resource, amount, capacity)| ResourceMargin {
+                    resource: resource.to_string(),
+                    amount,
+                    capacity,
+                })
+                .collect()
+            })
+            .unwrap_or_default(),
+        milestones: ctx
+            .db
+            .milestone()
+            .iter()
+            .map(|m| MilestoneEntry {
+                kind: m.kind,
+                achieved_at: m.achieved_at,
+                detail: m.detail,
+            })
+            .collect(),
+        mean_health: mean(|n| n.health),
+        mean_morale: mean(|n| n.morale),
+        colony_readiness: 0.0,
+    }
+    .finish()
+}
+
+/// How each faction (crew department, passenger cabin class) fared.
+fn faction_summaries(ctx: &ReducerContext) -> Vec<FactionSummary> {
+    let mut members: Vec<(String, u64)> = ctx
+        .db
+        .crew()
+        .iter()
+        .map(|c| (departments::name(c.department).to_string(), c.person_id))
+        .collect();
+    members.extend(ctx.db.passenger().iter().map(|p| {
+        let class = match p.cabin_class {
+            cabin_classes::FIRST => "First Class",
+            cabin_classes::STANDARD => "Standard Class",
+            _ => "Steerage",
+        };
+        (class.to_string(), p.person_id)
+    }));
+
+    let mut factions: Vec<FactionSummary> = Vec::new();
+    let mut morale_sums: Vec<f32> = Vec::new();
+    for (name, person_id) in members {
+        let Some(person) = ctx.db.person().id().find(person_id) else {
+            continue;
+        };
+        let index = match factions.iter().position(|g| g.name == name) {
+            Some(i) => i,
+            None => {
+                factions.push(FactionSummary {
+                    name,
+                    departed: 0,
+                    alive: 0,
+                    mean_morale: 0.0,
+                });
+                morale_sums.push(0.0);
+                factions.len() - 1
+            }
+        };
+        factions[index].departed += u32::from(!born_aboard(person.birth_date));
+        if person.is_alive {
+            factions[index].alive += 1;
+            morale_sums[index] += ctx
+                .db
+                .needs()
+                .person_id()
+                .find(person_id)
+                .map_or(0.0, |n| n.morale);
+        }
+    }
+    for (faction, sum) in factions.iter_mut().zip(morale_sums) {
+        if faction.alive > 0 {
+            faction.mean_morale = sum / faction.alive as f32;
+        }
+    }
+    factions
+}
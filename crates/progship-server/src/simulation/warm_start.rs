@@ -0,0 +1,40 @@
+//! Warm start - fast-forwarding a new ship so it boards with a history.
+//!
+//! Runs the simulation in coarse steps: people teleport to wherever their
+//! activity takes them instead of walking, and there is no wandering or
+//! automatic snapshot. Everything else (needs, conversations, relationships,
+//! wear, events and history) runs as in a normal tick.
+
+use crate::tables::*;
+use progship_logic::warm_start::{fast_forward_steps, warm_start_days};
+use spacetimedb::ReducerContext;
+
+use super::{finish_movements, tick_activities, tick_slow_systems};
+
+/// Simulated hours per warm start step.
+const WARM_START_STEP_HOURS: f64 = 0.25;
+
+/// Advance the ship `days` (capped, see `progship_logic::warm_start`) in
+/// coarse steps.
+pub fn fast_forward(ctx: &ReducerContext, days: f32) {
+    let steps = fast_forward_steps(days, WARM_START_STEP_HOURS);
+    if steps == 0 {
+        return;
+    }
+    log::info!(
+        "Warm start: fast-forwarding {:.1} days in {} steps",
+        warm_start_days(days),
+        steps
+    );
+    for _ in 0..steps {
+        let Some(mut config) = ctx.db.ship_config().id().find(0) else {
+            return;
+        };
+        config.sim_time += WARM_START_STEP_HOURS;
+        let sim_time = config.sim_time;
+        ctx.db.ship_config().id().update(config);
+        tick_activities(ctx, sim_time);
+        finish_movements(ctx, sim_time);
+        tick_slow_systems(ctx, sim_time, WARM_START_STEP_HOURS);
+    }
+}
@@ -0,0 +1,51 @@
+//! Simulation warnings - recoverable problems kept where operators can see
+//! them.
+
+use crate::tables::*;
+use progship_logic::watchdog::WARNING_KEEP;
+use spacetimedb::{ReducerContext, Table};
+
+/// Log that `system` ran into `message` and carried on, and count it on
+/// the matching simulation_warning row, dropping the stalest rows past
+/// [`WARNING_KEEP`].
+pub fn warn(ctx: &ReducerContext, system: &str, message: String) {
+    log::warn!("{}: {}", system, message);
+    let sim_time = ctx
+        .db
+        .ship_config()
+        .id()
+        .find(0)
+        .map_or(0.0, |c| c.sim_time);
+    let existing = ctx
+        .db
+        .simulation_warning()
+        .iter()
+        .find(|w| w.system == system && w.message == message);
+    if let Some(mut warning) = existing {
+        warning.last_at = sim_time;
+        warning.count = warning.count.saturating_add(1);
+        ctx.db.simulation_warning().id().update(warning);
+        return;
+    }
+    ctx.db.simulation_warning().insert(SimulationWarning {
+        id: 0,
+        system: system.to_string(),
+        message,
+        first_at: sim_time,
+        last_at: sim_time,
+        count: 1,
+    });
+
+    let mut kept: Vec<(f64, u64)> = ctx
+        .db
+        .simulation_warning()
+        .iter()
+        .map(|w| (w.last_at, w.id))
+        .collect();
+    if kept.len() > WARNING_KEEP {
+        kept.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+        for &(_, id) in &kept[..kept.len() - WARNING_KEEP] {
+            ctx.db.simulation_warning().id().delete(id);
+        }
+    }
+}
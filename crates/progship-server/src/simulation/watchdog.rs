@@ -0,0 +1,151 @@
+//! Watchdog system - hourly invariant checks that surface corrupt state.
+
+use crate::tables::*;
+use progship_logic::watchdog::{
+    diagnostic_severity, guard_violation, missing_components, needs_violations,
+    resource_violations, Violation, WATCHDOG_INTERVAL_HOURS,
+};
+use spacetimedb::{ReducerContext, Table};
+use std::collections::HashSet;
+
+use super::warnings::warn;
+
+/// Check simulation invariants once per sim-hour, recording each violation
+/// as a simulation warning and raising a diagnostic event when any are
+/// found.
+pub fn tick_watchdog(ctx: &ReducerContext, sim_time: f64, delta_hours: f64) {
+    let interval = |t: f64| (t / WATCHDOG_INTERVAL_HOURS).floor();
+    if interval(sim_time) == interval(sim_time - delta_hours) {
+        return;
+    }
+
+    let violations = check_invariants(ctx);
+    if violations.is_empty() {
+        return;
+    }
+    for v in &violations {
+        warn(ctx, "watchdog", v.to_string());
+    }
+    let already_active = ctx
+        .db
+        .event()
+        .iter()
+        .any(|e| e.event_type == event_types::DIAGNOSTIC && e.state != event_states::RESOLVED);
+    if !already_active {
+        let room_id = violations
+            .iter()
+            .find_map(|v| match v {
+                Violation::MissingRoom { person_id, .. } => ctx
+                    .db
+                    .position()
+                    .person_id()
+                    .find(*person_id)
+                    .map(|p| p.room_id),
+                _ => None,
+            })
+            .unwrap_or(0);
+        ctx.db.event().insert(Event {
+            id: 0,
+            event_type: event_types::DIAGNOSTIC,
+            room_id,
+            started_at: sim_time,
+            duration: WATCHDOG_INTERVAL_HOURS as f32,
+            state: event_states::ACTIVE,
+            responders_needed: 0,
+            responders_assigned: 0,
+            severity: diagnostic_severity(violations.len()),
+            escalated_to: None,
+        });
+    }
+}
+
+fn check_invariants(ctx: &ReducerContext) -> Vec<Violation> {
+    let mut violations = Vec::new();
+
+    for pos in ctx.db.position().iter() {
+        if ctx.db.room().id().find(pos.room_id).is_none() {
+            violations.push(Violation::MissingRoom {
+                person_id: pos.person_id,
+                room_id: pos.room_id,
+            });
+        }
+    }
+
+    for person in ctx.db.person().iter().filter(|p| p.is_alive) {
+        let id = person.id;
+        violations.extend(missing_components(
+            id,
+            &[
+                ("position", ctx.db.position().person_id().find(id).is_some()),
+                ("needs", ctx.db.needs().person_id().find(id).is_some()),
+                ("activity", ctx.db.activity().person_id().find(id).is_some()),
+            ],
+        ));
+    }
+
+    for n in ctx.db.needs().iter() {
+        violations.extend(needs_violations(
+            n.person_id,
+            &[
+                ("hunger", n.hunger),
+                ("fatigue", n.fatigue),
+                ("social", n.social),
+                ("comfort", n.comfort),
+                ("hygiene", n.hygiene),
+                ("health", n.health),
+                ("morale", n.morale),
+            ],
+        ));
+    }
+
+    if let Some(r) = ctx.db.ship_resources().id().find(0) {
+        violations.extend(resource_violations(&[
+            ("power", r.power),
+            ("water", r.water),
+            ("oxygen", r.oxygen),
+            ("food", r.food),
+            ("fuel", r.fuel),
+            ("spare_parts", r.spare_parts),
+        ]));
+    }
+
+    // Running conversations need both participants; participants need a
+    // running conversation
+    let mut orphaned: HashSet<u64> = HashSet::new();
+    for conv in ctx.db.conversation().iter() {
+        if conv.state == conversation_states::ENDED {
+            continue;
+        }
+        let joined = |person_id: u64| {
+            ctx.db
+                .in_conversation()
+                .person_id()
+                .find(person_id)
+                .is_some_and(|ic| ic.conversation_id == conv.id)
+        };
+        if !joined(conv.participant_a) || !joined(conv.participant_b) {
+            orphaned.insert(conv.id);
+        }
+    }
+    for ic in ctx.db.in_conversation().iter() {
+        let running = ctx
+            .db
+            .conversation()
+            .id()
+            .find(ic.conversation_id)
+            .is_some_and(|c| c.state != conversation_states::ENDED);
+        if !running {
+            orphaned.insert(ic.conversation_id);
+        }
+    }
+    let mut orphaned: Vec<u64> = orphaned.into_iter().collect();
+    orphaned.sort_unstable();
+    violations.extend(
+        orphaned
+            .into_iter()
+            .map(|conversation_id| Violation::OrphanedConversation { conversation_id }),
+    );
+    violations.extend(guard_violation());
+
+    violations
+}
@@ -0,0 +1,232 @@
+//! Water system - distribution from the recyclers over the water pipes.
+
+use crate::tables::*;
+use progship_logic::plumbing::{
+    burst_chance, bursts, freezes, leak_share, pipe_capacity, water_demand, BURST_DAMAGE,
+};
+use progship_logic::power_grid::{solve, Line, Load, LoadFlow};
+use spacetimedb::{ReducerContext, Table};
+use std::collections::{HashMap, HashSet};
+
+use super::ship_systems::{calculate_subsystem_efficiency, shed_nodes};
+
+/// Headroom pipes are laid with over the water they carry at launch.
+const PIPE_MARGIN: f32 = 1.25;
+
+/// Sim hours between burst rolls.
+const BURST_INTERVAL_HOURS: f64 = 1.0;
+
+/// The water network as the solver sees it.
+struct WaterModel {
+    sources: Vec<u64>,
+    /// Water recycled per hour.
+    production: f32,
+    loads: Vec<Load>,
+    /// Room each load feeds.
+    load_rooms: Vec<u32>,
+    lines: Vec<Line>,
+    /// InfraEdge ID of each line.
+    line_edges: Vec<u64>,
+    /// Whether each line runs through a frosted room.
+    line_frozen: Vec<bool>,
+}
+
+fn water_model(ctx: &ReducerContext) -> WaterModel {
+    let shed = shed_nodes(ctx);
+    let mut sources = Vec::new();
+    let mut production = 0.0;
+    for sub in ctx.db.subsystem().iter().filter(|s| {
+        s.subsystem_type == subsystem_types::WATER_FILTRATION
+            || s.subsystem_type == subsystem_types::WATER_DISTILLATION
+    }) {
+        if !shed.contains(&sub.node_id) {
+            production += sub.output * calculate_subsystem_efficiency(sub.health, sub.status);
+        }
+        sources.push(sub.node_id);
+    }
+
+    let rooms: Vec<Room> = ctx.db.room().iter().collect();
+    let (loads, load_rooms): (Vec<Load>, Vec<u32>) = rooms
+        .iter()
+        .filter_map(|r| {
+            let (draw, priority) = water_demand(r.room_type)?;
+            let load = Load {
+                node: r.node_id,
+                draw,
+                priority,
+            };
+            Some((load, r.id))
+        })
+        .unzip();
+
+    // A pipe freezes where either of its ends is iced over
+    let frost: HashMap<u32, f32> = ctx
+        .db
+        .room_effect()
+        .iter()
+        .filter(|e| e.kind == effect_kinds::FROST)
+        .map(|e| (e.room_id, e.intensity))
+        .collect();
+    let frozen_nodes: HashSet<u64> = rooms
+        .iter()
+        .filter(|r| frost.get(&r.id).is_some_and(|&f| freezes(f)))
+        .map(|r| r.node_id)
+        .collect();
+    let pipe_edges: HashMap<u64, (u64, u64)> = ctx
+        .db
+        .graph_edge()
+        .iter()
+        .filter(|e| e.edge_type == edge_types::WATER)
+        .map(|e| (e.id, (e.from_node, e.to_node)))
+        .collect();
+    let mut lines = Vec::new();
+    let mut line_edges = Vec::new();
+    let mut line_frozen = Vec::new();
+    for edge in ctx
+        .db
+        .infra_edge()
+        .iter()
+        .filter(|e| e.edge_type == infra_types::WATER_PIPE)
+    {
+        let Some(&(from, to)) = pipe_edges.get(&edge.graph_edge_id) else {
+            continue;
+        };
+        let frozen = frozen_nodes.contains(&from) || frozen_nodes.contains(&to);
+        lines.push(Line {
+            from,
+            to,
+            capacity: pipe_capacity(edge.capacity, edge.health, frozen),
+        });
+        line_edges.push(edge.id);
+        line_frozen.push(frozen);
+    }
+    // Pipes leaving a node no pipe feeds start at a header tank
+    for line in &lines {
+        if !lines.iter().any(|l| l.to == line.from) && !sources.contains(&line.from) {
+            sources.push(line.from);
+        }
+    }
+
+    WaterModel {
+        sources,
+        production,
+        loads,
+        load_rooms,
+        lines,
+        line_edges,
+        line_frozen,
+    }
+}
+
+/// Run the water network for `delta_hours`: serve wet rooms from the
+/// recyclers and the tanks, lose what worn pipes leak and roll bursts
+/// hourly. Rooms left dry can't be washed in and serve only cold meals
+/// (see `tick_needs` and `player_action`).
+pub fn tick_water(ctx: &ReducerContext, sim_time: f64, delta_hours: f32) {
+    let Some(mut resources) = ctx.db.ship_resources().id().find(0) else {
+        return;
+    };
+    let model = water_model(ctx);
+    let tank_per_hour = if delta_hours > 0.0 {
+        resources.water / delta_hours
+    } else {
+        0.0
+    };
+    let flow = solve(
+        &model.sources,
+        model.production + tank_per_hour,
+        &model.loads,
+        &model.lines,
+    );
+
+    let interval = |t: f64| (t / BURST_INTERVAL_HOURS).floor();
+    let burst_hours = ((interval(sim_time) - interval(sim_time - delta_hours as f64))
+        * BURST_INTERVAL_HOURS) as f32;
+    let mut leaked = 0.0;
+    for ((&edge_id, &carried), &frozen) in model
+        .line_edges
+        .iter()
+        .zip(&flow.line_flow)
+        .zip(&model.line_frozen)
+    {
+        let Some(mut edge) = ctx.db.infra_edge().id().find(edge_id) else {
+            continue;
+        };
+        leaked += carried * leak_share(edge.health) * delta_hours;
+        edge.current_flow = carried;
+        if burst_hours > 0.0
+            && bursts(
+                edge_id,
+                sim_time,
+                burst_chance(edge.health, frozen) * burst_hours,
+            )
+        {
+            edge.health = (edge.health - BURST_DAMAGE).max(0.0);
+            log::warn!(
+                "Water pipe {} burst{}, health now {:.2}",
+                edge_id,
+                if frozen { " frozen" } else { "" },
+                edge.health
+            );
+        }
+        ctx.db.infra_edge().id().update(edge);
+    }
+    resources.water = (resources.water - leaked).max(0.0);
+    ctx.db.ship_resources().id().update(resources);
+
+    record_room_water(ctx, &model, &flow);
+}
+
+fn record_room_water(ctx: &ReducerContext, model: &WaterModel, flow: &LoadFlow) {
+    for ((&room_id, load), &supplied) in model.load_rooms.iter().zip(&model.loads).zip(&flow.served)
+    {
+        let row = RoomWater {
+            room_id,
+            demand: load.draw,
+            supplied,
+        };
+        match ctx.db.room_water().room_id().find(room_id) {
+            Some(old) if old.supplied == row.supplied && old.demand == row.demand => {}
+            Some(old) => {
+                if old.supplied != row.supplied {
+                    if row.supplied {
+                        log::info!("Water back on in room {}", room_id);
+                    } else {
+                        log::warn!("Room {} has run dry", room_id);
+                    }
+                }
+                ctx.db.room_water().room_id().update(row);
+            }
+            None => {
+                ctx.db.room_water().insert(row);
+            }
+        }
+    }
+}
+
+/// Rooms whose water supply is cut off.
+pub fn dry_rooms(ctx: &ReducerContext) -> HashSet<u32> {
+    ctx.db
+        .room_water()
+        .iter()
+        .filter(|w| !w.supplied)
+        .map(|w| w.room_id)
+        .collect()
+}
+
+/// Lay every water pipe with headroom over the water it carries with every
+/// tap running, so a new ship starts with no room dry.
+pub fn size_water_pipes(ctx: &ReducerContext) {
+    let mut model = water_model(ctx);
+    for line in &mut model.lines {
+        line.capacity = f32::MAX;
+    }
+    let flow = solve(&model.sources, f32::MAX, &model.loads, &model.lines);
+    for (&edge_id, &carried) in model.line_edges.iter().zip(&flow.line_flow) {
+        if let Some(mut edge) = ctx.db.infra_edge().id().find(edge_id) {
+            edge.capacity = edge.capacity.max(carried * PIPE_MARGIN);
+            edge.current_flow = carried;
+            ctx.db.infra_edge().id().update(edge);
+        }
+    }
+}
@@ -3,7 +3,7 @@
 //! Each table is equivalent to an ECS component from progship-core,
 //! but stored in SpacetimeDB for persistence and multiplayer sync.
 
-use spacetimedb::{table, Identity, Timestamp};
+use spacetimedb::{table, Identity, ScheduleAt, Timestamp};
 
 // ============================================================================
 // SHIP CONFIGURATION
@@ -34,6 +34,126 @@ pub struct ShipConfig {
     pub death_count: u32,
     /// Current rationing level (0=normal, 1=light, 2=heavy, 3=emergency).
     pub rationing_level: u8,
+    /// Sim time the current stretch of rationing began, at any level.
+    pub rationing_since: f64,
+    /// Decks still waiting to be generated (0 once the ship is complete).
+    pub pending_decks: u32,
+    /// People still waiting to board a lazily generated ship (0 once
+    /// everyone is aboard).
+    pub pending_people: u32,
+    /// Days fast-forwarded once the ship is populated (0 for a day-zero ship).
+    pub warm_start_days: f32,
+    /// Generation seed; the same seed and parameters give the same ship.
+    pub seed: u64,
+    /// Scenario the ship was started from ("" for a custom ship); a scripted
+    /// scenario gives players objectives.
+    pub scenario: String,
+    /// Difficulty (see difficulties module).
+    pub difficulty: u8,
+    /// Longitudinal corridor arrangement the decks are laid out with
+    /// (`progship_logic::genlib::layout::CorridorTopology` as u8).
+    pub corridor_topology: u8,
+    /// Curve the bow and stern decks taper along
+    /// (`progship_logic::genlib::hull::TaperCurve` as u8).
+    pub hull_taper: u8,
+}
+
+/// Scheduled job laying out the next pending deck of a lazily generated ship,
+/// then boarding its people batch by batch.
+#[table(name = deck_generation_job, public, scheduled(crate::generation::generate_pending_deck))]
+pub struct DeckGenerationJob {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for the job.
+    pub scheduled_id: u64,
+    /// When the job runs.
+    pub scheduled_at: ScheduleAt,
+    /// Deck to lay out; the deck count once every deck is laid out and the
+    /// job boards the next batch of people.
+    pub deck: u32,
+}
+
+/// Who owns the ship (singleton, id=0): the identity that published the
+/// module, seeded in init. Only the owner may change ship-wide
+/// configuration or configure the stream.
+#[table(name = ship_owner, public)]
+pub struct ShipOwner {
+    #[primary_key]
+    /// Unique identifier (always 0 for singleton).
+    pub id: u32,
+    /// Identity that published the module.
+    pub owner: Identity,
+}
+
+/// Need decay multipliers by age, personality and fitness (singleton, id=0;
+/// see `progship_logic::balance`). Defaults apply until set_balance_config
+/// is called.
+#[table(name = balance_config, public)]
+pub struct BalanceConfig {
+    #[primary_key]
+    /// Unique identifier (always 0 for singleton).
+    pub id: u32,
+    /// Hunger multiplier for infants and children.
+    pub child_hunger: f32,
+    /// Fatigue multiplier for infants.
+    pub infant_fatigue: f32,
+    /// Fatigue multiplier for elders.
+    pub elder_fatigue: f32,
+    /// Social multiplier spread from extraversion 0 to 1, centred on 1.0.
+    pub extraversion_social: f32,
+    /// Comfort multiplier spread from neuroticism 0 to 1, centred on 1.0.
+    pub neuroticism_comfort: f32,
+    /// Extra fatigue at zero health; healthy people tire at the base rate.
+    pub unfit_fatigue: f32,
+}
+
+/// Optional simulation systems this ship runs (singleton, id=0; see
+/// `progship_logic::features`). Defaults apply until set_feature_flags is
+/// called.
+#[table(name = feature_flags, public)]
+pub struct FeatureFlags {
+    #[primary_key]
+    /// Unique identifier (always 0 for singleton).
+    pub id: u32,
+    /// Whether medical emergencies break out.
+    pub disease: bool,
+    /// Whether people age, have birthdays and die of old age.
+    pub generational: bool,
+    /// Whether altercations break out.
+    pub crime: bool,
+    /// Whether factions and shipboard governance run.
+    pub politics: bool,
+    /// Whether the atmosphere does double harm.
+    pub hardcore_atmosphere: bool,
+}
+
+/// Decks each zone is laid out on. Rows set with
+/// set_zone_decks pin a zone before init_ship; layout records the range it
+/// chose for every other zone.
+#[table(name = zone_config, public)]
+pub struct ZoneConfig {
+    #[primary_key]
+    /// Zone: 0=command, 1=habitation, 2=services, 3=recreation,
+    /// 4=life support, 5=cargo, 6=engineering.
+    pub zone: u8,
+    /// First deck of the zone.
+    pub first_deck: u32,
+    /// One past the zone's last deck.
+    pub end_deck: u32,
+    /// True if pinned with set_zone_decks rather than chosen by layout.
+    pub overridden: bool,
+}
+
+/// Population share of a naming culture (see `progship_logic::names`).
+/// Cultures without a row keep their default weight; set with
+/// set_culture_weight before init_ship.
+#[table(name = culture_mix, public)]
+pub struct CultureMix {
+    #[primary_key]
+    /// Index into `progship_logic::names::NAME_POOLS`.
+    pub culture: u8,
+    /// Relative weight; zero leaves the culture out.
+    pub weight: f32,
 }
 
 // ============================================================================
@@ -59,6 +179,15 @@ pub struct Person {
     pub is_alive: bool,
     /// SpacetimeDB identity of the player controlling this person, if any.
     pub owner_identity: Option<Identity>,
+    /// Age in whole years at departure.
+    pub age: u8,
+    /// Sim time of birth in hours (negative: before departure).
+    pub birth_date: f64,
+    /// Life stage (see life_stages module).
+    pub life_stage: u8,
+    /// Highest door access level this person is cleared for (see
+    /// access_levels module).
+    pub clearance: u8,
 }
 
 /// Physical position of a person within the ship's coordinate system.
@@ -122,6 +251,32 @@ pub struct Needs {
     pub morale: f32,
 }
 
+/// Radiation dose a person has taken, added up by tick_needs.
+#[table(name = radiation_dose, public)]
+pub struct RadiationDose {
+    #[primary_key]
+    /// Foreign key to Person.id.
+    pub person_id: u64,
+    /// Dose over the whole voyage in mSv.
+    pub lifetime_msv: f32,
+    /// Recent dose in mSv, which the body repairs over days.
+    pub acute_msv: f32,
+}
+
+/// Current emotion of a person, derived periodically from needs and events.
+#[table(name = emotion, public)]
+pub struct Emotion {
+    #[primary_key]
+    /// Foreign key to Person.id.
+    pub person_id: u64,
+    /// Current emotion (see emotions module).
+    pub emotion: u8,
+    /// How strongly it is felt (0.0-1.0).
+    pub intensity: f32,
+    /// Simulation time until which the person grieves a death.
+    pub grieving_until: f64,
+}
+
 /// Big Five personality traits for a person (0.0-1.0 normalized scale).
 #[table(name = personality, public)]
 pub struct Personality {
@@ -140,6 +295,16 @@ pub struct Personality {
     pub neuroticism: f32,
 }
 
+/// Backstory tag and quirks for a person.
+#[table(name = person_trait, public)]
+pub struct PersonTrait {
+    #[primary_key]
+    /// Foreign key to Person.id.
+    pub person_id: u64,
+    /// Bitmask of traits (see person_traits module).
+    pub traits: u16,
+}
+
 /// Professional skill levels for a person (0.0-1.0 normalized scale).
 #[table(name = skills, public)]
 pub struct Skills {
@@ -160,6 +325,226 @@ pub struct Skills {
     pub combat: f32,
 }
 
+/// How a crew member keeps their skills up over a long voyage, each list
+/// indexed by skill_types.
+#[table(name = skill_upkeep, public)]
+pub struct SkillUpkeep {
+    #[primary_key]
+    /// Foreign key to Person.id.
+    pub person_id: u64,
+    /// Level each skill was qualified at; refreshers train back up to it.
+    pub qualified: Vec<f32>,
+    /// Simulation time each skill was last practiced.
+    pub last_practiced: Vec<f64>,
+}
+
+/// A crew member's career: time served, certification in their department
+/// skill and when they reached their rank.
+#[table(name = career, public)]
+pub struct Career {
+    #[primary_key]
+    /// Foreign key to Person.id.
+    pub person_id: u64,
+    /// Hours served on duty and in training.
+    pub service_hours: f32,
+    /// Certification held (see certifications module).
+    pub certification: u8,
+    /// Simulation time they reached their current rank.
+    pub rank_since: f64,
+}
+
+/// A child's time in the nursery and school, kept on once they leave.
+#[table(name = schooling, public)]
+pub struct Schooling {
+    #[primary_key]
+    /// Foreign key to Person.id.
+    pub person_id: u64,
+    /// Hours spent in class.
+    pub hours: f32,
+    /// Of those, hours with a teacher in the room.
+    pub taught_hours: f32,
+}
+
+/// An adult assigned to teach in the school or nursery during school hours.
+#[table(name = teacher, public)]
+pub struct Teacher {
+    #[primary_key]
+    /// Foreign key to Person.id.
+    pub person_id: u64,
+    /// Classroom they teach in (Room.id).
+    pub room_id: u32,
+    /// Simulation time they were assigned.
+    pub assigned_at: f64,
+}
+
+/// Someone hurt enough to need medical care, waiting for a ward bed or in
+/// one. Removed on discharge.
+#[table(name = patient, public)]
+#[derive(Clone)]
+pub struct Patient {
+    #[primary_key]
+    /// Foreign key to Person.id.
+    pub person_id: u64,
+    /// How urgently they need treatment (see triage_levels module).
+    pub triage: u8,
+    /// Waiting or admitted (see patient_states module).
+    pub state: u8,
+    /// Ward they are admitted to (Room.id), while admitted.
+    pub room_id: Option<u32>,
+    /// Medical crew member looking after them, if any.
+    pub medic_id: Option<u64>,
+    /// Simulation time they became a patient.
+    pub arrived_at: f64,
+    /// Simulation time they were given a bed, while admitted.
+    pub admitted_at: Option<f64>,
+    /// Simulation time they are expected to be discharged, while admitted.
+    pub expected_discharge: Option<f64>,
+}
+
+/// An operation on a critically injured patient: scheduled, under way or
+/// done. Kept for a day after it finishes, during which the patient is not
+/// operated on again.
+#[table(name = surgery, public)]
+#[derive(Clone)]
+pub struct Surgery {
+    #[primary_key]
+    /// Foreign key to Person.id of the patient.
+    pub person_id: u64,
+    /// Medical crew member operating.
+    pub surgeon_id: u64,
+    /// Operating theatre (Room.id).
+    pub room_id: u32,
+    /// Simulation time the operation was scheduled.
+    pub scheduled_at: f64,
+    /// Simulation time the operation began, once both are in the theatre.
+    pub started_at: Option<f64>,
+    /// Hours the operation takes.
+    pub duration_hours: f32,
+    /// How it turned out (see surgery_outcomes module), once finished.
+    pub outcome: Option<u8>,
+    /// Simulation time it finished.
+    pub finished_at: Option<f64>,
+}
+
+/// Someone flagged with a contagious illness, kept in quarantine until
+/// recovered. Removed on release.
+#[table(name = quarantine, public)]
+#[derive(Clone)]
+pub struct Quarantine {
+    #[primary_key]
+    /// Foreign key to Person.id.
+    pub person_id: u64,
+    /// Outbreak they caught it in (Event.id).
+    pub event_id: u64,
+    /// Room they are isolated in.
+    pub room_id: u32,
+    /// Simulation time they were flagged.
+    pub flagged_at: f64,
+}
+
+/// A passenger making the voyage asleep in a cryo pod. Removed once they
+/// are revived.
+#[table(name = hibernation, public)]
+#[derive(Clone)]
+pub struct Hibernation {
+    #[primary_key]
+    /// Foreign key to Person.id.
+    pub person_id: u64,
+    /// Cryo bay they sleep in (Room.id).
+    pub room_id: u32,
+    /// Their pod (Furniture.id).
+    pub pod_id: u64,
+    /// Simulation time they went to sleep.
+    pub since: f64,
+    /// Simulation time they are due to be revived.
+    pub wake_at: f64,
+    /// Simulation time the medical crew last checked on them.
+    pub checked_at: f64,
+    /// How well they are keeping in the pod (0.0-1.0).
+    pub vitals: f32,
+    /// Simulation time their revival began, once it has.
+    pub reviving_since: Option<f64>,
+    /// Whether they are being revived in an emergency.
+    pub emergency: bool,
+    /// Revival event (Event.id) in their bay, once begun.
+    pub revival_event: Option<u64>,
+}
+
+/// Stress someone has built up, their counseling session and breakdown.
+/// Created lazily for NPCs; the player never has one.
+#[table(name = mental_health, public)]
+#[derive(Clone)]
+pub struct MentalHealth {
+    #[primary_key]
+    /// Foreign key to Person.id.
+    pub person_id: u64,
+    /// Built-up stress (0.0-1.0).
+    pub stress: f32,
+    /// Mental Health room of their booked session.
+    pub session_room: Option<u32>,
+    /// Counselor seeing them (Person.id).
+    pub counselor_id: Option<u64>,
+    /// Simulation time their session ends.
+    pub session_until: Option<f64>,
+    /// Simulation time their current breakdown ends.
+    pub breakdown_until: Option<f64>,
+    /// Breakdowns they have had.
+    pub breakdowns: u32,
+}
+
+/// How much someone has had to drink and how dependent on it they are.
+/// Created at their first drink.
+#[table(name = drinking, public)]
+#[derive(Clone)]
+pub struct Drinking {
+    #[primary_key]
+    /// Foreign key to Person.id.
+    pub person_id: u64,
+    /// How drunk they are (0.0 sober).
+    pub intoxication: f32,
+    /// How dependent on drink they are (0.0-1.0).
+    pub dependence: f32,
+    /// Drinks they have had.
+    pub drinks: u32,
+    /// Simulation time of their last drink.
+    pub last_drink_at: f64,
+}
+
+/// Something a person carries. Stolen items remember whom they were
+/// taken from until security returns them.
+#[table(name = inventory_item, public)]
+#[derive(Clone)]
+pub struct InventoryItem {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this item.
+    pub id: u64,
+    /// Person ID of whoever carries it.
+    pub owner_id: u64,
+    /// What it is (see item_kinds module).
+    pub kind: u8,
+    /// Simulation time its owner came by it.
+    pub acquired_at: f64,
+    /// Person ID of whoever it was stolen from, if it was.
+    pub stolen_from: Option<u64>,
+}
+
+/// Credits a person holds, earned and spent. Created the first time they
+/// are paid or buy something.
+#[table(name = wallet, public)]
+#[derive(Clone)]
+pub struct Wallet {
+    #[primary_key]
+    /// Foreign key to Person.id.
+    pub person_id: u64,
+    /// Credits on hand.
+    pub credits: u32,
+    /// Credits earned in wages.
+    pub earned: u32,
+    /// Credits spent at the shops and bars.
+    pub spent: u32,
+}
+
 /// Current activity state for a person's scheduled behavior.
 #[table(name = activity, public)]
 #[derive(Clone)]
@@ -175,6 +560,37 @@ pub struct Activity {
     pub duration: f32,
     /// Room where the activity takes place, if applicable.
     pub target_room_id: Option<u32>,
+    /// Body posture for clients to render (see postures module).
+    pub animation_state: u8,
+    /// Foreign key to the ActivityAnchor.id being used, if any.
+    pub target_anchor_id: Option<u64>,
+    /// Foreign key to the Person.id being interacted with, if any.
+    pub target_person_id: Option<u64>,
+}
+
+/// Why a person picked their current activity, for the inspector. Rows exist
+/// only for people someone asked about with `explain_activity`, and are
+/// refreshed each time that person picks a new activity.
+#[table(name = activity_explanation, public)]
+pub struct ActivityExplanation {
+    #[primary_key]
+    /// Foreign key to Person.id.
+    pub person_id: u64,
+    /// Simulation time of the explained pick in hours.
+    pub sim_time: f64,
+    /// Every candidate activity, best first, with its score factors.
+    pub candidates: Vec<String>,
+}
+
+/// Last day of a person's activities, rooms and conversations for the
+/// inspector.
+#[table(name = person_timeline, public)]
+pub struct PersonTimeline {
+    #[primary_key]
+    /// Foreign key to Person.id.
+    pub person_id: u64,
+    /// Packed entries, oldest first (see `progship_logic::timeline`).
+    pub entries: Vec<u64>,
 }
 
 /// Crew-specific information for personnel assigned to ship operations.
@@ -195,6 +611,53 @@ pub struct Crew {
     pub on_duty: bool,
 }
 
+/// A crew member's post in the chain of command.
+#[table(name = command_chain, public)]
+#[derive(Clone)]
+pub struct CommandChain {
+    #[primary_key]
+    /// Foreign key to Person.id.
+    pub person_id: u64,
+    /// Post held (see command_posts module).
+    pub post: u8,
+    /// Department the post answers for.
+    pub department: u8,
+    /// Person ID of the superior officer, `None` for the captain.
+    pub superior_id: Option<u64>,
+}
+
+/// A bulk order given by a command officer, in force until `expires_at`.
+#[table(name = standing_order, public)]
+#[derive(Clone)]
+pub struct StandingOrder {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this order.
+    pub id: u64,
+    /// What the order is (see order_kinds module).
+    pub kind: u8,
+    /// Department a damage-control order is for.
+    pub department: u8,
+    /// Deck a security sweep covers.
+    pub deck: i32,
+    /// Person ID of the officer who gave it, `None` if given by the server.
+    pub issued_by: Option<u64>,
+    /// Simulation time the order was given.
+    pub issued_at: f64,
+    /// Simulation time the order lapses.
+    pub expires_at: f64,
+}
+
+/// A person following a standing order instead of their own activity pick.
+#[table(name = order_assignment, public)]
+pub struct OrderAssignment {
+    #[primary_key]
+    /// Foreign key to Person.id.
+    pub person_id: u64,
+    /// Foreign key to StandingOrder.id.
+    pub order_id: u64,
+}
+
 /// Passenger-specific information for civilians traveling aboard the colony ship.
 #[table(name = passenger, public)]
 pub struct Passenger {
@@ -244,6 +707,11 @@ pub struct Room {
     /// Cell mask: packed list of axis-aligned rects [(x0,y0,x1,y1) as u16].
     /// Each rect = 8 bytes (4 × u16). Empty means use bbox (x,y,width,height).
     pub cells: Vec<u8>,
+    /// Bit set of walls (`1 << wall side`) that face the outer hull.
+    pub hull_sides: u8,
+    /// Whether the room has a window onto the stars (see
+    /// `progship_logic::genlib::hull::has_window`).
+    pub has_window: bool,
 }
 
 /// Logical graph node representing any functional entity in the ship's network.
@@ -315,10 +783,76 @@ pub struct Door {
     pub door_x: f32,
     /// Absolute world Y coordinate of door center in meters.
     pub door_y: f32,
-    /// Whether the door is currently open (true = open, false = closed).
+    /// Open, closed, locked or welded (see door_states module).
+    pub state: u8,
+}
+
+/// Layout metrics computed once generation finishes (singleton, id=0), for
+/// balancing the generator (see progship_logic::geometry::layout_stats).
+#[table(name = generation_stats, public)]
+pub struct GenerationStats {
+    #[primary_key]
+    /// Unique identifier (always 0 for singleton).
+    pub id: u32,
+    /// Rooms generated, including corridors and shafts.
+    pub room_count: u32,
+    /// Doors generated.
+    pub door_count: u32,
+    /// Floor area of every room except shafts, in square meters.
+    pub total_floor_area: f32,
+    /// Floor area per person aboard, in square meters.
+    pub area_per_person: f32,
+    /// Share of the floor area that is corridor.
+    pub corridor_fraction: f32,
+    /// Mean long-side to short-side ratio of the non-corridor rooms.
+    pub mean_aspect_ratio: f32,
+    /// Rooms that can't be reached from the rest of the ship.
+    pub unreachable_rooms: u32,
+    /// Doors by access level (index = access_levels value).
+    pub doors_by_access: Vec<u32>,
+}
+
+/// Opening through the outer hull: an airlock's outer door, an EVA hatch or
+/// a bay door.
+#[table(name = hull_hatch, public)]
+pub struct HullHatch {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this hatch.
+    pub id: u64,
+    /// Foreign key to the Room it opens out of.
+    pub room_id: u32,
+    /// Kind of hatch (see hatch_types module).
+    pub hatch_type: u8,
+    /// Hull-facing wall of the room the hatch is in (see wall_sides module).
+    pub wall: u8,
+    /// Absolute world X coordinate of hatch center in meters.
+    pub x: f32,
+    /// Absolute world Y coordinate of hatch center in meters.
+    pub y: f32,
+    /// Width of the opening in meters.
+    pub width: f32,
+    /// Whether the hatch is open to space.
     pub is_open: bool,
-    /// Whether the door is locked (requires access_level to toggle).
-    pub is_locked: bool,
+}
+
+/// Damage to the outer hull, leaking air until an EVA crew patches it.
+#[table(name = hull_damage, public)]
+pub struct HullDamage {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this damage.
+    pub id: u64,
+    /// Deck number the damage is on.
+    pub deck: i32,
+    /// Absolute world X coordinate of the damage in meters.
+    pub x: f32,
+    /// Absolute world Y coordinate of the damage in meters.
+    pub y: f32,
+    /// How bad it is (0.0-1.0); the worse, the faster it leaks.
+    pub severity: f32,
+    /// Simulation time the hull was damaged.
+    pub damaged_at: f64,
 }
 
 /// Procedurally generated corridor providing primary navigation paths between rooms.
@@ -369,11 +903,121 @@ pub struct VerticalShaft {
     pub height: f32,
 }
 
-/// Atmospheric conditions and life support status for a single deck.
-#[table(name = deck_atmosphere, public)]
-pub struct DeckAtmosphere {
+/// Piece of furniture or equipment placed inside a room.
+#[table(name = furniture, public)]
+pub struct Furniture {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this piece.
+    pub id: u64,
+    /// Foreign key to the Room.id holding this piece.
+    pub room_id: u32,
+    /// Type of furniture (see furniture_types module).
+    pub furniture_type: u8,
+    /// X coordinate of the piece center in meters.
+    pub x: f32,
+    /// Y coordinate of the piece center in meters.
+    pub y: f32,
+    /// Footprint along X in meters.
+    pub width: f32,
+    /// Footprint along Y in meters.
+    pub height: f32,
+}
+
+/// Interaction point inside a room where a person performs an activity.
+#[table(name = activity_anchor, public)]
+#[derive(Clone)]
+pub struct ActivityAnchor {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this anchor.
+    pub id: u64,
+    /// Foreign key to the Room.id holding this anchor.
+    pub room_id: u32,
+    /// Type of anchor (see anchor_types module).
+    pub anchor_type: u8,
+    /// Activity performed here (see activity_types module).
+    pub activity_type: u8,
+    /// X coordinate in meters.
+    pub x: f32,
+    /// Y coordinate in meters.
+    pub y: f32,
+}
+
+/// Named deck with its zone theme, gravity, and ambient lighting hints.
+#[table(name = deck, public)]
+pub struct Deck {
+    #[primary_key]
+    /// Deck number (0 = top deck).
+    pub deck: i32,
+    /// Display name, e.g. "Deck 4 — Habitation Ring B".
+    pub name: String,
+    /// Zone with the most rooms on this deck (0=command … 6=engineering).
+    pub primary_zone: u8,
+    /// Number of rooms per zone on this deck, indexed by zone.
+    pub zone_rooms: Vec<u32>,
+    /// Artificial gravity in g.
+    pub gravity: f32,
+    /// Ambient light level (0.0-1.0).
+    pub light_level: f32,
+    /// Ambient light color temperature in kelvin.
+    pub light_kelvin: u32,
+    /// Shift whose day the deck's lights follow (see shifts module).
+    pub light_schedule: u8,
+    /// Share (0-1) of the radiation outside the hull that reaches the deck.
+    pub radiation_transmission: f32,
+    /// Whether this is the storm shelter people take to in a solar flare.
+    pub storm_shelter: bool,
+}
+
+/// Nickname the crew gave a room, shown in place of its generated name.
+#[table(name = room_nickname, public)]
+pub struct RoomNickname {
+    #[primary_key]
+    /// Room.id of the renamed room.
+    pub room_id: u32,
+    /// Nickname (see progship_logic::nicknames).
+    pub name: String,
+    /// Person.id of whoever set it.
+    pub named_by: u64,
+}
+
+/// Nickname the crew gave a deck, shown in place of its generated name.
+#[table(name = deck_nickname, public)]
+pub struct DeckNickname {
+    #[primary_key]
+    /// Deck number of the renamed deck.
+    pub deck: i32,
+    /// Nickname (see progship_logic::nicknames).
+    pub name: String,
+    /// Person.id of whoever set it.
+    pub named_by: u64,
+}
+
+/// A deck's lights through the ship day. Created with the first tick.
+#[table(name = lighting, public)]
+pub struct Lighting {
+    #[primary_key]
+    /// Deck number these lights belong to.
+    pub deck: i32,
+    /// Hours command has shifted the deck's cycle later (negative: earlier).
+    pub shift_hours: f32,
+    /// Current mode (see lighting_modes module).
+    pub mode: u8,
+    /// Current light level (0.0-1.0).
+    pub level: f32,
+    /// Current color temperature in kelvin.
+    pub kelvin: u32,
+}
+
+/// Atmospheric conditions in a single room (see
+/// `progship_logic::atmosphere`).
+#[table(name = room_air, public)]
+pub struct RoomAir {
     #[primary_key]
-    /// Deck number for this atmosphere record.
+    /// Foreign key to Room.id.
+    pub room_id: u32,
+    /// Deck the room is on.
     pub deck: i32,
     /// Oxygen concentration (0.0-1.0, nominal ~0.21).
     pub oxygen: f32,
@@ -385,6 +1029,10 @@ pub struct DeckAtmosphere {
     pub temperature: f32,
     /// Air pressure in kilopascals (nominal ~101).
     pub pressure: f32,
+    /// Temperature life support holds the room at, from its deck's zone.
+    pub target_temperature: f32,
+    /// Humidity life support holds the room at, from its deck's zone.
+    pub target_humidity: f32,
 }
 
 // ============================================================================
@@ -435,6 +1083,10 @@ pub struct Subsystem {
     pub power_draw: f32,
     /// Number of crew required to operate this subsystem.
     pub crew_required: u8,
+    /// Rated production per hour at full health: kW for reactors, kg of O2,
+    /// liters of water or kg of food for producers, kg of fuel burned for
+    /// drives. Zero for subsystems that only support others.
+    pub output: f32,
 }
 
 /// Individual physical component within a subsystem (pump, valve, sensor, etc.).
@@ -487,6 +1139,21 @@ pub struct InfraEdge {
     pub health: f32,
 }
 
+/// An HVAC duct from the air handlers in HVAC Control out to the rooms of
+/// one graph node (see `progship_logic::hvac`).
+#[table(name = air_duct, public)]
+pub struct AirDuct {
+    #[primary_key]
+    /// Foreign key to InfraEdge.id of the duct.
+    pub infra_edge_id: u64,
+    /// Foreign key to GraphNode.id whose rooms the duct serves.
+    pub node_id: u64,
+    /// Dust and lint gathered in the duct (0.0 clean - 1.0 clogged).
+    pub fouling: f32,
+    /// Share of full airflow the duct carried on the last tick (0.0-1.0).
+    pub airflow: f32,
+}
+
 /// Ship-wide resource storage tracking current levels and maximum capacities (singleton, id=0).
 #[table(name = ship_resources, public)]
 pub struct ShipResources {
@@ -519,61 +1186,341 @@ pub struct ShipResources {
     pub spare_parts_cap: f32,
 }
 
-/// Active maintenance task assigned to repair or service a system component.
-#[table(name = maintenance_task, public)]
-#[derive(Clone)]
-pub struct MaintenanceTask {
+/// Where the ship is along its voyage and how its burns are going
+/// (singleton, id=0).
+#[table(name = voyage_state, public)]
+pub struct VoyageState {
     #[primary_key]
-    #[auto_inc]
-    /// Unique identifier for this maintenance task.
-    pub id: u64,
-    /// Foreign key to SystemComponent.id being serviced.
-    pub component_id: u64,
-    /// Foreign key to Subsystem.id (denormalized for quick lookup).
-    pub subsystem_id: u64,
-    /// Foreign key to Person.id of assigned crew member, if any.
-    pub assigned_crew_id: Option<u64>,
-    /// Priority level of this task (higher is more urgent).
-    pub priority: f32,
-    /// Task completion progress (0.0-1.0).
-    pub progress: f32,
-    /// Simulation time when this task was created.
-    pub created_at: f64,
-    /// Required skill type to perform this task (see skill_types module).
-    pub required_skill: u8,
-    /// Estimated duration to complete task in hours.
-    pub duration_hours: f32,
+    /// Unique identifier (always 0 for singleton).
+    pub id: u32,
+    /// Stage of the flight plan (see voyage_phases module).
+    pub phase: u8,
+    /// Light-years covered since launch.
+    pub covered_ly: f64,
+    /// Light-years from launch to the destination.
+    pub total_ly: f64,
+    /// Current velocity as a fraction of c.
+    pub velocity_c: f64,
+    /// Velocity change as a fraction of c the fuel left can still buy.
+    pub delta_v_c: f64,
+    /// Simulation time the ship is expected to arrive.
+    pub eta: f64,
 }
 
-// ============================================================================
-// SOCIAL
-// ============================================================================
+/// Whether a room's power feed is live, from the last grid tick.
+#[table(name = room_power, public)]
+pub struct RoomPower {
+    #[primary_key]
+    /// Foreign key to Room.id.
+    pub room_id: u32,
+    /// Lighting plus subsystem demand in kilowatts.
+    pub load_kw: f32,
+    /// False while the room is shed: lights out, subsystems stopped.
+    pub powered: bool,
+}
 
-/// Social relationship between two people aboard the ship.
-#[table(name = relationship, public)]
-pub struct Relationship {
+/// Ship-wide power balance from the last grid tick.
+#[table(name = power_grid, public)]
+pub struct PowerGrid {
     #[primary_key]
-    #[auto_inc]
-    /// Unique identifier for this relationship.
-    pub id: u64,
-    /// Foreign key to first Person.id.
-    pub person_a: u64,
-    /// Foreign key to second Person.id.
-    pub person_b: u64,
-    /// Type of relationship (see relationship_types module).
-    pub relationship_type: u8,
-    /// Relationship strength (-1.0 = hostile, 1.0 = close).
-    pub strength: f32,
-    /// Familiarity level (0.0 = strangers, 1.0 = well-known).
-    pub familiarity: f32,
-    /// Simulation time of last social interaction.
-    pub last_interaction: f64,
+    /// Unique identifier (always 0 for singleton).
+    pub id: u32,
+    /// Power generated by reactors and emergency generators in kilowatts.
+    pub generation_kw: f32,
+    /// Power wanted by every room and subsystem in kilowatts.
+    pub demand_kw: f32,
+    /// Power delivered in kilowatts; reserves make up any excess over generation.
+    pub supplied_kw: f32,
+    /// Whether loads are being shed.
+    pub brownout: bool,
 }
 
-/// Active conversation between two people with topic and state tracking.
-#[table(name = conversation, public)]
-#[derive(Clone)]
-pub struct Conversation {
+/// The coolant loop carrying the ship's heat to the radiators (singleton,
+/// id=0; see `progship_logic::thermal`).
+#[table(name = coolant_loop, public)]
+pub struct CoolantLoop {
+    #[primary_key]
+    /// Unique identifier (always 0 for singleton).
+    pub id: u32,
+    /// Coolant temperature in Celsius.
+    pub temperature: f32,
+    /// Heat the radiators shed at their rated temperature in kilowatts.
+    pub radiator_kw: f32,
+    /// kWh that warm the loop by a degree.
+    pub heat_capacity: f32,
+    /// Share of machinery heat the coolant pumps carried off on the last
+    /// tick (0.0-1.0).
+    pub coolant_flow: f32,
+    /// Heat given off by the reactors in kilowatts.
+    pub reactor_kw: f32,
+    /// Heat given off by the thrust chambers in kilowatts.
+    pub engine_kw: f32,
+    /// Heat given off by people in kilowatts.
+    pub cabin_kw: f32,
+    /// Heat given off by the galleys in kilowatts.
+    pub galley_kw: f32,
+    /// Heat the loop took in on the last tick in kilowatts.
+    pub absorbed_kw: f32,
+    /// Heat the radiators shed on the last tick in kilowatts.
+    pub radiated_kw: f32,
+}
+
+/// Whether a wet room's water supply is flowing, from the last water tick.
+#[table(name = room_water, public)]
+pub struct RoomWater {
+    #[primary_key]
+    /// Foreign key to Room.id.
+    pub room_id: u32,
+    /// Water the room draws per hour.
+    pub demand: f32,
+    /// False while the room is dry: no washing, only cold meals.
+    pub supplied: bool,
+}
+
+/// A bed of crops growing in a hydroponics room.
+#[table(name = crop, public)]
+pub struct Crop {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this bed.
+    pub id: u64,
+    /// Foreign key to Room.id of the hydroponics room.
+    pub room_id: u32,
+    /// Progress from planting (0.0) to ripe (1.0).
+    pub growth: f32,
+    /// Plant health (0.0-1.0); wilting crops yield less.
+    pub health: f32,
+    /// Raw food in kg a healthy bed yields at harvest.
+    pub expected_yield: f32,
+    /// Simulation time the bed was last planted.
+    pub planted_at: f64,
+}
+
+/// Meals cooked in a galley, waiting to be served to the decks it feeds.
+#[table(name = meal_buffer, public)]
+pub struct MealBuffer {
+    #[primary_key]
+    /// Foreign key to Room.id of the galley.
+    pub galley_room_id: u32,
+    /// Deck the galley is on.
+    pub deck: i32,
+    /// Meals ready to serve.
+    pub meals: f32,
+    /// Most meals the galley keeps ready.
+    pub capacity: f32,
+    /// Cooks at work in the galley on the last tick.
+    pub cooks: u32,
+}
+
+/// The plantings of an arboretum.
+#[table(name = garden, public)]
+pub struct Garden {
+    #[primary_key]
+    /// Foreign key to Room.id of the arboretum.
+    pub room_id: u32,
+    /// Deck the arboretum is on.
+    pub deck: i32,
+    /// Floor area of plantings in m².
+    pub area: f32,
+    /// Plant health (0.0-1.0); the healthier, the more air freshened and
+    /// the more visitors take from it.
+    pub health: f32,
+    /// How well the plantings grew on the last tick (0.0-1.0), from water,
+    /// light and CO2.
+    pub conditions: f32,
+    /// Simulation time a gardener last tended the plantings.
+    pub tended_at: f64,
+}
+
+/// A crate lot of cargo stowed in a hold, drawn down as it is used.
+#[table(name = cargo_item, public)]
+#[derive(Clone)]
+pub struct CargoItem {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this lot.
+    pub id: u64,
+    /// Kind of cargo (see cargo_categories module).
+    pub category: u8,
+    /// Foreign key to the Room.id of the hold it is stowed in.
+    pub room_id: u32,
+    /// Remaining mass in metric tons.
+    pub mass_tons: f32,
+    /// Remaining volume in cubic meters.
+    pub volume_m3: f32,
+}
+
+/// Spare parts of one kind aboard, wherever they are stowed.
+#[table(name = part_stock, public)]
+#[derive(Clone)]
+pub struct PartStock {
+    #[primary_key]
+    /// Kind of part (see part_kinds module).
+    pub kind: u8,
+    /// Parts in stock in metric tons.
+    pub mass_tons: f32,
+    /// Parts of this kind the ship set out with in metric tons.
+    pub launch_tons: f32,
+}
+
+/// Small craft based in a shuttle bay.
+#[table(name = shuttle, public)]
+#[derive(Clone)]
+pub struct Shuttle {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this shuttle.
+    pub id: u64,
+    /// Human-readable name of the shuttle.
+    pub name: String,
+    /// Foreign key to the Room.id of its home shuttle bay.
+    pub bay_room_id: u32,
+    /// Docked or launched (see shuttle_states module).
+    pub state: u8,
+    /// Number of seats.
+    pub capacity: u32,
+    /// Fuel aboard in kilograms.
+    pub fuel: f32,
+    /// Tank size in kilograms.
+    pub fuel_cap: f32,
+    /// Simulation time of the last launch in hours, while launched.
+    pub launched_at: Option<f64>,
+}
+
+/// A job on the crew's work board: a repair, pipe repair, emergency or
+/// fabrication filed by the system that needs it, taken by one crew member
+/// or robot at a time.
+#[table(name = work_order, public)]
+#[derive(Clone)]
+pub struct WorkOrder {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this work order.
+    pub id: u64,
+    /// What kind of work it is (see work_kinds module).
+    pub kind: u8,
+    /// Open, claimed, done or cancelled (see work_order_states module).
+    pub state: u8,
+    /// Why the order was filed, e.g. "Primary Pump at 42%".
+    pub reason: String,
+    /// Department whose work it is (see departments module).
+    pub department: u8,
+    /// Required skill type to do the work (see skill_types module).
+    pub required_skill: u8,
+    /// Spare parts in tons the job uses, or makes for a fabrication.
+    pub required_parts: f32,
+    /// Kind of those parts (see part_kinds module).
+    pub part_kind: u8,
+    /// Priority on the board (higher is more urgent; emergencies above 1.0).
+    pub priority: f32,
+    /// Foreign key to Room.id where the work is done, if it has a place.
+    pub room_id: Option<u32>,
+    /// Foreign key to Subsystem.id being repaired (0 = none).
+    pub subsystem_id: u64,
+    /// Foreign key to SystemComponent.id being serviced (0 = none).
+    pub component_id: u64,
+    /// Foreign key to InfraEdge.id of a pipe being repaired, if any.
+    pub infra_edge_id: Option<u64>,
+    /// Foreign key to Event.id of the emergency being dealt with, if any.
+    pub event_id: Option<u64>,
+    /// Foreign key to HullDamage.id of the hull being patched, if any.
+    pub hull_damage_id: Option<u64>,
+    /// Foreign key to Person.id of the crew member who claimed it, if any.
+    pub assigned_crew_id: Option<u64>,
+    /// Foreign key to Robot.id of the robot that claimed it, if any.
+    pub assigned_robot_id: Option<u64>,
+    /// Work completion progress (0.0-1.0).
+    pub progress: f32,
+    /// Estimated duration to complete the work in hours.
+    pub duration_hours: f32,
+    /// Simulation time when this order was filed.
+    pub created_at: f64,
+    /// Simulation time when the current claim was made, while claimed.
+    pub claimed_at: Option<f64>,
+    /// Simulation time when the order was done or cancelled.
+    pub completed_at: Option<f64>,
+}
+
+/// A suited crew member outside the hull on an EVA work order.
+#[table(name = eva_mission, public)]
+pub struct EvaMission {
+    #[primary_key]
+    /// Foreign key to Person.id of the crew member outside.
+    pub person_id: u64,
+    /// Foreign key to WorkOrder.id of the job they went out for.
+    pub work_order_id: u64,
+    /// Foreign key to HullHatch.id they went out through and come back by.
+    pub hatch_id: u64,
+    /// Stage of the trip (see eva_phases module).
+    pub phase: u8,
+    /// Hours left in the current stage (0 while working).
+    pub phase_hours: f32,
+    /// Hours of oxygen left in the suit.
+    pub oxygen_hours: f32,
+    /// Hours it takes to cross the hull between the hatch and the site.
+    pub traverse_hours: f32,
+    /// Simulation time they suited up.
+    pub started_at: f64,
+}
+
+/// A maintenance robot built in a Robotics Bay, working low-priority orders
+/// off the board between recharges.
+#[table(name = robot, public)]
+#[derive(Clone)]
+pub struct Robot {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this robot.
+    pub id: u64,
+    /// Designation, e.g. "MR-03".
+    pub name: String,
+    /// Foreign key to Room.id of the Robotics Bay it was built in.
+    pub home_room_id: u32,
+    /// Foreign key to Room.id it is in, or last left.
+    pub room_id: u32,
+    /// Idle, working, charging or broken down (see robot_states module).
+    pub state: u8,
+    /// Foreign key to WorkOrder.id it is working, if any.
+    pub work_order_id: Option<u64>,
+    /// Foreign key to Room.id it is headed for, while on its way.
+    pub target_room_id: Option<u32>,
+    /// Simulation time it reaches the room it is headed for.
+    pub arrives_at: f64,
+    /// Battery charge (0.0-1.0).
+    pub charge: f32,
+    /// Condition (0.0-1.0); the more worn, the likelier a breakdown.
+    pub health: f32,
+}
+
+// ============================================================================
+// SOCIAL
+// ============================================================================
+
+/// Social relationship between two people aboard the ship.
+#[table(name = relationship, public)]
+pub struct Relationship {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this relationship.
+    pub id: u64,
+    /// Foreign key to first Person.id.
+    pub person_a: u64,
+    /// Foreign key to second Person.id.
+    pub person_b: u64,
+    /// Type of relationship (see relationship_types module).
+    pub relationship_type: u8,
+    /// Relationship strength (-1.0 = hostile, 1.0 = close).
+    pub strength: f32,
+    /// Familiarity level (0.0 = strangers, 1.0 = well-known).
+    pub familiarity: f32,
+    /// Simulation time of last social interaction.
+    pub last_interaction: f64,
+}
+
+/// Active conversation between two people with topic and state tracking.
+#[table(name = conversation, public)]
+#[derive(Clone)]
+pub struct Conversation {
     #[primary_key]
     #[auto_inc]
     /// Unique identifier for this conversation.
@@ -590,6 +1537,23 @@ pub struct Conversation {
     pub participant_b: u64,
 }
 
+/// Ambient social state of a room, refreshed periodically for rendering
+/// and venue choice.
+#[table(name = room_ambience, public)]
+pub struct RoomAmbience {
+    #[primary_key]
+    /// Foreign key to Room.id.
+    pub room_id: u32,
+    /// Number of people in the room.
+    pub occupants: u32,
+    /// Number of conversations under way in the room.
+    pub conversations: u32,
+    /// Average morale of the occupants (0.0-1.0).
+    pub average_mood: f32,
+    /// Overall liveliness (0.0 = dead, 1.0 = buzzing).
+    pub liveliness: f32,
+}
+
 /// Marker table indicating a person is currently engaged in a conversation.
 #[table(name = in_conversation, public)]
 pub struct InConversation {
@@ -600,6 +1564,132 @@ pub struct InConversation {
     pub conversation_id: u64,
 }
 
+/// A passenger household traveling together in a shared cabin.
+#[table(name = family, public)]
+pub struct Family {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this family.
+    pub id: u64,
+    /// Surname shared by the household.
+    pub family_name: String,
+    /// Foreign key to the Room.id of the shared cabin.
+    pub home_room_id: u32,
+}
+
+/// Membership of a person in a family.
+#[table(name = family_member, public)]
+pub struct FamilyMember {
+    #[primary_key]
+    /// Foreign key to Person.id.
+    pub person_id: u64,
+    /// Foreign key to Family.id.
+    pub family_id: u64,
+    /// Role within the household (see family_roles module).
+    pub role: u8,
+}
+
+/// A baby on the way to the couple at the head of a family.
+#[table(name = pregnancy, public)]
+pub struct Pregnancy {
+    #[primary_key]
+    /// Foreign key to Person.id of the parent carrying the baby.
+    pub person_id: u64,
+    /// Foreign key to Person.id of the other parent.
+    pub partner_id: u64,
+    /// Foreign key to Family.id the baby is born into.
+    pub family_id: u64,
+    /// Sim time of conception (hours).
+    pub conceived_at: f64,
+    /// Sim time the baby is due (hours).
+    pub due_at: f64,
+}
+
+/// A funeral to be held in the chapel for someone who died.
+#[table(name = funeral, public)]
+pub struct Funeral {
+    #[primary_key]
+    /// Foreign key to Person.id of the deceased.
+    pub person_id: u64,
+    /// Foreign key to Room.id of the chapel it is held in.
+    pub chapel_id: u32,
+    /// Sim time the funeral begins (hours).
+    pub scheduled_at: f64,
+    /// Foreign key to the FUNERAL Event.id once it has begun.
+    pub event_id: Option<u64>,
+}
+
+/// One person's grief for someone who died, holding their morale down
+/// until it lifts.
+#[table(name = grief, public)]
+pub struct Grief {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this grief.
+    pub id: u64,
+    /// Foreign key to Person.id of the mourner.
+    pub person_id: u64,
+    /// Foreign key to Person.id of the deceased.
+    pub deceased_id: u64,
+    /// Morale the grief took away at first (0.0–0.5).
+    pub depth: f32,
+    /// Sim time of the death (hours).
+    pub since: f64,
+    /// Whether the mourner said goodbye at the funeral.
+    pub attended_funeral: bool,
+}
+
+/// The faction an adult belongs to and how loyal they are to it.
+#[table(name = faction_membership, public)]
+pub struct FactionMembership {
+    #[primary_key]
+    /// Foreign key to Person.id.
+    pub person_id: u64,
+    /// Faction they side with (see factions module).
+    pub faction: u8,
+    /// Loyalty to it from 0.0 (wavering) to 1.0 (devoted).
+    pub loyalty: f32,
+    /// Simulation time they joined.
+    pub joined_at: f64,
+}
+
+/// A faction's standing aboard, refreshed hourly.
+#[table(name = faction, public)]
+pub struct Faction {
+    #[primary_key]
+    /// Faction ID (see factions module).
+    pub id: u8,
+    /// Living members.
+    pub members: u32,
+    /// Members' average loyalty.
+    pub loyalty: f32,
+    /// Members' average morale.
+    pub morale: f32,
+}
+
+/// Morale across a department's people or the whole ship, refreshed every
+/// tick.
+#[table(name = morale_summary, public)]
+pub struct MoraleSummary {
+    #[primary_key]
+    /// Department (see departments module; passengers count as civilian),
+    /// or `progship_logic::morale::SHIP_WIDE` for everyone aboard.
+    pub scope: u8,
+    /// Living people counted.
+    pub people: u32,
+    /// Their average morale.
+    pub morale: f32,
+    /// Share of them whose morale is critically low.
+    pub despairing: f32,
+    /// Current mood (see morale_moods module).
+    pub mood: u8,
+    /// Simulation time the mood set in.
+    pub mood_since: f64,
+    /// Foreign key to the celebration, rally or strike Event the mood last
+    /// set off.
+    pub event_id: Option<u64>,
+}
+
 // ============================================================================
 // EVENTS
 // ============================================================================
@@ -628,6 +1718,209 @@ pub struct Event {
     pub responders_assigned: u8,
     /// Severity level of the event (0.0 = minor, 1.0 = critical).
     pub severity: f32,
+    /// Officer an unhandled event was escalated to, if any.
+    pub escalated_to: Option<u64>,
+}
+
+/// A solar flare or micrometeorite on its way to the ship, spotted by the
+/// sensors once it is close enough for them to see. Removed when it
+/// arrives.
+#[table(name = sensor_contact, public)]
+#[derive(Clone)]
+pub struct SensorContact {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this contact.
+    pub id: u64,
+    /// What is coming (see contact_kinds module).
+    pub kind: u8,
+    /// Severity it strikes with (0.0 = minor, 1.0 = critical).
+    pub severity: f32,
+    /// Room against the hull a micrometeorite will strike.
+    pub room_id: Option<u32>,
+    /// Simulation time it arrives.
+    pub arrives_at: f64,
+    /// Simulation time the sensors spotted it, if they have yet.
+    pub spotted_at: Option<f64>,
+}
+
+/// Smoke, a coolant spill or frost lingering in a room. At most one per
+/// room and kind; removed once it clears.
+#[table(name = room_effect, public)]
+#[derive(Clone)]
+pub struct RoomEffect {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this effect.
+    pub id: u64,
+    /// Foreign key to Room.id.
+    pub room_id: u32,
+    /// Kind of effect (see effect_kinds module).
+    pub kind: u8,
+    /// Strength from 0.0 (cleared) to 1.0.
+    pub intensity: f32,
+    /// Crew member sent to clean it up, if any.
+    pub cleaner_id: Option<u64>,
+}
+
+/// Precomputed evacuation step out of a room toward the nearest safe muster
+/// station. Rebuilt when doors open or close and when hazards start or end.
+#[table(name = evacuation_route, public)]
+pub struct EvacuationRoute {
+    #[primary_key]
+    /// Room this step leaves.
+    pub room_id: u32,
+    /// Room to walk into next.
+    pub next_room: u32,
+    /// Door X coordinate into next_room.
+    pub door_x: f32,
+    /// Door Y coordinate into next_room.
+    pub door_y: f32,
+    /// Muster station the route ends in.
+    pub muster_room: u32,
+    /// Estimated seconds to reach the muster station.
+    pub seconds: f32,
+}
+
+/// A problem a system ran into and recovered from instead of panicking the
+/// tick, or a broken invariant the watchdog found. Repeats of the same
+/// message count on one row; only the most recent are kept.
+#[table(name = simulation_warning, public)]
+pub struct SimulationWarning {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this warning.
+    pub id: u64,
+    /// System that raised it, e.g. "training" or "watchdog".
+    pub system: String,
+    /// What went wrong.
+    pub message: String,
+    /// Sim time it was first raised (hours).
+    pub first_at: f64,
+    /// Sim time it was last raised (hours).
+    pub last_at: f64,
+    /// Times it has been raised.
+    pub count: u32,
+}
+
+// ============================================================================
+// SECURITY
+// ============================================================================
+
+/// An offense in the incident log, open until its suspect is detained or
+/// the case goes cold. Only the most recent closed incidents are kept.
+#[table(name = incident, public)]
+#[derive(Clone)]
+pub struct Incident {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this incident.
+    pub id: u64,
+    /// What happened (see incident_kinds module).
+    pub kind: u8,
+    /// Room it happened in.
+    pub room_id: u32,
+    /// Person ID of the suspect.
+    pub offender_id: u64,
+    /// Person ID of the victim, if anyone was.
+    pub victim_id: Option<u64>,
+    /// Simulation time it happened.
+    pub occurred_at: f64,
+    /// Security crew member responding, if any.
+    pub responder_id: Option<u64>,
+    /// Simulation time the case was closed, `None` while open.
+    pub closed_at: Option<f64>,
+    /// Whether the suspect was detained.
+    pub detained: bool,
+}
+
+/// A suspect held in the brig until `until`.
+#[table(name = detention, public)]
+pub struct Detention {
+    #[primary_key]
+    /// Foreign key to Person.id.
+    pub person_id: u64,
+    /// Foreign key to Incident.id.
+    pub incident_id: u64,
+    /// Room they are held in.
+    pub room_id: u32,
+    /// Simulation time they are released.
+    pub until: f64,
+}
+
+/// A crew department refusing duty and holding its station until its
+/// demand is met or security puts it down. Kept after it ends.
+#[table(name = mutiny, public)]
+pub struct Mutiny {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this mutiny.
+    pub id: u64,
+    /// Foreign key to the MUTINY Event.id.
+    pub event_id: u64,
+    /// Department that mutinied (see departments module).
+    pub department: u8,
+    /// Room the mutineers occupy.
+    pub room_id: u32,
+    /// What they demand (see mutiny_demands module).
+    pub demand: u8,
+    /// Simulation time it began.
+    pub started_at: f64,
+    /// Simulation time security was sent in, if it was.
+    pub suppressing_since: Option<f64>,
+    /// Simulation time it ended, if it has.
+    pub ended_at: Option<f64>,
+    /// Whether it ended with the demand met rather than put down.
+    pub conceded: bool,
+}
+
+// ============================================================================
+// GOVERNANCE
+// ============================================================================
+
+/// A civilian council election.
+#[table(name = election, public)]
+pub struct Election {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this election.
+    pub id: u64,
+    /// Simulation time it was held.
+    pub held_at: f64,
+    /// Number of passengers who voted.
+    pub turnout: u32,
+}
+
+/// A passenger standing in an election, and how they fared.
+#[table(name = candidate, public)]
+pub struct Candidate {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this candidacy.
+    pub id: u64,
+    /// Foreign key to Election.id.
+    pub election_id: u64,
+    /// Foreign key to Person.id.
+    pub person_id: u64,
+    /// Votes they won.
+    pub votes: u32,
+    /// Whether they won a council seat.
+    pub elected: bool,
+}
+
+/// How the ship is run, as set by the civilian council (single row, id=0).
+#[table(name = policy, public)]
+pub struct Policy {
+    #[primary_key]
+    pub id: u32,
+    /// Rationing the council orders at least (see economy rationing levels).
+    pub rationing: u8,
+    /// Length of a crew watch (hours).
+    pub work_hours: u8,
+    /// Whether passengers keep to quarters overnight.
+    pub curfew: bool,
+    /// Simulation time the council last met.
+    pub decided_at: f64,
 }
 
 // ============================================================================
@@ -646,10 +1939,317 @@ pub struct ConnectedPlayer {
     pub connected_at: Timestamp,
 }
 
+/// A player's progress through the objectives of a scripted scenario (see
+/// progship_logic::scenarios::scenario_script).
+#[table(name = player_objective, public)]
+pub struct PlayerObjective {
+    #[primary_key]
+    /// Foreign key to the player's Person.id.
+    pub person_id: u64,
+    /// Index of the objective in progress; the objective count once all are
+    /// done.
+    pub current: u32,
+    /// Simulation time the last objective was completed, in hours.
+    pub completed_at: f64,
+}
+
+// ============================================================================
+// HISTORY
+// ============================================================================
+
+/// One simulation day of compacted events, conversations and maintenance
+/// tasks, kept after the rows themselves are removed.
+#[table(name = daily_history, public)]
+pub struct DailyHistory {
+    #[primary_key]
+    /// Simulation day (0-based).
+    pub day: u32,
+    /// Resolved events by type (index = event_types ID).
+    pub events: Vec<u32>,
+    /// Events that escalated to an officer.
+    pub escalated_events: u32,
+    /// Ended conversations by topic (index = conversation_topics ID).
+    pub conversations: Vec<u32>,
+    /// Repair work orders done.
+    pub repairs: u32,
+    /// Planned hours of the repair work orders done.
+    pub repair_hours: f32,
+}
+
+/// How long finished rows are kept before compaction (singleton, id=0).
+/// Defaults apply until set_history_retention is called.
+#[table(name = history_config, public)]
+pub struct HistoryConfig {
+    #[primary_key]
+    /// Unique identifier (always 0 for singleton).
+    pub id: u32,
+    /// Simulation days finished rows are kept.
+    pub retention_days: f64,
+}
+
+// ============================================================================
+// MILESTONES
+// ============================================================================
+
+/// A voyage milestone, recorded the first time it is reached.
+#[table(name = milestone, public)]
+pub struct Milestone {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this milestone.
+    pub id: u64,
+    /// What was reached (see milestone_kinds module).
+    pub kind: u8,
+    /// Simulation time when it was reached, in hours.
+    pub achieved_at: f64,
+    /// People involved (Person IDs), e.g. a newborn and their parents.
+    pub people: Vec<u64>,
+    /// Short description for the overview and the voyage report.
+    pub detail: String,
+}
+
+/// What the milestone checks remember between checks (singleton, id=0).
+#[table(name = milestone_tracker, public)]
+pub struct MilestoneTracker {
+    #[primary_key]
+    /// Unique identifier (always 0 for singleton).
+    pub id: u32,
+    /// Deaths counted at the last check.
+    pub death_count: u32,
+    /// Simulation time of the latest death (0 until someone dies).
+    pub last_fatality_at: f64,
+    /// When the power system went off line, while it is.
+    pub scram_started_at: Option<f64>,
+}
+
+// ============================================================================
+// SHIP'S COMPUTER
+// ============================================================================
+
+/// A problem aboard the ship's computer has flagged, kept up to date while
+/// it lasts and cleared once it is over.
+#[table(name = advisory, public)]
+pub struct Advisory {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this advisory.
+    pub id: u64,
+    /// What it is about (see advisory_kinds module).
+    pub kind: u8,
+    /// What it is about within its kind: a stock (see stocks module),
+    /// ShipSystem.id or Room.id; 0 for the power grid.
+    pub subject: u64,
+    /// How serious it is (see advisory_levels module).
+    pub level: u8,
+    /// Order on the list (higher first).
+    pub priority: f32,
+    /// What the computer says, e.g. "Food will run out in 42 days at
+    /// current consumption".
+    pub message: String,
+    /// Simulation time it was first raised.
+    pub raised_at: f64,
+    /// Simulation time it was last brought up to date.
+    pub updated_at: f64,
+}
+
+/// A store's level when the ship's computer last looked and how fast it is
+/// changing.
+#[table(name = stock_trend, public)]
+pub struct StockTrend {
+    #[primary_key]
+    /// Which store (see stocks module).
+    pub stock: u8,
+    /// Level at the last look.
+    pub level: f64,
+    /// Smoothed change an hour, negative while running down.
+    pub rate_per_hour: f64,
+    /// Simulation time of the last look.
+    pub sampled_at: f64,
+}
+
+// ============================================================================
+// VOYAGE REPORT
+// ============================================================================
+
+/// A death: who, when, where and why.
+#[table(name = casualty, public)]
+pub struct Casualty {
+    #[primary_key]
+    /// Person who died.
+    pub person_id: u64,
+    /// Simulation time of death, in hours.
+    pub died_at: f64,
+    /// Room they died in.
+    pub room_id: u32,
+    /// Cause of death (see death_causes module).
+    pub cause: u8,
+}
+
+/// People alive at the start of a simulation day.
+#[table(name = population_sample, public)]
+pub struct PopulationSample {
+    #[primary_key]
+    /// Simulation day (0-based).
+    pub day: u32,
+    /// Living people aboard.
+    pub alive: u32,
+}
+
+/// A voyage report, filed at arrival, at the loss of the ship or on request
+/// (see progship_logic::final_report::FinalReport).
+#[table(name = voyage_report, public)]
+pub struct VoyageReport {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this report.
+    pub id: u64,
+    /// Simulation time of the report, in hours.
+    pub sim_time: f64,
+    /// How the voyage stood (see voyage_outcomes module).
+    pub outcome: u8,
+    /// Why the ship was lost (see loss_causes module), NONE unless LOST.
+    pub loss_cause: u8,
+    /// The report as JSON.
+    pub json: String,
+    /// The report as Markdown.
+    pub markdown: String,
+}
+
+// ============================================================================
+// STATE EXPORTS
+// ============================================================================
+
+/// JSON state snapshot written by the export_state reducer for offline inspection.
+#[table(name = state_export, public)]
+pub struct StateExport {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this export.
+    pub id: u64,
+    /// Simulation time when the export was taken.
+    pub sim_time: f64,
+    /// Snapshot encoded as JSON (see progship_logic::snapshot::StateSnapshot).
+    pub data: String,
+    /// Whether the snapshot was taken by the autosnapshot schedule rather
+    /// than on request (only automatic ones are pruned).
+    pub automatic: bool,
+}
+
+/// Compact binary world frame written by the export_frame reducer for
+/// renderers outside SpacetimeDB.
+#[table(name = frame_export, public)]
+pub struct FrameExport {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this export.
+    pub id: u64,
+    /// Simulation time of the frame.
+    pub sim_time: f64,
+    /// Encoded key frame or delta (see progship_logic::frame).
+    pub data: Vec<u8>,
+    /// Export this delta applies to, or None for a key frame.
+    pub base_id: Option<u64>,
+}
+
+/// Schedule and destinations of automatic snapshots (singleton, id=0).
+/// Defaults apply until configure_autosnapshot is called.
+#[table(name = snapshot_config, public)]
+pub struct SnapshotConfig {
+    #[primary_key]
+    /// Unique identifier (always 0 for singleton).
+    pub id: u32,
+    /// Simulation hours between snapshots (0 disables them).
+    pub interval_hours: f64,
+    /// Automatic snapshots kept in the state_export table.
+    pub keep: u32,
+    /// Where snapshots are written (see snapshot_sinks module flags).
+    pub sinks: u8,
+}
+
+// ============================================================================
+// STREAM
+// ============================================================================
+
+/// Who may drive the ship from a live stream's chat, and how often
+/// (singleton, id=0). Seeded when the module is published; without it no
+/// stream action is accepted. The ship's owner (see ShipOwner) may always
+/// send stream actions and alone may configure the stream.
+#[table(name = stream_config, public)]
+pub struct StreamConfig {
+    #[primary_key]
+    /// Unique identifier (always 0 for singleton).
+    pub id: u32,
+    /// Identities (chat bots) allowed to send stream actions besides the
+    /// ship's owner.
+    pub operators: Vec<Identity>,
+    /// Bitmask of the votes chat may pass (one bit per stream_votes ID).
+    pub allowed_votes: u32,
+    /// Real seconds between actions of each kind, indexed by
+    /// stream_actions.
+    pub cooldown_seconds: Vec<u32>,
+}
+
+/// A stream action the ship carried out, newest kept for the cooldowns and
+/// for overlays to announce.
+#[table(name = stream_action, public)]
+pub struct StreamAction {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this action.
+    pub id: u64,
+    /// Kind of action (see stream_actions module).
+    pub action: u8,
+    /// What chat asked for, e.g. "Surprise Inspection" or a subscriber's
+    /// name.
+    pub detail: String,
+    /// Operator who sent the action.
+    pub sent_by: Identity,
+    /// When the action was taken, for the cooldowns.
+    pub taken_at: Timestamp,
+    /// Simulation time the action was taken in hours.
+    pub sim_time: f64,
+}
+
+/// A subscriber's name waiting to be given to the next baby born aboard.
+#[table(name = newborn_name, public)]
+pub struct NewbornName {
+    #[primary_key]
+    #[auto_inc]
+    /// Unique identifier for this name.
+    pub id: u64,
+    /// Given name for the baby.
+    pub name: String,
+    /// Simulation time the name was sent in hours.
+    pub queued_at: f64,
+    /// Foreign key to the Person.id of the baby named, once given.
+    pub person_id: Option<u64>,
+}
+
+/// NPC the stream has in the spotlight (singleton, id=0).
+#[table(name = spotlight, public)]
+pub struct Spotlight {
+    #[primary_key]
+    /// Unique identifier (always 0 for singleton).
+    pub id: u32,
+    /// Foreign key to Person.id.
+    pub person_id: u64,
+    /// Caption shown with them, e.g. who picked them.
+    pub caption: String,
+    /// Simulation time the spotlight ends in hours.
+    pub until: f64,
+}
+
 // ============================================================================
 // ENUM CONSTANTS
 // ============================================================================
 
+pub mod snapshot_sinks {
+    /// The state_export table, for `spacetime sql` and subscribed clients.
+    pub const TABLE: u8 = 1;
+    /// The module log, which outlives a data wipe (`spacetime logs`).
+    pub const LOG: u8 = 2;
+}
+
 pub mod node_types {
     pub const ROOM: u8 = 0;
     pub const CORRIDOR: u8 = 1;
@@ -676,10 +2276,55 @@ pub mod wall_sides {
     pub const WEST: u8 = 3;
 }
 
+pub mod door_states {
+    pub const OPEN: u8 = 0;
+    pub const CLOSED: u8 = 1;
+    pub const LOCKED: u8 = 2;
+    pub const WELDED: u8 = 3;
+}
+
+pub mod hatch_types {
+    pub const AIRLOCK: u8 = 0;
+    pub const EVA: u8 = 1;
+    pub const BAY_DOOR: u8 = 2;
+}
+
+pub mod furniture_types {
+    pub const BED: u8 = 0;
+    pub const BUNK: u8 = 1;
+    pub const MEDICAL_BED: u8 = 2;
+    pub const TABLE: u8 = 3;
+    pub const CONSOLE: u8 = 4;
+    pub const WORKBENCH: u8 = 5;
+    pub const HYDROPONIC_RACK: u8 = 6;
+    pub const STORAGE_RACK: u8 = 7;
+    pub const COUNTER: u8 = 8;
+    pub const CRYO_POD: u8 = 9;
+
+    /// Returns true if this furniture type is something a person sleeps in
+    pub fn is_bed(ft: u8) -> bool {
+        matches!(ft, BED | BUNK | MEDICAL_BED)
+    }
+}
+
+pub mod anchor_types {
+    pub const SERVING_LINE: u8 = 0;
+    pub const DINING_SEAT: u8 = 1;
+    pub const TREADMILL: u8 = 2;
+    pub const CONSOLE: u8 = 3;
+    pub const OPERATING_TABLE: u8 = 4;
+    pub const WORKBENCH: u8 = 5;
+    pub const PLANTER: u8 = 6;
+    pub const COUNTER: u8 = 7;
+    pub const BED: u8 = 8;
+    pub const SEAT: u8 = 9;
+}
+
 pub mod corridor_types {
     pub const MAIN: u8 = 0;
     pub const SERVICE: u8 = 1;
     pub const BRANCH: u8 = 2;
+    pub const CRAWLWAY: u8 = 3;
 }
 
 pub mod shaft_types {
@@ -691,7 +2336,9 @@ pub mod shaft_types {
 pub mod access_levels {
     pub const PUBLIC: u8 = 0;
     pub const CREW_ONLY: u8 = 1;
-    pub const RESTRICTED: u8 = 2;
+    pub const DEPARTMENT: u8 = 2;
+    pub const OFFICER: u8 = 3;
+    pub const CAPTAIN: u8 = 4;
 }
 
 pub mod groups {
@@ -765,6 +2412,7 @@ pub mod room_types {
     pub const QUARANTINE: u8 = 35;
     pub const MORGUE: u8 = 36;
     pub const MEDBAY: u8 = 37;
+    pub const CRYO_BAY: u8 = 38;
     // Recreation & Morale
     pub const GYM: u8 = 40;
     pub const THEATRE: u8 = 41;
@@ -818,6 +2466,7 @@ pub mod room_types {
     pub const CORRIDOR: u8 = 100;
     pub const SERVICE_CORRIDOR: u8 = 101;
     pub const CROSS_CORRIDOR: u8 = 102;
+    pub const CRAWLWAY: u8 = 103;
     pub const ELEVATOR_SHAFT: u8 = 110;
     pub const LADDER_SHAFT: u8 = 111;
     pub const SERVICE_ELEVATOR_SHAFT: u8 = 112;
@@ -896,12 +2545,33 @@ pub mod ranks {
     pub const CAPTAIN: u8 = 7;
 }
 
+pub mod command_posts {
+    pub const CAPTAIN: u8 = 0;
+    pub const EXECUTIVE_OFFICER: u8 = 1;
+    pub const DEPARTMENT_HEAD: u8 = 2;
+    pub const WATCH_OFFICER: u8 = 3;
+}
+
+pub mod certifications {
+    pub const NONE: u8 = 0;
+    pub const BASIC: u8 = 1;
+    pub const ADVANCED: u8 = 2;
+    pub const MASTER: u8 = 3;
+}
+
 pub mod shifts {
     pub const ALPHA: u8 = 0; // 0600-1400
     pub const BETA: u8 = 1; // 1400-2200
     pub const GAMMA: u8 = 2; // 2200-0600
 }
 
+pub mod postures {
+    pub const STANDING: u8 = 0;
+    pub const SITTING: u8 = 1;
+    pub const LYING: u8 = 2;
+    pub const WORKING: u8 = 3;
+}
+
 pub mod cabin_classes {
     pub const FIRST: u8 = 0;
     pub const STANDARD: u8 = 1;
@@ -922,6 +2592,8 @@ pub mod activity_types {
     pub const OFF_DUTY: u8 = 10;
     pub const EMERGENCY: u8 = 11;
     pub const EXERCISING: u8 = 12;
+    pub const TRAINING: u8 = 13;
+    pub const SURGERY: u8 = 14;
 }
 
 pub mod system_types {
@@ -992,6 +2664,7 @@ pub mod subsystem_types {
     pub const STAR_TRACKER: u8 = 50;
     pub const GYROSCOPE: u8 = 51;
     pub const ATTITUDE_THRUSTER: u8 = 52;
+    pub const SENSOR_ARRAY: u8 = 53;
     // Communications subsystems
     pub const ANTENNA_ARRAY: u8 = 60;
     pub const SIGNAL_PROCESSOR: u8 = 61;
@@ -1048,6 +2721,27 @@ pub mod relationship_types {
     pub const ENEMY: u8 = 8;
 }
 
+pub mod family_roles {
+    pub const HEAD: u8 = 0;
+    pub const PARTNER: u8 = 1;
+    pub const CHILD: u8 = 2;
+    pub const GRANDPARENT: u8 = 3;
+}
+
+pub mod life_stages {
+    pub const INFANT: u8 = 0;
+    pub const CHILD: u8 = 1;
+    pub const ADULT: u8 = 2;
+    pub const ELDER: u8 = 3;
+}
+
+pub mod emotions {
+    pub const CONTENT: u8 = 0;
+    pub const STRESSED: u8 = 1;
+    pub const GRIEVING: u8 = 2;
+    pub const EXCITED: u8 = 3;
+}
+
 pub mod conversation_topics {
     pub const GREETING: u8 = 0;
     pub const WORK: u8 = 1;
@@ -1066,6 +2760,16 @@ pub mod conversation_states {
     pub const ENDED: u8 = 2;
 }
 
+pub mod cargo_categories {
+    pub const COLONY_SUPPLIES: u8 = 0;
+    pub const MACHINERY: u8 = 1;
+    pub const SEED_VAULT: u8 = 2;
+    pub const PERSONAL_EFFECTS: u8 = 3;
+    pub const SPARE_PARTS: u8 = 4;
+    pub const MEDICAL_SUPPLIES: u8 = 5;
+    pub const RAW_MATERIALS: u8 = 6;
+}
+
 pub mod event_types {
     pub const SYSTEM_FAILURE: u8 = 0;
     pub const MEDICAL_EMERGENCY: u8 = 1;
@@ -1076,6 +2780,223 @@ pub mod event_types {
     pub const ALTERCATION: u8 = 6;
     pub const RESOURCE_SHORTAGE: u8 = 7;
     pub const DEATH: u8 = 8;
+    pub const DIAGNOSTIC: u8 = 9;
+    pub const SOLAR_FLARE: u8 = 10;
+    pub const FUNERAL: u8 = 11;
+    pub const RALLY: u8 = 12;
+    pub const STRIKE: u8 = 13;
+    pub const MUTINY: u8 = 14;
+    pub const PROMOTION: u8 = 15;
+    pub const VOYAGE_MILESTONE: u8 = 16;
+    pub const REVIVAL: u8 = 17;
+}
+
+pub mod effect_kinds {
+    pub const SMOKE: u8 = 0;
+    pub const COOLANT_SPILL: u8 = 1;
+    pub const FROST: u8 = 2;
+}
+
+pub mod milestone_kinds {
+    pub const FIRST_BIRTH: u8 = 0;
+    pub const FATALITY_FREE_100_DAYS: u8 = 1;
+    pub const REACTOR_SCRAM_SURVIVED: u8 = 2;
+    pub const ARRIVAL: u8 = 3;
+    pub const HELIOPAUSE: u8 = 4;
+    pub const ENGINE_CUTOFF: u8 = 5;
+    pub const MIDCOURSE_CORRECTION: u8 = 6;
+    pub const TURNOVER: u8 = 7;
+    pub const DESTINATION_FLYBY: u8 = 8;
+    pub const ARRIVAL_PREP: u8 = 9;
+}
+
+pub mod death_causes {
+    pub const UNKNOWN: u8 = 0;
+    pub const STARVATION: u8 = 1;
+    pub const EXHAUSTION: u8 = 2;
+    pub const ASPHYXIATION: u8 = 3;
+    pub const DECOMPRESSION: u8 = 4;
+    pub const FIRE: u8 = 5;
+    pub const ILLNESS: u8 = 6;
+    pub const OLD_AGE: u8 = 7;
+    pub const SURGERY: u8 = 8;
+}
+
+pub mod voyage_outcomes {
+    pub const IN_PROGRESS: u8 = 0;
+    pub const ARRIVED: u8 = 1;
+    pub const LOST: u8 = 2;
+}
+
+pub mod difficulties {
+    pub const TUTORIAL: u8 = 0;
+    pub const EASY: u8 = 1;
+    pub const NORMAL: u8 = 2;
+    pub const HARD: u8 = 3;
+}
+
+pub mod loss_causes {
+    pub const NONE: u8 = 0;
+    pub const ALL_DEAD: u8 = 1;
+    pub const LIFE_SUPPORT_COLLAPSE: u8 = 2;
+    pub const BELOW_VIABILITY: u8 = 3;
+    pub const FUEL_EXHAUSTED: u8 = 4;
+}
+
+pub mod order_kinds {
+    pub const DAMAGE_CONTROL: u8 = 0;
+    pub const SECURITY_SWEEP: u8 = 1;
+    pub const TO_QUARTERS: u8 = 2;
+}
+
+pub mod work_kinds {
+    pub const REPAIR: u8 = 0;
+    pub const PIPE_REPAIR: u8 = 1;
+    pub const EMERGENCY: u8 = 2;
+    pub const FABRICATION: u8 = 3;
+    pub const EVA_REPAIR: u8 = 4;
+    pub const CRYO_CHECK: u8 = 5;
+    pub const ROBOT_REPAIR: u8 = 6;
+    pub const GARDENING: u8 = 7;
+    pub const DUCT_CLEANING: u8 = 8;
+}
+
+pub mod part_kinds {
+    pub const MECHANICAL: u8 = 0;
+    pub const ELECTRICAL: u8 = 1;
+    pub const ELECTRONIC: u8 = 2;
+    pub const SEALS: u8 = 3;
+}
+
+pub mod work_order_states {
+    pub const OPEN: u8 = 0;
+    pub const CLAIMED: u8 = 1;
+    pub const DONE: u8 = 2;
+    pub const CANCELLED: u8 = 3;
+}
+
+pub mod triage_levels {
+    pub const MINOR: u8 = 0;
+    pub const SERIOUS: u8 = 1;
+    pub const CRITICAL: u8 = 2;
+}
+
+pub mod patient_states {
+    pub const WAITING: u8 = 0;
+    pub const ADMITTED: u8 = 1;
+}
+
+pub mod surgery_outcomes {
+    pub const SUCCESS: u8 = 0;
+    pub const COMPLICATION: u8 = 1;
+    pub const DEATH: u8 = 2;
+}
+
+pub mod lighting_modes {
+    pub const DAY: u8 = 0;
+    pub const EVENING: u8 = 1;
+    pub const NIGHT: u8 = 2;
+}
+
+pub mod eva_phases {
+    pub const CYCLING_OUT: u8 = 0;
+    pub const OUTBOUND: u8 = 1;
+    pub const WORKING: u8 = 2;
+    pub const RETURNING: u8 = 3;
+}
+
+pub mod robot_states {
+    pub const IDLE: u8 = 0;
+    pub const WORKING: u8 = 1;
+    pub const CHARGING: u8 = 2;
+    pub const BROKEN: u8 = 3;
+}
+
+pub mod voyage_phases {
+    pub const ACCELERATING: u8 = 0;
+    pub const CRUISING: u8 = 1;
+    pub const DECELERATING: u8 = 2;
+    pub const ARRIVED: u8 = 3;
+}
+
+pub mod contact_kinds {
+    pub const SOLAR_FLARE: u8 = 0;
+    pub const MICROMETEORITE: u8 = 1;
+}
+
+pub mod stocks {
+    pub const FOOD: u8 = 0;
+    pub const WATER: u8 = 1;
+    pub const OXYGEN: u8 = 2;
+    pub const POWER: u8 = 3;
+    pub const FUEL: u8 = 4;
+}
+
+pub mod advisory_kinds {
+    pub const STOCK: u8 = 0;
+    pub const SYSTEM: u8 = 1;
+    pub const POWER: u8 = 2;
+    pub const CROWDING: u8 = 3;
+}
+
+pub mod advisory_levels {
+    pub const CAUTION: u8 = 0;
+    pub const WARNING: u8 = 1;
+    pub const CRITICAL: u8 = 2;
+}
+
+pub mod item_kinds {
+    pub const TOOLKIT: u8 = 0;
+    pub const KEEPSAKE: u8 = 1;
+    pub const RATION: u8 = 2;
+    pub const CONTRABAND: u8 = 3;
+}
+
+pub mod stream_votes {
+    pub const SURPRISE_INSPECTION: u8 = 0;
+    pub const CELEBRATION: u8 = 1;
+    pub const DISCOVERY: u8 = 2;
+    pub const QUARTERS_DRILL: u8 = 3;
+    pub const ALTERCATION: u8 = 4;
+}
+
+pub mod stream_actions {
+    pub const VOTE: u8 = 0;
+    pub const NAME_NEWBORN: u8 = 1;
+    pub const SPOTLIGHT: u8 = 2;
+}
+
+pub mod morale_moods {
+    pub const STEADY: u8 = 0;
+    pub const LOW: u8 = 1;
+    pub const CRITICAL: u8 = 2;
+    pub const HIGH: u8 = 3;
+}
+
+pub mod factions {
+    pub const CREW_UNION: u8 = 0;
+    pub const COLONIST_COUNCIL: u8 = 1;
+    pub const RELIGIOUS: u8 = 2;
+    pub const CORPORATE: u8 = 3;
+}
+
+pub mod incident_kinds {
+    pub const THEFT: u8 = 0;
+    pub const VANDALISM: u8 = 1;
+    pub const ASSAULT: u8 = 2;
+    pub const MUTINY: u8 = 3;
+    pub const DRUNKENNESS: u8 = 4;
+}
+
+pub mod mutiny_demands {
+    pub const SHORTER_WATCHES: u8 = 0;
+    pub const END_RATIONING: u8 = 1;
+    pub const LIFT_CURFEW: u8 = 2;
+}
+
+pub mod shuttle_states {
+    pub const DOCKED: u8 = 0;
+    pub const LAUNCHED: u8 = 1;
 }
 
 pub mod event_states {
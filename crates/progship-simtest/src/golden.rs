@@ -0,0 +1,167 @@
+//! Generation golden values — structural hashes of the pure generation steps.
+//!
+//! For each fixed case, runs system selection, facility graph construction,
+//! deck layout (corridors plus treemap packing), zone finding and a
+//! standalone treemap, then hashes each result. The hashes are compared
+//! against `golden/generation.txt`; a mismatch means generation output
+//! changed. If the change was intended, rewrite the file with `--bless`.
+
+use progship_logic::config::{select_systems, SystemOverrides};
+use progship_logic::genlib::facilities::{assign_deck_zones, get_facility_manifest};
+use progship_logic::genlib::graph::build_facility_graph;
+use progship_logic::genlib::layout::{generate_layout, CorridorTopology, LayoutConfig};
+use progship_logic::genlib::treemap::squarified_treemap;
+use progship_logic::mission::MissionConfig;
+use progship_logic::ship_config::{estimate_summary, randomize_config};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::TestResult;
+
+const GOLDEN_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/golden/generation.txt");
+
+/// Fixed generation inputs: (case name, seed, corridor topology).
+const CASES: [(&str, u64, CorridorTopology); 4] = [
+    ("seed-1", 1, CorridorTopology::CentralSpine),
+    ("seed-42", 42, CorridorTopology::CentralSpine),
+    ("seed-2024", 2024, CorridorTopology::CentralSpine),
+    ("seed-42-twin", 42, CorridorTopology::TwinSpine),
+];
+
+/// Hash of every generation step for every case, keyed `"<case> <step>"`.
+fn generation_hashes() -> BTreeMap<String, u64> {
+    let manifest = get_facility_manifest();
+    let mut hashes = BTreeMap::new();
+    for (case, seed, topology) in CASES {
+        let builder = randomize_config(seed);
+        let summary = estimate_summary(&builder);
+        let deck_count = summary.deck_count.clamp(4, 16);
+
+        let mission = MissionConfig {
+            destination: builder.destination,
+            colony_target_pop: builder.colony_target,
+            tech_level: builder.tech_level,
+            budget_class: builder.budget_class,
+            seed,
+            ..MissionConfig::default()
+        };
+        let systems = select_systems(&mission, &SystemOverrides::default());
+
+        let graph = build_facility_graph(&manifest, summary.crew_count, summary.passenger_count);
+        let plan = generate_layout(
+            &graph.nodes,
+            &LayoutConfig {
+                deck_count,
+                population: summary.crew_count + summary.passenger_count,
+                topology,
+                ..LayoutConfig::default()
+            },
+        );
+
+        // Zone finding, from the decks each zone's rooms landed on
+        let mut zone_decks: [BTreeSet<i32>; 7] = Default::default();
+        for room in &plan.rooms {
+            if let Some(node) = room.node {
+                zone_decks[graph.nodes[node].deck_zone.min(6) as usize].insert(room.deck);
+            }
+        }
+        let needed = zone_decks.map(|decks| decks.len() as u32);
+        let zones = assign_deck_zones(&needed, deck_count);
+
+        let mut state = seed;
+        let weights: Vec<(f32, usize)> = (0..12)
+            .map(|i| {
+                state = state
+                    .wrapping_mul(6_364_136_223_846_793_005)
+                    .wrapping_add(1_442_695_040_888_963_407);
+                (10.0 + (state >> 40) as f32 % 90.0, i)
+            })
+            .collect();
+        let treemap = squarified_treemap(&weights, 0, 0, 40, 30);
+
+        hashes.insert(format!("{case} systems"), structural_hash(&systems));
+        hashes.insert(format!("{case} graph"), structural_hash(&graph));
+        hashes.insert(format!("{case} layout"), structural_hash(&plan));
+        hashes.insert(format!("{case} zones"), structural_hash(&zones));
+        hashes.insert(format!("{case} treemap"), structural_hash(&treemap));
+    }
+    hashes
+}
+
+/// FNV-1a hash of `value`'s JSON form, with floats rounded to centimeters so
+/// last-bit differences between platforms don't count as drift.
+fn structural_hash(value: &impl Serialize) -> u64 {
+    fn round_floats(value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::Number(n) if n.is_f64() => {
+                let rounded = (n.as_f64().unwrap_or(0.0) * 100.0).round() as i64;
+                *value = serde_json::Value::from(rounded);
+            }
+            serde_json::Value::Array(items) => items.iter_mut().for_each(round_floats),
+            serde_json::Value::Object(fields) => fields.values_mut().for_each(round_floats),
+            _ => {}
+        }
+    }
+    let mut json = serde_json::to_value(value).expect("generation output serializes");
+    round_floats(&mut json);
+    json.to_string()
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325, |h, b| {
+            (h ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+        })
+}
+
+/// Compare the generation hashes against the golden file, one result per
+/// case and step.
+pub fn check_golden(verbose: bool) -> Vec<TestResult> {
+    println!("--- Generation Golden Values ---");
+    let golden: BTreeMap<String, String> = std::fs::read_to_string(GOLDEN_PATH)
+        .unwrap_or_default()
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let (key, hash) = line.rsplit_once(' ')?;
+            Some((key.to_string(), hash.to_string()))
+        })
+        .collect();
+    if golden.is_empty() {
+        println!("  no golden values at {GOLDEN_PATH}; run with --bless");
+    }
+
+    generation_hashes()
+        .into_iter()
+        .map(|(key, hash)| {
+            let actual = format!("{hash:016x}");
+            let expected = golden.get(&key);
+            if verbose {
+                println!("  {key}: {actual}");
+            }
+            TestResult {
+                name: format!("golden_{}", key.replace([' ', '-'], "_")),
+                passed: expected == Some(&actual),
+                detail: match expected {
+                    Some(e) if *e == actual => format!("{key} matches"),
+                    Some(e) => format!("{key} drifted: expected {e}, got {actual}"),
+                    None => format!("{key} has no golden value, got {actual}"),
+                },
+            }
+        })
+        .collect()
+}
+
+/// Rewrite the golden file from the current generation output.
+pub fn bless() -> std::io::Result<()> {
+    let mut out = String::from(
+        "# Generation golden values: `cargo run -p progship-simtest -- --golden`.\n\
+         # Regenerate after intended changes with `-- --golden --bless`.\n",
+    );
+    for (key, hash) in generation_hashes() {
+        out.push_str(&format!("{key} {hash:016x}\n"));
+    }
+    if let Some(dir) = std::path::Path::new(GOLDEN_PATH).parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(GOLDEN_PATH, out)?;
+    println!("Wrote {GOLDEN_PATH}");
+    Ok(())
+}
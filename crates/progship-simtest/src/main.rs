@@ -6,8 +6,15 @@
 //! Usage:
 //!   cargo run -p progship-simtest
 //!   cargo run -p progship-simtest -- --verbose
+//!   cargo run -p progship-simtest -- --golden           # check generation drift
+//!   cargo run -p progship-simtest -- --golden --bless   # accept new golden values
 
-use progship_logic::constants::{activity_types, groups, room_types, shifts};
+mod golden;
+
+use progship_logic::balance::{BalanceConfig, DecayTraits, IDLE_RATES};
+use progship_logic::constants::{
+    activity_types, groups, life_stages, lighting_modes, room_types, shifts,
+};
 use progship_logic::duty;
 use progship_logic::economy::{self, RationingLevel, ResourceLevels, ResourceValues};
 use progship_logic::geometry::{self, DoorInfo, RoomRect, Severity};
@@ -46,8 +53,26 @@ struct TestResult {
 
 fn main() {
     let verbose = std::env::args().any(|a| a == "--verbose");
+    let golden_mode = std::env::args().any(|a| a == "--golden");
+    if golden_mode && std::env::args().any(|a| a == "--bless") {
+        if let Err(e) = golden::bless() {
+            eprintln!("failed to write golden values: {e}");
+            std::process::exit(1);
+        }
+        return;
+    }
     println!("=== ProgShip Simulation Harness ===\n");
 
+    let results = if golden_mode {
+        golden::check_golden(verbose)
+    } else {
+        run_all(verbose)
+    };
+    print_summary(&results, verbose);
+}
+
+/// Every validation section, in order.
+fn run_all(verbose: bool) -> Vec<TestResult> {
     let mut results = Vec::new();
 
     // 1. Facility manifest validation
@@ -77,13 +102,20 @@ fn main() {
     // 9. Geometry validation (synthetic layout)
     results.extend(validate_geometry(verbose));
 
+    // 10. Need decay by age, personality and fitness
+    results.extend(validate_need_decay(verbose));
+
+    results
+}
+
+fn print_summary(results: &[TestResult], verbose: bool) {
     // ── Summary ──
     println!();
     let passed = results.iter().filter(|r| r.passed).count();
     let failed = results.iter().filter(|r| !r.passed).count();
     let total = results.len();
 
-    for r in &results {
+    for r in results {
         let icon = if r.passed { "✓" } else { "✗" };
         if !r.passed || verbose {
             println!("  {} {}: {}", icon, r.name, r.detail);
@@ -700,6 +732,10 @@ fn validate_utility_ai(verbose: bool) -> Vec<TestResult> {
         }),
         fit_for_duty: true,
         should_be_on_duty: true,
+        life_stage: life_stages::ADULT,
+        traits: 0,
+        lighting: lighting_modes::DAY,
+        rationing: 0,
     };
 
     // Very hungry → eating
@@ -833,6 +869,135 @@ fn validate_utility_ai(verbose: bool) -> Vec<TestResult> {
     results
 }
 
+// ── 10. Need Decay ──────────────────────────────────────────────────────
+
+fn validate_need_decay(verbose: bool) -> Vec<TestResult> {
+    println!("--- Need Decay ---");
+    let mut results = Vec::new();
+    let balance = BalanceConfig::default();
+    // Idle hours until a need goes from satisfied (0) to urgent (1)
+    let hours_to_full = |rate: f32| 1.0 / rate;
+
+    let stages = [
+        life_stages::INFANT,
+        life_stages::CHILD,
+        life_stages::ADULT,
+        life_stages::ELDER,
+    ];
+    let steps = [0.0, 0.25, 0.5, 0.75, 1.0];
+
+    // Sweep: every age × trait × fitness combination builds needs up at a
+    // finite, positive pace
+    let mut valid = 0;
+    let mut total = 0;
+    let mut hunger_hours: Vec<(u8, f32)> = Vec::new();
+    let mut fatigue_hours: Vec<(u8, f32)> = Vec::new();
+    for &life_stage in &stages {
+        for &extraversion in &steps {
+            for &neuroticism in &steps {
+                for &fitness in &steps {
+                    let traits = DecayTraits {
+                        life_stage,
+                        extraversion,
+                        neuroticism,
+                        fitness,
+                    };
+                    let r = balance.personal_rates(IDLE_RATES, &traits);
+                    total += 1;
+                    if [r.0, r.1, r.2, r.3, r.4]
+                        .iter()
+                        .all(|v| v.is_finite() && *v > 0.0)
+                    {
+                        valid += 1;
+                    }
+                    hunger_hours.push((life_stage, hours_to_full(r.0)));
+                    fatigue_hours.push((life_stage, hours_to_full(r.1)));
+                }
+            }
+        }
+    }
+    results.push(TestResult {
+        name: format!("decay_sweep_{}_combos", total),
+        passed: valid == total,
+        detail: format!("{}/{} combinations build needs up", valid, total),
+    });
+
+    let mean = |samples: &[(u8, f32)], stage: u8| {
+        let hours: Vec<f32> = samples
+            .iter()
+            .filter(|(s, _)| *s == stage)
+            .map(|(_, h)| *h)
+            .collect();
+        hours.iter().sum::<f32>() / hours.len() as f32
+    };
+
+    let child_hunger = mean(&hunger_hours, life_stages::CHILD);
+    let adult_hunger = mean(&hunger_hours, life_stages::ADULT);
+    results.push(TestResult {
+        name: "decay_children_hungrier".into(),
+        passed: child_hunger < adult_hunger,
+        detail: format!(
+            "hungry after {:.1}h (child) vs {:.1}h (adult)",
+            child_hunger, adult_hunger
+        ),
+    });
+
+    let elder_fatigue = mean(&fatigue_hours, life_stages::ELDER);
+    let adult_fatigue = mean(&fatigue_hours, life_stages::ADULT);
+    results.push(TestResult {
+        name: "decay_elders_tire_faster".into(),
+        passed: elder_fatigue < adult_fatigue,
+        detail: format!(
+            "tired after {:.1}h (elder) vs {:.1}h (adult)",
+            elder_fatigue, adult_fatigue
+        ),
+    });
+
+    // Unfit people tire faster; fit adults match the flat idle rate
+    let fit = balance.personal_rates(IDLE_RATES, &DecayTraits::default());
+    let unfit = balance.personal_rates(
+        IDLE_RATES,
+        &DecayTraits {
+            fitness: 0.0,
+            ..Default::default()
+        },
+    );
+    results.push(TestResult {
+        name: "decay_fitness".into(),
+        passed: fit == IDLE_RATES && unfit.1 > fit.1,
+        detail: format!(
+            "tired after {:.1}h (fit) vs {:.1}h (unfit)",
+            hours_to_full(fit.1),
+            hours_to_full(unfit.1)
+        ),
+    });
+
+    // Across the whole sweep people get hungry on a day-ish timescale
+    let hours: Vec<f32> = hunger_hours.iter().map(|(_, h)| *h).collect();
+    let (min, max) = hours
+        .iter()
+        .fold((f32::MAX, f32::MIN), |(lo, hi), &h| (lo.min(h), hi.max(h)));
+    results.push(TestResult {
+        name: "decay_hunger_spread".into(),
+        passed: min >= 10.0 && max <= 40.0,
+        detail: format!("hungry after {:.1}h to {:.1}h", min, max),
+    });
+
+    if verbose {
+        println!("  Idle hours until urgent (mean over traits and fitness):");
+        for &stage in &stages {
+            println!(
+                "    {:7}: hunger {:5.1}h  fatigue {:5.1}h",
+                life_stages::name(stage),
+                mean(&hunger_hours, stage),
+                mean(&fatigue_hours, stage)
+            );
+        }
+    }
+
+    results
+}
+
 // ── 9. Geometry Validation ──────────────────────────────────────────────
 
 fn validate_geometry(verbose: bool) -> Vec<TestResult> {
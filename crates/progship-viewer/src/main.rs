@@ -1,14 +1,21 @@
 //! ProgShip Viewer - Bevy-based visualization for the simulation
 
+use bevy::input::keyboard::{Key, KeyboardInput};
 use bevy::input::mouse::{MouseMotion, MouseWheel};
 use bevy::prelude::*;
 use bevy::window::PrimaryWindow;
 use progship_core::components::{
-    Activity, ConversationTopic, Crew, Movement, Name, Needs, Passenger, Person, Position, Room,
-    RoomType, Vec3 as SimVec3,
+    Activity, ActivityType, ConversationTopic, Crew, Movement, Name, Needs, Passenger, Person,
+    Position, Room, RoomType, Vec3 as SimVec3,
 };
 use progship_core::engine::SimulationEngine;
-use progship_core::generation::ShipConfig;
+use progship_core::generation::{CorridorTopology, ShipConfig};
+use progship_core::save_slots::{format_saved_at, AutosaveSchedule, SaveSlots, SlotInfo};
+use progship_logic::encyclopedia::{self, Entry};
+use progship_logic::lighting::{
+    deck_ambience, kelvin_to_rgb, quarters_ambience, zone_light_schedule, Ambience,
+};
+use std::collections::HashMap;
 
 fn main() {
     App::new()
@@ -28,11 +35,18 @@ fn main() {
         .insert_resource(ViewerConfig::default())
         .insert_resource(CurrentDeck(0))
         .insert_resource(SelectedPerson(None))
+        .insert_resource(SaveBrowser::default())
+        .insert_resource(Encyclopedia::default())
         .add_systems(Startup, setup)
         .add_systems(
             Update,
             (
                 update_simulation,
+                save_browser_input,
+                autosave,
+                update_save_browser_text,
+                encyclopedia_input,
+                update_encyclopedia_text,
                 camera_controls,
                 deck_switching,
                 handle_click,
@@ -77,13 +91,97 @@ struct CurrentDeck(i32);
 #[derive(Resource)]
 struct ViewerConfig {
     time_scale: f32,
+    /// File given on the command line: a save or a server state export
+    open_path: Option<String>,
 }
 
 impl Default for ViewerConfig {
     fn default() -> Self {
         Self {
             time_scale: 1.0, // Real-time for smooth visuals (use +/- to adjust)
+            open_path: std::env::args().nth(1),
+        }
+    }
+}
+
+/// Save-slot browser (Ctrl+L) and the slot Ctrl+S saves to
+#[derive(Resource)]
+struct SaveBrowser {
+    slots: SaveSlots,
+    open: bool,
+    /// Slots on disk, refreshed when the browser opens or changes
+    listing: Vec<SlotInfo>,
+    selected: usize,
+    /// Name being typed for a new slot
+    naming: Option<String>,
+    /// Slot Ctrl+S saves to: the last one saved or loaded
+    current_slot: String,
+    autosave: AutosaveSchedule,
+}
+
+impl Default for SaveBrowser {
+    fn default() -> Self {
+        Self {
+            slots: SaveSlots::new("saves"),
+            open: false,
+            listing: Vec::new(),
+            selected: 0,
+            naming: None,
+            current_slot: "Quicksave".to_string(),
+            autosave: AutosaveSchedule::new(0.0),
+        }
+    }
+}
+
+impl SaveBrowser {
+    fn refresh(&mut self) {
+        self.listing = self.slots.list();
+        self.selected = self.selected.min(self.listing.len().saturating_sub(1));
+    }
+
+    /// Whether typed keys belong to the browser rather than the viewer
+    fn captures_keys(&self) -> bool {
+        self.open
+    }
+
+    fn save(&mut self, sim: &SimulationEngine, slot_name: &str) {
+        match self.slots.save(sim, slot_name) {
+            Ok(path) => {
+                println!("Saved \"{}\" to {}", slot_name, path.display());
+                self.current_slot = slot_name.to_string();
+            }
+            Err(e) => eprintln!("Failed to save \"{}\": {}", slot_name, e),
         }
+        self.refresh();
+    }
+}
+
+/// Shipopedia (F1): the entries and the one on screen while it is open
+#[derive(Resource)]
+struct Encyclopedia {
+    entries: Vec<Entry>,
+    page: Option<usize>,
+}
+
+impl Default for Encyclopedia {
+    fn default() -> Self {
+        Self {
+            entries: encyclopedia::entries(),
+            page: None,
+        }
+    }
+}
+
+/// Load a binary save or a JSON state export (`export_state` reducer) into
+/// the engine, whichever the file holds.
+fn load_file(sim: &mut SimulationEngine, path: &str) {
+    match std::fs::read(path) {
+        Ok(bytes) => match sim.load_bytes(&bytes) {
+            Ok(true) => println!("Loaded server state export from {}", path),
+            Ok(false) => println!("Loaded simulation from {}", path),
+            Err(e) => eprintln!("Failed to load {}: {}", path, e),
+        },
+        Err(e) => eprintln!("Failed to open {}: {}", path, e),
     }
 }
 
@@ -94,7 +192,18 @@ struct TimeText;
 #[derive(Component)]
 struct DeckText;
 
-fn setup(mut commands: Commands, mut sim: ResMut<SimWrapper>, viewer_config: Res<ViewerConfig>) {
+#[derive(Component)]
+struct SaveBrowserText;
+
+#[derive(Component)]
+struct EncyclopediaText;
+
+fn setup(
+    mut commands: Commands,
+    mut sim: ResMut<SimWrapper>,
+    viewer_config: Res<ViewerConfig>,
+    mut browser: ResMut<SaveBrowser>,
+) {
     // Setup camera
     commands.spawn(Camera2d::default());
 
@@ -107,9 +216,23 @@ fn setup(mut commands: Commands, mut sim: ResMut<SimWrapper>, viewer_config: Res
         passenger_capacity: 4000,
         ship_length: 400.0,
         ship_width: 60.0,
+        corridor_topology: CorridorTopology::TwinSpine,
+        ..Default::default()
     };
-    sim.0.generate(config.clone());
+    if let Some(path) = &viewer_config.open_path {
+        load_file(&mut sim.0, path);
+    } else {
+        sim.0.generate(config.clone());
+        info!(
+            "Generated {} with {} crew, {} passengers, {} decks",
+            config.name,
+            sim.0.crew_count(),
+            sim.0.passenger_count(),
+            config.num_decks
+        );
+    }
     sim.0.set_time_scale(viewer_config.time_scale);
+    browser.autosave = AutosaveSchedule::new(sim.0.sim_time);
 
     // Spawn UI text elements
     commands.spawn((
@@ -134,20 +257,40 @@ fn setup(mut commands: Commands, mut sim: ResMut<SimWrapper>, viewer_config: Res
         DeckText,
     ));
 
-    info!(
-        "Generated {} with {} crew, {} passengers, {} decks",
-        config.name,
-        sim.0.crew_count(),
-        sim.0.passenger_count(),
-        config.num_decks
-    );
+    commands.spawn((
+        Text2d::new(""),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        Transform::from_xyz(0.0, 0.0, 110.0),
+        SaveBrowserText,
+    ));
+
+    commands.spawn((
+        Text2d::new(""),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        Transform::from_xyz(0.0, 0.0, 110.0),
+        EncyclopediaText,
+    ));
 }
 
 fn update_simulation(
     time: Res<Time>,
     keyboard: Res<ButtonInput<KeyCode>>,
     mut sim: ResMut<SimWrapper>,
+    browser: Res<SaveBrowser>,
 ) {
+    if browser.captures_keys() {
+        sim.0.update(time.delta_secs());
+        return;
+    }
+
     // Time scale controls: +/= to speed up, - to slow down, 0 to pause/resume
     if keyboard.just_pressed(KeyCode::Equal) || keyboard.just_pressed(KeyCode::NumpadAdd) {
         let current = sim.0.time_scale();
@@ -166,33 +309,208 @@ fn update_simulation(
         }
     }
 
-    // Save with S key
-    if keyboard.just_pressed(KeyCode::KeyS)
-        && (keyboard.pressed(KeyCode::ControlLeft) || keyboard.pressed(KeyCode::ControlRight))
-    {
-        match std::fs::File::create("save.bin") {
-            Ok(file) => match sim.0.save(std::io::BufWriter::new(file)) {
-                Ok(()) => println!("Saved simulation to save.bin"),
-                Err(e) => eprintln!("Failed to save: {}", e),
-            },
-            Err(e) => eprintln!("Failed to create save file: {}", e),
+    sim.0.update(time.delta_secs());
+}
+
+/// Ctrl+S saves to the current slot and Ctrl+L toggles the slot browser.
+/// In the browser: Up/Down select, Enter loads, Delete removes, N saves to a
+/// new named slot (type the name, Enter to save, Esc to cancel).
+fn save_browser_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut key_events: EventReader<KeyboardInput>,
+    mut sim: ResMut<SimWrapper>,
+    mut browser: ResMut<SaveBrowser>,
+) {
+    let ctrl = keyboard.pressed(KeyCode::ControlLeft) || keyboard.pressed(KeyCode::ControlRight);
+
+    if let Some(mut name) = browser.naming.take() {
+        for event in key_events.read() {
+            if !event.state.is_pressed() {
+                continue;
+            }
+            match &event.logical_key {
+                Key::Character(c) if name.len() < 32 => name.push_str(c),
+                Key::Space if name.len() < 32 => name.push(' '),
+                Key::Backspace => {
+                    name.pop();
+                }
+                Key::Enter if !name.trim().is_empty() => {
+                    let slot_name = name.trim().to_string();
+                    browser.save(&sim.0, &slot_name);
+                    return;
+                }
+                Key::Escape => return,
+                _ => {}
+            }
         }
+        browser.naming = Some(name);
+        return;
     }
+    key_events.clear();
 
-    // Load with L key
-    if keyboard.just_pressed(KeyCode::KeyL)
-        && (keyboard.pressed(KeyCode::ControlLeft) || keyboard.pressed(KeyCode::ControlRight))
-    {
-        match std::fs::File::open("save.bin") {
-            Ok(file) => match sim.0.load(std::io::BufReader::new(file)) {
-                Ok(()) => println!("Loaded simulation from save.bin"),
-                Err(e) => eprintln!("Failed to load: {}", e),
-            },
-            Err(e) => eprintln!("Failed to open save file: {}", e),
+    if ctrl && keyboard.just_pressed(KeyCode::KeyS) {
+        let slot_name = browser.current_slot.clone();
+        browser.save(&sim.0, &slot_name);
+    }
+    if ctrl && keyboard.just_pressed(KeyCode::KeyL) {
+        browser.open = !browser.open;
+        browser.refresh();
+        return;
+    }
+    if !browser.open {
+        return;
+    }
+
+    if keyboard.just_pressed(KeyCode::Escape) {
+        browser.open = false;
+    } else if keyboard.just_pressed(KeyCode::ArrowUp) {
+        browser.selected = browser.selected.saturating_sub(1);
+    } else if keyboard.just_pressed(KeyCode::ArrowDown) {
+        browser.selected = (browser.selected + 1).min(browser.listing.len().saturating_sub(1));
+    } else if keyboard.just_pressed(KeyCode::KeyN) {
+        browser.naming = Some(String::new());
+    } else if let Some(slot) = browser.listing.get(browser.selected).cloned() {
+        let slot_name = slot.header.slot_name;
+        if keyboard.just_pressed(KeyCode::Enter) {
+            match browser.slots.load(&mut sim.0, &slot_name) {
+                Ok(()) => {
+                    println!("Loaded \"{}\"", slot_name);
+                    browser.current_slot = slot_name;
+                    browser.autosave = AutosaveSchedule::new(sim.0.sim_time);
+                    browser.open = false;
+                }
+                Err(e) => eprintln!("Failed to load \"{}\": {}", slot_name, e),
+            }
+        } else if keyboard.just_pressed(KeyCode::Delete) {
+            if let Err(e) = browser.slots.delete(&slot_name) {
+                eprintln!("Failed to delete \"{}\": {}", slot_name, e);
+            }
+            browser.refresh();
         }
     }
+}
 
-    sim.0.update(time.delta_secs());
+/// Draw the slot list over the middle of the view while the browser is open
+fn update_save_browser_text(
+    browser: Res<SaveBrowser>,
+    camera_state: Res<CameraState>,
+    mut query: Query<(&mut Text2d, &mut Transform), With<SaveBrowserText>>,
+) {
+    let Ok((mut text, mut transform)) = query.get_single_mut() else {
+        return;
+    };
+    transform.translation.x = camera_state.target.x;
+    transform.translation.y = camera_state.target.y;
+    transform.scale = Vec3::splat(camera_state.zoom);
+    if !browser.open {
+        text.clear();
+        return;
+    }
+
+    let mut lines = vec![format!(
+        "Save slots ({})   Enter load - N new - Del delete - Esc close",
+        browser.slots.dir().display()
+    )];
+    if let Some(name) = &browser.naming {
+        lines.push(format!("New slot name: {}_", name));
+    }
+    if browser.listing.is_empty() {
+        lines.push("(no saves yet)".to_string());
+    }
+    for (i, slot) in browser.listing.iter().enumerate() {
+        let h = &slot.header;
+        let marker = if i == browser.selected { ">" } else { " " };
+        lines.push(format!(
+            "{} {:<20} {}  {}  Day {}  {} crew, {} passengers",
+            marker,
+            h.slot_name,
+            format_saved_at(h.saved_at),
+            h.ship_name,
+            (h.sim_time / 24.0).floor() as i64 + 1,
+            h.crew_count,
+            h.passenger_count
+        ));
+    }
+    **text = lines.join("\n");
+}
+
+/// F1 toggles the shipopedia. While it is open, comma/period step through
+/// entries and PageUp/PageDown jump between sections.
+fn encyclopedia_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    browser: Res<SaveBrowser>,
+    mut shipopedia: ResMut<Encyclopedia>,
+) {
+    if browser.captures_keys() {
+        return;
+    }
+    if keyboard.just_pressed(KeyCode::F1) {
+        shipopedia.page = match shipopedia.page {
+            Some(_) => None,
+            None => Some(0),
+        };
+    }
+    let Some(page) = shipopedia.page else {
+        return;
+    };
+    let entries = &shipopedia.entries;
+    let page = if keyboard.just_pressed(KeyCode::Period) {
+        (page + 1).min(entries.len().saturating_sub(1))
+    } else if keyboard.just_pressed(KeyCode::Comma) {
+        page.saturating_sub(1)
+    } else if keyboard.just_pressed(KeyCode::PageDown) {
+        encyclopedia::next_section(entries, page)
+    } else if keyboard.just_pressed(KeyCode::PageUp) {
+        encyclopedia::previous_section(entries, page)
+    } else {
+        page
+    };
+    shipopedia.page = Some(page);
+}
+
+/// Draw the open shipopedia entry over the middle of the view
+fn update_encyclopedia_text(
+    shipopedia: Res<Encyclopedia>,
+    camera_state: Res<CameraState>,
+    mut query: Query<(&mut Text2d, &mut Transform), With<EncyclopediaText>>,
+) {
+    let Ok((mut text, mut transform)) = query.get_single_mut() else {
+        return;
+    };
+    transform.translation.x = camera_state.target.x;
+    transform.translation.y = camera_state.target.y;
+    transform.scale = Vec3::splat(camera_state.zoom);
+    let Some(entry) = shipopedia.page.and_then(|i| shipopedia.entries.get(i)) else {
+        text.clear();
+        return;
+    };
+    let section: Vec<&Entry> = shipopedia
+        .entries
+        .iter()
+        .filter(|e| e.category == entry.category)
+        .collect();
+    let index = section.iter().position(|e| *e == entry).unwrap_or(0);
+    **text = format!(
+        "Shipopedia - {} ({}/{})   , . entry - PgUp/PgDn section - F1 close\n\n{}",
+        entry.category.label(),
+        index + 1,
+        section.len(),
+        entry.to_text()
+    );
+}
+
+/// Save into the next autosave slot every few simulated hours
+fn autosave(sim: Res<SimWrapper>, mut browser: ResMut<SaveBrowser>) {
+    let Some(slot_name) = browser.autosave.due(sim.0.sim_time) else {
+        return;
+    };
+    match browser.slots.save(&sim.0, &slot_name) {
+        Ok(_) => info!("Autosaved to \"{}\"", slot_name),
+        Err(e) => eprintln!("Autosave failed: {}", e),
+    }
+    if browser.open {
+        browser.refresh();
+    }
 }
 
 fn camera_controls(
@@ -202,23 +520,26 @@ fn camera_controls(
     mouse_buttons: Res<ButtonInput<MouseButton>>,
     mut scroll_events: EventReader<MouseWheel>,
     mut motion_events: EventReader<MouseMotion>,
+    browser: Res<SaveBrowser>,
 ) {
     let pan_speed = 500.0 * camera_state.zoom;
     let zoom_speed = 0.1;
     let dt = 0.016;
 
-    // Keyboard pan
-    if keyboard.pressed(KeyCode::KeyW) || keyboard.pressed(KeyCode::ArrowUp) {
-        camera_state.target.y += pan_speed * dt;
-    }
-    if keyboard.pressed(KeyCode::KeyS) || keyboard.pressed(KeyCode::ArrowDown) {
-        camera_state.target.y -= pan_speed * dt;
-    }
-    if keyboard.pressed(KeyCode::KeyA) || keyboard.pressed(KeyCode::ArrowLeft) {
-        camera_state.target.x -= pan_speed * dt;
-    }
-    if keyboard.pressed(KeyCode::KeyD) || keyboard.pressed(KeyCode::ArrowRight) {
-        camera_state.target.x += pan_speed * dt;
+    // Keyboard pan (the save browser uses the keys while open)
+    if !browser.captures_keys() {
+        if keyboard.pressed(KeyCode::KeyW) || keyboard.pressed(KeyCode::ArrowUp) {
+            camera_state.target.y += pan_speed * dt;
+        }
+        if keyboard.pressed(KeyCode::KeyS) || keyboard.pressed(KeyCode::ArrowDown) {
+            camera_state.target.y -= pan_speed * dt;
+        }
+        if keyboard.pressed(KeyCode::KeyA) || keyboard.pressed(KeyCode::ArrowLeft) {
+            camera_state.target.x -= pan_speed * dt;
+        }
+        if keyboard.pressed(KeyCode::KeyD) || keyboard.pressed(KeyCode::ArrowRight) {
+            camera_state.target.x += pan_speed * dt;
+        }
     }
 
     // Mouse drag
@@ -252,7 +573,13 @@ fn deck_switching(
     keyboard: Res<ButtonInput<KeyCode>>,
     mut current_deck: ResMut<CurrentDeck>,
     sim: Res<SimWrapper>,
+    browser: Res<SaveBrowser>,
+    shipopedia: Res<Encyclopedia>,
 ) {
+    // PageUp/PageDown page through the shipopedia while it is open
+    if browser.captures_keys() || shipopedia.page.is_some() {
+        return;
+    }
     let num_decks = sim
         .0
         .ship_layout
@@ -321,7 +648,23 @@ fn render_rooms(sim: Res<SimWrapper>, current_deck: Res<CurrentDeck>, mut gizmos
         None => return,
     };
 
-    for &room_entity in &layout.rooms {
+    let ambience = deck_ambience(
+        1.0,
+        DAY_KELVIN,
+        deck_light_schedule(&sim.0, current_deck.0),
+        sim.0.hour_of_day(),
+    );
+    // (occupants, sleeping) per room
+    let mut sleepers: HashMap<u32, (u32, u32)> = HashMap::new();
+    for (_, (pos, activity)) in sim.0.world.query::<(&Position, &Activity)>().iter() {
+        let count = sleepers.entry(pos.room_id).or_default();
+        count.0 += 1;
+        if activity.activity_type == ActivityType::Sleeping {
+            count.1 += 1;
+        }
+    }
+
+    for (room_id, &room_entity) in layout.rooms.iter().enumerate() {
         let room = match sim.0.world.get::<&Room>(room_entity) {
             Ok(r) => r,
             Err(_) => continue,
@@ -335,7 +678,13 @@ fn render_rooms(sim: Res<SimWrapper>, current_deck: Res<CurrentDeck>, mut gizmos
         let center = Vec2::new(room.world_x, room.world_y);
         let size = Vec2::new(max_x - min_x, max_y - min_y);
 
-        let color = room_color(room.room_type);
+        let lit = match sleepers.get(&(room_id as u32)) {
+            Some(&(occupants, sleeping)) if is_quarters(room.room_type) => {
+                quarters_ambience(ambience, sleeping, occupants)
+            }
+            _ => ambience,
+        };
+        let color = lit_color(room_color(room.room_type), lit);
 
         // Room fill
         gizmos.rect_2d(Isometry2d::from_translation(center), size, color);
@@ -961,6 +1310,56 @@ fn draw_resource_bar(
     }
 }
 
+/// Daytime color temperature of deck lighting in kelvin.
+const DAY_KELVIN: u32 = 4500;
+
+/// Shift whose day a deck's lights follow: decks given over to engineering
+/// and life support run on the night watch's day, as on the server.
+fn deck_light_schedule(sim: &SimulationEngine, deck: i32) -> u8 {
+    let (mut plant, mut other) = (0, 0);
+    for (_, room) in sim.world.query::<&Room>().iter() {
+        if room.deck_level != deck {
+            continue;
+        }
+        match room.room_type {
+            RoomType::Engineering
+            | RoomType::ReactorRoom
+            | RoomType::MaintenanceBay
+            | RoomType::LifeSupport
+            | RoomType::Hydroponics
+            | RoomType::WaterRecycling => plant += 1,
+            RoomType::Corridor | RoomType::Elevator | RoomType::Ladder => {}
+            _ => other += 1,
+        }
+    }
+    // Zone 6 is engineering, zone 1 habitation
+    zone_light_schedule(if plant > other { 6 } else { 1 })
+}
+
+fn is_quarters(room_type: RoomType) -> bool {
+    matches!(
+        room_type,
+        RoomType::Quarters
+            | RoomType::QuartersCrew
+            | RoomType::QuartersOfficer
+            | RoomType::QuartersPassenger
+    )
+}
+
+/// Room fill shaded by light level and tinted by color temperature. Rooms
+/// never go fully black so the layout stays readable at night.
+fn lit_color(base: Color, lit: Ambience) -> Color {
+    let base = base.to_srgba();
+    let [r, g, b] = kelvin_to_rgb(lit.kelvin);
+    let shade = 0.3 + 0.7 * lit.level;
+    Color::srgba(
+        base.red * r * shade,
+        base.green * g * shade,
+        base.blue * b * shade,
+        base.alpha,
+    )
+}
+
 fn room_color(room_type: RoomType) -> Color {
     match room_type {
         RoomType::Bridge => Color::srgba(0.8, 0.2, 0.2, 0.7),
@@ -984,6 +1383,7 @@ fn room_color(room_type: RoomType) -> Color {
         RoomType::Observatory => Color::srgba(0.2, 0.3, 0.7, 0.7),
         RoomType::Corridor => Color::srgba(0.45, 0.45, 0.5, 0.6),
         RoomType::Elevator => Color::srgba(0.5, 0.5, 0.55, 0.7),
+        RoomType::Ladder => Color::srgba(0.45, 0.45, 0.5, 0.7),
         RoomType::Airlock => Color::srgba(0.3, 0.3, 0.3, 0.7),
         RoomType::Storage => Color::srgba(0.45, 0.4, 0.35, 0.7),
         RoomType::Laboratory => Color::srgba(0.5, 0.6, 0.7, 0.7),
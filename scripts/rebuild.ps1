@@ -6,7 +6,9 @@ param(
     [string]$ShipName = "Test Ship",
     [int]$Decks = 21,
     [int]$Length = 100,
-    [int]$Radius = 50
+    [int]$Radius = 50,
+    [int]$CorridorTopology = 0,
+    [int]$HullTaper = 0
 )
 
 $ErrorActionPreference = "Stop"
@@ -25,7 +27,7 @@ if ($LASTEXITCODE -ne 0) { Write-Host "Publish FAILED" -ForegroundColor Red; exi
 
 # Step 3: Init ship
 Write-Host "`n[3/6] Initializing ship..." -ForegroundColor Yellow
-spacetime call progship init_ship "`"$ShipName`"" $Decks $Length $Radius -s $ServerUrl
+spacetime call progship init_ship "`"$ShipName`"" $Decks $Length $Radius $CorridorTopology $HullTaper -s $ServerUrl
 if ($LASTEXITCODE -ne 0) { Write-Host "Init FAILED" -ForegroundColor Red; exit 1 }
 
 # Step 4: Dump and verify